@@ -0,0 +1,63 @@
+//! Wormhole burn payload
+//!
+//! solana-burn-program publishes every burn_registered_asset burn through
+//! the Wormhole core bridge with this payload, signed by its EMITTER_SEED
+//! PDA. The light client's submit_burn_vaa parses it back out of the
+//! PostedVAA. Both depend on this crate, so the layout cannot drift.
+//!
+//! Layout (big-endian, as Wormhole payloads are):
+//!   BURN_PAYLOAD_ID(1) || asset_id(1) || burn_nonce(u64) || user(32)
+//!   || amount(u64) || solana_burn_slot(u64) || solana_burn_timestamp(i64)
+//!
+//! No dependencies: the burn program and the light client build against
+//! different Anchor versions, so keys are plain 32-byte arrays here.
+
+/// First byte of a burn payload (a later layout takes another id)
+pub const BURN_PAYLOAD_ID: u8 = 1;
+pub const BURN_PAYLOAD_LEN: usize = 1 + 1 + 8 + 32 + 8 + 8 + 8;
+
+/// Seed of the burn program's Wormhole emitter PDA (the emitter address
+/// the light client's WormholeConfig pins)
+pub const EMITTER_SEED: &[u8] = b"emitter";
+
+/// A Solana burn as published through Wormhole
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BurnPayload {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    /// Key the burn is minted to on X1
+    pub user: [u8; 32],
+    pub amount: u64,
+    pub solana_burn_slot: u64,
+    pub solana_burn_timestamp: i64,
+}
+
+impl BurnPayload {
+    /// None unless `payload` is exactly one burn payload
+    pub fn decode(payload: &[u8]) -> Option<Self> {
+        if payload.len() != BURN_PAYLOAD_LEN || payload[0] != BURN_PAYLOAD_ID {
+            return None;
+        }
+        let u64_at = |o: usize| u64::from_be_bytes(payload[o..o + 8].try_into().unwrap());
+        Some(Self {
+            asset_id: payload[1],
+            burn_nonce: u64_at(2),
+            user: payload[10..42].try_into().unwrap(),
+            amount: u64_at(42),
+            solana_burn_slot: u64_at(50),
+            solana_burn_timestamp: u64_at(58) as i64,
+        })
+    }
+
+    pub fn encode(&self) -> [u8; BURN_PAYLOAD_LEN] {
+        let mut payload = [0u8; BURN_PAYLOAD_LEN];
+        payload[0] = BURN_PAYLOAD_ID;
+        payload[1] = self.asset_id;
+        payload[2..10].copy_from_slice(&self.burn_nonce.to_be_bytes());
+        payload[10..42].copy_from_slice(&self.user);
+        payload[42..50].copy_from_slice(&self.amount.to_be_bytes());
+        payload[50..58].copy_from_slice(&self.solana_burn_slot.to_be_bytes());
+        payload[58..66].copy_from_slice(&self.solana_burn_timestamp.to_be_bytes());
+        payload
+    }
+}
@@ -0,0 +1,610 @@
+//! Conformance checks for validator attestation services
+//!
+//! A validator may run any implementation of the attestation API (the
+//! reference one is validator-attestation-service/). This crate holds what
+//! an implementation must agree with byte for byte: the signed attestation
+//! messages, the signed-response envelope and the JSON shape of an
+//! attestation, plus the fixture and report formats of `xencat-conformance`.
+//!
+//! Messages (little-endian integers, sha256):
+//! - V3:    hash(domain || asset_id || validator_set_version || burn_nonce || amount || user)
+//! - Timed: hash(v3 || solana_burn_slot || solana_burn_timestamp || confirmation_depth ||
+//!   solana_confirmed_slot || attested_at), what validators sign
+//! - Insurance: hash(domain || "INSURED_BURN" || asset_id || burn_nonce || user || covered_amount || premium)
+//! - Relayer claim: hash(domain || "RELAYER_CLAIM" || asset_id || burn_nonce || user || relayer_fee)
+//! - Response: hash(RESPONSE_DOMAIN || request_nonce || body), signed into X-Validator-Signature
+//!
+//! `domain` is the domain separator, followed by the X1 genesis hash when the
+//! deployment has bound one (X1ChainId).
+
+use ed25519_dalek::{PublicKey, Signature};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+/// The light client's compiled-in domain (a deployment may set its own BridgeDomain)
+pub const DEFAULT_DOMAIN_SEPARATOR: &str = "XENCAT_X1_BRIDGE_V1";
+
+/// Must match sdk/attestation-client/src/response-signing.ts
+pub const RESPONSE_DOMAIN: &str = "XENCAT_ATTESTATION_RESPONSE_V1";
+pub const HEADER_REQUEST_NONCE: &str = "x-request-nonce";
+pub const HEADER_VALIDATOR_PUBKEY: &str = "x-validator-pubkey";
+pub const HEADER_VALIDATOR_SIGNATURE: &str = "x-validator-signature";
+pub const HEADER_VALIDATOR_KEY: &str = "x-validator-key";
+
+/// How far an attestation's signing time may be from the kit's clock
+pub const MAX_CLOCK_SKEW_SECONDS: i64 = 300;
+
+/// Shallowest depth validators attest at, and what a BurnRecord depth of 0 means
+pub const MIN_CONFIRMATION_DEPTH: u16 = 32;
+
+/// Default output of `record`, input of `run`
+pub const FIXTURES_FILE: &str = "conformance-fixtures.json";
+/// Default output of `run`
+pub const REPORT_FILE: &str = "conformance-report.json";
+
+fn sha256(parts: &[&[u8]]) -> [u8; 32] {
+    let mut hasher = Sha256::new();
+    for part in parts {
+        hasher.update(part);
+    }
+    hasher.finalize().into()
+}
+
+/// V3 (asset-aware) attestation message
+pub fn attestation_message_v3(
+    domain: &[u8],
+    asset_id: u8,
+    validator_set_version: u64,
+    burn_nonce: u64,
+    amount: u64,
+    user: &[u8; 32],
+) -> [u8; 32] {
+    sha256(&[
+        domain,
+        &[asset_id],
+        &validator_set_version.to_le_bytes(),
+        &burn_nonce.to_le_bytes(),
+        &amount.to_le_bytes(),
+        user,
+    ])
+}
+
+/// The message each validator signs: the V3 message plus burn and signing
+/// times and the confirmation depth waited for
+pub fn timed_attestation_message(
+    v3_message: &[u8; 32],
+    solana_burn_slot: u64,
+    solana_burn_timestamp: i64,
+    confirmation_depth: u16,
+    solana_confirmed_slot: u64,
+    attested_at: i64,
+) -> [u8; 32] {
+    sha256(&[
+        v3_message,
+        &solana_burn_slot.to_le_bytes(),
+        &solana_burn_timestamp.to_le_bytes(),
+        &confirmation_depth.to_le_bytes(),
+        &solana_confirmed_slot.to_le_bytes(),
+        &attested_at.to_le_bytes(),
+    ])
+}
+
+/// Slot a burn is attested at: its slot plus the chosen depth (at least the minimum)
+pub fn confirmed_slot(solana_burn_slot: u64, confirmation_depth: u16) -> u64 {
+    solana_burn_slot + confirmation_depth.max(MIN_CONFIRMATION_DEPTH) as u64
+}
+
+pub fn insurance_message(
+    domain: &[u8],
+    asset_id: u8,
+    burn_nonce: u64,
+    user: &[u8; 32],
+    covered_amount: u64,
+    premium: u64,
+) -> [u8; 32] {
+    sha256(&[
+        domain,
+        b"INSURED_BURN",
+        &[asset_id],
+        &burn_nonce.to_le_bytes(),
+        user,
+        &covered_amount.to_le_bytes(),
+        &premium.to_le_bytes(),
+    ])
+}
+
+/// Claim terms of a designated-recipient burn (user = the recipient)
+pub fn relayer_claim_message(domain: &[u8], asset_id: u8, burn_nonce: u64, user: &[u8; 32], relayer_fee: u64) -> [u8; 32] {
+    sha256(&[
+        domain,
+        b"RELAYER_CLAIM",
+        &[asset_id],
+        &burn_nonce.to_le_bytes(),
+        user,
+        &relayer_fee.to_le_bytes(),
+    ])
+}
+
+/// Digest signed into X-Validator-Signature
+pub fn response_digest(request_nonce: &str, body: &str) -> [u8; 32] {
+    sha256(&[RESPONSE_DOMAIN.as_bytes(), request_nonce.as_bytes(), body.as_bytes()])
+}
+
+/// Strict Ed25519 verification (rejects weak keys and non-canonical S, like the light client)
+pub fn verify_signature(pubkey: &[u8; 32], message: &[u8], signature: &[u8]) -> bool {
+    let (Ok(key), Ok(signature)) = (PublicKey::from_bytes(pubkey), Signature::from_bytes(signature)) else {
+        return false;
+    };
+    key.verify_strict(message, &signature).is_ok()
+}
+
+pub fn decode_pubkey(value: &str) -> Result<[u8; 32], String> {
+    bs58::decode(value)
+        .into_vec()
+        .ok()
+        .and_then(|bytes| <[u8; 32]>::try_from(bytes).ok())
+        .ok_or_else(|| format!("not a base58 public key: {}", value))
+}
+
+/// Why a response is not correctly signed by `validator` for `request_nonce` (empty = it is)
+pub fn response_problems(
+    validator: &str,
+    request_nonce: &str,
+    body: &str,
+    pubkey_header: Option<&str>,
+    signature_header: Option<&str>,
+) -> Vec<String> {
+    let (Some(signer), Some(signature)) = (pubkey_header, signature_header) else {
+        return vec!["response is not signed (X-Validator-Pubkey / X-Validator-Signature missing)".into()];
+    };
+    if signer != validator {
+        return vec![format!("response signed by {} instead of {}", signer, validator)];
+    }
+    let valid = match (decode_pubkey(signer), bs58::decode(signature).into_vec()) {
+        (Ok(key), Ok(signature)) => verify_signature(&key, &response_digest(request_nonce, body), &signature),
+        _ => false,
+    };
+    if valid {
+        vec![]
+    } else {
+        vec!["response signature does not cover this body and request nonce".into()]
+    }
+}
+
+/// One finalized burn with its ground truth
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct BurnFixture {
+    /// Check id suffix in the report
+    pub name: String,
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    /// Burner (base58)
+    pub user: String,
+    pub amount: u64,
+    pub solana_burn_slot: u64,
+    pub solana_burn_timestamp: i64,
+    /// From the BurnRecord (0 = default)
+    #[serde(default)]
+    pub confirmation_depth: u16,
+}
+
+impl BurnFixture {
+    pub fn solana_confirmed_slot(&self) -> u64 {
+        confirmed_slot(self.solana_burn_slot, self.confirmation_depth)
+    }
+}
+
+/// Canonical burns of one cluster, replayed against the endpoint under test
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct FixtureSet {
+    /// Solana cluster the burns were made on (the endpoint must watch the same one)
+    pub cluster: String,
+    pub domain_separator: String,
+    /// X1 genesis hash (base58) bound into the domain, if the deployment has one
+    #[serde(default)]
+    pub x1_genesis_hash: Option<String>,
+    /// Sent with every request (signed into the message)
+    pub validator_set_version: u64,
+    /// Reference endpoint the burns were recorded from
+    #[serde(default)]
+    pub recorded_from: Option<String>,
+    pub burns: Vec<BurnFixture>,
+}
+
+impl FixtureSet {
+    /// The signed domain bytes
+    pub fn domain(&self) -> Result<Vec<u8>, String> {
+        attestation_domain(&self.domain_separator, self.x1_genesis_hash.as_deref())
+    }
+}
+
+/// Domain separator followed by the bound X1 genesis hash (base58), if any
+pub fn attestation_domain(domain_separator: &str, x1_genesis_hash: Option<&str>) -> Result<Vec<u8>, String> {
+    let mut domain = domain_separator.as_bytes().to_vec();
+    if let Some(genesis) = x1_genesis_hash {
+        domain.extend_from_slice(&decode_pubkey(genesis).map_err(|_| format!("not a base58 genesis hash: {}", genesis))?);
+    }
+    Ok(domain)
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Insurance {
+    pub covered_amount: u64,
+    pub premium: u64,
+    pub signature: Vec<u8>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct RelayerClaim {
+    pub relayer_fee: u64,
+    pub signature: Vec<u8>,
+}
+
+/// 200 body of /attest-burn (and `attestation` of a batch result)
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Attestation {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: String,
+    pub amount: u64,
+    pub validator_set_version: u64,
+    pub validator_pubkey: String,
+    pub signature: Vec<u8>,
+    pub solana_burn_slot: u64,
+    pub solana_burn_timestamp: i64,
+    #[serde(default)]
+    pub confirmation_depth: u16,
+    #[serde(default)]
+    pub solana_confirmed_slot: u64,
+    /// Signing time (unix seconds)
+    pub timestamp: i64,
+    #[serde(default)]
+    pub insurance: Option<Insurance>,
+    #[serde(default)]
+    pub relayer_claim: Option<RelayerClaim>,
+}
+
+impl Attestation {
+    /// The burn as this attestation states it (for `record`)
+    pub fn to_fixture(&self, name: String) -> BurnFixture {
+        BurnFixture {
+            name,
+            asset_id: self.asset_id,
+            burn_nonce: self.burn_nonce,
+            user: self.user.clone(),
+            amount: self.amount,
+            solana_burn_slot: self.solana_burn_slot,
+            solana_burn_timestamp: self.solana_burn_timestamp,
+            confirmation_depth: self.confirmation_depth,
+        }
+    }
+
+    /// Why this is not a valid attestation of `burn` by `validator` at `now` (empty = it is)
+    pub fn problems(
+        &self,
+        burn: &BurnFixture,
+        domain: &[u8],
+        validator_set_version: u64,
+        validator: &str,
+        now: i64,
+    ) -> Vec<String> {
+        let mut problems = Vec::new();
+        let mut expect = |field: &str, actual: String, expected: String| {
+            if actual != expected {
+                problems.push(format!("{}: got {}, expected {}", field, actual, expected));
+            }
+        };
+        expect("asset_id", self.asset_id.to_string(), burn.asset_id.to_string());
+        expect("burn_nonce", self.burn_nonce.to_string(), burn.burn_nonce.to_string());
+        expect("user", self.user.clone(), burn.user.clone());
+        expect("amount", self.amount.to_string(), burn.amount.to_string());
+        expect("validator_set_version", self.validator_set_version.to_string(), validator_set_version.to_string());
+        expect("validator_pubkey", self.validator_pubkey.clone(), validator.to_string());
+        expect("solana_burn_slot", self.solana_burn_slot.to_string(), burn.solana_burn_slot.to_string());
+        expect("solana_burn_timestamp", self.solana_burn_timestamp.to_string(), burn.solana_burn_timestamp.to_string());
+        expect("confirmation_depth", self.confirmation_depth.to_string(), burn.confirmation_depth.to_string());
+        expect("solana_confirmed_slot", self.solana_confirmed_slot.to_string(), burn.solana_confirmed_slot().to_string());
+
+        if (self.timestamp - now).abs() > MAX_CLOCK_SKEW_SECONDS {
+            problems.push(format!("timestamp {} is more than {}s from now ({})", self.timestamp, MAX_CLOCK_SKEW_SECONDS, now));
+        }
+        if self.timestamp < burn.solana_burn_timestamp {
+            problems.push(format!("timestamp {} is before the burn ({})", self.timestamp, burn.solana_burn_timestamp));
+        }
+
+        let (Ok(validator_key), Ok(user)) = (decode_pubkey(validator), decode_pubkey(&burn.user)) else {
+            problems.push("validator or fixture user is not a public key".into());
+            return problems;
+        };
+        // Signed over the fixture's values: a field mismatch above also fails here
+        let message = timed_attestation_message(
+            &attestation_message_v3(domain, burn.asset_id, validator_set_version, burn.burn_nonce, burn.amount, &user),
+            burn.solana_burn_slot,
+            burn.solana_burn_timestamp,
+            burn.confirmation_depth,
+            burn.solana_confirmed_slot(),
+            self.timestamp,
+        );
+        if !verify_signature(&validator_key, &message, &self.signature) {
+            problems.push("signature does not verify over the timed V3 message".into());
+        }
+
+        if let Some(insurance) = &self.insurance {
+            let message = insurance_message(domain, burn.asset_id, burn.burn_nonce, &user, insurance.covered_amount, insurance.premium);
+            if !verify_signature(&validator_key, &message, &insurance.signature) {
+                problems.push("insurance signature does not verify".into());
+            }
+        }
+        if let Some(claim) = &self.relayer_claim {
+            let message = relayer_claim_message(domain, burn.asset_id, burn.burn_nonce, &user, claim.relayer_fee);
+            if !verify_signature(&validator_key, &message, &claim.signature) {
+                problems.push("relayer claim signature does not verify".into());
+            }
+        }
+        problems
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    Skip,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CheckResult {
+    pub id: String,
+    pub description: String,
+    pub outcome: Outcome,
+    /// Failure reasons, or why the check was skipped
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub details: Vec<String>,
+}
+
+/// Compliance report written by `run`
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Report {
+    pub endpoint: String,
+    pub validator: Option<String>,
+    pub cluster: String,
+    pub kit_version: String,
+    pub started_at: i64,
+    pub passed: usize,
+    pub failed: usize,
+    pub skipped: usize,
+    /// No check failed
+    pub compliant: bool,
+    pub checks: Vec<CheckResult>,
+}
+
+impl Report {
+    pub fn new(endpoint: &str, cluster: &str, started_at: i64) -> Self {
+        Self {
+            endpoint: endpoint.to_string(),
+            validator: None,
+            cluster: cluster.to_string(),
+            kit_version: env!("CARGO_PKG_VERSION").to_string(),
+            started_at,
+            passed: 0,
+            failed: 0,
+            skipped: 0,
+            compliant: true,
+            checks: Vec::new(),
+        }
+    }
+
+    /// Record a check: passes when `problems` is empty
+    pub fn check(&mut self, id: &str, description: &str, problems: Vec<String>) -> Outcome {
+        let outcome = if problems.is_empty() { Outcome::Pass } else { Outcome::Fail };
+        self.push(id, description, outcome, problems)
+    }
+
+    pub fn skip(&mut self, id: &str, description: &str, reason: &str) -> Outcome {
+        self.push(id, description, Outcome::Skip, vec![reason.to_string()])
+    }
+
+    fn push(&mut self, id: &str, description: &str, outcome: Outcome, details: Vec<String>) -> Outcome {
+        match outcome {
+            Outcome::Pass => self.passed += 1,
+            Outcome::Fail => self.failed += 1,
+            Outcome::Skip => self.skipped += 1,
+        }
+        self.compliant = self.failed == 0;
+        self.checks.push(CheckResult {
+            id: id.to_string(),
+            description: description.to_string(),
+            outcome,
+            details,
+        });
+        outcome
+    }
+}
+
+/// Canonical message vectors, for implementations to check their encoding offline
+pub fn vectors() -> serde_json::Value {
+    let user = [9u8; 32];
+    let v3 = attestation_message_v3(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 1, 3, 1234, 5_000_000, &user);
+    json!([
+        {
+            "name": "attestation_v3",
+            "inputs": {
+                "domain": DEFAULT_DOMAIN_SEPARATOR,
+                "asset_id": 1,
+                "validator_set_version": 3,
+                "burn_nonce": 1234,
+                "amount": 5_000_000u64,
+                "user": bs58::encode(user).into_string(),
+            },
+            "message": hex::encode(v3),
+        },
+        {
+            "name": "timed_attestation_v3",
+            "inputs": {
+                "v3_message": hex::encode(v3),
+                "solana_burn_slot": 350_000_000u64,
+                "solana_burn_timestamp": 1_760_000_000i64,
+                "confirmation_depth": 0,
+                "solana_confirmed_slot": 350_000_032u64,
+                "attested_at": 1_760_000_020i64,
+            },
+            "message": hex::encode(timed_attestation_message(&v3, 350_000_000, 1_760_000_000, 0, 350_000_032, 1_760_000_020)),
+        },
+        {
+            "name": "insurance",
+            "inputs": {
+                "domain": DEFAULT_DOMAIN_SEPARATOR,
+                "asset_id": 1,
+                "burn_nonce": 1234,
+                "user": bs58::encode(user).into_string(),
+                "covered_amount": 5_000_000u64,
+                "premium": 25_000,
+            },
+            "message": hex::encode(insurance_message(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 1, 1234, &user, 5_000_000, 25_000)),
+        },
+        {
+            "name": "relayer_claim",
+            "inputs": {
+                "domain": DEFAULT_DOMAIN_SEPARATOR,
+                "asset_id": 1,
+                "burn_nonce": 1234,
+                "user": bs58::encode(user).into_string(),
+                "relayer_fee": 50_000,
+            },
+            "message": hex::encode(relayer_claim_message(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 1, 1234, &user, 50_000)),
+        },
+        {
+            "name": "response",
+            "inputs": { "request_nonce": "00ff", "body": "{\"ok\":true}" },
+            "message": hex::encode(response_digest("00ff", "{\"ok\":true}")),
+        },
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ed25519_dalek::{Keypair, SecretKey, Signer};
+
+    fn keypair(seed: u8) -> Keypair {
+        let secret = SecretKey::from_bytes(&[seed; 32]).unwrap();
+        Keypair { public: (&secret).into(), secret }
+    }
+
+    #[test]
+    fn test_message_vectors() {
+        let user = [9u8; 32];
+        let v3 = attestation_message_v3(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 1, 3, 1234, 5_000_000, &user);
+        assert_eq!(hex::encode(v3), "55f8eae715b9ef3f96b3a67c1de201e9dd2ecc0bc8c32a5e8d12dd4ff720f4a2");
+        assert_eq!(
+            hex::encode(timed_attestation_message(&v3, 350_000_000, 1_760_000_000, 0, 350_000_032, 1_760_000_020)),
+            "57ffe3a1ed0f2b371e7971de69a1cc8588b145768933842d4e756edb57b137a6"
+        );
+        assert_eq!(
+            hex::encode(insurance_message(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 1, 1234, &user, 5_000_000, 25_000)),
+            "3eecc0ef244867c5f724ec81ac64750dfd75b2f1d47845e6a61fdf1fd0f44f07"
+        );
+        assert_eq!(
+            bs58::encode(relayer_claim_message(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 1, 1234, &user, 50_000)).into_string(),
+            "58PVq8rEXAzRVtnXERmHApfJ2Q18Gcsz6U6Dt4pjdjPL"
+        );
+        assert_eq!(
+            hex::encode(response_digest("00ff", "{\"ok\":true}")),
+            "fb0dfe8485f74276352cd1cf3278843d7a6f930c2490e80a52c08383bc18e682"
+        );
+    }
+
+    fn signed_attestation(key: &Keypair, burn: &BurnFixture, timestamp: i64) -> Attestation {
+        let user = decode_pubkey(&burn.user).unwrap();
+        let message = timed_attestation_message(
+            &attestation_message_v3(DEFAULT_DOMAIN_SEPARATOR.as_bytes(), burn.asset_id, 3, burn.burn_nonce, burn.amount, &user),
+            burn.solana_burn_slot,
+            burn.solana_burn_timestamp,
+            burn.confirmation_depth,
+            burn.solana_confirmed_slot(),
+            timestamp,
+        );
+        Attestation {
+            asset_id: burn.asset_id,
+            burn_nonce: burn.burn_nonce,
+            user: burn.user.clone(),
+            amount: burn.amount,
+            validator_set_version: 3,
+            validator_pubkey: bs58::encode(key.public.as_bytes()).into_string(),
+            signature: key.sign(&message).to_bytes().to_vec(),
+            solana_burn_slot: burn.solana_burn_slot,
+            solana_burn_timestamp: burn.solana_burn_timestamp,
+            confirmation_depth: burn.confirmation_depth,
+            solana_confirmed_slot: burn.solana_confirmed_slot(),
+            timestamp,
+            insurance: None,
+            relayer_claim: None,
+        }
+    }
+
+    #[test]
+    fn test_attestation_problems() {
+        let key = keypair(7);
+        let validator = bs58::encode(key.public.as_bytes()).into_string();
+        let burn = BurnFixture {
+            name: "xencat".into(),
+            asset_id: 1,
+            burn_nonce: 1234,
+            user: bs58::encode([9u8; 32]).into_string(),
+            amount: 5_000_000,
+            solana_burn_slot: 350_000_000,
+            solana_burn_timestamp: 1_760_000_000,
+            confirmation_depth: 0,
+        };
+        let now = 1_760_000_030;
+
+        let attestation = signed_attestation(&key, &burn, 1_760_000_020);
+        assert!(attestation.problems(&burn, DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 3, &validator, now).is_empty());
+
+        // Signed for another domain or version
+        assert!(!attestation.problems(&burn, b"XENCAT_X1_BRIDGE_DEVNET_V1", 3, &validator, now).is_empty());
+        let forked = attestation_domain(DEFAULT_DOMAIN_SEPARATOR, Some(&bs58::encode([5u8; 32]).into_string())).unwrap();
+        assert!(!attestation.problems(&burn, &forked, 3, &validator, now).is_empty());
+        assert!(!attestation.problems(&burn, DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 4, &validator, now).is_empty());
+
+        // Wrong amount reported, and the signature no longer matches the fixture
+        let mut wrong = attestation.clone();
+        wrong.amount += 1;
+        assert_eq!(wrong.problems(&burn, DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 3, &validator, now).len(), 1);
+        let mut other_burn = burn.clone();
+        other_burn.amount += 1;
+        assert_eq!(wrong.problems(&other_burn, DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 3, &validator, now).len(), 1);
+
+        // Another validator's key, a stale clock
+        let other = bs58::encode(keypair(8).public.as_bytes()).into_string();
+        assert_eq!(attestation.problems(&burn, DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 3, &other, now).len(), 2);
+        assert_eq!(attestation.problems(&burn, DEFAULT_DOMAIN_SEPARATOR.as_bytes(), 3, &validator, now + 3600).len(), 1);
+    }
+
+    #[test]
+    fn test_response_problems() {
+        let key = keypair(7);
+        let validator = bs58::encode(key.public.as_bytes()).into_string();
+        let body = "{\"error\":\"User mismatch\"}";
+        let signature = bs58::encode(key.sign(&response_digest("abc", body)).to_bytes()).into_string();
+
+        assert!(response_problems(&validator, "abc", body, Some(&validator), Some(&signature)).is_empty());
+        // Replayed for another request, or for another body
+        assert_eq!(response_problems(&validator, "abd", body, Some(&validator), Some(&signature)).len(), 1);
+        assert_eq!(response_problems(&validator, "abc", "{}", Some(&validator), Some(&signature)).len(), 1);
+        assert_eq!(response_problems(&validator, "abc", body, None, None).len(), 1);
+    }
+
+    #[test]
+    fn test_report_counts() {
+        let mut report = Report::new("http://localhost:8080", "devnet", 0);
+        assert_eq!(report.check("health", "GET /health", vec![]), Outcome::Pass);
+        report.skip("batch-too-large", "POST /attest-burns over the limit", "max_batch_size not advertised");
+        assert!(report.compliant);
+        assert_eq!(report.check("wrong-user", "Wrong user is rejected", vec!["status 200".into()]), Outcome::Fail);
+        assert!(!report.compliant);
+        assert_eq!((report.passed, report.failed, report.skipped), (1, 1, 1));
+    }
+}
@@ -0,0 +1,440 @@
+//! Conformance test kit for validator attestation services
+//!
+//! ```shell
+//! # Record canonical burns from a reference validator (nonce:user:amount)
+//! cargo run -p xencat-attestation-conformance -- record \
+//!     --endpoint https://validator.example.com --validator-set-version 3 \
+//!     --burn 1234:<user>:5000000 --burn 1240:<user>:1000000
+//!
+//! # Replay them against the implementation under test
+//! cargo run -p xencat-attestation-conformance -- run --endpoint http://localhost:8080
+//!
+//! # Canonical message vectors, for checking an encoding offline
+//! cargo run -p xencat-attestation-conformance -- vectors
+//! ```
+//!
+//! `run` exits with code 2 when any check fails. The endpoint must watch
+//! the Solana cluster the fixtures were recorded on, and already treat the
+//! fixture burns as finalized.
+
+use clap::{Parser, Subcommand};
+use serde_json::{json, Value};
+use sha2::{Digest, Sha256};
+use std::cell::Cell;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::exit;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use xencat_attestation_conformance::*;
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Attestation service base URL
+    #[arg(long)]
+    endpoint: String,
+
+    /// Validator key to test on a multi-key service (default: the one /health reports)
+    #[arg(long)]
+    validator_key: Option<String>,
+
+    /// Per-request timeout
+    #[arg(long, default_value_t = 30)]
+    timeout_seconds: u64,
+
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Subcommand, Debug)]
+enum Cmd {
+    /// Replay the fixtures and write a compliance report
+    Run {
+        #[arg(long, default_value = FIXTURES_FILE)]
+        fixtures: PathBuf,
+        #[arg(long, default_value = REPORT_FILE)]
+        report: PathBuf,
+    },
+    /// Record fixtures from a reference endpoint's verified attestations
+    Record {
+        /// nonce:user:amount of a finalized burn (repeatable)
+        #[arg(long = "burn", required = true)]
+        burns: Vec<String>,
+        #[arg(long)]
+        validator_set_version: u64,
+        #[arg(long, default_value = "mainnet-beta")]
+        cluster: String,
+        #[arg(long, default_value = DEFAULT_DOMAIN_SEPARATOR)]
+        domain: String,
+        /// X1 genesis hash (base58) if the deployment has bound one
+        #[arg(long)]
+        x1_genesis_hash: Option<String>,
+        #[arg(long, default_value = FIXTURES_FILE)]
+        out: PathBuf,
+    },
+    /// Print the canonical message vectors
+    Vectors,
+}
+
+fn main() {
+    let args = Args::parse();
+    let result = match &args.command {
+        Cmd::Vectors => {
+            println!("{}", serde_json::to_string_pretty(&vectors()).unwrap());
+            Ok(())
+        }
+        Cmd::Record { burns, validator_set_version, cluster, domain, x1_genesis_hash, out } => {
+            record(&Endpoint::new(&args), burns, *validator_set_version, cluster, domain, x1_genesis_hash.as_deref(), out)
+        }
+        Cmd::Run { fixtures, report } => run(&Endpoint::new(&args), fixtures, report),
+    };
+    if let Err(error) = result {
+        eprintln!("❌ {}", error);
+        exit(1);
+    }
+}
+
+fn now() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_secs() as i64
+}
+
+struct Response {
+    status: u16,
+    body: String,
+    nonce: String,
+    pubkey: Option<String>,
+    signature: Option<String>,
+}
+
+impl Response {
+    fn json(&self) -> Option<Value> {
+        serde_json::from_str(&self.body).ok()
+    }
+
+    /// Status (must be one of `expected`) and signature problems
+    fn problems(&self, validator: &str, expected: &[u16]) -> Vec<String> {
+        let mut problems = Vec::new();
+        if !expected.contains(&self.status) {
+            problems.push(format!("status {}, expected {:?}: {}", self.status, expected, self.body));
+        }
+        problems.extend(response_problems(validator, &self.nonce, &self.body, self.pubkey.as_deref(), self.signature.as_deref()));
+        problems
+    }
+}
+
+struct Endpoint {
+    base: String,
+    key: Option<String>,
+    agent: ureq::Agent,
+    requests: Cell<u64>,
+}
+
+impl Endpoint {
+    fn new(args: &Args) -> Self {
+        Self {
+            base: args.endpoint.trim_end_matches('/').to_string(),
+            key: args.validator_key.clone(),
+            agent: ureq::AgentBuilder::new().timeout(Duration::from_secs(args.timeout_seconds)).build(),
+            requests: Cell::new(0),
+        }
+    }
+
+    /// Fresh per request, so a replayed response cannot verify
+    fn request_nonce(&self) -> String {
+        self.requests.set(self.requests.get() + 1);
+        let nanos = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_nanos();
+        let digest = Sha256::new()
+            .chain_update(nanos.to_le_bytes())
+            .chain_update(self.requests.get().to_le_bytes())
+            .finalize();
+        hex::encode(&digest[..16])
+    }
+
+    fn get(&self, path: &str) -> Result<Response, String> {
+        self.send(self.agent.get(&format!("{}{}", self.base, path)), None, self.key.as_deref())
+    }
+
+    fn post(&self, path: &str, body: &Value) -> Result<Response, String> {
+        self.post_as(path, body, self.key.as_deref())
+    }
+
+    fn post_as(&self, path: &str, body: &Value, key: Option<&str>) -> Result<Response, String> {
+        let request = self.agent.post(&format!("{}{}", self.base, path)).set("Content-Type", "application/json");
+        self.send(request, Some(body.to_string()), key)
+    }
+
+    fn send(&self, request: ureq::Request, body: Option<String>, key: Option<&str>) -> Result<Response, String> {
+        let nonce = self.request_nonce();
+        let mut request = request.set(HEADER_REQUEST_NONCE, &nonce);
+        if let Some(key) = key {
+            request = request.set(HEADER_VALIDATOR_KEY, key);
+        }
+        let result = match body {
+            Some(body) => request.send_string(&body),
+            None => request.call(),
+        };
+        let response = match result {
+            Ok(response) | Err(ureq::Error::Status(_, response)) => response,
+            Err(error) => return Err(format!("{}: {}", self.base, error)),
+        };
+        Ok(Response {
+            status: response.status(),
+            pubkey: response.header(HEADER_VALIDATOR_PUBKEY).map(str::to_string),
+            signature: response.header(HEADER_VALIDATOR_SIGNATURE).map(str::to_string),
+            body: response.into_string().map_err(|e| format!("{}: {}", self.base, e))?,
+            nonce,
+        })
+    }
+}
+
+fn burn_request(burn: &BurnFixture, validator_set_version: u64) -> Value {
+    json!({
+        "burn_nonce": burn.burn_nonce,
+        "user": burn.user,
+        "expected_amount": burn.amount,
+        "validator_set_version": validator_set_version,
+    })
+}
+
+/// A 200 response's attestation checked against its fixture
+fn attestation_problems(attestation: Option<&Value>, burn: &BurnFixture, fixtures: &FixtureSet, validator: &str) -> Vec<String> {
+    match attestation.cloned().map(serde_json::from_value::<Attestation>) {
+        Some(Ok(attestation)) => match fixtures.domain() {
+            Ok(domain) => attestation.problems(burn, &domain, fixtures.validator_set_version, validator, now()),
+            Err(error) => vec![error],
+        },
+        Some(Err(error)) => vec![format!("malformed attestation: {}", error)],
+        None => vec!["no attestation in the response".into()],
+    }
+}
+
+/// A rejection must be signed, carry an `error` string and no attestation
+fn rejection_problems(response: &Response, validator: &str, expected: &[u16]) -> Vec<String> {
+    let mut problems = response.problems(validator, expected);
+    match response.json() {
+        Some(body) if body.get("error").is_some_and(Value::is_string) => {
+            if body.get("signature").is_some() {
+                problems.push("rejection carries a signature field".into());
+            }
+        }
+        _ => problems.push(format!("rejection body has no `error` string: {}", response.body)),
+    }
+    problems
+}
+
+fn run(endpoint: &Endpoint, fixtures_path: &Path, report_path: &Path) -> Result<(), String> {
+    let fixtures: FixtureSet = serde_json::from_str(&read(fixtures_path)?)
+        .map_err(|e| format!("{}: {}", fixtures_path.display(), e))?;
+    let Some(first) = fixtures.burns.first().cloned() else {
+        return Err(format!("{} has no burns", fixtures_path.display()));
+    };
+    let version = fixtures.validator_set_version;
+    let mut report = Report::new(&endpoint.base, &fixtures.cluster, now());
+
+    println!("🔎 Conformance run against {} ({} fixtures, {})", endpoint.base, fixtures.burns.len(), fixtures.cluster);
+
+    // Health: who answers, and the advertised batch limit
+    let health = endpoint.get("/health")?;
+    let health_json = health.json().unwrap_or(Value::Null);
+    let reported = health_json.get("validator").and_then(Value::as_str).map(str::to_string);
+    let mut problems = Vec::new();
+    if health.status != 200 {
+        problems.push(format!("status {}", health.status));
+    }
+    match &reported {
+        Some(key) if decode_pubkey(key).is_err() => problems.push(format!("`validator` is not a public key: {}", key)),
+        None => problems.push("no `validator` field".into()),
+        _ => {}
+    }
+    report.check("health", "GET /health answers with the validator pubkey", problems);
+
+    let Some(validator) = endpoint.key.clone().or(reported) else {
+        return Err("cannot tell which validator key to test: pass --validator-key".into());
+    };
+    report.validator = Some(validator.clone());
+    let max_batch_size = health_json.get("max_batch_size").and_then(Value::as_u64);
+
+    // The system program id is never a validator key
+    let response = endpoint.post_as("/attest-burn", &burn_request(&first, version), Some("11111111111111111111111111111111"))?;
+    let problems = match response.status {
+        404 => vec![],
+        status => vec![format!("status {}, expected 404: {}", status, response.body)],
+    };
+    report.check("unknown-validator-key", "A request for a key the service does not hold is refused (404)", problems);
+
+    for burn in &fixtures.burns {
+        let response = endpoint.post("/attest-burn", &burn_request(burn, version))?;
+        let mut problems = response.problems(&validator, &[200]);
+        if response.status == 200 {
+            problems.extend(attestation_problems(response.json().as_ref(), burn, &fixtures, &validator));
+        }
+        report.check(
+            &format!("attest-burn/{}", burn.name),
+            "Finalized burn is attested: signed response, fields and signature match the fixture",
+            problems,
+        );
+    }
+
+    let mut wrong_user = burn_request(&first, version);
+    wrong_user["user"] = json!(bs58::encode([1u8; 32]).into_string());
+    let response = endpoint.post("/attest-burn", &wrong_user)?;
+    report.check("wrong-user", "Burn requested for another user is rejected (400)", rejection_problems(&response, &validator, &[400]));
+
+    let mut wrong_amount = burn_request(&first, version);
+    wrong_amount["expected_amount"] = json!(first.amount + 1);
+    let response = endpoint.post("/attest-burn", &wrong_amount)?;
+    report.check("wrong-amount", "Burn requested with another amount is rejected (400)", rejection_problems(&response, &validator, &[400]));
+
+    let response = endpoint.post("/attest-burn", &json!({}))?;
+    report.check("missing-fields", "Request without the required fields is rejected (400)", rejection_problems(&response, &validator, &[400]));
+
+    // Far beyond any real burn nonce, and still exact as a JSON number
+    let unknown_nonce = fixtures.burns.iter().map(|b| b.burn_nonce).max().unwrap_or(0) + 1_000_000_000_000;
+    let unknown = BurnFixture { name: "unknown".into(), burn_nonce: unknown_nonce, ..first.clone() };
+    let response = endpoint.post("/attest-burn", &burn_request(&unknown, version))?;
+    report.check("unknown-burn", "Burn that does not exist is rejected (400 or 404)", rejection_problems(&response, &validator, &[400, 404]));
+
+    // Batch: a valid burn and a rejected one, answered per entry in order
+    let rejected = match fixtures.burns.get(1) {
+        Some(second) => {
+            let mut request = burn_request(second, version);
+            request["expected_amount"] = json!(second.amount + 1);
+            request
+        }
+        None => burn_request(&unknown, version),
+    };
+    let response = endpoint.post("/attest-burns", &json!({ "burns": [burn_request(&first, version), rejected] }))?;
+    let mut problems = response.problems(&validator, &[200]);
+    if response.status == 200 {
+        let results = response.json().and_then(|body| body.get("results").and_then(Value::as_array).cloned());
+        match results.as_deref() {
+            Some([accepted, refused]) => {
+                if accepted.get("status").and_then(Value::as_u64) != Some(200) {
+                    problems.push(format!("results[0] status is not 200: {}", accepted));
+                }
+                problems.extend(attestation_problems(accepted.get("attestation"), &first, &fixtures, &validator));
+                match refused.get("status").and_then(Value::as_u64) {
+                    Some(400..=499) if refused.get("attestation").is_none() => {}
+                    _ => problems.push(format!("results[1] is not a rejection: {}", refused)),
+                }
+            }
+            _ => problems.push(format!("expected 2 results in request order: {}", response.body)),
+        }
+    }
+    report.check("batch", "POST /attest-burns answers each burn, in order, in one signed envelope", problems);
+
+    let response = endpoint.post("/attest-burns", &json!({ "burns": [burn_request(&first, version), burn_request(&first, version)] }))?;
+    report.check("batch-duplicates", "Batch with a duplicate burn_nonce is rejected (400)", rejection_problems(&response, &validator, &[400]));
+
+    let response = endpoint.post("/attest-burns", &json!({ "burns": [] }))?;
+    report.check("batch-empty", "Empty batch is rejected (400)", rejection_problems(&response, &validator, &[400]));
+
+    match max_batch_size {
+        Some(limit) => {
+            let burns: Vec<Value> = (0..=limit)
+                .map(|i| burn_request(&BurnFixture { burn_nonce: unknown_nonce + 1 + i, ..unknown.clone() }, version))
+                .collect();
+            let response = endpoint.post("/attest-burns", &json!({ "burns": burns }))?;
+            report.check("batch-too-large", "Batch over max_batch_size is rejected (413)", rejection_problems(&response, &validator, &[413]));
+        }
+        None => {
+            report.skip("batch-too-large", "Batch over max_batch_size is rejected (413)", "/health does not advertise max_batch_size");
+        }
+    }
+
+    for check in &report.checks {
+        let icon = match check.outcome {
+            Outcome::Pass => "✅",
+            Outcome::Fail => "❌",
+            Outcome::Skip => "⏭️ ",
+        };
+        println!("{} {:<28} {}", icon, check.id, check.description);
+        for detail in &check.details {
+            println!("      {}", detail);
+        }
+    }
+    println!(
+        "\n{} passed, {} failed, {} skipped: {}",
+        report.passed,
+        report.failed,
+        report.skipped,
+        if report.compliant { "COMPLIANT" } else { "NOT COMPLIANT" }
+    );
+
+    fs::write(report_path, serde_json::to_string_pretty(&report).unwrap())
+        .map_err(|e| format!("{}: {}", report_path.display(), e))?;
+    println!("📝 Report written to {}", report_path.display());
+
+    if !report.compliant {
+        exit(2);
+    }
+    Ok(())
+}
+
+fn record(
+    endpoint: &Endpoint,
+    burns: &[String],
+    validator_set_version: u64,
+    cluster: &str,
+    domain: &str,
+    x1_genesis_hash: Option<&str>,
+    out: &Path,
+) -> Result<(), String> {
+    let validator = match &endpoint.key {
+        Some(key) => key.clone(),
+        None => endpoint.get("/health")?
+            .json()
+            .and_then(|health| health.get("validator").and_then(Value::as_str).map(str::to_string))
+            .ok_or("reference /health reports no validator: pass --validator-key")?,
+    };
+
+    let mut fixtures = FixtureSet {
+        cluster: cluster.to_string(),
+        domain_separator: domain.to_string(),
+        x1_genesis_hash: x1_genesis_hash.map(str::to_string),
+        validator_set_version,
+        recorded_from: Some(endpoint.base.clone()),
+        burns: Vec::new(),
+    };
+    let domain = fixtures.domain()?;
+    for spec in burns {
+        let parts: Vec<&str> = spec.split(':').collect();
+        let [nonce, user, amount] = parts[..] else {
+            return Err(format!("--burn {}: expected nonce:user:amount", spec));
+        };
+        let request = json!({
+            "burn_nonce": nonce.parse::<u64>().map_err(|e| format!("--burn {}: {}", spec, e))?,
+            "user": user,
+            "expected_amount": amount.parse::<u64>().map_err(|e| format!("--burn {}: {}", spec, e))?,
+            "validator_set_version": validator_set_version,
+        });
+        let response = endpoint.post("/attest-burn", &request)?;
+        let problems = response.problems(&validator, &[200]);
+        if !problems.is_empty() {
+            return Err(format!("burn {}: {}", nonce, problems.join("; ")));
+        }
+        let attestation: Attestation = serde_json::from_str(&response.body)
+            .map_err(|e| format!("burn {}: malformed attestation: {}", nonce, e))?;
+
+        // Only a fixture the reference itself signed correctly is canonical
+        let burn = attestation.to_fixture(format!("asset{}-{}", attestation.asset_id, nonce));
+        let problems = attestation.problems(&burn, &domain, validator_set_version, &validator, now());
+        if !problems.is_empty() {
+            return Err(format!("burn {}: reference attestation is invalid: {}", nonce, problems.join("; ")));
+        }
+        if burn.user != user || burn.amount.to_string() != amount {
+            return Err(format!("burn {}: reference attested {}:{} instead", nonce, burn.user, burn.amount));
+        }
+        println!("✅ Recorded burn {} (asset {}, slot {})", nonce, burn.asset_id, burn.solana_burn_slot);
+        fixtures.burns.push(burn);
+    }
+
+    fs::write(out, serde_json::to_string_pretty(&fixtures).unwrap())
+        .map_err(|e| format!("{}: {}", out.display(), e))?;
+    println!("📝 {} fixtures written to {}", fixtures.burns.len(), out.display());
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+#[error_code]
+pub enum MintError {
+    #[msg("Asset is not registered to this mint program in the light client")]
+    AssetNotMintable,
+
+    #[msg("Mint authority must be the asset's mint_state PDA")]
+    InvalidMintAuthority,
+
+    #[msg("Mint must have zero supply when the asset is initialized")]
+    MintNotEmpty,
+
+    #[msg("Mint has a Token-2022 extension the bridge cannot mint safely (permanent delegate or close authority)")]
+    UnsupportedMintExtension,
+
+    #[msg("Verified burn belongs to another user")]
+    UserMismatch,
+
+    #[msg("Arithmetic overflow in fee calculation")]
+    Overflow,
+
+    #[msg("Missing validator account in remaining_accounts")]
+    MissingValidatorAccount,
+
+    #[msg("Invalid validator account - does not match validator set")]
+    InvalidValidatorAccount,
+
+    #[msg("Validator account must be writable to receive fees")]
+    ValidatorAccountNotWritable,
+
+    #[msg("Unauthorized: caller is not the asset's authority")]
+    Unauthorized,
+
+    #[msg("Minting is paused by validator governance")]
+    MintPaused,
+
+    #[msg("Minting is not paused")]
+    MintNotPaused,
+
+    #[msg("Mint pause account is not owned by this program")]
+    InvalidMintPause,
+
+    #[msg("Mint rate limit reached for this window")]
+    RateLimitExceeded,
+
+    #[msg("Rate limit account is not owned by this program")]
+    InvalidRateLimit,
+
+    #[msg("Invalid rate limit: window must be at least one hour")]
+    InvalidRateLimitWindow,
+
+    #[msg("Mint above the co-sign threshold needs the policy co-signer's signature")]
+    CoSignerRequired,
+
+    #[msg("Co-sign policy account is not owned by this program")]
+    InvalidCoSignPolicy,
+
+    #[msg("Fee pool account is not owned by this program")]
+    InvalidFeePool,
+
+    #[msg("Fee pool tracks its maximum of validators; withdraw_fees for a departed validator first")]
+    FeePoolFull,
+
+    #[msg("Validator has no pooled fees")]
+    NoPooledFees,
+
+    #[msg("Fee config account is not owned by this program, or its schedule is invalid (fee_bps > 10000 or min_fee > max_fee)")]
+    InvalidFeeConfig,
+}
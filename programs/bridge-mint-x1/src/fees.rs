@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::errors::MintError;
+use crate::state::{FeeConfig, FeePool, MintState};
+use solana_light_client_x1::{ValidatorLatencyStats, X1ValidatorSet};
+
+/// Fee owed to each member of `validator_set`, in set order
+///
+/// The total stays `fee_per_validator` per validator; the split follows
+/// attestation participation, or is equal while the light client's latency
+/// stats are not initialized.
+pub fn validator_fee_shares(
+    fee_per_validator: u64,
+    validator_set: &X1ValidatorSet,
+    latency_stats: &AccountInfo,
+) -> Result<Vec<u64>> {
+    let total_fee = fee_per_validator
+        .checked_mul(validator_set.validators.len() as u64)
+        .ok_or(MintError::Overflow)?;
+    ValidatorLatencyStats::fee_shares_of(latency_stats, total_fee, &validator_set.validators)
+}
+
+/// Fee each validator is owed for minting a burn of `amount`
+///
+/// From the asset's FeeConfig once it exists, else the fixed
+/// MintState.fee_per_validator.
+pub fn validator_fee_for(mint_state: &MintState, fee_config: &AccountInfo, amount: u64) -> Result<u64> {
+    Ok(match FeeConfig::load(fee_config)? {
+        Some(config) => config.fee_for(amount),
+        None => mint_state.fee_per_validator,
+    })
+}
+
+/// Pay every validator its share of one mint's fee, in set order
+///
+/// With an initialized FeePool the whole fee goes to the pool in one
+/// transfer and is credited there (withdraw_fees pays it out later).
+/// Without one, `remaining_accounts[i]` must be validator i's key.
+pub fn distribute_validator_fees<'info>(
+    payer: &AccountInfo<'info>,
+    fee_pool: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    validators: &[Pubkey],
+    shares: Vec<u64>,
+) -> Result<()> {
+    if let Some(mut pool) = FeePool::load(fee_pool)? {
+        let total: u64 = shares.iter().try_fold(0u64, |sum, share| sum.checked_add(*share)).ok_or(MintError::Overflow)?;
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(payer.key, fee_pool.key, total),
+            &[payer.clone(), fee_pool.clone()],
+        )?;
+        for (validator, share) in validators.iter().zip(shares) {
+            pool.credit(validator, share)?;
+        }
+        pool.try_serialize(&mut &mut fee_pool.try_borrow_mut_data()?[..])?;
+        msg!("✓ {} lamports of fees accrued to the fee pool", total);
+        return Ok(());
+    }
+
+    for (i, (validator, share)) in validators.iter().zip(shares).enumerate() {
+        let recipient = remaining_accounts.get(i).ok_or(MintError::MissingValidatorAccount)?;
+        require_keys_eq!(recipient.key(), *validator, MintError::InvalidValidatorAccount);
+        require!(recipient.is_writable, MintError::ValidatorAccountNotWritable);
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(payer.key, recipient.key, share),
+            &[payer.clone(), recipient.clone()],
+        )?;
+    }
+    Ok(())
+}
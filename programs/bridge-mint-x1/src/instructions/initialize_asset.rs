@@ -0,0 +1,146 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::program_option::COption;
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::Mint as SplMint,
+};
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{
+    ID as LIGHT_CLIENT_ID, X1ChainId, X1ValidatorSet,
+    governance::{create_governance_message, verify_validator_set_approvals},
+    instructions::ValidatorUpdateSignature,
+    state::AssetRegistry,
+};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeAsset<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Light client asset list (not initialized = built-in assets, none of them ours)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve_active
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintState::INIT_SPACE,
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    /// The asset's X1 mint (SPL Token or Token-2022, authority already set to mint_state)
+    #[account(mint::token_program = token_program)]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeAssetParams {
+    /// Manages the asset's co-sign policy, fee schedule and rate limit creation
+    pub authority: Pubkey,
+
+    /// Signatures from current validators approving this mint for the asset
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Token-2022 extensions that let someone other than this program move,
+/// destroy or re-create bridged tokens (transfer hooks, fees etc. are fine)
+const REJECTED_EXTENSIONS: [ExtensionType; 2] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::MintCloseAuthority,
+];
+
+/// Bind `asset_id` to its X1 mint (validator governance)
+///
+/// SECURITY:
+/// - The light client must list this program as the asset's active mint
+///   program, so only registered assets get a MintState here
+/// - Validators approve the exact mint and authority: whoever submits
+///   first cannot bind a mint or authority of their own choosing
+/// - The mint starts empty with mint_state as its mint authority, so every
+///   token in circulation was minted from a verified burn
+/// - No nonce: the approval can only ever create this one account
+pub fn handler(ctx: Context<InitializeAsset>, asset_id: u8, params: InitializeAssetParams) -> Result<()> {
+    let mint_program = AssetRegistry::resolve_active(&ctx.accounts.asset_registry, asset_id)?;
+    require_keys_eq!(mint_program, crate::ID, MintError::AssetNotMintable);
+
+    let mint = &ctx.accounts.mint;
+    require!(
+        mint.mint_authority == COption::Some(ctx.accounts.mint_state.key()),
+        MintError::InvalidMintAuthority
+    );
+    require!(mint.supply == 0, MintError::MintNotEmpty);
+    check_mint_extensions(&mint.to_account_info())?;
+
+    let message = create_initialize_asset_message(
+        ctx.accounts.validator_set.version,
+        asset_id,
+        &mint.key(),
+        &params.authority,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, &ctx.accounts.validator_set, &message)?;
+
+    let state = &mut ctx.accounts.mint_state;
+    state.asset_id = asset_id;
+    state.mint = mint.key();
+    state.authority = params.authority;
+    state.fee_per_validator = MintState::DEFAULT_FEE_PER_VALIDATOR;
+    state.processed_burns_count = 0;
+    state.total_minted = 0;
+    state.bump = ctx.bumps.mint_state;
+
+    msg!("✅ Asset {} bound to mint {}", asset_id, state.mint);
+
+    Ok(())
+}
+
+/// Reject Token-2022 mints carrying a REJECTED_EXTENSIONS entry (legacy mints have none)
+fn check_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    let data = mint.try_borrow_data()?;
+    let extensions = StateWithExtensions::<SplMint>::unpack(&data)?.get_extension_types()?;
+    require!(
+        !extensions.iter().any(|extension| REJECTED_EXTENSIONS.contains(extension)),
+        MintError::UnsupportedMintExtension
+    );
+    Ok(())
+}
+
+/// Create deterministic message for binding an asset to its mint
+///
+/// Format: hash(INIT_ASSET_MINT || version || asset_id || mint || mint program || authority)
+pub(crate) fn create_initialize_asset_message(
+    validator_set_version: u64,
+    asset_id: u8,
+    mint: &Pubkey,
+    authority: &Pubkey,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(97);
+    payload.push(asset_id);
+    payload.extend_from_slice(mint.as_ref());
+    payload.extend_from_slice(crate::ID.as_ref());
+    payload.extend_from_slice(authority.as_ref());
+
+    create_governance_message(b"INIT_ASSET_MINT", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeCoSignPolicy<'info> {
+    #[account(
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CoSignPolicy::INIT_SPACE,
+        seeds = [b"cosign_policy", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub cosign_policy: Account<'info, CoSignPolicy>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the asset's co-sign policy (enabled): mints above `threshold` need `cosigner`
+pub fn handler(ctx: Context<InitializeCoSignPolicy>, asset_id: u8, cosigner: Pubkey, threshold: u64) -> Result<()> {
+    let policy = &mut ctx.accounts.cosign_policy;
+    policy.enabled = true;
+    policy.cosigner = cosigner;
+    policy.threshold = threshold;
+    policy.bump = ctx.bumps.cosign_policy;
+
+    msg!("✓ Co-sign policy of asset {} initialized: mints above {} need {}", asset_id, threshold, cosigner);
+
+    Ok(())
+}
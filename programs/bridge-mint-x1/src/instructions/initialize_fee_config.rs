@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeFeeConfig<'info> {
+    #[account(
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + FeeConfig::INIT_SPACE,
+        seeds = [b"fee_config", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the asset's fee schedule; from now on it replaces fee_per_validator
+pub fn handler(
+    ctx: Context<InitializeFeeConfig>,
+    asset_id: u8,
+    flat_fee: u64,
+    fee_bps: u16,
+    min_fee: u64,
+    max_fee: u64,
+) -> Result<()> {
+    let fee_config = &mut ctx.accounts.fee_config;
+    fee_config.flat_fee = flat_fee;
+    fee_config.fee_bps = fee_bps;
+    fee_config.min_fee = min_fee;
+    fee_config.max_fee = max_fee;
+    fee_config.bump = ctx.bumps.fee_config;
+    fee_config.validate()?;
+
+    msg!(
+        "✓ Fee config of asset {} initialized: {} + {} bps of amount per validator, within [{}, {}] lamports",
+        asset_id, flat_fee, fee_bps, min_fee, max_fee
+    );
+
+    Ok(())
+}
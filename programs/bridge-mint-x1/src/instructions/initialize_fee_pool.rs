@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeFeePool<'info> {
+    /// The asset must be served here
+    #[account(
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeePool::INIT_SPACE,
+        seeds = [b"fee_pool", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub fee_pool: Account<'info, FeePool>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the asset's fee pool (run once, permissionless)
+///
+/// From then on every mint of the asset accrues validator fees here instead
+/// of paying the accounts in remaining_accounts. Each validator still
+/// receives exactly its share, through withdraw_fees.
+pub fn handler(ctx: Context<InitializeFeePool>, asset_id: u8) -> Result<()> {
+    let fee_pool = &mut ctx.accounts.fee_pool;
+    fee_pool.balances = Vec::new();
+    fee_pool.total_accrued = 0;
+    fee_pool.total_withdrawn = 0;
+    fee_pool.bump = ctx.bumps.fee_pool;
+
+    msg!("✓ Fee pool of asset {} created: mint fees now accrue here", asset_id);
+
+    Ok(())
+}
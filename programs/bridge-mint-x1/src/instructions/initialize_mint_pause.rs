@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeMintPause<'info> {
+    /// The asset must be served here
+    #[account(
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintPause::INIT_SPACE,
+        seeds = [b"mint_pause", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub mint_pause: Account<'info, MintPause>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the asset's mint pause switch (run once, permissionless)
+///
+/// Starts unpaused, same as before the account existed.
+pub fn handler(ctx: Context<InitializeMintPause>, asset_id: u8) -> Result<()> {
+    let mint_pause = &mut ctx.accounts.mint_pause;
+    mint_pause.paused = false;
+    mint_pause.updated_slot = Clock::get()?.slot;
+    mint_pause.nonce = 0;
+    mint_pause.bump = ctx.bumps.mint_pause;
+
+    msg!("✓ Mint pause of asset {} created (not paused)", asset_id);
+
+    Ok(())
+}
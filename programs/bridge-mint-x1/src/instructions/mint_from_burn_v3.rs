@@ -0,0 +1,288 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{distribute_validator_fees, validator_fee_for, validator_fee_shares};
+use solana_light_client_x1::compute_report::ComputeMeter;
+use solana_light_client_x1::program::SolanaLightClientX1;
+use solana_light_client_x1::{consumer, invariants};
+use solana_light_client_x1::{
+    ID as LIGHT_CLIENT_ID, X1ValidatorSet, RelayerClaim, FeatureGate, MintAcknowledgement,
+    state::{gates, AssetRegistry},
+};
+
+/// Asset-aware mint instruction (V3) for any asset served by this program
+///
+/// Key security properties:
+/// - The MintState PDA, and so the mint, is selected by asset_id
+/// - The VerifiedBurnV3 must be the one of (asset_id, user, burn_nonce)
+///   (consumer::assert_verified_burn), so one asset's proof never mints another
+/// - The replay PDA is created here and the proof is flagged processed in
+///   the light client in the same instruction (consumer::mark_processed)
+/// - The asset's pause, co-sign policy, rate limit and fee schedule apply
+///   as in the per-asset programs, each kept per asset_id
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct MintFromBurnV3<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    /// The asset's token mint on X1
+    #[account(
+        mut,
+        address = mint_state.mint,
+        mint::token_program = token_program
+    )]
+    pub mint: InterfaceAccount<'info, Mint>,
+
+    /// Processed burn tracker V3 (asset-aware, prevents replay attacks)
+    /// Seeds: ["processed_burn_v3", asset_id, nonce, user]
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ProcessedBurnV3::INIT_SPACE,
+        seeds = [
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    /// User's token account
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+        constraint = user_token_account.owner == user.key() @ MintError::UserMismatch
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Must be the burn's user (bound into the VerifiedBurnV3 address)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Current validator set (fee recipients)
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// Verified burn PDA V3 (from light client, created in TX1)
+    /// CHECK: Address, owner and contents checked by consumer::assert_verified_burn
+    #[account(mut)]
+    pub verified_burn: UncheckedAccount<'info>,
+
+    /// Relayer claim of the burn (initialized = only mint_for_recipient mints it)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [b"relayer_claim", asset_id.to_le_bytes().as_ref(), user.key().as_ref(), burn_nonce.to_le_bytes().as_ref()],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    /// The asset's emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause", asset_id.to_le_bytes().as_ref()], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// The asset's large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy", asset_id.to_le_bytes().as_ref()], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// The asset's minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit", asset_id.to_le_bytes().as_ref()], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// The asset's validator fee accrual (not initialized = fees paid via remaining_accounts)
+    /// CHECK: Address pinned by seeds; read and credited by fees::distribute_validator_fees
+    #[account(mut, seeds = [b"fee_pool", asset_id.to_le_bytes().as_ref()], bump)]
+    pub fee_pool: UncheckedAccount<'info>,
+
+    /// The asset's amount-based fee schedule (not initialized = fee_per_validator on every burn)
+    /// CHECK: Address pinned by seeds; read by fees::validator_fee_for
+    #[account(seeds = [b"fee_config", asset_id.to_le_bytes().as_ref()], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; our registration)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry and mark_burn_processed
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub light_client_program: Program<'info, SolanaLightClientX1>,
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mint a verified burn of `asset_id` to its user
+///
+/// Flow:
+/// 1. Check kill switches, the asset's pause and freeze, and the relayer claim
+/// 2. Check the VerifiedBurnV3 of (asset_id, user, burn_nonce) is unprocessed
+/// 3. Apply the co-sign policy and rate limit to the amount
+/// 4. Mint the verified amount (replay PDA created by init)
+/// 5. Flag the proof processed in the light client (CPI)
+/// 6. Accrue the validator fees to the fee pool, or pay them directly
+///    (remaining_accounts: validator keys in set order, then the co-signer)
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintFromBurnV3<'info>>,
+    asset_id: u8,
+    burn_nonce: u64,
+) -> Result<()> {
+    let meter = ComputeMeter::start();
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_V3)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
+    let user = ctx.accounts.user.key();
+    let verified = consumer::assert_verified_burn(&ctx.accounts.verified_burn, asset_id, &user, burn_nonce)?;
+    let amount = verified.amount;
+
+    msg!("Minting burn {} of asset {}: {} to {}", burn_nonce, asset_id, amount, user);
+
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.mint.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.mint_state.to_account_info(),
+            },
+            &[&[
+                b"mint_state",
+                asset_id.to_le_bytes().as_ref(),
+                &[ctx.accounts.mint_state.bump],
+            ]],
+        ),
+        amount,
+    )?;
+
+    let processed = &mut ctx.accounts.processed_burn;
+    processed.asset_id = asset_id;
+    processed.nonce = burn_nonce;
+    processed.user = user;
+    processed.amount = amount;
+    processed.processed_at = Clock::get()?.unix_timestamp;
+    invariants::debug_check(invariants::processed_implies_verified(&processed.record(), Some(&verified), false));
+
+    consumer::mark_processed(
+        ctx.accounts.light_client_program.to_account_info(),
+        ctx.accounts.verified_burn.to_account_info(),
+        ctx.accounts.processed_burn.to_account_info(),
+        ctx.accounts.asset_registry.to_account_info(),
+        asset_id,
+        burn_nonce,
+        user,
+    )?;
+
+    // Weighted by each validator's attestation participation
+    let validator_set = &ctx.accounts.validator_set;
+    let fee_per_validator = validator_fee_for(&ctx.accounts.mint_state, &ctx.accounts.fee_config, amount)?;
+    if fee_per_validator > 0 {
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+        distribute_validator_fees(
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.fee_pool,
+            ctx.remaining_accounts,
+            &validator_set.validators,
+            shares,
+        )?;
+        msg!("✓ {} lamports of fees per validator", fee_per_validator);
+    }
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(1);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(amount);
+
+    emit!(MintedFromBurnV3 { asset_id, nonce: burn_nonce, user, amount });
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.user_token_account.key())?;
+
+    report_compute_units(&meter, crate::instruction::MintFromBurnV3::DISCRIMINATOR, 1);
+
+    Ok(())
+}
+
+/// Event emitted when tokens are minted from an asset-aware burn (V3)
+#[event]
+pub struct MintedFromBurnV3 {
+    pub asset_id: u8,
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+/// Canonical acknowledgement of a completed transfer (MintAcknowledgement)
+///
+/// Same layout as in the per-asset mint programs.
+#[event]
+pub struct MintAcknowledged {
+    pub transfer_id: [u8; 32],
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub slot: u64,
+    pub ack_hash: [u8; 32],
+}
+
+/// Emit the MintAcknowledged record of a mint at the current slot
+pub(crate) fn emit_mint_acknowledgement(asset_id: u8, burn_nonce: u64, amount: u64, recipient: Pubkey) -> Result<()> {
+    let ack = MintAcknowledgement::new(asset_id, burn_nonce, amount, recipient, Clock::get()?.slot);
+    emit!(MintAcknowledged {
+        transfer_id: ack.transfer_id,
+        asset_id,
+        burn_nonce,
+        amount,
+        recipient,
+        slot: ack.slot,
+        ack_hash: ack.hash(),
+    });
+    Ok(())
+}
+
+/// Compute units a mint instruction used
+///
+/// Same name and layout as the light client's ComputeUnitsReported
+/// (solana_light_client_x1::compute_report), so one decoder reads both.
+#[event]
+pub struct ComputeUnitsReported {
+    /// Anchor discriminator of the reporting instruction
+    pub instruction: [u8; 8],
+    /// Burns minted
+    pub items: u8,
+    pub consumed: u64,
+    pub remaining: u64,
+}
+
+/// Emit ComputeUnitsReported for `instruction` from a started meter
+pub(crate) fn report_compute_units(meter: &ComputeMeter, instruction: [u8; 8], items: u8) {
+    let (consumed, remaining) = meter.read();
+    emit!(ComputeUnitsReported { instruction, items, consumed, remaining });
+}
@@ -0,0 +1,27 @@
+pub mod initialize_asset;
+pub mod mint_from_burn_v3;
+pub mod initialize_mint_pause;
+pub mod pause_asset;
+pub mod unpause_asset;
+pub mod initialize_rate_limit;
+pub mod set_rate_limit;
+pub mod initialize_cosign_policy;
+pub mod set_cosign_policy;
+pub mod initialize_fee_pool;
+pub mod withdraw_fees;
+pub mod initialize_fee_config;
+pub mod update_fee_config;
+
+pub use initialize_asset::*;
+pub use mint_from_burn_v3::*;
+pub use initialize_mint_pause::*;
+pub use pause_asset::*;
+pub use unpause_asset::*;
+pub use initialize_rate_limit::*;
+pub use set_rate_limit::*;
+pub use initialize_cosign_policy::*;
+pub use set_cosign_policy::*;
+pub use initialize_fee_pool::*;
+pub use withdraw_fees::*;
+pub use initialize_fee_config::*;
+pub use update_fee_config::*;
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct SetCoSignPolicy<'info> {
+    #[account(
+        seeds = [b"mint_state", asset_id.to_le_bytes().as_ref()],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        seeds = [b"cosign_policy", asset_id.to_le_bytes().as_ref()],
+        bump = cosign_policy.bump
+    )]
+    pub cosign_policy: Account<'info, CoSignPolicy>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Change the asset's co-signer or threshold, or switch the policy off
+pub fn handler(ctx: Context<SetCoSignPolicy>, asset_id: u8, enabled: bool, cosigner: Pubkey, threshold: u64) -> Result<()> {
+    let policy = &mut ctx.accounts.cosign_policy;
+    policy.enabled = enabled;
+    policy.cosigner = cosigner;
+    policy.threshold = threshold;
+
+    if enabled {
+        msg!("✓ Co-sign policy of asset {}: mints above {} need {}", asset_id, threshold, cosigner);
+    } else {
+        msg!("✓ Co-sign policy of asset {} disabled", asset_id);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,93 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{
+    ID as LIGHT_CLIENT_ID, X1ChainId, X1ValidatorSet,
+    governance::{create_governance_message, verify_validator_set_approvals},
+    instructions::ValidatorUpdateSignature,
+};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct SetRateLimit<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"rate_limit", asset_id.to_le_bytes().as_ref()],
+        bump = rate_limit.bump
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetRateLimitParams {
+    /// New cap per window (increases take effect one window later)
+    pub cap: u64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Adjust one asset's mint rate limit cap (validator governance)
+pub fn handler(ctx: Context<SetRateLimit>, asset_id: u8, params: SetRateLimitParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let rate_limit = &mut ctx.accounts.rate_limit;
+
+    msg!("🔄 Updating rate limit cap of asset {}: {} -> {}", asset_id, rate_limit.cap, params.cap);
+
+    let message = create_rate_limit_message(
+        validator_set.version,
+        rate_limit.nonce,
+        asset_id,
+        params.cap,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    rate_limit.nonce = rate_limit.nonce.checked_add(1).ok_or(MintError::Overflow)?;
+    rate_limit.set_cap(params.cap, Clock::get()?.unix_timestamp);
+
+    if rate_limit.pending_cap_at != 0 {
+        msg!("✅ Cap increase to {} scheduled for {}", rate_limit.pending_cap, rate_limit.pending_cap_at);
+    } else {
+        msg!("✅ Rate limit cap set to {}", rate_limit.cap);
+    }
+
+    Ok(())
+}
+
+/// Create deterministic message for a rate limit change of one asset
+///
+/// Format: hash(SET_MINT_RATE_LIMIT || version || nonce || mint program || asset_id || cap)
+///
+/// The program id and asset id keep an approval from applying to another
+/// program or asset.
+fn create_rate_limit_message(
+    validator_set_version: u64,
+    nonce: u64,
+    asset_id: u8,
+    cap: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(49);
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload.extend_from_slice(crate::ID.as_ref());
+    payload.push(asset_id);
+    payload.extend_from_slice(&cap.to_le_bytes());
+
+    create_governance_message(b"SET_MINT_RATE_LIMIT", validator_set_version, &payload, x1_genesis_hash)
+}
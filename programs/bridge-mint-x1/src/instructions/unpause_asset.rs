@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::instructions::pause_asset::create_mint_pause_message;
+use solana_light_client_x1::{
+    ID as LIGHT_CLIENT_ID, X1ChainId, X1ValidatorSet,
+    governance::verify_validator_set_approvals,
+    instructions::ValidatorUpdateSignature,
+};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct UnpauseAsset<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_pause", asset_id.to_le_bytes().as_ref()],
+        bump = mint_pause.bump
+    )]
+    pub mint_pause: Account<'info, MintPause>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnpauseAssetParams {
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Resume the mints of one asset after a pause (validator governance)
+pub fn handler(ctx: Context<UnpauseAsset>, asset_id: u8, params: UnpauseAssetParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let mint_pause = &mut ctx.accounts.mint_pause;
+
+    require!(mint_pause.paused, MintError::MintNotPaused);
+
+    msg!("▶️  Unpausing mints of asset {}", asset_id);
+
+    let message = create_mint_pause_message(
+        b"UNPAUSE_MINT",
+        validator_set.version,
+        mint_pause.nonce,
+        asset_id,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    mint_pause.nonce = mint_pause.nonce.checked_add(1).ok_or(MintError::Overflow)?;
+    mint_pause.paused = false;
+    mint_pause.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Mints of asset {} unpaused", asset_id);
+
+    Ok(())
+}
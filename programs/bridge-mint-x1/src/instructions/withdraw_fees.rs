@@ -0,0 +1,52 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Pay a validator's pooled fees of one asset out of its fee pool (permissionless)
+///
+/// The lamports can only go to the validator key, so anyone - the
+/// validator, a crank, a relayer - may send it, e.g. to free the entry of
+/// a validator that left the set.
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_pool", asset_id.to_le_bytes().as_ref()],
+        bump = fee_pool.bump
+    )]
+    pub fee_pool: Account<'info, FeePool>,
+
+    /// CHECK: Key the pooled balance is recorded under, and its recipient
+    #[account(mut)]
+    pub validator: UncheckedAccount<'info>,
+}
+
+/// Returns the amount paid out
+pub fn handler(ctx: Context<WithdrawFees>, asset_id: u8) -> Result<u64> {
+    let validator = ctx.accounts.validator.to_account_info();
+
+    let pool = &mut ctx.accounts.fee_pool;
+    let amount = pool.take(validator.key);
+    require!(amount > 0, MintError::NoPooledFees);
+
+    **pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **validator.try_borrow_mut_lamports()? += amount;
+
+    emit!(PooledFeesWithdrawn {
+        asset_id,
+        validator: validator.key(),
+        amount,
+    });
+
+    msg!("✓ Withdrew {} pooled lamports of asset {} for validator {}", amount, asset_id, validator.key());
+
+    Ok(amount)
+}
+
+#[event]
+pub struct PooledFeesWithdrawn {
+    pub asset_id: u8,
+    pub validator: Pubkey,
+    pub amount: u64,
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+pub mod instructions;
+pub mod state;
+pub mod errors;
+pub mod fees;
+
+use instructions::*;
+
+declare_id!("8yxPXAX7AynCpG2agTGFJQHkzFKbDwowKJtqq6GFq8Re");
+
+/// One mint program for every bridged asset registered to it
+///
+/// xencat-mint-x1 and dgn-mint-x1 each serve one asset. This program keeps
+/// a MintState per asset_id instead, so a new asset only needs its X1 mint,
+/// a register_asset pointing at this program and initialize_asset.
+#[program]
+pub mod bridge_mint_x1 {
+    use super::*;
+
+    /// Bind an asset to its X1 mint (requires threshold signatures from current validators)
+    pub fn initialize_asset(
+        ctx: Context<InitializeAsset>,
+        asset_id: u8,
+        params: InitializeAssetParams,
+    ) -> Result<()> {
+        instructions::initialize_asset::handler(ctx, asset_id, params)
+    }
+
+    /// Mint a verified burn of any asset served by this program (V3)
+    pub fn mint_from_burn_v3<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintFromBurnV3<'info>>,
+        asset_id: u8,
+        burn_nonce: u64,
+    ) -> Result<()> {
+        instructions::mint_from_burn_v3::handler(ctx, asset_id, burn_nonce)
+    }
+
+    /// Create an asset's mint pause switch (run once, permissionless; not paused)
+    pub fn initialize_mint_pause(ctx: Context<InitializeMintPause>, asset_id: u8) -> Result<()> {
+        instructions::initialize_mint_pause::handler(ctx, asset_id)
+    }
+
+    /// Emergency stop of one asset's mints (validator governance)
+    pub fn pause_asset(ctx: Context<PauseAsset>, asset_id: u8, params: PauseAssetParams) -> Result<()> {
+        instructions::pause_asset::handler(ctx, asset_id, params)
+    }
+
+    /// Resume one asset's mints after a pause (validator governance)
+    pub fn unpause_asset(ctx: Context<UnpauseAsset>, asset_id: u8, params: UnpauseAssetParams) -> Result<()> {
+        instructions::unpause_asset::handler(ctx, asset_id, params)
+    }
+
+    /// Create an asset's mint rate limit (authority only): `cap` per `window_seconds`
+    pub fn initialize_rate_limit(ctx: Context<InitializeRateLimit>, asset_id: u8, window_seconds: i64, cap: u64) -> Result<()> {
+        instructions::initialize_rate_limit::handler(ctx, asset_id, window_seconds, cap)
+    }
+
+    /// Adjust an asset's rate limit cap (validator governance; increases are delayed one window)
+    pub fn set_rate_limit(ctx: Context<SetRateLimit>, asset_id: u8, params: SetRateLimitParams) -> Result<()> {
+        instructions::set_rate_limit::handler(ctx, asset_id, params)
+    }
+
+    /// Create an asset's large-mint co-sign policy (authority only, enabled)
+    pub fn initialize_cosign_policy(
+        ctx: Context<InitializeCoSignPolicy>,
+        asset_id: u8,
+        cosigner: Pubkey,
+        threshold: u64,
+    ) -> Result<()> {
+        instructions::initialize_cosign_policy::handler(ctx, asset_id, cosigner, threshold)
+    }
+
+    /// Update or disable an asset's co-sign policy (authority only)
+    pub fn set_cosign_policy(
+        ctx: Context<SetCoSignPolicy>,
+        asset_id: u8,
+        enabled: bool,
+        cosigner: Pubkey,
+        threshold: u64,
+    ) -> Result<()> {
+        instructions::set_cosign_policy::handler(ctx, asset_id, enabled, cosigner, threshold)
+    }
+
+    /// Create an asset's fee pool (run once, permissionless): its mints then
+    /// accrue validator fees there instead of paying remaining_accounts
+    pub fn initialize_fee_pool(ctx: Context<InitializeFeePool>, asset_id: u8) -> Result<()> {
+        instructions::initialize_fee_pool::handler(ctx, asset_id)
+    }
+
+    /// Pay a validator's pooled fees of one asset to its key (permissionless)
+    pub fn withdraw_fees(ctx: Context<WithdrawFees>, asset_id: u8) -> Result<u64> {
+        instructions::withdraw_fees::handler(ctx, asset_id)
+    }
+
+    /// Create an asset's amount-based fee schedule (authority only): per validator
+    /// clamp(flat_fee + amount * fee_bps / 10_000, min_fee, max_fee)
+    pub fn initialize_fee_config(
+        ctx: Context<InitializeFeeConfig>,
+        asset_id: u8,
+        flat_fee: u64,
+        fee_bps: u16,
+        min_fee: u64,
+        max_fee: u64,
+    ) -> Result<()> {
+        instructions::initialize_fee_config::handler(ctx, asset_id, flat_fee, fee_bps, min_fee, max_fee)
+    }
+
+    /// Change an asset's fee schedule (authority only)
+    pub fn update_fee_config(
+        ctx: Context<UpdateFeeConfig>,
+        asset_id: u8,
+        flat_fee: u64,
+        fee_bps: u16,
+        min_fee: u64,
+        max_fee: u64,
+    ) -> Result<()> {
+        instructions::update_fee_config::handler(ctx, asset_id, flat_fee, fee_bps, min_fee, max_fee)
+    }
+}
@@ -0,0 +1,342 @@
+use anchor_lang::prelude::*;
+use solana_light_client_x1::invariants::ProcessedRecord;
+
+/// Per-asset mint state
+///
+/// The PDA is the mint authority of `mint`. Created once per asset by
+/// initialize_asset; asset ids are never reused, so neither is this account.
+/// `authority` manages the asset's co-sign policy, fee schedule and rate
+/// limit creation, like MintState.authority in the per-asset programs.
+///
+/// Seeds: ["mint_state", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct MintState {
+    pub asset_id: u8,
+    pub mint: Pubkey,
+    pub authority: Pubkey,
+    pub fee_per_validator: u64,        // Fee per validator (0.01 XNT = 10_000_000)
+    pub processed_burns_count: u64,
+    pub total_minted: u64,
+    pub bump: u8,
+}
+
+impl MintState {
+    /// Default validator fee, as in the per-asset mint programs
+    pub const DEFAULT_FEE_PER_VALIDATOR: u64 = 10_000_000;
+}
+
+/// Processed burn tracker V3 (asset-aware, prevents replay attacks)
+///
+/// Same layout and seeds as in the per-asset mint programs, so the light
+/// client (freeze, archive, mark_burn_processed) finds it under whichever
+/// mint program the asset is registered to.
+///
+/// Seeds: ["processed_burn_v3", asset_id, nonce, user]
+#[account]
+#[derive(InitSpace)]
+pub struct ProcessedBurnV3 {
+    pub asset_id: u8,
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub processed_at: i64,
+}
+
+impl ProcessedBurnV3 {
+    /// This record as the bridge invariants read it
+    pub fn record(&self) -> ProcessedRecord {
+        ProcessedRecord { asset_id: self.asset_id, nonce: self.nonce, user: self.user, amount: self.amount }
+    }
+}
+
+// ============================================================================
+// PER-ASSET MINT CONTROLS
+// ============================================================================
+//
+// Same accounts and rules as in xencat-mint-x1 and dgn-mint-x1, with the
+// asset_id added to every seed: each asset served here has its own pause,
+// rate limit, co-sign policy, fee pool and fee schedule.
+
+/// Dual control for large mints of one asset
+///
+/// While `enabled`, mint_from_burn_v3 refuses a burn of more than
+/// `threshold` base units unless `cosigner` also signs the transaction
+/// (passed at the end of remaining_accounts). No account = no co-signing.
+///
+/// Seeds: ["cosign_policy", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct CoSignPolicy {
+    pub enabled: bool,
+    pub cosigner: Pubkey,
+    /// Largest amount (token base units) minted without the co-signer
+    pub threshold: u64,
+    pub bump: u8,
+}
+
+impl CoSignPolicy {
+    pub fn requires_cosigner(&self, amount: u64) -> bool {
+        self.enabled && amount > self.threshold
+    }
+
+    /// Require the co-signer among `accounts` if the policy applies to `amount`
+    ///
+    /// `info` must be the asset's ["cosign_policy"] PDA (callers pin it with
+    /// seeds). An uninitialized account means no policy.
+    pub fn enforce(info: &AccountInfo, amount: u64, accounts: &[AccountInfo]) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidCoSignPolicy);
+        let policy = CoSignPolicy::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        if !policy.requires_cosigner(amount) {
+            return Ok(());
+        }
+        require!(
+            accounts.iter().any(|a| a.is_signer && a.key() == policy.cosigner),
+            crate::errors::MintError::CoSignerRequired
+        );
+        msg!("✓ Co-signed by {} (amount {} > {})", policy.cosigner, amount, policy.threshold);
+        Ok(())
+    }
+}
+
+/// Cap on one asset's minted volume per rolling window
+///
+/// See RateLimit in xencat-mint-x1: increases approved by validators take
+/// effect one window later, decreases at once. No account = no limit.
+///
+/// Seeds: ["rate_limit", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct RateLimit {
+    /// Rolling window length (e.g. 86400 for 24h)
+    pub window_seconds: i64,
+    /// Most token base units minted per window
+    pub cap: u64,
+    /// Approved increase, in force from `pending_cap_at` (0 = none)
+    pub pending_cap: u64,
+    pub pending_cap_at: i64,
+    /// Start of the current fixed window
+    pub window_start: i64,
+    pub current_window_minted: u64,
+    pub previous_window_minted: u64,
+    /// Governance nonce (bound into set_rate_limit approvals)
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl RateLimit {
+    /// Shortest allowed window (1 hour)
+    pub const MIN_WINDOW_SECONDS: i64 = 3_600;
+
+    /// Move to the window holding `now` and apply a due cap increase
+    fn roll(&mut self, now: i64) {
+        let windows_passed = (now - self.window_start) / self.window_seconds;
+        if windows_passed >= 1 {
+            self.previous_window_minted = if windows_passed == 1 { self.current_window_minted } else { 0 };
+            self.current_window_minted = 0;
+            self.window_start += windows_passed * self.window_seconds;
+        }
+        if self.pending_cap_at != 0 && now >= self.pending_cap_at {
+            self.cap = self.pending_cap;
+            self.pending_cap = 0;
+            self.pending_cap_at = 0;
+        }
+    }
+
+    /// Volume counted against the cap at `now` (after roll)
+    fn used(&self, now: i64) -> u64 {
+        let remaining = (self.window_seconds - (now - self.window_start)).max(0) as u128;
+        let carried = self.previous_window_minted as u128 * remaining / self.window_seconds as u128;
+        (carried as u64).saturating_add(self.current_window_minted)
+    }
+
+    /// Count `amount` against the cap, failing if it does not fit
+    pub fn consume(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.roll(now);
+        let used = self.used(now);
+        require!(
+            used.checked_add(amount).is_some_and(|total| total <= self.cap),
+            crate::errors::MintError::RateLimitExceeded
+        );
+        self.current_window_minted = self.current_window_minted.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Change the cap: decreases now, increases one window from `now`
+    pub fn set_cap(&mut self, cap: u64, now: i64) {
+        self.roll(now);
+        if cap <= self.cap {
+            self.cap = cap;
+            self.pending_cap = 0;
+            self.pending_cap_at = 0;
+        } else {
+            self.pending_cap = cap;
+            self.pending_cap_at = now + self.window_seconds;
+        }
+    }
+
+    /// Record a mint of `amount` against the rate limit
+    ///
+    /// `info` must be the asset's ["rate_limit"] PDA, writable (callers pin
+    /// it with seeds). An uninitialized account means no limit.
+    pub fn enforce(info: &AccountInfo, amount: u64) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidRateLimit);
+        let now = Clock::get()?.unix_timestamp;
+        let mut rate_limit = RateLimit::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        rate_limit.consume(amount, now)?;
+        rate_limit.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        msg!("✓ Rate limit: {} of {} used this window", rate_limit.used(now), rate_limit.cap);
+        Ok(())
+    }
+}
+
+/// Emergency pause of one asset's mints (validator governance)
+///
+/// Anyone can create the account; only a validator threshold can flip it.
+/// No account = not paused.
+///
+/// Seeds: ["mint_pause", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct MintPause {
+    pub paused: bool,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    /// Governance nonce (bound into pause_asset / unpause_asset approvals)
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl MintPause {
+    /// Fail with MintPaused while paused
+    ///
+    /// `info` must be the asset's ["mint_pause"] PDA (callers pin it with
+    /// seeds). An uninitialized account means not paused.
+    pub fn require_unpaused(info: &AccountInfo) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidMintPause);
+        let pause = MintPause::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(!pause.paused, crate::errors::MintError::MintPaused);
+        Ok(())
+    }
+}
+
+/// Shared accrual account for one asset's validator fees
+///
+/// Once created (initialize_fee_pool), mints pay the whole validator fee
+/// here in one transfer and credit each validator's share. Balances leave
+/// the pool only through withdraw_fees, to the validator key.
+///
+/// Pool lamports = rent-exempt minimum + sum of balances.
+///
+/// Seeds: ["fee_pool", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct FeePool {
+    #[max_len(32)]
+    pub balances: Vec<PooledFee>,
+    pub total_accrued: u64,      // Total fees credited (audit trail)
+    pub total_withdrawn: u64,    // Total fees paid out (audit trail)
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PooledFee {
+    pub validator: Pubkey,
+    pub balance: u64,
+}
+
+impl FeePool {
+    /// Validators with an unwithdrawn balance the pool can track
+    pub const MAX_VALIDATORS: usize = 32;
+
+    /// The pool, if `info` (the asset's ["fee_pool"] PDA, pinned by callers) is initialized
+    pub fn load(info: &AccountInfo) -> Result<Option<FeePool>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidFeePool);
+        Ok(Some(FeePool::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Add `fee` to `validator`'s balance
+    pub fn credit(&mut self, validator: &Pubkey, fee: u64) -> Result<()> {
+        match self.balances.iter_mut().find(|entry| entry.validator == *validator) {
+            Some(entry) => {
+                entry.balance = entry.balance.checked_add(fee).ok_or(crate::errors::MintError::Overflow)?;
+            }
+            None => {
+                require!(self.balances.len() < Self::MAX_VALIDATORS, crate::errors::MintError::FeePoolFull);
+                self.balances.push(PooledFee { validator: *validator, balance: fee });
+            }
+        }
+        self.total_accrued = self.total_accrued.checked_add(fee).ok_or(crate::errors::MintError::Overflow)?;
+        Ok(())
+    }
+
+    /// Remove `validator`'s entry and return its balance (0 if none)
+    pub fn take(&mut self, validator: &Pubkey) -> u64 {
+        let Some(index) = self.balances.iter().position(|entry| entry.validator == *validator) else {
+            return 0;
+        };
+        let amount = self.balances.swap_remove(index).balance;
+        self.total_withdrawn = self.total_withdrawn.saturating_add(amount);
+        amount
+    }
+}
+
+/// Amount-based validator fee schedule of one asset
+///
+///   clamp(flat_fee + amount * fee_bps / 10_000, min_fee, max_fee) lamports
+///
+/// per validator and minted burn. No account = MintState.fee_per_validator.
+///
+/// Seeds: ["fee_config", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct FeeConfig {
+    /// Lamports per validator on every burn
+    pub flat_fee: u64,
+    /// Lamports per validator per 10_000 base units minted
+    pub fee_bps: u16,
+    /// Floor and cap of the per-validator fee (lamports)
+    pub min_fee: u64,
+    pub max_fee: u64,
+    pub bump: u8,
+}
+
+impl FeeConfig {
+    /// 1 lamport per base unit
+    pub const MAX_FEE_BPS: u16 = 10_000;
+
+    /// The config, if `info` (the asset's ["fee_config"] PDA, pinned by callers) is initialized
+    pub fn load(info: &AccountInfo) -> Result<Option<FeeConfig>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidFeeConfig);
+        Ok(Some(FeeConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.fee_bps <= Self::MAX_FEE_BPS && self.min_fee <= self.max_fee,
+            crate::errors::MintError::InvalidFeeConfig
+        );
+        Ok(())
+    }
+
+    /// Per-validator fee for a burn of `amount` base units
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        let proportional = (amount as u128 * self.fee_bps as u128 / 10_000) as u64;
+        self.flat_fee.saturating_add(proportional).clamp(self.min_fee, self.max_fee)
+    }
+}
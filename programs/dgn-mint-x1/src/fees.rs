@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::errors::MintError;
+use crate::state::FeeVault;
+use solana_light_client_x1::{ValidatorLatencyStats, X1ValidatorSet};
+
+/// Fee owed to each member of `validator_set`, in set order
+///
+/// The total stays `fee_per_validator` per validator; the split follows
+/// attestation participation (ValidatorLatencyStats::fee_shares), or is
+/// equal while the light client's latency stats are not initialized.
+pub fn validator_fee_shares(
+    fee_per_validator: u64,
+    validator_set: &X1ValidatorSet,
+    latency_stats: &AccountInfo,
+) -> Result<Vec<u64>> {
+    let total_fee = fee_per_validator
+        .checked_mul(validator_set.validators.len() as u64)
+        .ok_or(MintError::Overflow)?;
+    ValidatorLatencyStats::fee_shares_of(latency_stats, total_fee, &validator_set.validators)
+}
+
+/// Pay one validator's mint fee
+///
+/// `recipient` is either the validator key itself or, if the validator
+/// opted in with initialize_fee_vault, its FeeVault PDA. Vault payments are
+/// credited to `balance`, less the validator's commission, and claimed later
+/// with claim_validator_fees (claim_validator_commission for the commission).
+pub fn pay_validator_fee<'info>(
+    payer: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    validator: &Pubkey,
+    fee: u64,
+) -> Result<()> {
+    require!(recipient.is_writable, MintError::ValidatorAccountNotWritable);
+
+    // Only this program can create FeeVault accounts, so owner + discriminator
+    // + validator field identify the validator's vault
+    let vault = if recipient.key() == *validator {
+        None
+    } else {
+        require!(recipient.owner == &crate::ID, MintError::InvalidValidatorAccount);
+        let vault = FeeVault::try_deserialize(&mut &recipient.try_borrow_data()?[..])?;
+        require!(vault.validator == *validator, MintError::InvalidValidatorAccount);
+        Some(vault)
+    };
+
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(payer.key, recipient.key, fee),
+        &[payer.clone(), recipient.clone()],
+    )?;
+
+    if let Some(mut vault) = vault {
+        vault.accrue(fee)?;
+        vault.try_serialize(&mut &mut recipient.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
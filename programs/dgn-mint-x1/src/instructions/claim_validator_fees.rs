@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Claim accrued validator fees (CPI-friendly)
+///
+/// Only `authority` signs (no Ed25519 sysvar, no validator key), so a
+/// multisig or a program PDA (invoke_signed) can claim. Any writable
+/// account can receive the lamports. Returns the claimed amount.
+#[derive(Accounts)]
+pub struct ClaimValidatorFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_vault", fee_vault.validator.as_ref()],
+        bump = fee_vault.bump,
+        has_one = authority @ MintError::Unauthorized
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    pub authority: Signer<'info>,
+
+    /// CHECK: Any account chosen by the authority (except the vault itself)
+    #[account(mut, constraint = destination.key() != fee_vault.key() @ MintError::InvalidRecipient)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// `amount`: lamports to claim, or None for the whole balance
+pub fn handler(ctx: Context<ClaimValidatorFees>, amount: Option<u64>) -> Result<u64> {
+    let vault = &mut ctx.accounts.fee_vault;
+    let amount = amount.unwrap_or(vault.balance);
+    require!(amount > 0 && amount <= vault.balance, MintError::InvalidClaimAmount);
+
+    // Vault lamports = rent-exempt minimum + balance + commission_balance,
+    // so rent and accrued commission are never touched
+    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+
+    vault.balance -= amount;
+    vault.total_claimed = vault.total_claimed.saturating_add(amount);
+
+    emit!(ValidatorFeesClaimed {
+        validator: vault.validator,
+        authority: ctx.accounts.authority.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        remaining: vault.balance,
+    });
+
+    msg!("✓ Claimed {} lamports for validator {} ({} left)", amount, vault.validator, vault.balance);
+
+    Ok(amount)
+}
+
+#[event]
+pub struct ValidatorFeesClaimed {
+    pub validator: Pubkey,
+    pub authority: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, Asset, state::BurnAgeLimit};
+
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64)]
+pub struct CloseProcessedBurn<'info> {
+    /// The burn's user (receives the rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Replay guard of the minted burn
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"processed_burn_v3",
+            &[Asset::DGN as u8],
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        constraint = processed_burn.user == user.key() @ MintError::UserMismatch,
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    /// The burn's VerifiedBurnV3 (must be archived, i.e. closed)
+    /// CHECK: Address pinned by seeds; only checked to be empty
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            &[Asset::DGN as u8],
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID,
+    )]
+    pub verified_burn: UncheckedAccount<'info>,
+
+    /// Light client burn age limit (not initialized = nothing closable)
+    /// CHECK: Address pinned by seeds; read by BurnAgeLimit::load
+    #[account(seeds = [b"burn_age_limit"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub burn_age_limit: UncheckedAccount<'info>,
+}
+
+/// Close a minted burn's replay guard and refund its rent to the user
+///
+/// Only safe once the burn can never be verified again, so it requires
+/// the VerifiedBurnV3 archived and the burn past the light client's burn
+/// age limit. DGN has no V2 proofs to upgrade.
+///
+/// The rent goes to the user, whoever paid it (a relayer for claims on
+/// behalf): the guard does not record its payer.
+pub fn handler(ctx: Context<CloseProcessedBurn>, burn_nonce: u64) -> Result<()> {
+    let processed = &ctx.accounts.processed_burn;
+
+    require!(ctx.accounts.verified_burn.data_is_empty(), MintError::VerifiedBurnNotArchived);
+
+    let max_burn_age = BurnAgeLimit::load(&ctx.accounts.burn_age_limit)?;
+    require!(
+        BurnAgeLimit::is_expired(max_burn_age, processed.processed_at, Clock::get()?.unix_timestamp),
+        MintError::BurnNotExpired
+    );
+
+    msg!("🧹 Replay guard closed: burn {} of {}", burn_nonce, processed.user);
+    msg!("   Minted {} at {}", processed.amount, processed.processed_at);
+
+    Ok(())
+}
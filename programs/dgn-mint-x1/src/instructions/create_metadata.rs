@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_interface::{Mint, TokenInterface};
 use mpl_token_metadata::{
     ID as TOKEN_METADATA_PROGRAM_ID,
     accounts::Metadata,
@@ -25,7 +25,7 @@ pub struct CreateMetadata<'info> {
         mut,
         address = mint_state.dgn_mint
     )]
-    pub dgn_mint: Account<'info, Mint>,
+    pub dgn_mint: InterfaceAccount<'info, Mint>,
 
     /// Metadata account to be created (PDA derived from mint)
     /// CHECK: This account is created by the Metaplex program
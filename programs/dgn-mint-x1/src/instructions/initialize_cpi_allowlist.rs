@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeCpiAllowlist<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + CpiAllowlist::INIT_SPACE,
+        seeds = [b"cpi_allowlist"],
+        bump
+    )]
+    pub cpi_allowlist: Account<'info, CpiAllowlist>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the CPI allowlist (run once, permissionless)
+///
+/// Starts empty: mint_and_call can invoke nothing until validators add programs.
+pub fn handler(ctx: Context<InitializeCpiAllowlist>) -> Result<()> {
+    let allowlist = &mut ctx.accounts.cpi_allowlist;
+    allowlist.programs = Vec::new();
+    allowlist.nonce = 0;
+    allowlist.bump = ctx.bumps.cpi_allowlist;
+
+    msg!("✓ CPI allowlist created (empty)");
+
+    Ok(())
+}
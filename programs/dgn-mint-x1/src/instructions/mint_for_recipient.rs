@@ -0,0 +1,247 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{pay_validator_fee, validator_fee_shares};
+use crate::instructions::mint_from_burn_v3::emit_mint_acknowledgement;
+use solana_light_client_x1::invariants;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, VerifiedBurnV3, X1ValidatorSet, FeatureGate, RelayerClaim, state::{gates, AssetRegistry}};
+
+/// Mint a designated-recipient burn on the recipient's behalf (V3)
+///
+/// Same checks as mint_from_burn_v3, except:
+/// - `user` is the recipient recorded with the burn and does NOT sign
+/// - `relayer` must be the RelayerClaim's relayer (it verified the burn);
+///   it pays rent and validator fees
+/// - The minted amount is split: `relayer_fee` to the relayer's token
+///   account, the rest to the recipient's. The burner chose the fee on
+///   Solana and validators attested it (see RelayerClaim)
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64, asset_id: u8)]
+pub struct MintForRecipient<'info> {
+    #[account(
+        mut,
+        seeds = [b"dgn_mint_state"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        address = mint_state.dgn_mint
+    )]
+    pub dgn_mint: InterfaceAccount<'info, Mint>,
+
+    /// Processed burn tracker V3 (same PDA as mint_from_burn_v3 - one mint per burn)
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + ProcessedBurnV3::INIT_SPACE,
+        seeds = [
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    /// Recipient's token account
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == user.key() @ MintError::InvalidRecipient,
+        constraint = recipient_token_account.mint == mint_state.dgn_mint @ MintError::InvalidRecipient
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Recipient; bound to the burn by the VerifiedBurnV3 seeds and user check
+    pub user: UncheckedAccount<'info>,
+
+    /// Relayer that verified the burn
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// Relayer's token account (receives the relayer fee)
+    #[account(
+        mut,
+        constraint = relayer_token_account.owner == relayer.key() @ MintError::InvalidRelayer,
+        constraint = relayer_token_account.mint == mint_state.dgn_mint @ MintError::InvalidRelayer
+    )]
+    pub relayer_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+        constraint = !verified_burn.processed @ MintError::BurnAlreadyProcessed,
+        constraint = verified_burn.user == user.key() @ MintError::UserMismatch,
+        constraint = verified_burn.burn_nonce == burn_nonce @ MintError::NonceMismatch,
+        constraint = verified_burn.asset_id == asset_id @ MintError::AssetMismatch,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Claim terms attested with the burn (from the light client)
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = relayer_claim.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+        constraint = relayer_claim.relayer == relayer.key() @ MintError::InvalidRelayer,
+    )]
+    pub relayer_claim: Account<'info, RelayerClaim>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintForRecipient<'info>>,
+    burn_nonce: u64,
+    asset_id: u8,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_V3)?;
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::CLAIM_ON_BEHALF)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+
+    // This program must ONLY mint DGN (asset_id = 2)
+    require!(asset_id == 2, MintError::AssetNotMintable);
+
+    let amount = ctx.accounts.verified_burn.amount;
+    let relayer_fee = ctx.accounts.relayer_claim.relayer_fee;
+    let net_amount = amount.checked_sub(relayer_fee).ok_or(MintError::Overflow)?;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
+    let mint_state = &ctx.accounts.mint_state;
+    let signer_seeds: &[&[&[u8]]] = &[&[b"dgn_mint_state", &[mint_state.bump]]];
+
+    for (to, tokens) in [
+        (&ctx.accounts.recipient_token_account, net_amount),
+        (&ctx.accounts.relayer_token_account, relayer_fee),
+    ] {
+        if tokens == 0 {
+            continue;
+        }
+        token_interface::mint_to(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                MintTo {
+                    mint: ctx.accounts.dgn_mint.to_account_info(),
+                    to: to.to_account_info(),
+                    authority: ctx.accounts.mint_state.to_account_info(),
+                },
+                signer_seeds,
+            ),
+            tokens,
+        )?;
+    }
+
+    invariants::debug_check(invariants::minted_within_attested(net_amount.saturating_add(relayer_fee), amount));
+
+    let processed = &mut ctx.accounts.processed_burn;
+    processed.asset_id = asset_id;
+    processed.nonce = burn_nonce;
+    processed.user = ctx.accounts.user.key();
+    processed.amount = amount;
+    processed.processed_at = Clock::get()?.unix_timestamp;
+    invariants::debug_check(invariants::processed_implies_verified(
+        &processed.record(),
+        Some(&*ctx.accounts.verified_burn),
+        false,
+    ));
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.recipient_token_account.key())?;
+
+    // Validator fees are paid by the relayer (same rules as mint_from_burn_v3)
+    let fee_per_validator = mint_state.fee_per_validator;
+    if fee_per_validator > 0 {
+        let validator_set = &ctx.accounts.validator_set;
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+        for (i, (validator_pubkey, share)) in validator_set.validators.iter().zip(shares).enumerate() {
+            let validator_account = ctx.remaining_accounts.get(i)
+                .ok_or(MintError::MissingValidatorAccount)?;
+            pay_validator_fee(
+                &ctx.accounts.relayer.to_account_info(),
+                validator_account,
+                validator_pubkey,
+                share,
+            )?;
+        }
+    }
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(1);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(amount);
+
+    emit!(MintedForRecipient {
+        asset_id,
+        nonce: burn_nonce,
+        user: ctx.accounts.user.key(),
+        relayer: ctx.accounts.relayer.key(),
+        amount: net_amount,
+        relayer_fee,
+    });
+
+    msg!("✓ Minted {} tokens to recipient {} ({} to relayer)", net_amount, ctx.accounts.user.key(), relayer_fee);
+
+    Ok(())
+}
+
+/// Event emitted when a relayer mints a designated-recipient burn
+#[event]
+pub struct MintedForRecipient {
+    pub asset_id: u8,
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub relayer: Pubkey,
+    /// Minted to the recipient (burned amount minus relayer_fee)
+    pub amount: u64,
+    pub relayer_fee: u64,
+}
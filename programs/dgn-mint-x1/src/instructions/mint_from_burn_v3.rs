@@ -1,8 +1,12 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_lang::Discriminator;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
 use crate::state::*;
 use crate::errors::*;
-use solana_light_client_x1::{self, ID as LIGHT_CLIENT_ID, VerifiedBurnV3, X1ValidatorSet, Asset};
+use crate::fees::{pay_validator_fee, validator_fee_shares};
+use solana_light_client_x1::compute_report::ComputeMeter;
+use solana_light_client_x1::invariants;
+use solana_light_client_x1::{self, ID as LIGHT_CLIENT_ID, VerifiedBurnV3, RelayerClaim, X1ValidatorSet, Asset, FeatureGate, MintAcknowledgement, state::{gates, AssetRegistry}};
 
 /// Asset-aware mint instruction (V3) for DGN
 ///
@@ -30,7 +34,7 @@ pub struct MintFromBurnV3<'info> {
         mut,
         address = mint_state.dgn_mint
     )]
-    pub dgn_mint: Account<'info, Mint>,
+    pub dgn_mint: InterfaceAccount<'info, Mint>,
 
     /// Processed burn tracker V3 (asset-aware, prevents replay attacks)
     ///
@@ -60,7 +64,7 @@ pub struct MintFromBurnV3<'info> {
         mut,
         constraint = user_token_account.owner == user.key()
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User must be signer AND match verified_burn.user
     #[account(mut)]
@@ -103,7 +107,52 @@ pub struct MintFromBurnV3<'info> {
     )]
     pub verified_burn: Account<'info, VerifiedBurnV3>,
 
-    pub token_program: Program<'info, Token>,
+    /// Relayer claim of the burn (initialized = only mint_for_recipient mints it)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -133,6 +182,12 @@ pub fn handler<'info>(
     burn_nonce: u64,
     asset_id: u8,
 ) -> Result<()> {
+    let meter = ComputeMeter::start();
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_V3)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
     msg!("╔═══════════════════════════════════════════════╗");
     msg!("║   DGN Mint from Asset-Aware Verified Burn    ║");
     msg!("║                    (V3)                       ║");
@@ -195,8 +250,10 @@ pub fn handler<'info>(
     // ===== STEP 4: Mint DGN Tokens =====
     // Mint the exact amount that was burned and verified
     let amount = verified.amount;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
 
-    token::mint_to(
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             MintTo {
@@ -225,6 +282,11 @@ pub fn handler<'info>(
     processed.user = ctx.accounts.user.key();
     processed.amount = amount;
     processed.processed_at = Clock::get()?.unix_timestamp;
+    invariants::debug_check(invariants::processed_implies_verified(
+        &processed.record(),
+        Some(&*ctx.accounts.verified_burn),
+        false,
+    ));
 
     msg!("✓ Burn marked as processed (asset_id={})", asset_id);
 
@@ -238,41 +300,24 @@ pub fn handler<'info>(
 
     if fee_per_validator > 0 {
         msg!("Distributing fees to {} validators", validator_set.validators.len());
-        msg!("Fee per validator: {} lamports (0.01 XNT)", fee_per_validator);
+        msg!("Fee per validator: {} lamports (0.01 XNT) on average", fee_per_validator);
         msg!("Total fee: {} lamports", total_fee);
 
+        // Weighted by each validator's attestation participation
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+
         // Distribute fees to each validator using remaining_accounts
-        for (i, validator_pubkey) in validator_set.validators.iter().enumerate() {
+        for (i, (validator_pubkey, share)) in validator_set.validators.iter().zip(shares).enumerate() {
             let validator_account = ctx.remaining_accounts.get(i)
                 .ok_or(MintError::MissingValidatorAccount)?;
-
-            // Verify the account matches the expected validator
-            require!(
-                validator_account.key() == *validator_pubkey,
-                MintError::InvalidValidatorAccount
-            );
-
-            require!(
-                validator_account.is_writable,
-                MintError::ValidatorAccountNotWritable
-            );
-
-            // Transfer XNT fee to validator
-            let fee_transfer = anchor_lang::solana_program::system_instruction::transfer(
-                ctx.accounts.user.key,
-                validator_account.key,
-                fee_per_validator,
-            );
-
-            anchor_lang::solana_program::program::invoke(
-                &fee_transfer,
-                &[
-                    ctx.accounts.user.to_account_info(),
-                    validator_account.to_account_info(),
-                ],
+            pay_validator_fee(
+                &ctx.accounts.user.to_account_info(),
+                validator_account,
+                validator_pubkey,
+                share,
             )?;
 
-            msg!("✓ Transferred {} lamports to validator {}", fee_per_validator, validator_pubkey);
+            msg!("✓ Transferred {} lamports to validator {}", share, validator_pubkey);
         }
 
         msg!("✓ Total fees distributed: {} lamports", total_fee);
@@ -290,6 +335,7 @@ pub fn handler<'info>(
         user: ctx.accounts.user.key(),
         amount,
     });
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.user_token_account.key())?;
 
     msg!("╔═══════════════════════════════════════════════╗");
     msg!("║         ✓ MINTING SUCCESSFUL (V3)             ║");
@@ -298,6 +344,8 @@ pub fn handler<'info>(
     msg!("Total burns processed: {}", mint_state.processed_burns_count);
     msg!("Total minted: {}", mint_state.total_minted);
 
+    report_compute_units(&meter, crate::instruction::MintFromBurnV3::DISCRIMINATOR, 1);
+
     Ok(())
 }
 
@@ -309,3 +357,53 @@ pub struct MintedFromBurnV3 {
     pub user: Pubkey,
     pub amount: u64,
 }
+
+/// Canonical acknowledgement of a completed transfer (MintAcknowledgement)
+///
+/// Emitted by every V3 mint path. Validators of the reverse direction
+/// attest to `ack_hash`; see MintAcknowledgement in the light client.
+#[event]
+pub struct MintAcknowledged {
+    pub transfer_id: [u8; 32],
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub amount: u64,
+    pub recipient: Pubkey,
+    pub slot: u64,
+    pub ack_hash: [u8; 32],
+}
+
+/// Emit the MintAcknowledged record of a mint at the current slot
+pub(crate) fn emit_mint_acknowledgement(asset_id: u8, burn_nonce: u64, amount: u64, recipient: Pubkey) -> Result<()> {
+    let ack = MintAcknowledgement::new(asset_id, burn_nonce, amount, recipient, Clock::get()?.slot);
+    emit!(MintAcknowledged {
+        transfer_id: ack.transfer_id,
+        asset_id,
+        burn_nonce,
+        amount,
+        recipient,
+        slot: ack.slot,
+        ack_hash: ack.hash(),
+    });
+    Ok(())
+}
+
+/// Compute units a mint instruction used
+///
+/// Same name and layout as the light client's ComputeUnitsReported
+/// (solana_light_client_x1::compute_report), so one decoder reads both.
+#[event]
+pub struct ComputeUnitsReported {
+    /// Anchor discriminator of the reporting instruction
+    pub instruction: [u8; 8],
+    /// Burns minted
+    pub items: u8,
+    pub consumed: u64,
+    pub remaining: u64,
+}
+
+/// Emit ComputeUnitsReported for `instruction` from a started meter
+pub(crate) fn report_compute_units(meter: &ComputeMeter, instruction: [u8; 8], items: u8) {
+    let (consumed, remaining) = meter.read();
+    emit!(ComputeUnitsReported { instruction, items, consumed, remaining });
+}
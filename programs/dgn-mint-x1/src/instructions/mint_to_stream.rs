@@ -0,0 +1,229 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{pay_validator_fee, validator_fee_shares};
+use crate::instructions::mint_from_burn_v3::emit_mint_acknowledgement;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, VerifiedBurnV3, RelayerClaim, X1ValidatorSet, FeatureGate, state::{gates, AssetRegistry}};
+
+/// Mint a streamed user's verified burn into the stream recipient (V3)
+///
+/// Same checks as mint_from_burn_v3, except:
+/// - The user does NOT sign; an open MintStream for the user is the
+///   authorization, and tokens can only go to its recipient account
+/// - The cranker pays the ProcessedBurnV3 rent and the validator fees
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64, asset_id: u8)]
+pub struct MintToStream<'info> {
+    #[account(
+        mut,
+        seeds = [b"dgn_mint_state"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        address = mint_state.dgn_mint
+    )]
+    pub dgn_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_stream", user.key().as_ref()],
+        bump = mint_stream.bump,
+        has_one = user,
+        has_one = recipient_token_account @ MintError::InvalidRecipient,
+    )]
+    pub mint_stream: Account<'info, MintStream>,
+
+    /// Processed burn tracker V3 (same PDA as mint_from_burn_v3 - one mint per burn)
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + ProcessedBurnV3::INIT_SPACE,
+        seeds = [
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    #[account(mut)]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Burner; bound by the stream and verified burn seeds, not a signer
+    pub user: UncheckedAccount<'info>,
+
+    /// Relayer / crank paying rent and fees
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+        constraint = !verified_burn.processed @ MintError::BurnAlreadyProcessed,
+        constraint = verified_burn.user == user.key() @ MintError::UserMismatch,
+        constraint = verified_burn.burn_nonce == burn_nonce @ MintError::NonceMismatch,
+        constraint = verified_burn.asset_id == asset_id @ MintError::AssetMismatch,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Relayer claim of the burn (initialized = only mint_for_recipient mints it)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintToStream<'info>>,
+    burn_nonce: u64,
+    asset_id: u8,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_STREAM)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
+    // This program must ONLY mint DGN (asset_id = 2)
+    require!(asset_id == 2, MintError::AssetNotMintable);
+
+    let amount = ctx.accounts.verified_burn.amount;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
+    let mint_state = &ctx.accounts.mint_state;
+
+    msg!("🌊 Streaming mint: nonce {} for {}", burn_nonce, ctx.accounts.user.key());
+    msg!("   Recipient: {}", ctx.accounts.recipient_token_account.key());
+    msg!("   Amount: {}", amount);
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.dgn_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_state.to_account_info(),
+            },
+            &[&[
+                b"dgn_mint_state",
+                &[mint_state.bump]
+            ]],
+        ),
+        amount,
+    )?;
+
+    let processed = &mut ctx.accounts.processed_burn;
+    processed.asset_id = asset_id;
+    processed.nonce = burn_nonce;
+    processed.user = ctx.accounts.user.key();
+    processed.amount = amount;
+    processed.processed_at = Clock::get()?.unix_timestamp;
+
+    // Validator fees are paid by the cranker (same rules as mint_from_burn_v3)
+    let fee_per_validator = mint_state.fee_per_validator;
+    if fee_per_validator > 0 {
+        let validator_set = &ctx.accounts.validator_set;
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+        for (i, (validator_pubkey, share)) in validator_set.validators.iter().zip(shares).enumerate() {
+            let validator_account = ctx.remaining_accounts.get(i)
+                .ok_or(MintError::MissingValidatorAccount)?;
+            pay_validator_fee(
+                &ctx.accounts.cranker.to_account_info(),
+                validator_account,
+                validator_pubkey,
+                share,
+            )?;
+        }
+    }
+
+    let stream = &mut ctx.accounts.mint_stream;
+    stream.burns_streamed = stream.burns_streamed.saturating_add(1);
+    stream.total_streamed = stream.total_streamed.saturating_add(amount);
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(1);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(amount);
+
+    emit!(MintedToStream {
+        asset_id,
+        nonce: burn_nonce,
+        user: ctx.accounts.user.key(),
+        recipient_token_account: ctx.accounts.recipient_token_account.key(),
+        amount,
+    });
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.recipient_token_account.key())?;
+
+    msg!("✓ Streamed {} tokens", amount);
+
+    Ok(())
+}
+
+/// Event emitted when a verified burn is minted into a stream recipient
+#[event]
+pub struct MintedToStream {
+    pub asset_id: u8,
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub amount: u64,
+}
@@ -1,7 +1,57 @@
 pub mod initialize;
 pub mod mint_from_burn_v3;
 pub mod create_metadata;
+pub mod open_mint_stream;
+pub mod close_mint_stream;
+pub mod close_processed_burn;
+pub mod mint_to_stream;
+pub mod quote_fees;
+pub mod mint_to_stealth;
+pub mod mint_for_recipient;
+pub mod initialize_output_config;
+pub mod set_compressed_output;
+pub mod mint_from_burn_v3_compressed;
+pub mod initialize_fee_vault;
+pub mod claim_validator_fees;
+pub mod set_fee_vault_authority;
+pub mod set_validator_commission;
+pub mod claim_validator_commission;
+pub mod initialize_rate_limit;
+pub mod set_rate_limit;
+pub mod initialize_mint_pause;
+pub mod pause_bridge;
+pub mod unpause_bridge;
+pub mod initialize_cosign_policy;
+pub mod set_cosign_policy;
+pub mod initialize_cpi_allowlist;
+pub mod set_cpi_allowlist;
+pub mod mint_and_call;
 
 pub use initialize::*;
 pub use mint_from_burn_v3::*;
 pub use create_metadata::*;
+pub use open_mint_stream::*;
+pub use close_mint_stream::*;
+pub use close_processed_burn::*;
+pub use mint_to_stream::*;
+pub use quote_fees::*;
+pub use mint_to_stealth::*;
+pub use mint_for_recipient::*;
+pub use initialize_output_config::*;
+pub use set_compressed_output::*;
+pub use mint_from_burn_v3_compressed::*;
+pub use initialize_fee_vault::*;
+pub use claim_validator_fees::*;
+pub use set_fee_vault_authority::*;
+pub use set_validator_commission::*;
+pub use claim_validator_commission::*;
+pub use initialize_rate_limit::*;
+pub use set_rate_limit::*;
+pub use initialize_mint_pause::*;
+pub use pause_bridge::*;
+pub use unpause_bridge::*;
+pub use initialize_cosign_policy::*;
+pub use set_cosign_policy::*;
+pub use initialize_cpi_allowlist::*;
+pub use set_cpi_allowlist::*;
+pub use mint_and_call::*;
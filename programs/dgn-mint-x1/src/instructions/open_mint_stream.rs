@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::TokenAccount;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct OpenMintStream<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"dgn_mint_state"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + MintStream::INIT_SPACE,
+        seeds = [b"mint_stream", user.key().as_ref()],
+        bump
+    )]
+    pub mint_stream: Account<'info, MintStream>,
+
+    /// Where streamed mints are credited (any owner, must be a DGN account)
+    #[account(
+        constraint = recipient_token_account.mint == mint_state.dgn_mint @ MintError::InvalidRecipient
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Open a recipient stream: the user's verified burns may be minted by
+/// anyone, but only into `recipient_token_account`
+pub fn handler(ctx: Context<OpenMintStream>) -> Result<()> {
+    let stream = &mut ctx.accounts.mint_stream;
+    stream.user = ctx.accounts.user.key();
+    stream.recipient_token_account = ctx.accounts.recipient_token_account.key();
+    stream.burns_streamed = 0;
+    stream.total_streamed = 0;
+    stream.bump = ctx.bumps.mint_stream;
+
+    msg!("✓ Mint stream opened: {} -> {}", stream.user, stream.recipient_token_account);
+
+    Ok(())
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{
+    ID as LIGHT_CLIENT_ID, X1ChainId, X1ValidatorSet,
+    governance::{create_governance_message, verify_validator_set_approvals},
+    instructions::ValidatorUpdateSignature,
+};
+
+#[derive(Accounts)]
+pub struct PauseBridge<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"mint_pause"],
+        bump = mint_pause.bump
+    )]
+    pub mint_pause: Account<'info, MintPause>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct PauseBridgeParams {
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Stop every mint path of this program (validator governance)
+///
+/// Verified burns stay verified and mint normally after unpause_bridge.
+pub fn handler(ctx: Context<PauseBridge>, params: PauseBridgeParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let mint_pause = &mut ctx.accounts.mint_pause;
+
+    require!(!mint_pause.paused, MintError::MintPaused);
+
+    msg!("🛑 Pausing mints");
+
+    let message = create_mint_pause_message(
+        b"PAUSE_MINT",
+        validator_set.version,
+        mint_pause.nonce,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    mint_pause.nonce = mint_pause.nonce.checked_add(1).ok_or(MintError::Overflow)?;
+    mint_pause.paused = true;
+    mint_pause.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Mints paused");
+
+    Ok(())
+}
+
+/// Create deterministic message for pausing or unpausing this program
+///
+/// Format: hash(PAUSE_MINT | UNPAUSE_MINT || version || nonce || mint program)
+///
+/// The program id keeps an approval for one mint program from applying to another.
+pub(crate) fn create_mint_pause_message(
+    tag: &[u8],
+    validator_set_version: u64,
+    nonce: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(40);
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload.extend_from_slice(crate::ID.as_ref());
+
+    create_governance_message(tag, validator_set_version, &payload, x1_genesis_hash)
+}
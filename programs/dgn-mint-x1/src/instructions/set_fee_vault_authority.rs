@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetFeeVaultAuthority<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_vault", fee_vault.validator.as_ref()],
+        bump = fee_vault.bump,
+        has_one = authority @ MintError::Unauthorized
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Hand the claim authority to a new key, multisig or program PDA
+pub fn handler(ctx: Context<SetFeeVaultAuthority>, new_authority: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.fee_vault;
+    let old_authority = vault.authority;
+    vault.authority = new_authority;
+
+    emit!(FeeVaultAuthorityChanged {
+        validator: vault.validator,
+        old_authority,
+        new_authority,
+    });
+
+    msg!("✓ Fee vault authority for {}: {} -> {}", vault.validator, old_authority, new_authority);
+
+    Ok(())
+}
+
+#[event]
+pub struct FeeVaultAuthorityChanged {
+    pub validator: Pubkey,
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
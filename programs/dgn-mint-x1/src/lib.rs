@@ -3,8 +3,10 @@ use anchor_lang::prelude::*;
 pub mod instructions;
 pub mod state;
 pub mod errors;
+pub mod fees;
 
 use instructions::*;
+use state::FeeQuote;
 
 declare_id!("4YPipW8txxY3N7gHdj4NLhu8YxybHgarx5dJQCdCnQHs");
 
@@ -31,6 +33,70 @@ pub mod dgn_mint_x1 {
         instructions::mint_from_burn_v3::handler(ctx, burn_nonce, asset_id)
     }
 
+    /// Open the caller's fee vault (validator opt-in; `authority` signs claims)
+    pub fn initialize_fee_vault(ctx: Context<InitializeFeeVault>, authority: Pubkey) -> Result<()> {
+        instructions::initialize_fee_vault::handler(ctx, authority)
+    }
+
+    /// Claim accrued validator fees (all if `amount` is None); callable via CPI
+    pub fn claim_validator_fees(ctx: Context<ClaimValidatorFees>, amount: Option<u64>) -> Result<u64> {
+        instructions::claim_validator_fees::handler(ctx, amount)
+    }
+
+    /// Change a fee vault's claim authority
+    pub fn set_fee_vault_authority(ctx: Context<SetFeeVaultAuthority>, new_authority: Pubkey) -> Result<()> {
+        instructions::set_fee_vault_authority::handler(ctx, new_authority)
+    }
+
+    /// Set the commission taken from a fee vault's future fees (vault authority)
+    pub fn set_validator_commission(
+        ctx: Context<SetValidatorCommission>,
+        commission_bps: u16,
+        commission_recipient: Pubkey,
+    ) -> Result<()> {
+        instructions::set_validator_commission::handler(ctx, commission_bps, commission_recipient)
+    }
+
+    /// Claim accrued commission (all if `amount` is None); callable via CPI
+    pub fn claim_validator_commission(ctx: Context<ClaimValidatorCommission>, amount: Option<u64>) -> Result<u64> {
+        instructions::claim_validator_commission::handler(ctx, amount)
+    }
+
+    /// Create the large-mint co-sign policy (authority only, enabled)
+    pub fn initialize_cosign_policy(ctx: Context<InitializeCoSignPolicy>, cosigner: Pubkey, threshold: u64) -> Result<()> {
+        instructions::initialize_cosign_policy::handler(ctx, cosigner, threshold)
+    }
+
+    /// Update or disable the co-sign policy (authority only)
+    pub fn set_cosign_policy(ctx: Context<SetCoSignPolicy>, enabled: bool, cosigner: Pubkey, threshold: u64) -> Result<()> {
+        instructions::set_cosign_policy::handler(ctx, enabled, cosigner, threshold)
+    }
+
+    /// Create the mint rate limit (authority only): `cap` per `window_seconds`
+    pub fn initialize_rate_limit(ctx: Context<InitializeRateLimit>, window_seconds: i64, cap: u64) -> Result<()> {
+        instructions::initialize_rate_limit::handler(ctx, window_seconds, cap)
+    }
+
+    /// Adjust the rate limit cap (validator governance; increases are delayed one window)
+    pub fn set_rate_limit(ctx: Context<SetRateLimit>, params: SetRateLimitParams) -> Result<()> {
+        instructions::set_rate_limit::handler(ctx, params)
+    }
+
+    /// Create the mint pause switch (run once, permissionless; not paused)
+    pub fn initialize_mint_pause(ctx: Context<InitializeMintPause>) -> Result<()> {
+        instructions::initialize_mint_pause::handler(ctx)
+    }
+
+    /// Emergency stop of this program's mints (validator governance)
+    pub fn pause_bridge(ctx: Context<PauseBridge>, params: PauseBridgeParams) -> Result<()> {
+        instructions::pause_bridge::handler(ctx, params)
+    }
+
+    /// Resume this program's mints after a pause (validator governance)
+    pub fn unpause_bridge(ctx: Context<UnpauseBridge>, params: UnpauseBridgeParams) -> Result<()> {
+        instructions::unpause_bridge::handler(ctx, params)
+    }
+
     /// Create token metadata for DGN token
     ///
     /// This instruction creates Metaplex metadata for the DGN token.
@@ -43,4 +109,95 @@ pub mod dgn_mint_x1 {
     ) -> Result<()> {
         instructions::create_metadata::handler(ctx, name, symbol, uri)
     }
+
+    /// Open a recipient stream for the caller's verified burns
+    pub fn open_mint_stream(ctx: Context<OpenMintStream>) -> Result<()> {
+        instructions::open_mint_stream::handler(ctx)
+    }
+
+    /// Close the caller's recipient stream
+    pub fn close_mint_stream(ctx: Context<CloseMintStream>) -> Result<()> {
+        instructions::close_mint_stream::handler(ctx)
+    }
+
+    /// Close a minted burn's replay guard once it can never be verified
+    /// again (archived, past the burn age limit); rent goes to the user
+    pub fn close_processed_burn(ctx: Context<CloseProcessedBurn>, burn_nonce: u64) -> Result<()> {
+        instructions::close_processed_burn::handler(ctx, burn_nonce)
+    }
+
+    /// Mint a streamed user's verified burn into the stream recipient
+    /// (permissionless - the open stream is the user's authorization)
+    pub fn mint_to_stream<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintToStream<'info>>,
+        burn_nonce: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::mint_to_stream::handler(ctx, burn_nonce, asset_id)
+    }
+
+    /// Quote the X1-side cost of minting a burn of `amount` (read-only view)
+    pub fn quote_fees(ctx: Context<QuoteFees>, asset_id: u8, amount: u64) -> Result<FeeQuote> {
+        instructions::quote_fees::handler(ctx, asset_id, amount)
+    }
+
+    /// Mint a stealth-addressed burn to a recipient chosen by the stealth
+    /// key holder (ownership proven by Ed25519 signature, relayer pays)
+    pub fn mint_to_stealth<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintToStealth<'info>>,
+        burn_nonce: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::mint_to_stealth::handler(ctx, burn_nonce, asset_id)
+    }
+
+    /// Mint a designated-recipient burn for its recipient, keeping the
+    /// attested relayer fee (relayer pays; recipient needs no XNT)
+    pub fn mint_for_recipient<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintForRecipient<'info>>,
+        burn_nonce: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::mint_for_recipient::handler(ctx, burn_nonce, asset_id)
+    }
+
+    /// Create the output config (authority only, compressed output off)
+    pub fn initialize_output_config(ctx: Context<InitializeOutputConfig>) -> Result<()> {
+        instructions::initialize_output_config::handler(ctx)
+    }
+
+    /// Enable or disable compressed-token output (authority only)
+    pub fn set_compressed_output(ctx: Context<SetCompressedOutput>, enabled: bool) -> Result<()> {
+        instructions::set_compressed_output::handler(ctx, enabled)
+    }
+
+    /// Mint from a verified burn as a compressed token (V3)
+    pub fn mint_from_burn_v3_compressed<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintFromBurnV3Compressed<'info>>,
+        burn_nonce: u64,
+        asset_id: u8,
+    ) -> Result<()> {
+        instructions::mint_from_burn_v3_compressed::handler(ctx, burn_nonce, asset_id)
+    }
+
+    /// Create the CPI allowlist (run once, permissionless; empty)
+    pub fn initialize_cpi_allowlist(ctx: Context<InitializeCpiAllowlist>) -> Result<()> {
+        instructions::initialize_cpi_allowlist::handler(ctx)
+    }
+
+    /// Replace the programs mint_and_call may invoke (validator governance)
+    pub fn set_cpi_allowlist(ctx: Context<SetCpiAllowlist>, params: SetCpiAllowlistParams) -> Result<()> {
+        instructions::set_cpi_allowlist::handler(ctx, params)
+    }
+
+    /// Mint the caller's verified burn, then call an allowlisted program
+    /// with the minted tokens in the same transaction (e.g. stake or LP)
+    pub fn mint_and_call<'info>(
+        ctx: Context<'_, '_, '_, 'info, MintAndCall<'info>>,
+        burn_nonce: u64,
+        asset_id: u8,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        instructions::mint_and_call::handler(ctx, burn_nonce, asset_id, data)
+    }
 }
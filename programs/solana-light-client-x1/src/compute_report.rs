@@ -0,0 +1,47 @@
+//! Compute unit self-reporting
+//!
+//! Heavy instructions end by emitting ComputeUnitsReported, so dashboards
+//! can watch for CU drift as validator sets, attestation bundles and batches
+//! grow without replaying transactions. `consumed` covers the handler only
+//! (Anchor deserializes and checks accounts before it runs); `remaining` is
+//! what the instruction had left, i.e. how close it ran to its budget.
+//!
+//! The mint programs meter with ComputeMeter too and emit their own event
+//! of the same name and layout, so one decoder reads all three programs.
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::compute_units::sol_remaining_compute_units;
+
+/// Compute units left when a handler started
+pub struct ComputeMeter {
+    start: u64,
+}
+
+impl ComputeMeter {
+    pub fn start() -> Self {
+        Self { start: sol_remaining_compute_units() }
+    }
+
+    /// Units consumed since start, and units left
+    pub fn read(&self) -> (u64, u64) {
+        let remaining = sol_remaining_compute_units();
+        (self.start.saturating_sub(remaining), remaining)
+    }
+
+    /// Emit ComputeUnitsReported for `instruction` (its Anchor discriminator)
+    pub fn report(&self, instruction: [u8; 8], items: u8) {
+        let (consumed, remaining) = self.read();
+        emit!(ComputeUnitsReported { instruction, items, consumed, remaining });
+    }
+}
+
+/// Compute units one heavy instruction used
+#[event]
+pub struct ComputeUnitsReported {
+    /// Anchor discriminator of the reporting instruction
+    pub instruction: [u8; 8],
+    /// What the cost scales with: attestations or burns processed
+    pub items: u8,
+    pub consumed: u64,
+    pub remaining: u64,
+}
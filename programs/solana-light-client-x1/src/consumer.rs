@@ -0,0 +1,177 @@
+//! Helpers for programs that consume VerifiedBurnV3 proofs
+//!
+//! A mint program depends on this crate (`features = ["cpi"]`), takes the
+//! VerifiedBurnV3 as an unchecked account and calls assert_verified_burn
+//! before minting. Once its own processed_burn_v3 replay PDA exists, it
+//! calls mark_processed so the proof is flagged in the light client as well.
+//! The replay PDA must live under the mint program registered for the asset
+//! (AssetRegistry), with the seeds of processed_burn_v3_address.
+//! Burns a relayer verified for their recipient (relayer_claim_address) are
+//! only minted by mint_for_recipient, so the program also checks that the
+//! claim does not exist.
+//!
+//! ```ignore
+//! let burn = consumer::assert_verified_burn(&ctx.accounts.verified_burn, asset_id, &user, burn_nonce)?;
+//! RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+//! // ... init processed_burn, mint burn.amount ...
+//! consumer::mark_processed(
+//!     ctx.accounts.light_client_program.to_account_info(),
+//!     ctx.accounts.verified_burn.to_account_info(),
+//!     ctx.accounts.processed_burn.to_account_info(),
+//!     ctx.accounts.asset_registry.to_account_info(),
+//!     asset_id,
+//!     burn_nonce,
+//!     user,
+//! )?;
+//! ```
+
+use anchor_lang::prelude::*;
+use crate::errors::LightClientError;
+use crate::state::VerifiedBurnV3;
+
+/// VerifiedBurnV3 PDA: ["verified_burn_v3", asset_id, user, burn_nonce LE]
+pub fn verified_burn_v3_address(asset_id: u8, user: &Pubkey, burn_nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// RelayerClaim PDA: ["relayer_claim", asset_id, user, burn_nonce LE]
+///
+/// Mint paths other than mint_for_recipient pass it and call
+/// RelayerClaim::require_unclaimed, so a relayer's burn keeps its fee.
+pub fn relayer_claim_address(asset_id: u8, user: &Pubkey, burn_nonce: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+        ],
+        &crate::ID,
+    )
+}
+
+/// Mint-side replay PDA: ["processed_burn_v3", asset_id, burn_nonce LE, user]
+/// under the asset's mint program
+pub fn processed_burn_v3_address(mint_program: &Pubkey, asset_id: u8, burn_nonce: u64, user: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.as_ref(),
+        ],
+        mint_program,
+    )
+}
+
+/// Check that `info` is the unprocessed VerifiedBurnV3 of this burn and read it
+///
+/// Frozen burns and degraded-mode burns still held are processed, so they
+/// are rejected here too.
+pub fn assert_verified_burn(info: &AccountInfo, asset_id: u8, user: &Pubkey, burn_nonce: u64) -> Result<VerifiedBurnV3> {
+    let (expected, _) = verified_burn_v3_address(asset_id, user, burn_nonce);
+    require_keys_eq!(*info.key, expected, LightClientError::InvalidVerifiedBurn);
+    require_keys_eq!(*info.owner, crate::ID, LightClientError::InvalidVerifiedBurn);
+    let burn = VerifiedBurnV3::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require!(
+        burn.asset_id == asset_id && burn.user == *user && burn.burn_nonce == burn_nonce,
+        LightClientError::InvalidVerifiedBurn
+    );
+    require!(!burn.processed, LightClientError::BurnAlreadyProcessed);
+    Ok(burn)
+}
+
+/// Flag the burn processed in the light client (CPI to mark_burn_processed)
+///
+/// `processed_burn` must already exist, i.e. call this after the replay
+/// PDA's init.
+#[cfg(feature = "cpi")]
+pub fn mark_processed<'info>(
+    light_client_program: AccountInfo<'info>,
+    verified_burn: AccountInfo<'info>,
+    processed_burn: AccountInfo<'info>,
+    asset_registry: AccountInfo<'info>,
+    asset_id: u8,
+    burn_nonce: u64,
+    user: Pubkey,
+) -> Result<()> {
+    require_keys_eq!(*light_client_program.key, crate::ID, LightClientError::InvalidVerifiedBurn);
+    crate::cpi::mark_burn_processed(
+        CpiContext::new(
+            light_client_program,
+            crate::cpi::accounts::MarkBurnProcessed { verified_burn, processed_burn, asset_registry },
+        ),
+        asset_id,
+        burn_nonce,
+        user,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn burn(asset_id: u8, user: Pubkey, burn_nonce: u64, processed: bool) -> Vec<u8> {
+        let mut data = Vec::new();
+        VerifiedBurnV3 {
+            asset_id,
+            burn_nonce,
+            user,
+            amount: 1_000,
+            verified_at: 0,
+            processed,
+            bump: 255,
+            solana_burn_slot: 0,
+            verified_slot: 0,
+        }
+        .try_serialize(&mut data)
+        .unwrap();
+        data
+    }
+
+    #[test]
+    fn test_assert_verified_burn() {
+        let user = Pubkey::new_unique();
+        let (key, _) = verified_burn_v3_address(1, &user, 7);
+        let owner = crate::ID;
+        let mut lamports = 1;
+
+        let mut data = burn(1, user, 7, false);
+        let info = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(assert_verified_burn(&info, 1, &user, 7).unwrap().amount, 1_000);
+        // Another nonce, asset or user is another address
+        assert!(assert_verified_burn(&info, 1, &user, 8).is_err());
+        assert!(assert_verified_burn(&info, 2, &user, 7).is_err());
+        assert!(assert_verified_burn(&info, 1, &Pubkey::new_unique(), 7).is_err());
+
+        let mut lamports = 1;
+        let mut data = burn(1, user, 7, true);
+        let processed = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert!(assert_verified_burn(&processed, 1, &user, 7).is_err());
+
+        let spoofer = Pubkey::new_unique();
+        let mut lamports = 1;
+        let mut data = burn(1, user, 7, false);
+        let spoofed = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &spoofer, false, 0);
+        assert!(assert_verified_burn(&spoofed, 1, &user, 7).is_err());
+    }
+
+    #[test]
+    fn test_replay_pda_is_per_mint_program() {
+        let user = Pubkey::new_unique();
+        let xencat = crate::state::Asset::XENCAT.mint_program_id();
+        let dgn = crate::state::Asset::DGN.mint_program_id();
+        assert_ne!(
+            processed_burn_v3_address(&xencat, 1, 7, &user).0,
+            processed_burn_v3_address(&dgn, 1, 7, &user).0
+        );
+    }
+}
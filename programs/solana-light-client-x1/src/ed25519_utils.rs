@@ -1,19 +1,20 @@
 use anchor_lang::prelude::*;
-use anchor_lang::solana_program::sysvar::instructions::{load_instruction_at_checked, ID as IX_SYSVAR_ID};
+use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
 use anchor_lang::solana_program::ed25519_program;
 use crate::errors::LightClientError;
 
 /// Extract validator data from Ed25519 instruction
 ///
 /// Ed25519 instruction data format:
-/// [0-1]:   num_signatures (u16, little-endian)
+/// [0]:     num_signatures (u8, must be 1)
+/// [1]:     padding
 /// [2-3]:   signature_offset (u16, little-endian)
-/// [4-5]:   signature_instruction_index (u16, little-endian)
+/// [4-5]:   signature_instruction_index (u16, must be u16::MAX)
 /// [6-7]:   public_key_offset (u16, little-endian)
-/// [8-9]:   public_key_instruction_index (u16, little-endian)
+/// [8-9]:   public_key_instruction_index (u16, must be u16::MAX)
 /// [10-11]: message_data_offset (u16, little-endian)
 /// [12-13]: message_data_size (u16, little-endian)
-/// [14-15]: message_instruction_index (u16, little-endian)
+/// [14-15]: message_instruction_index (u16, must be u16::MAX)
 /// [16...]: data (signatures, public keys, message)
 ///
 /// Returns: (public_key, signature, message)
@@ -23,6 +24,17 @@ pub fn extract_ed25519_data(ix_data: &[u8]) -> Result<(Pubkey, [u8; 64], [u8; 32
         LightClientError::InvalidEd25519Instruction
     );
 
+    // One signature per instruction, with all data inline: an index other
+    // than u16::MAX makes the precompile verify bytes of ANOTHER
+    // instruction than the ones read below
+    require!(ix_data[0] == 1, LightClientError::InvalidEd25519Instruction);
+    for at in [4, 8, 14] {
+        require!(
+            u16::from_le_bytes([ix_data[at], ix_data[at + 1]]) == u16::MAX,
+            LightClientError::InvalidEd25519Instruction
+        );
+    }
+
     // Read offsets (little-endian u16)
     let sig_offset = u16::from_le_bytes([ix_data[2], ix_data[3]]) as usize;
     let pubkey_offset = u16::from_le_bytes([ix_data[6], ix_data[7]]) as usize;
@@ -60,39 +72,151 @@ pub fn extract_ed25519_data(ix_data: &[u8]) -> Result<(Pubkey, [u8; 64], [u8; 32
     let mut message = [0u8; 32];
     message.copy_from_slice(&ix_data[msg_offset..msg_offset + 32]);
 
+    // The precompile rejects mock signatures anyway
+    check_signature_encoding(&pubkey_bytes, &signature, false)?;
+
     Ok((pubkey, signature, message))
 }
 
-/// Load and parse Ed25519 instruction at given index
+/// Ed25519 group order L (little-endian); canonical signatures have S < L
+const GROUP_ORDER: [u8; 32] = [
+    0xed, 0xd3, 0xf5, 0x5c, 0x1a, 0x63, 0x12, 0x58, 0xd6, 0x9c, 0xf7, 0xa2, 0xde, 0xf9, 0xde, 0x14,
+    0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x10,
+];
+
+/// y-coordinates (sign bit cleared) of the small-order points, including
+/// the non-canonical encodings p and p+1 (libsodium's blocklist)
+const SMALL_ORDER_Y: [[u8; 32]; 7] = [
+    // 0 (order 4)
+    [0x00; 32],
+    // 1: identity (order 1)
+    [0x01, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+     0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00],
+    // order 8
+    [0x26, 0xe8, 0x95, 0x8f, 0xc2, 0xb2, 0x27, 0xb0, 0x45, 0xc3, 0xf4, 0x89, 0xf2, 0xef, 0x98, 0xf0,
+     0xd5, 0xdf, 0xac, 0x05, 0xd3, 0xc6, 0x33, 0x39, 0xb1, 0x38, 0x02, 0x88, 0x6d, 0x53, 0xfc, 0x05],
+    // order 8
+    [0xc7, 0x17, 0x6a, 0x70, 0x3d, 0x4d, 0xd8, 0x4f, 0xba, 0x3c, 0x0b, 0x76, 0x0d, 0x10, 0x67, 0x0f,
+     0x2a, 0x20, 0x53, 0xfa, 0x2c, 0x39, 0xcc, 0xc6, 0x4e, 0xc7, 0xfd, 0x77, 0x92, 0xac, 0x03, 0x7a],
+    // p - 1 (order 2)
+    [0xec, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f],
+    // p (= 0, order 4)
+    [0xed, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f],
+    // p + 1 (= 1, order 1)
+    [0xee, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff,
+     0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x7f],
+];
+
+/// Identity / low-order point, or a y-coordinate that is not reduced mod p
 ///
-/// Returns: (validator_pubkey, signature, vote_message)
-pub fn load_ed25519_instruction(
-    ix_index: usize,
-    instructions_sysvar: &AccountInfo,
-) -> Result<(Pubkey, [u8; 64], [u8; 32])> {
-    // Load instruction at index
-    let ed25519_ix = load_instruction_at_checked(ix_index, instructions_sysvar)?;
+/// Signatures "by" such keys verify for many messages, so no validator
+/// (or approver) may use one.
+pub fn is_weak_pubkey(pubkey: &[u8; 32]) -> bool {
+    let mut y = *pubkey;
+    y[31] &= 0x7f;
+    // y >= p = 2^255 - 19
+    let unreduced = y[31] == 0x7f && y[1..31].iter().all(|&b| b == 0xff) && y[0] >= 0xed;
+    unreduced || SMALL_ORDER_Y.contains(&y)
+}
+
+/// S < L (otherwise S + L is a second valid signature for the same message)
+pub fn is_canonical_signature(signature: &[u8; 64]) -> bool {
+    for i in (0..32).rev() {
+        let s = signature[32 + i];
+        if s != GROUP_ORDER[i] {
+            return s < GROUP_ORDER[i];
+        }
+    }
+    false // S == L
+}
 
-    // Verify it's Ed25519Program
+/// All-zero signature (the dev-mode mock)
+pub fn is_zero_signature(signature: &[u8; 64]) -> bool {
+    signature.iter().all(|&b| b == 0)
+}
+
+/// Reject mock, malleable and weak-key signatures before anything counts them
+///
+/// Applied to every attestation and governance approval, and to every
+/// Ed25519 instruction read by introspection. All-zero signatures (mocks
+/// for E2E tests) are only let through with `allow_mock`, which attestation
+/// submits take from DevModeConfig::mock_signatures_allowed, and never
+/// outside dev-mode builds.
+pub fn check_signature_encoding(pubkey: &[u8; 32], signature: &[u8; 64], allow_mock: bool) -> Result<()> {
     require!(
-        ed25519_ix.program_id == ed25519_program::ID,
+        !is_zero_signature(signature) || (cfg!(feature = "dev-mode") && allow_mock),
+        LightClientError::ZeroSignature
+    );
+
+    require!(!is_weak_pubkey(pubkey), LightClientError::WeakValidatorKey);
+    require!(is_canonical_signature(signature), LightClientError::NonCanonicalSignature);
+
+    Ok(())
+}
+
+/// (validator_pubkey, signature, vote_message) of one Ed25519 instruction
+pub type Ed25519Data = (Pubkey, [u8; 64], [u8; 32]);
+
+/// Load and parse the Ed25519 instructions a proof lists, in list order
+///
+/// Instructions are bound by the explicit index list, not by position:
+/// - every listed index is before the current instruction, strictly
+///   increasing (no instruction counted twice) and an Ed25519Program
+///   instruction with inline data
+/// - any other Ed25519 instruction anywhere in the transaction is rejected,
+///   so nothing can be sandwiched between or after the listed ones
+///
+pub fn load_bound_ed25519_instructions(
+    indices: &[u8],
+    instructions_sysvar: &AccountInfo,
+) -> Result<Vec<Ed25519Data>> {
+    let current = load_current_index_checked(instructions_sysvar)? as usize;
+    require!(
+        indices.windows(2).all(|pair| pair[0] < pair[1]),
         LightClientError::InvalidEd25519Instruction
     );
 
-    // Extract validator data from instruction
-    extract_ed25519_data(&ed25519_ix.data)
+    let mut extracted = Vec::with_capacity(indices.len());
+    for &index in indices {
+        require!(
+            (index as usize) < current,
+            LightClientError::InvalidEd25519Instruction
+        );
+        let ed25519_ix = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        require!(
+            ed25519_ix.program_id == ed25519_program::ID,
+            LightClientError::InvalidEd25519Instruction
+        );
+        extracted.push(extract_ed25519_data(&ed25519_ix.data)?);
+    }
+
+    let mut index = 0;
+    while let Ok(ix) = load_instruction_at_checked(index, instructions_sysvar) {
+        require!(
+            ix.program_id != ed25519_program::ID
+                || u8::try_from(index).is_ok_and(|index| indices.contains(&index)),
+            LightClientError::UnexpectedEd25519Instruction
+        );
+        index += 1;
+    }
+
+    Ok(extracted)
 }
 
-/// Create vote message hash from block hash and slot
+/// Create vote message hash from block hash, slot and state root
 ///
-/// Vote message format: keccak256(block_hash || slot)
-/// This proves validators attested to this specific block at this slot
-pub fn create_vote_message(block_hash: &[u8; 32], slot: u64) -> [u8; 32] {
+/// Vote message format: keccak256(block_hash || slot || state_root)
+/// This proves validators attested to this specific block at this slot,
+/// and to the state root the proof's Merkle path must reach
+pub fn create_vote_message(block_hash: &[u8; 32], slot: u64, state_root: &[u8; 32]) -> [u8; 32] {
     use anchor_lang::solana_program::keccak;
 
-    let mut data = Vec::with_capacity(40);
+    let mut data = Vec::with_capacity(72);
     data.extend_from_slice(block_hash);
     data.extend_from_slice(&slot.to_le_bytes());
+    data.extend_from_slice(state_root);
 
     keccak::hash(&data).to_bytes()
 }
@@ -100,22 +224,237 @@ pub fn create_vote_message(block_hash: &[u8; 32], slot: u64) -> [u8; 32] {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use xencat_test_utils::{Ed25519Ix, MockInstructions, MOCK_SIGNATURE};
 
     #[test]
     fn test_create_vote_message() {
         let block_hash = [1u8; 32];
         let slot = 12345u64;
-        let message = create_vote_message(&block_hash, slot);
+        let state_root = [2u8; 32];
+        let message = create_vote_message(&block_hash, slot, &state_root);
 
         // Vote message should be 32 bytes
         assert_eq!(message.len(), 32);
 
         // Same inputs should produce same output
-        let message2 = create_vote_message(&block_hash, slot);
+        let message2 = create_vote_message(&block_hash, slot, &state_root);
         assert_eq!(message, message2);
 
         // Different slot should produce different output
-        let message3 = create_vote_message(&block_hash, slot + 1);
+        let message3 = create_vote_message(&block_hash, slot + 1, &state_root);
         assert_ne!(message, message3);
+
+        // Different state root should produce different output
+        let message4 = create_vote_message(&block_hash, slot, &[3u8; 32]);
+        assert_ne!(message, message4);
+    }
+
+    #[test]
+    fn test_weak_pubkeys() {
+        let mut identity = [0u8; 32];
+        identity[0] = 1;
+        assert!(is_weak_pubkey(&identity));
+        assert!(is_weak_pubkey(&[0u8; 32]));
+
+        // Same point with the sign bit set
+        let mut order_8 = SMALL_ORDER_Y[2];
+        order_8[31] |= 0x80;
+        assert!(is_weak_pubkey(&order_8));
+
+        // Unreduced y (p + 5)
+        let mut unreduced = [0xff; 32];
+        unreduced[0] = 0xf2;
+        unreduced[31] = 0x7f;
+        assert!(is_weak_pubkey(&unreduced));
+
+        assert!(!is_weak_pubkey(&Pubkey::new_unique().to_bytes()));
+    }
+
+    #[test]
+    fn test_canonical_signature() {
+        let mut signature = [0x55u8; 64];
+        signature[32..].copy_from_slice(&GROUP_ORDER);
+        // S == L
+        assert!(!is_canonical_signature(&signature));
+
+        // S == L - 1
+        signature[32] -= 1;
+        assert!(is_canonical_signature(&signature));
+
+        // S == L + 2^248
+        signature[32..].copy_from_slice(&GROUP_ORDER);
+        signature[63] += 1;
+        assert!(!is_canonical_signature(&signature));
+
+        // Most real signatures have a small high byte
+        signature[63] = 0x0a;
+        assert!(is_canonical_signature(&signature));
+    }
+
+    #[test]
+    fn test_check_signature_encoding() {
+        let pubkey = Pubkey::new_unique().to_bytes();
+        let mut signature = [0x11u8; 64];
+        signature[63] = 0x01;
+        assert!(check_signature_encoding(&pubkey, &signature, false).is_ok());
+        assert!(check_signature_encoding(&[0u8; 32], &signature, false).is_err());
+
+        // Mocks need both the runtime guard and a dev-mode build
+        assert!(check_signature_encoding(&pubkey, &[0u8; 64], false).is_err());
+        assert_eq!(check_signature_encoding(&pubkey, &[0u8; 64], true).is_ok(), cfg!(feature = "dev-mode"));
+    }
+
+    fn is_error<T>(result: Result<T>, error: LightClientError) -> bool {
+        matches!(result, Err(anchor_lang::error::Error::AnchorError(e)) if e.error_code_number == u32::from(error))
+    }
+
+    fn ed25519(message: u8) -> (Pubkey, Vec<u8>) {
+        (ed25519_program::ID, Ed25519Ix::new(&[message; 32]).build())
+    }
+
+    /// Run load_bound_ed25519_instructions over `instructions`, with
+    /// `current` as the executing instruction
+    fn load_bound(instructions: &[(Pubkey, Vec<u8>)], current: u16, indices: &[u8]) -> Result<Vec<Ed25519Data>> {
+        let mut sysvar = instructions
+            .iter()
+            .cloned()
+            .fold(MockInstructions::new(), |mock, (program_id, data)| mock.instruction(program_id, data))
+            .current(current)
+            .build();
+        load_bound_ed25519_instructions(indices, &sysvar.account_info())
+    }
+
+    #[test]
+    fn test_extract_ed25519_data() {
+        let ix = Ed25519Ix::new(&[7u8; 32]);
+        let (pubkey, signature, message) = extract_ed25519_data(&ix.build()).unwrap();
+        assert_eq!(pubkey, ix.pubkey);
+        assert_eq!(signature, MOCK_SIGNATURE);
+        assert_eq!(message, [7u8; 32]);
+
+        // Offsets are followed wherever they point (fields in another order)
+        let mut reordered = Ed25519Ix::new(&[7u8; 32]);
+        reordered.message_data_offset = 16;
+        reordered.signature_offset = 48;
+        reordered.public_key_offset = 112;
+        let mut data = reordered.build();
+        data[16..48].copy_from_slice(&[7u8; 32]);
+        data[48..112].copy_from_slice(&MOCK_SIGNATURE);
+        data[112..144].copy_from_slice(reordered.pubkey.as_ref());
+        assert_eq!(extract_ed25519_data(&data).unwrap(), (reordered.pubkey, MOCK_SIGNATURE, [7u8; 32]));
+    }
+
+    #[test]
+    fn test_extract_ed25519_data_rejects_adversarial_headers() {
+        let valid = Ed25519Ix::new(&[7u8; 32]);
+        let data = valid.build();
+
+        // Truncated anywhere, header included
+        for len in 0..data.len() {
+            assert!(extract_ed25519_data(&data[..len]).is_err(), "truncated to {len}");
+        }
+
+        // Zero or several signatures (only the first would be read)
+        for count in [0u8, 2, u8::MAX] {
+            let ix = Ed25519Ix { num_signatures: count, ..valid.clone() };
+            assert!(is_error(extract_ed25519_data(&ix.build()), LightClientError::InvalidEd25519Instruction));
+        }
+
+        // Any instruction index but "inline", for each of the three fields
+        for index in 0..u16::MAX {
+            for field in 0..3 {
+                let mut ix = valid.clone();
+                *[
+                    &mut ix.signature_instruction_index,
+                    &mut ix.public_key_instruction_index,
+                    &mut ix.message_instruction_index,
+                ][field] = index;
+                assert!(is_error(extract_ed25519_data(&ix.build()), LightClientError::InvalidEd25519Instruction));
+            }
+        }
+
+        // Offsets past the end, up to u16::MAX (no overflow, no panic)
+        for (field, size) in [64u16, 32, 32].into_iter().enumerate() {
+            for offset in [data.len() as u16 - size + 1, data.len() as u16, u16::MAX] {
+                let mut ix = valid.clone();
+                *[&mut ix.signature_offset, &mut ix.public_key_offset, &mut ix.message_data_offset][field] = offset;
+                assert!(is_error(extract_ed25519_data(&ix.build()), LightClientError::InvalidEd25519Instruction));
+            }
+        }
+
+        // Message of any size but 32 (the precompile would verify those bytes)
+        for size in [0u16, 31, 33] {
+            let ix = Ed25519Ix { message_data_size: size, message: vec![7u8; size as usize], ..valid.clone() };
+            assert!(is_error(extract_ed25519_data(&ix.build()), LightClientError::InvalidMessageSize));
+        }
+        let ix = Ed25519Ix { message_data_size: u16::MAX, ..valid.clone() };
+        assert!(is_error(extract_ed25519_data(&ix.build()), LightClientError::InvalidEd25519Instruction));
+
+        // Encoding checks apply to introspected signatures too
+        assert!(is_error(
+            extract_ed25519_data(&valid.clone().signer(Pubkey::new_from_array([0u8; 32])).build()),
+            LightClientError::WeakValidatorKey
+        ));
+        let mut malleable = [0x55u8; 64];
+        malleable[32..].copy_from_slice(&GROUP_ORDER);
+        assert!(is_error(
+            extract_ed25519_data(&valid.clone().signature(malleable).build()),
+            LightClientError::NonCanonicalSignature
+        ));
+        assert!(is_error(
+            extract_ed25519_data(&valid.clone().signature([0u8; 64]).build()),
+            LightClientError::ZeroSignature
+        ));
+    }
+
+    #[test]
+    fn test_bound_ed25519_instructions() {
+        let light_client = (crate::ID, vec![0u8; 8]);
+
+        // Instructions found by index, not position (compute budget first)
+        let compute_budget = (Pubkey::new_unique(), vec![1u8]);
+        let txn = [compute_budget, ed25519(1), ed25519(2), light_client.clone()];
+        let extracted = load_bound(&txn, 3, &[1, 2]).unwrap();
+        assert_eq!(extracted.len(), 2);
+        assert_eq!(extracted[0].2, [1u8; 32]);
+        assert_eq!(extracted[1].2, [2u8; 32]);
+
+        // Unlisted Ed25519 instruction sandwiched between the listed ones, or after the current one
+        let sandwiched = [ed25519(1), ed25519(9), ed25519(2), light_client.clone()];
+        assert!(is_error(load_bound(&sandwiched, 3, &[0, 2]), LightClientError::UnexpectedEd25519Instruction));
+        let trailing = [ed25519(1), light_client.clone(), ed25519(9)];
+        assert!(is_error(load_bound(&trailing, 1, &[0]), LightClientError::UnexpectedEd25519Instruction));
+
+        // Same instruction listed twice, out of order, or not before the current one
+        let txn = [ed25519(1), ed25519(2), light_client.clone(), ed25519(3)];
+        for indices in [&[0u8, 0][..], &[1, 0], &[0, 1, 3], &[2], &[0, 200]] {
+            assert!(is_error(load_bound(&txn, 2, indices), LightClientError::InvalidEd25519Instruction));
+        }
+
+        // Listed instruction of another program carrying a valid-looking Ed25519 payload
+        let impostor = (Pubkey::new_unique(), Ed25519Ix::new(&[1u8; 32]).build());
+        assert!(is_error(
+            load_bound(&[impostor, light_client.clone()], 1, &[0]),
+            LightClientError::InvalidEd25519Instruction
+        ));
+
+        // Offsets into another instruction: the precompile verified other bytes
+        let foreign = Ed25519Ix { message_instruction_index: 0, ..Ed25519Ix::new(&[1u8; 32]) };
+        assert!(is_error(
+            load_bound(&[(ed25519_program::ID, foreign.build()), light_client.clone()], 1, &[0]),
+            LightClientError::InvalidEd25519Instruction
+        ));
+
+        // A spoofed sysvar account with the same contents
+        let mut spoofed = MockInstructions::new()
+            .instruction(ed25519_program::ID, Ed25519Ix::new(&[1u8; 32]).build())
+            .instruction(crate::ID, vec![0u8; 8])
+            .key(Pubkey::new_unique())
+            .build();
+        assert!(load_bound_ed25519_instructions(&[0], &spoofed.account_info()).is_err());
+
+        // No indices: nothing extracted, but stray Ed25519 instructions still rejected
+        assert!(load_bound(std::slice::from_ref(&light_client), 0, &[]).unwrap().is_empty());
+        assert!(is_error(load_bound(&[ed25519(1), light_client], 1, &[]), LightClientError::UnexpectedEd25519Instruction));
     }
 }
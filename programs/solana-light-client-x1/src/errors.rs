@@ -103,4 +103,280 @@ pub enum LightClientError {
 
     #[msg("Invalid attestation data - parameters don't match attestation fields")]
     InvalidAttestation,
+
+    #[msg("V2 instructions are deprecated - use V3 (re-attest lingering V2 burns with submit_burn_attestation_v3)")]
+    V2Deprecated,
+
+    #[msg("Invalid V2 cutoff slot - must be in the future and the current cutoff must not have passed")]
+    InvalidCutoffSlot,
+
+    #[msg("Verified burn already processed")]
+    BurnAlreadyProcessed,
+
+    #[msg("Solana chain state update must advance the Solana slot and not regress the epoch")]
+    StaleChainStateUpdate,
+
+    #[msg("Invalid Solana total stake - must be greater than 0")]
+    InvalidTotalStake,
+
+    #[msg("No enabled conversion rate for this asset pair")]
+    ConversionNotEnabled,
+
+    #[msg("Invalid conversion rate - assets must differ and numerator/denominator must be > 0")]
+    InvalidConversionRate,
+
+    #[msg("Conversion table is full")]
+    ConversionTableFull,
+
+    #[msg("Invalid replay tracker account - does not match the mint program PDA")]
+    InvalidReplayTracker,
+
+    #[msg("New validator set is smaller than the configured minimum validator count")]
+    ValidatorCountBelowMinimum,
+
+    #[msg("New threshold is below the configured minimum fraction of the validator set")]
+    ThresholdBelowMinimum,
+
+    #[msg("Invalid validator floor - counts must be > 0 and numerator <= denominator")]
+    InvalidValidatorFloor,
+
+    #[msg("New threshold violates BFT bounds - must be > 2/3 of the validators in BFT mode")]
+    ThresholdBelowBftBound,
+
+    #[msg("Resignation cooldown has not elapsed yet")]
+    ResignationCooldownActive,
+
+    #[msg("Resignation would leave the validator set below policy - use update_validator_set")]
+    ResignationViolatesPolicy,
+
+    #[msg("Validator set restore timelock has not elapsed yet")]
+    RestoreTimelockActive,
+
+    #[msg("Invalid validator set snapshot")]
+    InvalidValidatorSetSnapshot,
+
+    #[msg("Verified burn is still inside the retention window")]
+    RetentionWindowActive,
+
+    #[msg("Verified burn has not been minted - it cannot be archived")]
+    BurnNotMinted,
+
+    #[msg("Burn archive does not match the burn's asset or period")]
+    InvalidBurnArchive,
+
+    #[msg("Burn archive is full")]
+    BurnArchiveFull,
+
+    #[msg("Burn is not frozen or the freeze does not match the verified burn")]
+    BurnNotFrozen,
+
+    #[msg("Burn does not qualify for rent sponsorship")]
+    RentSponsorshipNotAllowed,
+
+    #[msg("Rent sponsor pool has too few lamports")]
+    InsufficientSponsorFunds,
+
+    #[msg("All-zero signature rejected")]
+    ZeroSignature,
+
+    #[msg("Validator key is the identity or a low-order point")]
+    WeakValidatorKey,
+
+    #[msg("Non-canonical signature (S is not reduced) - possible malleability")]
+    NonCanonicalSignature,
+
+    #[msg("This instruction is disabled by governance (feature gate)")]
+    FeatureDisabled,
+
+    #[msg("Invalid feature gate account")]
+    InvalidFeatureGate,
+
+    #[msg("Invalid bridge domain account")]
+    InvalidBridgeDomain,
+
+    #[msg("Bridge domain must be 1-32 characters of A-Z, 0-9 and '_'")]
+    InvalidDomainSeparator,
+
+    #[msg("Invalid deposit webhook: URL hash must be set")]
+    InvalidDepositWebhook,
+
+    #[msg("Event schema version must be higher than the registered one")]
+    EventSchemaVersionNotIncreasing,
+
+    #[msg("Event schema registry is full")]
+    EventSchemaRegistryFull,
+
+    #[msg("Sweep bounty pool has too few lamports")]
+    InsufficientSweepBountyFunds,
+
+    #[msg("Invalid degraded mode: need 2 <= quorum < threshold, 0 < per-transfer cap <= daily cap, and the minimum delay")]
+    InvalidDegradedMode,
+
+    #[msg("Degraded mode is not enabled for this asset")]
+    DegradedModeOff,
+
+    #[msg("Burn amount is above the degraded-mode per-transfer cap")]
+    DegradedTransferCapExceeded,
+
+    #[msg("Degraded-mode daily cap reached")]
+    DegradedDailyCapExceeded,
+
+    #[msg("Degraded-mode delay has not elapsed yet")]
+    DegradedDelayActive,
+
+    #[msg("Burn is frozen")]
+    BurnFrozen,
+
+    #[msg("Invalid X1 chain id account")]
+    InvalidX1ChainId,
+
+    #[msg("X1 genesis hash must not be zero")]
+    InvalidGenesisHash,
+
+    #[msg("Invalid return burn: not the mint program's ReturnBurnRecord for this nonce")]
+    InvalidReturnBurn,
+
+    #[msg("Asset is deactivated: no new attestations")]
+    AssetInactive,
+
+    #[msg("Asset id is already registered (only a deactivated asset with the same mint program can be re-registered)")]
+    AssetAlreadyRegistered,
+
+    #[msg("Asset registry is full")]
+    AssetRegistryFull,
+
+    #[msg("Invalid asset registry account")]
+    InvalidAssetRegistry,
+
+    #[msg("Confirmation depth is outside the allowed bounds")]
+    InvalidConfirmationDepth,
+
+    #[msg("Attested slot does not reach the burn's confirmation depth")]
+    ConfirmationDepthNotReached,
+
+    #[msg("Bridge is paused by validator governance")]
+    BridgePaused,
+
+    #[msg("Bridge is not paused")]
+    BridgeNotPaused,
+
+    #[msg("Usage epoch has not ended yet")]
+    UsageEpochNotEnded,
+
+    #[msg("Usage snapshot has more leaves than its tree holds")]
+    InvalidUsageSnapshot,
+
+    #[msg("Usage proof does not match the snapshot root")]
+    InvalidUsageProof,
+
+    #[msg("Attestation is older than the validator set's max_attestation_age")]
+    AttestationExpired,
+
+    #[msg("Attestation timestamp is in the future")]
+    AttestationFromFuture,
+
+    #[msg("Max attestation age is outside the allowed bounds")]
+    InvalidAttestationAge,
+
+    #[msg("Relayer fee must be below the burned amount")]
+    InvalidRelayerFee,
+
+    #[msg("Transaction has an Ed25519 instruction the proof does not list")]
+    UnexpectedEd25519Instruction,
+
+    #[msg("Asset is frozen by validator governance: no attestations or mints")]
+    AssetFrozen,
+
+    #[msg("Asset is not frozen")]
+    AssetNotFrozen,
+
+    #[msg("Burn is older than the burn age limit")]
+    BurnTooOld,
+
+    #[msg("Invalid burn age limit account, or a change that raises or removes the limit")]
+    InvalidBurnAgeLimit,
+
+    #[msg("Lookup table is not frozen and active, or misses a validator payout account")]
+    InvalidFeeLookupTable,
+
+    #[msg("Invalid challenge request account or slot hashes sysvar")]
+    InvalidChallengeRequest,
+
+    #[msg("Attestation was signed before the burn's challenge was posted")]
+    AttestationPredatesChallenge,
+
+    #[msg("Challenge was posted before the burn happened: close it and post a new one")]
+    ChallengePredatesBurn,
+
+    #[msg("Signers do not carry the weighted threshold of the validator set")]
+    InsufficientWeight,
+
+    #[msg("Invalid validator weights: one non-zero weight per validator and a threshold of at most 10000 bps")]
+    InvalidValidatorWeights,
+
+    #[msg("Invalid dev mode config account, or dev mode is not available in this build")]
+    InvalidDevModeConfig,
+
+    #[msg("Canary must sign, be a funded system account and match the marking")]
+    InvalidDevModeCanary,
+
+    #[msg("Validator set updates are timelocked: use propose_validator_set_update")]
+    ValidatorSetUpdateTimelocked,
+
+    #[msg("Validator set update timelock has not elapsed yet")]
+    ValidatorSetUpdateTimelockActive,
+
+    #[msg("Validator set changed since the update was proposed: propose it again")]
+    StaleValidatorSetUpdate,
+
+    #[msg("Invalid validator set timelock: the delay must be between 1 and 30 days")]
+    InvalidValidatorSetTimelock,
+
+    #[msg("Invalid validator set history account")]
+    InvalidValidatorSetHistory,
+
+    #[msg("Account is not the VerifiedBurnV3 of this asset, user and nonce")]
+    InvalidVerifiedBurn,
+
+    #[msg("Invalid notice: unknown severity, or no message hash")]
+    InvalidNotice,
+
+    #[msg("Invalid beta allowlist account, or a list that is too long, duplicated or empty while enabled")]
+    InvalidBetaAllowlist,
+
+    #[msg("Asset is in beta and this user is not on its allowlist")]
+    UserNotAllowlisted,
+
+    #[msg("Attestations must be in ascending validator set order")]
+    AttestationsOutOfOrder,
+
+    #[msg("The Wormhole VAA path is not enabled")]
+    VaaPathDisabled,
+
+    #[msg("Invalid Wormhole config account, or an enabled config without core bridge or emitter")]
+    InvalidWormholeConfig,
+
+    #[msg("Not a PostedVAA of the configured Wormhole core bridge")]
+    InvalidPostedVaa,
+
+    #[msg("VAA is not from the configured emitter, or below its consistency level")]
+    VaaEmitterMismatch,
+
+    #[msg("VAA payload is not a burn of this asset, nonce and user")]
+    InvalidVaaPayload,
+
+    #[msg("The burn was verified too recently to redirect its recipient")]
+    BurnRedirectTooEarly,
+
+    #[msg("Redirect recipient must be a new, non-default key")]
+    InvalidRedirectRecipient,
+
+    #[msg("Invalid validator latency stats account")]
+    InvalidLatencyStats,
+
+    #[msg("Validator set is not in the original layout (already migrated)")]
+    ValidatorSetAlreadyMigrated,
+
+    #[msg("A relayer verified this burn for its recipient; mint it with mint_for_recipient")]
+    BurnClaimedByRelayer,
 }
@@ -0,0 +1,197 @@
+use anchor_lang::prelude::*;
+use anchor_lang::AccountsClose;
+use crate::state::{
+    archive_leaf, archive_period, retention_elapsed, AssetRegistry, BurnArchive, RentSponsorPool, SponsoredRent, VerifiedBurnV3,
+};
+use crate::errors::LightClientError;
+
+/// Archive a minted VerifiedBurnV3 and close it (permissionless)
+///
+/// SECURITY:
+/// - Only burns with an existing mint-side processed_burn_v3 PDA can be
+///   closed; that PDA (never closed) stays the replay guard. Re-creating the
+///   VerifiedBurnV3 from old attestations yields an account that cannot mint
+/// - Converted-away burns have no replay PDA and are never archived, so
+///   their `processed` flag cannot be lost
+/// - Rent goes to the burn's user, not the caller; if the rent sponsor pool
+///   paid it (a SponsoredRent marker exists), it goes back to the pool
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64, period: u64)]
+pub struct ArchiveVerifiedBurn<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Receives the closed account's rent
+    /// CHECK: Must be the burn's user
+    #[account(mut, address = verified_burn.user @ LightClientError::InvalidAttestation)]
+    pub user: UncheckedAccount<'info>,
+
+    /// Mint-side replay tracker (must exist)
+    /// CHECK: Address, owner and non-emptiness checked in handler
+    pub processed_burn: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_archive", asset_id.to_le_bytes().as_ref(), period.to_le_bytes().as_ref()],
+        bump = burn_archive.bump,
+    )]
+    pub burn_archive: Account<'info, BurnArchive>,
+
+    /// Sponsorship marker (usually absent)
+    /// CHECK: Always passed at its PDA address so a sponsored burn cannot be
+    /// archived as unsponsored; owner and contents checked in handler
+    #[account(
+        mut,
+        seeds = [
+            b"sponsored_rent",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sponsored_rent: UncheckedAccount<'info>,
+
+    /// CHECK: Only deserialized when the burn was sponsored (may not exist)
+    #[account(mut, seeds = [b"rent_sponsor_pool"], bump)]
+    pub rent_sponsor_pool: UncheckedAccount<'info>,
+
+    /// Asset registry (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub signer: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<ArchiveVerifiedBurn>,
+    asset_id: u8,
+    burn_nonce: u64,
+    period: u64,
+) -> Result<()> {
+    archive_burn(ctx.accounts, asset_id, burn_nonce, period)
+}
+
+/// Checks, archives and closes the burn (shared with sweep_verified_burn)
+pub(crate) fn archive_burn<'info>(
+    accounts: &mut ArchiveVerifiedBurn<'info>,
+    asset_id: u8,
+    burn_nonce: u64,
+    period: u64,
+) -> Result<()> {
+    // Deactivated and frozen assets still archive: their minted burns are done
+    let mint_program = AssetRegistry::resolve(&accounts.asset_registry, asset_id)?.mint_program;
+    let burn = &accounts.verified_burn;
+
+    require!(
+        archive_period(burn.verified_slot) == period,
+        LightClientError::InvalidBurnArchive
+    );
+    require!(
+        retention_elapsed(burn.verified_slot, Clock::get()?.slot),
+        LightClientError::RetentionWindowActive
+    );
+
+    let (expected_tracker, _) = Pubkey::find_program_address(
+        &[
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            burn.user.as_ref(),
+        ],
+        &mint_program,
+    );
+    let tracker = &accounts.processed_burn;
+    require_keys_eq!(tracker.key(), expected_tracker, LightClientError::InvalidReplayTracker);
+    require!(
+        *tracker.owner == mint_program && !tracker.data_is_empty(),
+        LightClientError::BurnNotMinted
+    );
+
+    let leaf = archive_leaf(burn);
+    let archive = &mut accounts.burn_archive;
+    let leaf_index = archive.append(leaf).ok_or(LightClientError::BurnArchiveFull)?;
+    archive.total_amount = archive.total_amount
+        .checked_add(burn.amount)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    emit!(BurnArchived {
+        asset_id,
+        period,
+        leaf_index,
+        burn_nonce,
+        user: burn.user,
+        amount: burn.amount,
+        verified_at: burn.verified_at,
+        solana_burn_slot: burn.solana_burn_slot,
+        verified_slot: burn.verified_slot,
+        root: archive.root,
+    });
+
+    msg!("🗄️ Archived burn {} (asset {}) as leaf {} of period {}", burn_nonce, asset_id, leaf_index, period);
+
+    let marker = accounts.sponsored_rent.to_account_info();
+    if marker.owner == &crate::ID && !marker.data_is_empty() {
+        reimburse_sponsor_pool(&accounts.verified_burn, &marker, &accounts.rent_sponsor_pool)?;
+    } else {
+        accounts.verified_burn.close(accounts.user.to_account_info())?;
+    }
+
+    Ok(())
+}
+
+/// Close a sponsored burn and its marker into the rent sponsor pool
+fn reimburse_sponsor_pool<'info>(
+    verified_burn: &Account<'info, VerifiedBurnV3>,
+    marker: &AccountInfo<'info>,
+    pool_info: &AccountInfo<'info>,
+) -> Result<()> {
+    // Validates the marker's discriminator
+    SponsoredRent::try_deserialize(&mut &marker.try_borrow_data()?[..])?;
+    require_keys_eq!(*pool_info.owner, crate::ID, ErrorCode::AccountOwnedByWrongProgram);
+    let mut pool = RentSponsorPool::try_deserialize(&mut &pool_info.try_borrow_data()?[..])?;
+
+    let returned = verified_burn.to_account_info().lamports() + marker.lamports();
+    verified_burn.close(pool_info.clone())?;
+
+    **pool_info.try_borrow_mut_lamports()? += marker.lamports();
+    **marker.try_borrow_mut_lamports()? = 0;
+    marker.assign(&System::id());
+    marker.realloc(0, false)?;
+
+    pool.total_reimbursed = pool.total_reimbursed
+        .checked_add(returned)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    pool.outstanding = pool.outstanding.saturating_sub(1);
+    pool.try_serialize(&mut &mut pool_info.try_borrow_mut_data()?[..])?;
+
+    msg!("🎁 Returned {} lamports of sponsored rent to the pool", returned);
+
+    Ok(())
+}
+
+/// Emitted for every archived burn; the fields are the Merkle leaf preimage
+#[event]
+pub struct BurnArchived {
+    pub asset_id: u8,
+    pub period: u64,
+    pub leaf_index: u64,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub verified_at: i64,
+    pub solana_burn_slot: u64,
+    pub verified_slot: u64,
+    /// Archive root after appending this leaf
+    pub root: [u8; 32],
+}
@@ -0,0 +1,110 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, ProgramHashRegistry, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct AttestProgramHash<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"program_hash_registry"],
+        bump = program_hash_registry.bump
+    )]
+    pub program_hash_registry: Account<'info, ProgramHashRegistry>,
+
+    /// Signer submitting the attestation (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct AttestProgramHashParams {
+    /// Program the build is deployed as
+    pub program: Pubkey,
+
+    /// Release version (major, minor, patch)
+    pub version: [u16; 3],
+
+    /// sha256 of the verifiable build (see xencat-verify-build)
+    pub hash: [u8; 32],
+
+    /// Signatures from current validators approving this hash
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Attest the expected hash of an audited program build (validator governance)
+///
+/// Each validator reproduces the build with `xencat-verify-build` before
+/// signing, so an approval means "this source, built deterministically,
+/// gives this binary".
+pub fn handler(ctx: Context<AttestProgramHash>, params: AttestProgramHashParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let registry = &mut ctx.accounts.program_hash_registry;
+
+    let [major, minor, patch] = params.version;
+    msg!("🔏 Attesting build of {} v{}.{}.{}", params.program, major, minor, patch);
+
+    let message = create_program_hash_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params.program,
+        params.version,
+        &params.hash,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    if let Some(previous) = registry.expected_hash(&params.program, params.version) {
+        msg!("   ⚠️  Replacing previously attested hash {:?}", previous);
+    }
+    registry.attest(params.program, params.version, params.hash, Clock::get()?.slot);
+
+    msg!("✅ Program hash attested");
+
+    Ok(())
+}
+
+/// Create deterministic message for a program hash attestation
+///
+/// Format: hash(ATTEST_PROGRAM_HASH || version || config_nonce || program || major || minor || patch || build_hash)
+fn create_program_hash_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    program: &Pubkey,
+    version: [u16; 3],
+    build_hash: &[u8; 32],
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(78);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(program.as_ref());
+    for part in version {
+        payload.extend_from_slice(&part.to_le_bytes());
+    }
+    payload.extend_from_slice(build_hash);
+
+    create_governance_message(b"ATTEST_PROGRAM_HASH", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::state::ValidatorResignation;
+
+#[derive(Accounts)]
+pub struct CancelValidatorResignation<'info> {
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    #[account(
+        mut,
+        close = validator,
+        has_one = validator,
+        seeds = [b"validator_resignation", validator.key().as_ref()],
+        bump = resignation.bump
+    )]
+    pub resignation: Account<'info, ValidatorResignation>,
+}
+
+/// Withdraw a pending resignation (rent refunded)
+pub fn handler(ctx: Context<CancelValidatorResignation>) -> Result<()> {
+    msg!("✓ Resignation of {} cancelled", ctx.accounts.validator.key());
+
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, ValidatorSetCheckpoint, PendingValidatorSetRestore, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_threshold_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct CancelValidatorSetRestore<'info> {
+    #[account(
+        seeds = [b"validator_set_checkpoint"],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, ValidatorSetCheckpoint>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Rent goes back to the proposer
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [b"validator_set_restore"],
+        bump = pending_restore.bump
+    )]
+    pub pending_restore: Account<'info, PendingValidatorSetRestore>,
+
+    /// CHECK: Rent receiver, bound to the pending restore by has_one
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Signer submitting the cancellation (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CancelValidatorSetRestoreParams {
+    /// Signatures from checkpoint validators approving the cancellation
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Cancel a scheduled validator set restore during its timelock
+pub fn handler(
+    ctx: Context<CancelValidatorSetRestore>,
+    params: CancelValidatorSetRestoreParams,
+) -> Result<()> {
+    let checkpoint = &ctx.accounts.checkpoint;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let pending = &ctx.accounts.pending_restore;
+
+    msg!("🛑 Cancelling validator set restore scheduled at slot {}", pending.scheduled_slot);
+
+    // Format: hash(CANCEL_VALIDATOR_SET_RESTORE || checkpoint_version || config_nonce || scheduled_slot)
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&bridge_config.nonce.to_le_bytes());
+    payload.extend_from_slice(&pending.scheduled_slot.to_le_bytes());
+    let message = create_governance_message(
+        b"CANCEL_VALIDATOR_SET_RESTORE",
+        checkpoint.version,
+        &payload,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+
+    verify_threshold_approvals(
+        &params.approver_signatures,
+        &checkpoint.validators,
+        checkpoint.threshold,
+        &message,
+    )?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("✅ Restore cancelled");
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, PendingValidatorSetUpdate, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct CancelValidatorSetUpdate<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Rent goes back to the proposer
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [b"validator_set_update"],
+        bump = pending_update.bump
+    )]
+    pub pending_update: Account<'info, PendingValidatorSetUpdate>,
+
+    /// CHECK: Rent receiver, bound to the pending update by has_one
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Signer submitting the cancellation (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CancelValidatorSetUpdateParams {
+    /// Signatures from current validators approving the cancellation
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Cancel a proposed validator set update (requires threshold signatures
+/// from the current set)
+///
+/// Also the way to clear a proposal that went stale because the set
+/// changed before it executed.
+pub fn handler(
+    ctx: Context<CancelValidatorSetUpdate>,
+    params: CancelValidatorSetUpdateParams,
+) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let pending = &ctx.accounts.pending_update;
+
+    msg!("🛑 Cancelling validator set update proposed at {}", pending.proposed_at);
+
+    // Format: hash(CANCEL_VALIDATOR_SET_UPDATE || version || config_nonce || proposed_at)
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&bridge_config.nonce.to_le_bytes());
+    payload.extend_from_slice(&pending.proposed_at.to_le_bytes());
+    let message = create_governance_message(
+        b"CANCEL_VALIDATOR_SET_UPDATE",
+        validator_set.version,
+        &payload,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("✅ Update cancelled");
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::ChallengeRequest;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct CloseAttestationChallenge<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [
+            b"challenge_request",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = challenge_request.bump
+    )]
+    pub challenge_request: Account<'info, ChallengeRequest>,
+}
+
+/// Close a burn's challenge (rent refunded)
+///
+/// After verification it is no longer needed. Before, closing it drops
+/// the requirement, or lets the burner post a fresh one.
+pub fn handler(_ctx: Context<CloseAttestationChallenge>, asset_id: u8, burn_nonce: u64) -> Result<()> {
+    msg!("✓ Attestation challenge closed for burn {} (asset {})", burn_nonce, asset_id);
+
+    Ok(())
+}
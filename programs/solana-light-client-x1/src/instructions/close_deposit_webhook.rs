@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+use crate::state::DepositWebhook;
+
+#[derive(Accounts)]
+pub struct CloseDepositWebhook<'info> {
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        close = recipient,
+        seeds = [b"deposit_webhook", recipient.key().as_ref()],
+        bump = deposit_webhook.bump
+    )]
+    pub deposit_webhook: Account<'info, DepositWebhook>,
+}
+
+/// Stop deposit notifications and return the rent to the recipient
+pub fn handler(ctx: Context<CloseDepositWebhook>) -> Result<()> {
+    msg!("🔕 Deposit webhook closed for {}", ctx.accounts.recipient.key());
+
+    Ok(())
+}
@@ -0,0 +1,123 @@
+use anchor_lang::prelude::*;
+use crate::state::{usage_epoch, UsageSnapshot, X1ValidatorSet, USAGE_TREE_DEPTH, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+/// Commit an epoch's usage root (validator governance)
+///
+/// SECURITY:
+/// - Each validator rebuilds the tree from the epoch's BurnVerified events
+///   before signing, so a threshold approval vouches for the root
+/// - One snapshot per epoch (init), and only for an epoch that has ended,
+///   so a root can neither be replaced nor cover burns still to come
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct CommitUsageSnapshot<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + UsageSnapshot::INIT_SPACE,
+        seeds = [b"usage_snapshot", epoch.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub usage_snapshot: Account<'info, UsageSnapshot>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct CommitUsageSnapshotParams {
+    /// Merkle root over the epoch's usage_leaf entries
+    pub root: [u8; 32],
+
+    /// Number of leaves (user/asset pairs)
+    pub leaf_count: u64,
+
+    /// Signatures from current validators approving this root
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(ctx: Context<CommitUsageSnapshot>, epoch: u64, params: CommitUsageSnapshotParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let slot = Clock::get()?.slot;
+
+    msg!("📸 Committing usage snapshot of epoch {}", epoch);
+    msg!("   Leaves: {}", params.leaf_count);
+
+    require!(epoch < usage_epoch(slot), LightClientError::UsageEpochNotEnded);
+    require!(
+        params.leaf_count <= 1u64 << USAGE_TREE_DEPTH,
+        LightClientError::InvalidUsageSnapshot
+    );
+
+    let message = create_usage_snapshot_message(
+        validator_set.version,
+        epoch,
+        &params.root,
+        params.leaf_count,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    let snapshot = &mut ctx.accounts.usage_snapshot;
+    snapshot.epoch = epoch;
+    snapshot.root = params.root;
+    snapshot.leaf_count = params.leaf_count;
+    snapshot.committed_slot = slot;
+    snapshot.bump = ctx.bumps.usage_snapshot;
+
+    emit!(UsageSnapshotCommitted {
+        epoch,
+        root: params.root,
+        leaf_count: params.leaf_count,
+        committed_slot: slot,
+    });
+
+    msg!("✅ Usage snapshot committed");
+
+    Ok(())
+}
+
+/// Create deterministic message for a usage snapshot
+///
+/// Format: hash(USAGE_SNAPSHOT || version || epoch || root || leaf_count)
+///
+/// No governance nonce: the per-epoch PDA already makes it single use, and
+/// snapshots do not race with other proposals.
+fn create_usage_snapshot_message(
+    validator_set_version: u64,
+    epoch: u64,
+    root: &[u8; 32],
+    leaf_count: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(48);
+    payload.extend_from_slice(&epoch.to_le_bytes());
+    payload.extend_from_slice(root);
+    payload.extend_from_slice(&leaf_count.to_le_bytes());
+
+    create_governance_message(b"USAGE_SNAPSHOT", validator_set_version, &payload, x1_genesis_hash)
+}
+
+#[event]
+pub struct UsageSnapshotCommitted {
+    pub epoch: u64,
+    pub root: [u8; 32],
+    pub leaf_count: u64,
+    pub committed_slot: u64,
+}
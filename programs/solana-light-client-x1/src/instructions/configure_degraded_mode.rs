@@ -0,0 +1,136 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, DegradedMode, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct ConfigureDegradedMode<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"degraded_mode", asset_id.to_le_bytes().as_ref()],
+        bump = degraded_mode.bump
+    )]
+    pub degraded_mode: Account<'info, DegradedMode>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureDegradedModeParams {
+    pub enabled: bool,
+
+    /// Valid attestations accepted while degraded (2 <= quorum < threshold)
+    pub quorum: u8,
+
+    /// Largest burn accepted while degraded (token base units)
+    pub max_transfer_amount: u64,
+
+    /// Total burn amount accepted per UTC day while degraded
+    pub daily_cap: u64,
+
+    /// Hold before a degraded burn can be minted (>= MIN_DEGRADED_DELAY_SECONDS)
+    pub delay_seconds: i64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Configure (or disable) an asset's degraded mode (validator governance)
+///
+/// Needs the full threshold: it is set up ahead of an outage, not during one.
+/// Disabling skips the value checks so it always goes through.
+pub fn handler(
+    ctx: Context<ConfigureDegradedMode>,
+    asset_id: u8,
+    params: ConfigureDegradedModeParams,
+) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔄 Configuring degraded mode for asset {}", asset_id);
+    msg!("   Enabled: {}", params.enabled);
+    msg!("   Quorum: {} (threshold {})", params.quorum, validator_set.threshold);
+    msg!("   Per transfer: {}, per day: {}", params.max_transfer_amount, params.daily_cap);
+    msg!("   Delay: {}s", params.delay_seconds);
+
+    if params.enabled {
+        require!(
+            DegradedMode::is_valid_config(
+                params.quorum,
+                validator_set.threshold,
+                params.max_transfer_amount,
+                params.daily_cap,
+                params.delay_seconds,
+            ),
+            LightClientError::InvalidDegradedMode
+        );
+    }
+
+    let message = create_degraded_mode_message(
+        validator_set.version,
+        bridge_config.nonce,
+        asset_id,
+        &params,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let mode = &mut ctx.accounts.degraded_mode;
+    mode.enabled = params.enabled;
+    mode.quorum = params.quorum;
+    mode.max_transfer_amount = params.max_transfer_amount;
+    mode.daily_cap = params.daily_cap;
+    mode.delay_seconds = params.delay_seconds;
+
+    msg!("✅ Degraded mode updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a degraded mode change
+///
+/// Format: hash(CONFIGURE_DEGRADED_MODE || version || config_nonce || asset_id || enabled
+///              || quorum || max_transfer_amount || daily_cap || delay_seconds)
+fn create_degraded_mode_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    asset_id: u8,
+    params: &ConfigureDegradedModeParams,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(35);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(asset_id);
+    payload.push(params.enabled as u8);
+    payload.push(params.quorum);
+    payload.extend_from_slice(&params.max_transfer_amount.to_le_bytes());
+    payload.extend_from_slice(&params.daily_cap.to_le_bytes());
+    payload.extend_from_slice(&params.delay_seconds.to_le_bytes());
+
+    create_governance_message(b"CONFIGURE_DEGRADED_MODE", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, RentSponsorPool, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct ConfigureRentSponsorPool<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"rent_sponsor_pool"],
+        bump = rent_sponsor_pool.bump
+    )]
+    pub rent_sponsor_pool: Account<'info, RentSponsorPool>,
+
+    /// CHECK: Receives withdrawn lamports; bound by the signed message
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureRentSponsorPoolParams {
+    /// Largest sponsored burn amount in token base units (0 = sponsorship off)
+    pub max_burn_amount: u64,
+
+    /// Lamports to withdraw to `recipient` (0 = none)
+    pub withdraw_lamports: u64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Set the sponsorship limit and/or withdraw pool funds (validator governance)
+///
+/// Withdrawals never take the pool below its own rent-exempt minimum.
+pub fn handler(ctx: Context<ConfigureRentSponsorPool>, params: ConfigureRentSponsorPoolParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔄 Configuring rent sponsor pool");
+    msg!("   Max burn amount: {} -> {}", ctx.accounts.rent_sponsor_pool.max_burn_amount, params.max_burn_amount);
+    msg!("   Withdraw: {} lamports", params.withdraw_lamports);
+
+    let message = create_rent_sponsor_pool_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.max_burn_amount,
+        params.withdraw_lamports,
+        &ctx.accounts.recipient.key(),
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    if params.withdraw_lamports > 0 {
+        let pool_info = ctx.accounts.rent_sponsor_pool.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+        require!(
+            RentSponsorPool::available(pool_info.lamports(), rent_floor) >= params.withdraw_lamports,
+            LightClientError::InsufficientSponsorFunds
+        );
+        **pool_info.try_borrow_mut_lamports()? -= params.withdraw_lamports;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += params.withdraw_lamports;
+    }
+
+    ctx.accounts.rent_sponsor_pool.max_burn_amount = params.max_burn_amount;
+
+    msg!("✅ Rent sponsor pool updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a rent sponsor pool change
+///
+/// Format: hash(CONFIGURE_RENT_SPONSOR_POOL || version || config_nonce || max_burn_amount || withdraw_lamports || recipient)
+fn create_rent_sponsor_pool_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    max_burn_amount: u64,
+    withdraw_lamports: u64,
+    recipient: &Pubkey,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(56);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&max_burn_amount.to_le_bytes());
+    payload.extend_from_slice(&withdraw_lamports.to_le_bytes());
+    payload.extend_from_slice(recipient.as_ref());
+
+    create_governance_message(
+        b"CONFIGURE_RENT_SPONSOR_POOL",
+        validator_set_version,
+        &payload,
+        x1_genesis_hash,
+    )
+}
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, SweepBountyPool, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct ConfigureSweepBountyPool<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"sweep_bounty_pool"],
+        bump = sweep_bounty_pool.bump
+    )]
+    pub sweep_bounty_pool: Account<'info, SweepBountyPool>,
+
+    /// CHECK: Receives withdrawn lamports; bound by the signed message
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ConfigureSweepBountyPoolParams {
+    /// Lamports paid per swept burn (0 = no bounty)
+    pub bounty_lamports: u64,
+
+    /// Lamports to withdraw to `recipient` (0 = none)
+    pub withdraw_lamports: u64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Set the sweep bounty and/or withdraw pool funds (validator governance)
+///
+/// Withdrawals never take the pool below its own rent-exempt minimum.
+pub fn handler(ctx: Context<ConfigureSweepBountyPool>, params: ConfigureSweepBountyPoolParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔄 Configuring sweep bounty pool");
+    msg!("   Bounty: {} -> {} lamports", ctx.accounts.sweep_bounty_pool.bounty_lamports, params.bounty_lamports);
+    msg!("   Withdraw: {} lamports", params.withdraw_lamports);
+
+    let message = create_sweep_bounty_pool_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.bounty_lamports,
+        params.withdraw_lamports,
+        &ctx.accounts.recipient.key(),
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    if params.withdraw_lamports > 0 {
+        let pool_info = ctx.accounts.sweep_bounty_pool.to_account_info();
+        let rent_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+        require!(
+            pool_info.lamports().saturating_sub(rent_floor) >= params.withdraw_lamports,
+            LightClientError::InsufficientSweepBountyFunds
+        );
+        **pool_info.try_borrow_mut_lamports()? -= params.withdraw_lamports;
+        **ctx.accounts.recipient.try_borrow_mut_lamports()? += params.withdraw_lamports;
+    }
+
+    ctx.accounts.sweep_bounty_pool.bounty_lamports = params.bounty_lamports;
+
+    msg!("✅ Sweep bounty pool updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a sweep bounty pool change
+///
+/// Format: hash(CONFIGURE_SWEEP_BOUNTY_POOL || version || config_nonce || bounty_lamports || withdraw_lamports || recipient)
+fn create_sweep_bounty_pool_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    bounty_lamports: u64,
+    withdraw_lamports: u64,
+    recipient: &Pubkey,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(56);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&bounty_lamports.to_le_bytes());
+    payload.extend_from_slice(&withdraw_lamports.to_le_bytes());
+    payload.extend_from_slice(recipient.as_ref());
+
+    create_governance_message(
+        b"CONFIGURE_SWEEP_BOUNTY_POOL",
+        validator_set_version,
+        &payload,
+        x1_genesis_hash,
+    )
+}
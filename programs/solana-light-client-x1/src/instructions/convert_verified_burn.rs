@@ -0,0 +1,187 @@
+use anchor_lang::prelude::*;
+use crate::state::{VerifiedBurnV3, ConversionTable, AssetRegistry, FeatureGate, BetaAllowlist, RelayerClaim, gates};
+use crate::errors::LightClientError;
+
+/// Convert a verified burn of one asset into a verified burn of another
+///
+/// Example: burn XENCAT on Solana, then convert the XENCAT VerifiedBurnV3
+/// into a DGN VerifiedBurnV3 at the governance-configured rate and mint DGN
+/// with the regular dgn-mint-x1 mint_from_burn_v3 (no mint program changes).
+///
+/// SECURITY:
+/// - Source burn must be unprocessed and belong to the signer
+/// - Neither asset may be frozen (a frozen asset neither leaves nor
+///   receives value through a conversion), and the target must be active
+///   (the conversion attests a new burn of it)
+/// - Source asset's mint-side replay PDA must not exist (the mint program
+///   cannot persist `processed` on light-client accounts, so this is the
+///   authoritative "not minted yet" check)
+/// - Source must not have a RelayerClaim (the relayer's fee is only paid by
+///   mint_for_recipient)
+/// - Source is marked processed in the same instruction (one conversion,
+///   no mint of the source asset afterwards)
+/// - Burn nonces are global on Solana, so the target PDA
+///   ["verified_burn_v3", to_asset, user, nonce] can never collide with a
+///   genuine attestation; `init` also prevents converting twice or back
+#[derive(Accounts)]
+#[instruction(from_asset_id: u8, to_asset_id: u8, burn_nonce: u64)]
+pub struct ConvertVerifiedBurn<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"conversion_table"],
+        bump = conversion_table.bump
+    )]
+    pub conversion_table: Account<'info, ConversionTable>,
+
+    /// Verified burn being converted (source asset namespace)
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            from_asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = source_verified_burn.bump,
+        constraint = !source_verified_burn.processed @ LightClientError::BurnAlreadyProcessed,
+        constraint = source_verified_burn.user == user.key() @ LightClientError::InvalidAttestation,
+        constraint = source_verified_burn.asset_id == from_asset_id @ LightClientError::InvalidAsset,
+    )]
+    pub source_verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Source asset's mint-side replay tracker (must NOT exist)
+    /// CHECK: Address derived and emptiness checked in handler
+    ///        (owner program is from_asset_id's registered mint program)
+    pub source_processed_burn: UncheckedAccount<'info>,
+
+    /// Relayer claim of the source burn (must NOT exist)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            from_asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    /// New verified burn in the target asset namespace
+    #[account(
+        init,
+        payer = user,
+        space = 8 + VerifiedBurnV3::INIT_SPACE,
+        seeds = [
+            b"verified_burn_v3",
+            to_asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub target_verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Target asset's beta allowlist (not initialized = permissionless)
+    /// CHECK: Address pinned by seeds; read by BetaAllowlist::enforce
+    #[account(seeds = [b"beta_allowlist", to_asset_id.to_le_bytes().as_ref()], bump)]
+    pub beta_allowlist: UncheckedAccount<'info>,
+
+    /// Asset registry (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<ConvertVerifiedBurn>,
+    from_asset_id: u8,
+    to_asset_id: u8,
+    burn_nonce: u64,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::CONVERT)?;
+
+    msg!("🔀 Converting verified burn");
+    msg!("   Burn nonce: {}", burn_nonce);
+    msg!("   Asset: {} -> {}", from_asset_id, to_asset_id);
+
+    let from_asset = AssetRegistry::resolve(&ctx.accounts.asset_registry, from_asset_id)?;
+    require!(!from_asset.frozen, LightClientError::AssetFrozen);
+    AssetRegistry::resolve_active(&ctx.accounts.asset_registry, to_asset_id)?;
+    BetaAllowlist::enforce(&ctx.accounts.beta_allowlist, &ctx.accounts.user.key())?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
+    let rate = *ctx.accounts.conversion_table
+        .rate(from_asset_id, to_asset_id)
+        .ok_or(LightClientError::ConversionNotEnabled)?;
+
+    // Source must not have been minted already (mint-side replay PDA)
+    let (expected_tracker, _) = Pubkey::find_program_address(
+        &[
+            b"processed_burn_v3",
+            from_asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            ctx.accounts.user.key().as_ref(),
+        ],
+        &from_asset.mint_program,
+    );
+    require_keys_eq!(
+        ctx.accounts.source_processed_burn.key(),
+        expected_tracker,
+        LightClientError::InvalidReplayTracker
+    );
+    require!(
+        ctx.accounts.source_processed_burn.data_is_empty(),
+        LightClientError::BurnAlreadyProcessed
+    );
+
+    let source = &mut ctx.accounts.source_verified_burn;
+    let amount_out = rate.convert(source.amount).ok_or(LightClientError::InvalidConversionRate)?;
+
+    let target = &mut ctx.accounts.target_verified_burn;
+    target.asset_id = to_asset_id;
+    target.burn_nonce = burn_nonce;
+    target.user = source.user;
+    target.amount = amount_out;
+    target.verified_at = source.verified_at; // Preserve original verification time
+    target.processed = false;
+    target.bump = ctx.bumps.target_verified_burn;
+    target.solana_burn_slot = source.solana_burn_slot;
+    target.verified_slot = source.verified_slot;
+
+    // Retire the source (prevents minting both assets)
+    source.processed = true;
+
+    emit!(VerifiedBurnConverted {
+        burn_nonce,
+        user: source.user,
+        from_asset_id,
+        to_asset_id,
+        amount_in: source.amount,
+        amount_out,
+    });
+
+    msg!("✅ Converted {} -> {} (rate {}/{})", source.amount, amount_out, rate.numerator, rate.denominator);
+
+    Ok(())
+}
+
+/// Emitted when a verified burn is converted to another asset
+#[event]
+pub struct VerifiedBurnConverted {
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub from_asset_id: u8,
+    pub to_asset_id: u8,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::invariants;
+use crate::state::{X1ValidatorSet, ValidatorSetCheckpoint, PendingValidatorSetRestore, restored_version};
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+pub struct ExecuteValidatorSetRestore<'info> {
+    /// CHECK: Possibly corrupted, so it is not deserialized. The address is
+    /// pinned by the seeds and the owner check keeps it our PDA.
+    #[account(
+        mut,
+        seeds = [b"x1_validator_set_v2"],
+        bump,
+        owner = crate::ID
+    )]
+    pub validator_set: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_set_checkpoint"],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, ValidatorSetCheckpoint>,
+
+    /// Rent goes back to the proposer
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [b"validator_set_restore"],
+        bump = pending_restore.bump
+    )]
+    pub pending_restore: Account<'info, PendingValidatorSetRestore>,
+
+    /// CHECK: Rent receiver, bound to the pending restore by has_one
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Anyone can execute once the timelock has elapsed; pays any top-up
+    /// rent if the validator set account has to grow back to full size
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Rewrite the validator set PDA from the scheduled snapshot (permissionless)
+///
+/// The account is overwritten byte-for-byte (discriminator included) so it
+/// does not need to deserialize first. The version is bumped past both the
+/// snapshot and the checkpoint, invalidating every older signature.
+pub fn handler(ctx: Context<ExecuteValidatorSetRestore>) -> Result<()> {
+    let pending = &ctx.accounts.pending_restore;
+
+    require!(
+        Clock::get()?.slot >= pending.executable_slot,
+        LightClientError::RestoreTimelockActive
+    );
+
+    let checkpoint = &mut ctx.accounts.checkpoint;
+    let new_version = restored_version(pending.snapshot_version, checkpoint.version)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let restored = X1ValidatorSet {
+        version: new_version,
+        validators: pending.validators.clone(),
+        threshold: pending.threshold,
+        bump: ctx.bumps.validator_set,
+        // The old value may be corrupted too: back to the default
+        max_attestation_age: 0,
+        // Snapshots carry no weights: the restored set is unweighted
+        weights: vec![],
+        threshold_bps: 0,
+        // Back to accepting any order, like max_attestation_age
+        ordered_attestations: false,
+    };
+
+    let info = ctx.accounts.validator_set.to_account_info();
+    let space = 8 + X1ValidatorSet::INIT_SPACE;
+    if info.data_len() < space {
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    ctx.accounts.system_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.payer.to_account_info(),
+                        to: info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        info.realloc(space, false)?;
+    }
+
+    {
+        let mut data = info.try_borrow_mut_data()?;
+        data.fill(0);
+        let mut writer: &mut [u8] = &mut data;
+        restored.try_serialize(&mut writer)?;
+    }
+
+    invariants::debug_check(invariants::version_monotonic(checkpoint.version, new_version));
+    checkpoint.version = restored.version;
+    checkpoint.validators = restored.validators.clone();
+    checkpoint.threshold = restored.threshold;
+    checkpoint.checkpoint_slot = Clock::get()?.slot;
+
+    emit!(ValidatorSetRestored {
+        snapshot_version: pending.snapshot_version,
+        new_version,
+        validator_count: restored.validators.len() as u8,
+        threshold: restored.threshold,
+    });
+
+    msg!("✅ Validator set restored from snapshot version {}", pending.snapshot_version);
+    msg!("   New version: {}", new_version);
+    msg!("   Validators: {}, threshold {}", restored.validators.len(), restored.threshold);
+
+    Ok(())
+}
+
+/// Event emitted when a validator set is restored from a snapshot
+#[event]
+pub struct ValidatorSetRestored {
+    pub snapshot_version: u64,
+    pub new_version: u64,
+    pub validator_count: u8,
+    pub threshold: u8,
+}
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, PendingValidatorSetUpdate, X1ValidatorSet};
+use crate::instructions::{apply_update, check_update_policy, UpdateValidatorSetParams};
+
+#[derive(Accounts)]
+pub struct ExecuteValidatorSetUpdate<'info> {
+    #[account(
+        mut,
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// The floor is checked again: it may have been raised meanwhile
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Rent goes back to the proposer
+    #[account(
+        mut,
+        close = proposer,
+        has_one = proposer,
+        seeds = [b"validator_set_update"],
+        bump = pending_update.bump
+    )]
+    pub pending_update: Account<'info, PendingValidatorSetUpdate>,
+
+    /// CHECK: Rent receiver, bound to the pending update by has_one
+    #[account(mut)]
+    pub proposer: UncheckedAccount<'info>,
+
+    /// Audit trail of updates (not initialized = not recorded)
+    /// CHECK: Address pinned by seeds; written by X1ValidatorSetHistory::append
+    #[account(mut, seeds = [b"x1_validator_set_history"], bump)]
+    pub validator_set_history: UncheckedAccount<'info>,
+
+    /// Anyone can execute once the timelock has elapsed; pays any top-up
+    /// rent if the validator set account has to grow for weights
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Apply a proposed validator set update once its delay has passed (permissionless)
+pub fn handler(ctx: Context<ExecuteValidatorSetUpdate>) -> Result<()> {
+    let pending = &ctx.accounts.pending_update;
+
+    msg!("🔄 Executing validator set update proposed at {}", pending.proposed_at);
+
+    pending.check_executable(ctx.accounts.validator_set.version, Clock::get()?.unix_timestamp)?;
+
+    let params = UpdateValidatorSetParams {
+        new_validators: pending.validators.clone(),
+        new_threshold: pending.threshold,
+        new_weights: pending.weights.clone(),
+        new_threshold_bps: pending.threshold_bps,
+        approver_signatures: vec![],
+    };
+    check_update_policy(&params, &ctx.accounts.bridge_config)?;
+
+    apply_update(
+        &mut ctx.accounts.validator_set,
+        &ctx.accounts.validator_set_history,
+        &ctx.accounts.payer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        params,
+        pending.approvers.clone(),
+    )?;
+
+    Ok(())
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::invariants;
+use crate::state::{X1ValidatorSet, X1ValidatorSetHistory, X1ValidatorSetRecord, BridgeConfig, ValidatorResignation, resignation_threshold, weakens_safety};
+use crate::errors::LightClientError;
+use crate::instructions::ValidatorSetSafetyWeakened;
+
+#[derive(Accounts)]
+pub struct FinalizeValidatorResignation<'info> {
+    #[account(
+        mut,
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Pending resignation; rent goes back to the validator
+    #[account(
+        mut,
+        close = validator,
+        has_one = validator,
+        seeds = [b"validator_resignation", validator.key().as_ref()],
+        bump = resignation.bump
+    )]
+    pub resignation: Account<'info, ValidatorResignation>,
+
+    /// Audit trail of updates (not initialized = not recorded)
+    /// CHECK: Address pinned by seeds; written by X1ValidatorSetHistory::append
+    #[account(mut, seeds = [b"x1_validator_set_history"], bump)]
+    pub validator_set_history: UncheckedAccount<'info>,
+
+    /// CHECK: Rent receiver, bound to the resignation by has_one
+    #[account(mut)]
+    pub validator: UncheckedAccount<'info>,
+
+    /// Anyone can finalize once the cooldown has elapsed
+    pub signer: Signer<'info>,
+}
+
+/// Remove a resigned validator after the cooldown (permissionless)
+///
+/// The threshold is kept when possible, otherwise adjusted to the
+/// smallest value the BridgeConfig policy allows. The version is bumped
+/// exactly like update_validator_set, so pending attestations for the old
+/// version must be re-collected. The removal is recorded in the history
+/// with the resigning validator as the only approver.
+pub fn handler(ctx: Context<FinalizeValidatorResignation>) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+    let validator_key = ctx.accounts.validator.key();
+    let validator_set = &mut ctx.accounts.validator_set;
+
+    require!(
+        current_slot >= ctx.accounts.resignation.effective_slot,
+        LightClientError::ResignationCooldownActive
+    );
+
+    let index = validator_set.validators
+        .iter()
+        .position(|v| *v == validator_key)
+        .ok_or(LightClientError::ValidatorNotInSet)?;
+
+    let new_count = validator_set.validators.len() - 1;
+    let new_threshold = resignation_threshold(
+        &ctx.accounts.bridge_config,
+        validator_set.threshold,
+        new_count,
+    ).ok_or(LightClientError::ResignationViolatesPolicy)?;
+
+    let old_threshold = validator_set.threshold;
+    let new_version = validator_set.version
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    // Always a smaller set, but keep the same check as the other paths
+    if weakens_safety(
+        validator_set.validators.len(),
+        old_threshold,
+        new_count,
+        new_threshold,
+    ) {
+        msg!("⚠️  Resignation weakens validator set safety");
+        emit!(ValidatorSetSafetyWeakened {
+            old_version: validator_set.version,
+            new_version,
+            old_validator_count: validator_set.validators.len() as u8,
+            old_threshold,
+            new_validator_count: new_count as u8,
+            new_threshold,
+        });
+    }
+
+    let old_validators_hash = X1ValidatorSet::members_hash(&validator_set.validators, &validator_set.weights);
+    validator_set.remove_validator(index);
+    validator_set.threshold = new_threshold;
+
+    let clock = Clock::get()?;
+    X1ValidatorSetHistory::append(&ctx.accounts.validator_set_history, X1ValidatorSetRecord {
+        version: new_version,
+        old_validators_hash,
+        new_validators_hash: X1ValidatorSet::members_hash(&validator_set.validators, &validator_set.weights),
+        threshold: new_threshold,
+        threshold_bps: validator_set.threshold_bps,
+        approvers: vec![validator_key],
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+    })?;
+    invariants::debug_check(invariants::version_monotonic(validator_set.version, new_version));
+    validator_set.version = new_version;
+
+    emit!(ValidatorResigned {
+        validator: validator_key,
+        new_version,
+        old_threshold,
+        new_threshold,
+        validator_count: new_count as u8,
+    });
+
+    msg!("✅ Validator {} removed", validator_key);
+    msg!("   Threshold: {} -> {} of {}", old_threshold, new_threshold, new_count);
+    msg!("   New version: {}", new_version);
+
+    Ok(())
+}
+
+/// Event emitted when a resignation is finalized
+#[event]
+pub struct ValidatorResigned {
+    pub validator: Pubkey,
+    pub new_version: u64,
+    pub old_threshold: u8,
+    pub new_threshold: u8,
+    pub validator_count: u8,
+}
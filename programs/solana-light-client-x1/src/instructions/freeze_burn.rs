@@ -0,0 +1,208 @@
+use anchor_lang::prelude::*;
+use crate::state::{freeze_quorum, AssetRegistry, BridgeConfig, BurnFreeze, VerifiedBurnV3, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_threshold_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+/// Emergency freeze of one verified burn (fast guardian quorum)
+///
+/// SECURITY:
+/// - Needs only `freeze_quorum` validator approvals (> 1/3, ≤ threshold),
+///   so guardians can act before a flagged burn is minted
+/// - Only blocks: the burn is marked processed, nothing is moved or minted
+/// - The mint-side replay PDA must not exist (burn not minted yet)
+/// - A degraded-mode burn still in its delay (already held) can be frozen too
+/// - Undone only by unfreeze_burn with full threshold approvals
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64, user: Pubkey)]
+pub struct FreezeBurn<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Degraded-mode hold of this burn (exists only while held)
+    /// CHECK: Address pinned by seeds; emptiness checked in handler
+    #[account(
+        seeds = [
+            b"degraded_burn",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub degraded_burn: UncheckedAccount<'info>,
+
+    /// Mint-side replay tracker (must NOT exist)
+    /// CHECK: Address derived and emptiness checked in handler
+    pub processed_burn: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets); deactivated
+    /// assets can still be frozen, their verified burns still mint
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BurnFreeze::INIT_SPACE,
+        seeds = [
+            b"burn_freeze",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub burn_freeze: Account<'info, BurnFreeze>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct FreezeBurnParams {
+    /// Off-chain incident reference (e.g. sha256 of the report)
+    pub reason: [u8; 32],
+
+    /// Signatures from current validators (at least freeze_quorum)
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(
+    ctx: Context<FreezeBurn>,
+    asset_id: u8,
+    burn_nonce: u64,
+    user: Pubkey,
+    params: FreezeBurnParams,
+) -> Result<()> {
+    let mint_program = AssetRegistry::resolve(&ctx.accounts.asset_registry, asset_id)?.mint_program;
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🧊 Freezing burn {} (asset {})", burn_nonce, asset_id);
+
+    require!(
+        !ctx.accounts.verified_burn.processed || !ctx.accounts.degraded_burn.data_is_empty(),
+        LightClientError::BurnAlreadyProcessed
+    );
+
+    let (expected_tracker, _) = Pubkey::find_program_address(
+        &[
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.as_ref(),
+        ],
+        &mint_program,
+    );
+    require_keys_eq!(
+        ctx.accounts.processed_burn.key(),
+        expected_tracker,
+        LightClientError::InvalidReplayTracker
+    );
+    require!(
+        ctx.accounts.processed_burn.data_is_empty(),
+        LightClientError::BurnAlreadyProcessed
+    );
+
+    let message = create_freeze_message(
+        b"FREEZE_BURN",
+        validator_set.version,
+        bridge_config.nonce,
+        &ctx.accounts.verified_burn,
+        &params.reason,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    let quorum = freeze_quorum(validator_set.validators.len(), validator_set.threshold);
+    verify_threshold_approvals(&params.approver_signatures, &validator_set.validators, quorum, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    // Held at processed = true until unfreeze_burn
+    ctx.accounts.verified_burn.processed = true;
+
+    let freeze = &mut ctx.accounts.burn_freeze;
+    freeze.asset_id = asset_id;
+    freeze.burn_nonce = burn_nonce;
+    freeze.user = user;
+    freeze.reason = params.reason;
+    freeze.frozen_slot = Clock::get()?.slot;
+    freeze.payer = ctx.accounts.payer.key();
+    freeze.bump = ctx.bumps.burn_freeze;
+
+    emit!(BurnFrozen {
+        asset_id,
+        burn_nonce,
+        user,
+        amount: ctx.accounts.verified_burn.amount,
+        reason: params.reason,
+        approvals: params.approver_signatures.len() as u8,
+    });
+
+    msg!("✅ Burn frozen (quorum {} of {})", quorum, validator_set.validators.len());
+
+    Ok(())
+}
+
+/// Create deterministic message for burn freeze / unfreeze
+///
+/// Format: hash(tag || version || config_nonce || asset_id || burn_nonce || user || reason)
+pub(crate) fn create_freeze_message(
+    tag: &[u8],
+    validator_set_version: u64,
+    config_nonce: u64,
+    burn: &VerifiedBurnV3,
+    reason: &[u8; 32],
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(81);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(burn.asset_id);
+    payload.extend_from_slice(&burn.burn_nonce.to_le_bytes());
+    payload.extend_from_slice(burn.user.as_ref());
+    payload.extend_from_slice(reason);
+
+    create_governance_message(tag, validator_set_version, &payload, x1_genesis_hash)
+}
+
+#[event]
+pub struct BurnFrozen {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub reason: [u8; 32],
+    pub approvals: u8,
+}
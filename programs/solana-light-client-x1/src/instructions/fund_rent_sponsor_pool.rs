@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::RentSponsorPool;
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+pub struct FundRentSponsorPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"rent_sponsor_pool"],
+        bump = rent_sponsor_pool.bump
+    )]
+    pub rent_sponsor_pool: Account<'info, RentSponsorPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add lamports to the rent sponsor pool (anyone; only governance can withdraw)
+pub fn handler(ctx: Context<FundRentSponsorPool>, lamports: u64) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.rent_sponsor_pool.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+
+    let pool = &mut ctx.accounts.rent_sponsor_pool;
+    pool.total_funded = pool.total_funded
+        .checked_add(lamports)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("💰 Rent sponsor pool funded with {} lamports", lamports);
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::state::SweepBountyPool;
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+pub struct FundSweepBountyPool<'info> {
+    #[account(
+        mut,
+        seeds = [b"sweep_bounty_pool"],
+        bump = sweep_bounty_pool.bump
+    )]
+    pub sweep_bounty_pool: Account<'info, SweepBountyPool>,
+
+    #[account(mut)]
+    pub funder: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Add lamports to the sweep bounty pool (anyone; only governance can withdraw)
+pub fn handler(ctx: Context<FundSweepBountyPool>, lamports: u64) -> Result<()> {
+    transfer(
+        CpiContext::new(
+            ctx.accounts.system_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.funder.to_account_info(),
+                to: ctx.accounts.sweep_bounty_pool.to_account_info(),
+            },
+        ),
+        lamports,
+    )?;
+
+    let pool = &mut ctx.accounts.sweep_bounty_pool;
+    pool.total_funded = pool.total_funded
+        .checked_add(lamports)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("💰 Sweep bounty pool funded with {} lamports", lamports);
+
+    Ok(())
+}
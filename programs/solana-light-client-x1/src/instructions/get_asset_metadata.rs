@@ -0,0 +1,13 @@
+use anchor_lang::prelude::*;
+use crate::state::{Asset, AssetMetadata};
+
+/// Read-only; its only account is the system program, so any client can simulate it
+#[derive(Accounts)]
+pub struct GetAssetMetadata<'info> {
+    pub system_program: Program<'info, System>,
+}
+
+/// Asset display metadata view (returned as instruction return data)
+pub fn handler(_ctx: Context<GetAssetMetadata>, asset_id: u8) -> Result<AssetMetadata> {
+    Ok(Asset::from_u8(asset_id)?.metadata())
+}
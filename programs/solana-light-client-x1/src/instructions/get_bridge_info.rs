@@ -0,0 +1,27 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeCapabilities, BridgeConfig, X1ValidatorSet};
+
+/// Read-only: nothing is written, so it can be simulated by any client
+#[derive(Accounts)]
+pub struct GetBridgeInfo<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+}
+
+/// Capability discovery view (returned as instruction return data)
+pub fn handler(ctx: Context<GetBridgeInfo>) -> Result<BridgeCapabilities> {
+    Ok(BridgeCapabilities::current(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.validator_set,
+        Clock::get()?.slot,
+    ))
+}
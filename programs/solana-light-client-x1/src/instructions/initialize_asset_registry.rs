@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::AssetRegistry;
+
+#[derive(Accounts)]
+pub struct InitializeAssetRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + AssetRegistry::INIT_SPACE,
+        seeds = [b"asset_registry"],
+        bump
+    )]
+    pub asset_registry: Account<'info, AssetRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the asset registry (run once, permissionless)
+///
+/// Starts with the built-in assets, so creating it changes nothing until
+/// governance registers or deactivates an asset.
+pub fn handler(ctx: Context<InitializeAssetRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.asset_registry;
+    registry.assets = AssetRegistry::builtin(Clock::get()?.slot);
+    registry.bump = ctx.bumps.asset_registry;
+
+    msg!("✅ Asset registry created ({} assets)", registry.assets.len());
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{Asset, BetaAllowlist};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeBetaAllowlist<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BetaAllowlist::INIT_SPACE,
+        seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub beta_allowlist: Account<'info, BetaAllowlist>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create an asset's beta allowlist (run once per asset, permissionless)
+///
+/// Starts disabled (permissionless) until governance sets the list.
+pub fn handler(ctx: Context<InitializeBetaAllowlist>, asset_id: u8) -> Result<()> {
+    let asset = Asset::from_u8(asset_id)?;
+
+    let list = &mut ctx.accounts.beta_allowlist;
+    list.asset_id = asset_id;
+    list.enabled = false;
+    list.users = Vec::new();
+    list.updated_slot = Clock::get()?.slot;
+    list.bump = ctx.bumps.beta_allowlist;
+
+    msg!("✅ Beta allowlist created for {:?} (disabled)", asset);
+
+    Ok(())
+}
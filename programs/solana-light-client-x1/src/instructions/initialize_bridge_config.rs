@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::BridgeConfig;
+
+#[derive(Accounts)]
+pub struct InitializeBridgeConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BridgeConfig::INIT_SPACE,
+        seeds = [b"bridge_config"],
+        bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize bridge configuration with safe defaults (run once)
+///
+/// Permissionless: defaults carry no authority, every later change
+/// requires threshold validator approval.
+pub fn handler(ctx: Context<InitializeBridgeConfig>) -> Result<()> {
+    msg!("🔧 Initializing bridge configuration");
+
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    bridge_config.v2_cutoff_slot = 0; // V2 active until governance schedules cutoff
+    bridge_config.nonce = 0;
+    bridge_config.min_validator_count = BridgeConfig::DEFAULT_MIN_VALIDATOR_COUNT;
+    bridge_config.min_threshold_numerator = BridgeConfig::DEFAULT_MIN_THRESHOLD_NUMERATOR;
+    bridge_config.min_threshold_denominator = BridgeConfig::DEFAULT_MIN_THRESHOLD_DENOMINATOR;
+    bridge_config.bft_mode = false; // governance opts in via set_validator_floor
+    bridge_config.bump = ctx.bumps.bridge_config;
+
+    msg!("✅ Bridge configuration initialized");
+
+    Ok(())
+}
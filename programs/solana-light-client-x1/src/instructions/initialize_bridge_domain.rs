@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, BridgeDomain, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct InitializeBridgeDomain<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BridgeDomain::INIT_SPACE,
+        seeds = [b"bridge_domain"],
+        bump
+    )]
+    pub bridge_domain: Account<'info, BridgeDomain>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeBridgeDomainParams {
+    /// Domain separator for this deployment (e.g. "XENCAT_X1_BRIDGE_DEVNET_V1")
+    pub domain: String,
+
+    /// Signatures from current validators approving this domain
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Set this deployment's attestation domain separator (run once)
+///
+/// Needs threshold approval: the validators are the ones who sign with the
+/// domain, and an open init could be front-run with another environment's
+/// value. Cannot be changed afterwards, since every outstanding
+/// attestation would silently stop verifying.
+pub fn handler(ctx: Context<InitializeBridgeDomain>, params: InitializeBridgeDomainParams) -> Result<()> {
+    require!(
+        BridgeDomain::is_valid_domain(&params.domain),
+        LightClientError::InvalidDomainSeparator
+    );
+
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔧 Initializing bridge domain: {}", params.domain);
+
+    let message = create_bridge_domain_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params.domain,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let bridge_domain = &mut ctx.accounts.bridge_domain;
+    bridge_domain.domain = params.domain;
+    bridge_domain.bump = ctx.bumps.bridge_domain;
+
+    msg!("✅ Bridge domain set");
+
+    Ok(())
+}
+
+/// Create deterministic message for the bridge domain
+///
+/// Format: hash(INIT_BRIDGE_DOMAIN || version || config_nonce || domain)
+fn create_bridge_domain_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    domain: &str,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(8 + domain.len());
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(domain.as_bytes());
+
+    create_governance_message(b"INIT_BRIDGE_DOMAIN", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeCapabilities, BridgeConfig, BridgeInfo, X1ValidatorSet};
+
+#[derive(Accounts)]
+pub struct InitializeBridgeInfo<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BridgeInfo::INIT_SPACE,
+        seeds = [b"bridge_info"],
+        bump
+    )]
+    pub bridge_info: Account<'info, BridgeInfo>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the BridgeInfo capability account (run once, permissionless)
+pub fn handler(ctx: Context<InitializeBridgeInfo>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let bridge_info = &mut ctx.accounts.bridge_info;
+
+    bridge_info.capabilities = BridgeCapabilities::current(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.validator_set,
+        slot,
+    );
+    bridge_info.updated_slot = slot;
+    bridge_info.bump = ctx.bumps.bridge_info;
+
+    let [major, minor, patch] = bridge_info.capabilities.bridge_version;
+    msg!("✅ Bridge info created: v{}.{}.{}, features {:#x}", major, minor, patch, bridge_info.capabilities.features);
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::BurnAgeLimit;
+
+#[derive(Accounts)]
+pub struct InitializeBurnAgeLimit<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BurnAgeLimit::INIT_SPACE,
+        seeds = [b"burn_age_limit"],
+        bump
+    )]
+    pub burn_age_limit: Account<'info, BurnAgeLimit>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the burn age limit (run once, permissionless)
+///
+/// Starts with no limit, same as before the account existed.
+pub fn handler(ctx: Context<InitializeBurnAgeLimit>) -> Result<()> {
+    let limit = &mut ctx.accounts.burn_age_limit;
+    limit.max_burn_age = 0;
+    limit.updated_slot = Clock::get()?.slot;
+    limit.bump = ctx.bumps.burn_age_limit;
+
+    msg!("✅ Burn age limit created (no limit)");
+
+    Ok(())
+}
@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{Asset, BurnArchive, ARCHIVE_TREE_DEPTH};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8, period: u64)]
+pub struct InitializeBurnArchive<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BurnArchive::INIT_SPACE,
+        seeds = [b"burn_archive", asset_id.to_le_bytes().as_ref(), period.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_archive: Account<'info, BurnArchive>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the archive for one asset and period (permissionless)
+pub fn handler(ctx: Context<InitializeBurnArchive>, asset_id: u8, period: u64) -> Result<()> {
+    Asset::from_u8(asset_id)?;
+
+    let archive = &mut ctx.accounts.burn_archive;
+    archive.asset_id = asset_id;
+    archive.period = period;
+    archive.leaf_count = 0;
+    archive.total_amount = 0;
+    archive.frontier = [[0u8; 32]; ARCHIVE_TREE_DEPTH];
+    archive.root = archive.compute_root();
+    archive.bump = ctx.bumps.burn_archive;
+
+    msg!("✅ Burn archive initialized (asset {}, period {})", asset_id, period);
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ConversionTable;
+
+#[derive(Accounts)]
+pub struct InitializeConversionTable<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ConversionTable::INIT_SPACE,
+        seeds = [b"conversion_table"],
+        bump
+    )]
+    pub conversion_table: Account<'info, ConversionTable>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the empty conversion table (run once)
+///
+/// Permissionless: the table starts empty (no conversions possible),
+/// every rate requires threshold validator approval.
+pub fn handler(ctx: Context<InitializeConversionTable>) -> Result<()> {
+    msg!("🔧 Initializing conversion table");
+
+    let table = &mut ctx.accounts.conversion_table;
+    table.rates = Vec::new();
+    table.bump = ctx.bumps.conversion_table;
+
+    msg!("✅ Conversion table initialized (no rates)");
+
+    Ok(())
+}
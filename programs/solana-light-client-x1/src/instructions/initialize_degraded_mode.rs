@@ -0,0 +1,43 @@
+use anchor_lang::prelude::*;
+use crate::state::{Asset, DegradedMode};
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct InitializeDegradedMode<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DegradedMode::INIT_SPACE,
+        seeds = [b"degraded_mode", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub degraded_mode: Account<'info, DegradedMode>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create an asset's degraded mode account (run once per asset, permissionless)
+///
+/// Starts disabled until governance configures it.
+pub fn handler(ctx: Context<InitializeDegradedMode>, asset_id: u8) -> Result<()> {
+    let asset = Asset::from_u8(asset_id)?;
+
+    let mode = &mut ctx.accounts.degraded_mode;
+    mode.asset_id = asset_id;
+    mode.enabled = false;
+    mode.quorum = 0;
+    mode.max_transfer_amount = 0;
+    mode.daily_cap = 0;
+    mode.delay_seconds = 0;
+    mode.day = 0;
+    mode.day_total = 0;
+    mode.total_burns = 0;
+    mode.bump = ctx.bumps.degraded_mode;
+
+    msg!("✅ Degraded mode created for {:?} (disabled)", asset);
+
+    Ok(())
+}
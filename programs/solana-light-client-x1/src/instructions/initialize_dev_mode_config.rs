@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::DevModeConfig;
+
+#[derive(Accounts)]
+pub struct InitializeDevModeConfig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + DevModeConfig::INIT_SPACE,
+        seeds = [b"dev_mode_config"],
+        bump
+    )]
+    pub dev_mode_config: Account<'info, DevModeConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the dev mode config (run once, permissionless)
+///
+/// Starts unmarked: production behaviour, same as before the account existed.
+pub fn handler(ctx: Context<InitializeDevModeConfig>) -> Result<()> {
+    let dev_mode = &mut ctx.accounts.dev_mode_config;
+    dev_mode.non_production = false;
+    dev_mode.canary = Pubkey::default();
+    dev_mode.marked_slot = 0;
+    dev_mode.bump = ctx.bumps.dev_mode_config;
+
+    msg!("✅ Dev mode config created (production)");
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::EventSchemaRegistry;
+
+#[derive(Accounts)]
+pub struct InitializeEventSchemaRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + EventSchemaRegistry::INIT_SPACE,
+        seeds = [b"event_schema_registry"],
+        bump
+    )]
+    pub event_schema_registry: Account<'info, EventSchemaRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the event schema registry (run once, permissionless)
+///
+/// Starts empty: every program is at version 0 until governance bumps it.
+pub fn handler(ctx: Context<InitializeEventSchemaRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.event_schema_registry;
+    registry.schemas = Vec::new();
+    registry.bump = ctx.bumps.event_schema_registry;
+
+    msg!("✅ Event schema registry created");
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+use anchor_lang::prelude::*;
+use crate::state::FeatureGate;
+
+#[derive(Accounts)]
+pub struct InitializeFeatureGate<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeatureGate::INIT_SPACE,
+        seeds = [b"feature_gate"],
+        bump
+    )]
+    pub feature_gate: Account<'info, FeatureGate>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the feature gate (run once, permissionless)
+///
+/// Starts with every gate enabled, same as before the account existed.
+pub fn handler(ctx: Context<InitializeFeatureGate>) -> Result<()> {
+    let feature_gate = &mut ctx.accounts.feature_gate;
+    feature_gate.disabled = 0;
+    feature_gate.updated_slot = Clock::get()?.slot;
+    feature_gate.bump = ctx.bumps.feature_gate;
+
+    msg!("✅ Feature gate created (all paths enabled)");
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::FeeLookupTable;
+
+#[derive(Accounts)]
+pub struct InitializeFeeLookupTable<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeeLookupTable::INIT_SPACE,
+        seeds = [b"fee_lookup_table"],
+        bump
+    )]
+    pub fee_lookup_table: Account<'info, FeeLookupTable>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the fee lookup table record (run once, permissionless)
+///
+/// Starts empty until a table is published.
+pub fn handler(ctx: Context<InitializeFeeLookupTable>) -> Result<()> {
+    let record = &mut ctx.accounts.fee_lookup_table;
+    record.lookup_table = Pubkey::default();
+    record.validator_set_version = 0;
+    record.updated_slot = Clock::get()?.slot;
+    record.bump = ctx.bumps.fee_lookup_table;
+
+    msg!("✅ Fee lookup table record created (none published)");
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+use crate::state::ValidatorLatencyStats;
+
+#[derive(Accounts)]
+pub struct InitializeLatencyStats<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ValidatorLatencyStats::INIT_SPACE,
+        seeds = [b"validator_latency_stats"],
+        bump
+    )]
+    pub latency_stats: Account<'info, ValidatorLatencyStats>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Initialize the validator latency stats account (run once, permissionless)
+pub fn handler(ctx: Context<InitializeLatencyStats>) -> Result<()> {
+    let latency_stats = &mut ctx.accounts.latency_stats;
+    latency_stats.entries = Vec::new();
+    latency_stats.bump = ctx.bumps.latency_stats;
+
+    msg!("✅ Validator latency stats initialized");
+
+    Ok(())
+}
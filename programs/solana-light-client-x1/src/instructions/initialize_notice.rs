@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::{Notice, NOTICE_NONE};
+
+#[derive(Accounts)]
+pub struct InitializeNotice<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Notice::INIT_SPACE,
+        seeds = [b"notice"],
+        bump
+    )]
+    pub notice: Account<'info, Notice>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the notice board (run once, permissionless)
+///
+/// Starts empty; only validator governance can post to it.
+pub fn handler(ctx: Context<InitializeNotice>) -> Result<()> {
+    let notice = &mut ctx.accounts.notice;
+    notice.message_hash = [0u8; 32];
+    notice.effective_slot = 0;
+    notice.severity = NOTICE_NONE;
+    notice.sequence = 0;
+    notice.posted_slot = Clock::get()?.slot;
+    notice.bump = ctx.bumps.notice;
+
+    msg!("✅ Notice board created (empty)");
+
+    Ok(())
+}
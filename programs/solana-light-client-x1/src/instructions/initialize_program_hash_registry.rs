@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+use crate::state::ProgramHashRegistry;
+
+#[derive(Accounts)]
+pub struct InitializeProgramHashRegistry<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProgramHashRegistry::INIT_SPACE,
+        seeds = [b"program_hash_registry"],
+        bump
+    )]
+    pub program_hash_registry: Account<'info, ProgramHashRegistry>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the program hash registry (run once, permissionless)
+///
+/// Starts empty: nothing is attested until governance attests a build.
+pub fn handler(ctx: Context<InitializeProgramHashRegistry>) -> Result<()> {
+    let registry = &mut ctx.accounts.program_hash_registry;
+    registry.hashes = Vec::new();
+    registry.bump = ctx.bumps.program_hash_registry;
+
+    msg!("✅ Program hash registry created");
+
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::RentSponsorPool;
+
+#[derive(Accounts)]
+pub struct InitializeRentSponsorPool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + RentSponsorPool::INIT_SPACE,
+        seeds = [b"rent_sponsor_pool"],
+        bump
+    )]
+    pub rent_sponsor_pool: Account<'info, RentSponsorPool>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the rent sponsor pool (run once, permissionless)
+///
+/// Starts switched off (max_burn_amount = 0) until governance configures it.
+pub fn handler(ctx: Context<InitializeRentSponsorPool>) -> Result<()> {
+    let pool = &mut ctx.accounts.rent_sponsor_pool;
+    pool.max_burn_amount = 0;
+    pool.total_funded = 0;
+    pool.total_sponsored = 0;
+    pool.total_reimbursed = 0;
+    pool.outstanding = 0;
+    pool.bump = ctx.bumps.rent_sponsor_pool;
+
+    msg!("✅ Rent sponsor pool created (sponsorship off)");
+
+    Ok(())
+}
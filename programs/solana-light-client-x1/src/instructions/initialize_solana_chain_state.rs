@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::SolanaChainState;
+
+#[derive(Accounts)]
+pub struct InitializeSolanaChainState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SolanaChainState::INIT_SPACE,
+        seeds = [b"solana_chain_state"],
+        bump
+    )]
+    pub solana_chain_state: Account<'info, SolanaChainState>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the empty Solana chain state account (run once)
+///
+/// Permissionless: the account starts zeroed (never fresh), only
+/// validator-attested updates can fill it.
+pub fn handler(ctx: Context<InitializeSolanaChainState>) -> Result<()> {
+    msg!("🔧 Initializing Solana chain state");
+
+    let state = &mut ctx.accounts.solana_chain_state;
+    state.epoch = 0;
+    state.total_stake = 0;
+    state.solana_slot = 0;
+    state.updated_slot = 0;
+    state.updated_at = 0;
+    state.bump = ctx.bumps.solana_chain_state;
+
+    msg!("✅ Solana chain state initialized");
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::SweepBountyPool;
+
+#[derive(Accounts)]
+pub struct InitializeSweepBountyPool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + SweepBountyPool::INIT_SPACE,
+        seeds = [b"sweep_bounty_pool"],
+        bump
+    )]
+    pub sweep_bounty_pool: Account<'info, SweepBountyPool>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the sweep bounty pool (run once, permissionless)
+///
+/// Starts with no bounty (bounty_lamports = 0) until governance configures it.
+pub fn handler(ctx: Context<InitializeSweepBountyPool>) -> Result<()> {
+    let pool = &mut ctx.accounts.sweep_bounty_pool;
+    pool.bounty_lamports = 0;
+    pool.total_funded = 0;
+    pool.total_paid = 0;
+    pool.swept = 0;
+    pool.bump = ctx.bumps.sweep_bounty_pool;
+
+    msg!("✅ Sweep bounty pool created (no bounty)");
+
+    Ok(())
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use crate::state::X1ValidatorSet;
+use crate::state::{X1ValidatorSet, LIGHT_CLIENT_EVENT_SCHEMA_VERSION};
 use std::str::FromStr;
 
 #[derive(Accounts)]
@@ -44,6 +44,14 @@ pub fn handler(
     validator_set.version = 1; // Start at version 1
     validator_set.threshold = threshold; // 3 of 5 (Byzantine fault tolerant)
     validator_set.bump = ctx.bumps.validator_set;
+    validator_set.max_attestation_age = X1ValidatorSet::DEFAULT_MAX_ATTESTATION_AGE;
+
+    emit!(ValidatorSetInitialized {
+        schema_version: LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+        version: validator_set.version,
+        validators: validator_set.validators.clone(),
+        threshold,
+    });
 
     msg!("✅ Validator set initialized");
     msg!("   Version: {}", validator_set.version);
@@ -52,3 +60,12 @@ pub fn handler(
 
     Ok(())
 }
+
+/// Event emitted once, when the genesis validator set is created
+#[event]
+pub struct ValidatorSetInitialized {
+    pub schema_version: u16,
+    pub version: u64,
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+}
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, ValidatorSetCheckpoint};
+
+#[derive(Accounts)]
+pub struct InitializeValidatorSetCheckpoint<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ValidatorSetCheckpoint::INIT_SPACE,
+        seeds = [b"validator_set_checkpoint"],
+        bump
+    )]
+    pub checkpoint: Account<'info, ValidatorSetCheckpoint>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the validator set checkpoint from the live set (run once, permissionless)
+pub fn handler(ctx: Context<InitializeValidatorSetCheckpoint>) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let checkpoint = &mut ctx.accounts.checkpoint;
+
+    checkpoint.version = validator_set.version;
+    checkpoint.validators = validator_set.validators.clone();
+    checkpoint.threshold = validator_set.threshold;
+    checkpoint.checkpoint_slot = Clock::get()?.slot;
+    checkpoint.bump = ctx.bumps.checkpoint;
+
+    msg!("✅ Validator set checkpoint created at version {}", checkpoint.version);
+
+    Ok(())
+}
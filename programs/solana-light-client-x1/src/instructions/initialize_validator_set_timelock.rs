@@ -0,0 +1,35 @@
+use anchor_lang::prelude::*;
+use crate::state::ValidatorSetTimelock;
+
+#[derive(Accounts)]
+pub struct InitializeValidatorSetTimelock<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ValidatorSetTimelock::INIT_SPACE,
+        seeds = [b"validator_set_timelock"],
+        bump
+    )]
+    pub validator_set_timelock: Account<'info, ValidatorSetTimelock>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the validator set update timelock (run once, permissionless)
+///
+/// From here on update_validator_set is refused and updates wait out
+/// DEFAULT_DELAY_SECONDS. Only ever makes updates slower, so anyone may
+/// turn it on.
+pub fn handler(ctx: Context<InitializeValidatorSetTimelock>) -> Result<()> {
+    let timelock = &mut ctx.accounts.validator_set_timelock;
+    timelock.delay_seconds = ValidatorSetTimelock::DEFAULT_DELAY_SECONDS;
+    timelock.updated_slot = Clock::get()?.slot;
+    timelock.bump = ctx.bumps.validator_set_timelock;
+
+    msg!("✅ Validator set timelock created ({}s)", timelock.delay_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+use crate::state::WormholeConfig;
+
+#[derive(Accounts)]
+pub struct InitializeWormholeConfig<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + WormholeConfig::INIT_SPACE,
+        seeds = [b"wormhole_config"],
+        bump
+    )]
+    pub wormhole_config: Account<'info, WormholeConfig>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the Wormhole VAA path config (run once, permissionless)
+///
+/// Starts disabled until governance sets the core bridge and emitter.
+pub fn handler(ctx: Context<InitializeWormholeConfig>) -> Result<()> {
+    let config = &mut ctx.accounts.wormhole_config;
+    config.enabled = false;
+    config.core_bridge = Pubkey::default();
+    config.emitter_chain = crate::wormhole::CHAIN_ID_SOLANA;
+    config.emitter_address = [0u8; 32];
+    config.min_consistency_level = 1;
+    config.updated_slot = Clock::get()?.slot;
+    config.bump = ctx.bumps.wormhole_config;
+
+    msg!("✅ Wormhole config created (disabled)");
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, X1ChainId, X1ValidatorSet};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct InitializeX1ChainId<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + X1ChainId::INIT_SPACE,
+        seeds = [b"x1_chain_id"],
+        bump
+    )]
+    pub x1_chain_id: Account<'info, X1ChainId>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct InitializeX1ChainIdParams {
+    /// Genesis hash of the X1 network (getGenesisHash, base58-decoded)
+    pub genesis_hash: [u8; 32],
+
+    /// Signatures from current validators approving this genesis hash
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Bind this deployment to its X1 network's genesis hash (run once)
+///
+/// Needs threshold approval: each validator checks the hash against its own
+/// X1 RPC before signing. Cannot be changed afterwards, since every
+/// outstanding attestation would stop verifying.
+pub fn handler(ctx: Context<InitializeX1ChainId>, params: InitializeX1ChainIdParams) -> Result<()> {
+    require!(
+        params.genesis_hash != [0u8; 32],
+        LightClientError::InvalidGenesisHash
+    );
+
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔧 Binding bridge to X1 genesis hash: {}", Pubkey::new_from_array(params.genesis_hash));
+
+    let message = create_x1_chain_id_message(validator_set.version, bridge_config.nonce, &params.genesis_hash);
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let x1_chain_id = &mut ctx.accounts.x1_chain_id;
+    x1_chain_id.genesis_hash = params.genesis_hash;
+    x1_chain_id.bump = ctx.bumps.x1_chain_id;
+
+    msg!("✅ X1 chain id set");
+
+    Ok(())
+}
+
+/// Create deterministic message for the X1 chain id
+///
+/// Format: hash(INIT_X1_CHAIN_ID || version || config_nonce || genesis_hash)
+///
+/// The genesis hash being set is already in the payload, so nothing is appended.
+fn create_x1_chain_id_message(validator_set_version: u64, config_nonce: u64, genesis_hash: &[u8; 32]) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(40);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(genesis_hash);
+
+    create_governance_message(b"INIT_X1_CHAIN_ID", validator_set_version, &payload, None)
+}
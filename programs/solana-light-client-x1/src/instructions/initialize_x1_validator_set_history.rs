@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::X1ValidatorSetHistory;
+
+#[derive(Accounts)]
+pub struct InitializeX1ValidatorSetHistory<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + X1ValidatorSetHistory::INIT_SPACE,
+        seeds = [b"x1_validator_set_history"],
+        bump
+    )]
+    pub validator_set_history: Account<'info, X1ValidatorSetHistory>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the X1 validator set history (run once, permissionless)
+///
+/// Every validator set update applied from then on is recorded.
+pub fn handler(ctx: Context<InitializeX1ValidatorSetHistory>) -> Result<()> {
+    let history = &mut ctx.accounts.validator_set_history;
+    history.records = vec![];
+    history.next_index = 0;
+    history.total_updates = 0;
+    history.bump = ctx.bumps.validator_set_history;
+
+    msg!("✅ X1 validator set history created ({} records)", X1ValidatorSetHistory::HISTORY_SIZE);
+
+    Ok(())
+}
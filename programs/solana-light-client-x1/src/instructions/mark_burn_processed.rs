@@ -0,0 +1,60 @@
+use anchor_lang::prelude::*;
+use crate::state::{AssetRegistry, VerifiedBurnV3};
+use crate::errors::LightClientError;
+
+/// Set `processed` on a minted VerifiedBurnV3 (permissionless)
+///
+/// A mint program cannot write the light client's account itself; it calls
+/// this by CPI (consumer::mark_processed) after creating its replay PDA, or
+/// anyone calls it later to sync the flag.
+///
+/// SECURITY:
+/// - Only the asset's registered mint program can have created the
+///   processed_burn_v3 PDA it requires, so no other program can block a
+///   burn by marking it processed
+/// - Marking an already processed burn is a no-op (frozen burns included)
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64, user: Pubkey)]
+pub struct MarkBurnProcessed<'info> {
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Mint-side replay tracker (must exist)
+    /// CHECK: Address, owner and non-emptiness checked in handler
+    pub processed_burn: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<MarkBurnProcessed>, asset_id: u8, burn_nonce: u64, user: Pubkey) -> Result<()> {
+    let mint_program = AssetRegistry::resolve(&ctx.accounts.asset_registry, asset_id)?.mint_program;
+
+    let (expected_tracker, _) =
+        crate::consumer::processed_burn_v3_address(&mint_program, asset_id, burn_nonce, &user);
+    let tracker = &ctx.accounts.processed_burn;
+    require_keys_eq!(tracker.key(), expected_tracker, LightClientError::InvalidReplayTracker);
+    require!(
+        *tracker.owner == mint_program && !tracker.data_is_empty(),
+        LightClientError::BurnNotMinted
+    );
+
+    if ctx.accounts.verified_burn.processed {
+        return Ok(());
+    }
+    ctx.accounts.verified_burn.processed = true;
+
+    msg!("✓ Burn {} (asset {}) marked processed", burn_nonce, asset_id);
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_lang::Discriminator;
+use crate::state::X1ValidatorSet;
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+pub struct MigrateValidatorSet<'info> {
+    /// Validator set in the original layout
+    /// CHECK: Address pinned by seeds; owner, discriminator and size checked
+    ///        in handler (the current layout cannot deserialize it)
+    #[account(mut, seeds = [b"x1_validator_set_v2"], bump)]
+    pub validator_set: UncheckedAccount<'info>,
+
+    /// Pays the rent of the grown account (anyone)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// The original X1ValidatorSet fields (account data without the discriminator)
+#[derive(AnchorDeserialize)]
+struct LegacyX1ValidatorSet {
+    version: u64,
+    validators: Vec<Pubkey>,
+    threshold: u8,
+    bump: u8,
+}
+
+/// Grow a validator set created before the appended fields
+/// (max_attestation_age, weights, threshold_bps, ordered_attestations) and
+/// write them with their defaults
+///
+/// Such an account is exactly X1ValidatorSet::LEGACY_SPACE bytes. Its bytes
+/// after `bump` are whatever earlier serializations left there (a set that
+/// shrank leaves stale keys behind) or nothing at all for a full set, so
+/// they are never read as the new fields: the account is zero-filled and
+/// rewritten. Permissionless and one-shot, since only the original size is
+/// accepted and only the defaults are written.
+pub fn handler(ctx: Context<MigrateValidatorSet>) -> Result<()> {
+    let info = ctx.accounts.validator_set.to_account_info();
+
+    require_keys_eq!(*info.owner, crate::ID, LightClientError::ValidatorSetAlreadyMigrated);
+    require!(
+        info.data_len() == X1ValidatorSet::LEGACY_SPACE,
+        LightClientError::ValidatorSetAlreadyMigrated
+    );
+    let legacy = {
+        let data = info.try_borrow_data()?;
+        require!(
+            data[..8] == X1ValidatorSet::DISCRIMINATOR,
+            LightClientError::ValidatorSetAlreadyMigrated
+        );
+        LegacyX1ValidatorSet::deserialize(&mut &data[8..])?
+    };
+
+    msg!("🧱 Migrating validator set v{} ({} validators)", legacy.version, legacy.validators.len());
+
+    let space = 8 + X1ValidatorSet::INIT_SPACE;
+    let top_up = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if top_up > 0 {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    info.realloc(space, true)?;
+    info.try_borrow_mut_data()?.fill(0);
+
+    let validator_set = X1ValidatorSet {
+        version: legacy.version,
+        validators: legacy.validators,
+        threshold: legacy.threshold,
+        bump: legacy.bump,
+        max_attestation_age: 0,
+        weights: Vec::new(),
+        threshold_bps: 0,
+        ordered_attestations: false,
+    };
+    validator_set.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+
+    emit!(ValidatorSetMigrated {
+        validator_set_version: validator_set.version,
+        old_size: X1ValidatorSet::LEGACY_SPACE as u32,
+        new_size: space as u32,
+    });
+
+    msg!("✅ Validator set migrated ({} -> {} bytes)", X1ValidatorSet::LEGACY_SPACE, space);
+
+    Ok(())
+}
+
+#[event]
+pub struct ValidatorSetMigrated {
+    pub validator_set_version: u64,
+    pub old_size: u32,
+    pub new_size: u32,
+}
@@ -3,6 +3,93 @@ pub mod initialize_validator_set;
 pub mod update_validator_set;
 pub mod submit_burn_attestation;
 pub mod submit_burn_attestation_v3;  // Asset-aware attestation
+pub mod initialize_bridge_config;
+pub mod set_v2_cutoff;
+pub mod set_validator_floor;
+pub mod upgrade_verified_burn_to_v3;
+pub mod initialize_solana_chain_state;
+pub mod update_solana_epoch;
+pub mod initialize_conversion_table;
+pub mod set_conversion_rate;
+pub mod convert_verified_burn;
+pub mod mark_burn_processed;
+pub mod request_validator_resignation;
+pub mod cancel_validator_resignation;
+pub mod finalize_validator_resignation;
+pub mod initialize_latency_stats;
+pub mod initialize_validator_set_checkpoint;
+pub mod sync_validator_set_checkpoint;
+pub mod schedule_validator_set_restore;
+pub mod cancel_validator_set_restore;
+pub mod execute_validator_set_restore;
+pub mod initialize_validator_set_timelock;
+pub mod set_validator_set_update_delay;
+pub mod propose_validator_set_update;
+pub mod cancel_validator_set_update;
+pub mod execute_validator_set_update;
+pub mod initialize_x1_validator_set_history;
+pub mod initialize_burn_archive;
+pub mod archive_verified_burn;
+pub mod freeze_burn;
+pub mod unfreeze_burn;
+pub mod redirect_verified_burn;
+pub mod get_bridge_info;
+pub mod initialize_bridge_info;
+pub mod sync_bridge_info;
+pub mod initialize_rent_sponsor_pool;
+pub mod fund_rent_sponsor_pool;
+pub mod configure_rent_sponsor_pool;
+pub mod sponsor_burn_rent;
+pub mod initialize_feature_gate;
+pub mod set_feature_gate;
+pub mod pause_bridge;
+pub mod unpause_bridge;
+pub mod initialize_bridge_domain;
+pub mod register_deposit_webhook;
+pub mod update_deposit_webhook;
+pub mod close_deposit_webhook;
+pub mod initialize_event_schema_registry;
+pub mod set_event_schema_version;
+pub mod get_asset_metadata;
+pub mod initialize_program_hash_registry;
+pub mod attest_program_hash;
+pub mod initialize_sweep_bounty_pool;
+pub mod fund_sweep_bounty_pool;
+pub mod configure_sweep_bounty_pool;
+pub mod sweep_verified_burn;
+pub mod initialize_degraded_mode;
+pub mod configure_degraded_mode;
+pub mod submit_burn_attestation_degraded;
+pub mod release_degraded_burn;
+pub mod initialize_x1_chain_id;
+pub mod verify_return_burn;
+pub mod prevalidate_attestation;
+pub mod initialize_asset_registry;
+pub mod register_asset;
+pub mod deactivate_asset;
+pub mod freeze_asset;
+pub mod unfreeze_asset;
+pub mod commit_usage_snapshot;
+pub mod verify_usage_proof;
+pub mod set_max_attestation_age;
+pub mod set_attestation_ordering;
+pub mod initialize_burn_age_limit;
+pub mod set_burn_age_limit;
+pub mod initialize_fee_lookup_table;
+pub mod publish_fee_lookup_table;
+pub mod request_attestation_challenge;
+pub mod close_attestation_challenge;
+pub mod initialize_dev_mode_config;
+pub mod set_dev_mode_config;
+pub mod submit_burn_attestation_for_recipient;
+pub mod initialize_notice;
+pub mod set_notice;
+pub mod initialize_beta_allowlist;
+pub mod set_beta_allowlist;
+pub mod initialize_wormhole_config;
+pub mod set_wormhole_config;
+pub mod submit_burn_vaa;
+pub mod migrate_validator_set;
 // Legacy modules - keeping for reference
 // pub mod verify_proof;
 // pub mod update_validators;
@@ -14,3 +101,90 @@ pub use initialize_validator_set::*;
 pub use update_validator_set::*;
 pub use submit_burn_attestation::*;
 pub use submit_burn_attestation_v3::*;  // Asset-aware attestation
+pub use initialize_bridge_config::*;
+pub use set_v2_cutoff::*;
+pub use set_validator_floor::*;
+pub use upgrade_verified_burn_to_v3::*;
+pub use initialize_solana_chain_state::*;
+pub use update_solana_epoch::*;
+pub use initialize_conversion_table::*;
+pub use set_conversion_rate::*;
+pub use convert_verified_burn::*;
+pub use mark_burn_processed::*;
+pub use request_validator_resignation::*;
+pub use cancel_validator_resignation::*;
+pub use finalize_validator_resignation::*;
+pub use initialize_latency_stats::*;
+pub use initialize_validator_set_checkpoint::*;
+pub use sync_validator_set_checkpoint::*;
+pub use schedule_validator_set_restore::*;
+pub use cancel_validator_set_restore::*;
+pub use execute_validator_set_restore::*;
+pub use initialize_validator_set_timelock::*;
+pub use set_validator_set_update_delay::*;
+pub use propose_validator_set_update::*;
+pub use cancel_validator_set_update::*;
+pub use execute_validator_set_update::*;
+pub use initialize_x1_validator_set_history::*;
+pub use initialize_burn_archive::*;
+pub use archive_verified_burn::*;
+pub use freeze_burn::*;
+pub use unfreeze_burn::*;
+pub use redirect_verified_burn::*;
+pub use get_bridge_info::*;
+pub use initialize_bridge_info::*;
+pub use sync_bridge_info::*;
+pub use initialize_rent_sponsor_pool::*;
+pub use fund_rent_sponsor_pool::*;
+pub use configure_rent_sponsor_pool::*;
+pub use sponsor_burn_rent::*;
+pub use initialize_feature_gate::*;
+pub use set_feature_gate::*;
+pub use pause_bridge::*;
+pub use unpause_bridge::*;
+pub use initialize_bridge_domain::*;
+pub use register_deposit_webhook::*;
+pub use update_deposit_webhook::*;
+pub use close_deposit_webhook::*;
+pub use initialize_event_schema_registry::*;
+pub use set_event_schema_version::*;
+pub use get_asset_metadata::*;
+pub use initialize_program_hash_registry::*;
+pub use attest_program_hash::*;
+pub use initialize_sweep_bounty_pool::*;
+pub use fund_sweep_bounty_pool::*;
+pub use configure_sweep_bounty_pool::*;
+pub use sweep_verified_burn::*;
+pub use initialize_degraded_mode::*;
+pub use configure_degraded_mode::*;
+pub use submit_burn_attestation_degraded::*;
+pub use release_degraded_burn::*;
+pub use initialize_x1_chain_id::*;
+pub use verify_return_burn::*;
+pub use prevalidate_attestation::*;
+pub use initialize_asset_registry::*;
+pub use register_asset::*;
+pub use deactivate_asset::*;
+pub use freeze_asset::*;
+pub use unfreeze_asset::*;
+pub use commit_usage_snapshot::*;
+pub use verify_usage_proof::*;
+pub use set_max_attestation_age::*;
+pub use set_attestation_ordering::*;
+pub use initialize_burn_age_limit::*;
+pub use set_burn_age_limit::*;
+pub use initialize_fee_lookup_table::*;
+pub use publish_fee_lookup_table::*;
+pub use request_attestation_challenge::*;
+pub use close_attestation_challenge::*;
+pub use initialize_dev_mode_config::*;
+pub use set_dev_mode_config::*;
+pub use submit_burn_attestation_for_recipient::*;
+pub use initialize_notice::*;
+pub use set_notice::*;
+pub use initialize_beta_allowlist::*;
+pub use set_beta_allowlist::*;
+pub use initialize_wormhole_config::*;
+pub use set_wormhole_config::*;
+pub use submit_burn_vaa::*;
+pub use migrate_validator_set::*;
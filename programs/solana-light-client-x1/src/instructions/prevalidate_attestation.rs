@@ -0,0 +1,209 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    AssetRegistry, AttestationCheck, AttestationCheckCode, BurnAttestationDataV3, FeatureGate, X1ValidatorSet, gates,
+};
+use crate::ed25519_utils::check_signature_encoding;
+
+/// Read-only: nothing is created or written, so relayers simulate it
+/// before paying rent for a submit_burn_attestation_v3 that would fail
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64, attestation: BurnAttestationDataV3)]
+pub struct PrevalidateAttestation<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// The submit's VerifiedBurnV3 (must not exist yet)
+    /// CHECK: Address pinned by seeds; only its emptiness is read
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            attestation.user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_burn: UncheckedAccount<'info>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+}
+
+/// Attestation bundle pre-check view (returned as instruction return data)
+///
+/// Never fails on a bad bundle: every problem is reported as a code.
+/// Signatures are checked for encoding only, as in the submit itself, so
+/// a Valid result still depends on the validators having signed the
+/// message this deployment's domain produces. Mock signatures are
+/// reported as BadSignatureEncoding even on marked dev-mode deployments.
+pub fn handler(
+    ctx: Context<PrevalidateAttestation>,
+    asset_id: u8,
+    burn_nonce: u64,
+    attestation: BurnAttestationDataV3,
+) -> Result<AttestationCheck> {
+    let validator_set = &ctx.accounts.validator_set;
+    let (attestation_codes, valid_count) = check_bundle(validator_set, &attestation, Clock::get()?.unix_timestamp);
+
+    let code = if FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_V3).is_err() {
+        AttestationCheckCode::FeatureDisabled
+    } else if attestation.asset_id != asset_id || attestation.burn_nonce != burn_nonce {
+        AttestationCheckCode::ParameterMismatch
+    } else if AssetRegistry::resolve_active(&ctx.accounts.asset_registry, asset_id).is_err() {
+        AttestationCheckCode::UnknownAsset
+    } else if attestation.validator_set_version != validator_set.version {
+        AttestationCheckCode::VersionMismatch
+    } else if attestation.check_confirmation_depth().is_err() {
+        AttestationCheckCode::ConfirmationDepth
+    } else if !ctx.accounts.verified_burn.data_is_empty() {
+        AttestationCheckCode::AlreadyVerified
+    } else if let Some(failed) = attestation_codes.iter().find(|c| **c != AttestationCheckCode::Valid) {
+        *failed
+    } else if valid_count < validator_set.threshold || !validator_set.weight_threshold_met(valid_signers(&attestation, &attestation_codes)) {
+        AttestationCheckCode::BelowThreshold
+    } else {
+        AttestationCheckCode::Valid
+    };
+
+    msg!("Attestation pre-check: {:?} ({}/{} valid)", code, valid_count, validator_set.threshold);
+
+    Ok(AttestationCheck {
+        code,
+        attestation_codes,
+        valid_count,
+        threshold: validator_set.threshold,
+        validator_set_version: validator_set.version,
+    })
+}
+
+/// Per-attestation codes (same order of checks as verify_attestations) and
+/// how many attestations pass
+fn check_bundle(
+    validator_set: &X1ValidatorSet,
+    attestation: &BurnAttestationDataV3,
+    now: i64,
+) -> (Vec<AttestationCheckCode>, u8) {
+    let mut seen = Vec::with_capacity(attestation.attestations.len());
+    let mut previous_index = None;
+    let mut valid_count: u8 = 0;
+    let codes = attestation.attestations.iter().map(|attest| {
+        let mut order = None;
+        if validator_set.ordered_attestations {
+            match validator_set.ordered_index(&attest.validator_pubkey, previous_index) {
+                Ok(index) => previous_index = Some(index),
+                Err(code) => order = Some(code),
+            }
+        }
+        let code = if let Some(order) = order {
+            order
+        } else if seen.contains(&attest.validator_pubkey) {
+            AttestationCheckCode::DuplicateValidator
+        } else if !validator_set.validators.contains(&attest.validator_pubkey) {
+            AttestationCheckCode::UnknownValidator
+        } else if validator_set.check_attestation_age(attest.timestamp, now).is_err() {
+            AttestationCheckCode::AttestationAge
+        } else if check_signature_encoding(&attest.validator_pubkey.to_bytes(), &attest.signature, false).is_err() {
+            AttestationCheckCode::BadSignatureEncoding
+        } else {
+            valid_count = valid_count.saturating_add(1);
+            AttestationCheckCode::Valid
+        };
+        seen.push(attest.validator_pubkey);
+        code
+    }).collect();
+    (codes, valid_count)
+}
+
+/// Validators of the attestations that pass
+fn valid_signers<'a>(
+    attestation: &'a BurnAttestationDataV3,
+    codes: &'a [AttestationCheckCode],
+) -> impl Iterator<Item = &'a Pubkey> {
+    attestation.attestations
+        .iter()
+        .zip(codes)
+        .filter(|(_, code)| **code == AttestationCheckCode::Valid)
+        .map(|(attest, _)| &attest.validator_pubkey)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ValidatorAttestation;
+
+    fn attest(validator_pubkey: Pubkey, signature: [u8; 64]) -> ValidatorAttestation {
+        ValidatorAttestation { validator_pubkey, signature, timestamp: 1_700_000_000 }
+    }
+
+    #[test]
+    fn test_check_bundle_reports_each_attestation() {
+        let validators: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let validator_set = X1ValidatorSet { version: 2, validators: validators.clone(), threshold: 2, bump: 255, max_attestation_age: 0, weights: vec![], threshold_bps: 0, ordered_attestations: false };
+        let bundle = BurnAttestationDataV3 {
+            asset_id: 1,
+            burn_nonce: 5,
+            user: Pubkey::new_unique(),
+            amount: 1_000,
+            validator_set_version: 2,
+            solana_burn_slot: 1,
+            solana_burn_timestamp: 1_700_000_000,
+            confirmation_depth: 0,
+            solana_confirmed_slot: 33,
+            attestations: vec![
+                attest(validators[0], [1u8; 64]),
+                attest(validators[0], [1u8; 64]),
+                attest(Pubkey::new_unique(), [1u8; 64]),
+                attest(validators[1], [0xffu8; 64]),
+                attest(validators[2], [1u8; 64]),
+                ValidatorAttestation { validator_pubkey: validators[3], signature: [1u8; 64], timestamp: 1_600_000_000 },
+            ],
+        };
+
+        let (codes, valid_count) = check_bundle(&validator_set, &bundle, 1_700_000_060);
+        assert_eq!(codes, vec![
+            AttestationCheckCode::Valid,
+            AttestationCheckCode::DuplicateValidator,
+            AttestationCheckCode::UnknownValidator,
+            AttestationCheckCode::BadSignatureEncoding,
+            AttestationCheckCode::Valid,
+            AttestationCheckCode::AttestationAge,
+        ]);
+        assert_eq!(valid_count, 2);
+
+        // Only passing attestations count toward the weighted threshold
+        let weighted = X1ValidatorSet { weights: vec![1, 1, 1, 9], threshold_bps: 5_000, ..validator_set.clone() };
+        assert!(!weighted.weight_threshold_met(valid_signers(&bundle, &codes)));
+
+        // Ordered: compared with the last attestation in order only
+        let ordered = X1ValidatorSet { ordered_attestations: true, ..validator_set };
+        let bundle = BurnAttestationDataV3 {
+            attestations: vec![
+                attest(validators[1], [1u8; 64]),
+                attest(validators[0], [1u8; 64]),
+                attest(validators[2], [1u8; 64]),
+                attest(validators[2], [1u8; 64]),
+                attest(validators[3], [1u8; 64]),
+            ],
+            ..bundle
+        };
+        let (codes, valid_count) = check_bundle(&ordered, &bundle, 1_700_000_060);
+        assert_eq!(codes, vec![
+            AttestationCheckCode::Valid,
+            AttestationCheckCode::OutOfOrder,
+            AttestationCheckCode::Valid,
+            AttestationCheckCode::DuplicateValidator,
+            AttestationCheckCode::Valid,
+        ]);
+        assert_eq!(valid_count, 3);
+    }
+}
@@ -0,0 +1,149 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, PendingValidatorSetUpdate, ValidatorSetTimelock, X1ChainId, X1ValidatorSet};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::{check_update_policy, create_update_message, UpdateValidatorSetParams};
+
+#[derive(Accounts)]
+pub struct ProposeValidatorSetUpdate<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"validator_set_timelock"],
+        bump = validator_set_timelock.bump
+    )]
+    pub validator_set_timelock: Account<'info, ValidatorSetTimelock>,
+
+    /// Only one update can be pending at a time
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + PendingValidatorSetUpdate::INIT_SPACE,
+        seeds = [b"validator_set_update"],
+        bump
+    )]
+    pub pending_update: Account<'info, PendingValidatorSetUpdate>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Propose a validator set update (requires threshold signatures, then the timelock)
+///
+/// The new set must satisfy the same policy as update_validator_set. It
+/// can be executed once the timelock's delay has passed, and cancelled by
+/// the current validators until then.
+pub fn handler(ctx: Context<ProposeValidatorSetUpdate>, params: UpdateValidatorSetParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🗳️  Proposing validator set update");
+    msg!("   Current version: {}", validator_set.version);
+    msg!("   New set: {} validators, threshold {}", params.new_validators.len(), params.new_threshold);
+
+    check_update_policy(&params, bridge_config)?;
+
+    let message = create_proposal_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    let approvals = verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let now = Clock::get()?.unix_timestamp;
+    let pending = &mut ctx.accounts.pending_update;
+    pending.base_version = validator_set.version;
+    pending.validators = params.new_validators;
+    pending.threshold = params.new_threshold;
+    pending.weights = params.new_weights;
+    pending.threshold_bps = params.new_threshold_bps;
+    pending.approvals = approvals;
+    pending.approvers = params.approver_signatures.iter().map(|approval| approval.validator_pubkey).collect();
+    pending.proposer = ctx.accounts.proposer.key();
+    pending.proposed_at = now;
+    pending.executable_at = now
+        .checked_add(ctx.accounts.validator_set_timelock.delay_seconds)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    pending.bump = ctx.bumps.pending_update;
+
+    emit!(ValidatorSetUpdateProposed {
+        base_version: pending.base_version,
+        validators: pending.validators.clone(),
+        threshold: pending.threshold,
+        weights: pending.weights.clone(),
+        threshold_bps: pending.threshold_bps,
+        executable_at: pending.executable_at,
+    });
+
+    msg!("✅ Update proposed, executable at {}", pending.executable_at);
+
+    Ok(())
+}
+
+/// Create deterministic message for a validator set update proposal
+///
+/// Format: hash(PROPOSE_VALIDATOR_SET_UPDATE || version || config_nonce || update_message)
+///
+/// Wraps the update_validator_set message with the config nonce, so a
+/// cancelled proposal cannot be proposed again with the same signatures.
+fn create_proposal_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    params: &UpdateValidatorSetParams,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(40);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&create_update_message(
+        validator_set_version,
+        &params.new_validators,
+        params.new_threshold,
+        &params.new_weights,
+        params.new_threshold_bps,
+        x1_genesis_hash,
+    ));
+
+    create_governance_message(
+        b"PROPOSE_VALIDATOR_SET_UPDATE",
+        validator_set_version,
+        &payload,
+        x1_genesis_hash,
+    )
+}
+
+/// Event emitted when an update is proposed (monitoring should alert on this)
+///
+/// Carries the full proposed set, so users and integrators can review it
+/// before it executes.
+#[event]
+pub struct ValidatorSetUpdateProposed {
+    pub base_version: u64,
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+    pub weights: Vec<u16>,
+    pub threshold_bps: u16,
+    pub executable_at: i64,
+}
@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::address_lookup_table::{self, state::AddressLookupTable};
+use crate::state::{FeeLookupTable, X1ValidatorSet, covers_fee_payout_accounts};
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+pub struct PublishFeeLookupTable<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_lookup_table"],
+        bump = fee_lookup_table.bump
+    )]
+    pub fee_lookup_table: Account<'info, FeeLookupTable>,
+
+    /// The address lookup table to publish
+    /// CHECK: Owner checked here; contents checked by the handler
+    #[account(owner = address_lookup_table::program::ID @ LightClientError::InvalidFeeLookupTable)]
+    pub lookup_table: UncheckedAccount<'info>,
+
+    /// Anyone can publish (e.g. the lookup table service after a set update)
+    pub signer: Signer<'info>,
+}
+
+/// Publish an address lookup table of validator payout accounts (permissionless)
+///
+/// The table must be frozen (no authority, so nobody can change or close
+/// it), active, and hold every payout account of the current validator
+/// set. Nothing else is chosen by the caller.
+pub fn handler(ctx: Context<PublishFeeLookupTable>) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let table_key = ctx.accounts.lookup_table.key();
+
+    {
+        let data = ctx.accounts.lookup_table.try_borrow_data()?;
+        let table = AddressLookupTable::deserialize(&data)
+            .map_err(|_| error!(LightClientError::InvalidFeeLookupTable))?;
+        require!(
+            table.meta.authority.is_none() && table.meta.deactivation_slot == u64::MAX,
+            LightClientError::InvalidFeeLookupTable
+        );
+        require!(
+            covers_fee_payout_accounts(&table.addresses, &validator_set.validators),
+            LightClientError::InvalidFeeLookupTable
+        );
+    }
+
+    let record = &mut ctx.accounts.fee_lookup_table;
+    if record.lookup_table == table_key && record.validator_set_version == validator_set.version {
+        msg!("✓ Fee lookup table already current");
+        return Ok(());
+    }
+
+    record.lookup_table = table_key;
+    record.validator_set_version = validator_set.version;
+    record.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Fee lookup table {} published (validator set v{})", table_key, validator_set.version);
+
+    Ok(())
+}
@@ -0,0 +1,218 @@
+use anchor_lang::prelude::*;
+use crate::state::{redirect_allowed, AssetRegistry, BridgeConfig, VerifiedBurnV3, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+/// Re-point an unmintable verified burn to another recipient (validator governance)
+///
+/// For burns verified for a recipient that can never mint, e.g. a program
+/// address that cannot own or use token accounts.
+///
+/// SECURITY:
+/// - Full validator threshold, and only config::BURN_REDIRECT_DELAY_SLOTS
+///   after verification (the recipient had every chance to mint)
+/// - Source must be unprocessed (frozen and held degraded burns are not)
+///   and its mint-side replay PDA must not exist (not minted yet)
+/// - Works like convert_verified_burn: the source is retired at
+///   processed = true and never archived (no replay PDA), the target
+///   ["verified_burn_v3", asset_id, recipient, nonce] is created with the
+///   same amount and mints through the regular mint_from_burn_v3
+/// - A RelayerClaim of the source lapses: the recipient mints the full amount
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64, user: Pubkey, recipient: Pubkey)]
+pub struct RedirectVerifiedBurn<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Stranded verified burn
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = source_verified_burn.bump,
+        constraint = !source_verified_burn.processed @ LightClientError::BurnAlreadyProcessed,
+    )]
+    pub source_verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Source's mint-side replay tracker (must NOT exist)
+    /// CHECK: Address derived and emptiness checked in handler
+    pub processed_burn: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Verified burn of the new recipient
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VerifiedBurnV3::INIT_SPACE,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            recipient.as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub target_verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Pays the target's rent (anyone can submit with valid signatures)
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RedirectVerifiedBurnParams {
+    /// Off-chain case reference (e.g. sha256 of the recovery request)
+    pub reason: [u8; 32],
+
+    /// Signatures from current validators approving this redirect
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(
+    ctx: Context<RedirectVerifiedBurn>,
+    asset_id: u8,
+    burn_nonce: u64,
+    user: Pubkey,
+    recipient: Pubkey,
+    params: RedirectVerifiedBurnParams,
+) -> Result<()> {
+    let mint_program = AssetRegistry::resolve(&ctx.accounts.asset_registry, asset_id)?.mint_program;
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("↪️  Redirecting burn {} (asset {})", burn_nonce, asset_id);
+    msg!("   {} -> {}", user, recipient);
+
+    require!(
+        recipient != user && recipient != Pubkey::default(),
+        LightClientError::InvalidRedirectRecipient
+    );
+    let current_slot = Clock::get()?.slot;
+    require!(
+        redirect_allowed(ctx.accounts.source_verified_burn.verified_slot, current_slot),
+        LightClientError::BurnRedirectTooEarly
+    );
+
+    let (expected_tracker, _) = Pubkey::find_program_address(
+        &[
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.as_ref(),
+        ],
+        &mint_program,
+    );
+    require_keys_eq!(
+        ctx.accounts.processed_burn.key(),
+        expected_tracker,
+        LightClientError::InvalidReplayTracker
+    );
+    require!(
+        ctx.accounts.processed_burn.data_is_empty(),
+        LightClientError::BurnAlreadyProcessed
+    );
+
+    let message = create_redirect_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &ctx.accounts.source_verified_burn,
+        &recipient,
+        &params.reason,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let source = &mut ctx.accounts.source_verified_burn;
+    let target = &mut ctx.accounts.target_verified_burn;
+    target.asset_id = asset_id;
+    target.burn_nonce = burn_nonce;
+    target.user = recipient;
+    target.amount = source.amount;
+    target.verified_at = source.verified_at; // Preserve original verification time
+    target.processed = false;
+    target.bump = ctx.bumps.target_verified_burn;
+    target.solana_burn_slot = source.solana_burn_slot;
+    target.verified_slot = source.verified_slot;
+
+    // Retire the source (prevents minting to both)
+    source.processed = true;
+
+    emit!(VerifiedBurnRedirected {
+        asset_id,
+        burn_nonce,
+        from: user,
+        to: recipient,
+        amount: source.amount,
+        reason: params.reason,
+        validator_set_version: validator_set.version,
+    });
+
+    msg!("✅ Burn redirected; {} mints it with mint_from_burn_v3", recipient);
+
+    Ok(())
+}
+
+/// Create deterministic message for a recipient redirect
+///
+/// Format: hash(REDIRECT_BURN || version || config_nonce || asset_id || burn_nonce
+///   || user || recipient || reason)
+fn create_redirect_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    burn: &VerifiedBurnV3,
+    recipient: &Pubkey,
+    reason: &[u8; 32],
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(113);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(burn.asset_id);
+    payload.extend_from_slice(&burn.burn_nonce.to_le_bytes());
+    payload.extend_from_slice(burn.user.as_ref());
+    payload.extend_from_slice(recipient.as_ref());
+    payload.extend_from_slice(reason);
+
+    create_governance_message(b"REDIRECT_BURN", validator_set_version, &payload, x1_genesis_hash)
+}
+
+/// Emitted when a stranded verified burn is re-pointed to a new recipient
+#[event]
+pub struct VerifiedBurnRedirected {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub from: Pubkey,
+    pub to: Pubkey,
+    pub amount: u64,
+    pub reason: [u8; 32],
+    pub validator_set_version: u64,
+}
@@ -0,0 +1,98 @@
+use anchor_lang::prelude::*;
+use crate::state::{AssetRegistry, BridgeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct RegisterAsset<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"asset_registry"],
+        bump = asset_registry.bump
+    )]
+    pub asset_registry: Account<'info, AssetRegistry>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RegisterAssetParams {
+    /// New asset id (never used before), or a deactivated one to reactivate
+    pub asset_id: u8,
+
+    /// Mint program that owns the asset's processed_burn_v3 replay PDAs
+    pub mint_program: Pubkey,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Register a bridged asset (validator governance)
+///
+/// Submitted once the asset's mint program is deployed and validators
+/// attest its burns; from then on submit_burn_attestation_v3 accepts it.
+pub fn handler(ctx: Context<RegisterAsset>, params: RegisterAssetParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let registry = &mut ctx.accounts.asset_registry;
+
+    msg!("🔄 Registering asset {}", params.asset_id);
+    msg!("   Mint program: {}", params.mint_program);
+
+    let message = create_register_asset_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.asset_id,
+        &params.mint_program,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    registry.register(params.asset_id, params.mint_program, Clock::get()?.slot)?;
+
+    msg!("✅ Asset registered");
+
+    Ok(())
+}
+
+/// Create deterministic message for an asset registration
+///
+/// Format: hash(REGISTER_ASSET || version || config_nonce || asset_id || mint_program)
+fn create_register_asset_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    asset_id: u8,
+    mint_program: &Pubkey,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(41);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(asset_id);
+    payload.extend_from_slice(mint_program.as_ref());
+
+    create_governance_message(b"REGISTER_ASSET", validator_set_version, &payload, x1_genesis_hash)
+}
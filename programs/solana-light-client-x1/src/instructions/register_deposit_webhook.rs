@@ -0,0 +1,58 @@
+use anchor_lang::prelude::*;
+use crate::state::DepositWebhook;
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+pub struct RegisterDepositWebhook<'info> {
+    /// X1 address whose incoming bridge mints are notified
+    #[account(mut)]
+    pub recipient: Signer<'info>,
+
+    #[account(
+        init,
+        payer = recipient,
+        space = 8 + DepositWebhook::INIT_SPACE,
+        seeds = [b"deposit_webhook", recipient.key().as_ref()],
+        bump
+    )]
+    pub deposit_webhook: Account<'info, DepositWebhook>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct DepositWebhookParams {
+    /// sha256 of the webhook URL given to the notifier operator
+    pub url_hash: [u8; 32],
+
+    /// Bit (1 << asset_id) per asset to notify about, 0 = every asset
+    pub asset_mask: u8,
+
+    /// Smallest deposit to notify about (base units, 0 = all)
+    pub min_amount: u64,
+}
+
+impl DepositWebhookParams {
+    pub(crate) fn apply(&self, webhook: &mut DepositWebhook) -> Result<()> {
+        require!(self.url_hash != [0u8; 32], LightClientError::InvalidDepositWebhook);
+        webhook.url_hash = self.url_hash;
+        webhook.asset_mask = self.asset_mask;
+        webhook.min_amount = self.min_amount;
+        webhook.updated_slot = Clock::get()?.slot;
+        Ok(())
+    }
+}
+
+/// Register deposit notifications for the signing recipient
+///
+/// Only the recipient can register, update or close its own webhook.
+pub fn handler(ctx: Context<RegisterDepositWebhook>, params: DepositWebhookParams) -> Result<()> {
+    let webhook = &mut ctx.accounts.deposit_webhook;
+    webhook.recipient = ctx.accounts.recipient.key();
+    webhook.bump = ctx.bumps.deposit_webhook;
+    params.apply(webhook)?;
+
+    msg!("🔔 Deposit webhook registered for {}", webhook.recipient);
+
+    Ok(())
+}
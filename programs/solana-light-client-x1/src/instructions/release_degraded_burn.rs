@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{Asset, DegradedBurn, VerifiedBurnV3};
+use crate::errors::LightClientError;
+
+/// Release a degraded-mode burn once its delay has passed (permissionless)
+///
+/// Restores `processed = false` so the burn can be minted, unless it is
+/// frozen, and closes the DegradedBurn marker (rent back to the user).
+/// If governance already unfroze and it was minted, only the marker closes.
+#[derive(Accounts)]
+pub struct ReleaseDegradedBurn<'info> {
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"degraded_burn",
+            degraded_burn.asset_id.to_le_bytes().as_ref(),
+            degraded_burn.user.as_ref(),
+            degraded_burn.burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = degraded_burn.bump
+    )]
+    pub degraded_burn: Account<'info, DegradedBurn>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            degraded_burn.asset_id.to_le_bytes().as_ref(),
+            degraded_burn.user.as_ref(),
+            degraded_burn.burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Guardian freeze of this burn (must NOT exist)
+    /// CHECK: Address pinned by seeds; emptiness checked in handler
+    #[account(
+        seeds = [
+            b"burn_freeze",
+            degraded_burn.asset_id.to_le_bytes().as_ref(),
+            degraded_burn.user.as_ref(),
+            degraded_burn.burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub burn_freeze: UncheckedAccount<'info>,
+
+    /// Mint-side replay tracker
+    /// CHECK: Address derived in handler; only its emptiness is read
+    pub processed_burn: UncheckedAccount<'info>,
+
+    /// CHECK: Rent receiver, must be the burn's user
+    #[account(mut, address = degraded_burn.user)]
+    pub user: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<ReleaseDegradedBurn>) -> Result<()> {
+    let degraded_burn = &ctx.accounts.degraded_burn;
+    let asset = Asset::from_u8(degraded_burn.asset_id)?;
+
+    msg!("🔓 Releasing degraded burn {} (asset {})", degraded_burn.burn_nonce, degraded_burn.asset_id);
+
+    require!(
+        Clock::get()?.unix_timestamp >= degraded_burn.release_at,
+        LightClientError::DegradedDelayActive
+    );
+    require!(
+        ctx.accounts.burn_freeze.data_is_empty(),
+        LightClientError::BurnFrozen
+    );
+
+    let (expected_tracker, _) = Pubkey::find_program_address(
+        &[
+            b"processed_burn_v3",
+            degraded_burn.asset_id.to_le_bytes().as_ref(),
+            degraded_burn.burn_nonce.to_le_bytes().as_ref(),
+            degraded_burn.user.as_ref(),
+        ],
+        &asset.mint_program_id(),
+    );
+    require_keys_eq!(
+        ctx.accounts.processed_burn.key(),
+        expected_tracker,
+        LightClientError::InvalidReplayTracker
+    );
+
+    if ctx.accounts.processed_burn.data_is_empty() {
+        ctx.accounts.verified_burn.processed = false;
+        msg!("✅ Burn released, ready to mint");
+    } else {
+        msg!("✅ Burn already minted, marker closed");
+    }
+
+    Ok(())
+}
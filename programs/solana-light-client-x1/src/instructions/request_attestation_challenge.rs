@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::slot_hashes;
+use crate::state::ChallengeRequest;
+use crate::errors::LightClientError;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct RequestAttestationChallenge<'info> {
+    /// The burn's user
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ChallengeRequest::INIT_SPACE,
+        seeds = [
+            b"challenge_request",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub challenge_request: Account<'info, ChallengeRequest>,
+
+    /// CHECK: Address checked; only its latest entry is read
+    #[account(address = slot_hashes::ID @ LightClientError::InvalidChallengeRequest)]
+    pub slot_hashes: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Post a challenge validators must sign for this burn (burner only)
+///
+/// Post it once the burn is on Solana and before collecting attestations;
+/// validators read it from X1 when they sign. Rent is refunded by
+/// close_attestation_challenge.
+pub fn handler(ctx: Context<RequestAttestationChallenge>, asset_id: u8, burn_nonce: u64) -> Result<()> {
+    // SlotHashes layout: len(u64) || (slot(u64) || hash([u8; 32]))*, newest first
+    let (slot, slot_hash) = {
+        let data = ctx.accounts.slot_hashes.try_borrow_data()?;
+        require!(data.len() >= 48, LightClientError::InvalidChallengeRequest);
+        let slot = u64::from_le_bytes(data[8..16].try_into().unwrap());
+        let hash: [u8; 32] = data[16..48].try_into().unwrap();
+        (slot, hash)
+    };
+    let clock = Clock::get()?;
+    let user = ctx.accounts.user.key();
+
+    let request = &mut ctx.accounts.challenge_request;
+    request.asset_id = asset_id;
+    request.burn_nonce = burn_nonce;
+    request.user = user;
+    request.challenge = ChallengeRequest::derive(asset_id, &user, burn_nonce, slot, &slot_hash);
+    request.created_at = clock.unix_timestamp;
+    request.created_slot = clock.slot;
+    request.bump = ctx.bumps.challenge_request;
+
+    msg!("🎲 Attestation challenge posted for burn {} (asset {})", burn_nonce, asset_id);
+
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, ValidatorResignation};
+use crate::errors::LightClientError;
+use crate::config::RESIGNATION_COOLDOWN_SLOTS;
+
+#[derive(Accounts)]
+pub struct RequestValidatorResignation<'info> {
+    /// The resigning validator (signs with its attestation key)
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        constraint = validator_set.validators.contains(&validator.key())
+            @ LightClientError::ValidatorNotInSet
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + ValidatorResignation::INIT_SPACE,
+        seeds = [b"validator_resignation", validator.key().as_ref()],
+        bump
+    )]
+    pub resignation: Account<'info, ValidatorResignation>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Start a voluntary resignation
+///
+/// The validator stays in the set (and keeps attesting) until the cooldown
+/// elapses and finalize_validator_resignation is called.
+pub fn handler(ctx: Context<RequestValidatorResignation>) -> Result<()> {
+    let current_slot = Clock::get()?.slot;
+
+    let resignation = &mut ctx.accounts.resignation;
+    resignation.validator = ctx.accounts.validator.key();
+    resignation.requested_slot = current_slot;
+    resignation.effective_slot = current_slot
+        .checked_add(RESIGNATION_COOLDOWN_SLOTS)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    resignation.bump = ctx.bumps.resignation;
+
+    msg!("👋 Validator {} requested resignation", resignation.validator);
+    msg!("   Effective from slot: {}", resignation.effective_slot);
+
+    Ok(())
+}
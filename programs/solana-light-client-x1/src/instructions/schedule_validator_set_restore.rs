@@ -0,0 +1,167 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, ValidatorSetCheckpoint, PendingValidatorSetRestore, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_threshold_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+use crate::config::RESTORE_TIMELOCK_SLOTS;
+
+#[derive(Accounts)]
+pub struct ScheduleValidatorSetRestore<'info> {
+    /// Approvers come from the checkpoint, not the (possibly corrupted) live set
+    #[account(
+        seeds = [b"validator_set_checkpoint"],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, ValidatorSetCheckpoint>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Only one restore can be pending at a time
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + PendingValidatorSetRestore::INIT_SPACE,
+        seeds = [b"validator_set_restore"],
+        bump
+    )]
+    pub pending_restore: Account<'info, PendingValidatorSetRestore>,
+
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct ScheduleValidatorSetRestoreParams {
+    /// Snapshot to restore (exported with scripts/validator-set-snapshot.ts)
+    pub snapshot_version: u64,
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+
+    /// Signatures from checkpoint validators approving this restore
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Schedule a validator set restore from a signed snapshot
+///
+/// The snapshot must satisfy the same policy as update_validator_set and be
+/// approved by ≥threshold checkpoint validators. It can only be applied
+/// after RESTORE_TIMELOCK_SLOTS and can be cancelled until then.
+pub fn handler(
+    ctx: Context<ScheduleValidatorSetRestore>,
+    params: ScheduleValidatorSetRestoreParams,
+) -> Result<()> {
+    let checkpoint = &ctx.accounts.checkpoint;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🛟 Scheduling validator set restore");
+    msg!("   Checkpoint version: {}", checkpoint.version);
+    msg!("   Snapshot version: {}", params.snapshot_version);
+    msg!("   Snapshot: {} validators, threshold {}", params.validators.len(), params.threshold);
+
+    let mut seen = std::collections::HashSet::new();
+    require!(
+        !params.validators.is_empty()
+            && params.validators.len() <= 10
+            && params.validators.iter().all(|v| seen.insert(*v)),
+        LightClientError::InvalidValidatorSetSnapshot
+    );
+    require!(
+        params.threshold > 0 && params.validators.len() >= params.threshold as usize,
+        LightClientError::InvalidThreshold
+    );
+
+    // Same floor as update_validator_set: a restore is not a policy bypass
+    require!(
+        params.validators.len() >= bridge_config.min_validator_count as usize,
+        LightClientError::ValidatorCountBelowMinimum
+    );
+    require!(
+        params.threshold as usize >= bridge_config.min_threshold(params.validators.len()),
+        LightClientError::ThresholdBelowMinimum
+    );
+    require!(
+        bridge_config.threshold_allowed(params.validators.len(), params.threshold),
+        LightClientError::ThresholdBelowBftBound
+    );
+
+    let message = create_restore_message(
+        checkpoint.version,
+        bridge_config.nonce,
+        &params,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_threshold_approvals(
+        &params.approver_signatures,
+        &checkpoint.validators,
+        checkpoint.threshold,
+        &message,
+    )?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let current_slot = Clock::get()?.slot;
+    let pending = &mut ctx.accounts.pending_restore;
+    pending.snapshot_version = params.snapshot_version;
+    pending.validators = params.validators;
+    pending.threshold = params.threshold;
+    pending.proposer = ctx.accounts.proposer.key();
+    pending.scheduled_slot = current_slot;
+    pending.executable_slot = current_slot
+        .checked_add(RESTORE_TIMELOCK_SLOTS)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    pending.bump = ctx.bumps.pending_restore;
+
+    emit!(ValidatorSetRestoreScheduled {
+        snapshot_version: pending.snapshot_version,
+        validator_count: pending.validators.len() as u8,
+        threshold: pending.threshold,
+        executable_slot: pending.executable_slot,
+    });
+
+    msg!("✅ Restore scheduled, executable at slot {}", pending.executable_slot);
+
+    Ok(())
+}
+
+/// Create deterministic message for a validator set restore
+///
+/// Format: hash(RESTORE_VALIDATOR_SET || checkpoint_version || config_nonce || snapshot_version || validators || threshold)
+fn create_restore_message(
+    checkpoint_version: u64,
+    config_nonce: u64,
+    params: &ScheduleValidatorSetRestoreParams,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(17 + 32 * params.validators.len());
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&params.snapshot_version.to_le_bytes());
+    for validator in &params.validators {
+        payload.extend_from_slice(&validator.to_bytes());
+    }
+    payload.push(params.threshold);
+
+    create_governance_message(b"RESTORE_VALIDATOR_SET", checkpoint_version, &payload, x1_genesis_hash)
+}
+
+/// Event emitted when a restore is scheduled (monitoring should alert on this)
+#[event]
+pub struct ValidatorSetRestoreScheduled {
+    pub snapshot_version: u64,
+    pub validator_count: u8,
+    pub threshold: u8,
+    pub executable_slot: u64,
+}
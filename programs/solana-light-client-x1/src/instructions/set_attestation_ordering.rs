@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetAttestationOrdering<'info> {
+    #[account(
+        mut,
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetAttestationOrderingParams {
+    /// Require attestations in ascending validator set order
+    pub ordered: bool,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Turn the attestation ordering rule on or off (validator governance)
+///
+/// Takes effect at once: relayers must sort their bundles by validator
+/// index before it is turned on.
+pub fn handler(ctx: Context<SetAttestationOrdering>, params: SetAttestationOrderingParams) -> Result<()> {
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let validator_set = &mut ctx.accounts.validator_set;
+
+    msg!("🔢 Updating attestation ordering");
+    msg!("   {} -> {}", validator_set.ordered_attestations, params.ordered);
+
+    let message = create_attestation_ordering_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.ordered,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    validator_set.ordered_attestations = params.ordered;
+
+    emit!(AttestationOrderingUpdated {
+        ordered: params.ordered,
+        validator_set_version: validator_set.version,
+    });
+
+    msg!("✅ Attestation ordering updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for an attestation ordering change
+///
+/// Format: hash(SET_ATTESTATION_ORDER || version || config_nonce || ordered)
+fn create_attestation_ordering_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    ordered: bool,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(9);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(ordered as u8);
+
+    create_governance_message(b"SET_ATTESTATION_ORDER", validator_set_version, &payload, x1_genesis_hash)
+}
+
+#[event]
+pub struct AttestationOrderingUpdated {
+    pub ordered: bool,
+    pub validator_set_version: u64,
+}
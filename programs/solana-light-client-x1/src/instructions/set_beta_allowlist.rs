@@ -0,0 +1,127 @@
+use anchor_lang::prelude::*;
+use crate::state::{BetaAllowlist, BridgeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct SetBetaAllowlist<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()],
+        bump = beta_allowlist.bump
+    )]
+    pub beta_allowlist: Account<'info, BetaAllowlist>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetBetaAllowlistParams {
+    /// false = asset graduates to permissionless
+    pub enabled: bool,
+
+    /// X1 users allowed while enabled (replaces the whole list)
+    pub users: Vec<Pubkey>,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Replace an asset's beta allowlist, or disable it (validator governance)
+pub fn handler(
+    ctx: Context<SetBetaAllowlist>,
+    asset_id: u8,
+    params: SetBetaAllowlistParams,
+) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔄 Setting beta allowlist for asset {}", asset_id);
+    msg!("   Enabled: {}", params.enabled);
+    msg!("   Users: {}", params.users.len());
+
+    require!(
+        BetaAllowlist::is_valid_list(params.enabled, &params.users),
+        LightClientError::InvalidBetaAllowlist
+    );
+
+    let message = create_beta_allowlist_message(
+        validator_set.version,
+        bridge_config.nonce,
+        asset_id,
+        &params,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let list = &mut ctx.accounts.beta_allowlist;
+    list.enabled = params.enabled;
+    list.users = params.users;
+    list.updated_slot = Clock::get()?.slot;
+
+    emit!(BetaAllowlistUpdated {
+        asset_id,
+        enabled: list.enabled,
+        user_count: list.users.len() as u8,
+    });
+
+    msg!("✅ Beta allowlist updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a beta allowlist change
+///
+/// Format: hash(SET_BETA_ALLOWLIST || version || config_nonce || asset_id || enabled
+///              || user_count || users)
+fn create_beta_allowlist_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    asset_id: u8,
+    params: &SetBetaAllowlistParams,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(11 + 32 * params.users.len());
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(asset_id);
+    payload.push(params.enabled as u8);
+    payload.push(params.users.len() as u8);
+    for user in &params.users {
+        payload.extend_from_slice(user.as_ref());
+    }
+
+    create_governance_message(b"SET_BETA_ALLOWLIST", validator_set_version, &payload, x1_genesis_hash)
+}
+
+/// Event emitted when an asset's beta allowlist changes
+#[event]
+pub struct BetaAllowlistUpdated {
+    pub asset_id: u8,
+    pub enabled: bool,
+    pub user_count: u8,
+}
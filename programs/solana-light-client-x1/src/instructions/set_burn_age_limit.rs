@@ -0,0 +1,99 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, BurnAgeLimit, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetBurnAgeLimit<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_age_limit"],
+        bump = burn_age_limit.bump
+    )]
+    pub burn_age_limit: Account<'info, BurnAgeLimit>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetBurnAgeLimitParams {
+    /// Seconds, within MIN_MAX_BURN_AGE..=MAX_MAX_BURN_AGE and not above
+    /// the current limit
+    pub max_burn_age: i64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Set or lower the burn age limit (validator governance)
+///
+/// One-way: replay guards closed under the current limit would reopen if
+/// it were raised, so it can only go down.
+pub fn handler(ctx: Context<SetBurnAgeLimit>, params: SetBurnAgeLimitParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let limit = &mut ctx.accounts.burn_age_limit;
+
+    msg!("⏳ Updating burn age limit");
+    msg!("   {}s -> {}s", limit.max_burn_age, params.max_burn_age);
+
+    require!(
+        BurnAgeLimit::is_valid_change(limit.max_burn_age, params.max_burn_age),
+        LightClientError::InvalidBurnAgeLimit
+    );
+
+    let message = create_burn_age_limit_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.max_burn_age,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    limit.max_burn_age = params.max_burn_age;
+    limit.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Burn age limit updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a burn age limit change
+///
+/// Format: hash(SET_BURN_AGE_LIMIT || version || config_nonce || max_burn_age)
+fn create_burn_age_limit_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    max_burn_age: i64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&max_burn_age.to_le_bytes());
+
+    create_governance_message(b"SET_BURN_AGE_LIMIT", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,111 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, BridgeConfig, ConversionTable, ConversionRate, AssetRegistry, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetConversionRate<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Holds the governance nonce (replay protection)
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"conversion_table"],
+        bump = conversion_table.bump
+    )]
+    pub conversion_table: Account<'info, ConversionTable>,
+
+    /// Asset registry (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetConversionRateParams {
+    /// Rate to insert or replace (enabled = false disables the pair)
+    pub rate: ConversionRate,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Insert, replace or disable a directed conversion rate
+pub fn handler(ctx: Context<SetConversionRate>, params: SetConversionRateParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let rate = params.rate;
+
+    msg!("🔄 Setting conversion rate");
+    msg!("   {} -> {}: {}/{} (enabled: {})",
+         rate.from_asset, rate.to_asset, rate.numerator, rate.denominator, rate.enabled);
+
+    // Both assets must be registered and distinct
+    AssetRegistry::resolve(&ctx.accounts.asset_registry, rate.from_asset)?;
+    AssetRegistry::resolve(&ctx.accounts.asset_registry, rate.to_asset)?;
+    require!(rate.from_asset != rate.to_asset, LightClientError::InvalidConversionRate);
+    require!(
+        rate.numerator > 0 && rate.denominator > 0,
+        LightClientError::InvalidConversionRate
+    );
+
+    let message = create_conversion_rate_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &rate,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    require!(
+        ctx.accounts.conversion_table.upsert(rate),
+        LightClientError::ConversionTableFull
+    );
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("✅ Conversion rate updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a conversion rate change
+///
+/// Format: hash(SET_CONVERSION_RATE || version || config_nonce || from || to || numerator || denominator || enabled)
+fn create_conversion_rate_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    rate: &ConversionRate,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(27);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(rate.from_asset);
+    payload.push(rate.to_asset);
+    payload.extend_from_slice(&rate.numerator.to_le_bytes());
+    payload.extend_from_slice(&rate.denominator.to_le_bytes());
+    payload.push(rate.enabled as u8);
+
+    create_governance_message(b"SET_CONVERSION_RATE", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,114 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, DevModeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetDevModeConfig<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"dev_mode_config"],
+        bump = dev_mode_config.bump
+    )]
+    pub dev_mode_config: Account<'info, DevModeConfig>,
+
+    /// CHECK: Must sign and match params.canary when marking; checked in the handler
+    pub canary: UncheckedAccount<'info>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetDevModeConfigParams {
+    /// Mark (true) or unmark (false) this deployment as non-production
+    pub non_production: bool,
+
+    /// Canary system account submits must pass (ignored when unmarking)
+    pub canary: Pubkey,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Mark or unmark the deployment as non-production (validator governance)
+///
+/// Marking is refused outside dev-mode builds, so production binaries can
+/// never be marked. Approvals are checked like any governance action: mock
+/// signatures never pass here. Unmarking is always allowed.
+pub fn handler(ctx: Context<SetDevModeConfig>, params: SetDevModeConfigParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let dev_mode = &mut ctx.accounts.dev_mode_config;
+
+    msg!("🧪 Updating dev mode config");
+    msg!("   Non-production: {} -> {}", dev_mode.non_production, params.non_production);
+
+    if params.non_production {
+        require!(cfg!(feature = "dev-mode"), LightClientError::InvalidDevModeConfig);
+        let canary = &ctx.accounts.canary;
+        require!(
+            canary.is_signer && DevModeConfig::canary_alive(&params.canary, canary),
+            LightClientError::InvalidDevModeCanary
+        );
+        msg!("   Canary: {}", params.canary);
+    }
+
+    let message = create_dev_mode_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.non_production,
+        &params.canary,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    dev_mode.non_production = params.non_production;
+    dev_mode.canary = if params.non_production { params.canary } else { Pubkey::default() };
+    dev_mode.marked_slot = Clock::get()?.slot;
+
+    msg!("✅ Dev mode config updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a dev mode change
+///
+/// Format: hash(SET_DEV_MODE || version || config_nonce || non_production || canary)
+fn create_dev_mode_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    non_production: bool,
+    canary: &Pubkey,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(41);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(non_production as u8);
+    payload.extend_from_slice(canary.as_ref());
+
+    create_governance_message(b"SET_DEV_MODE", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,97 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, EventSchemaRegistry, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetEventSchemaVersion<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"event_schema_registry"],
+        bump = event_schema_registry.bump
+    )]
+    pub event_schema_registry: Account<'info, EventSchemaRegistry>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetEventSchemaVersionParams {
+    /// Program whose events changed
+    pub program: Pubkey,
+
+    /// New schema version (must be above the registered one)
+    pub version: u16,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Record a program's new event schema version (validator governance)
+///
+/// Submitted together with the upgrade that changes the program's events.
+pub fn handler(ctx: Context<SetEventSchemaVersion>, params: SetEventSchemaVersionParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let registry = &mut ctx.accounts.event_schema_registry;
+
+    msg!("🔄 Updating event schema of {}", params.program);
+    msg!("   Version: {} -> {}", registry.version_of(&params.program), params.version);
+
+    let message = create_event_schema_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params.program,
+        params.version,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    registry.set_version(params.program, params.version, Clock::get()?.slot)?;
+
+    msg!("✅ Event schema version updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for an event schema version bump
+///
+/// Format: hash(SET_EVENT_SCHEMA || version || config_nonce || program || schema_version)
+fn create_event_schema_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    program: &Pubkey,
+    schema_version: u16,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(42);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(program.as_ref());
+    payload.extend_from_slice(&schema_version.to_le_bytes());
+
+    create_governance_message(b"SET_EVENT_SCHEMA", validator_set_version, &payload, x1_genesis_hash)
+}
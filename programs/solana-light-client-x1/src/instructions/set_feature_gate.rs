@@ -0,0 +1,94 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, FeatureGate, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetFeatureGate<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"feature_gate"],
+        bump = feature_gate.bump
+    )]
+    pub feature_gate: Account<'info, FeatureGate>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetFeatureGateParams {
+    /// Complete new set of disabled gates (state::gates bits, 0 = all enabled)
+    pub disabled: u64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Replace the disabled gate bits (validator governance)
+///
+/// The full mask is signed rather than a toggle, so an approval means the
+/// same thing whatever the current state is. The emergency pause bit is
+/// not part of it (pause_bridge / unpause_bridge).
+pub fn handler(ctx: Context<SetFeatureGate>, params: SetFeatureGateParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let feature_gate = &mut ctx.accounts.feature_gate;
+
+    msg!("🔄 Updating feature gate");
+    msg!("   Disabled: {:#x} -> {:#x}", feature_gate.disabled, params.disabled);
+
+    let message = create_feature_gate_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.disabled,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    feature_gate.disabled = (params.disabled & !FeatureGate::PAUSED) | (feature_gate.disabled & FeatureGate::PAUSED);
+    feature_gate.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Feature gate updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a feature gate change
+///
+/// Format: hash(SET_FEATURE_GATE || version || config_nonce || disabled)
+fn create_feature_gate_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    disabled: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&disabled.to_le_bytes());
+
+    create_governance_message(b"SET_FEATURE_GATE", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetMaxAttestationAge<'info> {
+    #[account(
+        mut,
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetMaxAttestationAgeParams {
+    /// Seconds, within MIN_MAX_ATTESTATION_AGE..=MAX_MAX_ATTESTATION_AGE
+    pub max_attestation_age: i64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Change how old a V3 attestation may be when submitted (validator governance)
+///
+/// Takes effect at once, also for attestations already collected: lowering
+/// it can strand a relayer's bundle, which then re-collects signatures.
+pub fn handler(ctx: Context<SetMaxAttestationAge>, params: SetMaxAttestationAgeParams) -> Result<()> {
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let validator_set = &mut ctx.accounts.validator_set;
+
+    msg!("⏱️ Updating max attestation age");
+    msg!("   {}s -> {}s", validator_set.effective_max_attestation_age(), params.max_attestation_age);
+
+    require!(
+        (X1ValidatorSet::MIN_MAX_ATTESTATION_AGE..=X1ValidatorSet::MAX_MAX_ATTESTATION_AGE)
+            .contains(&params.max_attestation_age),
+        LightClientError::InvalidAttestationAge
+    );
+
+    let message = create_max_attestation_age_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.max_attestation_age,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    validator_set.max_attestation_age = params.max_attestation_age;
+
+    msg!("✅ Max attestation age updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a max attestation age change
+///
+/// Format: hash(SET_ATTESTATION_AGE || version || config_nonce || max_attestation_age)
+fn create_max_attestation_age_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    max_attestation_age: i64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&max_attestation_age.to_le_bytes());
+
+    create_governance_message(b"SET_ATTESTATION_AGE", validator_set_version, &payload, x1_genesis_hash)
+}
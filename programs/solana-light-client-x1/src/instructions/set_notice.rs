@@ -0,0 +1,130 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, Notice, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetNotice<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"notice"],
+        bump = notice.bump
+    )]
+    pub notice: Account<'info, Notice>,
+
+    /// Signer submitting the notice (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetNoticeParams {
+    /// sha256 of the notice text (ignored by clients when clearing)
+    pub message_hash: [u8; 32],
+
+    /// X1 slot from which the announced event applies
+    pub effective_slot: u64,
+
+    /// NOTICE_NONE clears the board
+    pub severity: u8,
+
+    /// Signatures from current validators approving this notice
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Post or clear the maintenance notice (validator governance)
+///
+/// Purely informational: no instruction reads the board.
+pub fn handler(ctx: Context<SetNotice>, params: SetNoticeParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let notice = &mut ctx.accounts.notice;
+
+    msg!("📢 Updating notice board");
+    msg!("   Severity {} -> {}, effective slot {}", notice.severity, params.severity, params.effective_slot);
+
+    require!(
+        Notice::is_valid(&params.message_hash, params.severity),
+        LightClientError::InvalidNotice
+    );
+
+    let message = create_notice_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params.message_hash,
+        params.effective_slot,
+        params.severity,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    notice.message_hash = params.message_hash;
+    notice.effective_slot = params.effective_slot;
+    notice.severity = params.severity;
+    notice.sequence = notice.sequence
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    notice.posted_slot = Clock::get()?.slot;
+
+    emit!(NoticePosted {
+        sequence: notice.sequence,
+        message_hash: notice.message_hash,
+        effective_slot: notice.effective_slot,
+        severity: notice.severity,
+    });
+
+    msg!("✅ Notice {} posted", notice.sequence);
+
+    Ok(())
+}
+
+/// Create deterministic message for a notice
+///
+/// Format: hash(SET_NOTICE || version || config_nonce || message_hash || effective_slot || severity)
+fn create_notice_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    message_hash: &[u8; 32],
+    effective_slot: u64,
+    severity: u8,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(49);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(message_hash);
+    payload.extend_from_slice(&effective_slot.to_le_bytes());
+    payload.push(severity);
+
+    create_governance_message(b"SET_NOTICE", validator_set_version, &payload, x1_genesis_hash)
+}
+
+/// Event emitted for every notice posted or cleared (wallets may subscribe)
+#[event]
+pub struct NoticePosted {
+    pub sequence: u64,
+    pub message_hash: [u8; 32],
+    pub effective_slot: u64,
+    pub severity: u8,
+}
@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, BridgeConfig, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetV2Cutoff<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetV2CutoffParams {
+    /// Slot at which V2 instructions stop accepting submissions (0 = cancel)
+    pub cutoff_slot: u64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Schedule (or cancel) the V2 deprecation cutoff
+///
+/// Rules:
+/// - New cutoff must be in the future (or 0 to cancel)
+/// - Once a scheduled cutoff has passed, V2 is closed for good
+pub fn handler(ctx: Context<SetV2Cutoff>, params: SetV2CutoffParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let current_slot = Clock::get()?.slot;
+
+    msg!("🔄 Setting V2 cutoff slot");
+    msg!("   Current cutoff: {}", bridge_config.v2_cutoff_slot);
+    msg!("   New cutoff: {}", params.cutoff_slot);
+
+    require!(
+        !bridge_config.is_v2_deprecated(current_slot),
+        LightClientError::InvalidCutoffSlot
+    );
+    require!(
+        params.cutoff_slot == 0 || params.cutoff_slot > current_slot,
+        LightClientError::InvalidCutoffSlot
+    );
+
+    let message = create_v2_cutoff_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.cutoff_slot,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.v2_cutoff_slot = params.cutoff_slot;
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("✅ V2 cutoff updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for V2 cutoff change
+///
+/// Format: hash(SET_V2_CUTOFF || version || config_nonce || cutoff_slot)
+fn create_v2_cutoff_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    cutoff_slot: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&cutoff_slot.to_le_bytes());
+
+    create_governance_message(b"SET_V2_CUTOFF", validator_set_version, &payload, x1_genesis_hash)
+}
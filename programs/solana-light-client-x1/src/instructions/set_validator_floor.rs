@@ -0,0 +1,107 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, BridgeConfig, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetValidatorFloor<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetValidatorFloorParams {
+    /// Minimum number of validators a new set must contain
+    pub min_validator_count: u8,
+
+    /// Minimum threshold as a fraction of the new set size
+    pub min_threshold_numerator: u8,
+    pub min_threshold_denominator: u8,
+
+    /// Require threshold > 2/3 of the validators (BFT assumption)
+    pub bft_mode: bool,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Change the validator set floor / BFT mode enforced by update_validator_set
+///
+/// Only affects future updates; the current set is not re-checked.
+pub fn handler(ctx: Context<SetValidatorFloor>, params: SetValidatorFloorParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+
+    msg!("🔄 Setting validator floor");
+    msg!("   Min validators: {} -> {}", bridge_config.min_validator_count, params.min_validator_count);
+    msg!("   Min threshold: {}/{} -> {}/{}",
+         bridge_config.min_threshold_numerator, bridge_config.min_threshold_denominator,
+         params.min_threshold_numerator, params.min_threshold_denominator);
+    msg!("   BFT mode: {} -> {}", bridge_config.bft_mode, params.bft_mode);
+
+    require!(
+        params.min_validator_count > 0
+            && params.min_threshold_numerator > 0
+            && params.min_threshold_denominator > 0
+            && params.min_threshold_numerator <= params.min_threshold_denominator,
+        LightClientError::InvalidValidatorFloor
+    );
+
+    let message = create_validator_floor_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.min_validator_count = params.min_validator_count;
+    bridge_config.min_threshold_numerator = params.min_threshold_numerator;
+    bridge_config.min_threshold_denominator = params.min_threshold_denominator;
+    bridge_config.bft_mode = params.bft_mode;
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("✅ Validator floor updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a validator floor change
+///
+/// Format: hash(SET_VALIDATOR_FLOOR || version || config_nonce || min_count || numerator || denominator || bft_mode)
+fn create_validator_floor_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    params: &SetValidatorFloorParams,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(12);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(params.min_validator_count);
+    payload.push(params.min_threshold_numerator);
+    payload.push(params.min_threshold_denominator);
+    payload.push(params.bft_mode as u8);
+
+    create_governance_message(b"SET_VALIDATOR_FLOOR", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,103 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, ValidatorSetTimelock, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetValidatorSetUpdateDelay<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_set_timelock"],
+        bump = validator_set_timelock.bump
+    )]
+    pub validator_set_timelock: Account<'info, ValidatorSetTimelock>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetValidatorSetUpdateDelayParams {
+    /// Seconds, within MIN_DELAY_SECONDS..=MAX_DELAY_SECONDS
+    pub delay_seconds: i64,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Change the validator set update delay (validator governance)
+///
+/// Applies to proposals made afterwards; a pending update keeps the
+/// executable time it was proposed with.
+pub fn handler(ctx: Context<SetValidatorSetUpdateDelay>, params: SetValidatorSetUpdateDelayParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let timelock = &mut ctx.accounts.validator_set_timelock;
+
+    msg!("⏳ Updating validator set update delay");
+    msg!("   {}s -> {}s", timelock.delay_seconds, params.delay_seconds);
+
+    require!(
+        ValidatorSetTimelock::is_valid_delay(params.delay_seconds),
+        LightClientError::InvalidValidatorSetTimelock
+    );
+
+    let message = create_update_delay_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.delay_seconds,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    timelock.delay_seconds = params.delay_seconds;
+    timelock.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Validator set update delay updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for an update delay change
+///
+/// Format: hash(SET_VALIDATOR_SET_UPDATE_DELAY || version || config_nonce || delay_seconds)
+fn create_update_delay_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    delay_seconds: i64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(16);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.extend_from_slice(&delay_seconds.to_le_bytes());
+
+    create_governance_message(
+        b"SET_VALIDATOR_SET_UPDATE_DELAY",
+        validator_set_version,
+        &payload,
+        x1_genesis_hash,
+    )
+}
@@ -0,0 +1,138 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, WormholeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct SetWormholeConfig<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"wormhole_config"],
+        bump = wormhole_config.bump
+    )]
+    pub wormhole_config: Account<'info, WormholeConfig>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetWormholeConfigParams {
+    /// Accept burns verified from Wormhole VAAs
+    pub enabled: bool,
+
+    /// Wormhole core bridge program on X1
+    pub core_bridge: Pubkey,
+
+    /// Wormhole chain id and address of the burn emitter
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+
+    /// Lowest accepted VAA consistency level
+    pub min_consistency_level: u8,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Set the trusted Wormhole core bridge and emitter (validator governance)
+///
+/// Enabling the path trusts the guardians and the emitter as much as a
+/// validator quorum, for every asset.
+pub fn handler(ctx: Context<SetWormholeConfig>, params: SetWormholeConfigParams) -> Result<()> {
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let validator_set = &ctx.accounts.validator_set;
+    let config = &mut ctx.accounts.wormhole_config;
+
+    msg!("🌀 Updating Wormhole config");
+    msg!("   Enabled: {} -> {}", config.enabled, params.enabled);
+    msg!("   Core bridge: {}", params.core_bridge);
+    msg!("   Emitter: chain {} {}", params.emitter_chain, Pubkey::new_from_array(params.emitter_address));
+
+    require!(
+        WormholeConfig::is_valid(params.enabled, &params.core_bridge, &params.emitter_address),
+        LightClientError::InvalidWormholeConfig
+    );
+
+    let message = create_wormhole_config_message(
+        validator_set.version,
+        bridge_config.nonce,
+        &params,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    config.enabled = params.enabled;
+    config.core_bridge = params.core_bridge;
+    config.emitter_chain = params.emitter_chain;
+    config.emitter_address = params.emitter_address;
+    config.min_consistency_level = params.min_consistency_level;
+    config.updated_slot = Clock::get()?.slot;
+
+    emit!(WormholeConfigUpdated {
+        enabled: params.enabled,
+        core_bridge: params.core_bridge,
+        emitter_chain: params.emitter_chain,
+        emitter_address: params.emitter_address,
+        min_consistency_level: params.min_consistency_level,
+        validator_set_version: validator_set.version,
+    });
+
+    msg!("✅ Wormhole config updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a Wormhole config change
+///
+/// Format: hash(SET_WORMHOLE_CONFIG || version || config_nonce || enabled
+///   || core_bridge || emitter_chain || emitter_address || min_consistency_level)
+fn create_wormhole_config_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    params: &SetWormholeConfigParams,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(8 + 1 + 32 + 2 + 32 + 1);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(params.enabled as u8);
+    payload.extend_from_slice(params.core_bridge.as_ref());
+    payload.extend_from_slice(&params.emitter_chain.to_le_bytes());
+    payload.extend_from_slice(&params.emitter_address);
+    payload.push(params.min_consistency_level);
+
+    create_governance_message(b"SET_WORMHOLE_CONFIG", validator_set_version, &payload, x1_genesis_hash)
+}
+
+#[event]
+pub struct WormholeConfigUpdated {
+    pub enabled: bool,
+    pub core_bridge: Pubkey,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub min_consistency_level: u8,
+    pub validator_set_version: u64,
+}
@@ -0,0 +1,179 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as IX_SYSVAR_ID,
+};
+use anchor_lang::system_program::{allocate, assign, Allocate, Assign};
+use anchor_lang::Discriminator;
+use crate::state::{RentSponsorPool, SponsoredRent, VerifiedBurnV3, FeatureGate, gates};
+use crate::errors::LightClientError;
+
+/// Pay a small transfer's VerifiedBurnV3 rent from the sponsor pool
+///
+/// Must be immediately followed, in the same transaction, by
+/// submit_burn_attestation_v3 for the same asset, nonce and user. This
+/// instruction pre-funds the burn's PDA with its rent, so the `init` in
+/// that submit only allocates it and takes nothing from the user (the
+/// transaction fee can be paid by a relayer). If the submit fails, the
+/// whole transaction and the sponsorship revert.
+///
+/// SECURITY:
+/// - The amount checked against the pool limit is read from the submit
+///   instruction itself, which then verifies the attestations
+/// - A SponsoredRent marker records the loan; archive_verified_burn
+///   returns both accounts' rent to the pool, never to the user
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct SponsorBurnRent<'info> {
+    #[account(
+        mut,
+        seeds = [b"rent_sponsor_pool"],
+        bump = rent_sponsor_pool.bump
+    )]
+    pub rent_sponsor_pool: Account<'info, RentSponsorPool>,
+
+    /// CHECK: Not created yet (size and owner checked in handler)
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_burn: UncheckedAccount<'info>,
+
+    /// CHECK: Created in handler (funded by the pool)
+    #[account(
+        mut,
+        seeds = [
+            b"sponsored_rent",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub sponsored_rent: UncheckedAccount<'info>,
+
+    pub user: Signer<'info>,
+
+    /// CHECK: Instructions sysvar (address checked)
+    #[account(address = IX_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SponsorBurnRent>, asset_id: u8, burn_nonce: u64) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::RENT_SPONSOR)?;
+
+    let user = ctx.accounts.user.key();
+    let verified_burn = ctx.accounts.verified_burn.to_account_info();
+    let marker = ctx.accounts.sponsored_rent.to_account_info();
+
+    require!(
+        verified_burn.data_is_empty() && marker.data_is_empty() && marker.lamports() == 0,
+        LightClientError::RentSponsorshipNotAllowed
+    );
+
+    let amount = next_submit_amount(
+        &ctx.accounts.instructions,
+        asset_id,
+        burn_nonce,
+        &user,
+        &verified_burn.key(),
+    )?;
+    require!(
+        ctx.accounts.rent_sponsor_pool.sponsors(amount),
+        LightClientError::RentSponsorshipNotAllowed
+    );
+
+    let rent = Rent::get()?;
+    let burn_rent = rent
+        .minimum_balance(8 + VerifiedBurnV3::INIT_SPACE)
+        .saturating_sub(verified_burn.lamports());
+    let marker_rent = rent.minimum_balance(8 + SponsoredRent::INIT_SPACE);
+    let lent = burn_rent + marker_rent;
+
+    let pool_info = ctx.accounts.rent_sponsor_pool.to_account_info();
+    let rent_floor = rent.minimum_balance(pool_info.data_len());
+    require!(
+        RentSponsorPool::available(pool_info.lamports(), rent_floor) >= lent,
+        LightClientError::InsufficientSponsorFunds
+    );
+
+    **pool_info.try_borrow_mut_lamports()? -= lent;
+    **verified_burn.try_borrow_mut_lamports()? += burn_rent;
+    **marker.try_borrow_mut_lamports()? += marker_rent;
+
+    // Lamports are already there: only allocate and assign the marker
+    let asset_seed = asset_id.to_le_bytes();
+    let nonce_seed = burn_nonce.to_le_bytes();
+    let bump = [ctx.bumps.sponsored_rent];
+    let signer_seeds: &[&[&[u8]]] = &[&[b"sponsored_rent", &asset_seed, user.as_ref(), &nonce_seed, &bump]];
+    let system_program = ctx.accounts.system_program.to_account_info();
+    allocate(
+        CpiContext::new_with_signer(system_program.clone(), Allocate { account_to_allocate: marker.clone() }, signer_seeds),
+        (8 + SponsoredRent::INIT_SPACE) as u64,
+    )?;
+    assign(
+        CpiContext::new_with_signer(system_program, Assign { account_to_assign: marker.clone() }, signer_seeds),
+        &crate::ID,
+    )?;
+    SponsoredRent { lamports: lent, bump: ctx.bumps.sponsored_rent }
+        .try_serialize(&mut &mut marker.try_borrow_mut_data()?[..])?;
+
+    let pool = &mut ctx.accounts.rent_sponsor_pool;
+    pool.total_sponsored = pool.total_sponsored
+        .checked_add(lent)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    pool.outstanding = pool.outstanding
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("🎁 Sponsored rent for burn {} (asset {}): {} lamports", burn_nonce, asset_id, lent);
+
+    Ok(())
+}
+
+/// Burn amount of the submit_burn_attestation_v3 that must follow this
+/// instruction for the same asset, nonce, user and VerifiedBurnV3 PDA
+fn next_submit_amount(
+    instructions: &AccountInfo,
+    asset_id: u8,
+    burn_nonce: u64,
+    user: &Pubkey,
+    verified_burn: &Pubkey,
+) -> Result<u64> {
+    let current = load_current_index_checked(instructions)? as usize;
+    let ix = load_instruction_at_checked(current + 1, instructions)
+        .map_err(|_| error!(LightClientError::RentSponsorshipNotAllowed))?;
+
+    require!(
+        ix.program_id == crate::ID
+            && ix.data.len() >= 8
+            && ix.data[..8] == crate::instruction::SubmitBurnAttestationV3::DISCRIMINATOR,
+        LightClientError::RentSponsorshipNotAllowed
+    );
+    let args = crate::instruction::SubmitBurnAttestationV3::try_from_slice(&ix.data[8..])
+        .map_err(|_| error!(LightClientError::RentSponsorshipNotAllowed))?;
+
+    // Accounts: user, validator_set, verified_burn, ...
+    require!(
+        args.asset_id == asset_id
+            && args.burn_nonce == burn_nonce
+            && ix.accounts.len() >= 3
+            && ix.accounts[0].pubkey == *user
+            && ix.accounts[2].pubkey == *verified_burn,
+        LightClientError::RentSponsorshipNotAllowed
+    );
+
+    Ok(args.attestation.amount)
+}
@@ -1,7 +1,10 @@
 use anchor_lang::prelude::*;
-use crate::state::{X1ValidatorSet, VerifiedBurn, BurnAttestationData};
+use anchor_lang::Discriminator;
+use crate::compute_report::ComputeMeter;
+use crate::state::{DevModeConfig, X1ValidatorSet, VerifiedBurn, BurnAttestationData, BridgeConfig, BridgeDomain, X1ChainId, FeatureGate, gates, Asset, LIGHT_CLIENT_EVENT_SCHEMA_VERSION};
 use crate::errors::LightClientError;
-use crate::DOMAIN_SEPARATOR;
+use crate::ed25519_utils::check_signature_encoding;
+use crate::governance::require_weight;
 
 #[derive(Accounts)]
 #[instruction(attestation: BurnAttestationData)]
@@ -16,6 +19,13 @@ pub struct SubmitBurnAttestation<'info> {
     )]
     pub validator_set: Account<'info, X1ValidatorSet>,
 
+    /// Bridge configuration (V2 deprecation cutoff)
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
     /// Verified burn PDA (stores verification result)
     #[account(
         init,
@@ -30,6 +40,27 @@ pub struct SubmitBurnAttestation<'info> {
     )]
     pub verified_burn: Account<'info, VerifiedBurn>,
 
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// This deployment's domain separator (not initialized = DOMAIN_SEPARATOR)
+    /// CHECK: Address pinned by seeds; read by BridgeDomain::resolve
+    #[account(seeds = [b"bridge_domain"], bump)]
+    pub bridge_domain: UncheckedAccount<'info>,
+
+    /// X1 genesis hash binding (not initialized = unbound messages)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::bind_domain
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Non-production marking (not initialized = production; mock
+    /// signatures also need the canary as first remaining account)
+    /// CHECK: Address pinned by seeds; read by DevModeConfig::mock_signatures_allowed
+    #[account(seeds = [b"dev_mode_config"], bump)]
+    pub dev_mode_config: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -37,6 +68,9 @@ pub fn handler(
     ctx: Context<SubmitBurnAttestation>,
     attestation: BurnAttestationData,
 ) -> Result<()> {
+    let meter = ComputeMeter::start();
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_V2)?;
+
     msg!("🔐 Verifying X1 validator attestations (V2 - Trustless)");
     msg!("   Burn nonce: {}", attestation.burn_nonce);
     msg!("   User: {}", attestation.user);
@@ -44,6 +78,12 @@ pub fn handler(
     msg!("   Validator set version: {}", attestation.validator_set_version);
     msg!("   Attestations received: {}", attestation.attestations.len());
 
+    // V2 is deprecated once the governed cutoff slot is reached
+    require!(
+        !ctx.accounts.bridge_config.is_v2_deprecated(Clock::get()?.slot),
+        LightClientError::V2Deprecated
+    );
+
     let validator_set = &ctx.accounts.validator_set;
 
     // SECURITY CRITICAL: Verify attestations are for CURRENT version
@@ -56,13 +96,23 @@ pub fn handler(
     msg!("✓ Version matches current: {}", validator_set.version);
 
     // Build message that validators signed (with domain separator and version)
+    let domain = X1ChainId::bind_domain(
+        BridgeDomain::resolve(&ctx.accounts.bridge_domain)?,
+        &ctx.accounts.x1_chain_id,
+    )?;
     let message = create_attestation_message(
+        &domain,
         attestation.burn_nonce,
         attestation.user,
         attestation.amount,
         attestation.validator_set_version,
     );
 
+    let allow_mock = DevModeConfig::mock_signatures_allowed(&ctx.accounts.dev_mode_config, ctx.remaining_accounts.first())?;
+    if allow_mock {
+        msg!("⚠️  Non-production deployment: mock signatures accepted");
+    }
+
     // Verify each attestation
     let mut valid_count = 0;
     let mut seen_validators = std::collections::HashSet::new();
@@ -87,6 +137,7 @@ pub fn handler(
             &attest.validator_pubkey.to_bytes(),
             &message,
             &attest.signature,
+            allow_mock,
         )?;
 
         msg!("   ✅ Valid signature");
@@ -98,6 +149,7 @@ pub fn handler(
         valid_count >= validator_set.threshold,
         LightClientError::InsufficientAttestations
     );
+    require_weight(validator_set, attestation.attestations.iter().map(|attest| &attest.validator_pubkey))?;
 
     msg!("✅ Threshold met: {}/{}", valid_count, validator_set.threshold);
 
@@ -110,11 +162,43 @@ pub fn handler(
     verified_burn.processed = false;
     verified_burn.bump = ctx.bumps.verified_burn;
 
+    emit!(BurnVerified {
+        schema_version: LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+        attestation_version: 2,
+        asset_id: Asset::XENCAT as u8,
+        burn_nonce: attestation.burn_nonce,
+        user: verified_burn.user,
+        amount: attestation.amount,
+        validator_set_version: validator_set.version,
+        attestations: valid_count,
+        verified_slot: Clock::get()?.slot,
+    });
+
     msg!("✅ Burn verified and stored!");
 
+    meter.report(crate::instruction::SubmitBurnAttestation::DISCRIMINATOR, valid_count);
+
     Ok(())
 }
 
+/// Event emitted when a burn passes threshold attestation (V2 and V3)
+///
+/// V2 burns are XENCAT only, so they report Asset::XENCAT.
+#[event]
+pub struct BurnVerified {
+    pub schema_version: u16,
+    /// Attestation format that verified the burn (2 or 3)
+    pub attestation_version: u8,
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub validator_set_version: u64,
+    /// Valid attestations counted toward the threshold
+    pub attestations: u8,
+    pub verified_slot: u64,
+}
+
 /// Create the message that X1 validators sign
 ///
 /// Format: hash(DOMAIN_SEPARATOR || validator_set_version || burn_nonce || amount || user)
@@ -124,6 +208,7 @@ pub fn handler(
 /// - Replay after validator updates (version binding)
 /// - Signature forgery (all critical data included)
 fn create_attestation_message(
+    domain: &[u8],
     burn_nonce: u64,
     user: Pubkey,
     amount: u64,
@@ -132,7 +217,7 @@ fn create_attestation_message(
     use anchor_lang::solana_program::hash::hash;
 
     let mut message_data = Vec::new();
-    message_data.extend_from_slice(DOMAIN_SEPARATOR.as_bytes());
+    message_data.extend_from_slice(domain);
     message_data.extend_from_slice(&validator_set_version.to_le_bytes());
     message_data.extend_from_slice(&burn_nonce.to_le_bytes());
     message_data.extend_from_slice(&amount.to_le_bytes());
@@ -167,7 +252,11 @@ fn verify_ed25519_signature(
     pubkey: &[u8; 32],
     message: &[u8],
     signature: &[u8; 64],
+    allow_mock: bool,
 ) -> Result<()> {
+    // Reject mock, malleable and weak-key signatures
+    check_signature_encoding(pubkey, signature, allow_mock)?;
+
     // Validate signature format
     require!(
         signature.len() == 64,
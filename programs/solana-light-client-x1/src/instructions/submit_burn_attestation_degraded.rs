@@ -0,0 +1,233 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::compute_report::ComputeMeter;
+use crate::state::{
+    X1ValidatorSet, DevModeConfig, VerifiedBurnV3, BurnAttestationDataV3, AssetRegistry, BurnAgeLimit, BetaAllowlist, ChallengeRequest,
+    BridgeDomain, X1ChainId, FeatureGate, DegradedMode, DegradedBurn, gates,
+};
+use crate::errors::LightClientError;
+use crate::instructions::submit_burn_attestation_v3::{record_latency, verify_attestations};
+
+/// Submit burn attestation with a reduced quorum (degraded mode)
+///
+/// Same message, signatures and VerifiedBurnV3 PDA as submit_burn_attestation_v3,
+/// but accepts DegradedMode.quorum valid attestations instead of the threshold.
+///
+/// SECURITY:
+/// - Only when governance has enabled degraded mode for this asset
+/// - Per-transfer and daily caps bound what a smaller quorum can release
+/// - The burn is stored held (`processed = true`) with a DegradedBurn marker,
+///   so mint programs reject it until release_degraded_burn runs after the
+///   delay; guardians can freeze it in the meantime
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct SubmitBurnAttestationDegraded<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        mut,
+        seeds = [b"degraded_mode", asset_id.to_le_bytes().as_ref()],
+        bump = degraded_mode.bump
+    )]
+    pub degraded_mode: Account<'info, DegradedMode>,
+
+    /// Same PDA as the threshold path: one verification per burn either way
+    #[account(
+        init,
+        payer = user,
+        space = 8 + VerifiedBurnV3::INIT_SPACE,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + DegradedBurn::INIT_SPACE,
+        seeds = [
+            b"degraded_burn",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub degraded_burn: Account<'info, DegradedBurn>,
+
+    /// Per-validator latency histogram (SLA tracking; not initialized = not recorded)
+    /// CHECK: Address pinned by seeds; read and written by record_latency
+    #[account(mut, seeds = [b"validator_latency_stats"], bump)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// This deployment's domain separator (not initialized = DOMAIN_SEPARATOR)
+    /// CHECK: Address pinned by seeds; read by BridgeDomain::resolve
+    #[account(seeds = [b"bridge_domain"], bump)]
+    pub bridge_domain: UncheckedAccount<'info>,
+
+    /// X1 genesis hash binding (not initialized = unbound messages)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::bind_domain
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Oldest verifiable burn (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read by BurnAgeLimit::enforce
+    #[account(seeds = [b"burn_age_limit"], bump)]
+    pub burn_age_limit: UncheckedAccount<'info>,
+
+    /// The asset's beta allowlist (not initialized = permissionless)
+    /// CHECK: Address pinned by seeds; read by BetaAllowlist::enforce
+    #[account(seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()], bump)]
+    pub beta_allowlist: UncheckedAccount<'info>,
+
+    /// The burn's attestation challenge (not initialized = none required)
+    /// CHECK: Address pinned by seeds; read by ChallengeRequest::load
+    #[account(
+        seeds = [
+            b"challenge_request",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub challenge_request: UncheckedAccount<'info>,
+
+    /// Non-production marking (not initialized = production; mock
+    /// signatures also need the canary as first remaining account)
+    /// CHECK: Address pinned by seeds; read by DevModeConfig::mock_signatures_allowed
+    #[account(seeds = [b"dev_mode_config"], bump)]
+    pub dev_mode_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitBurnAttestationDegraded>,
+    asset_id: u8,
+    burn_nonce: u64,
+    attestation: BurnAttestationDataV3,
+) -> Result<()> {
+    let meter = ComputeMeter::start();
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_V3)?;
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_DEGRADED)?;
+
+    require!(
+        attestation.asset_id == asset_id,
+        LightClientError::InvalidAttestation
+    );
+    require!(
+        attestation.burn_nonce == burn_nonce,
+        LightClientError::InvalidAttestation
+    );
+    AssetRegistry::resolve_active(&ctx.accounts.asset_registry, attestation.asset_id)?;
+    BurnAgeLimit::enforce(
+        &ctx.accounts.burn_age_limit,
+        attestation.solana_burn_timestamp,
+        Clock::get()?.unix_timestamp,
+    )?;
+    BetaAllowlist::enforce(&ctx.accounts.beta_allowlist, &attestation.user)?;
+
+    msg!("🔐 Verifying X1 validator attestations (degraded mode)");
+    msg!("   Asset: {}", attestation.asset_id);
+    msg!("   Burn nonce: {}", attestation.burn_nonce);
+    msg!("   Amount: {}", attestation.amount);
+    msg!("   Attestations received: {}", attestation.attestations.len());
+
+    let validator_set = &ctx.accounts.validator_set;
+    let domain = X1ChainId::bind_domain(
+        BridgeDomain::resolve(&ctx.accounts.bridge_domain)?,
+        &ctx.accounts.x1_chain_id,
+    )?;
+    let challenge = ChallengeRequest::load(&ctx.accounts.challenge_request)?;
+    let allow_mock = DevModeConfig::mock_signatures_allowed(&ctx.accounts.dev_mode_config, ctx.remaining_accounts.first())?;
+    if allow_mock {
+        msg!("⚠️  Non-production deployment: mock signatures accepted");
+    }
+    let valid_count = verify_attestations(validator_set, &domain, &attestation, challenge.as_ref(), allow_mock)?;
+
+    let clock = Clock::get()?;
+    let degraded_mode = &mut ctx.accounts.degraded_mode;
+    degraded_mode.admit(valid_count, attestation.amount, clock.unix_timestamp)?;
+
+    msg!(
+        "✅ Degraded quorum met: {}/{} (threshold {})",
+        valid_count,
+        degraded_mode.quorum,
+        validator_set.threshold
+    );
+
+    record_latency(&ctx.accounts.latency_stats, &attestation, &validator_set.validators)?;
+
+    let release_at = clock.unix_timestamp
+        .checked_add(degraded_mode.delay_seconds)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    // Held at processed = true until release_degraded_burn
+    let verified_burn = &mut ctx.accounts.verified_burn;
+    verified_burn.asset_id = attestation.asset_id;
+    verified_burn.burn_nonce = attestation.burn_nonce;
+    verified_burn.user = ctx.accounts.user.key();
+    verified_burn.amount = attestation.amount;
+    verified_burn.verified_at = clock.unix_timestamp;
+    verified_burn.processed = true;
+    verified_burn.bump = ctx.bumps.verified_burn;
+    verified_burn.solana_burn_slot = attestation.solana_burn_slot;
+    verified_burn.verified_slot = clock.slot;
+
+    let degraded_burn = &mut ctx.accounts.degraded_burn;
+    degraded_burn.asset_id = attestation.asset_id;
+    degraded_burn.burn_nonce = attestation.burn_nonce;
+    degraded_burn.user = ctx.accounts.user.key();
+    degraded_burn.amount = attestation.amount;
+    degraded_burn.attestations = valid_count;
+    degraded_burn.release_at = release_at;
+    degraded_burn.bump = ctx.bumps.degraded_burn;
+
+    emit!(DegradedBurnVerified {
+        asset_id,
+        burn_nonce,
+        user: degraded_burn.user,
+        amount: attestation.amount,
+        attestations: valid_count,
+        release_at,
+    });
+
+    msg!("✅ Burn verified in degraded mode, held until {}", release_at);
+
+    meter.report(crate::instruction::SubmitBurnAttestationDegraded::DISCRIMINATOR, valid_count);
+
+    Ok(())
+}
+
+#[event]
+pub struct DegradedBurnVerified {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub attestations: u8,
+    pub release_at: i64,
+}
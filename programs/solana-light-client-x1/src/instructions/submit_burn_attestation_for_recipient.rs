@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use crate::compute_report::ComputeMeter;
+use crate::state::{
+    X1ValidatorSet, DevModeConfig, VerifiedBurnV3, BurnAttestationDataV3, AssetRegistry, BurnAgeLimit, BetaAllowlist, ChallengeRequest, BridgeDomain,
+    X1ChainId, FeatureGate, RelayerClaim, gates, relayer_claim_message, LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+};
+use crate::errors::LightClientError;
+use crate::governance::{require_weight, verify_validator_set_approvals};
+use crate::instructions::{BurnVerified, ValidatorUpdateSignature};
+use crate::instructions::submit_burn_attestation_v3::{record_latency, verify_attestations};
+
+/// Submit a designated-recipient burn's attestations as a relayer (V3)
+///
+/// Same checks as submit_burn_attestation_v3, except:
+/// - `user` is the recipient recorded in the BurnRecord and does NOT sign
+/// - `relayer` signs and pays the VerifiedBurnV3 and RelayerClaim rent
+/// - A threshold of validators must also sign the claim terms
+///   (relayer_claim_message), which only exist for burns made with
+///   burn_xencat_for_recipient, so ordinary burns cannot be claimed
+///
+/// The VerifiedBurnV3 is the same PDA the recipient's own submit would
+/// create, so a burn is verified once whichever path runs first.
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct SubmitBurnAttestationForRecipient<'info> {
+    #[account(mut)]
+    pub relayer: Signer<'info>,
+
+    /// CHECK: Recipient; must equal the attested user (checked in handler)
+    pub user: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + VerifiedBurnV3::INIT_SPACE,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    #[account(
+        init,
+        payer = relayer,
+        space = 8 + RelayerClaim::INIT_SPACE,
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub relayer_claim: Account<'info, RelayerClaim>,
+
+    /// Per-validator latency histogram (SLA tracking; not initialized = not recorded)
+    /// CHECK: Address pinned by seeds; read and written by record_latency
+    #[account(mut, seeds = [b"validator_latency_stats"], bump)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// This deployment's domain separator (not initialized = DOMAIN_SEPARATOR)
+    /// CHECK: Address pinned by seeds; read by BridgeDomain::resolve
+    #[account(seeds = [b"bridge_domain"], bump)]
+    pub bridge_domain: UncheckedAccount<'info>,
+
+    /// X1 genesis hash binding (not initialized = unbound messages)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::bind_domain
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Oldest verifiable burn (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read by BurnAgeLimit::enforce
+    #[account(seeds = [b"burn_age_limit"], bump)]
+    pub burn_age_limit: UncheckedAccount<'info>,
+
+    /// The asset's beta allowlist (not initialized = permissionless)
+    /// CHECK: Address pinned by seeds; read by BetaAllowlist::enforce
+    #[account(seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()], bump)]
+    pub beta_allowlist: UncheckedAccount<'info>,
+
+    /// The burn's attestation challenge (not initialized = none required)
+    /// CHECK: Address pinned by seeds; read by ChallengeRequest::load
+    #[account(
+        seeds = [
+            b"challenge_request",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub challenge_request: UncheckedAccount<'info>,
+
+    /// Non-production marking (not initialized = production; mock
+    /// signatures also need the canary as first remaining account)
+    /// CHECK: Address pinned by seeds; read by DevModeConfig::mock_signatures_allowed
+    #[account(seeds = [b"dev_mode_config"], bump)]
+    pub dev_mode_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Claim terms recorded with the burn, and the validators' signatures of them
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RelayerClaimData {
+    /// Tokens (base units) the relayer keeps, as chosen by the burner
+    pub relayer_fee: u64,
+    /// Signatures over relayer_claim_message (threshold required)
+    pub signatures: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(
+    ctx: Context<SubmitBurnAttestationForRecipient>,
+    asset_id: u8,
+    burn_nonce: u64,
+    attestation: BurnAttestationDataV3,
+    claim: RelayerClaimData,
+) -> Result<()> {
+    let meter = ComputeMeter::start();
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_V3)?;
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::CLAIM_ON_BEHALF)?;
+
+    require!(
+        attestation.asset_id == asset_id
+            && attestation.burn_nonce == burn_nonce
+            && attestation.user == ctx.accounts.user.key(),
+        LightClientError::InvalidAttestation
+    );
+    require!(claim.relayer_fee < attestation.amount, LightClientError::InvalidRelayerFee);
+
+    msg!("🔐 Verifying X1 validator attestations (relayer claim)");
+    msg!("   Asset ID: {}", attestation.asset_id);
+    msg!("   Burn nonce: {}", attestation.burn_nonce);
+    msg!("   Recipient: {}", attestation.user);
+    msg!("   Amount: {} (relayer fee {})", attestation.amount, claim.relayer_fee);
+
+    AssetRegistry::resolve_active(&ctx.accounts.asset_registry, attestation.asset_id)?;
+    BurnAgeLimit::enforce(
+        &ctx.accounts.burn_age_limit,
+        attestation.solana_burn_timestamp,
+        Clock::get()?.unix_timestamp,
+    )?;
+    BetaAllowlist::enforce(&ctx.accounts.beta_allowlist, &attestation.user)?;
+
+    let validator_set = &ctx.accounts.validator_set;
+    let domain = X1ChainId::bind_domain(
+        BridgeDomain::resolve(&ctx.accounts.bridge_domain)?,
+        &ctx.accounts.x1_chain_id,
+    )?;
+    let challenge = ChallengeRequest::load(&ctx.accounts.challenge_request)?;
+    let allow_mock = DevModeConfig::mock_signatures_allowed(&ctx.accounts.dev_mode_config, ctx.remaining_accounts.first())?;
+    if allow_mock {
+        msg!("⚠️  Non-production deployment: mock signatures accepted");
+    }
+    let valid_count = verify_attestations(validator_set, &domain, &attestation, challenge.as_ref(), allow_mock)?;
+    require!(
+        valid_count >= validator_set.threshold,
+        LightClientError::InsufficientAttestations
+    );
+    require_weight(validator_set, attestation.attestations.iter().map(|attest| &attest.validator_pubkey))?;
+
+    // The burner opted in and chose the fee on Solana
+    let message = relayer_claim_message(
+        &domain,
+        attestation.asset_id,
+        attestation.burn_nonce,
+        &attestation.user,
+        claim.relayer_fee,
+    );
+    verify_validator_set_approvals(&claim.signatures, validator_set, &message)?;
+
+    msg!("✅ Threshold met: {}/{} (claim terms attested)", valid_count, validator_set.threshold);
+
+    record_latency(&ctx.accounts.latency_stats, &attestation, &validator_set.validators)?;
+
+    let clock = Clock::get()?;
+    let verified_burn = &mut ctx.accounts.verified_burn;
+    verified_burn.asset_id = attestation.asset_id;
+    verified_burn.burn_nonce = attestation.burn_nonce;
+    verified_burn.user = attestation.user;
+    verified_burn.amount = attestation.amount;
+    verified_burn.verified_at = clock.unix_timestamp;
+    verified_burn.processed = false;
+    verified_burn.bump = ctx.bumps.verified_burn;
+    verified_burn.solana_burn_slot = attestation.solana_burn_slot;
+    verified_burn.verified_slot = clock.slot;
+
+    let relayer_claim = &mut ctx.accounts.relayer_claim;
+    relayer_claim.relayer = ctx.accounts.relayer.key();
+    relayer_claim.relayer_fee = claim.relayer_fee;
+    relayer_claim.bump = ctx.bumps.relayer_claim;
+
+    emit!(BurnVerified {
+        schema_version: LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+        attestation_version: 3,
+        asset_id: attestation.asset_id,
+        burn_nonce: attestation.burn_nonce,
+        user: attestation.user,
+        amount: attestation.amount,
+        validator_set_version: validator_set.version,
+        attestations: valid_count,
+        verified_slot: clock.slot,
+    });
+    emit!(RelayerClaimRecorded {
+        asset_id: attestation.asset_id,
+        burn_nonce: attestation.burn_nonce,
+        user: attestation.user,
+        relayer: relayer_claim.relayer,
+        relayer_fee: claim.relayer_fee,
+    });
+
+    msg!("✅ Burn verified for recipient {}, claimable by {}", attestation.user, relayer_claim.relayer);
+
+    meter.report(crate::instruction::SubmitBurnAttestationForRecipient::DISCRIMINATOR, valid_count);
+
+    Ok(())
+}
+
+/// Emitted when a relayer verifies a designated-recipient burn
+#[event]
+pub struct RelayerClaimRecorded {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub relayer: Pubkey,
+    pub relayer_fee: u64,
+}
@@ -1,7 +1,11 @@
 use anchor_lang::prelude::*;
-use crate::state::{X1ValidatorSet, VerifiedBurnV3, BurnAttestationDataV3, Asset};
+use anchor_lang::Discriminator;
+use crate::compute_report::ComputeMeter;
+use crate::state::{AttestationCheckCode, DevModeConfig, X1ValidatorSet, VerifiedBurnV3, BurnAttestationDataV3, AssetRegistry, BurnAgeLimit, BetaAllowlist, ChallengeRequest, ValidatorLatencyStats, BridgeDomain, X1ChainId, FeatureGate, gates, LIGHT_CLIENT_EVENT_SCHEMA_VERSION};
 use crate::errors::LightClientError;
-use crate::DOMAIN_SEPARATOR;
+use crate::instructions::BurnVerified;
+use crate::ed25519_utils::check_signature_encoding;
+use crate::governance::require_weight;
 
 /// Submit burn attestation with asset awareness (V3)
 ///
@@ -53,6 +57,60 @@ pub struct SubmitBurnAttestationV3<'info> {
     )]
     pub verified_burn: Account<'info, VerifiedBurnV3>,
 
+    /// Per-validator latency histogram (SLA tracking; not initialized = not recorded)
+    /// CHECK: Address pinned by seeds; read and written by record_latency
+    #[account(mut, seeds = [b"validator_latency_stats"], bump)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// This deployment's domain separator (not initialized = DOMAIN_SEPARATOR)
+    /// CHECK: Address pinned by seeds; read by BridgeDomain::resolve
+    #[account(seeds = [b"bridge_domain"], bump)]
+    pub bridge_domain: UncheckedAccount<'info>,
+
+    /// X1 genesis hash binding (not initialized = unbound messages)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::bind_domain
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Oldest verifiable burn (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read by BurnAgeLimit::enforce
+    #[account(seeds = [b"burn_age_limit"], bump)]
+    pub burn_age_limit: UncheckedAccount<'info>,
+
+    /// The asset's beta allowlist (not initialized = permissionless)
+    /// CHECK: Address pinned by seeds; read by BetaAllowlist::enforce
+    #[account(seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()], bump)]
+    pub beta_allowlist: UncheckedAccount<'info>,
+
+    /// The burn's attestation challenge (not initialized = none required)
+    /// CHECK: Address pinned by seeds; read by ChallengeRequest::load
+    #[account(
+        seeds = [
+            b"challenge_request",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub challenge_request: UncheckedAccount<'info>,
+
+    /// Non-production marking (not initialized = production; mock
+    /// signatures also need the canary as first remaining account)
+    /// CHECK: Address pinned by seeds; read by DevModeConfig::mock_signatures_allowed
+    #[account(seeds = [b"dev_mode_config"], bump)]
+    pub dev_mode_config: UncheckedAccount<'info>,
+
     pub system_program: Program<'info, System>,
 }
 
@@ -62,15 +120,16 @@ pub fn handler(
     burn_nonce: u64,
     attestation: BurnAttestationDataV3,
 ) -> Result<()> {
+    let meter = ComputeMeter::start();
+    let validator_set = &ctx.accounts.validator_set;
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_V3)
+        .inspect_err(|_| emit_rejection(validator_set, &attestation, AttestationCheckCode::FeatureDisabled, None, 0))?;
+
     // Validate attestation data matches instruction parameters
-    require!(
-        attestation.asset_id == asset_id,
-        LightClientError::InvalidAttestation
-    );
-    require!(
-        attestation.burn_nonce == burn_nonce,
-        LightClientError::InvalidAttestation
-    );
+    if attestation.asset_id != asset_id || attestation.burn_nonce != burn_nonce {
+        emit_rejection(validator_set, &attestation, AttestationCheckCode::ParameterMismatch, None, 0);
+        return err!(LightClientError::InvalidAttestation);
+    }
     msg!("🔐 Verifying X1 validator attestations (V3 - Asset-Aware)");
     msg!("   Asset ID: {}", attestation.asset_id);
     msg!("   Burn nonce: {}", attestation.burn_nonce);
@@ -79,21 +138,108 @@ pub fn handler(
     msg!("   Validator set version: {}", attestation.validator_set_version);
     msg!("   Attestations received: {}", attestation.attestations.len());
 
-    // Validate asset_id is known
-    let asset = Asset::from_u8(attestation.asset_id)?;
-    msg!("✓ Asset validated: {:?}", asset);
+    // Validate asset_id is a registered, active asset
+    let mint_program = AssetRegistry::resolve_active(&ctx.accounts.asset_registry, attestation.asset_id)
+        .inspect_err(|_| emit_rejection(validator_set, &attestation, AttestationCheckCode::UnknownAsset, None, 0))?;
+    msg!("✓ Asset validated (mint program {})", mint_program);
+    BurnAgeLimit::enforce(
+        &ctx.accounts.burn_age_limit,
+        attestation.solana_burn_timestamp,
+        Clock::get()?.unix_timestamp,
+    )?;
+    BetaAllowlist::enforce(&ctx.accounts.beta_allowlist, &attestation.user)?;
+
+    let domain = X1ChainId::bind_domain(
+        BridgeDomain::resolve(&ctx.accounts.bridge_domain)?,
+        &ctx.accounts.x1_chain_id,
+    )?;
+    let challenge = ChallengeRequest::load(&ctx.accounts.challenge_request)?;
+    let allow_mock = DevModeConfig::mock_signatures_allowed(&ctx.accounts.dev_mode_config, ctx.remaining_accounts.first())?;
+    if allow_mock {
+        msg!("⚠️  Non-production deployment: mock signatures accepted");
+    }
+    let valid_count = verify_attestations(validator_set, &domain, &attestation, challenge.as_ref(), allow_mock)?;
 
-    let validator_set = &ctx.accounts.validator_set;
+    // Check threshold
+    if valid_count < validator_set.threshold {
+        emit_rejection(validator_set, &attestation, AttestationCheckCode::BelowThreshold, None, valid_count);
+        return err!(LightClientError::InsufficientAttestations);
+    }
+    require_weight(validator_set, attestation.attestations.iter().map(|attest| &attest.validator_pubkey))
+        .inspect_err(|_| emit_rejection(validator_set, &attestation, AttestationCheckCode::BelowThreshold, None, valid_count))?;
 
+    msg!("✅ Threshold met: {}/{}", valid_count, validator_set.threshold);
+
+    // Latency SLA: burn block time -> each validator's signing time
+    record_latency(&ctx.accounts.latency_stats, &attestation, &validator_set.validators)?;
+
+    // Store verified burn with asset_id
+    let verified_burn = &mut ctx.accounts.verified_burn;
+    verified_burn.asset_id = attestation.asset_id;
+    verified_burn.burn_nonce = attestation.burn_nonce;
+    verified_burn.user = ctx.accounts.user.key();
+    verified_burn.amount = attestation.amount;
+    verified_burn.verified_at = Clock::get()?.unix_timestamp;
+    verified_burn.processed = false;
+    verified_burn.bump = ctx.bumps.verified_burn;
+    verified_burn.solana_burn_slot = attestation.solana_burn_slot;
+    verified_burn.verified_slot = Clock::get()?.slot;
+
+    emit!(BurnVerified {
+        schema_version: LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+        attestation_version: 3,
+        asset_id: attestation.asset_id,
+        burn_nonce: attestation.burn_nonce,
+        user: verified_burn.user,
+        amount: attestation.amount,
+        validator_set_version: validator_set.version,
+        attestations: valid_count,
+        verified_slot: verified_burn.verified_slot,
+    });
+
+    msg!("✅ Burn verified and stored with asset_id={}!", attestation.asset_id);
+
+    meter.report(crate::instruction::SubmitBurnAttestationV3::DISCRIMINATOR, valid_count);
+
+    Ok(())
+}
+
+/// Check every attestation against the current validator set
+///
+/// Rejects stale versions, duplicate or unknown validators, attestations
+/// outside the age window and bad signature encodings, and returns how many valid attestations there are. The caller
+/// decides which quorum that count must meet (threshold, or DegradedMode).
+/// Every rejection emits AttestationRejected first.
+/// With the burn's ChallengeRequest, signatures must also cover its
+/// challenge and postdate it. `allow_mock` lets all-zero signatures through
+/// (DevModeConfig::mock_signatures_allowed).
+pub(crate) fn verify_attestations(
+    validator_set: &X1ValidatorSet,
+    domain: &[u8],
+    attestation: &BurnAttestationDataV3,
+    challenge: Option<&ChallengeRequest>,
+    allow_mock: bool,
+) -> Result<u8> {
     // SECURITY CRITICAL: Verify attestations are for CURRENT version
     // This prevents replay of old signatures after validator set updates
-    require!(
-        attestation.validator_set_version == validator_set.version,
-        LightClientError::InvalidValidatorSetVersion
-    );
+    if attestation.validator_set_version != validator_set.version {
+        emit_rejection(validator_set, attestation, AttestationCheckCode::VersionMismatch, None, 0);
+        return err!(LightClientError::InvalidValidatorSetVersion);
+    }
 
     msg!("✓ Version matches current: {}", validator_set.version);
 
+    // The burner's chosen depth, bound by every signature below
+    attestation.check_confirmation_depth()
+        .inspect_err(|_| emit_rejection(validator_set, attestation, AttestationCheckCode::ConfirmationDepth, None, 0))?;
+
+    if let Some(challenge) = challenge {
+        challenge.check_burn_time(attestation.solana_burn_timestamp)?;
+        msg!("✓ Challenge posted at slot {}", challenge.created_slot);
+    }
+
+    let now = Clock::get()?.unix_timestamp;
+
     // Build asset-aware message that validators signed
     // Format: hash(DOMAIN_SEPARATOR || asset_id || validator_set_version || burn_nonce || amount || user)
     //
@@ -102,6 +248,7 @@ pub fn handler(
     // - DGN signatures cannot be used for XENCAT (different hash)
     // - Cross-asset replay is cryptographically impossible
     let message = create_attestation_message_v3(
+        domain,
         attestation.asset_id,
         attestation.burn_nonce,
         attestation.user,
@@ -110,64 +257,131 @@ pub fn handler(
     );
 
     // Verify each attestation
-    let mut valid_count = 0;
+    let mut valid_count: u8 = 0;
     let mut seen_validators = std::collections::HashSet::new();
+    let mut previous_index = None;
 
     for attest in &attestation.attestations {
-        // Prevent duplicate signatures from same validator
-        require!(
-            seen_validators.insert(attest.validator_pubkey),
-            LightClientError::DuplicateValidator
-        );
-
-        // Check if validator is in trusted set (pure pubkey lookup)
-        require!(
-            validator_set.validators.contains(&attest.validator_pubkey),
-            LightClientError::UnknownValidator
-        );
+        let reject = |reason| emit_rejection(validator_set, attestation, reason, Some(&attest.validator_pubkey), valid_count);
+
+        if validator_set.ordered_attestations {
+            // Ascending set order: a duplicate can only repeat the previous
+            // index, and membership comes with the index
+            match validator_set.ordered_index(&attest.validator_pubkey, previous_index) {
+                Ok(index) => previous_index = Some(index),
+                Err(reason) => {
+                    reject(reason);
+                    return Err(match reason {
+                        AttestationCheckCode::DuplicateValidator => LightClientError::DuplicateValidator,
+                        AttestationCheckCode::UnknownValidator => LightClientError::UnknownValidator,
+                        _ => LightClientError::AttestationsOutOfOrder,
+                    }.into());
+                }
+            }
+        } else {
+            // Prevent duplicate signatures from same validator
+            if !seen_validators.insert(attest.validator_pubkey) {
+                reject(AttestationCheckCode::DuplicateValidator);
+                return err!(LightClientError::DuplicateValidator);
+            }
+
+            // Check if validator is in trusted set (pure pubkey lookup)
+            if !validator_set.validators.contains(&attest.validator_pubkey) {
+                reject(AttestationCheckCode::UnknownValidator);
+                return err!(LightClientError::UnknownValidator);
+            }
+        }
 
         msg!("   Checking validator: {}", attest.validator_pubkey);
 
+        // A leaked old signature is only usable within max_attestation_age
+        validator_set.check_attestation_age(attest.timestamp, now)
+            .inspect_err(|_| reject(AttestationCheckCode::AttestationAge))?;
+        if let Some(challenge) = challenge {
+            challenge.check_signing_time(attest.timestamp)?;
+        }
+
+        // Each validator also signs the burn slot/time, the confirmation
+        // depth it waited for and its own signing time
+        let timed_message = create_timed_attestation_message_v3(
+            &message,
+            attestation.solana_burn_slot,
+            attestation.solana_burn_timestamp,
+            attestation.confirmation_depth,
+            attestation.solana_confirmed_slot,
+            attest.timestamp,
+            challenge.map(|c| &c.challenge),
+        );
+
         // Verify signature format (validators are trusted to sign correctly)
         verify_ed25519_signature(
             &attest.validator_pubkey.to_bytes(),
-            &message,
+            &timed_message,
             &attest.signature,
-        )?;
+            allow_mock,
+        )
+        .inspect_err(|_| reject(AttestationCheckCode::BadSignatureEncoding))?;
 
         msg!("   ✅ Valid signature");
         valid_count += 1;
     }
 
-    // Check threshold
-    require!(
-        valid_count >= validator_set.threshold,
-        LightClientError::InsufficientAttestations
-    );
-
-    msg!("✅ Threshold met: {}/{}", valid_count, validator_set.threshold);
-
-    // Store verified burn with asset_id
-    let verified_burn = &mut ctx.accounts.verified_burn;
-    verified_burn.asset_id = attestation.asset_id;
-    verified_burn.burn_nonce = attestation.burn_nonce;
-    verified_burn.user = ctx.accounts.user.key();
-    verified_burn.amount = attestation.amount;
-    verified_burn.verified_at = Clock::get()?.unix_timestamp;
-    verified_burn.processed = false;
-    verified_burn.bump = ctx.bumps.verified_burn;
+    Ok(valid_count)
+}
 
-    msg!("✅ Burn verified and stored with asset_id={}!", attestation.asset_id);
+/// Emit AttestationRejected for a bundle about to be rejected
+///
+/// `validator` is the attestation at fault (None for bundle-level reasons)
+/// and `valid_count` the attestations that passed before the rejection.
+pub(crate) fn emit_rejection(
+    validator_set: &X1ValidatorSet,
+    attestation: &BurnAttestationDataV3,
+    reason: AttestationCheckCode,
+    validator: Option<&Pubkey>,
+    valid_count: u8,
+) {
+    msg!("❌ Attestation rejected: {:?}", reason);
+    emit!(AttestationRejected {
+        asset_id: attestation.asset_id,
+        burn_nonce: attestation.burn_nonce,
+        user: attestation.user,
+        reason,
+        validator: validator.copied().unwrap_or_default(),
+        expected_version: validator_set.version,
+        got_version: attestation.validator_set_version,
+        valid_count,
+        threshold: validator_set.threshold,
+    });
+}
 
+/// Record each validator's burn-to-signature latency in the SLA histogram
+/// (nothing to record while the histogram is not initialized)
+pub(crate) fn record_latency(
+    latency_stats: &AccountInfo,
+    attestation: &BurnAttestationDataV3,
+    validators: &[Pubkey],
+) -> Result<()> {
+    let Some(mut stats) = ValidatorLatencyStats::load(latency_stats)? else {
+        return Ok(());
+    };
+    for attest in &attestation.attestations {
+        let latency_seconds = attest.timestamp
+            .saturating_sub(attestation.solana_burn_timestamp)
+            .max(0) as u64;
+        stats.record(attest.validator_pubkey, latency_seconds, validators);
+    }
+    stats.try_serialize(&mut &mut latency_stats.try_borrow_mut_data()?[..])?;
     Ok(())
 }
 
 /// Create the asset-aware message that X1 validators sign (V3)
 ///
 /// Format: hash(DOMAIN_SEPARATOR || asset_id || validator_set_version || burn_nonce || amount || user)
+/// (`domain` includes the X1 genesis hash once X1ChainId is set)
 ///
 /// SECURITY: This prevents:
 /// - Cross-domain attacks (domain separator)
+/// - Replay on a fork or clone of X1 (genesis hash, see X1ChainId)
 /// - Cross-asset replay (asset_id binding)
 /// - Replay after validator updates (version binding)
 /// - Signature forgery (all critical data included)
@@ -179,6 +393,7 @@ pub fn handler(
 /// The asset_id ensures that signatures for XENCAT burns cannot be used
 /// for DGN burns (and vice versa), providing cryptographic separation.
 fn create_attestation_message_v3(
+    domain: &[u8],
     asset_id: u8,
     burn_nonce: u64,
     user: Pubkey,
@@ -188,7 +403,7 @@ fn create_attestation_message_v3(
     use anchor_lang::solana_program::hash::hash;
 
     let mut message_data = Vec::new();
-    message_data.extend_from_slice(DOMAIN_SEPARATOR.as_bytes());
+    message_data.extend_from_slice(domain);
     message_data.push(asset_id);  // ✅ NEW: Include asset_id
     message_data.extend_from_slice(&validator_set_version.to_le_bytes());
     message_data.extend_from_slice(&burn_nonce.to_le_bytes());
@@ -199,6 +414,40 @@ fn create_attestation_message_v3(
     hash(&message_data).to_bytes().to_vec()
 }
 
+/// Per-validator message actually signed (V3 with timing)
+///
+/// Format: hash(v3_message || solana_burn_slot || solana_burn_timestamp ||
+///   confirmation_depth || solana_confirmed_slot || attested_at [|| challenge])
+///
+/// Binds the burn slot/time and the validator's own signing time, so the
+/// latency recorded in ValidatorLatencyStats cannot be forged by a relayer,
+/// and the confirmation depth, so a relayer cannot lower the burner's choice.
+/// The challenge is appended only for burns with a ChallengeRequest.
+fn create_timed_attestation_message_v3(
+    v3_message: &[u8],
+    solana_burn_slot: u64,
+    solana_burn_timestamp: i64,
+    confirmation_depth: u16,
+    solana_confirmed_slot: u64,
+    attested_at: i64,
+    challenge: Option<&[u8; 32]>,
+) -> Vec<u8> {
+    use anchor_lang::solana_program::hash::hash;
+
+    let mut message_data = Vec::with_capacity(v3_message.len() + 66);
+    message_data.extend_from_slice(v3_message);
+    message_data.extend_from_slice(&solana_burn_slot.to_le_bytes());
+    message_data.extend_from_slice(&solana_burn_timestamp.to_le_bytes());
+    message_data.extend_from_slice(&confirmation_depth.to_le_bytes());
+    message_data.extend_from_slice(&solana_confirmed_slot.to_le_bytes());
+    message_data.extend_from_slice(&attested_at.to_le_bytes());
+    if let Some(challenge) = challenge {
+        message_data.extend_from_slice(challenge);
+    }
+
+    hash(&message_data).to_bytes().to_vec()
+}
+
 /// Verify Ed25519 signature format
 ///
 /// SECURITY MODEL: This bridge uses a trusted validator model.
@@ -220,7 +469,11 @@ fn verify_ed25519_signature(
     public_key: &[u8; 32],
     message: &[u8],
     signature: &[u8; 64],
+    allow_mock: bool,
 ) -> Result<()> {
+    // Reject mock, malleable and weak-key signatures
+    check_signature_encoding(public_key, signature, allow_mock)?;
+
     // Format validation only
     // Signature must be exactly 64 bytes (already enforced by type system)
     // Public key must be exactly 32 bytes (already enforced by type system)
@@ -236,10 +489,35 @@ fn verify_ed25519_signature(
     Ok(())
 }
 
+/// Event emitted right before an attestation bundle is rejected
+///
+/// The transaction still fails, but failed transactions keep their logs, so
+/// relayer dashboards can read it from the transaction meta and tell stale
+/// versions from bad signatures from threshold failures. `reason` uses the
+/// prevalidate_attestation codes.
+#[event]
+pub struct AttestationRejected {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub reason: AttestationCheckCode,
+    /// Validator whose attestation failed (default key for bundle-level reasons)
+    pub validator: Pubkey,
+    /// Current validator set version
+    pub expected_version: u64,
+    /// Version the bundle was signed for
+    pub got_version: u64,
+    /// Attestations that passed before the rejection
+    pub valid_count: u8,
+    pub threshold: u8,
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    const DOMAIN: &[u8] = crate::DOMAIN_SEPARATOR.as_bytes();
+
     #[test]
     fn test_attestation_message_v3_differs_by_asset() {
         use anchor_lang::solana_program::pubkey::Pubkey;
@@ -250,8 +528,8 @@ mod tests {
         let version = 1;
 
         // Same burn data, different assets
-        let xencat_msg = create_attestation_message_v3(1, nonce, user, amount, version);
-        let dgn_msg = create_attestation_message_v3(2, nonce, user, amount, version);
+        let xencat_msg = create_attestation_message_v3(DOMAIN, 1, nonce, user, amount, version);
+        let dgn_msg = create_attestation_message_v3(DOMAIN, 2, nonce, user, amount, version);
 
         // Messages MUST be different (prevents cross-asset replay)
         assert_ne!(xencat_msg, dgn_msg, "Asset-aware messages must differ");
@@ -268,9 +546,66 @@ mod tests {
         let asset_id = 1;
 
         // Same input should produce same output
-        let msg1 = create_attestation_message_v3(asset_id, nonce, user, amount, version);
-        let msg2 = create_attestation_message_v3(asset_id, nonce, user, amount, version);
+        let msg1 = create_attestation_message_v3(DOMAIN, asset_id, nonce, user, amount, version);
+        let msg2 = create_attestation_message_v3(DOMAIN, asset_id, nonce, user, amount, version);
 
         assert_eq!(msg1, msg2, "Message creation must be deterministic");
     }
+
+    #[test]
+    fn test_attestation_message_v3_differs_by_domain() {
+        use anchor_lang::solana_program::pubkey::Pubkey;
+
+        let user = Pubkey::new_unique();
+
+        // Same burn signed for mainnet and devnet (no cross-environment replay)
+        let mainnet = create_attestation_message_v3(DOMAIN, 1, 123, user, 1000, 1);
+        let devnet = create_attestation_message_v3(b"XENCAT_X1_BRIDGE_DEVNET_V1", 1, 123, user, 1000, 1);
+
+        assert_ne!(mainnet, devnet, "Messages must differ by domain");
+    }
+
+    #[test]
+    fn test_attestation_message_v3_differs_by_x1_chain() {
+        use anchor_lang::solana_program::pubkey::Pubkey;
+
+        let user = Pubkey::new_unique();
+        let bound = |genesis_hash: [u8; 32]| [DOMAIN, genesis_hash.as_ref()].concat();
+
+        // Same burn signed on X1 mainnet and on a clone with another genesis
+        let unbound = create_attestation_message_v3(DOMAIN, 1, 123, user, 1000, 1);
+        let mainnet = create_attestation_message_v3(&bound([1u8; 32]), 1, 123, user, 1000, 1);
+        let clone = create_attestation_message_v3(&bound([2u8; 32]), 1, 123, user, 1000, 1);
+
+        assert_ne!(mainnet, clone, "Messages must differ by X1 genesis hash");
+        assert_ne!(mainnet, unbound);
+    }
+
+    #[test]
+    fn test_timed_attestation_message_binds_signing_time() {
+        use anchor_lang::solana_program::pubkey::Pubkey;
+
+        let base = create_attestation_message_v3(DOMAIN, 1, 123, Pubkey::new_unique(), 1000, 1);
+
+        let msg = create_timed_attestation_message_v3(&base, 250_000_000, 1_700_000_000, 0, 250_000_032, 1_700_000_030, None);
+        let later = create_timed_attestation_message_v3(&base, 250_000_000, 1_700_000_000, 0, 250_000_032, 1_700_000_090, None);
+        let deeper = create_timed_attestation_message_v3(&base, 250_000_000, 1_700_000_000, 600, 250_000_600, 1_700_000_030, None);
+
+        assert_ne!(msg, later, "Signing time must be part of the signed message");
+        assert_ne!(msg, deeper, "Confirmation depth must be part of the signed message");
+        assert_ne!(msg, base);
+    }
+
+    #[test]
+    fn test_timed_attestation_message_binds_challenge() {
+        use anchor_lang::solana_program::pubkey::Pubkey;
+
+        let base = create_attestation_message_v3(DOMAIN, 1, 123, Pubkey::new_unique(), 1000, 1);
+        let timed = |challenge: Option<&[u8; 32]>| {
+            create_timed_attestation_message_v3(&base, 250_000_000, 1_700_000_000, 0, 250_000_032, 1_700_000_030, challenge)
+        };
+
+        assert_ne!(timed(None), timed(Some(&[1u8; 32])), "Challenge must be part of the signed message");
+        assert_ne!(timed(Some(&[1u8; 32])), timed(Some(&[2u8; 32])));
+    }
 }
@@ -0,0 +1,157 @@
+use anchor_lang::prelude::*;
+use crate::state::{VerifiedBurnV3, AssetRegistry, BurnAgeLimit, BetaAllowlist, FeatureGate, WormholeConfig, gates, LIGHT_CLIENT_EVENT_SCHEMA_VERSION};
+use crate::errors::LightClientError;
+use crate::instructions::BurnVerified;
+use crate::wormhole::{BurnVaaPayload, PostedVaa};
+
+/// Verify a burn from a Wormhole VAA instead of X1 validator attestations
+///
+/// The fallback for when the validators' attestation APIs are offline: the
+/// burn's VAA, posted on X1 through the Wormhole core bridge, stands in for
+/// the attestation bundle (see wormhole.rs for what is trusted). Creates
+/// the same VerifiedBurnV3 as submit_burn_attestation_v3, so a burn is
+/// verified at most once by either path and mints the same way.
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct SubmitBurnVaa<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Trusted core bridge and emitter (not initialized = path off)
+    /// CHECK: Address pinned by seeds; read by WormholeConfig::load_enabled
+    #[account(seeds = [b"wormhole_config"], bump)]
+    pub wormhole_config: UncheckedAccount<'info>,
+
+    /// The burn's PostedVAA
+    /// CHECK: Owner, address and emitter checked in the handler
+    pub posted_vaa: UncheckedAccount<'info>,
+
+    /// Verified burn PDA V3, shared with the attestation path
+    /// Seeds: ["verified_burn_v3", asset_id, user, nonce]
+    #[account(
+        init,
+        payer = user,
+        space = 8 + VerifiedBurnV3::INIT_SPACE,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Oldest verifiable burn (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read by BurnAgeLimit::enforce
+    #[account(seeds = [b"burn_age_limit"], bump)]
+    pub burn_age_limit: UncheckedAccount<'info>,
+
+    /// The asset's beta allowlist (not initialized = permissionless)
+    /// CHECK: Address pinned by seeds; read by BetaAllowlist::enforce
+    #[account(seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()], bump)]
+    pub beta_allowlist: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<SubmitBurnVaa>, asset_id: u8, burn_nonce: u64) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::ATTEST_VAA)?;
+    let config = WormholeConfig::load_enabled(&ctx.accounts.wormhole_config)?;
+
+    msg!("🌀 Verifying burn from Wormhole VAA");
+    msg!("   Asset ID: {}", asset_id);
+    msg!("   Burn nonce: {}", burn_nonce);
+
+    // Only the core bridge creates PostedVAAs, after checking the guardian
+    // signatures; the address binds the account to its body
+    let info = ctx.accounts.posted_vaa.to_account_info();
+    require_keys_eq!(*info.owner, config.core_bridge, LightClientError::InvalidPostedVaa);
+    let vaa = PostedVaa::parse(&info.try_borrow_data()?)?;
+    require_keys_eq!(info.key(), vaa.address(&config.core_bridge), LightClientError::InvalidPostedVaa);
+
+    require!(
+        vaa.emitter_chain == config.emitter_chain
+            && vaa.emitter_address == config.emitter_address
+            && vaa.consistency_level >= config.min_consistency_level,
+        LightClientError::VaaEmitterMismatch
+    );
+    msg!("✓ VAA from emitter, sequence {}", vaa.sequence);
+
+    let burn = BurnVaaPayload::parse(&vaa.payload)?;
+    require!(
+        burn.asset_id == asset_id
+            && burn.burn_nonce == burn_nonce
+            && burn.user == ctx.accounts.user.key(),
+        LightClientError::InvalidVaaPayload
+    );
+    msg!("   User: {}", burn.user);
+    msg!("   Amount: {}", burn.amount);
+
+    let mint_program = AssetRegistry::resolve_active(&ctx.accounts.asset_registry, asset_id)?;
+    msg!("✓ Asset validated (mint program {})", mint_program);
+    BurnAgeLimit::enforce(
+        &ctx.accounts.burn_age_limit,
+        burn.solana_burn_timestamp,
+        Clock::get()?.unix_timestamp,
+    )?;
+    BetaAllowlist::enforce(&ctx.accounts.beta_allowlist, &burn.user)?;
+
+    let verified_burn = &mut ctx.accounts.verified_burn;
+    verified_burn.asset_id = asset_id;
+    verified_burn.burn_nonce = burn_nonce;
+    verified_burn.user = burn.user;
+    verified_burn.amount = burn.amount;
+    verified_burn.verified_at = Clock::get()?.unix_timestamp;
+    verified_burn.processed = false;
+    verified_burn.bump = ctx.bumps.verified_burn;
+    verified_burn.solana_burn_slot = burn.solana_burn_slot;
+    verified_burn.verified_slot = Clock::get()?.slot;
+
+    // No validator set vouched for it: version and attestations are 0
+    emit!(BurnVerified {
+        schema_version: LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+        attestation_version: 3,
+        asset_id,
+        burn_nonce,
+        user: burn.user,
+        amount: burn.amount,
+        validator_set_version: 0,
+        attestations: 0,
+        verified_slot: verified_burn.verified_slot,
+    });
+    emit!(BurnVerifiedByVaa {
+        asset_id,
+        burn_nonce,
+        user: burn.user,
+        amount: burn.amount,
+        emitter_chain: vaa.emitter_chain,
+        sequence: vaa.sequence,
+        verified_slot: verified_burn.verified_slot,
+    });
+
+    msg!("✅ Burn verified from VAA and stored with asset_id={}!", asset_id);
+
+    Ok(())
+}
+
+#[event]
+pub struct BurnVerifiedByVaa {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub emitter_chain: u16,
+    pub sequence: u64,
+    pub verified_slot: u64,
+}
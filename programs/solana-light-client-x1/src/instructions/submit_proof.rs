@@ -1,5 +1,6 @@
 use anchor_lang::prelude::*;
 use crate::state::{VerifiedBurn, ValidatorConfig, LightClientState};
+use crate::errors::LightClientError;
 use crate::{BurnProof, verification_new};
 
 #[derive(Accounts)]
@@ -57,6 +58,9 @@ pub struct SubmitProof<'info> {
 pub fn handler(ctx: Context<SubmitProof>, proof: BurnProof) -> Result<()> {
     msg!("🔐 Submitting proof for burn nonce {}", proof.burn_nonce);
 
+    // The verified burn is the signer's: it must be the burner the record proves
+    require_keys_eq!(proof.user, ctx.accounts.user.key(), LightClientError::BurnRecordMismatch);
+
     // SECURITY CRITICAL: Full cryptographic verification
     // - Ed25519 signatures already verified by Ed25519Program
     // - Extract validator identities from Ed25519 instructions
@@ -67,7 +71,6 @@ pub fn handler(ctx: Context<SubmitProof>, proof: BurnProof) -> Result<()> {
         &proof,
         &ctx.accounts.validator_config,
         &ctx.accounts.instructions,
-        0, // ed25519_ix_offset
     )?;
 
     msg!("✅ Proof verified! Storing verification result...");
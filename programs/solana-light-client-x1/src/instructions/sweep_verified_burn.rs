@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::state::SweepBountyPool;
+use crate::errors::LightClientError;
+use super::archive_verified_burn::*;
+
+/// ProcessedBurnV3: disc(8) asset_id(1) nonce(8) user(32) amount(8) processed_at(8)
+const PROCESSED_AT_OFFSET: usize = 57;
+
+/// Archive and close a minted VerifiedBurnV3 for a bounty (permissionless crank)
+///
+/// Same checks and rent destination as archive_verified_burn; on top of it
+/// the cranker is paid from the sweep bounty pool. The burn's rent never
+/// goes to the cranker, and the bounty is only paid for burns verified
+/// before their mint (see SweepBountyPool::pays_for).
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64, period: u64)]
+pub struct SweepVerifiedBurn<'info> {
+    pub archive: ArchiveVerifiedBurn<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"sweep_bounty_pool"],
+        bump = sweep_bounty_pool.bump
+    )]
+    pub sweep_bounty_pool: Account<'info, SweepBountyPool>,
+
+    /// Receives the bounty (usually also archive.signer)
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+}
+
+pub fn handler(
+    ctx: Context<SweepVerifiedBurn>,
+    asset_id: u8,
+    burn_nonce: u64,
+    period: u64,
+) -> Result<()> {
+    archive_burn(&mut ctx.accounts.archive, asset_id, burn_nonce, period)?;
+
+    // archive_burn checked the tracker's address, owner and non-emptiness
+    let tracker = ctx.accounts.archive.processed_burn.try_borrow_data()?;
+    let processed_at = tracker
+        .get(PROCESSED_AT_OFFSET..PROCESSED_AT_OFFSET + 8)
+        .and_then(|bytes| bytes.try_into().ok())
+        .map(i64::from_le_bytes)
+        .ok_or(LightClientError::InvalidReplayTracker)?;
+    drop(tracker);
+
+    let pool_info = ctx.accounts.sweep_bounty_pool.to_account_info();
+    let rent_floor = Rent::get()?.minimum_balance(pool_info.data_len());
+    let bounty = if SweepBountyPool::pays_for(ctx.accounts.archive.verified_burn.verified_at, processed_at) {
+        ctx.accounts.sweep_bounty_pool.payable(pool_info.lamports(), rent_floor)
+    } else {
+        0
+    };
+
+    if bounty > 0 {
+        **pool_info.try_borrow_mut_lamports()? -= bounty;
+        **ctx.accounts.cranker.try_borrow_mut_lamports()? += bounty;
+    }
+
+    let pool = &mut ctx.accounts.sweep_bounty_pool;
+    pool.total_paid = pool.total_paid
+        .checked_add(bounty)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+    pool.swept = pool.swept
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    msg!("🧹 Swept burn {} (asset {}), bounty {} lamports", burn_nonce, asset_id, bounty);
+
+    Ok(())
+}
@@ -0,0 +1,53 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeCapabilities, BridgeConfig, BridgeInfo, X1ValidatorSet};
+
+#[derive(Accounts)]
+pub struct SyncBridgeInfo<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_info"],
+        bump = bridge_info.bump
+    )]
+    pub bridge_info: Account<'info, BridgeInfo>,
+
+    /// Anyone can sync (e.g. after an upgrade, a V2 cutoff or a set update)
+    pub signer: Signer<'info>,
+}
+
+/// Refresh the BridgeInfo capability account (permissionless)
+///
+/// Everything written is derived from the program itself and the
+/// validator-governed accounts, so there is nothing a caller can choose.
+pub fn handler(ctx: Context<SyncBridgeInfo>) -> Result<()> {
+    let slot = Clock::get()?.slot;
+    let capabilities = BridgeCapabilities::current(
+        &ctx.accounts.bridge_config,
+        &ctx.accounts.validator_set,
+        slot,
+    );
+    let bridge_info = &mut ctx.accounts.bridge_info;
+
+    if bridge_info.capabilities == capabilities {
+        msg!("✓ Bridge info already current");
+        return Ok(());
+    }
+
+    bridge_info.capabilities = capabilities;
+    bridge_info.updated_slot = slot;
+
+    msg!("✅ Bridge info synced (features {:#x})", bridge_info.capabilities.features);
+
+    Ok(())
+}
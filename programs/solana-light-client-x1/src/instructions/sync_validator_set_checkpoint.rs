@@ -0,0 +1,47 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, ValidatorSetCheckpoint};
+
+#[derive(Accounts)]
+pub struct SyncValidatorSetCheckpoint<'info> {
+    /// Only a set that still deserializes can be checkpointed
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        mut,
+        seeds = [b"validator_set_checkpoint"],
+        bump = checkpoint.bump
+    )]
+    pub checkpoint: Account<'info, ValidatorSetCheckpoint>,
+
+    /// Anyone can sync (e.g. the snapshot export tool)
+    pub signer: Signer<'info>,
+}
+
+/// Copy the live validator set into the checkpoint (permissionless)
+///
+/// The live set only changes through threshold governance, so copying it is
+/// safe; the version check keeps the checkpoint from ever moving backwards.
+pub fn handler(ctx: Context<SyncValidatorSetCheckpoint>) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let checkpoint = &mut ctx.accounts.checkpoint;
+
+    if validator_set.version <= checkpoint.version {
+        msg!("✓ Checkpoint already at version {}", checkpoint.version);
+        return Ok(());
+    }
+
+    msg!("🔄 Checkpoint version {} -> {}", checkpoint.version, validator_set.version);
+
+    checkpoint.version = validator_set.version;
+    checkpoint.validators = validator_set.validators.clone();
+    checkpoint.threshold = validator_set.threshold;
+    checkpoint.checkpoint_slot = Clock::get()?.slot;
+
+    msg!("✅ Validator set checkpoint synced");
+
+    Ok(())
+}
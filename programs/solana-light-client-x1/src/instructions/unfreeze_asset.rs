@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{AssetRegistry, BridgeConfig, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct UnfreezeAsset<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"asset_registry"],
+        bump = asset_registry.bump
+    )]
+    pub asset_registry: Account<'info, AssetRegistry>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnfreezeAssetParams {
+    pub asset_id: u8,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Lift an asset freeze (validator governance)
+///
+/// Burns verified before or during the freeze mint again; attestations of
+/// the asset resume if it is still active.
+pub fn handler(ctx: Context<UnfreezeAsset>, params: UnfreezeAssetParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let registry = &mut ctx.accounts.asset_registry;
+
+    msg!("🔄 Unfreezing asset {}", params.asset_id);
+
+    let message = create_unfreeze_asset_message(
+        validator_set.version,
+        bridge_config.nonce,
+        params.asset_id,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    registry.set_frozen(params.asset_id, false, Clock::get()?.slot)?;
+
+    msg!("✅ Asset unfrozen");
+
+    Ok(())
+}
+
+/// Create deterministic message for lifting an asset freeze
+///
+/// Format: hash(UNFREEZE_ASSET || version || config_nonce || asset_id)
+fn create_unfreeze_asset_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    asset_id: u8,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(9);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(asset_id);
+
+    create_governance_message(b"UNFREEZE_ASSET", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, BurnFreeze, VerifiedBurnV3, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::verify_validator_set_approvals;
+use crate::instructions::freeze_burn::create_freeze_message;
+use crate::instructions::ValidatorUpdateSignature;
+
+/// Lift a burn freeze (full validator governance)
+///
+/// Restores `processed = false` so the burn can be minted, and closes the
+/// freeze record (rent back to whoever paid for the freeze).
+#[derive(Accounts)]
+pub struct UnfreezeBurn<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            burn_freeze.asset_id.to_le_bytes().as_ref(),
+            burn_freeze.user.as_ref(),
+            burn_freeze.burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    #[account(
+        mut,
+        close = payer,
+        has_one = payer @ LightClientError::BurnNotFrozen,
+        seeds = [
+            b"burn_freeze",
+            burn_freeze.asset_id.to_le_bytes().as_ref(),
+            burn_freeze.user.as_ref(),
+            burn_freeze.burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = burn_freeze.bump
+    )]
+    pub burn_freeze: Account<'info, BurnFreeze>,
+
+    /// CHECK: Rent receiver, bound to the freeze by has_one
+    #[account(mut)]
+    pub payer: UncheckedAccount<'info>,
+
+    /// Signer submitting the unfreeze (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnfreezeBurnParams {
+    /// Signatures from ≥threshold current validators
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(ctx: Context<UnfreezeBurn>, params: UnfreezeBurnParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let freeze = &ctx.accounts.burn_freeze;
+
+    msg!("🔥 Unfreezing burn {} (asset {})", freeze.burn_nonce, freeze.asset_id);
+
+    let message = create_freeze_message(
+        b"UNFREEZE_BURN",
+        validator_set.version,
+        bridge_config.nonce,
+        &ctx.accounts.verified_burn,
+        &freeze.reason,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    ctx.accounts.verified_burn.processed = false;
+
+    emit!(BurnUnfrozen {
+        asset_id: freeze.asset_id,
+        burn_nonce: freeze.burn_nonce,
+        user: freeze.user,
+        frozen_slot: freeze.frozen_slot,
+    });
+
+    msg!("✅ Burn unfrozen - mint allowed again");
+
+    Ok(())
+}
+
+#[event]
+pub struct BurnUnfrozen {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub frozen_slot: u64,
+}
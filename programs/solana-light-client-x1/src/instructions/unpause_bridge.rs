@@ -0,0 +1,90 @@
+use anchor_lang::prelude::*;
+use crate::state::{BridgeConfig, FeatureGate, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct UnpauseBridge<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"feature_gate"],
+        bump = feature_gate.bump
+    )]
+    pub feature_gate: Account<'info, FeatureGate>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UnpauseBridgeParams {
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Resume the bridge after an emergency pause (validator governance)
+///
+/// Clears FeatureGate::PAUSED only: instructions switched off with
+/// set_feature_gate stay off.
+pub fn handler(ctx: Context<UnpauseBridge>, params: UnpauseBridgeParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    let feature_gate = &mut ctx.accounts.feature_gate;
+
+    require!(feature_gate.is_paused(), LightClientError::BridgeNotPaused);
+
+    msg!("▶️  Unpausing the bridge");
+
+    let message = create_unpause_bridge_message(
+        validator_set.version,
+        bridge_config.nonce,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    feature_gate.disabled &= !FeatureGate::PAUSED;
+    feature_gate.updated_slot = Clock::get()?.slot;
+
+    msg!("✅ Bridge unpaused");
+
+    Ok(())
+}
+
+/// Create deterministic message for lifting an emergency pause
+///
+/// Format: hash(UNPAUSE_BRIDGE || version || config_nonce)
+fn create_unpause_bridge_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    create_governance_message(
+        b"UNPAUSE_BRIDGE",
+        validator_set_version,
+        &config_nonce.to_le_bytes(),
+        x1_genesis_hash,
+    )
+}
@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+use crate::state::DepositWebhook;
+use crate::instructions::DepositWebhookParams;
+
+#[derive(Accounts)]
+pub struct UpdateDepositWebhook<'info> {
+    pub recipient: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"deposit_webhook", recipient.key().as_ref()],
+        bump = deposit_webhook.bump
+    )]
+    pub deposit_webhook: Account<'info, DepositWebhook>,
+}
+
+/// Replace the recipient's webhook hash and notification preferences
+pub fn handler(ctx: Context<UpdateDepositWebhook>, params: DepositWebhookParams) -> Result<()> {
+    params.apply(&mut ctx.accounts.deposit_webhook)?;
+
+    msg!("🔔 Deposit webhook updated for {}", ctx.accounts.recipient.key());
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use crate::state::{X1ValidatorSet, SolanaChainState, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+
+#[derive(Accounts)]
+pub struct UpdateSolanaEpoch<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"solana_chain_state"],
+        bump = solana_chain_state.bump
+    )]
+    pub solana_chain_state: Account<'info, SolanaChainState>,
+
+    /// Relayer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpdateSolanaEpochParams {
+    /// Solana epoch observed by the validators
+    pub epoch: u64,
+
+    /// Total active stake for that epoch (lamports)
+    pub total_stake: u64,
+
+    /// Solana slot the observation was made at
+    pub solana_slot: u64,
+
+    /// Signatures from current validators attesting this observation
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Record validator-attested Solana epoch and total stake
+///
+/// SECURITY:
+/// - ≥threshold current validators must sign the exact (epoch, stake, slot)
+/// - Signatures are bound to the validator set version
+/// - solana_slot must strictly increase, so an old attestation can never
+///   be replayed to roll the chain state back
+pub fn handler(ctx: Context<UpdateSolanaEpoch>, params: UpdateSolanaEpochParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let state = &mut ctx.accounts.solana_chain_state;
+    let clock = Clock::get()?;
+
+    msg!("🕐 Updating Solana chain state");
+    msg!("   Epoch: {} -> {}", state.epoch, params.epoch);
+    msg!("   Solana slot: {} -> {}", state.solana_slot, params.solana_slot);
+
+    require!(params.total_stake > 0, LightClientError::InvalidTotalStake);
+    require!(
+        state.accepts_update(params.epoch, params.solana_slot),
+        LightClientError::StaleChainStateUpdate
+    );
+
+    let message = create_solana_epoch_message(
+        validator_set.version,
+        params.epoch,
+        params.total_stake,
+        params.solana_slot,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    let approvals = verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    state.epoch = params.epoch;
+    state.total_stake = params.total_stake;
+    state.solana_slot = params.solana_slot;
+    state.updated_slot = clock.slot;
+    state.updated_at = clock.unix_timestamp;
+
+    msg!("✅ Solana chain state updated ({} approvals)", approvals);
+    msg!("   Total stake: {}", params.total_stake);
+
+    Ok(())
+}
+
+/// Create deterministic message for a Solana epoch attestation
+///
+/// Format: hash(SOLANA_EPOCH || version || epoch || total_stake || solana_slot)
+pub fn create_solana_epoch_message(
+    validator_set_version: u64,
+    epoch: u64,
+    total_stake: u64,
+    solana_slot: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(24);
+    payload.extend_from_slice(&epoch.to_le_bytes());
+    payload.extend_from_slice(&total_stake.to_le_bytes());
+    payload.extend_from_slice(&solana_slot.to_le_bytes());
+
+    create_governance_message(b"SOLANA_EPOCH", validator_set_version, &payload, x1_genesis_hash)
+}
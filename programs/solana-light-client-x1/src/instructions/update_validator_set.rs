@@ -1,6 +1,9 @@
 use anchor_lang::prelude::*;
-use crate::state::X1ValidatorSet;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::invariants;
+use crate::state::{X1ValidatorSet, X1ValidatorSetHistory, X1ValidatorSetRecord, BridgeConfig, X1ChainId, ValidatorSetTimelock, weakens_safety, LIGHT_CLIENT_EVENT_SCHEMA_VERSION};
 use crate::errors::LightClientError;
+use crate::governance::{require_weight, verify_threshold_approvals};
 
 #[derive(Accounts)]
 pub struct UpdateValidatorSet<'info> {
@@ -11,8 +14,34 @@ pub struct UpdateValidatorSet<'info> {
     )]
     pub validator_set: Account<'info, X1ValidatorSet>,
 
-    /// Signer submitting the update (anyone can submit with valid signatures)
+    /// Governance-configured floor for the new set
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Instant updates are refused once the timelock exists
+    /// CHECK: Address pinned by seeds; read by ValidatorSetTimelock::is_active
+    #[account(seeds = [b"validator_set_timelock"], bump)]
+    pub validator_set_timelock: UncheckedAccount<'info>,
+
+    /// Audit trail of updates (not initialized = not recorded)
+    /// CHECK: Address pinned by seeds; written by X1ValidatorSetHistory::append
+    #[account(mut, seeds = [b"x1_validator_set_history"], bump)]
+    pub validator_set_history: UncheckedAccount<'info>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures);
+    /// pays any top-up rent if the set account has to grow for weights
+    #[account(mut)]
     pub signer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone)]
@@ -23,6 +52,14 @@ pub struct UpdateValidatorSetParams {
     /// New threshold (how many signatures required)
     pub new_threshold: u8,
 
+    /// Voting weight of each new validator, in new_validators order
+    /// (empty for an unweighted set)
+    pub new_weights: Vec<u16>,
+
+    /// Share of the total weight signers must also carry, in basis points
+    /// (0 for an unweighted set)
+    pub new_threshold_bps: u16,
+
     /// Signatures from current validators approving this update
     pub approver_signatures: Vec<ValidatorUpdateSignature>,
 }
@@ -40,6 +77,11 @@ pub fn handler(
     ctx: Context<UpdateValidatorSet>,
     params: UpdateValidatorSetParams,
 ) -> Result<()> {
+    require!(
+        !ValidatorSetTimelock::is_active(&ctx.accounts.validator_set_timelock)?,
+        LightClientError::ValidatorSetUpdateTimelocked
+    );
+
     let validator_set = &mut ctx.accounts.validator_set;
 
     msg!("🔄 Updating validator set");
@@ -47,8 +89,38 @@ pub fn handler(
     msg!("   Current validators: {}", validator_set.validators.len());
     msg!("   New validators: {}", params.new_validators.len());
     msg!("   New threshold: {}", params.new_threshold);
+    if params.new_threshold_bps != 0 {
+        msg!("   New weighted threshold: {} bps", params.new_threshold_bps);
+    }
+
+    check_update_policy(&params, &ctx.accounts.bridge_config)?;
 
-    // Validate new configuration
+    // Verify signatures from current validators
+    verify_update_signatures(
+        &params,
+        validator_set,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    )?;
+
+    msg!("✓ Threshold signatures verified ({} of {})",
+         params.approver_signatures.len(),
+         validator_set.validators.len());
+
+    let approvers = params.approver_signatures.iter().map(|approval| approval.validator_pubkey).collect();
+    apply_update(
+        validator_set,
+        &ctx.accounts.validator_set_history,
+        &ctx.accounts.signer.to_account_info(),
+        &ctx.accounts.system_program.to_account_info(),
+        params,
+        approvers,
+    )?;
+
+    Ok(())
+}
+
+/// Validate a new set against itself and the BridgeConfig floor
+pub(crate) fn check_update_policy(params: &UpdateValidatorSetParams, bridge_config: &BridgeConfig) -> Result<()> {
     require!(
         params.new_validators.len() >= params.new_threshold as usize,
         LightClientError::InvalidThreshold
@@ -62,106 +134,167 @@ pub fn handler(
         LightClientError::InvalidValidatorSetUpdate
     );
 
-    // Verify signatures from current validators
-    verify_update_signatures(
-        &params,
-        &validator_set.validators,
-        validator_set.threshold,
-        validator_set.version,
-    )?;
+    // Enforce the governance floor so a quorum cannot shrink the set to 1-of-1
+    require!(
+        params.new_validators.len() >= bridge_config.min_validator_count as usize,
+        LightClientError::ValidatorCountBelowMinimum
+    );
+    require!(
+        params.new_threshold as usize >= bridge_config.min_threshold(params.new_validators.len()),
+        LightClientError::ThresholdBelowMinimum
+    );
+    require!(
+        bridge_config.threshold_allowed(params.new_validators.len(), params.new_threshold),
+        LightClientError::ThresholdBelowBftBound
+    );
+    require!(
+        X1ValidatorSet::weights_valid(&params.new_validators, &params.new_weights, params.new_threshold_bps),
+        LightClientError::InvalidValidatorWeights
+    );
 
-    msg!("✓ Threshold signatures verified ({} of {})",
-         params.approver_signatures.len(),
-         validator_set.validators.len());
+    Ok(())
+}
 
+/// Replace the set with an approved update, bump its version and record
+/// the transition in `history`
+///
+/// `payer` covers any top-up rent if the account has to grow. Returns the
+/// new version.
+pub(crate) fn apply_update<'info>(
+    validator_set: &mut Account<'info, X1ValidatorSet>,
+    history: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    params: UpdateValidatorSetParams,
+    approvers: Vec<Pubkey>,
+) -> Result<u64> {
     // Increment version (MUST be monotonically increasing)
     let new_version = validator_set.version
         .checked_add(1)
         .ok_or(LightClientError::ArithmeticOverflow)?;
 
+    // Legal but weaker: surface it so monitoring can alert
+    if weakens_safety(
+        validator_set.validators.len(),
+        validator_set.threshold,
+        params.new_validators.len(),
+        params.new_threshold,
+    ) || params.new_threshold_bps < validator_set.threshold_bps {
+        msg!("⚠️  Update weakens validator set safety");
+        emit!(ValidatorSetSafetyWeakened {
+            old_version: validator_set.version,
+            new_version,
+            old_validator_count: validator_set.validators.len() as u8,
+            old_threshold: validator_set.threshold,
+            new_validator_count: params.new_validators.len() as u8,
+            new_threshold: params.new_threshold,
+        });
+    }
+
+    emit!(ValidatorSetUpdated {
+        schema_version: LIGHT_CLIENT_EVENT_SCHEMA_VERSION,
+        old_version: validator_set.version,
+        new_version,
+        validators: params.new_validators.clone(),
+        threshold: params.new_threshold,
+        approvals: approvers.len() as u8,
+        weights: params.new_weights.clone(),
+        threshold_bps: params.new_threshold_bps,
+    });
+
+    // Sets created before the weights were appended may be too small for them
+    let info = validator_set.to_account_info();
+    let space = 8 + X1ValidatorSet::INIT_SPACE;
+    if info.data_len() < space {
+        let top_up = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+        if top_up > 0 {
+            transfer(
+                CpiContext::new(
+                    system_program.clone(),
+                    Transfer {
+                        from: payer.clone(),
+                        to: info.clone(),
+                    },
+                ),
+                top_up,
+            )?;
+        }
+        info.realloc(space, false)?;
+    }
+
+    let clock = Clock::get()?;
+    X1ValidatorSetHistory::append(history, X1ValidatorSetRecord {
+        version: new_version,
+        old_validators_hash: X1ValidatorSet::members_hash(&validator_set.validators, &validator_set.weights),
+        new_validators_hash: X1ValidatorSet::members_hash(&params.new_validators, &params.new_weights),
+        threshold: params.new_threshold,
+        threshold_bps: params.new_threshold_bps,
+        approvers,
+        timestamp: clock.unix_timestamp,
+        slot: clock.slot,
+    })?;
+
     // Update validator set
     validator_set.validators = params.new_validators;
     validator_set.threshold = params.new_threshold;
+    validator_set.weights = params.new_weights;
+    validator_set.threshold_bps = params.new_threshold_bps;
+    invariants::debug_check(invariants::version_monotonic(validator_set.version, new_version));
     validator_set.version = new_version;
 
     msg!("✅ Validator set updated successfully");
     msg!("   New version: {}", new_version);
 
-    Ok(())
+    Ok(new_version)
 }
 
-/// Verify that ≥threshold current validators signed this update
+/// Verify that ≥threshold current validators (carrying the current
+/// weighted threshold, if any) signed this update
 ///
 /// SECURITY CRITICAL: This enforces the trustless governance model
 fn verify_update_signatures(
     params: &UpdateValidatorSetParams,
-    current_validators: &[Pubkey],
-    current_threshold: u8,
-    current_version: u64,
+    current: &X1ValidatorSet,
+    x1_genesis_hash: Option<[u8; 32]>,
 ) -> Result<()> {
-    require!(
-        !params.approver_signatures.is_empty(),
-        LightClientError::InvalidValidatorSetUpdate
-    );
-
-    // Must have at least threshold signatures
-    require!(
-        params.approver_signatures.len() >= current_threshold as usize,
-        LightClientError::InsufficientSignatures
-    );
-
-    let mut verified_count = 0;
-    let mut seen_validators = std::collections::HashSet::new();
-
     // Create message that validators should have signed
     // Format: "VALIDATOR_UPDATE:v{current_version}:{new_validators_hash}:{new_threshold}"
     let message = create_update_message(
-        current_version,
+        current.version,
         &params.new_validators,
         params.new_threshold,
+        &params.new_weights,
+        params.new_threshold_bps,
+        x1_genesis_hash,
     );
 
-    for sig_data in &params.approver_signatures {
-        // Check for duplicate approvers
-        require!(
-            seen_validators.insert(sig_data.validator_pubkey),
-            LightClientError::DuplicateValidator
-        );
-
-        // Verify validator is in CURRENT set
-        require!(
-            current_validators.contains(&sig_data.validator_pubkey),
-            LightClientError::ValidatorNotInSet
-        );
-
-        // Verify Ed25519 signature
-        verify_ed25519_signature(
-            &sig_data.validator_pubkey.to_bytes(),
-            &message,
-            &sig_data.signature,
-        )?;
-
-        verified_count += 1;
-    }
-
-    // Must meet threshold
-    require!(
-        verified_count >= current_threshold,
-        LightClientError::InsufficientSignatures
-    );
-
-    msg!("✓ Verified {} signatures (threshold: {})", verified_count, current_threshold);
+    verify_threshold_approvals(
+        &params.approver_signatures,
+        &current.validators,
+        current.threshold,
+        &message,
+    )?;
+    require_weight(current, params.approver_signatures.iter().map(|approval| &approval.validator_pubkey))?;
 
     Ok(())
 }
 
 /// Create deterministic message for validator update
 ///
-/// Format: hash(VALIDATOR_UPDATE || version || validators_data || threshold)
-fn create_update_message(
+/// Format: hash(VALIDATOR_UPDATE || version || validators_data || threshold
+///   [|| WEIGHTS || weights || threshold_bps] [|| x1_genesis_hash])
+///
+/// The weights are only bound for a weighted set, so unweighted updates
+/// sign the same message as before. The genesis hash is appended once
+/// X1ChainId is set, so an update signed on a fork or clone of X1 cannot
+/// be replayed here.
+pub(crate) fn create_update_message(
     current_version: u64,
     new_validators: &[Pubkey],
     new_threshold: u8,
+    new_weights: &[u16],
+    new_threshold_bps: u16,
+    x1_genesis_hash: Option<[u8; 32]>,
 ) -> Vec<u8> {
     use anchor_lang::solana_program::hash::hash;
 
@@ -173,30 +306,47 @@ fn create_update_message(
         message_data.extend_from_slice(&validator.to_bytes());
     }
     message_data.extend_from_slice(&[new_threshold]);
+    if new_threshold_bps != 0 {
+        message_data.extend_from_slice(b"WEIGHTS");
+        for weight in new_weights {
+            message_data.extend_from_slice(&weight.to_le_bytes());
+        }
+        message_data.extend_from_slice(&new_threshold_bps.to_le_bytes());
+    }
+    if let Some(genesis_hash) = x1_genesis_hash {
+        message_data.extend_from_slice(&genesis_hash);
+    }
 
     // Hash for consistent size
     hash(&message_data).to_bytes().to_vec()
 }
 
-/// Verify Ed25519 signature format
-///
-/// SECURITY MODEL: For validator governance, we TRUST the validators
-/// to only sign legitimate updates. Format validation ensures correct structure.
-fn verify_ed25519_signature(
-    pubkey: &[u8; 32],
-    message: &[u8],
-    signature: &[u8; 64],
-) -> Result<()> {
-    // Validate signature format
-    require!(signature.len() == 64, LightClientError::InvalidSignatureFormat);
-    require!(pubkey.len() == 32, LightClientError::InvalidValidatorSignature);
-    require!(!message.is_empty(), LightClientError::InvalidProofData);
-
-    // Validators are trusted to sign correctly
-    // Real security comes from:
-    // 1. Validators only sign legitimate updates
-    // 2. Byzantine fault tolerance (threshold)
-    // 3. Validator operational security
+/// Warning event: an update lowered the threshold, shrank the set, or
+/// lowered the signing fraction (still within the configured policy)
+#[event]
+pub struct ValidatorSetSafetyWeakened {
+    pub old_version: u64,
+    pub new_version: u64,
+    pub old_validator_count: u8,
+    pub old_threshold: u8,
+    pub new_validator_count: u8,
+    pub new_threshold: u8,
+}
 
-    Ok(())
+/// Event emitted on every validator set rotation
+///
+/// Carries the full new set, so relayers can switch signers without
+/// re-reading the account.
+#[event]
+pub struct ValidatorSetUpdated {
+    pub schema_version: u16,
+    pub old_version: u64,
+    pub new_version: u64,
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Approver signatures submitted with the update
+    pub approvals: u8,
+    /// Voting weights of the new set (empty = unweighted)
+    pub weights: Vec<u16>,
+    pub threshold_bps: u16,
 }
@@ -0,0 +1,239 @@
+use anchor_lang::prelude::*;
+use crate::state::{AssetRegistry, BridgeConfig, VerifiedBurn, VerifiedBurnV3, X1ValidatorSet, X1ChainId};
+use crate::errors::LightClientError;
+use crate::governance::{create_governance_message, verify_validator_set_approvals};
+use crate::instructions::ValidatorUpdateSignature;
+use crate::XENCAT_MINT_PROGRAM_ID;
+
+/// Upgrade a lingering V2 VerifiedBurn into a V3 PDA (validator governance)
+///
+/// V2 attestations never checked which asset was burned, so the V2 record
+/// alone cannot say which V3 namespace the burn belongs to. Validators
+/// look the burn up on Solana and approve the upgrade for its asset; the
+/// V3 burn is created under that asset_id, never assumed to be XENCAT. The
+/// V2 account is marked processed in the same instruction so the burn can
+/// only ever be minted once (V2 mint_from_burn rejects processed burns).
+///
+/// NOTE: The mint program cannot persist `processed` on light-client owned
+/// accounts, so a V2 burn that was already minted may still read as
+/// unprocessed here. The xencat-mint-x1 V2 replay PDA is therefore checked
+/// directly and must not exist.
+///
+/// Stays open after the V2 cutoff: the cutoff only stops new V2
+/// submissions and mints, and retiring the V2 record is what keeps the
+/// burn from being minted twice.
+#[derive(Accounts)]
+#[instruction(asset_id: u8, burn_nonce: u64)]
+pub struct UpgradeVerifiedBurnToV3<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    /// Holds the governance nonce
+    #[account(
+        mut,
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Existing V2 verified burn (must be unprocessed and owned by user)
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v2",
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn_v2.bump,
+        constraint = !verified_burn_v2.processed @ LightClientError::BurnAlreadyProcessed,
+        constraint = verified_burn_v2.user == user.key() @ LightClientError::InvalidAttestation,
+    )]
+    pub verified_burn_v2: Account<'info, VerifiedBurn>,
+
+    /// xencat-mint-x1 V2 replay tracker for this nonce (must NOT exist)
+    /// CHECK: Only checked for emptiness; derived from the mint program's seeds
+    #[account(
+        seeds = [
+            b"processed_burn",
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = XENCAT_MINT_PROGRAM_ID,
+        constraint = mint_processed_burn_v2.data_is_empty() @ LightClientError::BurnAlreadyProcessed,
+    )]
+    pub mint_processed_burn_v2: UncheckedAccount<'info>,
+
+    /// Governed asset list (not initialized = built-in assets)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::resolve_active
+    #[account(seeds = [b"asset_registry"], bump)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// New V3 verified burn in the approved asset's namespace
+    /// Seeds: ["verified_burn_v3", asset_id, user, nonce]
+    #[account(
+        init,
+        payer = user,
+        space = 8 + VerifiedBurnV3::INIT_SPACE,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_burn_v3: Account<'info, VerifiedBurnV3>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct UpgradeVerifiedBurnToV3Params {
+    /// Signatures from current validators approving the burn's asset
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(
+    ctx: Context<UpgradeVerifiedBurnToV3>,
+    asset_id: u8,
+    burn_nonce: u64,
+    params: UpgradeVerifiedBurnToV3Params,
+) -> Result<()> {
+    msg!("🔁 Upgrading V2 verified burn to V3");
+    msg!("   Burn nonce: {}", burn_nonce);
+    msg!("   Asset: {}", asset_id);
+
+    let validator_set = &ctx.accounts.validator_set;
+    let bridge_config = &mut ctx.accounts.bridge_config;
+    AssetRegistry::resolve_active(&ctx.accounts.asset_registry, asset_id)?;
+
+    let v2 = &mut ctx.accounts.verified_burn_v2;
+    require!(v2.burn_nonce == burn_nonce, LightClientError::InvalidAttestation);
+
+    let message = create_upgrade_message(
+        validator_set.version,
+        bridge_config.nonce,
+        asset_id,
+        burn_nonce,
+        &v2.user,
+        v2.amount,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    bridge_config.nonce = bridge_config.nonce
+        .checked_add(1)
+        .ok_or(LightClientError::ArithmeticOverflow)?;
+
+    let v3 = &mut ctx.accounts.verified_burn_v3;
+    upgrade_burn(v2, v3, asset_id, ctx.bumps.verified_burn_v3, Clock::get()?.slot);
+
+    msg!("✅ Burn upgraded: V2 retired, V3 created with asset_id={}", v3.asset_id);
+
+    Ok(())
+}
+
+/// Copy a V2 burn into its V3 record and retire the V2 record
+fn upgrade_burn(v2: &mut VerifiedBurn, v3: &mut VerifiedBurnV3, asset_id: u8, bump: u8, slot: u64) {
+    v3.asset_id = asset_id;
+    v3.burn_nonce = v2.burn_nonce;
+    v3.user = v2.user;
+    v3.amount = v2.amount;
+    v3.verified_at = v2.verified_at; // Preserve original verification time
+    v3.processed = false;
+    v3.bump = bump;
+    // V2 attestations carry no burn slot
+    v3.solana_burn_slot = 0;
+    v3.verified_slot = slot;
+
+    // Retire the V2 record (prevents double mint via V2 + V3)
+    v2.processed = true;
+}
+
+/// Create deterministic message for a V2 -> V3 upgrade
+///
+/// Format: hash(UPGRADE_BURN_V3 || version || config_nonce || asset_id || burn_nonce
+///   || user || amount)
+fn create_upgrade_message(
+    validator_set_version: u64,
+    config_nonce: u64,
+    asset_id: u8,
+    burn_nonce: u64,
+    user: &Pubkey,
+    amount: u64,
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(57);
+    payload.extend_from_slice(&config_nonce.to_le_bytes());
+    payload.push(asset_id);
+    payload.extend_from_slice(&burn_nonce.to_le_bytes());
+    payload.extend_from_slice(user.as_ref());
+    payload.extend_from_slice(&amount.to_le_bytes());
+
+    create_governance_message(b"UPGRADE_BURN_V3", validator_set_version, &payload, x1_genesis_hash)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upgrade_after_v2_cutoff() {
+        let config = BridgeConfig {
+            v2_cutoff_slot: 1000,
+            nonce: 0,
+            min_validator_count: BridgeConfig::DEFAULT_MIN_VALIDATOR_COUNT,
+            min_threshold_numerator: BridgeConfig::DEFAULT_MIN_THRESHOLD_NUMERATOR,
+            min_threshold_denominator: BridgeConfig::DEFAULT_MIN_THRESHOLD_DENOMINATOR,
+            bft_mode: false,
+            bump: 255,
+        };
+        let slot = 5000;
+        assert!(config.is_v2_deprecated(slot));
+
+        let mut v2 = VerifiedBurn {
+            burn_nonce: 42,
+            user: Pubkey::new_unique(),
+            amount: 1_000_000,
+            verified_at: 1_700_000_000,
+            processed: false,
+            bump: 254,
+        };
+        let mut v3 = VerifiedBurnV3 {
+            asset_id: 0,
+            burn_nonce: 0,
+            user: Pubkey::default(),
+            amount: 0,
+            verified_at: 0,
+            processed: false,
+            bump: 0,
+            solana_burn_slot: 0,
+            verified_slot: 0,
+        };
+
+        upgrade_burn(&mut v2, &mut v3, 2, 253, slot);
+
+        // V2 is retired, so it can no longer be minted alongside the V3 record
+        assert!(v2.processed);
+        assert_eq!(v3.asset_id, 2);
+        assert_eq!(v3.burn_nonce, v2.burn_nonce);
+        assert_eq!(v3.user, v2.user);
+        assert_eq!(v3.amount, v2.amount);
+        assert_eq!(v3.verified_at, v2.verified_at);
+        assert!(!v3.processed);
+        assert_eq!(v3.bump, 253);
+        assert_eq!(v3.solana_burn_slot, 0);
+        assert_eq!(v3.verified_slot, slot);
+    }
+}
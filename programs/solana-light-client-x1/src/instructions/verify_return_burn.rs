@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use crate::state::{
+    return_attestation_message, Asset, BridgeDomain, FeatureGate, ReturnBurn, VerifiedReturnBurn, X1ChainId,
+    X1ValidatorSet, gates,
+};
+use crate::errors::LightClientError;
+use crate::governance::verify_validator_set_approvals;
+use crate::instructions::ValidatorUpdateSignature;
+
+/// Verify a return burn (X1 → Solana) against a threshold of validators
+///
+/// The burn itself is X1 state: the mint program's ReturnBurnRecord is read
+/// here directly, and the validators attest that they saw it finalized.
+/// The resulting VerifiedReturnBurn is what the settlement validators
+/// require before signing the burn program's release_return.
+#[derive(Accounts)]
+#[instruction(asset_id: u8, return_nonce: u64)]
+pub struct VerifyReturnBurn<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// The mint program's ReturnBurnRecord for `return_nonce`
+    /// CHECK: Address derived and data parsed in handler
+    pub return_burn: UncheckedAccount<'info>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + VerifiedReturnBurn::INIT_SPACE,
+        seeds = [
+            b"verified_return_burn",
+            asset_id.to_le_bytes().as_ref(),
+            return_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub verified_return_burn: Account<'info, VerifiedReturnBurn>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// This deployment's domain separator (not initialized = DOMAIN_SEPARATOR)
+    /// CHECK: Address pinned by seeds; read by BridgeDomain::resolve
+    #[account(seeds = [b"bridge_domain"], bump)]
+    pub bridge_domain: UncheckedAccount<'info>,
+
+    /// X1 genesis hash binding (not initialized = unbound messages)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::bind_domain
+    #[account(seeds = [b"x1_chain_id"], bump)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerifyReturnBurnParams {
+    /// Validator set version the attestations were signed for (must be current)
+    pub validator_set_version: u64,
+
+    /// Signatures over return_attestation_message
+    pub attestations: Vec<ValidatorUpdateSignature>,
+}
+
+pub fn handler(
+    ctx: Context<VerifyReturnBurn>,
+    asset_id: u8,
+    return_nonce: u64,
+    params: VerifyReturnBurnParams,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::RETURN)?;
+
+    let asset = Asset::from_u8(asset_id)?;
+    let validator_set = &ctx.accounts.validator_set;
+    require!(
+        params.validator_set_version == validator_set.version,
+        LightClientError::InvalidValidatorSetVersion
+    );
+
+    msg!("🔐 Verifying return burn {} (asset {})", return_nonce, asset_id);
+
+    let (expected_record, _) = Pubkey::find_program_address(
+        &[b"return_burn", return_nonce.to_le_bytes().as_ref()],
+        &asset.mint_program_id(),
+    );
+    let info = &ctx.accounts.return_burn;
+    require_keys_eq!(info.key(), expected_record, LightClientError::InvalidReturnBurn);
+    require_keys_eq!(*info.owner, asset.mint_program_id(), LightClientError::InvalidReturnBurn);
+    let burn = ReturnBurn::parse(&info.try_borrow_data()?)
+        .ok_or(LightClientError::InvalidReturnBurn)?;
+    require!(burn.nonce == return_nonce, LightClientError::InvalidReturnBurn);
+
+    let domain = X1ChainId::bind_domain(
+        BridgeDomain::resolve(&ctx.accounts.bridge_domain)?,
+        &ctx.accounts.x1_chain_id,
+    )?;
+    let message = return_attestation_message(&domain, asset_id, validator_set.version, &burn);
+    let approvals = verify_validator_set_approvals(&params.attestations, validator_set, &message)?;
+
+    let verified = &mut ctx.accounts.verified_return_burn;
+    verified.asset_id = asset_id;
+    verified.return_nonce = return_nonce;
+    verified.user = burn.user;
+    verified.solana_recipient = burn.solana_recipient;
+    verified.amount = burn.amount;
+    verified.x1_slot = burn.slot;
+    verified.validator_set_version = validator_set.version;
+    verified.verified_slot = Clock::get()?.slot;
+    verified.bump = ctx.bumps.verified_return_burn;
+
+    msg!("✅ Return burn verified ({} attestations): {} to {}", approvals, burn.amount, burn.solana_recipient);
+
+    Ok(())
+}
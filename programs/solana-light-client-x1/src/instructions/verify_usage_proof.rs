@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::{usage_leaf, UsageSnapshot};
+use crate::errors::LightClientError;
+
+/// Read-only: incentive programs CPI it (or read the snapshot and call
+/// UsageSnapshot::verify themselves)
+#[derive(Accounts)]
+#[instruction(epoch: u64)]
+pub struct VerifyUsageProof<'info> {
+    #[account(
+        seeds = [b"usage_snapshot", epoch.to_le_bytes().as_ref()],
+        bump = usage_snapshot.bump
+    )]
+    pub usage_snapshot: Account<'info, UsageSnapshot>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct VerifyUsageProofParams {
+    pub user: Pubkey,
+    pub asset_id: u8,
+    /// The user's bridged volume of the asset in the epoch
+    pub volume: u64,
+    pub leaf_index: u64,
+    /// USAGE_TREE_DEPTH siblings, leaf first
+    pub proof: Vec<[u8; 32]>,
+}
+
+pub fn handler(ctx: Context<VerifyUsageProof>, epoch: u64, params: VerifyUsageProofParams) -> Result<()> {
+    let leaf = usage_leaf(epoch, &params.user, params.asset_id, params.volume);
+    require!(
+        ctx.accounts.usage_snapshot.verify(leaf, params.leaf_index, &params.proof),
+        LightClientError::InvalidUsageProof
+    );
+
+    msg!("✅ {} bridged {} of asset {} in epoch {}", params.user, params.volume, params.asset_id, epoch);
+
+    Ok(())
+}
@@ -0,0 +1,173 @@
+//! Bridge-wide invariants
+//!
+//! Properties that must hold after EVERY instruction, whatever feature
+//! produced the state:
+//! - processed implies verified: a mint-side replay record has a matching
+//!   VerifiedBurnV3, or the burn was archived after minting
+//! - minted ≤ attested: no mint path releases more than the verified amount
+//! - version monotonicity: validator set versions never go back, and a mint
+//!   program never runs ahead of the light client's set
+//!
+//! Handlers call `debug_check` at the points they change this state, so the
+//! checks run in `cargo test` and debug builds and compile out of release
+//! (deployed) builds. tests/invariants.ts runs the same checks against
+//! on-chain accounts after every transaction of the integration suite.
+
+use anchor_lang::prelude::*;
+use crate::state::VerifiedBurnV3;
+
+/// A broken invariant (a state-machine bug, never a user error)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Violation {
+    /// Replay record without a VerifiedBurnV3 and not archived
+    ProcessedWithoutVerified { asset_id: u8, nonce: u64, user: Pubkey },
+    /// Replay record and VerifiedBurnV3 describe different burns
+    ProcessedMismatch { asset_id: u8, nonce: u64, user: Pubkey },
+    MintedAboveAttested { minted: u64, attested: u64 },
+    VersionRegressed { before: u64, after: u64 },
+    MintVersionAhead { mint_version: u64, light_client_version: u64 },
+}
+
+impl std::fmt::Display for Violation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Violation::ProcessedWithoutVerified { asset_id, nonce, user } => write!(
+                f, "burn {}/{} of {} processed but neither verified nor archived", asset_id, nonce, user
+            ),
+            Violation::ProcessedMismatch { asset_id, nonce, user } => write!(
+                f, "burn {}/{} of {} processed for a different verified burn", asset_id, nonce, user
+            ),
+            Violation::MintedAboveAttested { minted, attested } => write!(
+                f, "minted {} above the attested {}", minted, attested
+            ),
+            Violation::VersionRegressed { before, after } => write!(
+                f, "validator set version went from {} to {}", before, after
+            ),
+            Violation::MintVersionAhead { mint_version, light_client_version } => write!(
+                f, "mint program at validator set version {}, light client at {}", mint_version, light_client_version
+            ),
+        }
+    }
+}
+
+/// The fields of a mint program's ProcessedBurnV3 the invariants read
+/// (the type itself lives in each mint program)
+#[derive(Debug, Clone, Copy)]
+pub struct ProcessedRecord {
+    pub asset_id: u8,
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+/// A replay record needs its VerifiedBurnV3 (same burn, amount within the
+/// attested one), unless archive_verified_burn closed it after minting
+pub fn processed_implies_verified(
+    processed: &ProcessedRecord,
+    verified: Option<&VerifiedBurnV3>,
+    archived: bool,
+) -> std::result::Result<(), Violation> {
+    let (asset_id, nonce, user) = (processed.asset_id, processed.nonce, processed.user);
+    match verified {
+        None if archived => Ok(()),
+        None => Err(Violation::ProcessedWithoutVerified { asset_id, nonce, user }),
+        Some(verified) => {
+            if verified.asset_id != asset_id || verified.burn_nonce != nonce || verified.user != user {
+                return Err(Violation::ProcessedMismatch { asset_id, nonce, user });
+            }
+            minted_within_attested(processed.amount, verified.amount)
+        }
+    }
+}
+
+/// Everything a mint released for one burn (recipient + relayer, etc.)
+pub fn minted_within_attested(minted: u64, attested: u64) -> std::result::Result<(), Violation> {
+    if minted > attested {
+        return Err(Violation::MintedAboveAttested { minted, attested });
+    }
+    Ok(())
+}
+
+/// Validator set version across one instruction
+pub fn version_monotonic(before: u64, after: u64) -> std::result::Result<(), Violation> {
+    if after < before {
+        return Err(Violation::VersionRegressed { before, after });
+    }
+    Ok(())
+}
+
+/// A mint program's recorded validator set version vs the light client's
+pub fn mint_version_not_ahead(mint_version: u64, light_client_version: u64) -> std::result::Result<(), Violation> {
+    if mint_version > light_client_version {
+        return Err(Violation::MintVersionAhead { mint_version, light_client_version });
+    }
+    Ok(())
+}
+
+/// Panic on a violation in debug builds; a no-op in release builds
+pub fn debug_check(result: std::result::Result<(), Violation>) {
+    #[cfg(debug_assertions)]
+    if let Err(violation) = result {
+        panic!("bridge invariant violated: {}", violation);
+    }
+    #[cfg(not(debug_assertions))]
+    let _ = result;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn verified(amount: u64) -> VerifiedBurnV3 {
+        VerifiedBurnV3 {
+            asset_id: 1,
+            burn_nonce: 7,
+            user: Pubkey::new_from_array([3u8; 32]),
+            amount,
+            verified_at: 0,
+            processed: true,
+            bump: 255,
+            solana_burn_slot: 0,
+            verified_slot: 0,
+        }
+    }
+
+    #[test]
+    fn test_processed_implies_verified() {
+        let processed = ProcessedRecord { asset_id: 1, nonce: 7, user: Pubkey::new_from_array([3u8; 32]), amount: 100 };
+
+        assert!(processed_implies_verified(&processed, Some(&verified(100)), false).is_ok());
+        assert!(processed_implies_verified(&processed, None, true).is_ok());
+        assert!(matches!(
+            processed_implies_verified(&processed, None, false),
+            Err(Violation::ProcessedWithoutVerified { .. })
+        ));
+        assert_eq!(
+            processed_implies_verified(&processed, Some(&verified(99)), false),
+            Err(Violation::MintedAboveAttested { minted: 100, attested: 99 })
+        );
+
+        let other_user = ProcessedRecord { user: Pubkey::new_unique(), ..processed };
+        assert!(matches!(
+            processed_implies_verified(&other_user, Some(&verified(100)), false),
+            Err(Violation::ProcessedMismatch { .. })
+        ));
+    }
+
+    #[test]
+    fn test_versions() {
+        assert!(version_monotonic(3, 3).is_ok());
+        assert!(version_monotonic(3, 4).is_ok());
+        assert_eq!(version_monotonic(4, 3), Err(Violation::VersionRegressed { before: 4, after: 3 }));
+
+        assert!(mint_version_not_ahead(3, 4).is_ok());
+        assert!(mint_version_not_ahead(5, 4).is_err());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "bridge invariant violated")]
+    fn test_debug_check_panics() {
+        debug_check(minted_within_attested(2, 1));
+    }
+}
@@ -4,8 +4,19 @@ pub mod instructions;
 pub mod state;
 pub mod errors;
 // pub mod verification; // Legacy - disabled, using verification_new instead
+#[cfg(test)]
+mod verification; // Compiled only as the reference for verification_diff
 pub mod verification_new;
+#[cfg(test)]
+mod verification_diff;
+#[cfg(test)]
+mod stake_math_props;
 pub mod ed25519_utils;
+pub mod governance;
+pub mod invariants;
+pub mod compute_report;
+pub mod consumer;
+pub mod wormhole;
 
 use instructions::*;
 pub use state::{
@@ -18,6 +29,43 @@ pub use state::{
     Asset,
     VerifiedBurnV3,
     BurnAttestationDataV3,
+    AttestationCheck,
+    AttestationCheckCode,
+    BridgeConfig,
+    SolanaChainState,
+    ConversionRate,
+    ConversionTable,
+    ValidatorResignation,
+    ValidatorLatency,
+    ValidatorLatencyStats,
+    ValidatorSetCheckpoint,
+    PendingValidatorSetRestore,
+    ValidatorSetTimelock,
+    PendingValidatorSetUpdate,
+    X1ValidatorSetHistory,
+    X1ValidatorSetRecord,
+    BridgeCapabilities,
+    BridgeInfo,
+    RentSponsorPool,
+    SponsoredRent,
+    FeatureGate,
+    BridgeDomain,
+    X1ChainId,
+    MintAcknowledgement,
+    ReturnBurn,
+    VerifiedReturnBurn,
+    RegisteredAsset,
+    AssetRegistry,
+    DepositWebhook,
+    EventSchema,
+    EventSchemaRegistry,
+    AssetMetadata,
+    ProgramHash,
+    ProgramHashRegistry,
+    SweepBountyPool,
+    DegradedMode,
+    DegradedBurn,
+    RelayerClaim,
     // Legacy state structures - keeping for reference
     LightClientState,
     ValidatorSet,
@@ -31,8 +79,19 @@ declare_id!("BXBZtvFfCtCapQgqFTxGQ9hgJTQZUoHFzBXD2V3ys5C5");
 
 /// Domain separator for cryptographic signature binding
 /// Prevents cross-domain signature replay attacks
+///
+/// Default only: a deployment with a BridgeDomain account signs with that
+/// value instead (see BridgeDomain::resolve)
 pub const DOMAIN_SEPARATOR: &str = "XENCAT_X1_BRIDGE_V1";
 
+/// xencat-mint-x1 program ID (owner of V2 processed_burn replay PDAs)
+pub const XENCAT_MINT_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("8kmoPKtLAjjzQRN5i4emUsmWeu3LM5yPWFrsqZVyekhk");
+
+/// dgn-mint-x1 program ID (owner of DGN processed_burn_v3 replay PDAs)
+pub const DGN_MINT_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("4YPipW8txxY3N7gHdj4NLhu8YxybHgarx5dJQCdCnQHs");
+
 /// Production configuration constants for 5-validator bridge
 pub mod config {
     /// Target number of validators for proof verification
@@ -57,6 +116,36 @@ pub mod config {
     /// 6667 = 66.67% of total stake
     /// Used when we have full validator set participation
     pub const BFT_THRESHOLD_BASIS_POINTS: u64 = 6667; // 66.67%
+
+    /// Slots between a validator's resignation request and its removal
+    /// 432_000 slots ≈ 2 days at 400ms, enough for relayers to drain in-flight burns
+    pub const RESIGNATION_COOLDOWN_SLOTS: u64 = 432_000;
+
+    /// Slots between approving a validator set restore and applying it
+    /// 1_296_000 slots ≈ 6 days, so operators can spot and cancel a bad restore
+    pub const RESTORE_TIMELOCK_SLOTS: u64 = 1_296_000;
+
+    /// Length of a burn archive period (one X1 epoch)
+    pub const ARCHIVE_PERIOD_SLOTS: u64 = 432_000;
+
+    /// Periods a minted VerifiedBurnV3 is kept before it may be archived and closed
+    /// 30 periods ≈ 2 months at 400ms, longer than any relayer or dispute window
+    pub const BURN_RETENTION_PERIODS: u64 = 30;
+
+    /// Shortest hold of a burn verified in degraded mode (reduced quorum)
+    /// 6 hours: time for the live guardians to review and freeze it
+    pub const MIN_DEGRADED_DELAY_SECONDS: i64 = 21_600;
+
+    /// Slots between a burn's verification and a governed redirect of its recipient
+    /// 6_480_000 slots ≈ 30 days at 400ms: only burns their recipient cannot mint
+    pub const BURN_REDIRECT_DELAY_SLOTS: u64 = 6_480_000;
+
+    /// Length of a usage snapshot epoch (one X1 epoch, as archive periods)
+    pub const USAGE_EPOCH_SLOTS: u64 = 432_000;
+
+    /// Bridge protocol version reported by get_bridge_info (major, minor, patch)
+    /// Minor: new capability bit or instruction; major: message or account layout break
+    pub const BRIDGE_VERSION: [u16; 3] = [3, 1, 0];
 }
 
 #[program]
@@ -72,6 +161,9 @@ pub mod solana_light_client_x1 {
     }
 
     /// Update validator set (requires threshold signatures from current validators)
+    ///
+    /// The new set must satisfy the BridgeConfig validator floor. Refused
+    /// once the validator set timelock exists (propose/execute instead).
     pub fn update_validator_set(
         ctx: Context<UpdateValidatorSet>,
         params: UpdateValidatorSetParams,
@@ -79,7 +171,475 @@ pub mod solana_light_client_x1 {
         instructions::update_validator_set::handler(ctx, params)
     }
 
+    /// Initialize bridge configuration (run once, permissionless)
+    pub fn initialize_bridge_config(ctx: Context<InitializeBridgeConfig>) -> Result<()> {
+        instructions::initialize_bridge_config::handler(ctx)
+    }
+
+    /// Schedule the V2 deprecation cutoff (requires threshold signatures)
+    pub fn set_v2_cutoff(
+        ctx: Context<SetV2Cutoff>,
+        params: SetV2CutoffParams,
+    ) -> Result<()> {
+        instructions::set_v2_cutoff::handler(ctx, params)
+    }
+
+    /// Set the minimum validator count / threshold fraction enforced by
+    /// update_validator_set (requires threshold signatures)
+    pub fn set_validator_floor(
+        ctx: Context<SetValidatorFloor>,
+        params: SetValidatorFloorParams,
+    ) -> Result<()> {
+        instructions::set_validator_floor::handler(ctx, params)
+    }
+
+    /// Start a voluntary resignation (signed by the resigning validator)
+    pub fn request_validator_resignation(ctx: Context<RequestValidatorResignation>) -> Result<()> {
+        instructions::request_validator_resignation::handler(ctx)
+    }
+
+    /// Withdraw a pending resignation
+    pub fn cancel_validator_resignation(ctx: Context<CancelValidatorResignation>) -> Result<()> {
+        instructions::cancel_validator_resignation::handler(ctx)
+    }
+
+    /// Remove a resigned validator once the cooldown has elapsed (permissionless)
+    pub fn finalize_validator_resignation(ctx: Context<FinalizeValidatorResignation>) -> Result<()> {
+        instructions::finalize_validator_resignation::handler(ctx)
+    }
+
+    /// Check a V3 attestation bundle's version, membership, duplicates and
+    /// signature encodings without creating anything (view: read the
+    /// return data from a simulation)
+    pub fn prevalidate_attestation(
+        ctx: Context<PrevalidateAttestation>,
+        asset_id: u8,
+        burn_nonce: u64,
+        attestation: BurnAttestationDataV3,
+    ) -> Result<AttestationCheck> {
+        instructions::prevalidate_attestation::handler(ctx, asset_id, burn_nonce, attestation)
+    }
+
+    /// Report versions, attestation message versions, assets and feature
+    /// flags (view: read the return data from a simulation)
+    pub fn get_bridge_info(ctx: Context<GetBridgeInfo>) -> Result<BridgeCapabilities> {
+        instructions::get_bridge_info::handler(ctx)
+    }
+
+    /// Create the BridgeInfo capability account (run once, permissionless)
+    pub fn initialize_bridge_info(ctx: Context<InitializeBridgeInfo>) -> Result<()> {
+        instructions::initialize_bridge_info::handler(ctx)
+    }
+
+    /// Refresh the BridgeInfo capability account (permissionless)
+    pub fn sync_bridge_info(ctx: Context<SyncBridgeInfo>) -> Result<()> {
+        instructions::sync_bridge_info::handler(ctx)
+    }
+
+    /// Create the validator set checkpoint from the live set (run once, permissionless)
+    pub fn initialize_validator_set_checkpoint(ctx: Context<InitializeValidatorSetCheckpoint>) -> Result<()> {
+        instructions::initialize_validator_set_checkpoint::handler(ctx)
+    }
+
+    /// Copy the live validator set into the checkpoint (permissionless)
+    pub fn sync_validator_set_checkpoint(ctx: Context<SyncValidatorSetCheckpoint>) -> Result<()> {
+        instructions::sync_validator_set_checkpoint::handler(ctx)
+    }
+
+    /// Schedule a validator set restore from a snapshot (requires threshold
+    /// signatures from the checkpoint validators, then a timelock)
+    pub fn schedule_validator_set_restore(
+        ctx: Context<ScheduleValidatorSetRestore>,
+        params: ScheduleValidatorSetRestoreParams,
+    ) -> Result<()> {
+        instructions::schedule_validator_set_restore::handler(ctx, params)
+    }
+
+    /// Cancel a scheduled restore (requires threshold signatures from the checkpoint validators)
+    pub fn cancel_validator_set_restore(
+        ctx: Context<CancelValidatorSetRestore>,
+        params: CancelValidatorSetRestoreParams,
+    ) -> Result<()> {
+        instructions::cancel_validator_set_restore::handler(ctx, params)
+    }
+
+    /// Rewrite the validator set PDA from a scheduled restore once the timelock has elapsed (permissionless)
+    pub fn execute_validator_set_restore(ctx: Context<ExecuteValidatorSetRestore>) -> Result<()> {
+        instructions::execute_validator_set_restore::handler(ctx)
+    }
+
+    /// Create the validator set update timelock, refusing instant updates
+    /// from then on (run once, permissionless)
+    pub fn initialize_validator_set_timelock(ctx: Context<InitializeValidatorSetTimelock>) -> Result<()> {
+        instructions::initialize_validator_set_timelock::handler(ctx)
+    }
+
+    /// Set the validator set update delay (requires threshold signatures)
+    pub fn set_validator_set_update_delay(
+        ctx: Context<SetValidatorSetUpdateDelay>,
+        params: SetValidatorSetUpdateDelayParams,
+    ) -> Result<()> {
+        instructions::set_validator_set_update_delay::handler(ctx, params)
+    }
+
+    /// Propose a validator set update (requires threshold signatures, then
+    /// the timelock's delay)
+    pub fn propose_validator_set_update(
+        ctx: Context<ProposeValidatorSetUpdate>,
+        params: UpdateValidatorSetParams,
+    ) -> Result<()> {
+        instructions::propose_validator_set_update::handler(ctx, params)
+    }
+
+    /// Cancel a proposed update (requires threshold signatures)
+    pub fn cancel_validator_set_update(
+        ctx: Context<CancelValidatorSetUpdate>,
+        params: CancelValidatorSetUpdateParams,
+    ) -> Result<()> {
+        instructions::cancel_validator_set_update::handler(ctx, params)
+    }
+
+    /// Apply a proposed update once its delay has elapsed (permissionless)
+    pub fn execute_validator_set_update(ctx: Context<ExecuteValidatorSetUpdate>) -> Result<()> {
+        instructions::execute_validator_set_update::handler(ctx)
+    }
+
+    /// Create the X1 validator set history, recording every update from
+    /// then on (run once, permissionless)
+    pub fn initialize_x1_validator_set_history(ctx: Context<InitializeX1ValidatorSetHistory>) -> Result<()> {
+        instructions::initialize_x1_validator_set_history::handler(ctx)
+    }
+
+    /// Create the burn archive for one asset and archive period (permissionless)
+    pub fn initialize_burn_archive(
+        ctx: Context<InitializeBurnArchive>,
+        asset_id: u8,
+        period: u64,
+    ) -> Result<()> {
+        instructions::initialize_burn_archive::handler(ctx, asset_id, period)
+    }
+
+    /// Append a minted, out-of-retention VerifiedBurnV3 to its period's archive and close it (permissionless)
+    pub fn archive_verified_burn(
+        ctx: Context<ArchiveVerifiedBurn>,
+        asset_id: u8,
+        burn_nonce: u64,
+        period: u64,
+    ) -> Result<()> {
+        instructions::archive_verified_burn::handler(ctx, asset_id, burn_nonce, period)
+    }
+
+    /// Emergency freeze of one verified burn (fast guardian quorum, blocks its mint)
+    pub fn freeze_burn(
+        ctx: Context<FreezeBurn>,
+        asset_id: u8,
+        burn_nonce: u64,
+        user: Pubkey,
+        params: FreezeBurnParams,
+    ) -> Result<()> {
+        instructions::freeze_burn::handler(ctx, asset_id, burn_nonce, user, params)
+    }
+
+    /// Lift a burn freeze (requires threshold signatures from current validators)
+    pub fn unfreeze_burn(ctx: Context<UnfreezeBurn>, params: UnfreezeBurnParams) -> Result<()> {
+        instructions::unfreeze_burn::handler(ctx, params)
+    }
+
+    /// Re-point a stranded verified burn to a new recipient (threshold signatures, ~30 days after verification)
+    pub fn redirect_verified_burn(
+        ctx: Context<RedirectVerifiedBurn>,
+        asset_id: u8,
+        burn_nonce: u64,
+        user: Pubkey,
+        recipient: Pubkey,
+        params: RedirectVerifiedBurnParams,
+    ) -> Result<()> {
+        instructions::redirect_verified_burn::handler(ctx, asset_id, burn_nonce, user, recipient, params)
+    }
+
+    /// Initialize the rent sponsor pool (run once, permissionless; starts off)
+    pub fn initialize_rent_sponsor_pool(ctx: Context<InitializeRentSponsorPool>) -> Result<()> {
+        instructions::initialize_rent_sponsor_pool::handler(ctx)
+    }
+
+    /// Add lamports to the rent sponsor pool (anyone)
+    pub fn fund_rent_sponsor_pool(ctx: Context<FundRentSponsorPool>, lamports: u64) -> Result<()> {
+        instructions::fund_rent_sponsor_pool::handler(ctx, lamports)
+    }
+
+    /// Set the sponsored burn limit / withdraw pool funds (requires threshold signatures)
+    pub fn configure_rent_sponsor_pool(
+        ctx: Context<ConfigureRentSponsorPool>,
+        params: ConfigureRentSponsorPoolParams,
+    ) -> Result<()> {
+        instructions::configure_rent_sponsor_pool::handler(ctx, params)
+    }
+
+    /// Pay a small burn's VerifiedBurnV3 rent from the pool (must precede its submit_burn_attestation_v3)
+    pub fn sponsor_burn_rent(ctx: Context<SponsorBurnRent>, asset_id: u8, burn_nonce: u64) -> Result<()> {
+        instructions::sponsor_burn_rent::handler(ctx, asset_id, burn_nonce)
+    }
+
+    /// Initialize the feature gate (run once, permissionless; everything enabled)
+    pub fn initialize_feature_gate(ctx: Context<InitializeFeatureGate>) -> Result<()> {
+        instructions::initialize_feature_gate::handler(ctx)
+    }
+
+    /// Switch individual instructions off/on (requires threshold signatures)
+    pub fn set_feature_gate(ctx: Context<SetFeatureGate>, params: SetFeatureGateParams) -> Result<()> {
+        instructions::set_feature_gate::handler(ctx, params)
+    }
+
+    /// Emergency stop of every gated path (requires threshold signatures)
+    pub fn pause_bridge(ctx: Context<PauseBridge>, params: PauseBridgeParams) -> Result<()> {
+        instructions::pause_bridge::handler(ctx, params)
+    }
+
+    /// Lift an emergency pause (requires threshold signatures)
+    pub fn unpause_bridge(ctx: Context<UnpauseBridge>, params: UnpauseBridgeParams) -> Result<()> {
+        instructions::unpause_bridge::handler(ctx, params)
+    }
+
+    /// Set this deployment's attestation domain separator (run once, requires threshold signatures)
+    pub fn initialize_bridge_domain(ctx: Context<InitializeBridgeDomain>, params: InitializeBridgeDomainParams) -> Result<()> {
+        instructions::initialize_bridge_domain::handler(ctx, params)
+    }
+
+    /// Bind attestation and update messages to the X1 genesis hash (run once, requires threshold signatures)
+    pub fn initialize_x1_chain_id(ctx: Context<InitializeX1ChainId>, params: InitializeX1ChainIdParams) -> Result<()> {
+        instructions::initialize_x1_chain_id::handler(ctx, params)
+    }
+
+    /// Register deposit notifications for the signing X1 recipient (webhook URL hash + preferences)
+    pub fn register_deposit_webhook(ctx: Context<RegisterDepositWebhook>, params: DepositWebhookParams) -> Result<()> {
+        instructions::register_deposit_webhook::handler(ctx, params)
+    }
+
+    /// Change the recipient's webhook URL hash or preferences (recipient only)
+    pub fn update_deposit_webhook(ctx: Context<UpdateDepositWebhook>, params: DepositWebhookParams) -> Result<()> {
+        instructions::update_deposit_webhook::handler(ctx, params)
+    }
+
+    /// Remove the recipient's deposit webhook (recipient only, rent refunded)
+    pub fn close_deposit_webhook(ctx: Context<CloseDepositWebhook>) -> Result<()> {
+        instructions::close_deposit_webhook::handler(ctx)
+    }
+
+    /// Initialize the event schema registry (run once, permissionless; every program at version 0)
+    pub fn initialize_event_schema_registry(ctx: Context<InitializeEventSchemaRegistry>) -> Result<()> {
+        instructions::initialize_event_schema_registry::handler(ctx)
+    }
+
+    /// Bump a program's event schema version after an upgrade (requires threshold signatures)
+    pub fn set_event_schema_version(ctx: Context<SetEventSchemaVersion>, params: SetEventSchemaVersionParams) -> Result<()> {
+        instructions::set_event_schema_version::handler(ctx, params)
+    }
+
+    /// Symbol, name, decimals and logo hash of an asset for wallets
+    /// (view: read the return data from a simulation)
+    pub fn get_asset_metadata(ctx: Context<GetAssetMetadata>, asset_id: u8) -> Result<AssetMetadata> {
+        instructions::get_asset_metadata::handler(ctx, asset_id)
+    }
+
+    /// Initialize the program hash registry (run once, permissionless; nothing attested)
+    pub fn initialize_program_hash_registry(ctx: Context<InitializeProgramHashRegistry>) -> Result<()> {
+        instructions::initialize_program_hash_registry::handler(ctx)
+    }
+
+    /// Attest the expected hash of a program build (validator governance)
+    pub fn attest_program_hash(ctx: Context<AttestProgramHash>, params: AttestProgramHashParams) -> Result<()> {
+        instructions::attest_program_hash::handler(ctx, params)
+    }
+
+    /// Initialize the sweep bounty pool (run once, permissionless; no bounty)
+    pub fn initialize_sweep_bounty_pool(ctx: Context<InitializeSweepBountyPool>) -> Result<()> {
+        instructions::initialize_sweep_bounty_pool::handler(ctx)
+    }
+
+    /// Add lamports to the sweep bounty pool (anyone)
+    pub fn fund_sweep_bounty_pool(ctx: Context<FundSweepBountyPool>, lamports: u64) -> Result<()> {
+        instructions::fund_sweep_bounty_pool::handler(ctx, lamports)
+    }
+
+    /// Set the sweep bounty / withdraw pool funds (requires threshold signatures)
+    pub fn configure_sweep_bounty_pool(
+        ctx: Context<ConfigureSweepBountyPool>,
+        params: ConfigureSweepBountyPoolParams,
+    ) -> Result<()> {
+        instructions::configure_sweep_bounty_pool::handler(ctx, params)
+    }
+
+    /// Archive and close a minted, out-of-retention VerifiedBurnV3 and pay the caller a bounty (permissionless)
+    pub fn sweep_verified_burn(
+        ctx: Context<SweepVerifiedBurn>,
+        asset_id: u8,
+        burn_nonce: u64,
+        period: u64,
+    ) -> Result<()> {
+        instructions::sweep_verified_burn::handler(ctx, asset_id, burn_nonce, period)
+    }
+
+    /// Initialize an asset's degraded mode account (run once per asset, permissionless; disabled)
+    pub fn initialize_degraded_mode(ctx: Context<InitializeDegradedMode>, asset_id: u8) -> Result<()> {
+        instructions::initialize_degraded_mode::handler(ctx, asset_id)
+    }
+
+    /// Set an asset's degraded quorum, caps and delay (requires threshold signatures)
+    pub fn configure_degraded_mode(
+        ctx: Context<ConfigureDegradedMode>,
+        asset_id: u8,
+        params: ConfigureDegradedModeParams,
+    ) -> Result<()> {
+        instructions::configure_degraded_mode::handler(ctx, asset_id, params)
+    }
+
+    /// Submit burn with a reduced quorum of attestations; held until release_degraded_burn
+    pub fn submit_burn_attestation_degraded(
+        ctx: Context<SubmitBurnAttestationDegraded>,
+        asset_id: u8,
+        burn_nonce: u64,
+        attestation: BurnAttestationDataV3,
+    ) -> Result<()> {
+        instructions::submit_burn_attestation_degraded::handler(ctx, asset_id, burn_nonce, attestation)
+    }
+
+    /// Submit a designated-recipient burn as a relayer (relayer pays; claim terms attested)
+    pub fn submit_burn_attestation_for_recipient(
+        ctx: Context<SubmitBurnAttestationForRecipient>,
+        asset_id: u8,
+        burn_nonce: u64,
+        attestation: BurnAttestationDataV3,
+        claim: RelayerClaimData,
+    ) -> Result<()> {
+        instructions::submit_burn_attestation_for_recipient::handler(ctx, asset_id, burn_nonce, attestation, claim)
+    }
+
+    /// Release a degraded-mode burn for minting once its delay has passed (permissionless)
+    pub fn release_degraded_burn(ctx: Context<ReleaseDegradedBurn>) -> Result<()> {
+        instructions::release_degraded_burn::handler(ctx)
+    }
+
+    /// Record an X1 return burn attested by a threshold of validators (X1 → Solana)
+    pub fn verify_return_burn(
+        ctx: Context<VerifyReturnBurn>,
+        asset_id: u8,
+        return_nonce: u64,
+        params: VerifyReturnBurnParams,
+    ) -> Result<()> {
+        instructions::verify_return_burn::handler(ctx, asset_id, return_nonce, params)
+    }
+
+    /// Initialize the asset registry with the built-in assets (run once, permissionless)
+    pub fn initialize_asset_registry(ctx: Context<InitializeAssetRegistry>) -> Result<()> {
+        instructions::initialize_asset_registry::handler(ctx)
+    }
+
+    /// Register a bridged asset and its mint program (validator governance)
+    pub fn register_asset(ctx: Context<RegisterAsset>, params: RegisterAssetParams) -> Result<()> {
+        instructions::register_asset::handler(ctx, params)
+    }
+
+    /// Stop attesting new burns of an asset (validator governance)
+    pub fn deactivate_asset(ctx: Context<DeactivateAsset>, params: DeactivateAssetParams) -> Result<()> {
+        instructions::deactivate_asset::handler(ctx, params)
+    }
+
+    /// Stop attestations and mints of one asset (validator governance)
+    pub fn freeze_asset(ctx: Context<FreezeAsset>, params: FreezeAssetParams) -> Result<()> {
+        instructions::freeze_asset::handler(ctx, params)
+    }
+
+    /// Lift an asset freeze (validator governance)
+    pub fn unfreeze_asset(ctx: Context<UnfreezeAsset>, params: UnfreezeAssetParams) -> Result<()> {
+        instructions::unfreeze_asset::handler(ctx, params)
+    }
+
+    /// Grow a validator set created before its appended fields and zero-fill them (run once, permissionless)
+    pub fn migrate_validator_set(ctx: Context<MigrateValidatorSet>) -> Result<()> {
+        instructions::migrate_validator_set::handler(ctx)
+    }
+
+    /// Set how old a V3 attestation may be when submitted (requires threshold signatures)
+    pub fn set_max_attestation_age(ctx: Context<SetMaxAttestationAge>, params: SetMaxAttestationAgeParams) -> Result<()> {
+        instructions::set_max_attestation_age::handler(ctx, params)
+    }
+
+    /// Require attestations in ascending validator set order, or stop requiring it (requires threshold signatures)
+    pub fn set_attestation_ordering(ctx: Context<SetAttestationOrdering>, params: SetAttestationOrderingParams) -> Result<()> {
+        instructions::set_attestation_ordering::handler(ctx, params)
+    }
+
+    /// Initialize the burn age limit (run once, permissionless; no limit)
+    pub fn initialize_burn_age_limit(ctx: Context<InitializeBurnAgeLimit>) -> Result<()> {
+        instructions::initialize_burn_age_limit::handler(ctx)
+    }
+
+    /// Set or lower the oldest burn a V3 attestation may verify (requires threshold signatures)
+    pub fn set_burn_age_limit(ctx: Context<SetBurnAgeLimit>, params: SetBurnAgeLimitParams) -> Result<()> {
+        instructions::set_burn_age_limit::handler(ctx, params)
+    }
+
+    /// Initialize the fee lookup table record (run once, permissionless)
+    pub fn initialize_fee_lookup_table(ctx: Context<InitializeFeeLookupTable>) -> Result<()> {
+        instructions::initialize_fee_lookup_table::handler(ctx)
+    }
+
+    /// Publish a frozen address lookup table of the current validators' payout accounts (permissionless)
+    pub fn publish_fee_lookup_table(ctx: Context<PublishFeeLookupTable>) -> Result<()> {
+        instructions::publish_fee_lookup_table::handler(ctx)
+    }
+
+    /// Post a challenge validators must sign for one of the caller's burns
+    pub fn request_attestation_challenge(
+        ctx: Context<RequestAttestationChallenge>,
+        asset_id: u8,
+        burn_nonce: u64,
+    ) -> Result<()> {
+        instructions::request_attestation_challenge::handler(ctx, asset_id, burn_nonce)
+    }
+
+    /// Close the caller's attestation challenge (rent refunded)
+    pub fn close_attestation_challenge(
+        ctx: Context<CloseAttestationChallenge>,
+        asset_id: u8,
+        burn_nonce: u64,
+    ) -> Result<()> {
+        instructions::close_attestation_challenge::handler(ctx, asset_id, burn_nonce)
+    }
+
+    /// Create the dev mode config (permissionless, starts as production)
+    pub fn initialize_dev_mode_config(ctx: Context<InitializeDevModeConfig>) -> Result<()> {
+        instructions::initialize_dev_mode_config::handler(ctx)
+    }
+
+    /// Mark or unmark a dev-mode deployment as non-production (requires threshold signatures)
+    pub fn set_dev_mode_config(ctx: Context<SetDevModeConfig>, params: SetDevModeConfigParams) -> Result<()> {
+        instructions::set_dev_mode_config::handler(ctx, params)
+    }
+
+    /// Commit the Merkle root of an ended epoch's per-user bridged volume (requires threshold signatures)
+    pub fn commit_usage_snapshot(
+        ctx: Context<CommitUsageSnapshot>,
+        epoch: u64,
+        params: CommitUsageSnapshotParams,
+    ) -> Result<()> {
+        instructions::commit_usage_snapshot::handler(ctx, epoch, params)
+    }
+
+    /// Check a user's bridged volume against an epoch's usage snapshot
+    /// (read-only; fails on an invalid proof, so incentive programs can CPI it)
+    pub fn verify_usage_proof(ctx: Context<VerifyUsageProof>, epoch: u64, params: VerifyUsageProofParams) -> Result<()> {
+        instructions::verify_usage_proof::handler(ctx, epoch, params)
+    }
+
+    /// Initialize the validator latency stats account (run once, permissionless)
+    pub fn initialize_latency_stats(ctx: Context<InitializeLatencyStats>) -> Result<()> {
+        instructions::initialize_latency_stats::handler(ctx)
+    }
+
     /// Submit burn with X1 validator attestations (V2 - XENCAT only)
+    ///
+    /// DEPRECATED: Rejected once the governed V2 cutoff slot is reached.
     pub fn submit_burn_attestation(
         ctx: Context<SubmitBurnAttestation>,
         attestation: BurnAttestationData,
@@ -101,6 +661,102 @@ pub mod solana_light_client_x1 {
         instructions::submit_burn_attestation_v3::handler(ctx, asset_id, burn_nonce, attestation)
     }
 
+    /// Upgrade an unprocessed V2 VerifiedBurn into a VerifiedBurnV3 of the approved asset
+    /// (before the V2 cutoff; requires threshold signatures)
+    pub fn upgrade_verified_burn_to_v3(
+        ctx: Context<UpgradeVerifiedBurnToV3>,
+        asset_id: u8,
+        burn_nonce: u64,
+        params: UpgradeVerifiedBurnToV3Params,
+    ) -> Result<()> {
+        instructions::upgrade_verified_burn_to_v3::handler(ctx, asset_id, burn_nonce, params)
+    }
+
+    /// Initialize the Solana chain state account (run once, permissionless)
+    pub fn initialize_solana_chain_state(ctx: Context<InitializeSolanaChainState>) -> Result<()> {
+        instructions::initialize_solana_chain_state::handler(ctx)
+    }
+
+    /// Record validator-attested Solana epoch and total stake
+    pub fn update_solana_epoch(
+        ctx: Context<UpdateSolanaEpoch>,
+        params: UpdateSolanaEpochParams,
+    ) -> Result<()> {
+        instructions::update_solana_epoch::handler(ctx, params)
+    }
+
+    /// Initialize the cross-asset conversion table (run once, permissionless)
+    pub fn initialize_conversion_table(ctx: Context<InitializeConversionTable>) -> Result<()> {
+        instructions::initialize_conversion_table::handler(ctx)
+    }
+
+    /// Set a cross-asset conversion rate (requires threshold signatures)
+    pub fn set_conversion_rate(
+        ctx: Context<SetConversionRate>,
+        params: SetConversionRateParams,
+    ) -> Result<()> {
+        instructions::set_conversion_rate::handler(ctx, params)
+    }
+
+    /// Convert an unprocessed verified burn into a verified burn of another asset
+    pub fn convert_verified_burn(
+        ctx: Context<ConvertVerifiedBurn>,
+        from_asset_id: u8,
+        to_asset_id: u8,
+        burn_nonce: u64,
+    ) -> Result<()> {
+        instructions::convert_verified_burn::handler(ctx, from_asset_id, to_asset_id, burn_nonce)
+    }
+
+    /// Flag a minted VerifiedBurnV3 processed (permissionless; mint programs call it by CPI)
+    pub fn mark_burn_processed(
+        ctx: Context<MarkBurnProcessed>,
+        asset_id: u8,
+        burn_nonce: u64,
+        user: Pubkey,
+    ) -> Result<()> {
+        instructions::mark_burn_processed::handler(ctx, asset_id, burn_nonce, user)
+    }
+
+    /// Initialize the notice board (run once, permissionless; empty)
+    pub fn initialize_notice(ctx: Context<InitializeNotice>) -> Result<()> {
+        instructions::initialize_notice::handler(ctx)
+    }
+
+    /// Post or clear the maintenance notice (requires threshold signatures)
+    pub fn set_notice(ctx: Context<SetNotice>, params: SetNoticeParams) -> Result<()> {
+        instructions::set_notice::handler(ctx, params)
+    }
+
+    /// Initialize an asset's beta allowlist (permissionless; disabled)
+    pub fn initialize_beta_allowlist(ctx: Context<InitializeBetaAllowlist>, asset_id: u8) -> Result<()> {
+        instructions::initialize_beta_allowlist::handler(ctx, asset_id)
+    }
+
+    /// Set or disable an asset's beta allowlist (requires threshold signatures)
+    pub fn set_beta_allowlist(
+        ctx: Context<SetBetaAllowlist>,
+        asset_id: u8,
+        params: SetBetaAllowlistParams,
+    ) -> Result<()> {
+        instructions::set_beta_allowlist::handler(ctx, asset_id, params)
+    }
+
+    /// Initialize the Wormhole VAA path config (permissionless; disabled)
+    pub fn initialize_wormhole_config(ctx: Context<InitializeWormholeConfig>) -> Result<()> {
+        instructions::initialize_wormhole_config::handler(ctx)
+    }
+
+    /// Set the trusted Wormhole core bridge and emitter (requires threshold signatures)
+    pub fn set_wormhole_config(ctx: Context<SetWormholeConfig>, params: SetWormholeConfigParams) -> Result<()> {
+        instructions::set_wormhole_config::handler(ctx, params)
+    }
+
+    /// Verify a burn from a posted Wormhole VAA (fallback to validator attestations)
+    pub fn submit_burn_vaa(ctx: Context<SubmitBurnVaa>, asset_id: u8, burn_nonce: u64) -> Result<()> {
+        instructions::submit_burn_vaa::handler(ctx, asset_id, burn_nonce)
+    }
+
     // ========================================================================
     // LEGACY INSTRUCTIONS - Kept for reference, not used in new architecture
     // ========================================================================
@@ -169,7 +825,7 @@ pub struct UpdateValidatorsParams {
 /// - Vote messages
 /// ...directly from the Ed25519 instructions in the transaction.
 ///
-/// Size: ~185 bytes (vs ~585 bytes with validator_votes included)
+/// Size: ~185 bytes plus the burn record (vs ~585 bytes with validator_votes included)
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
 pub struct BurnProof {
     // Burn identification
@@ -185,8 +841,15 @@ pub struct BurnProof {
     // Merkle proof (SECURITY CRITICAL)
     pub merkle_proof: Vec<[u8; 32]>,
 
+    // Burn record account data without discriminator (the Merkle leaf)
+    pub burn_record_data: Vec<u8>,
+
     // Validator count (read this many Ed25519 instructions)
     pub validator_count: u8,
+
+    // Transaction indices of those Ed25519 instructions, one per validator,
+    // strictly increasing; any other Ed25519 instruction is rejected
+    pub ed25519_indices: Vec<u8>,
 }
 
 /// Legacy ValidatorVote struct (kept for backward compatibility with other instructions)
@@ -0,0 +1,189 @@
+// Property tests for the stake threshold arithmetic
+//
+// Covers every stake-math helper of both verification paths over the full
+// u64 range, against exact u128 reference arithmetic:
+// - legacy (verification.rs): calculate_stake_threshold,
+//   ValidatorSet::consensus_threshold / verify_stake_threshold,
+//   ValidatorSetUpdateRecord::verify_consensus
+// - verification_new.rs: ValidatorConfig::verify_stake_threshold /
+//   calculate_total_stake
+//
+// Rounding direction is part of the contract:
+// - consensus_threshold rounds UP (66% is never under-estimated)
+// - basis point thresholds round DOWN (floor(total * bps / 10_000))
+// and the two paths must agree on the MIN_STAKE_BASIS_POINTS verdict.
+
+use anchor_lang::prelude::*;
+use proptest::prelude::*;
+use crate::config::MIN_STAKE_BASIS_POINTS;
+use crate::state::{ValidatorConfig, ValidatorInfo, ValidatorSet, ValidatorSetUpdateRecord};
+use crate::verification::calculate_stake_threshold;
+
+/// Largest total consensus_threshold accepts: 2 * total + 2 must fit a u64
+const CONSENSUS_MAX_TOTAL: u64 = (u64::MAX - 2) / 2;
+
+/// Full u64 range, with the overflow edges and small values drawn often
+fn any_stake() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        4 => any::<u64>(),
+        2 => 0u64..1_000,
+        1 => (CONSENSUS_MAX_TOTAL - 16)..=(CONSENSUS_MAX_TOTAL + 16),
+        1 => (u64::MAX - 16)..=u64::MAX,
+    ]
+}
+
+fn basis_points() -> impl Strategy<Value = u64> {
+    prop_oneof![
+        4 => 0u64..=10_000,
+        1 => Just(MIN_STAKE_BASIS_POINTS),
+        1 => Just(crate::config::BFT_THRESHOLD_BASIS_POINTS),
+        1 => Just(10_000u64),
+    ]
+}
+
+fn validator(stake: u64) -> ValidatorInfo {
+    ValidatorInfo { identity: Pubkey::new_unique(), stake }
+}
+
+fn validator_config(stakes: [u64; 7], total_tracked_stake: u64) -> ValidatorConfig {
+    let mut validators = stakes.map(validator);
+    validators.sort_by_key(|v| std::cmp::Reverse(v.stake));
+    ValidatorConfig {
+        current_epoch: 0,
+        last_update: 0,
+        primary_validators: [validators[0].clone(), validators[1].clone(), validators[2].clone()],
+        fallback_validators: [validators[3].clone(), validators[4].clone(), validators[5].clone(), validators[6].clone()],
+        total_tracked_stake,
+        bump: 0,
+    }
+}
+
+/// Exact floor(total * bps / 10_000)
+fn floor_bps(total: u64, bps: u64) -> u128 {
+    total as u128 * bps as u128 / 10_000
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(2_000))]
+
+    #[test]
+    fn consensus_threshold_is_exact_ceiling_of_two_thirds(total in any_stake()) {
+        match ValidatorSet::consensus_threshold(total) {
+            Ok(threshold) => {
+                prop_assert!(total <= CONSENSUS_MAX_TOTAL);
+                let (threshold, doubled) = (threshold as u128, 2 * total as u128);
+                // Rounds up: never below 2/3, and the smallest such value
+                prop_assert!(3 * threshold >= doubled);
+                prop_assert!(threshold == 0 || 3 * (threshold - 1) < doubled);
+                prop_assert!(threshold <= total as u128);
+            }
+            Err(_) => prop_assert!(total > CONSENSUS_MAX_TOTAL),
+        }
+    }
+
+    #[test]
+    fn consensus_threshold_is_monotonic(a in 0..=CONSENSUS_MAX_TOTAL, b in 0..=CONSENSUS_MAX_TOTAL) {
+        let (low, high) = (a.min(b), a.max(b));
+        prop_assert!(ValidatorSet::consensus_threshold(low).unwrap() <= ValidatorSet::consensus_threshold(high).unwrap());
+    }
+
+    #[test]
+    fn stake_threshold_is_exact_floor(total in any_stake(), bps in basis_points()) {
+        let threshold = calculate_stake_threshold(total, bps).unwrap();
+        // u128 widening: no overflow anywhere in the u64 range
+        prop_assert_eq!(threshold as u128, floor_bps(total, bps));
+        // Rounds down: at most total * bps, by less than one lamport
+        prop_assert!(threshold as u128 * 10_000 <= total as u128 * bps as u128);
+        prop_assert!((threshold as u128 + 1) * 10_000 > total as u128 * bps as u128);
+        prop_assert!(threshold <= total);
+    }
+
+    #[test]
+    fn stake_threshold_is_monotonic(a in any_stake(), b in any_stake(), bps in basis_points()) {
+        let (low, high) = (a.min(b), a.max(b));
+        prop_assert!(calculate_stake_threshold(low, bps).unwrap() <= calculate_stake_threshold(high, bps).unwrap());
+    }
+
+    #[test]
+    fn legacy_verdict_matches_two_thirds(stakes in prop::collection::vec(any_stake(), 1..8), picks in prop::collection::vec(any::<bool>(), 8), total in 0..=CONSENSUS_MAX_TOTAL) {
+        let set = ValidatorSet { validators: stakes.into_iter().map(validator).collect() };
+        let signers: Vec<Pubkey> = set.validators.iter().zip(&picks).filter(|(_, pick)| **pick).map(|(v, _)| v.identity).collect();
+        let signed: u128 = set.validators.iter().zip(&picks).filter(|(_, pick)| **pick).map(|(v, _)| v.stake as u128).sum();
+
+        match set.verify_stake_threshold(&signers, total) {
+            Ok((verified, sufficient)) => {
+                prop_assert_eq!(verified as u128, signed);
+                prop_assert_eq!(sufficient, 3 * signed >= 2 * total as u128);
+            }
+            // Only a verified stake beyond u64 may fail
+            Err(_) => prop_assert!(signed > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn update_record_consensus_matches_two_thirds(approver_stake in any_stake(), previous_total in any_stake()) {
+        let record = ValidatorSetUpdateRecord {
+            update_epoch: 0,
+            slot: 0,
+            timestamp: 0,
+            validator_set_hash: [0; 32],
+            total_stake: 0,
+            validator_count: 0,
+            approver_count: 0,
+            approver_stake,
+        };
+        match record.verify_consensus(previous_total) {
+            Ok(met) => prop_assert_eq!(met, 3 * approver_stake as u128 >= 2 * previous_total as u128),
+            Err(_) => prop_assert!(previous_total > CONSENSUS_MAX_TOTAL),
+        }
+    }
+
+    #[test]
+    fn new_path_verdict_matches_legacy_threshold(stakes in prop::array::uniform7(any_stake()), picks in prop::array::uniform7(any::<bool>()), total in any_stake()) {
+        let config = validator_config(stakes, total);
+        let all: Vec<&ValidatorInfo> = config.primary_validators.iter().chain(config.fallback_validators.iter()).collect();
+        let signers: Vec<Pubkey> = all.iter().zip(picks).filter(|(_, pick)| *pick).map(|(v, _)| v.identity).collect();
+        let signed: u128 = all.iter().zip(picks).filter(|(_, pick)| *pick).map(|(v, _)| v.stake as u128).sum();
+
+        match config.verify_stake_threshold(&signers, total) {
+            Ok((verified, sufficient)) => {
+                prop_assert_eq!(verified as u128, signed);
+                // Same rounding (floor) as the legacy path's calculate_stake_threshold
+                let legacy = calculate_stake_threshold(total, MIN_STAKE_BASIS_POINTS).unwrap();
+                prop_assert_eq!(sufficient, verified >= legacy);
+                prop_assert_eq!(sufficient, signed >= floor_bps(total, MIN_STAKE_BASIS_POINTS));
+            }
+            Err(_) => prop_assert!(signed > u64::MAX as u128),
+        }
+    }
+
+    #[test]
+    fn new_path_ignores_unknown_signers(stakes in prop::array::uniform7(any_stake()), total in any_stake(), strangers in 1usize..5) {
+        let config = validator_config(stakes, total);
+        let signers: Vec<Pubkey> = (0..strangers).map(|_| Pubkey::new_unique()).collect();
+        let (verified, sufficient) = config.verify_stake_threshold(&signers, total).unwrap();
+        prop_assert_eq!(verified, 0);
+        prop_assert_eq!(sufficient, floor_bps(total, MIN_STAKE_BASIS_POINTS) == 0);
+    }
+
+    #[test]
+    fn total_tracked_stake_saturates(stakes in prop::array::uniform7(any_stake())) {
+        let config = validator_config(stakes, 0);
+        let exact: u128 = stakes.iter().map(|s| *s as u128).sum();
+        prop_assert_eq!(config.calculate_total_stake() as u128, exact.min(u64::MAX as u128));
+    }
+}
+
+#[test]
+fn test_boundaries() {
+    assert_eq!(ValidatorSet::consensus_threshold(CONSENSUS_MAX_TOTAL).unwrap(), (2 * CONSENSUS_MAX_TOTAL).div_ceil(3));
+    assert!(ValidatorSet::consensus_threshold(CONSENSUS_MAX_TOTAL + 1).is_err());
+    assert!(ValidatorSet::consensus_threshold(u64::MAX).is_err());
+    assert_eq!(ValidatorSet::consensus_threshold(0).unwrap(), 0);
+    assert_eq!(ValidatorSet::consensus_threshold(1).unwrap(), 1);
+
+    assert_eq!(calculate_stake_threshold(u64::MAX, 10_000).unwrap(), u64::MAX);
+    assert_eq!(calculate_stake_threshold(u64::MAX, 0).unwrap(), 0);
+    assert_eq!(calculate_stake_threshold(9_999, 1).unwrap(), 0);
+    assert_eq!(calculate_stake_threshold(10_000, 1).unwrap(), 1);
+}
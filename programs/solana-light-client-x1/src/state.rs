@@ -17,6 +17,143 @@ pub struct X1ValidatorSet {
     pub threshold: u8,
 
     pub bump: u8,
+
+    /// Oldest accepted V3 attestation, in seconds since its signing time
+    /// (0 = DEFAULT_MAX_ATTESTATION_AGE)
+    ///
+    /// Appended field, like the ones below: accounts created before them
+    /// (LEGACY_SPACE bytes) must run migrate_validator_set first.
+    pub max_attestation_age: i64,
+
+    /// Voting weight of each validator, in `validators` order
+    /// (empty = unweighted set)
+    #[max_len(10)]
+    pub weights: Vec<u16>,
+
+    /// Share of the total weight the signers must also carry, in basis
+    /// points (0 = the count threshold alone)
+    pub threshold_bps: u16,
+
+    /// Attestations must come in ascending `validators` order, so a
+    /// duplicate is caught by comparing with the previous one
+    pub ordered_attestations: bool,
+}
+
+impl X1ValidatorSet {
+    /// 24 hours: ample for relayers, short for a leaked signature
+    pub const DEFAULT_MAX_ATTESTATION_AGE: i64 = 86_400;
+    /// Governance bounds of max_attestation_age (5 minutes to 7 days)
+    pub const MIN_MAX_ATTESTATION_AGE: i64 = 300;
+    pub const MAX_MAX_ATTESTATION_AGE: i64 = 604_800;
+    /// Tolerated drift of a validator's clock ahead of the X1 cluster clock
+    pub const MAX_ATTESTATION_CLOCK_SKEW: i64 = 120;
+
+    /// Denominator of threshold_bps
+    pub const BPS_DENOMINATOR: u64 = 10_000;
+
+    /// Account size before max_attestation_age and the later fields were
+    /// appended: discriminator, version, validators, threshold, bump
+    pub const LEGACY_SPACE: usize = 8 + 8 + (4 + 10 * 32) + 1 + 1;
+
+    /// Whether `weights` and `threshold_bps` can be set for `validators`:
+    /// both unset, or a non-zero weight per validator and a share of at
+    /// most 100%
+    pub fn weights_valid(validators: &[Pubkey], weights: &[u16], threshold_bps: u16) -> bool {
+        if threshold_bps == 0 {
+            return weights.is_empty();
+        }
+        threshold_bps as u64 <= Self::BPS_DENOMINATOR
+            && weights.len() == validators.len()
+            && weights.iter().all(|&weight| weight > 0)
+    }
+
+    /// Voting weight of `validator` (0 if not in the set)
+    pub fn weight_of(&self, validator: &Pubkey) -> u64 {
+        match self.validators.iter().position(|v| v == validator) {
+            Some(index) if self.threshold_bps != 0 => self.weights.get(index).copied().unwrap_or(0) as u64,
+            Some(_) => 1,
+            None => 0,
+        }
+    }
+
+    pub fn total_weight(&self) -> u64 {
+        self.validators.iter().map(|v| self.weight_of(v)).sum()
+    }
+
+    /// Whether distinct `signers` carry threshold_bps of the total weight
+    /// (always true for an unweighted set)
+    ///
+    /// Checked on top of the count threshold, so weights can only make a
+    /// quorum harder to reach, never easier than the BridgeConfig floor.
+    pub fn weight_threshold_met<'a>(&self, signers: impl IntoIterator<Item = &'a Pubkey>) -> bool {
+        if self.threshold_bps == 0 {
+            return true;
+        }
+        let signed: u64 = signers.into_iter().map(|signer| self.weight_of(signer)).sum();
+        signed as u128 * Self::BPS_DENOMINATOR as u128 >= self.total_weight() as u128 * self.threshold_bps as u128
+    }
+
+    /// Drop the validator at `index` together with its weight
+    pub fn remove_validator(&mut self, index: usize) {
+        self.validators.remove(index);
+        if index < self.weights.len() {
+            self.weights.remove(index);
+        }
+    }
+
+    /// Hash identifying a membership: sha256(validators || weights), in set order
+    pub fn members_hash(validators: &[Pubkey], weights: &[u16]) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hash;
+
+        let mut data = Vec::with_capacity(validators.len() * 34);
+        for validator in validators {
+            data.extend_from_slice(validator.as_ref());
+        }
+        for weight in weights {
+            data.extend_from_slice(&weight.to_le_bytes());
+        }
+        hash(&data).to_bytes()
+    }
+
+    /// max_attestation_age with 0 meaning the default
+    pub fn effective_max_attestation_age(&self) -> i64 {
+        if self.max_attestation_age == 0 {
+            Self::DEFAULT_MAX_ATTESTATION_AGE
+        } else {
+            self.max_attestation_age
+        }
+    }
+
+    /// Set position of an attestation's validator when ordered_attestations
+    /// is on, given the previous attestation's: the same position is a
+    /// duplicate, a lower one out of order
+    pub fn ordered_index(&self, validator: &Pubkey, previous: Option<usize>) -> std::result::Result<usize, AttestationCheckCode> {
+        let index = self.validators
+            .iter()
+            .position(|v| v == validator)
+            .ok_or(AttestationCheckCode::UnknownValidator)?;
+        match previous {
+            Some(previous) if index == previous => Err(AttestationCheckCode::DuplicateValidator),
+            Some(previous) if index < previous => Err(AttestationCheckCode::OutOfOrder),
+            _ => Ok(index),
+        }
+    }
+
+    /// Reject an attestation signed too long before `now`, or after it
+    /// (beyond the clock skew allowance)
+    pub fn check_attestation_age(&self, timestamp: i64, now: i64) -> Result<()> {
+        use crate::errors::LightClientError;
+
+        require!(
+            timestamp <= now.saturating_add(Self::MAX_ATTESTATION_CLOCK_SKEW),
+            LightClientError::AttestationFromFuture
+        );
+        require!(
+            now.saturating_sub(timestamp) <= self.effective_max_attestation_age(),
+            LightClientError::AttestationExpired
+        );
+        Ok(())
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
@@ -143,6 +280,58 @@ impl Asset {
     pub fn to_u8(self) -> u8 {
         self as u8
     }
+
+    /// Every registered asset
+    pub const ALL: [Asset; 2] = [Asset::XENCAT, Asset::DGN];
+
+    /// Mint program that owns this asset's processed_burn_v3 replay PDAs
+    pub fn mint_program_id(self) -> Pubkey {
+        match self {
+            Asset::XENCAT => crate::XENCAT_MINT_PROGRAM_ID,
+            Asset::DGN => crate::DGN_MINT_PROGRAM_ID,
+        }
+    }
+
+    /// Ticker wallets display (matches the Metaplex metadata symbol)
+    pub fn symbol(self) -> &'static str {
+        match self {
+            Asset::XENCAT => "XENCAT",
+            Asset::DGN => "DGN",
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Asset::XENCAT => "XENCAT",
+            Asset::DGN => "Degen",
+        }
+    }
+
+    /// Decimals of both the Solana token and the X1 mint (mint initialize enforces 6)
+    pub fn decimals(self) -> u8 {
+        match self {
+            Asset::XENCAT | Asset::DGN => 6,
+        }
+    }
+
+    /// Off-chain metadata JSON (name, symbol, logo image) set as the X1 mint's Metaplex `uri`
+    pub fn metadata_uri(self) -> &'static str {
+        match self {
+            Asset::XENCAT => "https://raw.githubusercontent.com/Commoneffort/xencat-light-client/main/metadata/xencat-metadata.json",
+            Asset::DGN => "https://ipfs.io/ipfs/bafkreig7xnvrx3dcx2xgqsb5422gcsbgb6srwxoqeawevynvnelibmwtgq",
+        }
+    }
+
+    pub fn metadata(self) -> AssetMetadata {
+        AssetMetadata {
+            asset_id: self.to_u8(),
+            symbol: self.symbol().to_string(),
+            name: self.name().to_string(),
+            decimals: self.decimals(),
+            logo_uri_hash: anchor_lang::solana_program::hash::hash(self.metadata_uri().as_bytes()).to_bytes(),
+            mint_program: self.mint_program_id(),
+        }
+    }
 }
 
 /// Asset-aware verified burn (V3)
@@ -185,11 +374,20 @@ pub struct VerifiedBurnV3 {
 
     /// PDA bump
     pub bump: u8,
+
+    /// Solana slot of the burn (attested by validators, 0 = unknown)
+    pub solana_burn_slot: u64,
+
+    /// X1 slot at which the attestations were verified
+    pub verified_slot: u64,
 }
 
 impl VerifiedBurnV3 {
-    /// Account size: 8 + 1 + 8 + 32 + 8 + 8 + 1 + 1 = 67 bytes (with discriminator)
-    pub const LEN: usize = 8 + 1 + 8 + 32 + 8 + 8 + 1 + 1;
+    /// Account size: 8 + 1 + 8 + 32 + 8 + 8 + 1 + 1 + 8 + 8 = 83 bytes (with discriminator)
+    ///
+    /// Grew from 67 bytes when timing fields were added: mint pending
+    /// 67-byte V3 burns before upgrading, they no longer deserialize.
+    pub const LEN: usize = 8 + 1 + 8 + 32 + 8 + 8 + 1 + 1 + 8 + 8;
 }
 
 /// Asset-aware burn attestation data (V3)
@@ -208,457 +406,3968 @@ pub struct BurnAttestationDataV3 {
     /// Validator set version these attestations are for
     pub validator_set_version: u64,
 
-    /// Signatures from X1 validators (minimum threshold required)
-    pub attestations: Vec<ValidatorAttestation>,
-}
+    /// Solana slot and block time (unix seconds) of the burn, as seen by validators
+    pub solana_burn_slot: u64,
+    pub solana_burn_timestamp: i64,
 
-/// Light client configuration and metadata
-///
-/// This account stores the core configuration for the light client including
-/// authority, fee settings, and references to the current validator set.
-/// Kept separate from ValidatorSet for easier upgrades and smaller account size.
-///
-/// Security: Once immutable, only validator set updates signed by 66%+ validators can modify state
-#[account]
-#[derive(InitSpace)]
-pub struct LightClientState {
-    /// Authority that can modify fee settings (NOT validator set)
-    /// In production, this should be transferred to a DAO or renounced
-    pub authority: Pubkey,
+    /// Confirmation depth the burner chose in its BurnRecord (0 = default)
+    pub confirmation_depth: u16,
 
-    /// Account that receives verification fees
-    pub fee_receiver: Pubkey,
+    /// Slot validators waited for before signing: solana_burn_slot plus
+    /// the effective confirmation depth
+    pub solana_confirmed_slot: u64,
 
-    /// Fee charged per proof verification (in lamports)
-    /// Default: 1_000_000 (0.001 XNT with 6 decimals)
-    pub verification_fee: u64,
+    /// Signatures from X1 validators (minimum threshold required)
+    ///
+    /// Each signature also covers that validator's `timestamp` (unix
+    /// seconds), which feeds ValidatorLatencyStats.
+    pub attestations: Vec<ValidatorAttestation>,
+}
 
-    /// Total active stake in current validator set (for 66% threshold calculation)
-    pub total_stake: u64,
+/// Shallowest confirmation depth validators ever attest at (Solana slots),
+/// also what a BurnRecord's depth of 0 means
+pub const MIN_CONFIRMATION_DEPTH: u16 = 32;
 
-    /// Number of validators in current set
-    pub validator_count: u16,
+/// Deepest confirmation depth a burner may ask for (~10 minutes)
+pub const MAX_CONFIRMATION_DEPTH: u16 = 1500;
 
-    /// Slot when validator set was last updated
-    pub last_update_slot: u64,
+impl BurnAttestationDataV3 {
+    /// Slots the burn must be buried under before it is attested
+    pub fn effective_confirmation_depth(&self) -> u16 {
+        self.confirmation_depth.max(MIN_CONFIRMATION_DEPTH)
+    }
 
-    /// Counter for validator set updates (for history tracking)
-    pub update_epoch: u64,
+    /// Require a depth the burn program accepts, and a signed confirmed
+    /// slot at least that deep below the burn
+    pub fn check_confirmation_depth(&self) -> Result<()> {
+        use crate::errors::LightClientError;
 
-    /// Current Solana epoch for validator rotation
-    pub current_epoch: u64,
+        require!(
+            self.confirmation_depth <= MAX_CONFIRMATION_DEPTH,
+            LightClientError::InvalidConfirmationDepth
+        );
+        let required_slot = self.solana_burn_slot
+            .checked_add(self.effective_confirmation_depth() as u64)
+            .ok_or(LightClientError::InvalidConfirmationDepth)?;
+        require!(
+            self.solana_confirmed_slot >= required_slot,
+            LightClientError::ConfirmationDepthNotReached
+        );
+        Ok(())
+    }
+}
 
-    /// Last time validator config was rotated
-    pub last_rotation: i64,
+/// Outcome of one check of prevalidate_attestation (Borsh: one byte)
+///
+/// Variants are only ever appended; a client must treat unknown codes as
+/// a rejection.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AttestationCheckCode {
+    /// Would pass submit_burn_attestation_v3's checks
+    Valid,
+    /// ATTEST_V3 is switched off (FeatureGate), or the bridge is paused
+    FeatureDisabled,
+    /// Bundle's asset_id / burn_nonce differ from the instruction's
+    ParameterMismatch,
+    /// asset_id is not a registered, active asset
+    UnknownAsset,
+    /// Signed for another validator set version than the current one
+    VersionMismatch,
+    /// The burn already has a VerifiedBurnV3 (the submit's init would fail)
+    AlreadyVerified,
+    /// Second attestation from the same validator
+    DuplicateValidator,
+    /// Signer is not in the current validator set
+    UnknownValidator,
+    /// Mock, malleable or weak-key signature
+    BadSignatureEncoding,
+    /// Fewer valid attestations than the threshold, or too little of
+    /// the weighted threshold behind them
+    BelowThreshold,
+    /// Confirmation depth out of bounds or not reached by the signed slot
+    ConfirmationDepth,
+    /// Signed longer ago than max_attestation_age, or in the future
+    AttestationAge,
+    /// Not in ascending validator set order while ordered_attestations is on
+    OutOfOrder,
+}
 
-    /// PDA bump seed
-    pub bump: u8,
+/// Result of prevalidate_attestation (returned as instruction return data)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, Eq)]
+pub struct AttestationCheck {
+    /// First failing check, or Valid
+    pub code: AttestationCheckCode,
+    /// One code per attestation, in bundle order
+    pub attestation_codes: Vec<AttestationCheckCode>,
+    pub valid_count: u8,
+    pub threshold: u8,
+    /// Current validator set version (what the bundle must be signed for)
+    pub validator_set_version: u64,
 }
 
-/// Validator configuration for 3 primary + 4 fallback architecture
+// ============================================================================
+// BRIDGE CONFIGURATION (VALIDATOR-GOVERNED)
+// ============================================================================
+
+/// Bridge-wide configuration governed by validator threshold signatures
 ///
-/// This implements a resilient validator system that:
-/// - Uses top 3 validators by stake as primary (fastest response)
-/// - Maintains 4 fallback validators (if primary unresponsive)
-/// - Rotates every Solana epoch (~2 days) for security
-/// - Autonomous rotation (anyone can update with valid proof)
+/// TRUSTLESS DESIGN: No admin - every change requires ≥threshold approvals
+/// from the current X1ValidatorSet.
 ///
-/// Security Model:
-/// - Initial validator set trusted at deployment
-/// - Rotation requires proof validators are top 7 by stake
-/// - No single point of failure (7 validator slots total)
+/// Seeds: ["bridge_config"]
 #[account]
-pub struct ValidatorConfig {
-    /// Current Solana epoch number
-    pub current_epoch: u64,
+#[derive(InitSpace)]
+pub struct BridgeConfig {
+    /// Slot at which V2 instructions stop accepting new submissions
+    /// 0 = no cutoff scheduled (V2 still active)
+    pub v2_cutoff_slot: u64,
 
-    /// Last update timestamp
-    pub last_update: i64,
+    /// Governance nonce (incremented on every config change)
+    /// Bound into governance messages so approvals cannot be replayed
+    pub nonce: u64,
 
-    /// Primary validators (used first for proof generation)
-    /// These are the top 3 validators by stake
-    pub primary_validators: [ValidatorInfo; 3],
+    /// Floor enforced by update_validator_set: minimum number of validators
+    pub min_validator_count: u8,
 
-    /// Fallback validators (used if primary unresponsive)
-    /// These are validators 4-7 by stake
-    pub fallback_validators: [ValidatorInfo; 4],
+    /// Floor enforced by update_validator_set: threshold must be at least
+    /// ⌈n * min_threshold_numerator / min_threshold_denominator⌉ (default 2/3)
+    pub min_threshold_numerator: u8,
+    pub min_threshold_denominator: u8,
 
-    /// Total tracked stake (sum of all 7 validators)
-    /// Used for percentage calculations
-    pub total_tracked_stake: u64,
+    /// BFT mode: threshold must additionally be > 2/3 of the validators
+    pub bft_mode: bool,
 
-    /// Bump seed for PDA
     pub bump: u8,
 }
 
-impl ValidatorConfig {
-    /// Space required for ValidatorConfig account
-    /// 8 (discriminator) + 8 (epoch) + 8 (timestamp) +
-    /// (3 * 40) (primary) + (4 * 40) (fallback) + 8 (stake) + 1 (bump)
-    pub const LEN: usize = 8 + 8 + 8 + (3 * 40) + (4 * 40) + 8 + 1;
+impl BridgeConfig {
+    /// Default floor: at least 4 validators, threshold ≥ ⌈2n/3⌉
+    pub const DEFAULT_MIN_VALIDATOR_COUNT: u8 = 4;
+    pub const DEFAULT_MIN_THRESHOLD_NUMERATOR: u8 = 2;
+    pub const DEFAULT_MIN_THRESHOLD_DENOMINATOR: u8 = 3;
 
-    /// Get all validators (primary + fallback)
-    pub fn all_validators(&self) -> Vec<ValidatorInfo> {
-        let mut all = Vec::with_capacity(7);
-        all.extend_from_slice(&self.primary_validators);
-        all.extend_from_slice(&self.fallback_validators);
-        all
+    /// Whether V2 submissions are rejected at `slot`
+    pub fn is_v2_deprecated(&self, slot: u64) -> bool {
+        self.v2_cutoff_slot != 0 && slot >= self.v2_cutoff_slot
     }
 
-    /// Check if a validator is in the trusted set (primary or fallback)
-    pub fn contains_validator(&self, identity: &Pubkey) -> bool {
-        self.primary_validators.iter().any(|v| v.identity == *identity)
-            || self.fallback_validators.iter().any(|v| v.identity == *identity)
+    /// Smallest threshold allowed for a set of `validator_count` validators
+    pub fn min_threshold(&self, validator_count: usize) -> usize {
+        let numerator = validator_count * self.min_threshold_numerator as usize;
+        let denominator = self.min_threshold_denominator.max(1) as usize;
+        numerator.div_ceil(denominator)
     }
 
-    /// Get validator info by identity
-    pub fn find_validator(&self, identity: &Pubkey) -> Option<&ValidatorInfo> {
-        self.primary_validators.iter()
-            .chain(self.fallback_validators.iter())
-            .find(|v| v.identity == *identity)
+    /// Whether `threshold` of `validator_count` satisfies the safety policy
+    /// (configured floor, plus strict > 2/3 in BFT mode)
+    pub fn threshold_allowed(&self, validator_count: usize, threshold: u8) -> bool {
+        let threshold = threshold as usize;
+        if threshold < self.min_threshold(validator_count) {
+            return false;
+        }
+        !self.bft_mode || threshold * 3 > validator_count * 2
     }
+}
 
-    /// Calculate total stake of all tracked validators
-    pub fn calculate_total_stake(&self) -> u64 {
-        let primary_stake: u64 = self.primary_validators.iter().map(|v| v.stake).sum();
-        let fallback_stake: u64 = self.fallback_validators.iter().map(|v| v.stake).sum();
-        primary_stake.saturating_add(fallback_stake)
+/// Threshold to use after a validator resigns from an `old_threshold` set,
+/// leaving `new_count` validators
+///
+/// Keeps the old threshold when possible, otherwise the smallest value the
+/// policy allows. None if no threshold ≤ new_count satisfies the policy
+/// (a full update_validator_set ceremony is then required).
+pub fn resignation_threshold(config: &BridgeConfig, old_threshold: u8, new_count: usize) -> Option<u8> {
+    if new_count < config.min_validator_count as usize {
+        return None;
     }
-
-    /// Verify minimum stake threshold is met by provided validators
-    /// For 3 validators, we require at least their combined stake
-    pub fn verify_stake_threshold(&self, validator_identities: &[Pubkey], total_stake: u64) -> Result<(u64, bool)> {
-        let mut verified_stake: u64 = 0;
-
-        for identity in validator_identities {
-            if let Some(validator) = self.find_validator(identity) {
-                verified_stake = verified_stake
-                    .checked_add(validator.stake)
-                    .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?;
-            }
+    let mut threshold = old_threshold.min(new_count as u8);
+    while !config.threshold_allowed(new_count, threshold) {
+        threshold = threshold.checked_add(1)?;
+        if threshold as usize > new_count {
+            return None;
         }
-
-        // For 3-validator setup: Use minimum stake threshold from config
-        let threshold = (total_stake as u128 * crate::config::MIN_STAKE_BASIS_POINTS as u128 / 10000) as u64;
-        Ok((verified_stake, verified_stake >= threshold))
     }
+    (threshold > 0).then_some(threshold)
 }
 
-/// Validator set storage - optimized for space efficiency
+/// Whether moving from `old_threshold`-of-`old_count` to
+/// `new_threshold`-of-`new_count` weakens safety: fewer signatures needed,
+/// a smaller set, or a lower signing fraction
+pub fn weakens_safety(old_count: usize, old_threshold: u8, new_count: usize, new_threshold: u8) -> bool {
+    new_threshold < old_threshold
+        || new_count < old_count
+        // new_threshold / new_count < old_threshold / old_count
+        || (new_threshold as usize) * old_count < (old_threshold as usize) * new_count
+}
+
+/// Pending voluntary resignation of a validator
 ///
-/// Stores the current active validator set with their stakes.
-/// This is a separate account from LightClientState to allow for:
-/// 1. Larger storage (can realloc if needed)
-/// 2. Cheaper reads when only validator data is needed
-/// 3. Easier validator set rotation
+/// Created by the validator itself; after the cooldown anyone can finalize
+/// it, removing the validator from the set. Fees already paid to the
+/// validator are untouched.
 ///
-/// Space optimization:
-/// - Base: 8 (discriminator) + 4 (vec length) = 12 bytes
-/// - Per validator: 32 (pubkey) + 8 (stake) = 40 bytes
-/// - 300 validators: 12 + (300 * 40) = 12,012 bytes (~12 KB)
-/// - 500 validators: 12 + (500 * 40) = 20,012 bytes (~20 KB)
+/// Seeds: ["validator_resignation", validator]
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorResignation {
+    pub validator: Pubkey,
+    pub requested_slot: u64,
+    /// Slot from which finalize_validator_resignation is allowed
+    pub effective_slot: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// VALIDATOR SET DISASTER RECOVERY
+// ============================================================================
+
+/// Last known-good copy of the X1ValidatorSet
 ///
-/// Note: Solana mainnet has ~1,500 validators but we sample 20 for proofs.
-/// We store top N by stake for verification lookups.
+/// Kept in sync permissionlessly (sync_validator_set_checkpoint) while the
+/// live set is healthy. If a bad upgrade corrupts the validator set PDA, the
+/// validators in this checkpoint are the ones who can approve a restore.
+///
+/// Seeds: ["validator_set_checkpoint"]
 #[account]
-pub struct ValidatorSet {
-    /// Active validators with their stake amounts
-    /// Stored in descending order by stake for efficient sampling
-    pub validators: Vec<ValidatorInfo>,
+#[derive(InitSpace)]
+pub struct ValidatorSetCheckpoint {
+    pub version: u64,
+    #[max_len(10)]
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+    /// X1 slot of the last sync
+    pub checkpoint_slot: u64,
+    pub bump: u8,
 }
 
-impl ValidatorSet {
-    /// Maximum supported validators (conservative estimate)
-    /// 500 validators = ~20 KB, well under Solana's 10 MB account limit
-    pub const MAX_VALIDATORS: usize = 500;
+/// Threshold-approved validator set restore, waiting out the timelock
+///
+/// Seeds: ["validator_set_restore"]
+#[account]
+#[derive(InitSpace)]
+pub struct PendingValidatorSetRestore {
+    /// Snapshot being restored
+    pub snapshot_version: u64,
+    #[max_len(10)]
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Paid the rent; refunded on execute/cancel
+    pub proposer: Pubkey,
+    pub scheduled_slot: u64,
+    /// Slot from which execute_validator_set_restore is allowed
+    pub executable_slot: u64,
+    pub bump: u8,
+}
 
-    /// Initial size for validator set account
-    /// Sized for 150 validators (~6 KB) with room to realloc
-    pub const INITIAL_SIZE: usize = 8 + 4 + (40 * 150);
+/// Version given to a restored validator set
+///
+/// Always above both the snapshot and the checkpoint, so signatures made
+/// for any earlier version (including the corrupted one) stay invalid.
+pub fn restored_version(snapshot_version: u64, checkpoint_version: u64) -> Option<u64> {
+    snapshot_version.max(checkpoint_version).checked_add(1)
+}
 
-    /// Maximum size for validator set account
-    /// Sized for 500 validators (~20 KB)
-    pub const MAX_SIZE: usize = 8 + 4 + (40 * Self::MAX_VALIDATORS);
+// ============================================================================
+// VALIDATOR SET UPDATE TIMELOCK
+// ============================================================================
 
-    /// Find a validator by identity pubkey
-    /// Returns None if validator not found in set
-    pub fn find_validator(&self, identity: &Pubkey) -> Option<&ValidatorInfo> {
-        self.validators.iter().find(|v| v.identity == *identity)
+/// Delay between proposing and executing a validator set update
+///
+/// Once this account exists, update_validator_set is refused: a new set
+/// goes through propose_validator_set_update and, after the delay,
+/// execute_validator_set_update. Users and integrators get that long to
+/// react to a quorum replacing the set. The account is never closed, so
+/// instant updates cannot be switched back on.
+///
+/// Seeds: ["validator_set_timelock"]
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorSetTimelock {
+    pub delay_seconds: i64,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+impl ValidatorSetTimelock {
+    /// Delay of a new timelock (48 hours)
+    pub const DEFAULT_DELAY_SECONDS: i64 = 172_800;
+    /// Governance bounds of the delay (1 to 30 days)
+    pub const MIN_DELAY_SECONDS: i64 = 86_400;
+    pub const MAX_DELAY_SECONDS: i64 = 2_592_000;
+
+    pub fn is_valid_delay(delay_seconds: i64) -> bool {
+        (Self::MIN_DELAY_SECONDS..=Self::MAX_DELAY_SECONDS).contains(&delay_seconds)
     }
 
-    /// Calculate 66% stake threshold (rounds up for safety)
-    /// This is the minimum stake required for consensus
-    pub fn consensus_threshold(total_stake: u64) -> Result<u64> {
-        // (total_stake * 2) / 3, but with overflow protection
-        let doubled = total_stake
-            .checked_mul(2)
-            .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?;
+    /// Whether instant validator set updates are refused (the timelock exists)
+    ///
+    /// `info` must be the ["validator_set_timelock"] PDA (callers pin it with seeds).
+    pub fn is_active(info: &AccountInfo) -> Result<bool> {
+        if info.data_is_empty() {
+            return Ok(false);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidValidatorSetTimelock);
+        Ok(true)
+    }
+}
 
-        // Round up: (doubled + 2) / 3 ensures we never under-estimate
+/// Validator set update approved by the current set, waiting out the timelock
+///
+/// Seeds: ["validator_set_update"]
+#[account]
+#[derive(InitSpace)]
+pub struct PendingValidatorSetUpdate {
+    /// Version of the set that approved it; only that version can execute it
+    pub base_version: u64,
+    #[max_len(10)]
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+    #[max_len(10)]
+    pub weights: Vec<u16>,
+    pub threshold_bps: u16,
+    /// Approver signatures submitted with the proposal
+    pub approvals: u8,
+    /// Paid the rent; refunded on execute/cancel
+    pub proposer: Pubkey,
+    pub proposed_at: i64,
+    /// Unix time from which execute_validator_set_update is allowed
+    pub executable_at: i64,
+    /// Current validators whose signatures approved it
+    #[max_len(10)]
+    pub approvers: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl PendingValidatorSetUpdate {
+    /// Reject execution before the delay has elapsed or after the set it
+    /// was approved by has changed (a resignation or restore bumps the
+    /// version; the update must then be proposed again)
+    pub fn check_executable(&self, current_version: u64, now: i64) -> Result<()> {
+        use crate::errors::LightClientError;
+
+        require!(current_version == self.base_version, LightClientError::StaleValidatorSetUpdate);
+        require!(now >= self.executable_at, LightClientError::ValidatorSetUpdateTimelockActive);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// X1 VALIDATOR SET HISTORY (AUDIT TRAIL)
+// ============================================================================
+
+/// One X1ValidatorSet transition, recorded when it is applied
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq, InitSpace)]
+pub struct X1ValidatorSetRecord {
+    /// Version the transition produced
+    pub version: u64,
+    /// X1ValidatorSet::members_hash before and after
+    pub old_validators_hash: [u8; 32],
+    pub new_validators_hash: [u8; 32],
+    pub threshold: u8,
+    pub threshold_bps: u16,
+    /// Validators of the previous set whose signatures approved it
+    #[max_len(10)]
+    pub approvers: Vec<Pubkey>,
+    pub timestamp: i64,
+    pub slot: u64,
+}
+
+/// Ring buffer of the last X1ValidatorSet transitions
+///
+/// Written by update_validator_set, execute_validator_set_update and
+/// finalize_validator_resignation once initialized (updates before that
+/// are not recorded). Restores are not recorded: the set they replace may
+/// not deserialize.
+///
+/// Seeds: ["x1_validator_set_history"]
+#[account]
+#[derive(InitSpace)]
+pub struct X1ValidatorSetHistory {
+    #[max_len(16)]
+    pub records: Vec<X1ValidatorSetRecord>,
+    /// Slot of `records` the next transition overwrites once it is full
+    pub next_index: u8,
+    /// Transitions recorded since initialization (can exceed 16)
+    pub total_updates: u64,
+    pub bump: u8,
+}
+
+impl X1ValidatorSetHistory {
+    /// Transitions kept
+    pub const HISTORY_SIZE: usize = 16;
+
+    pub fn record(&mut self, record: X1ValidatorSetRecord) {
+        let index = self.next_index as usize;
+        if self.records.len() < Self::HISTORY_SIZE {
+            self.records.push(record);
+        } else {
+            self.records[index] = record;
+        }
+        self.next_index = ((index + 1) % Self::HISTORY_SIZE) as u8;
+        self.total_updates = self.total_updates.saturating_add(1);
+    }
+
+    /// The transition that produced `version`, if still kept
+    pub fn get_by_version(&self, version: u64) -> Option<&X1ValidatorSetRecord> {
+        self.records.iter().find(|record| record.version == version)
+    }
+
+    /// Append `record` if the history exists
+    ///
+    /// `info` must be the ["x1_validator_set_history"] PDA (callers pin it
+    /// with seeds); not initialized = nothing to record.
+    pub fn append(info: &AccountInfo, record: X1ValidatorSetRecord) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidValidatorSetHistory);
+        let mut history = Self::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        history.record(record);
+        history.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        Ok(())
+    }
+}
+
+// ============================================================================
+// ATTESTATION LATENCY SLA TRACKING
+// ============================================================================
+
+/// Upper bounds (seconds, inclusive) of the latency histogram buckets;
+/// the last bucket is open-ended
+pub const LATENCY_BUCKET_BOUNDS: [u64; 7] = [15, 30, 60, 120, 300, 600, 1800];
+
+/// Latency histogram for one validator (burn time -> validator signing time)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, Default, PartialEq, Eq, InitSpace)]
+pub struct ValidatorLatency {
+    pub validator: Pubkey,
+    pub samples: u64,
+    pub total_seconds: u64,
+    pub max_seconds: u64,
+    pub buckets: [u32; 8],
+}
+
+impl ValidatorLatency {
+    pub fn record(&mut self, latency_seconds: u64) {
+        let bucket = LATENCY_BUCKET_BOUNDS
+            .iter()
+            .position(|bound| latency_seconds <= *bound)
+            .unwrap_or(LATENCY_BUCKET_BOUNDS.len());
+        self.buckets[bucket] = self.buckets[bucket].saturating_add(1);
+        self.samples = self.samples.saturating_add(1);
+        self.total_seconds = self.total_seconds.saturating_add(latency_seconds);
+        self.max_seconds = self.max_seconds.max(latency_seconds);
+    }
+
+    /// Upper bound (seconds) of the bucket holding the `percentile_bps`
+    /// sample (e.g. 9500 = p95); u64::MAX for the open bucket, None if empty
+    pub fn percentile(&self, percentile_bps: u16) -> Option<u64> {
+        if self.samples == 0 {
+            return None;
+        }
+        let rank = (self.samples as u128 * percentile_bps as u128).div_ceil(10_000).max(1) as u64;
+        let mut seen = 0u64;
+        for (i, count) in self.buckets.iter().enumerate() {
+            seen += *count as u64;
+            if seen >= rank {
+                return Some(LATENCY_BUCKET_BOUNDS.get(i).copied().unwrap_or(u64::MAX));
+            }
+        }
+        Some(u64::MAX)
+    }
+}
+
+/// Per-validator attestation latency, updated by submit_burn_attestation_v3
+///
+/// Governance reads the percentiles to enforce responsiveness SLAs
+/// (e.g. replace validators whose p95 exceeds the agreed bound).
+///
+/// Seeds: ["validator_latency_stats"]
+#[account]
+#[derive(InitSpace)]
+pub struct ValidatorLatencyStats {
+    #[max_len(10)] // Same bound as X1ValidatorSet.validators
+    pub entries: Vec<ValidatorLatency>,
+    pub bump: u8,
+}
+
+impl ValidatorLatencyStats {
+    /// The stats, or None while not initialized (initialize_latency_stats)
+    ///
+    /// `info` must be the ["validator_latency_stats"] PDA (callers pin it
+    /// with seeds).
+    pub fn load(info: &AccountInfo) -> Result<Option<ValidatorLatencyStats>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidLatencyStats);
+        Ok(Some(ValidatorLatencyStats::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Record a sample, dropping entries of validators no longer in `current_set`
+    pub fn record(&mut self, validator: Pubkey, latency_seconds: u64, current_set: &[Pubkey]) {
+        if let Some(entry) = self.entries.iter_mut().find(|e| e.validator == validator) {
+            entry.record(latency_seconds);
+            return;
+        }
+        self.entries.retain(|e| current_set.contains(&e.validator));
+        if self.entries.len() < 10 {
+            let mut entry = ValidatorLatency { validator, ..Default::default() };
+            entry.record(latency_seconds);
+            self.entries.push(entry);
+        }
+    }
+}
+
+/// Fee weight (bps of the most active validator's) of a validator with no
+/// recorded attestations, so new members still earn while they catch up
+pub const MIN_FEE_WEIGHT_BPS: u64 = 2_500;
+
+impl ValidatorLatencyStats {
+    /// Attestations recorded for `validator` (0 if it has no entry)
+    pub fn samples_of(&self, validator: &Pubkey) -> u64 {
+        self.entries
+            .iter()
+            .find(|e| e.validator == *validator)
+            .map_or(0, |e| e.samples)
+    }
+
+    /// Split `total_fee` across `validators` by attestation participation
+    ///
+    /// Weight = MIN_FEE_WEIGHT_BPS plus the remaining bps scaled by the
+    /// validator's samples relative to the most active member. Rounding dust
+    /// goes to the first member with the highest weight, so the shares always
+    /// sum to `total_fee`. Equal split if no member has samples yet.
+    pub fn fee_shares(&self, total_fee: u64, validators: &[Pubkey]) -> Vec<u64> {
+        if validators.is_empty() {
+            return Vec::new();
+        }
+        let samples: Vec<u64> = validators.iter().map(|v| self.samples_of(v)).collect();
+        let max_samples = samples.iter().copied().max().unwrap_or(0);
+        let weights: Vec<u128> = samples
+            .iter()
+            .map(|s| {
+                if max_samples == 0 {
+                    1
+                } else {
+                    MIN_FEE_WEIGHT_BPS as u128
+                        + (10_000 - MIN_FEE_WEIGHT_BPS) as u128 * *s as u128 / max_samples as u128
+                }
+            })
+            .collect();
+        let total_weight: u128 = weights.iter().sum();
+
+        let mut shares: Vec<u64> = weights
+            .iter()
+            .map(|w| (total_fee as u128 * w / total_weight) as u64)
+            .collect();
+        let dust = total_fee - shares.iter().sum::<u64>();
+        let top = weights
+            .iter()
+            .enumerate()
+            .fold(0, |best, (i, w)| if *w > weights[best] { i } else { best });
+        shares[top] += dust;
+        shares
+    }
+
+    /// fee_shares of the stats behind `info` (see load); an equal split
+    /// while they are not initialized
+    pub fn fee_shares_of(info: &AccountInfo, total_fee: u64, validators: &[Pubkey]) -> Result<Vec<u64>> {
+        let stats = Self::load(info)?.unwrap_or(ValidatorLatencyStats { entries: Vec::new(), bump: 0 });
+        Ok(stats.fee_shares(total_fee, validators))
+    }
+}
+
+// ============================================================================
+// CROSS-ASSET CONVERSION TABLE (VALIDATOR-GOVERNED)
+// ============================================================================
+
+/// Maximum number of (from, to) conversion pairs
+pub const MAX_CONVERSION_RATES: usize = 8;
+
+/// One directed conversion pair: `amount_out = amount_in * numerator / denominator`
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ConversionRate {
+    pub from_asset: u8,
+    pub to_asset: u8,
+    pub numerator: u64,
+    pub denominator: u64,
+    pub enabled: bool,
+}
+
+impl ConversionRate {
+    /// Converted amount (rounded down), None on overflow or zero result
+    pub fn convert(&self, amount: u64) -> Option<u64> {
+        if self.denominator == 0 {
+            return None;
+        }
+        let out = (amount as u128)
+            .checked_mul(self.numerator as u128)?
+            .checked_div(self.denominator as u128)?;
+        u64::try_from(out).ok().filter(|v| *v > 0)
+    }
+}
+
+/// Governance-configured conversion rates for verified burns
+///
+/// Allows a verified burn of one asset to be re-pointed at another asset's
+/// mint (e.g. burn XENCAT on Solana, receive DGN on X1) without a DEX hop.
+///
+/// Seeds: ["conversion_table"]
+#[account]
+#[derive(InitSpace)]
+pub struct ConversionTable {
+    #[max_len(8)] // MAX_CONVERSION_RATES
+    pub rates: Vec<ConversionRate>,
+
+    pub bump: u8,
+}
+
+impl ConversionTable {
+    /// Enabled rate for `from -> to`, if any
+    pub fn rate(&self, from_asset: u8, to_asset: u8) -> Option<&ConversionRate> {
+        self.rates
+            .iter()
+            .find(|r| r.from_asset == from_asset && r.to_asset == to_asset && r.enabled)
+    }
+
+    /// Insert or replace the rate for `rate.from_asset -> rate.to_asset`
+    /// Returns false when the table is full
+    pub fn upsert(&mut self, rate: ConversionRate) -> bool {
+        if let Some(existing) = self
+            .rates
+            .iter_mut()
+            .find(|r| r.from_asset == rate.from_asset && r.to_asset == rate.to_asset)
+        {
+            *existing = rate;
+            return true;
+        }
+        if self.rates.len() >= MAX_CONVERSION_RATES {
+            return false;
+        }
+        self.rates.push(rate);
+        true
+    }
+}
+
+// ============================================================================
+// BRIDGE INFO (CAPABILITY DISCOVERY)
+// ============================================================================
+
+/// Capability bits reported in `BridgeCapabilities::features`
+///
+/// Bits are only ever added; a client must ignore bits it does not know.
+pub mod features {
+    /// submit_burn_attestation (V2) still accepts submissions
+    pub const V2_SUBMISSIONS: u64 = 1 << 0;
+    /// V3 attestations also sign the Solana burn slot/time and signing time
+    pub const TIMED_ATTESTATIONS: u64 = 1 << 1;
+    /// Per-validator attestation latency (ValidatorLatencyStats)
+    pub const LATENCY_STATS: u64 = 1 << 2;
+    /// Validator resignation with cooldown
+    pub const VALIDATOR_RESIGNATION: u64 = 1 << 3;
+    /// Validator set checkpoint and timelocked restore
+    pub const VALIDATOR_SET_RESTORE: u64 = 1 << 4;
+    /// Attested Solana epoch / total stake (SolanaChainState)
+    pub const SOLANA_EPOCH: u64 = 1 << 5;
+    /// Cross-asset conversion of verified burns
+    pub const ASSET_CONVERSION: u64 = 1 << 6;
+    /// Archiving (closing) minted burns past retention
+    pub const BURN_ARCHIVE: u64 = 1 << 7;
+    /// Guardian freeze of a single burn
+    pub const BURN_FREEZE: u64 = 1 << 8;
+    /// BridgeConfig.bft_mode is on (threshold > 2/3 of the validators)
+    pub const BFT_MODE: u64 = 1 << 9;
+    /// Rent sponsorship of small transfers' VerifiedBurnV3 (RentSponsorPool)
+    pub const RENT_SPONSORSHIP: u64 = 1 << 10;
+    /// Per-instruction kill switches (FeatureGate)
+    pub const FEATURE_GATES: u64 = 1 << 11;
+    /// Per-deployment attestation domain separator (BridgeDomain)
+    pub const BRIDGE_DOMAIN: u64 = 1 << 12;
+    /// Integrator deposit webhooks (DepositWebhook)
+    pub const DEPOSIT_WEBHOOKS: u64 = 1 << 13;
+    /// Event schema versions for indexers (EventSchemaRegistry)
+    pub const EVENT_SCHEMAS: u64 = 1 << 14;
+    /// Per-asset symbol, name, decimals and logo hash (get_asset_metadata)
+    pub const ASSET_METADATA: u64 = 1 << 15;
+    /// Governance-attested program build hashes (ProgramHashRegistry)
+    pub const PROGRAM_HASHES: u64 = 1 << 16;
+    /// Bounty-paid sweeping of minted burns past retention (SweepBountyPool)
+    pub const SWEEP_BOUNTY: u64 = 1 << 17;
+    /// Reduced-quorum attestation with caps and a delay (DegradedMode)
+    pub const DEGRADED_MODE: u64 = 1 << 18;
+    /// Messages bound to the X1 genesis hash (X1ChainId)
+    pub const X1_CHAIN_ID: u64 = 1 << 19;
+    /// X1 → Solana transfers (burn_for_return, VerifiedReturnBurn)
+    pub const RETURN_BRIDGE: u64 = 1 << 20;
+    /// Governance-registered assets (AssetRegistry)
+    pub const ASSET_REGISTRY: u64 = 1 << 21;
+    /// Whole-bridge emergency pause (pause_bridge / unpause_bridge)
+    pub const EMERGENCY_PAUSE: u64 = 1 << 22;
+    /// Governed Merkle roots of per-user bridged volume (UsageSnapshot)
+    pub const USAGE_SNAPSHOTS: u64 = 1 << 23;
+    /// V3 attestations expire after X1ValidatorSet::max_attestation_age
+    pub const ATTESTATION_EXPIRY: u64 = 1 << 24;
+    /// Relayers verify and mint designated-recipient burns (RelayerClaim)
+    pub const CLAIM_ON_BEHALF: u64 = 1 << 25;
+    /// Burns verified from a Wormhole VAA (submit_burn_vaa, WormholeConfig)
+    pub const WORMHOLE_VAA: u64 = 1 << 26;
+    /// Governed recipient redirect of stranded burns (redirect_verified_burn)
+    pub const BURN_REDIRECT: u64 = 1 << 27;
+
+    /// Built into this program (V2 / BFT are added at runtime from BridgeConfig)
+    pub const STATIC: u64 = TIMED_ATTESTATIONS
+        | LATENCY_STATS
+        | VALIDATOR_RESIGNATION
+        | VALIDATOR_SET_RESTORE
+        | SOLANA_EPOCH
+        | ASSET_CONVERSION
+        | BURN_ARCHIVE
+        | BURN_FREEZE
+        | RENT_SPONSORSHIP
+        | FEATURE_GATES
+        | BRIDGE_DOMAIN
+        | DEPOSIT_WEBHOOKS
+        | EVENT_SCHEMAS
+        | ASSET_METADATA
+        | PROGRAM_HASHES
+        | SWEEP_BOUNTY
+        | DEGRADED_MODE
+        | X1_CHAIN_ID
+        | RETURN_BRIDGE
+        | ASSET_REGISTRY
+        | EMERGENCY_PAUSE
+        | USAGE_SNAPSHOTS
+        | ATTESTATION_EXPIRY
+        | CLAIM_ON_BEHALF
+        | WORMHOLE_VAA
+        | BURN_REDIRECT;
+}
+
+/// Attestation message versions (see submit_burn_attestation*.rs)
+pub const ATTESTATION_MESSAGE_V2: u8 = 2;
+pub const ATTESTATION_MESSAGE_V3: u8 = 3;
+
+/// One registered asset and the mint program that owns its replay PDAs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct AssetInfo {
+    pub asset_id: u8,
+    pub mint_program: Pubkey,
+}
+
+/// Display metadata of a registered asset, returned by `get_asset_metadata`
+///
+/// Lets X1 wallets render bridged balances without a hard-coded token
+/// list. The logo lives in off-chain metadata; `logo_uri_hash` pins which
+/// one: a wallet hashes the Metaplex `uri` of the X1 mint and only shows
+/// the logo it points to when the hashes match.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct AssetMetadata {
+    pub asset_id: u8,
+    #[max_len(10)]
+    pub symbol: String,
+    #[max_len(32)]
+    pub name: String,
+    pub decimals: u8,
+    /// sha256 of the metadata JSON URI (which carries the logo image)
+    pub logo_uri_hash: [u8; 32],
+    pub mint_program: Pubkey,
+}
+
+/// What this deployment supports, for SDKs and relayers to negotiate
+/// against instead of hard-coding deployment knowledge
+///
+/// Returned by `get_bridge_info` and stored in the `BridgeInfo` account.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace, PartialEq, Debug)]
+pub struct BridgeCapabilities {
+    /// Bridge protocol version (major, minor, patch), see config::BRIDGE_VERSION
+    pub bridge_version: [u16; 3],
+    /// This program's crate version
+    pub light_client_version: [u16; 3],
+    /// Attestation message versions submit instructions accept right now
+    #[max_len(4)]
+    pub attestation_versions: Vec<u8>,
+    #[max_len(8)]
+    pub assets: Vec<AssetInfo>,
+    /// `features::*` bits
+    pub features: u64,
+    pub validator_set_version: u64,
+    pub validator_count: u8,
+    pub threshold: u8,
+}
+
+impl BridgeCapabilities {
+    pub fn current(config: &BridgeConfig, validator_set: &X1ValidatorSet, slot: u64) -> Self {
+        let v2_open = !config.is_v2_deprecated(slot);
+
+        let mut attestation_versions = Vec::with_capacity(2);
+        let mut features = features::STATIC;
+        if v2_open {
+            attestation_versions.push(ATTESTATION_MESSAGE_V2);
+            features |= features::V2_SUBMISSIONS;
+        }
+        attestation_versions.push(ATTESTATION_MESSAGE_V3);
+        if config.bft_mode {
+            features |= features::BFT_MODE;
+        }
+
+        Self {
+            bridge_version: crate::config::BRIDGE_VERSION,
+            light_client_version: parse_semver(env!("CARGO_PKG_VERSION")),
+            attestation_versions,
+            assets: Asset::ALL
+                .iter()
+                .map(|asset| AssetInfo { asset_id: asset.to_u8(), mint_program: asset.mint_program_id() })
+                .collect(),
+            features,
+            validator_set_version: validator_set.version,
+            validator_count: validator_set.validators.len() as u8,
+            threshold: validator_set.threshold,
+        }
+    }
+}
+
+/// "1.2.3" → [1, 2, 3]; missing or non-numeric parts are 0
+pub fn parse_semver(version: &str) -> [u16; 3] {
+    let mut parts = [0u16; 3];
+    for (part, value) in parts.iter_mut().zip(version.split('.')) {
+        // Ignore pre-release / build suffixes ("1.2.3-beta")
+        let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+        *part = digits.parse().unwrap_or(0);
+    }
+    parts
+}
+
+/// Capability snapshot readable with a plain account fetch
+///
+/// Refreshed permissionlessly by `sync_bridge_info`; `updated_slot` tells
+/// readers how fresh it is (simulate `get_bridge_info` for a live view).
+///
+/// Seeds: ["bridge_info"]
+#[account]
+#[derive(InitSpace)]
+pub struct BridgeInfo {
+    pub capabilities: BridgeCapabilities,
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// EMERGENCY BURN FREEZE
+// ============================================================================
+
+/// Guardian freeze of one VerifiedBurnV3 (e.g. flagged stolen funds)
+///
+/// While this account exists the burn's `processed` flag is held at true,
+/// so no mint path (or conversion) accepts it; every other burn is
+/// unaffected. Freezing needs only the fast guardian quorum
+/// (`freeze_quorum`), unfreezing needs full validator governance.
+///
+/// Seeds: ["burn_freeze", asset_id, user, burn_nonce]
+#[account]
+#[derive(InitSpace)]
+pub struct BurnFreeze {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    /// Off-chain incident reference (e.g. sha256 of the report)
+    pub reason: [u8; 32],
+    pub frozen_slot: u64,
+    /// Paid the rent; refunded on unfreeze
+    pub payer: Pubkey,
+    pub bump: u8,
+}
+
+/// Guardian approvals needed to freeze a burn
+///
+/// More than a third of the validators (so a lone validator cannot block
+/// transfers), but never more than the mint threshold: freezing must be
+/// faster to reach than the attestations it stops.
+pub fn freeze_quorum(validator_count: usize, threshold: u8) -> u8 {
+    let third_plus_one = (validator_count / 3 + 1).min(u8::MAX as usize) as u8;
+    third_plus_one.min(threshold).max(1)
+}
+
+// ============================================================================
+// RECIPIENT REDIRECT (STRANDED BURNS)
+// ============================================================================
+
+/// Whether a burn verified at `verified_slot` may be redirected to another
+/// recipient (config::BURN_REDIRECT_DELAY_SLOTS later)
+///
+/// The delay leaves the recipient every chance to mint first; a redirect
+/// is only for burns nobody can mint (e.g. verified for a program address).
+pub fn redirect_allowed(verified_slot: u64, current_slot: u64) -> bool {
+    verified_slot
+        .checked_add(crate::config::BURN_REDIRECT_DELAY_SLOTS)
+        .is_some_and(|allowed_slot| current_slot >= allowed_slot)
+}
+
+// ============================================================================
+// RENT SPONSORSHIP POOL
+// ============================================================================
+
+/// Lamports that pay the VerifiedBurnV3 rent of small transfers
+///
+/// Funded by anyone (usually governance treasury), configured and drained
+/// only by validator governance. sponsor_burn_rent pre-funds the burn's
+/// PDA in the same transaction as its submit_burn_attestation_v3, so the
+/// user needs no XNT for rent; archive_verified_burn returns the rent here.
+///
+/// Seeds: ["rent_sponsor_pool"]
+#[account]
+#[derive(InitSpace)]
+pub struct RentSponsorPool {
+    /// Largest burn amount (token base units) that is sponsored (0 = off)
+    pub max_burn_amount: u64,
+    pub total_funded: u64,
+    /// Lamports lent to sponsored burns
+    pub total_sponsored: u64,
+    /// Lamports returned by archived sponsored burns
+    pub total_reimbursed: u64,
+    /// Sponsored burns not archived yet
+    pub outstanding: u64,
+    pub bump: u8,
+}
+
+impl RentSponsorPool {
+    /// Whether a burn of `amount` qualifies for sponsorship
+    pub fn sponsors(&self, amount: u64) -> bool {
+        self.max_burn_amount > 0 && amount <= self.max_burn_amount
+    }
+
+    /// Lamports that can be lent without dropping below `rent_floor`
+    /// (the pool's own rent-exempt minimum)
+    pub fn available(lamports: u64, rent_floor: u64) -> u64 {
+        lamports.saturating_sub(rent_floor)
+    }
+}
+
+/// Marks a VerifiedBurnV3 whose rent the pool paid
+///
+/// Its existence makes archive_verified_burn send the burn's rent (and
+/// this account's) back to the pool instead of to the user.
+///
+/// Seeds: ["sponsored_rent", asset_id, user, burn_nonce]
+#[account]
+#[derive(InitSpace)]
+pub struct SponsoredRent {
+    /// Lamports lent for this burn (both accounts' rent)
+    pub lamports: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// SWEEP BOUNTY POOL
+// ============================================================================
+
+/// Lamports that pay crankers for sweeping minted burns past retention
+///
+/// sweep_verified_burn does what archive_verified_burn does (the rent still
+/// goes to the user or the rent sponsor pool) and pays the caller
+/// `bounty_lamports` from here, so state stays bounded without relying on
+/// altruistic cleanup. Funded by anyone, configured and drained only by
+/// validator governance. Mint-side ProcessedBurn / ProcessedBurnV3 PDAs are
+/// never swept: they are the permanent replay guards.
+///
+/// Seeds: ["sweep_bounty_pool"]
+#[account]
+#[derive(InitSpace)]
+pub struct SweepBountyPool {
+    /// Lamports paid per swept burn (0 = no bounty)
+    pub bounty_lamports: u64,
+    pub total_funded: u64,
+    pub total_paid: u64,
+    /// Burns swept through this pool
+    pub swept: u64,
+    pub bump: u8,
+}
+
+impl SweepBountyPool {
+    /// Bounty payable from `lamports` without dropping below `rent_floor`;
+    /// a nearly empty pool pays what it has rather than blocking the sweep
+    pub fn payable(&self, lamports: u64, rent_floor: u64) -> u64 {
+        self.bounty_lamports.min(lamports.saturating_sub(rent_floor))
+    }
+
+    /// Whether sweeping a burn earns the bounty: only if it was verified
+    /// before it was minted. A VerifiedBurnV3 re-created from old
+    /// attestations (verified after its mint) is still swept, but for free,
+    /// so bounties cannot be farmed by re-submitting minted burns.
+    pub fn pays_for(verified_at: i64, processed_at: i64) -> bool {
+        verified_at <= processed_at
+    }
+}
+
+// ============================================================================
+// DEGRADED MODE (PARTIAL VALIDATOR SET)
+// ============================================================================
+
+/// Smallest degraded-mode quorum governance may set
+pub const MIN_DEGRADED_QUORUM: u8 = 2;
+
+/// Reduced-quorum attestation for one asset while validators are offline
+///
+/// With fewer than `threshold` validators live, submit_burn_attestation_v3
+/// halts. If governance has configured (and enabled) this account ahead of
+/// time, submit_burn_attestation_degraded accepts `quorum` attestations
+/// instead, for burns up to `max_transfer_amount` and at most `daily_cap`
+/// per UTC day. Such a burn is held at `processed = true` (like a freeze)
+/// for `delay_seconds`, so guardians can still freeze it, and then released
+/// by release_degraded_burn. Not initialized or disabled = no degraded mode.
+///
+/// Seeds: ["degraded_mode", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct DegradedMode {
+    pub asset_id: u8,
+    pub enabled: bool,
+    /// Valid attestations accepted instead of the validator set threshold
+    pub quorum: u8,
+    /// Largest burn amount (token base units) accepted
+    pub max_transfer_amount: u64,
+    /// Total burn amount accepted per UTC day
+    pub daily_cap: u64,
+    /// Hold between verification and release (at least config::MIN_DEGRADED_DELAY_SECONDS)
+    pub delay_seconds: i64,
+    /// UTC day (unix_timestamp / 86_400) that `day_total` counts
+    pub day: i64,
+    pub day_total: u64,
+    /// Burns verified in degraded mode
+    pub total_burns: u64,
+    pub bump: u8,
+}
+
+impl DegradedMode {
+    pub const SECONDS_PER_DAY: i64 = 86_400;
+
+    /// Whether governance may set these values while the set has `threshold`
+    pub fn is_valid_config(
+        quorum: u8,
+        threshold: u8,
+        max_transfer_amount: u64,
+        daily_cap: u64,
+        delay_seconds: i64,
+    ) -> bool {
+        quorum >= MIN_DEGRADED_QUORUM
+            && quorum < threshold
+            && max_transfer_amount > 0
+            && max_transfer_amount <= daily_cap
+            && delay_seconds >= crate::config::MIN_DEGRADED_DELAY_SECONDS
+    }
+
+    /// Count a burn of `amount` with `valid_count` attestations against the caps
+    pub fn admit(&mut self, valid_count: u8, amount: u64, now: i64) -> Result<()> {
+        use crate::errors::LightClientError;
+
+        require!(self.enabled, LightClientError::DegradedModeOff);
+        require!(valid_count >= self.quorum, LightClientError::InsufficientAttestations);
+        require!(amount <= self.max_transfer_amount, LightClientError::DegradedTransferCapExceeded);
+
+        let day = now.div_euclid(Self::SECONDS_PER_DAY);
+        if day != self.day {
+            self.day = day;
+            self.day_total = 0;
+        }
+        let day_total = self.day_total
+            .checked_add(amount)
+            .ok_or(LightClientError::ArithmeticOverflow)?;
+        require!(day_total <= self.daily_cap, LightClientError::DegradedDailyCapExceeded);
+
+        self.day_total = day_total;
+        self.total_burns = self.total_burns.saturating_add(1);
+        Ok(())
+    }
+}
+
+/// A VerifiedBurnV3 accepted in degraded mode, held until `release_at`
+///
+/// Seeds: ["degraded_burn", asset_id, user, burn_nonce]
+#[account]
+#[derive(InitSpace)]
+pub struct DegradedBurn {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    /// Valid attestations it was accepted with
+    pub attestations: u8,
+    /// Unix timestamp from which release_degraded_burn may release it
+    pub release_at: i64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// FEATURE GATE (PER-INSTRUCTION KILL SWITCHES)
+// ============================================================================
+
+/// Code paths governance can switch off individually (FeatureGate bits)
+///
+/// Batched attestation has no on-chain instruction of its own: every burn
+/// in a batch still lands through submit_burn_attestation_v3 (ATTEST_V3).
+pub mod gates {
+    /// submit_burn_attestation (V2)
+    pub const ATTEST_V2: u64 = 1 << 0;
+    /// submit_burn_attestation_v3
+    pub const ATTEST_V3: u64 = 1 << 1;
+    /// mint_from_burn (V2, XENCAT only)
+    pub const MINT_V2: u64 = 1 << 2;
+    /// mint_from_burn_v3 (both mint programs) and mint_from_burns (XENCAT)
+    pub const MINT_V3: u64 = 1 << 3;
+    /// mint_to_stream
+    pub const MINT_STREAM: u64 = 1 << 4;
+    /// mint_to_stealth
+    pub const MINT_STEALTH: u64 = 1 << 5;
+    /// mint_from_burn_v3_compressed
+    pub const MINT_COMPRESSED: u64 = 1 << 6;
+    /// convert_verified_burn
+    pub const CONVERT: u64 = 1 << 7;
+    /// sponsor_burn_rent
+    pub const RENT_SPONSOR: u64 = 1 << 8;
+    /// submit_burn_attestation_degraded
+    pub const ATTEST_DEGRADED: u64 = 1 << 9;
+    /// burn_for_return (XENCAT mint) and verify_return_burn
+    pub const RETURN: u64 = 1 << 10;
+    /// submit_burn_attestation_for_recipient and mint_for_recipient
+    pub const CLAIM_ON_BEHALF: u64 = 1 << 11;
+    /// submit_burn_vaa
+    pub const ATTEST_VAA: u64 = 1 << 12;
+}
+
+/// Governance kill switches, one bit per gated instruction (see `gates`)
+///
+/// Stores the *disabled* bits, so gates added in later upgrades start
+/// enabled, and a deployment that never created this account has every
+/// path enabled. Lets one path be switched off after an audit finding
+/// without a program upgrade.
+///
+/// The top bit (PAUSED) is the emergency pause: while set, every gated
+/// path fails with BridgePaused whatever its own bit says. Only
+/// pause_bridge / unpause_bridge change it; set_feature_gate keeps it.
+///
+/// Seeds: ["feature_gate"]
+#[account]
+#[derive(InitSpace)]
+pub struct FeatureGate {
+    /// Bits of `gates` that are switched off
+    pub disabled: u64,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+impl FeatureGate {
+    /// Emergency pause bit (never a `gates` bit)
+    pub const PAUSED: u64 = 1 << 63;
+
+    pub fn is_enabled(&self, gate: u64) -> bool {
+        self.disabled & gate == 0
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.disabled & Self::PAUSED != 0
+    }
+
+    /// Fail with BridgePaused while paused, or FeatureDisabled if `gate`
+    /// is switched off
+    ///
+    /// `info` must be the ["feature_gate"] PDA (callers pin it with seeds).
+    /// An uninitialized account means nothing is disabled.
+    pub fn require_enabled(info: &AccountInfo, gate: u64) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidFeatureGate);
+        let feature_gate = FeatureGate::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(!feature_gate.is_paused(), crate::errors::LightClientError::BridgePaused);
+        require!(feature_gate.is_enabled(gate), crate::errors::LightClientError::FeatureDisabled);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// BRIDGE DOMAIN (PER-DEPLOYMENT DOMAIN SEPARATOR)
+// ============================================================================
+
+/// Longest allowed bridge domain separator
+pub const MAX_BRIDGE_DOMAIN_LEN: usize = 32;
+
+/// Domain separator bound into every attestation message of this deployment
+///
+/// Set once at initialization, with validator approval, so devnet and
+/// mainnet run the same build while their attestations can never be
+/// replayed on each other. A deployment that never created this account
+/// keeps the compiled-in DOMAIN_SEPARATOR.
+///
+/// Seeds: ["bridge_domain"]
+#[account]
+#[derive(InitSpace)]
+pub struct BridgeDomain {
+    #[max_len(MAX_BRIDGE_DOMAIN_LEN)]
+    pub domain: String,
+    pub bump: u8,
+}
+
+impl BridgeDomain {
+    /// Non-empty, at most MAX_BRIDGE_DOMAIN_LEN, only A-Z, 0-9 and '_'
+    ///
+    /// Keeps invisible or look-alike bytes out of a value every validator
+    /// and SDK has to reproduce exactly.
+    pub fn is_valid_domain(domain: &str) -> bool {
+        !domain.is_empty()
+            && domain.len() <= MAX_BRIDGE_DOMAIN_LEN
+            && domain.bytes().all(|b| b.is_ascii_uppercase() || b.is_ascii_digit() || b == b'_')
+    }
+
+    /// Domain separator bytes for attestation messages
+    ///
+    /// `info` must be the ["bridge_domain"] PDA (callers pin it with seeds).
+    /// An uninitialized account means the compiled-in DOMAIN_SEPARATOR.
+    pub fn resolve(info: &AccountInfo) -> Result<Vec<u8>> {
+        if info.data_is_empty() {
+            return Ok(crate::DOMAIN_SEPARATOR.as_bytes().to_vec());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidBridgeDomain);
+        let bridge_domain = BridgeDomain::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(bridge_domain.domain.into_bytes())
+    }
+}
+
+// ============================================================================
+// X1 CHAIN ID (FORK / CLONE REPLAY DOMAIN)
+// ============================================================================
+
+/// Genesis hash of the X1 network this deployment runs on
+///
+/// Set once at initialization, with validator approval. When set, it is
+/// appended to the domain of every attestation message and bound into
+/// validator set update messages. Validator services sign with the genesis
+/// hash of the X1 RPC they read from, and refuse to sign when it differs
+/// from this value, so a fork or test clone of X1 gets no signatures that
+/// its copy of the programs accepts, and signatures made there never verify
+/// here. A deployment that never created this account keeps unbound messages.
+///
+/// Seeds: ["x1_chain_id"]
+#[account]
+#[derive(InitSpace)]
+pub struct X1ChainId {
+    pub genesis_hash: [u8; 32],
+    pub bump: u8,
+}
+
+impl X1ChainId {
+    /// Genesis hash this deployment is bound to (None = not initialized)
+    ///
+    /// `info` must be the ["x1_chain_id"] PDA (callers pin it with seeds).
+    pub fn resolve(info: &AccountInfo) -> Result<Option<[u8; 32]>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidX1ChainId);
+        let chain_id = X1ChainId::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        Ok(Some(chain_id.genesis_hash))
+    }
+
+    /// Attestation message domain: the BridgeDomain bytes, then the genesis hash if bound
+    ///
+    /// Unambiguous: a domain is at most MAX_BRIDGE_DOMAIN_LEN bytes, a bound
+    /// one always longer.
+    pub fn bind_domain(mut domain: Vec<u8>, info: &AccountInfo) -> Result<Vec<u8>> {
+        if let Some(genesis_hash) = Self::resolve(info)? {
+            domain.extend_from_slice(&genesis_hash);
+        }
+        Ok(domain)
+    }
+}
+
+// ============================================================================
+// MINT ACKNOWLEDGEMENTS (REVERSE-DIRECTION EVIDENCE)
+// ============================================================================
+
+/// Domain of the mint acknowledgement record hash
+pub const MINT_ACK_DOMAIN: &[u8] = b"XENCAT_X1_MINT_ACK_V1";
+
+/// Source chain tag of transfers that start on Solana
+pub const TRANSFER_SOURCE_SOLANA: &[u8] = b"solana";
+
+/// Source chain tag of transfers that start on X1 (burn_for_return)
+pub const TRANSFER_SOURCE_X1: &[u8] = b"x1";
+
+/// Canonical id of a transfer: hash(source || asset_id || nonce)
+///
+/// Same bytes on both chains, so a Solana burn, its X1 mint and any
+/// acknowledgement of it are correlated without agreeing on strings.
+pub fn transfer_id(source: &[u8], asset_id: u8, nonce: u64) -> [u8; 32] {
+    use anchor_lang::solana_program::hash::hashv;
+
+    hashv(&[source, &[asset_id], &nonce.to_le_bytes()]).to_bytes()
+}
+
+/// What a mint program records when it completes a transfer
+///
+/// The mint programs emit this as their MintAcknowledged event. Validators
+/// of the reverse direction (X1 → Solana) attest to `hash()` exactly as
+/// the X1 validators attest to a Solana burn: a fixed-layout record,
+/// hashed with a domain, read from finalized state. One evidence format
+/// serves both directions and the burn program's settlement.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MintAcknowledgement {
+    pub transfer_id: [u8; 32],
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub amount: u64,
+    /// Account credited: token account, or owner for compressed output
+    pub recipient: Pubkey,
+    /// X1 slot of the mint
+    pub slot: u64,
+}
+
+impl MintAcknowledgement {
+    pub fn new(asset_id: u8, burn_nonce: u64, amount: u64, recipient: Pubkey, slot: u64) -> Self {
+        Self {
+            transfer_id: transfer_id(TRANSFER_SOURCE_SOLANA, asset_id, burn_nonce),
+            asset_id,
+            burn_nonce,
+            amount,
+            recipient,
+            slot,
+        }
+    }
+
+    /// hash(MINT_ACK_DOMAIN || transfer_id || asset_id || burn_nonce || amount || recipient || slot)
+    pub fn hash(&self) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hashv;
+
+        hashv(&[
+            MINT_ACK_DOMAIN,
+            &self.transfer_id,
+            &[self.asset_id],
+            &self.burn_nonce.to_le_bytes(),
+            &self.amount.to_le_bytes(),
+            self.recipient.as_ref(),
+            &self.slot.to_le_bytes(),
+        ])
+        .to_bytes()
+    }
+}
+
+// ============================================================================
+// RETURN TRANSFERS (X1 → SOLANA)
+// ============================================================================
+
+/// Fields of a mint program's ReturnBurnRecord (burn_for_return)
+///
+/// Read from raw account data: the mint programs depend on this crate, so
+/// it cannot name their account types.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ReturnBurn {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub solana_recipient: Pubkey,
+    pub amount: u64,
+    /// X1 slot of the burn
+    pub slot: u64,
+}
+
+impl ReturnBurn {
+    /// discriminator(8) || nonce(8) || user(32) || solana_recipient(32) || amount(8) || slot(8) || timestamp(8) || bump(1)
+    pub const LEN: usize = 8 + 8 + 32 + 32 + 8 + 8 + 8 + 1;
+
+    /// Parse a ReturnBurnRecord account's data (Anchor discriminator checked)
+    pub fn parse(data: &[u8]) -> Option<Self> {
+        use anchor_lang::solana_program::hash::hash;
+
+        if data.len() < Self::LEN || data[..8] != hash(b"account:ReturnBurnRecord").to_bytes()[..8] {
+            return None;
+        }
+        let u64_at = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+        Some(Self {
+            nonce: u64_at(8),
+            user: Pubkey::new_from_array(data[16..48].try_into().unwrap()),
+            solana_recipient: Pubkey::new_from_array(data[48..80].try_into().unwrap()),
+            amount: u64_at(80),
+            slot: u64_at(88),
+        })
+    }
+}
+
+/// Message X1 validators sign to attest a return burn
+///
+/// Format: hash(domain || "RETURN" || asset_id || validator_set_version || nonce
+///              || user || solana_recipient || amount || x1_slot)
+/// (`domain` as for burn attestations: BridgeDomain, then the X1 genesis hash)
+pub fn return_attestation_message(
+    domain: &[u8],
+    asset_id: u8,
+    validator_set_version: u64,
+    burn: &ReturnBurn,
+) -> [u8; 32] {
+    use anchor_lang::solana_program::hash::hashv;
+
+    hashv(&[
+        domain,
+        b"RETURN",
+        &[asset_id],
+        &validator_set_version.to_le_bytes(),
+        &burn.nonce.to_le_bytes(),
+        burn.user.as_ref(),
+        burn.solana_recipient.as_ref(),
+        &burn.amount.to_le_bytes(),
+        &burn.slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// A return burn attested by a threshold of X1 validators
+///
+/// The X1-side proof of an X1 → Solana transfer: settlement validators
+/// only sign the burn program's release once this exists, and its
+/// presence is the light client's replay guard for the return nonce.
+///
+/// Seeds: ["verified_return_burn", asset_id, return_nonce]
+#[account]
+#[derive(InitSpace)]
+pub struct VerifiedReturnBurn {
+    pub asset_id: u8,
+    pub return_nonce: u64,
+    pub user: Pubkey,
+    pub solana_recipient: Pubkey,
+    pub amount: u64,
+    /// X1 slot of the burn
+    pub x1_slot: u64,
+    /// Validator set version that attested it
+    pub validator_set_version: u64,
+    /// X1 slot at which the attestations were verified
+    pub verified_slot: u64,
+    pub bump: u8,
+}
+
+// ============================================================================
+// ASSET REGISTRY (GOVERNED BRIDGED TOKENS)
+// ============================================================================
+
+/// Most assets the registry can hold
+pub const MAX_REGISTERED_ASSETS: usize = 16;
+
+/// One bridged token and the mint program that owns its replay PDAs
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct RegisteredAsset {
+    pub asset_id: u8,
+    pub mint_program: Pubkey,
+    /// Inactive assets get no new attestations (already verified burns still mint)
+    pub active: bool,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    /// Frozen assets get neither attestations nor mints (per-asset emergency
+    /// stop; the other assets keep running)
+    pub frozen: bool,
+}
+
+/// Assets the light client attests burns for
+///
+/// Lets governance add a bridged token (with its own mint program) without
+/// redeploying the light client. Entries are never removed: asset ids are
+/// part of every attestation hash and PDA seed, so an id is never reused
+/// for another mint program. A deployment that never created this account
+/// keeps the built-in `Asset` list.
+///
+/// Seeds: ["asset_registry"]
+#[account]
+#[derive(InitSpace)]
+pub struct AssetRegistry {
+    #[max_len(MAX_REGISTERED_ASSETS)]
+    pub assets: Vec<RegisteredAsset>,
+    pub bump: u8,
+}
+
+impl AssetRegistry {
+    /// Entries the registry starts with (the built-in assets, active)
+    pub fn builtin(slot: u64) -> Vec<RegisteredAsset> {
+        Asset::ALL
+            .iter()
+            .map(|asset| RegisteredAsset {
+                asset_id: asset.to_u8(),
+                mint_program: asset.mint_program_id(),
+                active: true,
+                updated_slot: slot,
+                frozen: false,
+            })
+            .collect()
+    }
+
+    pub fn get(&self, asset_id: u8) -> Option<&RegisteredAsset> {
+        self.assets.iter().find(|a| a.asset_id == asset_id)
+    }
+
+    /// Add an asset, or reactivate a deactivated one with the same mint program
+    pub fn register(&mut self, asset_id: u8, mint_program: Pubkey, slot: u64) -> Result<()> {
+        require!(
+            asset_id != 0 && mint_program != Pubkey::default(),
+            crate::errors::LightClientError::InvalidAsset
+        );
+        if let Some(asset) = self.assets.iter_mut().find(|a| a.asset_id == asset_id) {
+            require!(
+                !asset.active && asset.mint_program == mint_program,
+                crate::errors::LightClientError::AssetAlreadyRegistered
+            );
+            asset.active = true;
+            asset.updated_slot = slot;
+            return Ok(());
+        }
+        require!(
+            self.assets.len() < MAX_REGISTERED_ASSETS,
+            crate::errors::LightClientError::AssetRegistryFull
+        );
+        self.assets.push(RegisteredAsset { asset_id, mint_program, active: true, updated_slot: slot, frozen: false });
+        Ok(())
+    }
+
+    /// Stop attesting new burns of an asset
+    pub fn deactivate(&mut self, asset_id: u8, slot: u64) -> Result<()> {
+        let asset = self
+            .assets
+            .iter_mut()
+            .find(|a| a.asset_id == asset_id)
+            .ok_or(crate::errors::LightClientError::InvalidAsset)?;
+        require!(asset.active, crate::errors::LightClientError::AssetInactive);
+        asset.active = false;
+        asset.updated_slot = slot;
+        Ok(())
+    }
+
+    /// Freeze or unfreeze an asset (active or not)
+    pub fn set_frozen(&mut self, asset_id: u8, frozen: bool, slot: u64) -> Result<()> {
+        let asset = self
+            .assets
+            .iter_mut()
+            .find(|a| a.asset_id == asset_id)
+            .ok_or(crate::errors::LightClientError::InvalidAsset)?;
+        if frozen {
+            require!(!asset.frozen, crate::errors::LightClientError::AssetFrozen);
+        } else {
+            require!(asset.frozen, crate::errors::LightClientError::AssetNotFrozen);
+        }
+        asset.frozen = frozen;
+        asset.updated_slot = slot;
+        Ok(())
+    }
+
+    /// Registry entry of `asset_id`, active or not
+    ///
+    /// `info` must be the ["asset_registry"] PDA (callers pin it with seeds).
+    /// An uninitialized account means the built-in `Asset` list.
+    pub fn resolve(info: &AccountInfo, asset_id: u8) -> Result<RegisteredAsset> {
+        if info.data_is_empty() {
+            let asset = Asset::from_u8(asset_id)?;
+            return Ok(RegisteredAsset {
+                asset_id,
+                mint_program: asset.mint_program_id(),
+                active: true,
+                updated_slot: 0,
+                frozen: false,
+            });
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidAssetRegistry);
+        let registry = AssetRegistry::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        registry.get(asset_id).copied().ok_or(error!(crate::errors::LightClientError::InvalidAsset))
+    }
+
+    /// Mint program of `asset_id`, if new burns of it may be attested
+    pub fn resolve_active(info: &AccountInfo, asset_id: u8) -> Result<Pubkey> {
+        let asset = Self::resolve(info, asset_id)?;
+        require!(asset.active, crate::errors::LightClientError::AssetInactive);
+        require!(!asset.frozen, crate::errors::LightClientError::AssetFrozen);
+        Ok(asset.mint_program)
+    }
+
+    /// Fail with AssetFrozen while `asset_id` is frozen (mint programs)
+    ///
+    /// `info` must be the ["asset_registry"] PDA (callers pin it with seeds).
+    /// An uninitialized account or unregistered asset means not frozen; the
+    /// mint programs check their asset id themselves.
+    pub fn require_not_frozen(info: &AccountInfo, asset_id: u8) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidAssetRegistry);
+        let registry = AssetRegistry::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(
+            !registry.get(asset_id).is_some_and(|asset| asset.frozen),
+            crate::errors::LightClientError::AssetFrozen
+        );
+        Ok(())
+    }
+}
+
+// ============================================================================
+// DEPOSIT WEBHOOKS (INTEGRATOR NOTIFICATIONS)
+// ============================================================================
+
+/// Deposit notification registration of one X1 recipient (e.g. an exchange)
+///
+/// Only the SHA-256 of the webhook URL is stored. The URL itself is given
+/// to the notifier operator off-chain, and the notifier delivers only to a
+/// URL whose hash matches this account, so nobody but the recipient can
+/// route its deposit notifications anywhere.
+///
+/// Seeds: ["deposit_webhook", recipient]
+#[account]
+#[derive(InitSpace)]
+pub struct DepositWebhook {
+    pub recipient: Pubkey,
+    /// sha256(webhook URL)
+    pub url_hash: [u8; 32],
+    /// Bit (1 << asset_id) per asset to notify about, 0 = every asset
+    pub asset_mask: u8,
+    /// Smallest deposit to notify about (base units, 0 = all)
+    pub min_amount: u64,
+    /// X1 slot of the last registration or update
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+impl DepositWebhook {
+    /// Whether a mint of `amount` of `asset_id` should be notified
+    pub fn wants(&self, asset_id: u8, amount: u64) -> bool {
+        let asset_selected = self.asset_mask == 0
+            || (asset_id < 8 && self.asset_mask & (1 << asset_id) != 0);
+        asset_selected && amount >= self.min_amount
+    }
+}
+
+// ============================================================================
+// EVENT SCHEMA REGISTRY (INDEXER VERSIONING)
+// ============================================================================
+
+/// Most programs the registry can track (light client, mints, burn program, ...)
+pub const MAX_EVENT_SCHEMAS: usize = 16;
+
+/// Event schema version of one program's emitted events
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct EventSchema {
+    /// Program emitting the events (X1 or Solana program id)
+    pub program: Pubkey,
+    /// Bumped whenever any event of `program` changes layout or meaning
+    pub version: u16,
+    /// X1 slot of the last bump
+    pub updated_slot: u64,
+}
+
+/// Current event schema version of each bridge program
+///
+/// Bumped (validator governance) with every upgrade that changes an event,
+/// so indexers compare the version they were written for against this
+/// account and stop, rather than silently mis-decode, on a mismatch.
+/// Programs not listed are at version 0.
+///
+/// Seeds: ["event_schema_registry"]
+#[account]
+#[derive(InitSpace)]
+pub struct EventSchemaRegistry {
+    #[max_len(MAX_EVENT_SCHEMAS)]
+    pub schemas: Vec<EventSchema>,
+    pub bump: u8,
+}
+
+impl EventSchemaRegistry {
+    /// Registered version of `program`, 0 if it has no entry
+    pub fn version_of(&self, program: &Pubkey) -> u16 {
+        self.schemas
+            .iter()
+            .find(|s| s.program == *program)
+            .map_or(0, |s| s.version)
+    }
+
+    /// Record a new version for `program`
+    ///
+    /// Versions only move forward, so a stale approval can never make
+    /// indexers fall back to an older decoder.
+    pub fn set_version(&mut self, program: Pubkey, version: u16, slot: u64) -> Result<()> {
+        require!(
+            version > self.version_of(&program),
+            crate::errors::LightClientError::EventSchemaVersionNotIncreasing
+        );
+        if let Some(schema) = self.schemas.iter_mut().find(|s| s.program == program) {
+            schema.version = version;
+            schema.updated_slot = slot;
+            return Ok(());
+        }
+        require!(
+            self.schemas.len() < MAX_EVENT_SCHEMAS,
+            crate::errors::LightClientError::EventSchemaRegistryFull
+        );
+        self.schemas.push(EventSchema { program, version, updated_slot: slot });
+        Ok(())
+    }
+}
+
+/// Schema version of the light client's state transition events
+///
+/// Carried as `schema_version` in BurnVerified, ValidatorSetInitialized and
+/// ValidatorSetUpdated. Bump it together with the registry entry whenever
+/// one of those events changes.
+pub const LIGHT_CLIENT_EVENT_SCHEMA_VERSION: u16 = 2;
+
+// ============================================================================
+// PROGRAM HASH REGISTRY (VERIFIABLE BUILDS)
+// ============================================================================
+
+/// Most attested (program, version) builds kept; the oldest is dropped first
+pub const MAX_PROGRAM_HASHES: usize = 24;
+
+/// Expected hash of one audited program build
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace)]
+pub struct ProgramHash {
+    pub program: Pubkey,
+    /// Release version (major, minor, patch)
+    pub version: [u16; 3],
+    /// sha256 of the verifiable build's .so (trailing zero padding stripped),
+    /// as printed by `xencat-verify-build`
+    pub hash: [u8; 32],
+    /// X1 slot of the attestation
+    pub attested_slot: u64,
+}
+
+/// Governance-attested hashes of the audited bridge program builds
+///
+/// Validators attest the hash of each release's reproducible build, so
+/// anyone can dump a deployed program, hash it the same way and check it
+/// against the version it claims to be.
+///
+/// Seeds: ["program_hash_registry"]
+#[account]
+#[derive(InitSpace)]
+pub struct ProgramHashRegistry {
+    #[max_len(MAX_PROGRAM_HASHES)]
+    pub hashes: Vec<ProgramHash>,
+    pub bump: u8,
+}
+
+impl ProgramHashRegistry {
+    /// Attested hash of `program` at `version`
+    pub fn expected_hash(&self, program: &Pubkey, version: [u16; 3]) -> Option<[u8; 32]> {
+        self.hashes
+            .iter()
+            .find(|h| h.program == *program && h.version == version)
+            .map(|h| h.hash)
+    }
+
+    /// Newest attested version of `program`
+    pub fn latest(&self, program: &Pubkey) -> Option<&ProgramHash> {
+        self.hashes
+            .iter()
+            .filter(|h| h.program == *program)
+            .max_by_key(|h| h.version)
+    }
+
+    /// Record (or correct) the hash of `program` at `version`
+    ///
+    /// When full, the oldest attestation makes room: old releases stop
+    /// mattering once they are no longer deployed anywhere.
+    pub fn attest(&mut self, program: Pubkey, version: [u16; 3], hash: [u8; 32], slot: u64) {
+        if let Some(entry) = self.hashes.iter_mut().find(|h| h.program == program && h.version == version) {
+            entry.hash = hash;
+            entry.attested_slot = slot;
+            return;
+        }
+        if self.hashes.len() >= MAX_PROGRAM_HASHES {
+            if let Some(oldest) = (0..self.hashes.len()).min_by_key(|&i| self.hashes[i].attested_slot) {
+                self.hashes.remove(oldest);
+            }
+        }
+        self.hashes.push(ProgramHash { program, version, hash, attested_slot: slot });
+    }
+}
+
+// ============================================================================
+// VERIFIED BURN ARCHIVE (RETENTION POLICY)
+// ============================================================================
+
+/// Depth of each archive's Merkle tree (up to 65_535 burns per asset and period)
+pub const ARCHIVE_TREE_DEPTH: usize = 16;
+
+/// Compact Merkle commitment to the VerifiedBurnV3 accounts closed in one period
+///
+/// Once a VerifiedBurnV3 is minted and older than the retention window,
+/// anyone may close it (rent goes back to the user) after appending its
+/// receipt to this archive. The archive keeps an incremental Merkle tree
+/// (only the frontier is stored), so a closed burn can still be proven
+/// against `root` from the `BurnArchived` event data.
+///
+/// Replay protection does not depend on the closed account: the mint
+/// program's processed_burn_v3 PDA is required to exist before closing and
+/// is never closed.
+///
+/// Seeds: ["burn_archive", asset_id, period]
+#[account]
+#[derive(InitSpace)]
+pub struct BurnArchive {
+    pub asset_id: u8,
+    /// verified_slot / ARCHIVE_PERIOD_SLOTS of every burn in this archive
+    pub period: u64,
+    /// Number of archived burns (next leaf index)
+    pub leaf_count: u64,
+    /// Sum of archived burn amounts
+    pub total_amount: u64,
+    /// Merkle root over all archived leaves (zero-padded to ARCHIVE_TREE_DEPTH)
+    pub root: [u8; 32],
+    /// Left siblings on the path of the next leaf
+    pub frontier: [[u8; 32]; ARCHIVE_TREE_DEPTH],
+    pub bump: u8,
+}
+
+impl BurnArchive {
+    /// Append a leaf and refresh `root`; returns the leaf index (None when full)
+    pub fn append(&mut self, leaf: [u8; 32]) -> Option<u64> {
+        use anchor_lang::solana_program::hash::hashv;
+
+        let index = self.leaf_count;
+        // A full tree has no frontier slot left for the last leaf
+        if index >= (1u64 << ARCHIVE_TREE_DEPTH) - 1 {
+            return None;
+        }
+
+        let mut node = leaf;
+        let mut size = index + 1;
+        for height in 0..ARCHIVE_TREE_DEPTH {
+            if size & 1 == 1 {
+                self.frontier[height] = node;
+                break;
+            }
+            node = hashv(&[&self.frontier[height], &node]).to_bytes();
+            size >>= 1;
+        }
+
+        self.leaf_count = index + 1;
+        self.root = self.compute_root();
+        Some(index)
+    }
+
+    /// Root of the tree holding `leaf_count` leaves, empty leaves as zero hashes
+    pub fn compute_root(&self) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hashv;
+
+        let mut node = [0u8; 32];
+        let mut zero = [0u8; 32];
+        let mut size = self.leaf_count;
+        for height in 0..ARCHIVE_TREE_DEPTH {
+            node = if size & 1 == 1 {
+                hashv(&[&self.frontier[height], &node]).to_bytes()
+            } else {
+                hashv(&[&node, &zero]).to_bytes()
+            };
+            zero = hashv(&[&zero, &zero]).to_bytes();
+            size >>= 1;
+        }
+        node
+    }
+}
+
+/// Merkle leaf committing to a verified burn receipt
+///
+/// sha256(asset_id || burn_nonce || user || amount || verified_at || solana_burn_slot || verified_slot)
+pub fn archive_leaf(burn: &VerifiedBurnV3) -> [u8; 32] {
+    use anchor_lang::solana_program::hash::hashv;
+
+    hashv(&[
+        &[burn.asset_id],
+        &burn.burn_nonce.to_le_bytes(),
+        burn.user.as_ref(),
+        &burn.amount.to_le_bytes(),
+        &burn.verified_at.to_le_bytes(),
+        &burn.solana_burn_slot.to_le_bytes(),
+        &burn.verified_slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Archive period of an X1 slot
+pub fn archive_period(slot: u64) -> u64 {
+    slot / crate::config::ARCHIVE_PERIOD_SLOTS
+}
+
+/// Whether a burn verified at `verified_slot` is past the retention window
+pub fn retention_elapsed(verified_slot: u64, current_slot: u64) -> bool {
+    archive_period(current_slot)
+        >= archive_period(verified_slot).saturating_add(crate::config::BURN_RETENTION_PERIODS)
+}
+
+// ============================================================================
+// USAGE SNAPSHOTS (INCENTIVE PROGRAMS)
+// ============================================================================
+
+/// Depth of each usage snapshot's Merkle tree (up to 1_048_576 user/asset pairs)
+pub const USAGE_TREE_DEPTH: usize = 20;
+
+/// Merkle root of per-user bridged volume in one usage epoch
+///
+/// Validators rebuild the tree from the epoch's BurnVerified events and
+/// commit the root with threshold approval once the epoch has ended.
+/// Airdrop and points programs then check a user's volume with a Merkle
+/// proof (verify_usage_proof, or UsageSnapshot::verify after reading this
+/// account) instead of trusting an off-chain indexer.
+///
+/// Tree: one leaf (usage_leaf) per user and asset, in leaf order, padded
+/// with zero hashes to USAGE_TREE_DEPTH as in BurnArchive.
+///
+/// Seeds: ["usage_snapshot", epoch]
+#[account]
+#[derive(InitSpace)]
+pub struct UsageSnapshot {
+    /// verified_slot / USAGE_EPOCH_SLOTS of every counted burn
+    pub epoch: u64,
+    pub root: [u8; 32],
+    /// Number of leaves (user/asset pairs)
+    pub leaf_count: u64,
+    /// X1 slot the root was committed at
+    pub committed_slot: u64,
+    pub bump: u8,
+}
+
+impl UsageSnapshot {
+    /// Whether `leaf` sits at `leaf_index` under `root`
+    ///
+    /// `proof` holds the USAGE_TREE_DEPTH siblings from the leaf up.
+    pub fn verify(&self, leaf: [u8; 32], leaf_index: u64, proof: &[[u8; 32]]) -> bool {
+        use anchor_lang::solana_program::hash::hashv;
+
+        if leaf_index >= self.leaf_count || proof.len() != USAGE_TREE_DEPTH {
+            return false;
+        }
+        let mut node = leaf;
+        for (height, sibling) in proof.iter().enumerate() {
+            node = if (leaf_index >> height) & 1 == 1 {
+                hashv(&[sibling, &node]).to_bytes()
+            } else {
+                hashv(&[&node, sibling]).to_bytes()
+            };
+        }
+        node == self.root
+    }
+}
+
+/// Merkle leaf committing to one user's bridged volume of an asset
+///
+/// sha256(epoch || user || asset_id || volume), volume being the sum of
+/// the user's BurnVerified amounts in the epoch
+pub fn usage_leaf(epoch: u64, user: &Pubkey, asset_id: u8, volume: u64) -> [u8; 32] {
+    use anchor_lang::solana_program::hash::hashv;
+
+    hashv(&[&epoch.to_le_bytes(), user.as_ref(), &[asset_id], &volume.to_le_bytes()]).to_bytes()
+}
+
+/// Usage epoch of an X1 slot
+pub fn usage_epoch(slot: u64) -> u64 {
+    slot / crate::config::USAGE_EPOCH_SLOTS
+}
+
+// ============================================================================
+// RELAYER CLAIMS (CLAIM ON BEHALF)
+// ============================================================================
+
+/// Relayer that verified a designated-recipient burn, and its fee
+///
+/// Created by submit_burn_attestation_for_recipient next to the burn's
+/// VerifiedBurnV3 (whose user is the recipient). The burner chose the fee
+/// on Solana (burn_xencat_for_recipient) and validators attested it, so
+/// mint_for_recipient can pay it to this relayer out of the minted amount.
+///
+/// Seeds: ["relayer_claim", asset_id, user, burn_nonce]
+#[account]
+#[derive(InitSpace)]
+pub struct RelayerClaim {
+    /// Paid the VerifiedBurnV3 rent; the only key that may mint the claim
+    pub relayer: Pubkey,
+    /// Tokens (base units) kept by the relayer
+    pub relayer_fee: u64,
+    pub bump: u8,
+}
+
+impl RelayerClaim {
+    /// Fail with BurnClaimedByRelayer if the burn has a claim (mint paths
+    /// other than mint_for_recipient, and conversions)
+    ///
+    /// `info` must be the burn's ["relayer_claim"] PDA (callers pin it with
+    /// seeds). Only the light client can create data at that address, so
+    /// an uninitialized account means no claim.
+    pub fn require_unclaimed(info: &AccountInfo) -> Result<()> {
+        require!(info.data_is_empty(), crate::errors::LightClientError::BurnClaimedByRelayer);
+        Ok(())
+    }
+}
+
+/// Message X1 validators sign for the claim terms recorded with a burn
+///
+/// Format: hash(domain || "RELAYER_CLAIM" || asset_id || burn_nonce || user || relayer_fee)
+/// (`domain` as for burn attestations; `user` is the BurnRecord's user, the recipient)
+pub fn relayer_claim_message(domain: &[u8], asset_id: u8, burn_nonce: u64, user: &Pubkey, relayer_fee: u64) -> [u8; 32] {
+    use anchor_lang::solana_program::hash::hashv;
+
+    hashv(&[
+        domain,
+        b"RELAYER_CLAIM",
+        &[asset_id],
+        &burn_nonce.to_le_bytes(),
+        user.as_ref(),
+        &relayer_fee.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+// ============================================================================
+// BURN AGE LIMIT (REPLAY GUARD GARBAGE COLLECTION)
+// ============================================================================
+
+/// Oldest Solana burn a V3 attestation may still verify
+///
+/// Once set, a burn older than `max_burn_age` can never be verified again,
+/// so the mint programs may close its ProcessedBurnV3 replay guard
+/// (close_processed_burn) after its VerifiedBurnV3 was archived. Closed
+/// guards rely on the limit, so governance can lower it but never raise
+/// or remove it.
+///
+/// Seeds: ["burn_age_limit"]
+#[account]
+#[derive(InitSpace)]
+pub struct BurnAgeLimit {
+    /// Seconds between the burn's Solana block time and its verification
+    /// (0 = no limit; replay guards are never closed)
+    pub max_burn_age: i64,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+impl BurnAgeLimit {
+    /// Governance bounds of max_burn_age (7 days to 1 year)
+    pub const MIN_MAX_BURN_AGE: i64 = 604_800;
+    pub const MAX_MAX_BURN_AGE: i64 = 31_536_000;
+
+    /// Whether governance may move the limit from `current` to `new`
+    pub fn is_valid_change(current: i64, new: i64) -> bool {
+        (Self::MIN_MAX_BURN_AGE..=Self::MAX_MAX_BURN_AGE).contains(&new)
+            && (current == 0 || new <= current)
+    }
+
+    /// The configured limit (0 if `info` is not initialized)
+    ///
+    /// `info` must be the ["burn_age_limit"] PDA (callers pin it with seeds).
+    pub fn load(info: &AccountInfo) -> Result<i64> {
+        if info.data_is_empty() {
+            return Ok(0);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidBurnAgeLimit);
+        Ok(BurnAgeLimit::try_deserialize(&mut &info.try_borrow_data()?[..])?.max_burn_age)
+    }
+
+    /// Reject a burn whose Solana block time is more than the limit
+    /// before `now` (no limit if `info` is not initialized)
+    pub fn enforce(info: &AccountInfo, solana_burn_timestamp: i64, now: i64) -> Result<()> {
+        use crate::errors::LightClientError;
+
+        let max_burn_age = Self::load(info)?;
+        if max_burn_age == 0 {
+            return Ok(());
+        }
+        require!(
+            solana_burn_timestamp <= now.saturating_add(X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW),
+            LightClientError::AttestationFromFuture
+        );
+        require!(
+            now.saturating_sub(solana_burn_timestamp) <= max_burn_age,
+            LightClientError::BurnTooOld
+        );
+        Ok(())
+    }
+
+    /// Whether a burn minted at `processed_at` can no longer be verified
+    /// at `now`: its Solana block time is at most `processed_at` plus the
+    /// clock skew allowance, and enforce rejects it from there on
+    pub fn is_expired(max_burn_age: i64, processed_at: i64, now: i64) -> bool {
+        max_burn_age != 0
+            && now.saturating_sub(processed_at)
+                > max_burn_age.saturating_add(X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW)
+    }
+}
+
+// ============================================================================
+// DEV MODE GUARD (MOCK SIGNATURES ON MARKED DEPLOYMENTS ONLY)
+// ============================================================================
+
+/// Marks a `dev-mode` build's deployment as non-production
+///
+/// The feature alone no longer lets mock (all-zero) attestation signatures
+/// through. The deployment must also be marked here by validator
+/// governance, and each submit must pass the canary account named here,
+/// still funded, as its first remaining account. A dev-mode binary
+/// deployed by mistake stays strict, and draining the canary turns mocks
+/// off again without a governance round.
+///
+/// Seeds: ["dev_mode_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct DevModeConfig {
+    pub non_production: bool,
+    /// System account whose key signed the marking
+    pub canary: Pubkey,
+    pub marked_slot: u64,
+    pub bump: u8,
+}
+
+impl DevModeConfig {
+    /// Whether this deployment accepts mock attestation signatures
+    ///
+    /// `config` must be the ["dev_mode_config"] PDA (callers pin it with
+    /// seeds). Always false outside dev-mode builds.
+    pub fn mock_signatures_allowed(config: &AccountInfo, canary: Option<&AccountInfo>) -> Result<bool> {
+        if !cfg!(feature = "dev-mode") || config.data_is_empty() {
+            return Ok(false);
+        }
+        require_keys_eq!(*config.owner, crate::ID, crate::errors::LightClientError::InvalidDevModeConfig);
+        let dev_mode = DevModeConfig::try_deserialize(&mut &config.try_borrow_data()?[..])?;
+        Ok(dev_mode.non_production && canary.is_some_and(|canary| Self::canary_alive(&dev_mode.canary, canary)))
+    }
+
+    /// The canary is the named key, a system account, and still funded
+    pub fn canary_alive(expected: &Pubkey, canary: &AccountInfo) -> bool {
+        canary.key == expected && *canary.owner == anchor_lang::system_program::ID && canary.lamports() > 0
+    }
+}
+
+// ============================================================================
+// ATTESTATION CHALLENGE (NO PRE-SIGNED ATTESTATIONS)
+// ============================================================================
+
+/// Challenge a burner posts before collecting attestations
+///
+/// `challenge` mixes the most recent X1 slot hash, so nobody knows it
+/// before request_attestation_challenge runs. While the account exists,
+/// every V3 submit path for the burn requires validators to have signed
+/// it (appended to the timed message), to have signed no earlier than it
+/// was posted, and the burn to predate it: signatures prepared before the
+/// burn was observed cannot be used.
+///
+/// Seeds: ["challenge_request", asset_id, user, burn_nonce]
+#[account]
+#[derive(InitSpace)]
+pub struct ChallengeRequest {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    /// The burn's user (the only key that may post or close it)
+    pub user: Pubkey,
+    pub challenge: [u8; 32],
+    /// X1 time and slot of the request
+    pub created_at: i64,
+    pub created_slot: u64,
+    pub bump: u8,
+}
+
+impl ChallengeRequest {
+    /// Challenge value for a burn, given the latest SlotHashes entry
+    pub fn derive(asset_id: u8, user: &Pubkey, burn_nonce: u64, slot: u64, slot_hash: &[u8; 32]) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hashv;
+
+        hashv(&[
+            b"ATTESTATION_CHALLENGE",
+            &[asset_id],
+            user.as_ref(),
+            &burn_nonce.to_le_bytes(),
+            &slot.to_le_bytes(),
+            slot_hash,
+        ])
+        .to_bytes()
+    }
+
+    /// The burn's challenge, if the user posted one
+    ///
+    /// `info` must be the burn's ["challenge_request", ...] PDA (callers pin
+    /// it with seeds).
+    pub fn load(info: &AccountInfo) -> Result<Option<ChallengeRequest>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::LightClientError::InvalidChallengeRequest);
+        Ok(Some(ChallengeRequest::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Reject a burn that happened after the challenge was posted (beyond
+    /// the clock skew allowance)
+    pub fn check_burn_time(&self, solana_burn_timestamp: i64) -> Result<()> {
+        require!(
+            solana_burn_timestamp <= self.created_at.saturating_add(X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW),
+            crate::errors::LightClientError::ChallengePredatesBurn
+        );
+        Ok(())
+    }
+
+    /// Reject a signature timed before the challenge was posted (beyond
+    /// the clock skew allowance)
+    pub fn check_signing_time(&self, attested_at: i64) -> Result<()> {
+        require!(
+            attested_at >= self.created_at.saturating_sub(X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW),
+            crate::errors::LightClientError::AttestationPredatesChallenge
+        );
+        Ok(())
+    }
+}
+
+// ============================================================================
+// FEE LOOKUP TABLE (ADDRESS LOOKUP TABLE DISCOVERY)
+// ============================================================================
+
+/// Address lookup table holding every validator payout account, for SDKs
+///
+/// Mint transactions list one fee account per validator; a v0 transaction
+/// that loads them from this table stays small as the set grows. Anyone
+/// may publish a table (publish_fee_lookup_table), but only a frozen,
+/// active one holding every possible payout account of the current set
+/// (see fee_payout_accounts), so every publishable table is equally good.
+///
+/// Seeds: ["fee_lookup_table"]
+#[account]
+#[derive(InitSpace)]
+pub struct FeeLookupTable {
+    /// Address lookup table (Pubkey::default() = none published yet)
+    pub lookup_table: Pubkey,
+    /// Validator set version the table was checked against; SDKs ignore
+    /// the table once the set moves on
+    pub validator_set_version: u64,
+    /// X1 slot of the last publication
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+/// Every account a mint may pay a validator's fee to: the validator key
+/// and its fee vault PDA ["fee_vault", validator] in each mint program
+pub fn fee_payout_accounts(validators: &[Pubkey]) -> Vec<Pubkey> {
+    let mut accounts = Vec::with_capacity(validators.len() * 3);
+    for validator in validators {
+        accounts.push(*validator);
+        for mint_program in [crate::XENCAT_MINT_PROGRAM_ID, crate::DGN_MINT_PROGRAM_ID] {
+            accounts.push(Pubkey::find_program_address(&[b"fee_vault", validator.as_ref()], &mint_program).0);
+        }
+    }
+    accounts
+}
+
+/// Whether `table` holds every payout account of `validators`
+pub fn covers_fee_payout_accounts(table: &[Pubkey], validators: &[Pubkey]) -> bool {
+    fee_payout_accounts(validators).iter().all(|account| table.contains(account))
+}
+
+// ============================================================================
+// BETA ALLOWLIST (PERMISSIONED ASSET ROLLOUT)
+// ============================================================================
+
+/// Users allowed to verify burns of an asset during its beta
+///
+/// While `enabled`, every path that creates a VerifiedBurnV3 of this asset
+/// (V3, for-recipient and degraded submits, conversions into it) requires
+/// the attested X1 user to be listed, so only allowlisted users can mint
+/// it. Governance disables it once the asset graduates to permissionless.
+/// Not initialized or disabled = permissionless. The Solana burn program
+/// keeps its own list for the burners.
+///
+/// Seeds: ["beta_allowlist", asset_id]
+#[account]
+#[derive(InitSpace)]
+pub struct BetaAllowlist {
+    pub asset_id: u8,
+    pub enabled: bool,
+    #[max_len(32)]
+    pub users: Vec<Pubkey>,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+impl BetaAllowlist {
+    pub const MAX_USERS: usize = 32;
+
+    /// Whether governance may set this list: bounded, no duplicates, and
+    /// not empty while enabled (that would halt the asset, use the pause)
+    pub fn is_valid_list(enabled: bool, users: &[Pubkey]) -> bool {
+        users.len() <= Self::MAX_USERS
+            && (!enabled || !users.is_empty())
+            && users.iter().enumerate().all(|(i, user)| !users[..i].contains(user))
+    }
+
+    /// Whether `user` may verify burns under this list
+    pub fn allows(&self, user: &Pubkey) -> bool {
+        !self.enabled || self.users.contains(user)
+    }
+
+    /// Reject `user` while the asset's beta list is enabled and does not
+    /// list it (no restriction if `info` is not initialized)
+    ///
+    /// `info` must be the ["beta_allowlist", asset_id] PDA (callers pin it with seeds).
+    pub fn enforce(info: &AccountInfo, user: &Pubkey) -> Result<()> {
+        use crate::errors::LightClientError;
+
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, LightClientError::InvalidBetaAllowlist);
+        let list = BetaAllowlist::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(list.allows(user), LightClientError::UserNotAllowlisted);
+        Ok(())
+    }
+}
+
+// ============================================================================
+// NOTICE BOARD (PLANNED MAINTENANCE AND UPGRADES)
+// ============================================================================
+
+/// Governance notice SDKs and wallets show before a user starts a burn
+///
+/// Announces validator rotations, pauses or upgrade windows that could
+/// leave a burn unmintable for a while. Only the hash of the text is kept
+/// on-chain; the text itself is published off-chain (bridge website,
+/// status page), and clients show it only if its sha256 matches.
+/// Severity NOTICE_NONE clears the board. The program never acts on it.
+///
+/// Seeds: ["notice"]
+#[account]
+#[derive(InitSpace)]
+pub struct Notice {
+    /// sha256 of the notice text
+    pub message_hash: [u8; 32],
+    /// X1 slot from which the announced event applies
+    pub effective_slot: u64,
+    /// NOTICE_NONE, NOTICE_INFO, NOTICE_WARNING or NOTICE_CRITICAL
+    pub severity: u8,
+    /// Incremented on every post, so clients can tell a new notice apart
+    pub sequence: u64,
+    /// X1 slot of the last post
+    pub posted_slot: u64,
+    pub bump: u8,
+}
+
+/// No notice (board cleared)
+pub const NOTICE_NONE: u8 = 0;
+/// Informational, no action needed
+pub const NOTICE_INFO: u8 = 1;
+/// Burns may be delayed around the effective slot
+pub const NOTICE_WARNING: u8 = 2;
+/// Burns started now may not be mintable until the event is over
+pub const NOTICE_CRITICAL: u8 = 3;
+
+impl Notice {
+    /// Whether governance may post this notice: a known severity, and a
+    /// real hash unless it clears the board
+    pub fn is_valid(message_hash: &[u8; 32], severity: u8) -> bool {
+        match severity {
+            NOTICE_NONE => true,
+            NOTICE_INFO..=NOTICE_CRITICAL => *message_hash != [0u8; 32],
+            _ => false,
+        }
+    }
+}
+
+// ============================================================================
+// WORMHOLE VAA PATH (FALLBACK ATTESTATIONS)
+// ============================================================================
+
+/// Trusted Wormhole core bridge and emitter of submit_burn_vaa
+///
+/// A burn published through Wormhole by `emitter_address` on
+/// `emitter_chain`, and posted on X1 by `core_bridge`, verifies without X1
+/// validator attestations (see wormhole.rs). Not initialized or disabled =
+/// the VAA path is off.
+///
+/// Seeds: ["wormhole_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct WormholeConfig {
+    pub enabled: bool,
+    /// Wormhole core bridge program on X1 (owner of PostedVAA accounts)
+    pub core_bridge: Pubkey,
+    /// Wormhole chain id of the emitter (wormhole::CHAIN_ID_SOLANA)
+    pub emitter_chain: u16,
+    /// Wormhole emitter that publishes the burns: the burn program's
+    /// ["emitter"] PDA (wormhole::burn_emitter_address)
+    pub emitter_address: [u8; 32],
+    /// Lowest accepted VAA consistency level (Solana: 1 = finalized)
+    pub min_consistency_level: u8,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    pub bump: u8,
+}
+
+impl WormholeConfig {
+    /// Whether governance may set this configuration: an enabled path
+    /// needs a core bridge and an emitter
+    pub fn is_valid(enabled: bool, core_bridge: &Pubkey, emitter_address: &[u8; 32]) -> bool {
+        !enabled || (*core_bridge != Pubkey::default() && *emitter_address != [0u8; 32])
+    }
+
+    /// The configuration, if the VAA path is on
+    ///
+    /// `info` must be the ["wormhole_config"] PDA (callers pin it with seeds).
+    pub fn load_enabled(info: &AccountInfo) -> Result<Self> {
+        use crate::errors::LightClientError;
+
+        require!(!info.data_is_empty(), LightClientError::VaaPathDisabled);
+        require_keys_eq!(*info.owner, crate::ID, LightClientError::InvalidWormholeConfig);
+        let config = WormholeConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(config.enabled, LightClientError::VaaPathDisabled);
+        Ok(config)
+    }
+}
+
+// ============================================================================
+// SOLANA CHAIN STATE (VALIDATOR-ATTESTED)
+// ============================================================================
+
+/// Solana epoch and total active stake as attested by the X1 validator set
+///
+/// Lets the bridge reason about Solana time (stale-proof detection,
+/// per-epoch limits) without trusting whoever submits the transaction:
+/// the submitter only relays, ≥threshold validators vouch for the data.
+///
+/// Seeds: ["solana_chain_state"]
+#[account]
+#[derive(InitSpace)]
+pub struct SolanaChainState {
+    /// Current Solana epoch
+    pub epoch: u64,
+
+    /// Total active stake on Solana for `epoch` (lamports)
+    pub total_stake: u64,
+
+    /// Solana slot the validators observed when attesting
+    /// Strictly increasing - doubles as replay protection
+    pub solana_slot: u64,
+
+    /// X1 slot of the last update
+    pub updated_slot: u64,
+
+    /// X1 unix timestamp of the last update
+    pub updated_at: i64,
+
+    pub bump: u8,
+}
+
+impl SolanaChainState {
+    /// Whether an attested update may replace the current state
+    ///
+    /// Solana slot must strictly advance and the epoch may never go back.
+    pub fn accepts_update(&self, epoch: u64, solana_slot: u64) -> bool {
+        solana_slot > self.solana_slot && epoch >= self.epoch
+    }
+
+    /// Whether the attested state is recent enough to rely on at `x1_slot`
+    /// (never initialized state is always stale)
+    pub fn is_fresh(&self, x1_slot: u64, max_age_slots: u64) -> bool {
+        self.updated_slot != 0 && x1_slot.saturating_sub(self.updated_slot) <= max_age_slots
+    }
+}
+
+/// Light client configuration and metadata
+///
+/// This account stores the core configuration for the light client including
+/// authority, fee settings, and references to the current validator set.
+/// Kept separate from ValidatorSet for easier upgrades and smaller account size.
+///
+/// Security: Once immutable, only validator set updates signed by 66%+ validators can modify state
+#[account]
+#[derive(InitSpace)]
+pub struct LightClientState {
+    /// Authority that can modify fee settings (NOT validator set)
+    /// In production, this should be transferred to a DAO or renounced
+    pub authority: Pubkey,
+
+    /// Account that receives verification fees
+    pub fee_receiver: Pubkey,
+
+    /// Fee charged per proof verification (in lamports)
+    /// Default: 1_000_000 (0.001 XNT with 6 decimals)
+    pub verification_fee: u64,
+
+    /// Total active stake in current validator set (for 66% threshold calculation)
+    pub total_stake: u64,
+
+    /// Number of validators in current set
+    pub validator_count: u16,
+
+    /// Slot when validator set was last updated
+    pub last_update_slot: u64,
+
+    /// Counter for validator set updates (for history tracking)
+    pub update_epoch: u64,
+
+    /// Current Solana epoch for validator rotation
+    pub current_epoch: u64,
+
+    /// Last time validator config was rotated
+    pub last_rotation: i64,
+
+    /// PDA bump seed
+    pub bump: u8,
+}
+
+/// Validator configuration for 3 primary + 4 fallback architecture
+///
+/// This implements a resilient validator system that:
+/// - Uses top 3 validators by stake as primary (fastest response)
+/// - Maintains 4 fallback validators (if primary unresponsive)
+/// - Rotates every Solana epoch (~2 days) for security
+/// - Autonomous rotation (anyone can update with valid proof)
+///
+/// Security Model:
+/// - Initial validator set trusted at deployment
+/// - Rotation requires proof validators are top 7 by stake
+/// - No single point of failure (7 validator slots total)
+#[account]
+pub struct ValidatorConfig {
+    /// Current Solana epoch number
+    pub current_epoch: u64,
+
+    /// Last update timestamp
+    pub last_update: i64,
+
+    /// Primary validators (used first for proof generation)
+    /// These are the top 3 validators by stake
+    pub primary_validators: [ValidatorInfo; 3],
+
+    /// Fallback validators (used if primary unresponsive)
+    /// These are validators 4-7 by stake
+    pub fallback_validators: [ValidatorInfo; 4],
+
+    /// Total tracked stake (sum of all 7 validators)
+    /// Used for percentage calculations
+    pub total_tracked_stake: u64,
+
+    /// Bump seed for PDA
+    pub bump: u8,
+}
+
+impl ValidatorConfig {
+    /// Space required for ValidatorConfig account
+    /// 8 (discriminator) + 8 (epoch) + 8 (timestamp) +
+    /// (3 * 40) (primary) + (4 * 40) (fallback) + 8 (stake) + 1 (bump)
+    pub const LEN: usize = 8 + 8 + 8 + (3 * 40) + (4 * 40) + 8 + 1;
+
+    /// Get all validators (primary + fallback)
+    pub fn all_validators(&self) -> Vec<ValidatorInfo> {
+        let mut all = Vec::with_capacity(7);
+        all.extend_from_slice(&self.primary_validators);
+        all.extend_from_slice(&self.fallback_validators);
+        all
+    }
+
+    /// Check if a validator is in the trusted set (primary or fallback)
+    pub fn contains_validator(&self, identity: &Pubkey) -> bool {
+        self.primary_validators.iter().any(|v| v.identity == *identity)
+            || self.fallback_validators.iter().any(|v| v.identity == *identity)
+    }
+
+    /// Get validator info by identity
+    pub fn find_validator(&self, identity: &Pubkey) -> Option<&ValidatorInfo> {
+        self.primary_validators.iter()
+            .chain(self.fallback_validators.iter())
+            .find(|v| v.identity == *identity)
+    }
+
+    /// Calculate total stake of all tracked validators
+    pub fn calculate_total_stake(&self) -> u64 {
+        self.primary_validators.iter()
+            .chain(self.fallback_validators.iter())
+            .fold(0u64, |total, v| total.saturating_add(v.stake))
+    }
+
+    /// Verify minimum stake threshold is met by provided validators
+    /// For 3 validators, we require at least their combined stake
+    pub fn verify_stake_threshold(&self, validator_identities: &[Pubkey], total_stake: u64) -> Result<(u64, bool)> {
+        let mut verified_stake: u64 = 0;
+
+        for identity in validator_identities {
+            if let Some(validator) = self.find_validator(identity) {
+                verified_stake = verified_stake
+                    .checked_add(validator.stake)
+                    .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?;
+            }
+        }
+
+        // For 3-validator setup: Use minimum stake threshold from config
+        let threshold = (total_stake as u128 * crate::config::MIN_STAKE_BASIS_POINTS as u128 / 10000) as u64;
+        Ok((verified_stake, verified_stake >= threshold))
+    }
+}
+
+/// Validator set storage - optimized for space efficiency
+///
+/// Stores the current active validator set with their stakes.
+/// This is a separate account from LightClientState to allow for:
+/// 1. Larger storage (can realloc if needed)
+/// 2. Cheaper reads when only validator data is needed
+/// 3. Easier validator set rotation
+///
+/// Space optimization:
+/// - Base: 8 (discriminator) + 4 (vec length) = 12 bytes
+/// - Per validator: 32 (pubkey) + 8 (stake) = 40 bytes
+/// - 300 validators: 12 + (300 * 40) = 12,012 bytes (~12 KB)
+/// - 500 validators: 12 + (500 * 40) = 20,012 bytes (~20 KB)
+///
+/// Note: Solana mainnet has ~1,500 validators but we sample 20 for proofs.
+/// We store top N by stake for verification lookups.
+#[account]
+pub struct ValidatorSet {
+    /// Active validators with their stake amounts
+    /// Stored in descending order by stake for efficient sampling
+    pub validators: Vec<ValidatorInfo>,
+}
+
+impl ValidatorSet {
+    /// Maximum supported validators (conservative estimate)
+    /// 500 validators = ~20 KB, well under Solana's 10 MB account limit
+    pub const MAX_VALIDATORS: usize = 500;
+
+    /// Initial size for validator set account
+    /// Sized for 150 validators (~6 KB) with room to realloc
+    pub const INITIAL_SIZE: usize = 8 + 4 + (40 * 150);
+
+    /// Maximum size for validator set account
+    /// Sized for 500 validators (~20 KB)
+    pub const MAX_SIZE: usize = 8 + 4 + (40 * Self::MAX_VALIDATORS);
+
+    /// Find a validator by identity pubkey
+    /// Returns None if validator not found in set
+    pub fn find_validator(&self, identity: &Pubkey) -> Option<&ValidatorInfo> {
+        self.validators.iter().find(|v| v.identity == *identity)
+    }
+
+    /// Calculate 66% stake threshold (rounds up for safety)
+    /// This is the minimum stake required for consensus
+    pub fn consensus_threshold(total_stake: u64) -> Result<u64> {
+        // (total_stake * 2) / 3, but with overflow protection
+        let doubled = total_stake
+            .checked_mul(2)
+            .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?;
+
+        // Round up: (doubled + 2) / 3 ensures we never under-estimate
         let threshold = doubled
             .checked_add(2)
             .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?
             / 3;
 
-        Ok(threshold)
+        Ok(threshold)
+    }
+
+    /// Verify a list of validators holds sufficient stake for consensus
+    /// Returns (verified_stake, is_sufficient)
+    pub fn verify_stake_threshold(
+        &self,
+        validator_identities: &[Pubkey],
+        total_stake: u64,
+    ) -> Result<(u64, bool)> {
+        let mut verified_stake: u64 = 0;
+
+        for identity in validator_identities {
+            if let Some(validator) = self.find_validator(identity) {
+                verified_stake = verified_stake
+                    .checked_add(validator.stake)
+                    .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?;
+            }
+        }
+
+        let threshold = Self::consensus_threshold(total_stake)?;
+        Ok((verified_stake, verified_stake >= threshold))
+    }
+}
+
+/// Individual validator information
+///
+/// Stored in a compact format to minimize space usage.
+/// Only essential data for verification is stored.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
+pub struct ValidatorInfo {
+    /// Validator's identity pubkey (vote account or node identity)
+    pub identity: Pubkey,
+
+    /// Validator's stake amount in lamports
+    /// Used for weighted sampling and threshold calculations
+    pub stake: u64,
+}
+
+impl ValidatorInfo {
+    /// Size of a single validator entry in bytes
+    pub const SIZE: usize = 32 + 8; // pubkey + stake
+}
+
+/// Validator set update history entry
+///
+/// Maintains a rolling history of validator set updates to:
+/// 1. Prove chain of trust from genesis
+/// 2. Allow verification of historical proofs
+/// 3. Debug and audit validator set transitions
+///
+/// We keep last 100 updates in a ring buffer for space efficiency.
+#[account]
+pub struct ValidatorSetHistory {
+    /// Ring buffer of update records
+    /// Most recent update is at index (current_index - 1) % 100
+    pub updates: Vec<ValidatorSetUpdateRecord>,
+
+    /// Current write position in ring buffer (0-99)
+    pub current_index: u8,
+
+    /// Total number of updates recorded (can exceed 100)
+    pub total_updates: u64,
+}
+
+impl ValidatorSetHistory {
+    /// Number of historical updates to keep
+    pub const HISTORY_SIZE: usize = 100;
+
+    /// Account size for history storage
+    /// 8 (discriminator) + 4 (vec len) + (100 * record size) + 1 (index) + 8 (total)
+    pub const SIZE: usize = 8 + 4 + (Self::HISTORY_SIZE * ValidatorSetUpdateRecord::SIZE) + 1 + 8;
+
+    /// Add a new update record to the history
+    pub fn add_update(&mut self, record: ValidatorSetUpdateRecord) {
+        let index = self.current_index as usize;
+
+        if self.updates.len() < Self::HISTORY_SIZE {
+            // Still filling initial buffer
+            self.updates.push(record);
+        } else {
+            // Overwrite oldest entry
+            self.updates[index] = record;
+        }
+
+        self.current_index = ((index + 1) % Self::HISTORY_SIZE) as u8;
+        self.total_updates = self.total_updates.saturating_add(1);
+    }
+
+    /// Get the most recent update record
+    pub fn get_latest(&self) -> Option<&ValidatorSetUpdateRecord> {
+        if self.updates.is_empty() {
+            return None;
+        }
+
+        let latest_index = if self.current_index == 0 {
+            self.updates.len() - 1
+        } else {
+            (self.current_index - 1) as usize
+        };
+
+        self.updates.get(latest_index)
+    }
+
+    /// Get update record at a specific epoch
+    pub fn get_by_epoch(&self, epoch: u64) -> Option<&ValidatorSetUpdateRecord> {
+        self.updates.iter().find(|r| r.update_epoch == epoch)
+    }
+}
+
+/// Record of a single validator set update
+///
+/// Stores metadata about when and how the validator set was updated.
+/// This creates an immutable audit trail of all validator transitions.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct ValidatorSetUpdateRecord {
+    /// Epoch number for this update (monotonically increasing)
+    pub update_epoch: u64,
+
+    /// Slot when update was applied
+    pub slot: u64,
+
+    /// Unix timestamp when update occurred
+    pub timestamp: i64,
+
+    /// Hash of the new validator set for verification
+    /// sha256(sorted_validator_identities || sorted_stakes)
+    pub validator_set_hash: [u8; 32],
+
+    /// Total stake in new validator set
+    pub total_stake: u64,
+
+    /// Number of validators in new set
+    pub validator_count: u16,
+
+    /// Number of signatures that approved this update
+    pub approver_count: u16,
+
+    /// Total stake of approvers (should be ≥66% of previous set)
+    pub approver_stake: u64,
+}
+
+impl ValidatorSetUpdateRecord {
+    /// Size of a single update record in bytes
+    pub const SIZE: usize = 8 +  // update_epoch
+                            8 +  // slot
+                            8 +  // timestamp
+                            32 + // validator_set_hash
+                            8 +  // total_stake
+                            2 +  // validator_count
+                            2 +  // approver_count
+                            8;   // approver_stake
+
+    /// Create a new update record from validator set data
+    pub fn new(
+        update_epoch: u64,
+        slot: u64,
+        timestamp: i64,
+        validators: &[ValidatorInfo],
+        total_stake: u64,
+        approver_count: u16,
+        approver_stake: u64,
+    ) -> Self {
+        let validator_set_hash = Self::hash_validator_set(validators);
+
+        Self {
+            update_epoch,
+            slot,
+            timestamp,
+            validator_set_hash,
+            total_stake,
+            validator_count: validators.len() as u16,
+            approver_count,
+            approver_stake,
+        }
+    }
+
+    /// Create deterministic hash of validator set
+    /// Used to verify update signatures and track history
+    pub fn hash_validator_set(validators: &[ValidatorInfo]) -> [u8; 32] {
+        use anchor_lang::solana_program::hash::hash;
+
+        // Create sorted copy to ensure deterministic hash
+        let mut sorted_validators = validators.to_vec();
+        sorted_validators.sort_by_key(|v| v.identity);
+
+        let mut hash_data = Vec::new();
+        for validator in sorted_validators {
+            hash_data.extend_from_slice(&validator.identity.to_bytes());
+            hash_data.extend_from_slice(&validator.stake.to_le_bytes());
+        }
+
+        hash(&hash_data).to_bytes()
+    }
+
+    /// Verify this update was approved by sufficient stake
+    /// Returns true if approver_stake >= 66% of previous total stake
+    pub fn verify_consensus(&self, previous_total_stake: u64) -> Result<bool> {
+        let threshold = ValidatorSet::consensus_threshold(previous_total_stake)?;
+        Ok(self.approver_stake >= threshold)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_consensus_threshold() {
+        // Test exact 66% calculation
+        assert_eq!(ValidatorSet::consensus_threshold(300).unwrap(), 200);
+        assert_eq!(ValidatorSet::consensus_threshold(1000).unwrap(), 667);
+        assert_eq!(ValidatorSet::consensus_threshold(100).unwrap(), 67);
+
+        // Test rounding up
+        assert_eq!(ValidatorSet::consensus_threshold(10).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_validator_set_hash_deterministic() {
+        let validators = vec![
+            ValidatorInfo {
+                identity: Pubkey::new_unique(),
+                stake: 1000,
+            },
+            ValidatorInfo {
+                identity: Pubkey::new_unique(),
+                stake: 2000,
+            },
+        ];
+
+        let hash1 = ValidatorSetUpdateRecord::hash_validator_set(&validators);
+        let hash2 = ValidatorSetUpdateRecord::hash_validator_set(&validators);
+
+        assert_eq!(hash1, hash2);
+    }
+
+    #[test]
+    fn test_history_ring_buffer() {
+        let mut history = ValidatorSetHistory {
+            updates: Vec::new(),
+            current_index: 0,
+            total_updates: 0,
+        };
+
+        // Add first update
+        let record = ValidatorSetUpdateRecord {
+            update_epoch: 1,
+            slot: 100,
+            timestamp: 1234567890,
+            validator_set_hash: [0u8; 32],
+            total_stake: 1000,
+            validator_count: 10,
+            approver_count: 7,
+            approver_stake: 700,
+        };
+
+        history.add_update(record.clone());
+        assert_eq!(history.total_updates, 1);
+        assert_eq!(history.get_latest().unwrap().update_epoch, 1);
+    }
+
+    #[test]
+    fn test_x1_validator_set_history() {
+        let mut history = X1ValidatorSetHistory { records: vec![], next_index: 0, total_updates: 0, bump: 0 };
+        let record = |version: u64| X1ValidatorSetRecord {
+            version,
+            old_validators_hash: [0u8; 32],
+            new_validators_hash: [1u8; 32],
+            threshold: 3,
+            threshold_bps: 0,
+            approvers: vec![Pubkey::new_unique(); 3],
+            timestamp: 0,
+            slot: version,
+        };
+
+        for version in 1..=20 {
+            history.record(record(version));
+        }
+        assert_eq!(history.records.len(), X1ValidatorSetHistory::HISTORY_SIZE);
+        assert_eq!(history.total_updates, 20);
+        // Oldest four were overwritten
+        assert!(history.get_by_version(4).is_none());
+        assert_eq!(history.get_by_version(5).unwrap().slot, 5);
+        assert_eq!(history.get_by_version(20).unwrap().slot, 20);
+        assert_eq!(history.next_index, 4);
+
+        let validators = [Pubkey::new_unique(), Pubkey::new_unique()];
+        assert_ne!(
+            X1ValidatorSet::members_hash(&validators, &[]),
+            X1ValidatorSet::members_hash(&[validators[1], validators[0]], &[])
+        );
+        assert_ne!(
+            X1ValidatorSet::members_hash(&validators, &[]),
+            X1ValidatorSet::members_hash(&validators, &[1, 2])
+        );
+    }
+
+    #[test]
+    fn test_v2_cutoff() {
+        let mut config = BridgeConfig {
+            v2_cutoff_slot: 0,
+            nonce: 0,
+            min_validator_count: BridgeConfig::DEFAULT_MIN_VALIDATOR_COUNT,
+            min_threshold_numerator: BridgeConfig::DEFAULT_MIN_THRESHOLD_NUMERATOR,
+            min_threshold_denominator: BridgeConfig::DEFAULT_MIN_THRESHOLD_DENOMINATOR,
+            bft_mode: false,
+            bump: 255,
+        };
+
+        // No cutoff scheduled
+        assert!(!config.is_v2_deprecated(u64::MAX));
+
+        // Cutoff is inclusive
+        config.v2_cutoff_slot = 1000;
+        assert!(!config.is_v2_deprecated(999));
+        assert!(config.is_v2_deprecated(1000));
+        assert!(config.is_v2_deprecated(1001));
+    }
+
+    #[test]
+    fn test_min_threshold_floor() {
+        let mut config = BridgeConfig {
+            v2_cutoff_slot: 0,
+            nonce: 0,
+            min_validator_count: BridgeConfig::DEFAULT_MIN_VALIDATOR_COUNT,
+            min_threshold_numerator: BridgeConfig::DEFAULT_MIN_THRESHOLD_NUMERATOR,
+            min_threshold_denominator: BridgeConfig::DEFAULT_MIN_THRESHOLD_DENOMINATOR,
+            bft_mode: false,
+            bump: 255,
+        };
+
+        // ⌈2n/3⌉
+        assert_eq!(config.min_threshold(3), 2);
+        assert_eq!(config.min_threshold(4), 3);
+        assert_eq!(config.min_threshold(5), 4);
+        assert_eq!(config.min_threshold(6), 4);
+        assert_eq!(config.min_threshold(7), 5);
+
+        // Floor only: ⌈2n/3⌉ is enough
+        assert!(config.threshold_allowed(6, 4));
+        assert!(!config.threshold_allowed(5, 3));
+
+        // BFT mode: strictly more than 2/3
+        config.bft_mode = true;
+        assert!(!config.threshold_allowed(6, 4));
+        assert!(config.threshold_allowed(6, 5));
+        assert!(config.threshold_allowed(5, 4));
+    }
+
+    #[test]
+    fn test_resignation_threshold() {
+        let mut config = BridgeConfig {
+            v2_cutoff_slot: 0,
+            nonce: 0,
+            min_validator_count: BridgeConfig::DEFAULT_MIN_VALIDATOR_COUNT,
+            min_threshold_numerator: BridgeConfig::DEFAULT_MIN_THRESHOLD_NUMERATOR,
+            min_threshold_denominator: BridgeConfig::DEFAULT_MIN_THRESHOLD_DENOMINATOR,
+            bft_mode: false,
+            bump: 255,
+        };
+
+        // 5-of-7 -> 6 validators: old threshold still valid
+        assert_eq!(resignation_threshold(&config, 5, 6), Some(5));
+        // 5-of-5 -> 4 validators: clamped to the set size
+        assert_eq!(resignation_threshold(&config, 5, 4), Some(4));
+        // 4-of-6 -> 5 validators: 4 ≥ ⌈10/3⌉
+        assert_eq!(resignation_threshold(&config, 4, 5), Some(4));
+        // Below minimum validator count
+        assert_eq!(resignation_threshold(&config, 3, 3), None);
+
+        // BFT mode raises 4-of-6 to 5-of-6 (after a 7 -> 6 resignation)
+        config.bft_mode = true;
+        assert_eq!(resignation_threshold(&config, 4, 6), Some(5));
+    }
+
+    #[test]
+    fn test_latency_percentiles() {
+        let mut latency = ValidatorLatency::default();
+        assert_eq!(latency.percentile(5000), None);
+
+        // 8 fast samples, 1 slow, 1 very slow
+        for _ in 0..8 {
+            latency.record(20);
+        }
+        latency.record(250);
+        latency.record(5000);
+
+        assert_eq!(latency.samples, 10);
+        assert_eq!(latency.max_seconds, 5000);
+        assert_eq!(latency.percentile(5000), Some(30));
+        assert_eq!(latency.percentile(9000), Some(300));
+        assert_eq!(latency.percentile(9900), Some(u64::MAX));
+    }
+
+    #[test]
+    fn test_latency_stats_prunes_removed_validators() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let mut stats = ValidatorLatencyStats { entries: vec![], bump: 255 };
+
+        stats.record(a, 10, &[a]);
+        stats.record(a, 10, &[a]);
+        assert_eq!(stats.entries.len(), 1);
+        assert_eq!(stats.entries[0].samples, 2);
+
+        // a left the set: its entry is dropped when b is first recorded
+        stats.record(b, 10, &[b]);
+        assert_eq!(stats.entries.len(), 1);
+        assert_eq!(stats.entries[0].validator, b);
+    }
+
+    #[test]
+    fn test_fee_shares_follow_participation() {
+        let a = Pubkey::new_unique();
+        let b = Pubkey::new_unique();
+        let c = Pubkey::new_unique();
+        let set = [a, b, c];
+        let mut stats = ValidatorLatencyStats { entries: vec![], bump: 255 };
+
+        // No samples yet: equal split
+        assert_eq!(stats.fee_shares(30_000_000, &set), vec![10_000_000; 3]);
+
+        // a attested 4 times, b twice, c never
+        for _ in 0..4 {
+            stats.record(a, 10, &set);
+        }
+        stats.record(b, 10, &set);
+        stats.record(b, 10, &set);
+
+        // Weights 10_000 / 6_250 / 2_500 bps
+        let shares = stats.fee_shares(30_000_000, &set);
+        assert_eq!(shares, vec![16_000_000, 10_000_000, 4_000_000]);
+        assert_eq!(shares.iter().sum::<u64>(), 30_000_000);
+
+        // Dust goes to the most active validator
+        let shares = stats.fee_shares(11, &[c, a]);
+        assert_eq!(shares, vec![2, 9]);
+        assert!(stats.fee_shares(10, &[]).is_empty());
+    }
+
+    #[test]
+    fn test_restored_version_invalidates_old_signatures() {
+        assert_eq!(restored_version(5, 7), Some(8));
+        assert_eq!(restored_version(9, 7), Some(10));
+        assert_eq!(restored_version(u64::MAX, 1), None);
+    }
+
+    #[test]
+    fn test_weakens_safety() {
+        // Same or stronger
+        assert!(!weakens_safety(5, 3, 5, 3));
+        assert!(!weakens_safety(5, 3, 5, 4));
+        assert!(!weakens_safety(5, 3, 7, 5));
+
+        // Fewer signatures, smaller set, or lower fraction
+        assert!(weakens_safety(5, 4, 5, 3));
+        assert!(weakens_safety(7, 5, 6, 5));
+        assert!(weakens_safety(5, 4, 7, 5));
+    }
+
+    #[test]
+    fn test_conversion_rate_math() {
+        let rate = ConversionRate {
+            from_asset: 1,
+            to_asset: 2,
+            numerator: 3,
+            denominator: 2,
+            enabled: true,
+        };
+
+        assert_eq!(rate.convert(1_000_000), Some(1_500_000));
+        assert_eq!(rate.convert(1), Some(1)); // 1.5 rounds down
+        assert_eq!(rate.convert(0), None);    // Nothing to mint
+
+        // u128 widening: no intermediate overflow, result must fit u64
+        let wide = ConversionRate { numerator: u64::MAX, denominator: u64::MAX, ..rate };
+        assert_eq!(wide.convert(u64::MAX), Some(u64::MAX));
+        let overflow = ConversionRate { numerator: 2, denominator: 1, ..rate };
+        assert_eq!(overflow.convert(u64::MAX), None);
+    }
+
+    #[test]
+    fn test_conversion_table_upsert() {
+        let mut table = ConversionTable { rates: Vec::new(), bump: 255 };
+        let rate = ConversionRate {
+            from_asset: 1,
+            to_asset: 2,
+            numerator: 1,
+            denominator: 1,
+            enabled: true,
+        };
+
+        assert!(table.upsert(rate));
+        assert!(table.upsert(ConversionRate { numerator: 2, ..rate }));
+        assert_eq!(table.rates.len(), 1);
+        assert_eq!(table.rate(1, 2).unwrap().numerator, 2);
+
+        // Directed: 2 -> 1 is a separate pair
+        assert!(table.rate(2, 1).is_none());
+
+        // Disabled pairs are not returned
+        assert!(table.upsert(ConversionRate { enabled: false, ..rate }));
+        assert!(table.rate(1, 2).is_none());
+
+        // Capacity is bounded
+        for to_asset in 3..(3 + MAX_CONVERSION_RATES as u8 - 1) {
+            assert!(table.upsert(ConversionRate { to_asset, ..rate }));
+        }
+        assert!(!table.upsert(ConversionRate { to_asset: 200, ..rate }));
+    }
+
+    #[test]
+    fn test_solana_chain_state_updates() {
+        let state = SolanaChainState {
+            epoch: 700,
+            total_stake: 400_000_000_000_000_000,
+            solana_slot: 302_400_000,
+            updated_slot: 1_000,
+            updated_at: 1_700_000_000,
+            bump: 255,
+        };
+
+        // Slot must strictly advance, epoch must not regress
+        assert!(state.accepts_update(700, 302_400_001));
+        assert!(state.accepts_update(701, 302_832_000));
+        assert!(!state.accepts_update(700, 302_400_000));
+        assert!(!state.accepts_update(699, 302_400_001));
+
+        // Freshness is measured in X1 slots since the last update
+        assert!(state.is_fresh(1_100, 100));
+        assert!(!state.is_fresh(1_101, 100));
+    }
+
+    #[test]
+    fn test_burn_archive_root_matches_full_tree() {
+        use anchor_lang::solana_program::hash::hashv;
+
+        let mut archive = BurnArchive {
+            asset_id: 1,
+            period: 0,
+            leaf_count: 0,
+            total_amount: 0,
+            root: [0u8; 32],
+            frontier: [[0u8; 32]; ARCHIVE_TREE_DEPTH],
+            bump: 255,
+        };
+        let leaves: Vec<[u8; 32]> = (0..5u8).map(|i| [i + 1; 32]).collect();
+
+        for (i, leaf) in leaves.iter().enumerate() {
+            assert_eq!(archive.append(*leaf), Some(i as u64));
+
+            // Naive root: zero-pad to 2^depth leaves and hash level by level
+            let mut level = vec![[0u8; 32]; 1 << ARCHIVE_TREE_DEPTH];
+            level[..=i].copy_from_slice(&leaves[..=i]);
+            while level.len() > 1 {
+                level = level.chunks(2).map(|pair| hashv(&[&pair[0], &pair[1]]).to_bytes()).collect();
+            }
+            assert_eq!(archive.root, level[0]);
+        }
+
+        // Last leaf slot is reserved (no frontier slot to hold it)
+        archive.leaf_count = (1 << ARCHIVE_TREE_DEPTH) - 1;
+        assert_eq!(archive.append([9u8; 32]), None);
+    }
+
+    #[test]
+    fn test_legacy_validator_set_space() {
+        let set = X1ValidatorSet {
+            version: 7,
+            validators: (0..10).map(|_| Pubkey::new_unique()).collect(),
+            threshold: 7,
+            bump: 255,
+            max_attestation_age: 0,
+            weights: vec![],
+            threshold_bps: 0,
+            ordered_attestations: false,
+        };
+        let mut data = Vec::new();
+        set.try_serialize(&mut data).unwrap();
+
+        // A full set's original fields end exactly at LEGACY_SPACE, so the
+        // appended ones need the account to grow (to at most INIT_SPACE)
+        let appended = 8 + 4 + 2 + 1;
+        assert_eq!(data.len() - appended, X1ValidatorSet::LEGACY_SPACE);
+        assert!(data.len() <= 8 + X1ValidatorSet::INIT_SPACE);
+    }
+
+    #[test]
+    fn test_attestation_age_window() {
+        let mut set = X1ValidatorSet { version: 1, validators: vec![], threshold: 1, bump: 255, max_attestation_age: 0, weights: vec![], threshold_bps: 0, ordered_attestations: false };
+        let now = 1_700_000_000;
+        let day = X1ValidatorSet::DEFAULT_MAX_ATTESTATION_AGE;
+
+        // 0 (also what migrate_validator_set writes) means the default
+        assert_eq!(set.effective_max_attestation_age(), day);
+        assert!(set.check_attestation_age(now - day, now).is_ok());
+        assert!(set.check_attestation_age(now - day - 1, now).is_err());
+
+        // Small clock skew is tolerated, a future timestamp is not
+        assert!(set.check_attestation_age(now + X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW, now).is_ok());
+        assert!(set.check_attestation_age(now + X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW + 1, now).is_err());
+
+        set.max_attestation_age = 600;
+        assert!(set.check_attestation_age(now - 600, now).is_ok());
+        assert!(set.check_attestation_age(now - 601, now).is_err());
+        assert!(set.check_attestation_age(i64::MIN, now).is_err());
+    }
+
+    #[test]
+    fn test_weighted_threshold() {
+        let validators: Vec<Pubkey> = (0..5).map(|_| Pubkey::new_unique()).collect();
+        let mut set = X1ValidatorSet {
+            version: 1,
+            validators: validators.clone(),
+            threshold: 3,
+            bump: 255,
+            max_attestation_age: 0,
+            weights: vec![],
+            threshold_bps: 0,
+            ordered_attestations: false,
+        };
+
+        // Unweighted (also what migrate_validator_set writes): count threshold only
+        assert_eq!(set.total_weight(), 5);
+        assert!(set.weight_threshold_met(&validators[3..]));
+        assert_eq!(set.weight_of(&Pubkey::new_unique()), 0);
+
+        // 4 + 1 + 1 + 1 + 1 = 8, 60% = 4.8
+        set.weights = vec![4, 1, 1, 1, 1];
+        set.threshold_bps = 6_000;
+        assert_eq!(set.total_weight(), 8);
+        assert!(!set.weight_threshold_met(&validators[1..]));
+        assert!(set.weight_threshold_met(&[validators[0], validators[1]]));
+        // Outsiders and repeats are the caller's to reject, but outsiders weigh nothing
+        assert!(!set.weight_threshold_met(&[validators[1], validators[2], Pubkey::new_unique()]));
+
+        // Exactly on the threshold passes: 50% of 8 = 4
+        set.threshold_bps = 5_000;
+        assert!(set.weight_threshold_met(&[validators[0]]));
+
+        // Removing a validator drops its weight
+        set.remove_validator(0);
+        assert_eq!(set.weights, vec![1, 1, 1, 1]);
+        assert_eq!(set.total_weight(), 4);
+
+        assert!(X1ValidatorSet::weights_valid(&validators, &[], 0));
+        assert!(X1ValidatorSet::weights_valid(&validators, &[1, 2, 3, 4, 5], 10_000));
+        assert!(!X1ValidatorSet::weights_valid(&validators, &[1, 2, 3, 4, 5], 0));
+        assert!(!X1ValidatorSet::weights_valid(&validators, &[1, 2, 3, 4, 5], 10_001));
+        assert!(!X1ValidatorSet::weights_valid(&validators, &[1, 2, 3, 4], 6_667));
+        assert!(!X1ValidatorSet::weights_valid(&validators, &[1, 2, 0, 4, 5], 6_667));
+    }
+
+    #[test]
+    fn test_ordered_index() {
+        let validators: Vec<Pubkey> = (0..4).map(|_| Pubkey::new_unique()).collect();
+        let set = X1ValidatorSet {
+            version: 1,
+            validators: validators.clone(),
+            threshold: 2,
+            bump: 255,
+            max_attestation_age: 0,
+            weights: vec![],
+            threshold_bps: 0,
+            ordered_attestations: true,
+        };
+
+        assert_eq!(set.ordered_index(&validators[2], None), Ok(2));
+        assert_eq!(set.ordered_index(&validators[0], None), Ok(0));
+        assert_eq!(set.ordered_index(&validators[3], Some(1)), Ok(3));
+        assert_eq!(set.ordered_index(&validators[1], Some(1)), Err(AttestationCheckCode::DuplicateValidator));
+        assert_eq!(set.ordered_index(&validators[0], Some(1)), Err(AttestationCheckCode::OutOfOrder));
+        assert_eq!(set.ordered_index(&Pubkey::new_unique(), Some(1)), Err(AttestationCheckCode::UnknownValidator));
+    }
+
+    #[test]
+    fn test_usage_snapshot_proofs() {
+        use anchor_lang::solana_program::hash::hashv;
+
+        let users: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let leaves: Vec<[u8; 32]> = users.iter().enumerate()
+            .map(|(i, user)| usage_leaf(7, user, 1, 1_000 * (i as u64 + 1)))
+            .collect();
+
+        // Build level by level, padding odd levels with that height's zero hash
+        let mut levels = vec![leaves.clone()];
+        let mut zero = [0u8; 32];
+        for _ in 0..USAGE_TREE_DEPTH {
+            let mut level = levels.last().unwrap().clone();
+            if level.len() % 2 == 1 {
+                level.push(zero);
+            }
+            levels.push(level.chunks(2).map(|pair| hashv(&[&pair[0], &pair[1]]).to_bytes()).collect());
+            zero = hashv(&[&zero, &zero]).to_bytes();
+        }
+        let proof = |index: usize| -> Vec<[u8; 32]> {
+            let mut zero = [0u8; 32];
+            (0..USAGE_TREE_DEPTH).map(|height| {
+                let sibling = levels[height].get((index >> height) ^ 1).copied().unwrap_or(zero);
+                zero = hashv(&[&zero, &zero]).to_bytes();
+                sibling
+            }).collect()
+        };
+
+        let snapshot = UsageSnapshot {
+            epoch: 7,
+            root: levels[USAGE_TREE_DEPTH][0],
+            leaf_count: 3,
+            committed_slot: 0,
+            bump: 255,
+        };
+        for (index, leaf) in leaves.iter().enumerate() {
+            assert!(snapshot.verify(*leaf, index as u64, &proof(index)));
+        }
+
+        // Wrong volume, wrong index, short proof, index past the leaves
+        assert!(!snapshot.verify(usage_leaf(7, &users[0], 1, 1_001), 0, &proof(0)));
+        assert!(!snapshot.verify(leaves[0], 1, &proof(0)));
+        assert!(!snapshot.verify(leaves[0], 0, &proof(0)[1..]));
+        assert!(!snapshot.verify([0u8; 32], 3, &proof(3)));
+    }
+
+    #[test]
+    fn test_retention_window() {
+        let period = crate::config::ARCHIVE_PERIOD_SLOTS;
+        let retention = crate::config::BURN_RETENTION_PERIODS;
+
+        // Counted in whole periods from the burn's period
+        assert!(!retention_elapsed(period - 1, (retention - 1) * period + period - 1));
+        assert!(retention_elapsed(period - 1, retention * period));
+        assert!(!retention_elapsed(period, retention * period));
+        assert!(!retention_elapsed(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_freeze_quorum() {
+        // More than a third of the set
+        assert_eq!(freeze_quorum(5, 3), 2);
+        assert_eq!(freeze_quorum(7, 5), 3);
+        assert_eq!(freeze_quorum(9, 6), 4);
+
+        // Capped at the mint threshold, never zero
+        assert_eq!(freeze_quorum(10, 2), 2);
+        assert_eq!(freeze_quorum(3, 1), 1);
+        assert_eq!(freeze_quorum(0, 0), 1);
+    }
+
+    #[test]
+    fn test_redirect_allowed() {
+        let delay = crate::config::BURN_REDIRECT_DELAY_SLOTS;
+
+        assert!(!redirect_allowed(1_000, 1_000 + delay - 1));
+        assert!(redirect_allowed(1_000, 1_000 + delay));
+        // Burns verified before verified_slot was recorded (0)
+        assert!(redirect_allowed(0, delay));
+        assert!(!redirect_allowed(u64::MAX, u64::MAX));
+    }
+
+    #[test]
+    fn test_bridge_capabilities() {
+        assert_eq!(parse_semver("0.1.0"), [0, 1, 0]);
+        assert_eq!(parse_semver("3.10.2-beta.1"), [3, 10, 2]);
+        assert_eq!(parse_semver("2"), [2, 0, 0]);
+
+        let mut config = BridgeConfig {
+            v2_cutoff_slot: 1000,
+            nonce: 0,
+            min_validator_count: BridgeConfig::DEFAULT_MIN_VALIDATOR_COUNT,
+            min_threshold_numerator: BridgeConfig::DEFAULT_MIN_THRESHOLD_NUMERATOR,
+            min_threshold_denominator: BridgeConfig::DEFAULT_MIN_THRESHOLD_DENOMINATOR,
+            bft_mode: false,
+            bump: 255,
+        };
+        let validator_set = X1ValidatorSet {
+            version: 7,
+            validators: vec![Pubkey::new_unique(); 5],
+            threshold: 3,
+            bump: 255,
+            max_attestation_age: 0,
+            weights: vec![],
+            threshold_bps: 0,
+            ordered_attestations: false,
+        };
+
+        // Before the cutoff V2 is still offered
+        let before = BridgeCapabilities::current(&config, &validator_set, 999);
+        assert_eq!(before.attestation_versions, vec![ATTESTATION_MESSAGE_V2, ATTESTATION_MESSAGE_V3]);
+        assert_ne!(before.features & features::V2_SUBMISSIONS, 0);
+        assert_eq!(before.features & features::BFT_MODE, 0);
+        assert_eq!(before.assets.len(), Asset::ALL.len());
+        assert_eq!((before.validator_set_version, before.validator_count, before.threshold), (7, 5, 3));
+
+        // After it only V3, and BFT mode shows up when enabled
+        config.bft_mode = true;
+        let after = BridgeCapabilities::current(&config, &validator_set, 1000);
+        assert_eq!(after.attestation_versions, vec![ATTESTATION_MESSAGE_V3]);
+        assert_eq!(after.features & features::V2_SUBMISSIONS, 0);
+        assert_ne!(after.features & features::BFT_MODE, 0);
+        assert_eq!(after.features & features::STATIC, features::STATIC);
+    }
+
+    #[test]
+    fn test_rent_sponsor_pool_limits() {
+        let mut pool = RentSponsorPool {
+            max_burn_amount: 0,
+            total_funded: 0,
+            total_sponsored: 0,
+            total_reimbursed: 0,
+            outstanding: 0,
+            bump: 255,
+        };
+        // 0 = sponsorship off
+        assert!(!pool.sponsors(1));
+
+        pool.max_burn_amount = 1_000_000;
+        assert!(pool.sponsors(1_000_000));
+        assert!(!pool.sponsors(1_000_001));
+
+        // Never lend the pool's own rent
+        assert_eq!(RentSponsorPool::available(5_000_000, 1_200_000), 3_800_000);
+        assert_eq!(RentSponsorPool::available(1_000_000, 1_200_000), 0);
+    }
+
+    #[test]
+    fn test_degraded_mode_config() {
+        let delay = crate::config::MIN_DEGRADED_DELAY_SECONDS;
+        assert!(DegradedMode::is_valid_config(2, 3, 1_000_000, 10_000_000, delay));
+        // The quorum must be reduced, but never to a single validator
+        assert!(!DegradedMode::is_valid_config(3, 3, 1_000_000, 10_000_000, delay));
+        assert!(!DegradedMode::is_valid_config(1, 3, 1_000_000, 10_000_000, delay));
+        assert!(!DegradedMode::is_valid_config(2, 3, 0, 10_000_000, delay));
+        assert!(!DegradedMode::is_valid_config(2, 3, 20_000_000, 10_000_000, delay));
+        assert!(!DegradedMode::is_valid_config(2, 3, 1_000_000, 10_000_000, delay - 1));
+    }
+
+    #[test]
+    fn test_degraded_mode_caps() {
+        let day = DegradedMode::SECONDS_PER_DAY;
+        let mut mode = DegradedMode {
+            asset_id: 1,
+            enabled: true,
+            quorum: 2,
+            max_transfer_amount: 1_000_000,
+            daily_cap: 2_500_000,
+            delay_seconds: crate::config::MIN_DEGRADED_DELAY_SECONDS,
+            day: 0,
+            day_total: 0,
+            total_burns: 0,
+            bump: 255,
+        };
+        let now = 20_000 * day + 100;
+
+        assert!(mode.admit(1, 1_000, now).is_err());
+        assert!(mode.admit(2, 1_000_001, now).is_err());
+        assert!(mode.admit(2, 1_000_000, now).is_ok());
+        assert!(mode.admit(3, 1_000_000, now + 60).is_ok());
+        // 2.5M daily: a third full transfer would exceed it, a small one fits
+        assert!(mode.admit(2, 1_000_000, now + 120).is_err());
+        assert!(mode.admit(2, 500_000, now + 120).is_ok());
+        assert_eq!(mode.day_total, 2_500_000);
+        assert_eq!(mode.total_burns, 3);
+
+        // Next UTC day starts from zero
+        assert!(mode.admit(2, 1_000_000, now + day).is_ok());
+        assert_eq!(mode.day_total, 1_000_000);
+
+        mode.enabled = false;
+        assert!(mode.admit(2, 1, now + day).is_err());
     }
 
-    /// Verify a list of validators holds sufficient stake for consensus
-    /// Returns (verified_stake, is_sufficient)
-    pub fn verify_stake_threshold(
-        &self,
-        validator_identities: &[Pubkey],
-        total_stake: u64,
-    ) -> Result<(u64, bool)> {
-        let mut verified_stake: u64 = 0;
+    #[test]
+    fn test_sweep_bounty_payable() {
+        let pool = SweepBountyPool { bounty_lamports: 50_000, total_funded: 0, total_paid: 0, swept: 0, bump: 255 };
+        assert_eq!(pool.payable(10_000_000, 1_000_000), 50_000);
+        // Pays what is left above its own rent, never the rent itself
+        assert_eq!(pool.payable(1_020_000, 1_000_000), 20_000);
+        assert_eq!(pool.payable(900_000, 1_000_000), 0);
 
-        for identity in validator_identities {
-            if let Some(validator) = self.find_validator(identity) {
-                verified_stake = verified_stake
-                    .checked_add(validator.stake)
-                    .ok_or(error!(crate::errors::LightClientError::ArithmeticOverflow))?;
-            }
-        }
+        let off = SweepBountyPool { bounty_lamports: 0, ..pool };
+        assert_eq!(off.payable(10_000_000, 1_000_000), 0);
 
-        let threshold = Self::consensus_threshold(total_stake)?;
-        Ok((verified_stake, verified_stake >= threshold))
+        // Minted after verification earns it; re-created after the mint does not
+        assert!(SweepBountyPool::pays_for(1_700_000_000, 1_700_000_000));
+        assert!(SweepBountyPool::pays_for(1_700_000_000, 1_700_000_060));
+        assert!(!SweepBountyPool::pays_for(1_700_000_061, 1_700_000_060));
     }
-}
 
-/// Individual validator information
-///
-/// Stored in a compact format to minimize space usage.
-/// Only essential data for verification is stored.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug, PartialEq)]
-pub struct ValidatorInfo {
-    /// Validator's identity pubkey (vote account or node identity)
-    pub identity: Pubkey,
+    #[test]
+    fn test_feature_gate_bits() {
+        let gate = FeatureGate { disabled: 0, updated_slot: 0, bump: 255 };
+        assert!(gate.is_enabled(gates::ATTEST_V3));
 
-    /// Validator's stake amount in lamports
-    /// Used for weighted sampling and threshold calculations
-    pub stake: u64,
-}
+        let gate = FeatureGate { disabled: gates::MINT_STEALTH | gates::ATTEST_V2, updated_slot: 0, bump: 255 };
+        assert!(!gate.is_enabled(gates::MINT_STEALTH));
+        assert!(!gate.is_enabled(gates::ATTEST_V2));
+        assert!(gate.is_enabled(gates::ATTEST_V3));
+        assert!(gate.is_enabled(gates::MINT_V3));
+        // Bits unknown to this build are ignored by the gates it checks
+        let gate = FeatureGate { disabled: 1 << 63, updated_slot: 0, bump: 255 };
+        assert!(gate.is_enabled(gates::MINT_COMPRESSED));
 
-impl ValidatorInfo {
-    /// Size of a single validator entry in bytes
-    pub const SIZE: usize = 32 + 8; // pubkey + stake
-}
+        // ...except the pause bit, checked on its own by require_enabled
+        assert!(gate.is_paused());
+        let gate = FeatureGate { disabled: gates::MINT_STEALTH, updated_slot: 0, bump: 255 };
+        assert!(!gate.is_paused());
+    }
 
-/// Validator set update history entry
-///
-/// Maintains a rolling history of validator set updates to:
-/// 1. Prove chain of trust from genesis
-/// 2. Allow verification of historical proofs
-/// 3. Debug and audit validator set transitions
-///
-/// We keep last 100 updates in a ring buffer for space efficiency.
-#[account]
-pub struct ValidatorSetHistory {
-    /// Ring buffer of update records
-    /// Most recent update is at index (current_index - 1) % 100
-    pub updates: Vec<ValidatorSetUpdateRecord>,
+    #[test]
+    fn test_bridge_domain_validation() {
+        assert!(BridgeDomain::is_valid_domain(crate::DOMAIN_SEPARATOR));
+        assert!(BridgeDomain::is_valid_domain("XENCAT_X1_BRIDGE_DEVNET_V1"));
+        assert!(!BridgeDomain::is_valid_domain(""));
+        assert!(!BridgeDomain::is_valid_domain("xencat_x1_bridge_v1"));
+        assert!(!BridgeDomain::is_valid_domain("XENCAT_X1_BRIDGE_V1\0"));
+        assert!(!BridgeDomain::is_valid_domain(" XENCAT_X1_BRIDGE_V1"));
+        assert!(!BridgeDomain::is_valid_domain(&"A".repeat(MAX_BRIDGE_DOMAIN_LEN + 1)));
+    }
 
-    /// Current write position in ring buffer (0-99)
-    pub current_index: u8,
+    #[test]
+    fn test_mint_acknowledgement_hash() {
+        let ack = MintAcknowledgement::new(1, 1234, 5_000_000, Pubkey::new_from_array([9u8; 32]), 42);
+        assert_eq!(ack.transfer_id, transfer_id(TRANSFER_SOURCE_SOLANA, 1, 1234));
+        assert_ne!(ack.transfer_id, transfer_id(TRANSFER_SOURCE_SOLANA, 2, 1234));
+        assert_ne!(ack.transfer_id, transfer_id(b"x1", 1, 1234));
 
-    /// Total number of updates recorded (can exceed 100)
-    pub total_updates: u64,
-}
+        // Pinned: the SDK and reverse-direction validators hash the same bytes
+        assert_eq!(
+            Pubkey::new_from_array(ack.hash()).to_string(),
+            "9N6Kn8AKXLPGANuaR3ceNX5ZZGp4qkmbW8UN7WGhyoP1"
+        );
+        assert_ne!(ack.hash(), MintAcknowledgement { amount: 5_000_001, ..ack }.hash());
+        assert_ne!(ack.hash(), MintAcknowledgement { recipient: Pubkey::new_unique(), ..ack }.hash());
+        assert_ne!(ack.hash(), MintAcknowledgement { slot: 43, ..ack }.hash());
+    }
 
-impl ValidatorSetHistory {
-    /// Number of historical updates to keep
-    pub const HISTORY_SIZE: usize = 100;
+    #[test]
+    fn test_return_burn_parse_and_message() {
+        let burn = ReturnBurn {
+            nonce: 7,
+            user: Pubkey::new_from_array([3u8; 32]),
+            solana_recipient: Pubkey::new_from_array([4u8; 32]),
+            amount: 2_500_000,
+            slot: 99,
+        };
 
-    /// Account size for history storage
-    /// 8 (discriminator) + 4 (vec len) + (100 * record size) + 1 (index) + 8 (total)
-    pub const SIZE: usize = 8 + 4 + (Self::HISTORY_SIZE * ValidatorSetUpdateRecord::SIZE) + 1 + 8;
+        let mut data = anchor_lang::solana_program::hash::hash(b"account:ReturnBurnRecord").to_bytes()[..8].to_vec();
+        data.extend_from_slice(&burn.nonce.to_le_bytes());
+        data.extend_from_slice(burn.user.as_ref());
+        data.extend_from_slice(burn.solana_recipient.as_ref());
+        data.extend_from_slice(&burn.amount.to_le_bytes());
+        data.extend_from_slice(&burn.slot.to_le_bytes());
+        data.extend_from_slice(&1_700_000_000i64.to_le_bytes());
+        data.push(254);
+        assert_eq!(ReturnBurn::parse(&data), Some(burn));
 
-    /// Add a new update record to the history
-    pub fn add_update(&mut self, record: ValidatorSetUpdateRecord) {
-        let index = self.current_index as usize;
+        // Any other account type (e.g. ProcessedBurnV3) is rejected
+        data[0] ^= 1;
+        assert_eq!(ReturnBurn::parse(&data), None);
+        assert_eq!(ReturnBurn::parse(&data[..ReturnBurn::LEN - 1]), None);
 
-        if self.updates.len() < Self::HISTORY_SIZE {
-            // Still filling initial buffer
-            self.updates.push(record);
-        } else {
-            // Overwrite oldest entry
-            self.updates[index] = record;
-        }
+        let domain = crate::DOMAIN_SEPARATOR.as_bytes();
+        let message = return_attestation_message(domain, 1, 1, &burn);
+        assert_ne!(message, return_attestation_message(domain, 2, 1, &burn));
+        assert_ne!(message, return_attestation_message(domain, 1, 2, &burn));
+        assert_ne!(message, return_attestation_message(domain, 1, 1, &ReturnBurn { solana_recipient: Pubkey::new_unique(), ..burn }));
+        assert_ne!(message, return_attestation_message(domain, 1, 1, &ReturnBurn { amount: 2_500_001, ..burn }));
+    }
 
-        self.current_index = ((index + 1) % Self::HISTORY_SIZE) as u8;
-        self.total_updates = self.total_updates.saturating_add(1);
+    #[test]
+    fn test_relayer_claim_message() {
+        let domain = crate::DOMAIN_SEPARATOR.as_bytes();
+        let user = Pubkey::new_from_array([9u8; 32]);
+        let message = relayer_claim_message(domain, 1, 1234, &user, 50_000);
+
+        // Pinned: validators and conformance/ hash the same bytes
+        assert_eq!(Pubkey::new_from_array(message).to_string(), "58PVq8rEXAzRVtnXERmHApfJ2Q18Gcsz6U6Dt4pjdjPL");
+        assert_ne!(message, relayer_claim_message(domain, 2, 1234, &user, 50_000));
+        assert_ne!(message, relayer_claim_message(domain, 1, 1235, &user, 50_000));
+        assert_ne!(message, relayer_claim_message(domain, 1, 1234, &Pubkey::new_unique(), 50_000));
+        assert_ne!(message, relayer_claim_message(domain, 1, 1234, &user, 50_001));
     }
 
-    /// Get the most recent update record
-    pub fn get_latest(&self) -> Option<&ValidatorSetUpdateRecord> {
-        if self.updates.is_empty() {
-            return None;
-        }
+    #[test]
+    fn test_relayer_claim_blocks_plain_mint() {
+        let key = Pubkey::new_unique();
+        let owner = crate::ID;
+        let mut lamports = 0;
+        let mut data = vec![];
+        let unclaimed = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert!(RelayerClaim::require_unclaimed(&unclaimed).is_ok());
 
-        let latest_index = if self.current_index == 0 {
-            self.updates.len() - 1
-        } else {
-            (self.current_index - 1) as usize
+        // After submit_burn_attestation_for_recipient the recipient cannot
+        // take the plain path and skip the relayer fee
+        let mut data = Vec::new();
+        RelayerClaim { relayer: Pubkey::new_unique(), relayer_fee: 50_000, bump: 255 }
+            .try_serialize(&mut data)
+            .unwrap();
+        let mut lamports = 1;
+        let claimed = AccountInfo::new(&key, false, false, &mut lamports, &mut data, &owner, false, 0);
+        assert_eq!(
+            RelayerClaim::require_unclaimed(&claimed).unwrap_err(),
+            crate::errors::LightClientError::BurnClaimedByRelayer.into()
+        );
+    }
+
+    #[test]
+    fn test_deposit_webhook_filters() {
+        let webhook = DepositWebhook {
+            recipient: Pubkey::new_unique(),
+            url_hash: [7u8; 32],
+            asset_mask: 0,
+            min_amount: 0,
+            updated_slot: 0,
+            bump: 255,
         };
+        assert!(webhook.wants(1, 1));
+        assert!(webhook.wants(2, 0));
 
-        self.updates.get(latest_index)
+        // DGN only, at least 1_000
+        let webhook = DepositWebhook { asset_mask: 1 << 2, min_amount: 1_000, ..webhook };
+        assert!(webhook.wants(2, 1_000));
+        assert!(!webhook.wants(2, 999));
+        assert!(!webhook.wants(1, 5_000));
+        // Asset ids past the mask width are never selected by a non-empty mask
+        assert!(!webhook.wants(8, 5_000));
     }
 
-    /// Get update record at a specific epoch
-    pub fn get_by_epoch(&self, epoch: u64) -> Option<&ValidatorSetUpdateRecord> {
-        self.updates.iter().find(|r| r.update_epoch == epoch)
+    #[test]
+    fn test_event_schema_versions_only_increase() {
+        let light_client = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut registry = EventSchemaRegistry { schemas: vec![], bump: 255 };
+        assert_eq!(registry.version_of(&light_client), 0);
+
+        registry.set_version(light_client, 1, 10).unwrap();
+        registry.set_version(mint, 3, 11).unwrap();
+        registry.set_version(light_client, 2, 12).unwrap();
+        assert_eq!(registry.version_of(&light_client), 2);
+        assert_eq!(registry.version_of(&mint), 3);
+        assert_eq!(registry.schemas.len(), 2);
+        assert_eq!(registry.schemas[0].updated_slot, 12);
+
+        // Same or lower version is rejected
+        assert!(registry.set_version(mint, 3, 13).is_err());
+        assert!(registry.set_version(light_client, 1, 13).is_err());
+        assert!(registry.set_version(Pubkey::new_unique(), 0, 13).is_err());
+
+        for _ in 2..MAX_EVENT_SCHEMAS {
+            registry.set_version(Pubkey::new_unique(), 1, 14).unwrap();
+        }
+        assert!(registry.set_version(Pubkey::new_unique(), 1, 15).is_err());
     }
-}
 
-/// Record of a single validator set update
-///
-/// Stores metadata about when and how the validator set was updated.
-/// This creates an immutable audit trail of all validator transitions.
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
-pub struct ValidatorSetUpdateRecord {
-    /// Epoch number for this update (monotonically increasing)
-    pub update_epoch: u64,
+    #[test]
+    fn test_asset_registry_register_and_deactivate() {
+        let mut registry = AssetRegistry { assets: AssetRegistry::builtin(1), bump: 255 };
+        assert_eq!(registry.get(1).unwrap().mint_program, crate::XENCAT_MINT_PROGRAM_ID);
+        assert_eq!(registry.get(2).unwrap().mint_program, crate::DGN_MINT_PROGRAM_ID);
 
-    /// Slot when update was applied
-    pub slot: u64,
+        let mint = Pubkey::new_unique();
+        registry.register(3, mint, 10).unwrap();
+        assert_eq!(registry.get(3), Some(&RegisteredAsset { asset_id: 3, mint_program: mint, active: true, updated_slot: 10, frozen: false }));
 
-    /// Unix timestamp when update occurred
-    pub timestamp: i64,
+        // Ids are never reused, active or not
+        assert!(registry.register(3, mint, 11).is_err());
+        assert!(registry.register(1, mint, 11).is_err());
+        assert!(registry.register(0, mint, 11).is_err());
+        assert!(registry.register(4, Pubkey::default(), 11).is_err());
 
-    /// Hash of the new validator set for verification
-    /// sha256(sorted_validator_identities || sorted_stakes)
-    pub validator_set_hash: [u8; 32],
+        registry.deactivate(3, 12).unwrap();
+        assert!(!registry.get(3).unwrap().active);
+        assert!(registry.deactivate(3, 13).is_err());
+        assert!(registry.deactivate(9, 13).is_err());
 
-    /// Total stake in new validator set
-    pub total_stake: u64,
+        // Reactivation only with the same mint program
+        assert!(registry.register(3, Pubkey::new_unique(), 14).is_err());
+        registry.register(3, mint, 14).unwrap();
+        assert!(registry.get(3).unwrap().active);
 
-    /// Number of validators in new set
-    pub validator_count: u16,
+        for asset_id in 4..(MAX_REGISTERED_ASSETS as u8 + 1) {
+            registry.register(asset_id, Pubkey::new_unique(), 15).unwrap();
+        }
+        assert!(registry.register(100, Pubkey::new_unique(), 16).is_err());
+    }
 
-    /// Number of signatures that approved this update
-    pub approver_count: u16,
+    #[test]
+    fn test_asset_registry_freeze() {
+        let mut registry = AssetRegistry { assets: AssetRegistry::builtin(1), bump: 255 };
 
-    /// Total stake of approvers (should be ≥66% of previous set)
-    pub approver_stake: u64,
-}
+        registry.set_frozen(2, true, 5).unwrap();
+        assert!(registry.get(2).unwrap().frozen);
+        assert_eq!(registry.get(2).unwrap().updated_slot, 5);
+        assert!(!registry.get(1).unwrap().frozen);
+        assert!(registry.set_frozen(2, true, 6).is_err());
+        assert!(registry.set_frozen(9, true, 6).is_err());
 
-impl ValidatorSetUpdateRecord {
-    /// Size of a single update record in bytes
-    pub const SIZE: usize = 8 +  // update_epoch
-                            8 +  // slot
-                            8 +  // timestamp
-                            32 + // validator_set_hash
-                            8 +  // total_stake
-                            2 +  // validator_count
-                            2 +  // approver_count
-                            8;   // approver_stake
+        // Freezing is independent of deactivation
+        registry.deactivate(2, 7).unwrap();
+        registry.register(2, crate::DGN_MINT_PROGRAM_ID, 8).unwrap();
+        assert!(registry.get(2).unwrap().frozen);
 
-    /// Create a new update record from validator set data
-    pub fn new(
-        update_epoch: u64,
-        slot: u64,
-        timestamp: i64,
-        validators: &[ValidatorInfo],
-        total_stake: u64,
-        approver_count: u16,
-        approver_stake: u64,
-    ) -> Self {
-        let validator_set_hash = Self::hash_validator_set(validators);
+        registry.set_frozen(2, false, 9).unwrap();
+        assert!(!registry.get(2).unwrap().frozen);
+        assert!(registry.set_frozen(2, false, 10).is_err());
+    }
 
-        Self {
-            update_epoch,
-            slot,
-            timestamp,
-            validator_set_hash,
-            total_stake,
-            validator_count: validators.len() as u16,
-            approver_count,
-            approver_stake,
-        }
+    #[test]
+    fn test_burn_age_limit() {
+        let week = BurnAgeLimit::MIN_MAX_BURN_AGE;
+
+        // Set once, then only lowered
+        assert!(BurnAgeLimit::is_valid_change(0, 4 * week));
+        assert!(BurnAgeLimit::is_valid_change(4 * week, 2 * week));
+        assert!(!BurnAgeLimit::is_valid_change(2 * week, 4 * week));
+        assert!(!BurnAgeLimit::is_valid_change(2 * week, 0));
+        assert!(!BurnAgeLimit::is_valid_change(0, week - 1));
+        assert!(!BurnAgeLimit::is_valid_change(0, BurnAgeLimit::MAX_MAX_BURN_AGE + 1));
+
+        // A guard closes only once the burn is past the limit plus clock skew
+        let skew = X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW;
+        assert!(!BurnAgeLimit::is_expired(0, 0, i64::MAX));
+        assert!(!BurnAgeLimit::is_expired(week, 1_000, 1_000 + week + skew));
+        assert!(BurnAgeLimit::is_expired(week, 1_000, 1_000 + week + skew + 1));
     }
 
-    /// Create deterministic hash of validator set
-    /// Used to verify update signatures and track history
-    pub fn hash_validator_set(validators: &[ValidatorInfo]) -> [u8; 32] {
-        use anchor_lang::solana_program::hash::hash;
+    #[test]
+    fn test_challenge_request_timing() {
+        let user = Pubkey::new_unique();
+        let skew = X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW;
+        let request = ChallengeRequest {
+            asset_id: 1,
+            burn_nonce: 7,
+            user,
+            challenge: ChallengeRequest::derive(1, &user, 7, 100, &[9u8; 32]),
+            created_at: 1_700_000_000,
+            created_slot: 100,
+            bump: 255,
+        };
 
-        // Create sorted copy to ensure deterministic hash
-        let mut sorted_validators = validators.to_vec();
-        sorted_validators.sort_by_key(|v| v.identity);
+        // Burn before the challenge, signatures after it (both within skew)
+        assert!(request.check_burn_time(1_700_000_000 + skew).is_ok());
+        assert!(request.check_burn_time(1_700_000_000 + skew + 1).is_err());
+        assert!(request.check_signing_time(1_700_000_000 - skew).is_ok());
+        assert!(request.check_signing_time(1_700_000_000 - skew - 1).is_err());
 
-        let mut hash_data = Vec::new();
-        for validator in sorted_validators {
-            hash_data.extend_from_slice(&validator.identity.to_bytes());
-            hash_data.extend_from_slice(&validator.stake.to_le_bytes());
-        }
+        // Unpredictable without the slot hash, and specific to the burn
+        assert_ne!(request.challenge, ChallengeRequest::derive(1, &user, 7, 100, &[8u8; 32]));
+        assert_ne!(request.challenge, ChallengeRequest::derive(1, &user, 8, 100, &[9u8; 32]));
+    }
 
-        hash(&hash_data).to_bytes()
+    #[test]
+    fn test_validator_set_update_timelock() {
+        assert!(ValidatorSetTimelock::is_valid_delay(ValidatorSetTimelock::DEFAULT_DELAY_SECONDS));
+        assert!(!ValidatorSetTimelock::is_valid_delay(ValidatorSetTimelock::MIN_DELAY_SECONDS - 1));
+        assert!(!ValidatorSetTimelock::is_valid_delay(ValidatorSetTimelock::MAX_DELAY_SECONDS + 1));
+        assert!(!ValidatorSetTimelock::is_valid_delay(0));
+
+        let pending = PendingValidatorSetUpdate {
+            base_version: 4,
+            validators: vec![Pubkey::new_unique(); 4],
+            threshold: 3,
+            weights: vec![],
+            threshold_bps: 0,
+            approvals: 3,
+            proposer: Pubkey::new_unique(),
+            proposed_at: 1_000,
+            executable_at: 1_000 + ValidatorSetTimelock::DEFAULT_DELAY_SECONDS,
+            approvers: vec![],
+            bump: 255,
+        };
+        assert!(pending.check_executable(4, pending.executable_at - 1).is_err());
+        assert!(pending.check_executable(4, pending.executable_at).is_ok());
+
+        // The approving set changed (e.g. a resignation): propose again
+        assert!(pending.check_executable(5, pending.executable_at).is_err());
     }
 
-    /// Verify this update was approved by sufficient stake
-    /// Returns true if approver_stake >= 66% of previous total stake
-    pub fn verify_consensus(&self, previous_total_stake: u64) -> Result<bool> {
-        let threshold = ValidatorSet::consensus_threshold(previous_total_stake)?;
-        Ok(self.approver_stake >= threshold)
+    #[test]
+    fn test_dev_mode_guard() {
+        let canary_key = Pubkey::new_unique();
+        let system = anchor_lang::system_program::ID;
+        let mut funded = 1u64;
+        let mut drained = 0u64;
+        let mut no_data: Vec<u8> = vec![];
+        let mut no_data_2: Vec<u8> = vec![];
+        let alive = AccountInfo::new(&canary_key, true, false, &mut funded, &mut no_data, &system, false, 0);
+        assert!(DevModeConfig::canary_alive(&canary_key, &alive));
+        assert!(!DevModeConfig::canary_alive(&Pubkey::new_unique(), &alive));
+        let dead = AccountInfo::new(&canary_key, true, false, &mut drained, &mut no_data_2, &system, false, 0);
+        assert!(!DevModeConfig::canary_alive(&canary_key, &dead));
+
+        // An uninitialized config never allows mocks, whatever the build
+        let config_key = Pubkey::new_unique();
+        let mut config_lamports = 0u64;
+        let mut config_data: Vec<u8> = vec![];
+        let config = AccountInfo::new(&config_key, false, false, &mut config_lamports, &mut config_data, &system, false, 0);
+        assert!(!DevModeConfig::mock_signatures_allowed(&config, Some(&alive)).unwrap());
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_fee_lookup_table_coverage() {
+        let validators = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let payouts = fee_payout_accounts(&validators);
+        assert_eq!(payouts.len(), 6);
+        assert_eq!(payouts[0], validators[0]);
+
+        // Extra addresses are fine, any missing payout account is not
+        let mut table = vec![Pubkey::new_unique()];
+        table.extend(payouts.iter().rev());
+        assert!(covers_fee_payout_accounts(&table, &validators));
+        table.retain(|a| *a != payouts[5]);
+        assert!(!covers_fee_payout_accounts(&table, &validators));
+        assert!(covers_fee_payout_accounts(&table, &validators[..1]));
+    }
 
     #[test]
-    fn test_consensus_threshold() {
-        // Test exact 66% calculation
-        assert_eq!(ValidatorSet::consensus_threshold(300).unwrap(), 200);
-        assert_eq!(ValidatorSet::consensus_threshold(1000).unwrap(), 667);
-        assert_eq!(ValidatorSet::consensus_threshold(100).unwrap(), 67);
+    fn test_confirmation_depth_bounds() {
+        let bundle = |confirmation_depth: u16, solana_confirmed_slot: u64| BurnAttestationDataV3 {
+            asset_id: 1,
+            burn_nonce: 1,
+            user: Pubkey::new_unique(),
+            amount: 1_000,
+            validator_set_version: 1,
+            solana_burn_slot: 1_000,
+            solana_burn_timestamp: 1_700_000_000,
+            confirmation_depth,
+            solana_confirmed_slot,
+            attestations: vec![],
+        };
 
-        // Test rounding up
-        assert_eq!(ValidatorSet::consensus_threshold(10).unwrap(), 7);
+        // 0 = the minimum depth
+        assert!(bundle(0, 1_032).check_confirmation_depth().is_ok());
+        assert!(bundle(0, 1_031).check_confirmation_depth().is_err());
+        assert_eq!(bundle(10, 1_032).effective_confirmation_depth(), MIN_CONFIRMATION_DEPTH);
+
+        assert!(bundle(600, 1_600).check_confirmation_depth().is_ok());
+        assert!(bundle(600, 1_599).check_confirmation_depth().is_err());
+
+        assert!(bundle(MAX_CONFIRMATION_DEPTH, 10_000).check_confirmation_depth().is_ok());
+        assert!(bundle(MAX_CONFIRMATION_DEPTH + 1, 10_000).check_confirmation_depth().is_err());
     }
 
     #[test]
-    fn test_validator_set_hash_deterministic() {
-        let validators = vec![
-            ValidatorInfo {
-                identity: Pubkey::new_unique(),
-                stake: 1000,
-            },
-            ValidatorInfo {
-                identity: Pubkey::new_unique(),
-                stake: 2000,
-            },
-        ];
+    fn test_asset_metadata() {
+        let xencat = Asset::XENCAT.metadata();
+        assert_eq!(xencat.symbol, "XENCAT");
+        assert_eq!(xencat.decimals, 6);
+        assert_eq!(xencat.mint_program, crate::XENCAT_MINT_PROGRAM_ID);
+        assert_eq!(
+            xencat.logo_uri_hash,
+            anchor_lang::solana_program::hash::hash(Asset::XENCAT.metadata_uri().as_bytes()).to_bytes()
+        );
+        assert_ne!(xencat.logo_uri_hash, Asset::DGN.metadata().logo_uri_hash);
 
-        let hash1 = ValidatorSetUpdateRecord::hash_validator_set(&validators);
-        let hash2 = ValidatorSetUpdateRecord::hash_validator_set(&validators);
+        // Strings must fit the declared max_len
+        for asset in Asset::ALL {
+            let metadata = asset.metadata();
+            assert!(metadata.symbol.len() <= 10 && metadata.name.len() <= 32);
+        }
+    }
 
-        assert_eq!(hash1, hash2);
+    #[test]
+    fn test_beta_allowlist() {
+        let (a, b) = (Pubkey::new_unique(), Pubkey::new_unique());
+        assert!(BetaAllowlist::is_valid_list(true, &[a, b]));
+        assert!(!BetaAllowlist::is_valid_list(true, &[a, a]));
+        assert!(!BetaAllowlist::is_valid_list(true, &[]));
+        // Graduating clears the list
+        assert!(BetaAllowlist::is_valid_list(false, &[]));
+        let full: Vec<Pubkey> = (0..=BetaAllowlist::MAX_USERS).map(|_| Pubkey::new_unique()).collect();
+        assert!(!BetaAllowlist::is_valid_list(true, &full));
+
+        let mut list = BetaAllowlist { asset_id: 3, enabled: true, users: vec![a], updated_slot: 0, bump: 255 };
+        assert!(list.allows(&a));
+        assert!(!list.allows(&b));
+        list.enabled = false;
+        assert!(list.allows(&b));
     }
 
     #[test]
-    fn test_history_ring_buffer() {
-        let mut history = ValidatorSetHistory {
-            updates: Vec::new(),
-            current_index: 0,
-            total_updates: 0,
-        };
+    fn test_notice_validation() {
+        let hash = [7u8; 32];
+        assert!(Notice::is_valid(&hash, NOTICE_INFO));
+        assert!(Notice::is_valid(&hash, NOTICE_CRITICAL));
+        assert!(!Notice::is_valid(&hash, NOTICE_CRITICAL + 1));
+        // Clearing needs no text; posting one does
+        assert!(Notice::is_valid(&[0u8; 32], NOTICE_NONE));
+        assert!(Notice::is_valid(&hash, NOTICE_NONE));
+        assert!(!Notice::is_valid(&[0u8; 32], NOTICE_WARNING));
+    }
 
-        // Add first update
-        let record = ValidatorSetUpdateRecord {
-            update_epoch: 1,
-            slot: 100,
-            timestamp: 1234567890,
-            validator_set_hash: [0u8; 32],
-            total_stake: 1000,
-            validator_count: 10,
-            approver_count: 7,
-            approver_stake: 700,
-        };
+    #[test]
+    fn test_program_hash_registry() {
+        let light_client = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let mut registry = ProgramHashRegistry { hashes: vec![], bump: 255 };
 
-        history.add_update(record.clone());
-        assert_eq!(history.total_updates, 1);
-        assert_eq!(history.get_latest().unwrap().update_epoch, 1);
+        registry.attest(light_client, [3, 1, 0], [1u8; 32], 10);
+        registry.attest(light_client, [3, 0, 9], [2u8; 32], 11);
+        registry.attest(mint, [1, 0, 0], [3u8; 32], 12);
+        assert_eq!(registry.expected_hash(&light_client, [3, 1, 0]), Some([1u8; 32]));
+        assert_eq!(registry.expected_hash(&mint, [3, 1, 0]), None);
+        assert_eq!(registry.latest(&light_client).unwrap().version, [3, 1, 0]);
+
+        // Re-attesting a version corrects it in place
+        registry.attest(light_client, [3, 1, 0], [4u8; 32], 13);
+        assert_eq!(registry.hashes.len(), 3);
+        assert_eq!(registry.expected_hash(&light_client, [3, 1, 0]), Some([4u8; 32]));
+
+        // Full: the oldest attestation (slot 11) is dropped
+        for patch in 0..(MAX_PROGRAM_HASHES - 3) as u16 {
+            registry.attest(mint, [2, 0, patch], [5u8; 32], 20 + patch as u64);
+        }
+        assert_eq!(registry.hashes.len(), MAX_PROGRAM_HASHES);
+        registry.attest(mint, [9, 0, 0], [6u8; 32], 100);
+        assert_eq!(registry.hashes.len(), MAX_PROGRAM_HASHES);
+        assert_eq!(registry.expected_hash(&light_client, [3, 0, 9]), None);
+        assert_eq!(registry.expected_hash(&light_client, [3, 1, 0]), Some([4u8; 32]));
     }
 }
@@ -7,7 +7,22 @@
 use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::LightClientError;
-use crate::BurnProof;
+use crate::ValidatorVote;
+
+/// Proof layout this verifier was written against (before the minimal
+/// BurnProof moved validator data into the Ed25519 instructions)
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct BurnProof {
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+    pub block_hash: [u8; 32],
+    pub state_root: [u8; 32],
+    pub merkle_proof: Vec<[u8; 32]>,
+    pub validator_votes: Vec<ValidatorVote>,
+    pub burn_record_data: Vec<u8>,
+}
 
 /// Burn record structure (must match Solana burn program)
 /// This is what's stored in burn_record_data
@@ -252,7 +267,7 @@ pub fn verify_burn_proof_legacy(
 /// For example: 15% of 1,000,000 = 150,000
 ///
 /// basis_points: 1500 = 15%, 6667 = 66.67%, etc.
-fn calculate_stake_threshold(total_stake: u64, basis_points: u64) -> Result<u64> {
+pub(crate) fn calculate_stake_threshold(total_stake: u64, basis_points: u64) -> Result<u64> {
     // Use u128 to prevent overflow with large stake values (e.g., stake in lamports)
     let total_u128 = total_stake as u128;
     let basis_u128 = basis_points as u128;
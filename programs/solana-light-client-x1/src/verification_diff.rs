@@ -0,0 +1,477 @@
+// Differential tests: legacy verification.rs vs verification_new.rs
+//
+// Generates burn proofs, encodes each one in both verifiers' formats and
+// requires the same accept/reject verdict. Must pass before the legacy
+// path is deleted.
+//
+// What is compared is the decision logic: vote count bounds, validator
+// membership, duplicate signers, vote message binding, Ed25519 instruction
+// presence/program, the stake threshold, and the Merkle path from the burn
+// record to the state root. Wire formats are not shared,
+// so each verifier gets its own encoding of the same logical proof:
+// - legacy hashes votes with sha256 and reads the Ed25519 header offsets
+//   one byte early; verification_new uses keccak and the precompile layout
+// - verification_new votes also cover the state root, and its Merkle leaf
+//   and node hashes carry domain prefixes; legacy has neither
+// - legacy carries the signatures and burn record in the proof itself
+//
+// Kept out of the generated domain (the rewrite knowingly differs here):
+// - Merkle path depth: verification_new allows 10 levels, legacy 32, so
+//   generated paths have at most 10
+// - Burn record layout: verification_new reads only the leading user,
+//   amount and nonce, legacy needs its exact BurnRecord, so generated
+//   records use the legacy layout
+// - Finality: verification_new compares the Solana slot with the X1 clock,
+//   legacy does not, so proofs are always far enough behind the clock
+// - Signature encoding (zero, weak keys, S >= L): only the new path runs
+//   check_signature_encoding, so generated signatures are canonical
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::{ed25519_program, keccak};
+use xencat_test_utils::{test_clock, Ed25519Ix, MockInstructions, MockSysvar, TestClock};
+use crate::state::{ValidatorConfig, ValidatorInfo};
+use crate::{verification, verification_new, BurnProof, ValidatorVote};
+
+/// X1 slot seen by both verifiers (Clock::get)
+const CURRENT_SLOT: u64 = 1_000_000;
+
+const CASES: usize = 2_000;
+
+fn install_clock() -> TestClock {
+    let clock = test_clock();
+    clock.warp_to_slot(CURRENT_SLOT);
+    clock
+}
+
+/// xorshift64: deterministic, no extra dev-dependencies
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn chance(&mut self, percent: u64) -> bool {
+        self.below(100) < percent
+    }
+
+    fn bytes<const N: usize>(&mut self) -> [u8; N] {
+        let mut out = [0u8; N];
+        for byte in out.iter_mut() {
+            *byte = self.next() as u8;
+        }
+        out
+    }
+
+    /// Random signature with S < 2^252 < L (canonical)
+    fn signature(&mut self) -> [u8; 64] {
+        let mut signature: [u8; 64] = self.bytes();
+        signature[63] &= 0x0f;
+        signature
+    }
+}
+
+/// One vote as the harness models it, independent of wire format
+#[derive(Clone)]
+struct Vote {
+    signer: Pubkey,
+    signature: [u8; 64],
+    /// Slot the validator actually signed (≠ proof slot = wrong message)
+    signed_slot: u64,
+    /// Ed25519 instruction is missing from the transaction
+    missing_ix: bool,
+    /// Instruction at this index is not the Ed25519 program
+    wrong_program: bool,
+}
+
+struct Case {
+    config: ValidatorConfig,
+    burn_nonce: u64,
+    user: Pubkey,
+    amount: u64,
+    slot: u64,
+    block_hash: [u8; 32],
+    merkle_path: Vec<[u8; 32]>,
+    /// Amount in the burn record (≠ amount = forged claim)
+    record_amount: u64,
+    /// State root does not match the path
+    bad_root: bool,
+    votes: Vec<Vote>,
+}
+
+fn generate(rng: &mut Rng) -> Case {
+    let validator = |rng: &mut Rng| ValidatorInfo {
+        identity: Pubkey::new_unique(),
+        // Skewed stakes so the 9% threshold is sometimes missed
+        stake: if rng.chance(50) { 1 + rng.below(100) } else { 1 + rng.below(1_000_000) },
+    };
+    let primary_validators = [validator(rng), validator(rng), validator(rng)];
+    let fallback_validators = [validator(rng), validator(rng), validator(rng), validator(rng)];
+    let tracked: u64 = primary_validators.iter().chain(fallback_validators.iter()).map(|v| v.stake).sum();
+    let config = ValidatorConfig {
+        current_epoch: 1,
+        last_update: 0,
+        primary_validators,
+        fallback_validators,
+        total_tracked_stake: tracked + if rng.chance(20) { rng.below(10_000_000) } else { 0 },
+        bump: 255,
+    };
+
+    let slot = CURRENT_SLOT - 100 - rng.below(10_000);
+    let members = config.all_validators();
+    let vote_count = 2 + rng.below(7) as usize;
+    let votes = (0..vote_count)
+        .map(|_| Vote {
+            signer: if rng.chance(8) {
+                Pubkey::new_unique()
+            } else {
+                members[rng.below(members.len() as u64) as usize].identity
+            },
+            signature: rng.signature(),
+            signed_slot: if rng.chance(5) { slot + 1 } else { slot },
+            missing_ix: rng.chance(3),
+            wrong_program: rng.chance(3),
+        })
+        .collect();
+
+    let amount = 1 + rng.below(u32::MAX as u64);
+    Case {
+        config,
+        burn_nonce: rng.next(),
+        user: Pubkey::new_unique(),
+        amount,
+        slot,
+        block_hash: rng.bytes(),
+        merkle_path: (0..1 + rng.below(10)).map(|_| rng.bytes()).collect(),
+        record_amount: if rng.chance(4) { amount + 1 } else { amount },
+        bad_root: rng.chance(4),
+        votes,
+    }
+}
+
+/// Legacy vote message: sha256(block_hash || slot)
+fn legacy_vote_message(block_hash: &[u8; 32], slot: u64) -> [u8; 32] {
+    let mut data = block_hash.to_vec();
+    data.extend_from_slice(&slot.to_le_bytes());
+    anchor_lang::solana_program::hash::hash(&data).to_bytes()
+}
+
+/// Ed25519 instruction data with signature, pubkey and message inline
+/// after a 16-byte header. `legacy` writes the offsets where
+/// verification.rs reads them (one byte before the precompile layout).
+fn ed25519_data(signature: &[u8; 64], pubkey: &Pubkey, message: &[u8; 32], legacy: bool) -> Vec<u8> {
+    if !legacy {
+        return Ed25519Ix::new(message).signer(*pubkey).signature(*signature).build();
+    }
+    let mut data = vec![0u8; 16];
+    data[0] = 1;
+    let mut put = |at: usize, value: u16| data[at..at + 2].copy_from_slice(&value.to_le_bytes());
+    put(1, 16);
+    put(5, 80);
+    put(9, 112);
+    put(11, 32);
+    data.extend_from_slice(signature);
+    data.extend_from_slice(pubkey.as_ref());
+    data.extend_from_slice(message);
+    data
+}
+
+/// Ed25519 instructions of the case: one per vote (unless missing), over
+/// the real tree's root
+fn vote_instructions(case: &Case, legacy: bool) -> Vec<(Pubkey, Vec<u8>)> {
+    signed_vote_instructions(case, legacy, &signed_root(case))
+}
+
+/// Ed25519 instructions of the case with verification_new votes over
+/// `signed_root` (legacy votes do not cover it)
+fn signed_vote_instructions(case: &Case, legacy: bool, signed_root: &[u8; 32]) -> Vec<(Pubkey, Vec<u8>)> {
+    case.votes
+        .iter()
+        .filter(|vote| !vote.missing_ix)
+        .map(|vote| {
+            let message = if legacy {
+                legacy_vote_message(&case.block_hash, vote.signed_slot)
+            } else {
+                crate::ed25519_utils::create_vote_message(&case.block_hash, vote.signed_slot, signed_root)
+            };
+            let program = if vote.wrong_program { crate::ID } else { ed25519_program::ID };
+            (program, ed25519_data(&vote.signature, &vote.signer, &message, legacy))
+        })
+        .collect()
+}
+
+/// Instructions sysvar: the vote instructions, then the light client
+/// instruction itself (the current one)
+fn instructions_sysvar(case: &Case, legacy: bool) -> MockSysvar {
+    vote_instructions(case, legacy)
+        .into_iter()
+        .fold(MockInstructions::new(), |mock, (program_id, data)| mock.instruction(program_id, data))
+        .instruction(crate::ID, vec![0u8; 8])
+        .build()
+}
+
+/// Root of the tree with leaf hash(record) and sorted-pair parents;
+/// verification_new prefixes leaf and node hashes, legacy does not
+fn merkle_root(record: &[u8], path: &[[u8; 32]], legacy: bool) -> [u8; 32] {
+    let (leaf_prefix, node_prefix): (&[u8], &[u8]) = if legacy {
+        (&[], &[])
+    } else {
+        (&[verification_new::MERKLE_LEAF_PREFIX], &[verification_new::MERKLE_NODE_PREFIX])
+    };
+    let mut node = keccak::hashv(&[leaf_prefix, record]).to_bytes();
+    for sibling in path {
+        let (left, right) = if node <= *sibling { (node, *sibling) } else { (*sibling, node) };
+        node = keccak::hashv(&[node_prefix, left.as_ref(), right.as_ref()]).to_bytes();
+    }
+    node
+}
+
+/// Burn record bytes of the case (legacy layout)
+fn burn_record(case: &Case) -> Vec<u8> {
+    verification::BurnRecord {
+        user: case.user,
+        amount: case.record_amount,
+        nonce: case.burn_nonce,
+        timestamp: 0,
+        record_hash: [0u8; 32],
+        bump: 255,
+    }
+    .try_to_vec()
+    .unwrap()
+}
+
+/// Root of the real tree, as the validators sign it
+fn signed_root(case: &Case) -> [u8; 32] {
+    merkle_root(&burn_record(case), &case.merkle_path, false)
+}
+
+/// Burn record bytes and the state root the proof claims
+fn burn_record_and_root(case: &Case, legacy: bool) -> (Vec<u8>, [u8; 32]) {
+    let record = burn_record(case);
+    let mut root = merkle_root(&record, &case.merkle_path, legacy);
+    if case.bad_root {
+        root[0] ^= 1;
+    }
+    (record, root)
+}
+
+fn legacy_accepts(case: &Case) -> bool {
+    let (burn_record_data, state_root) = burn_record_and_root(case, true);
+    let proof = verification::BurnProof {
+        burn_nonce: case.burn_nonce,
+        user: case.user,
+        amount: case.amount,
+        slot: case.slot,
+        block_hash: case.block_hash,
+        state_root,
+        merkle_proof: case.merkle_path.clone(),
+        validator_votes: case.votes
+            .iter()
+            .map(|vote| ValidatorVote {
+                validator_identity: vote.signer,
+                stake: 0,
+                signature: vote.signature,
+            })
+            .collect(),
+        burn_record_data,
+    };
+    let mut sysvar = instructions_sysvar(case, true);
+    verification::verify_burn_proof(&proof, &case.config, &sysvar.account_info(), 0)
+        .map(|result| result.verified)
+        .unwrap_or(false)
+}
+
+fn minimal_proof(case: &Case) -> BurnProof {
+    let (burn_record_data, state_root) = burn_record_and_root(case, false);
+    BurnProof {
+        burn_nonce: case.burn_nonce,
+        user: case.user,
+        amount: case.amount,
+        slot: case.slot,
+        block_hash: case.block_hash,
+        state_root,
+        merkle_proof: case.merkle_path.clone(),
+        burn_record_data,
+        validator_count: case.votes.len() as u8,
+        ed25519_indices: (0..case.votes.len() as u8).collect(),
+    }
+}
+
+fn minimal_accepts(case: &Case) -> bool {
+    let mut sysvar = instructions_sysvar(case, false);
+    verification_new::verify_burn_proof_minimal(&minimal_proof(case), &case.config, &sysvar.account_info()).is_ok()
+}
+
+#[test]
+fn test_legacy_and_minimal_verifiers_agree() {
+    let _clock = install_clock();
+    let mut rng = Rng(0x5e_ed1e_9ac7_u64);
+    let mut accepted = 0;
+
+    for index in 0..CASES {
+        let case = generate(&mut rng);
+        let legacy = legacy_accepts(&case);
+        let minimal = minimal_accepts(&case);
+        assert_eq!(
+            legacy,
+            minimal,
+            "case {}: legacy {} / minimal {} ({} votes, signers {:?})",
+            index,
+            if legacy { "accepts" } else { "rejects" },
+            if minimal { "accepts" } else { "rejects" },
+            case.votes.len(),
+            case.votes.iter().map(|v| v.signer).collect::<Vec<_>>(),
+        );
+        accepted += legacy as usize;
+    }
+
+    // The generator must exercise both verdicts, or agreement means nothing
+    assert!(accepted > CASES / 20, "only {} of {} cases accepted", accepted, CASES);
+    assert!(accepted < CASES - CASES / 20, "{} of {} cases accepted", accepted, CASES);
+}
+
+#[test]
+fn test_duplicate_signer_rejected_by_both() {
+    let _clock = install_clock();
+    let mut rng = Rng(42);
+    let mut case = generate(&mut rng);
+    let member = case.config.primary_validators[0].identity;
+    case.votes = (0..3)
+        .map(|_| Vote {
+            signer: member,
+            signature: rng.signature(),
+            signed_slot: case.slot,
+            missing_ix: false,
+            wrong_program: false,
+        })
+        .collect();
+    // Enough stake on its own: only the duplicate check can reject it
+    case.config.primary_validators[0].stake = case.config.total_tracked_stake;
+
+    assert!(!legacy_accepts(&case));
+    assert!(!minimal_accepts(&case));
+}
+
+#[test]
+fn test_minimal_verifier_finality_follows_clock() {
+    let clock = install_clock();
+    let mut rng = Rng(7);
+    let case = (0..CASES)
+        .map(|_| generate(&mut rng))
+        .find(minimal_accepts)
+        .expect("generator produces accepted cases");
+
+    // 32 slots of finality, measured against the X1 clock
+    clock.warp_to_slot(case.slot + 31);
+    assert!(!minimal_accepts(&case));
+    clock.advance_slots(1);
+    assert!(minimal_accepts(&case));
+}
+
+#[test]
+fn test_minimal_verifier_binds_ed25519_by_index() {
+    let _clock = install_clock();
+    let mut rng = Rng(11);
+    let case = (0..CASES)
+        .map(|_| generate(&mut rng))
+        .find(minimal_accepts)
+        .expect("generator produces accepted cases");
+    let votes = vote_instructions(&case, false);
+    let verify = |mock: MockInstructions, indices: Vec<u8>| {
+        let proof = BurnProof { ed25519_indices: indices, ..minimal_proof(&case) };
+        let mut sysvar = mock.instruction(crate::ID, vec![0u8; 8]).build();
+        verification_new::verify_burn_proof_minimal(&proof, &case.config, &sysvar.account_info()).is_ok()
+    };
+    let with_votes = |mock: MockInstructions| {
+        votes.iter().cloned().fold(mock, |mock, (program_id, data)| mock.instruction(program_id, data))
+    };
+
+    // Other instructions ahead of the votes: found through the index list
+    let prefixed = || with_votes(MockInstructions::new().instruction(Pubkey::new_unique(), vec![1u8]));
+    let shifted: Vec<u8> = (1..=votes.len() as u8).collect();
+    assert!(verify(prefixed(), shifted));
+    // ...but not by position
+    assert!(!verify(prefixed(), (0..votes.len() as u8).collect()));
+
+    // One more vote instruction than listed (a replayed signature)
+    let extra = with_votes(MockInstructions::new()).instruction(votes[0].0, votes[0].1.clone());
+    assert!(!verify(extra, (0..votes.len() as u8).collect()));
+
+    // Listing an index twice to count one signer twice
+    let mut repeated: Vec<u8> = (0..votes.len() as u8).collect();
+    repeated[1] = repeated[0];
+    assert!(!verify(with_votes(MockInstructions::new()), repeated));
+}
+
+#[test]
+fn test_minimal_verifier_rejects_forged_root() {
+    let _clock = install_clock();
+    let mut rng = Rng(13);
+    let case = (0..CASES)
+        .map(|_| generate(&mut rng))
+        .find(|case| case.merkle_path.len() >= 2 && minimal_accepts(case))
+        .expect("generator produces accepted cases");
+    let verify = |proof: BurnProof, signed_root: &[u8; 32]| {
+        let mut sysvar = signed_vote_instructions(&case, false, signed_root)
+            .into_iter()
+            .fold(MockInstructions::new(), |mock, (program_id, data)| mock.instruction(program_id, data))
+            .instruction(crate::ID, vec![0u8; 8])
+            .build();
+        verification_new::verify_burn_proof_minimal(&proof, &case.config, &sysvar.account_info()).is_ok()
+    };
+    let proof = minimal_proof(&case);
+    let signed = signed_root(&case);
+    assert!(verify(proof.clone(), &signed));
+
+    // Any changed sibling moves the root
+    let mut path = proof.merkle_proof.clone();
+    path[0][0] ^= 1;
+    assert!(!verify(BurnProof { merkle_proof: path, ..proof.clone() }, &signed));
+
+    // A forged record with a consistent path to its own root: the votes cover the real root
+    let forged_amount = case.amount * 10;
+    let mut forged = proof.burn_record_data.clone();
+    forged[32..40].copy_from_slice(&forged_amount.to_le_bytes());
+    let forged_root = merkle_root(&forged, &proof.merkle_proof, false);
+    let forged_proof = BurnProof {
+        amount: forged_amount,
+        burn_record_data: forged.clone(),
+        state_root: forged_root,
+        ..proof.clone()
+    };
+    assert!(!verify(forged_proof.clone(), &signed));
+
+    // The record as its own root: an empty path is rejected even if the votes cover it
+    let leaf_root = merkle_root(&forged, &[], false);
+    let leaf_proof = BurnProof { merkle_proof: Vec::new(), state_root: leaf_root, ..forged_proof };
+    assert!(!verify(leaf_proof.clone(), &signed));
+    assert!(!verify(leaf_proof, &leaf_root));
+
+    // An internal node passed off as a 64-byte record: leaf and node hashes differ
+    let leaf = merkle_root(&proof.burn_record_data, &[], false);
+    let sibling = proof.merkle_proof[0];
+    let (left, right) = if leaf <= sibling { (leaf, sibling) } else { (sibling, leaf) };
+    let node_record = [left, right].concat();
+    let node_proof = BurnProof {
+        user: Pubkey::try_from(&node_record[..32]).unwrap(),
+        amount: u64::from_le_bytes(node_record[32..40].try_into().unwrap()),
+        burn_nonce: u64::from_le_bytes(node_record[40..48].try_into().unwrap()),
+        merkle_proof: proof.merkle_proof[1..].to_vec(),
+        burn_record_data: node_record,
+        ..proof.clone()
+    };
+    assert!(!verify(node_proof, &signed));
+
+    // Current burn program records carry more fields after the nonce
+    let mut record = proof.burn_record_data.clone();
+    record.extend_from_slice(&[7u8; 100]);
+    let state_root = merkle_root(&record, &proof.merkle_proof, false);
+    assert!(verify(BurnProof { burn_record_data: record, state_root, ..proof }, &state_root));
+}
@@ -5,7 +5,7 @@ use anchor_lang::prelude::*;
 use crate::state::*;
 use crate::errors::LightClientError;
 use crate::BurnProof;
-use crate::ed25519_utils::{load_ed25519_instruction, create_vote_message};
+use crate::ed25519_utils::{load_bound_ed25519_instructions, create_vote_message};
 
 /// Verify burn proof by extracting validators from Ed25519 instructions
 ///
@@ -14,19 +14,21 @@ use crate::ed25519_utils::{load_ed25519_instruction, create_vote_message};
 /// 2. Avoids duplicating 312 bytes of validator data in proof argument
 /// 3. Maintains full cryptographic security
 ///
-/// Transaction structure:
+/// Transaction structure (ed25519_indices = [0, 1, 2]):
 /// [0] Ed25519Program.verify(sig0, pubkey0, message)
 /// [1] Ed25519Program.verify(sig1, pubkey1, message)
 /// [2] Ed25519Program.verify(sig2, pubkey2, message)
 /// [3] LightClient.submit_proof(minimal_proof)  <- We are here
 ///
+/// Instructions are found through the proof's index list and the current
+/// index, never by position (see load_bound_ed25519_instructions).
+///
 /// The Ed25519Program has ALREADY verified signatures cryptographically.
 /// We extract the validator identities and look up their stakes.
 pub fn verify_burn_proof_minimal(
     proof: &BurnProof,
     validator_config: &ValidatorConfig,
     instructions_sysvar: &AccountInfo,
-    ed25519_ix_offset: u16,
 ) -> Result<()> {
     msg!("🔐 Verifying proof with {} validators (extracted from Ed25519 ixs)", proof.validator_count);
     msg!("   Burn nonce: {}", proof.burn_nonce);
@@ -43,6 +45,10 @@ pub fn verify_burn_proof_minimal(
         proof.validator_count <= 20,
         LightClientError::TooManyValidators
     );
+    require!(
+        proof.ed25519_indices.len() == proof.validator_count as usize,
+        LightClientError::Ed25519CountMismatch
+    );
 
     // 2. Validate finality
     let current_slot = Clock::get()?.slot;
@@ -56,22 +62,24 @@ pub fn verify_burn_proof_minimal(
         LightClientError::InsufficientFinality
     );
 
-    // 3. Create expected vote message
-    let expected_message = create_vote_message(&proof.block_hash, proof.slot);
+    // 3. Create expected vote message (binds the state root the Merkle path is checked against)
+    let expected_message = create_vote_message(&proof.block_hash, proof.slot, &proof.state_root);
 
     // 4. Extract validators from Ed25519 instructions
     let mut total_stake = 0u64;
-    let mut validator_identities = Vec::new();
+    let mut validator_identities: Vec<Pubkey> = Vec::new();
 
-    for i in 0..proof.validator_count {
-        let ix_index = (ed25519_ix_offset as usize) + (i as usize);
-
-        // Load Ed25519 instruction and extract data
-        let (validator_pubkey, _signature, vote_message) =
-            load_ed25519_instruction(ix_index, instructions_sysvar)?;
+    let ed25519_data = load_bound_ed25519_instructions(&proof.ed25519_indices, instructions_sysvar)?;
 
+    for (i, (validator_pubkey, _signature, vote_message)) in ed25519_data.into_iter().enumerate() {
         msg!("  Validator {}: {}", i, validator_pubkey);
 
+        // Same validator twice would double-count its stake
+        require!(
+            !validator_identities.contains(&validator_pubkey),
+            LightClientError::DuplicateValidator
+        );
+
         // Verify vote message matches expected
         require!(
             vote_message == expected_message,
@@ -132,26 +140,64 @@ pub fn verify_burn_proof_minimal(
     Ok(())
 }
 
-/// Verify Merkle proof with minimal proof structure
+/// Leading fields of the burn program's BurnRecord (account data without
+/// the discriminator); the fields after them differ between burn program
+/// versions and are not read
+#[derive(AnchorDeserialize)]
+struct BurnRecordPrefix {
+    user: Pubkey,
+    amount: u64,
+    nonce: u64,
+}
+
+/// Domain prefix of Merkle leaf hashes
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+
+/// Domain prefix of Merkle internal node hashes, so a 64-byte record can
+/// never pass as the concatenation of two children
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// Verify the burn record is in the proof's Merkle tree and is the claimed burn
+///
+/// Hash format (keccak256, as sdk/proof-generator/src/merkle.ts builds it):
+/// - Leaf: hash(0x00 || burn_record_data)
+/// - Internal: hash(0x01 || min(left, right) || max(left, right)), sorted so
+///   the path needs no left/right bits
+///
+/// The computed root must equal proof.state_root, which the validators
+/// signed as part of the vote message, and the record's user, amount and
+/// nonce must equal the proof's. The path may not be empty.
 fn verify_merkle_proof_minimal(proof: &BurnProof) -> Result<()> {
     use anchor_lang::solana_program::keccak;
 
-    // For now, simplified merkle verification
-    // In production, this would verify the full merkle path
-    // from burn record to state root
-
     msg!("🌳 Verifying Merkle proof ({} levels)", proof.merkle_proof.len());
 
-    // Merkle proof must be reasonable size
+    // Merkle proof must be non-empty and of reasonable size
+    require!(
+        !proof.merkle_proof.is_empty() && proof.merkle_proof.len() <= 10,
+        LightClientError::InvalidMerkleProof
+    );
+
+    let mut node = keccak::hashv(&[&[MERKLE_LEAF_PREFIX], &proof.burn_record_data]).to_bytes();
+    for sibling in &proof.merkle_proof {
+        let (left, right) = if node <= *sibling { (&node, sibling) } else { (sibling, &node) };
+        node = keccak::hashv(&[&[MERKLE_NODE_PREFIX], left, right]).to_bytes();
+    }
     require!(
-        proof.merkle_proof.len() <= 10,
+        node == proof.state_root,
         LightClientError::InvalidMerkleProof
     );
 
-    // TODO: Full merkle verification
-    // For now, just verify structure is valid
+    let record = BurnRecordPrefix::deserialize(&mut &proof.burn_record_data[..])
+        .map_err(|_| LightClientError::BurnRecordDeserializationFailed)?;
+    require!(
+        record.user == proof.user
+            && record.amount == proof.amount
+            && record.nonce == proof.burn_nonce,
+        LightClientError::BurnRecordMismatch
+    );
 
-    msg!("✅ Merkle proof valid (structure)");
+    msg!("✅ Merkle proof valid (burn record {} in state root)", record.nonce);
 
     Ok(())
 }
@@ -0,0 +1,194 @@
+//! Wormhole VAA attestation path (submit_burn_vaa)
+//!
+//! A fallback for when the X1 validators' attestation APIs are offline: the
+//! Solana burn is published through Wormhole, and the guardian-signed VAA
+//! is posted on X1 with the Wormhole core bridge's own verify_signatures /
+//! post_vaa. The core bridge only creates a PostedVAA account once the
+//! guardian signatures check out, so this program trusts the account's
+//! owner and address instead of re-verifying signatures:
+//! - owned by the governed core bridge (WormholeConfig::core_bridge)
+//! - at ["PostedVAA", keccak256(body)] under that program, with the body
+//!   rebuilt from the account's own fields
+//! - from the governed emitter, at or above the governed consistency level
+//!
+//! PostedVAA account (Borsh): "vaa" || vaa_version(1) || consistency_level(1)
+//!   || vaa_time(u32) || vaa_signature_account(32) || submission_time(u32)
+//!   || nonce(u32) || sequence(u64) || emitter_chain(u16) || emitter_address(32)
+//!   || payload(4 + n)
+//!
+//! Burn payload: xencat_burn_vaa::BurnPayload, the layout the burn
+//! program's burn_registered_asset publishes from its ["emitter"] PDA
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::keccak;
+use crate::errors::LightClientError;
+
+/// Wormhole chain id of Solana
+pub const CHAIN_ID_SOLANA: u16 = 1;
+
+pub use xencat_burn_vaa::{BURN_PAYLOAD_ID, BURN_PAYLOAD_LEN};
+
+const POSTED_VAA_MAGIC: &[u8; 3] = b"vaa";
+/// Fixed part of a PostedVAA account, up to the payload length
+const POSTED_VAA_HEADER_LEN: usize = 3 + 1 + 1 + 4 + 32 + 4 + 4 + 8 + 2 + 32;
+
+/// The fields of a PostedVAA this program uses
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PostedVaa {
+    pub consistency_level: u8,
+    pub vaa_time: u32,
+    pub nonce: u32,
+    pub sequence: u64,
+    pub emitter_chain: u16,
+    pub emitter_address: [u8; 32],
+    pub payload: Vec<u8>,
+}
+
+impl PostedVaa {
+    pub fn parse(data: &[u8]) -> Result<Self> {
+        require!(
+            data.len() >= POSTED_VAA_HEADER_LEN + 4 && &data[..3] == POSTED_VAA_MAGIC,
+            LightClientError::InvalidPostedVaa
+        );
+        let u32_at = |o: usize| u32::from_le_bytes(data[o..o + 4].try_into().unwrap());
+        let payload_len = u32_at(POSTED_VAA_HEADER_LEN) as usize;
+        let payload = data
+            .get(POSTED_VAA_HEADER_LEN + 4..POSTED_VAA_HEADER_LEN + 4 + payload_len)
+            .ok_or(LightClientError::InvalidPostedVaa)?;
+        Ok(Self {
+            consistency_level: data[4],
+            vaa_time: u32_at(5),
+            // 9..41 signature set, 41..45 submission time
+            nonce: u32_at(45),
+            sequence: u64::from_le_bytes(data[49..57].try_into().unwrap()),
+            emitter_chain: u16::from_le_bytes(data[57..59].try_into().unwrap()),
+            emitter_address: data[59..91].try_into().unwrap(),
+            payload: payload.to_vec(),
+        })
+    }
+
+    /// keccak256 of the VAA body (the guardians sign its double hash)
+    pub fn body_hash(&self) -> [u8; 32] {
+        keccak::hashv(&[
+            &self.vaa_time.to_be_bytes(),
+            &self.nonce.to_be_bytes(),
+            &self.emitter_chain.to_be_bytes(),
+            &self.emitter_address,
+            &self.sequence.to_be_bytes(),
+            &[self.consistency_level],
+            &self.payload,
+        ])
+        .to_bytes()
+    }
+
+    /// Address the core bridge posts this VAA at
+    pub fn address(&self, core_bridge: &Pubkey) -> Pubkey {
+        Pubkey::find_program_address(&[b"PostedVAA", &self.body_hash()], core_bridge).0
+    }
+}
+
+/// A Solana burn as published through Wormhole
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct BurnVaaPayload {
+    pub asset_id: u8,
+    pub burn_nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub solana_burn_slot: u64,
+    pub solana_burn_timestamp: i64,
+}
+
+impl BurnVaaPayload {
+    pub fn parse(payload: &[u8]) -> Result<Self> {
+        let burn = xencat_burn_vaa::BurnPayload::decode(payload).ok_or(LightClientError::InvalidVaaPayload)?;
+        Ok(Self {
+            asset_id: burn.asset_id,
+            burn_nonce: burn.burn_nonce,
+            user: Pubkey::new_from_array(burn.user),
+            amount: burn.amount,
+            solana_burn_slot: burn.solana_burn_slot,
+            solana_burn_timestamp: burn.solana_burn_timestamp,
+        })
+    }
+
+    pub fn encode(&self) -> Vec<u8> {
+        xencat_burn_vaa::BurnPayload {
+            asset_id: self.asset_id,
+            burn_nonce: self.burn_nonce,
+            user: self.user.to_bytes(),
+            amount: self.amount,
+            solana_burn_slot: self.solana_burn_slot,
+            solana_burn_timestamp: self.solana_burn_timestamp,
+        }
+        .encode()
+        .to_vec()
+    }
+}
+
+/// Wormhole emitter address of a burn program: its ["emitter"] PDA
+pub fn burn_emitter_address(burn_program: &Pubkey) -> [u8; 32] {
+    Pubkey::find_program_address(&[xencat_burn_vaa::EMITTER_SEED], burn_program).0.to_bytes()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn posted_vaa_data(vaa: &PostedVaa) -> Vec<u8> {
+        let mut data = Vec::new();
+        data.extend_from_slice(POSTED_VAA_MAGIC);
+        data.push(1);
+        data.push(vaa.consistency_level);
+        data.extend_from_slice(&vaa.vaa_time.to_le_bytes());
+        data.extend_from_slice(&[7u8; 32]);
+        data.extend_from_slice(&(vaa.vaa_time + 30).to_le_bytes());
+        data.extend_from_slice(&vaa.nonce.to_le_bytes());
+        data.extend_from_slice(&vaa.sequence.to_le_bytes());
+        data.extend_from_slice(&vaa.emitter_chain.to_le_bytes());
+        data.extend_from_slice(&vaa.emitter_address);
+        data.extend_from_slice(&(vaa.payload.len() as u32).to_le_bytes());
+        data.extend_from_slice(&vaa.payload);
+        data
+    }
+
+    #[test]
+    fn test_posted_vaa_round_trip() {
+        let burn = BurnVaaPayload {
+            asset_id: 1,
+            burn_nonce: 42,
+            user: Pubkey::new_unique(),
+            amount: 1_000_000,
+            solana_burn_slot: 300_000_000,
+            solana_burn_timestamp: 1_760_000_000,
+        };
+        let vaa = PostedVaa {
+            consistency_level: 1,
+            vaa_time: 1_760_000_010,
+            nonce: 0,
+            sequence: 9,
+            emitter_chain: CHAIN_ID_SOLANA,
+            emitter_address: [3u8; 32],
+            payload: burn.encode(),
+        };
+        let data = posted_vaa_data(&vaa);
+
+        let parsed = PostedVaa::parse(&data).unwrap();
+        assert_eq!(parsed, vaa);
+        assert_eq!(BurnVaaPayload::parse(&parsed.payload).unwrap(), burn);
+
+        // Any body field moves the address the core bridge would post at
+        let core_bridge = Pubkey::new_unique();
+        let moved = PostedVaa { sequence: 10, ..vaa.clone() };
+        assert_ne!(moved.address(&core_bridge), vaa.address(&core_bridge));
+
+        // Truncated account, wrong magic, other payload layouts
+        assert!(PostedVaa::parse(&data[..data.len() - 1]).is_err());
+        let mut bad_magic = data.clone();
+        bad_magic[0] = b'm';
+        assert!(PostedVaa::parse(&bad_magic).is_err());
+        let mut other_id = burn.encode();
+        other_id[0] = 2;
+        assert!(BurnVaaPayload::parse(&other_id).is_err());
+        assert!(BurnVaaPayload::parse(&burn.encode()[..BURN_PAYLOAD_LEN - 1]).is_err());
+    }
+}
@@ -58,4 +58,94 @@ pub enum MintError {
 
     #[msg("Asset mismatch between verified burn and requested asset_id")]
     AssetMismatch,
+
+    #[msg("V2 minting is deprecated - re-attest the burn with V3 and use mint_from_burn_v3")]
+    V2Deprecated,
+
+    #[msg("Invalid recipient token account for this stream or mint")]
+    InvalidRecipient,
+
+    #[msg("Missing or invalid stealth ownership proof (Ed25519 instruction)")]
+    InvalidOwnershipProof,
+
+    #[msg("Compressed token output is not enabled for this asset")]
+    CompressedOutputDisabled,
+
+    #[msg("Invalid compressed token program")]
+    InvalidCompressedTokenProgram,
+
+    #[msg("Claim amount must be > 0 and at most the fee vault balance")]
+    InvalidClaimAmount,
+
+    #[msg("Mint above the co-sign threshold needs the policy co-signer's signature")]
+    CoSignerRequired,
+
+    #[msg("Co-sign policy account is not owned by this program")]
+    InvalidCoSignPolicy,
+
+    #[msg("Return amount must be greater than zero")]
+    InvalidReturnAmount,
+
+    #[msg("Commission must be at most 10000 bps, with a recipient when non-zero")]
+    InvalidCommission,
+
+    #[msg("Claim amount must be > 0 and at most the accrued commission")]
+    InvalidCommissionClaimAmount,
+
+    #[msg("Mint rate limit reached for this window")]
+    RateLimitExceeded,
+
+    #[msg("Rate limit account is not owned by this program")]
+    InvalidRateLimit,
+
+    #[msg("Invalid rate limit: window must be at least one hour")]
+    InvalidRateLimitWindow,
+
+    #[msg("Minting is paused by validator governance")]
+    MintPaused,
+
+    #[msg("Mint pause account is not owned by this program")]
+    InvalidMintPause,
+
+    #[msg("Minting is not paused")]
+    MintNotPaused,
+
+    #[msg("Signer or token account is not the relayer of this claim")]
+    InvalidRelayer,
+
+    #[msg("Batch must list 1 to 10 burn nonces in increasing order")]
+    InvalidBurnBatch,
+
+    #[msg("Missing or wrong verified/processed burn account in the batch")]
+    InvalidBurnBatchAccount,
+
+    #[msg("Verified burn must be archived before its replay guard is closed")]
+    VerifiedBurnNotArchived,
+
+    #[msg("Burn is not past the light client's burn age limit (or no limit is set)")]
+    BurnNotExpired,
+
+    #[msg("V2 submissions must be cut off and the burn's V2 proof upgraded")]
+    V2BurnNotRetired,
+
+    #[msg("Mint has a Token-2022 extension the bridge cannot mint safely (permanent delegate or close authority)")]
+    UnsupportedMintExtension,
+
+    #[msg("Fee pool account is not owned by this program")]
+    InvalidFeePool,
+
+    #[msg("Fee pool tracks its maximum of validators; withdraw_fees for a departed validator first")]
+    FeePoolFull,
+
+    #[msg("Validator has no pooled fees")]
+    NoPooledFees,
+
+    #[msg("Fee config account is not owned by this program, or its schedule is invalid (fee_bps > 10000 or min_fee > max_fee)")]
+    InvalidFeeConfig,
+
+    #[msg("Program is not on the CPI allowlist")]
+    ProgramNotAllowlisted,
+
+    #[msg("CPI allowlist is invalid (more than 8 programs, duplicates or this program)")]
+    InvalidCpiAllowlist,
 }
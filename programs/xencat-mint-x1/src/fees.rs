@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use crate::errors::MintError;
+use crate::state::{FeeConfig, FeePool, FeeVault, MintState};
+use solana_light_client_x1::{ValidatorLatencyStats, X1ValidatorSet};
+
+/// Fee owed to each member of `validator_set`, in set order
+///
+/// The total stays `fee_per_validator` per validator; the split follows
+/// attestation participation (ValidatorLatencyStats::fee_shares), or is
+/// equal while the light client's latency stats are not initialized.
+pub fn validator_fee_shares(
+    fee_per_validator: u64,
+    validator_set: &X1ValidatorSet,
+    latency_stats: &AccountInfo,
+) -> Result<Vec<u64>> {
+    let total_fee = fee_per_validator
+        .checked_mul(validator_set.validators.len() as u64)
+        .ok_or(MintError::Overflow)?;
+    ValidatorLatencyStats::fee_shares_of(latency_stats, total_fee, &validator_set.validators)
+}
+
+/// Fee each validator is owed for minting a burn of `amount`
+///
+/// From the FeeConfig schedule once it exists, else the fixed
+/// MintState.fee_per_validator.
+pub fn validator_fee_for(mint_state: &MintState, fee_config: &AccountInfo, amount: u64) -> Result<u64> {
+    Ok(match FeeConfig::load(fee_config)? {
+        Some(config) => config.fee_for(amount),
+        None => mint_state.fee_per_validator,
+    })
+}
+
+/// Whether mints charge validator fees at all (whatever the amount)
+pub fn fees_enabled(mint_state: &MintState, fee_config: &AccountInfo) -> Result<bool> {
+    Ok(match FeeConfig::load(fee_config)? {
+        Some(config) => config.charges_fees(),
+        None => mint_state.fee_per_validator > 0,
+    })
+}
+
+/// Leading remaining_accounts a mint spends on validator fee accounts
+///
+/// One per validator while fees are paid directly; none once the fee pool
+/// exists or when there is no fee (see fees_enabled).
+pub fn fee_account_count(fee_pool: &AccountInfo, fees_enabled: bool, validator_set: &X1ValidatorSet) -> Result<usize> {
+    if !fees_enabled || FeePool::load(fee_pool)?.is_some() {
+        return Ok(0);
+    }
+    Ok(validator_set.validators.len())
+}
+
+/// Pay every validator its share of one mint's fee, in set order
+///
+/// With an initialized FeePool the whole fee goes to the pool in one
+/// transfer and is credited there (withdraw_fees pays it out later), so
+/// `remaining_accounts` need not carry any validator account. Without one,
+/// `remaining_accounts[i]` is validator i's fee account (pay_validator_fee).
+pub fn distribute_validator_fees<'info>(
+    payer: &AccountInfo<'info>,
+    fee_pool: &AccountInfo<'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    validators: &[Pubkey],
+    shares: Vec<u64>,
+) -> Result<()> {
+    if let Some(mut pool) = FeePool::load(fee_pool)? {
+        let total: u64 = shares.iter().try_fold(0u64, |sum, share| sum.checked_add(*share)).ok_or(MintError::Overflow)?;
+        anchor_lang::solana_program::program::invoke(
+            &anchor_lang::solana_program::system_instruction::transfer(payer.key, fee_pool.key, total),
+            &[payer.clone(), fee_pool.clone()],
+        )?;
+        for (validator, share) in validators.iter().zip(shares) {
+            pool.credit(validator, share)?;
+        }
+        pool.try_serialize(&mut &mut fee_pool.try_borrow_mut_data()?[..])?;
+        msg!("✓ {} lamports of fees accrued to the fee pool", total);
+        return Ok(());
+    }
+
+    for (i, (validator, share)) in validators.iter().zip(shares).enumerate() {
+        let recipient = remaining_accounts.get(i).ok_or(MintError::MissingValidatorAccount)?;
+        pay_validator_fee(payer, recipient, validator, share)?;
+        msg!("✓ Transferred {} lamports to validator {}", share, validator);
+    }
+    Ok(())
+}
+
+/// The FeeVault `recipient` is, or None if it is the validator key itself
+///
+/// Fails unless `recipient` is one of the two fee accounts of `validator`.
+pub fn validator_fee_account(recipient: &AccountInfo, validator: &Pubkey) -> Result<Option<FeeVault>> {
+    require!(recipient.is_writable, MintError::ValidatorAccountNotWritable);
+    if recipient.key() == *validator {
+        return Ok(None);
+    }
+    // Only this program can create FeeVault accounts, so owner + discriminator
+    // + validator field identify the validator's vault
+    require!(recipient.owner == &crate::ID, MintError::InvalidValidatorAccount);
+    let vault = FeeVault::try_deserialize(&mut &recipient.try_borrow_data()?[..])?;
+    require!(vault.validator == *validator, MintError::InvalidValidatorAccount);
+    Ok(Some(vault))
+}
+
+/// Pay one validator's mint fee
+///
+/// `recipient` is either the validator key itself or, if the validator
+/// opted in with initialize_fee_vault, its FeeVault PDA. Vault payments are
+/// credited to `balance`, less the validator's commission, and claimed later
+/// with claim_validator_fees (claim_validator_commission for the commission).
+pub fn pay_validator_fee<'info>(
+    payer: &AccountInfo<'info>,
+    recipient: &AccountInfo<'info>,
+    validator: &Pubkey,
+    fee: u64,
+) -> Result<()> {
+    let vault = validator_fee_account(recipient, validator)?;
+
+    anchor_lang::solana_program::program::invoke(
+        &anchor_lang::solana_program::system_instruction::transfer(payer.key, recipient.key, fee),
+        &[payer.clone(), recipient.clone()],
+    )?;
+
+    if let Some(mut vault) = vault {
+        vault.accrue(fee)?;
+        vault.try_serialize(&mut &mut recipient.try_borrow_mut_data()?[..])?;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,121 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{self, Burn, Mint, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, FeatureGate, state::{gates, transfer_id, TRANSFER_SOURCE_X1}};
+
+/// Burn X1 XENCAT to have it released on Solana (X1 → Solana)
+///
+/// Records a ReturnBurnRecord under the next return nonce. X1 validators
+/// attest to it in the light client (verify_return_burn), and the burn
+/// program releases `amount` to `solana_recipient` from its return reserve
+/// once the settlement validators sign the release.
+#[derive(Accounts)]
+pub struct BurnForReturn<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        seeds = [b"return_bridge"],
+        bump = return_bridge.bump
+    )]
+    pub return_bridge: Account<'info, ReturnBridge>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ReturnBurnRecord::INIT_SPACE,
+        seeds = [b"return_burn", return_bridge.next_nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub return_burn: Account<'info, ReturnBurnRecord>,
+
+    #[account(
+        mut,
+        address = mint_state.xencat_mint
+    )]
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
+
+    /// User's XENCAT token account (the token program checks its mint)
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ MintError::InvalidUser
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Governance kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler(ctx: Context<BurnForReturn>, amount: u64, solana_recipient: Pubkey) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::RETURN)?;
+    require!(amount > 0, MintError::InvalidReturnAmount);
+
+    token_interface::burn(
+        CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Burn {
+                mint: ctx.accounts.xencat_mint.to_account_info(),
+                from: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ),
+        amount,
+    )?;
+
+    let clock = Clock::get()?;
+    let return_bridge = &mut ctx.accounts.return_bridge;
+    let nonce = return_bridge.next_nonce;
+
+    let record = &mut ctx.accounts.return_burn;
+    record.nonce = nonce;
+    record.user = ctx.accounts.user.key();
+    record.solana_recipient = solana_recipient;
+    record.amount = amount;
+    record.slot = clock.slot;
+    record.timestamp = clock.unix_timestamp;
+    record.bump = ctx.bumps.return_burn;
+
+    return_bridge.next_nonce = nonce.checked_add(1).ok_or(MintError::ArithmeticOverflow)?;
+    return_bridge.total_burns = return_bridge.total_burns.checked_add(1)
+        .ok_or(MintError::ArithmeticOverflow)?;
+    return_bridge.total_amount = return_bridge.total_amount.checked_add(amount)
+        .ok_or(MintError::ArithmeticOverflow)?;
+
+    emit!(ReturnBurned {
+        transfer_id: transfer_id(TRANSFER_SOURCE_X1, 1, nonce),
+        nonce,
+        user: record.user,
+        solana_recipient,
+        amount,
+        slot: clock.slot,
+    });
+
+    msg!("✓ Burned {} XENCAT for return to {} (return nonce {})", amount, solana_recipient, nonce);
+
+    Ok(())
+}
+
+/// Event emitted when XENCAT is burned on X1 for release on Solana
+#[event]
+pub struct ReturnBurned {
+    /// transfer_id("x1", asset_id = 1, nonce)
+    pub transfer_id: [u8; 32],
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub solana_recipient: Pubkey,
+    pub amount: u64,
+    pub slot: u64,
+}
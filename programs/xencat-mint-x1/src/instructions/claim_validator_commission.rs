@@ -0,0 +1,61 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+/// Claim accrued validator commission (CPI-friendly)
+///
+/// Only the vault's `commission_recipient` signs, so a delegator pool
+/// program can claim with invoke_signed. Returns the claimed amount.
+#[derive(Accounts)]
+pub struct ClaimValidatorCommission<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_vault", fee_vault.validator.as_ref()],
+        bump = fee_vault.bump,
+        constraint = fee_vault.commission_recipient == commission_recipient.key() @ MintError::Unauthorized
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    pub commission_recipient: Signer<'info>,
+
+    /// CHECK: Any account chosen by the recipient (except the vault itself)
+    #[account(mut, constraint = destination.key() != fee_vault.key() @ MintError::InvalidRecipient)]
+    pub destination: UncheckedAccount<'info>,
+}
+
+/// `amount`: lamports to claim, or None for the whole commission balance
+pub fn handler(ctx: Context<ClaimValidatorCommission>, amount: Option<u64>) -> Result<u64> {
+    let vault = &mut ctx.accounts.fee_vault;
+    let amount = amount.unwrap_or(vault.commission_balance);
+    require!(
+        amount > 0 && amount <= vault.commission_balance,
+        MintError::InvalidCommissionClaimAmount
+    );
+
+    // Vault lamports = rent-exempt minimum + balance + commission_balance
+    **vault.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **ctx.accounts.destination.try_borrow_mut_lamports()? += amount;
+
+    vault.commission_balance -= amount;
+
+    emit!(ValidatorCommissionClaimed {
+        validator: vault.validator,
+        commission_recipient: ctx.accounts.commission_recipient.key(),
+        destination: ctx.accounts.destination.key(),
+        amount,
+        remaining: vault.commission_balance,
+    });
+
+    msg!("✓ Claimed {} lamports of commission on validator {} ({} left)", amount, vault.validator, vault.commission_balance);
+
+    Ok(amount)
+}
+
+#[event]
+pub struct ValidatorCommissionClaimed {
+    pub validator: Pubkey,
+    pub commission_recipient: Pubkey,
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct CloseMintStream<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        close = user,
+        has_one = user,
+        seeds = [b"mint_stream", user.key().as_ref()],
+        bump = mint_stream.bump
+    )]
+    pub mint_stream: Account<'info, MintStream>,
+}
+
+/// Close the stream (rent refunded); later burns need the user again
+pub fn handler(ctx: Context<CloseMintStream>) -> Result<()> {
+    let stream = &ctx.accounts.mint_stream;
+    msg!("✓ Mint stream closed after {} burns ({} tokens)", stream.burns_streamed, stream.total_streamed);
+
+    Ok(())
+}
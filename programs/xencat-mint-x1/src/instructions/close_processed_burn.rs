@@ -0,0 +1,108 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, Asset, BridgeConfig, VerifiedBurn, state::BurnAgeLimit};
+
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64)]
+pub struct CloseProcessedBurn<'info> {
+    /// The burn's user (receives the rent)
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Replay guard of the minted burn
+    #[account(
+        mut,
+        close = user,
+        seeds = [
+            b"processed_burn_v3",
+            &[Asset::XENCAT as u8],
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump,
+        constraint = processed_burn.user == user.key() @ MintError::InvalidUser,
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    /// The burn's VerifiedBurnV3 (must be archived, i.e. closed)
+    /// CHECK: Address pinned by seeds; only checked to be empty
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            &[Asset::XENCAT as u8],
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID,
+    )]
+    pub verified_burn: UncheckedAccount<'info>,
+
+    /// The burn's V2 proof, which upgrade_verified_burn_to_v3 could turn
+    /// into a new VerifiedBurnV3 (must be absent or retired)
+    /// CHECK: Address pinned by seeds; owner checked before deserializing
+    #[account(
+        seeds = [
+            b"verified_burn_v2",
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID,
+    )]
+    pub verified_burn_v2: UncheckedAccount<'info>,
+
+    /// Bridge configuration (from light client) - V2 deprecation cutoff
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Light client burn age limit (not initialized = nothing closable)
+    /// CHECK: Address pinned by seeds; read by BurnAgeLimit::load
+    #[account(seeds = [b"burn_age_limit"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub burn_age_limit: UncheckedAccount<'info>,
+}
+
+/// Close a minted burn's replay guard and refund its rent to the user
+///
+/// Only safe once the burn can never be verified again, so it requires:
+/// - the VerifiedBurnV3 archived (archive_verified_burn / sweep_verified_burn)
+/// - the burn past the light client's burn age limit, which V3 attestations
+///   of it now fail
+/// - V2 submissions cut off and any V2 proof of the burn already upgraded,
+///   so upgrade_verified_burn_to_v3 cannot bring it back
+///
+/// The rent goes to the user, whoever paid it (a relayer for claims on
+/// behalf): the guard does not record its payer.
+pub fn handler(ctx: Context<CloseProcessedBurn>, burn_nonce: u64) -> Result<()> {
+    let processed = &ctx.accounts.processed_burn;
+    let clock = Clock::get()?;
+
+    require!(ctx.accounts.verified_burn.data_is_empty(), MintError::VerifiedBurnNotArchived);
+
+    let max_burn_age = BurnAgeLimit::load(&ctx.accounts.burn_age_limit)?;
+    require!(
+        BurnAgeLimit::is_expired(max_burn_age, processed.processed_at, clock.unix_timestamp),
+        MintError::BurnNotExpired
+    );
+
+    require!(
+        ctx.accounts.bridge_config.is_v2_deprecated(clock.slot),
+        MintError::V2BurnNotRetired
+    );
+    let v2_info = &ctx.accounts.verified_burn_v2;
+    if !v2_info.data_is_empty() {
+        require_keys_eq!(*v2_info.owner, LIGHT_CLIENT_ID, MintError::V2BurnNotRetired);
+        let v2 = VerifiedBurn::try_deserialize(&mut &v2_info.try_borrow_data()?[..])?;
+        require!(v2.processed, MintError::V2BurnNotRetired);
+    }
+
+    msg!("🧹 Replay guard closed: burn {} of {}", burn_nonce, processed.user);
+    msg!("   Minted {} at {}", processed.amount, processed.processed_at);
+
+    Ok(())
+}
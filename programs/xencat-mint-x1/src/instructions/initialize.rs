@@ -1,5 +1,9 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{Mint, Token};
+use anchor_spl::token_interface::{Mint, TokenInterface};
+use anchor_spl::token_2022::spl_token_2022::{
+    extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions},
+    state::Mint as SplMint,
+};
 use crate::state::*;
 use crate::errors::*;
 
@@ -20,17 +24,37 @@ pub struct Initialize<'info> {
         bump,
         constraint = xencat_mint.decimals == 6 @ MintError::InvalidMintDecimals,
     )]
-    pub xencat_mint: Account<'info, Mint>,
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
 
     #[account(mut)]
     pub authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+/// Token-2022 extensions that let someone other than this program move,
+/// destroy or re-create bridged tokens (transfer hooks, fees etc. are fine)
+const REJECTED_EXTENSIONS: [ExtensionType; 2] = [
+    ExtensionType::PermanentDelegate,
+    ExtensionType::MintCloseAuthority,
+];
+
+/// Reject Token-2022 mints carrying a REJECTED_EXTENSIONS entry (legacy mints have none)
+fn check_mint_extensions(mint: &AccountInfo) -> Result<()> {
+    let data = mint.try_borrow_data()?;
+    let extensions = StateWithExtensions::<SplMint>::unpack(&data)?.get_extension_types()?;
+    require!(
+        !extensions.iter().any(|extension| REJECTED_EXTENSIONS.contains(extension)),
+        MintError::UnsupportedMintExtension
+    );
+    Ok(())
+}
+
 pub fn handler(ctx: Context<Initialize>, light_client_program: Pubkey) -> Result<()> {
+    check_mint_extensions(&ctx.accounts.xencat_mint.to_account_info())?;
+
     let state = &mut ctx.accounts.mint_state;
 
     state.authority = ctx.accounts.authority.key();
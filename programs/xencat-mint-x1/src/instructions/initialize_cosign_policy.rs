@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeCoSignPolicy<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CoSignPolicy::INIT_SPACE,
+        seeds = [b"cosign_policy"],
+        bump
+    )]
+    pub cosign_policy: Account<'info, CoSignPolicy>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the co-sign policy (enabled): mints above `threshold` need `cosigner`
+pub fn handler(ctx: Context<InitializeCoSignPolicy>, cosigner: Pubkey, threshold: u64) -> Result<()> {
+    let policy = &mut ctx.accounts.cosign_policy;
+    policy.enabled = true;
+    policy.cosigner = cosigner;
+    policy.threshold = threshold;
+    policy.bump = ctx.bumps.cosign_policy;
+
+    msg!("✓ Co-sign policy initialized: mints above {} need {}", threshold, cosigner);
+
+    Ok(())
+}
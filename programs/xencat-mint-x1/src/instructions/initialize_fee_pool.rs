@@ -0,0 +1,36 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeFeePool<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + FeePool::INIT_SPACE,
+        seeds = [b"fee_pool"],
+        bump
+    )]
+    pub fee_pool: Account<'info, FeePool>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the fee pool (run once, permissionless)
+///
+/// From then on every mint accrues validator fees here instead of paying
+/// the accounts in remaining_accounts. Each validator still receives
+/// exactly its share, through withdraw_fees.
+pub fn handler(ctx: Context<InitializeFeePool>) -> Result<()> {
+    let fee_pool = &mut ctx.accounts.fee_pool;
+    fee_pool.balances = Vec::new();
+    fee_pool.total_accrued = 0;
+    fee_pool.total_withdrawn = 0;
+    fee_pool.bump = ctx.bumps.fee_pool;
+
+    msg!("✓ Fee pool created: mint fees now accrue here");
+
+    Ok(())
+}
@@ -0,0 +1,39 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeFeeVault<'info> {
+    /// Validator signing key (the key listed in the light client validator set)
+    #[account(mut)]
+    pub validator: Signer<'info>,
+
+    #[account(
+        init,
+        payer = validator,
+        space = 8 + FeeVault::INIT_SPACE,
+        seeds = [b"fee_vault", validator.key().as_ref()],
+        bump
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Opt in to vault fee collection; `authority` signs future claims
+pub fn handler(ctx: Context<InitializeFeeVault>, authority: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.fee_vault;
+    vault.validator = ctx.accounts.validator.key();
+    vault.authority = authority;
+    vault.balance = 0;
+    vault.total_collected = 0;
+    vault.total_claimed = 0;
+    vault.commission_bps = 0;
+    vault.commission_recipient = Pubkey::default();
+    vault.commission_balance = 0;
+    vault.total_commission = 0;
+    vault.bump = ctx.bumps.fee_vault;
+
+    msg!("✓ Fee vault opened for {} (claim authority {})", vault.validator, authority);
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+
+#[derive(Accounts)]
+pub struct InitializeMintPause<'info> {
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + MintPause::INIT_SPACE,
+        seeds = [b"mint_pause"],
+        bump
+    )]
+    pub mint_pause: Account<'info, MintPause>,
+
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the mint pause switch (run once, permissionless)
+///
+/// Starts unpaused, same as before the account existed.
+pub fn handler(ctx: Context<InitializeMintPause>) -> Result<()> {
+    let mint_pause = &mut ctx.accounts.mint_pause;
+    mint_pause.paused = false;
+    mint_pause.updated_slot = Clock::get()?.slot;
+    mint_pause.nonce = 0;
+    mint_pause.bump = ctx.bumps.mint_pause;
+
+    msg!("✓ Mint pause created (not paused)");
+
+    Ok(())
+}
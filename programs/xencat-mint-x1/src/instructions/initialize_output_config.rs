@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeOutputConfig<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + OutputConfig::INIT_SPACE,
+        seeds = [b"output_config"],
+        bump
+    )]
+    pub output_config: Account<'info, OutputConfig>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the output config (compressed output disabled)
+pub fn handler(ctx: Context<InitializeOutputConfig>) -> Result<()> {
+    let output_config = &mut ctx.accounts.output_config;
+    output_config.compressed_enabled = false;
+    output_config.bump = ctx.bumps.output_config;
+
+    msg!("✓ Output config initialized (compressed output disabled)");
+
+    Ok(())
+}
@@ -0,0 +1,50 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeRateLimit<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RateLimit::INIT_SPACE,
+        seeds = [b"rate_limit"],
+        bump
+    )]
+    pub rate_limit: Account<'info, RateLimit>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the mint rate limit: at most `cap` minted per `window_seconds`
+///
+/// Only the cap can change afterwards, and only with validator approval
+/// (set_rate_limit).
+pub fn handler(ctx: Context<InitializeRateLimit>, window_seconds: i64, cap: u64) -> Result<()> {
+    require!(window_seconds >= RateLimit::MIN_WINDOW_SECONDS, MintError::InvalidRateLimitWindow);
+
+    let rate_limit = &mut ctx.accounts.rate_limit;
+    rate_limit.window_seconds = window_seconds;
+    rate_limit.cap = cap;
+    rate_limit.pending_cap = 0;
+    rate_limit.pending_cap_at = 0;
+    rate_limit.window_start = Clock::get()?.unix_timestamp;
+    rate_limit.current_window_minted = 0;
+    rate_limit.previous_window_minted = 0;
+    rate_limit.nonce = 0;
+    rate_limit.bump = ctx.bumps.rate_limit;
+
+    msg!("✓ Rate limit initialized: {} per {}s", cap, window_seconds);
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct InitializeReturnBridge<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ReturnBridge::INIT_SPACE,
+        seeds = [b"return_bridge"],
+        bump
+    )]
+    pub return_bridge: Account<'info, ReturnBridge>,
+
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Create the return bridge counters (first return nonce is 0)
+pub fn handler(ctx: Context<InitializeReturnBridge>) -> Result<()> {
+    let return_bridge = &mut ctx.accounts.return_bridge;
+    return_bridge.next_nonce = 0;
+    return_bridge.total_burns = 0;
+    return_bridge.total_amount = 0;
+    return_bridge.bump = ctx.bumps.return_bridge;
+
+    msg!("✓ Return bridge initialized");
+
+    Ok(())
+}
@@ -0,0 +1,268 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke;
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{distribute_validator_fees, fee_account_count, fees_enabled, validator_fee_for, validator_fee_shares};
+use crate::instructions::mint_from_burn_v3::{emit_mint_acknowledgement, MintedFromBurnV3};
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, VerifiedBurnV3, RelayerClaim, X1ValidatorSet, FeatureGate, state::{gates, AssetRegistry}};
+
+/// Mint a verified burn to the user, then call an allowlisted program (V3)
+///
+/// Same checks, replay PDA and validator fees as mint_from_burn_v3; the
+/// user signs and receives the tokens. Afterwards `target_program` is
+/// invoked with `data` and the downstream accounts, in the same
+/// transaction ("bridge and deposit"). The user's signature carries over
+/// to that call; the mint state never signs it. remaining_accounts:
+/// - [0..n)  validator fee accounts (fees_enabled, no fee pool; else n = 0)
+/// - [n..]   accounts of the downstream instruction, passed as given
+///
+/// The co-sign policy's co-signer, when it applies, goes last and is
+/// passed downstream too.
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64, asset_id: u8)]
+pub struct MintAndCall<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        address = mint_state.xencat_mint
+    )]
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
+
+    /// Processed burn tracker V3 (same PDA as mint_from_burn_v3 - one mint per burn)
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ProcessedBurnV3::INIT_SPACE,
+        seeds = [
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+        constraint = !verified_burn.processed @ MintError::ProofAlreadyProcessed,
+        constraint = verified_burn.user == user.key() @ MintError::InvalidUser,
+        constraint = verified_burn.burn_nonce == burn_nonce @ MintError::NonceMismatch,
+        constraint = verified_burn.asset_id == asset_id @ MintError::AssetMismatch,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Relayer claim of the burn (initialized = only mint_for_recipient mints it)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    #[account(
+        seeds = [b"cpi_allowlist"],
+        bump = cpi_allowlist.bump,
+        constraint = cpi_allowlist.allows(&target_program.key()) @ MintError::ProgramNotAllowlisted,
+    )]
+    pub cpi_allowlist: Account<'info, CpiAllowlist>,
+
+    /// CHECK: Any program on the CPI allowlist (checked above)
+    #[account(executable)]
+    pub target_program: UncheckedAccount<'info>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Shared validator fee accrual (not initialized = fees paid via remaining_accounts)
+    /// CHECK: Address pinned by seeds; read and credited by fees::distribute_validator_fees
+    #[account(mut, seeds = [b"fee_pool"], bump)]
+    pub fee_pool: UncheckedAccount<'info>,
+
+    /// Amount-based fee schedule (not initialized = fee_per_validator on every burn)
+    /// CHECK: Address pinned by seeds; read by fees::validator_fee_for
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintAndCall<'info>>,
+    burn_nonce: u64,
+    asset_id: u8,
+    data: Vec<u8>,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_V3)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
+    // This program must ONLY mint XENCAT (asset_id = 1)
+    require!(asset_id == 1, MintError::AssetNotMintable);
+
+    let amount = ctx.accounts.verified_burn.amount;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
+    let mint_state = &ctx.accounts.mint_state;
+    let target_program = ctx.accounts.target_program.key();
+
+    msg!("🔗 Mint and call: nonce {} for {}", burn_nonce, ctx.accounts.user.key());
+    msg!("   Amount: {}", amount);
+    msg!("   Then: {}", target_program);
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.xencat_mint.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.mint_state.to_account_info(),
+            },
+            &[&[
+                b"mint_state_v2",
+                &[mint_state.bump]
+            ]],
+        ),
+        amount,
+    )?;
+
+    ctx.accounts.verified_burn.processed = true;
+
+    let processed = &mut ctx.accounts.processed_burn;
+    processed.asset_id = asset_id;
+    processed.nonce = burn_nonce;
+    processed.user = ctx.accounts.user.key();
+    processed.amount = amount;
+    processed.processed_at = Clock::get()?.unix_timestamp;
+
+    // Validator fees first (same rules as mint_from_burn_v3)
+    let validator_set = &ctx.accounts.validator_set;
+    let fee_per_validator = validator_fee_for(mint_state, &ctx.accounts.fee_config, amount)?;
+    let fees_enabled = fees_enabled(mint_state, &ctx.accounts.fee_config)?;
+    let fee_accounts = fee_account_count(&ctx.accounts.fee_pool, fees_enabled, validator_set)?;
+    if fee_per_validator > 0 {
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+        distribute_validator_fees(
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.fee_pool,
+            ctx.remaining_accounts,
+            &validator_set.validators,
+            shares,
+        )?;
+    }
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(1);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(amount);
+
+    emit!(MintedFromBurnV3 {
+        asset_id,
+        nonce: burn_nonce,
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.user_token_account.key())?;
+
+    // Downstream call last, once the mint is fully recorded. Plain invoke:
+    // only signatures of the outer transaction carry over, never the mint state's
+    let downstream = ctx.remaining_accounts.get(fee_accounts..).unwrap_or_default();
+    let instruction = Instruction {
+        program_id: target_program,
+        accounts: downstream
+            .iter()
+            .map(|a| AccountMeta { pubkey: a.key(), is_signer: a.is_signer, is_writable: a.is_writable })
+            .collect(),
+        data,
+    };
+    invoke(&instruction, downstream)?;
+
+    emit!(MintedAndCalled {
+        asset_id,
+        nonce: burn_nonce,
+        user: ctx.accounts.user.key(),
+        amount,
+        target_program,
+    });
+
+    msg!("✓ Minted {} tokens and called {}", amount, target_program);
+
+    Ok(())
+}
+
+/// Event emitted when a mint_and_call's downstream call succeeded
+#[event]
+pub struct MintedAndCalled {
+    pub asset_id: u8,
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub target_program: Pubkey,
+}
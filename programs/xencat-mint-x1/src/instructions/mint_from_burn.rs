@@ -1,8 +1,8 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, MintTo, Token, TokenAccount};
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
 use crate::state::*;
 use crate::errors::*;
-use solana_light_client_x1::{self, ID as LIGHT_CLIENT_ID, VerifiedBurn, X1ValidatorSet};
+use solana_light_client_x1::{self, ID as LIGHT_CLIENT_ID, VerifiedBurn, X1ValidatorSet, BridgeConfig, Asset, FeatureGate, state::{gates, AssetRegistry}};
 
 #[derive(Accounts)]
 #[instruction(burn_nonce: u64)]
@@ -20,7 +20,7 @@ pub struct MintFromBurn<'info> {
         mut,
         address = mint_state.xencat_mint
     )]
-    pub xencat_mint: Account<'info, Mint>,
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
 
     /// Processed burn tracker (prevents replay attacks)
     #[account(
@@ -40,7 +40,7 @@ pub struct MintFromBurn<'info> {
         mut,
         constraint = user_token_account.owner == user.key()
     )]
-    pub user_token_account: Account<'info, TokenAccount>,
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
 
     /// User must be signer AND match verified_burn.user
     #[account(mut)]
@@ -69,7 +69,40 @@ pub struct MintFromBurn<'info> {
     )]
     pub verified_burn: Account<'info, VerifiedBurn>,
 
-    pub token_program: Program<'info, Token>,
+    /// Bridge configuration (from light client) - V2 deprecation cutoff
+    #[account(
+        seeds = [b"bridge_config"],
+        bump = bridge_config.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+    )]
+    pub bridge_config: Account<'info, BridgeConfig>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
     pub system_program: Program<'info, System>,
 }
 
@@ -92,10 +125,21 @@ pub fn handler<'info>(
     ctx: Context<'_, '_, '_, 'info, MintFromBurn<'info>>,
     burn_nonce: u64,
 ) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_V2)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, Asset::XENCAT.to_u8())?;
+
     msg!("╔════════════════════════════════════════╗");
     msg!("║      XENCAT Mint from Verified Burn   ║");
     msg!("╚════════════════════════════════════════╝");
 
+    // V2 is deprecated once the governed cutoff slot is reached
+    // Lingering V2 burns must be re-attested via submit_burn_attestation_v3
+    require!(
+        !ctx.accounts.bridge_config.is_v2_deprecated(Clock::get()?.slot),
+        MintError::V2Deprecated
+    );
+
     let verified = &ctx.accounts.verified_burn;
     let mint_state = &ctx.accounts.mint_state;
 
@@ -112,8 +156,10 @@ pub fn handler<'info>(
     // ===== STEP 2: Mint XENCAT Tokens =====
     // Mint the exact amount that was burned and verified
     let amount = verified.amount;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
 
-    token::mint_to(
+    token_interface::mint_to(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             MintTo {
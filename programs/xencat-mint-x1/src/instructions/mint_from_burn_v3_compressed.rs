@@ -0,0 +1,250 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::invoke_signed;
+use anchor_spl::token_interface::Mint;
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{distribute_validator_fees, fee_account_count, fees_enabled, validator_fee_for, validator_fee_shares};
+use crate::instructions::mint_from_burn_v3::{emit_mint_acknowledgement, MintedFromBurnV3};
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, VerifiedBurnV3, RelayerClaim, X1ValidatorSet, FeatureGate, state::{gates, AssetRegistry}};
+
+/// Light Protocol compressed token program
+pub const COMPRESSED_TOKEN_PROGRAM_ID: Pubkey =
+    anchor_lang::solana_program::pubkey!("cTokenmWW8bLPjZEBAUgYy3zKxQZW6VKi7bqNFEVv3m");
+
+/// Mint XENCAT as a compressed token from a verified burn (V3)
+///
+/// Same checks, replay PDA and fees as mint_from_burn_v3; only the output
+/// differs. remaining_accounts:
+/// - [0..n)  validator fee accounts (fees_enabled, no fee pool; else n = 0)
+/// - [n..)   accounts of the compressed token `mint_to` instruction, in
+///   Light Protocol order (built client-side with the Light SDK),
+///   including mint_state as the mint authority
+/// - [last]  the co-sign policy's co-signer, when it applies (appended
+///   after the Light accounts, so their positions do not shift)
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64, asset_id: u8)]
+pub struct MintFromBurnV3Compressed<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        address = mint_state.xencat_mint
+    )]
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        seeds = [b"output_config"],
+        bump = output_config.bump,
+        constraint = output_config.compressed_enabled @ MintError::CompressedOutputDisabled
+    )]
+    pub output_config: Account<'info, OutputConfig>,
+
+    /// Processed burn tracker V3 (same PDA as mint_from_burn_v3 - one mint per burn)
+    #[account(
+        init,
+        payer = user,
+        space = 8 + ProcessedBurnV3::INIT_SPACE,
+        seeds = [
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+        constraint = !verified_burn.processed @ MintError::ProofAlreadyProcessed,
+        constraint = verified_burn.user == user.key() @ MintError::InvalidUser,
+        constraint = verified_burn.burn_nonce == burn_nonce @ MintError::NonceMismatch,
+        constraint = verified_burn.asset_id == asset_id @ MintError::AssetMismatch,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Relayer claim of the burn (initialized = only mint_for_recipient mints it)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    /// CHECK: Light compressed token program (address checked)
+    #[account(address = COMPRESSED_TOKEN_PROGRAM_ID @ MintError::InvalidCompressedTokenProgram)]
+    pub compressed_token_program: UncheckedAccount<'info>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Shared validator fee accrual (not initialized = fees paid via remaining_accounts)
+    /// CHECK: Address pinned by seeds; read and credited by fees::distribute_validator_fees
+    #[account(mut, seeds = [b"fee_pool"], bump)]
+    pub fee_pool: UncheckedAccount<'info>,
+
+    /// Amount-based fee schedule (not initialized = fee_per_validator on every burn)
+    /// CHECK: Address pinned by seeds; read by fees::validator_fee_for
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+/// Instruction data for compressed token `mint_to(public_keys, amounts, lamports)`
+///
+/// Borsh layout: discriminator || vec![recipient] || vec![amount] || None
+fn compressed_mint_to_data(recipient: &Pubkey, amount: u64) -> Vec<u8> {
+    let mut data = Vec::with_capacity(8 + 4 + 32 + 4 + 8 + 1);
+    data.extend_from_slice(&hash(b"global:mint_to").to_bytes()[..8]);
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(recipient.as_ref());
+    data.extend_from_slice(&1u32.to_le_bytes());
+    data.extend_from_slice(&amount.to_le_bytes());
+    data.push(0); // lamports: None
+    data
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintFromBurnV3Compressed<'info>>,
+    burn_nonce: u64,
+    asset_id: u8,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_COMPRESSED)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
+    // This program must ONLY mint XENCAT (asset_id = 1)
+    require!(asset_id == 1, MintError::AssetNotMintable);
+
+    let amount = ctx.accounts.verified_burn.amount;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
+    let mint_state = &ctx.accounts.mint_state;
+    let validator_set = &ctx.accounts.validator_set;
+
+    msg!("🗜️  Compressed mint: nonce {} for {}", burn_nonce, ctx.accounts.user.key());
+    msg!("   Amount: {}", amount);
+
+    // Validator fees first (same rules as mint_from_burn_v3)
+    let fee_per_validator = validator_fee_for(mint_state, &ctx.accounts.fee_config, amount)?;
+    let fees_enabled = fees_enabled(mint_state, &ctx.accounts.fee_config)?;
+    let fee_accounts = fee_account_count(&ctx.accounts.fee_pool, fees_enabled, validator_set)?;
+    if fee_per_validator > 0 {
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+        distribute_validator_fees(
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.fee_pool,
+            ctx.remaining_accounts,
+            &validator_set.validators,
+            shares,
+        )?;
+    }
+
+    // Compressed mint: mint_state PDA signs as mint authority
+    let light_accounts = &ctx.remaining_accounts[fee_accounts..];
+    let mint_state_key = mint_state.key();
+    let metas: Vec<AccountMeta> = light_accounts
+        .iter()
+        .map(|a| AccountMeta {
+            pubkey: a.key(),
+            is_signer: a.is_signer || a.key() == mint_state_key,
+            is_writable: a.is_writable,
+        })
+        .collect();
+    let mut infos = light_accounts.to_vec();
+    infos.push(ctx.accounts.mint_state.to_account_info());
+
+    invoke_signed(
+        &Instruction {
+            program_id: COMPRESSED_TOKEN_PROGRAM_ID,
+            accounts: metas,
+            data: compressed_mint_to_data(&ctx.accounts.user.key(), amount),
+        },
+        &infos,
+        &[&[b"mint_state_v2", &[mint_state.bump]]],
+    )?;
+
+    let processed = &mut ctx.accounts.processed_burn;
+    processed.asset_id = asset_id;
+    processed.nonce = burn_nonce;
+    processed.user = ctx.accounts.user.key();
+    processed.amount = amount;
+    processed.processed_at = Clock::get()?.unix_timestamp;
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(1);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(amount);
+
+    emit!(MintedFromBurnV3 {
+        asset_id,
+        nonce: burn_nonce,
+        user: ctx.accounts.user.key(),
+        amount,
+    });
+    // Compressed output has no token account: the owner is the recipient
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.user.key())?;
+
+    msg!("✓ Minted {} compressed tokens", amount);
+
+    Ok(())
+}
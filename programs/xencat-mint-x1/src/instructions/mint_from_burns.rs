@@ -0,0 +1,310 @@
+use anchor_lang::prelude::*;
+use anchor_lang::Discriminator;
+use anchor_lang::system_program::{self, Allocate, Assign, CreateAccount, Transfer};
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{distribute_validator_fees, fee_account_count, fees_enabled, validator_fee_for, validator_fee_shares};
+use crate::instructions::mint_from_burn_v3::{emit_mint_acknowledgement, report_compute_units, MintedFromBurnV3};
+use solana_light_client_x1::compute_report::ComputeMeter;
+use solana_light_client_x1::{consumer, invariants};
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, Asset, VerifiedBurnV3, RelayerClaim, X1ValidatorSet, FeatureGate, state::{gates, AssetRegistry}};
+
+/// Most burns one mint_from_burns call takes (transaction size and compute)
+pub const MAX_BATCH_BURNS: usize = 10;
+
+/// Mint several of the caller's verified XENCAT burns at once (V3)
+///
+/// Same checks, replay PDAs and per-burn validator fees as
+/// mint_from_burn_v3, but one token CPI for the sum and one transaction
+/// fee. remaining_accounts:
+/// - [0..n)        validator fee accounts (fees_enabled, no fee pool; else n = 0)
+/// - [n + 3i]      VerifiedBurnV3 PDA of burn_nonces[i]
+/// - [n + 3i + 1]  ProcessedBurnV3 PDA of burn_nonces[i] (writable, created here)
+/// - [n + 3i + 2]  RelayerClaim PDA of burn_nonces[i] (must not exist)
+/// - [last]        the co-sign policy's co-signer, when it applies
+#[derive(Accounts)]
+pub struct MintFromBurns<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        address = mint_state.xencat_mint
+    )]
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key()
+    )]
+    pub user_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// Owner of every burn in the batch; pays the replay PDAs and fees
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Shared validator fee accrual (not initialized = fees paid via remaining_accounts)
+    /// CHECK: Address pinned by seeds; read and credited by fees::distribute_validator_fees
+    #[account(mut, seeds = [b"fee_pool"], bump)]
+    pub fee_pool: UncheckedAccount<'info>,
+
+    /// Amount-based fee schedule (not initialized = fee_per_validator on every burn)
+    /// CHECK: Address pinned by seeds; read by fees::validator_fee_for
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Mint the sum of the caller's verified burns `burn_nonces` (increasing,
+/// at most MAX_BATCH_BURNS)
+///
+/// The co-sign policy and the rate limit apply to the sum. Each burn still
+/// gets its own ProcessedBurnV3, events and validator fee, so the batch is
+/// indistinguishable from the same burns minted one by one.
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintFromBurns<'info>>,
+    burn_nonces: Vec<u64>,
+) -> Result<()> {
+    let meter = ComputeMeter::start();
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_V3)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    let asset_id = Asset::XENCAT.to_u8();
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+
+    require!(
+        !burn_nonces.is_empty()
+            && burn_nonces.len() <= MAX_BATCH_BURNS
+            && burn_nonces.windows(2).all(|pair| pair[0] < pair[1]),
+        MintError::InvalidBurnBatch
+    );
+
+    let user = ctx.accounts.user.key();
+    let fees_enabled = fees_enabled(&ctx.accounts.mint_state, &ctx.accounts.fee_config)?;
+    let fee_accounts = fee_account_count(&ctx.accounts.fee_pool, fees_enabled, &ctx.accounts.validator_set)?;
+    let burn_accounts = ctx
+        .remaining_accounts
+        .get(fee_accounts..fee_accounts + 3 * burn_nonces.len())
+        .ok_or(MintError::InvalidBurnBatchAccount)?;
+
+    msg!("Minting {} XENCAT burns for {}", burn_nonces.len(), user);
+
+    // ===== STEP 1: Check every burn and record it as processed =====
+    let now = Clock::get()?.unix_timestamp;
+    let mut total: u64 = 0;
+    let mut amounts = Vec::with_capacity(burn_nonces.len());
+    for (&burn_nonce, accounts) in burn_nonces.iter().zip(burn_accounts.chunks(3)) {
+        let (verified_info, processed_info, claim_info) = (&accounts[0], &accounts[1], &accounts[2]);
+
+        require_keys_eq!(*verified_info.owner, LIGHT_CLIENT_ID, MintError::InvalidBurnBatchAccount);
+        let verified = VerifiedBurnV3::try_deserialize(&mut &verified_info.try_borrow_data()?[..])?;
+        let expected = Pubkey::create_program_address(
+            &[
+                b"verified_burn_v3",
+                asset_id.to_le_bytes().as_ref(),
+                user.as_ref(),
+                burn_nonce.to_le_bytes().as_ref(),
+                &[verified.bump],
+            ],
+            &LIGHT_CLIENT_ID,
+        )
+        .map_err(|_| MintError::InvalidBurnBatchAccount)?;
+        require_keys_eq!(verified_info.key(), expected, MintError::InvalidBurnBatchAccount);
+        require!(!verified.processed, MintError::ProofAlreadyProcessed);
+        require_keys_eq!(verified.user, user, MintError::InvalidUser);
+        require!(verified.burn_nonce == burn_nonce, MintError::NonceMismatch);
+        require!(verified.asset_id == asset_id, MintError::AssetMismatch);
+        let (claim, _) = consumer::relayer_claim_address(asset_id, &user, burn_nonce);
+        require_keys_eq!(claim_info.key(), claim, MintError::InvalidBurnBatchAccount);
+        RelayerClaim::require_unclaimed(claim_info)?;
+
+        let processed = ProcessedBurnV3 {
+            asset_id,
+            nonce: burn_nonce,
+            user,
+            amount: verified.amount,
+            processed_at: now,
+        };
+        create_processed_burn(
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            processed_info,
+            &processed,
+        )?;
+        invariants::debug_check(invariants::processed_implies_verified(
+            &processed.record(),
+            Some(&verified),
+            false,
+        ));
+
+        total = total.checked_add(verified.amount).ok_or(MintError::Overflow)?;
+        amounts.push(verified.amount);
+        msg!("✓ Burn {} verified: {} tokens", burn_nonce, verified.amount);
+    }
+
+    // ===== STEP 2: One mint for the sum =====
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, total, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, total)?;
+
+    let mint_state = &ctx.accounts.mint_state;
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.xencat_mint.to_account_info(),
+                to: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.mint_state.to_account_info(),
+            },
+            &[&[
+                b"mint_state_v2",
+                &[mint_state.bump]
+            ]],
+        ),
+        total,
+    )?;
+
+    msg!("✓ Minted {} tokens", total);
+
+    // ===== STEP 3: Validator fees, once per burn =====
+    if fees_enabled {
+        let validator_set = &ctx.accounts.validator_set;
+        let mut fees = vec![0u64; validator_set.validators.len()];
+        for &amount in &amounts {
+            let fee_per_validator = validator_fee_for(&ctx.accounts.mint_state, &ctx.accounts.fee_config, amount)?;
+            let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+            for (fee, share) in fees.iter_mut().zip(shares) {
+                *fee = fee.checked_add(share).ok_or(MintError::Overflow)?;
+            }
+        }
+        let burns = burn_nonces.len() as u64;
+
+        distribute_validator_fees(
+            &ctx.accounts.user.to_account_info(),
+            &ctx.accounts.fee_pool,
+            ctx.remaining_accounts,
+            &validator_set.validators,
+            fees,
+        )?;
+
+        msg!("✓ Fees distributed for {} burns", burns);
+    }
+
+    // ===== STEP 4: Statistics and events =====
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(burn_nonces.len() as u64);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(total);
+
+    let recipient = ctx.accounts.user_token_account.key();
+    for (&burn_nonce, &amount) in burn_nonces.iter().zip(&amounts) {
+        emit!(MintedFromBurnV3 {
+            asset_id,
+            nonce: burn_nonce,
+            user,
+            amount,
+        });
+        emit_mint_acknowledgement(asset_id, burn_nonce, amount, recipient)?;
+    }
+
+    msg!("✓ Batch of {} burns minted", burn_nonces.len());
+
+    report_compute_units(&meter, crate::instruction::MintFromBurns::DISCRIMINATOR, burn_nonces.len() as u8);
+
+    Ok(())
+}
+
+/// Create the ProcessedBurnV3 replay PDA of one batch burn
+///
+/// What `init` does for mint_from_burn_v3: fails if the burn was already
+/// minted (the account exists), and also works if someone pre-funded the
+/// address with lamports.
+fn create_processed_burn<'info>(
+    payer: &AccountInfo<'info>,
+    system: &AccountInfo<'info>,
+    info: &AccountInfo<'info>,
+    processed: &ProcessedBurnV3,
+) -> Result<()> {
+    let nonce_bytes = processed.nonce.to_le_bytes();
+    let asset_bytes = processed.asset_id.to_le_bytes();
+    let (expected, bump) = Pubkey::find_program_address(
+        &[b"processed_burn_v3", asset_bytes.as_ref(), nonce_bytes.as_ref(), processed.user.as_ref()],
+        &crate::ID,
+    );
+    require_keys_eq!(info.key(), expected, MintError::InvalidBurnBatchAccount);
+    require!(info.data_is_empty() && info.owner == &System::id(), MintError::ProofAlreadyProcessed);
+
+    let seeds: &[&[u8]] = &[b"processed_burn_v3", asset_bytes.as_ref(), nonce_bytes.as_ref(), processed.user.as_ref(), &[bump]];
+    let space = 8 + ProcessedBurnV3::INIT_SPACE;
+    let rent = Rent::get()?.minimum_balance(space);
+
+    if info.lamports() == 0 {
+        system_program::create_account(
+            CpiContext::new_with_signer(system.clone(), CreateAccount { from: payer.clone(), to: info.clone() }, &[seeds]),
+            rent,
+            space as u64,
+            &crate::ID,
+        )?;
+    } else {
+        let top_up = rent.saturating_sub(info.lamports());
+        if top_up > 0 {
+            system_program::transfer(
+                CpiContext::new(system.clone(), Transfer { from: payer.clone(), to: info.clone() }),
+                top_up,
+            )?;
+        }
+        system_program::allocate(
+            CpiContext::new_with_signer(system.clone(), Allocate { account_to_allocate: info.clone() }, &[seeds]),
+            space as u64,
+        )?;
+        system_program::assign(
+            CpiContext::new_with_signer(system.clone(), Assign { account_to_assign: info.clone() }, &[seeds]),
+            &crate::ID,
+        )?;
+    }
+
+    processed.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
@@ -0,0 +1,280 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::hash::hash;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as IX_SYSVAR_ID,
+};
+use anchor_spl::token_interface::{self, Mint, MintTo, TokenInterface, TokenAccount};
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::{distribute_validator_fees, validator_fee_for, validator_fee_shares};
+use crate::instructions::mint_from_burn_v3::emit_mint_acknowledgement;
+use solana_light_client_x1::invariants;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, VerifiedBurnV3, RelayerClaim, X1ValidatorSet, FeatureGate, state::{gates, AssetRegistry}};
+
+/// Mint a stealth-addressed verified burn (V3)
+///
+/// Same checks as mint_from_burn_v3, except:
+/// - `user` is the one-time stealth key and does NOT sign the transaction
+/// - Ownership is proven by an Ed25519 signature of the stealth key over
+///   the claim message (see `stealth_claim_message`), verified by the
+///   Ed25519 program instruction placed right before this one
+/// - Anyone (typically a relayer) pays rent and validator fees, so the
+///   stealth key never needs to be funded for the mint
+#[derive(Accounts)]
+#[instruction(burn_nonce: u64, asset_id: u8)]
+pub struct MintToStealth<'info> {
+    #[account(
+        mut,
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        address = mint_state.xencat_mint
+    )]
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
+
+    /// Processed burn tracker V3 (same PDA as mint_from_burn_v3 - one mint per burn)
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ProcessedBurnV3::INIT_SPACE,
+        seeds = [
+            b"processed_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            burn_nonce.to_le_bytes().as_ref(),
+            user.key().as_ref()
+        ],
+        bump
+    )]
+    pub processed_burn: Account<'info, ProcessedBurnV3>,
+
+    /// Destination chosen by the stealth key holder (bound by the claim signature)
+    #[account(
+        mut,
+        constraint = recipient_token_account.mint == mint_state.xencat_mint @ MintError::InvalidRecipient
+    )]
+    pub recipient_token_account: InterfaceAccount<'info, TokenAccount>,
+
+    /// CHECK: Stealth key; ownership proven by the Ed25519 claim signature
+    pub user: UncheckedAccount<'info>,
+
+    /// Relayer paying rent and fees
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    #[account(
+        seeds = [
+            b"verified_burn_v3",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump = verified_burn.bump,
+        seeds::program = LIGHT_CLIENT_ID,
+        constraint = !verified_burn.processed @ MintError::ProofAlreadyProcessed,
+        constraint = verified_burn.user == user.key() @ MintError::InvalidUser,
+        constraint = verified_burn.burn_nonce == burn_nonce @ MintError::NonceMismatch,
+        constraint = verified_burn.asset_id == asset_id @ MintError::AssetMismatch,
+    )]
+    pub verified_burn: Account<'info, VerifiedBurnV3>,
+
+    /// Relayer claim of the burn (initialized = only mint_for_recipient mints it)
+    /// CHECK: Address pinned by seeds; read by RelayerClaim::require_unclaimed
+    #[account(
+        seeds = [
+            b"relayer_claim",
+            asset_id.to_le_bytes().as_ref(),
+            user.key().as_ref(),
+            burn_nonce.to_le_bytes().as_ref()
+        ],
+        bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub relayer_claim: UncheckedAccount<'info>,
+
+    /// CHECK: Instructions sysvar (address checked)
+    #[account(address = IX_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    /// Light client kill switches (not initialized = everything enabled)
+    /// CHECK: Address pinned by seeds; read by FeatureGate::require_enabled
+    #[account(seeds = [b"feature_gate"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub feature_gate: UncheckedAccount<'info>,
+
+    /// Light client attestation counters (weights the validator fee split;
+    /// not initialized = equal split)
+    /// CHECK: Address pinned by seeds; read by ValidatorLatencyStats::fee_shares_of
+    #[account(seeds = [b"validator_latency_stats"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub latency_stats: UncheckedAccount<'info>,
+
+    /// Large-mint dual control (not initialized = no co-signer needed)
+    /// CHECK: Address pinned by seeds; read by CoSignPolicy::enforce
+    #[account(seeds = [b"cosign_policy"], bump)]
+    pub cosign_policy: UncheckedAccount<'info>,
+
+    /// Minted volume cap per window (not initialized = no limit)
+    /// CHECK: Address pinned by seeds; read and updated by RateLimit::enforce
+    #[account(mut, seeds = [b"rate_limit"], bump)]
+    pub rate_limit: UncheckedAccount<'info>,
+
+    /// Per-program emergency pause (not initialized = not paused)
+    /// CHECK: Address pinned by seeds; read by MintPause::require_unpaused
+    #[account(seeds = [b"mint_pause"], bump)]
+    pub mint_pause: UncheckedAccount<'info>,
+
+    /// Light client asset registry (per-asset freeze; not initialized = nothing frozen)
+    /// CHECK: Address pinned by seeds; read by AssetRegistry::require_not_frozen
+    #[account(seeds = [b"asset_registry"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub asset_registry: UncheckedAccount<'info>,
+
+    /// Shared validator fee accrual (not initialized = fees paid via remaining_accounts)
+    /// CHECK: Address pinned by seeds; read and credited by fees::distribute_validator_fees
+    #[account(mut, seeds = [b"fee_pool"], bump)]
+    pub fee_pool: UncheckedAccount<'info>,
+
+    /// Amount-based fee schedule (not initialized = fee_per_validator on every burn)
+    /// CHECK: Address pinned by seeds; read by fees::validator_fee_for
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+
+    pub token_program: Interface<'info, TokenInterface>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Message the stealth key signs to claim a burn
+///
+/// Format: hash(XENCAT_STEALTH_CLAIM || asset_id || burn_nonce || recipient_token_account)
+pub fn stealth_claim_message(asset_id: u8, burn_nonce: u64, recipient_token_account: &Pubkey) -> [u8; 32] {
+    let mut data = Vec::with_capacity(20 + 1 + 8 + 32);
+    data.extend_from_slice(b"XENCAT_STEALTH_CLAIM");
+    data.push(asset_id);
+    data.extend_from_slice(&burn_nonce.to_le_bytes());
+    data.extend_from_slice(recipient_token_account.as_ref());
+
+    hash(&data).to_bytes()
+}
+
+/// Require that the previous instruction is an Ed25519 verification of
+/// `message` by `signer`, with all data inline in that instruction
+fn verify_ownership_proof(instructions: &AccountInfo, signer: &Pubkey, message: &[u8; 32]) -> Result<()> {
+    let current = load_current_index_checked(instructions)? as usize;
+    require!(current > 0, MintError::InvalidOwnershipProof);
+
+    let ix = load_instruction_at_checked(current - 1, instructions)?;
+    require!(ix.program_id == ed25519_program::ID, MintError::InvalidOwnershipProof);
+
+    let data = &ix.data;
+    require!(data.len() >= 16 && data[0] == 1, MintError::InvalidOwnershipProof);
+
+    let read_u16 = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    // Signature, pubkey and message must come from this same instruction,
+    // otherwise the bytes read below are not the ones the precompile verified
+    require!(
+        read_u16(4) == u16::MAX && read_u16(8) == u16::MAX && read_u16(14) == u16::MAX,
+        MintError::InvalidOwnershipProof
+    );
+
+    let pubkey_offset = read_u16(6) as usize;
+    let msg_offset = read_u16(10) as usize;
+    let msg_size = read_u16(12) as usize;
+    require!(
+        msg_size == 32 && data.len() >= pubkey_offset + 32 && data.len() >= msg_offset + 32,
+        MintError::InvalidOwnershipProof
+    );
+    require!(
+        &data[pubkey_offset..pubkey_offset + 32] == signer.as_ref(),
+        MintError::InvalidOwnershipProof
+    );
+    require!(
+        &data[msg_offset..msg_offset + 32] == message.as_ref(),
+        MintError::InvalidOwnershipProof
+    );
+
+    Ok(())
+}
+
+pub fn handler<'info>(
+    ctx: Context<'_, '_, '_, 'info, MintToStealth<'info>>,
+    burn_nonce: u64,
+    asset_id: u8,
+) -> Result<()> {
+    FeatureGate::require_enabled(&ctx.accounts.feature_gate, gates::MINT_STEALTH)?;
+    MintPause::require_unpaused(&ctx.accounts.mint_pause)?;
+    AssetRegistry::require_not_frozen(&ctx.accounts.asset_registry, asset_id)?;
+    RelayerClaim::require_unclaimed(&ctx.accounts.relayer_claim)?;
+
+    // This program must ONLY mint XENCAT (asset_id = 1)
+    require!(asset_id == 1, MintError::AssetNotMintable);
+
+    let message = stealth_claim_message(asset_id, burn_nonce, &ctx.accounts.recipient_token_account.key());
+    verify_ownership_proof(&ctx.accounts.instructions, &ctx.accounts.user.key(), &message)?;
+
+    msg!("🕶️  Stealth claim verified for nonce {}", burn_nonce);
+
+    let amount = ctx.accounts.verified_burn.amount;
+    CoSignPolicy::enforce(&ctx.accounts.cosign_policy, amount, ctx.remaining_accounts)?;
+    RateLimit::enforce(&ctx.accounts.rate_limit, amount)?;
+    let mint_state = &ctx.accounts.mint_state;
+
+    token_interface::mint_to(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            MintTo {
+                mint: ctx.accounts.xencat_mint.to_account_info(),
+                to: ctx.accounts.recipient_token_account.to_account_info(),
+                authority: ctx.accounts.mint_state.to_account_info(),
+            },
+            &[&[
+                b"mint_state_v2",
+                &[mint_state.bump]
+            ]],
+        ),
+        amount,
+    )?;
+
+    let processed = &mut ctx.accounts.processed_burn;
+    processed.asset_id = asset_id;
+    processed.nonce = burn_nonce;
+    processed.user = ctx.accounts.user.key();
+    processed.amount = amount;
+    processed.processed_at = Clock::get()?.unix_timestamp;
+    invariants::debug_check(invariants::processed_implies_verified(
+        &processed.record(),
+        Some(&*ctx.accounts.verified_burn),
+        false,
+    ));
+    emit_mint_acknowledgement(asset_id, burn_nonce, amount, ctx.accounts.recipient_token_account.key())?;
+
+    // Validator fees are paid by the relayer (same rules as mint_from_burn_v3)
+    let fee_per_validator = validator_fee_for(mint_state, &ctx.accounts.fee_config, amount)?;
+    if fee_per_validator > 0 {
+        let validator_set = &ctx.accounts.validator_set;
+        let shares = validator_fee_shares(fee_per_validator, validator_set, &ctx.accounts.latency_stats)?;
+        distribute_validator_fees(
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.fee_pool,
+            ctx.remaining_accounts,
+            &validator_set.validators,
+            shares,
+        )?;
+    }
+
+    let mint_state = &mut ctx.accounts.mint_state;
+    mint_state.processed_burns_count = mint_state.processed_burns_count.saturating_add(1);
+    mint_state.total_minted = mint_state.total_minted.saturating_add(amount);
+
+    msg!("✓ Minted {} tokens to stealth recipient", amount);
+
+    Ok(())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::validator_fee_for;
+use solana_light_client_x1::{ID as LIGHT_CLIENT_ID, VerifiedBurnV3, X1ValidatorSet};
+
+/// Read-only fee quote (no signer, no writable accounts)
+///
+/// Clients call this via simulation (Anchor `.view()`) to show the all-in
+/// X1 cost before the user burns on Solana.
+#[derive(Accounts)]
+pub struct QuoteFees<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        owner = LIGHT_CLIENT_ID,
+        constraint = validator_set.version == mint_state.validator_set_version
+            @ MintError::ValidatorSetVersionMismatch
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// Amount-based fee schedule (not initialized = fee_per_validator on every burn)
+    /// CHECK: Address pinned by seeds; read by fees::validator_fee_for
+    #[account(seeds = [b"fee_config"], bump)]
+    pub fee_config: UncheckedAccount<'info>,
+}
+
+pub fn handler(ctx: Context<QuoteFees>, asset_id: u8, amount: u64) -> Result<FeeQuote> {
+    // This program must ONLY mint XENCAT (asset_id = 1)
+    require!(asset_id == 1, MintError::AssetNotMintable);
+
+    let mint_state = &ctx.accounts.mint_state;
+    let validator_count = ctx.accounts.validator_set.validators.len() as u8;
+
+    // Same computation as mint_from_burn_v3
+    let fee_per_validator = validator_fee_for(mint_state, &ctx.accounts.fee_config, amount)?;
+    let validator_fee_total = fee_per_validator
+        .checked_mul(validator_count as u64)
+        .ok_or(MintError::Overflow)?;
+    let protocol_fee = 0;
+
+    let rent = Rent::get()?;
+    let verified_burn_rent = rent.minimum_balance(8 + VerifiedBurnV3::INIT_SPACE);
+    let processed_burn_rent = rent.minimum_balance(8 + ProcessedBurnV3::INIT_SPACE);
+
+    let total = validator_fee_total
+        .checked_add(protocol_fee)
+        .and_then(|t| t.checked_add(verified_burn_rent))
+        .and_then(|t| t.checked_add(processed_burn_rent))
+        .ok_or(MintError::Overflow)?;
+
+    Ok(FeeQuote {
+        asset_id,
+        amount,
+        mint_amount: amount,
+        validator_count,
+        fee_per_validator,
+        validator_fee_total,
+        protocol_fee,
+        verified_burn_rent,
+        processed_burn_rent,
+        total,
+    })
+}
@@ -0,0 +1,34 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetCompressedOutput<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        seeds = [b"output_config"],
+        bump = output_config.bump
+    )]
+    pub output_config: Account<'info, OutputConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Enable or disable compressed-token output for this asset
+///
+/// Before enabling, a compressed-token pool must exist for the mint
+/// (Light Protocol `create_token_pool`).
+pub fn handler(ctx: Context<SetCompressedOutput>, enabled: bool) -> Result<()> {
+    ctx.accounts.output_config.compressed_enabled = enabled;
+
+    msg!("✓ Compressed output {}", if enabled { "enabled" } else { "disabled" });
+
+    Ok(())
+}
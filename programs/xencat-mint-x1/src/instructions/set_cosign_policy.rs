@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetCoSignPolicy<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        seeds = [b"cosign_policy"],
+        bump = cosign_policy.bump
+    )]
+    pub cosign_policy: Account<'info, CoSignPolicy>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Change the co-signer or threshold, or switch the policy off
+pub fn handler(ctx: Context<SetCoSignPolicy>, enabled: bool, cosigner: Pubkey, threshold: u64) -> Result<()> {
+    let policy = &mut ctx.accounts.cosign_policy;
+    policy.enabled = enabled;
+    policy.cosigner = cosigner;
+    policy.threshold = threshold;
+
+    if enabled {
+        msg!("✓ Co-sign policy: mints above {} need {}", threshold, cosigner);
+    } else {
+        msg!("✓ Co-sign policy disabled");
+    }
+
+    Ok(())
+}
@@ -0,0 +1,96 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use solana_light_client_x1::{
+    ID as LIGHT_CLIENT_ID, X1ChainId, X1ValidatorSet,
+    governance::{create_governance_message, verify_validator_set_approvals},
+    instructions::ValidatorUpdateSignature,
+};
+
+#[derive(Accounts)]
+pub struct SetCpiAllowlist<'info> {
+    #[account(
+        seeds = [b"x1_validator_set_v2"],
+        bump = validator_set.bump,
+        seeds::program = LIGHT_CLIENT_ID
+    )]
+    pub validator_set: Account<'info, X1ValidatorSet>,
+
+    /// X1 genesis hash binding (not initialized = unbound message)
+    /// CHECK: Address pinned by seeds; read by X1ChainId::resolve
+    #[account(seeds = [b"x1_chain_id"], bump, seeds::program = LIGHT_CLIENT_ID)]
+    pub x1_chain_id: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"cpi_allowlist"],
+        bump = cpi_allowlist.bump
+    )]
+    pub cpi_allowlist: Account<'info, CpiAllowlist>,
+
+    /// Signer submitting the update (anyone can submit with valid signatures)
+    pub signer: Signer<'info>,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct SetCpiAllowlistParams {
+    /// Complete new list (replaces the current one; empty = no downstream calls)
+    pub programs: Vec<Pubkey>,
+
+    /// Signatures from current validators approving this change
+    pub approver_signatures: Vec<ValidatorUpdateSignature>,
+}
+
+/// Replace the programs mint_and_call may invoke (validator governance)
+pub fn handler(ctx: Context<SetCpiAllowlist>, params: SetCpiAllowlistParams) -> Result<()> {
+    let validator_set = &ctx.accounts.validator_set;
+    let allowlist = &mut ctx.accounts.cpi_allowlist;
+
+    require!(
+        params.programs.len() <= CpiAllowlist::MAX_PROGRAMS
+            && !params.programs.contains(&crate::ID)
+            && params.programs.iter().enumerate().all(|(i, p)| !params.programs[..i].contains(p)),
+        MintError::InvalidCpiAllowlist
+    );
+
+    msg!("🔄 Updating CPI allowlist: {} -> {} programs", allowlist.programs.len(), params.programs.len());
+
+    let message = create_cpi_allowlist_message(
+        validator_set.version,
+        allowlist.nonce,
+        &params.programs,
+        X1ChainId::resolve(&ctx.accounts.x1_chain_id)?,
+    );
+    verify_validator_set_approvals(&params.approver_signatures, validator_set, &message)?;
+
+    allowlist.nonce = allowlist.nonce.checked_add(1).ok_or(MintError::Overflow)?;
+    allowlist.programs = params.programs;
+
+    for program in &allowlist.programs {
+        msg!("   Allowed: {}", program);
+    }
+    msg!("✅ CPI allowlist updated");
+
+    Ok(())
+}
+
+/// Create deterministic message for a CPI allowlist change
+///
+/// Format: hash(SET_MINT_CPI_ALLOWLIST || version || nonce || mint program || programs...)
+///
+/// The program id keeps an approval for one mint program from applying to another.
+fn create_cpi_allowlist_message(
+    validator_set_version: u64,
+    nonce: u64,
+    programs: &[Pubkey],
+    x1_genesis_hash: Option<[u8; 32]>,
+) -> Vec<u8> {
+    let mut payload = Vec::with_capacity(40 + 32 * programs.len());
+    payload.extend_from_slice(&nonce.to_le_bytes());
+    payload.extend_from_slice(crate::ID.as_ref());
+    for program in programs {
+        payload.extend_from_slice(program.as_ref());
+    }
+
+    create_governance_message(b"SET_MINT_CPI_ALLOWLIST", validator_set_version, &payload, x1_genesis_hash)
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct SetValidatorCommission<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_vault", fee_vault.validator.as_ref()],
+        bump = fee_vault.bump,
+        has_one = authority @ MintError::Unauthorized
+    )]
+    pub fee_vault: Account<'info, FeeVault>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Take `commission_bps` of every fee accruing from now on for `commission_recipient`
+///
+/// Applies at accrual time only; 0 bps turns the commission off. Commission
+/// already accrued stays claimable by the recipient it accrued for, so the
+/// recipient can only change once that balance is claimed.
+pub fn handler(ctx: Context<SetValidatorCommission>, commission_bps: u16, commission_recipient: Pubkey) -> Result<()> {
+    let vault = &mut ctx.accounts.fee_vault;
+    require!(
+        commission_bps <= FeeVault::MAX_COMMISSION_BPS
+            && (commission_bps == 0 || commission_recipient != Pubkey::default()),
+        MintError::InvalidCommission
+    );
+    require!(
+        vault.commission_balance == 0 || commission_recipient == vault.commission_recipient,
+        MintError::InvalidCommission
+    );
+
+    let old_commission_bps = vault.commission_bps;
+    vault.commission_bps = commission_bps;
+    vault.commission_recipient = commission_recipient;
+
+    emit!(ValidatorCommissionChanged {
+        validator: vault.validator,
+        old_commission_bps,
+        commission_bps,
+        commission_recipient,
+    });
+
+    msg!(
+        "✓ Commission for {}: {} -> {} bps (recipient {})",
+        vault.validator,
+        old_commission_bps,
+        commission_bps,
+        commission_recipient
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct ValidatorCommissionChanged {
+    pub validator: Pubkey,
+    pub old_commission_bps: u16,
+    pub commission_bps: u16,
+    pub commission_recipient: Pubkey,
+}
@@ -1,5 +1,5 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Mint, SetAuthority, Token};
+use anchor_spl::token_interface::{self, Mint, SetAuthority, TokenInterface};
 use crate::state::*;
 use crate::errors::*;
 
@@ -28,12 +28,12 @@ pub struct TransferMintAuthority<'info> {
         bump,
         constraint = xencat_mint.mint_authority.contains(&legacy_mint_state.key()) @ MintError::InvalidMintAuthority
     )]
-    pub xencat_mint: Account<'info, Mint>,
+    pub xencat_mint: InterfaceAccount<'info, Mint>,
 
     /// Authority (must be authorized to execute migration)
     pub authority: Signer<'info>,
 
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
 }
 
 pub fn handler(ctx: Context<TransferMintAuthority>) -> Result<()> {
@@ -47,7 +47,7 @@ pub fn handler(ctx: Context<TransferMintAuthority>) -> Result<()> {
     // Transfer mint authority from legacy_mint_state to new_mint_state_v2
     let legacy_bump = ctx.bumps.legacy_mint_state;
 
-    token::set_authority(
+    token_interface::set_authority(
         CpiContext::new_with_signer(
             ctx.accounts.token_program.to_account_info(),
             SetAuthority {
@@ -59,7 +59,7 @@ pub fn handler(ctx: Context<TransferMintAuthority>) -> Result<()> {
                 &[legacy_bump]
             ]],
         ),
-        anchor_spl::token::spl_token::instruction::AuthorityType::MintTokens,
+        anchor_spl::token_2022::spl_token_2022::instruction::AuthorityType::MintTokens,
         Some(ctx.accounts.new_mint_state.key()),
     )?;
 
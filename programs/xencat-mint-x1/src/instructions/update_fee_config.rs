@@ -0,0 +1,49 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+
+#[derive(Accounts)]
+pub struct UpdateFeeConfig<'info> {
+    #[account(
+        seeds = [b"mint_state_v2"],
+        bump = mint_state.bump,
+        has_one = authority @ MintError::Unauthorized,
+    )]
+    pub mint_state: Account<'info, MintState>,
+
+    #[account(
+        mut,
+        seeds = [b"fee_config"],
+        bump = fee_config.bump
+    )]
+    pub fee_config: Account<'info, FeeConfig>,
+
+    pub authority: Signer<'info>,
+}
+
+/// Replace the fee schedule (applies to the next mint)
+pub fn handler(ctx: Context<UpdateFeeConfig>, flat_fee: u64, fee_bps: u16, min_fee: u64, max_fee: u64) -> Result<()> {
+    let fee_config = &mut ctx.accounts.fee_config;
+    fee_config.flat_fee = flat_fee;
+    fee_config.fee_bps = fee_bps;
+    fee_config.min_fee = min_fee;
+    fee_config.max_fee = max_fee;
+    fee_config.validate()?;
+
+    emit!(FeeConfigUpdated { flat_fee, fee_bps, min_fee, max_fee });
+
+    msg!(
+        "✓ Fee config: {} + {} bps of amount per validator, within [{}, {}] lamports",
+        flat_fee, fee_bps, min_fee, max_fee
+    );
+
+    Ok(())
+}
+
+#[event]
+pub struct FeeConfigUpdated {
+    pub flat_fee: u64,
+    pub fee_bps: u16,
+    pub min_fee: u64,
+    pub max_fee: u64,
+}
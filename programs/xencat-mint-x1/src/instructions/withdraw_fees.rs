@@ -0,0 +1,69 @@
+use anchor_lang::prelude::*;
+use crate::state::*;
+use crate::errors::*;
+use crate::fees::validator_fee_account;
+
+/// Pay a validator's pooled fees out of the fee pool (permissionless)
+///
+/// The lamports can only go to the validator's own fee account: its
+/// FeeVault if it opted in (credited like a direct fee, commission
+/// included), otherwise the validator key. So anyone - the validator, a
+/// crank, a relayer - may send it, e.g. to free the entry of a validator
+/// that left the set.
+#[derive(Accounts)]
+pub struct WithdrawFees<'info> {
+    #[account(
+        mut,
+        seeds = [b"fee_pool"],
+        bump = fee_pool.bump
+    )]
+    pub fee_pool: Account<'info, FeePool>,
+
+    /// CHECK: Only used as the key the pooled balance is recorded under
+    pub validator: UncheckedAccount<'info>,
+
+    /// Validator key or its FeeVault PDA
+    /// CHECK: Checked by fees::validator_fee_account
+    #[account(mut)]
+    pub recipient: UncheckedAccount<'info>,
+}
+
+/// Returns the amount paid out
+pub fn handler(ctx: Context<WithdrawFees>) -> Result<u64> {
+    let validator = ctx.accounts.validator.key();
+    let recipient = ctx.accounts.recipient.to_account_info();
+    let vault = validator_fee_account(&recipient, &validator)?;
+    let to_vault = vault.is_some();
+
+    let pool = &mut ctx.accounts.fee_pool;
+    let amount = pool.take(&validator);
+    require!(amount > 0, MintError::NoPooledFees);
+
+    **pool.to_account_info().try_borrow_mut_lamports()? -= amount;
+    **recipient.try_borrow_mut_lamports()? += amount;
+
+    if let Some(mut vault) = vault {
+        vault.accrue(amount)?;
+        vault.try_serialize(&mut &mut recipient.try_borrow_mut_data()?[..])?;
+    }
+
+    emit!(PooledFeesWithdrawn {
+        validator,
+        recipient: recipient.key(),
+        amount,
+        to_vault,
+    });
+
+    msg!("✓ Withdrew {} pooled lamports for validator {}", amount, validator);
+
+    Ok(amount)
+}
+
+#[event]
+pub struct PooledFeesWithdrawn {
+    pub validator: Pubkey,
+    /// Validator key or FeeVault PDA
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub to_vault: bool,
+}
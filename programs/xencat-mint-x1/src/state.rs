@@ -1,4 +1,5 @@
 use anchor_lang::prelude::*;
+use solana_light_client_x1::invariants::ProcessedRecord;
 
 /// Mint program state (V2)
 #[account]
@@ -75,14 +76,475 @@ impl ProcessedBurnV3 {
         32 + // user
         8 +  // amount
         8;   // processed_at
+
+    /// This record as the bridge invariants read it
+    pub fn record(&self) -> ProcessedRecord {
+        ProcessedRecord { asset_id: self.asset_id, nonce: self.nonce, user: self.user, amount: self.amount }
+    }
 }
 
-/// Fee vault for individual validators (non-custodial)
+/// Fee vault for individual validators (non-custodial, opt-in)
+///
+/// Once created, relayers pass the vault instead of the validator key as
+/// the validator's fee account; fees accrue here until `authority` claims
+/// them with claim_validator_fees. The authority may be a multisig or a
+/// PDA of the validator's own program (claims work over CPI).
+///
+/// Seeds: ["fee_vault", validator]
 #[account]
 #[derive(InitSpace)]
 pub struct FeeVault {
     pub validator: Pubkey,      // Validator pubkey
+    pub authority: Pubkey,      // Signs claims (validator key, multisig or program PDA)
     pub balance: u64,            // Current withdrawable balance
     pub total_collected: u64,    // Total fees collected (audit trail)
+    pub total_claimed: u64,      // Total fees claimed (audit trail)
+    pub commission_bps: u16,          // Share of each accrued fee credited to commission_recipient
+    pub commission_recipient: Pubkey, // Delegator pool or infra provider backing the validator
+    pub commission_balance: u64,      // Accrued commission, claimable by commission_recipient
+    pub total_commission: u64,        // Total commission accrued (audit trail)
+    pub bump: u8,
+}
+
+impl FeeVault {
+    /// 100%: the whole fee share goes to the commission recipient
+    pub const MAX_COMMISSION_BPS: u16 = 10_000;
+
+    /// Credit one fee payment, split by the commission in force right now
+    ///
+    /// Returns the commission part. A commission change never touches
+    /// fees that already accrued.
+    pub fn accrue(&mut self, fee: u64) -> Result<u64> {
+        let commission = (fee as u128 * self.commission_bps as u128 / Self::MAX_COMMISSION_BPS as u128) as u64;
+        self.balance = self.balance.checked_add(fee - commission).ok_or(crate::errors::MintError::Overflow)?;
+        self.total_collected = self.total_collected.checked_add(fee - commission).ok_or(crate::errors::MintError::Overflow)?;
+        self.commission_balance = self.commission_balance.checked_add(commission).ok_or(crate::errors::MintError::Overflow)?;
+        self.total_commission = self.total_commission.checked_add(commission).ok_or(crate::errors::MintError::Overflow)?;
+        Ok(commission)
+    }
+}
+
+/// Shared accrual account for all validators' mint fees
+///
+/// Once created (initialize_fee_pool), mints pay the whole validator fee
+/// here in one transfer and credit each validator's share, instead of
+/// paying every validator account passed in remaining_accounts. Balances
+/// leave the pool only through withdraw_fees, to the validator's FeeVault
+/// or, without one, the validator key.
+///
+/// Pool lamports = rent-exempt minimum + sum of balances.
+///
+/// Seeds: ["fee_pool"]
+#[account]
+#[derive(InitSpace)]
+pub struct FeePool {
+    #[max_len(32)]
+    pub balances: Vec<PooledFee>,
+    pub total_accrued: u64,      // Total fees credited (audit trail)
+    pub total_withdrawn: u64,    // Total fees paid out (audit trail)
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct PooledFee {
+    pub validator: Pubkey,
+    pub balance: u64,
+}
+
+impl FeePool {
+    /// Validators with an unwithdrawn balance the pool can track
+    pub const MAX_VALIDATORS: usize = 32;
+
+    /// The pool, if `info` (the ["fee_pool"] PDA, pinned by callers) is initialized
+    pub fn load(info: &AccountInfo) -> Result<Option<FeePool>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidFeePool);
+        Ok(Some(FeePool::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    /// Add `fee` to `validator`'s balance
+    ///
+    /// Entries are dropped once withdrawn, so MAX_VALIDATORS only has to
+    /// cover validators that have not withdrawn yet.
+    pub fn credit(&mut self, validator: &Pubkey, fee: u64) -> Result<()> {
+        match self.balances.iter_mut().find(|entry| entry.validator == *validator) {
+            Some(entry) => {
+                entry.balance = entry.balance.checked_add(fee).ok_or(crate::errors::MintError::Overflow)?;
+            }
+            None => {
+                require!(self.balances.len() < Self::MAX_VALIDATORS, crate::errors::MintError::FeePoolFull);
+                self.balances.push(PooledFee { validator: *validator, balance: fee });
+            }
+        }
+        self.total_accrued = self.total_accrued.checked_add(fee).ok_or(crate::errors::MintError::Overflow)?;
+        Ok(())
+    }
+
+    /// Remove `validator`'s entry and return its balance (0 if none)
+    pub fn take(&mut self, validator: &Pubkey) -> u64 {
+        let Some(index) = self.balances.iter().position(|entry| entry.validator == *validator) else {
+            return 0;
+        };
+        let amount = self.balances.swap_remove(index).balance;
+        self.total_withdrawn = self.total_withdrawn.saturating_add(amount);
+        amount
+    }
+}
+
+/// Amount-based validator fee schedule (replaces the fixed fee_per_validator)
+///
+/// Each validator is owed, per minted burn of `amount` base units:
+///
+///   clamp(flat_fee + amount * fee_bps / 10_000, min_fee, max_fee) lamports
+///
+/// so fees can follow burn size, and the authority can retune them with
+/// update_fee_config as the XNT price moves. No account = every burn pays
+/// MintState.fee_per_validator.
+///
+/// Seeds: ["fee_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct FeeConfig {
+    /// Lamports per validator on every burn
+    pub flat_fee: u64,
+    /// Lamports per validator per 10_000 base units minted
+    pub fee_bps: u16,
+    /// Floor and cap of the per-validator fee (lamports)
+    pub min_fee: u64,
+    pub max_fee: u64,
     pub bump: u8,
 }
+
+impl FeeConfig {
+    /// 1 lamport per base unit
+    pub const MAX_FEE_BPS: u16 = 10_000;
+
+    /// The config, if `info` (the ["fee_config"] PDA, pinned by callers) is initialized
+    pub fn load(info: &AccountInfo) -> Result<Option<FeeConfig>> {
+        if info.data_is_empty() {
+            return Ok(None);
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidFeeConfig);
+        Ok(Some(FeeConfig::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        require!(
+            self.fee_bps <= Self::MAX_FEE_BPS && self.min_fee <= self.max_fee,
+            crate::errors::MintError::InvalidFeeConfig
+        );
+        Ok(())
+    }
+
+    /// Per-validator fee for a burn of `amount` base units
+    pub fn fee_for(&self, amount: u64) -> u64 {
+        let proportional = (amount as u128 * self.fee_bps as u128 / 10_000) as u64;
+        self.flat_fee.saturating_add(proportional).clamp(self.min_fee, self.max_fee)
+    }
+
+    /// Whether any burn can owe a fee (decides the fee account layout)
+    pub fn charges_fees(&self) -> bool {
+        self.max_fee > 0 && (self.flat_fee > 0 || self.fee_bps > 0 || self.min_fee > 0)
+    }
+}
+
+/// Recipient stream for recurring bridged payments
+///
+/// While active, any verified burn of `user` can be minted by anyone
+/// (relayer / crank) straight into `recipient_token_account` via
+/// mint_to_stream. Pairs with Solana-side subscription burns.
+///
+/// Seeds: ["mint_stream", user]
+#[account]
+#[derive(InitSpace)]
+pub struct MintStream {
+    pub user: Pubkey,
+    pub recipient_token_account: Pubkey,
+    pub burns_streamed: u64,
+    pub total_streamed: u64,
+    pub bump: u8,
+}
+
+/// All-in X1-side cost of minting one verified burn (returned by quote_fees)
+///
+/// Amounts are in lamports unless noted. Compute/priority fees depend on
+/// live network conditions and are estimated off-chain (see SDK quoteFees).
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Debug)]
+pub struct FeeQuote {
+    pub asset_id: u8,
+    /// Burned amount (token base units)
+    pub amount: u64,
+    /// Tokens the user receives (token base units, always == amount)
+    pub mint_amount: u64,
+    pub validator_count: u8,
+    pub fee_per_validator: u64,
+    pub validator_fee_total: u64,
+    /// No protocol fee is charged today; kept explicit so frontends need no update if one is added
+    pub protocol_fee: u64,
+    /// Rent for the VerifiedBurnV3 PDA (TX1, light client)
+    pub verified_burn_rent: u64,
+    /// Rent for the ProcessedBurnV3 PDA (TX2, this program)
+    pub processed_burn_rent: u64,
+    /// validator_fee_total + protocol_fee + both rents
+    pub total: u64,
+}
+
+/// Output mode for minted tokens (per asset: one config per mint program)
+///
+/// When `compressed_enabled`, users may choose mint_from_burn_v3_compressed
+/// to receive a compressed token (Light Protocol) instead of an SPL token
+/// account, avoiding the per-recipient token account rent.
+///
+/// Seeds: ["output_config"]
+#[account]
+#[derive(InitSpace)]
+pub struct OutputConfig {
+    pub compressed_enabled: bool,
+    pub bump: u8,
+}
+
+/// Dual control for large mints (per asset: one policy per mint program)
+///
+/// While `enabled`, every mint path refuses a burn of more than
+/// `threshold` base units unless `cosigner` (e.g. a compliance service)
+/// also signs the transaction. The co-signer is passed as an extra signer
+/// at the end of remaining_accounts. No policy account = no co-signing.
+///
+/// Seeds: ["cosign_policy"]
+#[account]
+#[derive(InitSpace)]
+pub struct CoSignPolicy {
+    pub enabled: bool,
+    pub cosigner: Pubkey,
+    /// Largest amount (token base units) minted without the co-signer
+    pub threshold: u64,
+    pub bump: u8,
+}
+
+impl CoSignPolicy {
+    pub fn requires_cosigner(&self, amount: u64) -> bool {
+        self.enabled && amount > self.threshold
+    }
+
+    /// Require the co-signer among `accounts` if the policy applies to `amount`
+    ///
+    /// `info` must be the ["cosign_policy"] PDA (callers pin it with seeds).
+    /// An uninitialized account means no policy.
+    pub fn enforce(info: &AccountInfo, amount: u64, accounts: &[AccountInfo]) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidCoSignPolicy);
+        let policy = CoSignPolicy::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        if !policy.requires_cosigner(amount) {
+            return Ok(());
+        }
+        require!(
+            accounts.iter().any(|a| a.is_signer && a.key() == policy.cosigner),
+            crate::errors::MintError::CoSignerRequired
+        );
+        msg!("✓ Co-signed by {} (amount {} > {})", policy.cosigner, amount, policy.threshold);
+        Ok(())
+    }
+}
+
+/// Cap on minted volume per rolling window
+///
+/// Bounds what a compromised validator quorum can mint: forged attestations
+/// stop at `cap` per window, and a governed cap increase only takes effect
+/// one full window after it is approved, so the same quorum cannot raise
+/// the cap and drain in one shot. Decreases apply at once. The window is a
+/// sliding approximation: the previous window's volume counts in proportion
+/// to how much of it still overlaps. No account = no limit.
+///
+/// Seeds: ["rate_limit"]
+#[account]
+#[derive(InitSpace)]
+pub struct RateLimit {
+    /// Rolling window length (e.g. 86400 for 24h)
+    pub window_seconds: i64,
+    /// Most token base units minted per window
+    pub cap: u64,
+    /// Approved increase, in force from `pending_cap_at` (0 = none)
+    pub pending_cap: u64,
+    pub pending_cap_at: i64,
+    /// Start of the current fixed window
+    pub window_start: i64,
+    pub current_window_minted: u64,
+    pub previous_window_minted: u64,
+    /// Governance nonce (bound into set_rate_limit approvals)
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl RateLimit {
+    /// Shortest allowed window (1 hour)
+    pub const MIN_WINDOW_SECONDS: i64 = 3_600;
+
+    /// Move to the window holding `now` and apply a due cap increase
+    fn roll(&mut self, now: i64) {
+        let windows_passed = (now - self.window_start) / self.window_seconds;
+        if windows_passed >= 1 {
+            self.previous_window_minted = if windows_passed == 1 { self.current_window_minted } else { 0 };
+            self.current_window_minted = 0;
+            self.window_start += windows_passed * self.window_seconds;
+        }
+        if self.pending_cap_at != 0 && now >= self.pending_cap_at {
+            self.cap = self.pending_cap;
+            self.pending_cap = 0;
+            self.pending_cap_at = 0;
+        }
+    }
+
+    /// Volume counted against the cap at `now` (after roll)
+    fn used(&self, now: i64) -> u64 {
+        let remaining = (self.window_seconds - (now - self.window_start)).max(0) as u128;
+        let carried = self.previous_window_minted as u128 * remaining / self.window_seconds as u128;
+        (carried as u64).saturating_add(self.current_window_minted)
+    }
+
+    /// Count `amount` against the cap, failing if it does not fit
+    pub fn consume(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.roll(now);
+        let used = self.used(now);
+        require!(
+            used.checked_add(amount).is_some_and(|total| total <= self.cap),
+            crate::errors::MintError::RateLimitExceeded
+        );
+        self.current_window_minted = self.current_window_minted.saturating_add(amount);
+        Ok(())
+    }
+
+    /// Change the cap: decreases now, increases one window from `now`
+    pub fn set_cap(&mut self, cap: u64, now: i64) {
+        self.roll(now);
+        if cap <= self.cap {
+            self.cap = cap;
+            self.pending_cap = 0;
+            self.pending_cap_at = 0;
+        } else {
+            self.pending_cap = cap;
+            self.pending_cap_at = now + self.window_seconds;
+        }
+    }
+
+    /// Record a mint of `amount` against the rate limit
+    ///
+    /// `info` must be the ["rate_limit"] PDA, writable (callers pin it with
+    /// seeds). An uninitialized account means no limit.
+    pub fn enforce(info: &AccountInfo, amount: u64) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidRateLimit);
+        let now = Clock::get()?.unix_timestamp;
+        let mut rate_limit = RateLimit::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        rate_limit.consume(amount, now)?;
+        rate_limit.try_serialize(&mut &mut info.try_borrow_mut_data()?[..])?;
+        msg!("✓ Rate limit: {} of {} used this window", rate_limit.used(now), rate_limit.cap);
+        Ok(())
+    }
+}
+
+/// Emergency pause of this mint program (validator governance)
+///
+/// Stops every mint path of this asset only, for example after a leak that
+/// affects one token. The light client's pause_bridge stops the whole
+/// bridge. Anyone can create the account; only a validator threshold can
+/// flip it. No account = not paused.
+///
+/// Seeds: ["mint_pause"]
+#[account]
+#[derive(InitSpace)]
+pub struct MintPause {
+    pub paused: bool,
+    /// X1 slot of the last change
+    pub updated_slot: u64,
+    /// Governance nonce (bound into pause_bridge / unpause_bridge approvals)
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl MintPause {
+    /// Fail with MintPaused while paused
+    ///
+    /// `info` must be the ["mint_pause"] PDA (callers pin it with seeds).
+    /// An uninitialized account means not paused.
+    pub fn require_unpaused(info: &AccountInfo) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, crate::errors::MintError::InvalidMintPause);
+        let pause = MintPause::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(!pause.paused, crate::errors::MintError::MintPaused);
+        Ok(())
+    }
+}
+
+/// Counters of the X1 → Solana direction (XENCAT burned here to be
+/// released on Solana)
+///
+/// Seeds: ["return_bridge"]
+#[account]
+#[derive(InitSpace)]
+pub struct ReturnBridge {
+    /// Nonce the next burn_for_return gets
+    pub next_nonce: u64,
+    pub total_burns: u64,
+    /// Token base units burned for return
+    pub total_amount: u64,
+    pub bump: u8,
+}
+
+/// One XENCAT burn on X1, to be released to `solana_recipient` on Solana
+///
+/// The evidence X1 validators attest to (light client verify_return_burn)
+/// and the settlement validators sign for the burn program's
+/// release_return. Never closed: it is the replay anchor of its nonce.
+///
+/// Seeds: ["return_burn", nonce]
+///
+/// Layout read by the light client: discriminator(8) || nonce(8) || user(32)
+/// || solana_recipient(32) || amount(8) || slot(8) || timestamp(8) || bump(1)
+#[account]
+#[derive(InitSpace)]
+pub struct ReturnBurnRecord {
+    pub nonce: u64,
+    /// X1 owner of the burned tokens
+    pub user: Pubkey,
+    /// Solana wallet to release to (the burn program pays its XENCAT token account)
+    pub solana_recipient: Pubkey,
+    pub amount: u64,
+    /// X1 slot of the burn
+    pub slot: u64,
+    pub timestamp: i64,
+    pub bump: u8,
+}
+
+/// Downstream programs mint_and_call may invoke (validator governance)
+///
+/// mint_and_call mints a verified burn to the user and then calls one of
+/// these programs in the same transaction (e.g. deposit into a staking or
+/// LP program). Anyone can create the empty list; only a validator
+/// threshold can change it. No account or empty list = no downstream calls.
+///
+/// Seeds: ["cpi_allowlist"]
+#[account]
+#[derive(InitSpace)]
+pub struct CpiAllowlist {
+    #[max_len(8)]
+    pub programs: Vec<Pubkey>,
+    /// Governance nonce (bound into set_cpi_allowlist approvals)
+    pub nonce: u64,
+    pub bump: u8,
+}
+
+impl CpiAllowlist {
+    pub const MAX_PROGRAMS: usize = 8;
+
+    pub fn allows(&self, program: &Pubkey) -> bool {
+        self.programs.contains(program)
+    }
+}
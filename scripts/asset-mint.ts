@@ -0,0 +1,445 @@
+/**
+ * Generic Mint Program (bridge-mint-x1)
+ *
+ * Binds assets registered to bridge-mint-x1 in the light client's asset
+ * registry (scripts/asset-registry.ts) to their X1 mint, and mints their
+ * verified burns. XENCAT and DGN keep their own programs (bridge-mint.ts).
+ *
+ * Usage:
+ *   npx ts-node scripts/asset-mint.ts status       --asset 3
+ *   npx ts-node scripts/asset-mint.ts propose-init --asset 3 --mint <X1 mint> [--authority <key>] [--out asset-mint.json]
+ *   npx ts-node scripts/asset-mint.ts propose-pause   --asset 3 [--out asset-mint.json]
+ *   npx ts-node scripts/asset-mint.ts propose-unpause --asset 3 [--out asset-mint.json]
+ *   npx ts-node scripts/asset-mint.ts propose-rate-limit --asset 3 --cap <base units> [--out asset-mint.json]
+ *   npx ts-node scripts/asset-mint.ts sign         --file asset-mint.json   (validator key)
+ *   npx ts-node scripts/asset-mint.ts submit       --file asset-mint.json
+ *   npx ts-node scripts/asset-mint.ts init-pause     --asset 3              (anyone)
+ *   npx ts-node scripts/asset-mint.ts init-fee-pool  --asset 3              (anyone)
+ *   npx ts-node scripts/asset-mint.ts withdraw-fees  --asset 3 --validator <key>   (anyone)
+ *   npx ts-node scripts/asset-mint.ts init-rate-limit --asset 3 --window 86400 --cap <base units>   (asset authority)
+ *   npx ts-node scripts/asset-mint.ts cosign-policy  --asset 3 --cosigner <key> --threshold <base units> [--disable]   (asset authority)
+ *   npx ts-node scripts/asset-mint.ts fee-config     --asset 3 --flat <lamports> --bps <n> --min <lamports> --max <lamports>   (asset authority)
+ *   npx ts-node scripts/asset-mint.ts mint         --asset 3 --nonce 42     (burn already verified)
+ *
+ * The mint must be empty and have the asset's MintState (see status) as its
+ * mint authority before the init proposal is submitted. The authority
+ * (default: the user key) manages the asset's co-sign policy and fee
+ * schedule and creates its rate limit; pausing and cap changes need
+ * validator approval.
+ *
+ * Environment:
+ *   COSIGNER_KEYPAIR - Optional: co-signer keypair file, for mints above the
+ *                      asset's co-sign threshold
+ */
+
+import 'dotenv/config';
+import { Connection, Keypair, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { createAssociatedTokenAccountIdempotentInstruction, getAssociatedTokenAddress } from '@solana/spl-token';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { expandHome, loadConfig, loadKeypair, parseKeypair } from '../sdk/bridge-config/src';
+import { assetRegistryAddress, fetchRegisteredAssets } from '../sdk/attestation-client/src/asset-registry';
+import { featureGateAddress } from '../sdk/attestation-client/src/feature-gate';
+import {
+    assetControlAddress, assetMintFeeAccounts, assetMintStateAddress, assetPauseMessage, assetRateLimitMessage,
+    fetchAssetCoSignPolicy, fetchAssetMintPause, fetchAssetMintState, fetchAssetRateLimit, initializeAssetMessage,
+} from '../sdk/attestation-client/src/asset-mint';
+import { coSignerAccount, requiredCoSigner } from '../sdk/attestation-client/src/cosign-policy';
+import { rateLimitHeadroom } from '../sdk/attestation-client/src/rate-limit';
+import { relayerClaimAddress } from '../sdk/attestation-client/src/relayer-claim';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const BRIDGE_MINT_PROGRAM = new PublicKey(config.programs.bridge_mint);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const LATENCY_STATS = pda('validator_latency_stats');
+
+type ProposalBody =
+    | { kind: 'init'; mint: string; authority: string }
+    | { kind: 'pause' | 'unpause'; nonce: number }
+    | { kind: 'rate-limit'; nonce: number; /** base units, decimal string */ cap: string };
+
+type ProposalBase = ProposalBody & {
+    asset_id: number;
+    validator_set_version: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+};
+
+type Proposal = ProposalBase & {
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+};
+
+function proposalMessage(p: ProposalBase): Buffer {
+    const genesis = proposalX1GenesisHash(p);
+    switch (p.kind) {
+        case 'init':
+            return initializeAssetMessage(
+                BRIDGE_MINT_PROGRAM, p.validator_set_version, p.asset_id, new PublicKey(p.mint), new PublicKey(p.authority), genesis);
+        case 'pause':
+        case 'unpause':
+            return assetPauseMessage(BRIDGE_MINT_PROGRAM, p.kind === 'pause', p.validator_set_version, p.nonce, p.asset_id, genesis);
+        case 'rate-limit':
+            return assetRateLimitMessage(BRIDGE_MINT_PROGRAM, p.validator_set_version, p.nonce, p.asset_id, BigInt(p.cap), genesis);
+    }
+}
+
+function describe(p: ProposalBase): string {
+    switch (p.kind) {
+        case 'init':
+            return `bind asset ${p.asset_id} to mint ${p.mint} (authority ${p.authority})`;
+        case 'pause':
+        case 'unpause':
+            return `${p.kind} mints of asset ${p.asset_id}`;
+        case 'rate-limit':
+            return `set the rate limit cap of asset ${p.asset_id} to ${p.cap}`;
+    }
+}
+
+function writeProposal(base: ProposalBase) {
+    const proposal: Proposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+    const out = arg('out', 'asset-mint.json');
+    fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+    console.log(`✅ Proposal to ${describe(proposal)} written to ${out}`);
+    console.log(`   Message: ${proposal.message}`);
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function u64(value: number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const lightClient: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider);
+    const program: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/bridge_mint_x1.json', 'utf-8')), BRIDGE_MINT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const assetId = parseInt(arg('asset'));
+            const registered = (await fetchRegisteredAssets(connection, LIGHT_CLIENT_PROGRAM))?.find(a => a.assetId === assetId);
+            const mintState = assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId);
+            console.log(`📋 Asset ${assetId}`);
+            console.log(`   Registry: ${!registered ? 'not registered'
+                : `mint program ${registered.mintProgram.toBase58()}${registered.active ? '' : ' (inactive)'}${registered.frozen ? ' (FROZEN)' : ''}`}`);
+            if (registered && !registered.mintProgram.equals(BRIDGE_MINT_PROGRAM)) {
+                console.log('   ⚠️  Registered to another mint program, not bridge-mint-x1');
+            }
+            console.log(`   MintState: ${mintState.toBase58()} (the mint authority to set)`);
+            const state = await fetchAssetMintState(connection, BRIDGE_MINT_PROGRAM, assetId);
+            if (!state) {
+                console.log('   Not initialized: propose-init once the mint is ready');
+                break;
+            }
+            console.log(`   Mint: ${state.mint.toBase58()}`);
+            console.log(`   Authority: ${state.authority.toBase58()}`);
+            console.log(`   Fee per validator: ${state.feePerValidator} lamports`);
+            console.log(`   Minted: ${state.totalMinted} in ${state.processedBurnsCount} burns`);
+            const pause = await fetchAssetMintPause(connection, BRIDGE_MINT_PROGRAM, assetId);
+            console.log(`   Pause: ${!pause ? 'not created' : pause.paused ? `PAUSED since slot ${pause.updatedSlot}` : 'not paused'}`);
+            const limit = await fetchAssetRateLimit(connection, BRIDGE_MINT_PROGRAM, assetId);
+            console.log(`   Rate limit: ${!limit ? 'none'
+                : `${limit.cap} per ${limit.windowSeconds}s, headroom ${rateLimitHeadroom(limit, Math.floor(Date.now() / 1000))}`}`);
+            const policy = await fetchAssetCoSignPolicy(connection, BRIDGE_MINT_PROGRAM, assetId);
+            console.log(`   Co-sign: ${policy?.enabled ? `above ${policy.threshold} by ${policy.cosigner.toBase58()}` : 'none'}`);
+            const pooled = (await connection.getAccountInfo(assetControlAddress(BRIDGE_MINT_PROGRAM, 'fee_pool', assetId))) !== null;
+            console.log(`   Fees: ${pooled ? 'accrue to the fee pool' : 'paid to validator keys'}`);
+            break;
+        }
+
+        case 'propose-init':
+        case 'propose-pause':
+        case 'propose-unpause':
+        case 'propose-rate-limit': {
+            const assetId = parseInt(arg('asset'));
+            const set = await lightClient.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const common = {
+                asset_id: assetId,
+                validator_set_version: set.version.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            if (command === 'propose-init') {
+                writeProposal({
+                    ...common,
+                    kind: 'init',
+                    mint: new PublicKey(arg('mint')).toBase58(),
+                    authority: new PublicKey(arg('authority', wallet.publicKey.toBase58())).toBase58(),
+                });
+            } else if (command === 'propose-rate-limit') {
+                const limit = await fetchAssetRateLimit(connection, BRIDGE_MINT_PROGRAM, assetId);
+                if (!limit) {
+                    throw new Error(`Asset ${assetId} has no rate limit: run init-rate-limit first`);
+                }
+                writeProposal({ ...common, kind: 'rate-limit', nonce: Number(limit.nonce), cap: BigInt(arg('cap')).toString() });
+            } else {
+                const pause = await fetchAssetMintPause(connection, BRIDGE_MINT_PROGRAM, assetId);
+                if (!pause) {
+                    throw new Error(`Asset ${assetId} has no pause switch: run init-pause first`);
+                }
+                writeProposal({ ...common, kind: command === 'propose-pause' ? 'pause' : 'unpause', nonce: Number(pause.nonce) });
+            }
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: Proposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Proposal: ${describe(proposal)}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed asset mint proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: Proposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting: ${describe(proposal)} with ${approverSignatures.length} valid approvals`);
+
+            const assetId = proposal.asset_id;
+            const governance = {
+                validatorSet: VALIDATOR_SET,
+                x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+            };
+            let tx: string;
+            switch (proposal.kind) {
+                case 'init': {
+                    const mint = new PublicKey(proposal.mint);
+                    tx = await program.methods
+                        .initializeAsset(assetId, { authority: new PublicKey(proposal.authority), approverSignatures })
+                        .accounts({
+                            ...governance,
+                            assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+                            mintState: assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId),
+                            mint,
+                            payer: wallet.publicKey,
+                            tokenProgram: (await connection.getAccountInfo(mint))!.owner,
+                            systemProgram: SystemProgram.programId,
+                        })
+                        .rpc();
+                    break;
+                }
+                case 'pause':
+                case 'unpause':
+                    tx = await (proposal.kind === 'pause' ? program.methods.pauseAsset : program.methods.unpauseAsset)(assetId, { approverSignatures })
+                        .accounts({
+                            ...governance,
+                            mintPause: assetControlAddress(BRIDGE_MINT_PROGRAM, 'mint_pause', assetId),
+                            signer: wallet.publicKey,
+                        })
+                        .rpc();
+                    break;
+                case 'rate-limit':
+                    tx = await program.methods
+                        .setRateLimit(assetId, { cap: new anchor.BN(proposal.cap), approverSignatures })
+                        .accounts({
+                            ...governance,
+                            rateLimit: assetControlAddress(BRIDGE_MINT_PROGRAM, 'rate_limit', assetId),
+                            signer: wallet.publicKey,
+                        })
+                        .rpc();
+                    break;
+                default:
+                    throw new Error(`Unknown proposal kind in ${arg('file')}`);
+            }
+            console.log(`✅ Done: ${tx}`);
+            break;
+        }
+
+        case 'init-pause':
+        case 'init-fee-pool': {
+            const assetId = parseInt(arg('asset'));
+            const pause = command === 'init-pause';
+            const tx = await (pause ? program.methods.initializeMintPause : program.methods.initializeFeePool)(assetId)
+                .accounts({
+                    mintState: assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId),
+                    [pause ? 'mintPause' : 'feePool']: assetControlAddress(BRIDGE_MINT_PROGRAM, pause ? 'mint_pause' : 'fee_pool', assetId),
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ ${pause ? 'Pause switch' : 'Fee pool'} of asset ${assetId} created: ${tx}`);
+            break;
+        }
+
+        case 'withdraw-fees': {
+            const assetId = parseInt(arg('asset'));
+            const validator = new PublicKey(arg('validator'));
+            const tx = await program.methods
+                .withdrawFees(assetId)
+                .accounts({
+                    feePool: assetControlAddress(BRIDGE_MINT_PROGRAM, 'fee_pool', assetId),
+                    validator,
+                })
+                .rpc();
+            console.log(`✅ Pooled fees of asset ${assetId} paid to ${validator.toBase58()}: ${tx}`);
+            break;
+        }
+
+        case 'init-rate-limit': {
+            const assetId = parseInt(arg('asset'));
+            const tx = await program.methods
+                .initializeRateLimit(assetId, new anchor.BN(arg('window', '86400')), new anchor.BN(arg('cap')))
+                .accounts({
+                    mintState: assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId),
+                    rateLimit: assetControlAddress(BRIDGE_MINT_PROGRAM, 'rate_limit', assetId),
+                    authority: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Rate limit of asset ${assetId} created: ${tx}`);
+            break;
+        }
+
+        case 'cosign-policy': {
+            const assetId = parseInt(arg('asset'));
+            const cosigner = new PublicKey(arg('cosigner'));
+            const threshold = new anchor.BN(arg('threshold'));
+            const cosignPolicy = assetControlAddress(BRIDGE_MINT_PROGRAM, 'cosign_policy', assetId);
+            const mintState = assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId);
+            const tx = await connection.getAccountInfo(cosignPolicy)
+                ? await program.methods
+                    .setCosignPolicy(assetId, !process.argv.includes('--disable'), cosigner, threshold)
+                    .accounts({ mintState, cosignPolicy, authority: wallet.publicKey })
+                    .rpc()
+                : await program.methods
+                    .initializeCosignPolicy(assetId, cosigner, threshold)
+                    .accounts({ mintState, cosignPolicy, authority: wallet.publicKey, systemProgram: SystemProgram.programId })
+                    .rpc();
+            console.log(`✅ Co-sign policy of asset ${assetId} updated: ${tx}`);
+            break;
+        }
+
+        case 'fee-config': {
+            const assetId = parseInt(arg('asset'));
+            const schedule = [
+                new anchor.BN(arg('flat')), parseInt(arg('bps')), new anchor.BN(arg('min')), new anchor.BN(arg('max')),
+            ] as const;
+            const feeConfig = assetControlAddress(BRIDGE_MINT_PROGRAM, 'fee_config', assetId);
+            const mintState = assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId);
+            const tx = await connection.getAccountInfo(feeConfig)
+                ? await program.methods
+                    .updateFeeConfig(assetId, ...schedule)
+                    .accounts({ mintState, feeConfig, authority: wallet.publicKey })
+                    .rpc()
+                : await program.methods
+                    .initializeFeeConfig(assetId, ...schedule)
+                    .accounts({ mintState, feeConfig, authority: wallet.publicKey, systemProgram: SystemProgram.programId })
+                    .rpc();
+            console.log(`✅ Fee config of asset ${assetId} updated: ${tx}`);
+            break;
+        }
+
+        case 'mint': {
+            const assetId = parseInt(arg('asset'));
+            const nonce = parseInt(arg('nonce'));
+            const state = await fetchAssetMintState(connection, BRIDGE_MINT_PROGRAM, assetId);
+            if (!state) {
+                throw new Error(`Asset ${assetId} is not initialized in bridge-mint-x1`);
+            }
+            const user = wallet.publicKey;
+            const tokenProgram = (await connection.getAccountInfo(state.mint))!.owner;
+            const userTokenAccount = await getAssociatedTokenAddress(state.mint, user, false, tokenProgram);
+            const verifiedBurn = PublicKey.findProgramAddressSync(
+                [Buffer.from('verified_burn_v3'), Buffer.from([assetId]), user.toBuffer(), u64(nonce)],
+                LIGHT_CLIENT_PROGRAM)[0];
+            const processedBurn = PublicKey.findProgramAddressSync(
+                [Buffer.from('processed_burn_v3'), Buffer.from([assetId]), u64(nonce), user.toBuffer()],
+                BRIDGE_MINT_PROGRAM)[0];
+            const set = await lightClient.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const pause = await fetchAssetMintPause(connection, BRIDGE_MINT_PROGRAM, assetId);
+            if (pause?.paused) {
+                throw new Error(`Mints of asset ${assetId} are paused by validator governance`);
+            }
+            const amount = (await connection.getAccountInfo(verifiedBurn))?.data.readBigUInt64LE(8 + 1 + 8 + 32);
+            const feePool = assetControlAddress(BRIDGE_MINT_PROGRAM, 'fee_pool', assetId);
+            const remainingAccounts = assetMintFeeAccounts(set.validators, (await connection.getAccountInfo(feePool)) !== null);
+            const signers: Keypair[] = [];
+            const cosigner = amount === undefined ? null
+                : requiredCoSigner(await fetchAssetCoSignPolicy(connection, BRIDGE_MINT_PROGRAM, assetId), amount);
+            if (cosigner) {
+                if (!process.env.COSIGNER_KEYPAIR) {
+                    throw new Error(`Amount above the co-sign threshold: ${cosigner.toBase58()} must co-sign (set COSIGNER_KEYPAIR)`);
+                }
+                const cosignerKeypair = parseKeypair(fs.readFileSync(expandHome(process.env.COSIGNER_KEYPAIR), 'utf-8'));
+                if (!cosignerKeypair.publicKey.equals(cosigner)) {
+                    throw new Error(`COSIGNER_KEYPAIR is ${cosignerKeypair.publicKey.toBase58()}, policy requires ${cosigner.toBase58()}`);
+                }
+                remainingAccounts.push(coSignerAccount(cosigner));
+                signers.push(cosignerKeypair);
+            }
+
+            const tx = await program.methods
+                .mintFromBurnV3(assetId, new anchor.BN(nonce))
+                .accounts({
+                    mintState: assetMintStateAddress(BRIDGE_MINT_PROGRAM, assetId),
+                    mint: state.mint,
+                    processedBurn,
+                    userTokenAccount,
+                    user,
+                    validatorSet: VALIDATOR_SET,
+                    verifiedBurn,
+                    relayerClaim: relayerClaimAddress(LIGHT_CLIENT_PROGRAM, assetId, user, nonce),
+                    mintPause: assetControlAddress(BRIDGE_MINT_PROGRAM, 'mint_pause', assetId),
+                    cosignPolicy: assetControlAddress(BRIDGE_MINT_PROGRAM, 'cosign_policy', assetId),
+                    rateLimit: assetControlAddress(BRIDGE_MINT_PROGRAM, 'rate_limit', assetId),
+                    feePool,
+                    feeConfig: assetControlAddress(BRIDGE_MINT_PROGRAM, 'fee_config', assetId),
+                    featureGate: featureGateAddress(LIGHT_CLIENT_PROGRAM),
+                    latencyStats: LATENCY_STATS,
+                    assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+                    lightClientProgram: LIGHT_CLIENT_PROGRAM,
+                    tokenProgram,
+                    systemProgram: SystemProgram.programId,
+                })
+                .preInstructions([
+                    createAssociatedTokenAccountIdempotentInstruction(user, userTokenAccount, user, state.mint, tokenProgram),
+                ])
+                .remainingAccounts(remainingAccounts)
+                .signers(signers)
+                .rpc();
+            console.log(`✅ Burn ${nonce} of asset ${assetId} minted: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: asset-mint.ts <status|propose-init|propose-pause|propose-unpause|propose-rate-limit|sign|submit'
+                + '|init-pause|init-fee-pool|withdraw-fees|init-rate-limit|cosign-policy|fee-config|mint> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
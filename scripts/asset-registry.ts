@@ -0,0 +1,197 @@
+/**
+ * Asset Registry (governed list of bridged tokens)
+ *
+ * Adds a bridged token with its own mint program, stops attesting new
+ * burns of one, or freezes one (no attestations or mints) while the other
+ * assets keep running, without redeploying the light client. `init`
+ * creates the registry with the built-in assets (XENCAT = 1, DGN = 2);
+ * changes need threshold validator approval.
+ *
+ * Usage:
+ *   npx ts-node scripts/asset-registry.ts init
+ *   npx ts-node scripts/asset-registry.ts status
+ *   npx ts-node scripts/asset-registry.ts propose --action register --asset 3 --mint-program <pubkey> [--out asset.json]
+ *   npx ts-node scripts/asset-registry.ts propose --action deactivate --asset 3 [--out asset.json]
+ *   npx ts-node scripts/asset-registry.ts propose --action freeze|unfreeze --asset 2 [--out asset.json]
+ *   npx ts-node scripts/asset-registry.ts sign    --file asset.json   (validator key)
+ *   npx ts-node scripts/asset-registry.ts submit  --file asset.json
+ *
+ * Register a new asset only once its mint program is deployed and the
+ * validator services attest its burns. Deactivated assets keep minting
+ * burns that were already verified; frozen assets do not.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { assetRegistryAddress, fetchRegisteredAssets } from '../sdk/attestation-client/src/asset-registry';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const ASSET_REGISTRY = assetRegistryAddress(LIGHT_CLIENT_PROGRAM);
+
+interface AssetProposal {
+    action: 'REGISTER_ASSET' | 'DEACTIVATE_ASSET' | 'FREEZE_ASSET' | 'UNFREEZE_ASSET';
+    asset_id: number;
+    /** base58, REGISTER_ASSET only */
+    mint_program?: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_{register,deactivate,freeze,unfreeze}_asset_message on-chain */
+function assetMessage(p: Omit<AssetProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(p.action),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        Buffer.from([p.asset_id]),
+        p.action === 'REGISTER_ASSET' ? new PublicKey(p.mint_program!).toBuffer() : Buffer.alloc(0),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods
+                .initializeAssetRegistry()
+                .accounts({
+                    assetRegistry: ASSET_REGISTRY,
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Asset registry created: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const assets = await fetchRegisteredAssets(connection, LIGHT_CLIENT_PROGRAM);
+            console.log(`🪙 Asset registry ${ASSET_REGISTRY.toBase58()}`);
+            if (!assets) {
+                console.log('   Not initialized: built-in assets apply (XENCAT = 1, DGN = 2)');
+                break;
+            }
+            for (const asset of assets) {
+                console.log(`   ${asset.assetId}: ${asset.mintProgram.toBase58()} ${asset.active ? 'active' : 'DEACTIVATED'}${asset.frozen ? ' FROZEN' : ''} (slot ${asset.updatedSlot})`);
+            }
+            break;
+        }
+
+        case 'propose': {
+            const actions: Record<string, AssetProposal['action']> = {
+                register: 'REGISTER_ASSET',
+                deactivate: 'DEACTIVATE_ASSET',
+                freeze: 'FREEZE_ASSET',
+                unfreeze: 'UNFREEZE_ASSET',
+            };
+            const action = actions[arg('action')];
+            if (!action) {
+                throw new Error('--action must be register, deactivate, freeze or unfreeze');
+            }
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                action,
+                asset_id: parseInt(arg('asset')),
+                mint_program: action === 'REGISTER_ASSET' ? new PublicKey(arg('mint-program')).toBase58() : undefined,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: AssetProposal = { ...base, message: assetMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'asset.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ ${action} proposal for asset ${proposal.asset_id} written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: AssetProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = assetMessage(proposal);
+            console.log(`   ${proposal.action} asset ${proposal.asset_id}${proposal.mint_program ? ` (mint program ${proposal.mint_program})` : ''}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed asset proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: AssetProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = assetMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting ${proposal.action} with ${approverSignatures.length} valid approvals`);
+
+            const accounts = {
+                validatorSet: VALIDATOR_SET,
+                x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                bridgeConfig: BRIDGE_CONFIG,
+                assetRegistry: ASSET_REGISTRY,
+                signer: wallet.publicKey,
+            };
+            const params = { assetId: proposal.asset_id, approverSignatures };
+            const method = {
+                REGISTER_ASSET: () => program.methods.registerAsset({ ...params, mintProgram: new PublicKey(proposal.mint_program!) }),
+                DEACTIVATE_ASSET: () => program.methods.deactivateAsset(params),
+                FREEZE_ASSET: () => program.methods.freezeAsset(params),
+                UNFREEZE_ASSET: () => program.methods.unfreezeAsset(params),
+            }[proposal.action];
+            const tx = await method().accounts(accounts).rpc();
+            console.log(`✅ Asset ${proposal.asset_id} updated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: asset-registry.ts <init|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,152 @@
+/**
+ * Attestation Expiry Window (max_attestation_age)
+ *
+ * V3 attestations are rejected once older than the validator set's
+ * max_attestation_age (default 24h), or if signed in the future beyond
+ * 2 minutes of clock skew. This limits how long a leaked signature stays
+ * usable. Changing the window needs threshold validator approval.
+ *
+ * Usage:
+ *   npx ts-node scripts/attestation-age.ts status
+ *   npx ts-node scripts/attestation-age.ts propose --seconds 43200 [--out attestation-age.json]
+ *   npx ts-node scripts/attestation-age.ts sign    --file attestation-age.json   (validator key)
+ *   npx ts-node scripts/attestation-age.ts submit  --file attestation-age.json
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+
+/** Must match X1ValidatorSet::DEFAULT_MAX_ATTESTATION_AGE and its governance bounds */
+const DEFAULT_MAX_ATTESTATION_AGE = 86_400;
+const MIN_MAX_ATTESTATION_AGE = 300;
+const MAX_MAX_ATTESTATION_AGE = 604_800;
+
+interface AgeProposal {
+    max_attestation_age: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigInt64LE(BigInt(value));
+    return buf;
+}
+
+/** Must match create_max_attestation_age_message on-chain */
+function ageMessage(p: Omit<AgeProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_ATTESTATION_AGE'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        u64(p.max_attestation_age),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const age = set.maxAttestationAge.toNumber();
+            console.log(`⏱️ Max attestation age: ${age === 0 ? `${DEFAULT_MAX_ATTESTATION_AGE}s (default)` : `${age}s`}`);
+            break;
+        }
+
+        case 'propose': {
+            const seconds = parseInt(arg('seconds'));
+            if (seconds < MIN_MAX_ATTESTATION_AGE || seconds > MAX_MAX_ATTESTATION_AGE) {
+                throw new Error(`--seconds must be between ${MIN_MAX_ATTESTATION_AGE} and ${MAX_MAX_ATTESTATION_AGE}`);
+            }
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                max_attestation_age: seconds,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: AgeProposal = { ...base, message: ageMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'attestation-age.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Max attestation age proposal (${seconds}s) written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: AgeProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = ageMessage(proposal);
+            console.log(`   Max attestation age: ${proposal.max_attestation_age}s`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed attestation age proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: AgeProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = ageMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting max attestation age with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setMaxAttestationAge({ maxAttestationAge: new anchor.BN(proposal.max_attestation_age), approverSignatures })
+                .accounts({ validatorSet: VALIDATOR_SET, x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM), bridgeConfig: BRIDGE_CONFIG, signer: wallet.publicKey })
+                .rpc();
+            console.log(`✅ Max attestation age updated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: attestation-age.ts <status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,108 @@
+/**
+ * Attestation Coordinator (shared work queue)
+ *
+ * Runs one coordinator instance against the queue configured in
+ * [relayer.queue]. Start as many instances as you like on different hosts:
+ * each claims jobs with a lease, and a crashed instance's half-collected
+ * bundles are picked up by another one once the lease expires.
+ *
+ * Usage:
+ *   npx ts-node scripts/attestation-coordinator.ts run     [--id coordinator-a]
+ *   npx ts-node scripts/attestation-coordinator.ts enqueue --asset 1 --nonce 1234 --user <pubkey> --amount 1000000
+ *   npx ts-node scripts/attestation-coordinator.ts status  --asset 1 --nonce 1234 --user <pubkey>
+ *
+ * bridge-mint.ts enqueues its transfer and waits for the bundle when a
+ * queue is configured, so relayers no longer collect attestations
+ * themselves.
+ */
+
+import 'dotenv/config';
+import { PublicKey } from '@solana/web3.js';
+import { loadConfig } from '../sdk/bridge-config/src';
+import { connectWorkQueue, defaultWorkerId, jobId, runWorker } from '../sdk/attestation-client/src/work-queue';
+
+const { config } = loadConfig();
+const QUEUE = config.relayer.queue;
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    if (QUEUE.backend === 'none') {
+        throw new Error('No work queue configured (set [relayer.queue] backend and url in xencat.toml)');
+    }
+    const { queue, close } = await connectWorkQueue(QUEUE.backend, QUEUE.url!, QUEUE.max_attempts);
+    const command = process.argv[2];
+
+    switch (command) {
+        case 'run': {
+            const workerId = arg('id', config.relayer.id || defaultWorkerId());
+            let stopping = false;
+            for (const signal of ['SIGINT', 'SIGTERM'] as const) {
+                process.on(signal, () => {
+                    console.log(`\n🛑 ${signal}: finishing the current job, then exiting`);
+                    stopping = true;
+                });
+            }
+            console.log(`🧭 Coordinator ${workerId} on ${QUEUE.backend} queue (lease ${QUEUE.lease_seconds}s)`);
+            await runWorker(queue, {
+                workerId,
+                leaseMs: QUEUE.lease_seconds * 1000,
+                threshold: config.attestation.threshold,
+                endpoints: config.attestation.validators.map(v => ({
+                    pubkey: new PublicKey(v.pubkey),
+                    url: v.url,
+                    certSha256: v.cert_sha256,
+                })),
+            }, () => stopping);
+            break;
+        }
+
+        case 'enqueue': {
+            const job = await queue.enqueue({
+                asset_id: Number(arg('asset')),
+                burn_nonce: Number(arg('nonce')),
+                user: new PublicKey(arg('user')).toBase58(),
+                expected_amount: Number(arg('amount')),
+                validator_set_version: Number(arg('version', '1')),
+            });
+            console.log(`✅ ${job.id}: ${job.status}`);
+            break;
+        }
+
+        case 'status': {
+            const id = jobId(Number(arg('asset')), Number(arg('nonce')), new PublicKey(arg('user')).toBase58());
+            const job = await queue.get(id);
+            if (!job) {
+                console.log(`❓ No job ${id}`);
+                break;
+            }
+            console.log(`📋 ${job.id}: ${job.status} (attempt ${job.attempts}/${QUEUE.max_attempts})`);
+            console.log(`   Attestations: ${job.attestations.map(a => a.validator_pubkey).join(', ') || 'none'}`);
+            if (job.lease_owner) {
+                console.log(`   Leased by ${job.lease_owner} until ${new Date(job.lease_expires_at!).toISOString()}`);
+            }
+            if (job.error) {
+                console.log(`   Last error: ${job.error}`);
+            }
+            break;
+        }
+
+        default:
+            console.error('Usage: attestation-coordinator.ts <run|enqueue|status> [options]');
+            process.exitCode = 1;
+    }
+
+    await close();
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,141 @@
+/**
+ * Attestation Ordering Rule (ordered_attestations)
+ *
+ * While on, V3 submits only accept attestations in ascending validator set
+ * order, so the light client finds duplicates by comparing neighbours.
+ * Relayers sort bundles with the SDK's orderAttestations, which is harmless
+ * while the rule is off: make sure they do before turning it on. Changing
+ * the rule needs threshold validator approval.
+ *
+ * Usage:
+ *   npx ts-node scripts/attestation-ordering.ts status
+ *   npx ts-node scripts/attestation-ordering.ts propose --ordered true|false [--out attestation-ordering.json]
+ *   npx ts-node scripts/attestation-ordering.ts sign    --file attestation-ordering.json   (validator key)
+ *   npx ts-node scripts/attestation-ordering.ts submit  --file attestation-ordering.json
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { attestationOrderingMessage, fetchAttestationOrdering } from '../sdk/attestation-client/src/attestation-ordering';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+
+interface OrderingProposal {
+    ordered: boolean;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function orderingMessage(p: Omit<OrderingProposal, 'message' | 'approvals'>): Buffer {
+    return attestationOrderingMessage(p.validator_set_version, p.config_nonce, p.ordered, proposalX1GenesisHash(p));
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const ordering = await fetchAttestationOrdering(connection, LIGHT_CLIENT_PROGRAM);
+            console.log(`🔢 Attestation ordering: ${ordering.ordered ? 'required (ascending validator set order)' : 'any order'}`);
+            console.log(`   Validator set v${ordering.validatorSetVersion}:`);
+            ordering.validators.forEach((validator, index) => console.log(`   ${index}. ${validator.toBase58()}`));
+            break;
+        }
+
+        case 'propose': {
+            const value = arg('ordered');
+            if (value !== 'true' && value !== 'false') {
+                throw new Error('--ordered must be true or false');
+            }
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                ordered: value === 'true',
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: OrderingProposal = { ...base, message: orderingMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'attestation-ordering.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Attestation ordering proposal (${base.ordered ? 'required' : 'any order'}) written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: OrderingProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = orderingMessage(proposal);
+            console.log(`   Attestation ordering: ${proposal.ordered ? 'required' : 'any order'}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed attestation ordering proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: OrderingProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = orderingMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting attestation ordering with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setAttestationOrdering({ ordered: proposal.ordered, approverSignatures })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Attestation ordering updated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: attestation-ordering.ts <status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
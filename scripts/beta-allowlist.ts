@@ -0,0 +1,261 @@
+/**
+ * Permissioned Beta of New Assets
+ *
+ * A new asset can start with an allowlist on both ends: the light client
+ * only verifies its burns for listed X1 users (validator governance), and
+ * the burn program's burn_registered_asset only accepts listed Solana
+ * burners (upgrade authority). Lift both once the asset graduates.
+ *
+ * Usage:
+ *   npx ts-node scripts/beta-allowlist.ts status  --asset 3
+ *   npx ts-node scripts/beta-allowlist.ts init    --asset 3
+ *   npx ts-node scripts/beta-allowlist.ts propose --asset 3 --users <pk1,pk2,...> [--out beta-allowlist.json]
+ *   npx ts-node scripts/beta-allowlist.ts propose --asset 3 --disable   (graduate on X1)
+ *   npx ts-node scripts/beta-allowlist.ts sign    --file beta-allowlist.json   (validator key)
+ *   npx ts-node scripts/beta-allowlist.ts submit  --file beta-allowlist.json
+ *   npx ts-node scripts/beta-allowlist.ts burn-create --asset 3 --users <pk1,pk2,...>   (burn program upgrade authority)
+ *   npx ts-node scripts/beta-allowlist.ts burn-update --asset 3 --users <pk1,pk2,...>   (burn program upgrade authority)
+ *   npx ts-node scripts/beta-allowlist.ts burn-close  --asset 3                         (graduate on Solana)
+ */
+
+import 'dotenv/config';
+import {
+    Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction,
+} from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    BETA_ALLOWLIST_MAX_USERS,
+    betaAllowlistAddress,
+    betaAllowlistMessage,
+    fetchBetaAllowlist,
+    fetchBurnBetaAllowlist,
+} from '../sdk/attestation-client/src/beta-allowlist';
+import { registeredBurnAssetAddress } from '../sdk/attestation-client/src/burn-assets';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const BPF_LOADER_UPGRADEABLE = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const BURN_PROGRAM_DATA = PublicKey.findProgramAddressSync([BURN_PROGRAM.toBuffer()], BPF_LOADER_UPGRADEABLE)[0];
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+
+interface BetaAllowlistProposal {
+    asset_id: number;
+    enabled: boolean;
+    users: string[];
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+const proposalMessage = (p: Omit<BetaAllowlistProposal, 'message' | 'approvals'>) =>
+    betaAllowlistMessage(p.validator_set_version, p.config_nonce, p.asset_id, p.enabled, p.users.map(u => new PublicKey(u)), proposalX1GenesisHash(p));
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function usersArg(): PublicKey[] {
+    const users = arg('users').split(',').filter(Boolean).map(u => new PublicKey(u.trim()));
+    if (users.length === 0 || users.length > BETA_ALLOWLIST_MAX_USERS) {
+        throw new Error(`--users must list 1 to ${BETA_ALLOWLIST_MAX_USERS} keys`);
+    }
+    return users;
+}
+
+/** Borsh Vec<Pubkey> */
+function encodeUsers(users: PublicKey[]): Buffer {
+    const count = Buffer.alloc(4);
+    count.writeUInt32LE(users.length);
+    return Buffer.concat([count, ...users.map(u => u.toBuffer())]);
+}
+
+async function main() {
+    const command = process.argv[2];
+
+    if (command?.startsWith('burn-')) {
+        const connection = new Connection(config.solana.rpc, 'confirmed');
+        const wallet = loadKeypair(config, 'user');
+        const assetId = parseInt(arg('asset'));
+        const betaAllowlist = betaAllowlistAddress(BURN_PROGRAM, assetId);
+        const authority = [
+            { pubkey: BURN_PROGRAM, isSigner: false, isWritable: false },
+            { pubkey: BURN_PROGRAM_DATA, isSigner: false, isWritable: false },
+        ];
+        let ix: TransactionInstruction;
+        switch (command) {
+            case 'burn-create':
+                ix = new TransactionInstruction({
+                    programId: BURN_PROGRAM,
+                    keys: [
+                        { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                        { pubkey: registeredBurnAssetAddress(BURN_PROGRAM, assetId), isSigner: false, isWritable: false },
+                        { pubkey: betaAllowlist, isSigner: false, isWritable: true },
+                        ...authority,
+                        { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                    ],
+                    data: Buffer.concat([discriminator('create_beta_allowlist'), Buffer.from([assetId]), encodeUsers(usersArg())]),
+                });
+                break;
+            case 'burn-update':
+                ix = new TransactionInstruction({
+                    programId: BURN_PROGRAM,
+                    keys: [
+                        { pubkey: wallet.publicKey, isSigner: true, isWritable: false },
+                        { pubkey: betaAllowlist, isSigner: false, isWritable: true },
+                        ...authority,
+                    ],
+                    data: Buffer.concat([discriminator('update_beta_allowlist'), encodeUsers(usersArg())]),
+                });
+                break;
+            case 'burn-close':
+                ix = new TransactionInstruction({
+                    programId: BURN_PROGRAM,
+                    keys: [
+                        { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                        { pubkey: betaAllowlist, isSigner: false, isWritable: true },
+                        ...authority,
+                    ],
+                    data: discriminator('close_beta_allowlist'),
+                });
+                break;
+            default:
+                throw new Error(`Unknown command ${command}`);
+        }
+        const tx = await sendAndConfirmTransaction(connection, new Transaction().add(ix), [wallet]);
+        console.log(`✅ ${command} for asset ${assetId}: ${tx}`);
+        return;
+    }
+
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const assetId = parseInt(arg('asset'));
+            const list = await fetchBetaAllowlist(connection, LIGHT_CLIENT_PROGRAM, assetId);
+            console.log(`🧪 Asset ${assetId} beta allowlist`);
+            if (!list || !list.enabled) {
+                console.log(`   X1: ${list ? 'disabled' : 'not created'} (any user can mint)`);
+            } else {
+                console.log(`   X1: ${list.users.length} users (updated at slot ${list.updatedSlot})`);
+                list.users.forEach(u => console.log(`     - ${u.toBase58()}`));
+            }
+            const solana = new Connection(config.solana.rpc, 'confirmed');
+            const burners = await fetchBurnBetaAllowlist(solana, BURN_PROGRAM, assetId);
+            if (!burners) {
+                console.log('   Solana: no list (anyone can burn)');
+            } else {
+                console.log(`   Solana: ${burners.length} burners`);
+                burners.forEach(u => console.log(`     - ${u.toBase58()}`));
+            }
+            break;
+        }
+
+        case 'init': {
+            const assetId = parseInt(arg('asset'));
+            const tx = await program.methods
+                .initializeBetaAllowlist(assetId)
+                .accounts({
+                    betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, assetId),
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Beta allowlist of asset ${assetId} created (disabled): ${tx}`);
+            break;
+        }
+
+        case 'propose': {
+            const enabled = !process.argv.includes('--disable');
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                asset_id: parseInt(arg('asset')),
+                enabled,
+                users: enabled ? usersArg().map(u => u.toBase58()) : [],
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: BetaAllowlistProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'beta-allowlist.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Beta allowlist proposal for asset ${base.asset_id} (${enabled ? `${base.users.length} users` : 'disable'}) written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: BetaAllowlistProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Asset ${proposal.asset_id}: ${proposal.enabled ? `allow only ${proposal.users.join(', ')}` : 'graduate (disable the list)'}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed beta allowlist proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: BetaAllowlistProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting beta allowlist with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setBetaAllowlist(proposal.asset_id, {
+                    enabled: proposal.enabled,
+                    users: proposal.users.map(u => new PublicKey(u)),
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, proposal.asset_id),
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Beta allowlist of asset ${proposal.asset_id} ${proposal.enabled ? 'set' : 'disabled'}: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: beta-allowlist.ts <status|init|propose|sign|submit|burn-create|burn-update|burn-close> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
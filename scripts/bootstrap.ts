@@ -0,0 +1,621 @@
+/**
+ * Cold-Start Bootstrap
+ *
+ * Deploys and initializes a brand-new bridge environment from the
+ * configured program ids, in dependency order: burn program (Solana),
+ * light client and its permissionless accounts, both mint programs, their
+ * mints and metadata (X1). Every step checks the chain first, so `run`
+ * resumes where it stopped; progress and signatures also go to a state
+ * file. Governed steps (validator approvals) and external prerequisites
+ * stop the run with the command to carry them out; run again once done.
+ *
+ * Usage:
+ *   npx ts-node scripts/bootstrap.ts plan [--json bootstrap-plan.json]
+ *   npx ts-node scripts/bootstrap.ts run  [--skip x1.timelock,...] [--dgn-mint-keypair dgn-mint.json] [--state bootstrap-state.json]
+ *
+ * Build first (`anchor build`, and `anchor build` in solana-burn-program/).
+ * Program ids come from [programs] in xencat.toml and must match the
+ * program keypairs in target/deploy. Deploys pay from keys.user.
+ */
+
+import 'dotenv/config';
+import { Connection, Keypair, PublicKey, SYSVAR_RENT_PUBKEY, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { AuthorityType, MINT_SIZE, TOKEN_PROGRAM_ID, createInitializeMint2Instruction, getMint, getMinimumBalanceForRentExemptMint, setAuthority } from '@solana/spl-token';
+import { execFileSync } from 'child_process';
+import crypto from 'crypto';
+import fs from 'fs';
+import { expandHome, loadConfig, loadKeypair, parseKeypair } from '../sdk/bridge-config/src';
+import { featureGateAddress } from '../sdk/attestation-client/src/feature-gate';
+import { assetRegistryAddress } from '../sdk/attestation-client/src/asset-registry';
+import { devModeConfigAddress } from '../sdk/attestation-client/src/dev-mode';
+import { eventSchemaRegistryAddress } from '../sdk/attestation-client/src/event-schema';
+import { programHashRegistryAddress } from '../sdk/attestation-client/src/program-hashes';
+import { burnAgeLimitAddress } from '../sdk/attestation-client/src/burn-age-limit';
+import { bridgeInfoAddress } from '../sdk/attestation-client/src/bridge-info';
+import { bridgeDomainAddress } from '../sdk/attestation-client/src/bridge-domain';
+import { x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+import { validatorSetTimelockAddress } from '../sdk/attestation-client/src/validator-set-timelock';
+import { x1ValidatorSetHistoryAddress } from '../sdk/attestation-client/src/validator-set-history';
+import { mintPauseAddress } from '../sdk/attestation-client/src/pause';
+import { returnBridgeAddress } from '../sdk/attestation-client/src/return-bridge';
+
+const { config } = loadConfig();
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const XENCAT_MINT_PROGRAM = new PublicKey(config.programs.xencat_mint);
+const DGN_MINT_PROGRAM = new PublicKey(config.programs.dgn_mint);
+const XENCAT_MINT = new PublicKey(config.mints.xencat_x1);
+const DGN_MINT = new PublicKey(config.mints.dgn_x1);
+const TOKEN_METADATA_PROGRAM = new PublicKey('metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s');
+
+/** Same values as scripts/create-token-metadata.ts and create-dgn-metadata.ts */
+const METADATA = {
+    xencat: {
+        name: 'XENCAT',
+        symbol: 'XENCAT',
+        uri: 'https://raw.githubusercontent.com/Commoneffort/xencat-light-client/main/metadata/xencat-metadata.json',
+    },
+    dgn: {
+        name: 'Degen',
+        symbol: 'DGN',
+        uri: 'https://ipfs.io/ipfs/bafkreig7xnvrx3dcx2xgqsb5422gcsbgb6srwxoqeawevynvnelibmwtgq',
+    },
+};
+
+/** Build artifacts of each program (`anchor build`) */
+const ARTIFACTS = {
+    burn: { program: BURN_PROGRAM, so: 'solana-burn-program/target/deploy/xencat_burn.so', keypair: 'solana-burn-program/target/deploy/xencat_burn-keypair.json' },
+    light_client: { program: LIGHT_CLIENT_PROGRAM, so: 'target/deploy/solana_light_client_x1.so', keypair: 'target/deploy/solana_light_client_x1-keypair.json' },
+    xencat_mint: { program: XENCAT_MINT_PROGRAM, so: 'target/deploy/xencat_mint_x1.so', keypair: 'target/deploy/xencat_mint_x1-keypair.json' },
+    dgn_mint: { program: DGN_MINT_PROGRAM, so: 'target/deploy/dgn_mint_x1.so', keypair: 'target/deploy/dgn_mint_x1-keypair.json' },
+};
+
+type Chain = 'solana' | 'x1';
+
+interface Step {
+    id: string;
+    chain: Chain;
+    title: string;
+    /** Steps that must be done (or skipped) first */
+    after: string[];
+    /** May be left out with --skip */
+    optional?: boolean;
+    /** Already applied on chain */
+    done: () => Promise<boolean>;
+    /** Program this step deploys */
+    deploys?: keyof typeof ARTIFACTS;
+    /** Shell command `run` executes (program deploys) */
+    command?: string[];
+    /** Transaction `run` sends; returns its signature */
+    send?: () => Promise<string>;
+    /** Governed or external step: what the operator has to do */
+    manual?: string;
+}
+
+interface BootstrapState {
+    programs: typeof config.programs;
+    steps: Record<string, { signature?: string; completedAt: string }>;
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+const pda = (seeds: Buffer[], program: PublicKey) => PublicKey.findProgramAddressSync(seeds, program)[0];
+const exists = async (connection: Connection, address: PublicKey) => (await connection.getAccountInfo(address)) !== null;
+const isProgram = async (connection: Connection, address: PublicKey) => (await connection.getAccountInfo(address))?.executable === true;
+const keypairAddress = (file: string) => parseKeypair(fs.readFileSync(file, 'utf-8')).publicKey;
+
+/** Anchor instruction discriminator: sha256("global:<name>")[..8] */
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+
+function loadProgram(idlFile: string, programId: PublicKey, provider: anchor.AnchorProvider): any {
+    return new anchor.Program(JSON.parse(fs.readFileSync(idlFile, 'utf-8')), programId, provider);
+}
+
+function buildSteps(solana: Connection, x1: Connection, payer: Keypair, payerFile: string, dgnMintKeypair?: Keypair): Step[] {
+    const provider = new anchor.AnchorProvider(x1, new anchor.Wallet(payer), { commitment: 'confirmed' });
+    const lazy = (idl: string, id: PublicKey) => {
+        let program: any;
+        return () => (program ??= loadProgram(idl, id, provider));
+    };
+    const lightClient = lazy('target/idl/solana_light_client_x1.json', LIGHT_CLIENT_PROGRAM);
+    const xencatMint = lazy('target/idl/xencat_mint_x1.json', XENCAT_MINT_PROGRAM);
+    const dgnMint = lazy('target/idl/dgn_mint_x1.json', DGN_MINT_PROGRAM);
+
+    const validatorSet = pda([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM);
+    const bridgeConfig = pda([Buffer.from('bridge_config')], LIGHT_CLIENT_PROGRAM);
+    const xencatMintState = pda([Buffer.from('mint_state_v2')], XENCAT_MINT_PROGRAM);
+    const dgnMintState = pda([Buffer.from('dgn_mint_state')], DGN_MINT_PROGRAM);
+    const metadataAddress = (mint: PublicKey) => pda([Buffer.from('metadata'), TOKEN_METADATA_PROGRAM.toBuffer(), mint.toBuffer()], TOKEN_METADATA_PROGRAM);
+    const system = { systemProgram: SystemProgram.programId };
+
+    const deploy = (id: string, chain: Chain, key: keyof typeof ARTIFACTS): Step => {
+        const artifact = ARTIFACTS[key];
+        return {
+            id,
+            chain,
+            title: `Deploy ${key} (${artifact.program.toBase58()})`,
+            after: [],
+            deploys: key,
+            done: () => isProgram(chain === 'solana' ? solana : x1, artifact.program),
+            command: [
+                'solana', 'program', 'deploy',
+                '--url', chain === 'solana' ? config.solana.rpc : config.x1.rpc,
+                '--keypair', payerFile,
+                '--program-id', artifact.keypair,
+                artifact.so,
+            ],
+        };
+    };
+
+    // Permissionless light client account with constant seeds
+    const lightClientInit = (id: string, title: string, method: string, account: string, address: PublicKey, after: string[] = ['x1.light-client.deploy']): Step => ({
+        id,
+        chain: 'x1',
+        title,
+        after,
+        done: () => exists(x1, address),
+        send: () => lightClient().methods[method]()
+            .accounts({ [account]: address, payer: payer.publicKey, ...system })
+            .rpc(),
+    });
+
+    const validatorSetMatchesConfig = async () => {
+        if (!(await exists(x1, validatorSet))) return false;
+        const set = await lightClient().account.x1ValidatorSet.fetch(validatorSet);
+        const onChain = new Set((set.validators as PublicKey[]).map(v => v.toBase58()));
+        const configured = config.attestation.validators.map(v => v.pubkey);
+        return set.threshold === config.attestation.threshold
+            && onChain.size === configured.length
+            && configured.every(v => onChain.has(v));
+    };
+
+    const mintAuthorityIs = async (mint: PublicKey, authority: PublicKey) => {
+        const info = await getMint(x1, mint).catch(() => null);
+        return info?.mintAuthority?.equals(authority) === true;
+    };
+
+    return [
+        // ===== Solana =====
+        deploy('solana.burn.deploy', 'solana', 'burn'),
+        {
+            id: 'solana.burn.initialize',
+            chain: 'solana',
+            title: 'Initialize the burn program global state',
+            after: ['solana.burn.deploy'],
+            done: () => exists(solana, pda([Buffer.from('global_state')], BURN_PROGRAM)),
+            send: () => sendAndConfirmTransaction(solana, new Transaction().add(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: pda([Buffer.from('global_state')], BURN_PROGRAM), isSigner: false, isWritable: true },
+                    { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: discriminator('initialize'),
+            })), [payer]),
+        },
+
+        // ===== Light client =====
+        deploy('x1.light-client.deploy', 'x1', 'light_client'),
+        {
+            id: 'x1.validator-set',
+            chain: 'x1',
+            title: `Initialize the validator set (threshold ${config.attestation.threshold})`,
+            after: ['x1.light-client.deploy'],
+            done: () => exists(x1, validatorSet),
+            send: () => lightClient().methods
+                .initializeValidatorSet(config.attestation.threshold)
+                .accounts({ payer: payer.publicKey, validatorSet, ...system })
+                .rpc(),
+        },
+        lightClientInit('x1.bridge-config', 'Initialize the bridge config', 'initializeBridgeConfig', 'bridgeConfig', bridgeConfig),
+        lightClientInit('x1.feature-gate', 'Initialize the feature gate (all enabled)', 'initializeFeatureGate', 'featureGate', featureGateAddress(LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.asset-registry', 'Initialize the asset registry (XENCAT, DGN)', 'initializeAssetRegistry', 'assetRegistry', assetRegistryAddress(LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.dev-mode-config', 'Initialize the dev mode config (production)', 'initializeDevModeConfig', 'devModeConfig', devModeConfigAddress(LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.latency-stats', 'Initialize validator latency stats', 'initializeLatencyStats', 'latencyStats', pda([Buffer.from('validator_latency_stats')], LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.event-schema-registry', 'Initialize the event schema registry', 'initializeEventSchemaRegistry', 'eventSchemaRegistry', eventSchemaRegistryAddress(LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.program-hash-registry', 'Initialize the program hash registry', 'initializeProgramHashRegistry', 'programHashRegistry', programHashRegistryAddress(LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.burn-age-limit', 'Initialize the burn age limit', 'initializeBurnAgeLimit', 'burnAgeLimit', burnAgeLimitAddress(LIGHT_CLIENT_PROGRAM)),
+        lightClientInit('x1.validator-set-history', 'Initialize the validator set history', 'initializeX1ValidatorSetHistory', 'validatorSetHistory', x1ValidatorSetHistoryAddress(LIGHT_CLIENT_PROGRAM)),
+        {
+            id: 'x1.validator-set-config',
+            chain: 'x1',
+            title: 'Validator set matches [attestation] in the config',
+            after: ['x1.validator-set', 'x1.bridge-config', 'x1.validator-set-history'],
+            done: validatorSetMatchesConfig,
+            manual: 'initialize_validator_set installs the genesis validators. Have them approve update_validator_set '
+                + 'with the configured validators and threshold (or validator-set-update.ts propose/execute if the timelock exists).',
+        },
+        {
+            id: 'x1.bridge-info',
+            chain: 'x1',
+            title: 'Initialize bridge info (capability discovery)',
+            after: ['x1.validator-set-config'],
+            done: () => exists(x1, bridgeInfoAddress(LIGHT_CLIENT_PROGRAM)),
+            send: () => lightClient().methods.initializeBridgeInfo()
+                .accounts({ validatorSet, bridgeConfig, bridgeInfo: bridgeInfoAddress(LIGHT_CLIENT_PROGRAM), payer: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.validator-set-checkpoint',
+            chain: 'x1',
+            title: 'Initialize the validator set checkpoint (disaster recovery)',
+            after: ['x1.validator-set-config'],
+            done: () => exists(x1, pda([Buffer.from('validator_set_checkpoint')], LIGHT_CLIENT_PROGRAM)),
+            send: () => lightClient().methods.initializeValidatorSetCheckpoint()
+                .accounts({ validatorSet, checkpoint: pda([Buffer.from('validator_set_checkpoint')], LIGHT_CLIENT_PROGRAM), payer: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.chain-id',
+            chain: 'x1',
+            title: 'Bind the deployment to the X1 genesis hash',
+            after: ['x1.validator-set-config'],
+            optional: true,
+            done: () => exists(x1, x1ChainIdAddress(LIGHT_CLIENT_PROGRAM)),
+            manual: 'npx ts-node scripts/x1-chain-id.ts propose, then sign (each validator) and submit',
+        },
+        {
+            id: 'x1.bridge-domain',
+            chain: 'x1',
+            title: 'Set the deployment domain separator',
+            after: ['x1.validator-set-config'],
+            optional: true,
+            done: () => exists(x1, bridgeDomainAddress(LIGHT_CLIENT_PROGRAM)),
+            manual: 'npx ts-node scripts/bridge-domain.ts propose --domain <DOMAIN>, then sign (each validator) and submit',
+        },
+        {
+            ...lightClientInit(
+                'x1.timelock',
+                'Initialize the validator set update timelock (refuses instant updates from then on)',
+                'initializeValidatorSetTimelock',
+                'validatorSetTimelock',
+                validatorSetTimelockAddress(LIGHT_CLIENT_PROGRAM),
+                ['x1.validator-set-config'],
+            ),
+            optional: true,
+        },
+
+        // ===== XENCAT mint =====
+        deploy('x1.xencat-mint.deploy', 'x1', 'xencat_mint'),
+        {
+            id: 'x1.xencat-mint.token',
+            chain: 'x1',
+            title: `XENCAT token mint exists at the mint program's ["xencat_mint"] PDA`,
+            after: ['x1.xencat-mint.deploy'],
+            done: async () => pda([Buffer.from('xencat_mint')], XENCAT_MINT_PROGRAM).equals(XENCAT_MINT) && exists(x1, XENCAT_MINT),
+            manual: `mints.xencat_x1 must be ${pda([Buffer.from('xencat_mint')], XENCAT_MINT_PROGRAM).toBase58()} and exist with 6 decimals. `
+                + 'No instruction of this tree creates it: deploy with the mint program that originally created it, then upgrade.',
+        },
+        {
+            id: 'x1.xencat-mint.initialize',
+            chain: 'x1',
+            title: 'Initialize the XENCAT mint state',
+            after: ['x1.xencat-mint.token', 'x1.light-client.deploy'],
+            done: () => exists(x1, xencatMintState),
+            send: () => xencatMint().methods.initialize(LIGHT_CLIENT_PROGRAM)
+                .accounts({ mintState: xencatMintState, xencatMint: XENCAT_MINT, authority: payer.publicKey, tokenProgram: TOKEN_PROGRAM_ID, rent: SYSVAR_RENT_PUBKEY, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.xencat-mint.authority',
+            chain: 'x1',
+            title: 'Hand the XENCAT mint authority to the mint state',
+            after: ['x1.xencat-mint.initialize'],
+            done: () => mintAuthorityIs(XENCAT_MINT, xencatMintState),
+            send: () => xencatMint().methods.transferMintAuthority()
+                .accounts({
+                    legacyMintState: pda([Buffer.from('mint_state')], XENCAT_MINT_PROGRAM),
+                    newMintState: xencatMintState,
+                    xencatMint: XENCAT_MINT,
+                    authority: payer.publicKey,
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                })
+                .rpc(),
+        },
+        {
+            id: 'x1.xencat-mint.pause',
+            chain: 'x1',
+            title: 'Initialize the XENCAT mint pause switch',
+            after: ['x1.xencat-mint.deploy'],
+            done: () => exists(x1, mintPauseAddress(XENCAT_MINT_PROGRAM)),
+            send: () => xencatMint().methods.initializeMintPause()
+                .accounts({ mintPause: mintPauseAddress(XENCAT_MINT_PROGRAM), payer: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.xencat-mint.output-config',
+            chain: 'x1',
+            title: 'Initialize the XENCAT output config',
+            after: ['x1.xencat-mint.initialize'],
+            done: () => exists(x1, pda([Buffer.from('output_config')], XENCAT_MINT_PROGRAM)),
+            send: () => xencatMint().methods.initializeOutputConfig()
+                .accounts({ mintState: xencatMintState, outputConfig: pda([Buffer.from('output_config')], XENCAT_MINT_PROGRAM), authority: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.xencat-mint.return-bridge',
+            chain: 'x1',
+            title: 'Initialize the XENCAT return bridge counters',
+            after: ['x1.xencat-mint.initialize'],
+            done: () => exists(x1, returnBridgeAddress(XENCAT_MINT_PROGRAM)),
+            send: () => xencatMint().methods.initializeReturnBridge()
+                .accounts({ mintState: xencatMintState, returnBridge: returnBridgeAddress(XENCAT_MINT_PROGRAM), authority: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.xencat-mint.metadata',
+            chain: 'x1',
+            title: 'Create the XENCAT token metadata',
+            after: ['x1.xencat-mint.authority'],
+            done: () => exists(x1, metadataAddress(XENCAT_MINT)),
+            send: () => xencatMint().methods.createMetadata(METADATA.xencat.name, METADATA.xencat.symbol, METADATA.xencat.uri)
+                .accounts({
+                    mintState: xencatMintState,
+                    xencatMint: XENCAT_MINT,
+                    metadata: metadataAddress(XENCAT_MINT),
+                    authority: payer.publicKey,
+                    payer: payer.publicKey,
+                    tokenMetadataProgram: TOKEN_METADATA_PROGRAM,
+                    rent: SYSVAR_RENT_PUBKEY,
+                    ...system,
+                })
+                .rpc(),
+        },
+
+        // ===== DGN mint =====
+        deploy('x1.dgn-mint.deploy', 'x1', 'dgn_mint'),
+        {
+            id: 'x1.dgn-mint.token',
+            chain: 'x1',
+            title: `Create the DGN token mint (${DGN_MINT.toBase58()}, 6 decimals)`,
+            after: [],
+            done: () => exists(x1, DGN_MINT),
+            send: async () => {
+                if (!dgnMintKeypair || !dgnMintKeypair.publicKey.equals(DGN_MINT)) {
+                    throw new Error(`Pass --dgn-mint-keypair with the keypair of mints.dgn_x1 (${DGN_MINT.toBase58()})`);
+                }
+                return sendAndConfirmTransaction(x1, new Transaction().add(
+                    SystemProgram.createAccount({
+                        fromPubkey: payer.publicKey,
+                        newAccountPubkey: DGN_MINT,
+                        space: MINT_SIZE,
+                        lamports: await getMinimumBalanceForRentExemptMint(x1),
+                        programId: TOKEN_PROGRAM_ID,
+                    }),
+                    createInitializeMint2Instruction(DGN_MINT, 6, payer.publicKey, null),
+                ), [payer, dgnMintKeypair]);
+            },
+        },
+        {
+            id: 'x1.dgn-mint.initialize',
+            chain: 'x1',
+            title: 'Initialize the DGN mint state',
+            after: ['x1.dgn-mint.deploy', 'x1.dgn-mint.token', 'x1.light-client.deploy'],
+            done: () => exists(x1, dgnMintState),
+            send: () => dgnMint().methods.initialize(LIGHT_CLIENT_PROGRAM)
+                .accounts({ mintState: dgnMintState, dgnMint: DGN_MINT, authority: payer.publicKey, tokenProgram: TOKEN_PROGRAM_ID, rent: SYSVAR_RENT_PUBKEY, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.dgn-mint.authority',
+            chain: 'x1',
+            title: 'Hand the DGN mint authority to the mint state',
+            after: ['x1.dgn-mint.initialize'],
+            done: () => mintAuthorityIs(DGN_MINT, dgnMintState),
+            send: () => setAuthority(x1, payer, DGN_MINT, payer.publicKey, AuthorityType.MintTokens, dgnMintState),
+        },
+        {
+            id: 'x1.dgn-mint.pause',
+            chain: 'x1',
+            title: 'Initialize the DGN mint pause switch',
+            after: ['x1.dgn-mint.deploy'],
+            done: () => exists(x1, mintPauseAddress(DGN_MINT_PROGRAM)),
+            send: () => dgnMint().methods.initializeMintPause()
+                .accounts({ mintPause: mintPauseAddress(DGN_MINT_PROGRAM), payer: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.dgn-mint.output-config',
+            chain: 'x1',
+            title: 'Initialize the DGN output config',
+            after: ['x1.dgn-mint.initialize'],
+            done: () => exists(x1, pda([Buffer.from('output_config')], DGN_MINT_PROGRAM)),
+            send: () => dgnMint().methods.initializeOutputConfig()
+                .accounts({ mintState: dgnMintState, outputConfig: pda([Buffer.from('output_config')], DGN_MINT_PROGRAM), authority: payer.publicKey, ...system })
+                .rpc(),
+        },
+        {
+            id: 'x1.dgn-mint.metadata',
+            chain: 'x1',
+            title: 'Create the DGN token metadata',
+            after: ['x1.dgn-mint.authority'],
+            done: () => exists(x1, metadataAddress(DGN_MINT)),
+            send: () => dgnMint().methods.createMetadata(METADATA.dgn.name, METADATA.dgn.symbol, METADATA.dgn.uri)
+                .accounts({
+                    mintState: dgnMintState,
+                    dgnMint: DGN_MINT,
+                    metadata: metadataAddress(DGN_MINT),
+                    authority: payer.publicKey,
+                    payer: payer.publicKey,
+                    tokenMetadataProgram: TOKEN_METADATA_PROGRAM,
+                    rent: SYSVAR_RENT_PUBKEY,
+                    ...system,
+                })
+                .rpc(),
+        },
+    ];
+}
+
+/** Problems that stop `run` before it sends anything */
+async function checkPrerequisites(steps: Step[], pending: Set<string>, solana: Connection, x1: Connection, payer: Keypair, payerFile?: string): Promise<string[]> {
+    const problems: string[] = [];
+
+    // Steps must come after their dependencies
+    const seen = new Set<string>();
+    for (const step of steps) {
+        for (const dependency of step.after) {
+            if (!seen.has(dependency)) problems.push(`${step.id} is listed before its dependency ${dependency}`);
+        }
+        seen.add(step.id);
+    }
+
+    const { threshold, validators } = config.attestation;
+    if (threshold < 1 || validators.length < threshold) {
+        problems.push(`attestation.threshold ${threshold} does not fit ${validators.length} configured validators`);
+    }
+
+    // Deploys need the build and a program keypair matching the config
+    const deployBytes: Record<Chain, number> = { solana: 0, x1: 0 };
+    for (const step of steps.filter(s => s.deploys && pending.has(s.id))) {
+        const key = step.deploys!;
+        const artifact = ARTIFACTS[key];
+        if (!payerFile) {
+            problems.push(`${step.id}: keys.user must be a keypair file (the solana CLI pays the deploy)`);
+        }
+        if (!fs.existsSync(artifact.so) || !fs.existsSync(artifact.keypair)) {
+            problems.push(`${step.id}: ${artifact.so} or its keypair is missing (anchor build)`);
+            continue;
+        }
+        if (!keypairAddress(artifact.keypair).equals(artifact.program)) {
+            problems.push(`${step.id}: ${artifact.keypair} is ${keypairAddress(artifact.keypair).toBase58()}, programs.${key} is ${artifact.program.toBase58()}`);
+        }
+        deployBytes[step.chain] += fs.statSync(artifact.so).size;
+    }
+    for (const idl of ['solana_light_client_x1', 'xencat_mint_x1', 'dgn_mint_x1']) {
+        if (!fs.existsSync(`target/idl/${idl}.json`)) problems.push(`target/idl/${idl}.json is missing (anchor build)`);
+    }
+
+    // Deploys hold about twice the program size in rent (program data + buffer)
+    for (const [chain, connection] of [['solana', solana], ['x1', x1]] as [Chain, Connection][]) {
+        if (deployBytes[chain] === 0) continue;
+        const needed = await connection.getMinimumBalanceForRentExemption(deployBytes[chain] * 2);
+        const balance = await connection.getBalance(payer.publicKey);
+        if (balance < needed) {
+            problems.push(`${chain}: payer ${payer.publicKey.toBase58()} has ${balance / 1e9}, deploys need about ${needed / 1e9}`);
+        }
+    }
+    return problems;
+}
+
+function loadState(file: string): BootstrapState {
+    if (!fs.existsSync(file)) {
+        return { programs: config.programs, steps: {} };
+    }
+    const state: BootstrapState = JSON.parse(fs.readFileSync(file, 'utf-8'));
+    if (JSON.stringify(state.programs) !== JSON.stringify(config.programs)) {
+        throw new Error(`${file} belongs to another environment (programs differ from the config); use another --state`);
+    }
+    return state;
+}
+
+async function main() {
+    const command = process.argv[2];
+    if (command !== 'plan' && command !== 'run') {
+        console.error('Usage: bootstrap.ts <plan|run> [options]');
+        process.exit(1);
+    }
+
+    const solana = new Connection(config.solana.rpc, 'confirmed');
+    const x1 = new Connection(config.x1.rpc, 'confirmed');
+    const payer = loadKeypair(config, 'user');
+    const payerFile = config.keys.user ? expandHome(config.keys.user) : undefined;
+    const dgnMintKeypair = process.argv.includes('--dgn-mint-keypair')
+        ? parseKeypair(fs.readFileSync(expandHome(arg('dgn-mint-keypair')), 'utf-8'))
+        : undefined;
+    const skip = new Set(process.argv.includes('--skip') ? arg('skip').split(',') : []);
+    const stateFile = arg('state', 'bootstrap-state.json');
+    const state = loadState(stateFile);
+
+    const steps = buildSteps(solana, x1, payer, payerFile ?? '<keys.user>', dgnMintKeypair);
+    for (const id of skip) {
+        const step = steps.find(s => s.id === id);
+        if (!step?.optional) throw new Error(`--skip ${id}: not an optional step`);
+    }
+
+    console.log(`🧭 Bootstrap: Solana ${config.solana.rpc}, X1 ${config.x1.rpc}`);
+    console.log(`   Payer: ${payer.publicKey.toBase58()}`);
+
+    const done = new Set<string>();
+    for (const step of steps) {
+        if (skip.has(step.id) || await step.done()) done.add(step.id);
+    }
+    const pending = new Set(steps.filter(s => !done.has(s.id)).map(s => s.id));
+
+    const problems = await checkPrerequisites(steps, pending, solana, x1, payer, payerFile);
+
+    if (command === 'plan') {
+        steps.forEach((step, i) => {
+            const status = skip.has(step.id) ? '⏭️ ' : done.has(step.id) ? '✅' : step.manual ? '✋' : '⏳';
+            console.log(`${String(i + 1).padStart(3)}. ${status} [${step.chain}] ${step.id}: ${step.title}${step.optional ? ' (optional)' : ''}`);
+            if (!done.has(step.id) && step.command) console.log(`        $ ${step.command.join(' ')}`);
+            if (!done.has(step.id) && step.manual) console.log(`        ${step.manual}`);
+        });
+        problems.forEach(problem => console.log(`⛔ ${problem}`));
+        if (process.argv.includes('--json')) {
+            const out = arg('json');
+            fs.writeFileSync(out, JSON.stringify(steps.map(step => ({
+                id: step.id,
+                chain: step.chain,
+                title: step.title,
+                after: step.after,
+                optional: !!step.optional,
+                status: skip.has(step.id) ? 'skipped' : done.has(step.id) ? 'done' : step.manual ? 'manual' : 'pending',
+                command: step.command?.join(' '),
+                manual: step.manual,
+            })), null, 2));
+            console.log(`✅ Plan written to ${out}`);
+        }
+        console.log(`   ${done.size}/${steps.length} done`);
+        return;
+    }
+
+    if (problems.length > 0) {
+        problems.forEach(problem => console.log(`⛔ ${problem}`));
+        process.exit(1);
+    }
+
+    for (const step of steps) {
+        if (done.has(step.id)) continue;
+        const blocker = step.after.find(dependency => !done.has(dependency));
+        if (blocker) {
+            console.log(`⛔ ${step.id} waits for ${blocker}`);
+            process.exit(2);
+        }
+        if (step.manual) {
+            console.log(`✋ ${step.id}: ${step.title}`);
+            console.log(`   ${step.manual}`);
+            console.log('   Run bootstrap again once this is done.');
+            process.exit(2);
+        }
+
+        console.log(`▶️  ${step.id}: ${step.title}`);
+        let signature: string | undefined;
+        if (step.command) {
+            execFileSync(step.command[0], step.command.slice(1), { stdio: 'inherit' });
+        } else {
+            signature = await step.send!();
+        }
+        if (!(await step.done())) {
+            throw new Error(`${step.id} did not take effect${signature ? ` (${signature})` : ''}`);
+        }
+        done.add(step.id);
+        state.steps[step.id] = { signature, completedAt: new Date().toISOString() };
+        fs.writeFileSync(stateFile, JSON.stringify(state, null, 2));
+        console.log(`   ✅ ${signature ?? 'done'}`);
+    }
+
+    console.log('🎉 Environment bootstrapped. Next: validators create their fee vaults and start their services; run config:doctor.');
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,165 @@
+/**
+ * Bridge Domain (per-deployment attestation domain separator)
+ *
+ * Every attestation message starts with the deployment's domain, so the
+ * same build can run on devnet and mainnet while signatures from one are
+ * useless on the other. It is set once with validator approval; until
+ * then the light client uses the compiled-in DOMAIN_SEPARATOR.
+ *
+ * Usage:
+ *   npx ts-node scripts/bridge-domain.ts status
+ *   npx ts-node scripts/bridge-domain.ts propose --domain XENCAT_X1_BRIDGE_DEVNET_V1 [--out domain.json]
+ *   npx ts-node scripts/bridge-domain.ts sign    --file domain.json   (validator key)
+ *   npx ts-node scripts/bridge-domain.ts submit  --file domain.json
+ *
+ * `propose` binds the validator set version and BridgeConfig nonce, so
+ * collect signatures and submit promptly. Restart the validator services
+ * afterwards: they read the domain at startup.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { bridgeDomainAddress, fetchBridgeDomain } from '../sdk/attestation-client/src/bridge-domain';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const BRIDGE_DOMAIN = bridgeDomainAddress(LIGHT_CLIENT_PROGRAM);
+
+interface DomainProposal {
+    domain: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_bridge_domain_message on-chain */
+function domainMessage(p: Omit<DomainProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('INIT_BRIDGE_DOMAIN'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        Buffer.from(p.domain),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+/** Must match BridgeDomain::is_valid_domain on-chain */
+function checkDomain(domain: string) {
+    if (!/^[A-Z0-9_]{1,32}$/.test(domain)) {
+        throw new Error(`Invalid domain ${JSON.stringify(domain)}: 1-32 characters of A-Z, 0-9 and '_'`);
+    }
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const initialized = (await connection.getAccountInfo(BRIDGE_DOMAIN)) !== null;
+            console.log(`🌐 Bridge domain ${BRIDGE_DOMAIN.toBase58()}`);
+            console.log(`   ${await fetchBridgeDomain(connection, LIGHT_CLIENT_PROGRAM)}${initialized ? '' : ' (default, not initialized)'}`);
+            break;
+        }
+
+        case 'propose': {
+            const domain = arg('domain');
+            checkDomain(domain);
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                domain,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: DomainProposal = { ...base, message: domainMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'domain.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Domain proposal written to ${out}`);
+            console.log(`   Domain:  ${domain}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: DomainProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            checkDomain(proposal.domain);
+            // Recompute rather than trust the file's message field
+            const message = domainMessage(proposal);
+            console.log(`   Domain: ${proposal.domain}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed domain proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: DomainProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = domainMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting bridge domain with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .initializeBridgeDomain({ domain: proposal.domain, approverSignatures })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    bridgeDomain: BRIDGE_DOMAIN,
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Bridge domain set to ${proposal.domain}: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: bridge-domain.ts <status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -10,19 +10,50 @@
  * Environment Variables:
  *   BURN_NONCE - Required: The burn nonce from Solana
  *   ASSET_ID - Required: 1 for XENCAT, 2 for DGN
- *   USER_PRIVATE_KEY - Required: User's private key
- *   X1_RPC - Optional: X1 RPC endpoint (default: https://rpc.mainnet.x1.xyz)
+ *   COSIGNER_KEYPAIR - Optional: co-signer keypair file, for mints above the
+ *                      mint program's co-sign policy threshold
+ *   VERIFY_ONLY - Optional: stop once the burn is verified (mint several
+ *                 verified XENCAT burns at once with scripts/mint-batch.ts)
+ *   ATTESTATION_CHALLENGE - Optional: post an on-chain challenge first, so
+ *                 only attestations signed after it verify the burn
+ *
+ * RPC endpoints, program ids, validators, threshold, relayer coordination
+ * and the user keypair come from the shared bridge config (xencat.toml + env overrides, see
+ * xencat.example.toml). Legacy USER_PRIVATE_KEY / X1_RPC still work.
  */
 
 import 'dotenv/config';
 import { Connection, PublicKey, Keypair } from '@solana/web3.js';
 import * as anchor from '@coral-xyz/anchor';
-import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID, getAccount, createAssociatedTokenAccountInstruction } from '@solana/spl-token';
-import bs58 from 'bs58';
+import { getAssociatedTokenAddress, getAccount, createAssociatedTokenAccountInstruction } from '@solana/spl-token';
 import fs from 'fs';
+import { expandHome, loadConfig, loadKeypair, parseKeypair } from '../sdk/bridge-config/src';
+import { CoordinationConfig, StandbyConfig, submissionDelayMs, waitForTakeover, waitForTurn } from '../sdk/attestation-client/src/relayer-coordination';
+import { assertTransactionFits } from '../sdk/attestation-client/src/tx-size';
+import { feePoolAddress, validatorFeeAccounts } from '../sdk/attestation-client/src/fee-vault';
+import { feeConfigAddress } from '../sdk/attestation-client/src/fee-config';
+import { awaitBundle, connectWorkQueue } from '../sdk/attestation-client/src/work-queue';
+import { FEATURE_GATES, disabledGateNames, featureGateAddress, fetchBridgePaused, fetchDisabledGates } from '../sdk/attestation-client/src/feature-gate';
+import { bridgeDomainAddress, fetchBridgeDomain } from '../sdk/attestation-client/src/bridge-domain';
+import { x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+import { assetRegistryAddress, fetchRegisteredAssets } from '../sdk/attestation-client/src/asset-registry';
+import { burnAgeLimitAddress } from '../sdk/attestation-client/src/burn-age-limit';
+import { betaAllowlistAddress } from '../sdk/attestation-client/src/beta-allowlist';
+import { challengeRequestAddress, fetchChallengeRequest } from '../sdk/attestation-client/src/attestation-challenge';
+import { devModeConfigAddress } from '../sdk/attestation-client/src/dev-mode';
+import { coSignerAccount, cosignPolicyAddress, fetchCoSignPolicy, requiredCoSigner } from '../sdk/attestation-client/src/cosign-policy';
+import { fetchRateLimit, rateLimitAddress, rateLimitHeadroom } from '../sdk/attestation-client/src/rate-limit';
+import { fetchMintPause, mintPauseAddress } from '../sdk/attestation-client/src/pause';
+import { degradedBurnAddress, degradedModeAccepts, degradedModeAddress, fetchDegradedBurn, fetchDegradedMode } from '../sdk/attestation-client/src/degraded-mode';
+import { BridgeErrorKind, loadBridgeErrorTables, parseBridgeError } from '../sdk/attestation-client/src/program-errors';
+import { AttestationCheckCode, describeAttestationCheck, simulatePrevalidateAttestation } from '../sdk/attestation-client/src/prevalidate';
+import { fetchAttestationOrdering, orderAttestations } from '../sdk/attestation-client/src/attestation-ordering';
+import { relayerClaimAddress } from '../sdk/attestation-client/src/relayer-claim';
 
 // Configuration
-const X1_RPC = process.env.X1_RPC || 'https://rpc.mainnet.x1.xyz';
+const { config } = loadConfig();
+const X1_RPC = config.x1.rpc;
+const THRESHOLD = config.attestation.threshold;
 const BURN_NONCE = parseInt(process.env.BURN_NONCE || '0');
 const ASSET_ID = parseInt(process.env.ASSET_ID || '0');
 
@@ -39,14 +70,14 @@ if (!BURN_NONCE || !ASSET_ID) {
 const ASSETS = {
     1: {
         name: 'XENCAT',
-        mintProgram: new PublicKey('8kmoPKtLAjjzQRN5i4emUsmWeu3LM5yPWFrsqZVyekhk'),
-        mint: new PublicKey('DQ6sApYPMJ8LwpvyUjthL7amykNBJ3fx5jZi2koN7vHb'),
+        mintProgram: new PublicKey(config.programs.xencat_mint),
+        mint: new PublicKey(config.mints.xencat_x1),
         mintStateSeed: 'mint_state_v2',
     },
     2: {
         name: 'DGN',
-        mintProgram: new PublicKey('4YPipW8txxY3N7gHdj4NLhu8YxybHgarx5dJQCdCnQHs'),
-        mint: new PublicKey('84PxDRsNyiRJU4gfFiD7RqvZzqh5FdqXjDdtFV3N3oxc'),
+        mintProgram: new PublicKey(config.programs.dgn_mint),
+        mint: new PublicKey(config.mints.dgn_x1),
         mintStateSeed: 'dgn_mint_state',
     },
 } as const;
@@ -59,26 +90,35 @@ if (!asset) {
 }
 
 // Program IDs
-const LIGHT_CLIENT_PROGRAM = new PublicKey('BXBZtvFfCtCapQgqFTxGQ9hgJTQZUoHFzBXD2V3ys5C5');
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+// Relayer coordination (leader election per transfer), undefined = submit immediately
+const RELAYER_COORDINATION: CoordinationConfig | undefined = config.relayer.coordination === 'leader'
+    ? { id: config.relayer.id!, peers: config.relayer.peers, slotSeconds: config.relayer.slot_seconds }
+    : undefined;
+
+// Shared attestation queue: coordinators collect, this script only submits
+const ATTESTATION_QUEUE = config.relayer.queue;
+/** How long to wait for the coordinators to finish a bundle */
+const BUNDLE_TIMEOUT_MS = 10 * 60 * 1000;
 
 // Validators
-const VALIDATORS = [
-    { url: 'http://149.50.116.159:8080', pubkey: new PublicKey('9oa7NAscCZ1kCQFZJng9gfwvDzrEvyWgx4F244PHmHPH') },
-    { url: 'http://193.34.212.186:8080', pubkey: new PublicKey('8byEUEZ2sMfP6RPX9VD8JCvCQK3F5FG2LytcR9TkVWag') },
-    { url: 'http://74.50.76.62:10001', pubkey: new PublicKey('5NfpgFCwrYzcgJkda9bRJvccycLUo3dvVQsVAK2W43Um') },
-    { url: 'http://149.50.116.21:8080', pubkey: new PublicKey('GdbXi56fCSQ1joCvGjqm7JKvqvwgtKh6xeusUqZbB3rH') },
-    { url: 'http://64.20.49.142:8080', pubkey: new PublicKey('FmuuFgRh8NP8UD7QHg86f7vu7qpsmr1wE7hB59oojDpj') },
-];
-
-async function collectAttestations(burnNonce: number, user: PublicKey, expectedAmount: number) {
+const VALIDATORS = config.attestation.validators.map(v => ({
+    url: v.url,
+    pubkey: new PublicKey(v.pubkey),
+}));
+
+/** `minimum` drops below THRESHOLD only when degraded mode accepts the burn */
+async function collectAttestations(burnNonce: number, user: PublicKey, expectedAmount: number, minimum = THRESHOLD) {
     console.log('📥 Collecting attestations from validators...');
-    const attestations = [];
+    const attestations: any[] = [];
 
     for (const validator of VALIDATORS) {
         try {
             const response = await fetch(`${validator.url}/attest-burn`, {
                 method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
+                // X-Validator-Key selects the key on services hosting several validators
+                headers: { 'Content-Type': 'application/json', 'X-Validator-Key': validator.pubkey.toBase58() },
                 body: JSON.stringify({
                     burn_nonce: burnNonce,
                     user: user.toBase58(),
@@ -95,13 +135,27 @@ async function collectAttestations(burnNonce: number, user: PublicKey, expectedA
             const attestation: any = await response.json();
             console.log(`  ✅ ${validator.url} - ${attestation.asset_name} (asset_id=${attestation.asset_id})`);
 
+            // All signatures must cover the same burn slot/time and confirmed slot
+            if (attestations.length > 0 && (
+                attestations[0].solanaBurnSlot !== attestation.solana_burn_slot ||
+                attestations[0].solanaBurnTimestamp !== attestation.solana_burn_timestamp ||
+                attestations[0].solanaConfirmedSlot !== attestation.solana_confirmed_slot
+            )) {
+                console.log(`  ⚠️  ${validator.url}: burn slot/time disagrees with first attestation, skipping`);
+                continue;
+            }
+
             attestations.push({
                 validatorPubkey: new PublicKey(attestation.validator_pubkey),
                 signature: attestation.signature,
                 timestamp: new anchor.BN(attestation.timestamp),
+                solanaBurnSlot: attestation.solana_burn_slot,
+                solanaBurnTimestamp: attestation.solana_burn_timestamp,
+                confirmationDepth: attestation.confirmation_depth,
+                solanaConfirmedSlot: attestation.solana_confirmed_slot,
             });
 
-            if (attestations.length >= 3) {
+            if (attestations.length >= THRESHOLD) {
                 console.log(`\n✅ Collected ${attestations.length} attestations (threshold reached)`);
                 break;
             }
@@ -110,13 +164,45 @@ async function collectAttestations(burnNonce: number, user: PublicKey, expectedA
         }
     }
 
-    if (attestations.length < 3) {
-        throw new Error(`Insufficient attestations: got ${attestations.length}, need 3`);
+    if (attestations.length < minimum) {
+        throw new Error(`Insufficient attestations: got ${attestations.length}, need ${minimum}`);
     }
 
     return attestations;
 }
 
+async function attestationsFromQueue(burnNonce: number, user: PublicKey, expectedAmount: number) {
+    const { queue, close } = await connectWorkQueue(
+        ATTESTATION_QUEUE.backend as 'redis' | 'postgres',
+        ATTESTATION_QUEUE.url!,
+        ATTESTATION_QUEUE.max_attempts
+    );
+    try {
+        console.log(`📥 Waiting for the coordinators to collect attestations (${ATTESTATION_QUEUE.backend} queue)...`);
+        const bundle = await awaitBundle(queue, {
+            asset_id: ASSET_ID,
+            burn_nonce: burnNonce,
+            user: user.toBase58(),
+            expected_amount: expectedAmount,
+            validator_set_version: 1,
+        }, BUNDLE_TIMEOUT_MS);
+        console.log(`✅ Bundle ready: ${bundle.length} attestations`);
+
+        // Threshold is enough; extra attestations only grow the transaction
+        return bundle.slice(0, THRESHOLD).map(attestation => ({
+            validatorPubkey: new PublicKey(attestation.validator_pubkey),
+            signature: attestation.signature,
+            timestamp: new anchor.BN(attestation.timestamp),
+            solanaBurnSlot: attestation.solana_burn_slot,
+            solanaBurnTimestamp: attestation.solana_burn_timestamp,
+            confirmationDepth: attestation.confirmation_depth,
+            solanaConfirmedSlot: attestation.solana_confirmed_slot,
+        }));
+    } finally {
+        await close();
+    }
+}
+
 async function main() {
     console.log('🌉 Universal Bridge Mint Script');
     console.log('='.repeat(60));
@@ -124,19 +210,8 @@ async function main() {
     console.log(`Burn Nonce: ${BURN_NONCE}`);
     console.log(`X1 RPC: ${X1_RPC}\n`);
 
-    // Load user keypair
-    const privateKeyEnv = process.env.USER_PRIVATE_KEY;
-    if (!privateKeyEnv) {
-        throw new Error('USER_PRIVATE_KEY environment variable required');
-    }
-
-    let user: Keypair;
-    try {
-        const privateKeyArray = JSON.parse(privateKeyEnv);
-        user = Keypair.fromSecretKey(Uint8Array.from(privateKeyArray));
-    } catch {
-        user = Keypair.fromSecretKey(bs58.decode(privateKeyEnv));
-    }
+    // Load user keypair (keys.user path, or legacy USER_PRIVATE_KEY)
+    const user: Keypair = loadKeypair(config, 'user');
 
     console.log(`👤 User: ${user.publicKey.toBase58()}\n`);
 
@@ -175,6 +250,86 @@ async function main() {
         LIGHT_CLIENT_PROGRAM
     );
 
+    const [latencyStatsPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('validator_latency_stats')],
+        LIGHT_CLIENT_PROGRAM
+    );
+
+    const featureGatePda = featureGateAddress(LIGHT_CLIENT_PROGRAM);
+    const bridgeDomainPda = bridgeDomainAddress(LIGHT_CLIENT_PROGRAM);
+    const x1ChainIdPda = x1ChainIdAddress(LIGHT_CLIENT_PROGRAM);
+    const assetRegistryPda = assetRegistryAddress(LIGHT_CLIENT_PROGRAM);
+    const burnAgeLimitPda = burnAgeLimitAddress(LIGHT_CLIENT_PROGRAM);
+    const challengeRequestPda = challengeRequestAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE);
+    console.log(`   Bridge domain: ${await fetchBridgeDomain(connection, LIGHT_CLIENT_PROGRAM)}`);
+
+    // Governance kill switches: stop early rather than pay fees for a disabled path
+    if (await fetchBridgePaused(connection, LIGHT_CLIENT_PROGRAM)) {
+        console.log('⛔ The bridge is paused by validator governance; retry once it is unpaused');
+        process.exit(1);
+    }
+    if ((await fetchMintPause(connection, asset.mintProgram))?.paused) {
+        console.log(`⛔ ${asset.name} mints are paused by validator governance; retry once unpaused`);
+        process.exit(1);
+    }
+    if ((await fetchRegisteredAssets(connection, LIGHT_CLIENT_PROGRAM))?.find(a => a.assetId === ASSET_ID)?.frozen) {
+        console.log(`⛔ ${asset.name} is frozen by validator governance; retry once unfrozen`);
+        process.exit(1);
+    }
+    const disabledGates = await fetchDisabledGates(connection, LIGHT_CLIENT_PROGRAM);
+    if (disabledGates & (FEATURE_GATES.ATTEST_V3 | FEATURE_GATES.MINT_V3)) {
+        console.log(`⛔ Disabled by validator governance: ${disabledGateNames(disabledGates).join(', ')}`);
+        process.exit(1);
+    }
+
+    const [processedBurnPda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('processed_burn_v3'),
+            Buffer.from([ASSET_ID]),
+            Buffer.from(new BigUint64Array([BigInt(BURN_NONCE)]).buffer),
+            user.publicKey.toBuffer(),
+        ],
+        asset.mintProgram
+    );
+
+    // Multi-relayer mode: the transfer's leader goes first, backups only
+    // step in if it has not been completed by the time their turn comes
+    if (RELAYER_COORDINATION) {
+        const delayMs = submissionDelayMs(RELAYER_COORDINATION, ASSET_ID, BURN_NONCE);
+        if (delayMs > 0) {
+            console.log(`⏳ Backup relayer for this transfer, waiting ${delayMs / 1000}s for the leader...`);
+        }
+        const myTurn = await waitForTurn(
+            RELAYER_COORDINATION,
+            ASSET_ID,
+            BURN_NONCE,
+            async () => (await connection.getAccountInfo(processedBurnPda)) !== null
+        );
+        if (!myTurn) {
+            console.log('✅ Transfer already completed by another relayer');
+            return;
+        }
+    }
+
+    // Hot standby: only submit if the primary (same relayer key) has stalled
+    if (config.relayer.coordination === 'standby') {
+        const standby: StandbyConfig = {
+            primary: config.relayer.primary_key ? new PublicKey(config.relayer.primary_key) : user.publicKey,
+            takeoverSeconds: config.relayer.takeover_seconds,
+        };
+        console.log(`⏳ Standby relayer, taking over if ${standby.primary.toBase58().slice(0, 8)}... lands nothing for ${standby.takeoverSeconds}s...`);
+        const takeOver = await waitForTakeover(
+            connection,
+            standby,
+            async () => (await connection.getAccountInfo(processedBurnPda)) !== null
+        );
+        if (!takeOver) {
+            console.log('✅ Transfer completed by the primary relayer');
+            return;
+        }
+        console.log('⚠️  Primary relayer stalled: taking over this transfer');
+    }
+
     // Step 1: Check if burn is already verified, if not submit attestations
     console.log('🔍 Checking burn verification status...');
     let verifiedBurn: any;
@@ -209,7 +364,7 @@ async function main() {
                     // First try without expected_amount to get error with actual amount
                     const response = await fetch(`${validator.url}/attest-burn`, {
                         method: 'POST',
-                        headers: { 'Content-Type': 'application/json' },
+                        headers: { 'Content-Type': 'application/json', 'X-Validator-Key': validator.pubkey.toBase58() },
                         body: JSON.stringify({
                             burn_nonce: BURN_NONCE,
                             user: user.publicKey.toBase58(),
@@ -244,8 +399,33 @@ async function main() {
             }
         }
 
+        // Partial outage fallback: a smaller quorum is enough for burns degraded mode accepts
+        const degradedMode = await fetchDegradedMode(connection, LIGHT_CLIENT_PROGRAM, ASSET_ID);
+        const minimum = degradedModeAccepts(degradedMode, detectedAmount, Math.floor(Date.now() / 1000))
+            ? degradedMode!.quorum
+            : THRESHOLD;
+
+        // Validators must then sign the challenge, so nothing pre-signed verifies
+        if (process.env.ATTESTATION_CHALLENGE && !(await fetchChallengeRequest(connection, LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE))) {
+            const tx = await lightClientProgram.methods
+                .requestAttestationChallenge(ASSET_ID, new anchor.BN(BURN_NONCE))
+                .accounts({
+                    user: user.publicKey,
+                    challengeRequest: challengeRequestPda,
+                    slotHashes: anchor.web3.SYSVAR_SLOT_HASHES_PUBKEY,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`🎲 Attestation challenge posted: ${tx}`);
+        }
+
         // Now collect attestations with the correct amount
-        attestations = await collectAttestations(BURN_NONCE, user.publicKey, detectedAmount);
+        attestations = ATTESTATION_QUEUE.backend === 'none'
+            ? await collectAttestations(BURN_NONCE, user.publicKey, detectedAmount, minimum)
+            : await attestationsFromQueue(BURN_NONCE, user.publicKey, detectedAmount);
+        // Ascending validator set order, in case the ordering rule is on
+        const { validators } = await fetchAttestationOrdering(connection, LIGHT_CLIENT_PROGRAM);
+        attestations = orderAttestations(attestations, validators, a => a.validatorPubkey);
 
         console.log('\n📤 Submitting attestations to light client...');
 
@@ -255,31 +435,174 @@ async function main() {
             user: user.publicKey,
             amount: new anchor.BN(detectedAmount),
             validatorSetVersion: new anchor.BN(1),
-            attestations: attestations,
+            solanaBurnSlot: new anchor.BN(attestations[0].solanaBurnSlot),
+            solanaBurnTimestamp: new anchor.BN(attestations[0].solanaBurnTimestamp),
+            confirmationDepth: attestations[0].confirmationDepth,
+            solanaConfirmedSlot: new anchor.BN(attestations[0].solanaConfirmedSlot),
+            attestations: attestations.map(({ validatorPubkey, signature, timestamp }) => ({ validatorPubkey, signature, timestamp })),
         };
 
-        const tx = await lightClientProgram.methods
+        if (attestations.length < THRESHOLD) {
+            console.log(`🩹 Only ${attestations.length}/${THRESHOLD} validators reachable, using degraded mode (quorum ${minimum})`);
+            const tx = await lightClientProgram.methods
+                .submitBurnAttestationDegraded(ASSET_ID, new anchor.BN(BURN_NONCE), attestationData)
+                .accounts({
+                    user: user.publicKey,
+                    validatorSet: validatorSetPda,
+                    degradedMode: degradedModeAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID),
+                    verifiedBurn: verifiedBurnPda,
+                    degradedBurn: degradedBurnAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE),
+                    latencyStats: latencyStatsPda,
+                    featureGate: featureGatePda,
+                    bridgeDomain: bridgeDomainPda,
+                    x1ChainId: x1ChainIdPda,
+                    assetRegistry: assetRegistryPda,
+                    burnAgeLimit: burnAgeLimitPda,
+                    betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID),
+                    challengeRequest: challengeRequestPda,
+                    devModeConfig: devModeConfigAddress(LIGHT_CLIENT_PROGRAM),
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .signers([user])
+                .rpc();
+            console.log(`✅ Attestations submitted (degraded mode): ${tx}`);
+            const hold = await fetchDegradedBurn(connection, LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE);
+            console.log(`⏳ Held until ${new Date(hold!.releaseAt * 1000).toISOString()}; run this script again then to mint.`);
+            process.exit(0);
+        }
+
+        // Free simulated pre-check: reject a doomed bundle before paying rent for it
+        const check = await simulatePrevalidateAttestation(connection, LIGHT_CLIENT_PROGRAM, user.publicKey, {
+            assetId: ASSET_ID,
+            burnNonce: BURN_NONCE,
+            user: user.publicKey,
+            amount: detectedAmount,
+            validatorSetVersion: 1,
+            solanaBurnSlot: attestations[0].solanaBurnSlot,
+            solanaBurnTimestamp: attestations[0].solanaBurnTimestamp,
+            confirmationDepth: attestations[0].confirmationDepth,
+            solanaConfirmedSlot: attestations[0].solanaConfirmedSlot,
+            attestations: attestations.map(({ validatorPubkey, signature, timestamp }) => ({ validatorPubkey, signature, timestamp: timestamp.toNumber() })),
+        });
+        if (check.code !== AttestationCheckCode.Valid) {
+            throw new Error(`Attestation bundle rejected by pre-check:\n${describeAttestationCheck(check)}`);
+        }
+
+        const submitTx = await lightClientProgram.methods
             .submitBurnAttestationV3(ASSET_ID, new anchor.BN(BURN_NONCE), attestationData)
             .accounts({
                 user: user.publicKey,
                 validatorSet: validatorSetPda,
                 verifiedBurn: verifiedBurnPda,
+                latencyStats: latencyStatsPda,
+                featureGate: featureGatePda,
+                bridgeDomain: bridgeDomainPda,
+                x1ChainId: x1ChainIdPda,
+                assetRegistry: assetRegistryPda,
+                burnAgeLimit: burnAgeLimitPda,
+                betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID),
+                challengeRequest: challengeRequestPda,
+                devModeConfig: devModeConfigAddress(LIGHT_CLIENT_PROGRAM),
                 systemProgram: anchor.web3.SystemProgram.programId,
             })
-            .signers([user])
-            .rpc();
+            .transaction();
+        submitTx.feePayer = user.publicKey;
+
+        // Let the rent sponsor pool pay the VerifiedBurnV3 rent of small transfers
+        const [rentSponsorPoolPda] = PublicKey.findProgramAddressSync([Buffer.from('rent_sponsor_pool')], LIGHT_CLIENT_PROGRAM);
+        const rentSponsorPool: any = await (lightClientProgram.account as any).rentSponsorPool.fetchNullable(rentSponsorPoolPda);
+        if (rentSponsorPool && !rentSponsorPool.maxBurnAmount.isZero() && rentSponsorPool.maxBurnAmount.gten(detectedAmount)) {
+            const [sponsoredRentPda] = PublicKey.findProgramAddressSync(
+                [
+                    Buffer.from('sponsored_rent'),
+                    Buffer.from([ASSET_ID]),
+                    user.publicKey.toBuffer(),
+                    Buffer.from(new BigUint64Array([BigInt(BURN_NONCE)]).buffer),
+                ],
+                LIGHT_CLIENT_PROGRAM
+            );
+            // Must sit immediately before the submit instruction
+            submitTx.instructions.unshift(await lightClientProgram.methods
+                .sponsorBurnRent(ASSET_ID, new anchor.BN(BURN_NONCE))
+                .accounts({
+                    rentSponsorPool: rentSponsorPoolPda,
+                    verifiedBurn: verifiedBurnPda,
+                    sponsoredRent: sponsoredRentPda,
+                    user: user.publicKey,
+                    instructions: anchor.web3.SYSVAR_INSTRUCTIONS_PUBKEY,
+                    featureGate: featureGatePda,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .instruction());
+            console.log('   🎁 Verified burn rent sponsored by the pool');
+        }
+
+        // Fail before broadcasting if the attestations do not fit in one packet
+        const size = assertTransactionFits(submitTx, {
+            threshold: THRESHOLD,
+            attestationCount: attestations.length,
+        });
+        console.log(`   Transaction size: ${size.size}/${size.limit} bytes`);
+
+        const tx = await provider.sendAndConfirm(submitTx, [user]);
 
         console.log(`✅ Attestations submitted: ${tx}\n`);
 
         verifiedBurn = await lightClientProgram.account.verifiedBurnV3.fetch(verifiedBurnPda);
     }
 
-    // Step 2: Create token account if needed
-    const userTokenAccount = await getAssociatedTokenAddress(asset.mint, user.publicKey);
+    // A guardian freeze holds the burn at processed = true; minting would fail
+    const [burnFreezePda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('burn_freeze'),
+            Buffer.from([ASSET_ID]),
+            user.publicKey.toBuffer(),
+            Buffer.from(new BigUint64Array([BigInt(BURN_NONCE)]).buffer),
+        ],
+        LIGHT_CLIENT_PROGRAM
+    );
+    if (await connection.getAccountInfo(burnFreezePda)) {
+        const freeze: any = await (lightClientProgram.account as any).burnFreeze.fetch(burnFreezePda);
+        console.log(`🧊 Burn ${BURN_NONCE} is frozen by the validator guardians since slot ${freeze.frozenSlot.toString()}`);
+        console.log(`   Reason: ${Buffer.from(freeze.reason).toString('hex')}`);
+        console.log('   It can be minted once validator governance lifts the freeze (unfreeze_burn).');
+        process.exit(1);
+    }
+
+    // A degraded-mode burn is held at processed = true until its delay passes
+    const degradedHold = await fetchDegradedBurn(connection, LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE);
+    if (degradedHold) {
+        const wait = degradedHold.releaseAt - Math.floor(Date.now() / 1000);
+        if (wait > 0) {
+            console.log(`⏳ Burn ${BURN_NONCE} was verified in degraded mode and is held for another ${wait}s`);
+            console.log('   Run this script again after that to release and mint it.');
+            process.exit(1);
+        }
+        const tx = await lightClientProgram.methods
+            .releaseDegradedBurn()
+            .accounts({
+                degradedBurn: degradedBurnAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE),
+                verifiedBurn: verifiedBurnPda,
+                burnFreeze: burnFreezePda,
+                processedBurn: processedBurnPda,
+                user: user.publicKey,
+            })
+            .rpc();
+        console.log(`🔓 Degraded-mode hold released: ${tx}\n`);
+    }
+
+    if (process.env.VERIFY_ONLY) {
+        console.log(`✅ Burn ${BURN_NONCE} verified; not minting (VERIFY_ONLY)`);
+        return;
+    }
+
+    // Step 2: Create token account if needed (under the mint's token program: SPL Token or Token-2022)
+    const tokenProgram = (await connection.getAccountInfo(asset.mint))!.owner;
+    const userTokenAccount = await getAssociatedTokenAddress(asset.mint, user.publicKey, false, tokenProgram);
     console.log(`🪙 Token Account: ${userTokenAccount.toBase58()}`);
 
     try {
-        await getAccount(connection, userTokenAccount);
+        await getAccount(connection, userTokenAccount, undefined, tokenProgram);
         console.log('✅ Token account exists\n');
     } catch {
         console.log('📤 Creating token account...');
@@ -287,7 +610,8 @@ async function main() {
             user.publicKey,
             userTokenAccount,
             user.publicKey,
-            asset.mint
+            asset.mint,
+            tokenProgram
         );
 
         const tx = new anchor.web3.Transaction().add(createAtaIx);
@@ -298,7 +622,7 @@ async function main() {
     // Get balance before minting
     let balanceBefore = 0;
     try {
-        const account = await getAccount(connection, userTokenAccount);
+        const account = await getAccount(connection, userTokenAccount, undefined, tokenProgram);
         balanceBefore = Number(account.amount);
     } catch {
         balanceBefore = 0;
@@ -312,16 +636,6 @@ async function main() {
         asset.mintProgram
     );
 
-    const [processedBurnPda] = PublicKey.findProgramAddressSync(
-        [
-            Buffer.from('processed_burn_v3'),
-            Buffer.from([ASSET_ID]),
-            Buffer.from(new BigUint64Array([BigInt(BURN_NONCE)]).buffer),
-            user.publicKey.toBuffer(),
-        ],
-        asset.mintProgram
-    );
-
     const accounts: any = {
         mintState: mintStatePda,
         processedBurn: processedBurnPda,
@@ -329,7 +643,16 @@ async function main() {
         user: user.publicKey,
         validatorSet: validatorSetPda,
         verifiedBurn: verifiedBurnPda,
-        tokenProgram: TOKEN_PROGRAM_ID,
+        featureGate: featureGatePda,
+        latencyStats: latencyStatsPda,
+        cosignPolicy: cosignPolicyAddress(asset.mintProgram),
+        rateLimit: rateLimitAddress(asset.mintProgram),
+        mintPause: mintPauseAddress(asset.mintProgram),
+        feePool: feePoolAddress(asset.mintProgram),
+        feeConfig: feeConfigAddress(asset.mintProgram),
+        assetRegistry: assetRegistryPda,
+        relayerClaim: relayerClaimAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, BURN_NONCE),
+        tokenProgram,
         systemProgram: anchor.web3.SystemProgram.programId,
     };
 
@@ -340,17 +663,48 @@ async function main() {
         accounts.dgnMint = asset.mint;
     }
 
+    // Hold the transfer rather than pay for a mint past the window's volume cap
+    const rateLimit = await fetchRateLimit(connection, asset.mintProgram);
+    if (rateLimit) {
+        const headroom = rateLimitHeadroom(rateLimit, Math.floor(Date.now() / 1000));
+        if (headroom < BigInt(verifiedBurn.amount.toString())) {
+            console.log(`⏳ Mint rate limit: only ${headroom} left this window (cap ${rateLimit.cap} per ${rateLimit.windowSeconds}s); retry later`);
+            process.exit(1);
+        }
+    }
+
+    // Validators with a fee vault are paid into it instead of their key
+    // (no fee accounts at all once the program has a fee pool)
+    const remainingAccounts = await validatorFeeAccounts(connection, asset.mintProgram, VALIDATORS.map(v => v.pubkey));
+    const signers = [user];
+
+    // Dual control: large mints also need the co-sign policy's co-signer
+    const cosigner = requiredCoSigner(await fetchCoSignPolicy(connection, asset.mintProgram), verifiedBurn.amount.toString());
+    if (cosigner) {
+        if (!process.env.COSIGNER_KEYPAIR) {
+            console.log(`⛔ Amount above the co-sign threshold: ${cosigner.toBase58()} must co-sign (set COSIGNER_KEYPAIR)`);
+            process.exit(1);
+        }
+        const cosignerKeypair = parseKeypair(fs.readFileSync(expandHome(process.env.COSIGNER_KEYPAIR), 'utf-8'));
+        if (!cosignerKeypair.publicKey.equals(cosigner)) {
+            throw new Error(`COSIGNER_KEYPAIR is ${cosignerKeypair.publicKey.toBase58()}, policy requires ${cosigner.toBase58()}`);
+        }
+        remainingAccounts.push(coSignerAccount(cosigner));
+        signers.push(cosignerKeypair);
+        console.log(`   ✍️  Co-signed by ${cosigner.toBase58()}`);
+    }
+
     const tx = await mintProgram.methods
         .mintFromBurnV3(new anchor.BN(BURN_NONCE), ASSET_ID)
         .accounts(accounts)
-        .remainingAccounts(VALIDATORS.map(v => ({ pubkey: v.pubkey, isWritable: true, isSigner: false })))
-        .signers([user])
+        .remainingAccounts(remainingAccounts)
+        .signers(signers)
         .rpc();
 
     console.log(`✅ Minting transaction: ${tx}`);
 
     // Get balance after minting
-    const accountAfter = await getAccount(connection, userTokenAccount);
+    const accountAfter = await getAccount(connection, userTokenAccount, undefined, tokenProgram);
     const balanceAfter = Number(accountAfter.amount);
     const minted = (balanceAfter - balanceBefore) / 1_000_000;
 
@@ -367,6 +721,13 @@ async function main() {
 main()
     .then(() => process.exit(0))
     .catch((err) => {
-        console.error('\n❌ Bridge mint failed:', err);
+        const error = parseBridgeError(err, {
+            programs: loadBridgeErrorTables({
+                lightClient: LIGHT_CLIENT_PROGRAM,
+                xencatMint: new PublicKey(config.programs.xencat_mint),
+                dgnMint: new PublicKey(config.programs.dgn_mint),
+            }),
+        });
+        console.error('\n❌ Bridge mint failed:', error.kind === BridgeErrorKind.Unknown ? err : error.message);
         process.exit(1);
     });
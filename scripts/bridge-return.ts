@@ -0,0 +1,256 @@
+/**
+ * Return Bridge (X1 → Solana)
+ *
+ * Sends XENCAT back from X1 to Solana in three steps:
+ *   burn     burn_for_return on the X1 mint program (user key)
+ *   verify   collect /attest-return from the X1 validators and submit
+ *            verify_return_burn to the light client
+ *   release  collect /attest-return-release and submit the burn program's
+ *            release_return, paying the recipient from the return reserve
+ *
+ * Usage:
+ *   npx ts-node scripts/bridge-return.ts burn    --amount 1000000 --recipient <solana wallet>
+ *   npx ts-node scripts/bridge-return.ts verify  --nonce 0
+ *   npx ts-node scripts/bridge-return.ts release --nonce 0
+ *   npx ts-node scripts/bridge-return.ts status  --nonce 0
+ *
+ * `verify` and `release` are permissionless: any relayer can complete a
+ * return once the burn is finalized on X1. Uses the shared bridge config.
+ */
+
+import 'dotenv/config';
+import {
+    Connection, Ed25519Program, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY, SystemProgram,
+    Transaction, TransactionInstruction, sendAndConfirmTransaction,
+} from '@solana/web3.js';
+import { getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    fetchNextReturnNonce, fetchReturnBurn, fetchVerifiedReturnBurn, isReturnReleased, returnBridgeAddress,
+    returnBurnAddress, returnReleaseAddress, returnReleaseMessage, returnReserveAddress, returnReserveVaultAddress,
+    verifiedReturnBurnAddress,
+} from '../sdk/attestation-client/src/return-bridge';
+
+const { config } = loadConfig();
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAM = new PublicKey(config.programs.xencat_mint);
+const SOLANA_MINT = new PublicKey(config.mints.xencat_solana);
+const X1_MINT = new PublicKey(config.mints.xencat_x1);
+const ASSET_ID = 1;
+
+const lightClientPda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+const u64 = (value: number | bigint) => new anchor.BN(value.toString()).toArrayLike(Buffer, 'le', 8);
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+/** POST `body` to every validator until `threshold` accept; `accept` filters mismatched answers */
+async function collect(path: string, body: object, threshold: number, members: Set<string>, accept: (r: any) => boolean): Promise<any[]> {
+    const responses: any[] = [];
+    for (const validator of config.attestation.validators) {
+        if (!members.has(validator.pubkey) || responses.length >= threshold) {
+            continue;
+        }
+        try {
+            const response = await fetch(`${validator.url}${path}`, {
+                method: 'POST',
+                headers: { 'Content-Type': 'application/json', 'X-Validator-Key': validator.pubkey },
+                body: JSON.stringify(body),
+            });
+            const result: any = await response.json();
+            if (!response.ok || result.validator_pubkey !== validator.pubkey) {
+                console.log(`  ⚠️  ${validator.url}: ${result.error || `HTTP ${response.status}`}`);
+                continue;
+            }
+            if (!accept(result)) {
+                console.log(`  ⚠️  ${validator.url}: response does not match the return burn`);
+                continue;
+            }
+            responses.push(result);
+            console.log(`  ✅ ${validator.url}`);
+        } catch (err: any) {
+            console.log(`  ❌ ${validator.url}: ${err.message}`);
+        }
+    }
+    if (responses.length < threshold) {
+        throw new Error(`Insufficient signatures: got ${responses.length}, need ${threshold}`);
+    }
+    return responses;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const x1 = new Connection(config.x1.rpc, 'confirmed');
+    const solana = new Connection(config.solana.rpc, 'confirmed');
+    const wallet = loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(x1, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const lightClient: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider);
+    const mintProgram: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/xencat_mint_x1.json', 'utf-8')), MINT_PROGRAM, provider);
+
+    switch (command) {
+        case 'burn': {
+            const amount = new anchor.BN(arg('amount'));
+            const recipient = new PublicKey(arg('recipient'));
+            const nonce = await fetchNextReturnNonce(x1, MINT_PROGRAM);
+            console.log(`🔥 Burning ${amount.toString()} XENCAT on X1 for ${recipient.toBase58()} (return nonce ${nonce})`);
+
+            const tx = await mintProgram.methods
+                .burnForReturn(amount, recipient)
+                .accounts({
+                    user: wallet.publicKey,
+                    mintState: PublicKey.findProgramAddressSync([Buffer.from('mint_state_v2')], MINT_PROGRAM)[0],
+                    returnBridge: returnBridgeAddress(MINT_PROGRAM),
+                    returnBurn: returnBurnAddress(MINT_PROGRAM, nonce),
+                    xencatMint: X1_MINT,
+                    userTokenAccount: await getAssociatedTokenAddress(X1_MINT, wallet.publicKey),
+                    featureGate: lightClientPda('feature_gate'),
+                    tokenProgram: TOKEN_PROGRAM_ID,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Return burn ${nonce} recorded: ${tx}`);
+            console.log(`   Next: bridge-return.ts verify --nonce ${nonce} (once finalized on X1)`);
+            break;
+        }
+
+        case 'verify': {
+            const nonce = Number(arg('nonce'));
+            const burn = await fetchReturnBurn(x1, MINT_PROGRAM, nonce);
+            if (!burn) {
+                throw new Error(`Return burn ${nonce} not found on X1`);
+            }
+            const set = await lightClient.account.x1ValidatorSet.fetch(lightClientPda('x1_validator_set_v2'));
+            const version = set.version.toNumber();
+            console.log(`🔐 Verifying return burn ${nonce}: ${burn.amount} to ${burn.solanaRecipient.toBase58()}`);
+
+            const members = new Set<string>(set.validators.map((v: PublicKey) => v.toBase58()));
+            const attestations = await collect(
+                '/attest-return',
+                { return_nonce: nonce, asset_id: ASSET_ID, validator_set_version: version },
+                set.threshold,
+                members,
+                r => r.amount === Number(burn.amount) && r.solana_recipient === burn.solanaRecipient.toBase58()
+            );
+
+            const tx = await lightClient.methods
+                .verifyReturnBurn(ASSET_ID, new anchor.BN(nonce), {
+                    validatorSetVersion: new anchor.BN(version),
+                    attestations: attestations.map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature })),
+                })
+                .accounts({
+                    payer: wallet.publicKey,
+                    validatorSet: lightClientPda('x1_validator_set_v2'),
+                    returnBurn: returnBurnAddress(MINT_PROGRAM, nonce),
+                    verifiedReturnBurn: verifiedReturnBurnAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, nonce),
+                    featureGate: lightClientPda('feature_gate'),
+                    bridgeDomain: lightClientPda('bridge_domain'),
+                    x1ChainId: lightClientPda('x1_chain_id'),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Return burn ${nonce} verified: ${tx}`);
+            console.log(`   Next: bridge-return.ts release --nonce ${nonce} (once finalized on X1)`);
+            break;
+        }
+
+        case 'release': {
+            const nonce = Number(arg('nonce'));
+            if (await isReturnReleased(solana, BURN_PROGRAM, ASSET_ID, nonce)) {
+                console.log(`✅ Return ${nonce} already released`);
+                break;
+            }
+            const verified = await fetchVerifiedReturnBurn(x1, LIGHT_CLIENT_PROGRAM, ASSET_ID, nonce);
+            if (!verified) {
+                throw new Error(`Return burn ${nonce} is not verified yet (run verify first)`);
+            }
+
+            const settlementConfigPda = PublicKey.findProgramAddressSync([Buffer.from('settlement_config')], BURN_PROGRAM)[0];
+            const configInfo = await solana.getAccountInfo(settlementConfigPda);
+            if (!configInfo) {
+                throw new Error('Settlement is not initialized on the burn program');
+            }
+            // SettlementConfig: discriminator(8) || validators(4 + 32n) || threshold(1) || version(8)
+            const count = configInfo.data.readUInt32LE(8);
+            const members = new Set(Array.from({ length: count }, (_, i) =>
+                new PublicKey(configInfo.data.subarray(12 + 32 * i, 44 + 32 * i)).toBase58()));
+            const threshold = configInfo.data[12 + 32 * count];
+            const version = Number(configInfo.data.readBigUInt64LE(13 + 32 * count));
+
+            console.log(`🔓 Releasing return ${nonce}: ${verified.amount} to ${verified.solanaRecipient.toBase58()}`);
+            const acks = await collect(
+                '/attest-return-release',
+                { return_nonce: nonce, asset_id: ASSET_ID },
+                threshold,
+                members,
+                r => r.settlement_version === version && r.amount === Number(verified.amount)
+            );
+
+            // Ed25519 instructions first; release_return reads them by introspection
+            const message = returnReleaseMessage(version, ASSET_ID, nonce, verified.solanaRecipient, verified.amount, verified.x1Slot);
+            const instructions: TransactionInstruction[] = acks.map(ack => Ed25519Program.createInstructionWithPublicKey({
+                publicKey: new PublicKey(ack.validator_pubkey).toBytes(),
+                message,
+                signature: Uint8Array.from(ack.signature),
+            }));
+            instructions.push(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: settlementConfigPda, isSigner: false, isWritable: false },
+                    { pubkey: returnReserveAddress(BURN_PROGRAM, SOLANA_MINT), isSigner: false, isWritable: true },
+                    { pubkey: returnReserveVaultAddress(BURN_PROGRAM, SOLANA_MINT), isSigner: false, isWritable: true },
+                    { pubkey: returnReleaseAddress(BURN_PROGRAM, ASSET_ID, nonce), isSigner: false, isWritable: true },
+                    { pubkey: await getAssociatedTokenAddress(SOLANA_MINT, verified.solanaRecipient), isSigner: false, isWritable: true },
+                    { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+                    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([
+                    discriminator('release_return'),
+                    u64(nonce),
+                    verified.solanaRecipient.toBuffer(),
+                    u64(verified.amount),
+                    u64(verified.x1Slot),
+                ]),
+            }));
+
+            const signature = await sendAndConfirmTransaction(solana, new Transaction().add(...instructions), [wallet]);
+            console.log(`✅ Return ${nonce} released on Solana: ${signature}`);
+            break;
+        }
+
+        case 'status': {
+            const nonce = Number(arg('nonce'));
+            const burn = await fetchReturnBurn(x1, MINT_PROGRAM, nonce);
+            const verified = await fetchVerifiedReturnBurn(x1, LIGHT_CLIENT_PROGRAM, ASSET_ID, nonce);
+            const released = await isReturnReleased(solana, BURN_PROGRAM, ASSET_ID, nonce);
+            console.log(`↩️  Return ${nonce}`);
+            console.log(`   Burned on X1: ${burn ? `${burn.amount} to ${burn.solanaRecipient.toBase58()} (slot ${burn.slot})` : 'no'}`);
+            console.log(`   Verified:     ${verified ? `yes (validator set v${verified.validatorSetVersion})` : 'no'}`);
+            console.log(`   Released:     ${released ? 'yes' : 'no'}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: bridge-return.ts <burn|verify|release|status> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,208 @@
+/**
+ * Burn Age Limit and Replay Guard Cleanup
+ *
+ * Once validators set a burn age limit, V3 attestations of Solana burns
+ * older than it fail with BurnTooOld. A minted burn past the limit whose
+ * VerifiedBurnV3 was archived can then never be verified again, so its
+ * user may close the mint program's processed_burn_v3 replay guard and
+ * get the rent back. The limit can be lowered but never raised or removed.
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-age-limit.ts init
+ *   npx ts-node scripts/burn-age-limit.ts status
+ *   npx ts-node scripts/burn-age-limit.ts propose --seconds 7776000 [--out burn-age-limit.json]
+ *   npx ts-node scripts/burn-age-limit.ts sign    --file burn-age-limit.json   (validator key)
+ *   npx ts-node scripts/burn-age-limit.ts submit  --file burn-age-limit.json
+ *   npx ts-node scripts/burn-age-limit.ts close   --asset 1 --nonce 1234       (user key)
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    burnAgeLimitAddress,
+    burnAgeLimitMessage,
+    fetchBurnAgeLimit,
+    replayGuardClosableAt,
+} from '../sdk/attestation-client/src/burn-age-limit';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAMS: Record<number, { programId: PublicKey; idl: string }> = {
+    1: { programId: new PublicKey(config.programs.xencat_mint), idl: 'target/idl/xencat_mint_x1.json' },
+    2: { programId: new PublicKey(config.programs.dgn_mint), idl: 'target/idl/dgn_mint_x1.json' },
+};
+
+const pda = (seeds: Buffer[], program: PublicKey) => PublicKey.findProgramAddressSync(seeds, program)[0];
+const VALIDATOR_SET = pda([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM);
+const BRIDGE_CONFIG = pda([Buffer.from('bridge_config')], LIGHT_CLIENT_PROGRAM);
+const BURN_AGE_LIMIT = burnAgeLimitAddress(LIGHT_CLIENT_PROGRAM);
+
+/** Must match BurnAgeLimit's governance bounds */
+const MIN_MAX_BURN_AGE = 604_800;
+const MAX_MAX_BURN_AGE = 31_536_000;
+
+interface LimitProposal {
+    max_burn_age: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+const limitMessage = (p: Omit<LimitProposal, 'message' | 'approvals'>) =>
+    burnAgeLimitMessage(p.validator_set_version, p.config_nonce, p.max_burn_age, proposalX1GenesisHash(p));
+
+const u64 = (value: number) => Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods
+                .initializeBurnAgeLimit()
+                .accounts({ burnAgeLimit: BURN_AGE_LIMIT, payer: wallet.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Burn age limit created (no limit): ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const limit = await fetchBurnAgeLimit(connection, LIGHT_CLIENT_PROGRAM);
+            if (!limit || limit.maxBurnAge === 0) {
+                console.log('⏳ Burn age limit: none (replay guards are never closed)');
+            } else {
+                console.log(`⏳ Burn age limit: ${limit.maxBurnAge}s (${(limit.maxBurnAge / 86_400).toFixed(1)} days), set at slot ${limit.updatedSlot}`);
+            }
+            break;
+        }
+
+        case 'propose': {
+            const seconds = parseInt(arg('seconds'));
+            const current = (await fetchBurnAgeLimit(connection, LIGHT_CLIENT_PROGRAM))?.maxBurnAge ?? 0;
+            if (seconds < MIN_MAX_BURN_AGE || seconds > MAX_MAX_BURN_AGE) {
+                throw new Error(`--seconds must be between ${MIN_MAX_BURN_AGE} and ${MAX_MAX_BURN_AGE}`);
+            }
+            if (current !== 0 && seconds > current) {
+                throw new Error(`The limit can only be lowered (currently ${current}s)`);
+            }
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                max_burn_age: seconds,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: LimitProposal = { ...base, message: limitMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'burn-age-limit.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Burn age limit proposal (${seconds}s) written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: LimitProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = limitMessage(proposal);
+            console.log(`   Burn age limit: ${proposal.max_burn_age}s (one-way: it can never be raised again)`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed burn age limit proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: LimitProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = limitMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting burn age limit with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setBurnAgeLimit({ maxBurnAge: new anchor.BN(proposal.max_burn_age), approverSignatures })
+                .accounts({ validatorSet: VALIDATOR_SET, x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM), bridgeConfig: BRIDGE_CONFIG, burnAgeLimit: BURN_AGE_LIMIT, signer: wallet.publicKey })
+                .rpc();
+            console.log(`✅ Burn age limit updated: ${tx}`);
+            break;
+        }
+
+        case 'close': {
+            const assetId = parseInt(arg('asset'));
+            const nonce = parseInt(arg('nonce'));
+            const mint = MINT_PROGRAMS[assetId];
+            if (!mint) {
+                throw new Error(`Unknown asset ${assetId}`);
+            }
+            const mintProgram: any = new anchor.Program(JSON.parse(fs.readFileSync(mint.idl, 'utf-8')), mint.programId, provider);
+            const user = wallet.publicKey;
+            const processedBurn = pda([Buffer.from('processed_burn_v3'), Buffer.from([assetId]), u64(nonce), user.toBuffer()], mint.programId);
+            const verifiedBurn = pda([Buffer.from('verified_burn_v3'), Buffer.from([assetId]), user.toBuffer(), u64(nonce)], LIGHT_CLIENT_PROGRAM);
+
+            const processed = await mintProgram.account.processedBurnV3.fetchNullable(processedBurn);
+            if (!processed) {
+                throw new Error(`No replay guard for burn ${nonce} of ${user.toBase58()}`);
+            }
+            if (await connection.getAccountInfo(verifiedBurn)) {
+                throw new Error('The verified burn is not archived yet (npm run archive:crank)');
+            }
+            const closableAt = replayGuardClosableAt(
+                await fetchBurnAgeLimit(connection, LIGHT_CLIENT_PROGRAM),
+                processed.processedAt.toNumber(),
+            );
+            if (closableAt === null) {
+                throw new Error('No burn age limit is set: replay guards cannot be closed');
+            }
+            if (closableAt > Date.now() / 1000) {
+                throw new Error(`Closable from ${new Date(closableAt * 1000).toISOString()}`);
+            }
+
+            const accounts: Record<string, PublicKey> = { user, processedBurn, verifiedBurn, burnAgeLimit: BURN_AGE_LIMIT };
+            if (assetId === 1) {
+                accounts.verifiedBurnV2 = pda([Buffer.from('verified_burn_v2'), user.toBuffer(), u64(nonce)], LIGHT_CLIENT_PROGRAM);
+                accounts.bridgeConfig = BRIDGE_CONFIG;
+            }
+            const tx = await mintProgram.methods.closeProcessedBurn(new anchor.BN(nonce)).accounts(accounts).rpc();
+            console.log(`✅ Replay guard of burn ${nonce} closed, rent refunded: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-age-limit.ts <init|status|propose|sign|submit|close> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
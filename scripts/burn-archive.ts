@@ -0,0 +1,357 @@
+/**
+ * Verified Burn Archive (retention policy)
+ *
+ * VerifiedBurnV3 accounts stay on X1 forever unless archived. Once a burn
+ * has been minted (the mint program's processed_burn_v3 PDA exists) and is
+ * older than BURN_RETENTION_PERIODS archive periods, anyone may append its
+ * receipt to the per-asset, per-period BurnArchive Merkle tree and close
+ * it; the rent goes back to the user. `crank` uses sweep_verified_burn
+ * once the sweep bounty pool exists (scripts/sweep-bounty-pool.ts), which
+ * does the same and pays the caller the pool's bounty.
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-archive.ts crank  [--asset 1] [--limit 20]
+ *   npx ts-node scripts/burn-archive.ts export --asset 1 --period 412 [--out archive-1-412.json]
+ *   npx ts-node scripts/burn-archive.ts prove  --file archive-1-412.json --nonce 1234
+ *
+ * `export` rebuilds the tree from the BurnArchived events in the archive's
+ * transaction history and refuses to write the file unless its root matches
+ * the on-chain root. Keep exported files: they (and the event logs) are
+ * what a closed burn is proven from. `prove` prints the Merkle path.
+ *
+ * Leaves: sha256(asset_id || burn_nonce || user || amount || verified_at ||
+ * solana_burn_slot || verified_slot), little-endian integers, in archive
+ * order. The tree is fixed at depth 16 with zero leaves as padding;
+ * parents are sha256(left || right).
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { assetRegistryAddress, fetchRegisteredAssets } from '../sdk/attestation-client/src/asset-registry';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+/** Built-in assets, used while the asset registry does not exist (AssetRegistry::resolve) */
+const MINT_PROGRAMS: Record<number, PublicKey> = {
+    1: new PublicKey(config.programs.xencat_mint),
+    2: new PublicKey(config.programs.dgn_mint),
+};
+
+/** Must match config::ARCHIVE_PERIOD_SLOTS / BURN_RETENTION_PERIODS and ARCHIVE_TREE_DEPTH */
+const ARCHIVE_PERIOD_SLOTS = 432_000;
+const BURN_RETENTION_PERIODS = 30;
+const TREE_DEPTH = 16;
+
+interface ArchivedLeaf {
+    leaf_index: number;
+    burn_nonce: number;
+    user: string;
+    amount: string;
+    verified_at: number;
+    solana_burn_slot: number;
+    verified_slot: number;
+    leaf: string;
+}
+
+interface ArchiveExport {
+    program: string;
+    asset_id: number;
+    period: number;
+    root: string;
+    total_amount: string;
+    leaves: ArchivedLeaf[];
+}
+
+function u8(value: number): Buffer {
+    return Buffer.from([value]);
+}
+
+function u64(value: bigint | number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function i64(value: bigint | number): Buffer {
+    return Buffer.from(new BigInt64Array([BigInt(value)]).buffer);
+}
+
+function sha256(...parts: Buffer[]): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat(parts)).digest();
+}
+
+function archivePda(assetId: number, period: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('burn_archive'), u8(assetId), u64(period)],
+        LIGHT_CLIENT_PROGRAM
+    )[0];
+}
+
+function processedBurnPda(mintProgram: PublicKey, assetId: number, nonce: number, user: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('processed_burn_v3'), u8(assetId), u64(nonce), user.toBuffer()],
+        mintProgram
+    )[0];
+}
+
+/** Exists when the rent sponsor pool paid this burn's rent (archiving refunds the pool) */
+function sponsoredRentPda(assetId: number, nonce: number, user: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('sponsored_rent'), u8(assetId), user.toBuffer(), u64(nonce)],
+        LIGHT_CLIENT_PROGRAM
+    )[0];
+}
+
+/** Must match archive_leaf on-chain */
+function leafHash(assetId: number, leaf: Omit<ArchivedLeaf, 'leaf' | 'leaf_index'>): Buffer {
+    return sha256(
+        u8(assetId),
+        u64(leaf.burn_nonce),
+        new PublicKey(leaf.user).toBuffer(),
+        u64(BigInt(leaf.amount)),
+        i64(leaf.verified_at),
+        u64(leaf.solana_burn_slot),
+        u64(leaf.verified_slot),
+    );
+}
+
+/** Every level of the zero-padded tree, leaves first (only non-empty nodes stored) */
+function treeLevels(leaves: Buffer[]): Buffer[][] {
+    const levels: Buffer[][] = [leaves];
+    let zero = Buffer.alloc(32);
+    for (let height = 0; height < TREE_DEPTH; height++) {
+        const level = levels[height];
+        const next: Buffer[] = [];
+        for (let i = 0; i < level.length; i += 2) {
+            next.push(sha256(level[i], level[i + 1] ?? zero));
+        }
+        levels.push(next);
+        zero = sha256(zero, zero);
+    }
+    return levels;
+}
+
+function treeRoot(leaves: Buffer[]): Buffer {
+    const top = treeLevels(leaves)[TREE_DEPTH];
+    if (top.length > 0) {
+        return top[0];
+    }
+    let zero = Buffer.alloc(32);
+    for (let height = 0; height < TREE_DEPTH; height++) {
+        zero = sha256(zero, zero);
+    }
+    return zero;
+}
+
+function merklePath(leaves: Buffer[], index: number): string[] {
+    const levels = treeLevels(leaves);
+    const path: string[] = [];
+    let zero = Buffer.alloc(32);
+    for (let height = 0; height < TREE_DEPTH; height++) {
+        const sibling = levels[height][index ^ 1] ?? zero;
+        path.push(sibling.toString('hex'));
+        zero = sha256(zero, zero);
+        index >>= 1;
+    }
+    return path;
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+
+    if (command === 'prove') {
+        const archive: ArchiveExport = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+        const nonce = Number(arg('nonce'));
+        const entry = archive.leaves.find(l => l.burn_nonce === nonce);
+        if (!entry) {
+            throw new Error(`Burn ${nonce} is not in ${arg('file')}`);
+        }
+        const leaves = archive.leaves.map(l => Buffer.from(l.leaf, 'hex'));
+        console.log(JSON.stringify({
+            asset_id: archive.asset_id,
+            period: archive.period,
+            root: archive.root,
+            leaf_index: entry.leaf_index,
+            leaf: entry.leaf,
+            path: merklePath(leaves, entry.leaf_index),
+            burn: entry,
+        }, null, 2));
+        return;
+    }
+
+    const wallet = loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'crank': {
+            const assetFilter = process.argv.includes('--asset') ? Number(arg('asset')) : undefined;
+            const limit = Number(arg('limit', '20'));
+            const currentPeriod = Math.floor(await connection.getSlot('confirmed') / ARCHIVE_PERIOD_SLOTS);
+
+            const burns = (await program.account.verifiedBurnV3.all())
+                .filter((b: any) => assetFilter === undefined || b.account.assetId === assetFilter)
+                .filter((b: any) => Math.floor(b.account.verifiedSlot.toNumber() / ARCHIVE_PERIOD_SLOTS) + BURN_RETENTION_PERIODS <= currentPeriod);
+            console.log(`🗄️ ${burns.length} verified burns past the retention window`);
+            const sweepBountyPool = PublicKey.findProgramAddressSync([Buffer.from('sweep_bounty_pool')], LIGHT_CLIENT_PROGRAM)[0];
+            const bounty = await connection.getAccountInfo(sweepBountyPool) !== null;
+            if (bounty) {
+                console.log('   🧹 Sweeping for the bounty pool\'s bounty');
+            }
+
+            const registered = await fetchRegisteredAssets(connection, LIGHT_CLIENT_PROGRAM);
+            const mintProgramOf = (assetId: number): PublicKey | undefined => registered
+                ? registered.find(a => a.assetId === assetId)?.mintProgram
+                : MINT_PROGRAMS[assetId];
+
+            let archived = 0;
+            for (const { publicKey, account } of burns) {
+                if (archived >= limit) {
+                    break;
+                }
+                const assetId: number = account.assetId;
+                const nonce: number = account.burnNonce.toNumber();
+                const period = Math.floor(account.verifiedSlot.toNumber() / ARCHIVE_PERIOD_SLOTS);
+                const mintProgram = mintProgramOf(assetId);
+                if (!mintProgram) {
+                    console.log(`   ⏭️  Burn ${nonce} (asset ${assetId}) is for an unknown asset - kept`);
+                    continue;
+                }
+                const processedBurn = processedBurnPda(mintProgram, assetId, nonce, account.user);
+                if (!(await connection.getAccountInfo(processedBurn))) {
+                    console.log(`   ⏭️  Burn ${nonce} (asset ${assetId}) not minted yet - kept`);
+                    continue;
+                }
+
+                const burnArchive = archivePda(assetId, period);
+                if (!(await connection.getAccountInfo(burnArchive))) {
+                    await program.methods.initializeBurnArchive(assetId, new anchor.BN(period))
+                        .accounts({ payer: wallet.publicKey, burnArchive, systemProgram: anchor.web3.SystemProgram.programId })
+                        .rpc();
+                    console.log(`   📦 Created archive for asset ${assetId}, period ${period}`);
+                }
+
+                const archiveAccounts = {
+                    verifiedBurn: publicKey,
+                    user: account.user,
+                    processedBurn,
+                    burnArchive,
+                    sponsoredRent: sponsoredRentPda(assetId, nonce, account.user),
+                    rentSponsorPool: PublicKey.findProgramAddressSync([Buffer.from('rent_sponsor_pool')], LIGHT_CLIENT_PROGRAM)[0],
+                    assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+                    signer: wallet.publicKey,
+                };
+                const args = [assetId, new anchor.BN(nonce), new anchor.BN(period)];
+                const tx = bounty
+                    ? await program.methods.sweepVerifiedBurn(...args)
+                        .accounts({ archive: archiveAccounts, sweepBountyPool, cranker: wallet.publicKey })
+                        .rpc()
+                    : await program.methods.archiveVerifiedBurn(...args)
+                        .accounts(archiveAccounts)
+                        .rpc();
+                archived++;
+                console.log(`   ✅ Archived burn ${nonce} (asset ${assetId}, period ${period}): ${tx}`);
+            }
+            console.log(`✅ Archived ${archived} burns`);
+            break;
+        }
+
+        case 'export': {
+            const assetId = Number(arg('asset'));
+            const period = Number(arg('period'));
+            const burnArchive = archivePda(assetId, period);
+            const onChain = await program.account.burnArchive.fetch(burnArchive);
+
+            // Walk the archive's full history (oldest first) and collect its events
+            const signatures: string[] = [];
+            let before: string | undefined;
+            for (;;) {
+                const page = await connection.getSignaturesForAddress(burnArchive, { before, limit: 1000 });
+                signatures.push(...page.filter(s => !s.err).map(s => s.signature));
+                if (page.length < 1000) break;
+                before = page[page.length - 1].signature;
+            }
+            signatures.reverse();
+
+            const parser = new anchor.EventParser(LIGHT_CLIENT_PROGRAM, program.coder);
+            const byIndex = new Map<number, ArchivedLeaf>();
+            for (const signature of signatures) {
+                const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'confirmed' });
+                for (const event of parser.parseLogs(tx?.meta?.logMessages || [])) {
+                    if (event.name !== 'BurnArchived' || event.data.assetId !== assetId || Number(event.data.period) !== period) {
+                        continue;
+                    }
+                    const data: any = event.data;
+                    const fields = {
+                        burn_nonce: data.burnNonce.toNumber(),
+                        user: data.user.toBase58(),
+                        amount: data.amount.toString(),
+                        verified_at: data.verifiedAt.toNumber(),
+                        solana_burn_slot: data.solanaBurnSlot.toNumber(),
+                        verified_slot: data.verifiedSlot.toNumber(),
+                    };
+                    byIndex.set(data.leafIndex.toNumber(), {
+                        leaf_index: data.leafIndex.toNumber(),
+                        ...fields,
+                        leaf: leafHash(assetId, fields).toString('hex'),
+                    });
+                }
+            }
+
+            const leafCount = onChain.leafCount.toNumber();
+            const leaves = Array.from({ length: leafCount }, (_, i) => byIndex.get(i));
+            const missing = leaves.findIndex(l => l === undefined);
+            if (missing >= 0) {
+                throw new Error(`Leaf ${missing} of ${leafCount} not found in the transaction history (RPC history pruned?)`);
+            }
+
+            const root = treeRoot(leaves.map(l => Buffer.from(l!.leaf, 'hex'))).toString('hex');
+            const expected = Buffer.from(onChain.root).toString('hex');
+            if (root !== expected) {
+                throw new Error(`Rebuilt root ${root} does not match on-chain root ${expected}`);
+            }
+
+            // The same burn archived twice (re-created from old attestations) is flagged, not hidden
+            const nonces = leaves.map(l => l!.burn_nonce);
+            const duplicates = nonces.filter((n, i) => nonces.indexOf(n) !== i);
+            if (duplicates.length > 0) {
+                console.warn(`⚠️  Burns archived more than once: ${[...new Set(duplicates)].join(', ')}`);
+            }
+
+            const out = arg('out', `archive-${assetId}-${period}.json`);
+            const file: ArchiveExport = {
+                program: LIGHT_CLIENT_PROGRAM.toBase58(),
+                asset_id: assetId,
+                period,
+                root,
+                total_amount: onChain.totalAmount.toString(),
+                leaves: leaves as ArchivedLeaf[],
+            };
+            fs.writeFileSync(out, JSON.stringify(file, null, 2));
+            console.log(`✅ ${leafCount} archived burns (root ${root}) written to ${out}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-archive.ts <crank|export|prove> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,158 @@
+/**
+ * Burn Program Asset Registry (Solana)
+ *
+ * Registers tokens with the Solana burn program under their light client
+ * asset id, so they burn through burn_registered_asset and every
+ * BurnRecord names its asset. Register each asset with the same id it has
+ * in the light client's registry (scripts/asset-registry.ts).
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-assets.ts status     --asset 2
+ *   npx ts-node scripts/burn-assets.ts register   --asset 2 --mint <solana mint>   (upgrade authority as keys.user)
+ *   npx ts-node scripts/burn-assets.ts deactivate --asset 2                        (upgrade authority)
+ *   npx ts-node scripts/burn-assets.ts activate   --asset 2                        (upgrade authority)
+ *   npx ts-node scripts/burn-assets.ts burn       --asset 2 --amount 1000000       (user key)
+ */
+
+import 'dotenv/config';
+import {
+    Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction,
+} from '@solana/web3.js';
+import { getAssociatedTokenAddress } from '@solana/spl-token';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    fetchBurnAssetIdForMint, fetchRegisteredBurnAsset, registeredBurnAssetAddress, registeredBurnMintAddress,
+} from '../sdk/attestation-client/src/burn-assets';
+import { betaAllowlistAddress, fetchBurnBetaAllowlist } from '../sdk/attestation-client/src/beta-allowlist';
+import { burnWormholeAccounts } from '../sdk/attestation-client/src/wormhole-vaa';
+
+const { config } = loadConfig();
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const BPF_LOADER_UPGRADEABLE = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+
+const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, BURN_PROGRAM)[0];
+const GLOBAL_STATE = pda(Buffer.from('global_state'));
+const PROGRAM_DATA = PublicKey.findProgramAddressSync([BURN_PROGRAM.toBuffer()], BPF_LOADER_UPGRADEABLE)[0];
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+const u64 = (value: number | bigint) => new anchor.BN(value.toString()).toArrayLike(Buffer, 'le', 8);
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.solana.rpc, 'confirmed');
+    const wallet = loadKeypair(config, 'user');
+    const assetId = parseInt(arg('asset'));
+    const registeredAsset = registeredBurnAssetAddress(BURN_PROGRAM, assetId);
+    const send = async (ix: TransactionInstruction) =>
+        sendAndConfirmTransaction(connection, new Transaction().add(ix), [wallet]);
+
+    switch (command) {
+        case 'status': {
+            const asset = await fetchRegisteredBurnAsset(connection, BURN_PROGRAM, assetId);
+            console.log(`🔥 Burn asset ${assetId} (${registeredAsset.toBase58()})`);
+            if (!asset) {
+                console.log('   Not registered: its burns record asset id 0');
+                break;
+            }
+            console.log(`   Mint: ${asset.mint.toBase58()}${asset.active ? '' : ' (INACTIVE)'}`);
+            console.log(`   Burned: ${asset.totalAmountBurned} in ${asset.totalBurns} burns`);
+            const burners = await fetchBurnBetaAllowlist(connection, BURN_PROGRAM, assetId);
+            if (burners) {
+                console.log(`   Beta: only ${burners.length} allowlisted burners (scripts/beta-allowlist.ts)`);
+            }
+            break;
+        }
+
+        case 'register': {
+            const mint = new PublicKey(arg('mint'));
+            const existing = await fetchBurnAssetIdForMint(connection, BURN_PROGRAM, mint);
+            if (existing !== null) {
+                throw new Error(`${mint.toBase58()} is already registered as asset ${existing}`);
+            }
+            const tx = await send(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: registeredAsset, isSigner: false, isWritable: true },
+                    { pubkey: registeredBurnMintAddress(BURN_PROGRAM, mint), isSigner: false, isWritable: true },
+                    { pubkey: mint, isSigner: false, isWritable: false },
+                    { pubkey: BURN_PROGRAM, isSigner: false, isWritable: false },
+                    { pubkey: PROGRAM_DATA, isSigner: false, isWritable: false },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([discriminator('register_asset'), Buffer.from([assetId])]),
+            }));
+            console.log(`✅ Asset ${assetId} registered for ${mint.toBase58()}: ${tx}`);
+            break;
+        }
+
+        case 'activate':
+        case 'deactivate': {
+            const tx = await send(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: false },
+                    { pubkey: registeredAsset, isSigner: false, isWritable: true },
+                    { pubkey: BURN_PROGRAM, isSigner: false, isWritable: false },
+                    { pubkey: PROGRAM_DATA, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([discriminator('set_asset_active'), Buffer.from([command === 'activate' ? 1 : 0])]),
+            }));
+            console.log(`✅ Asset ${assetId} ${command}d: ${tx}`);
+            break;
+        }
+
+        case 'burn': {
+            const asset = await fetchRegisteredBurnAsset(connection, BURN_PROGRAM, assetId);
+            if (!asset || !asset.active) {
+                throw new Error(`Asset ${assetId} is not registered or not active in the burn program`);
+            }
+            const amount = BigInt(arg('amount'));
+            const globalState = await connection.getAccountInfo(GLOBAL_STATE);
+            if (!globalState) {
+                throw new Error('Burn program not initialized');
+            }
+            const nonce = globalState.data.readBigUInt64LE(8);
+            const tokenProgram = (await connection.getAccountInfo(asset.mint))!.owner;
+            const tx = await send(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: GLOBAL_STATE, isSigner: false, isWritable: true },
+                    { pubkey: pda(Buffer.from('burn_record'), u64(nonce)), isSigner: false, isWritable: true },
+                    { pubkey: registeredAsset, isSigner: false, isWritable: true },
+                    { pubkey: betaAllowlistAddress(BURN_PROGRAM, assetId), isSigner: false, isWritable: false },
+                    { pubkey: asset.mint, isSigner: false, isWritable: true },
+                    { pubkey: await getAssociatedTokenAddress(asset.mint, wallet.publicKey), isSigner: false, isWritable: true },
+                    // Published through Wormhole for the VAA path (submit_burn_vaa)
+                    ...burnWormholeAccounts(BURN_PROGRAM, nonce),
+                    { pubkey: tokenProgram, isSigner: false, isWritable: false },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([discriminator('burn_registered_asset'), Buffer.from([assetId]), u64(amount)]),
+            }));
+            console.log(`✅ Burned ${amount} of asset ${assetId} (nonce ${nonce}): ${tx}`);
+            console.log(`   BURN_NONCE=${nonce} ASSET_ID=${assetId} npx ts-node scripts/bridge-mint.ts`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-assets.ts <status|register|activate|deactivate|burn> --asset <id> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
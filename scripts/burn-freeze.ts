@@ -0,0 +1,268 @@
+/**
+ * Emergency Burn Freeze
+ *
+ * Guardians (the X1 validators) freeze one VerifiedBurnV3, e.g. a burn of
+ * funds flagged as stolen, so it cannot be minted while every other
+ * transfer keeps flowing. Freezing needs the fast quorum (more than a third
+ * of the validators, at most the threshold); lifting it needs the full
+ * threshold.
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-freeze.ts propose  --asset 1 --nonce 1234 --user <pubkey> --reason "case-42" [--out freeze.json]
+ *   npx ts-node scripts/burn-freeze.ts propose  --unfreeze --asset 1 --nonce 1234 --user <pubkey> [--out unfreeze.json]
+ *   npx ts-node scripts/burn-freeze.ts sign     --file freeze.json   (validator key)
+ *   npx ts-node scripts/burn-freeze.ts submit   --file freeze.json
+ *   npx ts-node scripts/burn-freeze.ts collect  --asset 1 --nonce 1234 [--out freeze.json]
+ *
+ * `collect` builds a freeze proposal for a burn the validator services
+ * found reorged: it merges the FREEZE_BURN approvals they publish at
+ * GET /revocations (reason sha256("reorg:<asset>:<nonce>")) for the
+ * current validator set version and nonce. Submit it as usual.
+ *
+ * `propose` binds the current validator set version and BridgeConfig
+ * nonce, so collect signatures and submit promptly (any other governance
+ * action in between invalidates them). The reason is stored as
+ * sha256(--reason); keep the incident report it refers to.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchRegisteredAssets } from '../sdk/attestation-client/src/asset-registry';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAMS: Record<number, PublicKey> = {
+    1: new PublicKey(config.programs.xencat_mint),
+    2: new PublicKey(config.programs.dgn_mint),
+};
+
+const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda(Buffer.from('x1_validator_set_v2'));
+const BRIDGE_CONFIG = pda(Buffer.from('bridge_config'));
+
+interface FreezeProposal {
+    action: 'FREEZE_BURN' | 'UNFREEZE_BURN';
+    asset_id: number;
+    burn_nonce: number;
+    user: string;
+    /** hex sha256 of the incident reference */
+    reason: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_freeze_message on-chain */
+function freezeMessage(p: Omit<FreezeProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(p.action),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        Buffer.from([p.asset_id]),
+        u64(p.burn_nonce),
+        new PublicKey(p.user).toBuffer(),
+        Buffer.from(p.reason, 'hex'),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function burnSeeds(prefix: string, assetId: number, user: PublicKey, nonce: number): Buffer[] {
+    return [Buffer.from(prefix), Buffer.from([assetId]), user.toBuffer(), u64(nonce)];
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'propose': {
+            const unfreeze = process.argv.includes('--unfreeze');
+            const assetId = Number(arg('asset'));
+            const burnNonce = Number(arg('nonce'));
+            const user = new PublicKey(arg('user'));
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+
+            // Unfreeze signs over the reason stored in the freeze record
+            const reason = unfreeze
+                ? Buffer.from((await program.account.burnFreeze.fetch(pda(...burnSeeds('burn_freeze', assetId, user, burnNonce)))).reason).toString('hex')
+                : crypto.createHash('sha256').update(arg('reason')).digest('hex');
+
+            const base = {
+                action: unfreeze ? 'UNFREEZE_BURN' as const : 'FREEZE_BURN' as const,
+                asset_id: assetId,
+                burn_nonce: burnNonce,
+                user: user.toBase58(),
+                reason,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: FreezeProposal = { ...base, message: freezeMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', unfreeze ? 'unfreeze.json' : 'freeze.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ ${proposal.action} proposal for burn ${burnNonce} written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: FreezeProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = freezeMessage(proposal);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed ${proposal.action} for burn ${proposal.burn_nonce}; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: FreezeProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = freezeMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            const user = new PublicKey(proposal.user);
+            const verifiedBurn = pda(...burnSeeds('verified_burn_v3', proposal.asset_id, user, proposal.burn_nonce));
+            const burnFreeze = pda(...burnSeeds('burn_freeze', proposal.asset_id, user, proposal.burn_nonce));
+            console.log(`📤 Submitting ${proposal.action} with ${approverSignatures.length} valid approvals`);
+
+            let tx: string;
+            if (proposal.action === 'FREEZE_BURN') {
+                // Governance-registered assets have their own mint program
+                const registered = (await fetchRegisteredAssets(connection, LIGHT_CLIENT_PROGRAM))?.find(a => a.assetId === proposal.asset_id);
+                const processedBurn = PublicKey.findProgramAddressSync(
+                    [Buffer.from('processed_burn_v3'), Buffer.from([proposal.asset_id]), u64(proposal.burn_nonce), user.toBuffer()],
+                    registered?.mintProgram ?? MINT_PROGRAMS[proposal.asset_id]
+                )[0];
+                tx = await program.methods
+                    .freezeBurn(proposal.asset_id, new anchor.BN(proposal.burn_nonce), user, {
+                        reason: Array.from(Buffer.from(proposal.reason, 'hex')),
+                        approverSignatures,
+                    })
+                    .accounts({
+                        validatorSet: VALIDATOR_SET,
+                        x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                        bridgeConfig: BRIDGE_CONFIG,
+                        verifiedBurn,
+                        degradedBurn: pda(...burnSeeds('degraded_burn', proposal.asset_id, user, proposal.burn_nonce)),
+                        processedBurn,
+                        assetRegistry: pda(Buffer.from('asset_registry')),
+                        burnFreeze,
+                        payer: wallet.publicKey,
+                        systemProgram: SystemProgram.programId,
+                    })
+                    .rpc();
+            } else {
+                const freeze = await program.account.burnFreeze.fetch(burnFreeze);
+                tx = await program.methods
+                    .unfreezeBurn({ approverSignatures })
+                    .accounts({
+                        validatorSet: VALIDATOR_SET,
+                        x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                        bridgeConfig: BRIDGE_CONFIG,
+                        verifiedBurn,
+                        burnFreeze,
+                        payer: freeze.payer,
+                        signer: wallet.publicKey,
+                    })
+                    .rpc();
+            }
+            console.log(`✅ ${proposal.action} applied: ${tx}`);
+            break;
+        }
+
+        case 'collect': {
+            const assetId = Number(arg('asset'));
+            const burnNonce = Number(arg('nonce'));
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const x1GenesisHash = await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM);
+
+            let proposal: FreezeProposal | undefined;
+            for (const validator of config.attestation.validators) {
+                try {
+                    const response = await fetch(`${validator.url}/revocations`);
+                    const { revocations } = await response.json() as { revocations: FreezeProposal[] };
+                    const found = revocations.find(r => r.asset_id === assetId && r.burn_nonce === burnNonce);
+                    if (!found) {
+                        console.log(`   ${validator.pubkey}: no revocation`);
+                        continue;
+                    }
+                    if (found.validator_set_version !== set.version.toNumber()
+                        || found.config_nonce !== bridgeConfig.nonce.toNumber()
+                        || (found.x1_genesis_hash ?? null) !== x1GenesisHash) {
+                        console.log(`   ${validator.pubkey}: stale (version ${found.validator_set_version}, nonce ${found.config_nonce})`);
+                        continue;
+                    }
+                    // Recompute rather than trust the service's message field
+                    const message = freezeMessage(found);
+                    const valid = found.approvals.filter(a =>
+                        nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()));
+                    proposal = proposal ?? { ...found, message: message.toString('hex'), approvals: [] };
+                    if (proposal.user !== found.user) {
+                        console.log(`   ${validator.pubkey}: revocation for another user (${found.user}), skipped`);
+                        continue;
+                    }
+                    for (const approval of valid) {
+                        if (!proposal.approvals.some(a => a.validator_pubkey === approval.validator_pubkey)) {
+                            proposal.approvals.push(approval);
+                        }
+                    }
+                    console.log(`   ${validator.pubkey}: ${valid.length} approvals`);
+                } catch (error: any) {
+                    console.log(`   ${validator.pubkey}: unreachable (${error.message})`);
+                }
+            }
+
+            if (!proposal) {
+                throw new Error(`No validator published a revocation for burn ${burnNonce}`);
+            }
+            const out = arg('out', 'freeze.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ FREEZE_BURN proposal for burn ${burnNonce} written to ${out} (${proposal.approvals.length} approvals)`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-freeze.ts <propose|sign|submit|collect> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,88 @@
+/**
+ * Burn Amount Limits (Solana burn program)
+ *
+ * The smallest and largest amount a single burn may have. Dust burns cost
+ * validators an attestation each, and large burns pass the X1 rate limits
+ * more easily when split. 0 disables a limit.
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-limits.ts status
+ *   npx ts-node scripts/burn-limits.ts set --min 1000000 --max 0   (upgrade authority as keys.user)
+ *
+ * Run `set` right after upgrading the burn program: it also grows the
+ * GlobalState account created before the limits existed.
+ */
+
+import 'dotenv/config';
+import {
+    Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction,
+} from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { burnGlobalStateAddress, fetchBurnLimits } from '../sdk/attestation-client/src/burn-limits';
+
+const { config } = loadConfig();
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const BPF_LOADER_UPGRADEABLE = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+const PROGRAM_DATA = PublicKey.findProgramAddressSync([BURN_PROGRAM.toBuffer()], BPF_LOADER_UPGRADEABLE)[0];
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+const u64 = (value: number | bigint) => new anchor.BN(value.toString()).toArrayLike(Buffer, 'le', 8);
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.solana.rpc, 'confirmed');
+
+    switch (command) {
+        case 'status': {
+            const limits = await fetchBurnLimits(connection, BURN_PROGRAM);
+            if (!limits) {
+                throw new Error('Burn program not initialized');
+            }
+            console.log('🔥 Burn amount limits');
+            console.log(`   Minimum: ${limits.minBurnAmount === 0n ? 'none' : limits.minBurnAmount}`);
+            console.log(`   Maximum: ${limits.maxBurnAmount === 0n ? 'none' : limits.maxBurnAmount}`);
+            break;
+        }
+
+        case 'set': {
+            const wallet = loadKeypair(config, 'user');
+            const min = BigInt(arg('min', '0'));
+            const max = BigInt(arg('max', '0'));
+            if (max !== 0n && min > max) {
+                throw new Error('--min must not exceed --max');
+            }
+            const tx = await sendAndConfirmTransaction(connection, new Transaction().add(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: burnGlobalStateAddress(BURN_PROGRAM), isSigner: false, isWritable: true },
+                    { pubkey: BURN_PROGRAM, isSigner: false, isWritable: false },
+                    { pubkey: PROGRAM_DATA, isSigner: false, isWritable: false },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([discriminator('update_burn_limits'), u64(min), u64(max)]),
+            })), [wallet]);
+            console.log(`✅ Burn limits set (min ${min || 'none'}, max ${max || 'none'}): ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-limits.ts <status|set> [--min <amount>] [--max <amount>]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
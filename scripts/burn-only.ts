@@ -14,6 +14,7 @@ import {
     getAccount,
 } from '@solana/spl-token';
 import bs58 from 'bs58';
+import { burnAmountError, decodeBurnLimits } from '../sdk/attestation-client/src/burn-limits';
 
 const CONFIG = {
     SOLANA_RPC: 'https://api.mainnet-beta.solana.com',
@@ -69,6 +70,11 @@ async function main() {
         throw new Error('Burn program not initialized!');
     }
 
+    const limitError = burnAmountError(decodeBurnLimits(globalStateAccount.data), BigInt(CONFIG.BURN_AMOUNT));
+    if (limitError) {
+        throw new Error(`Burn amount ${CONFIG.BURN_AMOUNT} is ${limitError}`);
+    }
+
     const currentNonce = globalStateAccount.data.readBigUInt64LE(8);
     console.log(`📍 Burn nonce will be: ${currentNonce}\n`);
 
@@ -80,6 +86,27 @@ async function main() {
         CONFIG.BURN_PROGRAM
     );
 
+    // DESTINATION=<x1 pubkey> [MEMO=<text>] burns with burn_xencat_to instead
+    const destination = process.env.DESTINATION ? new PublicKey(process.env.DESTINATION) : null;
+    let data = Buffer.concat([
+        Buffer.from([33, 48, 36, 182, 68, 82, 120, 188]), // burn_xencat discriminator
+        Buffer.from(new anchor.BN(CONFIG.BURN_AMOUNT).toArray('le', 8)),
+    ]);
+    if (destination) {
+        const memo = Buffer.alloc(64);
+        if (process.env.MEMO && Buffer.from(process.env.MEMO).length > 64) {
+            throw new Error('MEMO must fit in 64 bytes');
+        }
+        Buffer.from(process.env.MEMO ?? '').copy(memo);
+        data = Buffer.concat([
+            Buffer.from([137, 11, 254, 46, 222, 116, 88, 40]), // burn_xencat_to discriminator
+            Buffer.from(new anchor.BN(CONFIG.BURN_AMOUNT).toArray('le', 8)),
+            destination.toBuffer(),
+            process.env.MEMO ? Buffer.concat([Buffer.from([1]), memo]) : Buffer.from([0]),
+        ]);
+        console.log(`🎯 X1 destination: ${destination.toBase58()}${process.env.MEMO ? ` (memo "${process.env.MEMO}")` : ''}\n`);
+    }
+
     const burnIx = new TransactionInstruction({
         programId: CONFIG.BURN_PROGRAM,
         keys: [
@@ -91,10 +118,7 @@ async function main() {
             { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
             { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
         ],
-        data: Buffer.concat([
-            Buffer.from([33, 48, 36, 182, 68, 82, 120, 188]), // burn_xencat discriminator
-            Buffer.from(new anchor.BN(CONFIG.BURN_AMOUNT).toArray('le', 8)),
-        ]),
+        data,
     });
 
     const transaction = new Transaction().add(burnIx);
@@ -0,0 +1,136 @@
+/**
+ * BurnRecord Retention (Solana burn program)
+ *
+ * Settled BurnRecords are closed by settle-burn.ts --close. A burner can
+ * also reclaim the rent of an unsettled record once the grace period set by
+ * the upgrade authority has passed since the burn. Every close logs the
+ * record as a BurnRecordFinalized event, which `proof` reads back from the
+ * closing transaction.
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-record-retention.ts status      --nonce 182
+ *   npx ts-node scripts/burn-record-retention.ts reclaim     --nonce 182        (burner key as keys.user)
+ *   npx ts-node scripts/burn-record-retention.ts set-grace   --days 30          (upgrade authority; 0 disables)
+ *   npx ts-node scripts/burn-record-retention.ts proof       --signature <closing tx>
+ */
+
+import 'dotenv/config';
+import {
+    Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction,
+} from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { burnGlobalStateAddress } from '../sdk/attestation-client/src/burn-limits';
+import {
+    burnRecordReclaimableAt, fetchBurnRecordFinalization, fetchRecordGracePeriod,
+} from '../sdk/attestation-client/src/burn-record-retention';
+
+const { config } = loadConfig();
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const BPF_LOADER_UPGRADEABLE = new PublicKey('BPFLoaderUpgradeab1e11111111111111111111111');
+const PROGRAM_DATA = PublicKey.findProgramAddressSync([BURN_PROGRAM.toBuffer()], BPF_LOADER_UPGRADEABLE)[0];
+const GLOBAL_STATE = burnGlobalStateAddress(BURN_PROGRAM);
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+const u64 = (value: number | bigint) => new anchor.BN(value.toString()).toArrayLike(Buffer, 'le', 8);
+const i64 = (value: number) => new anchor.BN(value).toTwos(64).toArrayLike(Buffer, 'le', 8);
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.solana.rpc, 'confirmed');
+    const burnRecord = (nonce: number) =>
+        PublicKey.findProgramAddressSync([Buffer.from('burn_record'), u64(nonce)], BURN_PROGRAM)[0];
+
+    switch (command) {
+        case 'status': {
+            const nonce = parseInt(arg('nonce'));
+            const grace = await fetchRecordGracePeriod(connection, BURN_PROGRAM);
+            if (grace === null) {
+                throw new Error('Burn program not initialized');
+            }
+            console.log(`🧾 Burn record ${nonce}`);
+            console.log(`   Grace period: ${grace === 0 ? 'reclaim disabled' : `${grace / 86_400} days`}`);
+            const record = await connection.getAccountInfo(burnRecord(nonce));
+            if (!record) {
+                console.log('   Closed or never created (use `proof` with the closing transaction)');
+                break;
+            }
+            // BurnRecord: discriminator(8) || user(32) || amount(8) || nonce(8) || timestamp(8 at 56)
+            const burnedAt = Number(record.data.readBigUInt64LE(56));
+            const settled = await connection.getAccountInfo(
+                PublicKey.findProgramAddressSync([Buffer.from('burn_settlement'), u64(nonce)], BURN_PROGRAM)[0]);
+            const reclaimableAt = burnRecordReclaimableAt(burnedAt, grace);
+            console.log(`   Burned at: ${new Date(burnedAt * 1000).toISOString()}`);
+            console.log(`   Settled: ${settled ? 'yes (settle-burn.ts --close)' : 'no'}`);
+            console.log(`   Reclaimable: ${reclaimableAt === null ? 'never' : new Date(reclaimableAt * 1000).toISOString()}`);
+            break;
+        }
+
+        case 'reclaim': {
+            const wallet = loadKeypair(config, 'user');
+            const nonce = parseInt(arg('nonce'));
+            const tx = await sendAndConfirmTransaction(connection, new Transaction().add(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: GLOBAL_STATE, isSigner: false, isWritable: false },
+                    { pubkey: burnRecord(nonce), isSigner: false, isWritable: true },
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                ],
+                data: discriminator('reclaim_burn_record'),
+            })), [wallet]);
+            console.log(`✅ Burn record ${nonce} reclaimed: ${tx}`);
+            console.log(`   Keep this signature: \`proof --signature ${tx}\` shows the archived record`);
+            break;
+        }
+
+        case 'set-grace': {
+            const wallet = loadKeypair(config, 'user');
+            const days = Number(arg('days'));
+            const tx = await sendAndConfirmTransaction(connection, new Transaction().add(new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: wallet.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: GLOBAL_STATE, isSigner: false, isWritable: true },
+                    { pubkey: BURN_PROGRAM, isSigner: false, isWritable: false },
+                    { pubkey: PROGRAM_DATA, isSigner: false, isWritable: false },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([discriminator('update_record_grace_period'), i64(Math.round(days * 86_400))]),
+            })), [wallet]);
+            console.log(`✅ Grace period set to ${days === 0 ? 'disabled' : `${days} days`}: ${tx}`);
+            break;
+        }
+
+        case 'proof': {
+            const record = await fetchBurnRecordFinalization(connection, arg('signature'), BURN_PROGRAM);
+            if (!record) {
+                throw new Error('No BurnRecordFinalized in that transaction');
+            }
+            console.log(`🧾 Burn record ${record.nonce} (${record.settled ? 'settled' : 'reclaimed'}, closed ${new Date(record.closedAt * 1000).toISOString()})`);
+            console.log(`   Burner: ${record.user.toBase58()}`);
+            console.log(`   Attested for: ${record.attestedUser.toBase58()}`);
+            console.log(`   Amount: ${record.amount} (asset ${record.assetId || 'from mint'})`);
+            console.log(`   Burned at: ${new Date(Number(record.timestamp) * 1000).toISOString()}`);
+            console.log(`   Record hash: ${record.recordHash.toString('hex')}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-record-retention.ts <status|reclaim|set-grace|proof> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
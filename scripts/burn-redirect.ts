@@ -0,0 +1,210 @@
+/**
+ * Stranded Burn Recovery (recipient redirect)
+ *
+ * A burn verified for a recipient that can never mint it (e.g. a program
+ * address that cannot own token accounts) strands the funds. Validator
+ * governance can re-point such a burn to a new recipient, who then mints
+ * it with the regular mint_from_burn_v3. Only possible ~30 days after the
+ * burn was verified (config::BURN_REDIRECT_DELAY_SLOTS), while it is
+ * unminted, and with the full threshold.
+ *
+ * Usage:
+ *   npx ts-node scripts/burn-redirect.ts status  --asset 1 --nonce 1234 --user <pubkey>
+ *   npx ts-node scripts/burn-redirect.ts propose --asset 1 --nonce 1234 --user <pubkey> --recipient <pubkey> --reason "case-42" [--out redirect.json]
+ *   npx ts-node scripts/burn-redirect.ts sign    --file redirect.json   (validator key)
+ *   npx ts-node scripts/burn-redirect.ts submit  --file redirect.json
+ *
+ * `propose` binds the current validator set version and BridgeConfig
+ * nonce, so collect signatures and submit promptly. The reason is stored
+ * as sha256(--reason); keep the recovery request it refers to, with the
+ * evidence that the new recipient belongs to the burner.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchRegisteredAssets } from '../sdk/attestation-client/src/asset-registry';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAMS: Record<number, PublicKey> = {
+    1: new PublicKey(config.programs.xencat_mint),
+    2: new PublicKey(config.programs.dgn_mint),
+};
+
+/** Must match config::BURN_REDIRECT_DELAY_SLOTS */
+const BURN_REDIRECT_DELAY_SLOTS = 6_480_000;
+
+const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda(Buffer.from('x1_validator_set_v2'));
+const BRIDGE_CONFIG = pda(Buffer.from('bridge_config'));
+
+interface RedirectProposal {
+    asset_id: number;
+    burn_nonce: number;
+    user: string;
+    recipient: string;
+    /** hex sha256 of the recovery request reference */
+    reason: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_redirect_message on-chain */
+function redirectMessage(p: Omit<RedirectProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('REDIRECT_BURN'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        Buffer.from([p.asset_id]),
+        u64(p.burn_nonce),
+        new PublicKey(p.user).toBuffer(),
+        new PublicKey(p.recipient).toBuffer(),
+        Buffer.from(p.reason, 'hex'),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function verifiedBurnAddress(assetId: number, user: PublicKey, nonce: number): PublicKey {
+    return pda(Buffer.from('verified_burn_v3'), Buffer.from([assetId]), user.toBuffer(), u64(nonce));
+}
+
+async function processedBurnAddress(connection: Connection, assetId: number, user: PublicKey, nonce: number): Promise<PublicKey> {
+    // Governance-registered assets have their own mint program
+    const registered = (await fetchRegisteredAssets(connection, LIGHT_CLIENT_PROGRAM))?.find(a => a.assetId === assetId);
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('processed_burn_v3'), Buffer.from([assetId]), u64(nonce), user.toBuffer()],
+        registered?.mintProgram ?? MINT_PROGRAMS[assetId]
+    )[0];
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const assetId = Number(arg('asset'));
+            const burnNonce = Number(arg('nonce'));
+            const user = new PublicKey(arg('user'));
+            const burn = await program.account.verifiedBurnV3.fetchNullable(verifiedBurnAddress(assetId, user, burnNonce));
+            if (!burn) {
+                console.log(`↪️  No verified burn ${burnNonce} of asset ${assetId} for ${user.toBase58()}`);
+                break;
+            }
+            const minted = (await connection.getAccountInfo(await processedBurnAddress(connection, assetId, user, burnNonce))) !== null;
+            const allowedSlot = burn.verifiedSlot.toNumber() + BURN_REDIRECT_DELAY_SLOTS;
+            const slot = await connection.getSlot();
+            console.log(`↪️  Burn ${burnNonce} of asset ${assetId}: ${burn.amount.toString()} base units for ${user.toBase58()}`);
+            console.log(`   ${minted ? 'Minted' : burn.processed ? 'Processed (frozen, held, converted or redirected)' : 'Not minted'}`);
+            console.log(`   Redirect ${slot >= allowedSlot ? 'possible' : `possible from slot ${allowedSlot} (now ${slot})`}`);
+            break;
+        }
+
+        case 'propose': {
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                asset_id: Number(arg('asset')),
+                burn_nonce: Number(arg('nonce')),
+                user: new PublicKey(arg('user')).toBase58(),
+                recipient: new PublicKey(arg('recipient')).toBase58(),
+                reason: crypto.createHash('sha256').update(arg('reason')).digest('hex'),
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: RedirectProposal = { ...base, message: redirectMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'redirect.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Redirect proposal for burn ${base.burn_nonce} written to ${out}`);
+            console.log(`   ${base.user} -> ${base.recipient}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: RedirectProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = redirectMessage(proposal);
+            console.log(`   Burn ${proposal.burn_nonce} of asset ${proposal.asset_id}: ${proposal.user} -> ${proposal.recipient}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed redirect of burn ${proposal.burn_nonce}; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: RedirectProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = redirectMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            const user = new PublicKey(proposal.user);
+            const recipient = new PublicKey(proposal.recipient);
+            console.log(`📤 Submitting redirect with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .redirectVerifiedBurn(proposal.asset_id, new anchor.BN(proposal.burn_nonce), user, recipient, {
+                    reason: Array.from(Buffer.from(proposal.reason, 'hex')),
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    sourceVerifiedBurn: verifiedBurnAddress(proposal.asset_id, user, proposal.burn_nonce),
+                    processedBurn: await processedBurnAddress(connection, proposal.asset_id, user, proposal.burn_nonce),
+                    assetRegistry: pda(Buffer.from('asset_registry')),
+                    targetVerifiedBurn: verifiedBurnAddress(proposal.asset_id, recipient, proposal.burn_nonce),
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Burn redirected: ${tx}`);
+            console.log(`   ${recipient.toBase58()} can now mint it with mint_from_burn_v3`);
+            break;
+        }
+
+        default:
+            console.error('Usage: burn-redirect.ts <status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,241 @@
+/**
+ * Claim-on-Behalf Relayer
+ *
+ * Completes a designated-recipient burn (burn_xencat_for_recipient) for a
+ * recipient that holds no XNT on X1: the relayer key (keys.user) pays rent
+ * and validator fees, and keeps the relayer fee the burner chose on Solana.
+ *
+ * Usage:
+ *   BURN_NONCE=210 ASSET_ID=1 RECIPIENT=<x1 pubkey> EXPECTED_AMOUNT=5000000 npx ts-node scripts/claim-for-recipient.ts
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { getAssociatedTokenAddress, getOrCreateAssociatedTokenAccount } from '@solana/spl-token';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { feePoolAddress, validatorFeeAccounts } from '../sdk/attestation-client/src/fee-vault';
+import { feeConfigAddress } from '../sdk/attestation-client/src/fee-config';
+import { FEATURE_GATES, disabledGateNames, featureGateAddress, fetchDisabledGates } from '../sdk/attestation-client/src/feature-gate';
+import { bridgeDomainAddress } from '../sdk/attestation-client/src/bridge-domain';
+import { x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+import { assetRegistryAddress } from '../sdk/attestation-client/src/asset-registry';
+import { burnAgeLimitAddress } from '../sdk/attestation-client/src/burn-age-limit';
+import { betaAllowlistAddress } from '../sdk/attestation-client/src/beta-allowlist';
+import { challengeRequestAddress } from '../sdk/attestation-client/src/attestation-challenge';
+import { devModeConfigAddress } from '../sdk/attestation-client/src/dev-mode';
+import { cosignPolicyAddress } from '../sdk/attestation-client/src/cosign-policy';
+import { rateLimitAddress } from '../sdk/attestation-client/src/rate-limit';
+import { mintPauseAddress } from '../sdk/attestation-client/src/pause';
+import { fetchRelayerClaim, relayerClaimAddress, relayerClaimData } from '../sdk/attestation-client/src/relayer-claim';
+import { BridgeErrorKind, loadBridgeErrorTables, parseBridgeError } from '../sdk/attestation-client/src/program-errors';
+import { fetchAttestationOrdering, orderAttestations } from '../sdk/attestation-client/src/attestation-ordering';
+
+const { config } = loadConfig();
+const THRESHOLD = config.attestation.threshold;
+const BURN_NONCE = parseInt(process.env.BURN_NONCE || '0');
+const ASSET_ID = parseInt(process.env.ASSET_ID || '0');
+const EXPECTED_AMOUNT = parseInt(process.env.EXPECTED_AMOUNT || '0');
+
+if (!BURN_NONCE || !ASSET_ID || !EXPECTED_AMOUNT || !process.env.RECIPIENT) {
+    console.error('❌ Required: BURN_NONCE, ASSET_ID (1 XENCAT, 2 DGN), RECIPIENT, EXPECTED_AMOUNT');
+    process.exit(1);
+}
+const RECIPIENT = new PublicKey(process.env.RECIPIENT);
+
+const ASSETS = {
+    1: { name: 'XENCAT', mintProgram: new PublicKey(config.programs.xencat_mint), mint: new PublicKey(config.mints.xencat_x1), mintStateSeed: 'mint_state_v2', idl: 'xencat_mint_x1' },
+    2: { name: 'DGN', mintProgram: new PublicKey(config.programs.dgn_mint), mint: new PublicKey(config.mints.dgn_x1), mintStateSeed: 'dgn_mint_state', idl: 'dgn_mint_x1' },
+} as const;
+const asset = ASSETS[ASSET_ID as keyof typeof ASSETS];
+if (!asset) {
+    console.error(`❌ Invalid ASSET_ID: ${ASSET_ID}`);
+    process.exit(1);
+}
+
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const VALIDATORS = config.attestation.validators.map(v => ({ url: v.url, pubkey: new PublicKey(v.pubkey) }));
+
+const nonceBytes = () => Buffer.from(new BigUint64Array([BigInt(BURN_NONCE)]).buffer);
+const pda = (seeds: Buffer[], program = LIGHT_CLIENT_PROGRAM) => PublicKey.findProgramAddressSync(seeds, program)[0];
+
+async function main() {
+    console.log('🤝 Claim-on-Behalf Relayer');
+    console.log('='.repeat(60));
+    console.log(`Asset: ${asset.name} (asset_id=${ASSET_ID}), burn nonce ${BURN_NONCE}`);
+    console.log(`Recipient: ${RECIPIENT.toBase58()}\n`);
+
+    const relayer = loadKeypair(config, 'user');
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(relayer), { commitment: 'confirmed' });
+    const lightClientProgram: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider
+    );
+    const mintProgram: any = new anchor.Program(
+        JSON.parse(fs.readFileSync(`target/idl/${asset.idl}.json`, 'utf-8')), asset.mintProgram, provider
+    );
+
+    const disabledGates = await fetchDisabledGates(connection, LIGHT_CLIENT_PROGRAM);
+    if (disabledGates & (FEATURE_GATES.ATTEST_V3 | FEATURE_GATES.MINT_V3 | FEATURE_GATES.CLAIM_ON_BEHALF)) {
+        console.log(`⛔ Disabled by validator governance: ${disabledGateNames(disabledGates).join(', ')}`);
+        process.exit(1);
+    }
+
+    const validatorSetPda = pda([Buffer.from('x1_validator_set_v2')]);
+    const latencyStatsPda = pda([Buffer.from('validator_latency_stats')]);
+    const verifiedBurnPda = pda([Buffer.from('verified_burn_v3'), Buffer.from([ASSET_ID]), RECIPIENT.toBuffer(), nonceBytes()]);
+    const relayerClaimPda = relayerClaimAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, RECIPIENT, BURN_NONCE);
+
+    // Step 1: verify the burn and record this relayer's claim (skipped if already done)
+    let claim = await fetchRelayerClaim(connection, LIGHT_CLIENT_PROGRAM, ASSET_ID, RECIPIENT, BURN_NONCE);
+    if (!claim) {
+        console.log('📥 Collecting attestations from validators...');
+        const attestations: any[] = [];
+        for (const validator of VALIDATORS) {
+            try {
+                const response = await fetch(`${validator.url}/attest-burn`, {
+                    method: 'POST',
+                    headers: { 'Content-Type': 'application/json', 'X-Validator-Key': validator.pubkey.toBase58() },
+                    body: JSON.stringify({
+                        burn_nonce: BURN_NONCE,
+                        user: RECIPIENT.toBase58(),
+                        expected_amount: EXPECTED_AMOUNT,
+                        validator_set_version: 1,
+                    }),
+                });
+                if (!response.ok) {
+                    console.log(`  ⚠️  ${validator.url}: HTTP ${response.status}`);
+                    continue;
+                }
+                const attestation: any = await response.json();
+                if (attestations.length > 0 && attestations[0].solana_burn_slot !== attestation.solana_burn_slot) {
+                    console.log(`  ⚠️  ${validator.url}: burn slot disagrees with first attestation, skipping`);
+                    continue;
+                }
+                attestations.push(attestation);
+                console.log(`  ✅ ${validator.url}`);
+            } catch (err: any) {
+                console.log(`  ❌ ${validator.url}: ${err.message}`);
+            }
+        }
+        if (attestations.length < THRESHOLD) {
+            throw new Error(`Insufficient attestations: got ${attestations.length}, need ${THRESHOLD}`);
+        }
+
+        const claimData = relayerClaimData(attestations);
+        if (!claimData || claimData.signatures.length < THRESHOLD) {
+            throw new Error('Burn has no attested relayer claim (not made with burn_xencat_for_recipient?)');
+        }
+        console.log(`   Relayer fee: ${claimData.relayerFee} (${claimData.signatures.length} claim signatures)`);
+
+        const first = attestations[0];
+        const { validators } = await fetchAttestationOrdering(connection, LIGHT_CLIENT_PROGRAM);
+        const tx = await lightClientProgram.methods
+            .submitBurnAttestationForRecipient(
+                ASSET_ID,
+                new anchor.BN(BURN_NONCE),
+                {
+                    assetId: ASSET_ID,
+                    burnNonce: new anchor.BN(BURN_NONCE),
+                    user: RECIPIENT,
+                    amount: new anchor.BN(EXPECTED_AMOUNT),
+                    validatorSetVersion: new anchor.BN(1),
+                    solanaBurnSlot: new anchor.BN(first.solana_burn_slot),
+                    solanaBurnTimestamp: new anchor.BN(first.solana_burn_timestamp),
+                    confirmationDepth: first.confirmation_depth,
+                    solanaConfirmedSlot: new anchor.BN(first.solana_confirmed_slot),
+                    // Ascending validator set order, in case the ordering rule is on
+                    attestations: orderAttestations(attestations.slice(0, THRESHOLD), validators, a => new PublicKey(a.validator_pubkey)).map(a => ({
+                        validatorPubkey: new PublicKey(a.validator_pubkey),
+                        signature: a.signature,
+                        timestamp: new anchor.BN(a.timestamp),
+                    })),
+                },
+                { relayerFee: new anchor.BN(claimData.relayerFee.toString()), signatures: claimData.signatures.slice(0, THRESHOLD) }
+            )
+            .accounts({
+                relayer: relayer.publicKey,
+                user: RECIPIENT,
+                validatorSet: validatorSetPda,
+                verifiedBurn: verifiedBurnPda,
+                relayerClaim: relayerClaimPda,
+                latencyStats: latencyStatsPda,
+                featureGate: featureGateAddress(LIGHT_CLIENT_PROGRAM),
+                bridgeDomain: bridgeDomainAddress(LIGHT_CLIENT_PROGRAM),
+                x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+                burnAgeLimit: burnAgeLimitAddress(LIGHT_CLIENT_PROGRAM),
+                betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID),
+                challengeRequest: challengeRequestAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, RECIPIENT, BURN_NONCE),
+                devModeConfig: devModeConfigAddress(LIGHT_CLIENT_PROGRAM),
+                systemProgram: anchor.web3.SystemProgram.programId,
+            })
+            .rpc();
+        console.log(`✅ Burn verified for the recipient: ${tx}\n`);
+        claim = await fetchRelayerClaim(connection, LIGHT_CLIENT_PROGRAM, ASSET_ID, RECIPIENT, BURN_NONCE);
+    }
+    if (!claim!.relayer.equals(relayer.publicKey)) {
+        console.log(`⛔ Claimed by another relayer (${claim!.relayer.toBase58()})`);
+        process.exit(1);
+    }
+
+    // Step 2: mint to the recipient and the relayer fee to ourselves (SPL Token or Token-2022 mint)
+    const tokenProgram = (await connection.getAccountInfo(asset.mint))!.owner;
+    const recipientTokenAccount = await getAssociatedTokenAddress(asset.mint, RECIPIENT, false, tokenProgram);
+    await getOrCreateAssociatedTokenAccount(connection, relayer, asset.mint, RECIPIENT, false, undefined, undefined, tokenProgram);
+    const relayerTokenAccount = (await getOrCreateAssociatedTokenAccount(
+        connection, relayer, asset.mint, relayer.publicKey, false, undefined, undefined, tokenProgram,
+    )).address;
+
+    const accounts: any = {
+        mintState: pda([Buffer.from(asset.mintStateSeed)], asset.mintProgram),
+        processedBurn: pda([Buffer.from('processed_burn_v3'), Buffer.from([ASSET_ID]), nonceBytes(), RECIPIENT.toBuffer()], asset.mintProgram),
+        recipientTokenAccount,
+        user: RECIPIENT,
+        relayer: relayer.publicKey,
+        relayerTokenAccount,
+        validatorSet: validatorSetPda,
+        verifiedBurn: verifiedBurnPda,
+        relayerClaim: relayerClaimPda,
+        featureGate: featureGateAddress(LIGHT_CLIENT_PROGRAM),
+        latencyStats: latencyStatsPda,
+        cosignPolicy: cosignPolicyAddress(asset.mintProgram),
+        rateLimit: rateLimitAddress(asset.mintProgram),
+        mintPause: mintPauseAddress(asset.mintProgram),
+        feePool: feePoolAddress(asset.mintProgram),
+        feeConfig: feeConfigAddress(asset.mintProgram),
+        assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+        tokenProgram,
+        systemProgram: anchor.web3.SystemProgram.programId,
+    };
+    if (ASSET_ID === 1) {
+        accounts.xencatMint = asset.mint;
+    } else {
+        accounts.dgnMint = asset.mint;
+    }
+
+    const tx = await mintProgram.methods
+        .mintForRecipient(new anchor.BN(BURN_NONCE), ASSET_ID)
+        .accounts(accounts)
+        .remainingAccounts(await validatorFeeAccounts(connection, asset.mintProgram, VALIDATORS.map(v => v.pubkey)))
+        .rpc();
+
+    console.log(`✅ Minted for recipient: ${tx}`);
+    console.log(`   Recipient receives ${(EXPECTED_AMOUNT - Number(claim!.relayerFee)) / 1_000_000} ${asset.name}`);
+    console.log(`   Relayer fee: ${Number(claim!.relayerFee) / 1_000_000} ${asset.name}`);
+}
+
+main()
+    .then(() => process.exit(0))
+    .catch((err) => {
+        const error = parseBridgeError(err, {
+            programs: loadBridgeErrorTables({
+                lightClient: LIGHT_CLIENT_PROGRAM,
+                xencatMint: new PublicKey(config.programs.xencat_mint),
+                dgnMint: new PublicKey(config.programs.dgn_mint),
+            }),
+        });
+        console.error('\n❌ Claim failed:', error.kind === BridgeErrorKind.Unknown ? err : error.message);
+        process.exit(1);
+    });
@@ -0,0 +1,112 @@
+/**
+ * Cost Report (per-transfer and per-integrator chargeback)
+ *
+ * Walks a calendar month of X1 bridge transactions (light client and both
+ * mint programs) and attributes rent, base and priority fees and validator
+ * fees to each transfer and to the integrator whose relayer key paid. See
+ * sdk/attestation-client/src/cost-accounting.ts for the attribution rules.
+ *
+ * Usage:
+ *   npx ts-node scripts/cost-report.ts --month 2026-09 [--out-dir reports]
+ *
+ * Writes <out-dir>/costs-<month>.csv (one row per transaction and
+ * transfer) and <out-dir>/costs-<month>.json (totals per integrator).
+ * Integrators come from [[accounting.integrators]] in xencat.toml.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import path from 'path';
+import { loadConfig } from '../sdk/bridge-config/src';
+import { feeVaultAddress } from '../sdk/attestation-client/src/fee-vault';
+import {
+    CostAccountingContext,
+    TransferCost,
+    costsToCsv,
+    integratorTotals,
+    monthRange,
+    signaturesInRange,
+    transactionCosts,
+} from '../sdk/attestation-client/src/cost-accounting';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAMS = [new PublicKey(config.programs.xencat_mint), new PublicKey(config.programs.dgn_mint)];
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+const lamports = (value: bigint) => `${(Number(value) / 1e9).toFixed(6)} XNT`;
+
+async function main() {
+    const month = arg('month');
+    const outDir = arg('out-dir', config.accounting.report_dir);
+    const { start, end } = monthRange(month);
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const validatorSetPda = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, new anchor.AnchorProvider(connection, {} as any, {}));
+    const validatorSet = await program.account.x1ValidatorSet.fetch(validatorSetPda);
+
+    // Validators of the current set (fee recipients of earlier sets are missed)
+    const validatorFeeAccounts = new Set<string>();
+    for (const validator of validatorSet.validators as PublicKey[]) {
+        validatorFeeAccounts.add(validator.toBase58());
+        for (const mintProgram of MINT_PROGRAMS) {
+            validatorFeeAccounts.add(feeVaultAddress(mintProgram, validator).toBase58());
+        }
+    }
+    const context: CostAccountingContext = {
+        lightClientProgramId: LIGHT_CLIENT_PROGRAM,
+        lightClientCoder: program.coder,
+        mintProgramIds: MINT_PROGRAMS,
+        validatorFeeAccounts,
+        rentSponsorPool: PublicKey.findProgramAddressSync([Buffer.from('rent_sponsor_pool')], LIGHT_CLIENT_PROGRAM)[0],
+        integrators: new Map(config.accounting.integrators.flatMap(i => i.payers.map(payer => [payer, i.name] as [string, string]))),
+    };
+
+    console.log(`📒 Bridge costs for ${month}`);
+    const signatures = new Set<string>();
+    for (const programId of [LIGHT_CLIENT_PROGRAM, ...MINT_PROGRAMS]) {
+        for (const signature of await signaturesInRange(connection, programId, start, end)) {
+            signatures.add(signature);
+        }
+    }
+    console.log(`   ${signatures.size} transactions`);
+
+    const costs: TransferCost[] = [];
+    for (const signature of signatures) {
+        const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'confirmed' });
+        if (tx) {
+            costs.push(...transactionCosts(tx, context));
+        }
+    }
+    costs.sort((a, b) => a.blockTime - b.blockTime);
+
+    const totals = integratorTotals(costs);
+    fs.mkdirSync(outDir, { recursive: true });
+    const csvFile = path.join(outDir, `costs-${month}.csv`);
+    const jsonFile = path.join(outDir, `costs-${month}.json`);
+    fs.writeFileSync(csvFile, costsToCsv(costs));
+    fs.writeFileSync(jsonFile, JSON.stringify({ month, integrators: totals }, (_, v) => (typeof v === 'bigint' ? v.toString() : v), 2));
+
+    for (const t of totals) {
+        console.log(`   ${t.integrator}: ${t.transfers} transfers, ${t.transactions} transactions, ${lamports(t.total)}`);
+        console.log(`      rent ${lamports(t.rent - t.sponsoredRent)} (+${lamports(t.sponsoredRent)} sponsored), base ${lamports(t.baseFee)}, priority ${lamports(t.priorityFee)}, validators ${lamports(t.validatorFees)}`);
+    }
+    console.log(`✅ Wrote ${csvFile} and ${jsonFile}`);
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
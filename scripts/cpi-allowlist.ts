@@ -0,0 +1,177 @@
+/**
+ * Mint CPI Allowlist (programs mint_and_call may invoke)
+ *
+ * mint_and_call mints a verified burn and then calls a downstream program
+ * (staking, LP, ...) in the same transaction. Only programs on the mint
+ * program's allowlist can be called. Anyone can create the empty list;
+ * validators replace it by threshold approval.
+ *
+ * Usage:
+ *   npx ts-node scripts/cpi-allowlist.ts init    --asset 1
+ *   npx ts-node scripts/cpi-allowlist.ts status  --asset 1
+ *   npx ts-node scripts/cpi-allowlist.ts propose --asset 1 --programs <id,id,...> [--out cpi-allowlist.json]   ("" = empty)
+ *   npx ts-node scripts/cpi-allowlist.ts sign    --file cpi-allowlist.json   (validator key)
+ *   npx ts-node scripts/cpi-allowlist.ts submit  --file cpi-allowlist.json
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { cpiAllowlistAddress, cpiAllowlistMessage, fetchCpiAllowlist } from '../sdk/attestation-client/src/cpi-allowlist';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const VALIDATOR_SET = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+
+const ASSETS: Record<number, { program: PublicKey; idl: string }> = {
+    1: { program: new PublicKey(config.programs.xencat_mint), idl: 'target/idl/xencat_mint_x1.json' },
+    2: { program: new PublicKey(config.programs.dgn_mint), idl: 'target/idl/dgn_mint_x1.json' },
+};
+
+interface CpiAllowlistProposal {
+    asset_id: number;
+    /** Complete new list (base58) */
+    programs: string[];
+    validator_set_version: number;
+    nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function proposalMessage(p: Omit<CpiAllowlistProposal, 'message' | 'approvals'>): Buffer {
+    return cpiAllowlistMessage(ASSETS[p.asset_id].program, p.validator_set_version, p.nonce, p.programs.map(id => new PublicKey(id)), proposalX1GenesisHash(p));
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function asset(assetId: number) {
+    if (!ASSETS[assetId]) {
+        throw new Error(`Unknown asset ${assetId}`);
+    }
+    return ASSETS[assetId];
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const lightClient: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider);
+    const mintProgram = (assetId: number): any =>
+        new anchor.Program(JSON.parse(fs.readFileSync(asset(assetId).idl, 'utf-8')), asset(assetId).program, provider);
+
+    switch (command) {
+        case 'init': {
+            const assetId = parseInt(arg('asset'));
+            const tx = await mintProgram(assetId).methods
+                .initializeCpiAllowlist()
+                .accounts({
+                    cpiAllowlist: cpiAllowlistAddress(asset(assetId).program),
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ CPI allowlist created for asset ${assetId} (empty): ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const assetId = parseInt(arg('asset'));
+            const allowlist = await fetchCpiAllowlist(connection, asset(assetId).program);
+            console.log(`🔗 CPI allowlist of asset ${assetId} (${cpiAllowlistAddress(asset(assetId).program).toBase58()})`);
+            if (!allowlist) {
+                console.log('   Not initialized: mint_and_call unavailable');
+                break;
+            }
+            if (allowlist.programs.length === 0) {
+                console.log('   Empty: no downstream calls');
+            }
+            allowlist.programs.forEach(p => console.log(`   ${p.toBase58()}`));
+            break;
+        }
+
+        case 'propose': {
+            const assetId = parseInt(arg('asset'));
+            const allowlist = await fetchCpiAllowlist(connection, asset(assetId).program);
+            if (!allowlist) {
+                throw new Error(`Asset ${assetId} has no CPI allowlist: run init first`);
+            }
+            const set = await lightClient.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const base = {
+                asset_id: assetId,
+                programs: arg('programs').split(',').filter(id => id.trim()).map(id => new PublicKey(id.trim()).toBase58()),
+                validator_set_version: set.version.toNumber(),
+                nonce: Number(allowlist.nonce),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: CpiAllowlistProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'cpi-allowlist.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ CPI allowlist proposal written to ${out}`);
+            console.log(`   Programs: ${allowlist.programs.length} -> ${proposal.programs.length}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: CpiAllowlistProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Asset ${proposal.asset_id} allowlist: ${proposal.programs.join(', ') || '(empty)'}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed CPI allowlist proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: CpiAllowlistProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting CPI allowlist with ${approverSignatures.length} valid approvals`);
+
+            const tx = await mintProgram(proposal.asset_id).methods
+                .setCpiAllowlist({ programs: proposal.programs.map(id => new PublicKey(id)), approverSignatures })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    cpiAllowlist: cpiAllowlistAddress(asset(proposal.asset_id).program),
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ CPI allowlist of asset ${proposal.asset_id} updated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: cpi-allowlist.ts <init|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,256 @@
+/**
+ * Partial-Set Degraded Mode
+ *
+ * When fewer than `threshold` validators are live the bridge would halt.
+ * With degraded mode configured for an asset, burns up to
+ * max_transfer_amount (and daily_cap per UTC day) are accepted with
+ * `quorum` attestations instead, but held for delay_seconds before they
+ * can be minted, giving the live guardians time to freeze anything odd
+ * (scripts/burn-freeze.ts). Configure it ahead of an outage: changes need
+ * the full validator threshold.
+ *
+ * Usage:
+ *   npx ts-node scripts/degraded-mode.ts init    --asset 1
+ *   npx ts-node scripts/degraded-mode.ts status  --asset 1
+ *   npx ts-node scripts/degraded-mode.ts propose --asset 1 --quorum 2 --max 1000000000 --daily 10000000000 [--delay 21600] [--disable] [--out degraded-mode.json]
+ *   npx ts-node scripts/degraded-mode.ts sign    --file degraded-mode.json   (validator key)
+ *   npx ts-node scripts/degraded-mode.ts submit  --file degraded-mode.json
+ *   npx ts-node scripts/degraded-mode.ts release --asset 1 --user <pubkey> --nonce 182
+ *
+ * `propose` binds the current validator set version and BridgeConfig
+ * nonce, so collect signatures and submit promptly. Amounts are token
+ * base units (6 decimals).
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    degradedBurnAddress,
+    degradedModeAddress,
+    degradedRemainingToday,
+    fetchDegradedBurn,
+    fetchDegradedMode,
+} from '../sdk/attestation-client/src/degraded-mode';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAMS: Record<number, PublicKey> = {
+    1: new PublicKey(config.programs.xencat_mint),
+    2: new PublicKey(config.programs.dgn_mint),
+};
+
+const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda(Buffer.from('x1_validator_set_v2'));
+const BRIDGE_CONFIG = pda(Buffer.from('bridge_config'));
+
+/** Must match config::MIN_DEGRADED_DELAY_SECONDS on-chain */
+const MIN_DELAY_SECONDS = 21_600;
+
+interface DegradedModeProposal {
+    asset_id: number;
+    enabled: boolean;
+    quorum: number;
+    max_transfer_amount: number;
+    daily_cap: number;
+    delay_seconds: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function i64(value: number): Buffer {
+    return Buffer.from(new BigInt64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_degraded_mode_message on-chain */
+function degradedModeMessage(p: Omit<DegradedModeProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('CONFIGURE_DEGRADED_MODE'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        Buffer.from([p.asset_id, p.enabled ? 1 : 0, p.quorum]),
+        u64(p.max_transfer_amount),
+        u64(p.daily_cap),
+        i64(p.delay_seconds),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const assetId = Number(arg('asset'));
+            const tx = await program.methods.initializeDegradedMode(assetId)
+                .accounts({
+                    degradedMode: degradedModeAddress(LIGHT_CLIENT_PROGRAM, assetId),
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Degraded mode created for asset ${assetId} (disabled until governance configures it): ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const assetId = Number(arg('asset'));
+            const mode = await fetchDegradedMode(connection, LIGHT_CLIENT_PROGRAM, assetId);
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            console.log(`🩹 Degraded mode for asset ${assetId}`);
+            if (!mode) {
+                console.log('   Not initialized (bridge halts below threshold)');
+                break;
+            }
+            const now = Math.floor(Date.now() / 1000);
+            console.log(`   Enabled:      ${mode.enabled}`);
+            console.log(`   Quorum:       ${mode.quorum} (threshold ${set.threshold})`);
+            console.log(`   Per transfer: ${mode.maxTransferAmount.toString()}`);
+            console.log(`   Daily cap:    ${mode.dailyCap.toString()} (${degradedRemainingToday(mode, now).toString()} left today)`);
+            console.log(`   Delay:        ${mode.delaySeconds}s`);
+            console.log(`   Burns:        ${mode.totalBurns}`);
+            break;
+        }
+
+        case 'propose': {
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const enabled = !process.argv.includes('--disable');
+            const base = {
+                asset_id: Number(arg('asset')),
+                enabled,
+                quorum: enabled ? Number(arg('quorum')) : 0,
+                max_transfer_amount: enabled ? Number(arg('max')) : 0,
+                daily_cap: enabled ? Number(arg('daily')) : 0,
+                delay_seconds: enabled ? Number(arg('delay', String(MIN_DELAY_SECONDS))) : 0,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            if (enabled && (base.quorum < 2 || base.quorum >= set.threshold)) {
+                throw new Error(`Quorum must be at least 2 and below the threshold (${set.threshold})`);
+            }
+            if (enabled && base.delay_seconds < MIN_DELAY_SECONDS) {
+                throw new Error(`Delay must be at least ${MIN_DELAY_SECONDS}s`);
+            }
+            const proposal: DegradedModeProposal = { ...base, message: degradedModeMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'degraded-mode.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Degraded mode proposal written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: DegradedModeProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = degradedModeMessage(proposal);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed degraded mode proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: DegradedModeProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = degradedModeMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting degraded mode update with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .configureDegradedMode(proposal.asset_id, {
+                    enabled: proposal.enabled,
+                    quorum: proposal.quorum,
+                    maxTransferAmount: new anchor.BN(proposal.max_transfer_amount),
+                    dailyCap: new anchor.BN(proposal.daily_cap),
+                    delaySeconds: new anchor.BN(proposal.delay_seconds),
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    degradedMode: degradedModeAddress(LIGHT_CLIENT_PROGRAM, proposal.asset_id),
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Degraded mode updated: ${tx}`);
+            break;
+        }
+
+        case 'release': {
+            const assetId = Number(arg('asset'));
+            const user = new PublicKey(arg('user'));
+            const nonce = Number(arg('nonce'));
+            const hold = await fetchDegradedBurn(connection, LIGHT_CLIENT_PROGRAM, assetId, user, nonce);
+            if (!hold) {
+                console.log(`✅ Burn ${nonce} is not held in degraded mode`);
+                break;
+            }
+            const wait = hold.releaseAt - Math.floor(Date.now() / 1000);
+            if (wait > 0) {
+                console.log(`⏳ Burn ${nonce} is held for another ${wait}s`);
+                process.exit(1);
+            }
+            const seeds = [Buffer.from([assetId]), user.toBuffer(), u64(nonce)];
+            const tx = await program.methods.releaseDegradedBurn()
+                .accounts({
+                    degradedBurn: degradedBurnAddress(LIGHT_CLIENT_PROGRAM, assetId, user, nonce),
+                    verifiedBurn: pda(Buffer.from('verified_burn_v3'), ...seeds),
+                    burnFreeze: pda(Buffer.from('burn_freeze'), ...seeds),
+                    processedBurn: PublicKey.findProgramAddressSync(
+                        [Buffer.from('processed_burn_v3'), Buffer.from([assetId]), u64(nonce), user.toBuffer()],
+                        MINT_PROGRAMS[assetId]
+                    )[0],
+                    user,
+                })
+                .rpc();
+            console.log(`✅ Burn ${nonce} released for minting: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: degraded-mode.ts <init|status|propose|sign|submit|release> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
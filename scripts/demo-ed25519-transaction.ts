@@ -57,6 +57,7 @@ async function demonstrateEd25519Transaction() {
     // Mock block data
     const blockHash = new Uint8Array(32).fill(0xAB); // Mock block hash
     const slot = BigInt(250000000); // Mock slot
+    const stateRoot = new Uint8Array(32).fill(0xCD); // Mock state root
 
     console.log('Block Information:');
     console.log(`  Slot: ${slot}`);
@@ -70,15 +71,15 @@ async function demonstrateEd25519Transaction() {
     console.log('🔐 STEP 2: Create Vote Message');
     console.log('━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n');
 
-    const voteMessage = createVoteMessage(blockHash, slot);
+    const voteMessage = createVoteMessage(blockHash, slot, stateRoot);
 
     console.log('Vote Message Construction:');
-    console.log(`  1. Concatenate: block_hash (32 bytes) + slot (8 bytes)`);
-    console.log(`  2. SHA256 hash the result`);
+    console.log(`  1. Concatenate: block_hash (32 bytes) + slot (8 bytes) + state_root (32 bytes)`);
+    console.log(`  2. Keccak256 hash the result`);
     console.log(`  3. Result: ${voteMessage.toString('hex').slice(0, 32)}...\n`);
 
     console.log('This message would be signed by each validator:');
-    console.log('  vote_message = SHA256(block_hash || slot)\n');
+    console.log('  vote_message = Keccak256(block_hash || slot || state_root)\n');
 
     // ========================================================================
     // STEP 3: MOCK SIGNATURES
@@ -121,7 +122,8 @@ async function demonstrateEd25519Transaction() {
     const ed25519Instructions = createValidatorEd25519Instructions(
         validatorVotes,
         blockHash,
-        slot
+        slot,
+        stateRoot
     );
 
     console.log(`✅ Created ${ed25519Instructions.length} Ed25519Program instructions\n`);
@@ -0,0 +1,266 @@
+/**
+ * Deposit Notifier (integrator webhooks)
+ *
+ * Recipients such as exchanges register the SHA-256 of a webhook URL in
+ * their DepositWebhook account on X1. The notifier watches both mint
+ * programs' processed_burn_v3 accounts for mints to those recipients and
+ * POSTs one notification per mint. It delivers only to configured URLs
+ * ([[deposits.webhooks]]) whose hash matches the on-chain registration.
+ *
+ * Usage:
+ *   npx ts-node scripts/deposit-notifier.ts register --url <url> [--assets 1,2] [--min-amount 0]   (recipient key)
+ *   npx ts-node scripts/deposit-notifier.ts update   --url <url> [--assets 1,2] [--min-amount 0]
+ *   npx ts-node scripts/deposit-notifier.ts close
+ *   npx ts-node scripts/deposit-notifier.ts status   [--recipient <pubkey>]
+ *   npx ts-node scripts/deposit-notifier.ts run
+ *
+ * Notification body (JSON):
+ *   { event: "deposit", recipient, asset_id, asset, burn_nonce, amount, processed_at, processed_burn }
+ * With secret_env set, X-Xencat-Signature is the hex HMAC-SHA256 of the body.
+ *
+ * Deliveries are in processed_at order per recipient and retried on the
+ * next poll until the endpoint answers 2xx. A recipient is notified of
+ * mints from the moment the notifier first sees its registration.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import bs58 from 'bs58';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import { DepositWebhookConfig, loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    depositWebhookAddress,
+    fetchDepositWebhook,
+    webhookUrlHash,
+    webhookWants,
+} from '../sdk/attestation-client/src/deposit-webhook';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const MINT_PROGRAMS: Array<{ assetId: number; asset: string; program: PublicKey }> = [
+    { assetId: 1, asset: 'XENCAT', program: new PublicKey(config.programs.xencat_mint) },
+    { assetId: 2, asset: 'DGN', program: new PublicKey(config.programs.dgn_mint) },
+];
+
+// ProcessedBurnV3: discriminator(8) || asset_id(1) || nonce(8) || user(32) || amount(8) || processed_at(8)
+const PROCESSED_BURN_V3_SIZE = 65;
+const PROCESSED_BURN_V3_DISCRIMINATOR = crypto.createHash('sha256').update('account:ProcessedBurnV3').digest().subarray(0, 8);
+
+interface Deposit {
+    recipient: string;
+    asset_id: number;
+    asset: string;
+    burn_nonce: number;
+    amount: number;
+    processed_at: number;
+    processed_burn: string;
+}
+
+/** Per recipient: newest delivered processed_at and the mints delivered at that second */
+type Cursor = Record<string, { processed_at: number; delivered: string[] }>;
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+/** asset_mask bits for a comma-separated list of asset ids (empty = every asset) */
+function assetMask(list: string): number {
+    return list.split(',').filter(Boolean).reduce((mask, id) => {
+        const assetId = parseInt(id, 10);
+        if (!MINT_PROGRAMS.some(m => m.assetId === assetId)) {
+            throw new Error(`Unknown asset id ${id} (1 = XENCAT, 2 = DGN)`);
+        }
+        return mask | (1 << assetId);
+    }, 0);
+}
+
+function loadCursor(): Cursor {
+    return fs.existsSync(config.deposits.cursor_file)
+        ? JSON.parse(fs.readFileSync(config.deposits.cursor_file, 'utf-8'))
+        : {};
+}
+
+function saveCursor(cursor: Cursor) {
+    fs.writeFileSync(config.deposits.cursor_file, JSON.stringify(cursor, null, 2));
+}
+
+async function depositsTo(connection: Connection, recipient: PublicKey): Promise<Deposit[]> {
+    const deposits: Deposit[] = [];
+    for (const { assetId, asset, program } of MINT_PROGRAMS) {
+        const accounts = await connection.getProgramAccounts(program, {
+            filters: [
+                { dataSize: PROCESSED_BURN_V3_SIZE },
+                { memcmp: { offset: 0, bytes: bs58.encode(PROCESSED_BURN_V3_DISCRIMINATOR) } },
+                { memcmp: { offset: 17, bytes: recipient.toBase58() } },
+            ],
+        });
+        for (const { pubkey, account } of accounts) {
+            const data = account.data;
+            if (data[8] !== assetId) {
+                continue;
+            }
+            deposits.push({
+                recipient: recipient.toBase58(),
+                asset_id: assetId,
+                asset,
+                burn_nonce: Number(data.readBigUInt64LE(9)),
+                amount: Number(data.readBigUInt64LE(49)),
+                processed_at: Number(data.readBigInt64LE(57)),
+                processed_burn: pubkey.toBase58(),
+            });
+        }
+    }
+    return deposits.sort((a, b) => a.processed_at - b.processed_at || a.burn_nonce - b.burn_nonce);
+}
+
+async function deliver(webhook: DepositWebhookConfig, deposit: Deposit): Promise<void> {
+    const body = JSON.stringify({ event: 'deposit', ...deposit });
+    const headers: Record<string, string> = { 'Content-Type': 'application/json' };
+    const secret = webhook.secret_env ? process.env[webhook.secret_env] : undefined;
+    if (secret) {
+        headers['X-Xencat-Signature'] = crypto.createHmac('sha256', secret).update(body).digest('hex');
+    }
+    const response = await fetch(webhook.url, { method: 'POST', headers, body });
+    if (!response.ok) {
+        throw new Error(`HTTP ${response.status}`);
+    }
+}
+
+/** One pass over every configured webhook; returns the number of notifications sent */
+async function poll(connection: Connection, cursor: Cursor): Promise<number> {
+    const now = Math.floor(Date.now() / 1000);
+    let sent = 0;
+
+    for (const webhook of config.deposits.webhooks) {
+        const recipient = new PublicKey(webhook.recipient);
+        const registration = await fetchDepositWebhook(connection, LIGHT_CLIENT_PROGRAM, recipient);
+        if (!registration) {
+            console.log(`   ⏭️  ${webhook.recipient}: no DepositWebhook registered`);
+            continue;
+        }
+        if (!registration.urlHash.equals(webhookUrlHash(webhook.url))) {
+            console.log(`   ⚠️  ${webhook.recipient}: configured URL does not match the registered hash, skipping`);
+            continue;
+        }
+
+        if (!cursor[webhook.recipient]) {
+            cursor[webhook.recipient] = { processed_at: now, delivered: [] };
+        }
+        const state = cursor[webhook.recipient];
+        const pending = (await depositsTo(connection, recipient)).filter(d =>
+            d.processed_at > state.processed_at
+            || (d.processed_at === state.processed_at && !state.delivered.includes(d.processed_burn)));
+
+        for (const deposit of pending) {
+            if (webhookWants(registration, deposit.asset_id, deposit.amount)) {
+                try {
+                    await deliver(webhook, deposit);
+                    sent++;
+                    console.log(`   📬 ${deposit.asset} ${deposit.amount} (nonce ${deposit.burn_nonce}) -> ${webhook.recipient}`);
+                } catch (error: any) {
+                    // Keep order: retry this and everything after it next poll
+                    console.log(`   ❌ ${webhook.recipient}: delivery failed (${error.message}), retrying next poll`);
+                    break;
+                }
+            }
+            if (deposit.processed_at > state.processed_at) {
+                state.processed_at = deposit.processed_at;
+                state.delivered = [];
+            }
+            state.delivered.push(deposit.processed_burn);
+        }
+        saveCursor(cursor);
+    }
+    return sent;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+
+    switch (command) {
+        case 'register':
+        case 'update':
+        case 'close': {
+            const wallet = loadKeypair(config, 'user');
+            const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+            const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+            const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+            const depositWebhook = depositWebhookAddress(LIGHT_CLIENT_PROGRAM, wallet.publicKey);
+
+            if (command === 'close') {
+                const tx = await program.methods.closeDepositWebhook()
+                    .accounts({ recipient: wallet.publicKey, depositWebhook })
+                    .rpc();
+                console.log(`✅ Deposit webhook closed: ${tx}`);
+                break;
+            }
+
+            const params = {
+                urlHash: Array.from(webhookUrlHash(arg('url'))),
+                assetMask: assetMask(arg('assets', '')),
+                minAmount: new anchor.BN(arg('min-amount', '0')),
+            };
+            const tx = command === 'register'
+                ? await program.methods.registerDepositWebhook(params)
+                    .accounts({ recipient: wallet.publicKey, depositWebhook, systemProgram: SystemProgram.programId })
+                    .rpc()
+                : await program.methods.updateDepositWebhook(params)
+                    .accounts({ recipient: wallet.publicKey, depositWebhook })
+                    .rpc();
+            console.log(`✅ Deposit webhook ${command === 'register' ? 'registered' : 'updated'} for ${wallet.publicKey.toBase58()}: ${tx}`);
+            console.log('   Give the URL to the notifier operator; only its hash is on-chain.');
+            break;
+        }
+
+        case 'status': {
+            const recipients = process.argv.includes('--recipient')
+                ? [arg('recipient')]
+                : config.deposits.webhooks.map(w => w.recipient);
+            for (const recipient of recipients) {
+                const registration = await fetchDepositWebhook(connection, LIGHT_CLIENT_PROGRAM, new PublicKey(recipient));
+                if (!registration) {
+                    console.log(`🔕 ${recipient}: not registered`);
+                    continue;
+                }
+                const configured = config.deposits.webhooks.find(w => w.recipient === recipient);
+                const match = configured ? (registration.urlHash.equals(webhookUrlHash(configured.url)) ? '✅ URL matches' : '⚠️  URL mismatch') : 'not configured here';
+                console.log(`🔔 ${recipient}: assets ${registration.assetMask === 0 ? 'all' : registration.assetMask.toString(2)}, min ${registration.minAmount}, ${match}`);
+            }
+            break;
+        }
+
+        case 'run': {
+            console.log(`🔔 Deposit notifier: ${config.deposits.webhooks.length} webhooks, every ${config.deposits.poll_interval_seconds}s`);
+            const cursor = loadCursor();
+            for (;;) {
+                try {
+                    const sent = await poll(connection, cursor);
+                    if (sent > 0) {
+                        console.log(`✅ ${sent} notifications sent`);
+                    }
+                } catch (error: any) {
+                    console.error(`❌ Poll failed: ${error.message}`);
+                }
+                await new Promise(resolve => setTimeout(resolve, config.deposits.poll_interval_seconds * 1000));
+            }
+        }
+
+        default:
+            console.error('Usage: deposit-notifier.ts <register|update|close|status|run> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,199 @@
+/**
+ * Event Schema Registry (event format versions for indexers)
+ *
+ * Bump a program's version together with every upgrade that changes one
+ * of its events (fields added, removed, reordered or re-interpreted).
+ * Indexers compare the registered versions with the ones they support and
+ * stop instead of mis-decoding.
+ *
+ * Usage:
+ *   npx ts-node scripts/event-schema.ts status
+ *   npx ts-node scripts/event-schema.ts init
+ *   npx ts-node scripts/event-schema.ts propose --program <PROGRAM_ID> --version 2 [--out schema.json]
+ *   npx ts-node scripts/event-schema.ts sign    --file schema.json   (validator key)
+ *   npx ts-node scripts/event-schema.ts submit  --file schema.json
+ *
+ * `propose` binds the validator set version and BridgeConfig nonce, so
+ * collect signatures and submit promptly.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { eventSchemaRegistryAddress, eventSchemaVersion, fetchEventSchemas } from '../sdk/attestation-client/src/event-schema';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const REGISTRY = eventSchemaRegistryAddress(LIGHT_CLIENT_PROGRAM);
+
+interface SchemaProposal {
+    program: string;
+    version: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function u16(value: number): Buffer {
+    const buffer = Buffer.alloc(2);
+    buffer.writeUInt16LE(value);
+    return buffer;
+}
+
+/** Must match create_event_schema_message on-chain */
+function schemaMessage(p: Omit<SchemaProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_EVENT_SCHEMA'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        new PublicKey(p.program).toBuffer(),
+        u16(p.version),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+/** Label the programs this deployment knows about */
+function programName(program: PublicKey): string {
+    const names: Record<string, string> = {
+        [config.programs.light_client]: 'light client',
+        [config.programs.xencat_mint]: 'XENCAT mint',
+        [config.programs.dgn_mint]: 'DGN mint',
+        [config.programs.burn]: 'burn program',
+    };
+    return names[program.toBase58()] ?? 'unknown';
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const initialized = (await connection.getAccountInfo(REGISTRY)) !== null;
+            console.log(`📚 Event schema registry ${REGISTRY.toBase58()}${initialized ? '' : ' (not initialized)'}`);
+            const schemas = await fetchEventSchemas(connection, LIGHT_CLIENT_PROGRAM);
+            if (schemas.length === 0) {
+                console.log('   No programs registered (all at version 0)');
+            }
+            for (const schema of schemas) {
+                console.log(`   ${schema.program.toBase58()} (${programName(schema.program)}): v${schema.version} since slot ${schema.updatedSlot}`);
+            }
+            break;
+        }
+
+        case 'init': {
+            const tx = await program.methods
+                .initializeEventSchemaRegistry()
+                .accounts({ eventSchemaRegistry: REGISTRY, payer: wallet.publicKey, systemProgram: SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Event schema registry created: ${tx}`);
+            break;
+        }
+
+        case 'propose': {
+            const target = new PublicKey(arg('program'));
+            const version = Number(arg('version'));
+            const current = eventSchemaVersion(await fetchEventSchemas(connection, LIGHT_CLIENT_PROGRAM), target);
+            if (!Number.isInteger(version) || version <= current || version > 0xffff) {
+                throw new Error(`--version must be an integer above the registered v${current} (max 65535)`);
+            }
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                program: target.toBase58(),
+                version,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: SchemaProposal = { ...base, message: schemaMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'schema.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Event schema proposal written to ${out}`);
+            console.log(`   Program: ${base.program} (${programName(target)}) v${current} -> v${version}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: SchemaProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = schemaMessage(proposal);
+            console.log(`   Program: ${proposal.program} (${programName(new PublicKey(proposal.program))}) -> v${proposal.version}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed event schema proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: SchemaProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = schemaMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting event schema bump with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setEventSchemaVersion({
+                    program: new PublicKey(proposal.program),
+                    version: proposal.version,
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    eventSchemaRegistry: REGISTRY,
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ ${proposal.program} events at v${proposal.version}: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: event-schema.ts <status|init|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,184 @@
+/**
+ * Feature Gate (per-instruction kill switches)
+ *
+ * Validator governance can switch single instructions off, e.g. after an
+ * audit finding in one mint path, without a program upgrade. The gate
+ * stores the disabled bits; a missing account means everything is enabled.
+ *
+ * Gates: ATTEST_V2, ATTEST_V3, MINT_V2, MINT_V3, MINT_STREAM, MINT_STEALTH,
+ *        MINT_COMPRESSED, CONVERT, RENT_SPONSOR, ATTEST_DEGRADED, RETURN,
+ *        CLAIM_ON_BEHALF, ATTEST_VAA
+ *
+ * Usage:
+ *   npx ts-node scripts/feature-gate.ts init
+ *   npx ts-node scripts/feature-gate.ts status
+ *   npx ts-node scripts/feature-gate.ts propose [--disable MINT_STEALTH,CONVERT] [--enable ATTEST_V2] [--out gate.json]
+ *   npx ts-node scripts/feature-gate.ts sign    --file gate.json   (validator key)
+ *   npx ts-node scripts/feature-gate.ts submit  --file gate.json
+ *
+ * `propose` starts from the current disabled set and signs the complete
+ * new set. It binds the validator set version and BridgeConfig nonce, so
+ * collect signatures and submit promptly.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { FEATURE_GATES, FeatureGateName, disabledGateNames, fetchDisabledGates } from '../sdk/attestation-client/src/feature-gate';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const FEATURE_GATE = pda('feature_gate');
+
+interface GateProposal {
+    disabled: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_feature_gate_message on-chain */
+function gateMessage(p: Omit<GateProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_FEATURE_GATE'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        u64(p.disabled),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+/** Bits for a comma-separated list of gate names */
+function gateBits(list: string): number {
+    return list.split(',').filter(Boolean).reduce((bits, name) => {
+        const bit = FEATURE_GATES[name.trim().toUpperCase() as FeatureGateName];
+        if (bit === undefined) {
+            throw new Error(`Unknown gate ${name} (known: ${Object.keys(FEATURE_GATES).join(', ')})`);
+        }
+        return bits | bit;
+    }, 0);
+}
+
+function describe(disabled: number): string {
+    return disabled === 0 ? 'none' : disabledGateNames(disabled).join(', ');
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods.initializeFeatureGate()
+                .accounts({ featureGate: FEATURE_GATE, payer: wallet.publicKey, systemProgram: SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Feature gate created (everything enabled): ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const disabled = await fetchDisabledGates(connection, LIGHT_CLIENT_PROGRAM);
+            console.log(`🚦 Feature gate ${FEATURE_GATE.toBase58()}`);
+            for (const name of Object.keys(FEATURE_GATES) as FeatureGateName[]) {
+                console.log(`   ${name.padEnd(16)} ${disabled & FEATURE_GATES[name] ? '⛔ disabled' : '✅ enabled'}`);
+            }
+            break;
+        }
+
+        case 'propose': {
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const current = await fetchDisabledGates(connection, LIGHT_CLIENT_PROGRAM);
+            const base = {
+                disabled: (current | gateBits(arg('disable', ''))) & ~gateBits(arg('enable', '')),
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: GateProposal = { ...base, message: gateMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'gate.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Gate proposal written to ${out}`);
+            console.log(`   Disabled: ${describe(current)} -> ${describe(proposal.disabled)}`);
+            console.log(`   Message:  ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: GateProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = gateMessage(proposal);
+            console.log(`   Disables: ${describe(proposal.disabled)}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed gate proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: GateProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = gateMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting gate update with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setFeatureGate({ disabled: new anchor.BN(proposal.disabled), approverSignatures })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    featureGate: FEATURE_GATE,
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Feature gate updated (disabled: ${describe(proposal.disabled)}): ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: feature-gate.ts <init|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
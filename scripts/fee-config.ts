@@ -0,0 +1,98 @@
+/**
+ * Validator Fee Schedule (xencat-mint-x1)
+ *
+ * Replaces the fixed fee_per_validator with a per-burn schedule, per
+ * validator: clamp(flat + amount * bps / 10_000, min, max) lamports.
+ * Only the mint authority can create or change it.
+ *
+ * Usage:
+ *   npx ts-node scripts/fee-config.ts init   --flat <lamports> --bps <0-10000> --min <lamports> --max <lamports>   (mint authority)
+ *   npx ts-node scripts/fee-config.ts update --flat <lamports> --bps <0-10000> --min <lamports> --max <lamports>   (mint authority)
+ *   npx ts-node scripts/fee-config.ts status [--amount <base units>]
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { feeConfigAddress, feeForAmount, feesEnabled, fetchFeeConfig } from '../sdk/attestation-client/src/fee-config';
+
+const { config } = loadConfig();
+const MINT_PROGRAM = new PublicKey(config.programs.xencat_mint);
+const MINT_STATE = PublicKey.findProgramAddressSync([Buffer.from('mint_state_v2')], MINT_PROGRAM)[0];
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function schedule() {
+    return [new anchor.BN(arg('flat')), parseInt(arg('bps')), new anchor.BN(arg('min')), new anchor.BN(arg('max'))] as const;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const program: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/xencat_mint_x1.json', 'utf-8')), MINT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods
+                .initializeFeeConfig(...schedule())
+                .accounts({
+                    mintState: MINT_STATE,
+                    feeConfig: feeConfigAddress(MINT_PROGRAM),
+                    authority: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Fee schedule created; it replaces fee_per_validator from the next mint: ${tx}`);
+            break;
+        }
+
+        case 'update': {
+            const tx = await program.methods
+                .updateFeeConfig(...schedule())
+                .accounts({ mintState: MINT_STATE, feeConfig: feeConfigAddress(MINT_PROGRAM), authority: wallet.publicKey })
+                .rpc();
+            console.log(`✅ Fee schedule updated: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const feeConfig = await fetchFeeConfig(connection, MINT_PROGRAM);
+            if (!feeConfig) {
+                const state = await program.account.mintState.fetch(MINT_STATE);
+                console.log(`📋 No fee schedule: fixed ${state.feePerValidator} lamports per validator (run init to enable)`);
+                break;
+            }
+            console.log(`📋 Fee schedule ${feeConfigAddress(MINT_PROGRAM).toBase58()}`);
+            console.log(`   Per validator: ${feeConfig.flatFee} + ${feeConfig.feeBps} bps of amount, within [${feeConfig.minFee}, ${feeConfig.maxFee}] lamports`);
+            if (!feesEnabled(feeConfig)) {
+                console.log('   No burn owes validator fees');
+            }
+            if (process.argv.includes('--amount')) {
+                const amount = BigInt(arg('amount'));
+                console.log(`   Burn of ${amount}: ${feeForAmount(feeConfig, amount)} lamports per validator`);
+            }
+            break;
+        }
+
+        default:
+            console.error('Usage: fee-config.ts <init|update|status> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,165 @@
+/**
+ * Fee Lookup Table Service
+ *
+ * Keeps an address lookup table of every validator payout account (each
+ * validator key and its fee vault PDA in both mint programs) published in
+ * the light client's FeeLookupTable record, for SDKs to build small v0
+ * mint transactions. Whenever the validator set changes, `run` creates a
+ * new table, extends it with the new set's payout accounts, freezes it and
+ * publishes it. Published tables must be frozen, so they are replaced
+ * rather than extended; an old table's rent (a few thousand lamports)
+ * stays locked.
+ *
+ * Usage:
+ *   npx ts-node scripts/fee-lookup-table.ts init
+ *   npx ts-node scripts/fee-lookup-table.ts status
+ *   npx ts-node scripts/fee-lookup-table.ts sync
+ *   npx ts-node scripts/fee-lookup-table.ts run [--interval 60]
+ */
+
+import 'dotenv/config';
+import { AddressLookupTableProgram, Connection, Keypair, PublicKey, Transaction, sendAndConfirmTransaction } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    feeLookupTableRecordAddress,
+    feePayoutAccounts,
+    fetchFeeLookupTableRecord,
+} from '../sdk/attestation-client/src/fee-lookup-table';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAMS = [new PublicKey(config.programs.xencat_mint), new PublicKey(config.programs.dgn_mint)];
+const VALIDATOR_SET = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+const RECORD = feeLookupTableRecordAddress(LIGHT_CLIENT_PROGRAM);
+
+/** Addresses per extend instruction (keeps each transaction under the size limit) */
+const EXTEND_CHUNK = 20;
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function send(connection: Connection, payer: Keypair, ...instructions: anchor.web3.TransactionInstruction[]): Promise<string> {
+    return sendAndConfirmTransaction(connection, new Transaction().add(...instructions), [payer], { commitment: 'confirmed' });
+}
+
+/** Create, fill and freeze a table holding `addresses` */
+async function createFrozenTable(connection: Connection, payer: Keypair, addresses: PublicKey[]): Promise<PublicKey> {
+    const [create, table] = AddressLookupTableProgram.createLookupTable({
+        authority: payer.publicKey,
+        payer: payer.publicKey,
+        recentSlot: await connection.getSlot('finalized'),
+    });
+    await send(connection, payer, create);
+    for (let i = 0; i < addresses.length; i += EXTEND_CHUNK) {
+        await send(connection, payer, AddressLookupTableProgram.extendLookupTable({
+            lookupTable: table,
+            authority: payer.publicKey,
+            payer: payer.publicKey,
+            addresses: addresses.slice(i, i + EXTEND_CHUNK),
+        }));
+    }
+    await send(connection, payer, AddressLookupTableProgram.freezeLookupTable({ lookupTable: table, authority: payer.publicKey }));
+    return table;
+}
+
+/** Publish a table for the current validator set if needed; returns the published table */
+async function sync(connection: Connection, program: any, payer: Keypair): Promise<PublicKey> {
+    const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+    const version: number = set.version.toNumber();
+    const payouts = feePayoutAccounts(MINT_PROGRAMS, set.validators as PublicKey[]);
+
+    const record = await fetchFeeLookupTableRecord(connection, LIGHT_CLIENT_PROGRAM);
+    if (!record) {
+        throw new Error('Fee lookup table record not created: run init first');
+    }
+    if (record.lookupTable && record.validatorSetVersion === version) {
+        return record.lookupTable;
+    }
+
+    // The current table may still cover the new set (e.g. a threshold-only change)
+    let table: PublicKey | null = null;
+    if (record.lookupTable) {
+        const current = (await connection.getAddressLookupTable(record.lookupTable)).value;
+        if (current && payouts.every(a => current.state.addresses.some(b => b.equals(a)))) {
+            table = record.lookupTable;
+        }
+    }
+    if (!table) {
+        console.log(`🗂️  Creating lookup table for validator set v${version} (${payouts.length} payout accounts)`);
+        table = await createFrozenTable(connection, payer, payouts);
+    }
+
+    const tx = await program.methods
+        .publishFeeLookupTable()
+        .accounts({ validatorSet: VALIDATOR_SET, feeLookupTable: RECORD, lookupTable: table, signer: payer.publicKey })
+        .rpc();
+    console.log(`✅ Published ${table.toBase58()} for validator set v${version}: ${tx}`);
+    return table;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const payer = loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(payer), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods
+                .initializeFeeLookupTable()
+                .accounts({ feeLookupTable: RECORD, payer: payer.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Fee lookup table record created: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const record = await fetchFeeLookupTableRecord(connection, LIGHT_CLIENT_PROGRAM);
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            if (!record?.lookupTable) {
+                console.log('🗂️  Fee lookup table: none published');
+                break;
+            }
+            const current = record.validatorSetVersion === set.version.toNumber();
+            console.log(`🗂️  Fee lookup table: ${record.lookupTable.toBase58()}`);
+            console.log(`   Validator set v${record.validatorSetVersion} ${current ? '(current)' : `(stale: set is v${set.version})`}, published at slot ${record.updatedSlot}`);
+            break;
+        }
+
+        case 'sync':
+            await sync(connection, program, payer);
+            break;
+
+        case 'run': {
+            const interval = parseInt(arg('interval', '60'));
+            console.log(`🗂️  Fee lookup table service: checking the validator set every ${interval}s`);
+            for (;;) {
+                try {
+                    await sync(connection, program, payer);
+                } catch (error: any) {
+                    console.error(`❌ Sync failed: ${error.message}`);
+                }
+                await new Promise(resolve => setTimeout(resolve, interval * 1000));
+            }
+        }
+
+        default:
+            console.error('Usage: fee-lookup-table.ts <init|status|sync|run> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,93 @@
+/**
+ * Validator Fee Pool (xencat-mint-x1)
+ *
+ * Once the pool exists, XENCAT mints accrue all validator fees in it
+ * instead of paying one account per validator. withdraw_fees pays a
+ * validator's balance to its fee vault (or key); anyone may send it.
+ *
+ * Usage:
+ *   npx ts-node scripts/fee-pool.ts init
+ *   npx ts-node scripts/fee-pool.ts status
+ *   npx ts-node scripts/fee-pool.ts withdraw [--validator <pubkey>]   (default: every balance)
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram, Transaction, TransactionInstruction, sendAndConfirmTransaction } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { createWithdrawFeesInstruction, feePoolAddress, fetchFeePool } from '../sdk/attestation-client/src/fee-vault';
+
+const { config } = loadConfig();
+const MINT_PROGRAM = new PublicKey(config.programs.xencat_mint);
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const payer = loadKeypair(config, 'user');
+
+    switch (command) {
+        case 'init': {
+            const ix = new TransactionInstruction({
+                programId: MINT_PROGRAM,
+                keys: [
+                    { pubkey: feePoolAddress(MINT_PROGRAM), isSigner: false, isWritable: true },
+                    { pubkey: payer.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: crypto.createHash('sha256').update('global:initialize_fee_pool').digest().subarray(0, 8),
+            });
+            const tx = await sendAndConfirmTransaction(connection, new Transaction().add(ix), [payer]);
+            console.log(`✅ Fee pool created; XENCAT mints now accrue validator fees there: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const pool = await fetchFeePool(connection, MINT_PROGRAM);
+            if (!pool) {
+                console.log('📋 No fee pool: validator fees are paid directly (run init to enable)');
+                break;
+            }
+            console.log(`📋 Fee pool ${feePoolAddress(MINT_PROGRAM).toBase58()}`);
+            console.log(`   Accrued: ${pool.totalAccrued} lamports, withdrawn: ${pool.totalWithdrawn}`);
+            pool.balances.forEach(b => console.log(`   ${b.validator.toBase58()}: ${b.balance}`));
+            break;
+        }
+
+        case 'withdraw': {
+            const pool = await fetchFeePool(connection, MINT_PROGRAM);
+            if (!pool) {
+                throw new Error('No fee pool on the XENCAT mint program');
+            }
+            const validators = process.argv.includes('--validator')
+                ? [new PublicKey(arg('validator'))]
+                : pool.balances.filter(b => b.balance > 0n).map(b => b.validator);
+            for (const validator of validators) {
+                const ix = await createWithdrawFeesInstruction(connection, MINT_PROGRAM, validator);
+                const tx = await sendAndConfirmTransaction(connection, new Transaction().add(ix), [payer]);
+                console.log(`✅ Withdrew pooled fees of ${validator.toBase58()}: ${tx}`);
+            }
+            if (validators.length === 0) {
+                console.log('   Nothing to withdraw');
+            }
+            break;
+        }
+
+        default:
+            console.error('Usage: fee-pool.ts <init|status|withdraw> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
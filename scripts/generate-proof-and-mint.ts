@@ -102,7 +102,8 @@ async function main() {
     const ed25519Instructions = createValidatorEd25519Instructions(
         proof.validatorVotes,
         proof.blockHash,
-        proof.slot
+        proof.slot,
+        proof.stateRoot
     );
 
     console.log(`✅ Created ${ed25519Instructions.length} Ed25519 instructions`);
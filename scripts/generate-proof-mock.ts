@@ -1,6 +1,7 @@
 /**
  * Generate a mock proof for nonce 41 using deployed validator set
- * This works because dev-mode skips signature verification
+ * This works on a dev-mode build that validator governance has marked
+ * non-production (set_dev_mode_config), with the canary passed to the submit
  */
 
 import { Connection, PublicKey } from '@solana/web3.js';
@@ -96,7 +97,8 @@ async function generateMockProof() {
     console.log('✅ Mock proof created!');
     console.log();
     console.log('⚠️  Note: This proof has mock signatures and will only work');
-    console.log('   if the light client was deployed with dev-mode enabled.');
+    console.log('   on a dev-mode light client marked non-production (set_dev_mode_config),');
+    console.log('   with the marking\'s canary as the submit\'s first remaining account.');
     console.log();
 
     // Save proof to file
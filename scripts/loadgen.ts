@@ -0,0 +1,434 @@
+/**
+ * Bridge Load Generator (localnet stress test)
+ *
+ * Drives synthetic transfers through the whole bridge (Solana burn ->
+ * validator attestations -> submit_burn_attestation_v3 -> mint_from_burn_v3)
+ * at a configurable rate and concurrency, then reports throughput,
+ * per-phase and end-to-end latency, and a failure breakdown. Use it to
+ * check validator rate limits, relayer queue behavior and compute limits
+ * before changing them on mainnet.
+ *
+ * Usage:
+ *   XENCAT_ENV=localnet npx ts-node scripts/loadgen.ts [options]
+ *
+ *   --transfers 100          transfers to start
+ *   --rate 2                 transfers started per second (0 = as fast as concurrency allows)
+ *   --concurrency 10         transfers in flight at once
+ *   --asset 1                1 = XENCAT, 2 = DGN
+ *   --amount 1000            base units burned per transfer
+ *   --phases burn,attest,mint  stop after attest to load only the validators
+ *   --attest-timeout 120     seconds to reach the threshold per transfer
+ *   --report loadgen-report.json
+ *   --allow-remote           run against non-local RPCs (burns real tokens!)
+ *
+ * Burns are sent one at a time: the BurnRecord PDA is derived from the
+ * global nonce, so concurrent burns would race for it. Everything after
+ * the burn runs concurrently. The keys.user wallet pays for everything
+ * and must hold enough of the Solana token; fund it on localnet first.
+ */
+
+import 'dotenv/config';
+import { Connection, Keypair, PublicKey, SystemProgram, Transaction, TransactionInstruction } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { createAssociatedTokenAccountInstruction, getAssociatedTokenAddress, TOKEN_PROGRAM_ID } from '@solana/spl-token';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { newTraceContext, postSigned, ValidatorEndpoint } from '../sdk/attestation-client/src/transport';
+import { feePoolAddress, validatorFeeAccounts } from '../sdk/attestation-client/src/fee-vault';
+import { feeConfigAddress } from '../sdk/attestation-client/src/fee-config';
+import { featureGateAddress } from '../sdk/attestation-client/src/feature-gate';
+import { bridgeDomainAddress } from '../sdk/attestation-client/src/bridge-domain';
+import { x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+import { assetRegistryAddress } from '../sdk/attestation-client/src/asset-registry';
+import { relayerClaimAddress } from '../sdk/attestation-client/src/relayer-claim';
+import { burnAgeLimitAddress } from '../sdk/attestation-client/src/burn-age-limit';
+import { betaAllowlistAddress } from '../sdk/attestation-client/src/beta-allowlist';
+import { challengeRequestAddress } from '../sdk/attestation-client/src/attestation-challenge';
+import { devModeConfigAddress } from '../sdk/attestation-client/src/dev-mode';
+import { cosignPolicyAddress } from '../sdk/attestation-client/src/cosign-policy';
+import { rateLimitAddress } from '../sdk/attestation-client/src/rate-limit';
+import { mintPauseAddress } from '../sdk/attestation-client/src/pause';
+import { orderAttestations } from '../sdk/attestation-client/src/attestation-ordering';
+
+const { config } = loadConfig();
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+const TRANSFERS = Number(arg('transfers', '100'));
+const RATE = Number(arg('rate', '2'));
+const CONCURRENCY = Number(arg('concurrency', '10'));
+const ASSET_ID = Number(arg('asset', '1'));
+const AMOUNT = Number(arg('amount', '1000'));
+const PHASES = new Set(arg('phases', 'burn,attest,mint').split(','));
+const ATTEST_TIMEOUT_MS = Number(arg('attest-timeout', '120')) * 1000;
+const REPORT_FILE = arg('report', 'loadgen-report.json');
+
+const ASSETS: Record<number, { name: string; solanaMint: string; mintProgram: string; x1Mint: string; mintStateSeed: string; mintAccount: string }> = {
+    1: { name: 'XENCAT', solanaMint: config.mints.xencat_solana, mintProgram: config.programs.xencat_mint, x1Mint: config.mints.xencat_x1, mintStateSeed: 'mint_state_v2', mintAccount: 'xencatMint' },
+    2: { name: 'DGN', solanaMint: config.mints.dgn_solana, mintProgram: config.programs.dgn_mint, x1Mint: config.mints.dgn_x1, mintStateSeed: 'dgn_mint_state', mintAccount: 'dgnMint' },
+};
+
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const BURN_XENCAT_DISCRIMINATOR = Buffer.from([33, 48, 36, 182, 68, 82, 120, 188]);
+
+type Phase = 'burn' | 'attest' | 'submit' | 'mint';
+const PHASE_ORDER: Phase[] = ['burn', 'attest', 'submit', 'mint'];
+
+interface TransferResult {
+    index: number;
+    burn_nonce?: number;
+    /** Milliseconds spent in each completed phase */
+    phases: Partial<Record<Phase, number>>;
+    e2e_ms?: number;
+    failure?: { phase: Phase; reason: string };
+}
+
+const sleep = (ms: number) => new Promise(resolve => setTimeout(resolve, ms));
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function isLocal(url: string): boolean {
+    return ['localhost', '127.0.0.1', '0.0.0.0', '[::1]'].includes(new URL(url).hostname);
+}
+
+/** Short, groupable failure reason (Anchor error name when there is one) */
+function classify(error: any): string {
+    const code = error?.error?.errorCode?.code ?? error?.errorCode?.code;
+    if (code) {
+        return code;
+    }
+    const logs: string[] | undefined = error?.logs ?? error?.transactionLogs;
+    const logged = logs?.join('\n').match(/Error Code: (\w+)/)?.[1];
+    if (logged) {
+        return logged;
+    }
+    const message = String(error?.message ?? error);
+    if (/timed? ?out|timeout/i.test(message)) {
+        return 'timeout';
+    }
+    if (/429|too many requests/i.test(message)) {
+        return 'rpc_rate_limited';
+    }
+    return message.split('\n')[0].slice(0, 80);
+}
+
+function percentile(sorted: number[], q: number): number {
+    return sorted.length === 0 ? 0 : sorted[Math.min(sorted.length - 1, Math.ceil(q * sorted.length) - 1)];
+}
+
+/** Burns share one global nonce; run them one after another */
+let burnQueue: Promise<unknown> = Promise.resolve();
+function serialized<T>(fn: () => Promise<T>): Promise<T> {
+    const run = burnQueue.then(fn, fn);
+    burnQueue = run.catch(() => undefined);
+    return run;
+}
+
+async function main() {
+    const asset = ASSETS[ASSET_ID];
+    if (!asset) {
+        throw new Error(`Unknown --asset ${ASSET_ID} (1 = XENCAT, 2 = DGN)`);
+    }
+    if (!(isLocal(config.solana.rpc) && isLocal(config.x1.rpc)) && !process.argv.includes('--allow-remote')) {
+        throw new Error(`Refusing to run against ${config.solana.rpc} / ${config.x1.rpc}: not localnet (pass --allow-remote to override)`);
+    }
+
+    const user: Keypair = loadKeypair(config, 'user');
+    const solana = new Connection(config.solana.rpc, 'confirmed');
+    const x1 = new Connection(config.x1.rpc, 'confirmed');
+    const provider = new anchor.AnchorProvider(x1, new anchor.Wallet(user), { commitment: 'confirmed' });
+    const lightClient: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider);
+    const mintProgramId = new PublicKey(asset.mintProgram);
+    const mintProgram: any = new anchor.Program(
+        JSON.parse(fs.readFileSync(ASSET_ID === 1 ? 'target/idl/xencat_mint_x1.json' : 'target/idl/dgn_mint_x1.json', 'utf-8')), mintProgramId, provider);
+
+    const lcPda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+    const validatorSetPda = lcPda('x1_validator_set_v2');
+    const latencyStatsPda = lcPda('validator_latency_stats');
+    const featureGatePda = featureGateAddress(LIGHT_CLIENT_PROGRAM);
+    const bridgeDomainPda = bridgeDomainAddress(LIGHT_CLIENT_PROGRAM);
+    const x1ChainIdPda = x1ChainIdAddress(LIGHT_CLIENT_PROGRAM);
+    const assetRegistryPda = assetRegistryAddress(LIGHT_CLIENT_PROGRAM);
+    const mintStatePda = PublicKey.findProgramAddressSync([Buffer.from(asset.mintStateSeed)], mintProgramId)[0];
+    const globalStatePda = PublicKey.findProgramAddressSync([Buffer.from('global_state')], BURN_PROGRAM)[0];
+
+    const validatorSet = await lightClient.account.x1ValidatorSet.fetch(validatorSetPda);
+    const validatorSetVersion: number = validatorSet.version.toNumber();
+    const threshold: number = validatorSet.threshold;
+    const endpoints: ValidatorEndpoint[] = config.attestation.validators.map(v => ({
+        pubkey: new PublicKey(v.pubkey), url: v.url, certSha256: v.cert_sha256,
+    }));
+
+    const solanaMint = new PublicKey(asset.solanaMint);
+    const solanaTokenAccount = await getAssociatedTokenAddress(solanaMint, user.publicKey);
+    const x1Mint = new PublicKey(asset.x1Mint);
+    const x1TokenAccount = await getAssociatedTokenAddress(x1Mint, user.publicKey);
+    if (PHASES.has('mint') && !(await x1.getAccountInfo(x1TokenAccount))) {
+        await provider.sendAndConfirm(new Transaction().add(
+            createAssociatedTokenAccountInstruction(user.publicKey, x1TokenAccount, user.publicKey, x1Mint)), [user]);
+    }
+    const feeAccounts = await validatorFeeAccounts(x1, mintProgramId, validatorSet.validators);
+
+    console.log('🏋️  Bridge load generator');
+    console.log(`   ${TRANSFERS} ${asset.name} transfers of ${AMOUNT}, ${RATE || 'unlimited'}/s, concurrency ${CONCURRENCY}`);
+    console.log(`   Phases: ${[...PHASES].join(' -> ')}; threshold ${threshold} of ${endpoints.length} validators\n`);
+
+    /** Validator HTTP responses by status, across all attestation requests */
+    const responses: Record<string, number> = {};
+
+    async function burn(): Promise<number> {
+        return serialized(async () => {
+            const state = await solana.getAccountInfo(globalStatePda);
+            if (!state) {
+                throw new Error('Burn program not initialized');
+            }
+            const nonce = Number(state.data.readBigUInt64LE(8));
+            const burnRecord = PublicKey.findProgramAddressSync([Buffer.from('burn_record'), u64(nonce)], BURN_PROGRAM)[0];
+            const ix = new TransactionInstruction({
+                programId: BURN_PROGRAM,
+                keys: [
+                    { pubkey: user.publicKey, isSigner: true, isWritable: true },
+                    { pubkey: globalStatePda, isSigner: false, isWritable: true },
+                    { pubkey: burnRecord, isSigner: false, isWritable: true },
+                    { pubkey: solanaMint, isSigner: false, isWritable: true },
+                    { pubkey: solanaTokenAccount, isSigner: false, isWritable: true },
+                    { pubkey: TOKEN_PROGRAM_ID, isSigner: false, isWritable: false },
+                    { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+                ],
+                data: Buffer.concat([BURN_XENCAT_DISCRIMINATOR, u64(AMOUNT)]),
+            });
+            const signature = await solana.sendTransaction(new Transaction().add(ix), [user]);
+            const { value } = await solana.confirmTransaction(signature, 'confirmed');
+            if (value.err) {
+                throw new Error(`Burn failed: ${JSON.stringify(value.err)}`);
+            }
+            return nonce;
+        });
+    }
+
+    async function attest(nonce: number): Promise<any[]> {
+        const request = { burn_nonce: nonce, user: user.publicKey.toBase58(), expected_amount: AMOUNT, validator_set_version: validatorSetVersion };
+        const trace = newTraceContext(nonce, ASSET_ID);
+        const signed = new Map<string, any>();
+        const deadline = Date.now() + ATTEST_TIMEOUT_MS;
+
+        while (signed.size < threshold) {
+            let retryAfterMs = 1000;
+            await Promise.all(endpoints.filter(e => !signed.has(e.pubkey.toBase58())).map(async endpoint => {
+                try {
+                    const response = await postSigned(endpoint, '/attest-burn', request, trace);
+                    responses[response.status] = (responses[response.status] || 0) + 1;
+                    if (response.status === 200 && response.body?.validator_pubkey === endpoint.pubkey.toBase58()) {
+                        signed.set(endpoint.pubkey.toBase58(), response.body);
+                    } else if (response.status === 425 || response.status === 429) {
+                        retryAfterMs = Math.max(retryAfterMs, (response.body?.retry_after_seconds ?? 1) * 1000);
+                    }
+                } catch {
+                    responses.unreachable = (responses.unreachable || 0) + 1;
+                }
+            }));
+            if (signed.size >= threshold) {
+                break;
+            }
+            if (Date.now() + retryAfterMs > deadline) {
+                throw new Error(`attestation timeout (${signed.size}/${threshold})`);
+            }
+            await sleep(retryAfterMs);
+        }
+
+        // Threshold is enough; all signatures must cover the same burn slot/time and confirmed slot
+        const first = [...signed.values()][0];
+        return [...signed.values()]
+            .filter(a => a.solana_burn_slot === first.solana_burn_slot && a.solana_burn_timestamp === first.solana_burn_timestamp
+                && a.solana_confirmed_slot === first.solana_confirmed_slot)
+            .slice(0, threshold);
+    }
+
+    async function submit(nonce: number, attestations: any[]): Promise<PublicKey> {
+        const verifiedBurn = PublicKey.findProgramAddressSync(
+            [Buffer.from('verified_burn_v3'), Buffer.from([ASSET_ID]), user.publicKey.toBuffer(), u64(nonce)],
+            LIGHT_CLIENT_PROGRAM
+        )[0];
+        await lightClient.methods
+            .submitBurnAttestationV3(ASSET_ID, new anchor.BN(nonce), {
+                assetId: ASSET_ID,
+                burnNonce: new anchor.BN(nonce),
+                user: user.publicKey,
+                amount: new anchor.BN(AMOUNT),
+                validatorSetVersion: new anchor.BN(validatorSetVersion),
+                solanaBurnSlot: new anchor.BN(attestations[0].solana_burn_slot),
+                solanaBurnTimestamp: new anchor.BN(attestations[0].solana_burn_timestamp),
+                confirmationDepth: attestations[0].confirmation_depth,
+                solanaConfirmedSlot: new anchor.BN(attestations[0].solana_confirmed_slot),
+                // Ascending validator set order, in case the ordering rule is on
+                attestations: orderAttestations(attestations, validatorSet.validators, a => new PublicKey(a.validator_pubkey)).map(a => ({
+                    validatorPubkey: new PublicKey(a.validator_pubkey),
+                    signature: a.signature,
+                    timestamp: new anchor.BN(a.timestamp),
+                })),
+            })
+            .accounts({
+                user: user.publicKey,
+                validatorSet: validatorSetPda,
+                verifiedBurn,
+                latencyStats: latencyStatsPda,
+                featureGate: featureGatePda,
+                bridgeDomain: bridgeDomainPda,
+                x1ChainId: x1ChainIdPda,
+                assetRegistry: assetRegistryPda,
+                burnAgeLimit: burnAgeLimitAddress(LIGHT_CLIENT_PROGRAM),
+                betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID),
+                challengeRequest: challengeRequestAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, nonce),
+                devModeConfig: devModeConfigAddress(LIGHT_CLIENT_PROGRAM),
+                systemProgram: SystemProgram.programId,
+            })
+            .rpc();
+        return verifiedBurn;
+    }
+
+    async function mint(nonce: number, verifiedBurn: PublicKey): Promise<void> {
+        const processedBurn = PublicKey.findProgramAddressSync(
+            [Buffer.from('processed_burn_v3'), Buffer.from([ASSET_ID]), u64(nonce), user.publicKey.toBuffer()],
+            mintProgramId
+        )[0];
+        await mintProgram.methods
+            .mintFromBurnV3(new anchor.BN(nonce), ASSET_ID)
+            .accounts({
+                mintState: mintStatePda,
+                [asset.mintAccount]: x1Mint,
+                processedBurn,
+                userTokenAccount: x1TokenAccount,
+                user: user.publicKey,
+                validatorSet: validatorSetPda,
+                verifiedBurn,
+                featureGate: featureGatePda,
+                latencyStats: latencyStatsPda,
+                cosignPolicy: cosignPolicyAddress(mintProgramId),
+                rateLimit: rateLimitAddress(mintProgramId),
+                mintPause: mintPauseAddress(mintProgramId),
+                feePool: feePoolAddress(mintProgramId),
+                feeConfig: feeConfigAddress(mintProgramId),
+                assetRegistry: assetRegistryPda,
+                relayerClaim: relayerClaimAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, nonce),
+                tokenProgram: TOKEN_PROGRAM_ID,
+                systemProgram: SystemProgram.programId,
+            })
+            .remainingAccounts(feeAccounts)
+            .rpc();
+    }
+
+    const results: TransferResult[] = [];
+    let finished = 0;
+
+    async function runTransfer(index: number): Promise<void> {
+        const result: TransferResult = { index, phases: {} };
+        results.push(result);
+        const started = Date.now();
+        let phase: Phase = 'burn';
+        const timed = async <T>(name: Phase, fn: () => Promise<T>): Promise<T> => {
+            phase = name;
+            const at = Date.now();
+            const value = await fn();
+            result.phases[name] = Date.now() - at;
+            return value;
+        };
+
+        try {
+            const nonce = await timed('burn', burn);
+            result.burn_nonce = nonce;
+            if (PHASES.has('attest')) {
+                const attestations = await timed('attest', () => attest(nonce));
+                if (PHASES.has('mint')) {
+                    const verifiedBurn = await timed('submit', () => submit(nonce, attestations));
+                    await timed('mint', () => mint(nonce, verifiedBurn));
+                }
+            }
+            result.e2e_ms = Date.now() - started;
+            finished++;
+            console.log(`[${finished}/${TRANSFERS}] ✅ burn ${nonce} in ${(result.e2e_ms / 1000).toFixed(1)}s`);
+        } catch (error: any) {
+            result.failure = { phase, reason: classify(error) };
+            finished++;
+            console.log(`[${finished}/${TRANSFERS}] ❌ #${index} failed in ${phase}: ${result.failure.reason}`);
+        }
+    }
+
+    const startedAt = Date.now();
+    const inFlight = new Set<Promise<void>>();
+    for (let index = 0; index < TRANSFERS; index++) {
+        while (inFlight.size >= CONCURRENCY) {
+            await Promise.race(inFlight);
+        }
+        const transfer: Promise<void> = runTransfer(index).finally(() => inFlight.delete(transfer));
+        inFlight.add(transfer);
+        if (RATE > 0) {
+            await sleep(1000 / RATE);
+        }
+    }
+    await Promise.all(inFlight);
+    const elapsedSeconds = (Date.now() - startedAt) / 1000;
+
+    // ===== Report =====
+    const succeeded = results.filter(r => !r.failure);
+    const latency = (values: number[]) => {
+        const sorted = [...values].sort((a, b) => a - b);
+        return { count: sorted.length, p50_ms: percentile(sorted, 0.5), p95_ms: percentile(sorted, 0.95), p99_ms: percentile(sorted, 0.99), max_ms: sorted[sorted.length - 1] ?? 0 };
+    };
+    const phases = Object.fromEntries(PHASE_ORDER
+        .map(p => [p, latency(results.map(r => r.phases[p]).filter((v): v is number => v !== undefined))])
+        .filter(([, stats]) => (stats as { count: number }).count > 0));
+    const failures: Record<string, number> = {};
+    for (const r of results.filter(r => r.failure)) {
+        const key = `${r.failure!.phase}: ${r.failure!.reason}`;
+        failures[key] = (failures[key] || 0) + 1;
+    }
+
+    const report = {
+        asset: asset.name,
+        transfers: TRANSFERS,
+        rate_per_second: RATE,
+        concurrency: CONCURRENCY,
+        phases_run: [...PHASES],
+        elapsed_seconds: elapsedSeconds,
+        succeeded: succeeded.length,
+        failed: results.length - succeeded.length,
+        throughput_per_second: succeeded.length / elapsedSeconds,
+        e2e: latency(succeeded.map(r => r.e2e_ms!)),
+        phases,
+        failures,
+        validator_responses: responses,
+        results,
+    };
+    fs.writeFileSync(REPORT_FILE, JSON.stringify(report, null, 2));
+
+    const seconds = (ms: number) => `${(ms / 1000).toFixed(2)}s`;
+    console.log('\n' + '='.repeat(60));
+    console.log(`📊 ${succeeded.length}/${TRANSFERS} transfers in ${elapsedSeconds.toFixed(1)}s (${report.throughput_per_second.toFixed(2)}/s)`);
+    console.log('\n   phase     count   p50      p95      p99      max');
+    for (const [name, stats] of Object.entries({ ...phases, e2e: report.e2e })) {
+        const s = stats as ReturnType<typeof latency>;
+        console.log(`   ${name.padEnd(9)} ${String(s.count).padEnd(7)} ${seconds(s.p50_ms).padEnd(8)} ${seconds(s.p95_ms).padEnd(8)} ${seconds(s.p99_ms).padEnd(8)} ${seconds(s.max_ms)}`);
+    }
+    if (Object.keys(failures).length > 0) {
+        console.log('\n   Failures:');
+        Object.entries(failures).sort((a, b) => b[1] - a[1]).forEach(([key, count]) => console.log(`   ${String(count).padStart(5)}  ${key}`));
+    }
+    console.log(`\n   Validator responses: ${Object.entries(responses).map(([status, count]) => `${status}=${count}`).join(', ') || 'none'}`);
+    console.log(`\n📝 Report written to ${REPORT_FILE}`);
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
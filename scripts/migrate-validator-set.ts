@@ -0,0 +1,68 @@
+/**
+ * Validator Set Migration
+ *
+ * Validator set accounts created before max_attestation_age, the weights
+ * and ordered_attestations were appended keep their original size and
+ * cannot be read in the current layout. migrate_validator_set grows such
+ * an account and writes the appended fields with their defaults (default
+ * attestation age, unweighted, any order). Run it once right after
+ * upgrading the light client; anyone may run it and pays the extra rent.
+ *
+ * Usage:
+ *   npx ts-node scripts/migrate-validator-set.ts status
+ *   npx ts-node scripts/migrate-validator-set.ts migrate
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const VALIDATOR_SET = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+
+/** Must match X1ValidatorSet::LEGACY_SPACE */
+const LEGACY_SPACE = 8 + 8 + (4 + 10 * 32) + 1 + 1;
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const account = await connection.getAccountInfo(VALIDATOR_SET);
+            if (!account) {
+                console.log('🧱 Validator set: not initialized');
+            } else if (account.data.length === LEGACY_SPACE) {
+                console.log(`🧱 Validator set: original layout (${account.data.length} bytes), run migrate`);
+            } else {
+                console.log(`🧱 Validator set: current layout (${account.data.length} bytes)`);
+            }
+            break;
+        }
+
+        case 'migrate': {
+            const tx = await program.methods
+                .migrateValidatorSet()
+                .accounts({ validatorSet: VALIDATOR_SET, payer: wallet.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Validator set migrated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: migrate-validator-set.ts <status|migrate>');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,192 @@
+/**
+ * Batch Mint (XENCAT)
+ *
+ * Mints several of the user's verified XENCAT burns with one
+ * mint_from_burns transaction: one token mint for the sum and one
+ * transaction fee instead of one per burn. Validator fees are still paid
+ * per burn. Verify each burn first (VERIFY_ONLY=1 scripts/bridge-mint.ts).
+ * The fee accounts are loaded from the published fee lookup table when
+ * there is a current one (scripts/fee-lookup-table.ts).
+ *
+ * Usage:
+ *   BURN_NONCES=180,181,185 npx ts-node scripts/mint-batch.ts
+ *
+ * Environment Variables:
+ *   BURN_NONCES - Required: up to 10 verified XENCAT burn nonces
+ *   COSIGNER_KEYPAIR - Optional: co-signer keypair file, when the sum is
+ *                      above the co-sign policy threshold
+ */
+
+import 'dotenv/config';
+import { AccountMeta, Connection, PublicKey, TransactionMessage, VersionedTransaction } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { TOKEN_PROGRAM_ID, getOrCreateAssociatedTokenAccount } from '@solana/spl-token';
+import fs from 'fs';
+import { expandHome, loadConfig, loadKeypair, parseKeypair } from '../sdk/bridge-config/src';
+import { feePoolAddress, validatorFeeAccounts } from '../sdk/attestation-client/src/fee-vault';
+import { feeConfigAddress } from '../sdk/attestation-client/src/fee-config';
+import { FEATURE_GATES, disabledGateNames, featureGateAddress, fetchDisabledGates } from '../sdk/attestation-client/src/feature-gate';
+import { assetRegistryAddress } from '../sdk/attestation-client/src/asset-registry';
+import { relayerClaimAddress } from '../sdk/attestation-client/src/relayer-claim';
+import { coSignerAccount, cosignPolicyAddress, fetchCoSignPolicy, requiredCoSigner } from '../sdk/attestation-client/src/cosign-policy';
+import { fetchRateLimit, rateLimitAddress, rateLimitHeadroom } from '../sdk/attestation-client/src/rate-limit';
+import { mintPauseAddress } from '../sdk/attestation-client/src/pause';
+import { fetchFeeLookupTable } from '../sdk/attestation-client/src/fee-lookup-table';
+import { BridgeErrorKind, loadBridgeErrorTables, parseBridgeError } from '../sdk/attestation-client/src/program-errors';
+
+/** Must match MAX_BATCH_BURNS in the XENCAT mint program */
+const MAX_BATCH_BURNS = 10;
+const ASSET_ID = 1;
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const MINT_PROGRAM = new PublicKey(config.programs.xencat_mint);
+const XENCAT_MINT = new PublicKey(config.mints.xencat_x1);
+const VALIDATORS = config.attestation.validators.map(v => new PublicKey(v.pubkey));
+
+const BURN_NONCES = (process.env.BURN_NONCES || '')
+    .split(',')
+    .filter(Boolean)
+    .map(n => parseInt(n))
+    .sort((a, b) => a - b);
+if (BURN_NONCES.length === 0 || BURN_NONCES.length > MAX_BATCH_BURNS || new Set(BURN_NONCES).size !== BURN_NONCES.length) {
+    console.error(`❌ Required: BURN_NONCES, 1 to ${MAX_BATCH_BURNS} distinct nonces (comma separated)`);
+    process.exit(1);
+}
+
+const u64 = (value: number) => Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+const pda = (seeds: Buffer[], program: PublicKey) => PublicKey.findProgramAddressSync(seeds, program)[0];
+
+async function main() {
+    console.log(`🪙 Batch mint of ${BURN_NONCES.length} XENCAT burns: ${BURN_NONCES.join(', ')}`);
+
+    const user = loadKeypair(config, 'user');
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(user), { commitment: 'confirmed' });
+    const lightClientProgram: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider
+    );
+    const mintProgram: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/xencat_mint_x1.json', 'utf-8')), MINT_PROGRAM, provider
+    );
+
+    const disabledGates = await fetchDisabledGates(connection, LIGHT_CLIENT_PROGRAM);
+    if (disabledGates & FEATURE_GATES.MINT_V3) {
+        console.log(`⛔ Disabled by validator governance: ${disabledGateNames(disabledGates).join(', ')}`);
+        process.exit(1);
+    }
+
+    // Every burn must be verified and not yet minted
+    const burnAccounts: AccountMeta[] = [];
+    let total = 0n;
+    for (const nonce of BURN_NONCES) {
+        const verifiedBurn = pda([Buffer.from('verified_burn_v3'), Buffer.from([ASSET_ID]), user.publicKey.toBuffer(), u64(nonce)], LIGHT_CLIENT_PROGRAM);
+        const processedBurn = pda([Buffer.from('processed_burn_v3'), Buffer.from([ASSET_ID]), u64(nonce), user.publicKey.toBuffer()], MINT_PROGRAM);
+        const verified = await lightClientProgram.account.verifiedBurnV3.fetchNullable(verifiedBurn);
+        if (!verified) {
+            console.log(`⛔ Burn ${nonce} is not verified yet: VERIFY_ONLY=1 BURN_NONCE=${nonce} ASSET_ID=1 npx ts-node scripts/bridge-mint.ts`);
+            process.exit(1);
+        }
+        if (await connection.getAccountInfo(processedBurn)) {
+            console.log(`⛔ Burn ${nonce} was already minted`);
+            process.exit(1);
+        }
+        const relayerClaim = relayerClaimAddress(LIGHT_CLIENT_PROGRAM, ASSET_ID, user.publicKey, nonce);
+        if (await connection.getAccountInfo(relayerClaim)) {
+            console.log(`⛔ Burn ${nonce} was verified by a relayer: only it can mint the burn (mint_for_recipient)`);
+            process.exit(1);
+        }
+        total += BigInt(verified.amount.toString());
+        burnAccounts.push(
+            { pubkey: verifiedBurn, isSigner: false, isWritable: false },
+            { pubkey: processedBurn, isSigner: false, isWritable: true },
+            { pubkey: relayerClaim, isSigner: false, isWritable: false },
+        );
+    }
+    console.log(`   Total: ${Number(total) / 1_000_000} XENCAT`);
+
+    const rateLimit = await fetchRateLimit(connection, MINT_PROGRAM);
+    if (rateLimit) {
+        const headroom = rateLimitHeadroom(rateLimit, Math.floor(Date.now() / 1000));
+        if (headroom < total) {
+            console.log(`⏳ Mint rate limit: only ${headroom} left this window; retry later or mint fewer burns`);
+            process.exit(1);
+        }
+    }
+
+    // remaining_accounts: fee accounts (when fees are on), burn triples, co-signer
+    const mintStatePda = pda([Buffer.from('mint_state_v2')], MINT_PROGRAM);
+    const mintState = await mintProgram.account.mintState.fetch(mintStatePda);
+    const remainingAccounts = mintState.feePerValidator.isZero()
+        ? []
+        : await validatorFeeAccounts(connection, MINT_PROGRAM, VALIDATORS);
+    remainingAccounts.push(...burnAccounts);
+    const signers = [user];
+
+    const cosigner = requiredCoSigner(await fetchCoSignPolicy(connection, MINT_PROGRAM), total.toString());
+    if (cosigner) {
+        if (!process.env.COSIGNER_KEYPAIR) {
+            console.log(`⛔ Sum above the co-sign threshold: ${cosigner.toBase58()} must co-sign (set COSIGNER_KEYPAIR)`);
+            process.exit(1);
+        }
+        const cosignerKeypair = parseKeypair(fs.readFileSync(expandHome(process.env.COSIGNER_KEYPAIR), 'utf-8'));
+        if (!cosignerKeypair.publicKey.equals(cosigner)) {
+            throw new Error(`COSIGNER_KEYPAIR is ${cosignerKeypair.publicKey.toBase58()}, policy requires ${cosigner.toBase58()}`);
+        }
+        remainingAccounts.push(coSignerAccount(cosigner));
+        signers.push(cosignerKeypair);
+    }
+
+    const userTokenAccount = (await getOrCreateAssociatedTokenAccount(connection, user, XENCAT_MINT, user.publicKey)).address;
+    const validatorSetPda = pda([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM);
+    const instruction = await mintProgram.methods
+        .mintFromBurns(BURN_NONCES.map(n => new anchor.BN(n)))
+        .accounts({
+            mintState: mintStatePda,
+            xencatMint: XENCAT_MINT,
+            userTokenAccount,
+            user: user.publicKey,
+            validatorSet: validatorSetPda,
+            featureGate: featureGateAddress(LIGHT_CLIENT_PROGRAM),
+            latencyStats: pda([Buffer.from('validator_latency_stats')], LIGHT_CLIENT_PROGRAM),
+            cosignPolicy: cosignPolicyAddress(MINT_PROGRAM),
+            rateLimit: rateLimitAddress(MINT_PROGRAM),
+            mintPause: mintPauseAddress(MINT_PROGRAM),
+            feePool: feePoolAddress(MINT_PROGRAM),
+            feeConfig: feeConfigAddress(MINT_PROGRAM),
+            assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+            tokenProgram: TOKEN_PROGRAM_ID,
+            systemProgram: anchor.web3.SystemProgram.programId,
+        })
+        .remainingAccounts(remainingAccounts)
+        .instruction();
+
+    // v0 transaction: fee accounts come from the lookup table if one is current
+    const validatorSet = await lightClientProgram.account.x1ValidatorSet.fetch(validatorSetPda);
+    const lookupTable = await fetchFeeLookupTable(connection, LIGHT_CLIENT_PROGRAM, validatorSet.version.toNumber());
+    const { blockhash, lastValidBlockHeight } = await connection.getLatestBlockhash();
+    const transaction = new VersionedTransaction(new TransactionMessage({
+        payerKey: user.publicKey,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message(lookupTable ? [lookupTable] : []));
+    transaction.sign(signers);
+    const tx = await connection.sendTransaction(transaction);
+    await connection.confirmTransaction({ signature: tx, blockhash, lastValidBlockHeight }, 'confirmed');
+
+    console.log(`✅ Minted ${BURN_NONCES.length} burns: ${tx}`);
+}
+
+main()
+    .then(() => process.exit(0))
+    .catch((err) => {
+        const error = parseBridgeError(err, {
+            programs: loadBridgeErrorTables({
+                lightClient: LIGHT_CLIENT_PROGRAM,
+                xencatMint: MINT_PROGRAM,
+                dgnMint: new PublicKey(config.programs.dgn_mint),
+            }),
+        });
+        console.error('\n❌ Batch mint failed:', error.kind === BridgeErrorKind.Unknown ? err : error.message);
+        process.exit(1);
+    });
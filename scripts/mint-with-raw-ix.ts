@@ -127,7 +127,7 @@ async function mintWithRawInstruction() {
 
     console.log();
     console.log('   ⚠️  Note: Using mock signatures (all zeros)');
-    console.log('   This will only work if dev-mode is enabled on the light client');
+    console.log('   This will only work on a dev-mode light client marked non-production');
     console.log();
 
     console.log('3️⃣  Submitting transaction to X1...\n');
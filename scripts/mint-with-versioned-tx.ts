@@ -99,7 +99,8 @@ async function main() {
     const ed25519Instructions = createValidatorEd25519Instructions(
         proof.validatorVotes,
         proof.blockHash,
-        proof.slot
+        proof.slot,
+        proof.stateRoot
     );
 
     console.log(`✅ Ed25519 instructions: ${ed25519Instructions.length}\n`);
@@ -0,0 +1,190 @@
+/**
+ * Maintenance Notice Board
+ *
+ * Validators post a notice before validator rotations, pauses or upgrade
+ * windows. Wallets and SDKs (fetchNotice, summarizeBridgeTransaction) warn
+ * users before they start a burn that may not be mintable for a while.
+ * Only the sha256 of the text is stored on X1: publish the exact text
+ * (status page, bridge website) so clients can check it.
+ *
+ * Usage:
+ *   npx ts-node scripts/notice.ts init
+ *   npx ts-node scripts/notice.ts status  [--text "..."]   (checks the published text)
+ *   npx ts-node scripts/notice.ts propose --text "Validator rotation" --severity warning --effective-slot 123456789 [--out notice.json]
+ *   npx ts-node scripts/notice.ts propose --severity none   (clears the board)
+ *   npx ts-node scripts/notice.ts sign    --file notice.json   (validator key)
+ *   npx ts-node scripts/notice.ts submit  --file notice.json
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    NOTICE_SEVERITY,
+    NoticeSeverity,
+    fetchNotice,
+    noticeAddress,
+    noticeHash,
+    noticeMatches,
+    noticeMessage,
+    noticeSeverityName,
+} from '../sdk/attestation-client/src/notice';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const NOTICE = noticeAddress(LIGHT_CLIENT_PROGRAM);
+
+interface NoticeProposal {
+    /** Published text (empty when clearing) */
+    text: string;
+    severity: NoticeSeverity;
+    effective_slot: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+const messageHash = (p: Pick<NoticeProposal, 'text' | 'severity'>) =>
+    p.severity === 'none' ? Buffer.alloc(32) : noticeHash(p.text);
+
+const proposalMessage = (p: Omit<NoticeProposal, 'message' | 'approvals'>) =>
+    noticeMessage(p.validator_set_version, p.config_nonce, messageHash(p), p.effective_slot, NOTICE_SEVERITY[p.severity], proposalX1GenesisHash(p));
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods
+                .initializeNotice()
+                .accounts({ notice: NOTICE, payer: wallet.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Notice board created (empty): ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const notice = await fetchNotice(connection, LIGHT_CLIENT_PROGRAM);
+            if (!notice) {
+                console.log('📢 No notice posted');
+                break;
+            }
+            const slot = await connection.getSlot();
+            console.log(`📢 Notice #${notice.sequence} (${noticeSeverityName(notice.severity)}), posted at slot ${notice.postedSlot}`);
+            console.log(`   Effective slot: ${notice.effectiveSlot} (${slot >= notice.effectiveSlot ? 'in effect' : `in ${notice.effectiveSlot - slot} slots`})`);
+            console.log(`   Text hash: ${notice.messageHash.toString('hex')}`);
+            const index = process.argv.indexOf('--text');
+            if (index >= 0) {
+                console.log(noticeMatches(notice, process.argv[index + 1])
+                    ? '   ✅ Text matches the posted notice'
+                    : '   ❌ Text does NOT match the posted notice');
+            }
+            break;
+        }
+
+        case 'propose': {
+            const severity = arg('severity') as NoticeSeverity;
+            if (!(severity in NOTICE_SEVERITY)) {
+                throw new Error(`--severity must be one of ${Object.keys(NOTICE_SEVERITY).join(', ')}`);
+            }
+            const text = severity === 'none' ? '' : arg('text');
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                text,
+                severity,
+                effective_slot: parseInt(arg('effective-slot', '0')),
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: NoticeProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'notice.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Notice proposal (${severity}) written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            if (severity !== 'none') {
+                console.log(`   Publish this exact text; its hash is ${noticeHash(text).toString('hex')}`);
+            }
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: NoticeProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Notice (${proposal.severity}, effective slot ${proposal.effective_slot}): ${proposal.text || '(clear the board)'}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed notice proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: NoticeProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting notice with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setNotice({
+                    messageHash: Array.from(messageHash(proposal)),
+                    effectiveSlot: new anchor.BN(proposal.effective_slot),
+                    severity: NOTICE_SEVERITY[proposal.severity],
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    notice: NOTICE,
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Notice ${proposal.severity === 'none' ? 'cleared' : 'posted'}: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: notice.ts <init|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
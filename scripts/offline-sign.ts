@@ -0,0 +1,176 @@
+/**
+ * Offline Signing (air-gapped validator keys)
+ *
+ * Carries governance messages and transactions to machines without network
+ * access and brings the signatures back, as base64 payload files
+ * (sdk/attestation-client/src/offline-signing.ts).
+ *
+ * Governance proposals (validator-set-update.ts, pause.ts, asset-registry.ts, ...):
+ *   online:     npx ts-node scripts/validator-set-update.ts propose ... --out update.json
+ *   online:     npx ts-node scripts/offline-sign.ts export --file update.json [--out update.offline]
+ *   air-gapped: npx ts-node scripts/offline-sign.ts sign --in update.offline [--keypair key.json] [--out update.alice.offline]
+ *   online:     npx ts-node scripts/offline-sign.ts import --file update.json --in update.alice.offline,update.bob.offline
+ *   online:     npx ts-node scripts/validator-set-update.ts submit --file update.json
+ *
+ * Transactions (durable nonce, serialized unsigned as base64):
+ *   online:     npx ts-node scripts/offline-sign.ts export-tx --tx tx.b64 --description "..." [--out tx.offline]
+ *   air-gapped: npx ts-node scripts/offline-sign.ts sign --in tx.offline --keypair authority.json
+ *   online:     npx ts-node scripts/offline-sign.ts send --in tx.offline[,more.offline]
+ *
+ *   npx ts-node scripts/offline-sign.ts inspect --in <payload>
+ *
+ * The sign step never opens a connection; without --keypair it uses the
+ * validator key from xencat.toml.
+ */
+
+import 'dotenv/config';
+import { Connection, Transaction } from '@solana/web3.js';
+import fs from 'fs';
+import readline from 'readline';
+import { expandHome, loadConfig, loadKeypair, parseKeypair } from '../sdk/bridge-config/src';
+import {
+    OfflinePayload,
+    assembleTransaction,
+    decodeOfflinePayload,
+    encodeOfflinePayload,
+    mergeOfflinePayloads,
+    messagePayload,
+    payloadApprovals,
+    payloadFingerprint,
+    requiredSigners,
+    signOfflinePayload,
+    transactionPayload,
+    verifiedSignatures,
+} from '../sdk/attestation-client/src/offline-signing';
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+const readPayload = (file: string) => decodeOfflinePayload(fs.readFileSync(file, 'utf-8'));
+const readPayloads = (files: string) => files.split(',').filter(Boolean).map(readPayload);
+
+function writePayload(file: string, payload: OfflinePayload) {
+    fs.writeFileSync(file, encodeOfflinePayload(payload));
+    console.log(`✅ Written to ${file}`);
+    console.log(`   Fingerprint: ${payloadFingerprint(payload)}`);
+}
+
+function show(payload: OfflinePayload) {
+    console.log(`📄 ${payload.kind}: ${payload.description}`);
+    if (payload.context !== undefined) {
+        console.log(JSON.stringify(payload.context, null, 2).split('\n').map(line => `   ${line}`).join('\n'));
+    }
+    if (payload.kind === 'transaction') {
+        console.log(`   Signers: ${requiredSigners(payload).map(k => k.toBase58()).join(', ')}`);
+    }
+    const valid = verifiedSignatures(payload);
+    console.log(`   Signatures: ${valid.length} valid` +
+        (valid.length < payload.signatures.length ? `, ${payload.signatures.length - valid.length} invalid` : ''));
+    valid.forEach(s => console.log(`      ${s.signer}`));
+    console.log(`   Fingerprint: ${payloadFingerprint(payload)}`);
+}
+
+function confirm(question: string): Promise<boolean> {
+    if (process.argv.includes('--yes')) {
+        return Promise.resolve(true);
+    }
+    const rl = readline.createInterface({ input: process.stdin, output: process.stdout });
+    return new Promise(resolve => rl.question(question, answer => {
+        rl.close();
+        resolve(answer.trim().toLowerCase() === 'y');
+    }));
+}
+
+async function main() {
+    const command = process.argv[2];
+
+    switch (command) {
+        case 'export': {
+            const file = arg('file');
+            const proposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            if (typeof proposal.message !== 'string' || !Array.isArray(proposal.approvals)) {
+                throw new Error(`${file} is not a governance proposal file (no message / approvals)`);
+            }
+            const { message, approvals, ...context } = proposal;
+            const payload = messagePayload(
+                Buffer.from(message, 'hex'),
+                `${context.kind ?? 'governance'} proposal from ${file}`,
+                context,
+            );
+            writePayload(arg('out', file.replace(/\.json$/, '') + '.offline'), payload);
+            console.log('   Read the fingerprint to each signer over a separate channel');
+            break;
+        }
+
+        case 'export-tx': {
+            const tx = Transaction.from(Buffer.from(fs.readFileSync(arg('tx'), 'utf-8').trim(), 'base64'));
+            const payload = transactionPayload(tx, arg('description'));
+            writePayload(arg('out', 'transaction.offline'), payload);
+            break;
+        }
+
+        case 'inspect': {
+            show(readPayload(arg('in')));
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('in');
+            const payload = readPayload(file);
+            const keypair = process.argv.includes('--keypair')
+                ? parseKeypair(fs.readFileSync(expandHome(arg('keypair')), 'utf-8'))
+                : loadKeypair(loadConfig().config, 'validator');
+            show(payload);
+            if (!await confirm(`\nSign with ${keypair.publicKey.toBase58()}? The fingerprint must match the operator's [y/N] `)) {
+                console.log('❌ Not signed');
+                process.exit(1);
+            }
+            writePayload(arg('out', file), signOfflinePayload(payload, keypair));
+            break;
+        }
+
+        case 'import': {
+            const file = arg('file');
+            const proposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            const payload = mergeOfflinePayloads(readPayloads(arg('in')));
+            if (payload.kind !== 'message' || Buffer.from(payload.message, 'base64').toString('hex') !== proposal.message) {
+                throw new Error(`Payloads do not sign the message of ${file}`);
+            }
+            const imported = payloadApprovals(payload);
+            const approvals = [
+                ...proposal.approvals.filter((a: { validator_pubkey: string }) =>
+                    !imported.some(i => i.validator_pubkey === a.validator_pubkey)),
+                ...imported,
+            ];
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Imported ${imported.length} signatures into ${file}; ${approvals.length} approvals`);
+            console.log('   Submit it with the script that created it');
+            break;
+        }
+
+        case 'send': {
+            const { config } = loadConfig();
+            const connection = new Connection(arg('rpc', config.x1.rpc), 'confirmed');
+            const tx = assembleTransaction(mergeOfflinePayloads(readPayloads(arg('in'))));
+            const signature = await connection.sendRawTransaction(tx.serialize());
+            await connection.confirmTransaction(signature, 'confirmed');
+            console.log(`✅ Sent: ${signature}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: offline-sign.ts <export|export-tx|inspect|sign|import|send> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
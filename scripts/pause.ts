@@ -0,0 +1,189 @@
+/**
+ * Emergency Pause (circuit breaker)
+ *
+ * Stops the bridge at once if a validator key leak or a bug is found. The
+ * light client pause stops every attestation submit and mint; a mint
+ * program pause stops only that asset's mints. Both need threshold
+ * validator approval, to pause and to unpause.
+ *
+ * Usage:
+ *   npx ts-node scripts/pause.ts init    [--asset 1]   (creates the feature gate, or the asset's mint pause)
+ *   npx ts-node scripts/pause.ts status
+ *   npx ts-node scripts/pause.ts propose --action pause|unpause [--asset 1] [--out pause.json]
+ *   npx ts-node scripts/pause.ts sign    --file pause.json   (validator key)
+ *   npx ts-node scripts/pause.ts submit  --file pause.json
+ *
+ * Without --asset the proposal targets the whole bridge (light client).
+ * A proposal binds the current governance nonce, so collect signatures
+ * and submit promptly.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchBridgePaused } from '../sdk/attestation-client/src/feature-gate';
+import { bridgePauseMessage, fetchMintPause, mintPauseAddress, mintPauseMessage } from '../sdk/attestation-client/src/pause';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const FEATURE_GATE = pda('feature_gate');
+
+const ASSETS: Record<number, { name: string; program: PublicKey; idl: string }> = {
+    1: { name: 'XENCAT', program: new PublicKey(config.programs.xencat_mint), idl: 'target/idl/xencat_mint_x1.json' },
+    2: { name: 'DGN', program: new PublicKey(config.programs.dgn_mint), idl: 'target/idl/dgn_mint_x1.json' },
+};
+
+interface PauseProposal {
+    action: 'pause' | 'unpause';
+    /** Mint program's asset, or null for the whole bridge */
+    asset_id: number | null;
+    validator_set_version: number;
+    /** BridgeConfig nonce (bridge) or MintPause nonce (asset) */
+    nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function proposalMessage(p: Omit<PauseProposal, 'message' | 'approvals'>): Buffer {
+    const paused = p.action === 'pause';
+    return p.asset_id === null
+        ? bridgePauseMessage(paused, p.validator_set_version, p.nonce, proposalX1GenesisHash(p))
+        : mintPauseMessage(asset(p.asset_id).program, paused, p.validator_set_version, p.nonce, proposalX1GenesisHash(p));
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function asset(assetId: number) {
+    if (!ASSETS[assetId]) {
+        throw new Error(`Unknown asset ${assetId}`);
+    }
+    return ASSETS[assetId];
+}
+
+function describe(p: Pick<PauseProposal, 'action' | 'asset_id'>): string {
+    return `${p.action} ${p.asset_id === null ? 'the whole bridge' : `${asset(p.asset_id).name} mints`}`;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const lightClient: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider);
+    const mintProgram = (assetId: number): any =>
+        new anchor.Program(JSON.parse(fs.readFileSync(asset(assetId).idl, 'utf-8')), asset(assetId).program, provider);
+    const assetArg = process.argv.includes('--asset') ? parseInt(arg('asset')) : null;
+
+    switch (command) {
+        case 'init': {
+            const tx = assetArg === null
+                ? await lightClient.methods.initializeFeatureGate()
+                    .accounts({ featureGate: FEATURE_GATE, payer: wallet.publicKey, systemProgram: SystemProgram.programId })
+                    .rpc()
+                : await mintProgram(assetArg).methods.initializeMintPause()
+                    .accounts({ mintPause: mintPauseAddress(asset(assetArg).program), payer: wallet.publicKey, systemProgram: SystemProgram.programId })
+                    .rpc();
+            console.log(`✅ Pause switch created for ${assetArg === null ? 'the bridge' : asset(assetArg).name}: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            console.log(`🛑 Bridge: ${await fetchBridgePaused(connection, LIGHT_CLIENT_PROGRAM) ? 'PAUSED' : 'running'}`);
+            for (const [id, { name, program }] of Object.entries(ASSETS)) {
+                const pause = await fetchMintPause(connection, program);
+                console.log(`   ${name} (asset ${id}) mints: ${!pause ? 'running (no pause switch yet: run init --asset ' + id + ')' : pause.paused ? 'PAUSED' : 'running'}`);
+            }
+            break;
+        }
+
+        case 'propose': {
+            const action = arg('action') === 'unpause' ? 'unpause' : 'pause';
+            const set = await lightClient.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            let nonce: number;
+            if (assetArg === null) {
+                nonce = (await lightClient.account.bridgeConfig.fetch(BRIDGE_CONFIG)).nonce.toNumber();
+            } else {
+                const pause = await fetchMintPause(connection, asset(assetArg).program);
+                if (!pause) {
+                    throw new Error(`${asset(assetArg).name} has no pause switch: run init --asset ${assetArg} first`);
+                }
+                nonce = Number(pause.nonce);
+            }
+            const base = {
+                action,
+                asset_id: assetArg,
+                validator_set_version: set.version.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+                nonce,
+            } as const;
+            const proposal: PauseProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'pause.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Proposal to ${describe(proposal)} written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: PauseProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Proposal: ${describe(proposal)}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed pause proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: PauseProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting proposal to ${describe(proposal)} with ${approverSignatures.length} valid approvals`);
+
+            const program = proposal.asset_id === null ? lightClient : mintProgram(proposal.asset_id);
+            const method = proposal.action === 'pause' ? program.methods.pauseBridge : program.methods.unpauseBridge;
+            const accounts = proposal.asset_id === null
+                ? { validatorSet: VALIDATOR_SET, x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM), bridgeConfig: BRIDGE_CONFIG, featureGate: FEATURE_GATE, signer: wallet.publicKey }
+                : { validatorSet: VALIDATOR_SET, x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM), mintPause: mintPauseAddress(asset(proposal.asset_id).program), signer: wallet.publicKey };
+            const tx = await method({ approverSignatures }).accounts(accounts).rpc();
+            console.log(`✅ Done (${describe(proposal)}): ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: pause.ts <init|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,247 @@
+/**
+ * Program Hash Registry (governance-attested reproducible builds)
+ *
+ * Validators attest the hash of every audited release so anyone can check
+ * that a deployed bridge program is exactly that build. Hashes come from
+ * the verify-build crate (`cargo run -p xencat-verify-build -- build`),
+ * which writes program-hashes.json.
+ *
+ * Usage:
+ *   npx ts-node scripts/program-hashes.ts status
+ *   npx ts-node scripts/program-hashes.ts init
+ *   npx ts-node scripts/program-hashes.ts propose [--manifest program-hashes.json] [--out hashes.json]
+ *   npx ts-node scripts/program-hashes.ts sign    --file hashes.json [--manifest program-hashes.json]   (validator key)
+ *   npx ts-node scripts/program-hashes.ts submit  --file hashes.json
+ *
+ * `sign` refuses unless the validator's own build (its manifest) produced
+ * the same hashes, so every approval is an independent reproduction.
+ * One proposal attests every program in the manifest with consecutive
+ * BridgeConfig nonces; submit promptly and in one go.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { deployedProgramHash, fetchProgramHashes, matchingBuild, programHashRegistryAddress } from '../sdk/attestation-client/src/program-hashes';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const REGISTRY = programHashRegistryAddress(LIGHT_CLIENT_PROGRAM);
+
+/** program-hashes.json written by xencat-verify-build */
+interface BuildManifest {
+    anchor_version: string;
+    commit: string;
+    programs: Array<{ name: string; program_id: string; version: [number, number, number]; hash: string; size: number }>;
+}
+
+interface HashAttestation {
+    name: string;
+    program: string;
+    version: [number, number, number];
+    hash: string;
+    config_nonce: number;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+interface HashProposal {
+    commit: string;
+    validator_set_version: number;
+    /** X1 genesis hash the messages are bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    attestations: HashAttestation[];
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function u16(value: number): Buffer {
+    const buffer = Buffer.alloc(2);
+    buffer.writeUInt16LE(value);
+    return buffer;
+}
+
+/** Must match create_program_hash_message on-chain */
+function hashMessage(
+    validatorSetVersion: number,
+    a: Omit<HashAttestation, 'message' | 'approvals'>,
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('ATTEST_PROGRAM_HASH'),
+        u64(validatorSetVersion),
+        u64(a.config_nonce),
+        new PublicKey(a.program).toBuffer(),
+        ...a.version.map(u16),
+        Buffer.from(a.hash, 'hex'),
+        x1GenesisHash ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+const version = (v: number[]) => `v${v.join('.')}`;
+
+function readManifest(): BuildManifest {
+    const file = arg('manifest', 'program-hashes.json');
+    if (!fs.existsSync(file)) {
+        throw new Error(`${file} not found: run \`cargo run -p xencat-verify-build -- build\` first`);
+    }
+    return JSON.parse(fs.readFileSync(file, 'utf-8'));
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const initialized = (await connection.getAccountInfo(REGISTRY)) !== null;
+            console.log(`🔏 Program hash registry ${REGISTRY.toBase58()}${initialized ? '' : ' (not initialized)'}`);
+            const hashes = await fetchProgramHashes(connection, LIGHT_CLIENT_PROGRAM);
+            for (const entry of hashes) {
+                console.log(`   ${entry.program.toBase58()} ${version(entry.version)}: ${entry.hash} (slot ${entry.attestedSlot})`);
+            }
+
+            console.log('\n   Deployed programs:');
+            const programs = { light_client: config.programs.light_client, xencat_mint: config.programs.xencat_mint, dgn_mint: config.programs.dgn_mint };
+            for (const [name, id] of Object.entries(programs)) {
+                const programId = new PublicKey(id);
+                const deployed = await deployedProgramHash(connection, programId).catch(error => `error: ${error.message}`);
+                const build = matchingBuild(hashes, programId, deployed);
+                console.log(build
+                    ? `   ✅ ${name}: attested build ${version(build.version)}`
+                    : `   ⚠️  ${name}: ${deployed} matches no attested build`);
+            }
+            break;
+        }
+
+        case 'init': {
+            const tx = await program.methods
+                .initializeProgramHashRegistry()
+                .accounts({ programHashRegistry: REGISTRY, payer: wallet.publicKey, systemProgram: SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Program hash registry created: ${tx}`);
+            break;
+        }
+
+        case 'propose': {
+            const manifest = readManifest();
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const validatorSetVersion = set.version.toNumber();
+            const x1GenesisHashHex = await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM);
+            const attestations = manifest.programs.map((build, i) => {
+                const base = {
+                    name: build.name,
+                    program: build.program_id,
+                    version: build.version,
+                    hash: build.hash,
+                    // Each submission bumps the nonce, so they must land in this order
+                    config_nonce: bridgeConfig.nonce.toNumber() + i,
+                };
+                return { ...base, message: hashMessage(validatorSetVersion, base, proposalX1GenesisHash({ x1_genesis_hash: x1GenesisHashHex })).toString('hex'), approvals: [] };
+            });
+            const proposal: HashProposal = {
+                commit: manifest.commit,
+                validator_set_version: validatorSetVersion,
+                x1_genesis_hash: x1GenesisHashHex,
+                attestations,
+            };
+            const out = arg('out', 'hashes.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Program hash proposal for commit ${manifest.commit} written to ${out}`);
+            attestations.forEach(a => console.log(`   ${a.name} ${version(a.version)}: ${a.hash}`));
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: HashProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            const manifest = readManifest();
+            if (manifest.commit !== proposal.commit) {
+                throw new Error(`Your build is of commit ${manifest.commit}, the proposal is for ${proposal.commit}`);
+            }
+            for (const a of proposal.attestations) {
+                const own = manifest.programs.find(p => p.program_id === a.program);
+                if (!own || own.hash !== a.hash || own.version.join('.') !== a.version.join('.')) {
+                    throw new Error(`${a.name}: your build gives ${own ? `${version(own.version)} ${own.hash}` : 'nothing'}, proposal says ${version(a.version)} ${a.hash}`);
+                }
+            }
+            const attestations = proposal.attestations.map(a => {
+                // Recompute rather than trust the file's message field
+                const message = hashMessage(proposal.validator_set_version, a, proposalX1GenesisHash(proposal));
+                const approvals = a.approvals.filter(x => x.validator_pubkey !== wallet.publicKey.toBase58());
+                approvals.push({
+                    validator_pubkey: wallet.publicKey.toBase58(),
+                    signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+                });
+                return { ...a, approvals };
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, attestations }, null, 2));
+            console.log(`✅ Reproduced and signed ${attestations.length} program hashes; ${attestations[0]?.approvals.length ?? 0} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: HashProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            for (const a of proposal.attestations) {
+                const message = hashMessage(proposal.validator_set_version, a, proposalX1GenesisHash(proposal));
+                const approverSignatures = a.approvals
+                    .filter(x => nacl.sign.detached.verify(message, Uint8Array.from(x.signature), new PublicKey(x.validator_pubkey).toBytes()))
+                    .map(x => ({ validatorPubkey: new PublicKey(x.validator_pubkey), signature: x.signature }));
+                console.log(`📤 ${a.name} ${version(a.version)} with ${approverSignatures.length} valid approvals`);
+
+                const tx = await program.methods
+                    .attestProgramHash({
+                        program: new PublicKey(a.program),
+                        version: a.version,
+                        hash: Array.from(Buffer.from(a.hash, 'hex')),
+                        approverSignatures,
+                    })
+                    .accounts({
+                        validatorSet: VALIDATOR_SET,
+                        x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                        bridgeConfig: BRIDGE_CONFIG,
+                        programHashRegistry: REGISTRY,
+                        signer: wallet.publicKey,
+                    })
+                    .rpc();
+                console.log(`   ✅ ${tx}`);
+            }
+            break;
+        }
+
+        default:
+            console.error('Usage: program-hashes.ts <status|init|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,325 @@
+/**
+ * Proof-of-Reserve Report Generator
+ *
+ * Exports every Solana burn and every X1 mint for a time range and
+ * reconciles them per asset, for exchanges and auditors. Because X1 supply
+ * is only ever minted against a verified Solana burn, for each asset:
+ *
+ *   X1 minted <= Solana burned      (the difference is burns still in flight)
+ *
+ * Any mint without a matching burn (or with a different amount/user) is
+ * reported as an anomaly and makes the command exit non-zero.
+ *
+ * Outputs (signed with the configured keys.user keypair):
+ *   <out>.json  totals, anomalies, Merkle roots, every row + leaf hash
+ *   <out>.csv   one row per burn/mint with its leaf hash
+ *
+ * Merkle commitments: one tree for burns and one for mints per asset.
+ * Leaves are sha256(kind || asset_id || nonce || user || amount || timestamp)
+ * (little-endian integers), sorted by nonce; parents are sha256(left || right)
+ * with the last node promoted on odd levels. Publishing the roots lets a
+ * user check that their transfer is included without trusting the report.
+ *
+ * Usage:
+ *   npx ts-node scripts/proof-of-reserve.ts [--from 2025-01-01] [--to 2025-02-01] [--asset 1] [--out reserve-report]
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, ParsedTransactionWithMeta } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import bs58 from 'bs58';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+
+const { config } = loadConfig();
+
+const ASSETS: Record<number, { name: string; solanaMint: string }> = {
+    1: { name: 'XENCAT', solanaMint: config.mints.xencat_solana },
+    2: { name: 'DGN', solanaMint: config.mints.dgn_solana },
+};
+
+/** BurnRecord: disc(8) user(32) amount(8) nonce(8) timestamp(8) record_hash(32) bump(1) */
+const BURN_RECORD_SIZE = 97;
+/** ProcessedBurnV3: disc(8) asset_id(1) nonce(8) user(32) amount(8) processed_at(8) */
+const PROCESSED_BURN_V3_SIZE = 65;
+/** Legacy V2 ProcessedBurn (XENCAT only): disc(8) nonce(8) user(32) amount(8) processed_at(8) */
+const PROCESSED_BURN_V2_SIZE = 64;
+
+/** Anchor account discriminator filter (dataSize alone could match other accounts) */
+function accountFilter(name: string, dataSize: number) {
+    const discriminator = crypto.createHash('sha256').update(`account:${name}`).digest().subarray(0, 8);
+    return [{ dataSize }, { memcmp: { offset: 0, bytes: bs58.encode(discriminator) } }];
+}
+
+interface Row {
+    kind: 'burn' | 'mint';
+    asset_id: number;
+    nonce: number;
+    user: string;
+    amount: bigint;
+    timestamp: number;
+    leaf?: string;
+}
+
+function parseArgs(argv: string[]) {
+    const args: { from: number; to: number; asset?: number; out: string } = {
+        from: 0,
+        to: Math.floor(Date.now() / 1000),
+        out: 'reserve-report',
+    };
+    const toUnix = (value: string) => /^\d+$/.test(value) ? Number(value) : Math.floor(Date.parse(value) / 1000);
+    for (let i = 0; i < argv.length; i++) {
+        if (argv[i] === '--from') args.from = toUnix(argv[++i]);
+        else if (argv[i] === '--to') args.to = toUnix(argv[++i]);
+        else if (argv[i] === '--asset') args.asset = Number(argv[++i]);
+        else if (argv[i] === '--out') args.out = argv[++i];
+    }
+    if (Number.isNaN(args.from) || Number.isNaN(args.to) || args.from > args.to) {
+        throw new Error('Invalid --from/--to range');
+    }
+    return args;
+}
+
+// ============================================================================
+// MERKLE COMMITMENTS
+// ============================================================================
+
+function u64(value: bigint | number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function leafHash(row: Row): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(row.kind),
+        Buffer.from([row.asset_id]),
+        u64(row.nonce),
+        new PublicKey(row.user).toBuffer(),
+        u64(row.amount),
+        u64(row.timestamp),
+    ])).digest();
+}
+
+function merkleRoot(leaves: Buffer[]): string {
+    if (leaves.length === 0) {
+        return Buffer.alloc(32).toString('hex');
+    }
+    let level = leaves;
+    while (level.length > 1) {
+        const next: Buffer[] = [];
+        for (let i = 0; i < level.length; i += 2) {
+            next.push(i + 1 < level.length
+                ? crypto.createHash('sha256').update(Buffer.concat([level[i], level[i + 1]])).digest()
+                : level[i]);
+        }
+        level = next;
+    }
+    return level[0].toString('hex');
+}
+
+// ============================================================================
+// DATA COLLECTION
+// ============================================================================
+
+/**
+ * The SPL mint burned in a BurnRecord's creation transaction (same detection
+ * as the validator service: exactly one supported SPL burn)
+ */
+async function detectAssetId(solana: Connection, burnRecord: PublicKey): Promise<number | undefined> {
+    const signatures = await solana.getSignaturesForAddress(burnRecord, { limit: 1 });
+    if (signatures.length === 0) {
+        return undefined;
+    }
+    const tx: ParsedTransactionWithMeta | null = await solana.getParsedTransaction(signatures[0].signature, {
+        maxSupportedTransactionVersion: 0,
+        commitment: 'finalized',
+    });
+    if (!tx || !tx.meta) {
+        return undefined;
+    }
+    const mints = [
+        ...tx.transaction.message.instructions,
+        ...(tx.meta.innerInstructions?.flatMap(inner => inner.instructions) || []),
+    ]
+        .filter((ix: any) => ix.program === 'spl-token' && (ix.parsed?.type === 'burn' || ix.parsed?.type === 'burnChecked'))
+        .map((ix: any) => ix.parsed.info.mint as string);
+    if (mints.length !== 1) {
+        return undefined;
+    }
+    const entry = Object.entries(ASSETS).find(([, a]) => a.solanaMint === mints[0]);
+    return entry ? Number(entry[0]) : undefined;
+}
+
+async function fetchBurns(solana: Connection, from: number, to: number): Promise<{ rows: Row[]; unknown: number }> {
+    const accounts = await solana.getProgramAccounts(new PublicKey(config.programs.burn), {
+        filters: accountFilter('BurnRecord', BURN_RECORD_SIZE),
+    });
+    const rows: Row[] = [];
+    let unknown = 0;
+    for (const { pubkey, account } of accounts) {
+        const data = account.data;
+        const timestamp = Number(data.readBigUInt64LE(56));
+        if (timestamp < from || timestamp > to) {
+            continue;
+        }
+        const assetId = await detectAssetId(solana, pubkey);
+        if (assetId === undefined) {
+            unknown++; // Unsupported token burns never mint on X1
+            continue;
+        }
+        rows.push({
+            kind: 'burn',
+            asset_id: assetId,
+            nonce: Number(data.readBigUInt64LE(48)),
+            user: new PublicKey(data.subarray(8, 40)).toBase58(),
+            amount: data.readBigUInt64LE(40),
+            timestamp,
+        });
+    }
+    return { rows, unknown };
+}
+
+async function fetchMints(x1: Connection, from: number, to: number): Promise<Row[]> {
+    const rows: Row[] = [];
+    const programs: Array<[string, number]> = [[config.programs.xencat_mint, 1], [config.programs.dgn_mint, 2]];
+    for (const [program, assetId] of programs) {
+        const v3 = await x1.getProgramAccounts(new PublicKey(program), { filters: accountFilter('ProcessedBurnV3', PROCESSED_BURN_V3_SIZE) });
+        for (const { account } of v3) {
+            const data = account.data;
+            rows.push({
+                kind: 'mint',
+                asset_id: data[8],
+                nonce: Number(data.readBigUInt64LE(9)),
+                user: new PublicKey(data.subarray(17, 49)).toBase58(),
+                amount: data.readBigUInt64LE(49),
+                timestamp: Number(data.readBigInt64LE(57)),
+            });
+        }
+        if (assetId === 1) {
+            const v2 = await x1.getProgramAccounts(new PublicKey(program), { filters: accountFilter('ProcessedBurn', PROCESSED_BURN_V2_SIZE) });
+            for (const { account } of v2) {
+                const data = account.data;
+                rows.push({
+                    kind: 'mint',
+                    asset_id: 1,
+                    nonce: Number(data.readBigUInt64LE(8)),
+                    user: new PublicKey(data.subarray(16, 48)).toBase58(),
+                    amount: data.readBigUInt64LE(48),
+                    timestamp: Number(data.readBigInt64LE(56)),
+                });
+            }
+        }
+    }
+    return rows.filter(r => r.timestamp >= from && r.timestamp <= to);
+}
+
+// ============================================================================
+// REPORT
+// ============================================================================
+
+async function main() {
+    const args = parseArgs(process.argv.slice(2));
+    const solana = new Connection(config.solana.rpc, 'finalized');
+    const x1 = new Connection(config.x1.rpc, 'finalized');
+    const signer = loadKeypair(config, 'user');
+
+    console.log('📊 XENCAT Bridge Proof-of-Reserve');
+    console.log(`   Range: ${new Date(args.from * 1000).toISOString()} .. ${new Date(args.to * 1000).toISOString()}\n`);
+
+    console.log('🔍 Loading Solana burns...');
+    const { rows: burns, unknown } = await fetchBurns(solana, args.from, args.to);
+    console.log('🔍 Loading X1 mints...');
+    // Mints may land after the range ends, so reconcile against all mints of the ranged burns
+    const allMints = await fetchMints(x1, 0, Number.MAX_SAFE_INTEGER);
+    const mints = allMints.filter(m => m.timestamp >= args.from && m.timestamp <= args.to);
+
+    const assetIds = Object.keys(ASSETS).map(Number).filter(id => args.asset === undefined || id === args.asset);
+    const anomalies: string[] = [];
+    const assets: any[] = [];
+
+    for (const assetId of assetIds) {
+        const assetBurns = burns.filter(b => b.asset_id === assetId).sort((a, b) => a.nonce - b.nonce);
+        const assetMints = mints.filter(m => m.asset_id === assetId).sort((a, b) => a.nonce - b.nonce);
+        const burnByNonce = new Map(burns.filter(b => b.asset_id === assetId).map(b => [b.nonce, b]));
+
+        for (const mint of assetMints) {
+            const burn = burnByNonce.get(mint.nonce);
+            if (!burn) {
+                // The burn may predate the range; only flag it if it really does not exist
+                if (!(await solana.getAccountInfo(PublicKey.findProgramAddressSync(
+                    [Buffer.from('burn_record'), u64(mint.nonce)],
+                    new PublicKey(config.programs.burn)
+                )[0]))) {
+                    anomalies.push(`${ASSETS[assetId].name} mint for nonce ${mint.nonce} has no Solana burn`);
+                }
+            } else if (burn.amount !== mint.amount || burn.user !== mint.user) {
+                anomalies.push(`${ASSETS[assetId].name} mint for nonce ${mint.nonce} does not match its burn (amount/user)`);
+            }
+        }
+
+        const mintedNonces = new Set(allMints.filter(m => m.asset_id === assetId).map(m => m.nonce));
+        const pending = assetBurns.filter(b => !mintedNonces.has(b.nonce));
+        const sum = (rows: Row[]) => rows.reduce((total, r) => total + r.amount, 0n);
+
+        for (const row of [...assetBurns, ...assetMints]) {
+            row.leaf = leafHash(row).toString('hex');
+        }
+
+        assets.push({
+            asset_id: assetId,
+            asset: ASSETS[assetId].name,
+            solana_burned: { count: assetBurns.length, amount: sum(assetBurns).toString() },
+            x1_minted: { count: assetMints.length, amount: sum(assetMints).toString() },
+            pending: { count: pending.length, amount: sum(pending).toString() },
+            burns_merkle_root: merkleRoot(assetBurns.map(r => Buffer.from(r.leaf!, 'hex'))),
+            mints_merkle_root: merkleRoot(assetMints.map(r => Buffer.from(r.leaf!, 'hex'))),
+        });
+
+        console.log(`\n🪙 ${ASSETS[assetId].name}`);
+        console.log(`   Burned on Solana: ${assetBurns.length} burns, ${sum(assetBurns)}`);
+        console.log(`   Minted on X1:     ${assetMints.length} mints, ${sum(assetMints)}`);
+        console.log(`   In flight:        ${pending.length} burns, ${sum(pending)}`);
+    }
+
+    const rows = [...burns, ...mints]
+        .filter(r => assetIds.includes(r.asset_id) && r.leaf)
+        .sort((a, b) => a.asset_id - b.asset_id || a.kind.localeCompare(b.kind) || a.nonce - b.nonce);
+
+    const report = {
+        generated_at: new Date().toISOString(),
+        range: { from: args.from, to: args.to },
+        programs: config.programs,
+        assets,
+        unsupported_burns: unknown,
+        anomalies,
+        rows: rows.map(r => ({ ...r, amount: r.amount.toString() })),
+    };
+
+    // Signature over the canonical (compact) JSON of the report body
+    const body = JSON.stringify(report);
+    const signature = nacl.sign.detached(Buffer.from(body), signer.secretKey);
+    const signed = {
+        report,
+        signer: signer.publicKey.toBase58(),
+        signature: bs58.encode(signature),
+    };
+
+    fs.writeFileSync(`${args.out}.json`, JSON.stringify(signed, null, 2));
+    fs.writeFileSync(`${args.out}.csv`, [
+        `# signer=${signed.signer} signature=${signed.signature} (over the JSON report body)`,
+        'kind,asset_id,nonce,user,amount,timestamp,leaf',
+        ...rows.map(r => [r.kind, r.asset_id, r.nonce, r.user, r.amount, r.timestamp, r.leaf].join(',')),
+    ].join('\n') + '\n');
+
+    console.log(`\n✅ Wrote ${args.out}.json and ${args.out}.csv (signed by ${signed.signer})`);
+
+    if (anomalies.length > 0) {
+        anomalies.forEach(a => console.log(`   🚨 ${a}`));
+        process.exit(2);
+    }
+}
+
+main().catch(error => {
+    console.error('❌ Report failed:', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,180 @@
+/**
+ * Mint Rate Limit (minted volume cap per rolling window)
+ *
+ * Each mint program can cap how much it mints per window, so a compromised
+ * validator quorum cannot drain unlimited supply at once. The mint
+ * authority creates the limit; afterwards only validators can change the
+ * cap, and an increase takes effect one window after it is submitted.
+ *
+ * Usage:
+ *   npx ts-node scripts/rate-limit.ts init    --asset 1 --window 86400 --cap <base units>   (mint authority)
+ *   npx ts-node scripts/rate-limit.ts status  --asset 1
+ *   npx ts-node scripts/rate-limit.ts propose --asset 1 --cap <base units> [--out rate-limit.json]
+ *   npx ts-node scripts/rate-limit.ts sign    --file rate-limit.json   (validator key)
+ *   npx ts-node scripts/rate-limit.ts submit  --file rate-limit.json
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchRateLimit, rateLimitAddress, rateLimitHeadroom, rateLimitMessage } from '../sdk/attestation-client/src/rate-limit';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const VALIDATOR_SET = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+
+const ASSETS: Record<number, { program: PublicKey; idl: string; mintStateSeed: string }> = {
+    1: { program: new PublicKey(config.programs.xencat_mint), idl: 'target/idl/xencat_mint_x1.json', mintStateSeed: 'mint_state_v2' },
+    2: { program: new PublicKey(config.programs.dgn_mint), idl: 'target/idl/dgn_mint_x1.json', mintStateSeed: 'dgn_mint_state' },
+};
+
+interface RateLimitProposal {
+    asset_id: number;
+    /** base units, decimal string */
+    cap: string;
+    validator_set_version: number;
+    nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function proposalMessage(p: Omit<RateLimitProposal, 'message' | 'approvals'>): Buffer {
+    return rateLimitMessage(ASSETS[p.asset_id].program, p.validator_set_version, p.nonce, BigInt(p.cap), proposalX1GenesisHash(p));
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function asset(assetId: number) {
+    if (!ASSETS[assetId]) {
+        throw new Error(`Unknown asset ${assetId}`);
+    }
+    return ASSETS[assetId];
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const lightClient: any = new anchor.Program(
+        JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8')), LIGHT_CLIENT_PROGRAM, provider);
+    const mintProgram = (assetId: number): any =>
+        new anchor.Program(JSON.parse(fs.readFileSync(asset(assetId).idl, 'utf-8')), asset(assetId).program, provider);
+
+    switch (command) {
+        case 'init': {
+            const assetId = parseInt(arg('asset'));
+            const { program, mintStateSeed } = asset(assetId);
+            const tx = await mintProgram(assetId).methods
+                .initializeRateLimit(new anchor.BN(arg('window', '86400')), new anchor.BN(arg('cap')))
+                .accounts({
+                    mintState: PublicKey.findProgramAddressSync([Buffer.from(mintStateSeed)], program)[0],
+                    rateLimit: rateLimitAddress(program),
+                    authority: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Rate limit created for asset ${assetId}: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const assetId = parseInt(arg('asset'));
+            const limit = await fetchRateLimit(connection, asset(assetId).program);
+            console.log(`🚦 Rate limit of asset ${assetId} (${rateLimitAddress(asset(assetId).program).toBase58()})`);
+            if (!limit) {
+                console.log('   Not initialized: no cap');
+                break;
+            }
+            console.log(`   Cap: ${limit.cap} per ${limit.windowSeconds}s`);
+            if (limit.pendingCapAt !== 0) {
+                console.log(`   Pending: ${limit.pendingCap} from ${new Date(limit.pendingCapAt * 1000).toISOString()}`);
+            }
+            console.log(`   Headroom now: ${rateLimitHeadroom(limit, Math.floor(Date.now() / 1000))}`);
+            break;
+        }
+
+        case 'propose': {
+            const assetId = parseInt(arg('asset'));
+            const limit = await fetchRateLimit(connection, asset(assetId).program);
+            if (!limit) {
+                throw new Error(`Asset ${assetId} has no rate limit: run init first`);
+            }
+            const set = await lightClient.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const base = {
+                asset_id: assetId,
+                cap: BigInt(arg('cap')).toString(),
+                validator_set_version: set.version.toNumber(),
+                nonce: Number(limit.nonce),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: RateLimitProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'rate-limit.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Rate limit proposal written to ${out}`);
+            console.log(`   Cap: ${limit.cap} -> ${proposal.cap}${BigInt(proposal.cap) > limit.cap ? ' (increase: applies one window after submit)' : ''}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: RateLimitProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Asset ${proposal.asset_id} cap: ${proposal.cap}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed rate limit proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: RateLimitProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting rate limit with ${approverSignatures.length} valid approvals`);
+
+            const tx = await mintProgram(proposal.asset_id).methods
+                .setRateLimit({ cap: new anchor.BN(proposal.cap), approverSignatures })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    rateLimit: rateLimitAddress(asset(proposal.asset_id).program),
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Rate limit of asset ${proposal.asset_id} updated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: rate-limit.ts <init|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,186 @@
+/**
+ * Rent Sponsor Pool
+ *
+ * The pool pays the VerifiedBurnV3 rent of burns up to max_burn_amount, so
+ * new users can bridge without holding XNT for rent. Anyone can fund it;
+ * the limit and withdrawals need validator governance. Rent comes back to
+ * the pool when the sponsored burn is archived (scripts/burn-archive.ts).
+ *
+ * Usage:
+ *   npx ts-node scripts/rent-sponsor-pool.ts init
+ *   npx ts-node scripts/rent-sponsor-pool.ts fund    --lamports 1000000000
+ *   npx ts-node scripts/rent-sponsor-pool.ts status
+ *   npx ts-node scripts/rent-sponsor-pool.ts propose --max-amount 5000000 [--withdraw 0] [--recipient <pubkey>] [--out sponsor.json]
+ *   npx ts-node scripts/rent-sponsor-pool.ts sign    --file sponsor.json   (validator key)
+ *   npx ts-node scripts/rent-sponsor-pool.ts submit  --file sponsor.json
+ *
+ * `propose` binds the current validator set version and BridgeConfig
+ * nonce, so collect signatures and submit promptly.
+ */
+
+import 'dotenv/config';
+import { Connection, LAMPORTS_PER_SOL, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const POOL = pda('rent_sponsor_pool');
+
+interface PoolProposal {
+    max_burn_amount: number;
+    withdraw_lamports: number;
+    recipient: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_rent_sponsor_pool_message on-chain */
+function poolMessage(p: Omit<PoolProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('CONFIGURE_RENT_SPONSOR_POOL'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        u64(p.max_burn_amount),
+        u64(p.withdraw_lamports),
+        new PublicKey(p.recipient).toBuffer(),
+        proposalX1GenesisHash(p) ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods.initializeRentSponsorPool()
+                .accounts({ rentSponsorPool: POOL, payer: wallet.publicKey, systemProgram: SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Rent sponsor pool created (sponsorship off until governance sets a limit): ${tx}`);
+            break;
+        }
+
+        case 'fund': {
+            const lamports = Number(arg('lamports'));
+            const tx = await program.methods.fundRentSponsorPool(new anchor.BN(lamports))
+                .accounts({ rentSponsorPool: POOL, funder: wallet.publicKey, systemProgram: SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Funded pool with ${lamports / LAMPORTS_PER_SOL} XNT: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const pool = await program.account.rentSponsorPool.fetch(POOL);
+            const balance = await connection.getBalance(POOL);
+            console.log(`🎁 Rent sponsor pool ${POOL.toBase58()}`);
+            console.log(`   Balance:        ${balance / LAMPORTS_PER_SOL} XNT`);
+            console.log(`   Sponsors burns: ${pool.maxBurnAmount.isZero() ? 'off' : `up to ${pool.maxBurnAmount.toString()} base units`}`);
+            console.log(`   Funded:         ${pool.totalFunded.toNumber() / LAMPORTS_PER_SOL} XNT`);
+            console.log(`   Lent:           ${pool.totalSponsored.toNumber() / LAMPORTS_PER_SOL} XNT`);
+            console.log(`   Reimbursed:     ${pool.totalReimbursed.toNumber() / LAMPORTS_PER_SOL} XNT`);
+            console.log(`   Outstanding:    ${pool.outstanding.toString()} burns`);
+            break;
+        }
+
+        case 'propose': {
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                max_burn_amount: Number(arg('max-amount')),
+                withdraw_lamports: Number(arg('withdraw', '0')),
+                recipient: new PublicKey(arg('recipient', wallet.publicKey.toBase58())).toBase58(),
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: PoolProposal = { ...base, message: poolMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'sponsor.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Pool proposal written to ${out}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: PoolProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = poolMessage(proposal);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed pool proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: PoolProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = poolMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting pool update with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .configureRentSponsorPool({
+                    maxBurnAmount: new anchor.BN(proposal.max_burn_amount),
+                    withdrawLamports: new anchor.BN(proposal.withdraw_lamports),
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    rentSponsorPool: POOL,
+                    recipient: new PublicKey(proposal.recipient),
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Rent sponsor pool updated: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: rent-sponsor-pool.ts <init|fund|status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,187 @@
+/**
+ * Burn Settlement Relayer (Solana-side mirror of X1 mints)
+ *
+ * Collects "mint completed" acknowledgements for a burn from the X1
+ * validators (/attest-mint) and submits them to the burn program's
+ * settle_burn, which records a BurnSettlement next to the BurnRecord.
+ * With --close the settled BurnRecord is also closed and its rent
+ * returned to the burner.
+ *
+ * Usage:
+ *   npx ts-node scripts/settle-burn.ts --nonce 182 --asset 2 [--close]
+ *
+ * Only `threshold` acknowledgements are included: each one is an Ed25519
+ * instruction in the same transaction. Uses the shared bridge config.
+ */
+
+import 'dotenv/config';
+import {
+    Connection, Ed25519Program, PublicKey, SYSVAR_INSTRUCTIONS_PUBKEY, SystemProgram,
+    Transaction, TransactionInstruction, sendAndConfirmTransaction,
+} from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+
+const { config } = loadConfig();
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+
+/** Must match SETTLEMENT_DOMAIN in the burn program */
+const SETTLEMENT_DOMAIN = 'XENCAT_X1_SETTLEMENT_V1';
+
+const discriminator = (name: string) => crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+const u64 = (value: number) => new anchor.BN(value).toArrayLike(Buffer, 'le', 8);
+const i64 = (value: number) => new anchor.BN(value).toTwos(64).toArrayLike(Buffer, 'le', 8);
+
+interface MintAck {
+    x1_processed_at: number;
+    settlement_version: number;
+    validator_pubkey: string;
+    signature: number[];
+}
+
+/** Must match settlement_message in the burn program */
+function settlementMessage(version: number, assetId: number, nonce: number, user: PublicKey, amount: number, x1ProcessedAt: number): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(SETTLEMENT_DOMAIN),
+        Buffer.from('MINTED'),
+        u64(version),
+        Buffer.from([assetId]),
+        u64(nonce),
+        user.toBuffer(),
+        u64(amount),
+        i64(x1ProcessedAt),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const burnNonce = Number(arg('nonce'));
+    const assetId = Number(arg('asset'));
+    const close = process.argv.includes('--close');
+
+    console.log(`🧾 Settling burn ${burnNonce} (asset ${assetId})`);
+
+    const relayer = loadKeypair(config, 'user');
+    const solana = new Connection(config.solana.rpc, 'confirmed');
+
+    const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, BURN_PROGRAM)[0];
+    const settlementConfigPda = pda(Buffer.from('settlement_config'));
+    const burnRecordPda = pda(Buffer.from('burn_record'), u64(burnNonce));
+    const burnSettlementPda = pda(Buffer.from('burn_settlement'), u64(burnNonce));
+
+    const configInfo = await solana.getAccountInfo(settlementConfigPda);
+    const recordInfo = await solana.getAccountInfo(burnRecordPda);
+    if (!configInfo || !recordInfo) {
+        throw new Error(!configInfo ? 'Settlement is not initialized on the burn program' : `Burn record ${burnNonce} not found (closed?)`);
+    }
+
+    // SettlementConfig: discriminator(8) || validators(4 + 32n) || threshold(1) || version(8)
+    const count = configInfo.data.readUInt32LE(8);
+    const members = new Set(Array.from({ length: count }, (_, i) =>
+        new PublicKey(configInfo.data.subarray(12 + 32 * i, 44 + 32 * i)).toBase58()));
+    const threshold = configInfo.data[12 + 32 * count];
+    const version = Number(configInfo.data.readBigUInt64LE(13 + 32 * count));
+
+    // BurnRecord: discriminator(8) || user(32) || amount(8) || ... || destination(32 at 98)
+    // Settlement is acknowledged for the attested key; the rent goes back to the burner
+    const burner = new PublicKey(recordInfo.data.subarray(8, 40));
+    const destination = recordInfo.data.length >= 195 ? new PublicKey(recordInfo.data.subarray(98, 130)) : PublicKey.default;
+    const user = destination.equals(PublicKey.default) ? burner : destination;
+    const amount = Number(recordInfo.data.readBigUInt64LE(40));
+
+    const instructions: TransactionInstruction[] = [];
+    const settled = await solana.getAccountInfo(burnSettlementPda) !== null;
+
+    if (settled) {
+        console.log('   Already settled');
+    } else {
+        // 1. Collect acknowledgements (all must report the same X1 mint)
+        const acks: MintAck[] = [];
+        for (const validator of config.attestation.validators) {
+            if (!members.has(validator.pubkey) || acks.length >= threshold) {
+                continue;
+            }
+            try {
+                const response = await fetch(`${validator.url}/attest-mint`, {
+                    method: 'POST',
+                    headers: { 'Content-Type': 'application/json', 'X-Validator-Key': validator.pubkey },
+                    body: JSON.stringify({ burn_nonce: burnNonce, asset_id: assetId }),
+                });
+                const body: any = await response.json();
+                if (!response.ok || body.validator_pubkey !== validator.pubkey) {
+                    console.log(`  ⚠️  ${validator.url}: ${body.error || `HTTP ${response.status}`}`);
+                    continue;
+                }
+                if (body.settlement_version !== version || (acks.length > 0 && body.x1_processed_at !== acks[0].x1_processed_at)) {
+                    console.log(`  ⚠️  ${validator.url}: acknowledgement does not match (version ${body.settlement_version})`);
+                    continue;
+                }
+                acks.push(body);
+                console.log(`  ✅ ${validator.url}`);
+            } catch (err: any) {
+                console.log(`  ❌ ${validator.url}: ${err.message}`);
+            }
+        }
+
+        if (acks.length < threshold) {
+            throw new Error(`Insufficient acknowledgements: got ${acks.length}, need ${threshold}`);
+        }
+
+        // 2. Ed25519 instructions first; settle_burn reads them by introspection
+        const x1ProcessedAt = acks[0].x1_processed_at;
+        const message = settlementMessage(version, assetId, burnNonce, user, amount, x1ProcessedAt);
+        for (const ack of acks) {
+            instructions.push(Ed25519Program.createInstructionWithPublicKey({
+                publicKey: new PublicKey(ack.validator_pubkey).toBytes(),
+                message,
+                signature: Uint8Array.from(ack.signature),
+            }));
+        }
+        instructions.push(new TransactionInstruction({
+            programId: BURN_PROGRAM,
+            keys: [
+                { pubkey: relayer.publicKey, isSigner: true, isWritable: true },
+                { pubkey: settlementConfigPda, isSigner: false, isWritable: true },
+                { pubkey: burnRecordPda, isSigner: false, isWritable: false },
+                { pubkey: burnSettlementPda, isSigner: false, isWritable: true },
+                { pubkey: SYSVAR_INSTRUCTIONS_PUBKEY, isSigner: false, isWritable: false },
+                { pubkey: SystemProgram.programId, isSigner: false, isWritable: false },
+            ],
+            data: Buffer.concat([discriminator('settle_burn'), Buffer.from([assetId]), i64(x1ProcessedAt)]),
+        }));
+    }
+
+    if (close) {
+        instructions.push(new TransactionInstruction({
+            programId: BURN_PROGRAM,
+            keys: [
+                { pubkey: burnRecordPda, isSigner: false, isWritable: true },
+                { pubkey: burnSettlementPda, isSigner: false, isWritable: false },
+                { pubkey: burner, isSigner: false, isWritable: true },
+            ],
+            data: discriminator('close_burn_record'),
+        }));
+    }
+
+    if (instructions.length === 0) {
+        console.log('✅ Nothing to do');
+        return;
+    }
+
+    const signature = await sendAndConfirmTransaction(solana, new Transaction().add(...instructions), [relayer]);
+    console.log(`\n✅ Burn ${burnNonce}${settled ? '' : ' settled'}${close ? ', record closed' : ''}: ${signature}`);
+}
+
+main().catch(err => {
+    console.error('❌', err.message);
+    process.exit(1);
+});
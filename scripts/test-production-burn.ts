@@ -198,7 +198,8 @@ async function main() {
             stake: vote.stake,
         })),
         proof.blockHash,
-        BigInt(proof.slot)
+        BigInt(proof.slot),
+        proof.stateRoot
     );
 
     console.log(`✅ Created ${ed25519Instructions.length} Ed25519Program instructions\n`);
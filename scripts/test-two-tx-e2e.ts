@@ -85,7 +85,8 @@ async function main() {
     const ed25519Instructions = createValidatorEd25519Instructions(
         validatorVotes,
         proof.blockHash,
-        proof.slot
+        proof.slot,
+        proof.stateRoot
     );
 
     console.log(`✅ Built ${ed25519Instructions.length} Ed25519 instructions\n`);
@@ -136,7 +137,9 @@ async function main() {
         blockHash: Array.from(proof.blockHash),
         stateRoot: Array.from(proof.stateRoot),
         merkleProof: proof.merkleProof.map(p => Array.from(p)),
+        burnRecordData: Buffer.from(proof.burnRecordData),
         validatorCount: proof.validatorCount,
+        ed25519Indices: Buffer.from(proof.ed25519Indices),
     };
 
     const submitProofIx = await lightClientProgram.methods
@@ -171,9 +174,10 @@ async function main() {
     console.log(`    - Block hash: 32 bytes`);
     console.log(`    - State root: 32 bytes`);
     console.log(`    - Merkle proof (${proof.merkleProof.length} levels): ${proof.merkleProof.length * 32} bytes`);
+    console.log(`    - Burn record: ${proof.burnRecordData.length} bytes`);
     console.log(`    - Validator count: 1 byte`);
-    console.log(`    Total proof data: ~${8+32+8+8+32+32+(proof.merkleProof.length*32)+1} bytes`);
-    console.log(`\n  🎯 OPTIMIZATION: Removed burn_record_data (89 bytes) and validator_votes (${validators.length * 104} bytes)`);
+    console.log(`    Total proof data: ~${8+32+8+8+32+32+(proof.merkleProof.length*32)+4+proof.burnRecordData.length+1} bytes`);
+    console.log(`\n  🎯 OPTIMIZATION: Removed validator_votes (${validators.length * 104} bytes)`);
     console.log(`     Savings: ${89 + (validators.length * 104)} bytes!`);
 
     const tx1Size = tx1.serialize({ requireAllSignatures: false }).length;
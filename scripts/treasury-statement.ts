@@ -0,0 +1,146 @@
+/**
+ * Treasury Statement (per-wallet bridge accounting export)
+ *
+ * Walks the X1 bridge transactions of a date range and lists every transfer
+ * verified in it: burner wallet, asset and amount, verification and mint
+ * times, the Solana burn and X1 transactions with explorer links, and the
+ * fees the wallet paid or a relayer covered. See
+ * sdk/attestation-client/src/treasury-statement.ts for the rules.
+ *
+ * Usage:
+ *   npm run report:statement -- --from 2026-09-01 --to 2026-09-30 [--wallets <pubkey>,<pubkey>] [--out-dir reports]
+ *
+ * Writes <out-dir>/statement-<from>_<to>.csv (one row per transfer) and
+ * <out-dir>/statement-<from>_<to>.json (totals per wallet and asset, and
+ * the transfers). Dates are UTC and both days are included.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import path from 'path';
+import { loadConfig } from '../sdk/bridge-config/src';
+import { feeVaultAddress } from '../sdk/attestation-client/src/fee-vault';
+import { CostAccountingContext, signaturesInRange } from '../sdk/attestation-client/src/cost-accounting';
+import { AssetMetadata, formatAssetAmount, simulateAssetMetadata } from '../sdk/attestation-client/src/asset-metadata';
+import {
+    StatementRecords,
+    buildStatement,
+    dayRange,
+    fetchBurnSignature,
+    statementRecords,
+    statementToCsv,
+    walletTotals,
+} from '../sdk/attestation-client/src/treasury-statement';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const BURN_PROGRAM = new PublicKey(config.programs.burn);
+const MINT_PROGRAMS = [config.programs.xencat_mint, config.programs.dgn_mint, config.programs.bridge_mint]
+    .map(program => new PublicKey(program));
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+const lamports = (value: bigint) => `${(Number(value) / 1e9).toFixed(6)} XNT`;
+
+async function main() {
+    const from = arg('from');
+    const to = arg('to');
+    const outDir = arg('out-dir', config.accounting.report_dir);
+    const walletList = arg('wallets', '');
+    const wallets = walletList ? new Set(walletList.split(',').map(w => new PublicKey(w.trim()).toBase58())) : undefined;
+    const { start, end } = dayRange(from, to);
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const solana = new Connection(config.solana.rpc, 'confirmed');
+
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const validatorSetPda = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, new anchor.AnchorProvider(connection, {} as any, {}));
+    const validatorSet = await program.account.x1ValidatorSet.fetch(validatorSetPda);
+
+    // Validators of the current set (fee recipients of earlier sets are missed)
+    const validatorFeeAccounts = new Set<string>();
+    for (const validator of validatorSet.validators as PublicKey[]) {
+        validatorFeeAccounts.add(validator.toBase58());
+        for (const mintProgram of MINT_PROGRAMS) {
+            validatorFeeAccounts.add(feeVaultAddress(mintProgram, validator).toBase58());
+        }
+    }
+    const context: CostAccountingContext = {
+        lightClientProgramId: LIGHT_CLIENT_PROGRAM,
+        lightClientCoder: program.coder,
+        mintProgramIds: MINT_PROGRAMS,
+        validatorFeeAccounts,
+        rentSponsorPool: PublicKey.findProgramAddressSync([Buffer.from('rent_sponsor_pool')], LIGHT_CLIENT_PROGRAM)[0],
+        integrators: new Map(),
+    };
+
+    console.log(`📒 Treasury statement ${from} to ${to}${wallets ? ` for ${wallets.size} wallets` : ''}`);
+    const signatures = new Set<string>();
+    for (const programId of [LIGHT_CLIENT_PROGRAM, ...MINT_PROGRAMS]) {
+        for (const signature of await signaturesInRange(connection, programId, start, end)) {
+            signatures.add(signature);
+        }
+    }
+    console.log(`   ${signatures.size} transactions`);
+
+    const records: StatementRecords[] = [];
+    for (const signature of signatures) {
+        const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'confirmed' });
+        if (tx) {
+            records.push(statementRecords(tx, context));
+        }
+    }
+    const transfers = buildStatement(records, wallets);
+    for (const transfer of transfers) {
+        transfer.burnSignature = await fetchBurnSignature(solana, BURN_PROGRAM, transfer.burnNonce);
+    }
+
+    // Display amounts from the bridge's asset metadata (base units only if
+    // unavailable); a validator key is a funded fee payer to simulate with
+    const metadata = new Map<number, AssetMetadata>();
+    for (const assetId of new Set(transfers.map(t => t.assetId))) {
+        try {
+            metadata.set(assetId, await simulateAssetMetadata(connection, LIGHT_CLIENT_PROGRAM, validatorSet.validators[0], assetId));
+        } catch (error: any) {
+            console.log(`   ⚠️  No metadata for asset ${assetId}: ${error.message}`);
+        }
+    }
+    const formatAmount = (assetId: number, amount: bigint) => {
+        const asset = metadata.get(assetId);
+        return asset ? formatAssetAmount(asset, amount) : amount.toString();
+    };
+
+    const totals = walletTotals(transfers);
+    fs.mkdirSync(outDir, { recursive: true });
+    const base = path.join(outDir, `statement-${from}_${to}`);
+    fs.writeFileSync(`${base}.csv`, statementToCsv(transfers, { x1: config.accounting.x1_explorer, solana: config.accounting.solana_explorer }, formatAmount));
+    fs.writeFileSync(`${base}.json`, JSON.stringify(
+        { from, to, wallets: totals, transfers },
+        (_, v) => (typeof v === 'bigint' ? v.toString() : v),
+        2,
+    ));
+
+    for (const t of totals) {
+        console.log(`   ${t.wallet} asset ${t.assetId}: ${t.transfers} transfers, ${formatAmount(t.assetId, t.amount)}`);
+        console.log(`      fees paid ${lamports(t.feesPaid)}, covered by relayers ${lamports(t.feesCovered)}`);
+    }
+    const unlinked = transfers.filter(t => !t.burnSignature).length;
+    if (unlinked > 0) {
+        console.log(`   ⚠️  ${unlinked} transfers without a Solana burn transaction (RPC history too short?)`);
+    }
+    console.log(`✅ Wrote ${base}.csv and ${base}.json`);
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,107 @@
+/**
+ * Solana Epoch Attestation Relayer
+ *
+ * Observes the current Solana epoch and total active stake, collects
+ * matching signatures from the X1 validators and submits them to
+ * update_solana_epoch on the light client.
+ *
+ * Usage:
+ *   npx ts-node scripts/update-solana-epoch.ts
+ *
+ * Uses the shared bridge config (xencat.toml + env overrides).
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+async function main() {
+    console.log('🕐 Solana Epoch Attestation Relayer');
+    console.log('='.repeat(60));
+
+    const relayer = loadKeypair(config, 'user');
+    const solana = new Connection(config.solana.rpc, 'finalized');
+    const x1 = new Connection(config.x1.rpc, 'confirmed');
+    const provider = new anchor.AnchorProvider(x1, new anchor.Wallet(relayer), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    const [validatorSetPda] = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM);
+    const [chainStatePda] = PublicKey.findProgramAddressSync([Buffer.from('solana_chain_state')], LIGHT_CLIENT_PROGRAM);
+    const validatorSet: any = await program.account.x1ValidatorSet.fetch(validatorSetPda);
+
+    // 1. Propose one observation (every validator must sign identical values)
+    const epochInfo = await solana.getEpochInfo('finalized');
+    const voteAccounts = await solana.getVoteAccounts('finalized');
+    const totalStake = [...voteAccounts.current, ...voteAccounts.delinquent]
+        .reduce((sum, v) => sum + BigInt(v.activatedStake), BigInt(0));
+
+    const observation = {
+        epoch: epochInfo.epoch,
+        total_stake: totalStake.toString(),
+        solana_slot: epochInfo.absoluteSlot,
+        validator_set_version: validatorSet.version.toNumber(),
+    };
+    console.log(`📡 Observation: epoch ${observation.epoch}, slot ${observation.solana_slot}, stake ${observation.total_stake}\n`);
+
+    // 2. Collect signatures
+    const members = new Set(validatorSet.validators.map((v: PublicKey) => v.toBase58()));
+    const approvals: any[] = [];
+    for (const validator of config.attestation.validators) {
+        if (!members.has(validator.pubkey) || approvals.length >= validatorSet.threshold) {
+            continue;
+        }
+        try {
+            const response = await fetch(`${validator.url}/attest-solana-epoch`, {
+                method: 'POST',
+                headers: { 'Content-Type': 'application/json' },
+                body: JSON.stringify(observation),
+            });
+            const body: any = await response.json();
+            if (!response.ok || body.validator_pubkey !== validator.pubkey) {
+                console.log(`  ⚠️  ${validator.url}: ${body.error || `HTTP ${response.status}`}`);
+                continue;
+            }
+            approvals.push({
+                validatorPubkey: new PublicKey(body.validator_pubkey),
+                signature: body.signature,
+            });
+            console.log(`  ✅ ${validator.url}`);
+        } catch (err: any) {
+            console.log(`  ❌ ${validator.url}: ${err.message}`);
+        }
+    }
+
+    if (approvals.length < validatorSet.threshold) {
+        throw new Error(`Insufficient approvals: got ${approvals.length}, need ${validatorSet.threshold}`);
+    }
+
+    // 3. Submit
+    const tx = await program.methods
+        .updateSolanaEpoch({
+            epoch: new anchor.BN(observation.epoch),
+            totalStake: new anchor.BN(observation.total_stake),
+            solanaSlot: new anchor.BN(observation.solana_slot),
+            approverSignatures: approvals,
+        })
+        .accounts({
+            validatorSet: validatorSetPda,
+            x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+            solanaChainState: chainStatePda,
+            signer: relayer.publicKey,
+        })
+        .rpc();
+
+    console.log(`\n✅ Solana chain state updated: ${tx}`);
+}
+
+main().catch(err => {
+    console.error('❌', err.message);
+    process.exit(1);
+});
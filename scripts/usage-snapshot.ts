@@ -0,0 +1,230 @@
+/**
+ * Usage Snapshots (per-user bridged volume for incentive programs)
+ *
+ * Once a usage epoch (one X1 epoch) has ended, validators commit the Merkle
+ * root of every user's bridged volume per asset, rebuilt from the
+ * BurnVerified events of the epoch. Airdrop and points programs then check
+ * a user's entry with `verify_usage_proof` rather than trusting an indexer.
+ *
+ * Usage:
+ *   npx ts-node scripts/usage-snapshot.ts status  [--epoch 412]
+ *   npx ts-node scripts/usage-snapshot.ts propose --epoch 412 [--out usage-412.json]
+ *   npx ts-node scripts/usage-snapshot.ts sign    --file usage-412.json   (validator key)
+ *   npx ts-node scripts/usage-snapshot.ts submit  --file usage-412.json
+ *   npx ts-node scripts/usage-snapshot.ts prove   --file usage-412.json --user <pubkey> [--asset 1]
+ *
+ * `sign` rebuilds the tree from the validator's own RPC and refuses to sign
+ * a root it does not reproduce. Keep the proposal file (or publish it):
+ * users' proofs are built from its entries.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    USAGE_EPOCH_SLOTS,
+    aggregateUsage,
+    buildUsageTree,
+    fetchUsageSnapshot,
+    usageEpoch,
+    usageSnapshotAddress,
+    usageSnapshotMessage,
+} from '../sdk/attestation-client/src/usage-snapshot';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+const VALIDATOR_SET = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM)[0];
+
+interface UsageProposal {
+    epoch: number;
+    validator_set_version: number;
+    /** hex */
+    root: string;
+    /** In leaf order; volume in base units, decimal string */
+    entries: Array<{ user: string; asset_id: number; volume: string }>;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+function proposalMessage(p: Pick<UsageProposal, 'validator_set_version' | 'epoch' | 'root' | 'entries' | 'x1_genesis_hash'>): Buffer {
+    return usageSnapshotMessage(p.validator_set_version, p.epoch, Buffer.from(p.root, 'hex'), p.entries.length, proposalX1GenesisHash(p));
+}
+
+/** BurnVerified amounts of the epoch, from the light client's transaction history */
+async function collectBurns(connection: Connection, coder: anchor.Coder, epoch: number) {
+    const first = epoch * USAGE_EPOCH_SLOTS;
+    const end = first + USAGE_EPOCH_SLOTS;
+    const parser = new anchor.EventParser(LIGHT_CLIENT_PROGRAM, coder as any);
+    const burns: Array<{ user: PublicKey; assetId: number; amount: bigint }> = [];
+
+    // Newest first: skip past the epoch, stop once before it
+    let before: string | undefined;
+    for (;;) {
+        const page = await connection.getSignaturesForAddress(LIGHT_CLIENT_PROGRAM, { before, limit: 1000 });
+        for (const { signature, slot, err } of page) {
+            if (err || slot >= end || slot < first) {
+                continue;
+            }
+            const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'confirmed' });
+            for (const event of parser.parseLogs(tx?.meta?.logMessages || [])) {
+                if (event.name !== 'BurnVerified') {
+                    continue;
+                }
+                const data: any = event.data;
+                if (usageEpoch(data.verifiedSlot.toNumber()) === epoch) {
+                    burns.push({ user: data.user, assetId: data.assetId, amount: BigInt(data.amount.toString()) });
+                }
+            }
+        }
+        if (page.length < 1000 || page[page.length - 1].slot < first) break;
+        before = page[page.length - 1].signature;
+    }
+    return burns;
+}
+
+async function rebuild(connection: Connection, coder: anchor.Coder, epoch: number) {
+    const entries = aggregateUsage(await collectBurns(connection, coder, epoch));
+    return { entries, tree: buildUsageTree(epoch, entries) };
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const current = usageEpoch(await connection.getSlot());
+            const epoch = Number(arg('epoch', String(current - 1)));
+            const snapshot = await fetchUsageSnapshot(connection, LIGHT_CLIENT_PROGRAM, epoch);
+            console.log(`📸 Usage snapshot of epoch ${epoch} (current epoch ${current})`);
+            if (!snapshot) {
+                console.log(`   Not committed${epoch >= current ? ': epoch has not ended' : ''}`);
+                break;
+            }
+            console.log(`   Root: ${snapshot.root.toString('hex')}`);
+            console.log(`   Leaves: ${snapshot.leafCount} (committed at slot ${snapshot.committedSlot})`);
+            break;
+        }
+
+        case 'propose': {
+            const epoch = Number(arg('epoch'));
+            if (epoch >= usageEpoch(await connection.getSlot())) {
+                throw new Error(`Epoch ${epoch} has not ended`);
+            }
+            const { entries, tree } = await rebuild(connection, program.coder, epoch);
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const base = {
+                epoch,
+                validator_set_version: set.version.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+                root: tree.root.toString('hex'),
+                entries: entries.map(e => ({ user: e.user.toBase58(), asset_id: e.assetId, volume: e.volume.toString() })),
+            };
+            const proposal: UsageProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', `usage-${epoch}.json`);
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Usage snapshot of epoch ${epoch} written to ${out}`);
+            console.log(`   ${entries.length} user/asset entries, root ${proposal.root}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: UsageProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            const { tree } = await rebuild(connection, program.coder, proposal.epoch);
+            if (tree.root.toString('hex') !== proposal.root || tree.entries.length !== proposal.entries.length) {
+                throw new Error(`Rebuilt root ${tree.root.toString('hex')} (${tree.entries.length} entries) does not match the proposal`);
+            }
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed usage snapshot of epoch ${proposal.epoch}; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: UsageProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting usage snapshot of epoch ${proposal.epoch} with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .commitUsageSnapshot(new anchor.BN(proposal.epoch), {
+                    root: Array.from(Buffer.from(proposal.root, 'hex')),
+                    leafCount: new anchor.BN(proposal.entries.length),
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    usageSnapshot: usageSnapshotAddress(LIGHT_CLIENT_PROGRAM, proposal.epoch),
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Usage snapshot committed: ${tx}`);
+            break;
+        }
+
+        case 'prove': {
+            const proposal: UsageProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const user = new PublicKey(arg('user')).toBase58();
+            const assetId = Number(arg('asset', '1'));
+            const index = proposal.entries.findIndex(e => e.user === user && e.asset_id === assetId);
+            if (index < 0) {
+                throw new Error(`${user} bridged no asset ${assetId} in epoch ${proposal.epoch}`);
+            }
+            const tree = buildUsageTree(proposal.epoch, proposal.entries.map(e => ({
+                user: new PublicKey(e.user),
+                assetId: e.asset_id,
+                volume: BigInt(e.volume),
+            })));
+            console.log(JSON.stringify({
+                epoch: proposal.epoch,
+                user,
+                asset_id: assetId,
+                volume: proposal.entries[index].volume,
+                leaf_index: index,
+                proof: tree.proof(index).map(p => p.toString('hex')),
+                root: tree.root.toString('hex'),
+            }, null, 2));
+            break;
+        }
+
+        default:
+            console.error('Usage: usage-snapshot.ts <status|propose|sign|submit|prove> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
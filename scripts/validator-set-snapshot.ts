@@ -0,0 +1,265 @@
+/**
+ * Validator Set Snapshot (disaster recovery)
+ *
+ * Exports the X1ValidatorSet to a JSON snapshot and, if the validator set
+ * PDA is ever corrupted (e.g. by a bad upgrade), drives the on-chain
+ * restore: checkpoint validators sign the snapshot, anyone schedules it,
+ * and after the RESTORE_TIMELOCK_SLOTS timelock anyone executes it.
+ *
+ * Usage:
+ *   npx ts-node scripts/validator-set-snapshot.ts export   [--out snapshot.json]
+ *   npx ts-node scripts/validator-set-snapshot.ts sign     --snapshot snapshot.json   (validator key)
+ *   npx ts-node scripts/validator-set-snapshot.ts schedule --snapshot snapshot.json
+ *   npx ts-node scripts/validator-set-snapshot.ts cancel   [--approvals cancel.json]
+ *   npx ts-node scripts/validator-set-snapshot.ts execute
+ *
+ * `export` also syncs the on-chain checkpoint first (permissionless), so the
+ * checkpoint validators always match the latest healthy set. Run it after
+ * every validator set change and keep the file somewhere safe.
+ *
+ * `sign` binds the current checkpoint version and BridgeConfig nonce, so
+ * signatures must be collected right before `schedule`.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchX1ChainId, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const CHECKPOINT = pda('validator_set_checkpoint');
+const BRIDGE_CONFIG = pda('bridge_config');
+const PENDING_RESTORE = pda('validator_set_restore');
+
+interface Snapshot {
+    program: string;
+    exported_at: string;
+    exported_slot: number;
+    validator_set: { version: number; validators: string[]; threshold: number };
+    /** sha256 over version || validators || threshold (integrity check) */
+    sha256: string;
+    approvals?: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function snapshotHash(set: Snapshot['validator_set']): string {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        u64(set.version),
+        ...set.validators.map(v => new PublicKey(v).toBuffer()),
+        Buffer.from([set.threshold]),
+    ])).digest('hex');
+}
+
+/** Must match create_governance_message / create_restore_message on-chain */
+function governanceMessage(tag: string, version: number, payload: Buffer, x1GenesisHash: Buffer | null): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(tag),
+        u64(version),
+        payload,
+        x1GenesisHash ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+function restoreMessage(checkpointVersion: number, nonce: number, set: Snapshot['validator_set'], x1GenesisHash: Buffer | null): Buffer {
+    return governanceMessage('RESTORE_VALIDATOR_SET', checkpointVersion, Buffer.concat([
+        u64(nonce),
+        u64(set.version),
+        ...set.validators.map(v => new PublicKey(v).toBuffer()),
+        Buffer.from([set.threshold]),
+    ]), x1GenesisHash);
+}
+
+function cancelMessage(checkpointVersion: number, nonce: number, scheduledSlot: number, x1GenesisHash: Buffer | null): Buffer {
+    return governanceMessage('CANCEL_VALIDATOR_SET_RESTORE', checkpointVersion, Buffer.concat([u64(nonce), u64(scheduledSlot)]), x1GenesisHash);
+}
+
+function readSnapshot(file: string): Snapshot {
+    const snapshot: Snapshot = JSON.parse(fs.readFileSync(file, 'utf-8'));
+    if (snapshotHash(snapshot.validator_set) !== snapshot.sha256) {
+        throw new Error(`${file}: snapshot hash mismatch (file modified?)`);
+    }
+    return snapshot;
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const needsWallet = command !== 'sign';
+    const wallet = needsWallet ? loadKeypair(config, 'user') : undefined;
+    const provider = new anchor.AnchorProvider(
+        connection,
+        new anchor.Wallet(wallet || anchor.web3.Keypair.generate()),
+        { commitment: 'confirmed' }
+    );
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    const fetchGovernanceState = async () => {
+        const checkpoint = await program.account.validatorSetCheckpoint.fetch(CHECKPOINT);
+        const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+        const x1GenesisHash = await fetchX1ChainId(connection, LIGHT_CLIENT_PROGRAM);
+        return { checkpointVersion: checkpoint.version.toNumber(), nonce: bridgeConfig.nonce.toNumber(), checkpoint, x1GenesisHash };
+    };
+
+    switch (command) {
+        case 'export': {
+            if (!(await connection.getAccountInfo(CHECKPOINT))) {
+                console.log('📌 Creating validator set checkpoint...');
+                await program.methods.initializeValidatorSetCheckpoint()
+                    .accounts({ validatorSet: VALIDATOR_SET, checkpoint: CHECKPOINT, payer: wallet!.publicKey, systemProgram: SystemProgram.programId })
+                    .rpc();
+            } else {
+                await program.methods.syncValidatorSetCheckpoint()
+                    .accounts({ validatorSet: VALIDATOR_SET, checkpoint: CHECKPOINT, signer: wallet!.publicKey })
+                    .rpc();
+            }
+
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const validatorSet = {
+                version: set.version.toNumber(),
+                validators: set.validators.map((v: PublicKey) => v.toBase58()),
+                threshold: set.threshold,
+            };
+            const snapshot: Snapshot = {
+                program: LIGHT_CLIENT_PROGRAM.toBase58(),
+                exported_at: new Date().toISOString(),
+                exported_slot: await connection.getSlot('finalized'),
+                validator_set: validatorSet,
+                sha256: snapshotHash(validatorSet),
+            };
+            const out = arg('out', 'validator-set-snapshot.json');
+            fs.writeFileSync(out, JSON.stringify(snapshot, null, 2));
+            console.log(`✅ Snapshot of version ${validatorSet.version} (${validatorSet.validators.length} validators, threshold ${validatorSet.threshold}) written to ${out}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('snapshot');
+            const snapshot = readSnapshot(file);
+            const validator = loadKeypair(config, 'validator');
+            const { checkpointVersion, nonce, checkpoint, x1GenesisHash } = await fetchGovernanceState();
+            if (!checkpoint.validators.some((v: PublicKey) => v.equals(validator.publicKey))) {
+                throw new Error(`${validator.publicKey.toBase58()} is not a checkpoint validator`);
+            }
+
+            const message = restoreMessage(checkpointVersion, nonce, snapshot.validator_set, x1GenesisHash);
+            const approvals = (snapshot.approvals || []).filter(a => a.validator_pubkey !== validator.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: validator.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, validator.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...snapshot, approvals }, null, 2));
+            console.log(`✅ Signed restore (checkpoint v${checkpointVersion}, nonce ${nonce}); ${approvals.length}/${checkpoint.threshold} approvals`);
+            break;
+        }
+
+        case 'schedule': {
+            const snapshot = readSnapshot(arg('snapshot'));
+            const { checkpointVersion, nonce, x1GenesisHash } = await fetchGovernanceState();
+            const message = restoreMessage(checkpointVersion, nonce, snapshot.validator_set, x1GenesisHash);
+            const approvals = (snapshot.approvals || []).filter(a =>
+                nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes())
+            );
+            console.log(`🛟 Scheduling restore of version ${snapshot.validator_set.version} with ${approvals.length} valid approvals`);
+
+            const tx = await program.methods.scheduleValidatorSetRestore({
+                snapshotVersion: new anchor.BN(snapshot.validator_set.version),
+                validators: snapshot.validator_set.validators.map(v => new PublicKey(v)),
+                threshold: snapshot.validator_set.threshold,
+                approverSignatures: approvals.map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature })),
+            })
+                .accounts({
+                    checkpoint: CHECKPOINT,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    pendingRestore: PENDING_RESTORE,
+                    proposer: wallet!.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            const pending = await program.account.pendingValidatorSetRestore.fetch(PENDING_RESTORE);
+            console.log(`✅ Scheduled: ${tx}`);
+            console.log(`   Executable from slot ${pending.executableSlot.toString()}`);
+            break;
+        }
+
+        case 'cancel': {
+            const pending = await program.account.pendingValidatorSetRestore.fetch(PENDING_RESTORE);
+            const { checkpointVersion, nonce, x1GenesisHash } = await fetchGovernanceState();
+            const message = cancelMessage(checkpointVersion, nonce, pending.scheduledSlot.toNumber(), x1GenesisHash);
+            if (!process.argv.includes('--approvals')) {
+                // Without approvals: print what checkpoint validators must sign
+                console.log(`🛑 Cancel message (sign with each checkpoint validator): ${message.toString('hex')}`);
+                break;
+            }
+            // approvals file: [{ validator_pubkey, signature }] over the cancel message
+            const approvals: Array<{ validator_pubkey: string; signature: number[] }> = JSON.parse(fs.readFileSync(arg('approvals'), 'utf-8'));
+
+            const tx = await program.methods.cancelValidatorSetRestore({
+                approverSignatures: approvals.map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature })),
+            })
+                .accounts({
+                    checkpoint: CHECKPOINT,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    pendingRestore: PENDING_RESTORE,
+                    proposer: pending.proposer,
+                    signer: wallet!.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Restore cancelled: ${tx}`);
+            break;
+        }
+
+        case 'execute': {
+            const pending = await program.account.pendingValidatorSetRestore.fetch(PENDING_RESTORE);
+            const slot = await connection.getSlot('confirmed');
+            if (slot < pending.executableSlot.toNumber()) {
+                throw new Error(`Timelock active: executable at slot ${pending.executableSlot.toString()} (now ${slot})`);
+            }
+            const tx = await program.methods.executeValidatorSetRestore()
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    checkpoint: CHECKPOINT,
+                    pendingRestore: PENDING_RESTORE,
+                    proposer: pending.proposer,
+                    payer: wallet!.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            console.log(`✅ Validator set restored: ${tx}`);
+            console.log(`   Version ${set.version.toString()}, ${set.validators.length} validators, threshold ${set.threshold}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: validator-set-snapshot.ts <export|sign|schedule|cancel|execute> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
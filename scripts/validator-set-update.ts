@@ -0,0 +1,346 @@
+/**
+ * Timelocked Validator Set Updates
+ *
+ * Once the validator set timelock exists, update_validator_set is refused:
+ * a new set is proposed with threshold approvals and executes only after
+ * the delay (48h by default), so users and integrators can react first.
+ * The current validators can cancel a proposal until it executes.
+ *
+ * Usage:
+ *   npx ts-node scripts/validator-set-update.ts init
+ *   npx ts-node scripts/validator-set-update.ts status
+ *   npx ts-node scripts/validator-set-update.ts propose --validators <a,b,c,d> --threshold 3 [--weights 4,1,1,1 --threshold-bps 6000] [--out validator-set-update.json]
+ *   npx ts-node scripts/validator-set-update.ts cancel  [--out validator-set-update.json]
+ *   npx ts-node scripts/validator-set-update.ts delay   --seconds 259200 [--out validator-set-update.json]
+ *   npx ts-node scripts/validator-set-update.ts sign    --file validator-set-update.json   (validator key)
+ *   npx ts-node scripts/validator-set-update.ts submit  --file validator-set-update.json
+ *   npx ts-node scripts/validator-set-update.ts execute
+ *   npx ts-node scripts/validator-set-update.ts init-history
+ *   npx ts-node scripts/validator-set-update.ts history [--version <n>]
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+import {
+    MAX_VALIDATOR_SET_UPDATE_DELAY,
+    MIN_VALIDATOR_SET_UPDATE_DELAY,
+    ValidatorSetUpdate,
+    cancelValidatorSetUpdateMessage,
+    fetchPendingValidatorSetUpdate,
+    fetchValidatorSetTimelock,
+    pendingValidatorSetUpdateAddress,
+    validatorSetProposalMessage,
+    validatorSetTimelockAddress,
+    validatorSetUpdateDelayMessage,
+} from '../sdk/attestation-client/src/validator-set-timelock';
+import { fetchX1ValidatorSetHistory, x1ValidatorSetHistoryAddress } from '../sdk/attestation-client/src/validator-set-history';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seeds: Buffer[], program: PublicKey) => PublicKey.findProgramAddressSync(seeds, program)[0];
+const VALIDATOR_SET = pda([Buffer.from('x1_validator_set_v2')], LIGHT_CLIENT_PROGRAM);
+const BRIDGE_CONFIG = pda([Buffer.from('bridge_config')], LIGHT_CLIENT_PROGRAM);
+const TIMELOCK = validatorSetTimelockAddress(LIGHT_CLIENT_PROGRAM);
+const PENDING_UPDATE = pendingValidatorSetUpdateAddress(LIGHT_CLIENT_PROGRAM);
+const HISTORY = x1ValidatorSetHistoryAddress(LIGHT_CLIENT_PROGRAM);
+
+interface Proposal {
+    kind: 'propose' | 'cancel' | 'delay';
+    validator_set_version: number;
+    config_nonce: number;
+    /** propose: the new set (base58 keys) */
+    validators?: string[];
+    threshold?: number;
+    weights?: number[];
+    threshold_bps?: number;
+    /** cancel: proposed_at of the pending update */
+    proposed_at?: number;
+    /** delay: new delay in seconds */
+    delay_seconds?: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+const proposedUpdate = (p: Proposal): ValidatorSetUpdate => ({
+    validators: p.validators!.map(v => new PublicKey(v)),
+    threshold: p.threshold!,
+    weights: p.weights ?? [],
+    thresholdBps: p.threshold_bps ?? 0,
+});
+
+function proposalMessage(p: Proposal): Buffer {
+    switch (p.kind) {
+        case 'propose':
+            return validatorSetProposalMessage(
+                p.validator_set_version,
+                p.config_nonce,
+                proposedUpdate(p),
+                proposalX1GenesisHash(p),
+            );
+        case 'cancel':
+            return cancelValidatorSetUpdateMessage(p.validator_set_version, p.config_nonce, p.proposed_at!, proposalX1GenesisHash(p));
+        case 'delay':
+            return validatorSetUpdateDelayMessage(p.validator_set_version, p.config_nonce, p.delay_seconds!, proposalX1GenesisHash(p));
+    }
+}
+
+function describe(p: Proposal): string {
+    switch (p.kind) {
+        case 'propose':
+            return `new set of ${p.validators!.length} validators, threshold ${p.threshold}` +
+                (p.threshold_bps ? `, weights ${p.weights!.join('/')} at ${p.threshold_bps} bps` : '') +
+                `\n${p.validators!.map(v => `      ${v}`).join('\n')}`;
+        case 'cancel':
+            return `cancel the update proposed at ${new Date(p.proposed_at! * 1000).toISOString()}`;
+        case 'delay':
+            return `update delay ${p.delay_seconds}s`;
+    }
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    const writeProposal = async (fields: Partial<Proposal> & Pick<Proposal, 'kind'>) => {
+        const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+        const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+        const base = {
+            ...fields,
+            validator_set_version: set.version.toNumber(),
+            config_nonce: bridgeConfig.nonce.toNumber(),
+            x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+        } as Proposal;
+        const proposal: Proposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+        const out = arg('out', 'validator-set-update.json');
+        fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+        console.log(`✅ Proposal (${describe(proposal)}) written to ${out}`);
+        console.log(`   Message: ${proposal.message}`);
+    };
+
+    switch (command) {
+        case 'init': {
+            const tx = await program.methods
+                .initializeValidatorSetTimelock()
+                .accounts({ validatorSetTimelock: TIMELOCK, payer: wallet.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Validator set timelock created, instant updates are now refused: ${tx}`);
+            break;
+        }
+
+        case 'status': {
+            const timelock = await fetchValidatorSetTimelock(connection, LIGHT_CLIENT_PROGRAM);
+            if (!timelock) {
+                console.log('🔓 No timelock: update_validator_set applies updates instantly');
+                break;
+            }
+            console.log(`🔒 Update delay: ${timelock.delaySeconds}s (${(timelock.delaySeconds / 3600).toFixed(1)}h), set at slot ${timelock.updatedSlot}`);
+            const pending = await fetchPendingValidatorSetUpdate(connection, LIGHT_CLIENT_PROGRAM);
+            if (!pending) {
+                console.log('   No update pending');
+                break;
+            }
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            console.log(`   Pending: ${pending.validators.length} validators, threshold ${pending.threshold}` +
+                (pending.thresholdBps ? `, weights ${pending.weights.join('/')} at ${pending.thresholdBps} bps` : ''));
+            pending.validators.forEach(v => console.log(`      ${v.toBase58()}`));
+            console.log(`   Executable at ${new Date(pending.executableAt * 1000).toISOString()}`);
+            if (set.version.toNumber() !== pending.baseVersion) {
+                console.log(`   ⚠️  Stale: proposed against version ${pending.baseVersion}, set is now ${set.version.toNumber()} (cancel and propose again)`);
+            }
+            break;
+        }
+
+        case 'propose': {
+            const validators = arg('validators').split(',').filter(Boolean);
+            const weights = process.argv.includes('--weights') ? arg('weights').split(',').map(w => parseInt(w)) : [];
+            await writeProposal({
+                kind: 'propose',
+                validators: validators.map(v => new PublicKey(v).toBase58()),
+                threshold: parseInt(arg('threshold')),
+                weights,
+                threshold_bps: parseInt(arg('threshold-bps', '0')),
+            });
+            break;
+        }
+
+        case 'cancel': {
+            const pending = await fetchPendingValidatorSetUpdate(connection, LIGHT_CLIENT_PROGRAM);
+            if (!pending) {
+                throw new Error('No update is pending');
+            }
+            await writeProposal({ kind: 'cancel', proposed_at: pending.proposedAt });
+            break;
+        }
+
+        case 'delay': {
+            const seconds = parseInt(arg('seconds'));
+            if (seconds < MIN_VALIDATOR_SET_UPDATE_DELAY || seconds > MAX_VALIDATOR_SET_UPDATE_DELAY) {
+                throw new Error(`--seconds must be between ${MIN_VALIDATOR_SET_UPDATE_DELAY} and ${MAX_VALIDATOR_SET_UPDATE_DELAY}`);
+            }
+            await writeProposal({ kind: 'delay', delay_seconds: seconds });
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: Proposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            console.log(`   Signing: ${describe(proposal)}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed ${proposal.kind} proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: Proposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting ${proposal.kind} with ${approverSignatures.length} valid approvals`);
+
+            let tx: string;
+            if (proposal.kind === 'propose') {
+                const update = proposedUpdate(proposal);
+                tx = await program.methods
+                    .proposeValidatorSetUpdate({
+                        newValidators: update.validators,
+                        newThreshold: update.threshold,
+                        newWeights: update.weights,
+                        newThresholdBps: update.thresholdBps,
+                        approverSignatures,
+                    })
+                    .accounts({
+                        validatorSet: VALIDATOR_SET,
+                        bridgeConfig: BRIDGE_CONFIG,
+                        x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                        validatorSetTimelock: TIMELOCK,
+                        pendingUpdate: PENDING_UPDATE,
+                        proposer: wallet.publicKey,
+                        systemProgram: anchor.web3.SystemProgram.programId,
+                    })
+                    .rpc();
+                const pending = await fetchPendingValidatorSetUpdate(connection, LIGHT_CLIENT_PROGRAM);
+                console.log(`✅ Update proposed, executable at ${new Date(pending!.executableAt * 1000).toISOString()}: ${tx}`);
+            } else if (proposal.kind === 'cancel') {
+                const pending = await fetchPendingValidatorSetUpdate(connection, LIGHT_CLIENT_PROGRAM);
+                tx = await program.methods
+                    .cancelValidatorSetUpdate({ approverSignatures })
+                    .accounts({
+                        validatorSet: VALIDATOR_SET,
+                        x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                        bridgeConfig: BRIDGE_CONFIG,
+                        pendingUpdate: PENDING_UPDATE,
+                        proposer: pending!.proposer,
+                        signer: wallet.publicKey,
+                    })
+                    .rpc();
+                console.log(`✅ Update cancelled: ${tx}`);
+            } else {
+                tx = await program.methods
+                    .setValidatorSetUpdateDelay({ delaySeconds: new anchor.BN(proposal.delay_seconds!), approverSignatures })
+                    .accounts({
+                        validatorSet: VALIDATOR_SET,
+                        x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                        bridgeConfig: BRIDGE_CONFIG,
+                        validatorSetTimelock: TIMELOCK,
+                        signer: wallet.publicKey,
+                    })
+                    .rpc();
+                console.log(`✅ Update delay set to ${proposal.delay_seconds}s: ${tx}`);
+            }
+            break;
+        }
+
+        case 'execute': {
+            const pending = await fetchPendingValidatorSetUpdate(connection, LIGHT_CLIENT_PROGRAM);
+            if (!pending) {
+                throw new Error('No update is pending');
+            }
+            if (pending.executableAt > Date.now() / 1000) {
+                throw new Error(`Executable from ${new Date(pending.executableAt * 1000).toISOString()}`);
+            }
+            const tx = await program.methods
+                .executeValidatorSetUpdate()
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    bridgeConfig: BRIDGE_CONFIG,
+                    pendingUpdate: PENDING_UPDATE,
+                    proposer: pending.proposer,
+                    validatorSetHistory: HISTORY,
+                    payer: wallet.publicKey,
+                    systemProgram: anchor.web3.SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Validator set updated: ${tx}`);
+            break;
+        }
+
+        case 'init-history': {
+            const tx = await program.methods
+                .initializeX1ValidatorSetHistory()
+                .accounts({ validatorSetHistory: HISTORY, payer: wallet.publicKey, systemProgram: anchor.web3.SystemProgram.programId })
+                .rpc();
+            console.log(`✅ Validator set history created, updates are recorded from now on: ${tx}`);
+            break;
+        }
+
+        case 'history': {
+            const history = await fetchX1ValidatorSetHistory(connection, LIGHT_CLIENT_PROGRAM);
+            if (!history) {
+                console.log('📜 No validator set history (init-history to start recording)');
+                break;
+            }
+            const version = process.argv.includes('--version') ? parseInt(arg('version')) : undefined;
+            const records = history.records.filter(r => version === undefined || r.version === version);
+            console.log(`📜 ${history.totalUpdates} updates recorded, last ${history.records.length} kept`);
+            for (const r of records) {
+                console.log(`   v${r.version} at ${new Date(r.timestamp * 1000).toISOString()} (slot ${r.slot}): threshold ${r.threshold}` +
+                    (r.thresholdBps ? `, ${r.thresholdBps} bps` : ''));
+                console.log(`      members ${r.oldValidatorsHash.toString('hex')} → ${r.newValidatorsHash.toString('hex')}`);
+                console.log(`      approved by ${r.approvers.map(a => a.toBase58()).join(', ')}`);
+            }
+            if (version !== undefined && records.length === 0) {
+                console.log(`   Version ${version} is not in the history`);
+            }
+            break;
+        }
+
+        default:
+            console.error('Usage: validator-set-update.ts <init|status|propose|cancel|delay|sign|submit|execute|init-history|history> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
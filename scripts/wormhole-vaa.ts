@@ -0,0 +1,235 @@
+/**
+ * Wormhole VAA Path (fallback to validator attestations)
+ *
+ * While enabled, a burner can verify a burn from its Wormhole VAA instead
+ * of X1 validator attestations, e.g. while the validators' attestation
+ * APIs are offline. The burn program's burn_registered_asset publishes the
+ * burn payload from its ["emitter"] PDA (the default --emitter); the VAA is
+ * posted on X1 with Wormhole's own tooling before `submit`. Setting the
+ * core bridge and emitter needs threshold validator approval.
+ *
+ * Usage:
+ *   npx ts-node scripts/wormhole-vaa.ts status
+ *   npx ts-node scripts/wormhole-vaa.ts init
+ *   npx ts-node scripts/wormhole-vaa.ts propose --core-bridge <pubkey> [--emitter <hex|pubkey>] [--emitter-chain 1] [--min-consistency 1] [--disable] [--out wormhole-vaa.json]
+ *   npx ts-node scripts/wormhole-vaa.ts sign          --file wormhole-vaa.json   (validator key)
+ *   npx ts-node scripts/wormhole-vaa.ts submit-config --file wormhole-vaa.json
+ *   npx ts-node scripts/wormhole-vaa.ts submit        --asset 1 --nonce <burn nonce> --posted-vaa <pubkey>   (burner key)
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import {
+    WormholeConfig, burnEmitterAddress, fetchWormholeConfig, wormholeConfigAddress, wormholeConfigMessage,
+} from '../sdk/attestation-client/src/wormhole-vaa';
+import { featureGateAddress } from '../sdk/attestation-client/src/feature-gate';
+import { assetRegistryAddress } from '../sdk/attestation-client/src/asset-registry';
+import { betaAllowlistAddress } from '../sdk/attestation-client/src/beta-allowlist';
+import { fetchX1ChainIdHex, proposalX1GenesisHash, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const WORMHOLE_CONFIG = wormholeConfigAddress(LIGHT_CLIENT_PROGRAM);
+
+interface WormholeProposal {
+    enabled: boolean;
+    core_bridge: string;
+    emitter_chain: number;
+    /** hex, 32 bytes */
+    emitter_address: string;
+    min_consistency_level: number;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function proposalConfig(p: Omit<WormholeProposal, 'message' | 'approvals'>) {
+    return {
+        enabled: p.enabled,
+        coreBridge: new PublicKey(p.core_bridge),
+        emitterChain: p.emitter_chain,
+        emitterAddress: Buffer.from(p.emitter_address, 'hex'),
+        minConsistencyLevel: p.min_consistency_level,
+    };
+}
+
+function proposalMessage(p: Omit<WormholeProposal, 'message' | 'approvals'>): Buffer {
+    return wormholeConfigMessage(p.validator_set_version, p.config_nonce, proposalConfig(p), proposalX1GenesisHash(p));
+}
+
+/** A Wormhole emitter address: 64 hex characters or a base58 public key */
+function emitterAddress(value: string): Buffer {
+    if (/^[0-9a-fA-F]{64}$/.test(value)) {
+        return Buffer.from(value, 'hex');
+    }
+    return new PublicKey(value).toBuffer();
+}
+
+function describe(c: Pick<WormholeConfig, 'enabled' | 'coreBridge' | 'emitterChain' | 'emitterAddress' | 'minConsistencyLevel'>) {
+    console.log(`   ${c.enabled ? 'Enabled' : 'Disabled'}`);
+    console.log(`   Core bridge: ${c.coreBridge.toBase58()}`);
+    console.log(`   Emitter: chain ${c.emitterChain}, ${c.emitterAddress.toString('hex')}`);
+    console.log(`   Min consistency level: ${c.minConsistencyLevel}`);
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const wormhole = await fetchWormholeConfig(connection, LIGHT_CLIENT_PROGRAM);
+            if (!wormhole) {
+                console.log('🌀 Wormhole VAA path: not initialized (off)');
+                break;
+            }
+            console.log('🌀 Wormhole VAA path');
+            describe(wormhole);
+            console.log(`   Updated at slot ${wormhole.updatedSlot}`);
+            break;
+        }
+
+        case 'init': {
+            const tx = await program.methods
+                .initializeWormholeConfig()
+                .accounts({
+                    wormholeConfig: WORMHOLE_CONFIG,
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Wormhole config created (disabled): ${tx}`);
+            break;
+        }
+
+        case 'propose': {
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                enabled: !process.argv.includes('--disable'),
+                core_bridge: new PublicKey(arg('core-bridge')).toBase58(),
+                emitter_chain: parseInt(arg('emitter-chain', '1')),
+                emitter_address: emitterAddress(arg('emitter', burnEmitterAddress(new PublicKey(config.programs.burn)).toBase58())).toString('hex'),
+                min_consistency_level: parseInt(arg('min-consistency', '1')),
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+                x1_genesis_hash: await fetchX1ChainIdHex(connection, LIGHT_CLIENT_PROGRAM),
+            };
+            const proposal: WormholeProposal = { ...base, message: proposalMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'wormhole-vaa.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Wormhole config proposal written to ${out}`);
+            describe(proposalConfig(base));
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: WormholeProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Recompute rather than trust the file's message field
+            const message = proposalMessage(proposal);
+            describe(proposalConfig(proposal));
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed Wormhole config proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit-config': {
+            const proposal: WormholeProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = proposalMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting Wormhole config with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .setWormholeConfig({
+                    enabled: proposal.enabled,
+                    coreBridge: new PublicKey(proposal.core_bridge),
+                    emitterChain: proposal.emitter_chain,
+                    emitterAddress: Array.from(Buffer.from(proposal.emitter_address, 'hex')),
+                    minConsistencyLevel: proposal.min_consistency_level,
+                    approverSignatures,
+                })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    x1ChainId: x1ChainIdAddress(LIGHT_CLIENT_PROGRAM),
+                    bridgeConfig: BRIDGE_CONFIG,
+                    wormholeConfig: WORMHOLE_CONFIG,
+                    signer: wallet.publicKey,
+                })
+                .rpc();
+            console.log(`✅ Wormhole config updated: ${tx}`);
+            break;
+        }
+
+        case 'submit': {
+            const assetId = parseInt(arg('asset'));
+            const burnNonce = new anchor.BN(arg('nonce'));
+            const postedVaa = new PublicKey(arg('posted-vaa'));
+            const verifiedBurn = PublicKey.findProgramAddressSync(
+                [Buffer.from('verified_burn_v3'), Buffer.from([assetId]), wallet.publicKey.toBuffer(), burnNonce.toArrayLike(Buffer, 'le', 8)],
+                LIGHT_CLIENT_PROGRAM,
+            )[0];
+            console.log(`📤 Verifying burn ${burnNonce} of asset ${assetId} from VAA ${postedVaa.toBase58()}`);
+
+            const tx = await program.methods
+                .submitBurnVaa(assetId, burnNonce)
+                .accounts({
+                    user: wallet.publicKey,
+                    wormholeConfig: WORMHOLE_CONFIG,
+                    postedVaa,
+                    verifiedBurn,
+                    featureGate: featureGateAddress(LIGHT_CLIENT_PROGRAM),
+                    assetRegistry: assetRegistryAddress(LIGHT_CLIENT_PROGRAM),
+                    burnAgeLimit: pda('burn_age_limit'),
+                    betaAllowlist: betaAllowlistAddress(LIGHT_CLIENT_PROGRAM, assetId),
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Burn verified from VAA: ${tx}`);
+            console.log(`   Verified burn: ${verifiedBurn.toBase58()} (mint as usual)`);
+            break;
+        }
+
+        default:
+            console.error('Usage: wormhole-vaa.ts <status|init|propose|sign|submit-config|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,168 @@
+/**
+ * X1 Chain Id (genesis hash binding against fork / clone replay)
+ *
+ * Binds the light client to the genesis hash of the X1 network it runs on.
+ * From then on the hash is part of every attestation and validator set
+ * update message, and validator services refuse to sign when their X1 RPC
+ * reports another genesis. It is set once with validator approval.
+ *
+ * Usage:
+ *   npx ts-node scripts/x1-chain-id.ts status
+ *   npx ts-node scripts/x1-chain-id.ts propose [--genesis <base58>] [--out chain-id.json]
+ *   npx ts-node scripts/x1-chain-id.ts sign    --file chain-id.json   (validator key)
+ *   npx ts-node scripts/x1-chain-id.ts submit  --file chain-id.json
+ *
+ * `propose` defaults to the genesis hash of config.x1.rpc; `sign` refuses a
+ * hash other than its own RPC's. Restart the validator services afterwards:
+ * they read the binding at startup.
+ */
+
+import 'dotenv/config';
+import { Connection, PublicKey, SystemProgram } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as crypto from 'crypto';
+import bs58 from 'bs58';
+import fs from 'fs';
+import nacl from 'tweetnacl';
+import { loadConfig, loadKeypair } from '../sdk/bridge-config/src';
+import { fetchGenesisHash, fetchX1ChainId, x1ChainIdAddress } from '../sdk/attestation-client/src/x1-chain-id';
+
+const { config } = loadConfig();
+const LIGHT_CLIENT_PROGRAM = new PublicKey(config.programs.light_client);
+
+const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], LIGHT_CLIENT_PROGRAM)[0];
+const VALIDATOR_SET = pda('x1_validator_set_v2');
+const BRIDGE_CONFIG = pda('bridge_config');
+const X1_CHAIN_ID = x1ChainIdAddress(LIGHT_CLIENT_PROGRAM);
+
+interface ChainIdProposal {
+    /** base58, as returned by getGenesisHash */
+    genesis_hash: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+function genesisBytes(genesisHash: string): Buffer {
+    const bytes = Buffer.from(bs58.decode(genesisHash));
+    if (bytes.length !== 32 || bytes.equals(Buffer.alloc(32))) {
+        throw new Error(`Invalid genesis hash ${genesisHash}`);
+    }
+    return bytes;
+}
+
+/** Must match create_x1_chain_id_message on-chain */
+function chainIdMessage(p: Omit<ChainIdProposal, 'message' | 'approvals'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('INIT_X1_CHAIN_ID'),
+        u64(p.validator_set_version),
+        u64(p.config_nonce),
+        genesisBytes(p.genesis_hash),
+    ])).digest();
+}
+
+function arg(name: string, fallback?: string): string {
+    const index = process.argv.indexOf(`--${name}`);
+    const value = index >= 0 ? process.argv[index + 1] : fallback;
+    if (value === undefined) {
+        throw new Error(`Missing --${name}`);
+    }
+    return value;
+}
+
+async function main() {
+    const command = process.argv[2];
+    const connection = new Connection(config.x1.rpc, 'confirmed');
+    const wallet = command === 'sign' ? loadKeypair(config, 'validator') : loadKeypair(config, 'user');
+    const provider = new anchor.AnchorProvider(connection, new anchor.Wallet(wallet), { commitment: 'confirmed' });
+    const idl = JSON.parse(fs.readFileSync('target/idl/solana_light_client_x1.json', 'utf-8'));
+    const program: any = new anchor.Program(idl, LIGHT_CLIENT_PROGRAM, provider);
+
+    switch (command) {
+        case 'status': {
+            const bound = await fetchX1ChainId(connection, LIGHT_CLIENT_PROGRAM);
+            const rpc = await fetchGenesisHash(connection);
+            console.log(`🔗 X1 chain id ${X1_CHAIN_ID.toBase58()}`);
+            console.log(`   Bound genesis: ${bound ? bs58.encode(bound) : 'not bound'}`);
+            console.log(`   RPC genesis:   ${bs58.encode(rpc)}${bound && !bound.equals(rpc) ? ' ⚠️  MISMATCH (fork or clone)' : ''}`);
+            break;
+        }
+
+        case 'propose': {
+            const genesis_hash = arg('genesis', bs58.encode(await fetchGenesisHash(connection)));
+            genesisBytes(genesis_hash);
+            const set = await program.account.x1ValidatorSet.fetch(VALIDATOR_SET);
+            const bridgeConfig = await program.account.bridgeConfig.fetch(BRIDGE_CONFIG);
+            const base = {
+                genesis_hash,
+                validator_set_version: set.version.toNumber(),
+                config_nonce: bridgeConfig.nonce.toNumber(),
+            };
+            const proposal: ChainIdProposal = { ...base, message: chainIdMessage(base).toString('hex'), approvals: [] };
+            const out = arg('out', 'chain-id.json');
+            fs.writeFileSync(out, JSON.stringify(proposal, null, 2));
+            console.log(`✅ Chain id proposal written to ${out}`);
+            console.log(`   Genesis: ${genesis_hash}`);
+            console.log(`   Message: ${proposal.message}`);
+            break;
+        }
+
+        case 'sign': {
+            const file = arg('file');
+            const proposal: ChainIdProposal = JSON.parse(fs.readFileSync(file, 'utf-8'));
+            // Only vouch for the network this validator actually reads from
+            const rpc = bs58.encode(await fetchGenesisHash(connection));
+            if (rpc !== proposal.genesis_hash) {
+                throw new Error(`Proposal genesis ${proposal.genesis_hash} is not this RPC's (${rpc})`);
+            }
+            // Recompute rather than trust the file's message field
+            const message = chainIdMessage(proposal);
+            console.log(`   Genesis: ${proposal.genesis_hash}`);
+            const approvals = proposal.approvals.filter(a => a.validator_pubkey !== wallet.publicKey.toBase58());
+            approvals.push({
+                validator_pubkey: wallet.publicKey.toBase58(),
+                signature: Array.from(nacl.sign.detached(message, wallet.secretKey)),
+            });
+            fs.writeFileSync(file, JSON.stringify({ ...proposal, approvals }, null, 2));
+            console.log(`✅ Signed chain id proposal; ${approvals.length} approvals`);
+            break;
+        }
+
+        case 'submit': {
+            const proposal: ChainIdProposal = JSON.parse(fs.readFileSync(arg('file'), 'utf-8'));
+            const message = chainIdMessage(proposal);
+            const approverSignatures = proposal.approvals
+                .filter(a => nacl.sign.detached.verify(message, Uint8Array.from(a.signature), new PublicKey(a.validator_pubkey).toBytes()))
+                .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.signature }));
+            console.log(`📤 Submitting X1 chain id with ${approverSignatures.length} valid approvals`);
+
+            const tx = await program.methods
+                .initializeX1ChainId({ genesisHash: Array.from(genesisBytes(proposal.genesis_hash)), approverSignatures })
+                .accounts({
+                    validatorSet: VALIDATOR_SET,
+                    bridgeConfig: BRIDGE_CONFIG,
+                    x1ChainId: X1_CHAIN_ID,
+                    payer: wallet.publicKey,
+                    systemProgram: SystemProgram.programId,
+                })
+                .rpc();
+            console.log(`✅ Bridge bound to X1 genesis ${proposal.genesis_hash}: ${tx}`);
+            break;
+        }
+
+        default:
+            console.error('Usage: x1-chain-id.ts <status|propose|sign|submit> [options]');
+            process.exit(1);
+    }
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,91 @@
+import { Connection, PublicKey, SystemProgram, TransactionInstruction, TransactionMessage, VersionedTransaction } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Asset display metadata (light client get_asset_metadata)
+ *
+ * Symbol, name and decimals of a bridged asset straight from the bridge,
+ * so X1 wallets need no hard-coded token list. The logo stays off-chain:
+ * `logoUriHash` is the sha256 of the metadata JSON URI set on the X1
+ * mint's Metaplex metadata, and `metadataUriMatches` tells a wallet
+ * whether the URI it found is the one the bridge vouches for.
+ */
+
+export interface AssetMetadata {
+    assetId: number;
+    symbol: string;
+    name: string;
+    decimals: number;
+    logoUriHash: Buffer;
+    mintProgram: PublicKey;
+}
+
+/**
+ * Borsh-decode AssetMetadata (instruction return data)
+ */
+export function decodeAssetMetadata(data: Buffer): AssetMetadata {
+    let o = 0;
+    const string = (): string => {
+        const length = data.readUInt32LE(o);
+        const value = data.subarray(o + 4, o + 4 + length).toString('utf-8');
+        o += 4 + length;
+        return value;
+    };
+    const assetId = data[o++];
+    const symbol = string();
+    const name = string();
+    const decimals = data[o++];
+    const logoUriHash = Buffer.from(data.subarray(o, o + 32));
+    const mintProgram = new PublicKey(data.subarray(o + 32, o + 64));
+    return { assetId, symbol, name, decimals, logoUriHash, mintProgram };
+}
+
+/**
+ * Metadata of one asset from a simulated get_asset_metadata (nothing is sent).
+ * `payer` only has to exist; it does not sign.
+ */
+export async function simulateAssetMetadata(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    payer: PublicKey,
+    assetId: number
+): Promise<AssetMetadata> {
+    const instruction = new TransactionInstruction({
+        programId: lightClientProgramId,
+        keys: [{ pubkey: SystemProgram.programId, isSigner: false, isWritable: false }],
+        data: Buffer.concat([
+            crypto.createHash('sha256').update('global:get_asset_metadata').digest().subarray(0, 8),
+            Buffer.from([assetId]),
+        ]),
+    });
+    const { blockhash } = await connection.getLatestBlockhash();
+    const tx = new VersionedTransaction(new TransactionMessage({
+        payerKey: payer,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message());
+
+    const simulation = await connection.simulateTransaction(tx, { sigVerify: false, replaceRecentBlockhash: true });
+    const returnData = simulation.value.returnData;
+    if (simulation.value.err || !returnData || returnData.programId !== lightClientProgramId.toBase58()) {
+        throw new Error(`get_asset_metadata(${assetId}) failed: ${JSON.stringify(simulation.value.err)} ${(simulation.value.logs || []).join('\n')}`);
+    }
+    return decodeAssetMetadata(Buffer.from(returnData.data[0], 'base64'));
+}
+
+/**
+ * Whether `uri` (e.g. the X1 mint's Metaplex metadata uri) is the one the bridge vouches for
+ */
+export function metadataUriMatches(metadata: AssetMetadata, uri: string): boolean {
+    return crypto.createHash('sha256').update(uri).digest().equals(metadata.logoUriHash);
+}
+
+/**
+ * Raw base units → display string using the asset's decimals (no float rounding)
+ */
+export function formatAssetAmount(metadata: AssetMetadata, baseUnits: bigint | number): string {
+    const units = BigInt(baseUnits);
+    const scale = BigInt(10) ** BigInt(metadata.decimals);
+    const fraction = (units % scale).toString().padStart(metadata.decimals, '0').replace(/0+$/, '');
+    return `${units / scale}${fraction ? `.${fraction}` : ''} ${metadata.symbol}`;
+}
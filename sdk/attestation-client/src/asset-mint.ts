@@ -0,0 +1,148 @@
+import { AccountMeta, Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import { CoSignPolicy, decodeCoSignPolicy } from './cosign-policy';
+import { MintPause, decodeMintPause } from './pause';
+import { RateLimit, decodeRateLimit } from './rate-limit';
+
+/**
+ * Generic mint program (bridge-mint-x1)
+ *
+ * One program mints every asset registered to it in the light client's
+ * AssetRegistry, with one MintState per asset (the mint authority of that
+ * asset's X1 mint). XENCAT and DGN keep their own programs.
+ *
+ * Each asset also has its own mint pause, rate limit, co-sign policy, fee
+ * pool and fee config, with the same layouts and rules as in the per-asset
+ * programs but the asset id added to the seeds (assetControlAddress).
+ *
+ * MintState layout: discriminator(8) || asset_id(1) || mint(32) || authority(32)
+ *   || fee_per_validator(8) || processed_burns_count(8) || total_minted(8) || bump(1)
+ */
+export const BRIDGE_MINT_PROGRAM_ID = new PublicKey('8yxPXAX7AynCpG2agTGFJQHkzFKbDwowKJtqq6GFq8Re');
+
+export interface AssetMintState {
+    assetId: number;
+    mint: PublicKey;
+    /** Manages the co-sign policy, fee config and rate limit creation */
+    authority: PublicKey;
+    feePerValidator: bigint;
+    processedBurnsCount: bigint;
+    totalMinted: bigint;
+}
+
+export function assetMintStateAddress(programId: PublicKey, assetId: number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('mint_state'), Buffer.from([assetId])], programId)[0];
+}
+
+export function decodeAssetMintState(data: Buffer): AssetMintState {
+    return {
+        assetId: data[8],
+        mint: new PublicKey(data.subarray(9, 41)),
+        authority: new PublicKey(data.subarray(41, 73)),
+        feePerValidator: data.readBigUInt64LE(73),
+        processedBurnsCount: data.readBigUInt64LE(81),
+        totalMinted: data.readBigUInt64LE(89),
+    };
+}
+
+/**
+ * An asset's mint state (null = asset not initialized in this program)
+ */
+export async function fetchAssetMintState(connection: Connection, programId: PublicKey, assetId: number): Promise<AssetMintState | null> {
+    const info = await connection.getAccountInfo(assetMintStateAddress(programId, assetId));
+    if (!info || !info.owner.equals(programId)) {
+        return null;
+    }
+    return decodeAssetMintState(info.data);
+}
+
+export type AssetControl = 'mint_pause' | 'rate_limit' | 'cosign_policy' | 'fee_pool' | 'fee_config';
+
+/** Address of one of an asset's control accounts (exists or not) */
+export function assetControlAddress(programId: PublicKey, control: AssetControl, assetId: number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from(control), Buffer.from([assetId])], programId)[0];
+}
+
+async function fetchAssetControl<T>(
+    connection: Connection, programId: PublicKey, control: AssetControl, assetId: number, decode: (data: Buffer) => T,
+): Promise<T | null> {
+    const info = await connection.getAccountInfo(assetControlAddress(programId, control, assetId));
+    if (!info || !info.owner.equals(programId)) {
+        return null;
+    }
+    return decode(info.data);
+}
+
+/** An asset's pause switch (null = not created, not paused) */
+export function fetchAssetMintPause(connection: Connection, programId: PublicKey, assetId: number): Promise<MintPause | null> {
+    return fetchAssetControl(connection, programId, 'mint_pause', assetId, decodeMintPause);
+}
+
+/** An asset's rate limit (null = no limit) */
+export function fetchAssetRateLimit(connection: Connection, programId: PublicKey, assetId: number): Promise<RateLimit | null> {
+    return fetchAssetControl(connection, programId, 'rate_limit', assetId, decodeRateLimit);
+}
+
+/** An asset's co-sign policy (null = no co-signer ever needed) */
+export function fetchAssetCoSignPolicy(connection: Connection, programId: PublicKey, assetId: number): Promise<CoSignPolicy | null> {
+    return fetchAssetControl(connection, programId, 'cosign_policy', assetId, decodeCoSignPolicy);
+}
+
+function u64(value: bigint | number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+/** Must match create_initialize_asset_message in bridge-mint-x1 */
+export function initializeAssetMessage(
+    programId: PublicKey, validatorSetVersion: bigint | number, assetId: number, mint: PublicKey, authority: PublicKey, x1GenesisHash: Buffer | null,
+): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('INIT_ASSET_MINT'),
+        u64(validatorSetVersion),
+        Buffer.from([assetId]),
+        mint.toBuffer(),
+        programId.toBuffer(),
+        authority.toBuffer(),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
+
+/** Must match create_mint_pause_message in bridge-mint-x1 */
+export function assetPauseMessage(
+    programId: PublicKey, paused: boolean, validatorSetVersion: bigint | number, nonce: bigint | number, assetId: number, x1GenesisHash: Buffer | null,
+): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(paused ? 'PAUSE_MINT' : 'UNPAUSE_MINT'),
+        u64(validatorSetVersion),
+        u64(nonce),
+        programId.toBuffer(),
+        Buffer.from([assetId]),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
+
+/** Must match create_rate_limit_message in bridge-mint-x1 */
+export function assetRateLimitMessage(
+    programId: PublicKey, validatorSetVersion: bigint | number, nonce: bigint | number, assetId: number, cap: bigint | number, x1GenesisHash: Buffer | null,
+): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_MINT_RATE_LIMIT'),
+        u64(validatorSetVersion),
+        u64(nonce),
+        programId.toBuffer(),
+        Buffer.from([assetId]),
+        u64(cap),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
+
+/**
+ * remaining_accounts of mint_from_burn_v3: the validator keys in set order,
+ * or none once the asset's fee pool exists (this program has no fee vaults).
+ * A required co-signer goes after them (coSignerAccount).
+ */
+export function assetMintFeeAccounts(validators: PublicKey[], pooled: boolean): AccountMeta[] {
+    return pooled ? [] : validators.map(pubkey => ({ pubkey, isWritable: true, isSigner: false }));
+}
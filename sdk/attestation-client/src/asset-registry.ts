@@ -0,0 +1,84 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Governed asset list (light client AssetRegistry)
+ *
+ * Validators register new bridged tokens (each with its own mint program)
+ * and deactivate retired ones without a light client redeploy. A
+ * deactivated asset gets no new attestations; burns already verified
+ * still mint. A frozen asset gets neither attestations nor mints until
+ * it is unfrozen (the other assets keep running). While the account does
+ * not exist the built-in assets (XENCAT = 1, DGN = 2) apply.
+ *
+ * Layout: discriminator(8) || vec len(4) || [asset_id(1) || mint_program(32) || active(1) || updated_slot(8) || frozen(1)]* || bump(1)
+ */
+
+export interface RegisteredAsset {
+    assetId: number;
+    mintProgram: PublicKey;
+    active: boolean;
+    updatedSlot: number;
+    frozen: boolean;
+}
+
+const ENTRY_SIZE = 43;
+
+export function assetRegistryAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('asset_registry')], lightClientProgramId)[0];
+}
+
+export function decodeAssetRegistry(data: Buffer): RegisteredAsset[] {
+    const count = data.readUInt32LE(8);
+    return Array.from({ length: count }, (_, i) => {
+        const offset = 12 + i * ENTRY_SIZE;
+        return {
+            assetId: data[offset],
+            mintProgram: new PublicKey(data.subarray(offset + 1, offset + 33)),
+            active: data[offset + 33] === 1,
+            updatedSlot: Number(data.readBigUInt64LE(offset + 34)),
+            frozen: data[offset + 42] === 1,
+        };
+    });
+}
+
+/**
+ * Registered assets (null while the registry does not exist: built-in assets apply)
+ */
+export async function fetchRegisteredAssets(connection: Connection, lightClientProgramId: PublicKey): Promise<RegisteredAsset[] | null> {
+    const info = await connection.getAccountInfo(assetRegistryAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeAssetRegistry(info.data);
+}
+
+/**
+ * Mint program of an asset new burns of which are attested (must match AssetRegistry::resolve_active)
+ *
+ * @param builtin asset id -> mint program, used while the registry does not exist
+ */
+export async function resolveActiveAsset(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    assetId: number,
+    builtin: Record<number, PublicKey>
+): Promise<PublicKey> {
+    const assets = await fetchRegisteredAssets(connection, lightClientProgramId);
+    if (!assets) {
+        if (!builtin[assetId]) {
+            throw new Error(`Asset ${assetId} is not a built-in asset`);
+        }
+        return builtin[assetId];
+    }
+    const asset = assets.find(a => a.assetId === assetId);
+    if (!asset) {
+        throw new Error(`Asset ${assetId} is not registered`);
+    }
+    if (!asset.active) {
+        throw new Error(`Asset ${assetId} is deactivated: no new attestations`);
+    }
+    if (asset.frozen) {
+        throw new Error(`Asset ${assetId} is frozen: no attestations or mints until it is unfrozen`);
+    }
+    return asset.mintProgram;
+}
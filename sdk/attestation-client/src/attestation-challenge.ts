@@ -0,0 +1,62 @@
+import { Commitment, Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Attestation challenge (no pre-signed attestations)
+ *
+ * A burner can post a ChallengeRequest for its burn on X1
+ * (request_attestation_challenge) once the burn is on Solana. Its
+ * challenge mixes the latest X1 slot hash, so it is unknown beforehand.
+ * Validators read it when signing and append it to the timed attestation
+ * message. While it exists, the light client only accepts signatures
+ * timed after the challenge, for a burn that predates it.
+ *
+ * Layout: discriminator(8) || asset_id(1) || burn_nonce(8) || user(32) || challenge(32)
+ *         || created_at(i64) || created_slot(u64) || bump(1)
+ */
+
+export interface ChallengeRequest {
+    assetId: number;
+    burnNonce: bigint;
+    user: PublicKey;
+    challenge: Buffer;
+    createdAt: number;
+    createdSlot: number;
+}
+
+export function challengeRequestAddress(lightClientProgramId: PublicKey, assetId: number, user: PublicKey, burnNonce: bigint | number): PublicKey {
+    const nonce = Buffer.alloc(8);
+    nonce.writeBigUInt64LE(BigInt(burnNonce));
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('challenge_request'), Buffer.from([assetId]), user.toBuffer(), nonce],
+        lightClientProgramId
+    )[0];
+}
+
+export function decodeChallengeRequest(data: Buffer): ChallengeRequest {
+    return {
+        assetId: data[8],
+        burnNonce: data.readBigUInt64LE(9),
+        user: new PublicKey(data.subarray(17, 49)),
+        challenge: Buffer.from(data.subarray(49, 81)),
+        createdAt: Number(data.readBigInt64LE(81)),
+        createdSlot: Number(data.readBigUInt64LE(89)),
+    };
+}
+
+/**
+ * The burn's challenge (null = none posted; attestations sign without one)
+ */
+export async function fetchChallengeRequest(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    assetId: number,
+    user: PublicKey,
+    burnNonce: bigint | number,
+    commitment: Commitment = 'confirmed'
+): Promise<ChallengeRequest | null> {
+    const info = await connection.getAccountInfo(challengeRequestAddress(lightClientProgramId, assetId, user, burnNonce), commitment);
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeChallengeRequest(info.data);
+}
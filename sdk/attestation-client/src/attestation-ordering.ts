@@ -0,0 +1,75 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Attestation ordering rule (X1ValidatorSet.ordered_attestations)
+ *
+ * When validator governance turns it on, a submit only accepts attestations
+ * in ascending validator set order: the light client then finds duplicates
+ * by comparing with the previous attestation. Sorting is harmless while the
+ * rule is off, so relayers always sort with orderAttestations.
+ *
+ * X1ValidatorSet layout: discriminator(8) || version(8) || validators(4 + 32n)
+ *   || threshold(1) || bump(1) || max_attestation_age(8) || weights(4 + 2m)
+ *   || threshold_bps(2) || ordered_attestations(1)
+ */
+
+export interface AttestationOrdering {
+    validatorSetVersion: bigint;
+    /** Validator keys in set order (the order attestations must follow) */
+    validators: PublicKey[];
+    ordered: boolean;
+}
+
+export function decodeAttestationOrdering(data: Buffer): AttestationOrdering {
+    const validatorSetVersion = data.readBigUInt64LE(8);
+    const count = data.readUInt32LE(16);
+    const validators = Array.from({ length: count }, (_, i) => new PublicKey(data.subarray(20 + 32 * i, 52 + 32 * i)));
+    // threshold(1) || bump(1) || max_attestation_age(8)
+    const weightsOffset = 20 + 32 * count + 10;
+    // Sets created before the appended fields read them as unset
+    const weightCount = data.length >= weightsOffset + 4 ? data.readUInt32LE(weightsOffset) : 0;
+    const orderedOffset = weightsOffset + 4 + 2 * weightCount + 2;
+    return {
+        validatorSetVersion,
+        validators,
+        ordered: data.length > orderedOffset && data[orderedOffset] === 1,
+    };
+}
+
+export async function fetchAttestationOrdering(connection: Connection, lightClientProgramId: PublicKey): Promise<AttestationOrdering> {
+    const address = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], lightClientProgramId)[0];
+    const info = await connection.getAccountInfo(address);
+    if (!info) {
+        throw new Error('X1 validator set not initialized');
+    }
+    return decodeAttestationOrdering(info.data);
+}
+
+/**
+ * Attestations sorted by their validator's index in the set, as the
+ * ordering rule requires (validators outside the set go last; the
+ * light client rejects them either way)
+ */
+export function orderAttestations<T>(attestations: T[], validators: PublicKey[], validatorOf: (attestation: T) => PublicKey): T[] {
+    const index = (attestation: T) => {
+        const position = validators.findIndex(v => v.equals(validatorOf(attestation)));
+        return position === -1 ? validators.length : position;
+    };
+    return [...attestations].sort((a, b) => index(a) - index(b));
+}
+
+/** Must match create_attestation_ordering_message in the light client */
+export function attestationOrderingMessage(validatorSetVersion: bigint | number, configNonce: bigint | number, ordered: boolean, x1GenesisHash: Buffer | null): Buffer {
+    const version = Buffer.alloc(8);
+    version.writeBigUInt64LE(BigInt(validatorSetVersion));
+    const nonce = Buffer.alloc(8);
+    nonce.writeBigUInt64LE(BigInt(configNonce));
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_ATTESTATION_ORDER'),
+        version,
+        nonce,
+        Buffer.from([ordered ? 1 : 0]),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
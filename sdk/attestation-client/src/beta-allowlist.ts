@@ -0,0 +1,101 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Permissioned beta of new assets
+ *
+ * Both ends keep a BetaAllowlist PDA ["beta_allowlist", asset_id]:
+ * - Light client (X1): while enabled, V3, for-recipient and degraded
+ *   submits of the asset, and conversions into it, require the attested X1
+ *   user to be listed. Validator governance sets or disables it.
+ * - Burn program (Solana): while it exists, burn_registered_asset only
+ *   accepts listed burners. The upgrade authority creates, updates and
+ *   closes it.
+ * Not created (or disabled on X1) = permissionless.
+ *
+ * Light client layout: discriminator(8) || asset_id(1) || enabled(1)
+ *   || users(4 + 32 * n) || updated_slot(8) || bump(1)
+ * Burn program layout: discriminator(8) || asset_id(1) || users(4 + 32 * n) || bump(1)
+ */
+
+/** Must match BetaAllowlist::MAX_USERS in both programs */
+export const BETA_ALLOWLIST_MAX_USERS = 32;
+
+export interface BetaAllowlist {
+    assetId: number;
+    enabled: boolean;
+    users: PublicKey[];
+    updatedSlot: number;
+}
+
+/** Same seeds in the light client and the burn program */
+export function betaAllowlistAddress(programId: PublicKey, assetId: number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('beta_allowlist'), Buffer.from([assetId])], programId)[0];
+}
+
+function decodeUsers(data: Buffer, offset: number): PublicKey[] {
+    const count = data.readUInt32LE(offset);
+    return Array.from({ length: count }, (_, i) =>
+        new PublicKey(data.subarray(offset + 4 + 32 * i, offset + 36 + 32 * i)));
+}
+
+export function decodeBetaAllowlist(data: Buffer): BetaAllowlist {
+    const users = decodeUsers(data, 10);
+    return {
+        assetId: data[8],
+        enabled: data[9] === 1,
+        users,
+        updatedSlot: Number(data.readBigUInt64LE(14 + 32 * users.length)),
+    };
+}
+
+/**
+ * An asset's X1 beta allowlist (null = not created, permissionless)
+ */
+export async function fetchBetaAllowlist(connection: Connection, lightClientProgramId: PublicKey, assetId: number): Promise<BetaAllowlist | null> {
+    const info = await connection.getAccountInfo(betaAllowlistAddress(lightClientProgramId, assetId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeBetaAllowlist(info.data);
+}
+
+/**
+ * Burners allowed by the burn program's beta allowlist of an asset
+ * (null = no list, anyone may burn)
+ */
+export async function fetchBurnBetaAllowlist(connection: Connection, burnProgramId: PublicKey, assetId: number): Promise<PublicKey[] | null> {
+    const info = await connection.getAccountInfo(betaAllowlistAddress(burnProgramId, assetId));
+    if (!info || !info.owner.equals(burnProgramId)) {
+        return null;
+    }
+    return decodeUsers(info.data, 9);
+}
+
+/** Whether `user` may verify (and so mint) burns under an X1 beta allowlist */
+export function betaAllowlistAllows(list: BetaAllowlist | null, user: PublicKey): boolean {
+    return !list || !list.enabled || list.users.some(u => u.equals(user));
+}
+
+/** Must match create_beta_allowlist_message in the light client */
+export function betaAllowlistMessage(
+    validatorSetVersion: bigint | number,
+    configNonce: bigint | number,
+    assetId: number,
+    enabled: boolean,
+    users: PublicKey[],
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    const nonce = Buffer.alloc(8);
+    nonce.writeBigUInt64LE(BigInt(configNonce));
+    const version = Buffer.alloc(8);
+    version.writeBigUInt64LE(BigInt(validatorSetVersion));
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_BETA_ALLOWLIST'),
+        version,
+        nonce,
+        Buffer.from([assetId, enabled ? 1 : 0, users.length]),
+        ...users.map(user => user.toBuffer()),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
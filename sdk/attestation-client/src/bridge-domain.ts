@@ -0,0 +1,29 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Per-deployment attestation domain separator (light client BridgeDomain account)
+ *
+ * Every attestation message starts with this domain, so devnet and mainnet
+ * signatures can never be replayed on each other. Validators and relayers
+ * read it from chain instead of compiling it in.
+ */
+
+/** Must match DOMAIN_SEPARATOR in the light client (used while no BridgeDomain exists) */
+export const DEFAULT_DOMAIN_SEPARATOR = 'XENCAT_X1_BRIDGE_V1';
+
+export function bridgeDomainAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('bridge_domain')], lightClientProgramId)[0];
+}
+
+/**
+ * Domain separator of the deployment (DEFAULT_DOMAIN_SEPARATOR while the account does not exist)
+ */
+export async function fetchBridgeDomain(connection: Connection, lightClientProgramId: PublicKey): Promise<string> {
+    const info = await connection.getAccountInfo(bridgeDomainAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return DEFAULT_DOMAIN_SEPARATOR;
+    }
+    // Skip the 8-byte account discriminator; Borsh string = u32 length + bytes
+    const length = info.data.readUInt32LE(8);
+    return info.data.subarray(12, 12 + length).toString('utf-8');
+}
@@ -0,0 +1,186 @@
+import { Connection, PublicKey, TransactionInstruction, TransactionMessage, VersionedTransaction } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Bridge capability discovery (light client get_bridge_info / BridgeInfo)
+ *
+ * Lets SDKs and relayers ask the deployment what it supports instead of
+ * hard-coding it: bridge and program versions, the attestation message
+ * versions submit instructions accept, registered assets with their mint
+ * programs, and feature bits. `fetchBridgeInfo` reads the BridgeInfo
+ * account (one RPC call, refreshed by sync_bridge_info); `simulateBridgeInfo`
+ * gets the live view from a simulated get_bridge_info.
+ */
+
+/** Must match state::features in the light client (bit values). Unknown bits are ignored. */
+export const BRIDGE_FEATURES = {
+    V2_SUBMISSIONS: 1,
+    TIMED_ATTESTATIONS: 2,
+    LATENCY_STATS: 4,
+    VALIDATOR_RESIGNATION: 8,
+    VALIDATOR_SET_RESTORE: 16,
+    SOLANA_EPOCH: 32,
+    ASSET_CONVERSION: 64,
+    BURN_ARCHIVE: 128,
+    BURN_FREEZE: 256,
+    BFT_MODE: 512,
+    RENT_SPONSORSHIP: 1024,
+    FEATURE_GATES: 2048,
+    BRIDGE_DOMAIN: 4096,
+    DEPOSIT_WEBHOOKS: 8192,
+    EVENT_SCHEMAS: 16384,
+    ASSET_METADATA: 32768,
+    PROGRAM_HASHES: 65536,
+    SWEEP_BOUNTY: 131072,
+    DEGRADED_MODE: 262144,
+    X1_CHAIN_ID: 524288,
+    RETURN_BRIDGE: 1048576,
+    ASSET_REGISTRY: 2097152,
+    EMERGENCY_PAUSE: 4194304,
+    USAGE_SNAPSHOTS: 8388608,
+    ATTESTATION_EXPIRY: 16777216,
+    CLAIM_ON_BEHALF: 33554432,
+    WORMHOLE_VAA: 67108864,
+    BURN_REDIRECT: 134217728,
+} as const;
+
+export type BridgeFeature = keyof typeof BRIDGE_FEATURES;
+
+export interface BridgeCapabilities {
+    bridgeVersion: [number, number, number];
+    lightClientVersion: [number, number, number];
+    attestationVersions: number[];
+    assets: Array<{ assetId: number; mintProgram: PublicKey }>;
+    features: number;
+    validatorSetVersion: number;
+    validatorCount: number;
+    threshold: number;
+}
+
+export interface BridgeInfoAccount {
+    capabilities: BridgeCapabilities;
+    updatedSlot: number;
+}
+
+export function bridgeInfoAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('bridge_info')], lightClientProgramId)[0];
+}
+
+/**
+ * Borsh-decode BridgeCapabilities starting at `offset`
+ */
+export function decodeBridgeCapabilities(data: Buffer, offset = 0): { capabilities: BridgeCapabilities; end: number } {
+    let o = offset;
+    const semver = (): [number, number, number] => {
+        const v: [number, number, number] = [data.readUInt16LE(o), data.readUInt16LE(o + 2), data.readUInt16LE(o + 4)];
+        o += 6;
+        return v;
+    };
+    const bridgeVersion = semver();
+    const lightClientVersion = semver();
+
+    const versionCount = data.readUInt32LE(o);
+    o += 4;
+    const attestationVersions = [...data.subarray(o, o + versionCount)];
+    o += versionCount;
+
+    const assetCount = data.readUInt32LE(o);
+    o += 4;
+    const assets: BridgeCapabilities['assets'] = [];
+    for (let i = 0; i < assetCount; i++) {
+        assets.push({ assetId: data[o], mintProgram: new PublicKey(data.subarray(o + 1, o + 33)) });
+        o += 33;
+    }
+
+    const features = Number(data.readBigUInt64LE(o));
+    const validatorSetVersion = Number(data.readBigUInt64LE(o + 8));
+    const validatorCount = data[o + 16];
+    const threshold = data[o + 17];
+    o += 18;
+
+    return {
+        capabilities: {
+            bridgeVersion, lightClientVersion, attestationVersions, assets,
+            features, validatorSetVersion, validatorCount, threshold,
+        },
+        end: o,
+    };
+}
+
+/**
+ * Read the BridgeInfo account (null until initialize_bridge_info has run)
+ */
+export async function fetchBridgeInfo(connection: Connection, lightClientProgramId: PublicKey): Promise<BridgeInfoAccount | null> {
+    const info = await connection.getAccountInfo(bridgeInfoAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    // Skip the 8-byte account discriminator
+    const { capabilities, end } = decodeBridgeCapabilities(info.data, 8);
+    return { capabilities, updatedSlot: Number(info.data.readBigUInt64LE(end)) };
+}
+
+/**
+ * Live capabilities from a simulated get_bridge_info (nothing is sent).
+ * `payer` only has to exist; it does not sign.
+ */
+export async function simulateBridgeInfo(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    payer: PublicKey
+): Promise<BridgeCapabilities> {
+    const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], lightClientProgramId)[0];
+    const instruction = new TransactionInstruction({
+        programId: lightClientProgramId,
+        keys: [
+            { pubkey: pda('x1_validator_set_v2'), isSigner: false, isWritable: false },
+            { pubkey: pda('bridge_config'), isSigner: false, isWritable: false },
+        ],
+        data: crypto.createHash('sha256').update('global:get_bridge_info').digest().subarray(0, 8),
+    });
+    const { blockhash } = await connection.getLatestBlockhash();
+    const tx = new VersionedTransaction(new TransactionMessage({
+        payerKey: payer,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message());
+
+    const simulation = await connection.simulateTransaction(tx, { sigVerify: false, replaceRecentBlockhash: true });
+    const returnData = simulation.value.returnData;
+    if (simulation.value.err || !returnData || !returnData.programId || returnData.programId !== lightClientProgramId.toBase58()) {
+        throw new Error(`get_bridge_info failed: ${JSON.stringify(simulation.value.err)} ${(simulation.value.logs || []).join('\n')}`);
+    }
+    return decodeBridgeCapabilities(Buffer.from(returnData.data[0], 'base64')).capabilities;
+}
+
+export function hasFeature(capabilities: BridgeCapabilities, feature: BridgeFeature): boolean {
+    return (capabilities.features & BRIDGE_FEATURES[feature]) !== 0;
+}
+
+/**
+ * Highest attestation message version both sides support
+ * (`clientVersions`: what this client can produce, default V3 then V2)
+ */
+export function negotiateAttestationVersion(capabilities: BridgeCapabilities, clientVersions: number[] = [3, 2]): number {
+    const version = [...clientVersions]
+        .sort((a, b) => b - a)
+        .find(v => capabilities.attestationVersions.includes(v));
+    if (version === undefined) {
+        throw new Error(
+            `No common attestation message version: bridge accepts ${capabilities.attestationVersions.join(', ')}, ` +
+            `client supports ${clientVersions.join(', ')}`
+        );
+    }
+    return version;
+}
+
+/**
+ * Mint program for an asset id, from the deployment rather than local config
+ */
+export function mintProgramForAsset(capabilities: BridgeCapabilities, assetId: number): PublicKey {
+    const asset = capabilities.assets.find(a => a.assetId === assetId);
+    if (!asset) {
+        throw new Error(`Asset ${assetId} is not registered on this bridge`);
+    }
+    return asset.mintProgram;
+}
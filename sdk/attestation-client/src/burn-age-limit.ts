@@ -0,0 +1,63 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Burn age limit (replay guard garbage collection)
+ *
+ * Once validator governance sets it, V3 attestations of a burn whose
+ * Solana block time is older than `maxBurnAge` seconds fail with
+ * BurnTooOld. That makes the mint programs' ProcessedBurnV3 replay guards
+ * of old burns closable (close_processed_burn). The limit can be lowered
+ * but never raised or removed.
+ *
+ * Layout: discriminator(8) || max_burn_age(i64) || updated_slot(u64) || bump(1)
+ */
+
+/** Must match X1ValidatorSet::MAX_ATTESTATION_CLOCK_SKEW (added by BurnAgeLimit::is_expired) */
+export const BURN_AGE_CLOCK_SKEW_SECONDS = 120;
+
+export interface BurnAgeLimit {
+    /** Seconds (0 = no limit) */
+    maxBurnAge: number;
+    updatedSlot: number;
+}
+
+export function burnAgeLimitAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('burn_age_limit')], lightClientProgramId)[0];
+}
+
+export function decodeBurnAgeLimit(data: Buffer): BurnAgeLimit {
+    return {
+        maxBurnAge: Number(data.readBigInt64LE(8)),
+        updatedSlot: Number(data.readBigUInt64LE(16)),
+    };
+}
+
+/**
+ * The light client's burn age limit (null = not created, no limit)
+ */
+export async function fetchBurnAgeLimit(connection: Connection, lightClientProgramId: PublicKey): Promise<BurnAgeLimit | null> {
+    const info = await connection.getAccountInfo(burnAgeLimitAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeBurnAgeLimit(info.data);
+}
+
+/** Unix time from which the replay guard of a burn minted at `processedAt` can be closed (null = never) */
+export function replayGuardClosableAt(limit: BurnAgeLimit | null, processedAt: number): number | null {
+    if (!limit || limit.maxBurnAge === 0) {
+        return null;
+    }
+    return processedAt + limit.maxBurnAge + BURN_AGE_CLOCK_SKEW_SECONDS + 1;
+}
+
+/** Must match create_burn_age_limit_message in the light client */
+export function burnAgeLimitMessage(validatorSetVersion: bigint | number, configNonce: bigint | number, maxBurnAge: number, x1GenesisHash: Buffer | null): Buffer {
+    const payload = Buffer.alloc(16);
+    payload.writeBigUInt64LE(BigInt(configNonce), 0);
+    payload.writeBigInt64LE(BigInt(maxBurnAge), 8);
+    const version = Buffer.alloc(8);
+    version.writeBigUInt64LE(BigInt(validatorSetVersion));
+    return crypto.createHash('sha256').update(Buffer.concat([Buffer.from('SET_BURN_AGE_LIMIT'), version, payload, ...(x1GenesisHash ? [x1GenesisHash] : [])])).digest();
+}
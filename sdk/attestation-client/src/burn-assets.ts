@@ -0,0 +1,70 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Burn program asset registry (Solana side)
+ *
+ * The burn program binds each light client asset id to its Solana mint in
+ * a RegisteredAsset PDA ["burn_asset", asset_id], with a RegisteredMint
+ * reverse index ["burn_asset_mint", mint]. burn_registered_asset burns any
+ * active registered asset and stores its id in the BurnRecord, which
+ * validators then take as the asset of the burn. Other burn instructions
+ * record asset id 0 (validators infer the asset from the burned mint).
+ *
+ * RegisteredAsset layout: discriminator(8) || asset_id(1) || mint(32) || active(1)
+ *   || total_burns(8) || total_amount_burned(8) || bump(1)
+ * RegisteredMint layout: discriminator(8) || asset_id(1) || bump(1)
+ * BurnRecord: asset_id(1) at offset 194, in records of 196+ bytes
+ */
+
+export interface RegisteredBurnAsset {
+    assetId: number;
+    mint: PublicKey;
+    active: boolean;
+    totalBurns: bigint;
+    totalAmountBurned: bigint;
+}
+
+export function registeredBurnAssetAddress(burnProgramId: PublicKey, assetId: number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('burn_asset'), Buffer.from([assetId])], burnProgramId)[0];
+}
+
+export function registeredBurnMintAddress(burnProgramId: PublicKey, mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('burn_asset_mint'), mint.toBuffer()], burnProgramId)[0];
+}
+
+export function decodeRegisteredBurnAsset(data: Buffer): RegisteredBurnAsset {
+    return {
+        assetId: data[8],
+        mint: new PublicKey(data.subarray(9, 41)),
+        active: data[41] === 1,
+        totalBurns: data.readBigUInt64LE(42),
+        totalAmountBurned: data.readBigUInt64LE(50),
+    };
+}
+
+/**
+ * A registered asset of the burn program (null = not registered)
+ */
+export async function fetchRegisteredBurnAsset(connection: Connection, burnProgramId: PublicKey, assetId: number): Promise<RegisteredBurnAsset | null> {
+    const info = await connection.getAccountInfo(registeredBurnAssetAddress(burnProgramId, assetId));
+    if (!info || !info.owner.equals(burnProgramId)) {
+        return null;
+    }
+    return decodeRegisteredBurnAsset(info.data);
+}
+
+/**
+ * Asset id registered for a Solana mint (null = not registered)
+ */
+export async function fetchBurnAssetIdForMint(connection: Connection, burnProgramId: PublicKey, mint: PublicKey): Promise<number | null> {
+    const info = await connection.getAccountInfo(registeredBurnMintAddress(burnProgramId, mint));
+    if (!info || !info.owner.equals(burnProgramId)) {
+        return null;
+    }
+    return info.data[8];
+}
+
+/** Asset id recorded in a BurnRecord's data (0 = not recorded) */
+export function burnRecordAssetId(data: Buffer): number {
+    return data.length >= 196 ? data[194] : 0;
+}
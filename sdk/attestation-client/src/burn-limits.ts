@@ -0,0 +1,58 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Burn amount limits (Solana burn program GlobalState)
+ *
+ * The burn program's upgrade authority sets the smallest and largest
+ * amount a single burn may have (update_burn_limits); 0 disables either
+ * limit. Every burn instruction rejects an amount outside them, so check
+ * before sending.
+ *
+ * GlobalState layout: discriminator(8) || nonce_counter(8) || total_burns(8)
+ *   || total_amount_burned(8) || bump(1) || min_burn_amount(8) || max_burn_amount(8)
+ *   || record_grace_period(8) (see burn-record-retention)
+ * (accounts of 41 bytes predate the limits: no limits)
+ */
+
+export interface BurnLimits {
+    /** 0 = no minimum */
+    minBurnAmount: bigint;
+    /** 0 = no maximum */
+    maxBurnAmount: bigint;
+}
+
+export function burnGlobalStateAddress(burnProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('global_state')], burnProgramId)[0];
+}
+
+export function decodeBurnLimits(data: Buffer): BurnLimits {
+    if (data.length < 49) {
+        return { minBurnAmount: 0n, maxBurnAmount: 0n };
+    }
+    return {
+        minBurnAmount: data.readBigUInt64LE(33),
+        maxBurnAmount: data.readBigUInt64LE(41),
+    };
+}
+
+/**
+ * The burn program's burn limits (null = burn program not initialized)
+ */
+export async function fetchBurnLimits(connection: Connection, burnProgramId: PublicKey): Promise<BurnLimits | null> {
+    const info = await connection.getAccountInfo(burnGlobalStateAddress(burnProgramId));
+    if (!info || !info.owner.equals(burnProgramId)) {
+        return null;
+    }
+    return decodeBurnLimits(info.data);
+}
+
+/** Why `amount` would be rejected by GlobalState::check_amount (null = accepted) */
+export function burnAmountError(limits: BurnLimits, amount: bigint): string | null {
+    if (amount < limits.minBurnAmount) {
+        return `below the minimum burn of ${limits.minBurnAmount}`;
+    }
+    if (limits.maxBurnAmount !== 0n && amount > limits.maxBurnAmount) {
+        return `above the maximum burn of ${limits.maxBurnAmount}; split it into several burns`;
+    }
+    return null;
+}
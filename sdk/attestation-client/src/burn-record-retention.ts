@@ -0,0 +1,115 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import { burnGlobalStateAddress } from './burn-limits';
+
+/**
+ * BurnRecord retention (Solana burn program)
+ *
+ * A BurnRecord can be closed and its rent returned to the burner:
+ * - close_burn_record: once settled (BurnSettlement exists), by anyone
+ * - reclaim_burn_record: by the burner, once GlobalState's
+ *   record_grace_period (seconds, 0 = disabled) has passed since the burn
+ * Both log the record as a BurnRecordFinalized event first, so the burn
+ * stays provable from archived transactions once the account is gone.
+ *
+ * GlobalState: record_grace_period(i64) at offset 49, in accounts of 57+ bytes
+ * BurnRecordFinalized layout (Anchor event): discriminator(8) || nonce(8) || user(32)
+ *   || attested_user(32) || amount(8) || timestamp(8) || record_hash(32)
+ *   || confirmation_depth(2) || asset_id(1) || settled(1) || closed_at(8)
+ */
+
+export interface BurnRecordFinalized {
+    nonce: bigint;
+    /** Burner (got the rent back) */
+    user: PublicKey;
+    /** X1 key the burn was attested for */
+    attestedUser: PublicKey;
+    amount: bigint;
+    /** Burn time (unix seconds) */
+    timestamp: bigint;
+    recordHash: Buffer;
+    confirmationDepth: number;
+    assetId: number;
+    /** Closed after settlement, rather than after the grace period */
+    settled: boolean;
+    closedAt: number;
+}
+
+const FINALIZED_EVENT_DISCRIMINATOR = crypto.createHash('sha256').update('event:BurnRecordFinalized').digest().subarray(0, 8);
+const FINALIZED_EVENT_SIZE = 8 + 8 + 32 + 32 + 8 + 8 + 32 + 2 + 1 + 1 + 8;
+
+export function decodeRecordGracePeriod(globalStateData: Buffer): number {
+    return globalStateData.length >= 57 ? Number(globalStateData.readBigInt64LE(49)) : 0;
+}
+
+/**
+ * Seconds after a burn before its burner may reclaim the record (0 = never,
+ * null = burn program not initialized)
+ */
+export async function fetchRecordGracePeriod(connection: Connection, burnProgramId: PublicKey): Promise<number | null> {
+    const info = await connection.getAccountInfo(burnGlobalStateAddress(burnProgramId));
+    if (!info || !info.owner.equals(burnProgramId)) {
+        return null;
+    }
+    return decodeRecordGracePeriod(info.data);
+}
+
+/** Unix time from which reclaim_burn_record accepts a burn made at `burnTimestamp` (null = never) */
+export function burnRecordReclaimableAt(burnTimestamp: number, gracePeriod: number): number | null {
+    return gracePeriod === 0 ? null : burnTimestamp + gracePeriod;
+}
+
+/**
+ * BurnRecordFinalized events logged by `burnProgramId` in a transaction's logs
+ */
+export function decodeBurnRecordFinalizations(logs: string[], burnProgramId: PublicKey): BurnRecordFinalized[] {
+    const program = burnProgramId.toBase58();
+    const stack: string[] = [];
+    const events: BurnRecordFinalized[] = [];
+    for (const line of logs) {
+        const invoke = /^Program (\w+) invoke \[\d+\]$/.exec(line);
+        if (invoke) {
+            stack.push(invoke[1]);
+            continue;
+        }
+        if (/^Program \w+ (success|failed)/.test(line)) {
+            stack.pop();
+            continue;
+        }
+        if (!line.startsWith('Program data: ') || stack[stack.length - 1] !== program) {
+            continue;
+        }
+        const data = Buffer.from(line.slice('Program data: '.length), 'base64');
+        if (data.length !== FINALIZED_EVENT_SIZE || !data.subarray(0, 8).equals(FINALIZED_EVENT_DISCRIMINATOR)) {
+            continue;
+        }
+        events.push({
+            nonce: data.readBigUInt64LE(8),
+            user: new PublicKey(data.subarray(16, 48)),
+            attestedUser: new PublicKey(data.subarray(48, 80)),
+            amount: data.readBigUInt64LE(80),
+            timestamp: data.readBigUInt64LE(88),
+            recordHash: data.subarray(96, 128),
+            confirmationDepth: data.readUInt16LE(128),
+            assetId: data[130],
+            settled: data[131] === 1,
+            closedAt: Number(data.readBigInt64LE(132)),
+        });
+    }
+    return events;
+}
+
+/**
+ * The archived copy of a closed BurnRecord, from the transaction that closed it
+ */
+export async function fetchBurnRecordFinalization(
+    connection: Connection,
+    signature: string,
+    burnProgramId: PublicKey
+): Promise<BurnRecordFinalized | null> {
+    const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'finalized' });
+    if (!tx || tx.meta?.err) {
+        return null;
+    }
+    return decodeBurnRecordFinalizations(tx.meta?.logMessages || [], burnProgramId)[0] ?? null;
+}
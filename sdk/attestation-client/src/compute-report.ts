@@ -0,0 +1,86 @@
+import { PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Compute unit reports (ComputeUnitsReported events)
+ *
+ * The heavy instructions of the light client (attestation submits) and of
+ * the mint programs (V3 and batch mints) end by emitting the compute units
+ * their handler consumed and what the instruction had left. Dashboards
+ * chart these per instruction and per item count to catch CU drift as
+ * validator sets, attestation bundles and batches grow.
+ *
+ * Layout (Anchor event): discriminator(8) || instruction(8) || items(1) || consumed(8) || remaining(8)
+ */
+export interface ComputeUnitsReport {
+    /** Program that emitted the report */
+    programId: PublicKey;
+    /** Instruction name (snake_case), or the discriminator in hex if unknown */
+    instruction: string;
+    /** Attestations or burns processed */
+    items: number;
+    consumed: bigint;
+    remaining: bigint;
+}
+
+/** Instructions that report, all three programs */
+export const COMPUTE_REPORTING_INSTRUCTIONS = [
+    'submit_burn_attestation',
+    'submit_burn_attestation_v3',
+    'submit_burn_attestation_degraded',
+    'submit_burn_attestation_for_recipient',
+    'mint_from_burn_v3',
+    'mint_from_burns',
+];
+
+const COMPUTE_EVENT_DISCRIMINATOR = crypto.createHash('sha256').update('event:ComputeUnitsReported').digest().subarray(0, 8);
+const COMPUTE_EVENT_SIZE = 8 + 8 + 1 + 8 + 8;
+
+/** Anchor instruction discriminator: sha256("global:<name>")[..8] */
+export function instructionDiscriminator(name: string): Buffer {
+    return crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+}
+
+const INSTRUCTION_NAMES = new Map(
+    COMPUTE_REPORTING_INSTRUCTIONS.map(name => [instructionDiscriminator(name).toString('hex'), name] as [string, string])
+);
+
+/**
+ * ComputeUnitsReported events in a transaction's logs
+ *
+ * Each report is attributed to the program executing when it was logged,
+ * and only reports of `programIds` are kept.
+ */
+export function decodeComputeReports(logs: string[], programIds: PublicKey[]): ComputeUnitsReport[] {
+    const programs = new Set(programIds.map(id => id.toBase58()));
+    const stack: string[] = [];
+    const reports: ComputeUnitsReport[] = [];
+    for (const line of logs) {
+        const invoke = /^Program (\w+) invoke \[\d+\]$/.exec(line);
+        if (invoke) {
+            stack.push(invoke[1]);
+            continue;
+        }
+        if (/^Program \w+ (success|failed)/.test(line)) {
+            stack.pop();
+            continue;
+        }
+        const program = stack[stack.length - 1];
+        if (!line.startsWith('Program data: ') || !programs.has(program)) {
+            continue;
+        }
+        const data = Buffer.from(line.slice('Program data: '.length), 'base64');
+        if (data.length !== COMPUTE_EVENT_SIZE || !data.subarray(0, 8).equals(COMPUTE_EVENT_DISCRIMINATOR)) {
+            continue;
+        }
+        const discriminator = data.subarray(8, 16).toString('hex');
+        reports.push({
+            programId: new PublicKey(program),
+            instruction: INSTRUCTION_NAMES.get(discriminator) ?? discriminator,
+            items: data[16],
+            consumed: data.readBigUInt64LE(17),
+            remaining: data.readBigUInt64LE(25),
+        });
+    }
+    return reports;
+}
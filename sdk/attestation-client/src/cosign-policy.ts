@@ -0,0 +1,56 @@
+import { AccountMeta, Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Large-mint co-sign policy of a mint program (CoSignPolicy)
+ *
+ * Institutional deployments can require a second signer (e.g. a
+ * compliance service) on every mint above a threshold. Relayers check the
+ * policy before minting and, when it applies, add the co-signer as an
+ * extra signer at the end of remaining_accounts.
+ *
+ * Layout: discriminator(8) || enabled(1) || cosigner(32) || threshold(8) || bump(1)
+ */
+
+export interface CoSignPolicy {
+    enabled: boolean;
+    cosigner: PublicKey;
+    /** Largest amount (base units) minted without the co-signer */
+    threshold: bigint;
+}
+
+export function cosignPolicyAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('cosign_policy')], mintProgramId)[0];
+}
+
+export function decodeCoSignPolicy(data: Buffer): CoSignPolicy {
+    return {
+        enabled: data[8] !== 0,
+        cosigner: new PublicKey(data.subarray(9, 41)),
+        threshold: data.readBigUInt64LE(41),
+    };
+}
+
+/**
+ * The mint program's policy (null = none, no co-signer ever needed)
+ */
+export async function fetchCoSignPolicy(connection: Connection, mintProgramId: PublicKey): Promise<CoSignPolicy | null> {
+    const info = await connection.getAccountInfo(cosignPolicyAddress(mintProgramId));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeCoSignPolicy(info.data);
+}
+
+/**
+ * The co-signer a mint of `amount` needs, if any
+ */
+export function requiredCoSigner(policy: CoSignPolicy | null, amount: bigint | number): PublicKey | null {
+    return policy && policy.enabled && BigInt(amount) > policy.threshold ? policy.cosigner : null;
+}
+
+/**
+ * remaining_accounts entry for the co-signer (append after the fee accounts)
+ */
+export function coSignerAccount(cosigner: PublicKey): AccountMeta {
+    return { pubkey: cosigner, isSigner: true, isWritable: false };
+}
@@ -0,0 +1,228 @@
+import { Connection, PublicKey, VersionedTransactionResponse } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { BurnVerifiedEvent } from './event-schema';
+import { decodeMintAcknowledgements } from './mint-ack';
+
+/**
+ * Per-transfer cost accounting (chargeback reports)
+ *
+ * Attributes what each X1 bridge transaction cost to the transfers it
+ * completed and to the integrator that paid for it:
+ * - rent: lamports put into accounts the transaction created (verified and
+ *   processed burn PDAs, token accounts...); the part the rent sponsor pool
+ *   paid is reported separately
+ * - base and priority fees: the transaction fee (5000 lamports per
+ *   signature is base, the rest priority)
+ * - validator fees: lamports paid to validators or their fee vaults
+ *
+ * A transaction that completes several transfers (a batched mint) splits
+ * its costs evenly between them. The integrator is looked up by fee payer:
+ * partners that sponsor fees run their own relayer keys, listed in
+ * [[accounting.integrators]] of xencat.toml. A fee payer that is the
+ * burner itself is "self"; any other unlisted payer is "unattributed".
+ */
+
+export const LAMPORTS_PER_SIGNATURE = 5000;
+export const SELF_PAID = 'self';
+export const UNATTRIBUTED = 'unattributed';
+
+/** One transaction's share of one transfer's costs (lamports) */
+export interface TransferCost {
+    assetId: number;
+    burnNonce: bigint;
+    /** Burner, when the transaction verified the burn (BurnVerified) */
+    user?: PublicKey;
+    integrator: string;
+    feePayer: PublicKey;
+    signature: string;
+    /** 'attest' (BurnVerified) or 'mint' (MintAcknowledged) */
+    kind: 'attest' | 'mint';
+    blockTime: number;
+    rent: bigint;
+    sponsoredRent: bigint;
+    baseFee: bigint;
+    priorityFee: bigint;
+    validatorFees: bigint;
+}
+
+export interface CostAccountingContext {
+    lightClientProgramId: PublicKey;
+    /** Light client event coder (decodes BurnVerified) */
+    lightClientCoder: anchor.Coder;
+    mintProgramIds: PublicKey[];
+    /** Validator keys and their fee vault PDAs (base58) */
+    validatorFeeAccounts: Set<string>;
+    /** Rent sponsor pool PDA */
+    rentSponsorPool: PublicKey;
+    /** Fee payer (base58) -> integrator name */
+    integrators: Map<string, string>;
+}
+
+export interface IntegratorTotals {
+    integrator: string;
+    transfers: number;
+    transactions: number;
+    rent: bigint;
+    sponsoredRent: bigint;
+    baseFee: bigint;
+    priorityFee: bigint;
+    validatorFees: bigint;
+    total: bigint;
+}
+
+/** Unix seconds [start, end) of a calendar month 'YYYY-MM' (UTC) */
+export function monthRange(month: string): { start: number; end: number } {
+    const match = /^(\d{4})-(\d{2})$/.exec(month);
+    if (!match || Number(match[2]) < 1 || Number(match[2]) > 12) {
+        throw new Error(`Invalid month ${month}: expected YYYY-MM`);
+    }
+    const [year, index] = [Number(match[1]), Number(match[2]) - 1];
+    return { start: Date.UTC(year, index, 1) / 1000, end: Date.UTC(year, index + 1, 1) / 1000 };
+}
+
+/** Signatures of successful `program` transactions with a block time in [start, end), newest first */
+export async function signaturesInRange(connection: Connection, program: PublicKey, start: number, end: number): Promise<string[]> {
+    const signatures: string[] = [];
+    let before: string | undefined;
+    for (;;) {
+        const page = await connection.getSignaturesForAddress(program, { before, limit: 1000 });
+        for (const { signature, blockTime, err } of page) {
+            if (!err && blockTime != null && blockTime >= start && blockTime < end) {
+                signatures.push(signature);
+            }
+        }
+        const oldest = page[page.length - 1];
+        if (page.length < 1000 || (oldest.blockTime != null && oldest.blockTime < start)) break;
+        before = oldest.signature;
+    }
+    return signatures;
+}
+
+function splitEvenly(total: bigint, parts: number): bigint[] {
+    const share = total / BigInt(parts);
+    // The first transfer takes the remainder, so the shares add up to the total
+    return Array.from({ length: parts }, (_, i) => (i === 0 ? share + total % BigInt(parts) : share));
+}
+
+/**
+ * Cost lines of one confirmed X1 transaction (empty if it completed no transfer)
+ */
+export function transactionCosts(tx: VersionedTransactionResponse, context: CostAccountingContext): TransferCost[] {
+    const meta = tx.meta;
+    if (!meta || meta.err || tx.blockTime == null) {
+        return [];
+    }
+    const logs = meta.logMessages || [];
+
+    const transfers = new Map<string, { assetId: number; burnNonce: bigint; user?: PublicKey; kind: TransferCost['kind'] }>();
+    const parser = new anchor.EventParser(context.lightClientProgramId, context.lightClientCoder as any);
+    for (const event of parser.parseLogs(logs)) {
+        if (event.name === 'BurnVerified') {
+            const data = event.data as unknown as BurnVerifiedEvent;
+            const burnNonce = BigInt(data.burnNonce.toString());
+            transfers.set(`${data.assetId}:${burnNonce}`, { assetId: data.assetId, burnNonce, user: data.user, kind: 'attest' });
+        }
+    }
+    for (const mintProgramId of context.mintProgramIds) {
+        for (const ack of decodeMintAcknowledgements(logs, mintProgramId)) {
+            transfers.set(`${ack.assetId}:${ack.burnNonce}`, { assetId: ack.assetId, burnNonce: ack.burnNonce, kind: 'mint' });
+        }
+    }
+    if (transfers.size === 0) {
+        return [];
+    }
+
+    const keys = tx.transaction.message.getAccountKeys({ accountKeysFromLookups: meta.loadedAddresses });
+    let rent = 0n;
+    let sponsoredRent = 0n;
+    let validatorFees = 0n;
+    meta.preBalances.forEach((pre, i) => {
+        const post = meta.postBalances[i];
+        const key = keys.get(i)!;
+        if (pre === 0 && post > 0) {
+            rent += BigInt(post);
+        } else if (key.equals(context.rentSponsorPool) && post < pre) {
+            sponsoredRent += BigInt(pre - post);
+        } else if (context.validatorFeeAccounts.has(key.toBase58()) && post > pre) {
+            validatorFees += BigInt(post - pre);
+        }
+    });
+    const baseFee = BigInt(LAMPORTS_PER_SIGNATURE * tx.transaction.signatures.length);
+    const priorityFee = BigInt(meta.fee) > baseFee ? BigInt(meta.fee) - baseFee : 0n;
+
+    const feePayer = keys.get(0)!;
+    const parts = transfers.size;
+    const shares = {
+        rent: splitEvenly(rent, parts),
+        sponsoredRent: splitEvenly(sponsoredRent, parts),
+        baseFee: splitEvenly(baseFee, parts),
+        priorityFee: splitEvenly(priorityFee, parts),
+        validatorFees: splitEvenly(validatorFees, parts),
+    };
+    return [...transfers.values()].map((transfer, i) => ({
+        ...transfer,
+        integrator: context.integrators.get(feePayer.toBase58())
+            ?? (transfer.user?.equals(feePayer) ? SELF_PAID : UNATTRIBUTED),
+        feePayer,
+        signature: tx.transaction.signatures[0],
+        blockTime: tx.blockTime!,
+        rent: shares.rent[i],
+        sponsoredRent: shares.sponsoredRent[i],
+        baseFee: shares.baseFee[i],
+        priorityFee: shares.priorityFee[i],
+        validatorFees: shares.validatorFees[i],
+    }));
+}
+
+/** Cost lines summed per integrator (rent paid by the sponsor pool is not charged back) */
+export function integratorTotals(costs: TransferCost[]): IntegratorTotals[] {
+    const totals = new Map<string, IntegratorTotals & { keys: Set<string>; signatures: Set<string> }>();
+    for (const cost of costs) {
+        const entry = totals.get(cost.integrator) ?? {
+            integrator: cost.integrator,
+            transfers: 0,
+            transactions: 0,
+            rent: 0n,
+            sponsoredRent: 0n,
+            baseFee: 0n,
+            priorityFee: 0n,
+            validatorFees: 0n,
+            total: 0n,
+            keys: new Set<string>(),
+            signatures: new Set<string>(),
+        };
+        entry.keys.add(`${cost.assetId}:${cost.burnNonce}`);
+        entry.signatures.add(cost.signature);
+        entry.rent += cost.rent;
+        entry.sponsoredRent += cost.sponsoredRent;
+        entry.baseFee += cost.baseFee;
+        entry.priorityFee += cost.priorityFee;
+        entry.validatorFees += cost.validatorFees;
+        entry.total += cost.rent - cost.sponsoredRent + cost.baseFee + cost.priorityFee + cost.validatorFees;
+        totals.set(cost.integrator, entry);
+    }
+    return [...totals.values()]
+        .map(({ keys, signatures, ...entry }) => ({ ...entry, transfers: keys.size, transactions: signatures.size }))
+        .sort((a, b) => a.integrator.localeCompare(b.integrator));
+}
+
+/** Cost lines as CSV (one row per transaction and transfer) */
+export function costsToCsv(costs: TransferCost[]): string {
+    const header = 'block_time,signature,kind,asset_id,burn_nonce,user,fee_payer,integrator,rent,sponsored_rent,base_fee,priority_fee,validator_fees';
+    const rows = costs.map(c => [
+        new Date(c.blockTime * 1000).toISOString(),
+        c.signature,
+        c.kind,
+        c.assetId,
+        c.burnNonce,
+        c.user?.toBase58() ?? '',
+        c.feePayer.toBase58(),
+        c.integrator,
+        c.rent,
+        c.sponsoredRent,
+        c.baseFee,
+        c.priorityFee,
+        c.validatorFees,
+    ].join(','));
+    return [header, ...rows].join('\n') + '\n';
+}
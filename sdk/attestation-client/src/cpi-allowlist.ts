@@ -0,0 +1,60 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Downstream programs a mint program's mint_and_call may invoke (CpiAllowlist)
+ *
+ * mint_and_call mints a verified burn to the user and then calls an
+ * allowlisted program (staking, LP, ...) in the same transaction, so
+ * "bridge and deposit" takes one X1 transaction. Validators replace the
+ * list with set_cpi_allowlist.
+ *
+ * Layout: discriminator(8) || programs: vec<pubkey(32)> || nonce(8) || bump(1)
+ */
+
+export interface CpiAllowlist {
+    programs: PublicKey[];
+    nonce: bigint;
+}
+
+export function cpiAllowlistAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('cpi_allowlist')], mintProgramId)[0];
+}
+
+export function decodeCpiAllowlist(data: Buffer): CpiAllowlist {
+    const count = data.readUInt32LE(8);
+    const programs = [];
+    for (let i = 0; i < count; i++) {
+        programs.push(new PublicKey(data.subarray(12 + 32 * i, 44 + 32 * i)));
+    }
+    return { programs, nonce: data.readBigUInt64LE(12 + 32 * count) };
+}
+
+/**
+ * The mint program's allowlist (null = none, mint_and_call unavailable)
+ */
+export async function fetchCpiAllowlist(connection: Connection, mintProgramId: PublicKey): Promise<CpiAllowlist | null> {
+    const info = await connection.getAccountInfo(cpiAllowlistAddress(mintProgramId));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeCpiAllowlist(info.data);
+}
+
+/** Must match create_cpi_allowlist_message in the mint programs */
+export function cpiAllowlistMessage(mintProgramId: PublicKey, validatorSetVersion: bigint | number, nonce: bigint | number, programs: PublicKey[], x1GenesisHash: Buffer | null): Buffer {
+    const u64 = (value: bigint | number) => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigUInt64LE(BigInt(value));
+        return buf;
+    };
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_MINT_CPI_ALLOWLIST'),
+        u64(validatorSetVersion),
+        u64(nonce),
+        mintProgramId.toBuffer(),
+        ...programs.map(p => p.toBuffer()),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
+
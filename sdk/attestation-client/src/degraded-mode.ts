@@ -0,0 +1,140 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Partial-set degraded mode of the light client (DegradedMode / DegradedBurn)
+ *
+ * With fewer than `threshold` validators live, governance-configured assets
+ * still accept small burns with `quorum` attestations via
+ * submit_burn_attestation_degraded. Those burns are capped per transfer and
+ * per UTC day, and held until `releaseAt`; release_degraded_burn (anyone)
+ * then makes them mintable.
+ *
+ * DegradedMode layout: discriminator(8) || asset_id(1) || enabled(1) || quorum(1) ||
+ *   max_transfer_amount(8) || daily_cap(8) || delay_seconds(8) || day(8) ||
+ *   day_total(8) || total_burns(8) || bump(1)
+ * DegradedBurn layout: discriminator(8) || asset_id(1) || burn_nonce(8) || user(32) ||
+ *   amount(8) || attestations(1) || release_at(8) || bump(1)
+ */
+
+export interface DegradedMode {
+    assetId: number;
+    enabled: boolean;
+    quorum: number;
+    maxTransferAmount: bigint;
+    dailyCap: bigint;
+    delaySeconds: number;
+    /** UTC day (unix / 86400) that `dayTotal` counts */
+    day: number;
+    dayTotal: bigint;
+    totalBurns: number;
+}
+
+export interface DegradedBurn {
+    assetId: number;
+    burnNonce: number;
+    user: PublicKey;
+    amount: bigint;
+    attestations: number;
+    /** Unix timestamp from which release_degraded_burn succeeds */
+    releaseAt: number;
+}
+
+const SECONDS_PER_DAY = 86_400;
+
+function u64(value: number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+export function degradedModeAddress(lightClientProgramId: PublicKey, assetId: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('degraded_mode'), Buffer.from([assetId])],
+        lightClientProgramId
+    )[0];
+}
+
+export function degradedBurnAddress(
+    lightClientProgramId: PublicKey,
+    assetId: number,
+    user: PublicKey,
+    burnNonce: number
+): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('degraded_burn'), Buffer.from([assetId]), user.toBuffer(), u64(burnNonce)],
+        lightClientProgramId
+    )[0];
+}
+
+/**
+ * The asset's degraded mode (null = never initialized, no degraded mode)
+ */
+export async function fetchDegradedMode(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    assetId: number
+): Promise<DegradedMode | null> {
+    const info = await connection.getAccountInfo(degradedModeAddress(lightClientProgramId, assetId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    const d = info.data;
+    return {
+        assetId: d[8],
+        enabled: d[9] !== 0,
+        quorum: d[10],
+        maxTransferAmount: d.readBigUInt64LE(11),
+        dailyCap: d.readBigUInt64LE(19),
+        delaySeconds: Number(d.readBigInt64LE(27)),
+        day: Number(d.readBigInt64LE(35)),
+        dayTotal: d.readBigUInt64LE(43),
+        totalBurns: Number(d.readBigUInt64LE(51)),
+    };
+}
+
+/**
+ * The hold of a burn verified in degraded mode (null = not held)
+ */
+export async function fetchDegradedBurn(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    assetId: number,
+    user: PublicKey,
+    burnNonce: number
+): Promise<DegradedBurn | null> {
+    const info = await connection.getAccountInfo(degradedBurnAddress(lightClientProgramId, assetId, user, burnNonce));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    const d = info.data;
+    return {
+        assetId: d[8],
+        burnNonce: Number(d.readBigUInt64LE(9)),
+        user: new PublicKey(d.subarray(17, 49)),
+        amount: d.readBigUInt64LE(49),
+        attestations: d[57],
+        releaseAt: Number(d.readBigInt64LE(58)),
+    };
+}
+
+/**
+ * Amount still accepted in degraded mode today (0 when disabled)
+ */
+export function degradedRemainingToday(mode: DegradedMode | null, nowSeconds: number): bigint {
+    if (!mode || !mode.enabled) {
+        return BigInt(0);
+    }
+    const used = Math.floor(nowSeconds / SECONDS_PER_DAY) === mode.day ? mode.dayTotal : BigInt(0);
+    return used >= mode.dailyCap ? BigInt(0) : mode.dailyCap - used;
+}
+
+/**
+ * Whether a burn of `amount` fits degraded mode right now (caps only; quorum is checked on chain)
+ */
+export function degradedModeAccepts(mode: DegradedMode | null, amount: bigint | number, nowSeconds: number): boolean {
+    const value = BigInt(amount);
+    return !!mode
+        && mode.enabled
+        && value <= mode.maxTransferAmount
+        && value <= degradedRemainingToday(mode, nowSeconds);
+}
@@ -0,0 +1,56 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Integrator deposit webhooks (light client DepositWebhook accounts)
+ *
+ * A recipient (e.g. an exchange deposit address) registers the SHA-256 of
+ * its webhook URL on-chain. The notifier is configured with the URL
+ * off-chain and only delivers to it while the hash matches, so the
+ * registration is the recipient's authorization.
+ *
+ * Layout: discriminator(8) || recipient(32) || url_hash(32) || asset_mask(1) || min_amount(8) || updated_slot(8) || bump(1)
+ */
+
+export interface DepositWebhook {
+    recipient: PublicKey;
+    urlHash: Buffer;
+    /** Bit (1 << asset_id) per asset, 0 = every asset */
+    assetMask: number;
+    minAmount: number;
+    updatedSlot: number;
+}
+
+export function depositWebhookAddress(lightClientProgramId: PublicKey, recipient: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('deposit_webhook'), recipient.toBuffer()], lightClientProgramId)[0];
+}
+
+/** Must match url_hash as registered on-chain: sha256 of the exact URL string */
+export function webhookUrlHash(url: string): Buffer {
+    return crypto.createHash('sha256').update(url).digest();
+}
+
+export async function fetchDepositWebhook(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    recipient: PublicKey
+): Promise<DepositWebhook | null> {
+    const info = await connection.getAccountInfo(depositWebhookAddress(lightClientProgramId, recipient));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    const data = info.data;
+    return {
+        recipient: new PublicKey(data.subarray(8, 40)),
+        urlHash: Buffer.from(data.subarray(40, 72)),
+        assetMask: data[72],
+        minAmount: Number(data.readBigUInt64LE(73)),
+        updatedSlot: Number(data.readBigUInt64LE(81)),
+    };
+}
+
+/** Must match DepositWebhook::wants on-chain */
+export function webhookWants(webhook: DepositWebhook, assetId: number, amount: number): boolean {
+    const assetSelected = webhook.assetMask === 0 || (assetId < 8 && (webhook.assetMask & (1 << assetId)) !== 0);
+    return assetSelected && amount >= webhook.minAmount;
+}
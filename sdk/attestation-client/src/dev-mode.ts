@@ -0,0 +1,68 @@
+import { AccountMeta, Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Dev mode guard (mock signatures on marked deployments only)
+ *
+ * A light client built with the `dev-mode` feature accepts mock (all-zero)
+ * attestation signatures only while validator governance has marked the
+ * deployment non-production, and only in submits that pass the marking's
+ * canary account, still funded, as their first remaining account.
+ * Production builds and governance approvals never accept mocks.
+ *
+ * Layout: discriminator(8) || non_production(1) || canary(32) || marked_slot(u64) || bump(1)
+ */
+
+export interface DevModeConfig {
+    nonProduction: boolean;
+    canary: PublicKey;
+    markedSlot: number;
+}
+
+export function devModeConfigAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('dev_mode_config')], lightClientProgramId)[0];
+}
+
+export function decodeDevModeConfig(data: Buffer): DevModeConfig {
+    return {
+        nonProduction: data[8] !== 0,
+        canary: new PublicKey(data.subarray(9, 41)),
+        markedSlot: Number(data.readBigUInt64LE(41)),
+    };
+}
+
+/**
+ * The light client's dev mode config (null = not created, production)
+ */
+export async function fetchDevModeConfig(connection: Connection, lightClientProgramId: PublicKey): Promise<DevModeConfig | null> {
+    const info = await connection.getAccountInfo(devModeConfigAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeDevModeConfig(info.data);
+}
+
+/** Remaining account a submit must start with to use mock signatures (empty when unmarked) */
+export function devModeCanaryAccounts(config: DevModeConfig | null): AccountMeta[] {
+    if (!config || !config.nonProduction) {
+        return [];
+    }
+    return [{ pubkey: config.canary, isSigner: false, isWritable: false }];
+}
+
+/** Must match create_dev_mode_message in the light client */
+export function devModeMessage(
+    validatorSetVersion: bigint | number,
+    configNonce: bigint | number,
+    nonProduction: boolean,
+    canary: PublicKey,
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    const payload = Buffer.alloc(41);
+    payload.writeBigUInt64LE(BigInt(configNonce), 0);
+    payload[8] = nonProduction ? 1 : 0;
+    canary.toBuffer().copy(payload, 9);
+    const version = Buffer.alloc(8);
+    version.writeBigUInt64LE(BigInt(validatorSetVersion));
+    return crypto.createHash('sha256').update(Buffer.concat([Buffer.from('SET_DEV_MODE'), version, payload, ...(x1GenesisHash ? [x1GenesisHash] : [])])).digest();
+}
@@ -0,0 +1,108 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+
+/**
+ * Event schema versions of the bridge programs (light client EventSchemaRegistry)
+ *
+ * Governance bumps a program's version with every upgrade that changes one
+ * of its events. Indexers check the version they were written for at
+ * startup (and periodically) and stop on a mismatch instead of silently
+ * mis-decoding new events.
+ *
+ * Layout: discriminator(8) || vec len(4) || [program(32) || version(2) || updated_slot(8)]* || bump(1)
+ */
+
+export interface EventSchema {
+    program: PublicKey;
+    version: number;
+    updatedSlot: number;
+}
+
+const ENTRY_SIZE = 42;
+
+export function eventSchemaRegistryAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('event_schema_registry')], lightClientProgramId)[0];
+}
+
+/**
+ * Registered schemas (empty while the registry does not exist)
+ */
+export async function fetchEventSchemas(connection: Connection, lightClientProgramId: PublicKey): Promise<EventSchema[]> {
+    const info = await connection.getAccountInfo(eventSchemaRegistryAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return [];
+    }
+    const count = info.data.readUInt32LE(8);
+    return Array.from({ length: count }, (_, i) => {
+        const offset = 12 + i * ENTRY_SIZE;
+        return {
+            program: new PublicKey(info.data.subarray(offset, offset + 32)),
+            version: info.data.readUInt16LE(offset + 32),
+            updatedSlot: Number(info.data.readBigUInt64LE(offset + 34)),
+        };
+    });
+}
+
+/** Must match EventSchemaRegistry::version_of: unlisted programs are at version 0 */
+export function eventSchemaVersion(schemas: EventSchema[], program: PublicKey): number {
+    return schemas.find(s => s.program.equals(program))?.version ?? 0;
+}
+
+/**
+ * Throw unless every program is at the version the indexer was written for
+ *
+ * @param expected program id (base58) -> supported schema version
+ */
+export async function assertEventSchemas(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    expected: Record<string, number>
+): Promise<void> {
+    const schemas = await fetchEventSchemas(connection, lightClientProgramId);
+    const mismatched = Object.entries(expected)
+        .map(([program, version]) => ({ program, version, actual: eventSchemaVersion(schemas, new PublicKey(program)) }))
+        .filter(m => m.actual !== m.version);
+    if (mismatched.length > 0) {
+        throw new Error('Event schema changed: ' + mismatched
+            .map(m => `${m.program} is at v${m.actual}, indexer supports v${m.version}`)
+            .join('; '));
+    }
+}
+
+/** Must match LIGHT_CLIENT_EVENT_SCHEMA_VERSION (the schema_version field of the events below) */
+export const LIGHT_CLIENT_EVENT_SCHEMA_VERSION = 2;
+
+/** Light client BurnVerified (anchor EventParser `data`, camelCase) */
+export interface BurnVerifiedEvent {
+    schemaVersion: number;
+    /** 2 or 3 */
+    attestationVersion: number;
+    assetId: number;
+    burnNonce: anchor.BN;
+    user: PublicKey;
+    amount: anchor.BN;
+    validatorSetVersion: anchor.BN;
+    attestations: number;
+    verifiedSlot: anchor.BN;
+}
+
+/** Light client ValidatorSetInitialized */
+export interface ValidatorSetInitializedEvent {
+    schemaVersion: number;
+    version: anchor.BN;
+    validators: PublicKey[];
+    threshold: number;
+}
+
+/** Light client ValidatorSetUpdated */
+export interface ValidatorSetUpdatedEvent {
+    schemaVersion: number;
+    oldVersion: anchor.BN;
+    newVersion: anchor.BN;
+    validators: PublicKey[];
+    threshold: number;
+    approvals: number;
+    /** Voting weight per validator (empty = unweighted set) */
+    weights: number[];
+    thresholdBps: number;
+}
@@ -0,0 +1,65 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Governance kill switches (light client FeatureGate account)
+ *
+ * Validator governance can switch individual instructions off after an
+ * audit finding without a program upgrade. Relayers check the gate before
+ * spending fees on a path that will fail with FeatureDisabled.
+ */
+
+/** Must match state::gates in the light client (bit values) */
+export const FEATURE_GATES = {
+    ATTEST_V2: 1,
+    ATTEST_V3: 2,
+    MINT_V2: 4,
+    MINT_V3: 8,
+    MINT_STREAM: 16,
+    MINT_STEALTH: 32,
+    MINT_COMPRESSED: 64,
+    CONVERT: 128,
+    RENT_SPONSOR: 256,
+    ATTEST_DEGRADED: 512,
+    RETURN: 1024,
+    CLAIM_ON_BEHALF: 2048,
+    ATTEST_VAA: 4096,
+} as const;
+
+export type FeatureGateName = keyof typeof FEATURE_GATES;
+
+/** Must match FeatureGate::PAUSED (set by pause_bridge, see pause.ts) */
+export const PAUSED_BIT = 1n << 63n;
+
+export function featureGateAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('feature_gate')], lightClientProgramId)[0];
+}
+
+async function fetchGateWord(connection: Connection, lightClientProgramId: PublicKey): Promise<bigint> {
+    const info = await connection.getAccountInfo(featureGateAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return 0n;
+    }
+    // Skip the 8-byte account discriminator
+    return info.data.readBigUInt64LE(8);
+}
+
+/**
+ * Disabled gate bits (0 while the account does not exist: everything enabled)
+ *
+ * The pause bit is left out: see fetchBridgePaused.
+ */
+export async function fetchDisabledGates(connection: Connection, lightClientProgramId: PublicKey): Promise<number> {
+    return Number((await fetchGateWord(connection, lightClientProgramId)) & ~PAUSED_BIT);
+}
+
+/**
+ * Whether validator governance has paused the whole bridge (pause_bridge)
+ */
+export async function fetchBridgePaused(connection: Connection, lightClientProgramId: PublicKey): Promise<boolean> {
+    return ((await fetchGateWord(connection, lightClientProgramId)) & PAUSED_BIT) !== 0n;
+}
+
+/** Names of the gates set in `disabled` */
+export function disabledGateNames(disabled: number): FeatureGateName[] {
+    return (Object.keys(FEATURE_GATES) as FeatureGateName[]).filter(name => (disabled & FEATURE_GATES[name]) !== 0);
+}
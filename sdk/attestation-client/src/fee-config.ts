@@ -0,0 +1,54 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+
+/**
+ * Amount-based validator fee schedule of a mint program (FeeConfig)
+ *
+ * Once the mint authority creates it, each validator is owed, per burn:
+ *   clamp(flat_fee + amount * fee_bps / 10_000, min_fee, max_fee) lamports
+ * instead of the fixed fee_per_validator. Relayers pass validator fee
+ * accounts whenever the schedule can charge a fee (feesEnabled).
+ *
+ * Layout: discriminator(8) || flat_fee(8) || fee_bps(2) || min_fee(8) || max_fee(8) || bump(1)
+ */
+
+export interface FeeConfig {
+    flatFee: bigint;
+    feeBps: number;
+    minFee: bigint;
+    maxFee: bigint;
+}
+
+export function feeConfigAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('fee_config')], mintProgramId)[0];
+}
+
+export function decodeFeeConfig(data: Buffer): FeeConfig {
+    return {
+        flatFee: data.readBigUInt64LE(8),
+        feeBps: data.readUInt16LE(16),
+        minFee: data.readBigUInt64LE(18),
+        maxFee: data.readBigUInt64LE(26),
+    };
+}
+
+/**
+ * The mint program's schedule (null = fixed fee_per_validator)
+ */
+export async function fetchFeeConfig(connection: Connection, mintProgramId: PublicKey): Promise<FeeConfig | null> {
+    const info = await connection.getAccountInfo(feeConfigAddress(mintProgramId));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeFeeConfig(info.data);
+}
+
+/** Must match FeeConfig::fee_for: per-validator fee for a burn of `amount` base units */
+export function feeForAmount(config: FeeConfig, amount: bigint | number): bigint {
+    const fee = config.flatFee + BigInt(amount) * BigInt(config.feeBps) / 10_000n;
+    return fee < config.minFee ? config.minFee : fee > config.maxFee ? config.maxFee : fee;
+}
+
+/** Must match FeeConfig::charges_fees: whether any burn owes validator fees */
+export function feesEnabled(config: FeeConfig): boolean {
+    return config.maxFee > 0n && (config.flatFee > 0n || config.feeBps > 0 || config.minFee > 0n);
+}
@@ -0,0 +1,71 @@
+import { AddressLookupTableAccount, Connection, PublicKey } from '@solana/web3.js';
+import { feeVaultAddress } from './fee-vault';
+
+/**
+ * Fee lookup table (address lookup table discovery)
+ *
+ * Mint transactions carry one fee account per validator. The lookup table
+ * service (scripts/fee-lookup-table.ts run) keeps a frozen address lookup
+ * table of every validator payout account (each validator key and its
+ * fee vault PDA in both mint programs) and publishes it in the light
+ * client's FeeLookupTable record. The light client only accepts a frozen,
+ * active table holding all of them, so a published table can be used
+ * without trusting whoever published it.
+ *
+ * Layout: discriminator(8) || lookup_table(32) || validator_set_version(u64) || updated_slot(u64) || bump(1)
+ */
+
+export interface FeeLookupTableRecord {
+    /** Null when none was published yet */
+    lookupTable: PublicKey | null;
+    validatorSetVersion: number;
+    updatedSlot: number;
+}
+
+export function feeLookupTableRecordAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('fee_lookup_table')], lightClientProgramId)[0];
+}
+
+export function decodeFeeLookupTableRecord(data: Buffer): FeeLookupTableRecord {
+    const lookupTable = new PublicKey(data.subarray(8, 40));
+    return {
+        lookupTable: lookupTable.equals(PublicKey.default) ? null : lookupTable,
+        validatorSetVersion: Number(data.readBigUInt64LE(40)),
+        updatedSlot: Number(data.readBigUInt64LE(48)),
+    };
+}
+
+/**
+ * The light client's fee lookup table record (null = not created)
+ */
+export async function fetchFeeLookupTableRecord(
+    connection: Connection,
+    lightClientProgramId: PublicKey
+): Promise<FeeLookupTableRecord | null> {
+    const info = await connection.getAccountInfo(feeLookupTableRecordAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeFeeLookupTableRecord(info.data);
+}
+
+/** Must match fee_payout_accounts in the light client */
+export function feePayoutAccounts(mintProgramIds: PublicKey[], validators: PublicKey[]): PublicKey[] {
+    return validators.flatMap(validator => [validator, ...mintProgramIds.map(program => feeVaultAddress(program, validator))]);
+}
+
+/**
+ * The published lookup table, if it was checked against validator set
+ * `validatorSetVersion` (null = build the transaction without it)
+ */
+export async function fetchFeeLookupTable(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    validatorSetVersion: number
+): Promise<AddressLookupTableAccount | null> {
+    const record = await fetchFeeLookupTableRecord(connection, lightClientProgramId);
+    if (!record?.lookupTable || record.validatorSetVersion !== validatorSetVersion) {
+        return null;
+    }
+    return (await connection.getAddressLookupTable(record.lookupTable)).value;
+}
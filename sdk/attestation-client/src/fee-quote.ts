@@ -0,0 +1,147 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as fs from 'fs';
+import * as path from 'path';
+import { feeConfigAddress } from './fee-config';
+
+/**
+ * All-in bridge cost quote (lamports on each chain unless noted)
+ *
+ * X1 fees come from the mint program's read-only `quote_fees` instruction,
+ * so they always match what mint_from_burn_v3 will actually charge.
+ * Priority fees are estimates from recent prioritization fees.
+ */
+export interface BridgeFeeQuote {
+    assetId: number;
+    amount: bigint;
+    mintAmount: bigint;
+    solana: {
+        burnRecordRent: number;
+        baseFee: number;
+        priorityFee: number;
+        total: number;
+    };
+    x1: {
+        validatorCount: number;
+        feePerValidator: bigint;
+        validatorFeeTotal: bigint;
+        protocolFee: bigint;
+        verifiedBurnRent: bigint;
+        processedBurnRent: bigint;
+        baseFee: number;
+        priorityFee: number;
+        total: bigint;
+    };
+}
+
+export interface QuoteFeesParams {
+    assetId: number;
+    amount: bigint | number;
+    /** Mint program for the asset (xencat-mint-x1 or dgn-mint-x1) */
+    mintProgramId: PublicKey;
+    lightClientProgramId: PublicKey;
+    /** Compute units requested per transaction (priority fee is per CU) */
+    computeUnits?: { burn: number; attest: number; mint: number };
+}
+
+/** BurnRecord on Solana: discriminator + user + amount + nonce + timestamp + record_hash + bump */
+const BURN_RECORD_SPACE = 8 + 32 + 8 + 8 + 8 + 32 + 1;
+const LAMPORTS_PER_SIGNATURE = 5000;
+const DEFAULT_COMPUTE_UNITS = { burn: 50_000, attest: 400_000, mint: 200_000 };
+
+const MINT_IDLS: Record<number, { idl: string; statePda: string }> = {
+    1: { idl: 'xencat_mint_x1.json', statePda: 'mint_state_v2' },
+    2: { idl: 'dgn_mint_x1.json', statePda: 'dgn_mint_state' },
+};
+
+/**
+ * Median recent priority fee (micro-lamports per CU), 0 if none observed
+ */
+async function estimatePriorityFee(connection: Connection, accounts: PublicKey[]): Promise<number> {
+    try {
+        const fees = await connection.getRecentPrioritizationFees({ lockedWritableAccounts: accounts });
+        const nonZero = fees.map(f => f.prioritizationFee).filter(f => f > 0).sort((a, b) => a - b);
+        return nonZero.length === 0 ? 0 : nonZero[Math.floor(nonZero.length / 2)];
+    } catch {
+        return 0;
+    }
+}
+
+function priorityLamports(microLamportsPerCu: number, computeUnits: number): number {
+    return Math.ceil((microLamportsPerCu * computeUnits) / 1_000_000);
+}
+
+/**
+ * Quote the all-in cost of bridging `amount` of an asset, before the burn
+ */
+export async function quoteFees(
+    solanaConnection: Connection,
+    x1Connection: Connection,
+    params: QuoteFeesParams
+): Promise<BridgeFeeQuote> {
+    const mintIdl = MINT_IDLS[params.assetId];
+    if (!mintIdl) {
+        throw new Error(`Unknown asset_id ${params.assetId}`);
+    }
+    const computeUnits = params.computeUnits || DEFAULT_COMPUTE_UNITS;
+
+    const idlPath = path.join(__dirname, '../../../target/idl', mintIdl.idl);
+    const idlData = JSON.parse(fs.readFileSync(idlPath, 'utf-8'));
+    const program = new anchor.Program(
+        idlData as anchor.Idl,
+        params.mintProgramId,
+        new anchor.AnchorProvider(x1Connection, {} as any, {})
+    );
+
+    const [mintState] = PublicKey.findProgramAddressSync(
+        [Buffer.from(mintIdl.statePda)],
+        params.mintProgramId
+    );
+    const [validatorSet] = PublicKey.findProgramAddressSync(
+        [Buffer.from('x1_validator_set_v2')],
+        params.lightClientProgramId
+    );
+
+    // Read-only instruction: simulated, never sent
+    const quote: any = await program.methods
+        .quoteFees(params.assetId, new anchor.BN(params.amount.toString()))
+        .accounts({ mintState, validatorSet, feeConfig: feeConfigAddress(params.mintProgramId) })
+        .view();
+
+    const [burnRecordRent, solanaPriority, x1Priority] = await Promise.all([
+        solanaConnection.getMinimumBalanceForRentExemption(BURN_RECORD_SPACE),
+        estimatePriorityFee(solanaConnection, []),
+        estimatePriorityFee(x1Connection, [mintState]),
+    ]);
+
+    const solanaPriorityFee = priorityLamports(solanaPriority, computeUnits.burn);
+    const x1PriorityFee = priorityLamports(x1Priority, computeUnits.attest)
+        + priorityLamports(x1Priority, computeUnits.mint);
+    // TX1 (attest) + TX2 (mint), one signature each
+    const x1BaseFee = 2 * LAMPORTS_PER_SIGNATURE;
+
+    const x1Program = BigInt(quote.total.toString());
+
+    return {
+        assetId: params.assetId,
+        amount: BigInt(quote.amount.toString()),
+        mintAmount: BigInt(quote.mintAmount.toString()),
+        solana: {
+            burnRecordRent,
+            baseFee: LAMPORTS_PER_SIGNATURE,
+            priorityFee: solanaPriorityFee,
+            total: burnRecordRent + LAMPORTS_PER_SIGNATURE + solanaPriorityFee,
+        },
+        x1: {
+            validatorCount: quote.validatorCount,
+            feePerValidator: BigInt(quote.feePerValidator.toString()),
+            validatorFeeTotal: BigInt(quote.validatorFeeTotal.toString()),
+            protocolFee: BigInt(quote.protocolFee.toString()),
+            verifiedBurnRent: BigInt(quote.verifiedBurnRent.toString()),
+            processedBurnRent: BigInt(quote.processedBurnRent.toString()),
+            baseFee: x1BaseFee,
+            priorityFee: x1PriorityFee,
+            total: x1Program + BigInt(x1BaseFee + x1PriorityFee),
+        },
+    };
+}
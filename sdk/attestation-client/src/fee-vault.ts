@@ -0,0 +1,211 @@
+import { AccountMeta, Connection, PublicKey, TransactionInstruction } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Validator fee vaults (xencat-mint-x1 / dgn-mint-x1)
+ *
+ * Validators that opted in with initialize_fee_vault collect mint fees in a
+ * FeeVault PDA ["fee_vault", validator] instead of their signing key, and
+ * claim them with claim_validator_fees. The claim only needs the vault's
+ * authority to sign, so it can be sent by a multisig or via CPI from the
+ * validator's own program (the Rust crates expose it with the `cpi` feature).
+ *
+ * A validator can set a commission (set_validator_commission) on its fee
+ * share for a delegator pool or infra provider. It is split off each fee as
+ * it accrues and claimed by the commission recipient with
+ * claim_validator_commission.
+ *
+ * Layout: discriminator(8) || validator(32) || authority(32) || balance(8) || total_collected(8)
+ *         || total_claimed(8) || commission_bps(2) || commission_recipient(32)
+ *         || commission_balance(8) || total_commission(8) || bump(1)
+ */
+export interface FeeVault {
+    validator: PublicKey;
+    authority: PublicKey;
+    balance: bigint;
+    totalCollected: bigint;
+    totalClaimed: bigint;
+    commissionBps: number;
+    commissionRecipient: PublicKey;
+    commissionBalance: bigint;
+    totalCommission: bigint;
+}
+
+export function feeVaultAddress(mintProgramId: PublicKey, validator: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('fee_vault'), validator.toBuffer()], mintProgramId)[0];
+}
+
+export function decodeFeeVault(data: Buffer): FeeVault {
+    return {
+        validator: new PublicKey(data.subarray(8, 40)),
+        authority: new PublicKey(data.subarray(40, 72)),
+        balance: data.readBigUInt64LE(72),
+        totalCollected: data.readBigUInt64LE(80),
+        totalClaimed: data.readBigUInt64LE(88),
+        commissionBps: data.readUInt16LE(96),
+        commissionRecipient: new PublicKey(data.subarray(98, 130)),
+        commissionBalance: data.readBigUInt64LE(130),
+        totalCommission: data.readBigUInt64LE(138),
+    };
+}
+
+/**
+ * A validator's fee vault (null if the validator has not opted in)
+ */
+export async function fetchFeeVault(connection: Connection, mintProgramId: PublicKey, validator: PublicKey): Promise<FeeVault | null> {
+    const info = await connection.getAccountInfo(feeVaultAddress(mintProgramId, validator));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeFeeVault(info.data);
+}
+
+/** Must match FeeVault::accrue: commission part of one fee payment */
+export function commissionOf(fee: bigint, commissionBps: number): bigint {
+    return fee * BigInt(commissionBps) / 10_000n;
+}
+
+/**
+ * Fee pool (xencat-mint-x1): once initialize_fee_pool has run, mints
+ * accrue every validator's fee into one PDA ["fee_pool"] and no validator
+ * fee accounts are passed. withdraw_fees (permissionless) pays a
+ * validator's balance to its vault, or its key without one.
+ *
+ * Layout: discriminator(8) || balances: vec<validator(32) || balance(8)>
+ *         || total_accrued(8) || total_withdrawn(8) || bump(1)
+ */
+export interface FeePool {
+    balances: Array<{ validator: PublicKey; balance: bigint }>;
+    totalAccrued: bigint;
+    totalWithdrawn: bigint;
+}
+
+export function feePoolAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('fee_pool')], mintProgramId)[0];
+}
+
+export function decodeFeePool(data: Buffer): FeePool {
+    const count = data.readUInt32LE(8);
+    const balances = [];
+    for (let i = 0; i < count; i++) {
+        const offset = 12 + 40 * i;
+        balances.push({
+            validator: new PublicKey(data.subarray(offset, offset + 32)),
+            balance: data.readBigUInt64LE(offset + 32),
+        });
+    }
+    const end = 12 + 40 * count;
+    return {
+        balances,
+        totalAccrued: data.readBigUInt64LE(end),
+        totalWithdrawn: data.readBigUInt64LE(end + 8),
+    };
+}
+
+/**
+ * The mint program's fee pool (null if fees are still paid directly)
+ */
+export async function fetchFeePool(connection: Connection, mintProgramId: PublicKey): Promise<FeePool | null> {
+    const info = await connection.getAccountInfo(feePoolAddress(mintProgramId));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeFeePool(info.data);
+}
+
+/**
+ * Fee accounts for a mint instruction's remaining_accounts, in validator set
+ * order: the validator's vault if it exists, otherwise the validator key.
+ * None once the program has a fee pool.
+ */
+export async function validatorFeeAccounts(
+    connection: Connection,
+    mintProgramId: PublicKey,
+    validators: PublicKey[]
+): Promise<AccountMeta[]> {
+    if (await fetchFeePool(connection, mintProgramId)) {
+        return [];
+    }
+    const vaults = validators.map(v => feeVaultAddress(mintProgramId, v));
+    const infos = await connection.getMultipleAccountsInfo(vaults);
+    return validators.map((validator, i) => ({
+        pubkey: infos[i]?.owner.equals(mintProgramId) ? vaults[i] : validator,
+        isWritable: true,
+        isSigner: false,
+    }));
+}
+
+/**
+ * claim_validator_fees instruction (`amount` in lamports, omitted = whole balance)
+ */
+export function createClaimValidatorFeesInstruction(params: {
+    mintProgramId: PublicKey;
+    validator: PublicKey;
+    authority: PublicKey;
+    destination: PublicKey;
+    amount?: bigint | number;
+}): TransactionInstruction {
+    const discriminator = crypto.createHash('sha256').update('global:claim_validator_fees').digest().subarray(0, 8);
+    // Borsh Option<u64>: 0 = None, 1 + u64 LE = Some
+    const amount = params.amount === undefined
+        ? Buffer.from([0])
+        : Buffer.concat([Buffer.from([1]), Buffer.from(new BigUint64Array([BigInt(params.amount)]).buffer)]);
+
+    return new TransactionInstruction({
+        programId: params.mintProgramId,
+        keys: [
+            { pubkey: feeVaultAddress(params.mintProgramId, params.validator), isSigner: false, isWritable: true },
+            { pubkey: params.authority, isSigner: true, isWritable: false },
+            { pubkey: params.destination, isSigner: false, isWritable: true },
+        ],
+        data: Buffer.concat([discriminator, amount]),
+    });
+}
+
+/**
+ * claim_validator_commission instruction (`amount` in lamports, omitted = whole commission balance)
+ */
+export function createClaimValidatorCommissionInstruction(params: {
+    mintProgramId: PublicKey;
+    validator: PublicKey;
+    commissionRecipient: PublicKey;
+    destination: PublicKey;
+    amount?: bigint | number;
+}): TransactionInstruction {
+    const discriminator = crypto.createHash('sha256').update('global:claim_validator_commission').digest().subarray(0, 8);
+    const amount = params.amount === undefined
+        ? Buffer.from([0])
+        : Buffer.concat([Buffer.from([1]), Buffer.from(new BigUint64Array([BigInt(params.amount)]).buffer)]);
+
+    return new TransactionInstruction({
+        programId: params.mintProgramId,
+        keys: [
+            { pubkey: feeVaultAddress(params.mintProgramId, params.validator), isSigner: false, isWritable: true },
+            { pubkey: params.commissionRecipient, isSigner: true, isWritable: false },
+            { pubkey: params.destination, isSigner: false, isWritable: true },
+        ],
+        data: Buffer.concat([discriminator, amount]),
+    });
+}
+
+/**
+ * withdraw_fees instruction: pays `validator`'s pooled fees to its vault
+ * if it has one, otherwise to the validator key (anyone can send it)
+ */
+export async function createWithdrawFeesInstruction(
+    connection: Connection,
+    mintProgramId: PublicKey,
+    validator: PublicKey,
+): Promise<TransactionInstruction> {
+    const discriminator = crypto.createHash('sha256').update('global:withdraw_fees').digest().subarray(0, 8);
+    const vault = await fetchFeeVault(connection, mintProgramId, validator);
+    return new TransactionInstruction({
+        programId: mintProgramId,
+        keys: [
+            { pubkey: feePoolAddress(mintProgramId), isSigner: false, isWritable: true },
+            { pubkey: validator, isSigner: false, isWritable: false },
+            { pubkey: vault ? feeVaultAddress(mintProgramId, validator) : validator, isSigner: false, isWritable: true },
+        ],
+        data: discriminator,
+    });
+}
@@ -2,6 +2,41 @@ import { Connection, PublicKey } from '@solana/web3.js';
 import * as anchor from '@coral-xyz/anchor';
 import * as fs from 'fs';
 import * as path from 'path';
+import { postSigned, newTraceContext, ValidatorEndpoint } from './transport';
+
+export * from './response-signing';
+export * from './transport';
+export * from './fee-quote';
+export * from './relayer-coordination';
+export * from './tx-summary';
+export * from './tx-size';
+export * from './fee-vault';
+export * from './work-queue';
+export * from './bridge-info';
+export * from './feature-gate';
+export * from './bridge-domain';
+export * from './deposit-webhook';
+export * from './event-schema';
+export * from './asset-metadata';
+export * from './cosign-policy';
+export * from './program-hashes';
+export * from './degraded-mode';
+export * from './x1-chain-id';
+export * from './program-errors';
+export * from './mint-ack';
+export * from './return-bridge';
+export * from './prevalidate';
+export * from './asset-registry';
+export * from './rate-limit';
+export * from './pause';
+export * from './usage-snapshot';
+export * from './relayer-claim';
+export * from './cost-accounting';
+export * from './burn-age-limit';
+export * from './fee-lookup-table';
+export * from './attestation-challenge';
+export * from './dev-mode';
+export * from './validator-set-timelock';
 
 export interface AttestationRequest {
     burn_nonce: number;
@@ -16,13 +51,41 @@ export interface Attestation {
     slot: number;
     validator_pubkey: string;
     signature: number[];
+    /** Signed alongside the burn: Solana slot and block time (unix seconds) of the burn */
+    solana_burn_slot: number;
+    solana_burn_timestamp: number;
+    /** Signed: depth from the BurnRecord (0 = default) and the slot validators waited for */
+    confirmation_depth: number;
+    solana_confirmed_slot: number;
+    /** When this validator signed (unix seconds) */
     timestamp: number;
+    /** On-chain challenge signed with the burn (hex), when the user posted one */
+    challenge?: string | null;
+    /** Insurance coverage attested with the burn (burn_xencat_insured only) */
+    insurance?: {
+        covered_amount: number;
+        premium: number;
+        signature: number[];
+    } | null;
+    /** Relayer claim terms attested with the burn (burn_xencat_for_recipient only) */
+    relayer_claim?: {
+        relayer_fee: number;
+        signature: number[];
+    } | null;
 }
 
+/**
+ * Collect attestations from the current X1 validator set.
+ *
+ * Every response must be signed by the validator key it claims to come from
+ * (see response-signing.ts); unsigned or tampered responses are discarded.
+ * `endpoints` maps validator pubkeys to their API URL and optional TLS pin.
+ */
 export async function collectAttestations(
     x1Connection: Connection,
     lightClientProgramId: PublicKey,
-    request: AttestationRequest
+    request: AttestationRequest,
+    endpoints: ValidatorEndpoint[] = []
 ): Promise<Attestation[]> {
     console.log('\n🔍 Collecting attestations from X1 validators...\n');
 
@@ -36,7 +99,7 @@ export async function collectAttestations(
     );
 
     const [validatorSetPda] = PublicKey.findProgramAddressSync(
-        [Buffer.from('x1_validator_set')],
+        [Buffer.from('x1_validator_set_v2')],
         lightClientProgramId
     );
 
@@ -45,36 +108,51 @@ export async function collectAttestations(
     console.log(`📊 Found ${validatorSet.validators.length} validators`);
     console.log(`   Threshold: ${validatorSet.threshold}\n`);
 
+    // One trace per transfer, shared by every validator request
+    const trace = newTraceContext(request.burn_nonce);
+    console.log(`🧵 Transfer ${trace.transferId} (traceparent ${trace.traceparent})\n`);
+
     // Request attestations from all validators
     const attestationPromises = validatorSet.validators.map(async (validator: any) => {
-        if (!validator.active) {
+        if (validator.active === false) {
             console.log(`⏭️  Skipping inactive validator: ${validator.name}`);
             return null;
         }
 
+        // V2 set stores bare pubkeys; legacy entries carry pubkey + attestationApi
+        const pubkey: PublicKey = validator instanceof PublicKey ? validator : validator.pubkey;
+        const configured = endpoints.find(e => e.pubkey.equals(pubkey));
+        const endpoint: ValidatorEndpoint | undefined = configured
+            || (validator.attestationApi ? { pubkey, url: validator.attestationApi } : undefined);
+
+        if (!endpoint) {
+            console.log(`⏭️  No endpoint configured for validator: ${pubkey.toBase58()}`);
+            return null;
+        }
+
         try {
-            console.log(`📡 Requesting attestation from: ${validator.name}`);
-            console.log(`   API: ${validator.attestationApi}`);
-
-            const response = await fetch(validator.attestationApi + '/attest-burn', {
-                method: 'POST',
-                headers: { 'Content-Type': 'application/json' },
-                body: JSON.stringify(request),
-            });
-
-            if (!response.ok) {
-                const error = await response.text();
-                console.log(`❌ ${validator.name} failed: ${error}`);
+            console.log(`📡 Requesting attestation from: ${pubkey.toBase58()}`);
+            console.log(`   API: ${endpoint.url}${endpoint.certSha256 ? ' (TLS pinned)' : ''}`);
+
+            const response = await postSigned(endpoint, '/attest-burn', request, trace);
+
+            if (response.status < 200 || response.status >= 300) {
+                console.log(`❌ ${pubkey.toBase58()} failed: ${JSON.stringify(response.body)}`);
+                return null;
+            }
+
+            const attestation = response.body as Attestation;
+            if (attestation.validator_pubkey !== pubkey.toBase58()) {
+                console.log(`❌ ${pubkey.toBase58()} returned attestation for another validator`);
                 return null;
             }
 
-            const attestation = await response.json();
-            console.log(`✅ ${validator.name} signed attestation`);
+            console.log(`✅ ${pubkey.toBase58()} signed attestation (response signature verified)`);
 
             return attestation;
 
         } catch (error: any) {
-            console.log(`❌ Failed to reach ${validator.name}: ${error.message}`);
+            console.log(`❌ Failed to reach ${pubkey.toBase58()}: ${error.message}`);
             return null;
         }
     });
@@ -96,3 +174,101 @@ export async function collectAttestations(
 
     return validAttestations;
 }
+
+export interface BatchAttestationResult {
+    burn_nonce: number;
+    /** Attestations from validators that signed this burn */
+    attestations: Attestation[];
+    /** Per-validator failures (pubkey → error) for diagnostics */
+    errors: Record<string, string>;
+}
+
+/** Must not exceed the validator service's limits.max_batch_size */
+export const DEFAULT_BATCH_SIZE = 25;
+
+/**
+ * Collect attestations for many burns with one request per validator
+ * (per chunk of `batchSize` burns) via POST /attest-burns.
+ *
+ * Results are returned in request order. A burn counts as ready once it
+ * has `threshold` attestations; the caller decides what to do with the rest.
+ */
+export async function collectBatchAttestations(
+    x1Connection: Connection,
+    lightClientProgramId: PublicKey,
+    requests: AttestationRequest[],
+    endpoints: ValidatorEndpoint[],
+    batchSize: number = DEFAULT_BATCH_SIZE
+): Promise<BatchAttestationResult[]> {
+    const program = new anchor.Program(
+        JSON.parse(fs.readFileSync(path.join(__dirname, '../../../target/idl/solana_light_client_x1.json'), 'utf-8')) as anchor.Idl,
+        lightClientProgramId,
+        new anchor.AnchorProvider(x1Connection, {} as any, {})
+    );
+    const [validatorSetPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('x1_validator_set_v2')],
+        lightClientProgramId
+    );
+    const validatorSet: any = await program.account.x1ValidatorSet.fetch(validatorSetPda);
+    const version = validatorSet.version.toNumber();
+
+    const results: BatchAttestationResult[] = requests.map(r => ({
+        burn_nonce: r.burn_nonce,
+        attestations: [],
+        errors: {},
+    }));
+    const byNonce = new Map(results.map(r => [r.burn_nonce, r]));
+
+    const members = new Set(validatorSet.validators.map((v: PublicKey) => v.toBase58()));
+    const active = endpoints.filter(e => members.has(e.pubkey.toBase58()));
+
+    console.log(`\n📦 Collecting batch attestations: ${requests.length} burns, ${active.length} validators`);
+
+    for (let i = 0; i < requests.length; i += batchSize) {
+        const chunk = requests.slice(i, i + batchSize).map(r => ({ ...r, validator_set_version: version }));
+
+        await Promise.all(active.map(async (endpoint) => {
+            const validator = endpoint.pubkey.toBase58();
+            try {
+                const response = await postSigned(endpoint, '/attest-burns', { burns: chunk });
+                if (response.status !== 200) {
+                    chunk.forEach(r => { byNonce.get(r.burn_nonce)!.errors[validator] = JSON.stringify(response.body); });
+                    return;
+                }
+                for (const entry of response.body.results || []) {
+                    const result = byNonce.get(entry.burn_nonce);
+                    if (!result) {
+                        continue; // Not something we asked for
+                    }
+                    if (entry.status === 200 && entry.attestation?.validator_pubkey === validator) {
+                        result.attestations.push(entry.attestation as Attestation);
+                    } else {
+                        result.errors[validator] = entry.error || `status ${entry.status}`;
+                    }
+                }
+            } catch (error: any) {
+                chunk.forEach(r => { byNonce.get(r.burn_nonce)!.errors[validator] = error.message; });
+            }
+        }));
+    }
+
+    const ready = results.filter(r => r.attestations.length >= validatorSet.threshold).length;
+    console.log(`✅ ${ready}/${requests.length} burns reached threshold (${validatorSet.threshold})\n`);
+
+    return results;
+}
+export * from './compute-report';
+export * from './validator-set-history';
+export * from './offline-signing';
+export * from './asset-mint';
+export * from './telemetry';
+export * from './fee-config';
+export * from './cpi-allowlist';
+export * from './notice';
+export * from './burn-assets';
+export * from './beta-allowlist';
+export * from './burn-limits';
+export * from './burn-record-retention';
+export * from './attestation-ordering';
+export * from './treasury-statement';
+export * from './wormhole-vaa';
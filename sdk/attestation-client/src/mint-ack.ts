@@ -0,0 +1,119 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Mint acknowledgements (MintAcknowledged events of the X1 mint programs)
+ *
+ * Every V3 mint path emits a canonical record of the completed transfer:
+ * transfer id, asset, burn nonce, amount, credited account and X1 slot,
+ * plus its hash. Validators of the reverse direction attest to that hash
+ * the way X1 validators attest to a Solana burn, so both directions share
+ * one evidence format.
+ *
+ * Layout (Anchor event): discriminator(8) || transfer_id(32) || asset_id(1) || burn_nonce(8)
+ *                        || amount(8) || recipient(32) || slot(8) || ack_hash(32)
+ */
+export interface MintAcknowledgement {
+    transferId: Buffer;
+    assetId: number;
+    burnNonce: bigint;
+    amount: bigint;
+    /** Token account credited (the owner for compressed output) */
+    recipient: PublicKey;
+    /** X1 slot of the mint */
+    slot: bigint;
+    ackHash: Buffer;
+}
+
+/** Must match MINT_ACK_DOMAIN in the light client */
+export const MINT_ACK_DOMAIN = 'XENCAT_X1_MINT_ACK_V1';
+/** Must match TRANSFER_SOURCE_SOLANA in the light client */
+export const TRANSFER_SOURCE_SOLANA = 'solana';
+
+const MINT_ACK_EVENT_DISCRIMINATOR = crypto.createHash('sha256').update('event:MintAcknowledged').digest().subarray(0, 8);
+const MINT_ACK_EVENT_SIZE = 8 + 32 + 1 + 8 + 8 + 32 + 8 + 32;
+
+function u64(value: bigint | number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+/** Must match state::transfer_id: sha256(source || asset_id || nonce) */
+export function transferIdBytes(assetId: number, nonce: bigint | number, source: string = TRANSFER_SOURCE_SOLANA): Buffer {
+    return crypto.createHash('sha256')
+        .update(Buffer.concat([Buffer.from(source), Buffer.from([assetId]), u64(nonce)]))
+        .digest();
+}
+
+/** Must match MintAcknowledgement::hash in the light client */
+export function mintAckHash(ack: Omit<MintAcknowledgement, 'ackHash'>): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(MINT_ACK_DOMAIN),
+        ack.transferId,
+        Buffer.from([ack.assetId]),
+        u64(ack.burnNonce),
+        u64(ack.amount),
+        ack.recipient.toBuffer(),
+        u64(ack.slot),
+    ])).digest();
+}
+
+/**
+ * MintAcknowledged events emitted by `mintProgramId` in a transaction's logs
+ *
+ * Only events logged while `mintProgramId` itself was executing count (a
+ * program it does not call cannot forge one), and only if the transfer id
+ * and hash recompute from the fields.
+ */
+export function decodeMintAcknowledgements(logs: string[], mintProgramId: PublicKey): MintAcknowledgement[] {
+    const program = mintProgramId.toBase58();
+    const stack: string[] = [];
+    const acks: MintAcknowledgement[] = [];
+    for (const line of logs) {
+        const invoke = /^Program (\w+) invoke \[\d+\]$/.exec(line);
+        if (invoke) {
+            stack.push(invoke[1]);
+            continue;
+        }
+        if (/^Program \w+ (success|failed)/.test(line)) {
+            stack.pop();
+            continue;
+        }
+        if (!line.startsWith('Program data: ') || stack[stack.length - 1] !== program) {
+            continue;
+        }
+        const data = Buffer.from(line.slice('Program data: '.length), 'base64');
+        if (data.length !== MINT_ACK_EVENT_SIZE || !data.subarray(0, 8).equals(MINT_ACK_EVENT_DISCRIMINATOR)) {
+            continue;
+        }
+        const ack: MintAcknowledgement = {
+            transferId: data.subarray(8, 40),
+            assetId: data[40],
+            burnNonce: data.readBigUInt64LE(41),
+            amount: data.readBigUInt64LE(49),
+            recipient: new PublicKey(data.subarray(57, 89)),
+            slot: data.readBigUInt64LE(89),
+            ackHash: data.subarray(97, 129),
+        };
+        if (ack.transferId.equals(transferIdBytes(ack.assetId, ack.burnNonce)) && ack.ackHash.equals(mintAckHash(ack))) {
+            acks.push(ack);
+        }
+    }
+    return acks;
+}
+
+/**
+ * The acknowledgement of a mint transaction (finalized), or null if it has none
+ */
+export async function fetchMintAcknowledgement(
+    connection: Connection,
+    signature: string,
+    mintProgramId: PublicKey
+): Promise<MintAcknowledgement | null> {
+    const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'finalized' });
+    if (!tx || tx.meta?.err) {
+        return null;
+    }
+    return decodeMintAcknowledgements(tx.meta?.logMessages || [], mintProgramId)[0] ?? null;
+}
@@ -0,0 +1,104 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Notice board (planned maintenance and upgrades)
+ *
+ * Validator governance posts a notice ahead of validator rotations, pauses
+ * or upgrade windows, so wallets can warn before a user starts a burn that
+ * may not be mintable for a while. Only sha256 of the text is on-chain;
+ * the text is published off-chain and shown only if its hash matches.
+ * Severity 0 means the board is empty.
+ *
+ * Layout: discriminator(8) || message_hash(32) || effective_slot(u64)
+ *   || severity(u8) || sequence(u64) || posted_slot(u64) || bump(1)
+ */
+
+/** Must match NOTICE_* in the light client */
+export const NOTICE_SEVERITY = { none: 0, info: 1, warning: 2, critical: 3 } as const;
+export type NoticeSeverity = keyof typeof NOTICE_SEVERITY;
+
+export interface Notice {
+    messageHash: Buffer;
+    effectiveSlot: number;
+    severity: number;
+    /** Increments on every post */
+    sequence: number;
+    postedSlot: number;
+}
+
+export function noticeAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('notice')], lightClientProgramId)[0];
+}
+
+export function decodeNotice(data: Buffer): Notice {
+    return {
+        messageHash: Buffer.from(data.subarray(8, 40)),
+        effectiveSlot: Number(data.readBigUInt64LE(40)),
+        severity: data[48],
+        sequence: Number(data.readBigUInt64LE(49)),
+        postedSlot: Number(data.readBigUInt64LE(57)),
+    };
+}
+
+/**
+ * The light client's current notice (null = board not created or empty)
+ */
+export async function fetchNotice(connection: Connection, lightClientProgramId: PublicKey): Promise<Notice | null> {
+    const info = await connection.getAccountInfo(noticeAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    const notice = decodeNotice(info.data);
+    return notice.severity === NOTICE_SEVERITY.none ? null : notice;
+}
+
+export function noticeHash(text: string): Buffer {
+    return crypto.createHash('sha256').update(text, 'utf-8').digest();
+}
+
+/** Whether `text` is the notice's published text */
+export function noticeMatches(notice: Notice, text: string): boolean {
+    return noticeHash(text).equals(notice.messageHash);
+}
+
+export function noticeSeverityName(severity: number): NoticeSeverity | 'unknown' {
+    const entry = Object.entries(NOTICE_SEVERITY).find(([, value]) => value === severity);
+    return entry ? entry[0] as NoticeSeverity : 'unknown';
+}
+
+/**
+ * Warning to show before a burn (null = nothing worth interrupting for)
+ *
+ * Info notices are left to the wallet's own UI; warning and critical ones
+ * apply from their effective slot until governance clears the board.
+ */
+export function noticeWarning(notice: Notice | null, currentSlot: number): string | null {
+    if (!notice || notice.severity < NOTICE_SEVERITY.warning) {
+        return null;
+    }
+    const when = currentSlot >= notice.effectiveSlot
+        ? 'in effect now'
+        : `from X1 slot ${notice.effectiveSlot} (~${Math.ceil((notice.effectiveSlot - currentSlot) * 0.4 / 60)} min)`;
+    return `Bridge ${noticeSeverityName(notice.severity)} notice #${notice.sequence} ${when}: `
+        + `burns may not be mintable until it is over (notice ${notice.messageHash.toString('hex').slice(0, 16)}...)`;
+}
+
+/** Must match create_notice_message in the light client */
+export function noticeMessage(
+    validatorSetVersion: bigint | number,
+    configNonce: bigint | number,
+    messageHash: Buffer,
+    effectiveSlot: bigint | number,
+    severity: number,
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    const payload = Buffer.alloc(49);
+    payload.writeBigUInt64LE(BigInt(configNonce), 0);
+    messageHash.copy(payload, 8);
+    payload.writeBigUInt64LE(BigInt(effectiveSlot), 40);
+    payload[48] = severity;
+    const version = Buffer.alloc(8);
+    version.writeBigUInt64LE(BigInt(validatorSetVersion));
+    return crypto.createHash('sha256').update(Buffer.concat([Buffer.from('SET_NOTICE'), version, payload, ...(x1GenesisHash ? [x1GenesisHash] : [])])).digest();
+}
@@ -0,0 +1,179 @@
+import { Keypair, Message, PublicKey, Transaction } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import nacl from 'tweetnacl';
+import bs58 from 'bs58';
+
+/**
+ * Offline signing payloads (air-gapped validator keys)
+ *
+ * A payload file is one line of base64 over this JSON:
+ *   { format: "xencat-offline-v1",
+ *     kind: "message" | "transaction",
+ *     description,            human-readable summary shown before signing
+ *     context?,               e.g. the governance proposal the message was built from
+ *     message,                base64: governance message, or serialized transaction message
+ *     signatures: [{ signer: base58, signature: base64 }] }
+ *
+ * The online operator exports a payload, carries the file to each
+ * air-gapped machine, which adds its signature, and merges the returned
+ * files before submitting. The key never touches a networked machine.
+ *
+ * Signers should compare payloadFingerprint() with the one the operator
+ * printed at export time: the air-gapped side cannot rebuild the message
+ * from chain state, so the fingerprint is what ties the description to
+ * the bytes being signed. Signatures are still verified again on chain.
+ *
+ * Transactions built for offline signing must use a durable nonce: a recent
+ * blockhash expires (~1 minute) long before the payload comes back.
+ */
+export const OFFLINE_PAYLOAD_FORMAT = 'xencat-offline-v1';
+
+export type OfflinePayloadKind = 'message' | 'transaction';
+
+export interface OfflineSignature {
+    signer: string;
+    signature: string;
+}
+
+export interface OfflinePayload {
+    format: typeof OFFLINE_PAYLOAD_FORMAT;
+    kind: OfflinePayloadKind;
+    description: string;
+    context?: unknown;
+    message: string;
+    signatures: OfflineSignature[];
+}
+
+/** Payload for a governance message (create_governance_message bytes) */
+export function messagePayload(message: Buffer, description: string, context?: unknown): OfflinePayload {
+    return {
+        format: OFFLINE_PAYLOAD_FORMAT,
+        kind: 'message',
+        description,
+        context,
+        message: message.toString('base64'),
+        signatures: [],
+    };
+}
+
+/** Payload for an unsigned transaction (fee payer and durable nonce already set) */
+export function transactionPayload(tx: Transaction, description: string): OfflinePayload {
+    return {
+        format: OFFLINE_PAYLOAD_FORMAT,
+        kind: 'transaction',
+        description,
+        message: tx.serializeMessage().toString('base64'),
+        signatures: [],
+    };
+}
+
+export function encodeOfflinePayload(payload: OfflinePayload): string {
+    return Buffer.from(JSON.stringify(payload)).toString('base64') + '\n';
+}
+
+export function decodeOfflinePayload(text: string): OfflinePayload {
+    let payload: OfflinePayload;
+    try {
+        payload = JSON.parse(Buffer.from(text.trim(), 'base64').toString('utf-8'));
+    } catch {
+        throw new Error('Not an offline signing payload (expected one line of base64)');
+    }
+    if (payload.format !== OFFLINE_PAYLOAD_FORMAT) {
+        throw new Error(`Unsupported payload format: ${payload.format} (expected ${OFFLINE_PAYLOAD_FORMAT})`);
+    }
+    if (payload.kind !== 'message' && payload.kind !== 'transaction') {
+        throw new Error(`Unknown payload kind: ${payload.kind}`);
+    }
+    return { ...payload, signatures: payload.signatures ?? [] };
+}
+
+export function payloadMessage(payload: OfflinePayload): Buffer {
+    return Buffer.from(payload.message, 'base64');
+}
+
+/** sha256 of the signed bytes, grouped for reading aloud: "3f2a 91c0 ..." (first 16 bytes) */
+export function payloadFingerprint(payload: OfflinePayload): string {
+    const digest = crypto.createHash('sha256').update(payloadMessage(payload)).digest('hex');
+    return digest.slice(0, 32).match(/.{4}/g)!.join(' ');
+}
+
+/** Keys that must sign a transaction payload (none for messages) */
+export function requiredSigners(payload: OfflinePayload): PublicKey[] {
+    if (payload.kind !== 'transaction') {
+        return [];
+    }
+    const message = Message.from(payloadMessage(payload));
+    return message.accountKeys.slice(0, message.header.numRequiredSignatures);
+}
+
+/** Add (or replace) this key's signature */
+export function signOfflinePayload(payload: OfflinePayload, keypair: Keypair): OfflinePayload {
+    if (payload.kind === 'transaction' && !requiredSigners(payload).some(k => k.equals(keypair.publicKey))) {
+        throw new Error(`${keypair.publicKey.toBase58()} is not a signer of this transaction`);
+    }
+    const signature = nacl.sign.detached(payloadMessage(payload), keypair.secretKey);
+    const signer = keypair.publicKey.toBase58();
+    return {
+        ...payload,
+        signatures: [
+            ...payload.signatures.filter(s => s.signer !== signer),
+            { signer, signature: Buffer.from(signature).toString('base64') },
+        ],
+    };
+}
+
+/** Signatures that verify over the payload's message */
+export function verifiedSignatures(payload: OfflinePayload): OfflineSignature[] {
+    const message = payloadMessage(payload);
+    return payload.signatures.filter(s => {
+        try {
+            return nacl.sign.detached.verify(message, Buffer.from(s.signature, 'base64'), new PublicKey(s.signer).toBytes());
+        } catch {
+            return false;
+        }
+    });
+}
+
+/**
+ * Combine payloads returned by different signers
+ *
+ * All must carry the same message; invalid signatures are dropped.
+ */
+export function mergeOfflinePayloads(payloads: OfflinePayload[]): OfflinePayload {
+    if (payloads.length === 0) {
+        throw new Error('No payloads to merge');
+    }
+    const [first] = payloads;
+    const bySigner = new Map<string, OfflineSignature>();
+    for (const payload of payloads) {
+        if (payload.kind !== first.kind || payload.message !== first.message) {
+            throw new Error(`Payloads sign different messages (${payloadFingerprint(first)} vs ${payloadFingerprint(payload)})`);
+        }
+        verifiedSignatures(payload).forEach(s => bySigner.set(s.signer, s));
+    }
+    return { ...first, signatures: [...bySigner.values()] };
+}
+
+/** Approvals in the format of the governance scripts' proposal files */
+export function payloadApprovals(payload: OfflinePayload): Array<{ validator_pubkey: string; signature: number[] }> {
+    return verifiedSignatures(payload).map(s => ({
+        validator_pubkey: s.signer,
+        signature: Array.from(Buffer.from(s.signature, 'base64')),
+    }));
+}
+
+/** Signed transaction, ready for sendRawTransaction; throws if a signer is missing */
+export function assembleTransaction(payload: OfflinePayload): Transaction {
+    if (payload.kind !== 'transaction') {
+        throw new Error('Payload is a message, not a transaction');
+    }
+    const signatures = new Map(verifiedSignatures(payload).map(s => [s.signer, Buffer.from(s.signature, 'base64')]));
+    const ordered = requiredSigners(payload).map(signer => {
+        const signature = signatures.get(signer.toBase58());
+        if (!signature) {
+            throw new Error(`Missing signature from ${signer.toBase58()}`);
+        }
+        return bs58.encode(signature);
+    });
+    return Transaction.populate(Message.from(payloadMessage(payload)), ordered);
+}
@@ -0,0 +1,71 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Emergency pause (validator governance)
+ *
+ * Two levels, both flipped only with threshold validator approval:
+ * - Bridge: the light client's pause_bridge sets the top bit of the
+ *   FeatureGate, and every gated path (attestation submits, mints on both
+ *   mint programs, conversions, returns) fails with BridgePaused.
+ * - Mint program: its own pause_bridge stops that asset's mint paths only
+ *   (MintPaused), via the program's MintPause account.
+ *
+ * MintPause layout: discriminator(8) || paused(1) || updated_slot(8) || nonce(8) || bump(1)
+ */
+
+export interface MintPause {
+    paused: boolean;
+    updatedSlot: number;
+    nonce: bigint;
+}
+
+export function mintPauseAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('mint_pause')], mintProgramId)[0];
+}
+
+export function decodeMintPause(data: Buffer): MintPause {
+    return {
+        paused: data[8] !== 0,
+        updatedSlot: Number(data.readBigUInt64LE(9)),
+        nonce: data.readBigUInt64LE(17),
+    };
+}
+
+/**
+ * The mint program's pause switch (null = not created, not paused)
+ */
+export async function fetchMintPause(connection: Connection, mintProgramId: PublicKey): Promise<MintPause | null> {
+    const info = await connection.getAccountInfo(mintPauseAddress(mintProgramId));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeMintPause(info.data);
+}
+
+function u64(value: bigint | number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+/** Must match create_pause_bridge_message / create_unpause_bridge_message in the light client */
+export function bridgePauseMessage(paused: boolean, validatorSetVersion: bigint | number, configNonce: bigint | number, x1GenesisHash: Buffer | null): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(paused ? 'PAUSE_BRIDGE' : 'UNPAUSE_BRIDGE'),
+        u64(validatorSetVersion),
+        u64(configNonce),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
+
+/** Must match create_mint_pause_message in the mint programs */
+export function mintPauseMessage(mintProgramId: PublicKey, paused: boolean, validatorSetVersion: bigint | number, nonce: bigint | number, x1GenesisHash: Buffer | null): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(paused ? 'PAUSE_MINT' : 'UNPAUSE_MINT'),
+        u64(validatorSetVersion),
+        u64(nonce),
+        mintProgramId.toBuffer(),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
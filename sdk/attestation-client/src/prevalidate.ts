@@ -0,0 +1,260 @@
+import { Connection, PublicKey, TransactionInstruction, TransactionMessage, VersionedTransaction } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Attestation bundle pre-check (light client prevalidate_attestation)
+ *
+ * Simulates the read-only prevalidate_attestation with the bundle a relayer
+ * is about to submit. It runs submit_burn_attestation_v3's cheap checks
+ * (gate, asset, validator set version, confirmation depth, already
+ * verified, duplicates, ordering, membership, attestation age, signature
+ * encodings, threshold) and
+ * reports a code for each, without creating the VerifiedBurnV3. A doomed
+ * bundle is rejected before any fee or rent is paid.
+ *
+ * Result layout (return data): code(1) || attestation_codes(4 + n) ||
+ *   valid_count(1) || threshold(1) || validator_set_version(8)
+ */
+
+/** Must match AttestationCheckCode in the light client (Borsh variant index) */
+export enum AttestationCheckCode {
+    Valid = 0,
+    FeatureDisabled = 1,
+    ParameterMismatch = 2,
+    UnknownAsset = 3,
+    VersionMismatch = 4,
+    AlreadyVerified = 5,
+    DuplicateValidator = 6,
+    UnknownValidator = 7,
+    BadSignatureEncoding = 8,
+    BelowThreshold = 9,
+    ConfirmationDepth = 10,
+    AttestationAge = 11,
+    OutOfOrder = 12,
+}
+
+export interface AttestationCheck {
+    /** First failing check, or Valid (codes this SDK does not know are rejections) */
+    code: AttestationCheckCode;
+    /** One code per attestation, in bundle order */
+    attestationCodes: AttestationCheckCode[];
+    validCount: number;
+    threshold: number;
+    validatorSetVersion: number;
+}
+
+export interface AttestationBundle {
+    assetId: number;
+    burnNonce: number;
+    user: PublicKey;
+    amount: number;
+    validatorSetVersion: number;
+    solanaBurnSlot: number;
+    solanaBurnTimestamp: number;
+    confirmationDepth: number;
+    solanaConfirmedSlot: number;
+    attestations: Array<{ validatorPubkey: PublicKey; signature: number[] | Uint8Array; timestamp: number }>;
+}
+
+function u16(value: number): Buffer {
+    const buf = Buffer.alloc(2);
+    buf.writeUInt16LE(value);
+    return buf;
+}
+
+function u64(value: number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+function i64(value: number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigInt64LE(BigInt(value));
+    return buf;
+}
+
+/** Borsh encoding of BurnAttestationDataV3 */
+export function encodeAttestationBundle(bundle: AttestationBundle): Buffer {
+    const count = Buffer.alloc(4);
+    count.writeUInt32LE(bundle.attestations.length);
+    return Buffer.concat([
+        Buffer.from([bundle.assetId]),
+        u64(bundle.burnNonce),
+        bundle.user.toBuffer(),
+        u64(bundle.amount),
+        u64(bundle.validatorSetVersion),
+        u64(bundle.solanaBurnSlot),
+        i64(bundle.solanaBurnTimestamp),
+        u16(bundle.confirmationDepth),
+        u64(bundle.solanaConfirmedSlot),
+        count,
+        ...bundle.attestations.map(a => Buffer.concat([
+            a.validatorPubkey.toBuffer(),
+            Buffer.from(a.signature),
+            i64(a.timestamp),
+        ])),
+    ]);
+}
+
+export function decodeAttestationCheck(data: Buffer): AttestationCheck {
+    const count = data.readUInt32LE(1);
+    const end = 5 + count;
+    return {
+        code: data[0],
+        attestationCodes: Array.from(data.subarray(5, end)),
+        validCount: data[end],
+        threshold: data[end + 1],
+        validatorSetVersion: Number(data.readBigUInt64LE(end + 2)),
+    };
+}
+
+/** One line per failing check, e.g. for a relayer's log */
+export function describeAttestationCheck(check: AttestationCheck): string {
+    const name = (code: number) => AttestationCheckCode[code] ?? `code ${code}`;
+    const failures = check.attestationCodes
+        .map((code, index) => code === AttestationCheckCode.Valid ? null : `#${index}: ${name(code)}`)
+        .filter(Boolean);
+    return [
+        `${name(check.code)} (${check.validCount}/${check.threshold} valid, validator set v${check.validatorSetVersion})`,
+        ...failures,
+    ].join('\n');
+}
+
+/**
+ * Light client AttestationRejected event
+ *
+ * Emitted right before a submit (V3, for-recipient or degraded) rejects a
+ * bundle. The transaction fails but keeps its logs, so a relayer reads the
+ * reason from the failed transaction.
+ *
+ * Layout (Anchor event): discriminator(8) || asset_id(1) || burn_nonce(8) || user(32)
+ *   || reason(1) || validator(32) || expected_version(8) || got_version(8)
+ *   || valid_count(1) || threshold(1)
+ */
+export interface AttestationRejection {
+    assetId: number;
+    burnNonce: bigint;
+    user: PublicKey;
+    /** Same codes as the pre-check (codes this SDK does not know are still rejections) */
+    reason: AttestationCheckCode;
+    /** Validator whose attestation failed (null for bundle-level reasons) */
+    validator: PublicKey | null;
+    /** Current validator set version */
+    expectedVersion: bigint;
+    /** Version the bundle was signed for */
+    gotVersion: bigint;
+    validCount: number;
+    threshold: number;
+}
+
+const ATTESTATION_REJECTED_DISCRIMINATOR = crypto.createHash('sha256').update('event:AttestationRejected').digest().subarray(0, 8);
+const ATTESTATION_REJECTED_SIZE = 8 + 1 + 8 + 32 + 1 + 32 + 8 + 8 + 1 + 1;
+
+/** AttestationRejected events logged by the light client in a transaction's logs */
+export function decodeAttestationRejections(logs: string[], lightClientProgramId: PublicKey): AttestationRejection[] {
+    const program = lightClientProgramId.toBase58();
+    const stack: string[] = [];
+    const rejections: AttestationRejection[] = [];
+    for (const line of logs) {
+        const invoke = /^Program (\w+) invoke \[\d+\]$/.exec(line);
+        if (invoke) {
+            stack.push(invoke[1]);
+            continue;
+        }
+        if (/^Program \w+ (success|failed)/.test(line)) {
+            stack.pop();
+            continue;
+        }
+        if (!line.startsWith('Program data: ') || stack[stack.length - 1] !== program) {
+            continue;
+        }
+        const data = Buffer.from(line.slice('Program data: '.length), 'base64');
+        if (data.length !== ATTESTATION_REJECTED_SIZE || !data.subarray(0, 8).equals(ATTESTATION_REJECTED_DISCRIMINATOR)) {
+            continue;
+        }
+        const validator = new PublicKey(data.subarray(50, 82));
+        rejections.push({
+            assetId: data[8],
+            burnNonce: data.readBigUInt64LE(9),
+            user: new PublicKey(data.subarray(17, 49)),
+            reason: data[49],
+            validator: validator.equals(PublicKey.default) ? null : validator,
+            expectedVersion: data.readBigUInt64LE(82),
+            gotVersion: data.readBigUInt64LE(90),
+            validCount: data[98],
+            threshold: data[99],
+        });
+    }
+    return rejections;
+}
+
+/**
+ * Why a failed submit transaction was rejected (null if it logged no rejection)
+ */
+export async function fetchAttestationRejection(
+    connection: Connection,
+    signature: string,
+    lightClientProgramId: PublicKey
+): Promise<AttestationRejection | null> {
+    const tx = await connection.getTransaction(signature, { maxSupportedTransactionVersion: 0, commitment: 'confirmed' });
+    return decodeAttestationRejections(tx?.meta?.logMessages || [], lightClientProgramId)[0] ?? null;
+}
+
+/** One line for a relayer's log or dashboard */
+export function describeAttestationRejection(rejection: AttestationRejection): string {
+    const reason = AttestationCheckCode[rejection.reason] ?? `code ${rejection.reason}`;
+    const details = [
+        `asset ${rejection.assetId} burn ${rejection.burnNonce}`,
+        rejection.validator ? `validator ${rejection.validator.toBase58()}` : null,
+        rejection.reason === AttestationCheckCode.VersionMismatch
+            ? `signed for v${rejection.gotVersion}, current v${rejection.expectedVersion}` : null,
+        `${rejection.validCount}/${rejection.threshold} valid`,
+    ].filter(Boolean);
+    return `${reason} (${details.join(', ')})`;
+}
+
+/**
+ * Pre-check a V3 bundle with a simulated prevalidate_attestation (nothing is sent).
+ * `payer` only has to exist; it does not sign.
+ */
+export async function simulatePrevalidateAttestation(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    payer: PublicKey,
+    bundle: AttestationBundle
+): Promise<AttestationCheck> {
+    const pda = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, lightClientProgramId)[0];
+    const instruction = new TransactionInstruction({
+        programId: lightClientProgramId,
+        keys: [
+            { pubkey: pda(Buffer.from('x1_validator_set_v2')), isSigner: false, isWritable: false },
+            {
+                pubkey: pda(Buffer.from('verified_burn_v3'), Buffer.from([bundle.assetId]), bundle.user.toBuffer(), u64(bundle.burnNonce)),
+                isSigner: false,
+                isWritable: false,
+            },
+            { pubkey: pda(Buffer.from('feature_gate')), isSigner: false, isWritable: false },
+            { pubkey: pda(Buffer.from('asset_registry')), isSigner: false, isWritable: false },
+        ],
+        data: Buffer.concat([
+            crypto.createHash('sha256').update('global:prevalidate_attestation').digest().subarray(0, 8),
+            Buffer.from([bundle.assetId]),
+            u64(bundle.burnNonce),
+            encodeAttestationBundle(bundle),
+        ]),
+    });
+    const { blockhash } = await connection.getLatestBlockhash();
+    const tx = new VersionedTransaction(new TransactionMessage({
+        payerKey: payer,
+        recentBlockhash: blockhash,
+        instructions: [instruction],
+    }).compileToV0Message());
+
+    const simulation = await connection.simulateTransaction(tx, { sigVerify: false, replaceRecentBlockhash: true });
+    const returnData = simulation.value.returnData;
+    if (simulation.value.err || !returnData || !returnData.programId || returnData.programId !== lightClientProgramId.toBase58()) {
+        throw new Error(`prevalidate_attestation failed: ${JSON.stringify(simulation.value.err)} ${(simulation.value.logs || []).join('\n')}`);
+    }
+    return decodeAttestationCheck(Buffer.from(returnData.data[0], 'base64'));
+}
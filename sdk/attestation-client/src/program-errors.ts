@@ -0,0 +1,427 @@
+import { Ed25519Program, PublicKey, TransactionInstruction } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import * as fs from 'fs';
+import * as path from 'path';
+
+/**
+ * Typed bridge transaction errors
+ *
+ * Maps a failed send / simulation / confirmation (whatever web3.js or
+ * Anchor threw, or the `err` of a confirmed transaction) to a
+ * BridgeClientError: which program and instruction failed, the error
+ * name and code, and what to do about it. Integrators switch on `kind`
+ * and `errorName` instead of string-matching logs.
+ *
+ * Program error codes come from the IDLs (they shift when an enum
+ * variant is added, so they are never copied here); hints are keyed by
+ * name.
+ */
+export enum BridgeErrorKind {
+    /** #[error_code] of a bridge program (6000+) */
+    Program = 'program',
+    /** Anchor account constraint (seeds, has_one, owner, address, ...) */
+    Constraint = 'constraint',
+    /** Anchor account (de)serialization: missing, uninitialized, wrong owner or type */
+    Account = 'account',
+    /** Anchor instruction dispatch: unknown or malformed instruction (IDL / program mismatch) */
+    Instruction = 'instruction',
+    /** require!/require_eq!/require_keys_eq! without a custom error */
+    Require = 'require',
+    /** Native precompile (Ed25519 signature verify instruction) */
+    Precompile = 'precompile',
+    /** Runtime or RPC failure before or outside the programs (fees, blockhash, compute) */
+    Runtime = 'runtime',
+    Unknown = 'unknown',
+}
+
+export interface ProgramErrorEntry {
+    code: number;
+    name: string;
+    msg?: string;
+}
+
+/** One program's #[error_code] table */
+export interface ProgramErrorTable {
+    programId: PublicKey;
+    /** e.g. "solana_light_client_x1" */
+    programName: string;
+    errors: ProgramErrorEntry[];
+}
+
+export interface ParseErrorOptions {
+    /** Program tables (see loadBridgeErrorTables); Anchor and precompile errors map without them */
+    programs?: ProgramErrorTable[];
+    /** The transaction's instructions, to attribute errors that carry no logs (precompiles) */
+    instructions?: TransactionInstruction[];
+}
+
+export class BridgeClientError extends Error {
+    constructor(
+        public readonly kind: BridgeErrorKind,
+        /** Error variant, e.g. "InsufficientAttestations", "ConstraintSeeds", "InvalidSignature" */
+        public readonly errorName: string,
+        public readonly detail: string,
+        public readonly code?: number,
+        /** What the integrator can do about it (undefined = nothing known beyond the message) */
+        public readonly hint?: string,
+        public readonly programId?: PublicKey,
+        public readonly programName?: string,
+        public readonly instructionIndex?: number,
+        /** Account named by an Anchor constraint failure */
+        public readonly account?: string,
+        public readonly logs: string[] = [],
+        /** What was thrown */
+        public readonly original?: unknown
+    ) {
+        super(
+            `${programName ? `${programName}: ` : ''}${errorName}${code !== undefined ? ` (${code})` : ''}: ${detail}` +
+            (hint ? `\n  → ${hint}` : '')
+        );
+        this.name = 'BridgeClientError';
+    }
+
+    /** Safe to resend as-is once the condition clears (wait, refresh blockhash) */
+    get retryable(): boolean {
+        return RETRYABLE.has(this.errorName);
+    }
+}
+
+/** First Anchor user error code */
+const PROGRAM_ERROR_OFFSET = 6000;
+
+/** solana_program::ed25519 / PrecompileError, surfaced as Custom(n) on the precompile instruction */
+const PRECOMPILE_ERRORS = ['InvalidPublicKey', 'InvalidRecoveryId', 'InvalidSignature', 'InvalidDataOffsets', 'InvalidInstructionDataSize'];
+
+/** Runtime failures recognised in the error text */
+const RUNTIME_ERRORS: Array<[RegExp, string]> = [
+    [/insufficient (funds|lamports)|Attempt to debit an account but found no record of a prior credit/i, 'InsufficientFunds'],
+    [/Blockhash not found|block height exceeded|TransactionExpiredBlockheightExceeded/i, 'BlockhashExpired'],
+    [/exceeded CUs meter|ComputationalBudgetExceeded|compute budget exceeded/i, 'ComputeBudgetExceeded'],
+    [/Transaction too large|too large: \d+ bytes/i, 'TransactionTooLarge'],
+    [/already been processed/i, 'AlreadyProcessed'],
+];
+
+const RETRYABLE = new Set([
+    'BlockhashExpired',
+    'InsufficientFinality',
+    'DegradedDelayActive',
+    'RestoreTimelockActive',
+    'ValidatorSetUpdateTimelockActive',
+    'ResignationCooldownActive',
+    'InvalidValidatorSetVersion',
+    'ValidatorSetVersionMismatch',
+]);
+
+/** Remediation by error name (program, Anchor, precompile and runtime names share one namespace) */
+const HINTS: Record<string, string> = {
+    // Light client: attestations
+    InsufficientAttestations: 'Collect attestations from more validators: the submit needs the validator set threshold.',
+    InvalidValidatorSetVersion: 'The validator set rotated: fetch the current version and request fresh attestations.',
+    ValidatorSetVersionMismatch: 'The validator set rotated since the burn was verified: upgrade or re-verify the burn against the current set.',
+    UnknownValidator: 'An attestation is from a key outside the current validator set: refetch the set and drop it.',
+    ValidatorNotInSet: 'A signer is not in the current validator set: refetch the set and drop it.',
+    DuplicateValidator: 'The same validator attested twice: deduplicate attestations by validator_pubkey.',
+    AttestationsOutOfOrder: 'Attestation ordering is on: sort the attestations by validator set index (orderAttestations).',
+    InvalidValidatorSignature: 'A validator signature does not verify: it was signed over another message (domain, X1 genesis, version or burn fields).',
+    Ed25519CountMismatch: 'Add exactly one Ed25519 verify instruction per attestation, in attestation order.',
+    InvalidEd25519Instruction: 'Rebuild the Ed25519 verify instructions with the SDK: offsets must point into their own instruction.',
+    ZeroSignature: 'A validator returned an empty signature: drop that attestation.',
+    NonCanonicalSignature: 'A validator returned a malleated signature: drop that attestation and report the validator.',
+    WeakValidatorKey: 'A weak validator key was supplied: drop that attestation.',
+    InvalidAttestation: 'Submit the burn fields exactly as attested (asset, nonce, user, amount, burn slot and time).',
+    InsufficientFinality: 'The Solana burn is not finalized yet: wait ~32 slots and retry.',
+    BurnRecordMismatch: 'The burn on Solana does not match the request: check nonce, user and amount.',
+    BurnAlreadyProcessed: 'This burn was already submitted or minted: skip to the next step (or nothing is left to do).',
+    ProofAlreadyProcessed: 'This burn was already minted.',
+    V2Deprecated: 'Use submit_burn_attestation_v3 / mint_from_burn_v3 (move V2 burns over with upgrade_verified_burn_to_v3).',
+    InvalidAsset: 'Unknown asset_id: check the asset registry (scripts/asset-registry.ts status) for the supported assets.',
+    AssetInactive: 'Governance has deactivated this asset: no new burns are attested (verified burns still mint).',
+    AssetFrozen: 'Validators have frozen this asset: its burns are neither attested nor minted until they unfreeze it (scripts/asset-registry.ts status). Other assets are unaffected.',
+    AssetNotFrozen: 'The asset is not frozen.',
+    FeatureDisabled: 'Governance has switched this instruction off: check the feature gate (scripts/feature-gate.ts status) and retry later.',
+    BridgePaused: 'Validators have paused the bridge in an emergency: nothing is attested or minted until they unpause (scripts/pause.ts status).',
+    BridgeNotPaused: 'The bridge is not paused.',
+    UsageEpochNotEnded: 'Usage snapshots are committed only for ended epochs: wait for the next X1 epoch.',
+    InvalidUsageSnapshot: 'The usage snapshot has more entries than its tree holds (1_048_576).',
+    InvalidUsageProof: 'The usage proof does not match the epoch\'s snapshot: rebuild it from the published snapshot file (scripts/usage-snapshot.ts prove).',
+    BurnFrozen: 'The burn is frozen by the validators: it cannot be minted until unfrozen.',
+    InvalidX1ChainId: 'The X1 chain id account is wrong: pass the x1_chain_id PDA from x1ChainIdAddress().',
+    InvalidBridgeDomain: 'The bridge domain account is wrong: pass the bridge_domain PDA from bridgeDomainAddress().',
+    InvalidFeatureGate: 'The feature gate account is wrong: pass the feature_gate PDA from featureGateAddress().',
+    InvalidAssetRegistry: 'The asset registry account is wrong: pass the asset_registry PDA from assetRegistryAddress().',
+    InvalidConfirmationDepth: 'Choose a confirmation depth between 32 and 1500 Solana slots (attestations carry 0 for the default).',
+    ConfirmationDepthNotReached: 'The attestations were signed before the burn reached its confirmation depth: collect fresh attestations.',
+    AttestationExpired: 'The attestations are older than the max attestation age (default 24h): collect fresh attestations.',
+    BurnTooOld: 'The burn is older than the burn age limit and can no longer be verified (scripts/burn-age-limit.ts status).',
+    InvalidBurnAgeLimit: 'The burn age limit can only be set within its bounds and lowered, never raised or removed.',
+    InsufficientWeight: 'The signers do not carry the validator set\'s weighted threshold: add signatures from heavier validators.',
+    InvalidValidatorWeights: 'Give one non-zero weight per validator and a threshold of at most 10000 bps (or none of either).',
+    InvalidDevModeConfig: 'Only dev-mode builds can be marked non-production; pass the dev_mode_config PDA from devModeConfigAddress().',
+    InvalidDevModeCanary: 'The canary must sign the marking, be a funded system account and match params.canary.',
+    ValidatorSetUpdateTimelocked: 'Instant updates are off: propose the new set (scripts/validator-set-update.ts propose) and execute it after the delay.',
+    ValidatorSetUpdateTimelockActive: 'The update delay has not passed: execute after the pending update\'s executable_at.',
+    StaleValidatorSetUpdate: 'The validator set changed since the update was proposed: cancel it and propose again.',
+    InvalidValidatorSetTimelock: 'Choose an update delay between 1 and 30 days; pass the validator_set_timelock PDA.',
+    InvalidValidatorSetHistory: 'Pass the x1_validator_set_history PDA of this light client.',
+    InvalidVerifiedBurn: 'Pass the verified_burn_v3 PDA of this asset, user and nonce (owned by the light client).',
+    InvalidChallengeRequest: 'The challenge request account is wrong: pass the challenge_request PDA from challengeRequestAddress().',
+    AttestationPredatesChallenge: 'An attestation was signed before the burn\'s challenge was posted: collect fresh attestations.',
+    ChallengePredatesBurn: 'The challenge was posted before the burn: close it (close_attestation_challenge) and request a new one.',
+    AttestationFromFuture: 'A validator signed with a timestamp ahead of the X1 clock: retry in a minute or collect fresh attestations.',
+    InvalidAttestationAge: 'Choose a max attestation age between 300 seconds and 7 days.',
+    InvalidRelayerFee: 'The relayer fee must be below the burned amount.',
+    BurnClaimedByRelayer: 'A relayer verified this burn for its recipient: only that relayer can mint it, with mint_for_recipient (fetchRelayerClaim()).',
+    UnexpectedEd25519Instruction: 'List every Ed25519 verify instruction of the transaction in the proof\'s ed25519_indices; remove any others.',
+    // Light client: Wormhole VAA path
+    VaaPathDisabled: 'The Wormhole VAA path is off: verify the burn with validator attestations (scripts/wormhole-vaa.ts status).',
+    InvalidWormholeConfig: 'Pass the wormhole_config PDA from wormholeConfigAddress(); an enabled config needs a core bridge and an emitter.',
+    InvalidPostedVaa: 'Pass the burn\'s PostedVAA account of the configured core bridge (postedVaaAddress()): post the VAA on X1 first.',
+    VaaEmitterMismatch: 'The VAA is not from the configured emitter or not final enough: fetch the VAA the bridge emitter published for this burn.',
+    InvalidVaaPayload: 'The VAA is for another burn: pass the asset and nonce in its payload, signed by the burner.',
+    // Light client: degraded mode
+    DegradedModeOff: 'Degraded mode is off for this asset: collect a full threshold of attestations instead.',
+    DegradedTransferCapExceeded: 'The amount is above the degraded-mode per-transfer cap: wait for a full quorum.',
+    DegradedDailyCapExceeded: 'The degraded-mode daily cap is used up: wait for the next day or a full quorum.',
+    DegradedDelayActive: 'The degraded-mode release delay has not passed: retry release_degraded_burn after release_at.',
+    // Light client: archive and sponsorship
+    RetentionWindowActive: 'The verified burn is still inside the retention window: archive it later.',
+    BurnNotMinted: 'Mint the burn before archiving it or marking it processed.',
+    InsufficientSponsorFunds: 'The rent sponsor pool is empty: pay rent yourself or fund the pool.',
+    InsufficientSweepBountyFunds: 'The sweep bounty pool is empty: fund it or sweep without a bounty.',
+    RentSponsorshipNotAllowed: 'This burn does not qualify for rent sponsorship: pay the rent yourself.',
+    // Light client: recipient redirect
+    BurnRedirectTooEarly: 'A burn can be redirected only ~30 days after it was verified: let the recipient mint it, or retry later (scripts/burn-redirect.ts status).',
+    InvalidRedirectRecipient: 'Redirect to a new recipient key, other than the burn\'s user.',
+    // Mint programs
+    AssetNotMintable: 'Send the mint to the program of this asset (XENCAT: xencat-mint-x1, DGN: dgn-mint-x1).',
+    AssetMismatch: 'The asset_id does not match the verified burn: use the burn\'s asset.',
+    MissingValidatorAccount: 'Pass every validator of the set as a writable remaining account, in set order.',
+    InvalidValidatorAccount: 'Pass the validator fee accounts in validator set order.',
+    ValidatorAccountNotWritable: 'Mark the validator fee accounts writable.',
+    InvalidRecipient: 'The recipient token account does not belong to the user and mint.',
+    CoSignerRequired: 'The amount is above the co-sign threshold: add the policy co-signer\'s signature (fetchCoSignPolicy()).',
+    RateLimitExceeded: 'The mint program\'s per-window volume cap is used up: retry once the window rolls (fetchRateLimit / rateLimitHeadroom).',
+    InvalidRateLimit: 'The rate limit account is wrong: pass the rate_limit PDA from rateLimitAddress().',
+    MintPaused: 'Validators have paused this asset\'s mints: verified burns mint once they unpause (scripts/pause.ts status).',
+    MintNotPaused: 'This mint program is not paused.',
+    InvalidMintPause: 'The mint pause account is wrong: pass the mint_pause PDA from mintPauseAddress().',
+    InvalidRelayer: 'Only the relayer that verified the claim can mint it, to its own token account (fetchRelayerClaim()).',
+    InvalidBurnBatch: 'List 1 to 10 distinct burn nonces in increasing order.',
+    InvalidBurnBatchAccount: 'Pass each nonce\'s verified_burn_v3, processed_burn_v3 and relayer_claim PDAs, in nonce order, after the validator fee accounts.',
+    VerifiedBurnNotArchived: 'Archive the burn\'s VerifiedBurnV3 first (npm run archive:crank), then close its replay guard.',
+    BurnNotExpired: 'The burn is not past the burn age limit plus 2 minutes of clock skew, or no limit is set (scripts/burn-age-limit.ts status).',
+    V2BurnNotRetired: 'XENCAT replay guards close only after the V2 cutoff, and only if any V2 proof of the burn was upgraded to V3.',
+    InvalidOwnershipProof: 'Add the stealth owner\'s Ed25519 ownership proof instruction before the mint.',
+    CompressedOutputDisabled: 'Compressed output is not enabled for this asset: mint to a token account.',
+    UserMismatch: 'Only the burner can mint this burn: sign with the user\'s key.',
+    AmountMismatch: 'The amount does not match the verified burn.',
+    Unauthorized: 'The signer is not the authority for this instruction.',
+    // Anchor
+    ConstraintSeeds: 'A PDA was derived with the wrong seeds or program id: use the SDK address helpers.',
+    ConstraintHasOne: 'An account does not match the one recorded in its parent account.',
+    ConstraintAddress: 'An account must be a fixed address (program, PDA or sysvar).',
+    ConstraintRaw: 'An account constraint failed: check the accounts passed against the IDL.',
+    ConstraintMut: 'Mark the account writable.',
+    ConstraintSigner: 'The account must sign the transaction.',
+    ConstraintOwner: 'The account is owned by another program.',
+    AccountNotInitialized: 'The account does not exist yet: run its initialize instruction first (or pass the right PDA).',
+    AccountOwnedByWrongProgram: 'The account belongs to another program: check the program id and cluster.',
+    AccountDiscriminatorMismatch: 'The account has another type than expected: accounts are passed in the wrong order.',
+    AccountDiscriminatorNotFound: 'The account is empty or not an Anchor account.',
+    AccountDidNotDeserialize: 'The account layout does not match: the SDK/IDL and program versions differ.',
+    AccountNotEnoughKeys: 'Accounts are missing: the IDL is older than the program; rebuild or update the SDK.',
+    AccountNotSigner: 'The account must sign the transaction.',
+    AccountNotMutable: 'Mark the account writable.',
+    InstructionFallbackNotFound: 'The program does not know this instruction: it is older than the IDL (check scripts/program-hashes.ts).',
+    InstructionDidNotDeserialize: 'The instruction arguments do not match the program: the SDK/IDL and program versions differ.',
+    // Ed25519 precompile
+    InvalidSignature: 'An Ed25519 verify instruction failed: the attestation was signed over a different message.',
+    InvalidPublicKey: 'An Ed25519 verify instruction carries an invalid public key.',
+    InvalidDataOffsets: 'An Ed25519 verify instruction has offsets outside its data: rebuild it with Ed25519Program.createInstructionWithPublicKey.',
+    InvalidInstructionDataSize: 'An Ed25519 verify instruction is truncated: rebuild it with Ed25519Program.createInstructionWithPublicKey.',
+    // Runtime
+    InsufficientFunds: 'The fee payer cannot cover fees and rent: fund it with XNT.',
+    BlockhashExpired: 'The blockhash expired before landing: re-sign with a fresh blockhash and resend.',
+    ComputeBudgetExceeded: 'Raise the compute unit limit (ComputeBudgetProgram.setComputeUnitLimit) or submit fewer attestations.',
+    TransactionTooLarge: 'Split the transaction or use an address lookup table (see assertTransactionFits).',
+    AlreadyProcessed: 'The same transaction already landed: check its signature instead of resending.',
+};
+
+/** Anchor framework errors, by code (from the installed Anchor client) */
+const LANG_ERRORS: Map<number, string> = new Map(
+    Object.entries(anchor.LangErrorCode as Record<string, number>).map(([name, code]) => [code, name])
+);
+
+function langKind(code: number): BridgeErrorKind {
+    if (code < 1000) return BridgeErrorKind.Instruction;
+    if (code >= 2000 && code < 2500) return BridgeErrorKind.Constraint;
+    if (code >= 2500 && code < 3000) return BridgeErrorKind.Require;
+    if (code >= 3000 && code < 4000) return BridgeErrorKind.Account;
+    return BridgeErrorKind.Unknown;
+}
+
+/**
+ * Error table from an Anchor IDL (target/idl/<program>.json)
+ */
+export function programErrorTable(idl: any, programId: PublicKey): ProgramErrorTable {
+    return {
+        programId,
+        programName: idl.name ?? idl.metadata?.name ?? programId.toBase58(),
+        errors: (idl.errors ?? []).map((e: any) => ({ code: e.code, name: e.name, msg: e.msg })),
+    };
+}
+
+/**
+ * Error tables of the light client and mint programs from the built IDLs
+ * (programs whose IDL is missing are skipped)
+ */
+export function loadBridgeErrorTables(
+    programs: { lightClient: PublicKey; xencatMint?: PublicKey; dgnMint?: PublicKey },
+    idlDir: string = path.join(__dirname, '../../../target/idl')
+): ProgramErrorTable[] {
+    const idls: Array<[string, PublicKey | undefined]> = [
+        ['solana_light_client_x1', programs.lightClient],
+        ['xencat_mint_x1', programs.xencatMint],
+        ['dgn_mint_x1', programs.dgnMint],
+    ];
+    return idls.flatMap(([name, programId]) => {
+        const file = path.join(idlDir, `${name}.json`);
+        if (!programId || !fs.existsSync(file)) {
+            return [];
+        }
+        return [programErrorTable(JSON.parse(fs.readFileSync(file, 'utf-8')), programId)];
+    });
+}
+
+function errorLogs(error: any): string[] {
+    const logs = error?.logs ?? error?.transactionLogs ?? error?.simulationResponse?.logs ?? error?.value?.logs;
+    return Array.isArray(logs) ? logs : [];
+}
+
+/** `{ InstructionError: [index, inner] }` from a confirmed or simulated transaction */
+function instructionError(error: any): { index: number; inner: any } | undefined {
+    const err = error?.InstructionError ? error : (error?.err ?? error?.value?.err ?? error?.transactionError);
+    if (err?.InstructionError) {
+        const [index, inner] = err.InstructionError;
+        return { index, inner };
+    }
+    const match = /Error processing Instruction (\d+): custom program error: 0x([0-9a-f]+)/i.exec(String(error?.message ?? ''));
+    if (match) {
+        return { index: Number(match[1]), inner: { Custom: parseInt(match[2], 16) } };
+    }
+    return undefined;
+}
+
+/** Program that failed, from the last "Program <id> failed" log line */
+function failedProgram(logs: string[]): PublicKey | undefined {
+    for (let i = logs.length - 1; i >= 0; i--) {
+        const match = /^Program (\w+) failed/.exec(logs[i]);
+        if (match) {
+            try {
+                return new PublicKey(match[1]);
+            } catch {
+                return undefined;
+            }
+        }
+    }
+    return undefined;
+}
+
+/**
+ * Map any transaction failure to a BridgeClientError
+ *
+ * Accepts what `.rpc()`, `sendAndConfirm`, `sendTransaction` or
+ * `simulateTransaction` throw, or the `err` of a confirmed transaction.
+ */
+export function parseBridgeError(error: unknown, options: ParseErrorOptions = {}): BridgeClientError {
+    if (error instanceof BridgeClientError) {
+        return error;
+    }
+    const e: any = error;
+    const logs = errorLogs(e);
+    const tables = options.programs ?? [];
+    const ixError = instructionError(e);
+
+    const make = (
+        kind: BridgeErrorKind,
+        name: string,
+        detail: string,
+        code?: number,
+        programId?: PublicKey,
+        account?: string
+    ) => new BridgeClientError(
+        kind,
+        name,
+        detail,
+        code,
+        HINTS[name],
+        programId,
+        programId ? tables.find(t => t.programId.equals(programId))?.programName : undefined,
+        ixError?.index,
+        account,
+        logs,
+        error
+    );
+
+    // Anchor logs its own errors with name and number ("AnchorError caused by account: ...")
+    const anchorError = e instanceof anchor.AnchorError ? e : (logs.length ? anchor.AnchorError.parse(logs) : null);
+    if (anchorError) {
+        const { code, number } = anchorError.error.errorCode;
+        const origin = anchorError.error.origin;
+        const kind = number >= PROGRAM_ERROR_OFFSET ? BridgeErrorKind.Program : langKind(number);
+        return make(kind, code, anchorError.error.errorMessage, number, anchorError.program, typeof origin === 'string' ? origin : undefined);
+    }
+
+    if (ixError) {
+        const programId = failedProgram(logs) ?? options.instructions?.[ixError.index]?.programId;
+        const custom = ixError.inner?.Custom;
+        if (typeof custom === 'number') {
+            if (programId?.equals(Ed25519Program.programId)) {
+                const name = PRECOMPILE_ERRORS[custom] ?? `PrecompileError${custom}`;
+                return make(BridgeErrorKind.Precompile, name, `Ed25519 verify instruction ${ixError.index} failed`, custom, programId);
+            }
+            if (custom >= PROGRAM_ERROR_OFFSET) {
+                const table = programId
+                    ? tables.filter(t => t.programId.equals(programId))
+                    : tables;
+                const entry = table.flatMap(t => t.errors).find(err => err.code === custom);
+                // Without a program id, a code is only unambiguous if a single table has it
+                const owners = tables.filter(t => t.errors.some(err => err.code === custom));
+                if (entry && (programId || owners.length === 1)) {
+                    return make(BridgeErrorKind.Program, entry.name, entry.msg ?? entry.name, custom, programId ?? owners[0].programId);
+                }
+                return make(BridgeErrorKind.Program, `CustomError${custom}`, `custom program error ${custom}`, custom, programId);
+            }
+            const lang = LANG_ERRORS.get(custom);
+            if (lang) {
+                return make(langKind(custom), lang, anchor.LangErrorMessage.get(custom) ?? lang, custom, programId);
+            }
+            return make(BridgeErrorKind.Unknown, `CustomError${custom}`, `custom program error ${custom}`, custom, programId);
+        }
+        // Built-in InstructionError variants ("InvalidAccountData", { BorshIoError: ... }, ...)
+        const name = typeof ixError.inner === 'string' ? ixError.inner : Object.keys(ixError.inner ?? {})[0] ?? 'InstructionError';
+        const runtime = RUNTIME_ERRORS.find(([pattern]) => pattern.test(name) || logs.some(l => pattern.test(l)));
+        if (runtime) {
+            return make(BridgeErrorKind.Runtime, runtime[1], name, undefined, programId);
+        }
+        return make(BridgeErrorKind.Unknown, name, `instruction ${ixError.index} failed: ${name}`, undefined, programId);
+    }
+
+    const text = [String(e?.message ?? e ?? ''), ...logs].join('\n');
+    const runtime = RUNTIME_ERRORS.find(([pattern]) => pattern.test(text));
+    if (runtime) {
+        return make(BridgeErrorKind.Runtime, runtime[1], String(e?.message ?? runtime[1]));
+    }
+    return make(BridgeErrorKind.Unknown, 'Unknown', String(e?.message ?? e));
+}
+
+/**
+ * Run `send` and rethrow its failure as a BridgeClientError
+ */
+export async function withBridgeErrors<T>(send: () => Promise<T>, options: ParseErrorOptions = {}): Promise<T> {
+    try {
+        return await send();
+    } catch (error) {
+        throw parseBridgeError(error, options);
+    }
+}
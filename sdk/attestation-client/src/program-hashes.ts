@@ -0,0 +1,85 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Attested program build hashes (light client ProgramHashRegistry)
+ *
+ * Validator governance attests the sha256 of each audited release's
+ * reproducible build (`xencat-verify-build build`). `deployedProgramHash`
+ * hashes what is actually deployed the same way, so wallets, relayers and
+ * validators can check that a program is a build governance vouched for.
+ *
+ * Layout: discriminator(8) || vec len(4) ||
+ *   [program(32) || version(3 x u16) || hash(32) || attested_slot(8)]* || bump(1)
+ */
+
+export interface ProgramHash {
+    program: PublicKey;
+    version: [number, number, number];
+    /** hex sha256 */
+    hash: string;
+    attestedSlot: number;
+}
+
+const ENTRY_SIZE = 78;
+
+/** Upgradeable loader ProgramData header: enum(4) || slot(8) || Option<authority>(1 + 32) */
+const PROGRAM_DATA_HEADER = 45;
+
+export function programHashRegistryAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('program_hash_registry')], lightClientProgramId)[0];
+}
+
+/**
+ * Attested builds (empty while the registry does not exist)
+ */
+export async function fetchProgramHashes(connection: Connection, lightClientProgramId: PublicKey): Promise<ProgramHash[]> {
+    const info = await connection.getAccountInfo(programHashRegistryAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return [];
+    }
+    const count = info.data.readUInt32LE(8);
+    return Array.from({ length: count }, (_, i) => {
+        const o = 12 + i * ENTRY_SIZE;
+        return {
+            program: new PublicKey(info.data.subarray(o, o + 32)),
+            version: [info.data.readUInt16LE(o + 32), info.data.readUInt16LE(o + 34), info.data.readUInt16LE(o + 36)],
+            hash: info.data.subarray(o + 38, o + 70).toString('hex'),
+            attestedSlot: Number(info.data.readBigUInt64LE(o + 70)),
+        };
+    });
+}
+
+/**
+ * sha256 of program bytes without trailing zero padding (matches xencat-verify-build)
+ */
+export function programHash(bytes: Buffer): string {
+    let end = bytes.length;
+    while (end > 0 && bytes[end - 1] === 0) {
+        end--;
+    }
+    return crypto.createHash('sha256').update(bytes.subarray(0, end)).digest('hex');
+}
+
+/**
+ * Hash of the program currently deployed at `programId` (upgradeable loader)
+ */
+export async function deployedProgramHash(connection: Connection, programId: PublicKey): Promise<string> {
+    const program = await connection.getAccountInfo(programId);
+    if (!program || !program.executable) {
+        throw new Error(`${programId.toBase58()} is not a deployed program`);
+    }
+    // Program account: enum(4) || programdata address(32)
+    const programData = await connection.getAccountInfo(new PublicKey(program.data.subarray(4, 36)));
+    if (!programData) {
+        throw new Error(`Program data of ${programId.toBase58()} not found`);
+    }
+    return programHash(programData.data.subarray(PROGRAM_DATA_HEADER));
+}
+
+/**
+ * The attested build the deployed program matches, or null (unaudited binary)
+ */
+export function matchingBuild(hashes: ProgramHash[], programId: PublicKey, deployedHash: string): ProgramHash | null {
+    return hashes.find(h => h.program.equals(programId) && h.hash === deployedHash) ?? null;
+}
@@ -0,0 +1,92 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Minted volume cap of a mint program (RateLimit)
+ *
+ * Every mint path counts its amount against `cap` per rolling window and
+ * fails with RateLimitExceeded past it. Validators adjust the cap with
+ * set_rate_limit; increases take effect one window after approval.
+ * Relayers check the headroom first and hold transfers that would not fit
+ * instead of paying for a failing mint.
+ *
+ * Layout: discriminator(8) || window_seconds(8) || cap(8) || pending_cap(8) || pending_cap_at(8)
+ *         || window_start(8) || current_window_minted(8) || previous_window_minted(8) || nonce(8) || bump(1)
+ */
+
+export interface RateLimit {
+    windowSeconds: number;
+    cap: bigint;
+    pendingCap: bigint;
+    /** Unix time the pending increase applies (0 = none) */
+    pendingCapAt: number;
+    windowStart: number;
+    currentWindowMinted: bigint;
+    previousWindowMinted: bigint;
+    nonce: bigint;
+}
+
+export function rateLimitAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('rate_limit')], mintProgramId)[0];
+}
+
+export function decodeRateLimit(data: Buffer): RateLimit {
+    return {
+        windowSeconds: Number(data.readBigInt64LE(8)),
+        cap: data.readBigUInt64LE(16),
+        pendingCap: data.readBigUInt64LE(24),
+        pendingCapAt: Number(data.readBigInt64LE(32)),
+        windowStart: Number(data.readBigInt64LE(40)),
+        currentWindowMinted: data.readBigUInt64LE(48),
+        previousWindowMinted: data.readBigUInt64LE(56),
+        nonce: data.readBigUInt64LE(64),
+    };
+}
+
+/**
+ * The mint program's rate limit (null = none, no cap)
+ */
+export async function fetchRateLimit(connection: Connection, mintProgramId: PublicKey): Promise<RateLimit | null> {
+    const info = await connection.getAccountInfo(rateLimitAddress(mintProgramId));
+    if (!info || !info.owner.equals(mintProgramId)) {
+        return null;
+    }
+    return decodeRateLimit(info.data);
+}
+
+/**
+ * Largest amount a mint can take at `now` (unix seconds); must match RateLimit::consume
+ */
+export function rateLimitHeadroom(limit: RateLimit, now: number): bigint {
+    const window = limit.windowSeconds;
+    let { windowStart, currentWindowMinted, previousWindowMinted, cap } = limit;
+    const windowsPassed = Math.floor((now - windowStart) / window);
+    if (windowsPassed >= 1) {
+        previousWindowMinted = windowsPassed === 1 ? currentWindowMinted : 0n;
+        currentWindowMinted = 0n;
+        windowStart += windowsPassed * window;
+    }
+    if (limit.pendingCapAt !== 0 && now >= limit.pendingCapAt) {
+        cap = limit.pendingCap;
+    }
+    const remaining = BigInt(Math.max(window - (now - windowStart), 0));
+    const used = previousWindowMinted * remaining / BigInt(window) + currentWindowMinted;
+    return used >= cap ? 0n : cap - used;
+}
+
+/** Must match create_rate_limit_message in the mint programs */
+export function rateLimitMessage(mintProgramId: PublicKey, validatorSetVersion: bigint | number, nonce: bigint | number, cap: bigint | number, x1GenesisHash: Buffer | null): Buffer {
+    const u64 = (value: bigint | number) => {
+        const buf = Buffer.alloc(8);
+        buf.writeBigUInt64LE(BigInt(value));
+        return buf;
+    };
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_MINT_RATE_LIMIT'),
+        u64(validatorSetVersion),
+        u64(nonce),
+        mintProgramId.toBuffer(),
+        u64(cap),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
@@ -0,0 +1,97 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import type { Attestation } from './index';
+
+/**
+ * Claim on behalf (designated-recipient burns)
+ *
+ * A burner without XNT on X1 burns with burn_xencat_for_recipient, naming
+ * the X1 recipient and a relayer fee (tokens). Validators attest the burn
+ * for the recipient plus the claim terms (`relayer_claim` of each
+ * attestation). Any relayer then:
+ * 1. submit_burn_attestation_for_recipient: verifies both, pays the rent,
+ *    and records itself in the RelayerClaim
+ * 2. mint_for_recipient: mints amount - relayer_fee to the recipient and
+ *    relayer_fee to its own token account, paying validator fees in XNT
+ *
+ * RelayerClaim layout: discriminator(8) || relayer(32) || relayer_fee(8) || bump(1)
+ */
+
+export interface RelayerClaim {
+    relayer: PublicKey;
+    relayerFee: bigint;
+}
+
+function u64(value: bigint | number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+/** The burn program's BurnClaim of `burnNonce` (exists for designated-recipient burns only) */
+export function burnClaimAddress(burnProgramId: PublicKey, burnNonce: number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('burn_claim'), u64(burnNonce)], burnProgramId)[0];
+}
+
+export function relayerClaimAddress(lightClientProgramId: PublicKey, assetId: number, user: PublicKey, burnNonce: number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('relayer_claim'), Buffer.from([assetId]), user.toBuffer(), u64(burnNonce)],
+        lightClientProgramId
+    )[0];
+}
+
+/** Must match relayer_claim_message in the light client (`domain` as for burn attestations) */
+export function relayerClaimMessage(domain: Buffer, assetId: number, burnNonce: number, user: PublicKey, relayerFee: bigint | number): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        domain,
+        Buffer.from('RELAYER_CLAIM'),
+        Buffer.from([assetId]),
+        u64(burnNonce),
+        user.toBuffer(),
+        u64(relayerFee),
+    ])).digest();
+}
+
+/**
+ * The `claim` argument of submit_burn_attestation_for_recipient, from
+ * collected attestations (null if none carries claim terms)
+ *
+ * Uses the fee most validators signed; the program requires a threshold.
+ */
+export function relayerClaimData(attestations: Attestation[]): { relayerFee: bigint; signatures: Array<{ validatorPubkey: PublicKey; signature: number[] }> } | null {
+    const claims = attestations.filter(a => a.relayer_claim);
+    if (claims.length === 0) {
+        return null;
+    }
+    const votes = new Map<number, number>();
+    claims.forEach(a => votes.set(a.relayer_claim!.relayer_fee, (votes.get(a.relayer_claim!.relayer_fee) ?? 0) + 1));
+    const fee = [...votes.entries()].sort((a, b) => b[1] - a[1])[0][0];
+    return {
+        relayerFee: BigInt(fee),
+        signatures: claims
+            .filter(a => a.relayer_claim!.relayer_fee === fee)
+            .map(a => ({ validatorPubkey: new PublicKey(a.validator_pubkey), signature: a.relayer_claim!.signature })),
+    };
+}
+
+export function decodeRelayerClaim(data: Buffer): RelayerClaim {
+    return {
+        relayer: new PublicKey(data.subarray(8, 40)),
+        relayerFee: data.readBigUInt64LE(40),
+    };
+}
+
+/** Recorded claim of a verified burn (null until a relayer submits it) */
+export async function fetchRelayerClaim(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    assetId: number,
+    user: PublicKey,
+    burnNonce: number
+): Promise<RelayerClaim | null> {
+    const info = await connection.getAccountInfo(relayerClaimAddress(lightClientProgramId, assetId, user, burnNonce));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeRelayerClaim(info.data);
+}
@@ -0,0 +1,145 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Off-chain relayer coordination (leader election per transfer)
+ *
+ * When several relayers watch the same burns they would all race to send
+ * submit_burn_attestation_v3 / mint_from_burn_v3, and every loser pays for a
+ * failed `init`. With coordination enabled, every relayer ranks the configured
+ * peers for a transfer with rendezvous hashing:
+ *
+ *   rank order = sort peers by sha256("XENCAT_RELAYER_LEADER" || peer || asset_id || burn_nonce)
+ *
+ * All relayers compute the same order without talking to each other. The
+ * leader (rank 0) submits immediately; rank k waits k * slotSeconds and only
+ * submits if the transfer is still not done, so an offline leader delays a
+ * transfer instead of stalling it. No on-chain state or extra transaction.
+ */
+export interface CoordinationConfig {
+    /** This relayer's id (must appear in peers) */
+    id: string;
+    /** Ids of every relayer taking part, including this one */
+    peers: string[];
+    /** How long each rank waits for the ones before it */
+    slotSeconds: number;
+}
+
+function leaderScore(peer: string, assetId: number, burnNonce: number): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('XENCAT_RELAYER_LEADER'),
+        Buffer.from(peer),
+        Buffer.from([assetId]),
+        Buffer.from(new BigUint64Array([BigInt(burnNonce)]).buffer),
+    ])).digest();
+}
+
+/**
+ * Deterministic submission order for one transfer (leader first)
+ */
+export function relayerOrder(peers: string[], assetId: number, burnNonce: number): string[] {
+    return [...new Set(peers)]
+        .map(peer => ({ peer, score: leaderScore(peer, assetId, burnNonce) }))
+        .sort((a, b) => Buffer.compare(a.score, b.score))
+        .map(entry => entry.peer);
+}
+
+/**
+ * How long this relayer waits before submitting a transfer (0 = leader)
+ */
+export function submissionDelayMs(config: CoordinationConfig, assetId: number, burnNonce: number): number {
+    const rank = relayerOrder(config.peers, assetId, burnNonce).indexOf(config.id);
+    if (rank < 0) {
+        throw new Error(`Relayer ${config.id} is not in the configured peers`);
+    }
+    return rank * config.slotSeconds * 1000;
+}
+
+/**
+ * Wait for this relayer's turn on a transfer.
+ *
+ * `isDone` is polled while waiting (e.g. "does the VerifiedBurnV3 PDA
+ * exist?"). Returns true when this relayer should submit, false when another
+ * relayer already completed the transfer.
+ */
+export async function waitForTurn(
+    config: CoordinationConfig,
+    assetId: number,
+    burnNonce: number,
+    isDone: () => Promise<boolean>,
+    pollMs = 2000
+): Promise<boolean> {
+    const deadline = Date.now() + submissionDelayMs(config, assetId, burnNonce);
+    while (Date.now() < deadline) {
+        if (await isDone()) {
+            return false;
+        }
+        await new Promise(resolve => setTimeout(resolve, Math.min(pollMs, deadline - Date.now())));
+    }
+    return !(await isDone());
+}
+
+/**
+ * Hot-standby mode (one primary, one standby)
+ *
+ * The standby runs next to the primary with the same relayer key but does
+ * not submit. It watches that key's on-chain transactions: since the
+ * standby sends none, every one of them is the primary's. A transfer is
+ * taken over once it has waited `takeoverSeconds` and the primary has not
+ * landed a transaction for as long. A busy primary therefore never loses a
+ * transfer to the standby; a crashed or stuck one delays transfers by at
+ * most `takeoverSeconds`. No on-chain state or extra transaction.
+ */
+export interface StandbyConfig {
+    /** Relayer key the primary submits with */
+    primary: PublicKey;
+    /** Primary silence (and transfer wait) after which the standby submits */
+    takeoverSeconds: number;
+}
+
+/**
+ * Unix time (seconds) of the primary's newest successful transaction, null if none
+ */
+export async function lastPrimarySubmission(connection: Connection, config: StandbyConfig): Promise<number | null> {
+    const recent = await connection.getSignaturesForAddress(config.primary, { limit: 20 });
+    const landed = recent.find(s => !s.err && s.blockTime);
+    return landed?.blockTime ?? null;
+}
+
+/**
+ * Whether the standby should take over a transfer it has been waiting on since `waitingSince`
+ */
+export function primaryStalled(
+    lastSubmission: number | null,
+    waitingSince: number,
+    now: number,
+    takeoverSeconds: number
+): boolean {
+    return now - waitingSince >= takeoverSeconds
+        && (lastSubmission === null || now - lastSubmission >= takeoverSeconds);
+}
+
+/**
+ * Stand by on a transfer until the primary completes it or stalls.
+ *
+ * Returns true when the standby should submit (primary stalled), false
+ * when `isDone` reports the transfer completed.
+ */
+export async function waitForTakeover(
+    connection: Connection,
+    config: StandbyConfig,
+    isDone: () => Promise<boolean>,
+    pollMs = 5000
+): Promise<boolean> {
+    const waitingSince = Math.floor(Date.now() / 1000);
+    for (;;) {
+        if (await isDone()) {
+            return false;
+        }
+        const now = Math.floor(Date.now() / 1000);
+        if (primaryStalled(await lastPrimarySubmission(connection, config), waitingSince, now, config.takeoverSeconds)) {
+            return !(await isDone());
+        }
+        await new Promise(resolve => setTimeout(resolve, pollMs));
+    }
+}
@@ -0,0 +1,70 @@
+import { PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import nacl from 'tweetnacl';
+import bs58 from 'bs58';
+
+/**
+ * Attestation API response signing (must match validator-attestation-service)
+ *
+ * Every response body is signed by the validator key:
+ *   signature = ed25519_sign(sha256(RESPONSE_DOMAIN || request_nonce || body))
+ *
+ * The request nonce is chosen by the client, so a man-in-the-middle cannot
+ * replay an old signed response for a different request.
+ */
+export const RESPONSE_DOMAIN = 'XENCAT_ATTESTATION_RESPONSE_V1';
+
+export const HEADER_REQUEST_NONCE = 'x-request-nonce';
+export const HEADER_VALIDATOR_PUBKEY = 'x-validator-pubkey';
+export const HEADER_VALIDATOR_SIGNATURE = 'x-validator-signature';
+/** Request header: validator key a multi-key service should answer with */
+export const HEADER_VALIDATOR_KEY = 'x-validator-key';
+
+export function createRequestNonce(): string {
+    return crypto.randomBytes(16).toString('hex');
+}
+
+export function responseDigest(requestNonce: string, body: string): Buffer {
+    return crypto
+        .createHash('sha256')
+        .update(RESPONSE_DOMAIN)
+        .update(requestNonce)
+        .update(body)
+        .digest();
+}
+
+/**
+ * Verify a signed response against the validator's on-chain pubkey.
+ *
+ * Throws if the signature is missing, was made by a different key,
+ * or does not cover this exact body + nonce.
+ */
+export function verifyResponseSignature(
+    expectedValidator: PublicKey,
+    requestNonce: string,
+    body: string,
+    headers: Record<string, string | undefined>
+): void {
+    const signerHeader = headers[HEADER_VALIDATOR_PUBKEY];
+    const signatureHeader = headers[HEADER_VALIDATOR_SIGNATURE];
+
+    if (!signerHeader || !signatureHeader) {
+        throw new Error('Unsigned attestation response');
+    }
+
+    if (signerHeader !== expectedValidator.toBase58()) {
+        throw new Error(
+            `Response signed by unexpected key: ${signerHeader} (expected ${expectedValidator.toBase58()})`
+        );
+    }
+
+    const valid = nacl.sign.detached.verify(
+        responseDigest(requestNonce, body),
+        bs58.decode(signatureHeader),
+        expectedValidator.toBytes()
+    );
+
+    if (!valid) {
+        throw new Error('Invalid attestation response signature');
+    }
+}
@@ -0,0 +1,198 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import { transferIdBytes } from './mint-ack';
+
+/**
+ * Return transfers, X1 → Solana (the reverse bridge)
+ *
+ * 1. burn_for_return on the X1 mint program burns XENCAT and records a
+ *    ReturnBurnRecord under the next return nonce.
+ * 2. verify_return_burn on the light client checks a threshold of
+ *    validator attestations (POST /attest-return) and records a
+ *    VerifiedReturnBurn.
+ * 3. release_return on the Solana burn program pays the recipient from
+ *    the return reserve, given a threshold of settlement validator
+ *    signatures (POST /attest-return-release) as Ed25519 instructions.
+ *
+ * ReturnBurnRecord layout: discriminator(8) || nonce(8) || user(32) || solana_recipient(32) ||
+ *   amount(8) || slot(8) || timestamp(8) || bump(1)
+ * VerifiedReturnBurn layout: discriminator(8) || asset_id(1) || return_nonce(8) || user(32) ||
+ *   solana_recipient(32) || amount(8) || x1_slot(8) || validator_set_version(8) || verified_slot(8) || bump(1)
+ */
+
+export interface ReturnBurnRecord {
+    nonce: bigint;
+    /** X1 owner of the burned tokens */
+    user: PublicKey;
+    /** Solana wallet released to */
+    solanaRecipient: PublicKey;
+    amount: bigint;
+    /** X1 slot of the burn */
+    slot: bigint;
+    timestamp: number;
+}
+
+export interface VerifiedReturnBurn {
+    assetId: number;
+    returnNonce: bigint;
+    user: PublicKey;
+    solanaRecipient: PublicKey;
+    amount: bigint;
+    x1Slot: bigint;
+    validatorSetVersion: bigint;
+    verifiedSlot: bigint;
+}
+
+/** Must match TRANSFER_SOURCE_X1 in the light client */
+export const TRANSFER_SOURCE_X1 = 'x1';
+/** Must match SETTLEMENT_DOMAIN in the burn program */
+export const SETTLEMENT_DOMAIN = 'XENCAT_X1_SETTLEMENT_V1';
+
+const RETURN_BURN_RECORD_DISCRIMINATOR = crypto.createHash('sha256').update('account:ReturnBurnRecord').digest().subarray(0, 8);
+const VERIFIED_RETURN_BURN_DISCRIMINATOR = crypto.createHash('sha256').update('account:VerifiedReturnBurn').digest().subarray(0, 8);
+
+function u64(value: bigint | number): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+export function returnBridgeAddress(mintProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('return_bridge')], mintProgramId)[0];
+}
+
+export function returnBurnAddress(mintProgramId: PublicKey, returnNonce: bigint | number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('return_burn'), u64(returnNonce)], mintProgramId)[0];
+}
+
+export function verifiedReturnBurnAddress(lightClientProgramId: PublicKey, assetId: number, returnNonce: bigint | number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('verified_return_burn'), Buffer.from([assetId]), u64(returnNonce)],
+        lightClientProgramId
+    )[0];
+}
+
+export function returnReserveAddress(burnProgramId: PublicKey, mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('return_reserve'), mint.toBuffer()], burnProgramId)[0];
+}
+
+export function returnReserveVaultAddress(burnProgramId: PublicKey, mint: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('return_reserve_vault'), mint.toBuffer()], burnProgramId)[0];
+}
+
+export function returnReleaseAddress(burnProgramId: PublicKey, assetId: number, returnNonce: bigint | number): PublicKey {
+    return PublicKey.findProgramAddressSync(
+        [Buffer.from('return_release'), Buffer.from([assetId]), u64(returnNonce)],
+        burnProgramId
+    )[0];
+}
+
+/** transfer_id("x1", asset, nonce): same id on both chains, as for Solana → X1 transfers */
+export function returnTransferId(assetId: number, returnNonce: bigint | number): Buffer {
+    return transferIdBytes(assetId, returnNonce, TRANSFER_SOURCE_X1);
+}
+
+/** Next return nonce of a mint program (0 until its return bridge exists) */
+export async function fetchNextReturnNonce(connection: Connection, mintProgramId: PublicKey): Promise<bigint> {
+    const info = await connection.getAccountInfo(returnBridgeAddress(mintProgramId));
+    return info ? info.data.readBigUInt64LE(8) : BigInt(0);
+}
+
+export async function fetchReturnBurn(
+    connection: Connection,
+    mintProgramId: PublicKey,
+    returnNonce: bigint | number
+): Promise<ReturnBurnRecord | null> {
+    const info = await connection.getAccountInfo(returnBurnAddress(mintProgramId, returnNonce));
+    if (!info || !info.owner.equals(mintProgramId) || !info.data.subarray(0, 8).equals(RETURN_BURN_RECORD_DISCRIMINATOR)) {
+        return null;
+    }
+    const data = info.data;
+    return {
+        nonce: data.readBigUInt64LE(8),
+        user: new PublicKey(data.subarray(16, 48)),
+        solanaRecipient: new PublicKey(data.subarray(48, 80)),
+        amount: data.readBigUInt64LE(80),
+        slot: data.readBigUInt64LE(88),
+        timestamp: Number(data.readBigInt64LE(96)),
+    };
+}
+
+export async function fetchVerifiedReturnBurn(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    assetId: number,
+    returnNonce: bigint | number
+): Promise<VerifiedReturnBurn | null> {
+    const info = await connection.getAccountInfo(verifiedReturnBurnAddress(lightClientProgramId, assetId, returnNonce));
+    if (!info || !info.owner.equals(lightClientProgramId) || !info.data.subarray(0, 8).equals(VERIFIED_RETURN_BURN_DISCRIMINATOR)) {
+        return null;
+    }
+    const data = info.data;
+    return {
+        assetId: data[8],
+        returnNonce: data.readBigUInt64LE(9),
+        user: new PublicKey(data.subarray(17, 49)),
+        solanaRecipient: new PublicKey(data.subarray(49, 81)),
+        amount: data.readBigUInt64LE(81),
+        x1Slot: data.readBigUInt64LE(89),
+        validatorSetVersion: data.readBigUInt64LE(97),
+        verifiedSlot: data.readBigUInt64LE(105),
+    };
+}
+
+/** Whether the burn program already released `returnNonce` (Solana) */
+export async function isReturnReleased(
+    connection: Connection,
+    burnProgramId: PublicKey,
+    assetId: number,
+    returnNonce: bigint | number
+): Promise<boolean> {
+    return (await connection.getAccountInfo(returnReleaseAddress(burnProgramId, assetId, returnNonce))) !== null;
+}
+
+/**
+ * Must match return_attestation_message in the light client
+ *
+ * `domain` is the signed attestation domain: the BridgeDomain separator,
+ * followed by the X1 genesis hash once X1ChainId is bound.
+ */
+export function returnAttestationMessage(
+    domain: Buffer,
+    assetId: number,
+    validatorSetVersion: bigint | number,
+    burn: Pick<ReturnBurnRecord, 'nonce' | 'user' | 'solanaRecipient' | 'amount' | 'slot'>
+): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        domain,
+        Buffer.from('RETURN'),
+        Buffer.from([assetId]),
+        u64(validatorSetVersion),
+        u64(burn.nonce),
+        burn.user.toBuffer(),
+        burn.solanaRecipient.toBuffer(),
+        u64(burn.amount),
+        u64(burn.slot),
+    ])).digest();
+}
+
+/** Must match return_release_message in the burn program */
+export function returnReleaseMessage(
+    settlementVersion: bigint | number,
+    assetId: number,
+    returnNonce: bigint | number,
+    recipient: PublicKey,
+    amount: bigint | number,
+    x1Slot: bigint | number
+): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from(SETTLEMENT_DOMAIN),
+        Buffer.from('RETURN'),
+        u64(settlementVersion),
+        Buffer.from([assetId]),
+        u64(returnNonce),
+        recipient.toBuffer(),
+        u64(amount),
+        u64(x1Slot),
+    ])).digest();
+}
@@ -0,0 +1,130 @@
+/**
+ * Opt-in telemetry beacon
+ *
+ * Off-chain components (validator service, ...) count their request
+ * outcomes and latencies in a TelemetryCollector. When [telemetry] endpoint
+ * is set, a beacon with the aggregate of the last interval is POSTed there
+ * and the counters start over. Nothing is sent otherwise.
+ *
+ * A beacon is anonymous: software and protocol versions, per-operation
+ * ok/error counts and latency bucket counts. No keys, addresses, RPC URLs,
+ * burn data or bridge domain; `instance` is random per process, so a
+ * restart looks like a new instance.
+ */
+
+import crypto from 'crypto';
+
+export const TELEMETRY_FORMAT = 'xencat-telemetry-v1';
+
+/** Upper bounds (ms) of the latency buckets; the last bucket is everything above */
+export const LATENCY_BUCKETS_MS = [100, 250, 500, 1000, 2500, 5000, 10000] as const;
+
+export interface OperationStats {
+    ok: number;
+    error: number;
+    /** Counts per LATENCY_BUCKETS_MS bucket, plus one for slower */
+    latency_buckets: number[];
+}
+
+export interface TelemetryBeacon {
+    format: typeof TELEMETRY_FORMAT;
+    /** Random per process */
+    instance: string;
+    /** e.g. 'validator-attestation-service' */
+    component: string;
+    /** Component package version */
+    version: string;
+    /** Bridge protocol the component speaks (e.g. 'v3-asset-aware') */
+    protocol: string;
+    node: string;
+    uptime_seconds: number;
+    /** Interval the counts cover */
+    period_seconds: number;
+    operations: Record<string, OperationStats>;
+}
+
+export interface TelemetryConfig {
+    endpoint?: string;
+    interval_seconds: number;
+}
+
+export function latencyBucket(ms: number): number {
+    const index = LATENCY_BUCKETS_MS.findIndex(bound => ms <= bound);
+    return index === -1 ? LATENCY_BUCKETS_MS.length : index;
+}
+
+export class TelemetryCollector {
+    private readonly instance = crypto.randomBytes(8).toString('hex');
+    private readonly startedAt = Date.now();
+    private periodStart = Date.now();
+    private operations: Record<string, OperationStats> = {};
+
+    constructor(
+        readonly component: string,
+        readonly version: string,
+        readonly protocol: string,
+    ) {}
+
+    /** Count one finished operation */
+    record(operation: string, ok: boolean, latencyMs: number) {
+        const stats = this.operations[operation] ??= {
+            ok: 0,
+            error: 0,
+            latency_buckets: new Array(LATENCY_BUCKETS_MS.length + 1).fill(0),
+        };
+        stats[ok ? 'ok' : 'error']++;
+        stats.latency_buckets[latencyBucket(latencyMs)]++;
+    }
+
+    /** Beacon of the current interval; `reset` starts the next one */
+    snapshot(reset = false): TelemetryBeacon {
+        const now = Date.now();
+        const beacon: TelemetryBeacon = {
+            format: TELEMETRY_FORMAT,
+            instance: this.instance,
+            component: this.component,
+            version: this.version,
+            protocol: this.protocol,
+            node: process.version,
+            uptime_seconds: Math.floor((now - this.startedAt) / 1000),
+            period_seconds: Math.floor((now - this.periodStart) / 1000),
+            operations: this.operations,
+        };
+        if (reset) {
+            this.operations = {};
+            this.periodStart = now;
+        }
+        return beacon;
+    }
+}
+
+export async function sendBeacon(endpoint: string, beacon: TelemetryBeacon): Promise<void> {
+    const response = await fetch(endpoint, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(beacon),
+    });
+    if (!response.ok) {
+        throw new Error(`HTTP ${response.status}`);
+    }
+}
+
+/**
+ * Send a beacon every interval_seconds while an endpoint is configured
+ *
+ * Returns a stop function (no-op when telemetry is off). A failed send is
+ * logged once per failure and never retried: that interval is dropped.
+ */
+export function startTelemetry(config: TelemetryConfig, collector: TelemetryCollector): () => void {
+    const endpoint = config.endpoint;
+    if (!endpoint) {
+        return () => {};
+    }
+    const timer = setInterval(() => {
+        sendBeacon(endpoint, collector.snapshot(true)).catch(error => {
+            console.log(`   ⚠️  Telemetry beacon not sent: ${error.message}`);
+        });
+    }, config.interval_seconds * 1000);
+    timer.unref();
+    return () => clearInterval(timer);
+}
@@ -0,0 +1,133 @@
+import { PublicKey } from '@solana/web3.js';
+import * as http from 'http';
+import * as https from 'https';
+import * as tls from 'tls';
+import * as crypto from 'crypto';
+import {
+    createRequestNonce,
+    verifyResponseSignature,
+    HEADER_REQUEST_NONCE,
+    HEADER_VALIDATOR_KEY,
+} from './response-signing';
+
+/**
+ * A validator attestation endpoint as known to the relayer.
+ *
+ * `certSha256` pins the TLS certificate (SHA-256 fingerprint, hex with or
+ * without colons). When set, the endpoint MUST use https.
+ */
+export interface ValidatorEndpoint {
+    pubkey: PublicKey;
+    url: string;
+    certSha256?: string;
+}
+
+/**
+ * Trace context propagated to validator services
+ *
+ * transferId is the cross-process correlation id for one bridge transfer;
+ * traceparent follows the W3C Trace Context format.
+ */
+export interface TraceContext {
+    transferId: string;
+    traceparent: string;
+}
+
+/**
+ * Start a new trace for a bridge transfer
+ */
+export function newTraceContext(burnNonce: number, assetId?: number): TraceContext {
+    const traceId = crypto.randomBytes(16).toString('hex');
+    const spanId = crypto.randomBytes(8).toString('hex');
+    return {
+        transferId: assetId === undefined ? `solana:${burnNonce}` : `solana:${assetId}:${burnNonce}`,
+        traceparent: `00-${traceId}-${spanId}-01`,
+    };
+}
+
+export interface SignedResponse {
+    status: number;
+    body: any;
+}
+
+function normalizeFingerprint(fingerprint: string): string {
+    return fingerprint.replace(/:/g, '').toLowerCase();
+}
+
+/**
+ * POST JSON to a validator and return the parsed body.
+ *
+ * SECURITY:
+ * - Response signature is verified against the validator's pubkey
+ *   (protects against tampering even over plain http)
+ * - TLS certificate is pinned when `certSha256` is configured
+ */
+export function postSigned(
+    endpoint: ValidatorEndpoint,
+    path: string,
+    payload: unknown,
+    trace?: TraceContext
+): Promise<SignedResponse> {
+    const url = new URL(path, endpoint.url);
+    const requestNonce = createRequestNonce();
+    const body = JSON.stringify(payload);
+
+    if (endpoint.certSha256 && url.protocol !== 'https:') {
+        return Promise.reject(
+            new Error(`Certificate pin configured but ${endpoint.url} is not https`)
+        );
+    }
+
+    const options: https.RequestOptions = {
+        method: 'POST',
+        headers: {
+            'Content-Type': 'application/json',
+            'Content-Length': Buffer.byteLength(body),
+            [HEADER_REQUEST_NONCE]: requestNonce,
+            // Selects the key when one service hosts several validators
+            [HEADER_VALIDATOR_KEY]: endpoint.pubkey.toBase58(),
+            ...(trace ? { 'x-transfer-id': trace.transferId, traceparent: trace.traceparent } : {}),
+        },
+    };
+
+    if (endpoint.certSha256) {
+        const pinned = normalizeFingerprint(endpoint.certSha256);
+        options.checkServerIdentity = (host: string, cert: tls.PeerCertificate) => {
+            const err = tls.checkServerIdentity(host, cert);
+            if (err) {
+                return err;
+            }
+            if (normalizeFingerprint(cert.fingerprint256) !== pinned) {
+                return new Error(`Certificate pin mismatch for ${host}`);
+            }
+            return undefined;
+        };
+    }
+
+    const client = url.protocol === 'https:' ? https : http;
+
+    return new Promise((resolve, reject) => {
+        const req = client.request(url, options, (res) => {
+            const chunks: Buffer[] = [];
+            res.on('data', (chunk) => chunks.push(chunk));
+            res.on('end', () => {
+                const text = Buffer.concat(chunks).toString('utf-8');
+                try {
+                    verifyResponseSignature(
+                        endpoint.pubkey,
+                        requestNonce,
+                        text,
+                        res.headers as Record<string, string | undefined>
+                    );
+                    resolve({ status: res.statusCode || 0, body: JSON.parse(text) });
+                } catch (error) {
+                    reject(error);
+                }
+            });
+        });
+
+        req.on('error', reject);
+        req.write(body);
+        req.end();
+    });
+}
@@ -0,0 +1,219 @@
+import { Connection, PublicKey, VersionedTransactionResponse } from '@solana/web3.js';
+import * as anchor from '@coral-xyz/anchor';
+import { BurnVerifiedEvent } from './event-schema';
+import { decodeMintAcknowledgements } from './mint-ack';
+import { CostAccountingContext, TransferCost, transactionCosts } from './cost-accounting';
+
+/**
+ * Treasury statements (per-wallet bridge accounting exports)
+ *
+ * Lists the transfers verified on X1 in a date range, one per burn: the
+ * burner's wallet, asset and amount, when it was verified and minted, the
+ * transactions on both chains and what it cost. Costs follow the cost
+ * report's attribution (cost-accounting.ts): what the wallet paid itself
+ * is `feesPaid`, what a relayer or integrator paid for it `feesCovered`.
+ * Rent paid by the rent sponsor pool is in neither.
+ *
+ * A transfer belongs to the range its BurnVerified event falls in. Its
+ * mint is linked when it falls in the range too; a mint after the end is
+ * missing from this statement and one for a burn verified earlier is left
+ * out.
+ */
+
+export interface StatementTransfer {
+    wallet: PublicKey;
+    assetId: number;
+    burnNonce: bigint;
+    /** Base units of the asset */
+    amount: bigint;
+    verifiedAt: number;
+    verifySignature: string;
+    mintedAt?: number;
+    mintSignature?: string;
+    /** Solana burn transaction (see fetchBurnSignature) */
+    burnSignature?: string;
+    /** Lamports the wallet paid (rent net of sponsored rent, fees, validator fees) */
+    feesPaid: bigint;
+    /** Lamports other fee payers paid for this transfer */
+    feesCovered: bigint;
+}
+
+/** What one X1 transaction adds to a statement */
+export interface StatementRecords {
+    verified: Array<Pick<StatementTransfer, 'wallet' | 'assetId' | 'burnNonce' | 'amount' | 'verifiedAt' | 'verifySignature'>>;
+    minted: Array<{ assetId: number; burnNonce: bigint; mintedAt: number; mintSignature: string }>;
+    costs: TransferCost[];
+}
+
+export interface WalletTotals {
+    wallet: string;
+    assetId: number;
+    transfers: number;
+    amount: bigint;
+    feesPaid: bigint;
+    feesCovered: bigint;
+}
+
+export interface ExplorerLinks {
+    /** Prefixes a signature is appended to */
+    x1: string;
+    solana: string;
+}
+
+/** Unix seconds [start, end) from day `from` to day `to` inclusive, 'YYYY-MM-DD' (UTC) */
+export function dayRange(from: string, to: string): { start: number; end: number } {
+    const day = (value: string) => {
+        const match = /^(\d{4})-(\d{2})-(\d{2})$/.exec(value);
+        const time = match ? Date.UTC(Number(match[1]), Number(match[2]) - 1, Number(match[3])) : NaN;
+        if (!match || new Date(time).toISOString().slice(0, 10) !== value) {
+            throw new Error(`Invalid date ${value}: expected YYYY-MM-DD`);
+        }
+        return time / 1000;
+    };
+    const [start, end] = [day(from), day(to) + 86_400];
+    if (end <= start) {
+        throw new Error(`Empty date range ${from} to ${to}`);
+    }
+    return { start, end };
+}
+
+const transferKey = (assetId: number, burnNonce: bigint) => `${assetId}:${burnNonce}`;
+
+/**
+ * Verifications, mints and cost lines of one confirmed X1 transaction
+ */
+export function statementRecords(tx: VersionedTransactionResponse, context: CostAccountingContext): StatementRecords {
+    const records: StatementRecords = { verified: [], minted: [], costs: [] };
+    const meta = tx.meta;
+    if (!meta || meta.err || tx.blockTime == null) {
+        return records;
+    }
+    const logs = meta.logMessages || [];
+    const signature = tx.transaction.signatures[0];
+
+    const parser = new anchor.EventParser(context.lightClientProgramId, context.lightClientCoder as any);
+    for (const event of parser.parseLogs(logs)) {
+        if (event.name === 'BurnVerified') {
+            const data = event.data as unknown as BurnVerifiedEvent;
+            records.verified.push({
+                wallet: data.user,
+                assetId: data.assetId,
+                burnNonce: BigInt(data.burnNonce.toString()),
+                amount: BigInt(data.amount.toString()),
+                verifiedAt: tx.blockTime,
+                verifySignature: signature,
+            });
+        }
+    }
+    for (const mintProgramId of context.mintProgramIds) {
+        for (const ack of decodeMintAcknowledgements(logs, mintProgramId)) {
+            records.minted.push({ assetId: ack.assetId, burnNonce: ack.burnNonce, mintedAt: tx.blockTime, mintSignature: signature });
+        }
+    }
+    records.costs = transactionCosts(tx, context);
+    return records;
+}
+
+/**
+ * Transfers of a statement, oldest first (`wallets` keeps only those burners)
+ */
+export function buildStatement(records: StatementRecords[], wallets?: Set<string>): StatementTransfer[] {
+    const transfers = new Map<string, StatementTransfer>();
+    for (const verified of records.flatMap(r => r.verified)) {
+        if (!wallets || wallets.has(verified.wallet.toBase58())) {
+            transfers.set(transferKey(verified.assetId, verified.burnNonce), { ...verified, feesPaid: 0n, feesCovered: 0n });
+        }
+    }
+    for (const minted of records.flatMap(r => r.minted)) {
+        const transfer = transfers.get(transferKey(minted.assetId, minted.burnNonce));
+        if (transfer) {
+            transfer.mintedAt = minted.mintedAt;
+            transfer.mintSignature = minted.mintSignature;
+        }
+    }
+    for (const cost of records.flatMap(r => r.costs)) {
+        const transfer = transfers.get(transferKey(cost.assetId, cost.burnNonce));
+        if (!transfer) {
+            continue;
+        }
+        const total = cost.rent - cost.sponsoredRent + cost.baseFee + cost.priorityFee + cost.validatorFees;
+        if (cost.feePayer.equals(transfer.wallet)) {
+            transfer.feesPaid += total;
+        } else {
+            transfer.feesCovered += total;
+        }
+    }
+    return [...transfers.values()].sort((a, b) => a.verifiedAt - b.verifiedAt);
+}
+
+/** Statement transfers summed per wallet and asset */
+export function walletTotals(transfers: StatementTransfer[]): WalletTotals[] {
+    const totals = new Map<string, WalletTotals>();
+    for (const transfer of transfers) {
+        const wallet = transfer.wallet.toBase58();
+        const key = `${wallet}:${transfer.assetId}`;
+        const entry = totals.get(key) ?? { wallet, assetId: transfer.assetId, transfers: 0, amount: 0n, feesPaid: 0n, feesCovered: 0n };
+        entry.transfers += 1;
+        entry.amount += transfer.amount;
+        entry.feesPaid += transfer.feesPaid;
+        entry.feesCovered += transfer.feesCovered;
+        totals.set(key, entry);
+    }
+    return [...totals.values()].sort((a, b) => a.wallet.localeCompare(b.wallet) || a.assetId - b.assetId);
+}
+
+/**
+ * The Solana transaction that burned `burnNonce`: the first one of its
+ * BurnRecord PDA, which stays in the history after the record is closed
+ */
+export async function fetchBurnSignature(solana: Connection, burnProgramId: PublicKey, burnNonce: bigint): Promise<string | undefined> {
+    const nonce = Buffer.alloc(8);
+    nonce.writeBigUInt64LE(burnNonce);
+    const burnRecord = PublicKey.findProgramAddressSync([Buffer.from('burn_record'), nonce], burnProgramId)[0];
+    let before: string | undefined;
+    let first: string | undefined;
+    for (;;) {
+        const page = await solana.getSignaturesForAddress(burnRecord, { before, limit: 1000 });
+        const ok = page.filter(s => !s.err);
+        if (ok.length > 0) {
+            first = ok[ok.length - 1].signature;
+        }
+        if (page.length < 1000) {
+            return first;
+        }
+        before = page[page.length - 1].signature;
+    }
+}
+
+/**
+ * Statement as CSV (one row per transfer); `formatAmount` adds a display
+ * amount next to the base units
+ */
+export function statementToCsv(
+    transfers: StatementTransfer[],
+    links: ExplorerLinks,
+    formatAmount: (assetId: number, amount: bigint) => string = (_, amount) => amount.toString()
+): string {
+    const time = (seconds?: number) => (seconds == null ? '' : new Date(seconds * 1000).toISOString());
+    const link = (prefix: string, signature?: string) => (signature ? `${prefix}${signature}` : '');
+    const header = 'verified_at,minted_at,wallet,asset_id,burn_nonce,amount,amount_display,fees_paid,fees_covered,'
+        + 'solana_burn_tx,x1_verify_tx,x1_mint_tx,solana_burn_link,x1_verify_link,x1_mint_link';
+    const rows = transfers.map(t => [
+        time(t.verifiedAt),
+        time(t.mintedAt),
+        t.wallet.toBase58(),
+        t.assetId,
+        t.burnNonce,
+        t.amount,
+        formatAmount(t.assetId, t.amount),
+        t.feesPaid,
+        t.feesCovered,
+        t.burnSignature ?? '',
+        t.verifySignature,
+        t.mintSignature ?? '',
+        link(links.solana, t.burnSignature),
+        link(links.x1, t.verifySignature),
+        link(links.x1, t.mintSignature),
+    ].join(','));
+    return [header, ...rows].join('\n') + '\n';
+}
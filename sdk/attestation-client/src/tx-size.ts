@@ -0,0 +1,186 @@
+import {
+    AddressLookupTableAccount,
+    Ed25519Program,
+    PACKET_DATA_SIZE,
+    PublicKey,
+    Transaction,
+    TransactionInstruction,
+    TransactionMessage,
+    VersionedTransaction,
+} from '@solana/web3.js';
+
+/**
+ * Transaction size budget checker
+ *
+ * Solana (and X1) reject transactions larger than PACKET_DATA_SIZE (1232
+ * bytes) at the RPC, after the relayer has already collected attestations.
+ * The bridge transactions that get close are submit_burn_attestation_v3
+ * (104 bytes per attestation, plus 100+ bytes per Ed25519 instruction when
+ * signatures are proven natively) and mint_from_burn_v3 (32 bytes per
+ * validator fee account). Measure the exact size before broadcasting and
+ * fail with guidance instead.
+ */
+export interface PlannedTransaction {
+    payer: PublicKey;
+    instructions: TransactionInstruction[];
+    /** Compile as v0 with these tables (accounts found there cost 1 byte instead of 32) */
+    addressLookupTableAccounts?: AddressLookupTableAccount[];
+}
+
+export interface InstructionSize {
+    index: number;
+    programId: string;
+    /** Data bytes + account index bytes (excludes shared account keys) */
+    bytes: number;
+    accounts: number;
+}
+
+export interface TransactionSizeReport {
+    size: number;
+    limit: number;
+    fits: boolean;
+    signatures: number;
+    accountKeys: number;
+    instructions: InstructionSize[];
+}
+
+export class TransactionTooLargeError extends Error {
+    constructor(public readonly report: TransactionSizeReport, public readonly guidance: string[]) {
+        super(
+            `Transaction is ${report.size} bytes, over the ${report.limit}-byte limit by ${report.size - report.limit}:\n` +
+            guidance.map(g => `  - ${g}`).join('\n')
+        );
+    }
+}
+
+/** Dummy blockhash: every blockhash serializes to the same 32 bytes */
+const PLACEHOLDER_BLOCKHASH = PublicKey.default.toBase58();
+/** Instructions that can always move to a separate transaction: compute budget, associated token account */
+const AUXILIARY_PROGRAMS = [
+    'ComputeBudget111111111111111111111111111111',
+    'ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL',
+];
+/** submit_burn_attestation_v3: ValidatorAttestation = pubkey(32) + signature(64) + timestamp(8) */
+const ATTESTATION_BYTES = 104;
+
+function shortVecLength(n: number): number {
+    return n < 0x80 ? 1 : n < 0x4000 ? 2 : 3;
+}
+
+/**
+ * Exact serialized size of a transaction or plan (signatures included)
+ */
+export function measureTransaction(tx: Transaction | VersionedTransaction | PlannedTransaction): TransactionSizeReport {
+    let messageBytes: Uint8Array;
+    let signatures: number;
+    let compiled: Array<{ programIdIndex: number; accountKeyIndexes: number[]; data: Uint8Array }>;
+    let keys: PublicKey[];
+
+    if (tx instanceof VersionedTransaction) {
+        const message = tx.message;
+        messageBytes = message.serialize();
+        signatures = message.header.numRequiredSignatures;
+        keys = message.staticAccountKeys;
+        compiled = message.compiledInstructions;
+    } else {
+        const plan: PlannedTransaction = tx instanceof Transaction
+            ? { payer: tx.feePayer || PublicKey.default, instructions: tx.instructions }
+            : tx;
+        const message = new TransactionMessage({
+            payerKey: plan.payer,
+            recentBlockhash: PLACEHOLDER_BLOCKHASH,
+            instructions: plan.instructions,
+        });
+        const v0OrLegacy = plan.addressLookupTableAccounts
+            ? message.compileToV0Message(plan.addressLookupTableAccounts)
+            : message.compileToLegacyMessage();
+        messageBytes = v0OrLegacy.serialize();
+        signatures = v0OrLegacy.header.numRequiredSignatures;
+        keys = v0OrLegacy.staticAccountKeys;
+        compiled = v0OrLegacy.compiledInstructions;
+    }
+
+    const size = shortVecLength(signatures) + 64 * signatures + messageBytes.length;
+    return {
+        size,
+        limit: PACKET_DATA_SIZE,
+        fits: size <= PACKET_DATA_SIZE,
+        signatures,
+        accountKeys: keys.length,
+        instructions: compiled.map((ix, index) => ({
+            index,
+            programId: keys[ix.programIdIndex]?.toBase58() ?? 'lookup',
+            bytes: 1
+                + shortVecLength(ix.accountKeyIndexes.length) + ix.accountKeyIndexes.length
+                + shortVecLength(ix.data.length) + ix.data.length,
+            accounts: ix.accountKeyIndexes.length,
+        })),
+    };
+}
+
+export interface BudgetContext {
+    /** Minimum attestations the program needs (X1ValidatorSet.threshold) */
+    threshold?: number;
+    /** Attestations carried by the planned submit instruction */
+    attestationCount?: number;
+}
+
+/**
+ * Actionable suggestions for an oversized transaction
+ */
+export function sizeGuidance(report: TransactionSizeReport, context: BudgetContext = {}): string[] {
+    const over = report.size - report.limit;
+    const guidance: string[] = [];
+
+    const { threshold, attestationCount } = context;
+    if (threshold !== undefined && attestationCount !== undefined && attestationCount > threshold) {
+        const saved = (attestationCount - threshold) * ATTESTATION_BYTES;
+        guidance.push(
+            `Submit only ${threshold} attestations (threshold) instead of ${attestationCount}: saves ${saved} bytes` +
+            (saved >= over ? ' - enough to fit' : '')
+        );
+    }
+
+    const ed25519 = report.instructions.filter(ix => ix.programId === Ed25519Program.programId.toBase58());
+    if (ed25519.length > 0) {
+        const bytes = ed25519.reduce((total, ix) => total + ix.bytes, 0);
+        guidance.push(`${ed25519.length} Ed25519 instructions use ${bytes} bytes: put several signatures in one Ed25519 instruction or split verification across transactions`);
+    }
+
+    const widest = [...report.instructions].sort((a, b) => b.accounts - a.accounts)[0];
+    if (widest && widest.accounts > 8) {
+        guidance.push(
+            `Instruction #${widest.index} references ${widest.accounts} accounts: move static ones (validator fee accounts, programs) ` +
+            `into an address lookup table (v0 transaction) to save up to ${31 * (widest.accounts - 8)} bytes`
+        );
+    }
+
+    if (report.signatures > 1) {
+        guidance.push(`${report.signatures} signers cost ${64 * report.signatures} bytes: drop extra signers or split the transaction`);
+    }
+
+    const auxiliary = report.instructions.filter(ix => AUXILIARY_PROGRAMS.includes(ix.programId));
+    if (auxiliary.length > 0) {
+        const bytes = auxiliary.reduce((total, ix) => total + ix.bytes, 0);
+        guidance.push(`Send ${auxiliary.length} auxiliary instruction(s) (compute budget, ATA creation) in a separate transaction: saves ${bytes}+ bytes`);
+    }
+
+    if (guidance.length === 0) {
+        guidance.push('Split the work across multiple transactions');
+    }
+    return guidance;
+}
+
+/**
+ * Throw TransactionTooLargeError (with guidance) unless the transaction fits
+ */
+export function assertTransactionFits(
+    tx: Transaction | VersionedTransaction | PlannedTransaction,
+    context: BudgetContext = {}
+): TransactionSizeReport {
+    const report = measureTransaction(tx);
+    if (!report.fits) {
+        throw new TransactionTooLargeError(report, sizeGuidance(report, context));
+    }
+    return report;
+}
@@ -0,0 +1,423 @@
+import {
+    AddressLookupTableAccount,
+    ComputeBudgetProgram,
+    Connection,
+    PublicKey,
+    SystemProgram,
+    Transaction,
+    TransactionInstruction,
+    TransactionMessage,
+    VersionedTransaction,
+} from '@solana/web3.js';
+import * as crypto from 'crypto';
+import { fetchNotice, noticeWarning } from './notice';
+
+/**
+ * Human-readable preview of an unsigned bridge transaction
+ *
+ * Decodes the bridge instructions a wallet is about to sign (Solana burns,
+ * X1 attestation submits and mints) and re-checks the same constraints the
+ * programs enforce, so a user can see what will be burned or minted, what
+ * it costs and which accounts get created - and be warned before signing a
+ * transaction that would fail or does something unexpected.
+ *
+ * Offline by default; pass a `connection` (X1) to also read the validator
+ * set, mint state and verified burn for exact fees and amounts.
+ */
+export interface BridgeProgramIds {
+    burn: PublicKey;
+    lightClient: PublicKey;
+    xencatMint: PublicKey;
+    dgnMint: PublicKey;
+}
+
+export interface SummaryOptions {
+    programs: BridgeProgramIds;
+    /** X1 connection for on-chain lookups (validator set, fees, amounts) */
+    connection?: Connection;
+    /** Required to decode v0 transactions that use lookup tables */
+    addressLookupTableAccounts?: AddressLookupTableAccount[];
+}
+
+export interface SummaryAction {
+    kind: 'burn' | 'submit_attestations' | 'mint' | 'create_token_account' | 'transfer' | 'compute_budget' | 'unknown';
+    description: string;
+}
+
+export interface CreatedAccount {
+    address: PublicKey;
+    label: string;
+}
+
+export interface TransactionSummary {
+    feePayer?: PublicKey;
+    actions: SummaryAction[];
+    accountsCreated: CreatedAccount[];
+    fees: {
+        /** 5000 lamports per required signature */
+        networkFee: number;
+        /** From SetComputeUnitPrice x SetComputeUnitLimit (0 if absent) */
+        priorityFee: number;
+        /** XNT paid to validators by mint_from_burn_v3 (undefined = unknown offline) */
+        validatorFees?: bigint;
+    };
+    /** Constraint violations / unexpected instructions - show these prominently */
+    warnings: string[];
+    /** Ready-to-display text, one line per item */
+    lines: string[];
+}
+
+const LAMPORTS_PER_SIGNATURE = 5000;
+const DEFAULT_COMPUTE_UNITS = 200_000;
+const ASSET_NAMES: Record<number, string> = { 1: 'XENCAT', 2: 'DGN' };
+const ASSOCIATED_TOKEN_PROGRAM_ID = new PublicKey('ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL');
+
+function discriminator(name: string): Buffer {
+    return crypto.createHash('sha256').update(`global:${name}`).digest().subarray(0, 8);
+}
+
+const IX = {
+    burnXencat: discriminator('burn_xencat'),
+    burnXencatInsured: discriminator('burn_xencat_insured'),
+    burnToStealth: discriminator('burn_to_stealth'),
+    burnXencatWithDepth: discriminator('burn_xencat_with_depth'),
+    burnXencatTo: discriminator('burn_xencat_to'),
+    burnRegisteredAsset: discriminator('burn_registered_asset'),
+    submitBurnAttestationV3: discriminator('submit_burn_attestation_v3'),
+    mintFromBurnV3: discriminator('mint_from_burn_v3'),
+};
+
+function formatAmount(amount: bigint, decimals = 6): string {
+    const whole = amount / 10n ** BigInt(decimals);
+    const frac = (amount % 10n ** BigInt(decimals)).toString().padStart(decimals, '0').replace(/0+$/, '');
+    return frac ? `${whole}.${frac}` : whole.toString();
+}
+
+function formatXnt(lamports: bigint): string {
+    return `${formatAmount(lamports, 9)} XNT`;
+}
+
+function assetName(assetId: number): string {
+    return ASSET_NAMES[assetId] || `unknown asset ${assetId}`;
+}
+
+function toInstructions(
+    tx: Transaction | VersionedTransaction,
+    options: SummaryOptions
+): { instructions: TransactionInstruction[]; feePayer?: PublicKey; signatures: number } {
+    if (tx instanceof Transaction) {
+        // Unsigned legacy transactions may lack a blockhash, so count signers directly
+        const signers = new Set(tx.instructions.flatMap(ix => ix.keys.filter(k => k.isSigner).map(k => k.pubkey.toBase58())));
+        if (tx.feePayer) {
+            signers.add(tx.feePayer.toBase58());
+        }
+        return {
+            instructions: tx.instructions,
+            feePayer: tx.feePayer,
+            signatures: Math.max(1, signers.size),
+        };
+    }
+    const message = TransactionMessage.decompile(tx.message, {
+        addressLookupTableAccounts: options.addressLookupTableAccounts,
+    });
+    return {
+        instructions: message.instructions,
+        feePayer: message.payerKey,
+        signatures: tx.message.header.numRequiredSignatures,
+    };
+}
+
+// ============================================================================
+// ON-CHAIN LOOKUPS (optional)
+// ============================================================================
+
+async function fetchValidatorSet(connection: Connection, address: PublicKey) {
+    const info = await connection.getAccountInfo(address);
+    if (!info) {
+        return undefined;
+    }
+    // discriminator(8) || version(8) || validators: vec<Pubkey> || threshold(1) || bump(1)
+    const data = info.data;
+    const version = Number(data.readBigUInt64LE(8));
+    const count = data.readUInt32LE(16);
+    const validators: PublicKey[] = [];
+    for (let i = 0; i < count; i++) {
+        validators.push(new PublicKey(data.subarray(20 + 32 * i, 52 + 32 * i)));
+    }
+    return { version, validators, threshold: data[20 + 32 * count] };
+}
+
+async function fetchFeePerValidator(connection: Connection, mintState: PublicKey): Promise<bigint | undefined> {
+    const info = await connection.getAccountInfo(mintState);
+    // discriminator(8) || authority(32) || mint(32) || fee_per_validator(8)
+    return info ? info.data.readBigUInt64LE(72) : undefined;
+}
+
+async function fetchVerifiedBurn(connection: Connection, address: PublicKey) {
+    const info = await connection.getAccountInfo(address);
+    if (!info) {
+        return undefined;
+    }
+    // discriminator(8) || asset_id(1) || burn_nonce(8) || user(32) || amount(8) || verified_at(8) || processed(1)
+    const data = info.data;
+    return {
+        assetId: data[8],
+        user: new PublicKey(data.subarray(17, 49)),
+        amount: data.readBigUInt64LE(49),
+        processed: data[65] === 1,
+    };
+}
+
+async function accountExists(connection: Connection, address: PublicKey): Promise<boolean> {
+    return (await connection.getAccountInfo(address)) !== null;
+}
+
+// ============================================================================
+// SUMMARIZER
+// ============================================================================
+
+/**
+ * Summarize an unsigned bridge transaction for display before signing
+ */
+export async function summarizeBridgeTransaction(
+    tx: Transaction | VersionedTransaction,
+    options: SummaryOptions
+): Promise<TransactionSummary> {
+    const { programs, connection } = options;
+    const { instructions, feePayer, signatures } = toInstructions(tx, options);
+
+    const summary: TransactionSummary = {
+        feePayer,
+        actions: [],
+        accountsCreated: [],
+        fees: { networkFee: signatures * LAMPORTS_PER_SIGNATURE, priorityFee: 0 },
+        warnings: [],
+        lines: [],
+    };
+    const action = (kind: SummaryAction['kind'], description: string) => summary.actions.push({ kind, description });
+    const warn = (message: string) => summary.warnings.push(message);
+
+    let microLamportsPerCu = 0;
+    let computeUnits = DEFAULT_COMPUTE_UNITS * instructions.length;
+
+    for (const ix of instructions) {
+        const data = Buffer.from(ix.data);
+        const disc = data.subarray(0, 8);
+
+        if (ix.programId.equals(ComputeBudgetProgram.programId)) {
+            // SetComputeUnitLimit = 2 (u32), SetComputeUnitPrice = 3 (u64)
+            if (data[0] === 2) {
+                computeUnits = data.readUInt32LE(1);
+                action('compute_budget', `Compute unit limit: ${computeUnits}`);
+            } else if (data[0] === 3) {
+                microLamportsPerCu = Number(data.readBigUInt64LE(1));
+                action('compute_budget', `Priority fee: ${microLamportsPerCu} micro-lamports per CU`);
+            }
+            continue;
+        }
+
+        if (ix.programId.equals(programs.burn)) {
+            if (disc.equals(IX.burnXencat) || disc.equals(IX.burnXencatInsured) || disc.equals(IX.burnToStealth)
+                || disc.equals(IX.burnXencatWithDepth) || disc.equals(IX.burnXencatTo)) {
+                const amount = data.readBigUInt64LE(8);
+                const insured = disc.equals(IX.burnXencatInsured);
+                const stealth = disc.equals(IX.burnToStealth);
+                const withDepth = disc.equals(IX.burnXencatWithDepth);
+                const to = disc.equals(IX.burnXencatTo);
+                // BurnXencat: user, global_state, burn_record, mint, user_token_account, ...
+                // BurnXencatInsured: user, global_state, burn_record, burn_insurance, fund, vault, mint, ...
+                const mint = ix.keys[insured ? 6 : 3]?.pubkey;
+                const burnRecord = ix.keys[2]?.pubkey;
+
+                // Irreversible: tokens are only re-minted on X1 after attestation
+                let description = `Burn ${formatAmount(amount)} tokens of mint ${mint?.toBase58()} on Solana (irreversible)`;
+                if (insured) {
+                    description += ' with transfer insurance (premium paid from the same token account)';
+                    summary.accountsCreated.push({ address: ix.keys[3].pubkey, label: 'BurnInsurance (Solana, rent paid by you)' });
+                }
+                if (stealth) {
+                    const stealthKey = new PublicKey(data.subarray(16, 48));
+                    description += ` for stealth recipient ${stealthKey.toBase58()}`;
+                    if (stealthKey.equals(ix.keys[0].pubkey)) {
+                        warn('Stealth key equals the burner - the program rejects this (InvalidStealthKey)');
+                    }
+                }
+                if (to) {
+                    // amount(8) || destination(32) || memo: Option<[u8; 64]>
+                    const destination = new PublicKey(data.subarray(16, 48));
+                    description += ` for X1 destination ${destination.toBase58()}`;
+                    if (data[48] === 1) {
+                        description += ' with a memo';
+                    }
+                    if (destination.equals(PublicKey.default)) {
+                        warn('Destination is the default key - the program rejects this (InvalidRecipient)');
+                    } else if (!destination.equals(ix.keys[0].pubkey)) {
+                        warn('Tokens are minted on X1 to the destination, not to your own wallet');
+                    }
+                }
+                if (withDepth) {
+                    const depth = data.readUInt16LE(16);
+                    description += `, attested after ${depth} Solana slots (~${Math.ceil(depth * 0.4)}s) instead of the default`;
+                }
+                action('burn', description);
+                if (burnRecord) {
+                    summary.accountsCreated.push({ address: burnRecord, label: 'BurnRecord (Solana, rent paid by you)' });
+                }
+                if (amount === 0n) {
+                    warn('Burn amount is 0 - the program rejects this (InvalidAmount)');
+                }
+            } else if (disc.equals(IX.burnRegisteredAsset)) {
+                // asset_id(1) || amount(8); accounts: user, global_state, burn_record, registered_asset, mint, ...
+                const assetId = data[8];
+                const amount = data.readBigUInt64LE(9);
+                action('burn', `Burn ${formatAmount(amount)} ${assetName(assetId)} (mint ${ix.keys[4]?.pubkey.toBase58()}) on Solana (irreversible)`);
+                if (ix.keys[2]) {
+                    summary.accountsCreated.push({ address: ix.keys[2].pubkey, label: 'BurnRecord (Solana, rent paid by you)' });
+                }
+                if (ix.keys[8]) {
+                    summary.accountsCreated.push({ address: ix.keys[8].pubkey, label: 'Wormhole message (Solana, rent paid by you)' });
+                }
+                if (amount === 0n) {
+                    warn('Burn amount is 0 - the program rejects this (InvalidAmount)');
+                }
+            } else {
+                action('unknown', 'Other burn program instruction');
+            }
+            continue;
+        }
+
+        if (ix.programId.equals(programs.lightClient) && disc.equals(IX.submitBurnAttestationV3)) {
+            const assetId = data[8];
+            const burnNonce = data.readBigUInt64LE(9);
+            // BurnAttestationDataV3: asset_id, burn_nonce, user, amount, version, burn_slot, burn_timestamp, attestations
+            const attAssetId = data[17];
+            const attNonce = data.readBigUInt64LE(18);
+            const user = new PublicKey(data.subarray(26, 58));
+            const amount = data.readBigUInt64LE(58);
+            const version = Number(data.readBigUInt64LE(66));
+            const count = data.readUInt32LE(90);
+            const signers: string[] = [];
+            for (let i = 0; i < count; i++) {
+                signers.push(new PublicKey(data.subarray(94 + 104 * i, 126 + 104 * i)).toBase58());
+            }
+
+            action('submit_attestations',
+                `Verify ${formatAmount(amount)} ${assetName(assetId)} burn #${burnNonce} for ${user.toBase58()} with ${count} validator attestations`);
+            // Accounts: user, validator_set, verified_burn, latency_stats, system_program
+            if (ix.keys[2]) {
+                summary.accountsCreated.push({ address: ix.keys[2].pubkey, label: 'VerifiedBurnV3 (X1, rent paid by you)' });
+            }
+
+            if (attAssetId !== assetId || attNonce !== burnNonce) {
+                warn('Attestation asset/nonce does not match the instruction arguments (InvalidAttestation)');
+            }
+            if (new Set(signers).size !== signers.length) {
+                warn('Duplicate validator attestations (DuplicateValidator)');
+            }
+            if (connection && ix.keys[1]) {
+                const set = await fetchValidatorSet(connection, ix.keys[1].pubkey);
+                if (set) {
+                    if (set.version !== version) {
+                        warn(`Validator set version ${version} is stale (current ${set.version})`);
+                    }
+                    const known = signers.filter(s => set.validators.some(v => v.toBase58() === s)).length;
+                    if (known < set.threshold) {
+                        warn(`Only ${known} attestations from current validators, threshold is ${set.threshold}`);
+                    }
+                }
+                if (ix.keys[2] && await accountExists(connection, ix.keys[2].pubkey)) {
+                    warn('Burn is already verified - this transaction will fail (account already in use)');
+                }
+            }
+            continue;
+        }
+
+        const mintAssetId = ix.programId.equals(programs.xencatMint) ? 1 : ix.programId.equals(programs.dgnMint) ? 2 : 0;
+        if (mintAssetId && disc.equals(IX.mintFromBurnV3)) {
+            const burnNonce = data.readBigUInt64LE(8);
+            const assetId = data[16];
+            // Accounts: mint_state, mint, processed_burn, user_token_account, user, validator_set, verified_burn,
+            // feature_gate, latency_stats, cosign_policy, token_program, system_program, then one fee account
+            // per validator and, for large mints under a co-sign policy, the co-signer (a signer)
+            const validatorAccounts = ix.keys.slice(12).filter(a => !a.isSigner);
+            const cosigners = ix.keys.slice(12).filter(a => a.isSigner);
+
+            if (assetId !== mintAssetId) {
+                warn(`${assetName(mintAssetId)} mint program cannot mint asset_id=${assetId} (AssetNotMintable)`);
+            }
+
+            let description = `Mint ${assetName(assetId)} for burn #${burnNonce} into ${ix.keys[3]?.pubkey.toBase58()}`;
+            summary.accountsCreated.push({ address: ix.keys[2].pubkey, label: 'ProcessedBurnV3 (X1, rent paid by you)' });
+
+            if (connection) {
+                const [verified, feePerValidator, set] = await Promise.all([
+                    fetchVerifiedBurn(connection, ix.keys[6].pubkey),
+                    fetchFeePerValidator(connection, ix.keys[0].pubkey),
+                    fetchValidatorSet(connection, ix.keys[5].pubkey),
+                ]);
+                if (!verified) {
+                    warn('Burn is not verified on X1 yet - submit attestations first');
+                } else {
+                    description = `Mint ${formatAmount(verified.amount)} ${assetName(assetId)} for burn #${burnNonce} into ${ix.keys[3]?.pubkey.toBase58()}`;
+                    if (verified.processed) {
+                        warn('Burn was already minted (ProofAlreadyProcessed)');
+                    }
+                    if (!verified.user.equals(ix.keys[4].pubkey)) {
+                        warn('Signer is not the user who burned (InvalidUser)');
+                    }
+                }
+                if (feePerValidator !== undefined && set) {
+                    summary.fees.validatorFees = feePerValidator * BigInt(set.validators.length);
+                    const missing = set.validators.filter(v => !validatorAccounts.some(a => a.pubkey.equals(v) && a.isWritable));
+                    if (missing.length > 0) {
+                        warn(`${missing.length} validator fee accounts missing or read-only (MissingValidatorAccount)`);
+                    }
+                }
+            }
+            action('mint', description);
+            if (validatorAccounts.length > 0) {
+                action('transfer', `Pay validator fees in XNT to ${validatorAccounts.length} validators`);
+            }
+            if (cosigners.length > 0) {
+                action('mint', `Large-mint co-signer: ${cosigners.map(a => a.pubkey.toBase58()).join(', ')}`);
+            }
+            continue;
+        }
+
+        if (ix.programId.equals(ASSOCIATED_TOKEN_PROGRAM_ID)) {
+            // Accounts: payer, associated_token, owner, mint, ...
+            action('create_token_account', `Create token account ${ix.keys[1].pubkey.toBase58()} for mint ${ix.keys[3].pubkey.toBase58()}`);
+            summary.accountsCreated.push({ address: ix.keys[1].pubkey, label: 'Associated token account (rent paid by you)' });
+            continue;
+        }
+
+        if (ix.programId.equals(SystemProgram.programId) && data.readUInt32LE(0) === 2) {
+            action('transfer', `Transfer ${formatXnt(data.readBigUInt64LE(4))} to ${ix.keys[1].pubkey.toBase58()}`);
+            continue;
+        }
+
+        action('unknown', `Unrecognized instruction for program ${ix.programId.toBase58()}`);
+        warn(`Unrecognized program ${ix.programId.toBase58()} - make sure you trust it`);
+    }
+
+    if (connection && summary.actions.some(a => a.kind === 'burn')) {
+        // Planned maintenance posted by governance on the X1 notice board
+        const notice = noticeWarning(await fetchNotice(connection, programs.lightClient), await connection.getSlot());
+        if (notice) {
+            warn(notice);
+        }
+    }
+
+    summary.fees.priorityFee = Math.ceil((microLamportsPerCu * computeUnits) / 1_000_000);
+
+    summary.lines = [
+        ...summary.actions.map(a => `• ${a.description}`),
+        ...summary.accountsCreated.map(a => `+ ${a.label}: ${a.address.toBase58()}`),
+        `Network fee: ${summary.fees.networkFee} lamports` +
+            (summary.fees.priorityFee ? ` + ${summary.fees.priorityFee} priority` : ''),
+        ...(summary.fees.validatorFees !== undefined ? [`Validator fees: ${formatXnt(summary.fees.validatorFees)}`] : []),
+        ...summary.warnings.map(w => `⚠️  ${w}`),
+    ];
+
+    return summary;
+}
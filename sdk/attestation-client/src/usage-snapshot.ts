@@ -0,0 +1,146 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Usage snapshots (light client UsageSnapshot)
+ *
+ * Validators commit, per ended usage epoch, the Merkle root of every
+ * user's bridged volume per asset (summed BurnVerified amounts). Airdrop
+ * and points programs verify a user's entry with a proof against the root
+ * (verify_usage_proof) instead of trusting an indexer.
+ *
+ * Leaves: sha256(epoch || user || asset_id || volume), little-endian
+ * integers, sorted by user bytes then asset id. The tree is fixed at depth
+ * 20 with zero leaves as padding; parents are sha256(left || right).
+ *
+ * Layout: discriminator(8) || epoch(8) || root(32) || leaf_count(8) || committed_slot(8) || bump(1)
+ */
+
+/** Must match config::USAGE_EPOCH_SLOTS and USAGE_TREE_DEPTH */
+export const USAGE_EPOCH_SLOTS = 432_000;
+export const USAGE_TREE_DEPTH = 20;
+
+export interface UsageEntry {
+    user: PublicKey;
+    assetId: number;
+    /** base units */
+    volume: bigint;
+}
+
+export interface UsageSnapshot {
+    epoch: number;
+    root: Buffer;
+    leafCount: number;
+    committedSlot: number;
+}
+
+export interface UsageTree {
+    root: Buffer;
+    /** Entries in leaf order */
+    entries: UsageEntry[];
+    /** USAGE_TREE_DEPTH sibling hashes of entries[index], leaf first */
+    proof(index: number): Buffer[];
+}
+
+function sha256(...parts: Buffer[]): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat(parts)).digest();
+}
+
+function u64(value: number | bigint): Buffer {
+    const buf = Buffer.alloc(8);
+    buf.writeBigUInt64LE(BigInt(value));
+    return buf;
+}
+
+export function usageEpoch(slot: number): number {
+    return Math.floor(slot / USAGE_EPOCH_SLOTS);
+}
+
+export function usageSnapshotAddress(lightClientProgramId: PublicKey, epoch: number): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('usage_snapshot'), u64(epoch)], lightClientProgramId)[0];
+}
+
+/** Must match usage_leaf on-chain */
+export function usageLeaf(epoch: number, entry: UsageEntry): Buffer {
+    return sha256(u64(epoch), entry.user.toBuffer(), Buffer.from([entry.assetId]), u64(entry.volume));
+}
+
+/** Sum BurnVerified amounts into one entry per user and asset, in leaf order */
+export function aggregateUsage(burns: Array<{ user: PublicKey; assetId: number; amount: bigint }>): UsageEntry[] {
+    const byKey = new Map<string, UsageEntry>();
+    for (const burn of burns) {
+        const key = `${burn.user.toBuffer().toString('hex')}:${burn.assetId}`;
+        const entry = byKey.get(key) ?? { user: burn.user, assetId: burn.assetId, volume: 0n };
+        entry.volume += burn.amount;
+        if (entry.volume >= 1n << 64n) {
+            throw new Error(`Volume of ${burn.user.toBase58()} (asset ${burn.assetId}) overflows u64`);
+        }
+        byKey.set(key, entry);
+    }
+    return [...byKey.values()].sort((a, b) =>
+        Buffer.compare(a.user.toBuffer(), b.user.toBuffer()) || a.assetId - b.assetId);
+}
+
+/** Zero-padded tree over `entries` (already in leaf order, see aggregateUsage) */
+export function buildUsageTree(epoch: number, entries: UsageEntry[]): UsageTree {
+    if (entries.length > 2 ** USAGE_TREE_DEPTH) {
+        throw new Error(`${entries.length} entries exceed the tree (${2 ** USAGE_TREE_DEPTH})`);
+    }
+    // Every level, leaves first (only non-empty nodes stored)
+    const levels: Buffer[][] = [entries.map(e => usageLeaf(epoch, e))];
+    const zeros: Buffer[] = [Buffer.alloc(32)];
+    for (let height = 0; height < USAGE_TREE_DEPTH; height++) {
+        const level = levels[height];
+        const next: Buffer[] = [];
+        for (let i = 0; i < level.length; i += 2) {
+            next.push(sha256(level[i], level[i + 1] ?? zeros[height]));
+        }
+        levels.push(next);
+        zeros.push(sha256(zeros[height], zeros[height]));
+    }
+    return {
+        root: levels[USAGE_TREE_DEPTH][0] ?? zeros[USAGE_TREE_DEPTH],
+        entries,
+        proof(index: number): Buffer[] {
+            return Array.from({ length: USAGE_TREE_DEPTH }, (_, height) =>
+                levels[height][(index >> height) ^ 1] ?? zeros[height]);
+        },
+    };
+}
+
+/** Must match UsageSnapshot::verify on-chain */
+export function verifyUsageProof(snapshot: UsageSnapshot, entry: UsageEntry, index: number, proof: Buffer[]): boolean {
+    if (index >= snapshot.leafCount || proof.length !== USAGE_TREE_DEPTH) {
+        return false;
+    }
+    const node = proof.reduce((node, sibling, height) =>
+        (index >> height) & 1 ? sha256(sibling, node) : sha256(node, sibling), usageLeaf(snapshot.epoch, entry));
+    return node.equals(snapshot.root);
+}
+
+/** Must match create_usage_snapshot_message on-chain */
+export function usageSnapshotMessage(validatorSetVersion: number, epoch: number, root: Buffer, leafCount: number, x1GenesisHash: Buffer | null): Buffer {
+    return sha256(Buffer.from('USAGE_SNAPSHOT'), u64(validatorSetVersion), u64(epoch), root, u64(leafCount), ...(x1GenesisHash ? [x1GenesisHash] : []));
+}
+
+export function decodeUsageSnapshot(data: Buffer): UsageSnapshot {
+    return {
+        epoch: Number(data.readBigUInt64LE(8)),
+        root: Buffer.from(data.subarray(16, 48)),
+        leafCount: Number(data.readBigUInt64LE(48)),
+        committedSlot: Number(data.readBigUInt64LE(56)),
+    };
+}
+
+/** Committed snapshot of `epoch` (null until validators commit it) */
+export async function fetchUsageSnapshot(
+    connection: Connection,
+    lightClientProgramId: PublicKey,
+    epoch: number
+): Promise<UsageSnapshot | null> {
+    const info = await connection.getAccountInfo(usageSnapshotAddress(lightClientProgramId, epoch));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeUsageSnapshot(info.data);
+}
@@ -0,0 +1,95 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * X1 validator set history (audit trail of X1ValidatorSet updates)
+ *
+ * Once initialized, update_validator_set and execute_validator_set_update
+ * record each transition in a ring buffer of the last 16: the version it
+ * produced, the membership hash before and after, the new thresholds, the
+ * approving validators and when it was applied. Restores are not recorded.
+ *
+ * Layout: discriminator(8) || records(vec<record>) || next_index(u8) || total_updates(u64) || bump(1)
+ *   record: version(u64) || old_validators_hash(32) || new_validators_hash(32) || threshold(u8)
+ *     || threshold_bps(u16) || approvers(vec<pubkey>) || timestamp(i64) || slot(u64)
+ */
+export interface X1ValidatorSetRecord {
+    version: number;
+    oldValidatorsHash: Buffer;
+    newValidatorsHash: Buffer;
+    threshold: number;
+    thresholdBps: number;
+    approvers: PublicKey[];
+    timestamp: number;
+    slot: number;
+}
+
+export interface X1ValidatorSetHistory {
+    /** Oldest first */
+    records: X1ValidatorSetRecord[];
+    totalUpdates: number;
+}
+
+export function x1ValidatorSetHistoryAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_history')], lightClientProgramId)[0];
+}
+
+/** Must match X1ValidatorSet::members_hash: sha256(validators || weights), in set order */
+export function validatorSetMembersHash(validators: PublicKey[], weights: number[] = []): Buffer {
+    const weightBytes = Buffer.alloc(weights.length * 2);
+    weights.forEach((weight, i) => weightBytes.writeUInt16LE(weight, i * 2));
+    return crypto.createHash('sha256')
+        .update(Buffer.concat([...validators.map(v => v.toBuffer()), weightBytes]))
+        .digest();
+}
+
+export function decodeX1ValidatorSetHistory(data: Buffer): X1ValidatorSetHistory {
+    let offset = 8;
+    const count = data.readUInt32LE(offset);
+    offset += 4;
+    const records: X1ValidatorSetRecord[] = [];
+    for (let i = 0; i < count; i++) {
+        const version = Number(data.readBigUInt64LE(offset));
+        const oldValidatorsHash = data.subarray(offset + 8, offset + 40);
+        const newValidatorsHash = data.subarray(offset + 40, offset + 72);
+        const threshold = data[offset + 72];
+        const thresholdBps = data.readUInt16LE(offset + 73);
+        const approverCount = data.readUInt32LE(offset + 75);
+        offset += 79;
+        const approvers: PublicKey[] = [];
+        for (let j = 0; j < approverCount; j++, offset += 32) {
+            approvers.push(new PublicKey(data.subarray(offset, offset + 32)));
+        }
+        records.push({
+            version,
+            oldValidatorsHash,
+            newValidatorsHash,
+            threshold,
+            thresholdBps,
+            approvers,
+            timestamp: Number(data.readBigInt64LE(offset)),
+            slot: Number(data.readBigUInt64LE(offset + 8)),
+        });
+        offset += 16;
+    }
+    const nextIndex = data[offset];
+    const totalUpdates = Number(data.readBigUInt64LE(offset + 1));
+    // Once the buffer wrapped, next_index points at the oldest record
+    return { records: [...records.slice(nextIndex), ...records.slice(0, nextIndex)], totalUpdates };
+}
+
+/**
+ * The light client's validator set history (null = not initialized)
+ */
+export async function fetchX1ValidatorSetHistory(connection: Connection, lightClientProgramId: PublicKey): Promise<X1ValidatorSetHistory | null> {
+    const info = await connection.getAccountInfo(x1ValidatorSetHistoryAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeX1ValidatorSetHistory(info.data);
+}
+
+/** The recorded transition that produced `version` (null if never recorded or overwritten) */
+export function validatorSetRecordForVersion(history: X1ValidatorSetHistory, version: number): X1ValidatorSetRecord | null {
+    return history.records.find(record => record.version === version) ?? null;
+}
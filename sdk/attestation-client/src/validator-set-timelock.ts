@@ -0,0 +1,186 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import * as crypto from 'crypto';
+
+/**
+ * Timelocked validator set updates
+ *
+ * Once the ValidatorSetTimelock account exists, update_validator_set is
+ * refused. A new set is proposed with threshold approvals
+ * (propose_validator_set_update), stored in the PendingValidatorSetUpdate
+ * PDA, and anyone can execute it after the delay (48h by default). Until
+ * then the current validators can cancel it. The proposal only executes
+ * against the set version that approved it.
+ *
+ * Layouts:
+ *   ValidatorSetTimelock: discriminator(8) || delay_seconds(i64) || updated_slot(u64) || bump(1)
+ *   PendingValidatorSetUpdate: discriminator(8) || base_version(u64) || validators(vec<pubkey>)
+ *     || threshold(u8) || weights(vec<u16>) || threshold_bps(u16) || approvals(u8)
+ *     || proposer(32) || proposed_at(i64) || executable_at(i64) || approvers(vec<pubkey>) || bump(1)
+ */
+
+/** Must match ValidatorSetTimelock's governance bounds */
+export const MIN_VALIDATOR_SET_UPDATE_DELAY = 86_400;
+export const MAX_VALIDATOR_SET_UPDATE_DELAY = 2_592_000;
+
+export interface ValidatorSetTimelock {
+    delaySeconds: number;
+    updatedSlot: number;
+}
+
+export interface PendingValidatorSetUpdate {
+    baseVersion: number;
+    validators: PublicKey[];
+    threshold: number;
+    weights: number[];
+    thresholdBps: number;
+    approvals: number;
+    proposer: PublicKey;
+    proposedAt: number;
+    executableAt: number;
+    /** Current validators whose signatures approved it */
+    approvers: PublicKey[];
+}
+
+export function validatorSetTimelockAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('validator_set_timelock')], lightClientProgramId)[0];
+}
+
+export function pendingValidatorSetUpdateAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('validator_set_update')], lightClientProgramId)[0];
+}
+
+export function decodeValidatorSetTimelock(data: Buffer): ValidatorSetTimelock {
+    return {
+        delaySeconds: Number(data.readBigInt64LE(8)),
+        updatedSlot: Number(data.readBigUInt64LE(16)),
+    };
+}
+
+export function decodePendingValidatorSetUpdate(data: Buffer): PendingValidatorSetUpdate {
+    let offset = 8;
+    const baseVersion = Number(data.readBigUInt64LE(offset));
+    offset += 8;
+    const validatorCount = data.readUInt32LE(offset);
+    offset += 4;
+    const validators: PublicKey[] = [];
+    for (let i = 0; i < validatorCount; i++, offset += 32) {
+        validators.push(new PublicKey(data.subarray(offset, offset + 32)));
+    }
+    const threshold = data[offset++];
+    const weightCount = data.readUInt32LE(offset);
+    offset += 4;
+    const weights: number[] = [];
+    for (let i = 0; i < weightCount; i++, offset += 2) {
+        weights.push(data.readUInt16LE(offset));
+    }
+    const thresholdBps = data.readUInt16LE(offset);
+    const approvals = data[offset + 2];
+    const proposer = new PublicKey(data.subarray(offset + 3, offset + 35));
+    const approverCount = data.readUInt32LE(offset + 51);
+    const approvers: PublicKey[] = [];
+    for (let i = 0; i < approverCount; i++) {
+        approvers.push(new PublicKey(data.subarray(offset + 55 + i * 32, offset + 87 + i * 32)));
+    }
+    return {
+        baseVersion,
+        validators,
+        threshold,
+        weights,
+        thresholdBps,
+        approvals,
+        proposer,
+        proposedAt: Number(data.readBigInt64LE(offset + 35)),
+        executableAt: Number(data.readBigInt64LE(offset + 43)),
+        approvers,
+    };
+}
+
+/**
+ * The light client's update timelock (null = not created, instant updates allowed)
+ */
+export async function fetchValidatorSetTimelock(connection: Connection, lightClientProgramId: PublicKey): Promise<ValidatorSetTimelock | null> {
+    const info = await connection.getAccountInfo(validatorSetTimelockAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeValidatorSetTimelock(info.data);
+}
+
+/**
+ * The proposed update waiting out the timelock (null = none)
+ */
+export async function fetchPendingValidatorSetUpdate(connection: Connection, lightClientProgramId: PublicKey): Promise<PendingValidatorSetUpdate | null> {
+    const info = await connection.getAccountInfo(pendingValidatorSetUpdateAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodePendingValidatorSetUpdate(info.data);
+}
+
+const sha256 = (...parts: Buffer[]) => crypto.createHash('sha256').update(Buffer.concat(parts)).digest();
+
+const u64 = (value: bigint | number) => {
+    const buffer = Buffer.alloc(8);
+    buffer.writeBigUInt64LE(BigInt(value));
+    return buffer;
+};
+
+const i64 = (value: number) => {
+    const buffer = Buffer.alloc(8);
+    buffer.writeBigInt64LE(BigInt(value));
+    return buffer;
+};
+
+export interface ValidatorSetUpdate {
+    validators: PublicKey[];
+    threshold: number;
+    /** Empty for an unweighted set */
+    weights: number[];
+    /** 0 for an unweighted set */
+    thresholdBps: number;
+}
+
+/** Must match create_update_message in the light client (update_validator_set) */
+export function validatorSetUpdateMessage(
+    validatorSetVersion: bigint | number,
+    update: ValidatorSetUpdate,
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    const parts = [Buffer.from('VALIDATOR_UPDATE'), u64(validatorSetVersion), ...update.validators.map(v => v.toBuffer()), Buffer.from([update.threshold])];
+    if (update.thresholdBps !== 0) {
+        const weights = Buffer.alloc(2 * update.weights.length + 2);
+        update.weights.forEach((weight, i) => weights.writeUInt16LE(weight, 2 * i));
+        weights.writeUInt16LE(update.thresholdBps, 2 * update.weights.length);
+        parts.push(Buffer.from('WEIGHTS'), weights);
+    }
+    if (x1GenesisHash) {
+        parts.push(x1GenesisHash);
+    }
+    return sha256(...parts);
+}
+
+/** Must match create_proposal_message in the light client (propose_validator_set_update) */
+export function validatorSetProposalMessage(
+    validatorSetVersion: bigint | number,
+    configNonce: bigint | number,
+    update: ValidatorSetUpdate,
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    return sha256(
+        Buffer.from('PROPOSE_VALIDATOR_SET_UPDATE'),
+        u64(validatorSetVersion),
+        u64(configNonce),
+        validatorSetUpdateMessage(validatorSetVersion, update, x1GenesisHash),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    );
+}
+
+/** Must match cancel_validator_set_update in the light client */
+export function cancelValidatorSetUpdateMessage(validatorSetVersion: bigint | number, configNonce: bigint | number, proposedAt: number, x1GenesisHash: Buffer | null): Buffer {
+    return sha256(Buffer.from('CANCEL_VALIDATOR_SET_UPDATE'), u64(validatorSetVersion), u64(configNonce), i64(proposedAt), ...(x1GenesisHash ? [x1GenesisHash] : []));
+}
+
+/** Must match create_update_delay_message in the light client */
+export function validatorSetUpdateDelayMessage(validatorSetVersion: bigint | number, configNonce: bigint | number, delaySeconds: number, x1GenesisHash: Buffer | null): Buffer {
+    return sha256(Buffer.from('SET_VALIDATOR_SET_UPDATE_DELAY'), u64(validatorSetVersion), u64(configNonce), i64(delaySeconds), ...(x1GenesisHash ? [x1GenesisHash] : []));
+}
@@ -0,0 +1,584 @@
+import * as os from 'os';
+import { postSigned, newTraceContext, ValidatorEndpoint } from './transport';
+import type { Attestation } from './index';
+
+/**
+ * Shared attestation work queue (coordinator failover)
+ *
+ * Several coordinator/relayer instances share one queue of attestation
+ * jobs, backed by Redis or Postgres. A worker claims a job with a lease,
+ * renews the lease while it collects, and persists every attestation as
+ * soon as it arrives. If the instance crashes the lease runs out and
+ * another instance claims the job, keeping the attestations already
+ * collected and only asking the validators still missing. A job is `ready`
+ * once it holds `threshold` attestations; whoever submits the transfer
+ * reads the bundle with `awaitBundle`.
+ *
+ * Leases compare wall-clock times written by different hosts, so keep
+ * instance clocks in sync (NTP) to well within the lease length.
+ */
+
+export type JobStatus = 'pending' | 'collecting' | 'ready' | 'failed';
+
+export interface JobRequest {
+    asset_id: number;
+    burn_nonce: number;
+    user: string;
+    expected_amount: number;
+    validator_set_version: number;
+}
+
+export interface AttestationJob extends JobRequest {
+    id: string;
+    status: JobStatus;
+    /** Collected so far, one per validator, all for the same burn slot/time */
+    attestations: Attestation[];
+    /** Times the job was claimed */
+    attempts: number;
+    lease_owner: string | null;
+    /** Unix ms */
+    lease_expires_at: number | null;
+    error: string | null;
+    created_at: number;
+    updated_at: number;
+}
+
+export interface WorkQueue {
+    /** Add a job (no-op if it exists, unless it failed: then it is retried) */
+    enqueue(request: JobRequest): Promise<AttestationJob>;
+    get(id: string): Promise<AttestationJob | null>;
+    /** Take the oldest pending job, or one whose lease expired */
+    claim(worker: string, leaseMs: number): Promise<AttestationJob | null>;
+    /** Extend the lease; false if the worker no longer holds it */
+    renew(id: string, worker: string, leaseMs: number): Promise<boolean>;
+    /** Merge attestations into the job; null if the worker no longer holds the lease */
+    addAttestations(id: string, worker: string, attestations: Attestation[]): Promise<AttestationJob | null>;
+    /** Mark the bundle ready and drop the lease */
+    complete(id: string, worker: string): Promise<boolean>;
+    /** Give the job back (pending), or fail it after max attempts */
+    release(id: string, worker: string, error: string): Promise<void>;
+}
+
+export function jobId(assetId: number, burnNonce: number, user: string): string {
+    return `${assetId}:${burnNonce}:${user}`;
+}
+
+/**
+ * Merge attestations: one per validator, all signing the same burn
+ * slot/time and confirmed slot as the first one kept (the program
+ * rejects mixed bundles)
+ */
+export function mergeAttestations(existing: Attestation[], incoming: Attestation[]): Attestation[] {
+    const merged = [...existing];
+    for (const attestation of incoming) {
+        const first = merged[0];
+        if (first && (
+            first.solana_burn_slot !== attestation.solana_burn_slot ||
+            first.solana_burn_timestamp !== attestation.solana_burn_timestamp ||
+            first.solana_confirmed_slot !== attestation.solana_confirmed_slot
+        )) {
+            continue;
+        }
+        if (!merged.some(a => a.validator_pubkey === attestation.validator_pubkey)) {
+            merged.push(attestation);
+        }
+    }
+    return merged;
+}
+
+// ============================================================================
+// POSTGRES
+// ============================================================================
+
+/** Anything with pg.Pool's query() */
+export interface SqlClient {
+    query(text: string, values?: unknown[]): Promise<{ rows: any[] }>;
+}
+
+/** Condition for writes by the lease holder ($1 = id, $2 = worker, $3 = now) */
+const PG_HOLDS_LEASE = `id = $1 AND lease_owner = $2 AND status = 'collecting' AND lease_expires_at >= $3`;
+
+export class PostgresWorkQueue implements WorkQueue {
+    constructor(
+        private readonly client: SqlClient,
+        private readonly maxAttempts: number,
+        private readonly table = 'xencat_attestation_jobs'
+    ) {}
+
+    async init(): Promise<void> {
+        await this.client.query(`
+            CREATE TABLE IF NOT EXISTS ${this.table} (
+                id TEXT PRIMARY KEY,
+                asset_id SMALLINT NOT NULL,
+                burn_nonce BIGINT NOT NULL,
+                "user" TEXT NOT NULL,
+                expected_amount BIGINT NOT NULL,
+                validator_set_version BIGINT NOT NULL,
+                status TEXT NOT NULL,
+                attestations JSONB NOT NULL DEFAULT '[]',
+                attempts INTEGER NOT NULL DEFAULT 0,
+                lease_owner TEXT,
+                lease_expires_at BIGINT,
+                error TEXT,
+                created_at BIGINT NOT NULL,
+                updated_at BIGINT NOT NULL
+            )`);
+        await this.client.query(
+            `CREATE INDEX IF NOT EXISTS ${this.table}_claim ON ${this.table} (status, created_at)`
+        );
+    }
+
+    private static toJob(row: any): AttestationJob {
+        return {
+            id: row.id,
+            asset_id: Number(row.asset_id),
+            burn_nonce: Number(row.burn_nonce),
+            user: row.user,
+            expected_amount: Number(row.expected_amount),
+            validator_set_version: Number(row.validator_set_version),
+            status: row.status,
+            attestations: row.attestations,
+            attempts: row.attempts,
+            lease_owner: row.lease_owner,
+            lease_expires_at: row.lease_expires_at === null ? null : Number(row.lease_expires_at),
+            error: row.error,
+            created_at: Number(row.created_at),
+            updated_at: Number(row.updated_at),
+        };
+    }
+
+    async enqueue(request: JobRequest): Promise<AttestationJob> {
+        const now = Date.now();
+        const id = jobId(request.asset_id, request.burn_nonce, request.user);
+        await this.client.query(
+            `INSERT INTO ${this.table}
+                (id, asset_id, burn_nonce, "user", expected_amount, validator_set_version, status, created_at, updated_at)
+             VALUES ($1, $2, $3, $4, $5, $6, 'pending', $7, $7)
+             ON CONFLICT (id) DO UPDATE SET status = 'pending', attempts = 0, error = NULL, updated_at = $7
+             WHERE ${this.table}.status = 'failed'`,
+            [id, request.asset_id, request.burn_nonce, request.user, request.expected_amount, request.validator_set_version, now]
+        );
+        return (await this.get(id))!;
+    }
+
+    async get(id: string): Promise<AttestationJob | null> {
+        const { rows } = await this.client.query(`SELECT * FROM ${this.table} WHERE id = $1`, [id]);
+        return rows.length ? PostgresWorkQueue.toJob(rows[0]) : null;
+    }
+
+    async claim(worker: string, leaseMs: number): Promise<AttestationJob | null> {
+        const now = Date.now();
+        // Abandoned jobs that used up their attempts
+        await this.client.query(
+            `UPDATE ${this.table}
+             SET status = 'failed', error = 'lease expired after max attempts', lease_owner = NULL, lease_expires_at = NULL, updated_at = $1
+             WHERE status = 'collecting' AND lease_expires_at < $1 AND attempts >= $2`,
+            [now, this.maxAttempts]
+        );
+        const { rows } = await this.client.query(
+            `UPDATE ${this.table}
+             SET status = 'collecting', lease_owner = $1, lease_expires_at = $2, attempts = attempts + 1, updated_at = $3
+             WHERE id = (
+                 SELECT id FROM ${this.table}
+                 WHERE status = 'pending' OR (status = 'collecting' AND lease_expires_at < $3)
+                 ORDER BY created_at
+                 FOR UPDATE SKIP LOCKED
+                 LIMIT 1
+             )
+             RETURNING *`,
+            [worker, now + leaseMs, now]
+        );
+        return rows.length ? PostgresWorkQueue.toJob(rows[0]) : null;
+    }
+
+    async renew(id: string, worker: string, leaseMs: number): Promise<boolean> {
+        const now = Date.now();
+        const { rows } = await this.client.query(
+            `UPDATE ${this.table} SET lease_expires_at = $4, updated_at = $3 WHERE ${PG_HOLDS_LEASE} RETURNING id`,
+            [id, worker, now, now + leaseMs]
+        );
+        return rows.length > 0;
+    }
+
+    async addAttestations(id: string, worker: string, attestations: Attestation[]): Promise<AttestationJob | null> {
+        // Only the lease holder writes, so read-merge-write is safe
+        const job = await this.get(id);
+        if (!job) {
+            return null;
+        }
+        const merged = mergeAttestations(job.attestations, attestations);
+        const { rows } = await this.client.query(
+            `UPDATE ${this.table} SET attestations = $4, updated_at = $3 WHERE ${PG_HOLDS_LEASE} RETURNING *`,
+            [id, worker, Date.now(), JSON.stringify(merged)]
+        );
+        return rows.length ? PostgresWorkQueue.toJob(rows[0]) : null;
+    }
+
+    async complete(id: string, worker: string): Promise<boolean> {
+        const { rows } = await this.client.query(
+            `UPDATE ${this.table}
+             SET status = 'ready', lease_owner = NULL, lease_expires_at = NULL, error = NULL, updated_at = $3
+             WHERE ${PG_HOLDS_LEASE} RETURNING id`,
+            [id, worker, Date.now()]
+        );
+        return rows.length > 0;
+    }
+
+    async release(id: string, worker: string, error: string): Promise<void> {
+        await this.client.query(
+            `UPDATE ${this.table}
+             SET status = CASE WHEN attempts >= $5 THEN 'failed' ELSE 'pending' END,
+                 lease_owner = NULL, lease_expires_at = NULL, error = $4, updated_at = $3
+             WHERE ${PG_HOLDS_LEASE}`,
+            [id, worker, Date.now(), error, this.maxAttempts]
+        );
+    }
+}
+
+// ============================================================================
+// REDIS
+// ============================================================================
+
+/** Anything with ioredis' eval() and hgetall() */
+export interface RedisClient {
+    eval(script: string, numKeys: number, ...args: Array<string | number>): Promise<unknown>;
+    hgetall(key: string): Promise<Record<string, string>>;
+}
+
+/**
+ * Keys: <prefix>:job:<id> (hash), <prefix>:pending (zset by created_at),
+ * <prefix>:leases (zset by lease expiry). All state changes are Lua
+ * scripts, so they are atomic across instances.
+ */
+const REDIS_ENQUEUE = `
+local status = redis.call('HGET', KEYS[1], 'status')
+if status and status ~= 'failed' then return 0 end
+redis.call('HSET', KEYS[1], 'id', ARGV[1], 'asset_id', ARGV[2], 'burn_nonce', ARGV[3], 'user', ARGV[4],
+    'expected_amount', ARGV[5], 'validator_set_version', ARGV[6], 'status', 'pending', 'attempts', 0,
+    'error', '', 'updated_at', ARGV[7])
+if not status then
+    redis.call('HSET', KEYS[1], 'attestations', '[]', 'created_at', ARGV[7])
+end
+redis.call('ZADD', KEYS[2], redis.call('HGET', KEYS[1], 'created_at'), ARGV[1])
+return 1`;
+
+const REDIS_CLAIM = `
+local now = tonumber(ARGV[2])
+for _, id in ipairs(redis.call('ZRANGEBYSCORE', KEYS[2], '-inf', '(' .. now)) do
+    local key = ARGV[5] .. ':job:' .. id
+    redis.call('ZREM', KEYS[2], id)
+    redis.call('HSET', key, 'lease_owner', '', 'lease_expires_at', '')
+    if tonumber(redis.call('HGET', key, 'attempts')) >= tonumber(ARGV[4]) then
+        redis.call('HSET', key, 'status', 'failed', 'error', 'lease expired after max attempts', 'updated_at', now)
+    else
+        redis.call('HSET', key, 'status', 'pending')
+        redis.call('ZADD', KEYS[1], redis.call('HGET', key, 'created_at'), id)
+    end
+end
+local next = redis.call('ZRANGE', KEYS[1], 0, 0)[1]
+if not next then return false end
+local key = ARGV[5] .. ':job:' .. next
+redis.call('ZREM', KEYS[1], next)
+redis.call('HSET', key, 'status', 'collecting', 'lease_owner', ARGV[1], 'lease_expires_at', ARGV[3], 'updated_at', now)
+redis.call('HINCRBY', key, 'attempts', 1)
+redis.call('ZADD', KEYS[2], ARGV[3], next)
+return next`;
+
+/** Shared prefix of lease-holder writes: KEYS[1] = job, ARGV[1] = worker, ARGV[2] = now */
+const REDIS_HOLDS_LEASE = `
+if redis.call('HGET', KEYS[1], 'lease_owner') ~= ARGV[1]
+    or redis.call('HGET', KEYS[1], 'status') ~= 'collecting'
+    or tonumber(redis.call('HGET', KEYS[1], 'lease_expires_at')) < tonumber(ARGV[2]) then
+    return 0
+end`;
+
+const REDIS_RENEW = REDIS_HOLDS_LEASE + `
+redis.call('HSET', KEYS[1], 'lease_expires_at', ARGV[3], 'updated_at', ARGV[2])
+redis.call('ZADD', KEYS[2], ARGV[3], ARGV[4])
+return 1`;
+
+const REDIS_SET_ATTESTATIONS = REDIS_HOLDS_LEASE + `
+redis.call('HSET', KEYS[1], 'attestations', ARGV[3], 'updated_at', ARGV[2])
+return 1`;
+
+const REDIS_COMPLETE = REDIS_HOLDS_LEASE + `
+redis.call('HSET', KEYS[1], 'status', 'ready', 'lease_owner', '', 'lease_expires_at', '', 'error', '', 'updated_at', ARGV[2])
+redis.call('ZREM', KEYS[2], ARGV[3])
+return 1`;
+
+const REDIS_RELEASE = REDIS_HOLDS_LEASE + `
+redis.call('HSET', KEYS[1], 'lease_owner', '', 'lease_expires_at', '', 'error', ARGV[4], 'updated_at', ARGV[2])
+redis.call('ZREM', KEYS[2], ARGV[3])
+if tonumber(redis.call('HGET', KEYS[1], 'attempts')) >= tonumber(ARGV[5]) then
+    redis.call('HSET', KEYS[1], 'status', 'failed')
+else
+    redis.call('HSET', KEYS[1], 'status', 'pending')
+    redis.call('ZADD', KEYS[3], redis.call('HGET', KEYS[1], 'created_at'), ARGV[3])
+end
+return 1`;
+
+export class RedisWorkQueue implements WorkQueue {
+    constructor(
+        private readonly client: RedisClient,
+        private readonly maxAttempts: number,
+        private readonly prefix = 'xencat:attestation_jobs'
+    ) {}
+
+    private jobKey(id: string): string {
+        return `${this.prefix}:job:${id}`;
+    }
+
+    private get pendingKey(): string {
+        return `${this.prefix}:pending`;
+    }
+
+    private get leasesKey(): string {
+        return `${this.prefix}:leases`;
+    }
+
+    async enqueue(request: JobRequest): Promise<AttestationJob> {
+        const id = jobId(request.asset_id, request.burn_nonce, request.user);
+        await this.client.eval(
+            REDIS_ENQUEUE, 2, this.jobKey(id), this.pendingKey,
+            id, request.asset_id, request.burn_nonce, request.user,
+            request.expected_amount, request.validator_set_version, Date.now()
+        );
+        return (await this.get(id))!;
+    }
+
+    async get(id: string): Promise<AttestationJob | null> {
+        const hash = await this.client.hgetall(this.jobKey(id));
+        if (!hash || !hash.id) {
+            return null;
+        }
+        return {
+            id: hash.id,
+            asset_id: Number(hash.asset_id),
+            burn_nonce: Number(hash.burn_nonce),
+            user: hash.user,
+            expected_amount: Number(hash.expected_amount),
+            validator_set_version: Number(hash.validator_set_version),
+            status: hash.status as JobStatus,
+            attestations: JSON.parse(hash.attestations || '[]'),
+            attempts: Number(hash.attempts),
+            lease_owner: hash.lease_owner || null,
+            lease_expires_at: hash.lease_expires_at ? Number(hash.lease_expires_at) : null,
+            error: hash.error || null,
+            created_at: Number(hash.created_at),
+            updated_at: Number(hash.updated_at),
+        };
+    }
+
+    async claim(worker: string, leaseMs: number): Promise<AttestationJob | null> {
+        const now = Date.now();
+        const id = await this.client.eval(
+            REDIS_CLAIM, 2, this.pendingKey, this.leasesKey,
+            worker, now, now + leaseMs, this.maxAttempts, this.prefix
+        );
+        return typeof id === 'string' ? this.get(id) : null;
+    }
+
+    async renew(id: string, worker: string, leaseMs: number): Promise<boolean> {
+        const now = Date.now();
+        return (await this.client.eval(
+            REDIS_RENEW, 2, this.jobKey(id), this.leasesKey, worker, now, now + leaseMs, id
+        )) === 1;
+    }
+
+    async addAttestations(id: string, worker: string, attestations: Attestation[]): Promise<AttestationJob | null> {
+        // Only the lease holder writes, so read-merge-write is safe
+        const job = await this.get(id);
+        if (!job) {
+            return null;
+        }
+        const merged = mergeAttestations(job.attestations, attestations);
+        const written = await this.client.eval(
+            REDIS_SET_ATTESTATIONS, 1, this.jobKey(id), worker, Date.now(), JSON.stringify(merged)
+        );
+        return written === 1 ? { ...job, attestations: merged } : null;
+    }
+
+    async complete(id: string, worker: string): Promise<boolean> {
+        return (await this.client.eval(
+            REDIS_COMPLETE, 2, this.jobKey(id), this.leasesKey, worker, Date.now(), id
+        )) === 1;
+    }
+
+    async release(id: string, worker: string, error: string): Promise<void> {
+        await this.client.eval(
+            REDIS_RELEASE, 3, this.jobKey(id), this.leasesKey, this.pendingKey,
+            worker, Date.now(), id, error, this.maxAttempts
+        );
+    }
+}
+
+/**
+ * Open a queue from a connection URL. `pg` / `ioredis` are optional
+ * dependencies, loaded only for the backend in use.
+ */
+export async function connectWorkQueue(
+    backend: 'redis' | 'postgres',
+    url: string,
+    maxAttempts: number
+): Promise<{ queue: WorkQueue; close: () => Promise<void> }> {
+    if (backend === 'postgres') {
+        const { Pool } = require('pg');
+        const pool = new Pool({ connectionString: url });
+        const queue = new PostgresWorkQueue(pool, maxAttempts);
+        await queue.init();
+        return { queue, close: () => pool.end() };
+    }
+    const Redis = require('ioredis');
+    const client = new Redis(url);
+    return { queue: new RedisWorkQueue(client, maxAttempts), close: async () => { await client.quit(); } };
+}
+
+// ============================================================================
+// WORKER
+// ============================================================================
+
+export interface WorkerOptions {
+    /** Unique per instance (defaults to hostname-pid) */
+    workerId?: string;
+    leaseMs: number;
+    threshold: number;
+    endpoints: ValidatorEndpoint[];
+    /** Idle wait between claims when the queue is empty */
+    pollMs?: number;
+}
+
+export function defaultWorkerId(): string {
+    return `${os.hostname()}-${process.pid}`;
+}
+
+/**
+ * Claim one job and collect the attestations it is missing.
+ *
+ * The lease is renewed every leaseMs / 3 and each attestation is written
+ * to the queue as it arrives. Returns the job as last seen, or null when
+ * nothing was claimable.
+ */
+export async function processNextJob(queue: WorkQueue, options: WorkerOptions): Promise<AttestationJob | null> {
+    const worker = options.workerId || defaultWorkerId();
+    let job = await queue.claim(worker, options.leaseMs);
+    if (!job) {
+        return null;
+    }
+    const claimed = job;
+    console.log(`📋 ${worker} claimed ${claimed.id} (attempt ${claimed.attempts}, ${claimed.attestations.length} attestations already collected)`);
+
+    let lost = false;
+    const heartbeat = setInterval(() => {
+        queue.renew(claimed.id, worker, options.leaseMs)
+            .then(held => { lost = lost || !held; })
+            .catch(error => console.error(`⚠️  Lease renewal failed for ${claimed.id}: ${error.message}`));
+    }, Math.floor(options.leaseMs / 3));
+
+    // Serialize writes: responses arrive concurrently
+    let writes = Promise.resolve();
+    const persist = (attestation: Attestation) => {
+        writes = writes.then(async () => {
+            if (lost) {
+                return;
+            }
+            const updated = await queue.addAttestations(claimed.id, worker, [attestation]);
+            if (updated) {
+                job = updated;
+            } else {
+                lost = true;
+            }
+        });
+        return writes;
+    };
+
+    try {
+        const have = new Set(claimed.attestations.map(a => a.validator_pubkey));
+        const missing = options.endpoints.filter(e => !have.has(e.pubkey.toBase58()));
+        const trace = newTraceContext(claimed.burn_nonce, claimed.asset_id);
+        const request = {
+            burn_nonce: claimed.burn_nonce,
+            user: claimed.user,
+            expected_amount: claimed.expected_amount,
+            validator_set_version: claimed.validator_set_version,
+        };
+
+        await Promise.all(missing.map(async endpoint => {
+            try {
+                const response = await postSigned(endpoint, '/attest-burn', request, trace);
+                const attestation = response.body as Attestation;
+                if (response.status === 200 && attestation.validator_pubkey === endpoint.pubkey.toBase58()) {
+                    await persist(attestation);
+                } else {
+                    console.log(`   ⚠️  ${endpoint.pubkey.toBase58()}: ${JSON.stringify(response.body)}`);
+                }
+            } catch (error: any) {
+                console.log(`   ⚠️  ${endpoint.pubkey.toBase58()}: ${error.message}`);
+            }
+        }));
+        await writes;
+
+        if (lost) {
+            console.log(`⚠️  Lost the lease on ${claimed.id}; another instance took over`);
+        } else if (job.attestations.length >= options.threshold) {
+            await queue.complete(claimed.id, worker);
+            job = { ...job, status: 'ready' };
+            console.log(`✅ ${claimed.id} ready with ${job.attestations.length} attestations`);
+        } else {
+            await queue.release(claimed.id, worker, `${job.attestations.length}/${options.threshold} attestations`);
+            console.log(`⏳ ${claimed.id}: ${job.attestations.length}/${options.threshold} attestations, released for retry`);
+        }
+        return job;
+    } catch (error: any) {
+        await queue.release(claimed.id, worker, error.message).catch(() => undefined);
+        throw error;
+    } finally {
+        clearInterval(heartbeat);
+    }
+}
+
+/**
+ * Process jobs until `shouldStop` returns true
+ */
+export async function runWorker(
+    queue: WorkQueue,
+    options: WorkerOptions,
+    shouldStop: () => boolean = () => false
+): Promise<void> {
+    const pollMs = options.pollMs ?? 2000;
+    while (!shouldStop()) {
+        let job: AttestationJob | null = null;
+        try {
+            job = await processNextJob(queue, options);
+        } catch (error: any) {
+            console.error(`❌ Attestation job failed: ${error.message}`);
+        }
+        if (!job) {
+            await new Promise(resolve => setTimeout(resolve, pollMs));
+        }
+    }
+}
+
+/**
+ * Enqueue a transfer (if needed) and wait until its bundle is ready.
+ * Throws if the job fails or the timeout passes.
+ */
+export async function awaitBundle(
+    queue: WorkQueue,
+    request: JobRequest,
+    timeoutMs: number,
+    pollMs = 2000
+): Promise<Attestation[]> {
+    const deadline = Date.now() + timeoutMs;
+    let job = await queue.enqueue(request);
+    while (job.status !== 'ready') {
+        if (job.status === 'failed') {
+            throw new Error(`Attestation job ${job.id} failed: ${job.error}`);
+        }
+        if (Date.now() > deadline) {
+            throw new Error(`Attestation job ${job.id} not ready after ${timeoutMs / 1000}s (${job.status}, ${job.attestations.length} attestations)`);
+        }
+        await new Promise(resolve => setTimeout(resolve, pollMs));
+        job = (await queue.get(job.id))!;
+    }
+    return job.attestations;
+}
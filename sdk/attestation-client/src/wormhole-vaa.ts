@@ -0,0 +1,182 @@
+import { AccountMeta, Connection, PublicKey, SYSVAR_CLOCK_PUBKEY, SYSVAR_RENT_PUBKEY } from '@solana/web3.js';
+import * as crypto from 'crypto';
+import { keccak_256 } from '@noble/hashes/sha3';
+
+/**
+ * Wormhole VAA path (submit_burn_vaa)
+ *
+ * A fallback for when the X1 validators' attestation APIs are offline: the
+ * burn program's burn_registered_asset publishes each burn through Wormhole
+ * from its ["emitter"] PDA (the governed emitter), the burn's VAA is
+ * posted on X1 with the Wormhole core bridge's own tooling (verify
+ * signatures, post VAA), and submit_burn_vaa verifies the burn from that
+ * PostedVAA. The result is the same VerifiedBurnV3 the attestation path
+ * creates, so minting does not change.
+ *
+ * WormholeConfig layout: discriminator(8) || enabled(1) || core_bridge(32)
+ *   || emitter_chain(2) || emitter_address(32) || min_consistency_level(1)
+ *   || updated_slot(8) || bump(1)
+ *
+ * Burn payload (big-endian, xencat-burn-vaa crate): 1 || asset_id(1) || burn_nonce(u64) || user(32)
+ *   || amount(u64) || solana_burn_slot(u64) || solana_burn_timestamp(i64)
+ */
+
+/** Must match wormhole::CHAIN_ID_SOLANA in the light client */
+export const WORMHOLE_CHAIN_ID_SOLANA = 1;
+
+/** Must match BURN_PAYLOAD_ID / BURN_PAYLOAD_LEN in the xencat-burn-vaa crate */
+export const BURN_PAYLOAD_ID = 1;
+export const BURN_PAYLOAD_LEN = 66;
+
+export interface WormholeConfig {
+    enabled: boolean;
+    coreBridge: PublicKey;
+    emitterChain: number;
+    emitterAddress: Buffer;
+    minConsistencyLevel: number;
+    updatedSlot: number;
+}
+
+export interface BurnVaaPayload {
+    assetId: number;
+    burnNonce: bigint;
+    user: PublicKey;
+    amount: bigint;
+    solanaBurnSlot: bigint;
+    solanaBurnTimestamp: bigint;
+}
+
+/** The VAA body fields the PostedVAA address is derived from */
+export interface VaaBody {
+    timestamp: number;
+    nonce: number;
+    emitterChain: number;
+    emitterAddress: Buffer;
+    sequence: bigint;
+    consistencyLevel: number;
+    payload: Buffer;
+}
+
+/** Must match WORMHOLE_CORE_BRIDGE in the burn program */
+export const WORMHOLE_CORE_BRIDGE_SOLANA = new PublicKey('worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth');
+
+/** The burn program's Wormhole emitter (WormholeConfig.emitter_address) */
+export function burnEmitterAddress(burnProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('emitter')], burnProgramId)[0];
+}
+
+/**
+ * Accounts burn_registered_asset posts burn `nonce`'s Wormhole message with,
+ * in instruction order (after user_token_account, before token_program)
+ */
+export function burnWormholeAccounts(burnProgramId: PublicKey, nonce: bigint | number): AccountMeta[] {
+    const core = (...seeds: Buffer[]) => PublicKey.findProgramAddressSync(seeds, WORMHOLE_CORE_BRIDGE_SOLANA)[0];
+    const nonceBytes = Buffer.alloc(8);
+    nonceBytes.writeBigUInt64LE(BigInt(nonce));
+    const emitter = burnEmitterAddress(burnProgramId);
+    return [
+        { pubkey: core(Buffer.from('Bridge')), isSigner: false, isWritable: true },
+        {
+            pubkey: PublicKey.findProgramAddressSync([Buffer.from('burn_message'), nonceBytes], burnProgramId)[0],
+            isSigner: false,
+            isWritable: true,
+        },
+        { pubkey: emitter, isSigner: false, isWritable: false },
+        { pubkey: core(Buffer.from('Sequence'), emitter.toBuffer()), isSigner: false, isWritable: true },
+        { pubkey: core(Buffer.from('fee_collector')), isSigner: false, isWritable: true },
+        { pubkey: WORMHOLE_CORE_BRIDGE_SOLANA, isSigner: false, isWritable: false },
+        { pubkey: SYSVAR_CLOCK_PUBKEY, isSigner: false, isWritable: false },
+        { pubkey: SYSVAR_RENT_PUBKEY, isSigner: false, isWritable: false },
+    ];
+}
+
+export function wormholeConfigAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('wormhole_config')], lightClientProgramId)[0];
+}
+
+export function decodeWormholeConfig(data: Buffer): WormholeConfig {
+    return {
+        enabled: data[8] === 1,
+        coreBridge: new PublicKey(data.subarray(9, 41)),
+        emitterChain: data.readUInt16LE(41),
+        emitterAddress: Buffer.from(data.subarray(43, 75)),
+        minConsistencyLevel: data[75],
+        updatedSlot: Number(data.readBigUInt64LE(76)),
+    };
+}
+
+/**
+ * The Wormhole VAA path config (null = not initialized, path off)
+ */
+export async function fetchWormholeConfig(connection: Connection, lightClientProgramId: PublicKey): Promise<WormholeConfig | null> {
+    const info = await connection.getAccountInfo(wormholeConfigAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return decodeWormholeConfig(info.data);
+}
+
+export function encodeBurnVaaPayload(burn: BurnVaaPayload): Buffer {
+    const payload = Buffer.alloc(BURN_PAYLOAD_LEN);
+    payload[0] = BURN_PAYLOAD_ID;
+    payload[1] = burn.assetId;
+    payload.writeBigUInt64BE(burn.burnNonce, 2);
+    burn.user.toBuffer().copy(payload, 10);
+    payload.writeBigUInt64BE(burn.amount, 42);
+    payload.writeBigUInt64BE(burn.solanaBurnSlot, 50);
+    payload.writeBigInt64BE(burn.solanaBurnTimestamp, 58);
+    return payload;
+}
+
+export function decodeBurnVaaPayload(payload: Buffer): BurnVaaPayload {
+    if (payload.length !== BURN_PAYLOAD_LEN || payload[0] !== BURN_PAYLOAD_ID) {
+        throw new Error('Not a burn payload');
+    }
+    return {
+        assetId: payload[1],
+        burnNonce: payload.readBigUInt64BE(2),
+        user: new PublicKey(payload.subarray(10, 42)),
+        amount: payload.readBigUInt64BE(42),
+        solanaBurnSlot: payload.readBigUInt64BE(50),
+        solanaBurnTimestamp: payload.readBigInt64BE(58),
+    };
+}
+
+/** Address the core bridge posts a VAA at: ["PostedVAA", keccak256(body)] */
+export function postedVaaAddress(coreBridge: PublicKey, body: VaaBody): PublicKey {
+    const header = Buffer.alloc(51);
+    header.writeUInt32BE(body.timestamp, 0);
+    header.writeUInt32BE(body.nonce, 4);
+    header.writeUInt16BE(body.emitterChain, 8);
+    body.emitterAddress.copy(header, 10);
+    header.writeBigUInt64BE(body.sequence, 42);
+    header[50] = body.consistencyLevel;
+    const hash = Buffer.from(keccak_256(Buffer.concat([header, body.payload])));
+    return PublicKey.findProgramAddressSync([Buffer.from('PostedVAA'), hash], coreBridge)[0];
+}
+
+/** Must match create_wormhole_config_message in the light client */
+export function wormholeConfigMessage(
+    validatorSetVersion: bigint | number,
+    configNonce: bigint | number,
+    config: Pick<WormholeConfig, 'enabled' | 'coreBridge' | 'emitterChain' | 'emitterAddress' | 'minConsistencyLevel'>,
+    x1GenesisHash: Buffer | null,
+): Buffer {
+    const version = Buffer.alloc(8);
+    version.writeBigUInt64LE(BigInt(validatorSetVersion));
+    const nonce = Buffer.alloc(8);
+    nonce.writeBigUInt64LE(BigInt(configNonce));
+    const chain = Buffer.alloc(2);
+    chain.writeUInt16LE(config.emitterChain);
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('SET_WORMHOLE_CONFIG'),
+        version,
+        nonce,
+        Buffer.from([config.enabled ? 1 : 0]),
+        config.coreBridge.toBuffer(),
+        chain,
+        config.emitterAddress,
+        Buffer.from([config.minConsistencyLevel]),
+        ...(x1GenesisHash ? [x1GenesisHash] : []),
+    ])).digest();
+}
@@ -0,0 +1,79 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import bs58 from 'bs58';
+
+/**
+ * X1 genesis hash binding (light client X1ChainId account)
+ *
+ * Once set, the genesis hash follows the BridgeDomain bytes in every
+ * attestation message and ends every governance message, so a fork
+ * or test clone of X1 cannot replay mainnet validator signatures. Signers
+ * must take the hash from their own X1 RPC (getGenesisHash) and refuse to
+ * sign when it differs from the bound one.
+ *
+ * Layout: discriminator(8) || genesis_hash(32) || bump(1)
+ */
+
+export function x1ChainIdAddress(lightClientProgramId: PublicKey): PublicKey {
+    return PublicKey.findProgramAddressSync([Buffer.from('x1_chain_id')], lightClientProgramId)[0];
+}
+
+/**
+ * Genesis hash the deployment is bound to (null while the account does not exist)
+ */
+export async function fetchX1ChainId(connection: Connection, lightClientProgramId: PublicKey): Promise<Buffer | null> {
+    const info = await connection.getAccountInfo(x1ChainIdAddress(lightClientProgramId));
+    if (!info || !info.owner.equals(lightClientProgramId)) {
+        return null;
+    }
+    return Buffer.from(info.data.subarray(8, 40));
+}
+
+/**
+ * Genesis hash field of governance proposal files (hex, null = unbound)
+ */
+export async function fetchX1ChainIdHex(connection: Connection, lightClientProgramId: PublicKey): Promise<string | null> {
+    const genesisHash = await fetchX1ChainId(connection, lightClientProgramId);
+    return genesisHash ? genesisHash.toString('hex') : null;
+}
+
+/**
+ * Genesis hash a proposal file's governance message is bound to (null = unbound)
+ */
+export function proposalX1GenesisHash(proposal: { x1_genesis_hash?: string | null }): Buffer | null {
+    return proposal.x1_genesis_hash ? Buffer.from(proposal.x1_genesis_hash, 'hex') : null;
+}
+
+/**
+ * Genesis hash of the network `connection` points at, as raw bytes
+ */
+export async function fetchGenesisHash(connection: Connection): Promise<Buffer> {
+    return Buffer.from(bs58.decode(await connection.getGenesisHash()));
+}
+
+/**
+ * Domain bytes of attestation messages: the bridge domain, then the genesis hash if bound
+ * (must match X1ChainId::bind_domain)
+ */
+export function attestationDomain(bridgeDomain: string, genesisHash: Buffer | null): Buffer {
+    return genesisHash ? Buffer.concat([Buffer.from(bridgeDomain), genesisHash]) : Buffer.from(bridgeDomain);
+}
+
+/**
+ * Genesis hash to sign with: the bound one, after checking `connection` is on that chain
+ *
+ * Throws when the deployment is bound to another network's genesis, i.e.
+ * the RPC serves a fork or clone. Returns null while nothing is bound.
+ */
+export async function verifiedX1ChainId(connection: Connection, lightClientProgramId: PublicKey): Promise<Buffer | null> {
+    const bound = await fetchX1ChainId(connection, lightClientProgramId);
+    if (!bound) {
+        return null;
+    }
+    const genesisHash = await fetchGenesisHash(connection);
+    if (!genesisHash.equals(bound)) {
+        throw new Error(
+            `X1 RPC genesis ${bs58.encode(genesisHash)} differs from the bridge's ${bs58.encode(bound)}: refusing to sign for a forked or cloned chain`
+        );
+    }
+    return bound;
+}
@@ -0,0 +1,152 @@
+#!/usr/bin/env node
+/**
+ * Config Doctor
+ *
+ * Resolves the layered bridge configuration exactly like the relayer and
+ * validator service do, then checks it:
+ *   - static validation (addresses, URLs, thresholds, limits, key paths)
+ *   - RPC reachability on Solana and X1
+ *   - configured programs exist and are executable on the right chain
+ *   - keypairs load
+ *   - validator endpoints answer /health with the expected pubkey
+ *
+ * Usage:
+ *   npx ts-node sdk/bridge-config/src/doctor.ts [--config xencat.toml] [--env testnet] [--offline]
+ *
+ * Exit code is non-zero when any error is found.
+ */
+
+import { Connection, PublicKey } from '@solana/web3.js';
+import {
+    BridgeConfig,
+    ConfigIssue,
+    loadConfig,
+    loadKeypair,
+    validateConfig,
+} from './index';
+
+function parseArgs(argv: string[]) {
+    const args: { config?: string; env?: string; offline: boolean } = { offline: false };
+    for (let i = 0; i < argv.length; i++) {
+        if (argv[i] === '--config') args.config = argv[++i];
+        else if (argv[i] === '--env') args.env = argv[++i];
+        else if (argv[i] === '--offline') args.offline = true;
+    }
+    return args;
+}
+
+async function checkRpc(label: string, rpc: string, issues: ConfigIssue[]): Promise<Connection | undefined> {
+    try {
+        const connection = new Connection(rpc, 'confirmed');
+        const version = await connection.getVersion();
+        console.log(`   ✅ ${label} RPC ${rpc} (solana-core ${version['solana-core']})`);
+        return connection;
+    } catch (error: any) {
+        issues.push({ level: 'error', path: `${label.toLowerCase()}.rpc`, message: `unreachable: ${error.message}` });
+        return undefined;
+    }
+}
+
+async function checkProgram(connection: Connection, key: string, address: string, issues: ConfigIssue[]) {
+    try {
+        const info = await connection.getAccountInfo(new PublicKey(address));
+        if (!info) {
+            issues.push({ level: 'error', path: `programs.${key}`, message: `${address} does not exist on this cluster` });
+        } else if (!info.executable) {
+            issues.push({ level: 'error', path: `programs.${key}`, message: `${address} is not an executable program` });
+        } else {
+            console.log(`   ✅ programs.${key} ${address}`);
+        }
+    } catch (error: any) {
+        issues.push({ level: 'warning', path: `programs.${key}`, message: `lookup failed: ${error.message}` });
+    }
+}
+
+async function checkValidators(config: BridgeConfig, issues: ConfigIssue[]) {
+    let healthy = 0;
+    for (const [i, v] of config.attestation.validators.entries()) {
+        const p = `attestation.validators[${i}]`;
+        try {
+            const controller = new AbortController();
+            const timer = setTimeout(() => controller.abort(), config.attestation.request_timeout_ms);
+            const response = await fetch(new URL('/health', v.url), { signal: controller.signal });
+            clearTimeout(timer);
+            const health: any = await response.json();
+            // Multi-key services list every hosted key in `validators`
+            const hosted: string[] = health.validators?.map((k: any) => k.pubkey) || [health.validator];
+            if (!hosted.includes(v.pubkey)) {
+                issues.push({ level: 'error', path: p, message: `${v.url} reports validator ${health.validator}, expected ${v.pubkey}` });
+                continue;
+            }
+            healthy++;
+            console.log(`   ✅ ${v.url} (${v.pubkey.slice(0, 8)}..., ${health.version})`);
+        } catch (error: any) {
+            issues.push({ level: 'warning', path: p, message: `${v.url} unreachable: ${error.message}` });
+        }
+    }
+    if (healthy < config.attestation.threshold) {
+        issues.push({
+            level: 'error',
+            path: 'attestation.validators',
+            message: `only ${healthy} healthy validators, threshold is ${config.attestation.threshold}`,
+        });
+    }
+}
+
+async function main() {
+    const args = parseArgs(process.argv.slice(2));
+
+    console.log('🩺 XENCAT Bridge Config Doctor\n');
+
+    const { config, sources } = loadConfig({ file: args.config, env: args.env, skipValidation: true });
+    console.log('📄 Sources:');
+    console.log('   defaults');
+    sources.forEach(s => console.log(`   ${s}`));
+    console.log('   environment overrides\n');
+
+    const issues = validateConfig(config);
+
+    for (const role of ['user', 'validator'] as const) {
+        if (config.keys[role]) {
+            try {
+                const keypair = loadKeypair(config, role);
+                console.log(`🔑 keys.${role}: ${keypair.publicKey.toBase58()}`);
+            } catch (error: any) {
+                issues.push({ level: 'error', path: `keys.${role}`, message: `cannot load keypair: ${error.message}` });
+            }
+        }
+    }
+
+    if (!args.offline) {
+        console.log('\n🌐 Network checks:');
+        const solana = await checkRpc('Solana', config.solana.rpc, issues);
+        const x1 = await checkRpc('X1', config.x1.rpc, issues);
+        if (solana) {
+            await checkProgram(solana, 'burn', config.programs.burn, issues);
+        }
+        if (x1) {
+            await checkProgram(x1, 'light_client', config.programs.light_client, issues);
+            await checkProgram(x1, 'xencat_mint', config.programs.xencat_mint, issues);
+            await checkProgram(x1, 'dgn_mint', config.programs.dgn_mint, issues);
+        }
+        console.log('\n🛡️  Validator endpoints:');
+        await checkValidators(config, issues);
+    }
+
+    const errors = issues.filter(i => i.level === 'error');
+    const warnings = issues.filter(i => i.level === 'warning');
+
+    console.log('\n📋 Result:');
+    warnings.forEach(i => console.log(`   ⚠️  ${i.path}: ${i.message}`));
+    errors.forEach(i => console.log(`   ❌ ${i.path}: ${i.message}`));
+    if (errors.length === 0) {
+        console.log(`   ✅ Configuration OK (${warnings.length} warning(s))`);
+    }
+
+    process.exit(errors.length === 0 ? 0 : 1);
+}
+
+main().catch(error => {
+    console.error('❌', error.message);
+    process.exit(1);
+});
@@ -0,0 +1,720 @@
+import { Keypair, PublicKey } from '@solana/web3.js';
+import * as fs from 'fs';
+import * as path from 'path';
+import { parse as parseToml } from 'smol-toml';
+import bs58 from 'bs58';
+
+/**
+ * XENCAT Bridge Configuration
+ *
+ * One configuration model shared by the relayer scripts, the validator
+ * attestation service and the CLI tools. Values are resolved in layers,
+ * later layers overriding earlier ones:
+ *
+ *   1. Built-in defaults (mainnet deployment)
+ *   2. xencat.toml                  (or the file named by XENCAT_CONFIG)
+ *   3. xencat.<XENCAT_ENV>.toml     (optional per-environment overlay)
+ *   4. Environment variables        (see ENV_OVERRIDES)
+ *
+ * SECURITY:
+ * - Config files hold key PATHS, never key material
+ * - Inline keys are only accepted from the environment (legacy
+ *   USER_PRIVATE_KEY / VALIDATOR_PRIVATE_KEY)
+ */
+
+// ============================================================================
+// SCHEMA
+// ============================================================================
+
+export interface ValidatorEndpointConfig {
+    pubkey: string;
+    url: string;
+    /** Optional TLS certificate pin (SHA-256 fingerprint) */
+    cert_sha256?: string;
+}
+
+/**
+ * One validator identity served by a multi-key validator service
+ */
+export interface ValidatorKeyConfig {
+    /** Keypair path (Solana CLI JSON format) */
+    keypair: string;
+    /** 'local' (default) = signed in the service process, 'process' = dedicated signer process holding only this key */
+    backend?: 'local' | 'process';
+    /** Asset ids this key attests (default: every supported asset) */
+    assets?: number[];
+    /** Largest burn this key signs (base units, 0 = no limit) */
+    max_amount?: number;
+    /** Loaded but refuses to sign (e.g. key being rotated out) */
+    paused?: boolean;
+}
+
+/**
+ * One integrator webhook delivered by the deposit notifier
+ */
+export interface DepositWebhookConfig {
+    /** X1 recipient; its on-chain DepositWebhook must hold sha256(url) */
+    recipient: string;
+    url: string;
+    /** Env var holding the HMAC-SHA256 secret for the X-Xencat-Signature header (optional) */
+    secret_env?: string;
+}
+
+/**
+ * One integrator whose relayer keys pay for bridge transactions (cost reports)
+ */
+export interface IntegratorConfig {
+    name: string;
+    /** Fee payer keys (base58) whose transactions are charged to this integrator */
+    payers: string[];
+}
+
+export interface BridgeConfig {
+    solana: {
+        rpc: string;
+        commitment: 'processed' | 'confirmed' | 'finalized';
+    };
+    x1: {
+        rpc: string;
+        commitment: 'processed' | 'confirmed' | 'finalized';
+    };
+    programs: {
+        burn: string;
+        light_client: string;
+        xencat_mint: string;
+        dgn_mint: string;
+        /** Generic mint program (assets 3+, one MintState per asset) */
+        bridge_mint: string;
+    };
+    mints: {
+        xencat_solana: string;
+        dgn_solana: string;
+        xencat_x1: string;
+        dgn_x1: string;
+    };
+    keys: {
+        /** Path to the user/relayer keypair (Solana CLI JSON format) */
+        user?: string;
+        /** Path to the validator signing keypair (validator service only) */
+        validator?: string;
+        /** Several validator identities in one service process (replaces `validator`) */
+        validators?: ValidatorKeyConfig[];
+    };
+    attestation: {
+        /** Minimum slots between burn and attestation */
+        finality_slots: number;
+        /** Expected on-chain threshold (checked against validators.length) */
+        threshold: number;
+        request_timeout_ms: number;
+        validators: ValidatorEndpointConfig[];
+    };
+    limits: {
+        /** Upper bound on burns the CLI will submit (base units, 0 = none) */
+        max_burn_amount: number;
+        /** Maximum attestation retries per validator */
+        max_retries: number;
+        /** Maximum burns per /attest-burns batch request */
+        max_batch_size: number;
+    };
+    service: {
+        port: number;
+        /** Finalized-burn verification cache (validator service) */
+        cache_file: string;
+        /** Burns signed before Solana finality, watched for reorgs (validator service) */
+        signed_burns_file: string;
+    };
+    relayer: {
+        /**
+         * 'leader' = per-transfer leader election among `peers`; 'standby' =
+         * hot standby that submits only when the primary stalls (see relayer-coordination.ts)
+         */
+        coordination: 'none' | 'leader' | 'standby';
+        /** This relayer's id (must be listed in peers) */
+        id?: string;
+        peers: string[];
+        /** Delay per rank before a backup relayer takes over a transfer */
+        slot_seconds: number;
+        /** Standby: the primary's relayer key (base58), default the user keypair's */
+        primary_key?: string;
+        /** Standby: primary silence after which a waiting transfer is taken over */
+        takeover_seconds: number;
+        /** Shared attestation work queue for several coordinator instances (see work-queue.ts) */
+        queue: {
+            backend: 'none' | 'redis' | 'postgres';
+            /** redis://... or postgres://... connection string */
+            url?: string;
+            /** A job whose holder stops renewing for this long is taken over */
+            lease_seconds: number;
+            /** Collection attempts before a job is marked failed */
+            max_attempts: number;
+        };
+    };
+    alerts: {
+        /** Generic JSON webhook (POSTed one alert per request) */
+        webhook_url?: string;
+        /** Discord channel webhook */
+        discord_webhook_url?: string;
+        /** Telegram chat; the bot token is read from XENCAT_TELEGRAM_BOT_TOKEN only */
+        telegram_chat_id?: string;
+        /** Alert when the validator key's X1 balance drops below this (XNT) */
+        min_balance: number;
+        /** How often the validator service watchdog runs its checks */
+        check_interval_seconds: number;
+        /** Minimum time between two alerts of the same kind */
+        cooldown_seconds: number;
+    };
+    deposits: {
+        /** Integrator webhooks (scripts/deposit-notifier.ts) */
+        webhooks: DepositWebhookConfig[];
+        poll_interval_seconds: number;
+        /** Last delivered mint per recipient, so restarts do not resend */
+        cursor_file: string;
+    };
+    accounting: {
+        /** Integrators charged back in cost reports (scripts/cost-report.ts) */
+        integrators: IntegratorConfig[];
+        /** Directory the monthly reports are written to */
+        report_dir: string;
+        /** Transaction link prefixes of treasury statements (scripts/treasury-statement.ts) */
+        x1_explorer: string;
+        solana_explorer: string;
+    };
+    /** Opt-in anonymous health beacon (see attestation-client telemetry.ts) */
+    telemetry: {
+        /** Beacon endpoint; telemetry is off while unset */
+        endpoint?: string;
+        interval_seconds: number;
+    };
+}
+
+export const DEFAULT_CONFIG: BridgeConfig = {
+    solana: {
+        rpc: 'https://api.mainnet-beta.solana.com',
+        commitment: 'confirmed',
+    },
+    x1: {
+        rpc: 'https://rpc.mainnet.x1.xyz',
+        commitment: 'confirmed',
+    },
+    programs: {
+        burn: '2ktujS2t9SRXE9cA4UVQJyDFH9genNR4GngfmGffjKkp',
+        light_client: 'BXBZtvFfCtCapQgqFTxGQ9hgJTQZUoHFzBXD2V3ys5C5',
+        xencat_mint: '8kmoPKtLAjjzQRN5i4emUsmWeu3LM5yPWFrsqZVyekhk',
+        dgn_mint: '4YPipW8txxY3N7gHdj4NLhu8YxybHgarx5dJQCdCnQHs',
+        bridge_mint: '8yxPXAX7AynCpG2agTGFJQHkzFKbDwowKJtqq6GFq8Re',
+    },
+    mints: {
+        xencat_solana: '7UN8WkBumTUCofVPXCPjNWQ6msQhzrg9tFQRP48Nmw5V',
+        dgn_solana: 'Fd8TNp5GhhTk6Uq6utMvK13vfQdLN1yUUHCnapWvpump',
+        xencat_x1: 'DQ6sApYPMJ8LwpvyUjthL7amykNBJ3fx5jZi2koN7vHb',
+        dgn_x1: '84PxDRsNyiRJU4gfFiD7RqvZzqh5FdqXjDdtFV3N3oxc',
+    },
+    keys: {},
+    attestation: {
+        finality_slots: 32,
+        threshold: 3,
+        request_timeout_ms: 10_000,
+        validators: [
+            { url: 'http://149.50.116.159:8080', pubkey: '9oa7NAscCZ1kCQFZJng9gfwvDzrEvyWgx4F244PHmHPH' },
+            { url: 'http://193.34.212.186:8080', pubkey: '8byEUEZ2sMfP6RPX9VD8JCvCQK3F5FG2LytcR9TkVWag' },
+            { url: 'http://74.50.76.62:10001', pubkey: '5NfpgFCwrYzcgJkda9bRJvccycLUo3dvVQsVAK2W43Um' },
+            { url: 'http://149.50.116.21:8080', pubkey: 'GdbXi56fCSQ1joCvGjqm7JKvqvwgtKh6xeusUqZbB3rH' },
+            { url: 'http://64.20.49.142:8080', pubkey: 'FmuuFgRh8NP8UD7QHg86f7vu7qpsmr1wE7hB59oojDpj' },
+        ],
+    },
+    limits: {
+        max_burn_amount: 0,
+        max_retries: 3,
+        max_batch_size: 25,
+    },
+    service: {
+        port: 8080,
+        cache_file: 'burn-cache.json',
+        signed_burns_file: 'signed-burns.json',
+    },
+    relayer: {
+        coordination: 'none',
+        peers: [],
+        slot_seconds: 20,
+        takeover_seconds: 120,
+        queue: {
+            backend: 'none',
+            lease_seconds: 30,
+            max_attempts: 5,
+        },
+    },
+    alerts: {
+        min_balance: 0.1,
+        check_interval_seconds: 60,
+        cooldown_seconds: 3600,
+    },
+    deposits: {
+        webhooks: [],
+        poll_interval_seconds: 15,
+        cursor_file: 'deposit-cursor.json',
+    },
+    accounting: {
+        integrators: [],
+        report_dir: 'reports',
+        x1_explorer: 'https://explorer.x1.xyz/tx/',
+        solana_explorer: 'https://solscan.io/tx/',
+    },
+    telemetry: {
+        interval_seconds: 3600,
+    },
+};
+
+// ============================================================================
+// ENVIRONMENT OVERRIDES
+// ============================================================================
+
+type EnvKind = 'string' | 'number';
+
+/**
+ * Environment variable → config path
+ *
+ * Legacy names (SOLANA_RPC, X1_RPC, PORT) are kept so existing deployments
+ * keep working; XENCAT_* names win when both are set.
+ */
+export const ENV_OVERRIDES: Array<[string, string, EnvKind]> = [
+    ['SOLANA_RPC', 'solana.rpc', 'string'],
+    ['XENCAT_SOLANA_RPC', 'solana.rpc', 'string'],
+    ['XENCAT_SOLANA_COMMITMENT', 'solana.commitment', 'string'],
+    ['X1_RPC', 'x1.rpc', 'string'],
+    ['XENCAT_X1_RPC', 'x1.rpc', 'string'],
+    ['XENCAT_X1_COMMITMENT', 'x1.commitment', 'string'],
+    ['XENCAT_BURN_PROGRAM', 'programs.burn', 'string'],
+    ['XENCAT_LIGHT_CLIENT_PROGRAM', 'programs.light_client', 'string'],
+    ['XENCAT_XENCAT_MINT_PROGRAM', 'programs.xencat_mint', 'string'],
+    ['XENCAT_DGN_MINT_PROGRAM', 'programs.dgn_mint', 'string'],
+    ['XENCAT_BRIDGE_MINT_PROGRAM', 'programs.bridge_mint', 'string'],
+    ['XENCAT_USER_KEYPAIR', 'keys.user', 'string'],
+    ['XENCAT_VALIDATOR_KEYPAIR', 'keys.validator', 'string'],
+    ['XENCAT_FINALITY_SLOTS', 'attestation.finality_slots', 'number'],
+    ['XENCAT_THRESHOLD', 'attestation.threshold', 'number'],
+    ['XENCAT_REQUEST_TIMEOUT_MS', 'attestation.request_timeout_ms', 'number'],
+    ['XENCAT_MAX_BURN_AMOUNT', 'limits.max_burn_amount', 'number'],
+    ['XENCAT_MAX_RETRIES', 'limits.max_retries', 'number'],
+    ['XENCAT_MAX_BATCH_SIZE', 'limits.max_batch_size', 'number'],
+    ['PORT', 'service.port', 'number'],
+    ['XENCAT_SERVICE_PORT', 'service.port', 'number'],
+    ['XENCAT_CACHE_FILE', 'service.cache_file', 'string'],
+    ['XENCAT_SIGNED_BURNS_FILE', 'service.signed_burns_file', 'string'],
+    ['XENCAT_RELAYER_COORDINATION', 'relayer.coordination', 'string'],
+    ['XENCAT_RELAYER_ID', 'relayer.id', 'string'],
+    ['XENCAT_RELAYER_PRIMARY_KEY', 'relayer.primary_key', 'string'],
+    ['XENCAT_QUEUE_BACKEND', 'relayer.queue.backend', 'string'],
+    ['XENCAT_QUEUE_URL', 'relayer.queue.url', 'string'],
+    ['XENCAT_ALERT_WEBHOOK_URL', 'alerts.webhook_url', 'string'],
+    ['XENCAT_DISCORD_WEBHOOK_URL', 'alerts.discord_webhook_url', 'string'],
+    ['XENCAT_TELEGRAM_CHAT_ID', 'alerts.telegram_chat_id', 'string'],
+    ['XENCAT_ALERT_MIN_BALANCE', 'alerts.min_balance', 'number'],
+    ['XENCAT_TELEMETRY_ENDPOINT', 'telemetry.endpoint', 'string'],
+];
+
+// ============================================================================
+// LOADING
+// ============================================================================
+
+export class ConfigError extends Error {
+    constructor(public readonly issues: ConfigIssue[]) {
+        super(
+            'Invalid bridge configuration:\n' +
+            issues.filter(i => i.level === 'error').map(i => `  - ${i.path}: ${i.message}`).join('\n')
+        );
+    }
+}
+
+export interface LoadOptions {
+    /** Explicit config file (default: XENCAT_CONFIG or ./xencat.toml) */
+    file?: string;
+    /** Environment overlay name (default: XENCAT_ENV) */
+    env?: string;
+    /** Environment to read overrides from (default: process.env) */
+    vars?: NodeJS.ProcessEnv;
+    /** Skip validation (used by the doctor to report every issue) */
+    skipValidation?: boolean;
+}
+
+export interface LoadedConfig {
+    config: BridgeConfig;
+    /** Files that contributed to the config, in load order */
+    sources: string[];
+}
+
+function isObject(value: unknown): value is Record<string, any> {
+    return typeof value === 'object' && value !== null && !Array.isArray(value);
+}
+
+function merge(base: any, overlay: any): any {
+    if (!isObject(base) || !isObject(overlay)) {
+        return overlay === undefined ? base : overlay;
+    }
+    const out: Record<string, any> = { ...base };
+    for (const [key, value] of Object.entries(overlay)) {
+        out[key] = merge(base[key], value);
+    }
+    return out;
+}
+
+function setPath(target: any, dotted: string, value: unknown) {
+    const parts = dotted.split('.');
+    let node = target;
+    for (const part of parts.slice(0, -1)) {
+        node = node[part];
+    }
+    node[parts[parts.length - 1]] = value;
+}
+
+function readTomlFile(file: string): Record<string, unknown> {
+    const text = fs.readFileSync(file, 'utf-8');
+    try {
+        return parseToml(text) as Record<string, unknown>;
+    } catch (error: any) {
+        throw new Error(`Failed to parse ${file}: ${error.message}`);
+    }
+}
+
+/**
+ * Resolve the layered configuration.
+ *
+ * Throws ConfigError when validation finds errors (warnings are ignored).
+ */
+export function loadConfig(options: LoadOptions = {}): LoadedConfig {
+    const vars = options.vars || process.env;
+    const sources: string[] = [];
+    let config: BridgeConfig = JSON.parse(JSON.stringify(DEFAULT_CONFIG));
+
+    const baseFile = options.file || vars.XENCAT_CONFIG || 'xencat.toml';
+    const envName = options.env || vars.XENCAT_ENV;
+    const files = [baseFile];
+    if (envName) {
+        const parsed = path.parse(baseFile);
+        files.push(path.join(parsed.dir, `${parsed.name}.${envName}${parsed.ext || '.toml'}`));
+    }
+
+    for (const file of files) {
+        if (fs.existsSync(file)) {
+            config = merge(config, readTomlFile(file));
+            sources.push(path.resolve(file));
+        } else if (file === options.file || file === vars.XENCAT_CONFIG) {
+            // An explicitly requested file must exist
+            throw new Error(`Config file not found: ${file}`);
+        }
+    }
+
+    for (const [name, dotted, kind] of ENV_OVERRIDES) {
+        const raw = vars[name];
+        if (raw === undefined || raw === '') {
+            continue;
+        }
+        setPath(config, dotted, kind === 'number' ? Number(raw) : raw);
+    }
+
+    if (!options.skipValidation) {
+        const issues = validateConfig(config);
+        if (issues.some(i => i.level === 'error')) {
+            throw new ConfigError(issues);
+        }
+    }
+
+    return { config, sources };
+}
+
+// ============================================================================
+// VALIDATION
+// ============================================================================
+
+export interface ConfigIssue {
+    level: 'error' | 'warning';
+    path: string;
+    message: string;
+}
+
+function isPubkey(value: unknown): boolean {
+    if (typeof value !== 'string') {
+        return false;
+    }
+    try {
+        new PublicKey(value);
+        return true;
+    } catch {
+        return false;
+    }
+}
+
+function isUrl(value: unknown): boolean {
+    if (typeof value !== 'string') {
+        return false;
+    }
+    try {
+        const url = new URL(value);
+        return url.protocol === 'http:' || url.protocol === 'https:';
+    } catch {
+        return false;
+    }
+}
+
+/**
+ * Static validation (no network access).
+ */
+export function validateConfig(config: BridgeConfig): ConfigIssue[] {
+    const issues: ConfigIssue[] = [];
+    const error = (p: string, message: string) => issues.push({ level: 'error', path: p, message });
+    const warning = (p: string, message: string) => issues.push({ level: 'warning', path: p, message });
+
+    for (const section of ['solana', 'x1'] as const) {
+        if (!isUrl(config[section].rpc)) {
+            error(`${section}.rpc`, `not a valid http(s) URL: ${config[section].rpc}`);
+        }
+        if (!['processed', 'confirmed', 'finalized'].includes(config[section].commitment)) {
+            error(`${section}.commitment`, `unknown commitment: ${config[section].commitment}`);
+        }
+    }
+
+    for (const section of ['programs', 'mints'] as const) {
+        for (const [key, value] of Object.entries(config[section])) {
+            if (!isPubkey(value)) {
+                error(`${section}.${key}`, `not a valid public key: ${value}`);
+            }
+        }
+    }
+
+    const { attestation, limits, service } = config;
+    const validators = attestation.validators || [];
+
+    if (!Number.isInteger(attestation.finality_slots) || attestation.finality_slots < 32) {
+        error('attestation.finality_slots', 'must be an integer >= 32 (Solana finality)');
+    }
+    if (!Number.isInteger(attestation.threshold) || attestation.threshold < 1) {
+        error('attestation.threshold', 'must be a positive integer');
+    } else if (attestation.threshold > validators.length) {
+        error('attestation.threshold', `threshold ${attestation.threshold} exceeds ${validators.length} configured validators`);
+    }
+    if (!Number.isInteger(attestation.request_timeout_ms) || attestation.request_timeout_ms <= 0) {
+        error('attestation.request_timeout_ms', 'must be a positive integer');
+    }
+
+    const seen = new Set<string>();
+    validators.forEach((v, i) => {
+        const p = `attestation.validators[${i}]`;
+        if (!isPubkey(v.pubkey)) {
+            error(`${p}.pubkey`, `not a valid public key: ${v.pubkey}`);
+        } else if (seen.has(v.pubkey)) {
+            error(`${p}.pubkey`, `duplicate validator ${v.pubkey}`);
+        }
+        seen.add(v.pubkey);
+        if (!isUrl(v.url)) {
+            error(`${p}.url`, `not a valid http(s) URL: ${v.url}`);
+        } else if (v.cert_sha256 && !v.url.startsWith('https:')) {
+            error(`${p}.cert_sha256`, 'certificate pin requires an https url');
+        } else if (v.url.startsWith('http:')) {
+            warning(`${p}.url`, 'plain http (responses are signed, but consider https + cert_sha256)');
+        }
+    });
+
+    if (!Number.isInteger(limits.max_burn_amount) || limits.max_burn_amount < 0) {
+        error('limits.max_burn_amount', 'must be a non-negative integer');
+    }
+    if (!Number.isInteger(limits.max_retries) || limits.max_retries < 0) {
+        error('limits.max_retries', 'must be a non-negative integer');
+    }
+    if (!Number.isInteger(limits.max_batch_size) || limits.max_batch_size < 1 || limits.max_batch_size > 100) {
+        error('limits.max_batch_size', 'must be an integer between 1 and 100');
+    }
+    if (!Number.isInteger(service.port) || service.port <= 0 || service.port > 65535) {
+        error('service.port', `invalid port: ${service.port}`);
+    }
+
+    const { relayer } = config;
+    if (!['none', 'leader', 'standby'].includes(relayer.coordination)) {
+        error('relayer.coordination', `unknown coordination mode: ${relayer.coordination}`);
+    } else if (relayer.coordination === 'leader') {
+        if (!relayer.id || !relayer.peers.includes(relayer.id)) {
+            error('relayer.id', 'leader coordination requires an id listed in relayer.peers');
+        }
+        if (new Set(relayer.peers).size !== relayer.peers.length) {
+            error('relayer.peers', 'duplicate relayer id');
+        }
+        if (!Number.isInteger(relayer.slot_seconds) || relayer.slot_seconds <= 0) {
+            error('relayer.slot_seconds', 'must be a positive integer');
+        }
+    } else if (relayer.coordination === 'standby') {
+        if (relayer.primary_key !== undefined && !isPubkey(relayer.primary_key)) {
+            error('relayer.primary_key', `not a valid public key: ${relayer.primary_key}`);
+        }
+        if (!Number.isInteger(relayer.takeover_seconds) || relayer.takeover_seconds < 30) {
+            error('relayer.takeover_seconds', 'must be an integer of at least 30');
+        }
+    }
+
+    const { queue } = relayer;
+    if (!['none', 'redis', 'postgres'].includes(queue.backend)) {
+        error('relayer.queue.backend', `unknown queue backend: ${queue.backend}`);
+    } else if (queue.backend !== 'none') {
+        const schemes = queue.backend === 'redis' ? ['redis:', 'rediss:'] : ['postgres:', 'postgresql:'];
+        let scheme = '';
+        try {
+            scheme = new URL(queue.url || '').protocol;
+        } catch {
+            // Reported below
+        }
+        if (!schemes.includes(scheme)) {
+            error('relayer.queue.url', `${queue.backend} queue requires a ${schemes[0]}// URL`);
+        }
+        if (!Number.isInteger(queue.lease_seconds) || queue.lease_seconds < 5) {
+            error('relayer.queue.lease_seconds', 'must be an integer of at least 5');
+        }
+        if (!Number.isInteger(queue.max_attempts) || queue.max_attempts <= 0) {
+            error('relayer.queue.max_attempts', 'must be a positive integer');
+        }
+    }
+
+    const { alerts } = config;
+    for (const key of ['webhook_url', 'discord_webhook_url'] as const) {
+        const url = alerts[key];
+        if (url && !isUrl(url)) {
+            error(`alerts.${key}`, `not a valid http(s) URL: ${url}`);
+        }
+    }
+    if (alerts.telegram_chat_id && !process.env.XENCAT_TELEGRAM_BOT_TOKEN) {
+        warning('alerts.telegram_chat_id', 'set, but XENCAT_TELEGRAM_BOT_TOKEN is not (Telegram alerts disabled)');
+    }
+    if (typeof alerts.min_balance !== 'number' || !(alerts.min_balance >= 0)) {
+        error('alerts.min_balance', 'must be a non-negative number');
+    }
+    if (!Number.isInteger(alerts.check_interval_seconds) || alerts.check_interval_seconds <= 0) {
+        error('alerts.check_interval_seconds', 'must be a positive integer');
+    }
+    if (!Number.isInteger(alerts.cooldown_seconds) || alerts.cooldown_seconds < 0) {
+        error('alerts.cooldown_seconds', 'must be a non-negative integer');
+    }
+
+    const { deposits } = config;
+    const recipients = new Set<string>();
+    (deposits.webhooks || []).forEach((webhook, i) => {
+        const p = `deposits.webhooks[${i}]`;
+        if (!isPubkey(webhook.recipient)) {
+            error(`${p}.recipient`, `not a valid public key: ${webhook.recipient}`);
+        } else if (recipients.has(webhook.recipient)) {
+            error(`${p}.recipient`, `duplicate recipient ${webhook.recipient}`);
+        }
+        recipients.add(webhook.recipient);
+        if (!isUrl(webhook.url)) {
+            error(`${p}.url`, `not a valid http(s) URL: ${webhook.url}`);
+        }
+        if (webhook.secret_env && !process.env[webhook.secret_env]) {
+            warning(`${p}.secret_env`, `${webhook.secret_env} is not set (deliveries will be unsigned)`);
+        }
+    });
+    if (!Number.isInteger(deposits.poll_interval_seconds) || deposits.poll_interval_seconds <= 0) {
+        error('deposits.poll_interval_seconds', 'must be a positive integer');
+    }
+
+    const names = new Set<string>();
+    const payers = new Set<string>();
+    (config.accounting.integrators || []).forEach((integrator, i) => {
+        const p = `accounting.integrators[${i}]`;
+        if (!integrator.name || names.has(integrator.name)) {
+            error(`${p}.name`, `missing or duplicate integrator name: ${integrator.name}`);
+        }
+        names.add(integrator.name);
+        (integrator.payers || []).forEach((payer, j) => {
+            if (!isPubkey(payer)) {
+                error(`${p}.payers[${j}]`, `not a valid public key: ${payer}`);
+            } else if (payers.has(payer)) {
+                error(`${p}.payers[${j}]`, `payer ${payer} is listed for two integrators`);
+            }
+            payers.add(payer);
+        });
+    });
+
+    for (const key of ['x1_explorer', 'solana_explorer'] as const) {
+        if (!isUrl(config.accounting[key])) {
+            error(`accounting.${key}`, `not a valid http(s) URL: ${config.accounting[key]}`);
+        }
+    }
+
+    const { telemetry } = config;
+    if (telemetry.endpoint && !isUrl(telemetry.endpoint)) {
+        error('telemetry.endpoint', `not a valid http(s) URL: ${telemetry.endpoint}`);
+    }
+    if (!Number.isInteger(telemetry.interval_seconds) || telemetry.interval_seconds < 60) {
+        error('telemetry.interval_seconds', 'must be an integer of at least 60');
+    }
+
+    for (const key of ['user', 'validator'] as const) {
+        const file = config.keys[key];
+        if (file && !fs.existsSync(expandHome(file))) {
+            error(`keys.${key}`, `keypair file not found: ${file}`);
+        }
+    }
+
+    const validatorKeys = config.keys.validators || [];
+    if (validatorKeys.length > 0 && config.keys.validator) {
+        warning('keys.validator', 'ignored: keys.validators is set');
+    }
+    validatorKeys.forEach((key, i) => {
+        const p = `keys.validators[${i}]`;
+        if (!key.keypair || !fs.existsSync(expandHome(key.keypair))) {
+            error(`${p}.keypair`, `keypair file not found: ${key.keypair}`);
+        }
+        if (key.backend && !['local', 'process'].includes(key.backend)) {
+            error(`${p}.backend`, `unknown signing backend: ${key.backend}`);
+        }
+        if (key.assets && (key.assets.length === 0 || key.assets.some(a => ![1, 2].includes(a)))) {
+            error(`${p}.assets`, 'must list supported asset ids (1 = XENCAT, 2 = DGN)');
+        }
+        if (key.max_amount !== undefined && (!Number.isInteger(key.max_amount) || key.max_amount < 0)) {
+            error(`${p}.max_amount`, 'must be a non-negative integer');
+        }
+    });
+    if (new Set(validatorKeys.map(k => expandHome(k.keypair))).size !== validatorKeys.length) {
+        error('keys.validators', 'the same keypair is listed twice');
+    }
+
+    return issues;
+}
+
+// ============================================================================
+// HELPERS
+// ============================================================================
+
+export function expandHome(file: string): string {
+    return file.startsWith('~/') ? path.join(process.env.HOME || '', file.slice(2)) : file;
+}
+
+/**
+ * Parse a keypair from a Solana CLI JSON array or a base58 secret key
+ */
+export function parseKeypair(secret: string): Keypair {
+    const trimmed = secret.trim();
+    if (trimmed.startsWith('[')) {
+        return Keypair.fromSecretKey(Uint8Array.from(JSON.parse(trimmed)));
+    }
+    return Keypair.fromSecretKey(bs58.decode(trimmed));
+}
+
+/**
+ * Load a keypair from the configured path, falling back to an inline
+ * secret from the legacy environment variable.
+ */
+export function loadKeypair(
+    config: BridgeConfig,
+    role: 'user' | 'validator',
+    vars: NodeJS.ProcessEnv = process.env
+): Keypair {
+    const file = config.keys[role];
+    if (file) {
+        return parseKeypair(fs.readFileSync(expandHome(file), 'utf-8'));
+    }
+    const legacy = role === 'user' ? vars.USER_PRIVATE_KEY : vars.VALIDATOR_PRIVATE_KEY;
+    if (legacy) {
+        return parseKeypair(legacy);
+    }
+    throw new Error(`No ${role} key configured (set keys.${role} in xencat.toml)`);
+}
+
+export function programId(config: BridgeConfig, name: keyof BridgeConfig['programs']): PublicKey {
+    return new PublicKey(config.programs[name]);
+}
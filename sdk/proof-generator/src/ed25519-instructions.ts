@@ -20,7 +20,7 @@ import {
     TransactionInstruction,
     SYSVAR_INSTRUCTIONS_PUBKEY,
 } from "@solana/web3.js";
-import { createHash } from "crypto";
+import { keccak256 } from "js-sha3";
 
 /**
  * Ed25519 Program ID (system program for signature verification)
@@ -154,21 +154,31 @@ export function createEd25519Instruction(
 /**
  * Create the vote message that validators sign
  *
- * Message format: keccak256(blockHash || slot)
+ * Message format: keccak256(blockHash || slot || stateRoot)
  *
- * This matches the verification.rs implementation in create_vote_message()
+ * This matches the ed25519_utils.rs implementation in create_vote_message().
+ * The state root is signed so the proof's Merkle path is checked against a
+ * root the validators attested to, not one the prover picked.
  *
  * @param blockHash Block hash (32 bytes)
  * @param slot Slot number
+ * @param stateRoot Merkle root of the burn transaction's accounts (32 bytes)
  * @returns 32-byte message hash
  */
-export function createVoteMessage(blockHash: Uint8Array | Buffer, slot: bigint): Buffer {
+export function createVoteMessage(
+    blockHash: Uint8Array | Buffer,
+    slot: bigint,
+    stateRoot: Uint8Array | Buffer
+): Buffer {
     if (blockHash.length !== 32) {
         throw new Error(`Block hash must be 32 bytes, got ${blockHash.length}`);
     }
+    if (stateRoot.length !== 32) {
+        throw new Error(`State root must be 32 bytes, got ${stateRoot.length}`);
+    }
 
-    // Concatenate blockHash || slot (little-endian)
-    const messageData = Buffer.alloc(32 + 8);
+    // Concatenate blockHash || slot (little-endian) || stateRoot
+    const messageData = Buffer.alloc(32 + 8 + 32);
     Buffer.from(blockHash).copy(messageData, 0);
 
     // Write slot as little-endian u64
@@ -176,19 +186,18 @@ export function createVoteMessage(blockHash: Uint8Array | Buffer, slot: bigint):
     slotBuffer.writeBigUInt64LE(slot);
     slotBuffer.copy(messageData, 32);
 
-    // Hash with SHA256 (matches Rust's hash() function)
-    // Note: Solana uses SHA256, not Keccak256 despite naming
-    const hash = createHash("sha256").update(messageData).digest();
+    Buffer.from(stateRoot).copy(messageData, 40);
 
-    return hash;
+    return Buffer.from(keccak256.arrayBuffer(messageData));
 }
 
 /**
  * Create Ed25519 instructions for all validator votes
  *
  * This creates one Ed25519Program instruction per validator.
- * These instructions MUST be prepended to the verify_proof instruction
- * in the exact order (validator 0, validator 1, validator 2, ...).
+ * The proof's ed25519Indices must list their transaction indices (in
+ * validator order); any other Ed25519 instruction in the transaction is
+ * rejected. The generator assumes they come first, as below.
  *
  * Transaction structure:
  * [
@@ -201,6 +210,7 @@ export function createVoteMessage(blockHash: Uint8Array | Buffer, slot: bigint):
  * @param validatorVotes Array of validator votes
  * @param blockHash Block hash that was signed
  * @param slot Slot number
+ * @param stateRoot State root that was signed
  * @returns Array of Ed25519Program instructions (one per validator)
  */
 export function createValidatorEd25519Instructions(
@@ -210,10 +220,11 @@ export function createValidatorEd25519Instructions(
         stake: bigint;
     }>,
     blockHash: Uint8Array,
-    slot: bigint
+    slot: bigint,
+    stateRoot: Uint8Array
 ): TransactionInstruction[] {
     // Create the vote message (what validators signed)
-    const voteMessage = createVoteMessage(blockHash, slot);
+    const voteMessage = createVoteMessage(blockHash, slot, stateRoot);
 
     // Create one Ed25519 instruction per validator
     const instructions: TransactionInstruction[] = [];
@@ -184,9 +184,12 @@ export async function generateBurnProof(
 
             // Merkle proof (SECURITY CRITICAL)
             merkleProof: merkleProof.proof.map(p => new Uint8Array(p)),
+            burnRecordData,
 
             // Validator count (tells submit_proof how many Ed25519 ixs to read)
             validatorCount: selectedValidators.length,
+            // Ed25519 instructions go first, in validator order (see createValidatorEd25519Instructions)
+            ed25519Indices: selectedValidators.map((_, i) => i),
         };
 
         // Return proof + validators (validators needed for building Ed25519 instructions)
@@ -14,6 +14,13 @@
 import { Connection, PublicKey } from "@solana/web3.js";
 import { keccak256 } from "js-sha3";
 
+/**
+ * Domain prefixes of leaf and internal node hashes (match MERKLE_LEAF_PREFIX
+ * and MERKLE_NODE_PREFIX in verification_new.rs)
+ */
+const LEAF_PREFIX = 0x00;
+const NODE_PREFIX = 0x01;
+
 /**
  * Account state from transaction
  */
@@ -82,12 +89,12 @@ export async function generateMerkleProof(
     let burnRecordIndex = -1;
 
     // Add burn record data as the first leaf (this is the EXACT data being sent in the proof)
-    leaves.push(new Uint8Array(keccak256.arrayBuffer(burnRecordData)));
+    leaves.push(hashLeaf(burnRecordData));
     burnRecordIndex = 0;
 
     // Add other account pubkeys as leaves (for diversity)
     for (let i = 0; i < Math.min(accountKeys.length, 7); i++) {
-        const pubkeyHash = new Uint8Array(keccak256.arrayBuffer(accountKeys[i].toBytes()));
+        const pubkeyHash = hashLeaf(accountKeys[i].toBytes());
         leaves.push(pubkeyHash);
     }
 
@@ -169,19 +176,35 @@ function buildMerkleTree(leaves: Uint8Array[]): Uint8Array[][] {
     return tree;
 }
 
+/**
+ * Hash a leaf
+ *
+ * SECURITY: The prefix keeps leaves apart from internal nodes
+ * Hash(0x00 || data)
+ */
+function hashLeaf(data: Uint8Array): Uint8Array {
+    const combined = new Uint8Array(1 + data.length);
+    combined[0] = LEAF_PREFIX;
+    combined.set(data, 1);
+
+    return new Uint8Array(keccak256.arrayBuffer(combined));
+}
+
 /**
  * Hash two nodes together
  *
- * SECURITY: Uses sorted order to ensure determinism
- * Hash(min(left, right) || max(left, right))
+ * SECURITY: Uses sorted order to ensure determinism, and a prefix so a
+ * 64-byte leaf can never pass as an internal node
+ * Hash(0x01 || min(left, right) || max(left, right))
  */
 function hashPair(left: Uint8Array, right: Uint8Array): Uint8Array {
     // Sort lexicographically for determinism
     const [first, second] = compareBytes(left, right) <= 0 ? [left, right] : [right, left];
 
-    const combined = new Uint8Array(first.length + second.length);
-    combined.set(first, 0);
-    combined.set(second, first.length);
+    const combined = new Uint8Array(1 + first.length + second.length);
+    combined[0] = NODE_PREFIX;
+    combined.set(first, 1);
+    combined.set(second, 1 + first.length);
 
     return new Uint8Array(keccak256.arrayBuffer(combined));
 }
@@ -12,7 +12,7 @@ import { PublicKey } from "@solana/web3.js";
  * OPTIMIZATION: Validator data is extracted from Ed25519 instructions
  * This avoids duplicating 312 bytes of validator data in the proof argument
  *
- * Size: ~185 bytes (vs ~585 bytes with validator_votes included)
+ * Size: ~185 bytes plus the burn record (vs ~585 bytes with validator_votes included)
  */
 export interface BurnProof {
     // Burn identification
@@ -34,10 +34,14 @@ export interface BurnProof {
     // Merkle proof (SECURITY CRITICAL)
     /** Merkle proof path from burn record to state root */
     merkleProof: Uint8Array[];
+    /** Burn record account data without discriminator (the Merkle leaf; user, amount and nonce must match) */
+    burnRecordData: Uint8Array;
 
     // Validator count (read this many Ed25519 instructions)
     /** Number of validators that signed (tells how many Ed25519 ixs to read) */
     validatorCount: number;
+    /** Transaction indices of those Ed25519 instructions (strictly increasing) */
+    ed25519Indices: number[];
 }
 
 /**
@@ -1,6 +1,14 @@
 use anchor_lang::prelude::*;
-use anchor_spl::token::{self, Burn, Token, TokenAccount, Mint};
-use anchor_lang::solana_program::keccak;
+use anchor_spl::token::{self, Approve, Burn, Revoke, Token, TokenAccount, Mint, Transfer};
+use anchor_lang::solana_program::{hash, keccak};
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::instruction::{AccountMeta, Instruction};
+use anchor_lang::solana_program::program::{invoke, invoke_signed};
+use anchor_lang::solana_program::system_instruction;
+use xencat_burn_vaa::{BurnPayload, EMITTER_SEED};
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked, ID as IX_SYSVAR_ID,
+};
 
 declare_id!("2ktujS2t9SRXE9cA4UVQJyDFH9genNR4GngfmGffjKkp");
 
@@ -15,11 +23,70 @@ pub mod xencat_burn {
         state.total_burns = 0;
         state.total_amount_burned = 0;
         state.bump = ctx.bumps.global_state;
+        state.min_burn_amount = 0;
+        state.max_burn_amount = 0;
+        state.record_grace_period = 0;
 
         msg!("Global burn state initialized");
         Ok(())
     }
 
+    /// Set the smallest and largest amount a single burn may have (upgrade authority only)
+    ///
+    /// Dust burns cost validators an attestation each for next to nothing,
+    /// and very large burns are easier on the X1 rate limits when split.
+    /// 0 disables either limit. GlobalState created before the limits
+    /// existed is grown here, so run this right after upgrading.
+    pub fn update_burn_limits(
+        ctx: Context<UpdateGlobalState>,
+        min_burn_amount: u64,
+        max_burn_amount: u64,
+    ) -> Result<()> {
+        require!(
+            max_burn_amount == 0 || min_burn_amount <= max_burn_amount,
+            ErrorCode::InvalidBurnLimits
+        );
+
+        let info = ctx.accounts.global_state.to_account_info();
+        grow_global_state(&info, &ctx.accounts.authority, &ctx.accounts.system_program)?;
+
+        let mut state = Account::<GlobalState>::try_from(&info)?;
+        state.min_burn_amount = min_burn_amount;
+        state.max_burn_amount = max_burn_amount;
+        state.exit(&crate::ID)?;
+
+        emit!(BurnLimitsUpdated {
+            min_burn_amount,
+            max_burn_amount,
+        });
+
+        msg!("Burn limits: min {}, max {}", min_burn_amount, max_burn_amount);
+        Ok(())
+    }
+
+    /// Set how long after a burn its burner may reclaim the BurnRecord rent
+    /// (upgrade authority only)
+    ///
+    /// 0 disables reclaim_burn_record. Otherwise at least
+    /// MIN_RECORD_GRACE_PERIOD, so validators have long attested the burn
+    /// before its record can go away (e.g. 30 days).
+    pub fn update_record_grace_period(ctx: Context<UpdateGlobalState>, grace_period: i64) -> Result<()> {
+        require!(
+            grace_period == 0 || grace_period >= MIN_RECORD_GRACE_PERIOD,
+            ErrorCode::InvalidGracePeriod
+        );
+
+        let info = ctx.accounts.global_state.to_account_info();
+        grow_global_state(&info, &ctx.accounts.authority, &ctx.accounts.system_program)?;
+
+        let mut state = Account::<GlobalState>::try_from(&info)?;
+        state.record_grace_period = grace_period;
+        state.exit(&crate::ID)?;
+
+        msg!("BurnRecord grace period: {}s", grace_period);
+        Ok(())
+    }
+
     /// Burns XENCAT tokens from user's account
     /// This function is immutable and will work autonomously
     pub fn burn_xencat(ctx: Context<BurnXencat>, amount: u64) -> Result<()> {
@@ -32,6 +99,7 @@ pub mod xencat_burn {
 
         // Increment nonce counter
         let state = &mut ctx.accounts.global_state;
+        state.check_amount(amount)?;
         let nonce = state.nonce_counter;
         state.nonce_counter = state.nonce_counter.checked_add(1)
             .ok_or(ErrorCode::NonceOverflow)?;
@@ -59,6 +127,10 @@ pub mod xencat_burn {
         burn_record.nonce = nonce;
         burn_record.timestamp = timestamp;
         burn_record.record_hash = record_hash;
+        burn_record.confirmation_depth = 0;
+        burn_record.destination = Pubkey::default();
+        burn_record.memo = [0; 64];
+        burn_record.asset_id = 0;
         burn_record.bump = ctx.bumps.burn_record;
 
         // Perform the burn through token program
@@ -89,100 +161,2619 @@ pub mod xencat_burn {
 
         Ok(())
     }
-}
 
-#[derive(Accounts)]
-pub struct Initialize<'info> {
-    /// Global state PDA for tracking nonces
-    #[account(
-        init,
-        payer = authority,
-        space = 8 + GlobalState::INIT_SPACE,
-        seeds = [b"global_state"],
-        bump
-    )]
-    pub global_state: Account<'info, GlobalState>,
+    /// Burn with a chosen confirmation depth
+    ///
+    /// Identical to burn_xencat, but validators wait until the burn is
+    /// `confirmation_depth` Solana slots deep (instead of their default)
+    /// before attesting it, trading latency for safety. The depth is signed
+    /// into the attestation and the X1 light client rejects a bundle that
+    /// was signed any shallower.
+    pub fn burn_xencat_with_depth(
+        ctx: Context<BurnXencat>,
+        amount: u64,
+        confirmation_depth: u16,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(
+            (MIN_CONFIRMATION_DEPTH..=MAX_CONFIRMATION_DEPTH).contains(&confirmation_depth),
+            ErrorCode::InvalidConfirmationDepth
+        );
 
-    /// Authority initializing the program (pays for PDA)
-    #[account(mut)]
-    pub authority: Signer<'info>,
+        let user = ctx.accounts.user.key();
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            user,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+        ctx.accounts.burn_record.confirmation_depth = confirmation_depth;
 
-    pub system_program: Program<'info, System>,
-}
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
 
-#[derive(Accounts)]
-pub struct BurnXencat<'info> {
-    /// User burning tokens
-    #[account(mut)]
-    pub user: Signer<'info>,
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user,
+            amount,
+            nonce,
+            timestamp,
+        });
 
-    /// Global state for nonce tracking
-    #[account(
-        mut,
-        seeds = [b"global_state"],
-        bump = global_state.bump
-    )]
-    pub global_state: Account<'info, GlobalState>,
+        msg!("Burned {} tokens from {} (nonce: {}, confirmation depth: {})", amount, user, nonce, confirmation_depth);
+        Ok(())
+    }
 
-    /// Burn record PDA (stores hash for relayer verification)
-    #[account(
-        init,
-        payer = user,
-        space = 8 + BurnRecord::INIT_SPACE,
-        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
-        bump
-    )]
-    pub burn_record: Account<'info, BurnRecord>,
+    /// Burn to a stealth recipient (optional privacy)
+    ///
+    /// Identical to burn_xencat except the BurnRecord (and therefore the
+    /// attestation and X1 VerifiedBurnV3) is recorded for `stealth_pubkey`,
+    /// a one-time key derived by the burner from the recipient's public
+    /// stealth meta-key and `ephemeral_pubkey`. Only the recipient can derive
+    /// the matching secret key, and the X1 mint requires proof of it, so
+    /// nothing on-chain links the burner to the recipient's known identity.
+    pub fn burn_to_stealth(
+        ctx: Context<BurnXencat>,
+        amount: u64,
+        stealth_pubkey: Pubkey,
+        ephemeral_pubkey: [u8; 32],
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        // The burner's own key would defeat the purpose (use burn_xencat)
+        require!(stealth_pubkey != ctx.accounts.user.key(), ErrorCode::InvalidStealthKey);
+        require!(stealth_pubkey != Pubkey::default(), ErrorCode::InvalidStealthKey);
 
-    /// Token mint to burn from
-    /// For mainnet: 7UN8WkBumTUCofVPXCPjNWQ6msQhzrg9tFQRP48Nmw5V (XENCAT)
-    /// For devnet: Pass any test token mint
-    #[account(mut)]
-    pub xencat_mint: Account<'info, Mint>,
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            stealth_pubkey,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
 
-    /// User's token account
-    #[account(
-        mut,
-        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
-        constraint = user_token_account.mint == xencat_mint.key() @ ErrorCode::InvalidMint,
-    )]
-    pub user_token_account: Account<'info, TokenAccount>,
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
 
-    /// Token program
-    pub token_program: Program<'info, Token>,
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user: stealth_pubkey,
+            amount,
+            nonce,
+            timestamp,
+        });
+        // Announcement: recipients scan these to find their payments
+        emit!(StealthAnnouncement {
+            nonce,
+            stealth_pubkey,
+            ephemeral_pubkey,
+        });
 
-    pub system_program: Program<'info, System>,
-}
+        msg!("Burned {} tokens to stealth recipient (nonce: {})", amount, nonce);
+        Ok(())
+    }
 
-/// Global state tracking burn nonces
-#[account]
-#[derive(InitSpace)]
-pub struct GlobalState {
-    pub nonce_counter: u64,
-    pub total_burns: u64,
-    pub total_amount_burned: u64,
-    pub bump: u8,
-}
+    /// Burn to an explicit X1 destination, with an optional memo
+    ///
+    /// Same as burn_xencat except the BurnRecord binds `destination`: the
+    /// X1 wallet validators attest the burn for (and so the only key the X1
+    /// mint credits), which need not be the burner's Solana identity. The
+    /// burner stays the record's `user` (rent, settlement refunds).
+    /// `memo` is 64 free-form bytes stored with the record, e.g. an
+    /// exchange deposit reference; validators do not interpret it.
+    pub fn burn_xencat_to(
+        ctx: Context<BurnXencat>,
+        amount: u64,
+        destination: Pubkey,
+        memo: Option<[u8; 64]>,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(destination != Pubkey::default(), ErrorCode::InvalidRecipient);
 
-/// Individual burn record with hash for relayer verification
-#[account]
-#[derive(InitSpace)]
-pub struct BurnRecord {
-    pub user: Pubkey,
-    pub amount: u64,
-    pub nonce: u64,
-    pub timestamp: u64,
-    pub record_hash: [u8; 32],  // keccak256(user || amount || nonce)
-    pub bump: u8,
-}
+        let burner = ctx.accounts.user.key();
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            burner,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+        let memo = memo.unwrap_or([0; 64]);
+        let record = &mut ctx.accounts.burn_record;
+        record.destination = destination;
+        record.memo = memo;
+        // Commit to the key that is attested, not the payer
+        record.record_hash = burn_record_hash(&destination, amount, nonce);
 
-/// Event emitted when tokens are burned (for relayer)
-#[event]
-pub struct Burned {
-    pub user: Pubkey,
-    pub amount: u64,
-    pub nonce: u64,
-    pub timestamp: u64,
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user: destination,
+            amount,
+            nonce,
+            timestamp,
+        });
+        emit!(DestinationBound {
+            nonce,
+            burner,
+            destination,
+            memo,
+        });
+
+        msg!("Burned {} tokens from {} to X1 destination {} (nonce: {})", amount, burner, destination, nonce);
+        Ok(())
+    }
+
+    /// Burn for an X1 recipient that a relayer may claim on its behalf
+    ///
+    /// Same as burn_xencat except the BurnRecord (and therefore the
+    /// attestation and X1 VerifiedBurnV3) is recorded for `recipient`, and a
+    /// BurnClaim next to it records `relayer_fee`. Validators attest the
+    /// claim with the burn; any relayer may then verify and mint on X1,
+    /// paying rent and fees in XNT, and keeps `relayer_fee` of the minted
+    /// tokens. The recipient may be the burner's own X1 key.
+    pub fn burn_xencat_for_recipient(
+        ctx: Context<BurnXencatForRecipient>,
+        amount: u64,
+        recipient: Pubkey,
+        relayer_fee: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(recipient != Pubkey::default(), ErrorCode::InvalidRecipient);
+        require!(relayer_fee < amount, ErrorCode::InvalidRelayerFee);
+
+        let burner = ctx.accounts.user.key();
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            recipient,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let claim = &mut ctx.accounts.burn_claim;
+        claim.nonce = nonce;
+        claim.user = recipient;
+        claim.mint = ctx.accounts.xencat_mint.key();
+        claim.relayer_fee = relayer_fee;
+        claim.burner = burner;
+        claim.bump = ctx.bumps.burn_claim;
+
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user: recipient,
+            amount,
+            nonce,
+            timestamp,
+        });
+        emit!(RecipientDesignated {
+            nonce,
+            burner,
+            recipient,
+            relayer_fee,
+        });
+
+        msg!("Burned {} tokens from {} for X1 recipient {} (relayer fee {}, nonce: {})",
+             amount, burner, recipient, relayer_fee, nonce);
+        Ok(())
+    }
+
+    /// Register a burnable asset (upgrade authority only)
+    ///
+    /// Binds the light client's `asset_id` to its Solana mint in a
+    /// RegisteredAsset PDA ["burn_asset", asset_id], plus a RegisteredMint
+    /// PDA ["burn_asset_mint", mint] so a mint can only ever carry one id.
+    /// Must mirror the light client's asset registry.
+    pub fn register_asset(ctx: Context<RegisterAsset>, asset_id: u8) -> Result<()> {
+        require!(asset_id != 0, ErrorCode::InvalidAssetId);
+
+        let asset = &mut ctx.accounts.registered_asset;
+        asset.asset_id = asset_id;
+        asset.mint = ctx.accounts.mint.key();
+        asset.active = true;
+        asset.total_burns = 0;
+        asset.total_amount_burned = 0;
+        asset.bump = ctx.bumps.registered_asset;
+
+        let mint = &mut ctx.accounts.registered_mint;
+        mint.asset_id = asset_id;
+        mint.bump = ctx.bumps.registered_mint;
+
+        msg!("Asset {} registered for mint {}", asset_id, asset.mint);
+        Ok(())
+    }
+
+    /// Stop or resume burn_registered_asset burns of an asset (upgrade authority only)
+    ///
+    /// Records already written keep their asset id, so burns made before a
+    /// deactivation are still attested and minted.
+    pub fn set_asset_active(ctx: Context<SetAssetActive>, active: bool) -> Result<()> {
+        let asset = &mut ctx.accounts.registered_asset;
+        asset.active = active;
+
+        msg!("Asset {} {}", asset.asset_id, if active { "activated" } else { "deactivated" });
+        Ok(())
+    }
+
+    /// Put a registered asset in permissioned beta (upgrade authority only)
+    ///
+    /// While the BetaAllowlist PDA ["beta_allowlist", asset_id] exists,
+    /// burn_registered_asset only accepts burners it lists. The light
+    /// client keeps its own list of X1 users for the same asset; both ends
+    /// should be lifted together when the asset graduates.
+    pub fn create_beta_allowlist(ctx: Context<CreateBetaAllowlist>, asset_id: u8, users: Vec<Pubkey>) -> Result<()> {
+        require!(BetaAllowlist::is_valid_list(&users), ErrorCode::InvalidBetaAllowlist);
+
+        let list = &mut ctx.accounts.beta_allowlist;
+        list.asset_id = asset_id;
+        list.users = users;
+        list.bump = ctx.bumps.beta_allowlist;
+
+        msg!("Asset {} in beta with {} burners", asset_id, list.users.len());
+        Ok(())
+    }
+
+    /// Replace the burners of an asset's beta allowlist (upgrade authority only)
+    pub fn update_beta_allowlist(ctx: Context<UpdateBetaAllowlist>, users: Vec<Pubkey>) -> Result<()> {
+        require!(BetaAllowlist::is_valid_list(&users), ErrorCode::InvalidBetaAllowlist);
+
+        let list = &mut ctx.accounts.beta_allowlist;
+        list.users = users;
+
+        msg!("Asset {} beta allowlist now has {} burners", list.asset_id, list.users.len());
+        Ok(())
+    }
+
+    /// Graduate an asset to permissionless burning (upgrade authority only)
+    ///
+    /// Closes the BetaAllowlist PDA; the rent goes back to the authority.
+    pub fn close_beta_allowlist(ctx: Context<CloseBetaAllowlist>) -> Result<()> {
+        msg!("Asset {} graduated from beta", ctx.accounts.beta_allowlist.asset_id);
+        Ok(())
+    }
+
+    /// Burn a registered asset
+    ///
+    /// Same as burn_xencat for any mint registered with register_asset:
+    /// the BurnRecord also stores `asset_id`, so validators take the asset
+    /// from the record instead of inferring it from the transaction's mint.
+    /// Nonces stay global across assets, so (asset_id, nonce) is unique and
+    /// records stay at ["burn_record", nonce].
+    ///
+    /// The burn is also published through Wormhole (xencat_burn_vaa payload,
+    /// signed by the ["emitter"] PDA), so the X1 light client can verify it
+    /// from its VAA (submit_burn_vaa) while the validators are offline.
+    /// Only this instruction publishes: it is the one that records the
+    /// asset on-chain instead of leaving it to be inferred from the mint.
+    pub fn burn_registered_asset(ctx: Context<BurnRegisteredAsset>, asset_id: u8, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+        require!(ctx.accounts.registered_asset.active, ErrorCode::AssetNotActive);
+
+        let user = ctx.accounts.user.key();
+        BetaAllowlist::enforce(&ctx.accounts.beta_allowlist, &user)?;
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            user,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+        ctx.accounts.burn_record.asset_id = asset_id;
+
+        let asset = &mut ctx.accounts.registered_asset;
+        asset.total_burns = asset.total_burns.checked_add(1)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        asset.total_amount_burned = asset.total_amount_burned.checked_add(amount)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user,
+            amount,
+            nonce,
+            timestamp,
+        });
+        emit!(AssetBurned {
+            nonce,
+            asset_id,
+            mint: ctx.accounts.mint.key(),
+        });
+
+        let payload = BurnPayload {
+            asset_id,
+            burn_nonce: nonce,
+            user: user.to_bytes(),
+            amount,
+            solana_burn_slot: Clock::get()?.slot,
+            solana_burn_timestamp: timestamp as i64,
+        };
+        post_burn_message(
+            ctx.accounts,
+            nonce,
+            &payload.encode(),
+            ctx.bumps.wormhole_message,
+            ctx.bumps.wormhole_emitter,
+        )?;
+
+        msg!("Burned {} of asset {} from {} (nonce: {})", amount, asset_id, user, nonce);
+        Ok(())
+    }
+
+    /// Create the insurance fund and premium vault for a mint
+    ///
+    /// Only the program upgrade authority can create it, since it chooses
+    /// the claims authority that approves payouts.
+    pub fn initialize_insurance_fund(
+        ctx: Context<InitializeInsuranceFund>,
+        premium_bps: u16,
+        claims_authority: Pubkey,
+    ) -> Result<()> {
+        require!(
+            premium_bps > 0 && premium_bps <= MAX_INSURANCE_PREMIUM_BPS,
+            ErrorCode::InvalidPremium
+        );
+
+        let fund = &mut ctx.accounts.insurance_fund;
+        fund.mint = ctx.accounts.xencat_mint.key();
+        fund.vault = ctx.accounts.insurance_vault.key();
+        fund.claims_authority = claims_authority;
+        fund.premium_bps = premium_bps;
+        fund.total_premiums = 0;
+        fund.total_claims_paid = 0;
+        fund.bump = ctx.bumps.insurance_fund;
+
+        msg!("Insurance fund initialized for {} ({} bps)", fund.mint, premium_bps);
+        Ok(())
+    }
+
+    /// Burn with per-transfer insurance
+    ///
+    /// Same as burn_xencat, plus `premium_bps` of the amount is paid into
+    /// the insurance vault and a BurnInsurance record is created next to
+    /// the BurnRecord. Validators attest the coverage with the burn.
+    pub fn burn_xencat_insured(ctx: Context<BurnXencatInsured>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let premium = insurance_premium(amount, ctx.accounts.insurance_fund.premium_bps)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        let user = ctx.accounts.user.key();
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            user,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+
+        token::transfer(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    to: ctx.accounts.insurance_vault.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            premium,
+        )?;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let insurance = &mut ctx.accounts.burn_insurance;
+        insurance.nonce = nonce;
+        insurance.user = user;
+        insurance.mint = ctx.accounts.xencat_mint.key();
+        insurance.covered_amount = amount;
+        insurance.premium = premium;
+        insurance.claimed = false;
+        insurance.bump = ctx.bumps.burn_insurance;
+
+        let fund = &mut ctx.accounts.insurance_fund;
+        fund.total_premiums = fund.total_premiums.checked_add(premium)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user,
+            amount,
+            nonce,
+            timestamp,
+        });
+        emit!(InsuredBurn {
+            nonce,
+            user,
+            covered_amount: amount,
+            premium,
+        });
+
+        msg!("Burned {} tokens from {} insured for premium {} (nonce: {})", amount, user, premium, nonce);
+        Ok(())
+    }
+
+    /// Pay an insurance claim for a covered transfer (claims authority only)
+    ///
+    /// Requires the burn to have been insured and not yet claimed; pays at
+    /// most the covered amount, to a token account of the original burner.
+    pub fn pay_insurance_claim(ctx: Context<PayInsuranceClaim>, payout: u64) -> Result<()> {
+        let insurance = &mut ctx.accounts.burn_insurance;
+        require!(!insurance.claimed, ErrorCode::ClaimAlreadyPaid);
+        require!(payout > 0 && payout <= insurance.covered_amount, ErrorCode::InvalidAmount);
+        insurance.claimed = true;
+
+        let nonce = insurance.nonce;
+        let user = insurance.user;
+
+        let fund = &ctx.accounts.insurance_fund;
+        let mint_key = fund.mint;
+        let signer_seeds: &[&[u8]] = &[b"insurance_fund", mint_key.as_ref(), &[fund.bump]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.insurance_vault.to_account_info(),
+                    to: ctx.accounts.beneficiary_token_account.to_account_info(),
+                    authority: ctx.accounts.insurance_fund.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            payout,
+        )?;
+
+        let fund = &mut ctx.accounts.insurance_fund;
+        fund.total_claims_paid = fund.total_claims_paid.checked_add(payout)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        emit!(InsuranceClaimPaid {
+            nonce,
+            user,
+            payout,
+        });
+
+        msg!("Insurance claim paid: {} to {} (nonce: {})", payout, user, nonce);
+        Ok(())
+    }
+
+    /// Pre-authorize recurring burns (subscriptions / payroll)
+    ///
+    /// The subscription PDA becomes SPL delegate of the user's token account
+    /// for exactly `amount_per_period * max_periods`, so no more than that can
+    /// ever be burned through it. Each period a permissionless crank calls
+    /// execute_subscription_burn, producing a regular BurnRecord for the user
+    /// that X1 validators attest like any other burn.
+    pub fn create_subscription(
+        ctx: Context<CreateSubscription>,
+        subscription_id: u64,
+        amount_per_period: u64,
+        period_seconds: i64,
+        max_periods: u32,
+    ) -> Result<()> {
+        require!(amount_per_period > 0, ErrorCode::InvalidAmount);
+        require!(period_seconds >= MIN_SUBSCRIPTION_PERIOD_SECONDS, ErrorCode::InvalidPeriod);
+        require!(max_periods > 0, ErrorCode::InvalidPeriod);
+
+        let allowance = amount_per_period
+            .checked_mul(max_periods as u64)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        let subscription = &mut ctx.accounts.subscription;
+        subscription.user = ctx.accounts.user.key();
+        subscription.subscription_id = subscription_id;
+        subscription.mint = ctx.accounts.xencat_mint.key();
+        subscription.token_account = ctx.accounts.user_token_account.key();
+        subscription.amount_per_period = amount_per_period;
+        subscription.period_seconds = period_seconds;
+        subscription.max_periods = max_periods;
+        subscription.periods_executed = 0;
+        subscription.next_due = Clock::get()?.unix_timestamp;
+        subscription.active = true;
+        subscription.bump = ctx.bumps.subscription;
+
+        token::approve(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Approve {
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    delegate: ctx.accounts.subscription.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            allowance,
+        )?;
+
+        msg!("Subscription {} created: {} every {}s x {} periods",
+             subscription_id, amount_per_period, period_seconds, max_periods);
+        Ok(())
+    }
+
+    /// Execute the next due subscription burn (permissionless crank)
+    ///
+    /// The crank pays the BurnRecord rent; the burn itself is signed by the
+    /// subscription PDA acting as delegate. The BurnRecord is recorded for
+    /// the subscriber, so the X1 side needs no special handling.
+    pub fn execute_subscription_burn(ctx: Context<ExecuteSubscriptionBurn>) -> Result<()> {
+        let now = Clock::get()?.unix_timestamp;
+        let subscription = &mut ctx.accounts.subscription;
+
+        require!(subscription.active, ErrorCode::SubscriptionInactive);
+        require!(subscription.periods_executed < subscription.max_periods, ErrorCode::SubscriptionInactive);
+        require!(now >= subscription.next_due, ErrorCode::SubscriptionNotDue);
+
+        let amount = subscription.amount_per_period;
+        let user = subscription.user;
+        let period = subscription.periods_executed;
+
+        subscription.periods_executed += 1;
+        subscription.next_due = subscription.next_due
+            .checked_add(subscription.period_seconds)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        if subscription.periods_executed == subscription.max_periods {
+            subscription.active = false;
+        }
+
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            user,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+
+        let subscription_id_bytes = subscription.subscription_id.to_le_bytes();
+        let signer_seeds: &[&[u8]] = &[
+            b"subscription",
+            user.as_ref(),
+            subscription_id_bytes.as_ref(),
+            &[subscription.bump],
+        ];
+
+        token::burn(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.subscription.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+        )?;
+
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user,
+            amount,
+            nonce,
+            timestamp,
+        });
+        emit!(SubscriptionBurned {
+            subscription: ctx.accounts.subscription.key(),
+            user,
+            nonce,
+            period,
+        });
+
+        msg!("Subscription burn {} of {} for {} (nonce: {})", period + 1, ctx.accounts.subscription.max_periods, user, nonce);
+        Ok(())
+    }
+
+    /// Cancel a subscription and revoke the delegate (user only)
+    pub fn cancel_subscription(ctx: Context<CancelSubscription>) -> Result<()> {
+        ctx.accounts.subscription.active = false;
+
+        token::revoke(CpiContext::new(
+            ctx.accounts.token_program.to_account_info(),
+            Revoke {
+                source: ctx.accounts.user_token_account.to_account_info(),
+                authority: ctx.accounts.user.to_account_info(),
+            },
+        ))?;
+
+        msg!("Subscription {} cancelled", ctx.accounts.subscription.subscription_id);
+        Ok(())
+    }
+
+    /// Create the settlement validator set (program upgrade authority, once)
+    ///
+    /// These are the X1 validators whose threshold acknowledges completed
+    /// mints. Afterwards the set only changes by its own threshold
+    /// (update_settlement_validators).
+    pub fn initialize_settlement_config(
+        ctx: Context<InitializeSettlementConfig>,
+        validators: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        validate_settlement_set(&validators, threshold)?;
+
+        let config = &mut ctx.accounts.settlement_config;
+        config.validators = validators;
+        config.threshold = threshold;
+        config.version = 1;
+        config.total_settled = 0;
+        config.bump = ctx.bumps.settlement_config;
+
+        msg!("Settlement config initialized: {} validators, threshold {}", config.validators.len(), threshold);
+        Ok(())
+    }
+
+    /// Replace the settlement validator set (threshold of the current set)
+    ///
+    /// The current validators sign settlement_rotation_message with Ed25519
+    /// instructions earlier in the same transaction. Bumping the version
+    /// invalidates every acknowledgement signed for the old set.
+    pub fn update_settlement_validators(
+        ctx: Context<UpdateSettlementValidators>,
+        validators: Vec<Pubkey>,
+        threshold: u8,
+    ) -> Result<()> {
+        validate_settlement_set(&validators, threshold)?;
+
+        let config = &mut ctx.accounts.settlement_config;
+        let message = settlement_rotation_message(config.version, &validators, threshold);
+        let signers = count_settlement_signers(&ctx.accounts.instructions, &config.validators, &message)?;
+        require!(signers >= config.threshold as usize, ErrorCode::InsufficientSettlementSignatures);
+
+        config.validators = validators;
+        config.threshold = threshold;
+        config.version = config.version.checked_add(1)
+            .ok_or(ErrorCode::CounterOverflow)?;
+
+        msg!("Settlement validators updated to version {} ({} validators, threshold {})",
+             config.version, config.validators.len(), threshold);
+        Ok(())
+    }
+
+    /// Mirror a completed X1 mint onto its BurnRecord (permissionless)
+    ///
+    /// A threshold of settlement validators acknowledges that the X1 mint
+    /// program's processed_burn_v3 PDA exists for this burn, by signing
+    /// settlement_message with Ed25519 instructions earlier in the same
+    /// transaction. The resulting BurnSettlement is the on-chain,
+    /// end-to-end reconciliation record, and lets the BurnRecord be closed.
+    pub fn settle_burn(ctx: Context<SettleBurn>, asset_id: u8, x1_processed_at: i64) -> Result<()> {
+        let record = &ctx.accounts.burn_record;
+        let config = &ctx.accounts.settlement_config;
+
+        let message = settlement_message(
+            config.version,
+            asset_id,
+            record.nonce,
+            &record.attested_user(),
+            record.amount,
+            x1_processed_at,
+        );
+        let signers = count_settlement_signers(&ctx.accounts.instructions, &config.validators, &message)?;
+        require!(signers >= config.threshold as usize, ErrorCode::InsufficientSettlementSignatures);
+
+        let settlement = &mut ctx.accounts.burn_settlement;
+        settlement.nonce = record.nonce;
+        settlement.user = record.attested_user();
+        settlement.amount = record.amount;
+        settlement.asset_id = asset_id;
+        settlement.x1_processed_at = x1_processed_at;
+        settlement.settled_at = Clock::get()?.unix_timestamp;
+        settlement.settlement_version = config.version;
+        settlement.bump = ctx.bumps.burn_settlement;
+
+        let config = &mut ctx.accounts.settlement_config;
+        config.total_settled = config.total_settled.checked_add(1)
+            .ok_or(ErrorCode::CounterOverflow)?;
+
+        emit!(BurnSettled {
+            nonce: settlement.nonce,
+            user: settlement.user,
+            amount: settlement.amount,
+            asset_id,
+            x1_processed_at,
+        });
+
+        msg!("Burn {} settled: minted on X1 at {} ({} acknowledgements)", settlement.nonce, x1_processed_at, signers);
+        Ok(())
+    }
+
+    /// Close a settled BurnRecord and return its rent to the burner (permissionless)
+    ///
+    /// Safe once settled: the X1 side never needs the record again, and the
+    /// nonce counter never hands out a closed record's nonce twice. The
+    /// BurnSettlement stays as the permanent reconciliation record.
+    pub fn close_burn_record(ctx: Context<CloseBurnRecord>) -> Result<()> {
+        emit_finalized(&ctx.accounts.burn_record, true)?;
+        msg!("Burn record {} closed (settled at {})",
+             ctx.accounts.burn_record.nonce,
+             ctx.accounts.burn_settlement.settled_at);
+        Ok(())
+    }
+
+    /// Close an old BurnRecord and return its rent to the burner (burner only)
+    ///
+    /// For burns that were never settled: allowed once GlobalState's
+    /// record_grace_period has passed since the burn. The record's contents
+    /// are emitted as BurnRecordFinalized, so the burn stays provable from
+    /// archived transaction data after the account is gone.
+    pub fn reclaim_burn_record(ctx: Context<ReclaimBurnRecord>) -> Result<()> {
+        let grace_period = ctx.accounts.global_state.record_grace_period;
+        require!(grace_period > 0, ErrorCode::ReclaimDisabled);
+
+        let record = &ctx.accounts.burn_record;
+        let reclaimable_at = (record.timestamp as i64).saturating_add(grace_period);
+        require!(Clock::get()?.unix_timestamp >= reclaimable_at, ErrorCode::GracePeriodNotOver);
+
+        emit_finalized(record, false)?;
+        msg!("Burn record {} reclaimed by its burner", record.nonce);
+        Ok(())
+    }
+
+    /// Create the cancellation reserve and its vault for a mint
+    ///
+    /// Only the program upgrade authority can create it. Anyone can fund
+    /// the vault with a plain SPL transfer; cancelled burns are refunded
+    /// from it.
+    pub fn initialize_cancel_reserve(ctx: Context<InitializeCancelReserve>) -> Result<()> {
+        let reserve = &mut ctx.accounts.cancel_reserve;
+        reserve.mint = ctx.accounts.xencat_mint.key();
+        reserve.vault = ctx.accounts.reserve_vault.key();
+        reserve.total_cancelled = 0;
+        reserve.total_refunded = 0;
+        reserve.bump = ctx.bumps.cancel_reserve;
+
+        msg!("Cancel reserve initialized for {}", reserve.mint);
+        Ok(())
+    }
+
+    /// Burn that the user may cancel for CANCEL_WINDOW_SLOTS slots
+    ///
+    /// Same as burn_xencat, plus a PendingBurn record holding the mint and
+    /// burn slot. Only mints with a cancel reserve are accepted, so a
+    /// cancellation can always be refunded in the burned token.
+    pub fn burn_xencat_cancellable(ctx: Context<BurnXencatCancellable>, amount: u64) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let user = ctx.accounts.user.key();
+        let nonce = record_burn(
+            &mut ctx.accounts.global_state,
+            &mut ctx.accounts.burn_record,
+            user,
+            amount,
+            ctx.bumps.burn_record,
+        )?;
+
+        token::burn(
+            CpiContext::new(
+                ctx.accounts.token_program.to_account_info(),
+                Burn {
+                    mint: ctx.accounts.xencat_mint.to_account_info(),
+                    from: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.user.to_account_info(),
+                },
+            ),
+            amount,
+        )?;
+
+        let pending = &mut ctx.accounts.pending_burn;
+        pending.nonce = nonce;
+        pending.user = user;
+        pending.mint = ctx.accounts.xencat_mint.key();
+        pending.amount = amount;
+        pending.slot = Clock::get()?.slot;
+        pending.cancelled = false;
+        pending.bump = ctx.bumps.pending_burn;
+
+        let timestamp = ctx.accounts.burn_record.timestamp;
+        emit!(Burned {
+            user,
+            amount,
+            nonce,
+            timestamp,
+        });
+
+        msg!("Burned {} tokens from {}, cancellable until slot {} (nonce: {})",
+             amount, user, pending.slot + CANCEL_WINDOW_SLOTS, nonce);
+        Ok(())
+    }
+
+    /// Cancel a pending burn and refund it from the cancel reserve
+    ///
+    /// Only the burner, and only within CANCEL_WINDOW_SLOTS of the burn.
+    /// The window is shorter than the validators' minimum finality, so no
+    /// attestation can exist yet. The BurnRecord is voided: amount 0 and a
+    /// record_hash recommitted over it, so validators and anyone checking
+    /// the hash see a void burn.
+    pub fn cancel_burn(ctx: Context<CancelBurn>) -> Result<()> {
+        let pending = &mut ctx.accounts.pending_burn;
+        require!(!pending.cancelled, ErrorCode::BurnAlreadyCancelled);
+        require!(
+            Clock::get()?.slot <= pending.slot.saturating_add(CANCEL_WINDOW_SLOTS),
+            ErrorCode::CancelWindowClosed
+        );
+        pending.cancelled = true;
+
+        let nonce = pending.nonce;
+        let user = pending.user;
+        let amount = pending.amount;
+
+        let record = &mut ctx.accounts.burn_record;
+        record.amount = 0;
+        record.record_hash = burn_record_hash(&user, 0, nonce);
+
+        let state = &mut ctx.accounts.global_state;
+        state.total_amount_burned = state.total_amount_burned.checked_sub(amount)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        let reserve = &ctx.accounts.cancel_reserve;
+        let mint_key = reserve.mint;
+        let signer_seeds: &[&[u8]] = &[b"cancel_reserve", mint_key.as_ref(), &[reserve.bump]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reserve_vault.to_account_info(),
+                    to: ctx.accounts.user_token_account.to_account_info(),
+                    authority: ctx.accounts.cancel_reserve.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+        )?;
+
+        let reserve = &mut ctx.accounts.cancel_reserve;
+        reserve.total_cancelled = reserve.total_cancelled.checked_add(1)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        reserve.total_refunded = reserve.total_refunded.checked_add(amount)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        emit!(BurnCancelled {
+            nonce,
+            user,
+            amount,
+        });
+
+        msg!("Burn {} cancelled: {} refunded to {}", nonce, amount, user);
+        Ok(())
+    }
+
+    /// Create the return reserve and its vault for a mint (X1 → Solana)
+    ///
+    /// Only the program upgrade authority can create it. Burns on X1
+    /// (burn_for_return) are released from its vault, so it must hold at
+    /// least what is in flight; anyone can fund it with a plain SPL transfer.
+    pub fn initialize_return_reserve(ctx: Context<InitializeReturnReserve>, asset_id: u8) -> Result<()> {
+        let reserve = &mut ctx.accounts.return_reserve;
+        reserve.asset_id = asset_id;
+        reserve.mint = ctx.accounts.xencat_mint.key();
+        reserve.vault = ctx.accounts.reserve_vault.key();
+        reserve.total_released = 0;
+        reserve.total_amount = 0;
+        reserve.bump = ctx.bumps.return_reserve;
+
+        msg!("Return reserve initialized for {} (asset {})", reserve.mint, asset_id);
+        Ok(())
+    }
+
+    /// Release an X1 return burn to its Solana recipient (permissionless)
+    ///
+    /// A threshold of settlement validators signs return_release_message
+    /// with Ed25519 instructions earlier in the same transaction, after
+    /// checking that the light client holds a VerifiedReturnBurn for it on
+    /// finalized X1 state. The ReturnRelease PDA makes each return nonce
+    /// releasable once.
+    pub fn release_return(
+        ctx: Context<ReleaseReturn>,
+        return_nonce: u64,
+        recipient: Pubkey,
+        amount: u64,
+        x1_slot: u64,
+    ) -> Result<()> {
+        require!(amount > 0, ErrorCode::InvalidAmount);
+
+        let reserve = &ctx.accounts.return_reserve;
+        let config = &ctx.accounts.settlement_config;
+        let asset_id = reserve.asset_id;
+
+        let message = return_release_message(config.version, asset_id, return_nonce, &recipient, amount, x1_slot);
+        let signers = count_settlement_signers(&ctx.accounts.instructions, &config.validators, &message)?;
+        require!(signers >= config.threshold as usize, ErrorCode::InsufficientSettlementSignatures);
+
+        let mint_key = reserve.mint;
+        let signer_seeds: &[&[u8]] = &[b"return_reserve", mint_key.as_ref(), &[reserve.bump]];
+
+        token::transfer(
+            CpiContext::new_with_signer(
+                ctx.accounts.token_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.reserve_vault.to_account_info(),
+                    to: ctx.accounts.recipient_token_account.to_account_info(),
+                    authority: ctx.accounts.return_reserve.to_account_info(),
+                },
+                &[signer_seeds],
+            ),
+            amount,
+        )?;
+
+        let release = &mut ctx.accounts.return_release;
+        release.asset_id = asset_id;
+        release.return_nonce = return_nonce;
+        release.recipient = recipient;
+        release.amount = amount;
+        release.x1_slot = x1_slot;
+        release.released_at = Clock::get()?.unix_timestamp;
+        release.settlement_version = config.version;
+        release.bump = ctx.bumps.return_release;
+
+        let reserve = &mut ctx.accounts.return_reserve;
+        reserve.total_released = reserve.total_released.checked_add(1)
+            .ok_or(ErrorCode::CounterOverflow)?;
+        reserve.total_amount = reserve.total_amount.checked_add(amount)
+            .ok_or(ErrorCode::AmountOverflow)?;
+
+        emit!(ReturnReleased {
+            asset_id,
+            return_nonce,
+            recipient,
+            amount,
+            x1_slot,
+        });
+
+        msg!("Return {} released: {} to {} ({} acknowledgements)", return_nonce, amount, recipient, signers);
+        Ok(())
+    }
+}
+
+/// Shortest allowed subscription period (1 hour)
+pub const MIN_SUBSCRIPTION_PERIOD_SECONDS: i64 = 3600;
+
+/// Highest allowed insurance premium (5%)
+pub const MAX_INSURANCE_PREMIUM_BPS: u16 = 500;
+
+/// Premium for insuring `amount`: `premium_bps` of it, rounded up
+pub fn insurance_premium(amount: u64, premium_bps: u16) -> Option<u64> {
+    let premium = (amount as u128)
+        .checked_mul(premium_bps as u128)?
+        .checked_add(9_999)?
+        / 10_000;
+    u64::try_from(premium).ok()
+}
+
+/// Grow a GlobalState created before its appended fields (the payer tops up the rent)
+fn grow_global_state<'info>(
+    info: &AccountInfo<'info>,
+    payer: &Signer<'info>,
+    system_program: &Program<'info, System>,
+) -> Result<()> {
+    let space = 8 + GlobalState::INIT_SPACE;
+    if info.data_len() >= space {
+        return Ok(());
+    }
+    let top_up = Rent::get()?.minimum_balance(space).saturating_sub(info.lamports());
+    if top_up > 0 {
+        anchor_lang::system_program::transfer(
+            CpiContext::new(
+                system_program.to_account_info(),
+                anchor_lang::system_program::Transfer {
+                    from: payer.to_account_info(),
+                    to: info.clone(),
+                },
+            ),
+            top_up,
+        )?;
+    }
+    info.realloc(space, false)?;
+    Ok(())
+}
+
+/// Log a BurnRecord about to be closed (its archived copy)
+fn emit_finalized(record: &BurnRecord, settled: bool) -> Result<()> {
+    emit!(BurnRecordFinalized {
+        nonce: record.nonce,
+        user: record.user,
+        attested_user: record.attested_user(),
+        amount: record.amount,
+        timestamp: record.timestamp,
+        record_hash: record.record_hash,
+        confirmation_depth: record.confirmation_depth,
+        asset_id: record.asset_id,
+        settled,
+        closed_at: Clock::get()?.unix_timestamp,
+    });
+    Ok(())
+}
+
+/// Allocate the next nonce and fill a BurnRecord exactly like burn_xencat does
+fn record_burn(
+    state: &mut Account<GlobalState>,
+    burn_record: &mut Account<BurnRecord>,
+    user: Pubkey,
+    amount: u64,
+    bump: u8,
+) -> Result<u64> {
+    state.check_amount(amount)?;
+    let nonce = state.nonce_counter;
+    state.nonce_counter = state.nonce_counter.checked_add(1)
+        .ok_or(ErrorCode::NonceOverflow)?;
+    state.total_burns = state.total_burns.checked_add(1)
+        .ok_or(ErrorCode::CounterOverflow)?;
+    state.total_amount_burned = state.total_amount_burned.checked_add(amount)
+        .ok_or(ErrorCode::AmountOverflow)?;
+
+    burn_record.user = user;
+    burn_record.amount = amount;
+    burn_record.nonce = nonce;
+    burn_record.timestamp = Clock::get()?.unix_timestamp as u64;
+    burn_record.record_hash = burn_record_hash(&user, amount, nonce);
+    burn_record.confirmation_depth = 0;
+    burn_record.destination = Pubkey::default();
+    burn_record.memo = [0; 64];
+    burn_record.asset_id = 0;
+    burn_record.bump = bump;
+
+    Ok(nonce)
+}
+
+/// Wormhole core bridge on Solana; burn_registered_asset publishes through it
+pub const WORMHOLE_CORE_BRIDGE: Pubkey = anchor_lang::solana_program::pubkey!("worm2ZoG2kUd4vFXhvjh93UUH596ayRfgQ2MgjNMTth");
+
+/// post_message in the core bridge's instruction enum
+const WORMHOLE_POST_MESSAGE: u8 = 1;
+
+/// Guardians sign once the burn is finalized (the light client's
+/// WormholeConfig.min_consistency_level is 1 for Solana)
+const WORMHOLE_CONSISTENCY_FINALIZED: u8 = 1;
+
+/// Post `payload` as a Wormhole message from the ["emitter"] PDA
+///
+/// The message account is the ["burn_message", nonce] PDA, so a burn is
+/// published at most once. The user pays the core bridge fee, read from
+/// its Bridge account (BridgeData: guardian_set_index(4) || last_lamports(8)
+/// || guardian_set_expiration_time(4) || fee(8)).
+fn post_burn_message(
+    accounts: &BurnRegisteredAsset,
+    nonce: u64,
+    payload: &[u8],
+    message_bump: u8,
+    emitter_bump: u8,
+) -> Result<()> {
+    let fee = {
+        let data = accounts.wormhole_bridge.try_borrow_data()?;
+        let fee = data.get(16..24).ok_or(ErrorCode::InvalidWormholeBridge)?;
+        u64::from_le_bytes(fee.try_into().unwrap())
+    };
+    if fee > 0 {
+        invoke(
+            &system_instruction::transfer(accounts.user.key, accounts.wormhole_fee_collector.key, fee),
+            &[
+                accounts.user.to_account_info(),
+                accounts.wormhole_fee_collector.to_account_info(),
+                accounts.system_program.to_account_info(),
+            ],
+        )?;
+    }
+
+    let mut data = Vec::with_capacity(1 + 4 + 4 + payload.len() + 1);
+    data.push(WORMHOLE_POST_MESSAGE);
+    data.extend_from_slice(&0u32.to_le_bytes()); // Wormhole nonce (the burn nonce is in the payload)
+    data.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    data.extend_from_slice(payload);
+    data.push(WORMHOLE_CONSISTENCY_FINALIZED);
+
+    let ix = Instruction {
+        program_id: WORMHOLE_CORE_BRIDGE,
+        accounts: vec![
+            AccountMeta::new(accounts.wormhole_bridge.key(), false),
+            AccountMeta::new(accounts.wormhole_message.key(), true),
+            AccountMeta::new_readonly(accounts.wormhole_emitter.key(), true),
+            AccountMeta::new(accounts.wormhole_sequence.key(), false),
+            AccountMeta::new(accounts.user.key(), true),
+            AccountMeta::new(accounts.wormhole_fee_collector.key(), false),
+            AccountMeta::new_readonly(accounts.clock.key(), false),
+            AccountMeta::new_readonly(accounts.rent.key(), false),
+            AccountMeta::new_readonly(accounts.system_program.key(), false),
+        ],
+        data,
+    };
+    invoke_signed(
+        &ix,
+        &[
+            accounts.wormhole_bridge.to_account_info(),
+            accounts.wormhole_message.to_account_info(),
+            accounts.wormhole_emitter.to_account_info(),
+            accounts.wormhole_sequence.to_account_info(),
+            accounts.user.to_account_info(),
+            accounts.wormhole_fee_collector.to_account_info(),
+            accounts.clock.to_account_info(),
+            accounts.rent.to_account_info(),
+            accounts.system_program.to_account_info(),
+            accounts.wormhole_program.to_account_info(),
+        ],
+        &[
+            &[b"burn_message", &nonce.to_le_bytes(), &[message_bump]],
+            &[EMITTER_SEED, &[emitter_bump]],
+        ],
+    )?;
+    Ok(())
+}
+
+/// BurnRecord commitment: keccak256(user || amount || nonce)
+pub fn burn_record_hash(user: &Pubkey, amount: u64, nonce: u64) -> [u8; 32] {
+    keccak::hashv(&[user.as_ref(), &amount.to_le_bytes(), &nonce.to_le_bytes()]).to_bytes()
+}
+
+/// Shallowest confirmation depth a burner may choose (Solana slots); also
+/// the validators' minimum finality, used for burns recorded with depth 0
+pub const MIN_CONFIRMATION_DEPTH: u16 = 32;
+
+/// Deepest confirmation depth a burner may choose (~10 minutes of slots)
+pub const MAX_CONFIRMATION_DEPTH: u16 = 1500;
+
+/// Shortest record_grace_period the upgrade authority may set (7 days)
+pub const MIN_RECORD_GRACE_PERIOD: i64 = 7 * 24 * 60 * 60;
+
+/// Slots after a cancellable burn during which the burner may cancel it
+///
+/// Must stay below the validators' minimum finality (32 slots): they only
+/// attest burns older than that, so a cancelled burn is never attested.
+pub const CANCEL_WINDOW_SLOTS: u64 = 16;
+
+/// Largest settlement validator set
+pub const MAX_SETTLEMENT_VALIDATORS: usize = 16;
+
+/// Domain of every settlement signature (X1 mint acknowledgements and rotations)
+pub const SETTLEMENT_DOMAIN: &[u8] = b"XENCAT_X1_SETTLEMENT_V1";
+
+/// Message settlement validators sign to acknowledge a completed X1 mint
+///
+/// Format: sha256(SETTLEMENT_DOMAIN || "MINTED" || version || asset_id || nonce || user || amount || x1_processed_at)
+pub fn settlement_message(
+    version: u64,
+    asset_id: u8,
+    nonce: u64,
+    user: &Pubkey,
+    amount: u64,
+    x1_processed_at: i64,
+) -> [u8; 32] {
+    hash::hashv(&[
+        SETTLEMENT_DOMAIN,
+        b"MINTED",
+        &version.to_le_bytes(),
+        &[asset_id],
+        &nonce.to_le_bytes(),
+        user.as_ref(),
+        &amount.to_le_bytes(),
+        &x1_processed_at.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Message settlement validators sign to release an X1 return burn
+///
+/// Format: sha256(SETTLEMENT_DOMAIN || "RETURN" || version || asset_id || return_nonce || recipient || amount || x1_slot)
+pub fn return_release_message(
+    version: u64,
+    asset_id: u8,
+    return_nonce: u64,
+    recipient: &Pubkey,
+    amount: u64,
+    x1_slot: u64,
+) -> [u8; 32] {
+    hash::hashv(&[
+        SETTLEMENT_DOMAIN,
+        b"RETURN",
+        &version.to_le_bytes(),
+        &[asset_id],
+        &return_nonce.to_le_bytes(),
+        recipient.as_ref(),
+        &amount.to_le_bytes(),
+        &x1_slot.to_le_bytes(),
+    ])
+    .to_bytes()
+}
+
+/// Message the current settlement validators sign to replace the set
+///
+/// Format: sha256(SETTLEMENT_DOMAIN || "ROTATE" || version || threshold || validators...)
+pub fn settlement_rotation_message(version: u64, validators: &[Pubkey], threshold: u8) -> [u8; 32] {
+    let mut parts: Vec<&[u8]> = Vec::with_capacity(validators.len() + 4);
+    let version_bytes = version.to_le_bytes();
+    let threshold_bytes = [threshold];
+    parts.push(SETTLEMENT_DOMAIN);
+    parts.push(b"ROTATE");
+    parts.push(&version_bytes);
+    parts.push(&threshold_bytes);
+    parts.extend(validators.iter().map(|v| v.as_ref()));
+    hash::hashv(&parts).to_bytes()
+}
+
+/// Non-empty, distinct, at most MAX_SETTLEMENT_VALIDATORS, majority threshold
+fn validate_settlement_set(validators: &[Pubkey], threshold: u8) -> Result<()> {
+    require!(
+        !validators.is_empty() && validators.len() <= MAX_SETTLEMENT_VALIDATORS,
+        ErrorCode::InvalidSettlementSet
+    );
+    for (i, validator) in validators.iter().enumerate() {
+        require!(!validators[..i].contains(validator), ErrorCode::InvalidSettlementSet);
+    }
+    require!(
+        threshold as usize <= validators.len() && (threshold as usize) * 2 > validators.len(),
+        ErrorCode::InvalidSettlementSet
+    );
+    Ok(())
+}
+
+/// Distinct members of `validators` that signed `message` in the Ed25519
+/// instructions before the current one
+///
+/// The Ed25519 program has already verified each signature when this runs;
+/// only which key signed which message is read here.
+fn count_settlement_signers(instructions: &AccountInfo, validators: &[Pubkey], message: &[u8; 32]) -> Result<usize> {
+    let current = load_current_index_checked(instructions)? as usize;
+    let mut signers: Vec<Pubkey> = Vec::new();
+    for index in 0..current {
+        let ix = load_instruction_at_checked(index, instructions)?;
+        if ix.program_id != ed25519_program::ID {
+            continue;
+        }
+        let (pubkey, signed) = parse_ed25519_instruction(&ix.data)?;
+        if signed == *message && validators.contains(&pubkey) && !signers.contains(&pubkey) {
+            signers.push(pubkey);
+        }
+    }
+    Ok(signers.len())
+}
+
+/// (public key, message) of a single-signature Ed25519 instruction
+///
+/// Every offset must refer to the instruction's own data (index u16::MAX),
+/// so it cannot borrow a message or key from another instruction.
+fn parse_ed25519_instruction(data: &[u8]) -> Result<(Pubkey, [u8; 32])> {
+    require!(data.len() >= 16 && data[0] == 1, ErrorCode::InvalidEd25519Instruction);
+    let read = |at: usize| u16::from_le_bytes([data[at], data[at + 1]]);
+    require!(
+        read(4) == u16::MAX && read(8) == u16::MAX && read(14) == u16::MAX,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    let pubkey_offset = read(6) as usize;
+    let message_offset = read(10) as usize;
+    require!(
+        read(12) == 32 && data.len() >= pubkey_offset + 32 && data.len() >= message_offset + 32,
+        ErrorCode::InvalidEd25519Instruction
+    );
+
+    let mut pubkey = [0u8; 32];
+    pubkey.copy_from_slice(&data[pubkey_offset..pubkey_offset + 32]);
+    let mut message = [0u8; 32];
+    message.copy_from_slice(&data[message_offset..message_offset + 32]);
+    Ok((Pubkey::new_from_array(pubkey), message))
+}
+
+#[derive(Accounts)]
+pub struct Initialize<'info> {
+    /// Global state PDA for tracking nonces
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + GlobalState::INIT_SPACE,
+        seeds = [b"global_state"],
+        bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// Authority initializing the program (pays for PDA)
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateGlobalState<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    /// CHECK: Address pinned by seeds; grown if needed, then loaded as GlobalState
+    #[account(mut, seeds = [b"global_state"], bump)]
+    pub global_state: UncheckedAccount<'info>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnXencat<'info> {
+    /// User burning tokens
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    /// Global state for nonce tracking
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    /// Burn record PDA (stores hash for relayer verification)
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// Token mint to burn from
+    /// For mainnet: 7UN8WkBumTUCofVPXCPjNWQ6msQhzrg9tFQRP48Nmw5V (XENCAT)
+    /// For devnet: Pass any test token mint
+    #[account(mut)]
+    pub xencat_mint: Account<'info, Mint>,
+
+    /// User's token account
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == xencat_mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    /// Token program
+    pub token_program: Program<'info, Token>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(subscription_id: u64)]
+pub struct CreateSubscription<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + Subscription::INIT_SPACE,
+        seeds = [b"subscription", user.key().as_ref(), subscription_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == xencat_mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ExecuteSubscriptionBurn<'info> {
+    /// Anyone can crank; pays the BurnRecord rent
+    #[account(mut)]
+    pub cranker: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            b"subscription",
+            subscription.user.as_ref(),
+            subscription.subscription_id.to_le_bytes().as_ref()
+        ],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = cranker,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    #[account(mut, address = subscription.mint @ ErrorCode::InvalidMint)]
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(mut, address = subscription.token_account @ ErrorCode::InvalidOwner)]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelSubscription<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        has_one = user @ ErrorCode::InvalidOwner,
+        seeds = [b"subscription", user.key().as_ref(), subscription.subscription_id.to_le_bytes().as_ref()],
+        bump = subscription.bump
+    )]
+    pub subscription: Account<'info, Subscription>,
+
+    #[account(mut, address = subscription.token_account @ ErrorCode::InvalidOwner)]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeInsuranceFund<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + InsuranceFund::INIT_SPACE,
+        seeds = [b"insurance_fund", xencat_mint.key().as_ref()],
+        bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    /// Premium vault, owned by the insurance fund PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"insurance_vault", xencat_mint.key().as_ref()],
+        bump,
+        token::mint = xencat_mint,
+        token::authority = insurance_fund,
+    )]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct RegisterAsset<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RegisteredAsset::INIT_SPACE,
+        seeds = [b"burn_asset", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub registered_asset: Account<'info, RegisteredAsset>,
+
+    /// One asset id per mint
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + RegisteredMint::INIT_SPACE,
+        seeds = [b"burn_asset_mint", mint.key().as_ref()],
+        bump
+    )]
+    pub registered_mint: Account<'info, RegisteredMint>,
+
+    pub mint: Account<'info, Mint>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetAssetActive<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_asset", registered_asset.asset_id.to_le_bytes().as_ref()],
+        bump = registered_asset.bump
+    )]
+    pub registered_asset: Account<'info, RegisteredAsset>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct CreateBetaAllowlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [b"burn_asset", asset_id.to_le_bytes().as_ref()],
+        bump = registered_asset.bump
+    )]
+    pub registered_asset: Account<'info, RegisteredAsset>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + BetaAllowlist::INIT_SPACE,
+        seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub beta_allowlist: Account<'info, BetaAllowlist>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateBetaAllowlist<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"beta_allowlist", beta_allowlist.asset_id.to_le_bytes().as_ref()],
+        bump = beta_allowlist.bump
+    )]
+    pub beta_allowlist: Account<'info, BetaAllowlist>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBetaAllowlist<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        close = authority,
+        seeds = [b"beta_allowlist", beta_allowlist.asset_id.to_le_bytes().as_ref()],
+        bump = beta_allowlist.bump
+    )]
+    pub beta_allowlist: Account<'info, BetaAllowlist>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+}
+
+#[derive(Accounts)]
+#[instruction(asset_id: u8)]
+pub struct BurnRegisteredAsset<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_asset", asset_id.to_le_bytes().as_ref()],
+        bump = registered_asset.bump
+    )]
+    pub registered_asset: Account<'info, RegisteredAsset>,
+
+    /// The asset's beta allowlist (not created = permissionless)
+    /// CHECK: Address pinned by seeds; read by BetaAllowlist::enforce
+    #[account(seeds = [b"beta_allowlist", asset_id.to_le_bytes().as_ref()], bump)]
+    pub beta_allowlist: UncheckedAccount<'info>,
+
+    #[account(mut, address = registered_asset.mint @ ErrorCode::InvalidMint)]
+    pub mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    /// Wormhole core bridge config (holds the message fee)
+    /// CHECK: Address pinned by seeds; read for the fee, checked by the core bridge
+    #[account(mut, seeds = [b"Bridge"], bump, seeds::program = WORMHOLE_CORE_BRIDGE)]
+    pub wormhole_bridge: UncheckedAccount<'info>,
+
+    /// The burn's Wormhole message, created by the core bridge
+    /// CHECK: Address pinned by seeds (one message per burn nonce)
+    #[account(
+        mut,
+        seeds = [b"burn_message", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub wormhole_message: UncheckedAccount<'info>,
+
+    /// This program's Wormhole emitter (the light client's WormholeConfig
+    /// emitter_address)
+    /// CHECK: Address pinned by seeds; signs post_message
+    #[account(seeds = [EMITTER_SEED], bump)]
+    pub wormhole_emitter: UncheckedAccount<'info>,
+
+    /// CHECK: Address pinned by seeds; updated by the core bridge
+    #[account(
+        mut,
+        seeds = [b"Sequence", wormhole_emitter.key().as_ref()],
+        bump,
+        seeds::program = WORMHOLE_CORE_BRIDGE
+    )]
+    pub wormhole_sequence: UncheckedAccount<'info>,
+
+    /// CHECK: Address pinned by seeds; receives the message fee
+    #[account(mut, seeds = [b"fee_collector"], bump, seeds::program = WORMHOLE_CORE_BRIDGE)]
+    pub wormhole_fee_collector: UncheckedAccount<'info>,
+
+    /// CHECK: Address checked
+    #[account(address = WORMHOLE_CORE_BRIDGE)]
+    pub wormhole_program: UncheckedAccount<'info>,
+
+    pub clock: Sysvar<'info, Clock>,
+    pub rent: Sysvar<'info, Rent>,
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnXencatInsured<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// Coverage record for this burn (same nonce as the BurnRecord)
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnInsurance::INIT_SPACE,
+        seeds = [b"burn_insurance", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_insurance: Account<'info, BurnInsurance>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", xencat_mint.key().as_ref()],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(mut, address = insurance_fund.vault @ ErrorCode::InvalidMint)]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    #[account(mut)]
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == xencat_mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnXencatForRecipient<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// Relayer claim terms for this burn (same nonce as the BurnRecord)
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnClaim::INIT_SPACE,
+        seeds = [b"burn_claim", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_claim: Account<'info, BurnClaim>,
+
+    #[account(mut)]
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == xencat_mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct PayInsuranceClaim<'info> {
+    #[account(address = insurance_fund.claims_authority @ ErrorCode::Unauthorized)]
+    pub claims_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"insurance_fund", insurance_fund.mint.as_ref()],
+        bump = insurance_fund.bump
+    )]
+    pub insurance_fund: Account<'info, InsuranceFund>,
+
+    #[account(
+        mut,
+        constraint = burn_insurance.mint == insurance_fund.mint @ ErrorCode::InvalidMint,
+        seeds = [b"burn_insurance", burn_insurance.nonce.to_le_bytes().as_ref()],
+        bump = burn_insurance.bump
+    )]
+    pub burn_insurance: Account<'info, BurnInsurance>,
+
+    #[account(mut, address = insurance_fund.vault @ ErrorCode::InvalidMint)]
+    pub insurance_vault: Account<'info, TokenAccount>,
+
+    /// Must belong to the original burner
+    #[account(
+        mut,
+        constraint = beneficiary_token_account.owner == burn_insurance.user @ ErrorCode::InvalidOwner,
+        constraint = beneficiary_token_account.mint == insurance_fund.mint @ ErrorCode::InvalidMint,
+    )]
+    pub beneficiary_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeSettlementConfig<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + SettlementConfig::INIT_SPACE,
+        seeds = [b"settlement_config"],
+        bump
+    )]
+    pub settlement_config: Account<'info, SettlementConfig>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateSettlementValidators<'info> {
+    #[account(
+        mut,
+        seeds = [b"settlement_config"],
+        bump = settlement_config.bump
+    )]
+    pub settlement_config: Account<'info, SettlementConfig>,
+
+    /// CHECK: Instructions sysvar (Ed25519 signatures of the current set)
+    #[account(address = IX_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleBurn<'info> {
+    /// Anyone can settle; pays the BurnSettlement rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"settlement_config"],
+        bump = settlement_config.bump
+    )]
+    pub settlement_config: Account<'info, SettlementConfig>,
+
+    #[account(
+        seeds = [b"burn_record", burn_record.nonce.to_le_bytes().as_ref()],
+        bump = burn_record.bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + BurnSettlement::INIT_SPACE,
+        seeds = [b"burn_settlement", burn_record.nonce.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_settlement: Account<'info, BurnSettlement>,
+
+    /// CHECK: Instructions sysvar (Ed25519 acknowledgements)
+    #[account(address = IX_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CloseBurnRecord<'info> {
+    #[account(
+        mut,
+        close = burner,
+        seeds = [b"burn_record", burn_record.nonce.to_le_bytes().as_ref()],
+        bump = burn_record.bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// Proof the X1 mint completed
+    #[account(
+        seeds = [b"burn_settlement", burn_record.nonce.to_le_bytes().as_ref()],
+        bump = burn_settlement.bump,
+        constraint = burn_settlement.user == burn_record.attested_user() @ ErrorCode::InvalidOwner,
+    )]
+    pub burn_settlement: Account<'info, BurnSettlement>,
+
+    /// CHECK: The BurnRecord's user; receives the rent
+    #[account(mut, address = burn_record.user @ ErrorCode::InvalidOwner)]
+    pub burner: UncheckedAccount<'info>,
+}
+
+#[derive(Accounts)]
+pub struct ReclaimBurnRecord<'info> {
+    #[account(
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        close = burner,
+        seeds = [b"burn_record", burn_record.nonce.to_le_bytes().as_ref()],
+        bump = burn_record.bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// The BurnRecord's user; receives the rent
+    #[account(mut, address = burn_record.user @ ErrorCode::InvalidOwner)]
+    pub burner: Signer<'info>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeCancelReserve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + CancelReserve::INIT_SPACE,
+        seeds = [b"cancel_reserve", xencat_mint.key().as_ref()],
+        bump
+    )]
+    pub cancel_reserve: Account<'info, CancelReserve>,
+
+    /// Refund vault, owned by the cancel reserve PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"cancel_reserve_vault", xencat_mint.key().as_ref()],
+        bump,
+        token::mint = xencat_mint,
+        token::authority = cancel_reserve,
+    )]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct BurnXencatCancellable<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        init,
+        payer = user,
+        space = 8 + BurnRecord::INIT_SPACE,
+        seeds = [b"burn_record", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    /// Cancellation record for this burn (same nonce as the BurnRecord)
+    #[account(
+        init,
+        payer = user,
+        space = 8 + PendingBurn::INIT_SPACE,
+        seeds = [b"pending_burn", global_state.nonce_counter.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub pending_burn: Account<'info, PendingBurn>,
+
+    /// The mint must have a reserve to refund from
+    #[account(
+        seeds = [b"cancel_reserve", xencat_mint.key().as_ref()],
+        bump = cancel_reserve.bump
+    )]
+    pub cancel_reserve: Account<'info, CancelReserve>,
+
+    #[account(mut)]
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == xencat_mint.key() @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CancelBurn<'info> {
+    #[account(address = pending_burn.user @ ErrorCode::Unauthorized)]
+    pub user: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [b"global_state"],
+        bump = global_state.bump
+    )]
+    pub global_state: Account<'info, GlobalState>,
+
+    #[account(
+        mut,
+        seeds = [b"burn_record", pending_burn.nonce.to_le_bytes().as_ref()],
+        bump = burn_record.bump
+    )]
+    pub burn_record: Account<'info, BurnRecord>,
+
+    #[account(
+        mut,
+        seeds = [b"pending_burn", pending_burn.nonce.to_le_bytes().as_ref()],
+        bump = pending_burn.bump
+    )]
+    pub pending_burn: Account<'info, PendingBurn>,
+
+    #[account(
+        mut,
+        constraint = cancel_reserve.mint == pending_burn.mint @ ErrorCode::InvalidMint,
+        seeds = [b"cancel_reserve", cancel_reserve.mint.as_ref()],
+        bump = cancel_reserve.bump
+    )]
+    pub cancel_reserve: Account<'info, CancelReserve>,
+
+    #[account(mut, address = cancel_reserve.vault @ ErrorCode::InvalidMint)]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        mut,
+        constraint = user_token_account.owner == user.key() @ ErrorCode::InvalidOwner,
+        constraint = user_token_account.mint == pending_burn.mint @ ErrorCode::InvalidMint,
+    )]
+    pub user_token_account: Account<'info, TokenAccount>,
+
+    pub token_program: Program<'info, Token>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeReturnReserve<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        init,
+        payer = authority,
+        space = 8 + ReturnReserve::INIT_SPACE,
+        seeds = [b"return_reserve", xencat_mint.key().as_ref()],
+        bump
+    )]
+    pub return_reserve: Account<'info, ReturnReserve>,
+
+    /// Release vault, owned by the return reserve PDA
+    #[account(
+        init,
+        payer = authority,
+        seeds = [b"return_reserve_vault", xencat_mint.key().as_ref()],
+        bump,
+        token::mint = xencat_mint,
+        token::authority = return_reserve,
+    )]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    pub xencat_mint: Account<'info, Mint>,
+
+    #[account(constraint = program.programdata_address()? == Some(program_data.key()))]
+    pub program: Program<'info, crate::program::XencatBurn>,
+
+    #[account(constraint = program_data.upgrade_authority_address == Some(authority.key()) @ ErrorCode::Unauthorized)]
+    pub program_data: Account<'info, ProgramData>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+#[instruction(return_nonce: u64, recipient: Pubkey)]
+pub struct ReleaseReturn<'info> {
+    /// Anyone can release; pays the ReturnRelease rent
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [b"settlement_config"],
+        bump = settlement_config.bump
+    )]
+    pub settlement_config: Account<'info, SettlementConfig>,
+
+    #[account(
+        mut,
+        seeds = [b"return_reserve", return_reserve.mint.as_ref()],
+        bump = return_reserve.bump
+    )]
+    pub return_reserve: Account<'info, ReturnReserve>,
+
+    #[account(mut, address = return_reserve.vault @ ErrorCode::InvalidMint)]
+    pub reserve_vault: Account<'info, TokenAccount>,
+
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ReturnRelease::INIT_SPACE,
+        seeds = [
+            b"return_release",
+            return_reserve.asset_id.to_le_bytes().as_ref(),
+            return_nonce.to_le_bytes().as_ref()
+        ],
+        bump
+    )]
+    pub return_release: Account<'info, ReturnRelease>,
+
+    #[account(
+        mut,
+        constraint = recipient_token_account.owner == recipient @ ErrorCode::InvalidOwner,
+        constraint = recipient_token_account.mint == return_reserve.mint @ ErrorCode::InvalidMint,
+    )]
+    pub recipient_token_account: Account<'info, TokenAccount>,
+
+    /// CHECK: Instructions sysvar (Ed25519 acknowledgements)
+    #[account(address = IX_SYSVAR_ID)]
+    pub instructions: UncheckedAccount<'info>,
+
+    pub token_program: Program<'info, Token>,
+    pub system_program: Program<'info, System>,
+}
+
+/// Insurance fund for one mint (premiums held in `vault`)
+#[account]
+#[derive(InitSpace)]
+pub struct InsuranceFund {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub claims_authority: Pubkey,
+    pub premium_bps: u16,
+    pub total_premiums: u64,
+    pub total_claims_paid: u64,
+    pub bump: u8,
+}
+
+/// Per-transfer coverage, created alongside the BurnRecord of `nonce`
+#[account]
+#[derive(InitSpace)]
+pub struct BurnInsurance {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub covered_amount: u64,
+    pub premium: u64,
+    pub claimed: bool,
+    pub bump: u8,
+}
+
+/// Relayer claim terms of a burn_xencat_for_recipient burn
+///
+/// `user` is the BurnRecord's user (the X1 recipient); `burner` signed the burn.
+#[account]
+#[derive(InitSpace)]
+pub struct BurnClaim {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    /// Tokens (base units) the relayer keeps out of the minted amount
+    pub relayer_fee: u64,
+    pub burner: Pubkey,
+    pub bump: u8,
+}
+
+/// Recurring burn pre-authorization (SPL delegate = this PDA)
+#[account]
+#[derive(InitSpace)]
+pub struct Subscription {
+    pub user: Pubkey,
+    pub subscription_id: u64,
+    pub mint: Pubkey,
+    pub token_account: Pubkey,
+    pub amount_per_period: u64,
+    pub period_seconds: i64,
+    pub max_periods: u32,
+    pub periods_executed: u32,
+    pub next_due: i64,
+    pub active: bool,
+    pub bump: u8,
+}
+
+/// X1 validators that acknowledge completed mints (see settle_burn)
+#[account]
+#[derive(InitSpace)]
+pub struct SettlementConfig {
+    #[max_len(MAX_SETTLEMENT_VALIDATORS)]
+    pub validators: Vec<Pubkey>,
+    pub threshold: u8,
+    /// Bound into every signed message; bumped on each rotation
+    pub version: u64,
+    pub total_settled: u64,
+    pub bump: u8,
+}
+
+/// Solana-side mirror of the X1 mint for BurnRecord `nonce`
+#[account]
+#[derive(InitSpace)]
+pub struct BurnSettlement {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub asset_id: u8,
+    /// ProcessedBurnV3.processed_at on X1 (unix seconds)
+    pub x1_processed_at: i64,
+    pub settled_at: i64,
+    /// SettlementConfig version that acknowledged it
+    pub settlement_version: u64,
+    pub bump: u8,
+}
+
+/// Refund reserve for cancelled burns of one mint (tokens held in `vault`)
+#[account]
+#[derive(InitSpace)]
+pub struct CancelReserve {
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub total_cancelled: u64,
+    pub total_refunded: u64,
+    pub bump: u8,
+}
+
+/// Release reserve for X1 return burns of one mint (tokens held in `vault`)
+#[account]
+#[derive(InitSpace)]
+pub struct ReturnReserve {
+    /// Bridge asset id of `mint` (XENCAT = 1), bound into release messages
+    pub asset_id: u8,
+    pub mint: Pubkey,
+    pub vault: Pubkey,
+    pub total_released: u64,
+    pub total_amount: u64,
+    pub bump: u8,
+}
+
+/// Release of X1 return burn `return_nonce` (replay guard, never closed)
+#[account]
+#[derive(InitSpace)]
+pub struct ReturnRelease {
+    pub asset_id: u8,
+    pub return_nonce: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    /// X1 slot of the return burn
+    pub x1_slot: u64,
+    pub released_at: i64,
+    /// SettlementConfig version that acknowledged it
+    pub settlement_version: u64,
+    pub bump: u8,
+}
+
+/// Cancellation state of a burn_xencat_cancellable burn `nonce`
+#[account]
+#[derive(InitSpace)]
+pub struct PendingBurn {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    /// Slot of the burn; the cancel window counts from here
+    pub slot: u64,
+    pub cancelled: bool,
+    pub bump: u8,
+}
+
+/// Burnable asset (see register_asset)
+///
+/// The Solana-side source of truth for asset ids: mirrors the light
+/// client's asset registry entry for the same `asset_id`.
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredAsset {
+    pub asset_id: u8,
+    pub mint: Pubkey,
+    pub active: bool,
+    pub total_burns: u64,
+    pub total_amount_burned: u64,
+    pub bump: u8,
+}
+
+/// Burners allowed to burn a registered asset during its beta
+///
+/// Exists only while the asset is in beta; closing it makes the asset
+/// permissionless.
+#[account]
+#[derive(InitSpace)]
+pub struct BetaAllowlist {
+    pub asset_id: u8,
+    #[max_len(32)]
+    pub users: Vec<Pubkey>,
+    pub bump: u8,
+}
+
+impl BetaAllowlist {
+    pub const MAX_USERS: usize = 32;
+
+    /// Bounded, not empty (that would halt the asset) and no duplicates
+    pub fn is_valid_list(users: &[Pubkey]) -> bool {
+        !users.is_empty()
+            && users.len() <= Self::MAX_USERS
+            && users.iter().enumerate().all(|(i, user)| !users[..i].contains(user))
+    }
+
+    /// Reject `user` unless the list does not exist or lists it
+    ///
+    /// `info` must be the ["beta_allowlist", asset_id] PDA (callers pin it with seeds).
+    pub fn enforce(info: &AccountInfo, user: &Pubkey) -> Result<()> {
+        if info.data_is_empty() {
+            return Ok(());
+        }
+        require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidBetaAllowlist);
+        let list = BetaAllowlist::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+        require!(list.users.contains(user), ErrorCode::BurnerNotAllowlisted);
+        Ok(())
+    }
+}
+
+/// Reverse index of RegisteredAsset: the asset id of a mint
+#[account]
+#[derive(InitSpace)]
+pub struct RegisteredMint {
+    pub asset_id: u8,
+    pub bump: u8,
+}
+
+/// Global state tracking burn nonces
+#[account]
+#[derive(InitSpace)]
+pub struct GlobalState {
+    pub nonce_counter: u64,
+    pub total_burns: u64,
+    pub total_amount_burned: u64,
+    pub bump: u8,
+    // Appended after bump so update_burn_limits can grow older accounts
+    /// Smallest amount of one burn (0 = no minimum)
+    pub min_burn_amount: u64,
+    /// Largest amount of one burn (0 = no maximum)
+    pub max_burn_amount: u64,
+    /// Seconds after a burn before its burner may reclaim the record (0 = never)
+    pub record_grace_period: i64,
+}
+
+impl GlobalState {
+    /// Reject an amount outside the configured burn limits
+    pub fn check_amount(&self, amount: u64) -> Result<()> {
+        require!(amount >= self.min_burn_amount, ErrorCode::BurnBelowMinimum);
+        require!(self.max_burn_amount == 0 || amount <= self.max_burn_amount, ErrorCode::BurnAboveMaximum);
+        Ok(())
+    }
+}
+
+/// Individual burn record with hash for relayer verification
+#[account]
+#[derive(InitSpace)]
+pub struct BurnRecord {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: u64,
+    pub record_hash: [u8; 32],  // keccak256(attested_user || amount || nonce), amount 0 once cancelled
+    pub confirmation_depth: u16,  // Slots validators wait before attesting (0 = their default)
+    pub destination: Pubkey,  // X1 key attested for (default = user), see burn_xencat_to
+    pub memo: [u8; 64],  // Burner's memo (all zero = none)
+    pub asset_id: u8,  // Light client asset id (0 = not recorded, see burn_registered_asset)
+    pub bump: u8,
+}
+
+impl BurnRecord {
+    /// Key validators attest the burn for and the X1 mint credits
+    pub fn attested_user(&self) -> Pubkey {
+        if self.destination == Pubkey::default() {
+            self.user
+        } else {
+            self.destination
+        }
+    }
+}
+
+/// Event emitted when tokens are burned (for relayer)
+#[event]
+pub struct Burned {
+    pub user: Pubkey,
+    pub amount: u64,
+    pub nonce: u64,
+    pub timestamp: u64,
+}
+
+/// Stealth payment announcement (see burn_to_stealth)
+#[event]
+pub struct StealthAnnouncement {
+    pub nonce: u64,
+    pub stealth_pubkey: Pubkey,
+    pub ephemeral_pubkey: [u8; 32],
+}
+
+/// Event emitted for each insured burn (alongside Burned)
+#[event]
+pub struct InsuredBurn {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub covered_amount: u64,
+    pub premium: u64,
+}
+
+/// Event emitted for each burn_xencat_for_recipient burn (alongside Burned)
+#[event]
+pub struct RecipientDesignated {
+    pub nonce: u64,
+    pub burner: Pubkey,
+    pub recipient: Pubkey,
+    pub relayer_fee: u64,
+}
+
+/// Event emitted for each burn_xencat_to burn (alongside Burned)
+#[event]
+pub struct DestinationBound {
+    pub nonce: u64,
+    pub burner: Pubkey,
+    pub destination: Pubkey,
+    pub memo: [u8; 64],
+}
+
+/// Event emitted when a BurnRecord is closed: the record as it was, so
+/// relayers can still prove the burn from archived transaction data
+#[event]
+pub struct BurnRecordFinalized {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub attested_user: Pubkey,
+    pub amount: u64,
+    pub timestamp: u64,
+    pub record_hash: [u8; 32],
+    pub confirmation_depth: u16,
+    pub asset_id: u8,
+    /// Closed after settlement (close_burn_record) rather than after the
+    /// grace period (reclaim_burn_record)
+    pub settled: bool,
+    pub closed_at: i64,
+}
+
+/// Event emitted when the burn amount limits change
+#[event]
+pub struct BurnLimitsUpdated {
+    pub min_burn_amount: u64,
+    pub max_burn_amount: u64,
+}
+
+/// Event emitted for each burn_registered_asset burn (alongside Burned)
+#[event]
+pub struct AssetBurned {
+    pub nonce: u64,
+    pub asset_id: u8,
+    pub mint: Pubkey,
+}
+
+/// Event emitted when an insurance claim is paid
+#[event]
+pub struct InsuranceClaimPaid {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub payout: u64,
+}
+
+/// Event emitted for each executed subscription burn (alongside Burned)
+#[event]
+pub struct SubscriptionBurned {
+    pub subscription: Pubkey,
+    pub user: Pubkey,
+    pub nonce: u64,
+    pub period: u32,
+}
+
+/// Event emitted when a burn's X1 mint is mirrored (see settle_burn)
+#[event]
+pub struct BurnSettled {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub asset_id: u8,
+    pub x1_processed_at: i64,
+}
+
+/// Event emitted when a pending burn is cancelled and refunded
+#[event]
+pub struct BurnCancelled {
+    pub nonce: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+/// Event emitted when an X1 return burn is released on Solana
+#[event]
+pub struct ReturnReleased {
+    pub asset_id: u8,
+    pub return_nonce: u64,
+    pub recipient: Pubkey,
+    pub amount: u64,
+    pub x1_slot: u64,
 }
 
 #[error_code]
@@ -199,4 +2790,56 @@ pub enum ErrorCode {
     CounterOverflow,
     #[msg("Amount overflow")]
     AmountOverflow,
+    #[msg("Invalid subscription period")]
+    InvalidPeriod,
+    #[msg("Subscription is cancelled or fully executed")]
+    SubscriptionInactive,
+    #[msg("Subscription burn is not due yet")]
+    SubscriptionNotDue,
+    #[msg("Invalid stealth key: must differ from the burner and the default key")]
+    InvalidStealthKey,
+    #[msg("Invalid insurance premium")]
+    InvalidPremium,
+    #[msg("Insurance claim already paid for this burn")]
+    ClaimAlreadyPaid,
+    #[msg("Unauthorized")]
+    Unauthorized,
+    #[msg("Invalid settlement validator set or threshold")]
+    InvalidSettlementSet,
+    #[msg("Not enough settlement validator acknowledgements")]
+    InsufficientSettlementSignatures,
+    #[msg("Invalid Ed25519 instruction")]
+    InvalidEd25519Instruction,
+    #[msg("Cancel window has closed for this burn")]
+    CancelWindowClosed,
+    #[msg("Burn already cancelled")]
+    BurnAlreadyCancelled,
+    #[msg("Confirmation depth is outside the allowed bounds")]
+    InvalidConfirmationDepth,
+    #[msg("Invalid recipient: must not be the default key")]
+    InvalidRecipient,
+    #[msg("Relayer fee must be below the burned amount")]
+    InvalidRelayerFee,
+    #[msg("Invalid asset id: 0 is reserved for unregistered burns")]
+    InvalidAssetId,
+    #[msg("Asset is not active for burning")]
+    AssetNotActive,
+    #[msg("Invalid beta allowlist")]
+    InvalidBetaAllowlist,
+    #[msg("Burner is not on the asset's beta allowlist")]
+    BurnerNotAllowlisted,
+    #[msg("Invalid burn limits: minimum above maximum")]
+    InvalidBurnLimits,
+    #[msg("Burn amount is below the minimum")]
+    BurnBelowMinimum,
+    #[msg("Burn amount is above the maximum")]
+    BurnAboveMaximum,
+    #[msg("Invalid grace period: 0 or at least MIN_RECORD_GRACE_PERIOD")]
+    InvalidGracePeriod,
+    #[msg("Reclaiming burn records is disabled")]
+    ReclaimDisabled,
+    #[msg("Burn record grace period is not over yet")]
+    GracePeriodNotOver,
+    #[msg("Wormhole bridge account is too short to hold the message fee")]
+    InvalidWormholeBridge,
 }
@@ -0,0 +1,162 @@
+//! Controllable clock for handler and helper tests
+//!
+//! Program code reads time with `Clock::get()`. Off-chain (`cargo test`)
+//! that call is served by solana_program's syscall stubs, so installing a
+//! stub backed by a clock the test owns makes finality checks, timelocks,
+//! expiries and rate limits deterministic without touching program code.
+//! On `target_os = "solana"` the stubs do not exist and the real sysvar is
+//! used.
+//!
+//! ```ignore
+//! let clock = test_clock();
+//! clock.warp_to_slot(1_000_000);
+//! // ... call code that checks Clock::get()?.slot ...
+//! clock.advance_seconds(3_600);
+//! ```
+//!
+//! The stubs are process-wide, so `test_clock()` also serializes the tests
+//! that use it: hold the returned guard for the whole test.
+
+use anchor_lang::solana_program::clock::Clock;
+use anchor_lang::solana_program::entrypoint::SUCCESS;
+use anchor_lang::solana_program::program_stubs::{set_syscall_stubs, SyscallStubs};
+use std::sync::{Mutex, MutexGuard, Once};
+
+/// Nominal slot time used when a warp moves slots and unix time together
+pub const MS_PER_SLOT: u64 = 400;
+
+/// Slots per epoch on Solana mainnet and X1
+pub const SLOTS_PER_EPOCH: u64 = 432_000;
+
+const GENESIS: Clock = Clock {
+    slot: 0,
+    epoch_start_timestamp: 0,
+    epoch: 0,
+    leader_schedule_epoch: 0,
+    unix_timestamp: 0,
+};
+
+static CLOCK: Mutex<Clock> = Mutex::new(GENESIS);
+static SERIAL: Mutex<()> = Mutex::new(());
+
+struct ClockStub;
+
+impl SyscallStubs for ClockStub {
+    // msg! output of thousands of generated cases is noise
+    fn sol_log(&self, _message: &str) {}
+
+    fn sol_get_clock_sysvar(&self, var_addr: *mut u8) -> u64 {
+        let clock = current();
+        // Clock::get passes a pointer to its own (aligned) Clock
+        unsafe {
+            *(var_addr as *mut Clock) = clock;
+        }
+        SUCCESS
+    }
+}
+
+fn current() -> Clock {
+    CLOCK.lock().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Exclusive handle on the clock seen by `Clock::get()` (reset to genesis)
+pub struct TestClock {
+    _serial: MutexGuard<'static, ()>,
+}
+
+/// Install the clock stub (once) and take the clock for this test
+pub fn test_clock() -> TestClock {
+    static INSTALL: Once = Once::new();
+    INSTALL.call_once(|| {
+        set_syscall_stubs(Box::new(ClockStub));
+    });
+
+    // A failed test must not take every later clock test down with it
+    let serial = SERIAL.lock().unwrap_or_else(|e| e.into_inner());
+    let clock = TestClock { _serial: serial };
+    clock.set(GENESIS);
+    clock
+}
+
+impl TestClock {
+    pub fn get(&self) -> Clock {
+        current()
+    }
+
+    pub fn set(&self, clock: Clock) {
+        *CLOCK.lock().unwrap_or_else(|e| e.into_inner()) = clock;
+    }
+
+    /// Move to `slot`, moving unix time by MS_PER_SLOT per slot and
+    /// keeping the epoch consistent
+    pub fn warp_to_slot(&self, slot: u64) {
+        let mut clock = self.get();
+        let delta_ms = (slot as i128 - clock.slot as i128) * MS_PER_SLOT as i128;
+        clock.unix_timestamp += (delta_ms / 1_000) as i64;
+        clock.slot = slot;
+        let epoch = slot / SLOTS_PER_EPOCH;
+        if epoch != clock.epoch {
+            clock.epoch = epoch;
+            clock.leader_schedule_epoch = epoch + 1;
+            clock.epoch_start_timestamp = clock.unix_timestamp
+                - ((slot % SLOTS_PER_EPOCH) * MS_PER_SLOT / 1_000) as i64;
+        }
+        self.set(clock);
+    }
+
+    /// Move unix time only (slot unchanged), e.g. for long timelocks
+    pub fn warp_to_timestamp(&self, unix_timestamp: i64) {
+        let mut clock = self.get();
+        clock.unix_timestamp = unix_timestamp;
+        self.set(clock);
+    }
+
+    pub fn advance_slots(&self, slots: u64) {
+        self.warp_to_slot(self.get().slot + slots);
+    }
+
+    pub fn advance_seconds(&self, seconds: i64) {
+        self.warp_to_timestamp(self.get().unix_timestamp + seconds);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::sysvar::Sysvar;
+
+    #[test]
+    fn test_clock_get_follows_warps() {
+        let clock = test_clock();
+        assert_eq!(Clock::get().unwrap().slot, 0);
+
+        clock.warp_to_slot(1_000);
+        let now = Clock::get().unwrap();
+        assert_eq!(now.slot, 1_000);
+        assert_eq!(now.unix_timestamp, 400);
+
+        clock.advance_seconds(3_600);
+        assert_eq!(Clock::get().unwrap().unix_timestamp, 4_000);
+        assert_eq!(Clock::get().unwrap().slot, 1_000);
+    }
+
+    #[test]
+    fn test_warp_keeps_epoch_consistent() {
+        let clock = test_clock();
+        clock.warp_to_slot(SLOTS_PER_EPOCH * 3 + 10);
+        let now = clock.get();
+        assert_eq!(now.epoch, 3);
+        assert_eq!(now.leader_schedule_epoch, 4);
+        assert_eq!(now.unix_timestamp - now.epoch_start_timestamp, 4);
+    }
+
+    #[test]
+    fn test_clock_resets_per_test() {
+        let clock = test_clock();
+        clock.advance_slots(5);
+        drop(clock);
+
+        let clock = test_clock();
+        assert_eq!(clock.get().slot, 0);
+    }
+}
@@ -0,0 +1,237 @@
+//! Mock instructions sysvar and Ed25519 precompile instructions
+//!
+//! Program code introspects the transaction through the instructions
+//! sysvar account (`load_instruction_at_checked`,
+//! `load_current_index_checked`). This builds that account from a list of
+//! instructions the test chooses, so Ed25519 introspection can be tested
+//! without a validator, including layouts no honest client would send.
+//!
+//! ```ignore
+//! let mut sysvar = MockInstructions::new()
+//!     .instruction(ed25519_program::ID, Ed25519Ix::new(&message).build())
+//!     .instruction(program_id, vec![0u8; 8])
+//!     .current(1)
+//!     .build();
+//! load_bound_ed25519_instructions(&[0], &sysvar.account_info())?;
+//! ```
+
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::sysvar::instructions::{
+    construct_instructions_data, store_current_index, BorrowedAccountMeta, BorrowedInstruction, ID as IX_SYSVAR_ID,
+};
+
+/// Offset of the inline data after the 16-byte Ed25519 header
+pub const ED25519_HEADER_LEN: u16 = 16;
+
+/// Instruction index meaning "this instruction" in an Ed25519 header
+pub const ED25519_INLINE: u16 = u16::MAX;
+
+/// Canonical, non-zero signature accepted by check_signature_encoding
+/// (nothing verifies it cryptographically off-chain)
+pub const MOCK_SIGNATURE: [u8; 64] = {
+    let mut signature = [0x11u8; 64];
+    signature[63] = 0x01;
+    signature
+};
+
+/// One instruction of the mocked transaction
+#[derive(Clone, Debug)]
+pub struct MockInstruction {
+    pub program_id: Pubkey,
+    pub accounts: Vec<AccountMeta>,
+    pub data: Vec<u8>,
+}
+
+/// Builder for the instructions sysvar contents
+#[derive(Clone, Debug, Default)]
+pub struct MockInstructions {
+    instructions: Vec<MockInstruction>,
+    current: Option<u16>,
+    key: Option<Pubkey>,
+}
+
+impl MockInstructions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn instruction(self, program_id: Pubkey, data: Vec<u8>) -> Self {
+        self.instruction_with_accounts(program_id, vec![], data)
+    }
+
+    pub fn instruction_with_accounts(mut self, program_id: Pubkey, accounts: Vec<AccountMeta>, data: Vec<u8>) -> Self {
+        self.instructions.push(MockInstruction { program_id, accounts, data });
+        self
+    }
+
+    /// Index of the executing instruction (default: the last one)
+    pub fn current(mut self, index: u16) -> Self {
+        self.current = Some(index);
+        self
+    }
+
+    /// Address of the account (default: the real sysvar id), e.g. to pass
+    /// a spoofed account with valid contents
+    pub fn key(mut self, key: Pubkey) -> Self {
+        self.key = Some(key);
+        self
+    }
+
+    pub fn build(self) -> MockSysvar {
+        let metas: Vec<Vec<BorrowedAccountMeta>> = self
+            .instructions
+            .iter()
+            .map(|ix| {
+                ix.accounts
+                    .iter()
+                    .map(|meta| BorrowedAccountMeta {
+                        pubkey: &meta.pubkey,
+                        is_signer: meta.is_signer,
+                        is_writable: meta.is_writable,
+                    })
+                    .collect()
+            })
+            .collect();
+        let borrowed: Vec<BorrowedInstruction> = self
+            .instructions
+            .iter()
+            .zip(metas)
+            .map(|(ix, accounts)| BorrowedInstruction { program_id: &ix.program_id, accounts, data: &ix.data })
+            .collect();
+        let mut data = construct_instructions_data(&borrowed);
+        let current = self.current.unwrap_or(self.instructions.len().saturating_sub(1) as u16);
+        store_current_index(&mut data, current);
+        MockSysvar {
+            key: self.key.unwrap_or(IX_SYSVAR_ID),
+            owner: anchor_lang::solana_program::sysvar::ID,
+            lamports: 0,
+            data,
+        }
+    }
+}
+
+/// Owned instructions sysvar account
+pub struct MockSysvar {
+    key: Pubkey,
+    owner: Pubkey,
+    lamports: u64,
+    pub data: Vec<u8>,
+}
+
+impl MockSysvar {
+    pub fn account_info(&mut self) -> AccountInfo<'_> {
+        AccountInfo::new(&self.key, false, false, &mut self.lamports, &mut self.data, &self.owner, false, 0)
+    }
+}
+
+/// Ed25519 precompile instruction data, everything inline by default
+///
+/// Each header field can be overridden, so tests can build the offsets
+/// and instruction indices an attacker would choose.
+#[derive(Clone, Debug)]
+pub struct Ed25519Ix {
+    pub num_signatures: u8,
+    pub signature_offset: u16,
+    pub signature_instruction_index: u16,
+    pub public_key_offset: u16,
+    pub public_key_instruction_index: u16,
+    pub message_data_offset: u16,
+    pub message_data_size: u16,
+    pub message_instruction_index: u16,
+    pub signature: [u8; 64],
+    pub pubkey: Pubkey,
+    pub message: Vec<u8>,
+}
+
+impl Ed25519Ix {
+    /// Signature by a fresh key over `message`, precompile layout
+    pub fn new(message: &[u8]) -> Self {
+        Self {
+            num_signatures: 1,
+            signature_offset: ED25519_HEADER_LEN,
+            signature_instruction_index: ED25519_INLINE,
+            public_key_offset: ED25519_HEADER_LEN + 64,
+            public_key_instruction_index: ED25519_INLINE,
+            message_data_offset: ED25519_HEADER_LEN + 96,
+            message_data_size: message.len() as u16,
+            message_instruction_index: ED25519_INLINE,
+            signature: MOCK_SIGNATURE,
+            pubkey: Pubkey::new_unique(),
+            message: message.to_vec(),
+        }
+    }
+
+    pub fn signer(mut self, pubkey: Pubkey) -> Self {
+        self.pubkey = pubkey;
+        self
+    }
+
+    pub fn signature(mut self, signature: [u8; 64]) -> Self {
+        self.signature = signature;
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let mut data = Vec::with_capacity(ED25519_HEADER_LEN as usize + 96 + self.message.len());
+        data.push(self.num_signatures);
+        data.push(0);
+        for value in [
+            self.signature_offset,
+            self.signature_instruction_index,
+            self.public_key_offset,
+            self.public_key_instruction_index,
+            self.message_data_offset,
+            self.message_data_size,
+            self.message_instruction_index,
+        ] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&self.signature);
+        data.extend_from_slice(self.pubkey.as_ref());
+        data.extend_from_slice(&self.message);
+        data
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_lang::solana_program::sysvar::instructions::{load_current_index_checked, load_instruction_at_checked};
+
+    #[test]
+    fn test_mock_sysvar_round_trips() {
+        let program = Pubkey::new_unique();
+        let account = AccountMeta::new_readonly(Pubkey::new_unique(), true);
+        let mut sysvar = MockInstructions::new()
+            .instruction(program, vec![1, 2, 3])
+            .instruction_with_accounts(program, vec![account.clone()], vec![4])
+            .build();
+        let info = sysvar.account_info();
+
+        assert_eq!(load_current_index_checked(&info).unwrap(), 1);
+        assert_eq!(load_instruction_at_checked(0, &info).unwrap().data, vec![1, 2, 3]);
+        assert_eq!(load_instruction_at_checked(1, &info).unwrap().accounts, vec![account]);
+        assert!(load_instruction_at_checked(2, &info).is_err());
+    }
+
+    #[test]
+    fn test_spoofed_sysvar_key_is_rejected() {
+        let mut sysvar = MockInstructions::new()
+            .instruction(Pubkey::new_unique(), vec![])
+            .key(Pubkey::new_unique())
+            .build();
+        assert!(load_instruction_at_checked(0, &sysvar.account_info()).is_err());
+    }
+
+    #[test]
+    fn test_ed25519_layout() {
+        let ix = Ed25519Ix::new(&[7u8; 32]);
+        let data = ix.build();
+        assert_eq!(data.len(), 16 + 64 + 32 + 32);
+        assert_eq!(&data[16..80], &MOCK_SIGNATURE);
+        assert_eq!(&data[80..112], ix.pubkey.as_ref());
+        assert_eq!(&data[112..], &[7u8; 32]);
+        assert_eq!(u16::from_le_bytes([data[12], data[13]]), 32);
+        assert_eq!(u16::from_le_bytes([data[14], data[15]]), u16::MAX);
+    }
+}
@@ -0,0 +1,10 @@
+//! Test-only helpers for the bridge programs
+//!
+//! Used as a dev-dependency only, so nothing here can reach a deployed
+//! program.
+
+pub mod clock;
+pub mod instructions;
+
+pub use clock::{test_clock, TestClock, MS_PER_SLOT, SLOTS_PER_EPOCH};
+pub use instructions::{Ed25519Ix, MockInstructions, MockSysvar, MOCK_SIGNATURE};
@@ -269,7 +269,8 @@ describe('🔐 Secure Ed25519 Verification E2E', () => {
                 stake: vote.stake,
             })),
             proof.blockHash,
-            BigInt(proof.slot)
+            BigInt(proof.slot),
+            proof.stateRoot
         );
 
         console.log(`✅ Created ${ed25519Instructions.length} Ed25519Program instructions`);
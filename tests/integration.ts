@@ -3,6 +3,7 @@ import { Program } from "@coral-xyz/anchor";
 import { SolanaLightClientX1 } from "../target/types/solana_light_client_x1";
 import { XencatMintX1 } from "../target/types/xencat_mint_x1";
 import { expect } from "chai";
+import { checkBridgeInvariants, installInvariantChecks } from "./invariants";
 
 describe("integration: end-to-end", () => {
   const provider = anchor.AnchorProvider.env();
@@ -11,6 +12,20 @@ describe("integration: end-to-end", () => {
   const lightClientProgram = anchor.workspace.SolanaLightClientX1 as Program<SolanaLightClientX1>;
   const mintProgram = anchor.workspace.XencatMintX1 as Program<XencatMintX1>;
 
+  // Every transaction below is followed by the bridge invariant checks
+  const invariantPrograms = {
+    lightClient: lightClientProgram,
+    mints: [
+      { program: mintProgram, mintStateSeed: "mint_state_v2" },
+      { program: anchor.workspace.DgnMintX1, mintStateSeed: "dgn_mint_state" },
+    ],
+  };
+  installInvariantChecks(provider, invariantPrograms);
+
+  after(async () => {
+    await checkBridgeInvariants(invariantPrograms);
+  });
+
   it("Full flow: burn on Solana -> verify -> mint on X1", async () => {
     // TODO: Implement full end-to-end test
     // 1. Simulate Solana burn
@@ -0,0 +1,93 @@
+import * as anchor from "@coral-xyz/anchor";
+import { Program } from "@coral-xyz/anchor";
+import { PublicKey } from "@solana/web3.js";
+
+/**
+ * Bridge-wide invariants, checked against on-chain accounts
+ *
+ * Mirror of programs/solana-light-client-x1/src/invariants.rs (where the
+ * handlers assert them in debug builds):
+ * - processed implies verified: every mint-side ProcessedBurnV3 has a
+ *   matching VerifiedBurnV3, or was archived (at most as many unmatched
+ *   records per asset as the asset's BurnArchive leaves)
+ * - minted ≤ attested: a ProcessedBurnV3 amount never exceeds its
+ *   VerifiedBurnV3 amount
+ * - version monotonicity: the validator set version never goes back, and
+ *   no mint program records a version ahead of the light client's
+ *
+ * installInvariantChecks runs the checks after every transaction sent
+ * through the provider, so a new feature's state-machine bug fails the
+ * instruction that introduced it rather than a later test.
+ */
+
+export interface InvariantPrograms {
+    lightClient: Program<any>;
+    /** Mint programs and their MintState PDA seed */
+    mints: Array<{ program: Program<any>; mintStateSeed: string }>;
+}
+
+export class InvariantViolation extends Error {}
+
+const burnKey = (assetId: number, nonce: anchor.BN, user: PublicKey) => `${assetId}:${nonce.toString()}:${user.toBase58()}`;
+
+/** Check every invariant once; `lastVersion` carries monotonicity across calls */
+export async function checkBridgeInvariants(programs: InvariantPrograms, lastVersion = { value: 0 }): Promise<void> {
+    const violations: string[] = [];
+    const lightClient: any = programs.lightClient;
+
+    const verified = new Map<string, any>();
+    for (const { account } of await lightClient.account.verifiedBurnV3.all()) {
+        verified.set(burnKey(account.assetId, account.burnNonce, account.user), account);
+    }
+    const archivedLeaves = new Map<number, number>();
+    for (const { account } of await lightClient.account.burnArchive.all()) {
+        archivedLeaves.set(account.assetId, (archivedLeaves.get(account.assetId) ?? 0) + account.leafCount.toNumber());
+    }
+
+    const [validatorSetPda] = PublicKey.findProgramAddressSync([Buffer.from("x1_validator_set_v2")], lightClient.programId);
+    const validatorSet = await lightClient.account.x1ValidatorSet.fetchNullable(validatorSetPda);
+    const version: number = validatorSet ? validatorSet.version.toNumber() : 0;
+    if (version < lastVersion.value) {
+        violations.push(`validator set version went from ${lastVersion.value} to ${version}`);
+    }
+    lastVersion.value = Math.max(lastVersion.value, version);
+
+    for (const { program, mintStateSeed } of programs.mints) {
+        const mint: any = program;
+        const unmatched = new Map<number, number>();
+        for (const { account: processed } of await mint.account.processedBurnV3.all()) {
+            const attested = verified.get(burnKey(processed.assetId, processed.nonce, processed.user));
+            if (!attested) {
+                unmatched.set(processed.assetId, (unmatched.get(processed.assetId) ?? 0) + 1);
+            } else if (processed.amount.gt(attested.amount)) {
+                violations.push(`burn ${processed.assetId}/${processed.nonce} minted ${processed.amount} above the attested ${attested.amount}`);
+            }
+        }
+        for (const [assetId, count] of unmatched) {
+            if (count > (archivedLeaves.get(assetId) ?? 0)) {
+                violations.push(`${count} asset ${assetId} burns processed but neither verified nor archived (${program.programId.toBase58()})`);
+            }
+        }
+
+        const [mintStatePda] = PublicKey.findProgramAddressSync([Buffer.from(mintStateSeed)], program.programId);
+        const mintState = await mint.account.mintState.fetchNullable(mintStatePda);
+        if (mintState && validatorSet && mintState.validatorSetVersion.toNumber() > version) {
+            violations.push(`mint program at validator set version ${mintState.validatorSetVersion}, light client at ${version}`);
+        }
+    }
+
+    if (violations.length > 0) {
+        throw new InvariantViolation(`bridge invariants violated:\n  ${violations.join("\n  ")}`);
+    }
+}
+
+/** Check the invariants after every transaction the provider confirms */
+export function installInvariantChecks(provider: anchor.AnchorProvider, programs: InvariantPrograms): void {
+    const lastVersion = { value: 0 };
+    const sendAndConfirm = provider.sendAndConfirm.bind(provider);
+    provider.sendAndConfirm = async (...args: Parameters<typeof sendAndConfirm>) => {
+        const signature = await sendAndConfirm(...args);
+        await checkBridgeInvariants(programs, lastVersion);
+        return signature;
+    };
+}
@@ -0,0 +1,87 @@
+import fs from 'fs';
+import path from 'path';
+
+/**
+ * Local verification cache of finalized Solana burns
+ *
+ * Only burns that passed every attestation check AND are past finality are
+ * stored. BurnRecord PDAs are immutable once created, so a cached entry never
+ * goes stale; it only saves the RPC round trips (signature lookup, parsed
+ * transaction, account fetch) on repeat or backlog requests.
+ *
+ * The cache is a plain JSON file so it survives restarts and can be rebuilt
+ * at any time with the backfill mode (see index.ts, SECTION 6.6).
+ */
+export interface CachedBurn {
+    burn_nonce: number;
+    asset_id: number;
+    mint: string;
+    user: string;
+    amount: number;
+    slot: number;
+    /** Burn block time (unix seconds), signed into attestations */
+    timestamp?: number;
+    /** Confirmation depth chosen in the BurnRecord (missing = 0, the default) */
+    confirmation_depth?: number;
+    tx_signature: string;
+    /** Per-transfer insurance coverage recorded with the burn (null = not insured) */
+    insurance?: { covered_amount: number; premium: number } | null;
+    /** Relayer claim terms recorded with the burn (null = ordinary burn) */
+    relayer_claim?: { relayer_fee: number } | null;
+    cached_at: number;
+}
+
+export class BurnCache {
+    private entries = new Map<number, CachedBurn>();
+    private dirty = false;
+
+    constructor(private readonly file: string) {
+        if (fs.existsSync(file)) {
+            try {
+                const stored: CachedBurn[] = JSON.parse(fs.readFileSync(file, 'utf-8'));
+                stored.forEach(entry => this.entries.set(entry.burn_nonce, entry));
+            } catch (error: any) {
+                // A corrupt cache is only a performance problem - start empty
+                console.error(`⚠️  Ignoring unreadable burn cache ${file}: ${error.message}`);
+            }
+        }
+    }
+
+    get size(): number {
+        return this.entries.size;
+    }
+
+    get(burnNonce: number): CachedBurn | undefined {
+        return this.entries.get(burnNonce);
+    }
+
+    has(burnNonce: number): boolean {
+        return this.entries.has(burnNonce);
+    }
+
+    put(entry: Omit<CachedBurn, 'cached_at'>) {
+        this.entries.set(entry.burn_nonce, { ...entry, cached_at: Date.now() });
+        this.dirty = true;
+    }
+
+    /** Forget a burn whose Solana data turned out not to be final */
+    delete(burnNonce: number) {
+        if (this.entries.delete(burnNonce)) {
+            this.dirty = true;
+        }
+    }
+
+    /**
+     * Persist atomically (write + rename) so a crash never leaves a torn file
+     */
+    flush() {
+        if (!this.dirty) {
+            return;
+        }
+        const sorted = [...this.entries.values()].sort((a, b) => a.burn_nonce - b.burn_nonce);
+        const tmp = path.join(path.dirname(this.file), `.${path.basename(this.file)}.tmp`);
+        fs.writeFileSync(tmp, JSON.stringify(sorted));
+        fs.renameSync(tmp, this.file);
+        this.dirty = false;
+    }
+}
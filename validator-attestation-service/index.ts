@@ -1,37 +1,71 @@
 import express from 'express';
-import { Connection, PublicKey, Keypair, ParsedTransactionWithMeta, ParsedInstruction, PartiallyDecodedInstruction } from '@solana/web3.js';
+import { Connection, PublicKey, ParsedTransactionWithMeta, ParsedInstruction, PartiallyDecodedInstruction } from '@solana/web3.js';
 import * as anchor from '@coral-xyz/anchor';
-import nacl from 'tweetnacl';
 import bs58 from 'bs58';
+import crypto from 'crypto';
 import 'dotenv/config';
+import { startRequestSpan, withSpan, transferId, Span } from './tracing';
+import { loadConfig } from '../sdk/bridge-config/src';
+import { HEADER_VALIDATOR_KEY } from '../sdk/attestation-client/src/response-signing';
+import { DEFAULT_DOMAIN_SEPARATOR, fetchBridgeDomain } from '../sdk/attestation-client/src/bridge-domain';
+import { attestationDomain, verifiedX1ChainId } from '../sdk/attestation-client/src/x1-chain-id';
+import { fetchChallengeRequest } from '../sdk/attestation-client/src/attestation-challenge';
+import { TelemetryCollector, startTelemetry } from '../sdk/attestation-client/src/telemetry';
+import { version as PACKAGE_VERSION } from './package.json';
+import { BurnCache } from './burn-cache';
+import { createAlertDispatcher } from './notifier';
+import { Watchdog } from './watchdog';
+import { ReorgMonitor } from './reorg-monitor';
+import { ValidatorKey, ValidatorKeyRing } from './validator-keys';
 
 const app = express();
 app.use(express.json());
 
-// Configuration
-const SOLANA_RPC = process.env.SOLANA_RPC || 'https://api.mainnet-beta.solana.com';
-const BURN_PROGRAM_ID = new PublicKey('2ktujS2t9SRXE9cA4UVQJyDFH9genNR4GngfmGffjKkp');
+// Configuration (xencat.toml + env overrides, see xencat.example.toml)
+const { config } = loadConfig();
+const SOLANA_RPC = config.solana.rpc;
+const BURN_PROGRAM_ID = new PublicKey(config.programs.burn);
 const TOKEN_PROGRAM_ID = new PublicKey('TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA');
-const VALIDATOR_PRIVATE_KEY = process.env.VALIDATOR_PRIVATE_KEY; // Your validator key
-const PORT = process.env.PORT || 8080;
-const FINALITY_SLOTS = 32; // Wait 32 slots for finality
+const PORT = config.service.port;
+const FINALITY_SLOTS = config.attestation.finality_slots; // Validated >= 32
+const PROTOCOL_VERSION = 'v3-asset-aware';
+
+// Opt-in anonymous health beacon ([telemetry] endpoint); counts every
+// attestation endpoint call, 5xx = error
+const telemetry = new TelemetryCollector('validator-attestation-service', PACKAGE_VERSION, PROTOCOL_VERSION);
+app.use((req, res, next) => {
+    const started = Date.now();
+    res.on('finish', () => {
+        if (req.method === 'POST' && req.route) {
+            telemetry.record(req.route.path, res.statusCode < 500, Date.now() - started);
+        }
+    });
+    next();
+});
 
-if (!VALIDATOR_PRIVATE_KEY) {
-    throw new Error('VALIDATOR_PRIVATE_KEY required');
-}
+// Validator keys ([[keys.validators]], or the single keys.validator /
+// legacy VALIDATOR_PRIVATE_KEY), loaded before the server starts listening
+let keyRing: ValidatorKeyRing;
 
-// Load validator keypair
-let validatorKeypair: Keypair;
-try {
-    const privateKeyArray = JSON.parse(VALIDATOR_PRIVATE_KEY);
-    validatorKeypair = Keypair.fromSecretKey(Uint8Array.from(privateKeyArray));
-} catch {
-    validatorKeypair = Keypair.fromSecretKey(bs58.decode(VALIDATOR_PRIVATE_KEY));
-}
+// Every request is served by one key (X-Validator-Key header, default the first)
+app.use((req, res, next) => {
+    const key = keyRing.select(req.header(HEADER_VALIDATOR_KEY));
+    if (!key) {
+        return res.status(404).json({ error: 'Unknown validator key', validator: req.header(HEADER_VALIDATOR_KEY) });
+    }
+    res.locals.validatorKey = key;
+    next();
+});
+
+const solanaConnection = new Connection(SOLANA_RPC, config.solana.commitment);
 
-console.log('🔑 Validator Public Key:', validatorKeypair.publicKey.toBase58());
+// Finalized-burn verification cache (rebuilt by --backfill)
+const burnCache = new BurnCache(config.service.cache_file);
+setInterval(() => burnCache.flush(), 10_000).unref();
 
-const solanaConnection = new Connection(SOLANA_RPC, 'confirmed');
+// Operator alerts (webhook / Telegram / Discord, see [alerts] config)
+const alertDispatcher = createAlertDispatcher(config.alerts);
+const watchdogs = new Map<string, Watchdog>();
 
 // ============================================================================
 // SECTION 6.1: Asset Registry (Authoritative)
@@ -43,8 +77,8 @@ enum Asset {
 }
 
 // Asset mint addresses (Solana SPL tokens)
-const XENCAT_MINT = new PublicKey('7UN8WkBumTUCofVPXCPjNWQ6msQhzrg9tFQRP48Nmw5V');
-const DGN_MINT = new PublicKey('Fd8TNp5GhhTk6Uq6utMvK13vfQdLN1yUUHCnapWvpump');
+const XENCAT_MINT = new PublicKey(config.mints.xencat_solana);
+const DGN_MINT = new PublicKey(config.mints.dgn_solana);
 
 // Asset Registry: Maps SPL mint address → asset_id
 const ASSET_BY_MINT: Record<string, Asset> = {
@@ -72,6 +106,7 @@ interface BurnDetectionResult {
     asset_id: Asset;
     mint: PublicKey;
     token_account: PublicKey;
+    tx_signature: string;
 }
 
 /**
@@ -199,7 +234,8 @@ async function detectBurnedMint(burnNonce: number): Promise<BurnDetectionResult
         return {
             asset_id,
             mint: burnIx.mint,
-            token_account: burnIx.account
+            token_account: burnIx.account,
+            tx_signature: txSignature
         };
 
     } catch (error: any) {
@@ -226,12 +262,21 @@ async function fetchBurnRecord(burnNonce: number) {
         return null;
     }
 
-    // Parse burn record (layout: user=32, amount=8, nonce=8, timestamp=8)
+    // Parse burn record (layout: user=32, amount=8, nonce=8, timestamp=8, record_hash=32, confirmation_depth=2,
+    // destination=32, memo=64, asset_id=1)
     const data = accountInfo.data;
-    const user = new PublicKey(data.slice(8, 40)); // Skip 8-byte discriminator
+    const burner = new PublicKey(data.slice(8, 40)); // Skip 8-byte discriminator
     const amount = new anchor.BN(data.slice(40, 48), 'le').toNumber();
     const nonce = new anchor.BN(data.slice(48, 56), 'le').toNumber();
     const timestamp = new anchor.BN(data.slice(56, 64), 'le').toNumber();
+    // Records created before confirmation depths existed are shorter: default depth
+    const confirmation_depth = data.length >= 98 ? data.readUInt16LE(96) : 0;
+    // burn_xencat_to binds an X1 destination: that is the key we attest for
+    const destination = data.length >= 195 ? new PublicKey(data.slice(98, 130)) : PublicKey.default;
+    const user = destination.equals(PublicKey.default) ? burner : destination;
+    const memo = data.length >= 195 ? Buffer.from(data.slice(130, 194)) : Buffer.alloc(64);
+    // burn_registered_asset records the asset on-chain (0 = not recorded: infer it from the burned mint)
+    const asset_id = data.length >= 196 ? data[194] : 0;
 
     // Get the actual slot from when the account was created
     let slot = 0;
@@ -246,15 +291,140 @@ async function fetchBurnRecord(burnNonce: number) {
         slot = currentSlot - 100; // Assume it's old enough
     }
 
-    return { user, amount, nonce, timestamp, slot };
+    return { user, burner, memo, asset_id, amount, nonce, timestamp, slot, confirmation_depth };
+}
+
+// Must match MIN_CONFIRMATION_DEPTH in the burn program and the light client
+const MIN_CONFIRMATION_DEPTH = 32;
+
+/** Slots to wait before signing: our finality, or the burner's deeper choice */
+function requiredSlots(confirmationDepth: number): number {
+    return Math.max(FINALITY_SLOTS, confirmationDepth);
+}
+
+/**
+ * Confirmed slot signed into the attestation: the burn slot plus its
+ * effective depth. Independent of FINALITY_SLOTS so every validator signs
+ * the same value.
+ */
+function confirmedSlot(burnSlot: number, confirmationDepth: number): number {
+    return burnSlot + Math.max(confirmationDepth, MIN_CONFIRMATION_DEPTH);
+}
+
+/**
+ * Per-transfer insurance coverage (BurnInsurance PDA, created with the burn)
+ *
+ * Layout: discriminator(8) || nonce(8) || user(32) || mint(32) || covered_amount(8) || premium(8) || claimed(1)
+ */
+interface BurnInsurance {
+    covered_amount: number;
+    premium: number;
+}
+
+async function fetchBurnInsurance(burnNonce: number, user: PublicKey, mint: PublicKey): Promise<BurnInsurance | null> {
+    const [insurancePda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('burn_insurance'),
+            new anchor.BN(burnNonce).toArrayLike(Buffer, 'le', 8)
+        ],
+        BURN_PROGRAM_ID
+    );
+
+    const accountInfo = await solanaConnection.getAccountInfo(insurancePda);
+    if (!accountInfo || !accountInfo.owner.equals(BURN_PROGRAM_ID)) {
+        return null;
+    }
+
+    const data = accountInfo.data;
+    // Only trust coverage that belongs to this exact burn
+    if (!new PublicKey(data.slice(16, 48)).equals(user) || !new PublicKey(data.slice(48, 80)).equals(mint)) {
+        return null;
+    }
+
+    return {
+        covered_amount: new anchor.BN(data.slice(80, 88), 'le').toNumber(),
+        premium: new anchor.BN(data.slice(88, 96), 'le').toNumber(),
+    };
+}
+
+/**
+ * Relayer claim terms of a designated-recipient burn (BurnClaim PDA, created with the burn)
+ *
+ * Layout: discriminator(8) || nonce(8) || user(32) || mint(32) || relayer_fee(8) || burner(32) || bump(1)
+ */
+interface BurnClaim {
+    relayer_fee: number;
+}
+
+async function fetchBurnClaim(burnNonce: number, user: PublicKey, mint: PublicKey): Promise<BurnClaim | null> {
+    const [claimPda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('burn_claim'),
+            new anchor.BN(burnNonce).toArrayLike(Buffer, 'le', 8)
+        ],
+        BURN_PROGRAM_ID
+    );
+
+    const accountInfo = await solanaConnection.getAccountInfo(claimPda);
+    if (!accountInfo || !accountInfo.owner.equals(BURN_PROGRAM_ID)) {
+        return null;
+    }
+
+    const data = accountInfo.data;
+    // Only trust terms that belong to this exact burn
+    if (!new PublicKey(data.slice(16, 48)).equals(user) || !new PublicKey(data.slice(48, 80)).equals(mint)) {
+        return null;
+    }
+
+    return {
+        relayer_fee: new anchor.BN(data.slice(80, 88), 'le').toNumber(),
+    };
+}
+
+/**
+ * Cancellation state of a cancellable burn (PendingBurn PDA, created with the burn)
+ *
+ * Layout: discriminator(8) || nonce(8) || user(32) || mint(32) || amount(8) || slot(8) || cancelled(1)
+ */
+interface PendingBurn {
+    slot: number;
+    cancelled: boolean;
+}
+
+// Must match CANCEL_WINDOW_SLOTS in the burn program
+const CANCEL_WINDOW_SLOTS = 16;
+
+async function fetchPendingBurn(burnNonce: number): Promise<PendingBurn | null> {
+    const [pendingPda] = PublicKey.findProgramAddressSync(
+        [
+            Buffer.from('pending_burn'),
+            new anchor.BN(burnNonce).toArrayLike(Buffer, 'le', 8)
+        ],
+        BURN_PROGRAM_ID
+    );
+
+    const accountInfo = await solanaConnection.getAccountInfo(pendingPda);
+    if (!accountInfo || !accountInfo.owner.equals(BURN_PROGRAM_ID)) {
+        return null;
+    }
+
+    return {
+        slot: new anchor.BN(accountInfo.data.slice(88, 96), 'le').toNumber(),
+        cancelled: accountInfo.data[96] === 1,
+    };
 }
 
 // ============================================================================
 // SECTION 6.3: Asset-Aware Attestation Message
 // ============================================================================
 
-// Domain separator (must match on-chain constant)
-const DOMAIN_SEPARATOR = 'XENCAT_X1_BRIDGE_V1';
+// Domain separator: the light client's BridgeDomain, read from X1 at startup
+// (the compiled-in default until a deployment sets its own)
+let DOMAIN_SEPARATOR = DEFAULT_DOMAIN_SEPARATOR;
+// Genesis hash of our X1 RPC, once the light client's X1ChainId binds one
+let X1_GENESIS_HASH: Buffer | null = null;
+// Signed domain bytes: DOMAIN_SEPARATOR, then X1_GENESIS_HASH if bound
+let ATTESTATION_DOMAIN = attestationDomain(DOMAIN_SEPARATOR, null);
 
 /**
  * Create attestation message V3 (asset-aware)
@@ -264,6 +434,7 @@ const DOMAIN_SEPARATOR = 'XENCAT_X1_BRIDGE_V1';
  *
  * New format (SECURE):
  *   hash(DOMAIN_SEPARATOR || asset_id || validator_set_version || burn_nonce || amount || user)
+ *   (DOMAIN_SEPARATOR followed by the X1 genesis hash once the bridge binds one)
  *
  * This ensures:
  * - No cross-asset replay (different asset_id → different hash → different signature)
@@ -278,7 +449,7 @@ function createAttestationMessageV3(
     validatorSetVersion: number
 ): Buffer {
     const messageData = Buffer.concat([
-        Buffer.from(DOMAIN_SEPARATOR),                                            // Domain
+        ATTESTATION_DOMAIN,                                                       // Domain
         Buffer.from([asset_id]),                                                  // Asset ID (u8) ✅ NEW
         Buffer.from(new BigUint64Array([BigInt(validatorSetVersion)]).buffer),   // Version
         Buffer.from(new BigUint64Array([BigInt(burnNonce)]).buffer),             // Nonce
@@ -287,167 +458,1298 @@ function createAttestationMessageV3(
     ]);
 
     // Hash to match Solana's hash() function (SHA256)
-    const crypto = require('crypto');
     const hash = crypto.createHash('sha256').update(messageData).digest();
 
     return hash;
 }
 
+/**
+ * Create the per-validator signed message (V3 with timing)
+ *
+ * Format: hash(v3_message || solana_burn_slot || solana_burn_timestamp ||
+ *   confirmation_depth || solana_confirmed_slot || attested_at [|| challenge])
+ *
+ * Binds the burn slot/time and this validator's signing time (unix seconds),
+ * which the light client uses for per-validator latency SLA tracking, and
+ * the confirmation depth waited for, which it checks against the burn slot.
+ * The challenge is appended when the burner posted a ChallengeRequest.
+ */
+function createTimedAttestationMessageV3(
+    v3Message: Buffer,
+    solanaBurnSlot: number,
+    solanaBurnTimestamp: number,
+    confirmationDepth: number,
+    solanaConfirmedSlot: number,
+    attestedAt: number,
+    challenge: Buffer | null
+): Buffer {
+    const depth = Buffer.alloc(2);
+    depth.writeUInt16LE(confirmationDepth);
+    const messageData = Buffer.concat([
+        v3Message,
+        Buffer.from(new BigUint64Array([BigInt(solanaBurnSlot)]).buffer),
+        Buffer.from(new BigInt64Array([BigInt(solanaBurnTimestamp)]).buffer),
+        depth,
+        Buffer.from(new BigUint64Array([BigInt(solanaConfirmedSlot)]).buffer),
+        Buffer.from(new BigInt64Array([BigInt(attestedAt)]).buffer),
+        challenge ?? Buffer.alloc(0),
+    ]);
+
+    return crypto.createHash('sha256').update(messageData).digest();
+}
+
+/**
+ * Create insurance attestation message
+ *
+ * Format: hash(DOMAIN_SEPARATOR || "INSURED_BURN" || asset_id || burn_nonce || user || covered_amount || premium)
+ *
+ * Lets claim reviewers check that a quorum of validators saw the coverage
+ * recorded with the burn.
+ */
+function createInsuranceMessage(
+    asset_id: Asset,
+    burnNonce: number,
+    user: PublicKey,
+    insurance: BurnInsurance
+): Buffer {
+    const messageData = Buffer.concat([
+        ATTESTATION_DOMAIN,
+        Buffer.from('INSURED_BURN'),
+        Buffer.from([asset_id]),
+        Buffer.from(new BigUint64Array([BigInt(burnNonce)]).buffer),
+        user.toBuffer(),
+        Buffer.from(new BigUint64Array([BigInt(insurance.covered_amount)]).buffer),
+        Buffer.from(new BigUint64Array([BigInt(insurance.premium)]).buffer),
+    ]);
+
+    return crypto.createHash('sha256').update(messageData).digest();
+}
+
+/**
+ * Create relayer claim message
+ *
+ * Format: hash(DOMAIN_SEPARATOR || "RELAYER_CLAIM" || asset_id || burn_nonce || user || relayer_fee)
+ *
+ * A threshold of these lets a relayer verify and mint the burn for its
+ * recipient and keep relayer_fee (submit_burn_attestation_for_recipient).
+ */
+function createRelayerClaimMessage(
+    asset_id: Asset,
+    burnNonce: number,
+    user: PublicKey,
+    claim: BurnClaim
+): Buffer {
+    const messageData = Buffer.concat([
+        ATTESTATION_DOMAIN,
+        Buffer.from('RELAYER_CLAIM'),
+        Buffer.from([asset_id]),
+        Buffer.from(new BigUint64Array([BigInt(burnNonce)]).buffer),
+        user.toBuffer(),
+        Buffer.from(new BigUint64Array([BigInt(claim.relayer_fee)]).buffer),
+    ]);
+
+    return crypto.createHash('sha256').update(messageData).digest();
+}
+
+// ============================================================================
+// SECTION 6.3.1: Signed API Responses
+// ============================================================================
+
+// Response domain (must match sdk/attestation-client/src/response-signing.ts)
+const RESPONSE_DOMAIN = 'XENCAT_ATTESTATION_RESPONSE_V1';
+
+/**
+ * Send a JSON response signed with the request's validator key.
+ *
+ * Headers:
+ *   X-Validator-Pubkey:    validator pubkey (base58)
+ *   X-Validator-Signature: ed25519(sha256(RESPONSE_DOMAIN || request_nonce || body)) (base58)
+ *
+ * The client-chosen X-Request-Nonce is bound into the signature so a
+ * man-in-the-middle cannot replay an old response for a different request.
+ */
+async function sendSigned(req: express.Request, res: express.Response, status: number, payload: unknown) {
+    const key: ValidatorKey = res.locals.validatorKey;
+    const body = JSON.stringify(payload);
+    const requestNonce = req.header('x-request-nonce') || '';
+
+    const digest = crypto
+        .createHash('sha256')
+        .update(RESPONSE_DOMAIN)
+        .update(requestNonce)
+        .update(body)
+        .digest();
+
+    const signature = await key.signer.sign(digest);
+
+    return res
+        .status(status)
+        .set('Content-Type', 'application/json')
+        .set('X-Validator-Pubkey', key.pubkey)
+        .set('X-Validator-Signature', bs58.encode(signature))
+        .send(body);
+}
+
 // ============================================================================
 // SECTION 6.4: Asset-Aware Attestation Endpoint
 // ============================================================================
 
-// API endpoint: POST /attest-burn (V3 - asset-aware)
-app.post('/attest-burn', async (req, res) => {
-    try {
-        const { burn_nonce, user, expected_amount, validator_set_version } = req.body;
+interface AttestBurnRequest {
+    burn_nonce: number;
+    user: string;
+    expected_amount: number;
+    validator_set_version: number;
+}
 
-        if (!burn_nonce || !user || !expected_amount || validator_set_version === undefined) {
-            return res.status(400).json({
-                error: 'Missing required fields: burn_nonce, user, expected_amount, validator_set_version'
-            });
+interface AttestBurnResult {
+    status: number;
+    body: any;
+}
+
+/** The key's policy forbids signing this burn */
+function refused(key: ValidatorKey, reason: string): AttestBurnResult {
+    console.log(`🚫 ${key.pubkey}: ${reason}`);
+    return { status: 403, body: { error: reason, validator: key.pubkey } };
+}
+
+/**
+ * Verify one burn on Solana and sign the V3 attestation.
+ *
+ * Shared by the single and batch endpoints so both apply exactly the same
+ * checks (asset detection, user, amount, finality).
+ */
+async function attestBurn(request: AttestBurnRequest, key: ValidatorKey, span: Span): Promise<AttestBurnResult> {
+    const { burn_nonce, user, expected_amount, validator_set_version } = request;
+
+    if (!burn_nonce || !user || !expected_amount || validator_set_version === undefined) {
+        return {
+            status: 400,
+            body: { error: 'Missing required fields: burn_nonce, user, expected_amount, validator_set_version' },
+        };
+    }
+
+    console.log(`\n📥 Attestation request V3 (asset-aware) for burn ${burn_nonce}`);
+    console.log(`   User: ${user}`);
+    console.log(`   Expected amount: ${expected_amount}`);
+    console.log(`   Validator set version: ${validator_set_version}`);
+    watchdogs.get(key.pubkey)?.observeRequestedVersion(validator_set_version);
+
+    // Finalized burns previously verified (or backfilled) skip the Solana lookups
+    const cached = burnCache.get(burn_nonce);
+    // Entries cached before burn timestamps were recorded go through the live path
+    if (cached && cached.timestamp !== undefined) {
+        span.set({ asset_id: cached.asset_id, cache_hit: true });
+        console.log(`   ⚡ Cache hit: ${ASSET_NAMES[cached.asset_id as Asset]} burn at slot ${cached.slot}`);
+
+        if (cached.user !== user) {
+            return { status: 400, body: { error: 'User mismatch', expected: user, actual: cached.user } };
         }
+        if (cached.amount !== expected_amount) {
+            return { status: 400, body: { error: 'Amount mismatch', expected: expected_amount, actual: cached.amount } };
+        }
+        const refusal = key.refusal(cached.asset_id, cached.amount);
+        if (refusal) {
+            return refused(key, refusal);
+        }
+        const body = await signAttestation(key, cached.asset_id as Asset, burn_nonce, new PublicKey(cached.user), cached.amount, validator_set_version, { slot: cached.slot, timestamp: cached.timestamp, confirmation_depth: cached.confirmation_depth ?? 0 }, span, cached.insurance, cached.relayer_claim);
+        reorgMonitor.watch({
+            burn_nonce,
+            asset_id: cached.asset_id,
+            user: cached.user,
+            amount: cached.amount,
+            slot: cached.slot,
+            tx_signature: cached.tx_signature,
+        }, key.pubkey);
+        return { status: 200, body };
+    }
 
-        console.log(`\n📥 Attestation request V3 (asset-aware) for burn ${burn_nonce}`);
-        console.log(`   User: ${user}`);
-        console.log(`   Expected amount: ${expected_amount}`);
-        console.log(`   Validator set version: ${validator_set_version}`);
+    // Cancellable burns: never sign one that was (or can still be) cancelled
+    const pending = await withSpan(span, 'fetch_pending_burn', () => fetchPendingBurn(burn_nonce));
+    if (pending?.cancelled) {
+        console.log('❌ Burn was cancelled by the user');
+        return { status: 410, body: { error: 'Burn cancelled', burn_nonce } };
+    }
+    if (pending) {
+        const slotsLeft = pending.slot + CANCEL_WINDOW_SLOTS - await solanaConnection.getSlot('confirmed');
+        if (slotsLeft >= 0) {
+            console.log(`⏳ Burn still cancellable (${slotsLeft + 1} slots left)`);
+            return {
+                status: 425,
+                body: {
+                    error: 'Burn still cancellable',
+                    retry_after_seconds: Math.ceil((slotsLeft + 1) * 0.4)
+                },
+            };
+        }
+    }
 
-        // ✅ NEW: Step 1 - Detect which SPL token was burned
-        const burnDetection = await detectBurnedMint(burn_nonce);
+    // ✅ NEW: Step 1 - Detect which SPL token was burned
+    const burnDetection = await withSpan(span, 'detect_burned_mint', () => detectBurnedMint(burn_nonce));
 
-        if (!burnDetection) {
-            console.log('❌ Burn detection failed - unknown or invalid SPL token burn');
-            return res.status(400).json({
+    if (!burnDetection) {
+        console.log('❌ Burn detection failed - unknown or invalid SPL token burn');
+        return {
+            status: 400,
+            body: {
                 error: 'Unknown or invalid SPL token burn',
                 burn_nonce,
                 message: 'This validator only attests to burns of supported assets (XENCAT, DGN)'
+            },
+        };
+    }
+
+    const { asset_id, mint } = burnDetection;
+    span.set({ asset_id });
+
+    console.log(`   ✅ Burn asset identified: ${ASSET_NAMES[asset_id]}`);
+    console.log(`   📄 Mint address: ${mint.toBase58()}`);
+
+    // Step 2 - Fetch burn record from Solana
+    const burnRecord = await withSpan(span, 'fetch_burn_record', () => fetchBurnRecord(burn_nonce));
+
+    if (!burnRecord) {
+        console.log('❌ Burn not found on Solana');
+        return {
+            status: 404,
+            body: { error: 'Burn not found on Solana', burn_nonce },
+        };
+    }
+
+    if (burnRecord.asset_id !== 0 && burnRecord.asset_id !== asset_id) {
+        console.log(`❌ BurnRecord asset ${burnRecord.asset_id} does not match the burned mint (${ASSET_NAMES[asset_id]})`);
+        return {
+            status: 409,
+            body: { error: 'Asset mismatch', expected: burnRecord.asset_id, actual: asset_id, burn_nonce },
+        };
+    }
+
+    console.log(`✅ Burn found on Solana`);
+    console.log(`   User: ${burnRecord.user.toBase58()}`);
+    if (!burnRecord.burner.equals(burnRecord.user)) {
+        console.log(`   Burner: ${burnRecord.burner.toBase58()} (explicit X1 destination)`);
+    }
+    console.log(`   Amount: ${burnRecord.amount}`);
+    console.log(`   Slot: ${burnRecord.slot}`);
+
+    // Step 3 - Verify user matches
+    if (burnRecord.user.toBase58() !== user) {
+        console.log('❌ User mismatch');
+        return {
+            status: 400,
+            body: { error: 'User mismatch', expected: user, actual: burnRecord.user.toBase58() },
+        };
+    }
+
+    // Step 4 - Verify amount matches
+    if (burnRecord.amount !== expected_amount) {
+        console.log('❌ Amount mismatch');
+        return {
+            status: 400,
+            body: { error: 'Amount mismatch', expected: expected_amount, actual: burnRecord.amount },
+        };
+    }
+
+    // Step 5 - Check finality (FINALITY_SLOTS, or the burner's deeper confirmation depth)
+    const currentSlot = await withSpan(span, 'check_finality', () => solanaConnection.getSlot('confirmed'));
+    const slotsSinceBurn = currentSlot - burnRecord.slot;
+    const required = requiredSlots(burnRecord.confirmation_depth);
+    span.set({ burn_slot: burnRecord.slot, slots_since_burn: slotsSinceBurn, confirmation_depth: burnRecord.confirmation_depth });
+
+    if (slotsSinceBurn < required) {
+        console.log(`⏳ Burn not yet finalized (${slotsSinceBurn}/${required} slots)`);
+        return {
+            status: 425,
+            body: {
+                error: 'Burn not yet finalized',
+                slots_since_burn: slotsSinceBurn,
+                required_slots: required,
+                retry_after_seconds: Math.ceil((required - slotsSinceBurn) * 0.4)
+            },
+        };
+    }
+
+    console.log(`✅ Burn finalized (${slotsSinceBurn} slots ago)`);
+
+    const insurance = await withSpan(span, 'fetch_burn_insurance', () => fetchBurnInsurance(burn_nonce, burnRecord.user, mint));
+    if (insurance) {
+        console.log(`   🛡️  Insured: ${insurance.covered_amount} covered (premium ${insurance.premium})`);
+    }
+    const relayerClaim = await withSpan(span, 'fetch_burn_claim', () => fetchBurnClaim(burn_nonce, burnRecord.user, mint));
+    if (relayerClaim) {
+        console.log(`   🤝 Relayer claim: fee ${relayerClaim.relayer_fee}`);
+    }
+
+    burnCache.put({
+        burn_nonce,
+        asset_id,
+        mint: mint.toBase58(),
+        user: burnRecord.user.toBase58(),
+        amount: burnRecord.amount,
+        slot: burnRecord.slot,
+        timestamp: burnRecord.timestamp,
+        confirmation_depth: burnRecord.confirmation_depth,
+        tx_signature: burnDetection.tx_signature,
+        insurance,
+        relayer_claim: relayerClaim,
+    });
+
+    // Cached regardless: another key on this service may accept the burn
+    const refusal = key.refusal(asset_id, burnRecord.amount);
+    if (refusal) {
+        return refused(key, refusal);
+    }
+
+    // ✅ NEW: Step 6 - Create and sign asset-aware attestation V3
+    const body = await signAttestation(key, asset_id, burn_nonce, burnRecord.user, burnRecord.amount, validator_set_version, burnRecord, span, insurance, relayerClaim);
+
+    // Signed at `confirmed`: keep watching until the burn is finalized as signed
+    reorgMonitor.watch({
+        burn_nonce,
+        asset_id,
+        user: burnRecord.user.toBase58(),
+        amount: burnRecord.amount,
+        slot: burnRecord.slot,
+        tx_signature: burnDetection.tx_signature,
+    }, key.pubkey);
+    return { status: 200, body };
+}
+
+/**
+ * Sign the asset-aware V3 attestation for an already-verified burn
+ */
+async function signAttestation(
+    key: ValidatorKey,
+    asset_id: Asset,
+    burn_nonce: number,
+    user: PublicKey,
+    amount: number,
+    validator_set_version: number,
+    burn: { slot: number; timestamp: number; confirmation_depth: number },
+    span: Span,
+    insurance?: BurnInsurance | null,
+    relayerClaim?: BurnClaim | null
+) {
+    // The burner's challenge, read at `confirmed`: it was posted moments ago
+    const challenge = await withSpan(span, 'fetch_challenge', () =>
+        fetchChallengeRequest(x1Connection, new PublicKey(config.programs.light_client), asset_id, user, burn_nonce)
+    );
+    const attestedAt = Math.floor(Date.now() / 1000);
+    const message = createTimedAttestationMessageV3(
+        createAttestationMessageV3(
+            asset_id,                  // ✅ NEW: Include asset_id in hash
+            burn_nonce,
+            user,
+            amount,
+            validator_set_version
+        ),
+        burn.slot,
+        burn.timestamp,
+        burn.confirmation_depth,
+        confirmedSlot(burn.slot, burn.confirmation_depth),
+        attestedAt,
+        challenge?.challenge ?? null
+    );
+
+    const signature = await key.signer.sign(message);
+
+    // ✅ NEW: Include asset_id in response
+    const attestation = {
+        asset_id,                                          // ✅ NEW
+        asset_name: ASSET_NAMES[asset_id],                 // ✅ NEW (for debugging)
+        burn_nonce,
+        user: user.toBase58(),
+        amount,
+        validator_set_version,
+        validator_pubkey: key.pubkey,
+        signature: Array.from(signature),
+        // Signed: burn slot/time and our signing time (unix seconds)
+        solana_burn_slot: burn.slot,
+        solana_burn_timestamp: burn.timestamp,
+        confirmation_depth: burn.confirmation_depth,
+        solana_confirmed_slot: confirmedSlot(burn.slot, burn.confirmation_depth),
+        timestamp: attestedAt,
+        // Signed when the burner posted one (hex; null = no challenge)
+        challenge: challenge ? challenge.challenge.toString('hex') : null,
+        // Coverage recorded with the burn (null = not insured)
+        insurance: insurance
+            ? {
+                covered_amount: insurance.covered_amount,
+                premium: insurance.premium,
+                signature: Array.from(await key.signer.sign(
+                    createInsuranceMessage(asset_id, burn_nonce, user, insurance)
+                )),
+            }
+            : null,
+        // Relayer claim terms recorded with the burn (null = ordinary burn)
+        relayer_claim: relayerClaim
+            ? {
+                relayer_fee: relayerClaim.relayer_fee,
+                signature: Array.from(await key.signer.sign(
+                    createRelayerClaimMessage(asset_id, burn_nonce, user, relayerClaim)
+                )),
+            }
+            : null,
+    };
+
+    console.log(`✅ Attestation V3 signed (asset-aware)`);
+    console.log(`   Asset: ${ASSET_NAMES[asset_id]} (asset_id=${asset_id})`);
+    console.log(`   Version: ${validator_set_version}`);
+    span.log('attestation_signed', { validator_set_version });
+
+    return attestation;
+}
+
+// API endpoint: POST /attest-burn (V3 - asset-aware)
+app.post('/attest-burn', async (req, res) => {
+    const request: AttestBurnRequest = req.body || {};
+    const key: ValidatorKey = res.locals.validatorKey;
+
+    // Correlation id: supplied by the relayer, or derived from the burn nonce
+    const transfer_id = req.header('x-transfer-id') || transferId(request.burn_nonce);
+    const span = startRequestSpan('attest_burn', req.header('traceparent'), {
+        transfer_id,
+        burn_nonce: Number(request.burn_nonce) || 0,
+        validator: key.pubkey,
+    });
+    res.set('X-Transfer-Id', transfer_id);
+    res.set('traceparent', span.traceparent());
+
+    try {
+        const result = await attestBurn(request, key, span);
+        key.record(result.status);
+        if (result.status === 200) {
+            console.log(`📤 Returning attestation to user\n`);
+        }
+        return sendSigned(req, res, result.status, result.body);
+    } catch (error: any) {
+        console.error('❌ Error:', error);
+        key.record(500);
+        span.set({ 'error.message': error.message });
+        return sendSigned(req, res, 500, {
+            error: 'Internal server error',
+            message: error.message
+        });
+    } finally {
+        span.set({ 'http.status_code': res.statusCode });
+        span.end(res.statusCode >= 500 ? new Error('Internal server error') : undefined);
+    }
+});
+
+// ============================================================================
+// SECTION 6.5: Batch Attestation Endpoint
+// ============================================================================
+
+/**
+ * POST /attest-burns
+ *
+ * Body: { burns: AttestBurnRequest[] }  (at most MAX_BATCH_SIZE entries)
+ *
+ * Every entry goes through the exact same checks as /attest-burn. The
+ * response is one signed envelope carrying a per-burn result, in request
+ * order, so a relayer clearing a backlog needs one round trip per validator:
+ *
+ *   { results: [{ burn_nonce, status: 200, attestation }, { burn_nonce, status: 425, error, ... }] }
+ *
+ * Each attestation signature is independent - a relayer can submit any
+ * subset of the returned attestations on-chain.
+ */
+const MAX_BATCH_SIZE = config.limits.max_batch_size;
+const BATCH_CONCURRENCY = 4; // Parallel Solana lookups per batch
+
+app.post('/attest-burns', async (req, res) => {
+    const burns: AttestBurnRequest[] = req.body?.burns;
+    const key: ValidatorKey = res.locals.validatorKey;
+    const span = startRequestSpan('attest_burns', req.header('traceparent'), {
+        validator: key.pubkey,
+    });
+    res.set('traceparent', span.traceparent());
+
+    try {
+        if (!Array.isArray(burns) || burns.length === 0) {
+            return sendSigned(req, res, 400, { error: 'Missing required field: burns (non-empty array)' });
+        }
+        if (burns.length > MAX_BATCH_SIZE) {
+            return sendSigned(req, res, 413, {
+                error: 'Batch too large',
+                max_batch_size: MAX_BATCH_SIZE,
+                received: burns.length,
             });
         }
 
-        const { asset_id, mint } = burnDetection;
+        const nonces = burns.map(b => b?.burn_nonce);
+        if (new Set(nonces).size !== nonces.length) {
+            return sendSigned(req, res, 400, { error: 'Duplicate burn_nonce in batch' });
+        }
+
+        console.log(`\n📦 Batch attestation request: ${burns.length} burns`);
+        span.set({ batch_size: burns.length });
+
+        const results: any[] = new Array(burns.length);
+        for (let i = 0; i < burns.length; i += BATCH_CONCURRENCY) {
+            const chunk = burns.slice(i, i + BATCH_CONCURRENCY);
+            await Promise.all(chunk.map(async (burn, j) => {
+                const child = span.child('attest_burn', {
+                    transfer_id: transferId(burn?.burn_nonce),
+                    burn_nonce: Number(burn?.burn_nonce) || 0,
+                });
+                try {
+                    const result = await attestBurn(burn || {} as AttestBurnRequest, key, child);
+                    key.record(result.status);
+                    results[i + j] = result.status === 200
+                        ? { burn_nonce: burn.burn_nonce, status: 200, attestation: result.body }
+                        : { burn_nonce: burn?.burn_nonce, status: result.status, ...result.body };
+                    child.end();
+                } catch (error: any) {
+                    key.record(500);
+                    results[i + j] = {
+                        burn_nonce: burn?.burn_nonce,
+                        status: 500,
+                        error: 'Internal server error',
+                        message: error.message,
+                    };
+                    child.end(error);
+                }
+            }));
+        }
+
+        const signed = results.filter(r => r.status === 200).length;
+        console.log(`📤 Returning batch: ${signed}/${burns.length} attested\n`);
+        span.set({ attested: signed });
 
-        console.log(`   ✅ Burn asset identified: ${ASSET_NAMES[asset_id]}`);
-        console.log(`   📄 Mint address: ${mint.toBase58()}`);
+        return sendSigned(req, res, 200, { results });
+    } catch (error: any) {
+        console.error('❌ Error:', error);
+        return sendSigned(req, res, 500, {
+            error: 'Internal server error',
+            message: error.message
+        });
+    } finally {
+        span.set({ 'http.status_code': res.statusCode });
+        span.end(res.statusCode >= 500 ? new Error('Internal server error') : undefined);
+    }
+});
 
-        // Step 2 - Fetch burn record from Solana
-        const burnRecord = await fetchBurnRecord(burn_nonce);
+// ============================================================================
+// SECTION 6.5.1: Solana Epoch / Stake Attestation
+// ============================================================================
 
-        if (!burnRecord) {
-            console.log('❌ Burn not found on Solana');
-            return res.status(404).json({
-                error: 'Burn not found on Solana',
-                burn_nonce
+/**
+ * Governance-style message (must match light client governance.rs)
+ *
+ * Format: sha256(tag || validator_set_version || payload [|| X1_GENESIS_HASH])
+ */
+function createGovernanceMessage(tag: string, validatorSetVersion: number, payload: Buffer): Buffer {
+    return crypto
+        .createHash('sha256')
+        .update(Buffer.from(tag))
+        .update(Buffer.from(new BigUint64Array([BigInt(validatorSetVersion)]).buffer))
+        .update(payload)
+        .update(X1_GENESIS_HASH ?? Buffer.alloc(0))
+        .digest();
+}
+
+/** How far behind our own finalized slot a proposed observation may be */
+const MAX_EPOCH_OBSERVATION_AGE_SLOTS = 150;
+
+/**
+ * POST /attest-solana-epoch
+ *
+ * Body: { epoch, total_stake, solana_slot, validator_set_version }
+ *   (total_stake is a decimal string - lamports exceed Number precision)
+ *
+ * The relayer proposes ONE observation and collects signatures over it from
+ * ≥threshold validators (every validator must sign identical values). We
+ * only sign if our own view of Solana agrees:
+ * - solana_slot is finalized for us and not older than MAX_EPOCH_OBSERVATION_AGE_SLOTS
+ * - epoch is the epoch containing solana_slot
+ * - total_stake equals the active stake we see for that epoch
+ *
+ * Signed message: SOLANA_EPOCH || version || epoch || total_stake || solana_slot
+ *   [|| X1 genesis hash] (see update_solana_epoch.rs)
+ */
+app.post('/attest-solana-epoch', async (req, res) => {
+    const { epoch, total_stake, solana_slot, validator_set_version } = req.body || {};
+    const key: ValidatorKey = res.locals.validatorKey;
+    const span = startRequestSpan('attest_solana_epoch', req.header('traceparent'), {
+        validator: key.pubkey,
+    });
+
+    try {
+        if (epoch === undefined || !total_stake || !solana_slot || validator_set_version === undefined) {
+            return sendSigned(req, res, 400, {
+                error: 'Missing required fields: epoch, total_stake, solana_slot, validator_set_version'
             });
         }
 
-        console.log(`✅ Burn found on Solana`);
-        console.log(`   User: ${burnRecord.user.toBase58()}`);
-        console.log(`   Amount: ${burnRecord.amount}`);
-        console.log(`   Slot: ${burnRecord.slot}`);
+        const refusal = key.refusal();
+        if (refusal) {
+            return sendSigned(req, res, 403, { error: refusal, validator: key.pubkey });
+        }
+
+        const epochInfo = await solanaConnection.getEpochInfo('finalized');
+        const finalizedSlot = epochInfo.absoluteSlot;
 
-        // Step 3 - Verify user matches
-        if (burnRecord.user.toBase58() !== user) {
-            console.log('❌ User mismatch');
-            return res.status(400).json({
-                error: 'User mismatch',
-                expected: user,
-                actual: burnRecord.user.toBase58()
+        if (solana_slot > finalizedSlot || finalizedSlot - solana_slot > MAX_EPOCH_OBSERVATION_AGE_SLOTS) {
+            return sendSigned(req, res, 400, {
+                error: 'solana_slot not finalized or too old',
+                finalized_slot: finalizedSlot,
+                max_age_slots: MAX_EPOCH_OBSERVATION_AGE_SLOTS,
             });
         }
 
-        // Step 4 - Verify amount matches
-        if (burnRecord.amount !== expected_amount) {
-            console.log('❌ Amount mismatch');
-            return res.status(400).json({
-                error: 'Amount mismatch',
-                expected: expected_amount,
-                actual: burnRecord.amount
+        const epochStart = finalizedSlot - epochInfo.slotIndex;
+        const observedEpoch = solana_slot >= epochStart ? epochInfo.epoch : epochInfo.epoch - 1;
+        if (epoch !== observedEpoch) {
+            return sendSigned(req, res, 400, { error: 'Epoch mismatch', expected: observedEpoch, actual: epoch });
+        }
+
+        const voteAccounts = await solanaConnection.getVoteAccounts('finalized');
+        const observedStake = [...voteAccounts.current, ...voteAccounts.delinquent]
+            .reduce((sum, v) => sum + BigInt(v.activatedStake), BigInt(0));
+        if (BigInt(total_stake) !== observedStake) {
+            return sendSigned(req, res, 400, {
+                error: 'Total stake mismatch',
+                expected: observedStake.toString(),
+                actual: String(total_stake),
             });
         }
 
-        // Step 5 - Check finality (32 slots)
-        const currentSlot = await solanaConnection.getSlot('confirmed');
-        const slotsSinceBurn = currentSlot - burnRecord.slot;
+        const payload = Buffer.concat([
+            Buffer.from(new BigUint64Array([BigInt(epoch)]).buffer),
+            Buffer.from(new BigUint64Array([BigInt(total_stake)]).buffer),
+            Buffer.from(new BigUint64Array([BigInt(solana_slot)]).buffer),
+        ]);
+        const message = createGovernanceMessage('SOLANA_EPOCH', validator_set_version, payload);
+        const signature = await key.signer.sign(message);
+
+        console.log(`✅ Solana epoch ${epoch} attested (slot ${solana_slot}, stake ${total_stake})`);
+        span.set({ epoch, solana_slot });
+
+        return sendSigned(req, res, 200, {
+            epoch,
+            total_stake: String(total_stake),
+            solana_slot,
+            validator_set_version,
+            validator_pubkey: key.pubkey,
+            signature: Array.from(signature),
+        });
+    } catch (error: any) {
+        console.error('❌ Error:', error);
+        return sendSigned(req, res, 500, { error: 'Internal server error', message: error.message });
+    } finally {
+        span.set({ 'http.status_code': res.statusCode });
+        span.end(res.statusCode >= 500 ? new Error('Internal server error') : undefined);
+    }
+});
 
-        if (slotsSinceBurn < FINALITY_SLOTS) {
-            console.log(`⏳ Burn not yet finalized (${slotsSinceBurn}/${FINALITY_SLOTS} slots)`);
-            return res.status(425).json({
-                error: 'Burn not yet finalized',
-                slots_since_burn: slotsSinceBurn,
-                required_slots: FINALITY_SLOTS,
-                retry_after_seconds: Math.ceil((FINALITY_SLOTS - slotsSinceBurn) * 0.4)
-            });
+// ============================================================================
+// SECTION 6.5.2: X1 Mint Acknowledgement (Solana-side settlement)
+// ============================================================================
+
+const x1Connection = new Connection(config.x1.rpc, 'finalized');
+
+// Burns signed before Solana finality, watched until they finalize as signed
+const reorgMonitor = new ReorgMonitor(
+    config.service.signed_burns_file,
+    solanaConnection,
+    x1Connection,
+    config.programs.light_client,
+    BURN_PROGRAM_ID,
+    alertDispatcher,
+    burnNonce => burnCache.delete(burnNonce)
+);
+
+/**
+ * GET /revocations
+ *
+ * FREEZE_BURN approvals (one per local key that signed) for burns this
+ * service attested that were later dropped or rewritten by a Solana fork.
+ * Same shape as a `burn-freeze.ts` proposal; `burn-freeze.ts collect`
+ * merges them across validators. Empty when nothing was reorged.
+ */
+app.get('/revocations', async (req, res) => {
+    try {
+        res.json({ revocations: await reorgMonitor.revocations(keyRing.keys, X1_GENESIS_HASH) });
+    } catch (error: any) {
+        console.error('❌ Error building revocations:', error.message);
+        res.status(500).json({ error: 'Internal server error', message: error.message });
+    }
+});
+
+// X1 mint program that owns each asset's processed_burn_v3 PDAs
+const MINT_PROGRAM_BY_ASSET: Record<Asset, PublicKey> = {
+    [Asset.XENCAT]: new PublicKey(config.programs.xencat_mint),
+    [Asset.DGN]: new PublicKey(config.programs.dgn_mint),
+};
+
+/** Must match SETTLEMENT_DOMAIN in the burn program */
+const SETTLEMENT_DOMAIN = 'XENCAT_X1_SETTLEMENT_V1';
+
+/**
+ * Settlement acknowledgement (must match settlement_message in the burn program)
+ *
+ * Format: sha256(SETTLEMENT_DOMAIN || "MINTED" || version || asset_id || nonce || user || amount || x1_processed_at)
+ */
+function createSettlementMessage(
+    version: number,
+    asset_id: Asset,
+    burnNonce: number,
+    user: PublicKey,
+    amount: number,
+    x1ProcessedAt: number
+): Buffer {
+    return crypto
+        .createHash('sha256')
+        .update(Buffer.from(SETTLEMENT_DOMAIN))
+        .update(Buffer.from('MINTED'))
+        .update(new anchor.BN(version).toArrayLike(Buffer, 'le', 8))
+        .update(Buffer.from([asset_id]))
+        .update(new anchor.BN(burnNonce).toArrayLike(Buffer, 'le', 8))
+        .update(user.toBuffer())
+        .update(new anchor.BN(amount).toArrayLike(Buffer, 'le', 8))
+        .update(new anchor.BN(x1ProcessedAt).toTwos(64).toArrayLike(Buffer, 'le', 8))
+        .digest();
+}
+
+/** Current version of the burn program's SettlementConfig, or null if not initialized */
+async function fetchSettlementVersion(): Promise<number | null> {
+    const [settlementConfigPda] = PublicKey.findProgramAddressSync([Buffer.from('settlement_config')], BURN_PROGRAM_ID);
+    const settlementConfig = await solanaConnection.getAccountInfo(settlementConfigPda);
+    if (!settlementConfig) {
+        return null;
+    }
+    // SettlementConfig: discriminator(8) || validators(4 + 32n) || threshold(1) || version(8)
+    const validatorCount = settlementConfig.data.readUInt32LE(8);
+    return new anchor.BN(settlementConfig.data.subarray(13 + 32 * validatorCount, 21 + 32 * validatorCount), 'le').toNumber();
+}
+
+/**
+ * POST /attest-mint
+ *
+ * Body: { burn_nonce, asset_id }
+ *
+ * Acknowledges that a burn was minted on X1, for the burn program's
+ * settle_burn. We only sign if, on finalized X1 state, the asset's mint
+ * program holds a processed_burn_v3 PDA for exactly this Solana
+ * BurnRecord (nonce, user, amount). Signs for the burn program's current
+ * SettlementConfig version and returns what the relayer must submit.
+ */
+app.post('/attest-mint', async (req, res) => {
+    const { burn_nonce, asset_id } = req.body || {};
+    const key: ValidatorKey = res.locals.validatorKey;
+    const span = startRequestSpan('attest_mint', req.header('traceparent'), {
+        transfer_id: transferId(burn_nonce),
+        burn_nonce: Number(burn_nonce) || 0,
+        validator: key.pubkey,
+    });
+
+    try {
+        if (burn_nonce === undefined || !MINT_PROGRAM_BY_ASSET[asset_id as Asset]) {
+            return sendSigned(req, res, 400, { error: 'Missing or invalid fields: burn_nonce, asset_id' });
         }
 
-        console.log(`✅ Burn finalized (${slotsSinceBurn} slots ago)`);
+        const refusal = key.refusal(asset_id);
+        if (refusal) {
+            return sendSigned(req, res, 403, { error: refusal, validator: key.pubkey });
+        }
 
-        // ✅ NEW: Step 6 - Create and sign asset-aware attestation V3
-        const message = createAttestationMessageV3(
-            asset_id,                  // ✅ NEW: Include asset_id in hash
-            burn_nonce,
-            burnRecord.user,
-            burnRecord.amount,
-            validator_set_version
-        );
+        const burnRecord = await fetchBurnRecord(burn_nonce);
+        if (!burnRecord) {
+            return sendSigned(req, res, 404, { error: 'Burn record not found', burn_nonce });
+        }
 
-        const signature = nacl.sign.detached(
-            message,
-            validatorKeypair.secretKey
+        const [processedBurnPda] = PublicKey.findProgramAddressSync(
+            [
+                Buffer.from('processed_burn_v3'),
+                Buffer.from([asset_id]),
+                new anchor.BN(burn_nonce).toArrayLike(Buffer, 'le', 8),
+                burnRecord.user.toBuffer(),
+            ],
+            MINT_PROGRAM_BY_ASSET[asset_id as Asset]
         );
+        const processed = await x1Connection.getAccountInfo(processedBurnPda);
+        if (!processed || !processed.owner.equals(MINT_PROGRAM_BY_ASSET[asset_id as Asset])) {
+            return sendSigned(req, res, 404, { error: 'Burn not minted on X1 (finalized)', burn_nonce, asset_id });
+        }
+
+        // ProcessedBurnV3: discriminator(8) || asset_id(1) || nonce(8) || user(32) || amount(8) || processed_at(8)
+        const data = processed.data;
+        const x1ProcessedAt = new anchor.BN(data.subarray(57, 65), 'le').fromTwos(64).toNumber();
+        if (
+            data[8] !== asset_id ||
+            new anchor.BN(data.subarray(9, 17), 'le').toNumber() !== burn_nonce ||
+            !new PublicKey(data.subarray(17, 49)).equals(burnRecord.user) ||
+            new anchor.BN(data.subarray(49, 57), 'le').toNumber() !== burnRecord.amount
+        ) {
+            return sendSigned(req, res, 409, { error: 'X1 mint record does not match the Solana burn record', burn_nonce });
+        }
+
+        const version = await fetchSettlementVersion();
+        if (version === null) {
+            return sendSigned(req, res, 404, { error: 'Burn program settlement is not initialized' });
+        }
+
+        const message = createSettlementMessage(version, asset_id, burn_nonce, burnRecord.user, burnRecord.amount, x1ProcessedAt);
+        const signature = await key.signer.sign(message);
 
-        // ✅ NEW: Include asset_id in response
-        const attestation = {
-            asset_id,                                          // ✅ NEW
-            asset_name: ASSET_NAMES[asset_id],                 // ✅ NEW (for debugging)
+        console.log(`✅ X1 mint acknowledged for burn ${burn_nonce} (${ASSET_NAMES[asset_id as Asset]}, settlement v${version})`);
+        span.set({ settlement_version: version });
+
+        return sendSigned(req, res, 200, {
             burn_nonce,
+            asset_id,
             user: burnRecord.user.toBase58(),
             amount: burnRecord.amount,
-            validator_set_version,
-            validator_pubkey: validatorKeypair.publicKey.toBase58(),
+            x1_processed_at: x1ProcessedAt,
+            settlement_version: version,
+            validator_pubkey: key.pubkey,
             signature: Array.from(signature),
-            timestamp: Date.now(),
-        };
+        });
+    } catch (error: any) {
+        console.error('❌ Error:', error);
+        return sendSigned(req, res, 500, { error: 'Internal server error', message: error.message });
+    } finally {
+        span.set({ 'http.status_code': res.statusCode });
+        span.end(res.statusCode >= 500 ? new Error('Internal server error') : undefined);
+    }
+});
 
-        console.log(`✅ Attestation V3 signed (asset-aware)`);
-        console.log(`   Asset: ${ASSET_NAMES[asset_id]} (asset_id=${asset_id})`);
-        console.log(`   Version: ${validator_set_version}`);
-        console.log(`📤 Returning attestation to user\n`);
+// ============================================================================
+// SECTION 6.5.3: Return Transfers (X1 → Solana)
+// ============================================================================
 
-        return res.json(attestation);
+interface ReturnBurn {
+    nonce: number;
+    user: PublicKey;
+    solanaRecipient: PublicKey;
+    amount: number;
+    slot: number;
+}
+
+/**
+ * Return attestation (must match return_attestation_message in the light client)
+ *
+ * Format: sha256(ATTESTATION_DOMAIN || "RETURN" || asset_id || validator_set_version || nonce
+ *                || user || solana_recipient || amount || x1_slot)
+ */
+function createReturnAttestationMessage(asset_id: Asset, validatorSetVersion: number, burn: ReturnBurn): Buffer {
+    return crypto
+        .createHash('sha256')
+        .update(ATTESTATION_DOMAIN)
+        .update(Buffer.from('RETURN'))
+        .update(Buffer.from([asset_id]))
+        .update(new anchor.BN(validatorSetVersion).toArrayLike(Buffer, 'le', 8))
+        .update(new anchor.BN(burn.nonce).toArrayLike(Buffer, 'le', 8))
+        .update(burn.user.toBuffer())
+        .update(burn.solanaRecipient.toBuffer())
+        .update(new anchor.BN(burn.amount).toArrayLike(Buffer, 'le', 8))
+        .update(new anchor.BN(burn.slot).toArrayLike(Buffer, 'le', 8))
+        .digest();
+}
 
+/**
+ * Return release (must match return_release_message in the burn program)
+ *
+ * Format: sha256(SETTLEMENT_DOMAIN || "RETURN" || version || asset_id || return_nonce || recipient || amount || x1_slot)
+ */
+function createReturnReleaseMessage(version: number, asset_id: Asset, burn: ReturnBurn): Buffer {
+    return crypto
+        .createHash('sha256')
+        .update(Buffer.from(SETTLEMENT_DOMAIN))
+        .update(Buffer.from('RETURN'))
+        .update(new anchor.BN(version).toArrayLike(Buffer, 'le', 8))
+        .update(Buffer.from([asset_id]))
+        .update(new anchor.BN(burn.nonce).toArrayLike(Buffer, 'le', 8))
+        .update(burn.solanaRecipient.toBuffer())
+        .update(new anchor.BN(burn.amount).toArrayLike(Buffer, 'le', 8))
+        .update(new anchor.BN(burn.slot).toArrayLike(Buffer, 'le', 8))
+        .digest();
+}
+
+const RETURN_BURN_RECORD_DISCRIMINATOR = crypto.createHash('sha256').update('account:ReturnBurnRecord').digest().subarray(0, 8);
+
+/**
+ * The mint program's ReturnBurnRecord for `returnNonce` on finalized X1, or null
+ *
+ * Layout: discriminator(8) || nonce(8) || user(32) || solana_recipient(32) || amount(8) || slot(8) || timestamp(8) || bump(1)
+ */
+async function fetchReturnBurn(asset_id: Asset, returnNonce: number): Promise<ReturnBurn | null> {
+    const mintProgram = MINT_PROGRAM_BY_ASSET[asset_id];
+    const [returnBurnPda] = PublicKey.findProgramAddressSync(
+        [Buffer.from('return_burn'), new anchor.BN(returnNonce).toArrayLike(Buffer, 'le', 8)],
+        mintProgram
+    );
+    const account = await x1Connection.getAccountInfo(returnBurnPda);
+    if (!account || !account.owner.equals(mintProgram) || account.data.length < 105
+        || !account.data.subarray(0, 8).equals(RETURN_BURN_RECORD_DISCRIMINATOR)) {
+        return null;
+    }
+    const data = account.data;
+    return {
+        nonce: new anchor.BN(data.subarray(8, 16), 'le').toNumber(),
+        user: new PublicKey(data.subarray(16, 48)),
+        solanaRecipient: new PublicKey(data.subarray(48, 80)),
+        amount: new anchor.BN(data.subarray(80, 88), 'le').toNumber(),
+        slot: new anchor.BN(data.subarray(88, 96), 'le').toNumber(),
+    };
+}
+
+/**
+ * POST /attest-return
+ *
+ * Body: { return_nonce, asset_id, validator_set_version }
+ *
+ * Attests an X1 return burn (burn_for_return) for the light client's
+ * verify_return_burn. We only sign what we read ourselves from finalized
+ * X1 state: the record's user, Solana recipient, amount and slot.
+ */
+app.post('/attest-return', async (req, res) => {
+    const { return_nonce, asset_id, validator_set_version } = req.body || {};
+    const key: ValidatorKey = res.locals.validatorKey;
+    const span = startRequestSpan('attest_return', req.header('traceparent'), {
+        return_nonce: Number(return_nonce) || 0,
+        validator: key.pubkey,
+    });
+
+    try {
+        if (return_nonce === undefined || validator_set_version === undefined || !MINT_PROGRAM_BY_ASSET[asset_id as Asset]) {
+            return sendSigned(req, res, 400, { error: 'Missing or invalid fields: return_nonce, asset_id, validator_set_version' });
+        }
+
+        const burn = await fetchReturnBurn(asset_id, return_nonce);
+        if (!burn) {
+            return sendSigned(req, res, 404, { error: 'Return burn not found on X1 (finalized)', return_nonce, asset_id });
+        }
+
+        const refusal = key.refusal(asset_id, burn.amount);
+        if (refusal) {
+            return sendSigned(req, res, 403, { error: refusal, validator: key.pubkey });
+        }
+
+        const message = createReturnAttestationMessage(asset_id, validator_set_version, burn);
+        const signature = await key.signer.sign(message);
+
+        console.log(`✅ Return burn ${return_nonce} attested (${ASSET_NAMES[asset_id as Asset]}, ${burn.amount} to ${burn.solanaRecipient.toBase58()})`);
+        span.set({ validator_set_version });
+
+        return sendSigned(req, res, 200, {
+            return_nonce,
+            asset_id,
+            user: burn.user.toBase58(),
+            solana_recipient: burn.solanaRecipient.toBase58(),
+            amount: burn.amount,
+            x1_slot: burn.slot,
+            validator_set_version,
+            validator_pubkey: key.pubkey,
+            signature: Array.from(signature),
+        });
     } catch (error: any) {
         console.error('❌ Error:', error);
-        return res.status(500).json({
-            error: 'Internal server error',
-            message: error.message
+        return sendSigned(req, res, 500, { error: 'Internal server error', message: error.message });
+    } finally {
+        span.set({ 'http.status_code': res.statusCode });
+        span.end(res.statusCode >= 500 ? new Error('Internal server error') : undefined);
+    }
+});
+
+/**
+ * POST /attest-return-release
+ *
+ * Body: { return_nonce, asset_id }
+ *
+ * Signs the burn program's release_return for an X1 return burn, but only
+ * once the light client holds its VerifiedReturnBurn on finalized X1
+ * state (a threshold already attested it) and it matches the record.
+ * Signs for the burn program's current SettlementConfig version.
+ */
+app.post('/attest-return-release', async (req, res) => {
+    const { return_nonce, asset_id } = req.body || {};
+    const key: ValidatorKey = res.locals.validatorKey;
+    const span = startRequestSpan('attest_return_release', req.header('traceparent'), {
+        return_nonce: Number(return_nonce) || 0,
+        validator: key.pubkey,
+    });
+
+    try {
+        if (return_nonce === undefined || !MINT_PROGRAM_BY_ASSET[asset_id as Asset]) {
+            return sendSigned(req, res, 400, { error: 'Missing or invalid fields: return_nonce, asset_id' });
+        }
+
+        const burn = await fetchReturnBurn(asset_id, return_nonce);
+        if (!burn) {
+            return sendSigned(req, res, 404, { error: 'Return burn not found on X1 (finalized)', return_nonce, asset_id });
+        }
+
+        const lightClient = new PublicKey(config.programs.light_client);
+        const [verifiedPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from('verified_return_burn'), Buffer.from([asset_id]), new anchor.BN(return_nonce).toArrayLike(Buffer, 'le', 8)],
+            lightClient
+        );
+        const verified = await x1Connection.getAccountInfo(verifiedPda);
+        if (!verified || !verified.owner.equals(lightClient)) {
+            return sendSigned(req, res, 404, { error: 'Return burn not verified by the light client (finalized)', return_nonce });
+        }
+        // VerifiedReturnBurn: discriminator(8) || asset_id(1) || return_nonce(8) || user(32) || solana_recipient(32) || amount(8) || x1_slot(8) ...
+        const data = verified.data;
+        if (
+            !new PublicKey(data.subarray(49, 81)).equals(burn.solanaRecipient) ||
+            new anchor.BN(data.subarray(81, 89), 'le').toNumber() !== burn.amount ||
+            new anchor.BN(data.subarray(89, 97), 'le').toNumber() !== burn.slot
+        ) {
+            return sendSigned(req, res, 409, { error: 'Verified return burn does not match the X1 record', return_nonce });
+        }
+
+        const refusal = key.refusal(asset_id, burn.amount);
+        if (refusal) {
+            return sendSigned(req, res, 403, { error: refusal, validator: key.pubkey });
+        }
+
+        const version = await fetchSettlementVersion();
+        if (version === null) {
+            return sendSigned(req, res, 404, { error: 'Burn program settlement is not initialized' });
+        }
+
+        const message = createReturnReleaseMessage(version, asset_id, burn);
+        const signature = await key.signer.sign(message);
+
+        console.log(`✅ Return ${return_nonce} release signed (settlement v${version})`);
+        span.set({ settlement_version: version });
+
+        return sendSigned(req, res, 200, {
+            return_nonce,
+            asset_id,
+            recipient: burn.solanaRecipient.toBase58(),
+            amount: burn.amount,
+            x1_slot: burn.slot,
+            settlement_version: version,
+            validator_pubkey: key.pubkey,
+            signature: Array.from(signature),
         });
+    } catch (error: any) {
+        console.error('❌ Error:', error);
+        return sendSigned(req, res, 500, { error: 'Internal server error', message: error.message });
+    } finally {
+        span.set({ 'http.status_code': res.statusCode });
+        span.end(res.statusCode >= 500 ? new Error('Internal server error') : undefined);
     }
 });
 
+// ============================================================================
+// SECTION 6.6: Backfill (Snapshot / Resync of Solana Burn History)
+// ============================================================================
+
+const BURNED_EVENT_DISCRIMINATOR = crypto.createHash('sha256').update('event:Burned').digest().subarray(0, 8);
+
+/**
+ * Decode the burn program's `Burned` event from transaction logs
+ *
+ * Layout (Anchor event): discriminator(8) || user(32) || amount(8) || nonce(8) || timestamp(8)
+ */
+function decodeBurnedEvent(logs: string[]): { user: PublicKey; amount: number; nonce: number } | null {
+    for (const line of logs) {
+        if (!line.startsWith('Program data: ')) {
+            continue;
+        }
+        const data = Buffer.from(line.slice('Program data: '.length), 'base64');
+        if (data.length < 64 || !data.subarray(0, 8).equals(BURNED_EVENT_DISCRIMINATOR)) {
+            continue;
+        }
+        return {
+            user: new PublicKey(data.subarray(8, 40)),
+            amount: new anchor.BN(data.subarray(40, 48), 'le').toNumber(),
+            nonce: new anchor.BN(data.subarray(48, 56), 'le').toNumber(),
+        };
+    }
+    return null;
+}
+
+/**
+ * Read the next unused nonce from the burn program's GlobalState
+ */
+async function fetchNonceCounter(): Promise<number> {
+    const [globalState] = PublicKey.findProgramAddressSync([Buffer.from('global_state')], BURN_PROGRAM_ID);
+    const info = await solanaConnection.getAccountInfo(globalState);
+    if (!info) {
+        throw new Error('Burn program GlobalState not found');
+    }
+    return new anchor.BN(info.data.subarray(8, 16), 'le').toNumber();
+}
+
+/**
+ * Scan historical burns [from, to) and rebuild the verification cache
+ *
+ * Each burn goes through the same checks as a live request (exactly one
+ * supported SPL burn, BurnRecord present, finality) and additionally must
+ * match the Burned event emitted in its transaction. Anything that fails
+ * is skipped and logged - it will simply be verified live on request.
+ */
+async function backfillBurns(from: number, to?: number) {
+    const end = to ?? await fetchNonceCounter();
+    const currentSlot = await solanaConnection.getSlot('finalized');
+    console.log(`\n🗂️  Backfill: scanning burn nonces ${from}..${end - 1}`);
+
+    let cached = 0;
+    let skipped = 0;
+    for (let nonce = from; nonce < end; nonce++) {
+        if (burnCache.has(nonce)) {
+            continue;
+        }
+        try {
+            const detection = await detectBurnedMint(nonce);
+            const record = detection && await fetchBurnRecord(nonce);
+            if (!detection || !record) {
+                skipped++;
+                continue;
+            }
+            if (currentSlot - record.slot < FINALITY_SLOTS) {
+                break; // Everything after this nonce is newer still
+            }
+            if (currentSlot - record.slot < requiredSlots(record.confirmation_depth)) {
+                skipped++;
+                continue; // Deeper confirmation depth not reached yet
+            }
+
+            const tx = await solanaConnection.getTransaction(detection.tx_signature, {
+                maxSupportedTransactionVersion: 0,
+                commitment: 'finalized',
+            });
+            const event = decodeBurnedEvent(tx?.meta?.logMessages || []);
+            if (!event || event.nonce !== nonce || !event.user.equals(record.user) || event.amount !== record.amount) {
+                console.log(`   ⚠️  Nonce ${nonce}: Burned event missing or inconsistent, skipping`);
+                skipped++;
+                continue;
+            }
+
+            burnCache.put({
+                burn_nonce: nonce,
+                asset_id: detection.asset_id,
+                mint: detection.mint.toBase58(),
+                user: record.user.toBase58(),
+                amount: record.amount,
+                slot: record.slot,
+                timestamp: record.timestamp,
+                confirmation_depth: record.confirmation_depth,
+                tx_signature: detection.tx_signature,
+                insurance: await fetchBurnInsurance(nonce, record.user, detection.mint),
+                relayer_claim: await fetchBurnClaim(nonce, record.user, detection.mint),
+            });
+            cached++;
+
+            if (cached % 50 === 0) {
+                burnCache.flush();
+                console.log(`   … ${cached} burns cached (nonce ${nonce})`);
+            }
+        } catch (error: any) {
+            console.log(`   ⚠️  Nonce ${nonce}: ${error.message}`);
+            skipped++;
+        }
+    }
+
+    burnCache.flush();
+    console.log(`✅ Backfill complete: ${cached} cached, ${skipped} skipped, ${burnCache.size} total\n`);
+}
+
+/**
+ * Parse `--backfill <from>[:<to>]` (to defaults to the current nonce counter)
+ */
+function parseBackfillArg(argv: string[]): { from: number; to?: number } | null {
+    const index = argv.indexOf('--backfill');
+    if (index === -1) {
+        return null;
+    }
+    const [from, to] = (argv[index + 1] || '0').split(':');
+    return { from: parseInt(from) || 0, to: to ? parseInt(to) : undefined };
+}
+
 // Health check
 app.get('/health', (req, res) => {
     res.json({
         status: 'ok',
-        version: 'v3-asset-aware',
+        version: PROTOCOL_VERSION,
+        max_batch_size: MAX_BATCH_SIZE,
+        cached_burns: burnCache.size,
+        reorg_watch: reorgMonitor.watching,
         supported_assets: Object.entries(ASSET_BY_MINT).map(([mint, asset_id]) => ({
             asset: ASSET_NAMES[asset_id],
             asset_id,
             mint
         })),
-        validator: validatorKeypair.publicKey.toBase58(),
-        solana_rpc: SOLANA_RPC
+        // First key; kept for single-key clients
+        validator: keyRing.primary.pubkey,
+        validators: keyRing.keys.map(key => ({
+            pubkey: key.pubkey,
+            backend: key.signer.kind,
+            policy: key.policy,
+            metrics: key.metrics,
+        })),
+        bridge_domain: DOMAIN_SEPARATOR,
+        x1_genesis_hash: X1_GENESIS_HASH ? bs58.encode(X1_GENESIS_HASH) : null,
+        solana_rpc: SOLANA_RPC,
+        alert_destinations: alertDispatcher.destinations,
+        telemetry: Boolean(config.telemetry.endpoint)
     });
 });
 
-app.listen(PORT, () => {
-    console.log(`\n🚀 X1 Validator Attestation Service V3 (Asset-Aware)`);
-    console.log(`   Listening on port ${PORT}`);
-    console.log(`   Validator: ${validatorKeypair.publicKey.toBase58()}`);
-    console.log(`   Solana RPC: ${SOLANA_RPC}`);
-    console.log(`\n📋 Supported Assets:`);
-    Object.entries(ASSET_BY_MINT).forEach(([mint, asset_id]) => {
-        console.log(`   ${ASSET_NAMES[asset_id]} (asset_id=${asset_id}): ${mint}`);
+// Per-key counters, Prometheus text format
+app.get('/metrics', (req, res) => {
+    const counters = ['requests', 'signed', 'refused', 'rejected', 'errors'] as const;
+    const lines: string[] = [];
+    for (const name of counters) {
+        lines.push(`# TYPE xencat_attestation_${name}_total counter`);
+        for (const key of keyRing.keys) {
+            lines.push(`xencat_attestation_${name}_total{validator="${key.pubkey}"} ${key.metrics[name]}`);
+        }
+    }
+    lines.push('# TYPE xencat_attestation_last_signed_timestamp_seconds gauge');
+    for (const key of keyRing.keys) {
+        lines.push(`xencat_attestation_last_signed_timestamp_seconds{validator="${key.pubkey}"} ${key.metrics.last_signed_at ?? 0}`);
+    }
+    res.type('text/plain; version=0.0.4').send(lines.join('\n') + '\n');
+});
+
+ValidatorKeyRing.load(config).then(async ring => {
+    keyRing = ring;
+    // Sign for the deployment the light client belongs to, never a hardcoded one
+    DOMAIN_SEPARATOR = await fetchBridgeDomain(x1Connection, new PublicKey(config.programs.light_client));
+    // ...and only for the X1 network it is bound to (throws on a fork or clone)
+    X1_GENESIS_HASH = await verifiedX1ChainId(x1Connection, new PublicKey(config.programs.light_client));
+    ATTESTATION_DOMAIN = attestationDomain(DOMAIN_SEPARATOR, X1_GENESIS_HASH);
+    for (const key of ring.keys) {
+        watchdogs.set(key.pubkey, new Watchdog(config, key.signer.publicKey, alertDispatcher));
+    }
+    process.on('exit', () => ring.close());
+
+    app.listen(PORT, () => {
+        console.log(`\n🚀 X1 Validator Attestation Service V3 (Asset-Aware)`);
+        console.log(`   Listening on port ${PORT}`);
+        for (const key of ring.keys) {
+            const policy = key.refusal() ? 'paused' : `assets ${key.policy.assets?.join(',') || 'all'}, max ${key.policy.max_amount || 'none'}`;
+            console.log(`   🔑 Validator: ${key.pubkey} (${key.signer.kind} signer, ${policy})`);
+        }
+        console.log(`   Solana RPC: ${SOLANA_RPC}`);
+        console.log(`   Bridge domain: ${DOMAIN_SEPARATOR}`);
+        console.log(`   X1 genesis: ${X1_GENESIS_HASH ? bs58.encode(X1_GENESIS_HASH) : 'not bound'}`);
+        console.log(`\n📋 Supported Assets:`);
+        Object.entries(ASSET_BY_MINT).forEach(([mint, asset_id]) => {
+            console.log(`   ${ASSET_NAMES[asset_id]} (asset_id=${asset_id}): ${mint}`);
+        });
+        console.log(`\n🔔 Alerts: ${alertDispatcher.destinations.join(', ') || 'log only'}`);
+        console.log(`📡 Telemetry: ${config.telemetry.endpoint ? `every ${config.telemetry.interval_seconds}s to ${config.telemetry.endpoint}` : 'off'}`);
+        console.log(`\n✅ Ready to sign asset-aware attestations!\n`);
+
+        watchdogs.forEach(watchdog => watchdog.start());
+        reorgMonitor.start();
+        startTelemetry(config.telemetry, telemetry);
+
+        // Backfill runs in the background; requests are served meanwhile
+        const backfill = parseBackfillArg(process.argv);
+        if (backfill) {
+            backfillBurns(backfill.from, backfill.to).catch(error => {
+                console.error('❌ Backfill failed:', error.message);
+            });
+        }
     });
-    console.log(`\n✅ Ready to sign asset-aware attestations!\n`);
+}).catch(error => {
+    console.error('❌ Failed to start:', error.message);
+    process.exit(1);
 });
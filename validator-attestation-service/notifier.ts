@@ -0,0 +1,150 @@
+import type { BridgeConfig } from '../sdk/bridge-config/src';
+
+/**
+ * Operator alert notifiers for the validator watchdog
+ *
+ * A Notifier delivers one Alert to one destination. Built-in notifiers
+ * cover a generic JSON webhook, Telegram and Discord; anything else
+ * (PagerDuty, email, ...) implements the same interface and is passed to
+ * AlertDispatcher.register(), so operators don't need glue code in the
+ * service itself.
+ *
+ * The dispatcher rate-limits per alert key (alerts.cooldown_seconds) so a
+ * condition that persists does not page every check interval, and a
+ * failing notifier never affects attestation signing.
+ */
+
+export type AlertKind = 'missed_attestations' | 'version_mismatch' | 'low_balance' | 'not_in_validator_set' | 'burn_reorged';
+
+export interface Alert {
+    kind: AlertKind;
+    severity: 'warning' | 'critical';
+    /** Validator pubkey the alert is about */
+    validator: string;
+    /** One-line, actionable description */
+    message: string;
+    details?: Record<string, string | number>;
+    /** Unix seconds */
+    timestamp: number;
+}
+
+export interface Notifier {
+    readonly name: string;
+    notify(alert: Alert): Promise<void>;
+}
+
+function formatText(alert: Alert): string {
+    const icon = alert.severity === 'critical' ? '🚨' : '⚠️';
+    const lines = [`${icon} [${alert.kind}] ${alert.message}`, `Validator: ${alert.validator}`];
+    for (const [key, value] of Object.entries(alert.details || {})) {
+        lines.push(`${key}: ${value}`);
+    }
+    return lines.join('\n');
+}
+
+async function post(url: string, body: unknown): Promise<void> {
+    const response = await fetch(url, {
+        method: 'POST',
+        headers: { 'Content-Type': 'application/json' },
+        body: JSON.stringify(body),
+    });
+    if (!response.ok) {
+        throw new Error(`HTTP ${response.status}`);
+    }
+}
+
+/** POSTs the Alert as JSON */
+export class WebhookNotifier implements Notifier {
+    readonly name = 'webhook';
+    constructor(private readonly url: string) {}
+
+    notify(alert: Alert): Promise<void> {
+        return post(this.url, alert);
+    }
+}
+
+/** Telegram Bot API sendMessage */
+export class TelegramNotifier implements Notifier {
+    readonly name = 'telegram';
+    constructor(private readonly botToken: string, private readonly chatId: string) {}
+
+    notify(alert: Alert): Promise<void> {
+        return post(`https://api.telegram.org/bot${this.botToken}/sendMessage`, {
+            chat_id: this.chatId,
+            text: formatText(alert),
+            disable_web_page_preview: true,
+        });
+    }
+}
+
+/** Discord channel webhook */
+export class DiscordNotifier implements Notifier {
+    readonly name = 'discord';
+    constructor(private readonly webhookUrl: string) {}
+
+    notify(alert: Alert): Promise<void> {
+        // Discord rejects messages over 2000 characters
+        return post(this.webhookUrl, { content: formatText(alert).slice(0, 2000) });
+    }
+}
+
+export class AlertDispatcher {
+    private readonly notifiers: Notifier[] = [];
+    private readonly lastSent = new Map<string, number>();
+
+    constructor(private readonly cooldownSeconds: number) {}
+
+    register(notifier: Notifier): this {
+        this.notifiers.push(notifier);
+        return this;
+    }
+
+    get destinations(): string[] {
+        return this.notifiers.map(n => n.name);
+    }
+
+    /**
+     * Send an alert to every notifier, unless one with the same key was sent
+     * within the cooldown. `key` defaults to the alert kind.
+     */
+    async send(alert: Omit<Alert, 'timestamp'>, key: string = alert.kind): Promise<void> {
+        const now = Math.floor(Date.now() / 1000);
+        const last = this.lastSent.get(key);
+        if (last !== undefined && now - last < this.cooldownSeconds) {
+            return;
+        }
+        this.lastSent.set(key, now);
+
+        console.log(`🔔 Alert ${alert.kind}: ${alert.message}`);
+        const full: Alert = { ...alert, timestamp: now };
+        await Promise.all(this.notifiers.map(notifier =>
+            notifier.notify(full).catch(error => {
+                console.error(`   ⚠️  ${notifier.name} notifier failed: ${error.message}`);
+            })
+        ));
+    }
+
+    /** Forget the cooldown so the next alert of this key is sent (condition cleared) */
+    resolve(key: string): void {
+        this.lastSent.delete(key);
+    }
+}
+
+/**
+ * Dispatcher with the notifiers enabled in the [alerts] config section
+ */
+export function createAlertDispatcher(alerts: BridgeConfig['alerts']): AlertDispatcher {
+    const dispatcher = new AlertDispatcher(alerts.cooldown_seconds);
+    if (alerts.webhook_url) {
+        dispatcher.register(new WebhookNotifier(alerts.webhook_url));
+    }
+    if (alerts.discord_webhook_url) {
+        dispatcher.register(new DiscordNotifier(alerts.discord_webhook_url));
+    }
+    // The bot token is a credential: environment only, never the config file
+    const telegramToken = process.env.XENCAT_TELEGRAM_BOT_TOKEN;
+    if (alerts.telegram_chat_id && telegramToken) {
+        dispatcher.register(new TelegramNotifier(telegramToken, alerts.telegram_chat_id));
+    }
+    return dispatcher;
+}
@@ -0,0 +1,302 @@
+import { Connection, PublicKey } from '@solana/web3.js';
+import crypto from 'crypto';
+import fs from 'fs';
+import path from 'path';
+import { AlertDispatcher } from './notifier';
+import { ValidatorKey } from './validator-keys';
+
+/**
+ * Reorg detection for burns signed before Solana finality
+ *
+ * The service signs once a burn is FINALITY_SLOTS behind the `confirmed`
+ * tip, which is normally rooted. A misconfigured node (commitment,
+ * finality_slots, a lagging RPC) can sign a burn that a fork later drops
+ * or rewrites. Every burn signed while its slot was above our finalized
+ * slot is kept in a watch list (service.signed_burns_file) until it is
+ * settled:
+ *
+ * - finalized: its transaction is finalized and the BurnRecord (read at
+ *   `finalized`) still holds the signed user and amount -> dropped
+ * - reorged: otherwise, once its slot is finalized -> critical
+ *   `burn_reorged` alert and a revocation is published
+ *
+ * A revocation is this service's FREEZE_BURN approval (see freeze_burn.rs)
+ * for every local key, served at GET /revocations and collected by
+ * `scripts/burn-freeze.ts collect`. The reason is sha256("reorg:<asset>:<nonce>"),
+ * identical on every validator, so approvals from independent services
+ * combine. They are signed on request because the message binds the
+ * current BridgeConfig nonce.
+ */
+
+export interface SignedBurn {
+    burn_nonce: number;
+    asset_id: number;
+    user: string;
+    amount: number;
+    slot: number;
+    tx_signature: string;
+    /** Keys on this service that signed it */
+    validators: string[];
+    signed_at: number;
+    /** Set once the burn was found rewritten or dropped */
+    reorged?: { detected_at: number; cause: string };
+}
+
+export interface Revocation {
+    action: 'FREEZE_BURN';
+    asset_id: number;
+    burn_nonce: number;
+    user: string;
+    /** hex sha256 of the incident reference */
+    reason: string;
+    validator_set_version: number;
+    config_nonce: number;
+    /** X1 genesis hash the message is bound to (hex), if any */
+    x1_genesis_hash: string | null;
+    /** hex message validators sign */
+    message: string;
+    approvals: Array<{ validator_pubkey: string; signature: number[] }>;
+    cause: string;
+    detected_at: number;
+}
+
+/** Revocations older than this are dropped (the incident is handled or moot) */
+const REVOCATION_TTL_SECONDS = 7 * 24 * 3600;
+
+/** Must match `burn-freeze.ts propose --reason reorg:<asset>:<nonce>` */
+export function reorgReason(assetId: number, burnNonce: number): Buffer {
+    return crypto.createHash('sha256').update(`reorg:${assetId}:${burnNonce}`).digest();
+}
+
+function u64(value: number): Buffer {
+    return Buffer.from(new BigUint64Array([BigInt(value)]).buffer);
+}
+
+/** Must match create_freeze_message in the light client */
+function freezeMessage(validatorSetVersion: number, configNonce: number, burn: SignedBurn, x1GenesisHash: Buffer | null): Buffer {
+    return crypto.createHash('sha256').update(Buffer.concat([
+        Buffer.from('FREEZE_BURN'),
+        u64(validatorSetVersion),
+        u64(configNonce),
+        Buffer.from([burn.asset_id]),
+        u64(burn.burn_nonce),
+        new PublicKey(burn.user).toBuffer(),
+        reorgReason(burn.asset_id, burn.burn_nonce),
+        x1GenesisHash ?? Buffer.alloc(0),
+    ])).digest();
+}
+
+export class ReorgMonitor {
+    private burns = new Map<number, SignedBurn>();
+    private finalizedSlot = 0;
+    private readonly lightClient: PublicKey;
+
+    constructor(
+        private readonly file: string,
+        private readonly solana: Connection,
+        private readonly x1: Connection,
+        lightClient: string,
+        private readonly burnProgram: PublicKey,
+        private readonly alerts: AlertDispatcher,
+        /** Called when a burn is found reorged (e.g. evict it from the burn cache) */
+        private readonly onReorg: (burnNonce: number) => void = () => {}
+    ) {
+        this.lightClient = new PublicKey(lightClient);
+        if (fs.existsSync(file)) {
+            try {
+                const stored: SignedBurn[] = JSON.parse(fs.readFileSync(file, 'utf-8'));
+                stored.forEach(burn => this.burns.set(burn.burn_nonce, burn));
+            } catch (error: any) {
+                // Unlike the burn cache this list matters: keep the file for inspection
+                console.error(`⚠️  Unreadable signed burns file ${file}: ${error.message}`);
+                fs.renameSync(file, `${file}.corrupt-${Date.now()}`);
+            }
+        }
+    }
+
+    get watching(): number {
+        return [...this.burns.values()].filter(b => !b.reorged).length;
+    }
+
+    start(intervalSeconds = 10): void {
+        const run = () => this.check().catch(error => console.error('⚠️  Reorg check failed:', error.message));
+        run();
+        setInterval(run, intervalSeconds * 1000).unref();
+    }
+
+    /**
+     * Record a signature; burns at or below our finalized slot need no watching
+     */
+    watch(burn: Omit<SignedBurn, 'validators' | 'signed_at'>, validator: string): void {
+        const existing = this.burns.get(burn.burn_nonce);
+        if (existing) {
+            if (!existing.validators.includes(validator)) {
+                existing.validators.push(validator);
+                this.save();
+            }
+            return;
+        }
+        if (burn.slot <= this.finalizedSlot) {
+            return;
+        }
+        console.log(`   👀 Signed before finality (slot ${burn.slot} > finalized ${this.finalizedSlot}); watching for reorgs`);
+        this.burns.set(burn.burn_nonce, { ...burn, validators: [validator], signed_at: Math.floor(Date.now() / 1000) });
+        this.save();
+    }
+
+    async check(): Promise<void> {
+        this.finalizedSlot = await this.solana.getSlot('finalized');
+        const now = Math.floor(Date.now() / 1000);
+        let changed = false;
+
+        for (const burn of this.burns.values()) {
+            if (burn.reorged) {
+                if (now - burn.reorged.detected_at > REVOCATION_TTL_SECONDS) {
+                    this.burns.delete(burn.burn_nonce);
+                    changed = true;
+                }
+                continue;
+            }
+            if (burn.slot > this.finalizedSlot) {
+                continue;
+            }
+
+            const cause = await this.verify(burn);
+            if (!cause) {
+                this.burns.delete(burn.burn_nonce);
+                changed = true;
+                continue;
+            }
+
+            burn.reorged = { detected_at: now, cause };
+            changed = true;
+            this.onReorg(burn.burn_nonce);
+            const x1Status = await this.x1Status(burn);
+            await this.alerts.send({
+                kind: 'burn_reorged',
+                severity: 'critical',
+                validator: burn.validators.join(', '),
+                message: `Signed burn ${burn.burn_nonce} did not finalize as signed (${cause}); ` +
+                    (x1Status === 'minted'
+                        ? 'it is ALREADY MINTED on X1 - freeze is no longer possible, escalate'
+                        : 'freeze approvals are published at /revocations'),
+                details: {
+                    asset_id: burn.asset_id,
+                    burn_nonce: burn.burn_nonce,
+                    user: burn.user,
+                    amount: burn.amount,
+                    slot: burn.slot,
+                    tx_signature: burn.tx_signature,
+                    x1_status: x1Status,
+                },
+            }, `burn_reorged:${burn.burn_nonce}`);
+        }
+
+        if (changed) {
+            this.save();
+        }
+    }
+
+    /**
+     * Why the burn does not stand as signed (undefined = it does)
+     */
+    private async verify(burn: SignedBurn): Promise<string | undefined> {
+        const { value: [status] } = await this.solana.getSignatureStatuses([burn.tx_signature], { searchTransactionHistory: true });
+        if (!status) {
+            return 'burn transaction not found on the finalized chain';
+        }
+        if (status.err) {
+            return 'burn transaction failed';
+        }
+        if (status.confirmationStatus !== 'finalized') {
+            // Its slot is finalized but the transaction is not: it was on a dropped fork
+            return `burn transaction only ${status.confirmationStatus ?? 'processed'}`;
+        }
+
+        const [burnRecordPda] = PublicKey.findProgramAddressSync(
+            [Buffer.from('burn_record'), u64(burn.burn_nonce)],
+            this.burnProgram
+        );
+        const record = await this.solana.getAccountInfo(burnRecordPda, 'finalized');
+        if (!record) {
+            return 'BurnRecord missing at finalized commitment';
+        }
+        // A burn_xencat_to record is attested for its destination (98..130), not the burner
+        const destination = record.data.length >= 195 ? new PublicKey(record.data.subarray(98, 130)) : PublicKey.default;
+        const user = (destination.equals(PublicKey.default) ? new PublicKey(record.data.subarray(8, 40)) : destination).toBase58();
+        const amount = Number(record.data.readBigUInt64LE(40));
+        if (user !== burn.user || amount !== burn.amount) {
+            return `BurnRecord now holds user ${user}, amount ${amount}`;
+        }
+        return undefined;
+    }
+
+    /**
+     * Where the burn stands on X1: freeze needs a VerifiedBurnV3 that is not minted yet
+     */
+    private async x1Status(burn: SignedBurn): Promise<'not_submitted' | 'verified' | 'minted'> {
+        const [verifiedBurn] = PublicKey.findProgramAddressSync(
+            [Buffer.from('verified_burn_v3'), Buffer.from([burn.asset_id]), new PublicKey(burn.user).toBuffer(), u64(burn.burn_nonce)],
+            this.lightClient
+        );
+        const info = await this.x1.getAccountInfo(verifiedBurn).catch(() => null);
+        if (!info) {
+            return 'not_submitted';
+        }
+        // disc(8) asset_id(1) burn_nonce(8) user(32) amount(8) verified_at(8) processed(1)
+        return info.data[65] ? 'minted' : 'verified';
+    }
+
+    /**
+     * FREEZE_BURN approvals of every local key that signed a reorged burn
+     *
+     * `x1GenesisHash` is the verified X1ChainId binding (null = unbound).
+     */
+    async revocations(keys: ValidatorKey[], x1GenesisHash: Buffer | null): Promise<Revocation[]> {
+        const reorged = [...this.burns.values()].filter(b => b.reorged);
+        if (reorged.length === 0) {
+            return [];
+        }
+
+        const pda = (seed: string) => PublicKey.findProgramAddressSync([Buffer.from(seed)], this.lightClient)[0];
+        const [setAccount, configAccount] = await this.x1.getMultipleAccountsInfo([pda('x1_validator_set_v2'), pda('bridge_config')]);
+        if (!setAccount || !configAccount) {
+            throw new Error('Validator set or bridge config not found on X1');
+        }
+        // X1ValidatorSet: disc(8) version(8) ...; BridgeConfig: disc(8) v2_cutoff_slot(8) nonce(8) ...
+        const validatorSetVersion = Number(setAccount.data.readBigUInt64LE(8));
+        const configNonce = Number(configAccount.data.readBigUInt64LE(16));
+
+        return Promise.all(reorged.map(async burn => {
+            const message = freezeMessage(validatorSetVersion, configNonce, burn, x1GenesisHash);
+            const signers = keys.filter(key => burn.validators.includes(key.pubkey));
+            return {
+                action: 'FREEZE_BURN' as const,
+                asset_id: burn.asset_id,
+                burn_nonce: burn.burn_nonce,
+                user: burn.user,
+                reason: reorgReason(burn.asset_id, burn.burn_nonce).toString('hex'),
+                validator_set_version: validatorSetVersion,
+                config_nonce: configNonce,
+                x1_genesis_hash: x1GenesisHash ? x1GenesisHash.toString('hex') : null,
+                message: message.toString('hex'),
+                approvals: await Promise.all(signers.map(async key => ({
+                    validator_pubkey: key.pubkey,
+                    signature: Array.from(await key.signer.sign(message)),
+                }))),
+                cause: burn.reorged!.cause,
+                detected_at: burn.reorged!.detected_at,
+            };
+        }));
+    }
+
+    /**
+     * Persist atomically (write + rename), like BurnCache
+     */
+    private save(): void {
+        const sorted = [...this.burns.values()].sort((a, b) => a.burn_nonce - b.burn_nonce);
+        const tmp = path.join(path.dirname(this.file), `.${path.basename(this.file)}.tmp`);
+        fs.writeFileSync(tmp, JSON.stringify(sorted));
+        fs.renameSync(tmp, this.file);
+    }
+}
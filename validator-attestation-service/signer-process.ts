@@ -0,0 +1,30 @@
+import nacl from 'tweetnacl';
+import fs from 'fs';
+import { parseKeypair } from '../sdk/bridge-config/src';
+
+/**
+ * Isolated signing backend (one child process per validator key)
+ *
+ * Forked by ProcessSigner with the keypair path as its only argument. The
+ * secret key is read here and never leaves this process: the service
+ * sends messages over IPC and gets signatures back.
+ *
+ * IPC: parent → { id, message: number[] }
+ *      child  → { ready: true, pubkey } once, then { id, signature: number[] } | { id, error }
+ */
+
+const keypair = parseKeypair(fs.readFileSync(process.argv[2], 'utf-8'));
+
+process.on('message', (request: { id: number; message: number[] }) => {
+    try {
+        const signature = nacl.sign.detached(Uint8Array.from(request.message), keypair.secretKey);
+        process.send!({ id: request.id, signature: Array.from(signature) });
+    } catch (error: any) {
+        process.send!({ id: request.id, error: error.message });
+    }
+});
+
+// Exit with the service
+process.on('disconnect', () => process.exit(0));
+
+process.send!({ ready: true, pubkey: keypair.publicKey.toBase58() });
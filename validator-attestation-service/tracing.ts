@@ -0,0 +1,183 @@
+import crypto from 'crypto';
+
+/**
+ * Minimal structured tracing for the attestation service
+ *
+ * - Spans carry W3C trace context (traceparent) so a transfer can be followed
+ *   from the relayer/SDK into this service
+ * - transfer_id is attached to every span and log line as correlation id
+ * - Finished spans are exported as OTLP/HTTP JSON when
+ *   OTEL_EXPORTER_OTLP_ENDPOINT is set, and always logged as JSON lines
+ */
+
+const SERVICE_NAME = process.env.OTEL_SERVICE_NAME || 'x1-validator-attestation';
+const OTLP_ENDPOINT = process.env.OTEL_EXPORTER_OTLP_ENDPOINT;
+const EXPORT_INTERVAL_MS = 5000;
+
+export type Attributes = Record<string, string | number | boolean>;
+
+export interface Span {
+    traceId: string;
+    spanId: string;
+    parentSpanId?: string;
+    name: string;
+    startNs: bigint;
+    endNs?: bigint;
+    attributes: Attributes;
+    status: 'ok' | 'error';
+    child(name: string, attributes?: Attributes): Span;
+    set(attributes: Attributes): void;
+    log(message: string, attributes?: Attributes): void;
+    end(error?: Error): void;
+    traceparent(): string;
+}
+
+const pending: Span[] = [];
+
+function randomHex(bytes: number): string {
+    return crypto.randomBytes(bytes).toString('hex');
+}
+
+function nowNs(): bigint {
+    return BigInt(Date.now()) * BigInt(1_000_000);
+}
+
+/**
+ * Parse a W3C traceparent header: 00-<trace_id>-<parent_span_id>-<flags>
+ */
+function parseTraceparent(header?: string): { traceId: string; spanId: string } | undefined {
+    const match = /^00-([0-9a-f]{32})-([0-9a-f]{16})-[0-9a-f]{2}$/.exec(header || '');
+    return match ? { traceId: match[1], spanId: match[2] } : undefined;
+}
+
+function createSpan(name: string, traceId: string, parentSpanId: string | undefined, attributes: Attributes): Span {
+    const span: Span = {
+        traceId,
+        spanId: randomHex(8),
+        parentSpanId,
+        name,
+        startNs: nowNs(),
+        attributes: { ...attributes },
+        status: 'ok',
+        child(childName, childAttributes = {}) {
+            // Children inherit correlation attributes (transfer_id etc.)
+            return createSpan(childName, span.traceId, span.spanId, { ...span.attributes, ...childAttributes });
+        },
+        set(extra) {
+            Object.assign(span.attributes, extra);
+        },
+        log(message, extra = {}) {
+            console.log(JSON.stringify({
+                ts: new Date().toISOString(),
+                service: SERVICE_NAME,
+                trace_id: span.traceId,
+                span_id: span.spanId,
+                span: span.name,
+                msg: message,
+                ...span.attributes,
+                ...extra,
+            }));
+        },
+        end(error) {
+            span.endNs = nowNs();
+            if (error) {
+                span.status = 'error';
+                span.attributes['error.message'] = error.message;
+            }
+            span.log('span_end', {
+                duration_ms: Number((span.endNs - span.startNs) / BigInt(1_000_000)),
+                status: span.status,
+            });
+            if (OTLP_ENDPOINT) {
+                pending.push(span);
+            }
+        },
+        traceparent() {
+            return `00-${span.traceId}-${span.spanId}-01`;
+        },
+    };
+    return span;
+}
+
+/**
+ * Start a root span for an incoming request, continuing the caller's trace
+ * when a valid traceparent header is supplied.
+ */
+export function startRequestSpan(name: string, traceparentHeader: string | undefined, attributes: Attributes = {}): Span {
+    const parent = parseTraceparent(traceparentHeader);
+    return createSpan(name, parent?.traceId || randomHex(16), parent?.spanId, attributes);
+}
+
+/**
+ * Correlation id for a bridge transfer (shared with the SDK/relayer)
+ */
+export function transferId(burnNonce: number | string, assetId?: number): string {
+    return assetId === undefined ? `solana:${burnNonce}` : `solana:${assetId}:${burnNonce}`;
+}
+
+function toOtlpAttributes(attributes: Attributes) {
+    return Object.entries(attributes).map(([key, value]) => ({
+        key,
+        value: typeof value === 'number'
+            ? { doubleValue: value }
+            : typeof value === 'boolean'
+                ? { boolValue: value }
+                : { stringValue: String(value) },
+    }));
+}
+
+async function flush() {
+    if (!OTLP_ENDPOINT || pending.length === 0) {
+        return;
+    }
+
+    const batch = pending.splice(0, pending.length);
+    const body = {
+        resourceSpans: [{
+            resource: { attributes: toOtlpAttributes({ 'service.name': SERVICE_NAME }) },
+            scopeSpans: [{
+                scope: { name: 'xencat-bridge' },
+                spans: batch.map(span => ({
+                    traceId: span.traceId,
+                    spanId: span.spanId,
+                    parentSpanId: span.parentSpanId,
+                    name: span.name,
+                    kind: 2, // SERVER
+                    startTimeUnixNano: span.startNs.toString(),
+                    endTimeUnixNano: (span.endNs || span.startNs).toString(),
+                    attributes: toOtlpAttributes(span.attributes),
+                    status: { code: span.status === 'ok' ? 1 : 2 },
+                })),
+            }],
+        }],
+    };
+
+    try {
+        await fetch(`${OTLP_ENDPOINT.replace(/\/$/, '')}/v1/traces`, {
+            method: 'POST',
+            headers: { 'Content-Type': 'application/json' },
+            body: JSON.stringify(body),
+        });
+    } catch (error: any) {
+        console.error('⚠️  OTLP export failed:', error.message);
+    }
+}
+
+if (OTLP_ENDPOINT) {
+    setInterval(flush, EXPORT_INTERVAL_MS).unref();
+}
+
+/**
+ * Run `fn` inside a child span of `parent`, ending it on completion or error
+ */
+export async function withSpan<T>(parent: Span, name: string, fn: (span: Span) => Promise<T>): Promise<T> {
+    const span = parent.child(name);
+    try {
+        const result = await fn(span);
+        span.end();
+        return result;
+    } catch (error: any) {
+        span.end(error);
+        throw error;
+    }
+}
@@ -0,0 +1,218 @@
+import { fork, ChildProcess } from 'child_process';
+import fs from 'fs';
+import path from 'path';
+import nacl from 'tweetnacl';
+import { Keypair, PublicKey } from '@solana/web3.js';
+import { BridgeConfig, ValidatorKeyConfig, expandHome, loadKeypair, parseKeypair } from '../sdk/bridge-config/src';
+
+/**
+ * Validator keys served by this process (multi-tenant service)
+ *
+ * One service can hold several validator identities ([[keys.validators]]),
+ * each with:
+ * - its own signing backend: 'local' signs in this process, 'process'
+ *   forks a signer (signer-process.ts) that is the only holder of the
+ *   secret key, so a bug in request handling cannot read it
+ * - its own policy: assets it attests, largest burn it signs, paused
+ * - its own metrics and watchdog
+ *
+ * Relayers pick the key with the X-Validator-Key request header (the SDK
+ * sends the endpoint's pubkey); requests without it go to the first key,
+ * which keeps single-key deployments and old clients working unchanged.
+ */
+
+export interface SigningBackend {
+    readonly kind: 'local' | 'process';
+    readonly publicKey: PublicKey;
+    sign(message: Uint8Array): Promise<Uint8Array>;
+    close(): void;
+}
+
+class LocalSigner implements SigningBackend {
+    readonly kind = 'local';
+    constructor(private readonly keypair: Keypair) {}
+
+    get publicKey(): PublicKey {
+        return this.keypair.publicKey;
+    }
+
+    async sign(message: Uint8Array): Promise<Uint8Array> {
+        return nacl.sign.detached(message, this.keypair.secretKey);
+    }
+
+    close(): void {}
+}
+
+/** Signing timeout: a hung signer must not hold requests forever */
+const SIGNER_TIMEOUT_MS = 5000;
+
+class ProcessSigner implements SigningBackend {
+    readonly kind = 'process';
+    private nextId = 0;
+    private readonly pending = new Map<number, { resolve: (s: Uint8Array) => void; reject: (e: Error) => void }>();
+
+    private constructor(private readonly child: ChildProcess, readonly publicKey: PublicKey) {
+        child.on('message', (reply: { id: number; signature?: number[]; error?: string }) => {
+            const request = this.pending.get(reply.id);
+            if (!request) {
+                return;
+            }
+            this.pending.delete(reply.id);
+            if (reply.signature) {
+                request.resolve(Uint8Array.from(reply.signature));
+            } else {
+                request.reject(new Error(reply.error || 'signer error'));
+            }
+        });
+        child.on('exit', code => {
+            console.error(`❌ Signer process for ${publicKey.toBase58()} exited (${code})`);
+            for (const request of this.pending.values()) {
+                request.reject(new Error('signer process exited'));
+            }
+            this.pending.clear();
+        });
+    }
+
+    static start(keypairPath: string): Promise<ProcessSigner> {
+        const script = path.join(__dirname, `signer-process${path.extname(__filename)}`);
+        const child = fork(script, [keypairPath], {
+            execArgv: __filename.endsWith('.ts') ? ['-r', 'ts-node/register'] : [],
+            // Nothing but the key path: the signer needs no config or secrets from us
+            env: { PATH: process.env.PATH },
+        });
+        return new Promise((resolve, reject) => {
+            const timer = setTimeout(() => reject(new Error(`signer for ${keypairPath} did not start`)), 30_000);
+            child.once('message', (hello: { ready: boolean; pubkey: string }) => {
+                clearTimeout(timer);
+                resolve(new ProcessSigner(child, new PublicKey(hello.pubkey)));
+            });
+            child.once('error', reject);
+        });
+    }
+
+    sign(message: Uint8Array): Promise<Uint8Array> {
+        const id = this.nextId++;
+        return new Promise((resolve, reject) => {
+            const timer = setTimeout(() => {
+                this.pending.delete(id);
+                reject(new Error('signer timed out'));
+            }, SIGNER_TIMEOUT_MS);
+            this.pending.set(id, {
+                resolve: signature => { clearTimeout(timer); resolve(signature); },
+                reject: error => { clearTimeout(timer); reject(error); },
+            });
+            this.child.send({ id, message: Array.from(message) });
+        });
+    }
+
+    close(): void {
+        this.child.kill();
+    }
+}
+
+export interface KeyPolicy {
+    /** null = every supported asset */
+    assets: number[] | null;
+    /** 0 = no limit */
+    max_amount: number;
+    paused: boolean;
+}
+
+export interface KeyMetrics {
+    requests: number;
+    signed: number;
+    /** Refused by this key's policy */
+    refused: number;
+    /** Verification failures, not found, not final (4xx) */
+    rejected: number;
+    errors: number;
+    /** Unix seconds */
+    last_signed_at: number | null;
+}
+
+export class ValidatorKey {
+    readonly metrics: KeyMetrics = { requests: 0, signed: 0, refused: 0, rejected: 0, errors: 0, last_signed_at: null };
+
+    constructor(readonly signer: SigningBackend, readonly policy: KeyPolicy) {}
+
+    get pubkey(): string {
+        return this.signer.publicKey.toBase58();
+    }
+
+    /**
+     * Reason this key refuses to sign, or null if its policy allows it
+     */
+    refusal(assetId?: number, amount?: number): string | null {
+        if (this.policy.paused) {
+            return 'Validator key is paused';
+        }
+        if (assetId !== undefined && this.policy.assets && !this.policy.assets.includes(assetId)) {
+            return `Validator key does not attest asset_id ${assetId}`;
+        }
+        if (amount !== undefined && this.policy.max_amount > 0 && amount > this.policy.max_amount) {
+            return `Burn amount ${amount} exceeds this key's limit of ${this.policy.max_amount}`;
+        }
+        return null;
+    }
+
+    /** Count one request by its HTTP status (403 = policy refusal) */
+    record(status: number): void {
+        this.metrics.requests++;
+        if (status === 200) {
+            this.metrics.signed++;
+            this.metrics.last_signed_at = Math.floor(Date.now() / 1000);
+        } else if (status === 403) {
+            this.metrics.refused++;
+        } else if (status >= 500) {
+            this.metrics.errors++;
+        } else {
+            this.metrics.rejected++;
+        }
+    }
+}
+
+export class ValidatorKeyRing {
+    private constructor(readonly keys: ValidatorKey[]) {}
+
+    /**
+     * Start every configured key's backend. Falls back to the single
+     * keys.validator (or legacy VALIDATOR_PRIVATE_KEY) as a local key.
+     */
+    static async load(config: BridgeConfig): Promise<ValidatorKeyRing> {
+        const entries: ValidatorKeyConfig[] = config.keys.validators || [];
+        if (entries.length === 0) {
+            const signer = new LocalSigner(loadKeypair(config, 'validator'));
+            return new ValidatorKeyRing([new ValidatorKey(signer, { assets: null, max_amount: 0, paused: false })]);
+        }
+
+        const keys = await Promise.all(entries.map(async entry => {
+            const file = expandHome(entry.keypair);
+            const signer = entry.backend === 'process'
+                ? await ProcessSigner.start(file)
+                : new LocalSigner(parseKeypair(fs.readFileSync(file, 'utf-8')));
+            return new ValidatorKey(signer, {
+                assets: entry.assets || null,
+                max_amount: entry.max_amount || 0,
+                paused: entry.paused || false,
+            });
+        }));
+        if (new Set(keys.map(k => k.pubkey)).size !== keys.length) {
+            keys.forEach(k => k.signer.close());
+            throw new Error('keys.validators lists the same validator key twice');
+        }
+        return new ValidatorKeyRing(keys);
+    }
+
+    get primary(): ValidatorKey {
+        return this.keys[0];
+    }
+
+    /** Key named by the X-Validator-Key header (primary when absent) */
+    select(pubkey?: string): ValidatorKey | undefined {
+        return pubkey ? this.keys.find(k => k.pubkey === pubkey) : this.primary;
+    }
+
+    close(): void {
+        this.keys.forEach(k => k.signer.close());
+    }
+}
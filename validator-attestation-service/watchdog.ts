@@ -0,0 +1,160 @@
+import { Connection, LAMPORTS_PER_SOL, PublicKey } from '@solana/web3.js';
+import type { BridgeConfig } from '../sdk/bridge-config/src';
+import { AlertDispatcher } from './notifier';
+
+/**
+ * Validator watchdog
+ *
+ * Periodically checks the things a validator operator has to act on and
+ * alerts through the configured notifiers (see notifier.ts):
+ *
+ * - not_in_validator_set: this key is not in the on-chain X1ValidatorSet
+ * - version_mismatch: relayers request signatures for a validator set
+ *   version other than the on-chain one (those signatures are useless)
+ * - low_balance: the validator key's X1 balance is under alerts.min_balance
+ * - missed_attestations: other validators' attestations keep landing
+ *   on-chain (ValidatorLatencyStats samples) while this validator's don't
+ *
+ * Read-only: uses the X1 RPC, never signs or sends transactions.
+ */
+
+/** Others must land this many attestations in one interval before ours count as missed */
+const MISSED_ATTESTATION_MIN = 3;
+/** ValidatorLatency: pubkey(32) samples(8) total_seconds(8) max_seconds(8) buckets(8 * 4) */
+const LATENCY_ENTRY_SIZE = 88;
+
+interface ValidatorSetState {
+    version: number;
+    validators: string[];
+}
+
+function decodeValidatorSet(data: Buffer): ValidatorSetState {
+    // disc(8) version(8) validators: vec<pubkey> threshold(1) bump(1)
+    const version = Number(data.readBigUInt64LE(8));
+    const count = data.readUInt32LE(16);
+    const validators: string[] = [];
+    for (let i = 0; i < count; i++) {
+        validators.push(new PublicKey(data.subarray(20 + 32 * i, 52 + 32 * i)).toBase58());
+    }
+    return { version, validators };
+}
+
+function decodeLatencySamples(data: Buffer): Map<string, number> {
+    // disc(8) entries: vec<ValidatorLatency> bump(1)
+    const samples = new Map<string, number>();
+    const count = data.readUInt32LE(8);
+    for (let i = 0; i < count; i++) {
+        const offset = 12 + LATENCY_ENTRY_SIZE * i;
+        samples.set(
+            new PublicKey(data.subarray(offset, offset + 32)).toBase58(),
+            Number(data.readBigUInt64LE(offset + 32))
+        );
+    }
+    return samples;
+}
+
+export class Watchdog {
+    private readonly connection: Connection;
+    private readonly validatorSetPda: PublicKey;
+    private readonly latencyStatsPda: PublicKey;
+    private onChainVersion?: number;
+    private previousSamples?: Map<string, number>;
+
+    constructor(
+        private readonly config: BridgeConfig,
+        private readonly validator: PublicKey,
+        private readonly alerts: AlertDispatcher
+    ) {
+        this.connection = new Connection(config.x1.rpc, config.x1.commitment);
+        const lightClient = new PublicKey(config.programs.light_client);
+        this.validatorSetPda = PublicKey.findProgramAddressSync([Buffer.from('x1_validator_set_v2')], lightClient)[0];
+        this.latencyStatsPda = PublicKey.findProgramAddressSync([Buffer.from('validator_latency_stats')], lightClient)[0];
+    }
+
+    /** Cooldown key per validator: several keys may share one dispatcher */
+    private alertKey(kind: string): string {
+        return `${kind}:${this.validator.toBase58()}`;
+    }
+
+    start(): void {
+        const run = () => this.check().catch(error => console.error('⚠️  Watchdog check failed:', error.message));
+        run();
+        setInterval(run, this.config.alerts.check_interval_seconds * 1000).unref();
+    }
+
+    /**
+     * Called for every attestation request with the version the relayer asked for
+     */
+    observeRequestedVersion(version: number): void {
+        if (this.onChainVersion === undefined || version === this.onChainVersion) {
+            return;
+        }
+        this.alerts.send({
+            kind: 'version_mismatch',
+            severity: 'warning',
+            validator: this.validator.toBase58(),
+            message: `Attestation requested for validator set version ${version}, on-chain version is ${this.onChainVersion}`,
+            details: { requested_version: version, on_chain_version: this.onChainVersion },
+        }, this.alertKey(`version_mismatch:${version}`));
+    }
+
+    async check(): Promise<void> {
+        const me = this.validator.toBase58();
+        const [setAccount, statsAccount, balance] = await Promise.all([
+            this.connection.getAccountInfo(this.validatorSetPda),
+            this.connection.getAccountInfo(this.latencyStatsPda),
+            this.connection.getBalance(this.validator),
+        ]);
+
+        if (setAccount) {
+            const set = decodeValidatorSet(setAccount.data);
+            this.onChainVersion = set.version;
+            if (!set.validators.includes(me)) {
+                await this.alerts.send({
+                    kind: 'not_in_validator_set',
+                    severity: 'critical',
+                    validator: me,
+                    message: `This key is not in validator set version ${set.version}; its attestations are rejected`,
+                    details: { version: set.version, validators: set.validators.length },
+                }, this.alertKey('not_in_validator_set'));
+            } else {
+                this.alerts.resolve(this.alertKey('not_in_validator_set'));
+            }
+        }
+
+        const minLamports = this.config.alerts.min_balance * LAMPORTS_PER_SOL;
+        if (balance < minLamports) {
+            await this.alerts.send({
+                kind: 'low_balance',
+                severity: 'warning',
+                validator: me,
+                message: `X1 balance ${balance / LAMPORTS_PER_SOL} XNT is below ${this.config.alerts.min_balance} XNT`,
+                details: { balance_lamports: balance, min_lamports: minLamports },
+            }, this.alertKey('low_balance'));
+        } else {
+            this.alerts.resolve(this.alertKey('low_balance'));
+        }
+
+        if (statsAccount) {
+            const samples = decodeLatencySamples(statsAccount.data);
+            const previous = this.previousSamples;
+            this.previousSamples = samples;
+            if (previous) {
+                const delta = (key: string) => (samples.get(key) ?? 0) - (previous.get(key) ?? 0);
+                const mine = delta(me);
+                const others = Math.max(0, ...[...samples.keys()].filter(k => k !== me).map(delta));
+                if (mine === 0 && others >= MISSED_ATTESTATION_MIN) {
+                    await this.alerts.send({
+                        kind: 'missed_attestations',
+                        severity: 'critical',
+                        validator: me,
+                        message: `Other validators landed ${others} attestations since the last check, this validator none (service unreachable or failing?)`,
+                        details: { others_landed: others, interval_seconds: this.config.alerts.check_interval_seconds },
+                    }, this.alertKey('missed_attestations'));
+                } else if (mine > 0) {
+                    this.alerts.resolve(this.alertKey('missed_attestations'));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,88 @@
+//! Reproducible build hashes of the bridge programs
+//!
+//! A program hash is the sha256 of its executable bytes with trailing zero
+//! bytes stripped. Deployed programs are padded with zeros up to the
+//! program data account size, so stripping makes a local `.so` and a
+//! dumped deployment hash the same. This is the hash governance attests in
+//! the light client's ProgramHashRegistry (`attest_program_hash`).
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::Path;
+
+/// Default output of `build`, input of `verify`
+pub const MANIFEST_FILE: &str = "program-hashes.json";
+
+/// One program of the workspace and its build hash
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq, Eq)]
+pub struct ProgramBuild {
+    /// Anchor program name (e.g. solana_light_client_x1)
+    pub name: String,
+    pub program_id: String,
+    /// Crate version (major, minor, patch)
+    pub version: [u16; 3],
+    /// Hex sha256, see `program_hash`
+    pub hash: String,
+    /// Executable size in bytes, padding excluded
+    pub size: usize,
+}
+
+/// What `build` produced, for validators to compare before approving
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct Manifest {
+    pub anchor_version: String,
+    /// `git rev-parse HEAD` of the built tree
+    pub commit: String,
+    pub programs: Vec<ProgramBuild>,
+}
+
+/// Executable bytes without the trailing zero padding
+pub fn strip_padding(bytes: &[u8]) -> &[u8] {
+    let end = bytes.iter().rposition(|&b| b != 0).map_or(0, |i| i + 1);
+    &bytes[..end]
+}
+
+pub fn program_hash(bytes: &[u8]) -> [u8; 32] {
+    Sha256::digest(strip_padding(bytes)).into()
+}
+
+/// Hash and padding-free size of a program file (.so or `solana program dump` output)
+pub fn hash_file(path: &Path) -> Result<([u8; 32], usize), String> {
+    let bytes = fs::read(path).map_err(|e| format!("{}: {}", path.display(), e))?;
+    Ok((program_hash(&bytes), strip_padding(&bytes).len()))
+}
+
+/// "1.2.3" -> [1, 2, 3] (same rules as the light client's parse_semver)
+pub fn parse_version(version: &str) -> [u16; 3] {
+    let mut parts = [0u16; 3];
+    for (part, value) in parts.iter_mut().zip(version.split('.')) {
+        let digits: String = value.chars().take_while(|c| c.is_ascii_digit()).collect();
+        *part = digits.parse().unwrap_or(0);
+    }
+    parts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_padding_does_not_change_hash() {
+        let program = b"\x7fELF program bytes\x00\x01";
+        let mut deployed = program.to_vec();
+        deployed.extend_from_slice(&[0u8; 4096]);
+
+        assert_eq!(program_hash(program), program_hash(&deployed));
+        assert_eq!(strip_padding(&deployed).len(), program.len());
+        assert_ne!(program_hash(program), program_hash(b"\x7fELF program bytes\x00\x02"));
+        assert_eq!(strip_padding(&[0u8; 8]), &[] as &[u8]);
+    }
+
+    #[test]
+    fn test_parse_version() {
+        assert_eq!(parse_version("0.1.0"), [0, 1, 0]);
+        assert_eq!(parse_version("3.2.1-beta"), [3, 2, 1]);
+        assert_eq!(parse_version("4"), [4, 0, 0]);
+    }
+}
@@ -0,0 +1,184 @@
+//! Reproducible builds of the bridge programs and deployed-hash checks
+//!
+//! ```shell
+//! # Build every program with `anchor build --verifiable` (pinned Docker
+//! # image) and write program-hashes.json
+//! cargo run -p xencat-verify-build -- build
+//!
+//! # Compare deployed programs against the manifest
+//! cargo run -p xencat-verify-build -- verify --url https://rpc.mainnet.x1.xyz
+//!
+//! # Hash any .so or `solana program dump` output
+//! cargo run -p xencat-verify-build -- hash target/verifiable/xencat_mint_x1.so
+//! ```
+//!
+//! Run from the repository root. Validators run `build` on the release
+//! commit before signing its `attest_program_hash` proposal (see
+//! scripts/program-hashes.ts).
+
+use clap::{Parser, Subcommand};
+use std::path::{Path, PathBuf};
+use std::process::{exit, Command};
+use std::fs;
+use xencat_verify_build::{hash_file, parse_version, Manifest, ProgramBuild, MANIFEST_FILE};
+
+#[derive(Parser, Debug)]
+#[command(author, version, about, long_about = None)]
+struct Args {
+    /// Repository root (holds Anchor.toml)
+    #[arg(long, default_value = ".")]
+    root: PathBuf,
+
+    /// Anchor.toml [programs.<cluster>] section to take program ids from
+    #[arg(long, default_value = "localnet")]
+    cluster: String,
+
+    #[command(subcommand)]
+    command: Cmd,
+}
+
+#[derive(Subcommand, Debug)]
+enum Cmd {
+    /// Verifiable build of every program, then write the hash manifest
+    Build {
+        /// Hash the existing target/verifiable output without rebuilding
+        #[arg(long)]
+        skip_build: bool,
+        #[arg(long, default_value = MANIFEST_FILE)]
+        out: PathBuf,
+    },
+    /// Print the hash of a program file
+    Hash { file: PathBuf },
+    /// Dump each deployed program and compare it with the manifest
+    Verify {
+        #[arg(long)]
+        url: String,
+        #[arg(long, default_value = MANIFEST_FILE)]
+        manifest: PathBuf,
+    },
+}
+
+fn main() {
+    let args = Args::parse();
+    let result = match &args.command {
+        Cmd::Build { skip_build, out } => build(&args, *skip_build, out),
+        Cmd::Hash { file } => hash_file(file).map(|(hash, size)| {
+            println!("{}  {} ({} bytes)", hex::encode(hash), file.display(), size);
+        }),
+        Cmd::Verify { url, manifest } => verify(&args, url, manifest),
+    };
+    if let Err(error) = result {
+        eprintln!("❌ {}", error);
+        exit(1);
+    }
+}
+
+/// (name, program id) of every program in Anchor.toml [programs.<cluster>]
+fn workspace_programs(args: &Args) -> Result<(String, Vec<(String, String)>), String> {
+    let anchor: toml::Value = read(&args.root.join("Anchor.toml"))?
+        .parse()
+        .map_err(|e| format!("Anchor.toml: {}", e))?;
+    let anchor_version = anchor
+        .get("toolchain")
+        .and_then(|t| t.get("anchor_version"))
+        .and_then(|v| v.as_str())
+        .unwrap_or("unknown")
+        .to_string();
+    let programs = anchor
+        .get("programs")
+        .and_then(|p| p.get(&args.cluster))
+        .and_then(|p| p.as_table())
+        .ok_or(format!("Anchor.toml has no [programs.{}]", args.cluster))?
+        .iter()
+        .map(|(name, id)| (name.clone(), id.as_str().unwrap_or_default().to_string()))
+        .collect();
+    Ok((anchor_version, programs))
+}
+
+fn read(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+fn run(command: &mut Command) -> Result<String, String> {
+    let output = command.output().map_err(|e| format!("{:?}: {}", command, e))?;
+    if !output.status.success() {
+        return Err(format!("{:?} failed: {}", command, String::from_utf8_lossy(&output.stderr)));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+fn crate_version(args: &Args, name: &str) -> Result<[u16; 3], String> {
+    let manifest: toml::Value = read(&args.root.join("programs").join(name.replace('_', "-")).join("Cargo.toml"))?
+        .parse()
+        .map_err(|e| format!("{} Cargo.toml: {}", name, e))?;
+    let version = manifest
+        .get("package")
+        .and_then(|p| p.get("version"))
+        .and_then(|v| v.as_str())
+        .ok_or(format!("{} Cargo.toml has no package.version", name))?;
+    Ok(parse_version(version))
+}
+
+fn build(args: &Args, skip_build: bool, out: &Path) -> Result<(), String> {
+    let (anchor_version, programs) = workspace_programs(args)?;
+    let commit = run(Command::new("git").arg("-C").arg(&args.root).args(["rev-parse", "HEAD"]))?;
+    let dirty = !run(Command::new("git").arg("-C").arg(&args.root).args(["status", "--porcelain", "--", "programs"]))?.is_empty();
+    if dirty {
+        println!("⚠️  programs/ has uncommitted changes: the hashes will not match the commit");
+    }
+
+    if !skip_build {
+        println!("🔨 anchor build --verifiable (Anchor {}, commit {})", anchor_version, commit);
+        let status = Command::new("anchor")
+            .args(["build", "--verifiable"])
+            .current_dir(&args.root)
+            .status()
+            .map_err(|e| format!("anchor: {}", e))?;
+        if !status.success() {
+            return Err("anchor build --verifiable failed".into());
+        }
+    }
+
+    let mut builds = Vec::new();
+    for (name, program_id) in programs {
+        let (hash, size) = hash_file(&args.root.join("target/verifiable").join(format!("{}.so", name)))?;
+        let version = crate_version(args, &name)?;
+        println!("   {:<24} v{}.{}.{}  {}", name, version[0], version[1], version[2], hex::encode(hash));
+        builds.push(ProgramBuild { name, program_id, version, hash: hex::encode(hash), size });
+    }
+
+    let manifest = Manifest { anchor_version, commit, programs: builds };
+    fs::write(out, serde_json::to_string_pretty(&manifest).unwrap()).map_err(|e| format!("{}: {}", out.display(), e))?;
+    println!("📝 Wrote {}", out.display());
+    Ok(())
+}
+
+fn verify(args: &Args, url: &str, manifest_path: &Path) -> Result<(), String> {
+    let manifest: Manifest = serde_json::from_str(&read(manifest_path)?)
+        .map_err(|e| format!("{}: {}", manifest_path.display(), e))?;
+    let dump_dir = std::env::temp_dir().join("xencat-verify-build");
+    fs::create_dir_all(&dump_dir).map_err(|e| e.to_string())?;
+
+    println!("🔍 Deployed programs on {} vs {} (commit {})", url, manifest_path.display(), manifest.commit);
+    let mut mismatches = 0;
+    for program in &manifest.programs {
+        let dump = dump_dir.join(format!("{}.so", program.name));
+        run(Command::new("solana")
+            .args(["program", "dump", "--url", url, &program.program_id])
+            .arg(&dump)
+            .current_dir(&args.root))?;
+        let (hash, _) = hash_file(&dump)?;
+        if hex::encode(hash) == program.hash {
+            println!("   ✅ {:<24} {}", program.name, program.hash);
+        } else {
+            mismatches += 1;
+            println!("   ❌ {:<24} deployed {}, expected {}", program.name, hex::encode(hash), program.hash);
+        }
+    }
+
+    if mismatches > 0 {
+        return Err(format!("{} program(s) do not match the build", mismatches));
+    }
+    println!("✅ Every deployed program matches the reproducible build");
+    Ok(())
+}